@@ -0,0 +1,164 @@
+use std::path::{Path, PathBuf};
+
+use tauri::AppHandle;
+use tauri_plugin_db::DatabasePluginExt;
+use tauri_plugin_listener::ListenerPluginExt;
+
+/// Flags that script the app instead of opening it. A second launch forwards them to the
+/// running instance through the single-instance plugin.
+#[derive(Debug, PartialEq)]
+pub enum CliCommand {
+    StartRecording,
+    StopRecording,
+    Export { note_id: String, path: PathBuf },
+    Quit,
+}
+
+/// `cwd` resolves relative export paths, since forwarded args come from another process.
+pub fn parse(args: &[String], cwd: &Path) -> Vec<CliCommand> {
+    let mut commands = vec![];
+    let mut args = args.iter().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--start-recording" => commands.push(CliCommand::StartRecording),
+            "--stop-recording" => commands.push(CliCommand::StopRecording),
+            "--quit" => commands.push(CliCommand::Quit),
+            "--export" => match (args.next(), args.next()) {
+                (Some(note_id), Some(path)) => commands.push(CliCommand::Export {
+                    note_id: note_id.clone(),
+                    path: cwd.join(path),
+                }),
+                _ => tracing::error!("cli_export_missing_args: --export <note-id> <path>"),
+            },
+            _ => {}
+        }
+    }
+
+    commands
+}
+
+pub async fn run(app: &AppHandle, commands: Vec<CliCommand>) {
+    for command in commands {
+        tracing::info!("cli: {:?}", command);
+
+        let result = match command {
+            CliCommand::StartRecording => start_recording(app).await,
+            CliCommand::StopRecording => {
+                app.stop_session().await;
+                Ok(())
+            }
+            CliCommand::Export { note_id, path } => export(app, &note_id, &path).await,
+            CliCommand::Quit => {
+                app.exit(0);
+                return;
+            }
+        };
+
+        if let Err(e) = result {
+            tracing::error!("cli_command_failed: {}", e);
+        }
+    }
+}
+
+async fn start_recording(app: &AppHandle) -> Result<(), String> {
+    if !matches!(
+        app.get_state().await,
+        tauri_plugin_listener::fsm::State::Inactive
+    ) {
+        return Ok(());
+    }
+
+    let user_id = app
+        .db_user_id()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("no user")?;
+
+    let now = chrono::Utc::now();
+    let session = hypr_db_user::Session {
+        id: uuid::Uuid::new_v4().to_string(),
+        created_at: now,
+        visited_at: now,
+        user_id,
+        calendar_event_id: None,
+        title: String::new(),
+        raw_memo_html: String::new(),
+        enhanced_memo_html: None,
+        conversations: vec![],
+        words: vec![],
+        record_start: None,
+        record_end: None,
+        pre_meeting_memo_html: None,
+    };
+    let id = session.id.clone();
+
+    app.db_upsert_session(session)
+        .await
+        .map_err(|e| e.to_string())?;
+    app.start_session(id).await;
+
+    Ok(())
+}
+
+async fn export(app: &AppHandle, note_id: &str, path: &Path) -> Result<(), String> {
+    let session = app
+        .db_get_session(note_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("note not found: {}", note_id))?;
+
+    let html = session
+        .enhanced_memo_html
+        .as_deref()
+        .unwrap_or(&session.raw_memo_html);
+    let body = hypr_buffer::html_to_md(html).map_err(|e| e.to_string())?;
+
+    let content = match session.title.trim() {
+        "" => body,
+        title => format!("# {}\n\n{}", title, body),
+    };
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(path, content).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        std::iter::once("hyprnote")
+            .chain(args.iter().copied())
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let cwd = Path::new("/home/user");
+
+        assert_eq!(parse(&args(&["--background"]), cwd), vec![]);
+        assert_eq!(
+            parse(&args(&["--stop-recording", "--quit"]), cwd),
+            vec![CliCommand::StopRecording, CliCommand::Quit]
+        );
+        assert_eq!(
+            parse(&args(&["--export", "abc", "notes/abc.md"]), cwd),
+            vec![CliCommand::Export {
+                note_id: "abc".to_string(),
+                path: PathBuf::from("/home/user/notes/abc.md"),
+            }]
+        );
+        assert_eq!(
+            parse(&args(&["--export", "abc", "/tmp/abc.md"]), cwd)[0],
+            CliCommand::Export {
+                note_id: "abc".to_string(),
+                path: PathBuf::from("/tmp/abc.md"),
+            }
+        );
+        assert_eq!(parse(&args(&["--export", "abc"]), cwd), vec![]);
+    }
+}
@@ -1,4 +1,12 @@
-use crate::{AppExt, StoreKey};
+use crate::{
+    locale,
+    permissions::{self, Permission, PermissionCheck},
+    profiles::{self, ProfileInfo},
+    share,
+    shortcuts::{self, ShortcutAction, ShortcutBinding},
+    updates::{self, UpdateChannel, UpdateInfo},
+    AppExt, StoreKey,
+};
 
 #[tauri::command]
 #[specta::specta]
@@ -79,3 +87,132 @@ pub fn set_individualization_needed<R: tauri::Runtime>(
         .set(StoreKey::IndividualizationNeeded, v)
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub fn list_shortcuts(app: tauri::AppHandle<tauri::Wry>) -> Vec<ShortcutBinding> {
+    shortcuts::list(&app)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_shortcut(
+    app: tauri::AppHandle<tauri::Wry>,
+    action: ShortcutAction,
+    accelerator: Option<String>,
+) -> Result<Vec<ShortcutBinding>, String> {
+    shortcuts::set(&app, action, accelerator)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn reset_shortcut(
+    app: tauri::AppHandle<tauri::Wry>,
+    action: ShortcutAction,
+) -> Result<Vec<ShortcutBinding>, String> {
+    shortcuts::reset(&app, action)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_update_channel(app: tauri::AppHandle<tauri::Wry>) -> UpdateChannel {
+    updates::get_channel(&app)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_update_channel(
+    app: tauri::AppHandle<tauri::Wry>,
+    channel: UpdateChannel,
+) -> Result<(), String> {
+    updates::set_channel(&app, channel)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn check_for_update(
+    app: tauri::AppHandle<tauri::Wry>,
+) -> Result<Option<UpdateInfo>, String> {
+    updates::check(&app).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn install_update(
+    app: tauri::AppHandle<tauri::Wry>,
+    on_progress: tauri::ipc::Channel<i8>,
+) -> Result<(), String> {
+    updates::install(&app, on_progress).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_display_language(
+    app: tauri::AppHandle<tauri::Wry>,
+    language: String,
+) -> Result<(), String> {
+    locale::apply(&app, &language).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_permission_report(app: tauri::AppHandle<tauri::Wry>) -> Vec<PermissionCheck> {
+    permissions::report(&app).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn open_permission_settings(
+    app: tauri::AppHandle<tauri::Wry>,
+    permission: Permission,
+) -> Result<(), String> {
+    permissions::open_settings(&app, permission)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn export_note_html(
+    app: tauri::AppHandle<tauri::Wry>,
+    session_id: String,
+    password: Option<String>,
+) -> Result<String, String> {
+    share::export_html(&app, session_id, password).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn export_note(
+    app: tauri::AppHandle<tauri::Wry>,
+    session_id: String,
+    format: hypr_export::ExportFormat,
+    template: Option<hypr_export::ExportTemplate>,
+) -> Result<String, String> {
+    share::export_note(&app, session_id, format, template.unwrap_or_default()).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn list_profiles(app: tauri::AppHandle<tauri::Wry>) -> Result<Vec<ProfileInfo>, String> {
+    profiles::list(&app)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn create_profile(
+    app: tauri::AppHandle<tauri::Wry>,
+    name: String,
+) -> Result<ProfileInfo, String> {
+    profiles::create(&app, name)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn delete_profile(app: tauri::AppHandle<tauri::Wry>, id: String) -> Result<(), String> {
+    profiles::delete(&app, id)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn switch_profile(app: tauri::AppHandle<tauri::Wry>, id: String) -> Result<(), String> {
+    profiles::switch(&app, id).await
+}
@@ -4,6 +4,7 @@ use tauri_plugin_windows::HyprWindow;
 pub enum DeeplinkAction {
     OpenInternal(HyprWindow, String),
     OpenExternal(String),
+    StopRecording,
 }
 
 pub fn parse(url: String) -> Vec<DeeplinkAction> {
@@ -19,12 +20,16 @@ pub fn parse(url: String) -> Vec<DeeplinkAction> {
         "/notification" => parse_notification_query(&parsed_url),
         "/register" => parse_register_query(&parsed_url),
         "/license" => parse_license_query(&parsed_url),
+        "/record/start" => parse_record_start_query(&parsed_url),
+        "/record/stop" => vec![DeeplinkAction::StopRecording],
 
+        path if path.starts_with("/note/") => parse_note_path(path),
         path => {
-            vec![DeeplinkAction::OpenInternal(
-                HyprWindow::Main,
-                path.to_string(),
-            )]
+            let path = match parsed_url.query() {
+                Some(query) => format!("{}?{}", path, query),
+                None => path.to_string(),
+            };
+            vec![DeeplinkAction::OpenInternal(HyprWindow::Main, path)]
         }
     };
 
@@ -110,12 +115,47 @@ fn parse_license_query(parsed_url: &url::Url) -> Vec<DeeplinkAction> {
     ]
 }
 
+fn parse_record_start_query(parsed_url: &url::Url) -> Vec<DeeplinkAction> {
+    let params = parsed_url
+        .query()
+        .and_then(|query| serde_qs::from_str::<RecordStartQuery>(query).ok());
+
+    let mut query = url::form_urlencoded::Serializer::new(String::new());
+    query.append_pair("record", "true");
+    if let Some(event_id) = params.and_then(|p| p.event_id) {
+        query.append_pair("event_id", &event_id);
+    }
+
+    vec![DeeplinkAction::OpenInternal(
+        HyprWindow::Main,
+        format!("/app/new?{}", query.finish()),
+    )]
+}
+
+fn parse_note_path(path: &str) -> Vec<DeeplinkAction> {
+    match path.trim_start_matches("/note/").trim_end_matches('/') {
+        "" => vec![DeeplinkAction::OpenInternal(
+            HyprWindow::Main,
+            "/app".to_string(),
+        )],
+        id => vec![DeeplinkAction::OpenInternal(
+            HyprWindow::Note(id.to_string()),
+            format!("/app/note/{}", id),
+        )],
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 struct NotificationQuery {
     event_id: Option<String>,
     event_url: Option<String>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RecordStartQuery {
+    event_id: Option<String>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 struct RegisterQuery {
     base_url: String,
@@ -158,6 +198,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_internal_path_keeps_query() {
+        let url = "hypr://hyprnote.com/app/new?record=true&event_id=abc";
+
+        let actions = parse(url.to_string());
+        assert_eq!(actions.len(), 1);
+
+        match &actions[0] {
+            DeeplinkAction::OpenInternal(window, url) => {
+                assert_eq!(*window, HyprWindow::Main);
+                assert_eq!(url, "/app/new?record=true&event_id=abc");
+            }
+            _ => panic!("Expected OpenInternal action"),
+        }
+    }
+
+    #[test]
+    fn test_parse_record_actions() {
+        let url = "hypr://hyprnote.com/record/start?event_id=abc%20123";
+        match &parse(url.to_string())[..] {
+            [DeeplinkAction::OpenInternal(window, url)] => {
+                assert_eq!(*window, HyprWindow::Main);
+                assert_eq!(url, "/app/new?record=true&event_id=abc+123");
+            }
+            actions => panic!("Unexpected actions: {:?}", actions),
+        }
+
+        let url = "hypr://hyprnote.com/record/start";
+        match &parse(url.to_string())[..] {
+            [DeeplinkAction::OpenInternal(_, url)] => assert_eq!(url, "/app/new?record=true"),
+            actions => panic!("Unexpected actions: {:?}", actions),
+        }
+
+        let url = "hypr://hyprnote.com/record/stop";
+        assert!(matches!(
+            &parse(url.to_string())[..],
+            [DeeplinkAction::StopRecording]
+        ));
+    }
+
+    #[test]
+    fn test_parse_note_path() {
+        let url = "hypr://hyprnote.com/note/123";
+        match &parse(url.to_string())[..] {
+            [DeeplinkAction::OpenInternal(window, url)] => {
+                assert_eq!(*window, HyprWindow::Note("123".to_string()));
+                assert_eq!(url, "/app/note/123");
+            }
+            actions => panic!("Unexpected actions: {:?}", actions),
+        }
+    }
+
     #[test]
     fn test_parse_license_query() {
         let url = "hypr://hyprnote.com/license?key=123";
@@ -1,7 +1,14 @@
+mod cli;
 mod commands;
 mod deeplink;
 mod ext;
+mod locale;
+mod permissions;
+mod profiles;
+mod share;
+mod shortcuts;
 mod store;
+mod updates;
 
 use ext::*;
 use store::*;
@@ -13,6 +20,35 @@ use tauri_plugin_windows::{HyprWindow, WindowsPluginExt};
 pub async fn main() {
     tauri::async_runtime::set(tokio::runtime::Handle::current());
 
+    let context = tauri::generate_context!();
+
+    // Before any plugin resolves a path.
+    let data_dir = hypr_data_dir::init(&std::env::args().collect::<Vec<_>>())
+        // Same as Tauri's app data directory.
+        .or_else(|| dirs::data_dir().map(|dir| dir.join(&context.config().identifier)));
+    if let Some(dir) = data_dir.as_deref() {
+        hypr_data_dir::profile::apply(dir);
+    }
+
+    // Until the user's display language is loaded from the database.
+    hypr_i18n::set_locale(hypr_i18n::Locale::detect());
+
+    // Launched by an MCP client (e.g. Claude Desktop) as a stdio server, not by the user.
+    if std::env::args().any(|arg| arg == "--mcp-stdio") {
+        let arg_value = |name: &str| std::env::args().skip_while(|arg| arg != name).nth(1);
+
+        let port = arg_value("--mcp-port")
+            .and_then(|port| port.parse().ok())
+            .unwrap_or(tauri_plugin_mcp::DEFAULT_PORT);
+        let api_key = std::env::var(tauri_plugin_mcp::API_KEY_ENV).unwrap_or_default();
+
+        if let Err(e) = tauri_plugin_mcp::run_stdio_bridge(port, &api_key).await {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let sentry_client = tauri_plugin_sentry::sentry::init((
         {
             #[cfg(not(debug_assertions))]
@@ -29,6 +65,9 @@ pub async fn main() {
             release: tauri_plugin_sentry::sentry::release_name!(),
             traces_sample_rate: 1.0,
             auto_session_tracking: true,
+            before_send: Some(std::sync::Arc::new(|event| {
+                hypr_consent::allows(hypr_consent::Category::CrashReports).then_some(event)
+            })),
             ..Default::default()
         },
     ));
@@ -40,8 +79,17 @@ pub async fn main() {
     // https://v2.tauri.app/plugin/deep-linking/#desktop
     // should always be the first plugin
     {
-        builder = builder.plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
-            app.window_show(HyprWindow::Main).unwrap();
+        builder = builder.plugin(tauri_plugin_single_instance::init(|app, argv, cwd| {
+            let commands = cli::parse(&argv, std::path::Path::new(&cwd));
+            if commands.is_empty() {
+                app.window_show(HyprWindow::Main).unwrap();
+                return;
+            }
+
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                cli::run(&app, commands).await;
+            });
         }));
     }
 
@@ -50,11 +98,6 @@ pub async fn main() {
         builder = builder.plugin(tauri_nspanel::init());
     }
 
-    let ctrl_n_shortcut = {
-        use tauri_plugin_global_shortcut::{Code, Modifiers, Shortcut};
-        Shortcut::new(Some(Modifiers::META | Modifiers::ALT), Code::KeyH)
-    };
-
     builder = builder
         .plugin(tauri_plugin_listener::init())
         .plugin(tauri_plugin_sse::init())
@@ -65,6 +108,7 @@ pub async fn main() {
         .plugin(tauri_plugin_store::Builder::default().build())
         .plugin(tauri_plugin_store2::init())
         .plugin(tauri_plugin_template::init())
+        .plugin(tauri_plugin_download_manager::init())
         .plugin(tauri_plugin_local_llm::init())
         .plugin(tauri_plugin_local_stt::init())
         .plugin(tauri_plugin_connector::init())
@@ -74,6 +118,7 @@ pub async fn main() {
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_webhook::init())
+        .plugin(tauri_plugin_calendar::init())
         .plugin(tauri_plugin_mcp::init())
         .plugin(tauri_plugin_obsidian::init())
         .plugin(tauri_plugin_sfx::init())
@@ -92,29 +137,7 @@ pub async fn main() {
         .plugin(tauri_plugin_process::init())
         .plugin(
             tauri_plugin_global_shortcut::Builder::new()
-                .with_handler(move |app, shortcut, event| {
-                    use tauri_plugin_global_shortcut::ShortcutState;
-                    use tauri_plugin_windows::{HyprWindow, Navigate};
-
-                    if shortcut == &ctrl_n_shortcut {
-                        match event.state() {
-                            ShortcutState::Pressed => {
-                                if let Ok(_) = HyprWindow::Main.show(&app) {
-                                    std::thread::sleep(std::time::Duration::from_millis(100));
-
-                                    let _ = HyprWindow::Main.emit_navigate(
-                                        &app,
-                                        Navigate {
-                                            path: "/app/new?record=true".to_string(),
-                                            search: None,
-                                        },
-                                    );
-                                }
-                            }
-                            _ => {}
-                        }
-                    }
-                })
+                .with_handler(shortcuts::handle)
                 .build(),
         )
         .plugin(tauri_plugin_autostart::init(
@@ -161,7 +184,9 @@ pub async fn main() {
     let specta_builder = make_specta_builder();
 
     let args: Vec<String> = std::env::args().collect();
-    let is_background_launch = args.contains(&"--background".to_string());
+    let cli_commands = cli::parse(&args, &std::env::current_dir().unwrap_or_default());
+    let is_background_launch =
+        args.contains(&"--background".to_string()) || !cli_commands.is_empty();
 
     let app = builder
         .invoke_handler({
@@ -169,15 +194,15 @@ pub async fn main() {
             move |invoke| handler(invoke)
         })
         .on_window_event(tauri_plugin_windows::on_window_event)
+        .manage(updates::PendingUpdate::default())
         .setup(move |app| {
             let app = app.handle().clone();
 
             specta_builder.mount_events(&app);
 
-            // {
-            //     use tauri_plugin_global_shortcut::GlobalShortcutExt;
-            //     app.global_shortcut().register(ctrl_n_shortcut)?;
-            // }
+            if let Err(e) = shortcuts::register_all(&app) {
+                tracing::error!("failed_to_register_shortcuts: {}", e);
+            }
 
             {
                 use tauri_plugin_deep_link::DeepLinkExt;
@@ -204,6 +229,14 @@ pub async fn main() {
                             deeplink::DeeplinkAction::OpenExternal(url) => {
                                 let _ = app_clone.opener().open_url(url.as_str(), None::<String>);
                             }
+                            deeplink::DeeplinkAction::StopRecording => {
+                                use tauri_plugin_listener::ListenerPluginExt;
+
+                                let app = app_clone.clone();
+                                tauri::async_runtime::spawn(async move {
+                                    app.stop_session().await;
+                                });
+                            }
                         }
                     }
                 });
@@ -215,6 +248,40 @@ pub async fn main() {
                 app.create_app_menu().unwrap();
             }
 
+            {
+                use tauri_plugin_listener::SessionEvent;
+                use tauri_specta::Event;
+
+                let app_clone = app.clone();
+                SessionEvent::listen_any(&app, move |event| match event.payload {
+                    SessionEvent::WakePhraseDetected {} => {
+                        shortcuts::new_note_and_record(&app_clone);
+                    }
+                    SessionEvent::WakeListening { phrase } => {
+                        use tauri_plugin_tray::TrayPluginExt;
+                        let _ = app_clone.set_wake_phrase(phrase);
+                    }
+                    _ => {}
+                });
+            }
+
+            {
+                use tauri_plugin_tray::TrayEvent;
+                use tauri_specta::Event;
+
+                let app_clone = app.clone();
+                TrayEvent::listen_any(&app, move |event| {
+                    if let TrayEvent::SwitchProfile { id } = event.payload {
+                        let app = app_clone.clone();
+                        tauri::async_runtime::spawn(async move {
+                            if let Err(e) = profiles::switch(&app, id).await {
+                                tracing::error!("failed_to_switch_profile: {}", e);
+                            }
+                        });
+                    }
+                });
+            }
+
             {
                 use tauri_plugin_autostart::ManagerExt;
                 let autostart_manager = app.autolaunch();
@@ -235,9 +302,22 @@ pub async fn main() {
                         let config = app_clone.db_get_config(user_id).await;
 
                         if let Ok(Some(ref config)) = config {
-                            if !config.general.telemetry_consent {
-                                let _ =
-                                    sentry_client.close(Some(std::time::Duration::from_secs(1)));
+                            // Carry an opt-out from the old single telemetry toggle over to
+                            // every category, then drop the old flag so this runs once.
+                            if let Some(telemetry_consent) = config.general.telemetry_consent {
+                                use tauri_plugin_analytics::{AnalyticsPluginExt, Consent};
+
+                                if !telemetry_consent {
+                                    let _ = app_clone.set_consent(Consent::all(false));
+                                }
+                                let _ = app_clone.db_set_config(config.clone()).await;
+                            }
+
+                            if let Err(e) = locale::apply(
+                                &app_clone,
+                                config.general.display_language.iso639().code(),
+                            ) {
+                                tracing::error!("failed_to_apply_locale: {}", e);
                             }
 
                             {
@@ -259,11 +339,20 @@ pub async fn main() {
                         });
                     }
                 }
+
+                {
+                    use tauri_plugin_listener::ListenerPluginExt;
+                    if let Err(e) = app_clone.refresh_wake_listener().await {
+                        tracing::warn!("failed_to_start_wake_listener: {}", e);
+                    }
+                }
+
+                cli::run(&app_clone, cli_commands).await;
             });
 
             Ok(())
         })
-        .build(tauri::generate_context!())
+        .build(context)
         .unwrap();
 
     if !is_background_launch {
@@ -289,6 +378,22 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::set_autostart::<tauri::Wry>,
             commands::is_individualization_needed::<tauri::Wry>,
             commands::set_individualization_needed::<tauri::Wry>,
+            commands::list_shortcuts,
+            commands::set_shortcut,
+            commands::reset_shortcut,
+            commands::get_update_channel,
+            commands::set_update_channel,
+            commands::check_for_update,
+            commands::install_update,
+            commands::set_display_language,
+            commands::get_permission_report,
+            commands::open_permission_settings,
+            commands::export_note_html,
+            commands::export_note,
+            commands::list_profiles,
+            commands::create_profile,
+            commands::delete_profile,
+            commands::switch_profile,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
@@ -0,0 +1,14 @@
+use tauri::AppHandle;
+use tauri_plugin_tray::TrayPluginExt;
+
+/// Switches native menus, dialogs and notifications to `language` (ISO 639-1).
+/// Languages without native strings fall back to English.
+pub fn apply(app: &AppHandle, language: &str) -> tauri::Result<()> {
+    let locale = hypr_i18n::Locale::from_tag(language).unwrap_or_default();
+    if locale == hypr_i18n::locale() {
+        return Ok(());
+    }
+
+    hypr_i18n::set_locale(locale);
+    app.refresh_locale()
+}
@@ -0,0 +1,141 @@
+use tauri::AppHandle;
+use tauri_plugin_listener::ListenerPluginExt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum Permission {
+    Microphone,
+    SystemAudio,
+    Accessibility,
+    Calendar,
+    Notifications,
+}
+
+impl Permission {
+    const ALL: [Permission; 5] = [
+        Permission::Microphone,
+        Permission::SystemAudio,
+        Permission::Accessibility,
+        Permission::Calendar,
+        Permission::Notifications,
+    ];
+
+    /// Recording doesn't work without these; the rest only turn off individual features.
+    fn required(self) -> bool {
+        matches!(self, Permission::Microphone | Permission::SystemAudio)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionStatus {
+    Granted,
+    NotGranted,
+    // Nothing to grant on this platform.
+    Unsupported,
+}
+
+impl From<bool> for PermissionStatus {
+    fn from(granted: bool) -> Self {
+        if granted {
+            Self::Granted
+        } else {
+            Self::NotGranted
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, specta::Type)]
+pub struct PermissionCheck {
+    pub permission: Permission,
+    pub status: PermissionStatus,
+    pub required: bool,
+    /// Whether `open_permission_settings` has a settings pane to show for it.
+    pub has_settings: bool,
+}
+
+pub async fn report(app: &AppHandle) -> Vec<PermissionCheck> {
+    let mut checks = Vec::with_capacity(Permission::ALL.len());
+
+    for permission in Permission::ALL {
+        checks.push(PermissionCheck {
+            permission,
+            status: status(app, permission).await,
+            required: permission.required(),
+            has_settings: settings_url(permission).is_some(),
+        });
+    }
+
+    checks
+}
+
+async fn status(app: &AppHandle, permission: Permission) -> PermissionStatus {
+    match permission {
+        Permission::Microphone => app.check_microphone_access().await.unwrap_or(false).into(),
+        Permission::SystemAudio => app
+            .check_system_audio_access()
+            .await
+            .unwrap_or(false)
+            .into(),
+        Permission::Accessibility => {
+            #[cfg(target_os = "macos")]
+            return hypr_detect::Detector::default()
+                .macos_check_accessibility_permission()
+                .unwrap_or(false)
+                .into();
+
+            #[cfg(not(target_os = "macos"))]
+            return PermissionStatus::Unsupported;
+        }
+        Permission::Calendar => {
+            #[cfg(target_os = "macos")]
+            {
+                use tauri_plugin_apple_calendar::AppleCalendarPluginExt;
+                return app.calendar_access_status().into();
+            }
+
+            #[cfg(not(target_os = "macos"))]
+            return PermissionStatus::Unsupported;
+        }
+        // Notifications are drawn by the app itself rather than posted through the system.
+        Permission::Notifications => {
+            if cfg!(target_os = "macos") {
+                PermissionStatus::Granted
+            } else {
+                PermissionStatus::Unsupported
+            }
+        }
+    }
+}
+
+fn settings_url(permission: Permission) -> Option<&'static str> {
+    if !cfg!(target_os = "macos") {
+        return None;
+    }
+
+    match permission {
+        Permission::Microphone => {
+            Some("x-apple.systempreferences:com.apple.preference.security?Privacy_Microphone")
+        }
+        Permission::SystemAudio => {
+            Some("x-apple.systempreferences:com.apple.preference.security?Privacy_AudioCapture")
+        }
+        Permission::Accessibility => {
+            Some("x-apple.systempreferences:com.apple.preference.security?Privacy_Accessibility")
+        }
+        Permission::Calendar => {
+            Some("x-apple.systempreferences:com.apple.preference.security?Privacy_Calendars")
+        }
+        Permission::Notifications => None,
+    }
+}
+
+pub fn open_settings(app: &AppHandle, permission: Permission) -> Result<(), String> {
+    use tauri_plugin_opener::OpenerExt;
+
+    let url =
+        settings_url(permission).ok_or_else(|| format!("no settings pane for {:?}", permission))?;
+    app.opener()
+        .open_url(url, None::<String>)
+        .map_err(|e| e.to_string())
+}
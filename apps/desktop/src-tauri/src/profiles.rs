@@ -0,0 +1,103 @@
+use std::path::PathBuf;
+
+use hypr_data_dir::profile::{self, Profile, Profiles};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_tray::TrayPluginExt;
+
+#[derive(Debug, Clone, serde::Serialize, specta::Type)]
+pub struct ProfileInfo {
+    pub id: String,
+    pub name: String,
+    pub active: bool,
+}
+
+impl ProfileInfo {
+    fn new(profile: Profile, active: &str) -> Self {
+        Self {
+            active: profile.id == active,
+            id: profile.id,
+            name: profile.name,
+        }
+    }
+}
+
+/// Where the profile list lives, whichever profile is open.
+fn root(app: &AppHandle) -> Result<PathBuf, String> {
+    hypr_data_dir::resolve_shared(|| app.path().app_data_dir()).map_err(|e| e.to_string())
+}
+
+fn update<T>(
+    app: &AppHandle,
+    f: impl FnOnce(&mut Profiles) -> Result<T, String>,
+) -> Result<T, String> {
+    let root = root(app)?;
+    let mut profiles = Profiles::load_or_default(&root);
+
+    let value = f(&mut profiles)?;
+    profiles.save(&root).map_err(|e| e.to_string())?;
+
+    if let Err(e) = app.refresh_profiles() {
+        tracing::warn!("failed_to_refresh_profile_menu: {}", e);
+    }
+    Ok(value)
+}
+
+pub fn list(app: &AppHandle) -> Result<Vec<ProfileInfo>, String> {
+    let profiles = Profiles::load_or_default(&root(app)?);
+    let active = profiles.active().id;
+
+    Ok(profiles
+        .profiles
+        .into_iter()
+        .map(|profile| ProfileInfo::new(profile, &active))
+        .collect())
+}
+
+pub fn create(app: &AppHandle, name: String) -> Result<ProfileInfo, String> {
+    if name.trim().is_empty() {
+        return Err("profile_name_empty".to_string());
+    }
+
+    update(app, |profiles| {
+        let profile = profiles.create(&name);
+        Ok(ProfileInfo::new(profile, &profiles.active().id))
+    })
+}
+
+/// Removes the profile along with everything recorded in it.
+pub fn delete(app: &AppHandle, id: String) -> Result<(), String> {
+    update(app, |profiles| {
+        if !profiles.remove(&id) {
+            return Err("profile_not_deletable".to_string());
+        }
+        Ok(())
+    })?;
+
+    let dir = profile::dir(&root(app)?, &id);
+    if dir.exists() {
+        std::fs::remove_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Plugins resolve their paths once at startup, so opening another profile means
+/// restarting into it. A running recording is stopped first so it is saved.
+pub async fn switch(app: &AppHandle, id: String) -> Result<(), String> {
+    let root = root(app)?;
+    let mut profiles = Profiles::load_or_default(&root);
+
+    if profiles.active().id == id {
+        return Ok(());
+    }
+    if !profiles.activate(&id) {
+        return Err("profile_not_found".to_string());
+    }
+
+    {
+        use tauri_plugin_listener::ListenerPluginExt;
+        app.stop_session().await;
+    }
+
+    profiles.save(&root).map_err(|e| e.to_string())?;
+    app.restart()
+}
@@ -0,0 +1,470 @@
+use std::num::NonZeroU32;
+
+use base64::Engine;
+use hypr_export::{format_timestamp, paragraphs};
+use ring::{
+    aead, pbkdf2,
+    rand::{SecureRandom, SystemRandom},
+};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_db::DatabasePluginExt;
+
+// OWASP's recommendation for PBKDF2-HMAC-SHA256.
+const PBKDF2_ITERATIONS: u32 = 600_000;
+
+const STYLE: &str = r#"
+body { max-width: 720px; margin: 48px auto; padding: 0 24px; font: 16px/1.6 -apple-system, BlinkMacSystemFont, "Segoe UI", sans-serif; color: #1f2937; }
+h1 { margin-bottom: 4px; }
+h2 { margin-top: 40px; border-bottom: 1px solid #e5e7eb; padding-bottom: 4px; }
+.meta, .time { color: #6b7280; font-size: 14px; }
+.time { font-variant-numeric: tabular-nums; margin-right: 8px; }
+.chapters a { color: inherit; }
+form { display: flex; gap: 8px; margin-top: 24px; }
+input { flex: 1; padding: 8px; font-size: 16px; }
+"#;
+
+const UNLOCK_SCRIPT: &str = r#"
+const payload = JSON.parse(document.getElementById("payload").textContent);
+const bytes = (b64) => Uint8Array.from(atob(b64), (c) => c.charCodeAt(0));
+
+document.getElementById("unlock").addEventListener("submit", async (e) => {
+  e.preventDefault();
+  const password = new TextEncoder().encode(document.getElementById("password").value);
+
+  try {
+    const base = await crypto.subtle.importKey("raw", password, "PBKDF2", false, ["deriveKey"]);
+    const key = await crypto.subtle.deriveKey(
+      { name: "PBKDF2", salt: bytes(payload.salt), iterations: payload.iterations, hash: "SHA-256" },
+      base,
+      { name: "AES-GCM", length: 256 },
+      false,
+      ["decrypt"],
+    );
+    const plain = await crypto.subtle.decrypt({ name: "AES-GCM", iv: bytes(payload.iv) }, key, bytes(payload.data));
+
+    document.getElementById("content").innerHTML = new TextDecoder().decode(plain);
+    document.getElementById("unlock").remove();
+  } catch {
+    document.getElementById("error").hidden = false;
+  }
+});
+"#;
+
+/// What a password-protected page carries: the rendered note, sealed with AES-256-GCM
+/// under a key the browser derives from the password.
+#[derive(Debug, serde::Serialize)]
+struct EncryptedNote {
+    salt: String,
+    iv: String,
+    iterations: u32,
+    /// Ciphertext followed by the tag, the layout WebCrypto expects.
+    data: String,
+}
+
+/// Writes the note, its chapters and transcript into one HTML file in the Downloads folder,
+/// and returns its path. With a password, the content only shows up after it is entered.
+pub async fn export_html(
+    app: &AppHandle,
+    session_id: String,
+    password: Option<String>,
+) -> Result<String, String> {
+    let session = app
+        .db_get_session(&session_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("session not found: {}", session_id))?;
+    let chapters = app
+        .db_list_session_chapters(&session_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let body = render_body(&session, &chapters);
+    let html = match password.filter(|p| !p.is_empty()) {
+        Some(password) => {
+            // Deriving the key takes a while on purpose, so it stays off the async workers.
+            let note = tokio::task::spawn_blocking(move || encrypt(&body, &password))
+                .await
+                .map_err(|e| e.to_string())?
+                .map_err(|_| "failed to encrypt the note".to_string())?;
+            render_locked_page(&note)
+        }
+        None => render_page(&title(&session), &body),
+    };
+
+    save_to_downloads(app, &file_name(&session.title, "html"), html.as_bytes())
+}
+
+/// Writes the note as a PDF or DOCX document, laid out by `template`, into the Downloads
+/// folder and returns its path.
+pub async fn export_note(
+    app: &AppHandle,
+    session_id: String,
+    format: hypr_export::ExportFormat,
+    template: hypr_export::ExportTemplate,
+) -> Result<String, String> {
+    let session = app
+        .db_get_session(&session_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("session not found: {}", session_id))?;
+    let participants = app
+        .db_list_session_participants(&session_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter(|human| !human.is_user)
+        .filter_map(|human| human.full_name.or(human.email))
+        .collect();
+
+    let duration_ms = match (session.record_start, session.record_end) {
+        (Some(start), Some(end)) => u64::try_from((end - start).num_milliseconds()).ok(),
+        _ => None,
+    };
+
+    let note = hypr_export::ExportNote {
+        title: title(&session),
+        date: session.created_at,
+        duration_ms,
+        participants,
+        note_html: note_html(&session).to_string(),
+        transcript: paragraphs(&session.words),
+    };
+    let document = hypr_export::export(&note, format, &template).map_err(|e| e.to_string())?;
+
+    save_to_downloads(
+        app,
+        &file_name(&session.title, format.extension()),
+        &document,
+    )
+}
+
+fn save_to_downloads(app: &AppHandle, file_name: &str, contents: &[u8]) -> Result<String, String> {
+    let dir = app.path().download_dir().map_err(|e| e.to_string())?;
+    let path = write_new_file(&dir, file_name, contents).map_err(|e| e.to_string())?;
+
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// Writes `contents` under `file_name` in `dir`, or `name (2).ext` and so on when that is
+/// taken, so exporting the same note twice never overwrites the first copy.
+fn write_new_file(
+    dir: &std::path::Path,
+    file_name: &str,
+    contents: &[u8],
+) -> std::io::Result<std::path::PathBuf> {
+    use std::io::Write;
+
+    let name = std::path::Path::new(file_name);
+    let stem = name.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = name.extension().unwrap_or_default().to_string_lossy();
+
+    for n in 1.. {
+        let path = match n {
+            1 => dir.join(file_name),
+            n => dir.join(format!("{} ({}).{}", stem, n, extension)),
+        };
+
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(mut file) => {
+                file.write_all(contents)?;
+                return Ok(path);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    unreachable!()
+}
+
+fn title(session: &hypr_db_user::Session) -> String {
+    match session.title.trim() {
+        "" => "Untitled".to_string(),
+        title => title.to_string(),
+    }
+}
+
+/// The enhanced note, falling back to what the user typed.
+fn note_html(session: &hypr_db_user::Session) -> &str {
+    session
+        .enhanced_memo_html
+        .as_deref()
+        .filter(|note| !note.trim().is_empty())
+        .unwrap_or(&session.raw_memo_html)
+}
+
+fn file_name(title: &str, extension: &str) -> String {
+    let stem: String = title
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    match stem.trim_matches('_') {
+        "" => format!("note.{}", extension),
+        stem => format!("{}.{}", stem, extension),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render_body(session: &hypr_db_user::Session, chapters: &[hypr_db_user::Chapter]) -> String {
+    let mut html = format!(
+        "<h1>{}</h1>\n<p class=\"meta\">{}</p>\n",
+        escape(&title(session)),
+        session.created_at.format("%B %-d, %Y")
+    );
+
+    // The page is opened in a browser, so markup the note shouldn't have, like scripts or
+    // event handlers, is stripped.
+    let note = ammonia::clean(note_html(session));
+    if !note.trim().is_empty() {
+        html.push_str(&format!("<section class=\"note\">\n{}\n</section>\n", note));
+    }
+
+    let paragraphs = paragraphs(&session.words);
+    // Timestamps count from the first word rather than the wall clock.
+    let origin_ms = paragraphs.first().map(|p| p.start_ms).unwrap_or_default();
+    let chapter_timestamp = |chapter: &hypr_db_user::Chapter| {
+        format_timestamp((chapter.start_ms.max(0) as u64).saturating_sub(origin_ms))
+    };
+
+    if !chapters.is_empty() {
+        html.push_str("<nav class=\"chapters\">\n<h2>Chapters</h2>\n<ol>\n");
+        for (i, chapter) in chapters.iter().enumerate() {
+            html.push_str(&format!(
+                "<li><span class=\"time\">{}</span><a href=\"#chapter-{}\">{}</a></li>\n",
+                chapter_timestamp(chapter),
+                i,
+                escape(&chapter.title)
+            ));
+        }
+        html.push_str("</ol>\n</nav>\n");
+    }
+
+    if !paragraphs.is_empty() {
+        html.push_str("<section class=\"transcript\">\n<h2>Transcript</h2>\n");
+
+        let mut chapters = chapters.iter().enumerate().peekable();
+        for paragraph in &paragraphs {
+            while let Some((i, chapter)) =
+                chapters.next_if(|(_, c)| c.start_ms <= paragraph.start_ms as i64)
+            {
+                html.push_str(&format!(
+                    "<h3 id=\"chapter-{}\">{}</h3>\n",
+                    i,
+                    escape(&chapter.title)
+                ));
+            }
+
+            html.push_str(&format!(
+                "<p><span class=\"time\">{}</span><strong>{}</strong> {}</p>\n",
+                format_timestamp(paragraph.start_ms.saturating_sub(origin_ms)),
+                escape(&paragraph.speaker),
+                escape(&paragraph.text)
+            ));
+        }
+
+        // Chapters past the last word still need somewhere for their links to land.
+        for (i, chapter) in chapters {
+            html.push_str(&format!(
+                "<h3 id=\"chapter-{}\">{}</h3>\n",
+                i,
+                escape(&chapter.title)
+            ));
+        }
+
+        html.push_str("</section>\n");
+    }
+
+    html
+}
+
+fn render_page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape(title),
+        STYLE,
+        body
+    )
+}
+
+/// The title stays out of the page too, since it often gives away what the meeting was about.
+fn render_locked_page(note: &EncryptedNote) -> String {
+    let payload = serde_json::to_string(note).unwrap_or_default();
+
+    let body = format!(
+        "<div id=\"content\">\n<h1>Shared note</h1>\n<p class=\"meta\">This note is password protected.</p>\n<form id=\"unlock\">\n<input id=\"password\" type=\"password\" placeholder=\"Password\" autofocus>\n<button type=\"submit\">Open</button>\n</form>\n<p id=\"error\" class=\"meta\" hidden>Wrong password.</p>\n</div>\n<script type=\"application/json\" id=\"payload\">{}</script>\n<script>{}</script>\n",
+        payload, UNLOCK_SCRIPT
+    );
+
+    render_page("Shared note", &body)
+}
+
+fn encrypt(plaintext: &str, password: &str) -> Result<EncryptedNote, ring::error::Unspecified> {
+    let rng = SystemRandom::new();
+
+    let mut salt = [0u8; 16];
+    rng.fill(&mut salt)?;
+    let mut iv = [0u8; aead::NONCE_LEN];
+    rng.fill(&mut iv)?;
+
+    let mut key = [0u8; 32];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        NonZeroU32::new(PBKDF2_ITERATIONS).unwrap(),
+        &salt,
+        password.as_bytes(),
+        &mut key,
+    );
+
+    let key = aead::LessSafeKey::new(aead::UnboundKey::new(&aead::AES_256_GCM, &key)?);
+    let mut data = plaintext.as_bytes().to_vec();
+    key.seal_in_place_append_tag(
+        aead::Nonce::assume_unique_for_key(iv),
+        aead::Aad::empty(),
+        &mut data,
+    )?;
+
+    let b64 = base64::engine::general_purpose::STANDARD;
+    Ok(EncryptedNote {
+        salt: b64.encode(salt),
+        iv: b64.encode(iv),
+        iterations: PBKDF2_ITERATIONS,
+        data: b64.encode(data),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use owhisper_interface::{SpeakerIdentity, Word2};
+
+    fn word(text: &str, speaker: u8, start_ms: u64) -> Word2 {
+        Word2 {
+            text: text.to_string(),
+            speaker: Some(SpeakerIdentity::Unassigned { index: speaker }),
+            confidence: None,
+            start_ms: Some(start_ms),
+            end_ms: Some(start_ms + 400),
+        }
+    }
+
+    fn chapter(title: &str, start_ms: i64) -> hypr_db_user::Chapter {
+        hypr_db_user::Chapter {
+            id: title.to_string(),
+            session_id: "session".to_string(),
+            title: title.to_string(),
+            start_ms,
+            end_ms: start_ms + 60_000,
+        }
+    }
+
+    #[test]
+    fn test_render_body() {
+        let session = hypr_db_user::Session {
+            id: "session".to_string(),
+            created_at: chrono::Utc::now(),
+            visited_at: chrono::Utc::now(),
+            user_id: "user".to_string(),
+            calendar_event_id: None,
+            title: "Q3 <planning>".to_string(),
+            raw_memo_html: "<p>raw</p>".to_string(),
+            enhanced_memo_html: Some(
+                "<h2>Summary</h2><p>enhanced</p><img src=x onerror=alert(1)><script>alert(2)</script>"
+                    .to_string(),
+            ),
+            conversations: vec![],
+            words: vec![
+                word("Hello", 0, 10_000),
+                word("there.", 0, 10_500),
+                word("Budget", 1, 75_000),
+            ],
+            record_start: None,
+            record_end: None,
+            pre_meeting_memo_html: None,
+        };
+
+        let html = render_body(
+            &session,
+            &[chapter("Intro", 10_000), chapter("Budget", 70_000)],
+        );
+
+        assert!(html.contains("<h1>Q3 &lt;planning&gt;</h1>"));
+        assert!(html.contains("<p>enhanced</p>"));
+        assert!(!html.contains("onerror") && !html.contains("<script>"));
+        assert!(!html.contains("<p>raw</p>"));
+        assert!(html.contains("<span class=\"time\">01:00</span><a href=\"#chapter-1\">Budget</a>"));
+        assert!(html.contains("<strong>Speaker 1</strong> Hello there."));
+
+        let intro = html.find("<h3 id=\"chapter-0\">").unwrap();
+        let budget = html.find("<h3 id=\"chapter-1\">").unwrap();
+        let second_speaker = html.find("<strong>Speaker 2</strong>").unwrap();
+        assert!(intro < budget && budget < second_speaker);
+    }
+
+    #[test]
+    fn test_write_new_file() {
+        let dir = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let first = write_new_file(&dir, "note.html", b"first").unwrap();
+        let second = write_new_file(&dir, "note.html", b"second").unwrap();
+
+        assert_eq!(first, dir.join("note.html"));
+        assert_eq!(second, dir.join("note (2).html"));
+        assert_eq!(std::fs::read(&first).unwrap(), b"first");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_encrypt() {
+        let note = encrypt("<p>secret</p>", "hunter2").unwrap();
+        let b64 = base64::engine::general_purpose::STANDARD;
+
+        let salt = b64.decode(&note.salt).unwrap();
+        let iv: [u8; aead::NONCE_LEN] = b64.decode(&note.iv).unwrap().try_into().unwrap();
+        let mut data = b64.decode(&note.data).unwrap();
+
+        let mut key = [0u8; 32];
+        pbkdf2::derive(
+            pbkdf2::PBKDF2_HMAC_SHA256,
+            NonZeroU32::new(note.iterations).unwrap(),
+            &salt,
+            b"hunter2",
+            &mut key,
+        );
+        let key = aead::LessSafeKey::new(aead::UnboundKey::new(&aead::AES_256_GCM, &key).unwrap());
+        let plain = key
+            .open_in_place(
+                aead::Nonce::assume_unique_for_key(iv),
+                aead::Aad::empty(),
+                &mut data,
+            )
+            .unwrap();
+
+        assert_eq!(plain, b"<p>secret</p>");
+        assert!(!render_locked_page(&note).contains("secret"));
+    }
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(file_name("Weekly Sync: Q3", "html"), "weekly_sync__q3.html");
+        assert_eq!(file_name("  ", "docx"), "note.docx");
+    }
+}
@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+
+use tauri::AppHandle;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutEvent, ShortcutState};
+
+use crate::{AppExt, StoreKey};
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    specta::Type,
+    strum::EnumIter,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ShortcutAction {
+    NewNoteAndRecord,
+    ToggleMute,
+    StopRecording,
+    MarkHighlight,
+}
+
+impl ShortcutAction {
+    fn default_accelerator(&self) -> Option<&'static str> {
+        match self {
+            Self::NewNoteAndRecord => Some("CmdOrCtrl+Alt+H"),
+            // Unbound until the user picks one, so we don't grab keys other apps rely on.
+            Self::ToggleMute => None,
+            Self::StopRecording => None,
+            Self::MarkHighlight => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, specta::Type)]
+pub struct ShortcutBinding {
+    pub action: ShortcutAction,
+    pub accelerator: Option<String>,
+    pub default_accelerator: Option<String>,
+}
+
+// Only overrides are stored; `None` means the user cleared the shortcut.
+type Overrides = HashMap<ShortcutAction, Option<String>>;
+
+fn load_overrides(app: &AppHandle) -> Overrides {
+    app.desktop_store()
+        .and_then(|store| store.get(StoreKey::Shortcuts).map_err(|e| e.to_string()))
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+fn resolve(overrides: &Overrides) -> Vec<ShortcutBinding> {
+    use strum::IntoEnumIterator;
+
+    ShortcutAction::iter()
+        .map(|action| {
+            let default_accelerator = action.default_accelerator().map(str::to_string);
+            ShortcutBinding {
+                action,
+                accelerator: match overrides.get(&action) {
+                    Some(accelerator) => accelerator.clone(),
+                    None => default_accelerator.clone(),
+                },
+                default_accelerator,
+            }
+        })
+        .collect()
+}
+
+fn parse(accelerator: &str) -> Result<Shortcut, String> {
+    accelerator
+        .parse::<Shortcut>()
+        .map_err(|e| format!("invalid shortcut '{}': {}", accelerator, e))
+}
+
+/// Rejects `accelerator` if it is malformed or already bound to another action.
+fn check_conflict(
+    bindings: &[ShortcutBinding],
+    action: ShortcutAction,
+    accelerator: &str,
+) -> Result<(), String> {
+    let shortcut = parse(accelerator)?;
+
+    for binding in bindings.iter().filter(|b| b.action != action) {
+        let Some(other) = binding.accelerator.as_deref() else {
+            continue;
+        };
+        if parse(other).is_ok_and(|other| other == shortcut) {
+            return Err(format!(
+                "'{}' is already used by {:?}",
+                accelerator, binding.action
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn list(app: &AppHandle) -> Vec<ShortcutBinding> {
+    resolve(&load_overrides(app))
+}
+
+pub fn set(
+    app: &AppHandle,
+    action: ShortcutAction,
+    accelerator: Option<String>,
+) -> Result<Vec<ShortcutBinding>, String> {
+    let mut overrides = load_overrides(app);
+
+    if let Some(accelerator) = accelerator.as_deref() {
+        check_conflict(&resolve(&overrides), action, accelerator)?;
+    }
+
+    let previous = overrides.insert(action, accelerator);
+    save(app, &overrides)?;
+
+    if let Err(e) = register_all(app) {
+        // Most likely taken by another app; put the old binding back.
+        match previous {
+            Some(previous) => overrides.insert(action, previous),
+            None => overrides.remove(&action),
+        };
+        save(app, &overrides)?;
+        let _ = register_all(app);
+        return Err(e);
+    }
+
+    Ok(resolve(&overrides))
+}
+
+pub fn reset(app: &AppHandle, action: ShortcutAction) -> Result<Vec<ShortcutBinding>, String> {
+    let mut overrides = load_overrides(app);
+    overrides.remove(&action);
+
+    if let Some(accelerator) = action.default_accelerator() {
+        check_conflict(&resolve(&overrides), action, accelerator)?;
+    }
+
+    save(app, &overrides)?;
+    register_all(app)?;
+    Ok(resolve(&overrides))
+}
+
+fn save(app: &AppHandle, overrides: &Overrides) -> Result<(), String> {
+    app.desktop_store()?
+        .set(StoreKey::Shortcuts, overrides)
+        .map_err(|e| e.to_string())
+}
+
+/// Replaces whatever is registered with the current bindings.
+pub fn register_all(app: &AppHandle) -> Result<(), String> {
+    let global_shortcut = app.global_shortcut();
+    global_shortcut
+        .unregister_all()
+        .map_err(|e| e.to_string())?;
+
+    for binding in list(app) {
+        let Some(accelerator) = binding.accelerator else {
+            continue;
+        };
+
+        let shortcut = parse(&accelerator)?;
+        global_shortcut
+            .register(shortcut)
+            .map_err(|e| format!("failed to register '{}': {}", accelerator, e))?;
+    }
+
+    Ok(())
+}
+
+/// Also what the wake phrase does.
+pub fn new_note_and_record(app: &AppHandle) {
+    use tauri_plugin_windows::{HyprWindow, Navigate};
+
+    if let Ok(_) = HyprWindow::Main.show(app) {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+
+        let _ = HyprWindow::Main.emit_navigate(
+            app,
+            Navigate {
+                path: "/app/new?record=true".to_string(),
+                search: None,
+            },
+        );
+    }
+}
+
+pub fn handle(app: &AppHandle, shortcut: &Shortcut, event: ShortcutEvent) {
+    if !matches!(event.state(), ShortcutState::Pressed) {
+        return;
+    }
+
+    let action = list(app).into_iter().find_map(|binding| {
+        let accelerator = binding.accelerator?;
+        (parse(&accelerator).ok()? == *shortcut).then_some(binding.action)
+    });
+
+    match action {
+        Some(ShortcutAction::NewNoteAndRecord) => new_note_and_record(app),
+        Some(ShortcutAction::ToggleMute) => {
+            use tauri_plugin_listener::ListenerPluginExt;
+
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                let muted = app.get_mic_muted().await;
+                app.set_mic_muted(!muted).await;
+            });
+        }
+        Some(ShortcutAction::StopRecording) => {
+            use tauri_plugin_listener::ListenerPluginExt;
+
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                app.stop_session().await;
+            });
+        }
+        Some(ShortcutAction::MarkHighlight) => {
+            use tauri_plugin_listener::ListenerPluginExt;
+
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                app.mark_highlight(None).await;
+            });
+        }
+        None => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let bindings = resolve(&Overrides::from([(ShortcutAction::NewNoteAndRecord, None)]));
+        assert_eq!(bindings.len(), 4);
+        assert_eq!(bindings[0].accelerator, None);
+        assert_eq!(
+            bindings[0].default_accelerator.as_deref(),
+            Some("CmdOrCtrl+Alt+H")
+        );
+
+        let bindings = resolve(&Overrides::from([(
+            ShortcutAction::StopRecording,
+            Some("CmdOrCtrl+Alt+S".to_string()),
+        )]));
+        assert_eq!(bindings[2].accelerator.as_deref(), Some("CmdOrCtrl+Alt+S"));
+    }
+
+    #[test]
+    fn test_check_conflict() {
+        let bindings = resolve(&Overrides::new());
+
+        assert!(check_conflict(&bindings, ShortcutAction::ToggleMute, "CmdOrCtrl+Alt+M").is_ok());
+        assert!(check_conflict(&bindings, ShortcutAction::ToggleMute, "CmdOrCtrl+Alt+H").is_err());
+        assert!(check_conflict(&bindings, ShortcutAction::ToggleMute, "Alt+NotAKey").is_err());
+        // Rebinding an action to its own shortcut is not a conflict.
+        assert!(check_conflict(
+            &bindings,
+            ShortcutAction::NewNoteAndRecord,
+            "CmdOrCtrl+Alt+H"
+        )
+        .is_ok());
+    }
+}
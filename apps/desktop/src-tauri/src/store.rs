@@ -4,6 +4,9 @@ use tauri_plugin_store2::ScopedStoreKey;
 pub enum StoreKey {
     OnboardingNeeded,
     IndividualizationNeeded,
+    Shortcuts,
+    UpdateChannel,
+    UpdateRolloutBucket,
 }
 
 impl ScopedStoreKey for StoreKey {}
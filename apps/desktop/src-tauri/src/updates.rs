@@ -0,0 +1,184 @@
+use std::sync::Mutex;
+
+use tauri::{ipc::Channel, AppHandle, Manager};
+use tauri_plugin_updater::{Update, UpdaterExt};
+
+use crate::{AppExt, StoreKey};
+
+const ENDPOINT: &str =
+    "https://desktop.hyprnote.com/update/{{target}}-{{arch}}/{{current_version}}";
+// Used for progress when the server doesn't send a content length.
+const FALLBACK_UPDATE_SIZE: u64 = 50 * 1024 * 1024;
+
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateChannel {
+    #[default]
+    Stable,
+    Beta,
+}
+
+#[derive(Debug, Clone, serde::Serialize, specta::Type)]
+pub struct UpdateInfo {
+    pub version: String,
+    pub current_version: String,
+    pub date: Option<String>,
+    pub body: Option<String>,
+}
+
+/// The update found by the last check, kept until the user installs it.
+#[derive(Default)]
+pub struct PendingUpdate(Mutex<Option<Update>>);
+
+pub fn get_channel(app: &AppHandle) -> UpdateChannel {
+    app.desktop_store()
+        .and_then(|store| {
+            store
+                .get(StoreKey::UpdateChannel)
+                .map_err(|e| e.to_string())
+        })
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+pub fn set_channel(app: &AppHandle, channel: UpdateChannel) -> Result<(), String> {
+    app.desktop_store()?
+        .set(StoreKey::UpdateChannel, channel)
+        .map_err(|e| e.to_string())?;
+
+    // An update found on the old channel may not exist on the new one.
+    app.state::<PendingUpdate>().0.lock().unwrap().take();
+    Ok(())
+}
+
+/// Percentile this install falls in for staged rollouts. Picked once, so a release that is
+/// rolled out to 10% keeps reaching the same 10%.
+fn rollout_bucket(app: &AppHandle) -> Result<u8, String> {
+    let store = app.desktop_store()?;
+
+    if let Some(bucket) = store
+        .get::<u8>(StoreKey::UpdateRolloutBucket)
+        .map_err(|e| e.to_string())?
+    {
+        return Ok(bucket);
+    }
+
+    let bucket = (uuid::Uuid::new_v4().as_u128() % 100) as u8;
+    store
+        .set(StoreKey::UpdateRolloutBucket, bucket)
+        .map_err(|e| e.to_string())?;
+    Ok(bucket)
+}
+
+/// Nightly builds only ever follow the nightly channel; the setting applies to release builds.
+fn endpoint(identifier: &str, channel: UpdateChannel, bucket: u8) -> String {
+    let channel = if identifier.ends_with(".nightly") {
+        "nightly"
+    } else {
+        match channel {
+            UpdateChannel::Stable => "stable",
+            UpdateChannel::Beta => "beta",
+        }
+    };
+
+    format!("{}?channel={}&bucket={}", ENDPOINT, channel, bucket)
+}
+
+async fn is_recording(app: &AppHandle) -> bool {
+    use tauri_plugin_listener::ListenerPluginExt;
+
+    !matches!(
+        app.get_state().await,
+        tauri_plugin_listener::fsm::State::Inactive
+    )
+}
+
+/// Returns `None` while recording, so the update is offered once the meeting is over.
+pub async fn check(app: &AppHandle) -> Result<Option<UpdateInfo>, String> {
+    if is_recording(app).await {
+        return Ok(None);
+    }
+
+    let url: url::Url = endpoint(
+        &app.config().identifier,
+        get_channel(app),
+        rollout_bucket(app)?,
+    )
+    .parse()
+    .map_err(|e| e.to_string())?;
+    let update = app
+        .updater_builder()
+        .endpoints(vec![url])
+        .map_err(|e| e.to_string())?
+        .build()
+        .map_err(|e| e.to_string())?
+        .check()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let info = update.as_ref().map(|update| UpdateInfo {
+        version: update.version.clone(),
+        current_version: update.current_version.clone(),
+        date: update.date.map(|date| date.to_string()),
+        body: update.body.clone(),
+    });
+    *app.state::<PendingUpdate>().0.lock().unwrap() = update;
+
+    Ok(info)
+}
+
+/// Downloads and installs the update from the last `check`, reporting progress in percent.
+pub async fn install(app: &AppHandle, on_progress: Channel<i8>) -> Result<(), String> {
+    if is_recording(app).await {
+        return Err("Updates can't be installed while recording".to_string());
+    }
+
+    let update = app
+        .state::<PendingUpdate>()
+        .0
+        .lock()
+        .unwrap()
+        .take()
+        .ok_or("no update available")?;
+
+    let mut downloaded = 0u64;
+    let result = update
+        .download_and_install(
+            |chunk, total| {
+                downloaded += chunk as u64;
+                let total = total.unwrap_or(FALLBACK_UPDATE_SIZE).max(1);
+                let _ = on_progress.send((downloaded * 100 / total).min(99) as i8);
+            },
+            || {
+                let _ = on_progress.send(100);
+            },
+        )
+        .await;
+
+    if let Err(e) = result {
+        let _ = on_progress.send(-1);
+        return Err(e.to_string());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint() {
+        assert_eq!(
+            endpoint("com.hyprnote.stable", UpdateChannel::Stable, 7),
+            "https://desktop.hyprnote.com/update/{{target}}-{{arch}}/{{current_version}}?channel=stable&bucket=7"
+        );
+        assert!(endpoint("com.hyprnote.stable", UpdateChannel::Beta, 7).contains("channel=beta"));
+        assert!(
+            endpoint("com.hyprnote.nightly", UpdateChannel::Stable, 7).contains("channel=nightly")
+        );
+    }
+}
@@ -1,3 +1,4 @@
+import { t } from "@lingui/core/macro";
 import { type QueryClient, useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
 import usePreviousValue from "beautiful-react-hooks/usePreviousValue";
 import { diffWords } from "diff";
@@ -13,7 +14,9 @@ import { commands as analyticsCommands } from "@hypr/plugin-analytics";
 import { commands as connectorCommands } from "@hypr/plugin-connector";
 import { commands as dbCommands } from "@hypr/plugin-db";
 import { commands as miscCommands } from "@hypr/plugin-misc";
+import { commands as taskCommands } from "@hypr/plugin-task";
 import { commands as templateCommands, type Grammar } from "@hypr/plugin-template";
+import { commands as webhookCommands } from "@hypr/plugin-webhook";
 import Editor, { type TiptapEditor } from "@hypr/tiptap/editor";
 import Renderer from "@hypr/tiptap/renderer";
 import { extractHashtags } from "@hypr/tiptap/shared";
@@ -35,10 +38,19 @@ async function generateTitleDirect(
   sessions: Record<string, any>,
   queryClient: QueryClient,
 ) {
-  const [config, { type }, provider] = await Promise.all([
+  await generateTitle(enhancedContent, targetSessionId, sessions);
+  await generateTags(targetSessionId, sessions, queryClient);
+}
+
+export async function generateTitle(
+  enhancedContent: string,
+  targetSessionId: string,
+  sessions: Record<string, any>,
+) {
+  const [config, { connection: { type } }, provider] = await Promise.all([
     dbCommands.getConfig(),
-    connectorCommands.getLlmConnection(),
-    modelProvider(),
+    connectorCommands.getLlmConnectionForTask("Title"),
+    modelProvider("Title"),
   ]);
 
   const [systemMessage, userMessage] = await Promise.all([
@@ -66,7 +78,13 @@ async function generateTitleDirect(
     const cleanedTitle = text.replace(/^["']|["']$/g, "").trim();
     sessions[targetSessionId].getState().updateTitle(cleanedTitle);
   }
+}
 
+export async function generateTags(
+  targetSessionId: string,
+  sessions: Record<string, any>,
+  queryClient: QueryClient,
+) {
   // check and run auto tag generation if the session has less than 2 tags
   const sessionTags = await dbCommands.listSessionTags(targetSessionId);
 
@@ -103,6 +121,72 @@ async function generateTitleDirect(
   }
 }
 
+export async function generateActionItems(targetSessionId: string, sessions: Record<string, any>) {
+  const session = await dbCommands.getSession({ id: targetSessionId });
+  if (!session?.words.length) {
+    return;
+  }
+
+  const [config, { connection: { type } }, provider] = await Promise.all([
+    dbCommands.getConfig(),
+    connectorCommands.getLlmConnectionForTask("Enhance"),
+    modelProvider("Enhance"),
+  ]);
+
+  const [systemMessage, userMessage] = await Promise.all([
+    templateCommands.render("action_items.system", { config, type }),
+    templateCommands.render("action_items.user", {
+      enhanced_note: extractTextFromHtml(session.enhanced_memo_html || session.raw_memo_html),
+      words: JSON.stringify(session.words),
+    }),
+  ]);
+
+  const { text } = await generateText({
+    abortSignal: AbortSignal.timeout(120_000),
+    model: provider.languageModel("defaultModel"),
+    messages: [
+      { role: "system", content: systemMessage },
+      { role: "user", content: userMessage },
+    ],
+  });
+
+  const items = text.trim();
+  if (!items || !sessions[targetSessionId]?.getState) {
+    return;
+  }
+
+  const heading = t`Action Items`;
+  const section = await miscCommands.opinionatedMdToHtml(`# ${heading}\n\n${items}`);
+  const store = sessions[targetSessionId].getState();
+  store.updateEnhancedNote(replaceSection(store.session.enhanced_memo_html ?? "", heading, section));
+}
+
+/**
+ * Puts `section` in place of the `<h1>` titled `heading` and everything up to the next `<h1>`,
+ * or at the end when there's no such heading yet. Running a stage again then updates its
+ * section instead of adding another one.
+ */
+function replaceSection(html: string, heading: string, section: string) {
+  const doc = new DOMParser().parseFromString(html, "text/html");
+  const existing = Array.from(doc.body.querySelectorAll("h1"))
+    .find((h1) => h1.textContent?.trim() === heading);
+
+  if (!existing) {
+    return html + section;
+  }
+
+  let node = existing.nextSibling;
+  while (node && node.nodeName !== "H1") {
+    const next = node.nextSibling;
+    node.remove();
+    node = next;
+  }
+
+  const placeholder = doc.createComment("section");
+  existing.replaceWith(placeholder);
+  return doc.body.innerHTML.replace("<!--section-->", section);
+}
+
 export default function EditorArea({
   editable,
   sessionId,
@@ -127,6 +211,7 @@ export default function EditorArea({
   const sessionStore = useSession(sessionId, (s) => ({
     session: s.session,
   }));
+  const pipelineEnhancing = useSession(sessionId, (s) => s.enhancing);
 
   const editorRef = useRef<{ editor: TiptapEditor | null }>(null);
 
@@ -170,6 +255,8 @@ export default function EditorArea({
     rawContent,
     isLocalLlm: llmConnectionQuery.data?.type === "HyprLocal",
     onSuccess: (content) => {
+      webhookCommands.dispatchWebhook("note.enhanced", { session_id: sessionId, content }).catch(console.error);
+
       if (hasTranscriptWords) {
         generateTitleDirect(content, sessionId, sessionsStore, queryClient).catch(console.error);
       }
@@ -179,7 +266,6 @@ export default function EditorArea({
   useAutoEnhance({
     sessionId,
     enhanceStatus: enhance.status,
-    enhanceMutate: enhance.mutate,
   });
 
   const handleChangeNote = useCallback(
@@ -284,8 +370,8 @@ export default function EditorArea({
               ref={editorRef}
               handleChange={handleChangeNote}
               initialContent={noteContent}
-              editable={enhance.status !== "pending"}
-              setContentFromOutside={!showRaw && enhance.status === "pending"}
+              editable={enhance.status !== "pending" && !pipelineEnhancing}
+              setContentFromOutside={!showRaw && (enhance.status === "pending" || pipelineEnhancing)}
               mentionConfig={{
                 trigger: "@",
                 handleSearch: handleMentionSearch,
@@ -342,6 +428,252 @@ export default function EditorArea({
   );
 }
 
+function extractH1Headers(htmlContent: string): string[] {
+  if (!htmlContent) {
+    return [];
+  }
+
+  const h1Regex = /<h1[^>]*>(.*?)<\/h1>/gi;
+  const headers: string[] = [];
+  let match;
+
+  while ((match = h1Regex.exec(htmlContent)) !== null) {
+    const headerText = match[1].replace(/<[^>]*>/g, "").trim();
+    if (headerText) {
+      headers.push(headerText);
+    }
+  }
+
+  return headers;
+}
+
+/**
+ * Writes the enhanced note of a session, handing each streamed version to `onContent`.
+ * Resolves to `undefined` when the recording is too short to enhance.
+ */
+export async function enhanceNote({
+  sessionId,
+  templateId,
+  userId,
+  onboardingSessionId,
+  rawContent,
+  preMeetingNote,
+  originalContent,
+  abortSignal,
+  onStart,
+  onProgress,
+  onContent,
+}: {
+  sessionId: string;
+  templateId?: string | null;
+  userId: string;
+  onboardingSessionId: string;
+  rawContent: string;
+  preMeetingNote: string;
+  /** Put back if the model fails before writing anything. */
+  originalContent: string;
+  abortSignal: AbortSignal;
+  onStart?: (options: { isLocalLlm: boolean }) => void;
+  onProgress?: (progress: number) => void;
+  onContent: (html: string) => void;
+}): Promise<string | undefined> {
+  const h1Headers = extractH1Headers(rawContent);
+
+  const preMeetingText = extractTextFromHtml(preMeetingNote);
+  const rawText = extractTextFromHtml(rawContent);
+
+  const finalInput = diffWords(preMeetingText, rawText)
+    ?.filter(diff => diff.added && !diff.removed)
+    .map(diff => diff.value)
+    .join(" ") || "";
+
+  const getWordsFunc = sessionId === onboardingSessionId ? dbCommands.getWordsOnboarding : dbCommands.getWords;
+
+  const [{ connection: { type, connection } }, config, words] = await Promise.all([
+    connectorCommands.getLlmConnectionForTask("Enhance"),
+    dbCommands.getConfig(),
+    getWordsFunc(sessionId),
+  ]);
+
+  const freshIsLocalLlm = type === "HyprLocal";
+  onStart?.({ isLocalLlm: freshIsLocalLlm });
+
+  const wordsThreshold = import.meta.env.DEV ? 5 : 100;
+  if (!words.length || words.length < wordsThreshold) {
+    toast({
+      id: "short-timeline",
+      title: "Recording too short",
+      content: `We need at least ${wordsThreshold} words to enhance your note.`,
+      dismissible: true,
+      duration: 5000,
+    });
+    return;
+  }
+
+  const effectiveTemplateId = templateId !== undefined
+    ? templateId
+    : config.general?.selected_template_id;
+
+  const selectedTemplate = await TemplateService.getTemplate(effectiveTemplateId ?? "");
+  let contextText = "";
+
+  // Print context tags if they exist
+  if (selectedTemplate?.context_option) {
+    analyticsCommands.event({
+      event: "enhance_with_context",
+      distinct_id: userId,
+    });
+    try {
+      const contextConfig = JSON.parse(selectedTemplate.context_option);
+      if (contextConfig.type === "tags" && contextConfig.selections?.length > 0) {
+        // Prepare and print context text from tagged sessions
+        contextText = await prepareContextText(
+          contextConfig.selections,
+          sessionId,
+          userId,
+        );
+      }
+    } catch (e) {
+      // Silent catch for malformed JSON
+      console.error("Error parsing context option:", e);
+    }
+  }
+
+  if (selectedTemplate !== null) {
+    const eventName = selectedTemplate?.tags.includes("builtin")
+      ? "builtin_template_enhancement_started"
+      : "custom_template_enhancement_started";
+    analyticsCommands.event({
+      event: eventName,
+      distinct_id: userId,
+    });
+  }
+
+  const shouldUseH1Headers = !effectiveTemplateId && h1Headers.length > 0;
+  const grammarSections = selectedTemplate?.sections.map(s => s.title) || null;
+
+  const participants = await dbCommands.sessionListParticipants(sessionId);
+
+  let customInstruction = selectedTemplate?.description;
+
+  const systemMessage = await templateCommands.render(
+    "enhance.system",
+    {
+      config,
+      type,
+      // Pass userHeaders when using H1 headers, templateInfo otherwise
+      ...(shouldUseH1Headers
+        ? { userHeaders: h1Headers }
+        : { templateInfo: selectedTemplate, customInstruction: customInstruction }),
+    },
+  );
+
+  const userMessage = await templateCommands.render(
+    "enhance.user",
+    {
+      type,
+      editor: finalInput,
+      words: JSON.stringify(words),
+      participants,
+      ...((contextText !== "" || contextText !== undefined || contextText !== null) ? { contextText } : {}),
+    },
+  );
+
+  const signal = AbortSignal.any([abortSignal, AbortSignal.timeout(120 * 1000)]);
+
+  const provider = await modelProvider("Enhance");
+  const model = sessionId === onboardingSessionId
+    ? provider.languageModel("onboardingModel")
+    : provider.languageModel("defaultModel");
+
+  const isHyprCloud = type !== "HyprLocal" && connection && connection.api_base.includes("pro.hyprnote.com");
+
+  if (sessionId !== onboardingSessionId) {
+    analyticsCommands.event({
+      event: "normal_enhance_start",
+      distinct_id: userId,
+      session_id: sessionId,
+      connection_type: type,
+      is_hypr_cloud: isHyprCloud,
+    });
+  }
+
+  const startedAt = Date.now();
+
+  const { text, fullStream } = streamText({
+    abortSignal: signal,
+    model,
+    onError: (error) => {
+      toast({
+        id: "something went wrong",
+        title: "🚨 Something went wrong",
+        content: (
+          <div>
+            Please try again or contact the team.
+            <br />
+            <br />
+            <span className="text-xs">Error: {String(error.error)}</span>
+          </div>
+        ),
+        dismissible: true,
+        duration: 5000,
+      });
+      throw error;
+    },
+    messages: [
+      { role: "system", content: systemMessage },
+      { role: "user", content: userMessage },
+    ],
+    experimental_transform: [
+      smoothStream({ delayInMs: 80, chunking: "line" }),
+    ],
+    ...(freshIsLocalLlm && {
+      providerOptions: {
+        [localProviderName]: {
+          metadata: {
+            grammar: {
+              task: "enhance",
+              sections: grammarSections,
+            } satisfies Grammar,
+          },
+        },
+      },
+    }),
+  });
+
+  let acc = "";
+
+  for await (const chunk of fullStream) {
+    if (chunk.type === "text-delta") {
+      acc += chunk.text;
+    }
+    if (chunk.type === "error") {
+      if (originalContent !== "" && acc === "") {
+        onContent(originalContent);
+      }
+      throw new Error(String(chunk.error));
+    }
+    if (chunk.type === "tool-call" && freshIsLocalLlm) {
+      onProgress?.(chunk.input?.progress ?? 0);
+    }
+
+    const html = await miscCommands.opinionatedMdToHtml(acc);
+    onContent(html);
+  }
+
+  // "model_" events are model performance metrics, gated by their own consent toggle.
+  analyticsCommands.event({
+    event: "model_enhance_completed",
+    distinct_id: userId,
+    connection_type: type,
+    is_hypr_cloud: isHyprCloud,
+    duration_ms: Date.now() - startedAt,
+    output_chars: acc.length,
+  });
+
+  return text.then(miscCommands.opinionatedMdToHtml);
+}
+
 export function useEnhanceMutation({
   sessionId,
   preMeetingNote,
@@ -361,36 +693,6 @@ export function useEnhanceMutation({
   const [isCancelled, setIsCancelled] = useState(false);
   const queryClient = useQueryClient();
 
-  // Extract H1 headers at component level (always available)
-  const extractH1Headers = useCallback((htmlContent: string): string[] => {
-    if (!htmlContent) {
-      return [];
-    }
-
-    const h1Regex = /<h1[^>]*>(.*?)<\/h1>/gi;
-    const headers: string[] = [];
-    let match;
-
-    while ((match = h1Regex.exec(htmlContent)) !== null) {
-      const headerText = match[1].replace(/<[^>]*>/g, "").trim();
-      if (headerText) {
-        headers.push(headerText);
-      }
-    }
-
-    return headers;
-  }, []);
-
-  const h1Headers = useMemo(() => extractH1Headers(rawContent), [rawContent, extractH1Headers]);
-
-  const preMeetingText = extractTextFromHtml(preMeetingNote);
-  const rawText = extractTextFromHtml(rawContent);
-
-  const finalInput = diffWords(preMeetingText, rawText)
-    ?.filter(diff => diff.added && !diff.removed)
-    .map(diff => diff.value)
-    .join(" ") || "";
-
   const setEnhanceController = useOngoingSession((s) => s.setEnhanceController);
   const { persistSession, setEnhancedContent } = useSession(sessionId, (s) => ({
     persistSession: s.persistSession,
@@ -399,8 +701,6 @@ export function useEnhanceMutation({
 
   const getCurrentEnhancedContent = useSession(sessionId, (s) => s.session?.enhanced_memo_html ?? "");
 
-  const originalContentRef = useRef<string>("");
-
   const enhance = useMutation({
     mutationKey: ["enhance", sessionId],
     mutationFn: async ({
@@ -409,191 +709,31 @@ export function useEnhanceMutation({
       templateId?: string | null;
     }) => {
       setIsCancelled(false);
-      originalContentRef.current = getCurrentEnhancedContent;
+      const originalContent = getCurrentEnhancedContent;
       const abortController = new AbortController();
       setEnhanceController(abortController);
 
       await queryClient.invalidateQueries({ queryKey: ["llm-connection"] });
       await new Promise(resolve => setTimeout(resolve, 100));
 
-      const getWordsFunc = sessionId === onboardingSessionId ? dbCommands.getWordsOnboarding : dbCommands.getWords;
-
-      const [{ type, connection }, config, words] = await Promise.all([
-        connectorCommands.getLlmConnection(),
-        dbCommands.getConfig(),
-        getWordsFunc(sessionId),
-      ]);
-
-      const freshIsLocalLlm = type === "HyprLocal";
-      setActualIsLocalLlm(freshIsLocalLlm);
-
-      if (freshIsLocalLlm) {
-        setProgress(0);
-      }
-
-      const wordsThreshold = import.meta.env.DEV ? 5 : 100;
-      if (!words.length || words.length < wordsThreshold) {
-        toast({
-          id: "short-timeline",
-          title: "Recording too short",
-          content: `We need at least ${wordsThreshold} words to enhance your note.`,
-          dismissible: true,
-          duration: 5000,
-        });
-        return;
-      }
-
-      const effectiveTemplateId = templateId !== undefined
-        ? templateId
-        : config.general?.selected_template_id;
-
-      const selectedTemplate = await TemplateService.getTemplate(effectiveTemplateId ?? "");
-      let contextText = "";
-
-      // Print context tags if they exist
-      if (selectedTemplate?.context_option) {
-        analyticsCommands.event({
-          event: "enhance_with_context",
-          distinct_id: userId,
-        });
-        try {
-          const contextConfig = JSON.parse(selectedTemplate.context_option);
-          if (contextConfig.type === "tags" && contextConfig.selections?.length > 0) {
-            // Prepare and print context text from tagged sessions
-            contextText = await prepareContextText(
-              contextConfig.selections,
-              sessionId,
-              userId,
-            );
+      return enhanceNote({
+        sessionId,
+        templateId,
+        userId,
+        onboardingSessionId,
+        rawContent,
+        preMeetingNote,
+        originalContent,
+        abortSignal: abortController.signal,
+        onStart: ({ isLocalLlm }) => {
+          setActualIsLocalLlm(isLocalLlm);
+          if (isLocalLlm) {
+            setProgress(0);
           }
-        } catch (e) {
-          // Silent catch for malformed JSON
-          console.error("Error parsing context option:", e);
-        }
-      }
-
-      if (selectedTemplate !== null) {
-        const eventName = selectedTemplate?.tags.includes("builtin")
-          ? "builtin_template_enhancement_started"
-          : "custom_template_enhancement_started";
-        analyticsCommands.event({
-          event: eventName,
-          distinct_id: userId,
-        });
-      }
-
-      const shouldUseH1Headers = !effectiveTemplateId && h1Headers.length > 0;
-      const grammarSections = selectedTemplate?.sections.map(s => s.title) || null;
-
-      const participants = await dbCommands.sessionListParticipants(sessionId);
-
-      let customInstruction = selectedTemplate?.description;
-
-      const systemMessage = await templateCommands.render(
-        "enhance.system",
-        {
-          config,
-          type,
-          // Pass userHeaders when using H1 headers, templateInfo otherwise
-          ...(shouldUseH1Headers
-            ? { userHeaders: h1Headers }
-            : { templateInfo: selectedTemplate, customInstruction: customInstruction }),
-        },
-      );
-
-      const userMessage = await templateCommands.render(
-        "enhance.user",
-        {
-          type,
-          editor: finalInput,
-          words: JSON.stringify(words),
-          participants,
-          ...((contextText !== "" || contextText !== undefined || contextText !== null) ? { contextText } : {}),
         },
-      );
-
-      const abortSignal = AbortSignal.any([abortController.signal, AbortSignal.timeout(120 * 1000)]);
-
-      const provider = await modelProvider();
-      const model = sessionId === onboardingSessionId
-        ? provider.languageModel("onboardingModel")
-        : provider.languageModel("defaultModel");
-
-      const isHyprCloud = type !== "HyprLocal" && connection && connection.api_base.includes("pro.hyprnote.com");
-
-      if (sessionId !== onboardingSessionId) {
-        analyticsCommands.event({
-          event: "normal_enhance_start",
-          distinct_id: userId,
-          session_id: sessionId,
-          connection_type: type,
-          is_hypr_cloud: isHyprCloud,
-        });
-      }
-
-      const { text, fullStream } = streamText({
-        abortSignal,
-        model,
-        onError: (error) => {
-          toast({
-            id: "something went wrong",
-            title: "🚨 Something went wrong",
-            content: (
-              <div>
-                Please try again or contact the team.
-                <br />
-                <br />
-                <span className="text-xs">Error: {String(error.error)}</span>
-              </div>
-            ),
-            dismissible: true,
-            duration: 5000,
-          });
-          throw error;
-        },
-        messages: [
-          { role: "system", content: systemMessage },
-          { role: "user", content: userMessage },
-        ],
-        experimental_transform: [
-          smoothStream({ delayInMs: 80, chunking: "line" }),
-        ],
-        ...(freshIsLocalLlm && {
-          providerOptions: {
-            [localProviderName]: {
-              metadata: {
-                grammar: {
-                  task: "enhance",
-                  sections: grammarSections,
-                } satisfies Grammar,
-              },
-            },
-          },
-        }),
+        onProgress: setProgress,
+        onContent: setEnhancedContent,
       });
-
-      let acc = "";
-
-      for await (const chunk of fullStream) {
-        if (chunk.type === "text-delta") {
-          acc += chunk.text;
-        }
-        if (chunk.type === "error") {
-          if (originalContentRef.current !== "" && acc === "") {
-            setEnhancedContent(originalContentRef.current);
-          }
-          throw new Error(String(chunk.error));
-        }
-        if (chunk.type === "tool-call" && freshIsLocalLlm) {
-          const chunkProgress = chunk.input?.progress ?? 0;
-          setProgress(chunkProgress);
-        }
-
-        const html = await miscCommands.opinionatedMdToHtml(acc);
-        setEnhancedContent(html);
-      }
-
-      return text.then(miscCommands.opinionatedMdToHtml);
     },
     onSuccess: (enhancedContent: string | undefined) => {
       setIsCancelled(false);
@@ -641,17 +781,14 @@ export function useEnhanceMutation({
 function useAutoEnhance({
   sessionId,
   enhanceStatus,
-  enhanceMutate,
 }: {
   sessionId: string;
   enhanceStatus: string;
-  enhanceMutate: (params: { triggerType: "auto"; templateId?: string | null }) => void;
 }) {
   const ongoingSessionStatus = useOngoingSession((s) => s.status);
   const autoEnhanceTemplate = useOngoingSession((s) => s.autoEnhanceTemplate);
   const setAutoEnhanceTemplate = useOngoingSession((s) => s.setAutoEnhanceTemplate);
   const prevOngoingSessionStatus = usePreviousValue(ongoingSessionStatus);
-  const setShowRaw = useSession(sessionId, (s) => s.setShowRaw);
 
   useEffect(() => {
     if (
@@ -659,13 +796,8 @@ function useAutoEnhance({
       && ongoingSessionStatus === "inactive"
       && enhanceStatus !== "pending"
     ) {
-      setShowRaw(false);
-
-      // Use the selected template and then clear it
-      enhanceMutate({
-        triggerType: "auto",
-        templateId: autoEnhanceTemplate,
-      });
+      // The template's pipeline runs from here, stage by stage; see `PipelineRunner`.
+      taskCommands.enqueuePipeline(sessionId, autoEnhanceTemplate ?? null).catch(console.error);
 
       // Clear the template after using it (one-time use)
       setAutoEnhanceTemplate(null);
@@ -674,8 +806,6 @@ function useAutoEnhance({
     ongoingSessionStatus,
     enhanceStatus,
     sessionId,
-    enhanceMutate,
-    setShowRaw,
     autoEnhanceTemplate,
     setAutoEnhanceTemplate,
     prevOngoingSessionStatus,
@@ -64,7 +64,7 @@ export function EventChip({ sessionId, isVeryNarrow = false, isNarrow = false }:
         return null;
       }
 
-      const meetingLink = await miscCommands.parseMeetingLink(eventData.note);
+      const meetingLink = eventData.meeting_link ?? await miscCommands.parseMeetingLink(eventData.note);
       return { ...eventData, meetingLink };
     },
   });
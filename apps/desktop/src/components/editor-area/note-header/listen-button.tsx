@@ -6,6 +6,7 @@ import {
   ChevronDownIcon,
   MicIcon,
   MicOffIcon,
+  PauseIcon,
   PlayIcon,
   StopCircleIcon,
   Volume2Icon,
@@ -68,8 +69,9 @@ export default function ListenButton({ sessionId, isCompact = false }: { session
       const currentModel = await localSttCommands.getLocalModel();
       const isDownloaded = await localSttCommands.isModelDownloaded(currentModel);
       const servers = await localSttCommands.getServers();
-      const isServerAvailable = (servers.external === "ready") || (servers.internal === "ready")
-        || (servers.custom === "ready");
+      const isServerAvailable = (servers.external?.health === "ready")
+        || (servers.internal?.health === "ready")
+        || (servers.custom?.health === "ready");
       return isDownloaded && isServerAvailable;
     },
   });
@@ -286,6 +288,11 @@ function RecordingControls({
     micMuted: s.micMuted,
     speakerMuted: s.speakerMuted,
   }));
+  const ongoingSessionPause = useOngoingSession((s) => ({
+    paused: s.paused,
+    pause: s.pause,
+    resume: s.resume,
+  }));
 
   const toggleMicMuted = useMutation({
     mutationFn: () => listenerCommands.setMicMuted(!ongoingSessionMuted.micMuted),
@@ -309,6 +316,16 @@ function RecordingControls({
         />
       </div>
 
+      <Button
+        variant="outline"
+        className="w-full mb-2 flex items-center gap-2"
+        disabled={sessionId === onboardingSessionId}
+        onClick={ongoingSessionPause.paused ? ongoingSessionPause.resume : ongoingSessionPause.pause}
+      >
+        {ongoingSessionPause.paused ? <PlayIcon size={16} /> : <PauseIcon size={16} />}
+        {ongoingSessionPause.paused ? <Trans>Resume recording</Trans> : <Trans>Pause recording</Trans>}
+      </Button>
+
       <StopButton onStop={onStop} />
     </>
   );
@@ -4,10 +4,22 @@ import { join } from "@tauri-apps/api/path";
 import { message } from "@tauri-apps/plugin-dialog";
 import { fetch as tauriFetch } from "@tauri-apps/plugin-http";
 import { openPath, openUrl } from "@tauri-apps/plugin-opener";
-import { BookText, Check, ChevronDown, ChevronUp, Copy, FileText, HelpCircle, Mail } from "lucide-react";
+import {
+  BookText,
+  Check,
+  ChevronDown,
+  ChevronUp,
+  Copy,
+  FileText,
+  FileType,
+  Globe,
+  HelpCircle,
+  Mail,
+} from "lucide-react";
 import { useState } from "react";
 
 import { useHypr } from "@/contexts";
+import { commands } from "@/types";
 import { commands as analyticsCommands } from "@hypr/plugin-analytics";
 import { Session, Tag } from "@hypr/plugin-db";
 import { commands as dbCommands } from "@hypr/plugin-db";
@@ -31,7 +43,7 @@ interface DirectAction {
 }
 
 interface ExportCard {
-  id: "pdf" | "email" | "obsidian";
+  id: "pdf" | "docx" | "html" | "email" | "obsidian";
   title: string;
   icon: React.ReactNode;
   description: string;
@@ -39,7 +51,7 @@ interface ExportCard {
 }
 
 interface ExportResult {
-  type: "copy" | "pdf" | "email" | "obsidian";
+  type: "copy" | "pdf" | "docx" | "html" | "email" | "obsidian";
   path?: string;
   url?: string;
   success?: boolean;
@@ -79,6 +91,23 @@ const exportHandlers = {
     return { type: "pdf", path };
   },
 
+  docx: async (session: Session, includeTranscript: boolean): Promise<ExportResult> => {
+    const path = await commands.exportNote(session.id, "docx", {
+      include_metadata: true,
+      include_summary: true,
+      include_action_items: true,
+      include_transcript: includeTranscript,
+    });
+    await message(`Meeting minutes saved to your 'Downloads' folder ("${path}")`);
+    return { type: "docx", path };
+  },
+
+  html: async (session: Session, password: string): Promise<ExportResult> => {
+    const path = await commands.exportNoteHtml(session.id, password || null);
+    await message(`Shareable page saved to your 'Downloads' folder ("${path}")`);
+    return { type: "html", path };
+  },
+
   email: async (
     session: Session,
     sessionParticipants?: Array<{ full_name: string | null; email: string | null }>,
@@ -333,6 +362,7 @@ export function useShareLogic() {
   const [selectedObsidianFolder, setSelectedObsidianFolder] = useState<string>("default");
   const [selectedPdfTheme, setSelectedPdfTheme] = useState<ThemeName>("default");
   const [includeTranscript, setIncludeTranscript] = useState(false);
+  const [htmlPassword, setHtmlPassword] = useState("");
   const [copySuccess, setCopySuccess] = useState(false);
   const hasEnhancedNote = !!session?.enhanced_memo_html;
 
@@ -385,6 +415,20 @@ export function useShareLogic() {
       description: "Save as PDF document",
       docsUrl: "https://docs.hyprnote.com/sharing#pdf",
     },
+    {
+      id: "docx",
+      title: "Word",
+      icon: <FileType size={16} />,
+      description: "Meeting minutes with action items, as a DOCX document",
+      docsUrl: "https://docs.hyprnote.com/sharing#docx",
+    },
+    {
+      id: "html",
+      title: "Web Page",
+      icon: <Globe size={16} />,
+      description: "Single HTML file with the note and transcript",
+      docsUrl: "https://docs.hyprnote.com/sharing#html",
+    },
     {
       id: "email",
       title: "Email",
@@ -434,6 +478,10 @@ export function useShareLogic() {
         result = await exportHandlers.copy(session);
       } else if (optionId === "pdf") {
         result = await exportHandlers.pdf(session, selectedPdfTheme);
+      } else if (optionId === "docx") {
+        result = await exportHandlers.docx(session, includeTranscript);
+      } else if (optionId === "html") {
+        result = await exportHandlers.html(session, htmlPassword);
       } else if (optionId === "email") {
         try {
           // fetch participants directly, bypassing cache
@@ -488,7 +536,7 @@ export function useShareLogic() {
         setCopySuccess(true);
         // Reset after 2 seconds
         setTimeout(() => setCopySuccess(false), 2000);
-      } else if (result?.type === "pdf" && result.path) {
+      } else if ((result?.type === "pdf" || result?.type === "docx" || result?.type === "html") && result.path) {
         openPath(result.path);
       } else if (result?.type === "email" && result.url) {
         openUrl(result.url);
@@ -509,6 +557,7 @@ export function useShareLogic() {
   const resetExpandedState = () => {
     setExpandedId(null);
     setCopySuccess(false);
+    setHtmlPassword("");
   };
 
   const handleOpenStateChange = (isOpen: boolean) => {
@@ -538,6 +587,8 @@ export function useShareLogic() {
     setSelectedPdfTheme,
     includeTranscript,
     setIncludeTranscript,
+    htmlPassword,
+    setHtmlPassword,
     copySuccess,
     isObsidianConfigured,
     obsidianFolders,
@@ -561,6 +612,8 @@ export function SharePopoverContent() {
     setSelectedPdfTheme,
     includeTranscript,
     setIncludeTranscript,
+    htmlPassword,
+    setHtmlPassword,
     copySuccess,
     obsidianFolders,
     directActions,
@@ -653,6 +706,35 @@ export function SharePopoverContent() {
                     </div>
                   )}
 
+                  {option.id === "docx" && (
+                    <div className="mb-2">
+                      <label className="flex items-center gap-2 text-xs">
+                        <input
+                          type="checkbox"
+                          checked={includeTranscript}
+                          onChange={(e) => setIncludeTranscript(e.target.checked)}
+                          className="rounded border-neutral-300 text-neutral-700 focus:ring-neutral-500 focus:ring-1"
+                        />
+                        <span className="text-neutral-600">Include transcript as appendix</span>
+                      </label>
+                    </div>
+                  )}
+
+                  {option.id === "html" && (
+                    <div className="mb-2">
+                      <label className="block text-xs font-medium text-neutral-600 mb-1">
+                        Password (optional)
+                      </label>
+                      <input
+                        type="password"
+                        value={htmlPassword}
+                        onChange={(e) => setHtmlPassword(e.target.value)}
+                        placeholder="Leave empty to share openly"
+                        className="w-full h-8 px-2 text-xs border border-neutral-200 rounded-md bg-white focus:outline-none focus:ring-1 focus:ring-neutral-500"
+                      />
+                    </div>
+                  )}
+
                   {option.id === "obsidian" && (
                     <>
                       <div className="mb-2">
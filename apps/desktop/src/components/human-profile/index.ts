@@ -1,2 +1,3 @@
+export * from "./meetings";
 export * from "./past-notes";
 export * from "./upcoming-events";
@@ -0,0 +1,79 @@
+import { Trans } from "@lingui/react/macro";
+import { useQuery } from "@tanstack/react-query";
+import { Link } from "@tanstack/react-router";
+import { format } from "date-fns";
+import { CalendarIcon, FileText } from "lucide-react";
+
+import { useHypr } from "@/contexts";
+import { commands as dbCommands, type Human, type PersonMeeting } from "@hypr/plugin-db";
+import { Card, CardContent } from "@hypr/ui/components/ui/card";
+import { EmptyState, LoadingSkeleton, ProfileSectionHeader } from "./common";
+
+// Mirrors how the people directory keys attendees.
+function personId(human: Human) {
+  return (human.email || human.full_name || "").trim().toLowerCase();
+}
+
+export function Meetings({ human }: { human: Human }) {
+  const { userId } = useHypr();
+  const id = personId(human);
+
+  const { data: meetings = [], isLoading } = useQuery({
+    enabled: !!id,
+    queryKey: ["people", "meetings", userId, id],
+    queryFn: () => dbCommands.listMeetingsWith(userId, id),
+  });
+
+  return (
+    <div className="mt-12">
+      <ProfileSectionHeader title="Meetings" />
+
+      {isLoading ? <LoadingSkeleton count={3} /> : meetings.length > 0
+        ? (
+          <div className="space-y-3">
+            {meetings.map((meeting) => (
+              <MeetingCard key={meeting.session_id ?? meeting.event_id} meeting={meeting} />
+            ))}
+          </div>
+        )
+        : (
+          <EmptyState
+            icon={<CalendarIcon className="h-14 w-14" />}
+            title={<Trans>No meetings with this contact yet</Trans>}
+          />
+        )}
+    </div>
+  );
+}
+
+function MeetingCard({ meeting }: { meeting: PersonMeeting }) {
+  const content = (
+    <Card className="hover:bg-gray-50 transition-colors cursor-pointer border border-gray-200 shadow-sm rounded-lg overflow-hidden">
+      <CardContent className="p-4">
+        <div className="flex items-start justify-between">
+          <div>
+            <h3 className="font-medium text-zinc-900">{meeting.title}</h3>
+            <p className="text-sm text-zinc-500 mt-1">
+              {format(new Date(meeting.date), "MMMM do, yyyy")}
+            </p>
+          </div>
+          {meeting.session_id && <FileText className="size-4 text-zinc-400" />}
+        </div>
+      </CardContent>
+    </Card>
+  );
+
+  if (meeting.session_id) {
+    return (
+      <Link to="/app/note/$id" params={{ id: meeting.session_id }} className="block">
+        {content}
+      </Link>
+    );
+  }
+
+  return (
+    <Link to="/app/note/event/$id" params={{ id: meeting.event_id! }} className="block">
+      {content}
+    </Link>
+  );
+}
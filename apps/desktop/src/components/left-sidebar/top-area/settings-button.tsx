@@ -2,7 +2,6 @@ import { Trans } from "@lingui/react/macro";
 import { useQuery } from "@tanstack/react-query";
 import type { LinkProps } from "@tanstack/react-router";
 import { getName, getVersion } from "@tauri-apps/api/app";
-import { check } from "@tauri-apps/plugin-updater";
 import { CastleIcon, CogIcon, ShieldIcon } from "lucide-react";
 import { useState } from "react";
 
@@ -10,6 +9,7 @@ import Shortcut from "@/components/shortcut";
 import { createUpdateToast } from "@/components/toast/ota";
 import { useHypr } from "@/contexts";
 import { useLicense } from "@/hooks/use-license";
+import { commands } from "@/types";
 import { openURL } from "@/utils/shell";
 import { commands as windowsCommands } from "@hypr/plugin-windows";
 import { Button } from "@hypr/ui/components/ui/button";
@@ -89,7 +89,7 @@ export function SettingsButton() {
     setOpen(false);
 
     try {
-      const update = await check();
+      const update = await commands.checkForUpdate();
 
       if (update) {
         const toastConfig = await createUpdateToast(update, "manual-update-check");
@@ -0,0 +1,131 @@
+import { useQueryClient } from "@tanstack/react-query";
+import { useEffect } from "react";
+
+import { enhanceNote, generateActionItems, generateTags, generateTitle } from "@/components/editor-area";
+import { useHypr } from "@/contexts";
+import { commands as dbCommands } from "@hypr/plugin-db";
+import { commands as taskCommands, events as taskEvents, type PipelineStageEvent } from "@hypr/plugin-task";
+import { commands as webhookCommands } from "@hypr/plugin-webhook";
+import type { OngoingSessionStore, SessionsStore } from "@hypr/utils/stores";
+
+// Well within the queue's 10 minute lease.
+const HEARTBEAT_INTERVAL_MS = 60 * 1000;
+
+/**
+ * Runs the pipeline stages that need the language model, whether or not their note is open.
+ * Mount it once, in the main window, so every stage runs exactly once. Transcribe and diarize
+ * run in the listener plugin; once they finish, an open note is reloaded to pick up their words.
+ */
+export function PipelineRunner({
+  sessionsStore,
+  ongoingSessionStore,
+}: {
+  sessionsStore: SessionsStore;
+  ongoingSessionStore: OngoingSessionStore;
+}) {
+  const { userId, onboardingSessionId } = useHypr();
+  const queryClient = useQueryClient();
+
+  useEffect(() => {
+    const runStage = async (event: PipelineStageEvent, abortController: AbortController) => {
+      const sessionId = event.session_id;
+      const session = await dbCommands.getSession({ id: sessionId });
+      if (!session) {
+        throw new Error(`session(id=${sessionId}) not found`);
+      }
+
+      const store = sessionsStore.getState().insert(session);
+      const sessions = { [sessionId]: store };
+
+      switch (event.stage) {
+        case "summarize": {
+          const { session: current, setEnhancing, updateEnhancedNote } = store.getState();
+          const { setEnhanceController } = ongoingSessionStore.getState();
+
+          setEnhancing(true);
+          setEnhanceController(abortController);
+          try {
+            const content = await enhanceNote({
+              sessionId,
+              templateId: event.template_id,
+              userId,
+              onboardingSessionId,
+              rawContent: current.raw_memo_html,
+              preMeetingNote: current.pre_meeting_memo_html ?? "",
+              originalContent: current.enhanced_memo_html ?? "",
+              abortSignal: abortController.signal,
+              onContent: updateEnhancedNote,
+            });
+            if (content) {
+              webhookCommands.dispatchWebhook("note.enhanced", { session_id: sessionId, content }).catch(console.error);
+            }
+          } finally {
+            setEnhancing(false);
+            setEnhanceController(null);
+          }
+          return;
+        }
+        case "action_items":
+          return generateActionItems(sessionId, sessions);
+        case "title":
+          return generateTitle(session.enhanced_memo_html || session.raw_memo_html, sessionId, sessions);
+        case "tags":
+          return generateTags(sessionId, sessions, queryClient);
+      }
+    };
+
+    const unlisten = taskEvents.taskEvent.listen(({ payload }) => {
+      if (!("stage" in payload)) {
+        return;
+      }
+
+      const event = payload.stage;
+      if (event.stage === "transcribe" || event.stage === "diarize") {
+        if (event.status === "completed") {
+          sessionsStore.getState().sessions[event.session_id]?.getState().refresh();
+        }
+        return;
+      }
+      if (event.status !== "started") {
+        return;
+      }
+
+      // Paused or cancelled from elsewhere: stop, and leave the run as it is.
+      let released = false;
+      const abortController = new AbortController();
+      const heartbeat = setInterval(() => {
+        taskCommands.heartbeatQueuedTask(event.task_id, null)
+          .then((task) => {
+            if (task.status !== "running") {
+              released = true;
+              abortController.abort();
+            }
+          })
+          .catch(console.error);
+      }, HEARTBEAT_INTERVAL_MS);
+
+      runStage(event, abortController)
+        .then(
+          () => taskCommands.completePipelineStage(event.task_id, event.stage),
+          (error) => {
+            if (released) {
+              return;
+            }
+            // Cancelling the summary stops the whole run, instead of retrying it.
+            if (String(error).includes("cancel") || error?.name === "AbortError") {
+              return taskCommands.cancelQueuedTask(event.task_id);
+            }
+            return taskCommands.failPipelineStage(event.task_id, event.stage, String(error));
+          },
+        )
+        .catch(console.error)
+        .finally(() => clearInterval(heartbeat));
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [sessionsStore, ongoingSessionStore, userId, onboardingSessionId, queryClient]);
+
+  return null;
+}
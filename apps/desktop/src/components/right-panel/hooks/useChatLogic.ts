@@ -9,7 +9,13 @@ import { useLicense } from "@/hooks/use-license";
 import { commands as analyticsCommands } from "@hypr/plugin-analytics";
 import { commands as connectorCommands } from "@hypr/plugin-connector";
 import { commands as dbCommands } from "@hypr/plugin-db";
-import { commands as mcpCommands } from "@hypr/plugin-mcp";
+import {
+  commands as mcpCommands,
+  guardToolExecute,
+  readOnlyToolNames,
+  StdioTransport,
+  withAuthRetry,
+} from "@hypr/plugin-mcp";
 import { commands as miscCommands } from "@hypr/plugin-misc";
 import { fetch as tauriFetch } from "@hypr/utils";
 import {
@@ -190,13 +196,13 @@ export function useChatLogic({
     const aiMessageId = crypto.randomUUID();
 
     try {
-      const provider = await modelProvider();
+      const provider = await modelProvider("Chat");
       const model = provider.languageModel("defaultModel");
 
       await queryClient.invalidateQueries({ queryKey: ["llm-connection"] });
       await new Promise(resolve => setTimeout(resolve, 100));
 
-      const llmConnection = await connectorCommands.getLlmConnection();
+      const { connection: llmConnection } = await connectorCommands.getLlmConnectionForTask("Chat");
       const { type } = llmConnection;
       const apiBase = llmConnection.connection?.api_base;
 
@@ -247,31 +253,33 @@ export function useChatLogic({
 
         for (const server of enabledSevers) {
           try {
-            const mcpClient = await experimental_createMCPClient({
-              transport: {
-                type: "sse",
-                url: server.url,
-                ...(server.headerKey && server.headerValue && {
-                  headers: {
-                    [server.headerKey]: server.headerValue,
+            const mcpClient = server.type === "stdio"
+              ? await experimental_createMCPClient({ transport: new StdioTransport(server) })
+              : await withAuthRetry(server, (headers) =>
+                experimental_createMCPClient({
+                  transport: {
+                    type: "sse",
+                    url: server.url,
+                    headers,
+                    onerror: (error) => {
+                      console.log("mcp client error: ", error);
+                    },
+                    onclose: () => {
+                      console.log("mcp client closed");
+                    },
                   },
-                }),
-                onerror: (error) => {
-                  console.log("mcp client error: ", error);
-                },
-                onclose: () => {
-                  console.log("mcp client closed");
-                },
-              },
-            });
+                }));
             allMcpClients.push(mcpClient);
 
             const tools = await mcpClient.tools();
+            const readOnly = await readOnlyToolNames(mcpClient);
             for (const [toolName, tool] of Object.entries(tools as Record<string, any>)) {
               newMcpTools[toolName] = dynamicTool({
                 description: tool.description,
                 inputSchema: tool.inputSchema || z.any(),
-                execute: tool.execute,
+                execute: guardToolExecute(server, toolName, tool.execute, {
+                  readOnly: readOnly.has(toolName),
+                }),
               });
             }
           } catch (error) {
@@ -1,10 +1,47 @@
-import { commands as mcpCommands } from "@hypr/plugin-mcp";
+import {
+  commands as mcpCommands,
+  guardToolExecute,
+  isUnauthorized,
+  type McpServer,
+  readOnlyToolNames,
+  StdioTransport,
+  withAuthRetry,
+} from "@hypr/plugin-mcp";
 import { dynamicTool, experimental_createMCPClient } from "@hypr/utils/ai";
 import { useQuery } from "@tanstack/react-query";
 import z from "zod";
 
 const mcpClientCache = new Map<string, any>();
 
+function serverKey(server: McpServer) {
+  return server.type === "stdio"
+    ? [server.command, ...(server.args ?? [])].join(" ")
+    : server.url;
+}
+
+function createClient(server: McpServer, forceRefresh = false) {
+  if (server.type === "stdio") {
+    return experimental_createMCPClient({ transport: new StdioTransport(server) });
+  }
+
+  const connect = (headers: Record<string, string>) =>
+    experimental_createMCPClient({
+      transport: {
+        type: "sse",
+        url: server.url,
+        headers,
+        onerror: (error) => {
+          console.error(`[MCP] Error from ${server.url}:`, error);
+        },
+        onclose: () => {
+          console.log(`[MCP] Connection closed for ${server.url}`);
+        },
+      },
+    });
+
+  return withAuthRetry(server, connect, forceRefresh);
+}
+
 export function useMcpTools() {
   return useQuery({
     queryKey: ["mcp-tools"],
@@ -16,7 +53,7 @@ export function useMcpTools() {
       });
 
       const servers = await mcpCommands.getServers();
-      console.log("[MCP] Found servers:", servers.length, servers.map(s => ({ url: s.url, enabled: s.enabled })));
+      console.log("[MCP] Found servers:", servers.length, servers.map(s => ({ server: serverKey(s), enabled: s.enabled })));
 
       const enabledServers = servers.filter((server) => server.enabled);
       console.log("[MCP] Enabled servers:", enabledServers.length);
@@ -30,42 +67,55 @@ export function useMcpTools() {
 
       for (const server of enabledServers) {
         const startTime = Date.now();
-        console.log(`[MCP] Processing server: ${server.url}`);
+        const key = serverKey(server);
+        console.log(`[MCP] Processing server: ${key}`);
 
         try {
-          let mcpClient = mcpClientCache.get(server.url);
+          let mcpClient = mcpClientCache.get(key);
 
           if (!mcpClient) {
-            console.log(`[MCP] Creating new client for ${server.url} (not in cache)`);
-            mcpClient = await experimental_createMCPClient({
-              transport: {
-                type: "sse",
-                url: server.url,
-                onerror: (error) => {
-                  console.error(`[MCP] Error from ${server.url}:`, error);
-                },
-                onclose: () => {
-                  console.log(`[MCP] Connection closed for ${server.url}`);
-                },
-              },
-            });
+            console.log(`[MCP] Creating new client for ${key} (not in cache)`);
+            mcpClient = await createClient(server);
 
-            mcpClientCache.set(server.url, mcpClient);
-            console.log(`[MCP] Client created and cached for ${server.url}`);
+            mcpClientCache.set(key, mcpClient);
+            console.log(`[MCP] Client created and cached for ${key}`);
           } else {
-            console.log(`[MCP] Using cached client for ${server.url}`);
+            console.log(`[MCP] Using cached client for ${key}`);
           }
 
-          console.log(`[MCP] Fetching tools from ${server.url}...`);
-          const tools = await mcpClient.tools();
+          console.log(`[MCP] Fetching tools from ${key}...`);
+          let tools;
+          try {
+            tools = await mcpClient.tools();
+          } catch (error) {
+            if (!isUnauthorized(server, error)) {
+              throw error;
+            }
+
+            // A cached client keeps sending the token it was created with, so rebuild it.
+            console.log(`[MCP] Token rejected by ${key}, reconnecting with a refreshed one`);
+            mcpClientCache.delete(key);
+            try {
+              await mcpClient.close();
+            } catch (closeError) {
+              console.error(`[MCP] Error closing client:`, closeError);
+            }
+
+            mcpClient = await createClient(server, true);
+            mcpClientCache.set(key, mcpClient);
+            tools = await mcpClient.tools();
+          }
+          const readOnly = await readOnlyToolNames(mcpClient);
           const toolCount = Object.keys(tools).length;
-          console.log(`[MCP] Received ${toolCount} tools from ${server.url}`);
+          console.log(`[MCP] Received ${toolCount} tools from ${key}`);
 
           for (const [toolName, tool] of Object.entries(tools as Record<string, any>)) {
             allTools[toolName] = dynamicTool({
               description: tool.description,
               inputSchema: tool.inputSchema || z.any(),
-              execute: tool.execute,
+              execute: guardToolExecute(server, toolName, tool.execute, {
+                readOnly: readOnly.has(toolName),
+              }),
               toModelOutput: (result: any) => {
                 console.log(`[MCP] Tool result:`, result);
                 return result;
@@ -74,10 +124,10 @@ export function useMcpTools() {
           }
 
           const elapsed = Date.now() - startTime;
-          console.log(`[MCP] Successfully processed ${server.url} in ${elapsed}ms`);
+          console.log(`[MCP] Successfully processed ${key} in ${elapsed}ms`);
         } catch (error) {
           const elapsed = Date.now() - startTime;
-          console.error(`[MCP] Error fetching tools from ${server.url} after ${elapsed}ms:`, error);
+          console.error(`[MCP] Error fetching tools from ${key} after ${elapsed}ms:`, error);
 
           if (error instanceof Error) {
             console.error(`[MCP] Error name: ${error.name}`);
@@ -85,8 +135,8 @@ export function useMcpTools() {
             console.error(`[MCP] Error stack:`, error.stack);
           }
 
-          mcpClientCache.delete(server.url);
-          console.log(`[MCP] Removed failed client from cache for ${server.url}`);
+          mcpClientCache.delete(key);
+          console.log(`[MCP] Removed failed client from cache for ${key}`);
         }
       }
 
@@ -5,6 +5,7 @@ import clsx from "clsx";
 
 import {
   AudioLinesIcon,
+  CaptionsIcon,
   CheckIcon,
   ChevronDownIcon,
   ClipboardIcon,
@@ -20,6 +21,7 @@ import { useHypr } from "@/contexts";
 import { useContainerWidth } from "@/hooks/use-container-width";
 import { commands as dbCommands, Human, Word2 } from "@hypr/plugin-db";
 import { commands as miscCommands } from "@hypr/plugin-misc";
+import { commands as windowsCommands } from "@hypr/plugin-windows";
 import TranscriptEditor, {
   getSpeakerLabel,
   SPEAKER_ID_ATTR,
@@ -78,6 +80,16 @@ function RenderInMeeting({ words }: { words: Word2[] }) {
         </div>
       </div>
 
+      <Button
+        onClick={() => windowsCommands.windowShow({ type: "captions" })}
+        size="sm"
+        variant="ghost"
+        title="Open live captions"
+        className="absolute top-1 right-2 z-10 h-7 px-2 text-neutral-500 hover:text-neutral-800"
+      >
+        <CaptionsIcon size={14} />
+      </Button>
+
       {!isAtBottom && (
         <Button
           onClick={scrollToBottom}
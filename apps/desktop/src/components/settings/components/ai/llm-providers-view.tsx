@@ -0,0 +1,249 @@
+import { Trans, useLingui } from "@lingui/react/macro";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { CheckCircle2Icon, Trash2Icon, XCircleIcon } from "lucide-react";
+import { useState } from "react";
+
+import {
+  commands as connectorCommands,
+  type CustomProvider,
+  type LlmTask,
+  type ProviderHealth,
+} from "@hypr/plugin-connector";
+import { Button } from "@hypr/ui/components/ui/button";
+import { Input } from "@hypr/ui/components/ui/input";
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@hypr/ui/components/ui/select";
+
+const TASKS: LlmTask[] = ["Enhance", "Title", "Chat", "Tags", "Translate", "Brief"];
+const DEFAULT_ROUTE = "__default__";
+
+export function LLMProvidersView() {
+  const { t } = useLingui();
+  const queryClient = useQueryClient();
+
+  const providers = useQuery({
+    queryKey: ["connector", "customProviders"],
+    queryFn: () => connectorCommands.listCustomProviders(),
+  });
+
+  const routes = useQuery({
+    queryKey: ["connector", "taskRoutes"],
+    queryFn: () => connectorCommands.getTaskRoutes(),
+  });
+
+  const invalidate = () => {
+    queryClient.invalidateQueries({ queryKey: ["connector"] });
+    queryClient.invalidateQueries({ queryKey: ["llm-connection"] });
+  };
+
+  const removeProvider = useMutation({
+    mutationFn: (id: string) => connectorCommands.removeCustomProvider(id),
+    onSuccess: invalidate,
+  });
+
+  const setRoute = useMutation({
+    mutationFn: ({ task, providerId }: { task: LlmTask; providerId: string | null }) =>
+      connectorCommands.setTaskRoute(task, providerId),
+    onSuccess: invalidate,
+  });
+
+  const taskLabels: Record<LlmTask, string> = {
+    Enhance: t`Note enhancement`,
+    Title: t`Title generation`,
+    Chat: t`Chat`,
+    Tags: t`Tag suggestions`,
+    Translate: t`Live translation`,
+    Brief: t`Pre-meeting briefs`,
+  };
+
+  return (
+    <div className="max-w-2xl space-y-6">
+      <div>
+        <h3 className="text-sm font-medium">
+          <Trans>OpenAI-compatible servers</Trans>
+        </h3>
+        <p className="text-xs text-muted-foreground">
+          <Trans>Register Ollama, LM Studio, vLLM or any other server that speaks the OpenAI API.</Trans>
+        </p>
+      </div>
+
+      <div className="space-y-2">
+        {(providers.data ?? []).map((provider) => (
+          <ProviderRow
+            key={provider.id}
+            provider={provider}
+            onRemove={() => removeProvider.mutate(provider.id)}
+          />
+        ))}
+      </div>
+
+      <AddProviderForm onAdded={invalidate} />
+
+      {(providers.data?.length ?? 0) > 0 && (
+        <div className="space-y-2">
+          <h3 className="text-sm font-medium">
+            <Trans>Task routing</Trans>
+          </h3>
+          {TASKS.map((task) => (
+            <div key={task} className="flex items-center justify-between">
+              <span className="text-sm">{taskLabels[task]}</span>
+              <Select
+                value={routes.data?.[task] ?? DEFAULT_ROUTE}
+                onValueChange={(value) =>
+                  setRoute.mutate({ task, providerId: value === DEFAULT_ROUTE ? null : value })}
+              >
+                <SelectTrigger className="w-[200px]">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  <SelectItem value={DEFAULT_ROUTE}>
+                    <Trans>Default model</Trans>
+                  </SelectItem>
+                  {(providers.data ?? []).map((provider) => (
+                    <SelectItem key={provider.id} value={provider.id}>
+                      {provider.name}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+}
+
+function ProviderRow({ provider, onRemove }: { provider: CustomProvider; onRemove: () => void }) {
+  const [health, setHealth] = useState<ProviderHealth | null>(null);
+
+  const check = useMutation({
+    mutationFn: () => connectorCommands.checkCustomProvider(provider.id),
+    onSuccess: setHealth,
+  });
+
+  return (
+    <div className="flex items-center justify-between rounded-lg border p-3">
+      <div className="min-w-0">
+        <div className="flex items-center gap-2 text-sm font-medium">
+          {provider.name}
+          {health && (health.ok
+            ? <CheckCircle2Icon className="h-4 w-4 text-green-600" />
+            : <XCircleIcon className="h-4 w-4 text-red-600" />)}
+        </div>
+        <div className="truncate text-xs text-muted-foreground">
+          {provider.api_base}
+          {provider.default_model && ` · ${provider.default_model}`}
+        </div>
+        {health && (
+          <div className="text-xs text-muted-foreground">
+            {health.ok
+              ? <Trans>{health.models.length} models · {health.latency_ms} ms</Trans>
+              : health.error}
+          </div>
+        )}
+      </div>
+      <div className="flex items-center gap-2">
+        <Button variant="outline" size="sm" disabled={check.isPending} onClick={() => check.mutate()}>
+          <Trans>Check</Trans>
+        </Button>
+        <Button variant="ghost" size="icon" onClick={onRemove}>
+          <Trash2Icon className="h-4 w-4" />
+        </Button>
+      </div>
+    </div>
+  );
+}
+
+function AddProviderForm({ onAdded }: { onAdded: () => void }) {
+  const { t } = useLingui();
+
+  const [name, setName] = useState("");
+  const [apiBase, setApiBase] = useState("");
+  const [apiKey, setApiKey] = useState("");
+  const [models, setModels] = useState<string[]>([]);
+  const [defaultModel, setDefaultModel] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const provider = (): CustomProvider => ({
+    id: crypto.randomUUID(),
+    name: name.trim() || apiBase,
+    api_base: apiBase.trim(),
+    api_key: apiKey.trim() || null,
+    default_model: defaultModel,
+  });
+
+  const discover = useMutation({
+    mutationFn: () => connectorCommands.listCustomProviderModels(provider()),
+    onSuccess: (found) => {
+      setError(null);
+      setModels(found);
+      setDefaultModel(found[0] ?? null);
+    },
+    onError: (e) => setError(String(e)),
+  });
+
+  const add = useMutation({
+    mutationFn: () => connectorCommands.upsertCustomProvider(provider()),
+    onSuccess: () => {
+      setName("");
+      setApiBase("");
+      setApiKey("");
+      setModels([]);
+      setDefaultModel(null);
+      setError(null);
+      onAdded();
+    },
+    onError: (e) => setError(String(e)),
+  });
+
+  return (
+    <div className="space-y-2 rounded-lg border p-3">
+      <div className="grid grid-cols-2 gap-2">
+        <Input placeholder={t`Name`} value={name} onChange={(e) => setName(e.target.value)} />
+        <Input
+          placeholder="http://localhost:11434/v1"
+          value={apiBase}
+          onChange={(e) => setApiBase(e.target.value)}
+        />
+        <Input
+          type="password"
+          placeholder={t`API key (optional)`}
+          value={apiKey}
+          onChange={(e) => setApiKey(e.target.value)}
+        />
+        <Select
+          value={defaultModel ?? undefined}
+          onValueChange={setDefaultModel}
+          disabled={models.length === 0}
+        >
+          <SelectTrigger>
+            <SelectValue placeholder={t`Default model`} />
+          </SelectTrigger>
+          <SelectContent>
+            {models.map((model) => (
+              <SelectItem key={model} value={model}>
+                {model}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+      </div>
+
+      {error && <p className="text-xs text-red-600">{error}</p>}
+
+      <div className="flex justify-end gap-2">
+        <Button
+          variant="outline"
+          size="sm"
+          disabled={!apiBase || discover.isPending}
+          onClick={() => discover.mutate()}
+        >
+          <Trans>Discover models</Trans>
+        </Button>
+        <Button size="sm" disabled={!apiBase || add.isPending} onClick={() => add.mutate()}>
+          <Trans>Add server</Trans>
+        </Button>
+      </div>
+    </div>
+  );
+}
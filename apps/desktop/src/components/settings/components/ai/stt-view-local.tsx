@@ -7,7 +7,12 @@ import { useEffect, useMemo } from "react";
 import { useHypr } from "@/contexts";
 import { useLicense } from "@/hooks/use-license";
 import { commands as analyticsCommands } from "@hypr/plugin-analytics";
-import { commands as localSttCommands, ServerHealth, type SupportedSttModel } from "@hypr/plugin-local-stt";
+import {
+  commands as localSttCommands,
+  ServerHealth,
+  type ServerMetrics,
+  type SupportedSttModel,
+} from "@hypr/plugin-local-stt";
 import { Button } from "@hypr/ui/components/ui/button";
 import { cn } from "@hypr/ui/lib/utils";
 import { SharedSTTProps, STTModel } from "./shared";
@@ -133,7 +138,8 @@ export function STTViewLocal({
     <div className="space-y-8">
       {/* Basic Models Section */}
       <BasicModelsSection
-        status={servers.data?.internal}
+        status={servers.data?.internal?.health}
+        metrics={servers.data?.internal?.metrics}
         modelsToShow={modelsToShow}
         selectedSTTModel={selectedSTTModel}
         setSelectedSTTModel={setSelectedSTTModel}
@@ -151,7 +157,8 @@ export function STTViewLocal({
 
           {/* Pro Models Section */}
           <ProModelsSection
-            status={servers.data?.external}
+            status={servers.data?.external?.health}
+            metrics={servers.data?.external?.metrics}
             selectedSTTModel={selectedSTTModel}
             setSelectedSTTModel={setSelectedSTTModel}
             downloadingModels={downloadingModels}
@@ -171,6 +178,7 @@ export function STTViewLocal({
 // ============================================
 function BasicModelsSection({
   status,
+  metrics,
   modelsToShow,
   selectedSTTModel,
   setSelectedSTTModel,
@@ -179,7 +187,7 @@ function BasicModelsSection({
   provider,
   setProviderToLocal,
   userId,
-}: ModelSectionProps) {
+}: ModelSectionProps & { metrics?: ServerMetrics | null }) {
   const handleShowFileLocation = async () => {
     const path = await localSttCommands.modelsDir();
     openPath(path);
@@ -194,6 +202,7 @@ function BasicModelsSection({
         status={status}
         docsUrl="https://docs.hyprnote.com/models"
       />
+      {metrics && <ServerMetricsSummary metrics={metrics} />}
 
       {/* Models List */}
       <div className="space-y-2 mt-4">
@@ -221,6 +230,7 @@ function BasicModelsSection({
 // ============================================
 function ProModelsSection({
   status,
+  metrics,
   selectedSTTModel,
   setSelectedSTTModel,
   downloadingModels,
@@ -228,7 +238,7 @@ function ProModelsSection({
   provider,
   setProviderToLocal,
   userId,
-}: Omit<ModelSectionProps, "modelsToShow">) {
+}: Omit<ModelSectionProps, "modelsToShow"> & { metrics?: ServerMetrics | null }) {
   const { getLicense } = useLicense();
 
   const handleShowFileLocation = async () => {
@@ -266,6 +276,7 @@ function ProModelsSection({
         status={status}
         docsUrl="https://docs.hyprnote.com/models"
       />
+      {metrics && <ServerMetricsSummary metrics={metrics} />}
 
       {/* Models List */}
       <div className="space-y-2 mt-4">
@@ -345,6 +356,31 @@ function SectionHeader({
   );
 }
 
+function ServerMetricsSummary({ metrics }: { metrics: ServerMetrics }) {
+  // A real-time factor above 1 means audio arrives faster than it is transcribed.
+  const fallingBehind = (metrics.real_time_factor ?? 0) > 1;
+
+  const parts = [
+    !metrics.model_loaded && "Model not loaded",
+    metrics.queue_depth !== null && `Queue: ${metrics.queue_depth}`,
+    metrics.real_time_factor !== null && `RTF: ${metrics.real_time_factor.toFixed(2)}`,
+    metrics.latency_ms !== null && `Latency: ${(metrics.latency_ms / 1000).toFixed(1)}s`,
+    !!metrics.dropped_frames && `Dropped: ${metrics.dropped_frames}`,
+    metrics.memory_bytes !== null && `Memory: ${(metrics.memory_bytes / 1024 / 1024).toFixed(0)} MB`,
+  ].filter(Boolean);
+
+  if (parts.length === 0) {
+    return null;
+  }
+
+  return (
+    <p className={cn("text-xs", fallingBehind ? "text-amber-600" : "text-gray-400")}>
+      {parts.join(" · ")}
+      {fallingBehind && " — transcription is falling behind"}
+    </p>
+  );
+}
+
 function ModelEntry({
   model,
   selectedSTTModel,
@@ -8,7 +8,11 @@ import { type Calendar, commands as dbCommands } from "@hypr/plugin-db";
 import { Checkbox } from "@hypr/ui/components/ui/checkbox";
 import { cn } from "@hypr/ui/lib/utils";
 
-export function CalendarSelector() {
+export function CalendarSelector({
+  sync = appleCalendarCommands.syncCalendars,
+}: {
+  sync?: () => Promise<unknown>;
+}) {
   const { userId } = useHypr();
 
   const calendarsQuery = useQuery({
@@ -42,7 +46,7 @@ export function CalendarSelector() {
   const syncCalendarsMutation = useMutation({
     mutationFn: async () => {
       const startTime = Date.now();
-      const result = await appleCalendarCommands.syncCalendars();
+      const result = await sync();
       const elapsedTime = Date.now() - startTime;
 
       if (elapsedTime < 500) {
@@ -0,0 +1,183 @@
+import { Trans } from "@lingui/react/macro";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { LinkIcon, RefreshCwIcon, Trash2Icon } from "lucide-react";
+import { useState } from "react";
+
+import { commands as calendarCommands, type Subscription, type SubscriptionKind } from "@hypr/plugin-calendar";
+import { Button } from "@hypr/ui/components/ui/button";
+import { Input } from "@hypr/ui/components/ui/input";
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@hypr/ui/components/ui/select";
+import { cn } from "@hypr/ui/lib/utils";
+
+const REFRESH_INTERVALS = [15, 30, 60, 180, 720];
+
+export function CalendarSubscriptions() {
+  const queryClient = useQueryClient();
+
+  const [url, setUrl] = useState("");
+  const [kind, setKind] = useState<SubscriptionKind>("Ics");
+  const [username, setUsername] = useState("");
+  const [password, setPassword] = useState("");
+
+  const subscriptionsQuery = useQuery({
+    queryKey: ["calendar", "subscriptions"],
+    queryFn: () => calendarCommands.listSubscriptions(),
+  });
+
+  const onChanged = () => {
+    subscriptionsQuery.refetch();
+    queryClient.invalidateQueries({ queryKey: ["calendars"] });
+  };
+
+  const addMutation = useMutation({
+    mutationFn: () =>
+      calendarCommands.addSubscription(
+        url.trim(),
+        kind,
+        username.trim() || null,
+        password || null,
+        null,
+      ),
+    onSuccess: () => {
+      setUrl("");
+      setUsername("");
+      setPassword("");
+      onChanged();
+    },
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: (subscription: Subscription) => calendarCommands.updateSubscription(subscription, null),
+    onSuccess: onChanged,
+    onError: console.error,
+  });
+
+  const removeMutation = useMutation({
+    mutationFn: (id: string) => calendarCommands.removeSubscription(id),
+    onSuccess: onChanged,
+    onError: console.error,
+  });
+
+  const syncMutation = useMutation({
+    mutationFn: () => calendarCommands.syncSubscriptions(),
+    onSuccess: onChanged,
+    onError: console.error,
+  });
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center gap-3">
+        <div className="flex size-6 items-center justify-center">
+          <LinkIcon size={16} />
+        </div>
+        <div>
+          <div className="flex items-center gap-2">
+            <div className="text-sm font-medium">
+              <Trans>Subscribed Calendars</Trans>
+            </div>
+            <button
+              disabled={syncMutation.isPending}
+              onClick={() => syncMutation.mutate()}
+              className="rounded-full p-1 hover:bg-gray-100 transition-colors"
+            >
+              <RefreshCwIcon
+                size={12}
+                className={cn(
+                  syncMutation.isPending && "animate-spin",
+                  "text-gray-500 hover:text-gray-700",
+                )}
+              />
+            </button>
+          </div>
+          <div className="text-xs text-muted-foreground">
+            <Trans>ICS feeds and CalDAV calendars, added by URL</Trans>
+          </div>
+        </div>
+      </div>
+
+      <div className="space-y-3 pl-9">
+        {(subscriptionsQuery.data ?? []).map((subscription) => (
+          <div key={subscription.id} className="rounded-lg border p-3 space-y-2">
+            <div className="flex items-center justify-between gap-2">
+              <div className="min-w-0">
+                <div className="truncate text-sm">{subscription.url}</div>
+                <div className="text-xs text-muted-foreground">
+                  {subscription.kind === "CalDav" ? "CalDAV" : "ICS"}
+                  {subscription.last_synced_at && (
+                    <>
+                      {" · "}
+                      <Trans>Synced {new Date(subscription.last_synced_at).toLocaleString()}</Trans>
+                    </>
+                  )}
+                </div>
+              </div>
+              <div className="flex items-center gap-2">
+                <Select
+                  value={subscription.refresh_interval_minutes.toString()}
+                  onValueChange={(value) =>
+                    updateMutation.mutate({ ...subscription, refresh_interval_minutes: Number(value) })}
+                >
+                  <SelectTrigger className="h-8 w-28 text-xs">
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    {REFRESH_INTERVALS.map((minutes) => (
+                      <SelectItem key={minutes} value={minutes.toString()}>
+                        {minutes < 60 ? `${minutes} min` : `${minutes / 60} h`}
+                      </SelectItem>
+                    ))}
+                  </SelectContent>
+                </Select>
+                <Button
+                  variant="ghost"
+                  size="icon"
+                  disabled={removeMutation.isPending}
+                  onClick={() => removeMutation.mutate(subscription.id)}
+                >
+                  <Trash2Icon size={14} />
+                </Button>
+              </div>
+            </div>
+            {subscription.last_error && <div className="text-xs text-red-600">{subscription.last_error}</div>}
+          </div>
+        ))}
+
+        <div className="space-y-2">
+          <div className="flex gap-2">
+            <Select value={kind} onValueChange={(value) => setKind(value as SubscriptionKind)}>
+              <SelectTrigger className="w-28">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value="Ics">ICS</SelectItem>
+                <SelectItem value="CalDav">CalDAV</SelectItem>
+              </SelectContent>
+            </Select>
+            <Input
+              value={url}
+              onChange={(e) => setUrl(e.target.value)}
+              placeholder={kind === "Ics" ? "webcal://example.com/calendar.ics" : "https://example.com/dav/calendars/me/work/"}
+            />
+          </div>
+          <div className="flex gap-2">
+            <Input
+              value={username}
+              onChange={(e) => setUsername(e.target.value)}
+              placeholder="Username (optional)"
+            />
+            <Input
+              type="password"
+              value={password}
+              onChange={(e) => setPassword(e.target.value)}
+              placeholder="Password (optional)"
+            />
+            <Button disabled={!url.trim() || addMutation.isPending} onClick={() => addMutation.mutate()}>
+              <Trans>Add</Trans>
+            </Button>
+          </div>
+          {addMutation.error && <div className="text-xs text-red-600">{String(addMutation.error)}</div>}
+        </div>
+      </div>
+    </div>
+  );
+}
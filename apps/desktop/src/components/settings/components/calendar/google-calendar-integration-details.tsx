@@ -0,0 +1,183 @@
+import { Trans } from "@lingui/react/macro";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { openUrl } from "@tauri-apps/plugin-opener";
+import { useEffect, useState } from "react";
+
+import { commands as authCommands, events as authEvents } from "@hypr/plugin-auth";
+import { commands as calendarCommands } from "@hypr/plugin-calendar";
+import { Button } from "@hypr/ui/components/ui/button";
+import { Input } from "@hypr/ui/components/ui/input";
+import { cn } from "@hypr/ui/lib/utils";
+import { CalendarSelector } from "./calendar-selector";
+import { GoogleIcon } from "./calendar-icon-with-text";
+
+export function GoogleCalendarIntegrationDetails() {
+  const queryClient = useQueryClient();
+
+  const [clientId, setClientId] = useState("");
+  const [clientSecret, setClientSecret] = useState("");
+  const [loginPort, setLoginPort] = useState<number | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const configQuery = useQuery({
+    queryKey: ["settings", "googleCalendarConfig"],
+    queryFn: () => authCommands.getGoogleCalendarConfig(),
+  });
+
+  const connectedQuery = useQuery({
+    queryKey: ["settings", "googleCalendarConnected"],
+    queryFn: () => authCommands.isGoogleCalendarConnected(),
+  });
+
+  const syncMutation = useMutation({
+    mutationFn: () => calendarCommands.syncGoogleCalendar(),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ["calendars"] });
+    },
+    onError: console.error,
+  });
+
+  useEffect(() => {
+    if (loginPort === null) {
+      return;
+    }
+
+    let unlisten: (() => void) | undefined;
+
+    authEvents.authEvent
+      .listen(({ payload }) => {
+        if (payload === "googleCalendarConnected") {
+          setLoginPort(null);
+          connectedQuery.refetch();
+          syncMutation.mutate();
+          return;
+        }
+
+        if (typeof payload === "object" && "googleCalendarError" in payload) {
+          setLoginPort(null);
+          setError(payload.googleCalendarError);
+        }
+      })
+      .then((fn) => {
+        unlisten = fn;
+      });
+
+    return () => {
+      unlisten?.();
+      authCommands.stopOauthServer(loginPort);
+    };
+  }, [loginPort]);
+
+  const saveConfigMutation = useMutation({
+    mutationFn: () =>
+      authCommands.setGoogleCalendarConfig({
+        client_id: clientId.trim(),
+        client_secret: clientSecret.trim() || null,
+      }),
+    onSuccess: () => configQuery.refetch(),
+    onError: console.error,
+  });
+
+  const connectMutation = useMutation({
+    mutationFn: () => authCommands.startGoogleCalendarLogin(),
+    onSuccess: ({ port, url }) => {
+      setError(null);
+      setLoginPort(port);
+      openUrl(url);
+    },
+    onError: (e) => setError(String(e)),
+  });
+
+  const disconnectMutation = useMutation({
+    mutationFn: () => calendarCommands.disconnectGoogleCalendar(),
+    onSuccess: () => {
+      connectedQuery.refetch();
+      queryClient.invalidateQueries({ queryKey: ["calendars"] });
+    },
+    onError: console.error,
+  });
+
+  if (!configQuery.isLoading && !configQuery.data) {
+    return (
+      <div className="space-y-2 rounded-lg border p-4">
+        <div className="text-xs text-muted-foreground">
+          <Trans>Enter the client ID of a "Desktop app" OAuth client from the Google Cloud console.</Trans>
+        </div>
+        <Input value={clientId} onChange={(e) => setClientId(e.target.value)} placeholder="Client ID" />
+        <div className="flex gap-2">
+          <Input
+            type="password"
+            value={clientSecret}
+            onChange={(e) => setClientSecret(e.target.value)}
+            placeholder="Client secret"
+          />
+          <Button
+            disabled={!clientId.trim() || saveConfigMutation.isPending}
+            onClick={() => saveConfigMutation.mutate()}
+          >
+            <Trans>Save</Trans>
+          </Button>
+        </div>
+      </div>
+    );
+  }
+
+  return (
+    <div
+      className={cn(
+        "flex flex-col rounded-lg border p-4",
+        !connectedQuery.data && "bg-muted",
+      )}
+    >
+      <div className="flex items-center justify-between">
+        <div className="flex items-center gap-3">
+          <div className="flex size-6 items-center justify-center">
+            <GoogleIcon />
+          </div>
+          <div>
+            <div className="text-sm font-medium">
+              <Trans>Google Account</Trans>
+            </div>
+            <div className="text-xs text-muted-foreground">
+              {connectedQuery.data
+                ? <Trans>Connected</Trans>
+                : loginPort !== null
+                ? <Trans>Continue in your browser</Trans>
+                : <Trans>Connect your calendar and track events</Trans>}
+            </div>
+          </div>
+        </div>
+        {connectedQuery.data
+          ? (
+            <Button
+              variant="outline"
+              size="sm"
+              disabled={disconnectMutation.isPending}
+              onClick={() => disconnectMutation.mutate()}
+            >
+              <Trans>Disconnect</Trans>
+            </Button>
+          )
+          : (
+            <Button
+              variant="outline"
+              size="sm"
+              disabled={connectMutation.isPending || loginPort !== null}
+              onClick={() => connectMutation.mutate()}
+              className="min-w-12 text-center"
+            >
+              <Trans>Connect</Trans>
+            </Button>
+          )}
+      </div>
+
+      {error && <div className="mt-2 text-xs text-red-600">{error}</div>}
+
+      {connectedQuery.data && (
+        <div className="mt-4 border-t pt-4">
+          <CalendarSelector sync={calendarCommands.syncGoogleCalendar} />
+        </div>
+      )}
+    </div>
+  );
+}
@@ -1,3 +1,5 @@
 export * from "./apple-calendar-integration-details";
 export * from "./calendar-icon-with-text";
 export * from "./calendar-selector";
+export * from "./calendar-subscriptions";
+export * from "./google-calendar-integration-details";
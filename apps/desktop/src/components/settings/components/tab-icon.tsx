@@ -6,6 +6,7 @@ import {
   CalendarIcon,
   CreditCardIcon,
   LayoutTemplateIcon,
+  ListTodoIcon,
   MessageSquareIcon,
   SettingsIcon,
   SparklesIcon,
@@ -56,6 +57,8 @@ export function TabIcon({ tab }: { tab: Tab }) {
       return <CreditCardIcon className="h-4 w-4" />;
     case "mcp":
       return <McpIcon className="h-4 w-4" />;
+    case "tasks":
+      return <ListTodoIcon className="h-4 w-4" />;
     default:
       return null;
   }
@@ -0,0 +1,104 @@
+import { useLingui } from "@lingui/react/macro";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { GripVertical as HandleIcon } from "lucide-react";
+import { Reorder, useDragControls } from "motion/react";
+
+import { commands as dbCommands, type Pipeline, type PipelineStage, type PipelineStep } from "@hypr/plugin-db";
+import { Switch } from "@hypr/ui/components/ui/switch";
+
+interface PipelineStagesProps {
+  // `null` for notes enhanced without a template.
+  templateId: string | null;
+  disabled: boolean;
+}
+
+/**
+ * What runs after a recording stops, in order. Drag to reorder, switch to turn off.
+ */
+export function PipelineStages({ templateId, disabled }: PipelineStagesProps) {
+  const queryClient = useQueryClient();
+  const queryKey = ["pipeline", templateId];
+
+  const pipeline = useQuery({
+    queryKey,
+    queryFn: () => dbCommands.getPipeline(templateId),
+  });
+
+  const save = useMutation({
+    mutationFn: (steps: PipelineStep[]) => dbCommands.upsertPipeline({ ...pipeline.data!, steps }),
+    onMutate: (steps) => {
+      queryClient.setQueryData<Pipeline>(queryKey, (prev) => prev && { ...prev, steps });
+    },
+    onSettled: () => queryClient.invalidateQueries({ queryKey }),
+  });
+
+  if (!pipeline.data) {
+    return null;
+  }
+
+  const steps = pipeline.data.steps;
+
+  const handleReorder = (next: PipelineStep[]) => {
+    if (!disabled) {
+      save.mutate(next);
+    }
+  };
+
+  const handleToggle = (stage: PipelineStage, enabled: boolean) => {
+    save.mutate(steps.map((step) => (step.stage === stage ? { ...step, enabled } : step)));
+  };
+
+  return (
+    <Reorder.Group values={steps} onReorder={handleReorder}>
+      <div className="flex flex-col space-y-2">
+        {steps.map((step) => (
+          <Reorder.Item key={step.stage} value={step}>
+            <StageItem disabled={disabled} step={step} onToggle={handleToggle} />
+          </Reorder.Item>
+        ))}
+      </div>
+    </Reorder.Group>
+  );
+}
+
+function StageItem({
+  disabled,
+  step,
+  onToggle,
+}: {
+  disabled: boolean;
+  step: PipelineStep;
+  onToggle: (stage: PipelineStage, enabled: boolean) => void;
+}) {
+  const { t } = useLingui();
+  const dragControls = useDragControls();
+
+  const labels: Record<PipelineStage, string> = {
+    transcribe: t`Transcribe the recording, if nothing was transcribed live`,
+    diarize: t`Name speakers with a known voice`,
+    summarize: t`Summarize`,
+    action_items: t`List action items`,
+    title: t`Generate a title`,
+    tags: t`Suggest tags`,
+  };
+
+  return (
+    <div className="flex items-center gap-2 rounded-md border border-border bg-card px-2 py-1.5">
+      <button
+        className="cursor-move opacity-30 hover:opacity-60 transition-opacity"
+        onPointerDown={(e) => dragControls.start(e)}
+        disabled={disabled}
+      >
+        <HandleIcon className="h-4 w-4 text-muted-foreground" />
+      </button>
+
+      <span className="flex-1 text-sm">{labels[step.stage]}</span>
+
+      <Switch
+        checked={step.enabled}
+        onCheckedChange={(enabled) => onToggle(step.stage, enabled)}
+        disabled={disabled}
+      />
+    </div>
+  );
+}
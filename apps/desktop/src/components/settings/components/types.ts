@@ -5,6 +5,7 @@ import {
   CreditCard,
   HelpCircle,
   LayoutTemplate,
+  ListTodo,
   MessageSquare,
   NetworkIcon,
   Settings,
@@ -22,6 +23,7 @@ export type Tab =
   | "templates"
   | "integrations"
   | "mcp"
+  | "tasks"
   | "billing"
   | "help-support";
 
@@ -35,6 +37,7 @@ export const TABS: { name: Tab; icon: LucideIcon }[] = [
   { name: "templates", icon: LayoutTemplate },
   { name: "integrations", icon: MessageSquare },
   { name: "mcp", icon: NetworkIcon },
+  { name: "tasks", icon: ListTodo },
   { name: "billing", icon: CreditCard },
   { name: "help-support", icon: HelpCircle },
 ];
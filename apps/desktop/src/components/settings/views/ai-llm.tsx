@@ -30,6 +30,7 @@ import { showLlmModelDownloadToast } from "../../toast/shared";
 
 import { LLMCustomView } from "../components/ai/llm-custom-view";
 import { LLMLocalView } from "../components/ai/llm-local-view";
+import { LLMProvidersView } from "../components/ai/llm-providers-view";
 import {
   ConfigureEndpointConfig,
   CustomFormValues,
@@ -678,6 +679,8 @@ export default function LlmAI() {
         <div className="space-y-8">
           <LLMCustomView {...customEndpointProps} />
 
+          <LLMProvidersView />
+
           <div
             className={cn(
               "max-w-2xl space-y-4",
@@ -1,10 +1,15 @@
 import { type CalendarIntegration } from "@/types";
 import { Accordion, AccordionContent, AccordionItem, AccordionTrigger } from "@hypr/ui/components/ui/accordion";
-import { AppleCalendarIntegrationDetails, CalendarIconWithText } from "../components/calendar";
+import {
+  AppleCalendarIntegrationDetails,
+  CalendarIconWithText,
+  CalendarSubscriptions,
+  GoogleCalendarIntegrationDetails,
+} from "../components/calendar";
 
 const supportedIntegrations: CalendarIntegration[] = [
   "apple-calendar",
-  // "google-calendar",
+  "google-calendar",
   // "outlook-calendar",
 ];
 
@@ -18,19 +23,22 @@ export default function Calendar() {
           </li>
         ))}
       </ul>
+      <div className="mt-6">
+        <CalendarSubscriptions />
+      </div>
     </div>
   );
 }
 
 function Integration({ type }: { type: CalendarIntegration }) {
   return (
-    <Accordion type="single" collapsible defaultValue={"apple"}>
-      <AccordionItem value="apple">
+    <Accordion type="single" collapsible defaultValue={"apple-calendar"}>
+      <AccordionItem value={type}>
         <AccordionTrigger>
           <CalendarIconWithText type={type} />
         </AccordionTrigger>
         <AccordionContent className="px-2">
-          <AppleCalendarIntegrationDetails />
+          {type === "apple-calendar" ? <AppleCalendarIntegrationDetails /> : <GoogleCalendarIntegrationDetails />}
         </AccordionContent>
       </AccordionItem>
     </Accordion>
@@ -4,12 +4,20 @@ import { Trans, useLingui } from "@lingui/react/macro";
 import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
 import * as autostart from "@tauri-apps/plugin-autostart";
 import { Plus, X } from "lucide-react";
-import { useEffect } from "react";
+import { useEffect, useState } from "react";
 import { useForm } from "react-hook-form";
 import { z } from "zod";
 
 import { showModelSelectToast } from "@/components/toast/model-select";
-import { commands as dbCommands, type ConfigGeneral } from "@hypr/plugin-db";
+import { commands, type ProfileInfo } from "@/types";
+import { commands as analyticsCommands, type Consent } from "@hypr/plugin-analytics";
+import {
+  commands as dbCommands,
+  type ConfigGeneral,
+  type DictionaryEntry,
+  type WordFilter,
+} from "@hypr/plugin-db";
+import { commands as listenerCommands } from "@hypr/plugin-listener";
 import { Badge } from "@hypr/ui/components/ui/badge";
 import { Button } from "@hypr/ui/components/ui/button";
 import { Command, CommandEmpty, CommandGroup, CommandInput, CommandItem } from "@hypr/ui/components/ui/command";
@@ -22,6 +30,8 @@ import {
   FormLabel,
   FormMessage,
 } from "@hypr/ui/components/ui/form";
+import { Input } from "@hypr/ui/components/ui/input";
+import { Label } from "@hypr/ui/components/ui/label";
 import { Popover, PopoverContent, PopoverTrigger } from "@hypr/ui/components/ui/popover";
 import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@hypr/ui/components/ui/select";
 import { Switch } from "@hypr/ui/components/ui/switch";
@@ -74,18 +84,62 @@ const SUPPORTED_LANGUAGES: ISO_639_1_CODE[] = [
   "he",
 ];
 
+const TRANSLATION_OFF = "off";
+
+const RETENTION_KEEP = "keep";
+const AUDIO_RETENTION_DAYS = [7, 30, 90, 365];
+const TRANSCRIPT_RETENTION_MONTHS = [3, 6, 12, 24];
+
+const CAPTION_LINES = [1, 2, 3, 4];
+const DEFAULT_CAPTION_LINES = 2;
+
+const formatRetention = (value: number | null | undefined) => value ? String(value) : RETENTION_KEEP;
+const parseRetention = (value: string) => value === RETENTION_KEEP ? null : Number(value);
+
 const schema = z.object({
   autostart: z.boolean().optional(),
   displayLanguage: z.enum(SUPPORTED_LANGUAGES as [string, ...string[]]),
   spokenLanguages: z.array(z.enum(SUPPORTED_LANGUAGES as [string, ...string[]])).min(1),
-  telemetryConsent: z.boolean().optional(),
   jargons: z.string(),
+  dictionary: z.string(),
+  fillerWords: z.enum(["keep", "remove", "mask"]),
+  profanity: z.enum(["keep", "remove", "mask"]),
   saveRecordings: z.boolean().optional(),
   summaryLanguage: z.enum(SUPPORTED_LANGUAGES as [string, ...string[]]),
+  translationLanguage: z.enum([TRANSLATION_OFF, ...SUPPORTED_LANGUAGES] as [string, ...string[]]),
+  wakePhrase: z.string(),
+  captionFile: z.string(),
+  captionLines: z.string(),
+  audioRetention: z.string(),
+  transcriptRetention: z.string(),
 });
 
 type Schema = z.infer<typeof schema>;
 
+// One entry per line: "Hyprnote: hyper note, hyper notes".
+function formatDictionary(entries: DictionaryEntry[]) {
+  return entries
+    .map((entry) =>
+      entry.sounds_like?.length
+        ? `${entry.term}: ${entry.sounds_like.join(", ")}`
+        : entry.term
+    )
+    .join("\n");
+}
+
+function parseDictionary(text: string): DictionaryEntry[] {
+  return text
+    .split("\n")
+    .map((line) => {
+      const [term, soundsLike = ""] = line.split(":");
+      return {
+        term: term.trim(),
+        sounds_like: soundsLike.split(",").map((s) => s.trim()).filter(Boolean),
+      };
+    })
+    .filter((entry) => entry.term);
+}
+
 export default function General() {
   const { t } = useLingui();
   const queryClient = useQueryClient();
@@ -104,10 +158,18 @@ export default function General() {
       autostart: false,
       displayLanguage: "en",
       spokenLanguages: ["en"],
-      telemetryConsent: true,
       jargons: "",
+      dictionary: "",
+      fillerWords: "keep",
+      profanity: "keep",
       saveRecordings: true,
       summaryLanguage: "en",
+      translationLanguage: TRANSLATION_OFF,
+      wakePhrase: "",
+      captionFile: "",
+      captionLines: String(DEFAULT_CAPTION_LINES),
+      audioRetention: RETENTION_KEEP,
+      transcriptRetention: RETENTION_KEEP,
     },
   });
 
@@ -117,10 +179,18 @@ export default function General() {
         autostart: config.data.general.autostart ?? false,
         displayLanguage: config.data.general.display_language ?? "en",
         spokenLanguages: config.data.general.spoken_languages ?? ["en"],
-        telemetryConsent: config.data.general.telemetry_consent ?? true,
         jargons: (config.data.general.jargons ?? []).join(", "),
+        dictionary: formatDictionary(config.data.general.dictionary ?? []),
+        fillerWords: config.data.general.filler_words ?? "keep",
+        profanity: config.data.general.profanity ?? "keep",
         saveRecordings: config.data.general.save_recordings ?? true,
         summaryLanguage: config.data.general.summary_language ?? "en",
+        translationLanguage: config.data.general.translation_language ?? TRANSLATION_OFF,
+        wakePhrase: config.data.general.wake_phrase ?? "",
+        captionFile: config.data.general.caption_file ?? "",
+        captionLines: String(config.data.general.caption_lines ?? DEFAULT_CAPTION_LINES),
+        audioRetention: formatRetention(config.data.general.audio_retention_days),
+        transcriptRetention: formatRetention(config.data.general.transcript_retention_months),
       });
     }
   }, [config.data, form]);
@@ -136,27 +206,40 @@ export default function General() {
         autostart: v.autostart ?? false,
         display_language: v.displayLanguage,
         spoken_languages: v.spokenLanguages,
-        telemetry_consent: v.telemetryConsent ?? true,
         jargons: v.jargons.split(",").map((jargon) => jargon.trim()).filter(Boolean),
+        dictionary: parseDictionary(v.dictionary),
+        filler_words: v.fillerWords,
+        profanity: v.profanity,
         save_recordings: v.saveRecordings ?? true,
         selected_template_id: config.data.general.selected_template_id,
         summary_language: v.summaryLanguage,
+        translation_language: v.translationLanguage === TRANSLATION_OFF ? null : v.translationLanguage,
+        wake_phrase: v.wakePhrase.trim() || null,
+        audio_retention_days: parseRetention(v.audioRetention),
+        transcript_retention_months: parseRetention(v.transcriptRetention),
+        caption_file: v.captionFile.trim() || null,
+        caption_lines: Number(v.captionLines),
       };
 
       await dbCommands.setConfig({
         ...config.data,
         general: nextGeneral,
       });
+
+      if (nextGeneral.wake_phrase !== (config.data.general.wake_phrase ?? null)) {
+        await listenerCommands.refreshWakeListener();
+      }
     },
     onSuccess: () => {
       queryClient.invalidateQueries({ queryKey: ["config", "general"] });
+      queryClient.invalidateQueries({ queryKey: ["retention-preview"] });
     },
     onError: console.error,
   });
 
   useEffect(() => {
     const subscription = form.watch((value, { name }) => {
-      if (name === "jargons") {
+      if (name === "jargons" || name === "dictionary" || name === "wakePhrase" || name === "captionFile") {
         return;
       }
 
@@ -176,6 +259,7 @@ export default function General() {
 
       if (name === "displayLanguage" && value.displayLanguage) {
         showModelSelectToast(value.displayLanguage);
+        commands.setDisplayLanguage(value.displayLanguage).catch(console.error);
       }
     });
 
@@ -238,34 +322,76 @@ export default function General() {
 
           <FormField
             control={form.control}
-            name="telemetryConsent"
+            name="audioRetention"
             render={({ field }) => (
               <FormItem className="flex flex-row items-center justify-between">
-                <div>
+                <div className="space-y-0.5">
                   <FormLabel>
-                    <Trans>Share usage data</Trans>
+                    <Trans>Delete recordings after</Trans>
                   </FormLabel>
-                  <FormDescription className="flex flex-col">
-                    <span>
-                      Help us improve Hyprnote by sharing anonymous usage data.
-                    </span>
-                    <span>
-                      Restart Hyprnote for the change to take effect.
-                    </span>
+                  <FormDescription>
+                    <Trans>Transcripts and notes stay when the audio is deleted</Trans>
                   </FormDescription>
                 </div>
+                <FormControl>
+                  <Select value={field.value} onValueChange={field.onChange}>
+                    <SelectTrigger className="w-[200px]">
+                      <SelectValue />
+                    </SelectTrigger>
+                    <SelectContent>
+                      <SelectItem value={RETENTION_KEEP}>
+                        <Trans>Never</Trans>
+                      </SelectItem>
+                      {AUDIO_RETENTION_DAYS.map((days) => (
+                        <SelectItem key={days} value={String(days)}>
+                          <Trans>{days} days</Trans>
+                        </SelectItem>
+                      ))}
+                    </SelectContent>
+                  </Select>
+                </FormControl>
+              </FormItem>
+            )}
+          />
 
+          <FormField
+            control={form.control}
+            name="transcriptRetention"
+            render={({ field }) => (
+              <FormItem className="flex flex-row items-center justify-between">
+                <div className="space-y-0.5">
+                  <FormLabel>
+                    <Trans>Delete transcripts after</Trans>
+                  </FormLabel>
+                  <FormDescription>
+                    <Trans>Notes, including enhanced ones, are always kept</Trans>
+                  </FormDescription>
+                </div>
                 <FormControl>
-                  <Switch
-                    checked={field.value}
-                    onCheckedChange={field.onChange}
-                    color="gray"
-                  />
+                  <Select value={field.value} onValueChange={field.onChange}>
+                    <SelectTrigger className="w-[200px]">
+                      <SelectValue />
+                    </SelectTrigger>
+                    <SelectContent>
+                      <SelectItem value={RETENTION_KEEP}>
+                        <Trans>Never</Trans>
+                      </SelectItem>
+                      {TRANSCRIPT_RETENTION_MONTHS.map((months) => (
+                        <SelectItem key={months} value={String(months)}>
+                          <Trans>{months} months</Trans>
+                        </SelectItem>
+                      ))}
+                    </SelectContent>
+                  </Select>
                 </FormControl>
               </FormItem>
             )}
           />
 
+          <RetentionPreview />
+
+          <TelemetryConsent />
+
           <FormField
             control={form.control}
             name="summaryLanguage"
@@ -302,6 +428,47 @@ export default function General() {
             )}
           />
 
+          <FormField
+            control={form.control}
+            name="translationLanguage"
+            render={({ field }) => (
+              <FormItem className="flex flex-row items-center justify-between">
+                <div className="space-y-0.5">
+                  <FormLabel>
+                    <Trans>Live translation</Trans>
+                  </FormLabel>
+                  <FormDescription>
+                    <Trans>Show a translation next to the transcript while recording</Trans>
+                  </FormDescription>
+                </div>
+                <FormControl>
+                  <Select
+                    value={field.value}
+                    onValueChange={field.onChange}
+                  >
+                    <SelectTrigger className="w-[200px]">
+                      <SelectValue>
+                        {field.value === TRANSLATION_OFF
+                          ? t`Off`
+                          : LANGUAGES_ISO_639_1[field.value as ISO_639_1_CODE]?.name}
+                      </SelectValue>
+                    </SelectTrigger>
+                    <SelectContent className="max-h-[250px] overflow-auto">
+                      <SelectItem value={TRANSLATION_OFF}>
+                        <Trans>Off</Trans>
+                      </SelectItem>
+                      {SUPPORTED_LANGUAGES.map((lang) => (
+                        <SelectItem key={lang} value={lang}>
+                          {LANGUAGES_ISO_639_1[lang].name}
+                        </SelectItem>
+                      ))}
+                    </SelectContent>
+                  </Select>
+                </FormControl>
+              </FormItem>
+            )}
+          />
+
           {
             /*
           <FormField
@@ -452,8 +619,448 @@ export default function General() {
               </FormItem>
             )}
           />
+          <FormField
+            control={form.control}
+            name="dictionary"
+            render={({ field }) => (
+              <FormItem>
+                <div className="space-y-0.5">
+                  <FormLabel>
+                    <Trans>Spelling Dictionary</Trans>
+                  </FormLabel>
+                  <FormDescription>
+                    <Trans>
+                      One name or term per line, optionally followed by what gets transcribed instead
+                    </Trans>
+                  </FormDescription>
+                </div>
+                <FormControl>
+                  <Textarea
+                    {...field}
+                    rows={4}
+                    onBlur={() => mutation.mutate(form.getValues())}
+                    placeholder={"Hyprnote: hyper note, hyper notes"}
+                    className="focus-visible:ring-1 focus-visible:ring-ring resize-none"
+                  />
+                </FormControl>
+                <FormMessage />
+              </FormItem>
+            )}
+          />
+          <FormField
+            control={form.control}
+            name="wakePhrase"
+            render={({ field }) => (
+              <FormItem>
+                <div className="space-y-0.5">
+                  <FormLabel>
+                    <Trans>Wake phrase</Trans>
+                  </FormLabel>
+                  <FormDescription>
+                    <Trans>
+                      Say this while nothing is recording to start a new note. The microphone is
+                      listened to on this device while it is set. Needs a downloaded Whisper model.
+                    </Trans>
+                  </FormDescription>
+                </div>
+                <FormControl>
+                  <Input
+                    {...field}
+                    onBlur={() => mutation.mutate(form.getValues())}
+                    onKeyDown={(e) => {
+                      if (e.key === "Enter") {
+                        e.preventDefault();
+                        mutation.mutate(form.getValues());
+                        e.currentTarget.blur();
+                      }
+                    }}
+                    placeholder={t({ id: "Off (e.g., Start taking notes)" })}
+                    className="focus-visible:ring-1 focus-visible:ring-ring"
+                  />
+                </FormControl>
+                <FormMessage />
+              </FormItem>
+            )}
+          />
+          <FormField
+            control={form.control}
+            name="captionFile"
+            render={({ field }) => (
+              <FormItem>
+                <div className="space-y-0.5">
+                  <FormLabel>
+                    <Trans>Caption file</Trans>
+                  </FormLabel>
+                  <FormDescription>
+                    <Trans>
+                      The latest captions are written to this file while recording. Add it to OBS or
+                      other streaming tools as a text source.
+                    </Trans>
+                  </FormDescription>
+                </div>
+                <FormControl>
+                  <Input
+                    {...field}
+                    onBlur={() => mutation.mutate(form.getValues())}
+                    onKeyDown={(e) => {
+                      if (e.key === "Enter") {
+                        e.preventDefault();
+                        mutation.mutate(form.getValues());
+                        e.currentTarget.blur();
+                      }
+                    }}
+                    placeholder={t({ id: "Off (e.g., /Users/me/captions.txt)" })}
+                    className="focus-visible:ring-1 focus-visible:ring-ring"
+                  />
+                </FormControl>
+                <FormMessage />
+              </FormItem>
+            )}
+          />
+          <FormField
+            control={form.control}
+            name="captionLines"
+            render={({ field }) => (
+              <FormItem className="flex flex-row items-center justify-between">
+                <div className="space-y-0.5">
+                  <FormLabel>
+                    <Trans>Caption lines</Trans>
+                  </FormLabel>
+                  <FormDescription>
+                    <Trans>How many lines of captions streaming tools are given at a time</Trans>
+                  </FormDescription>
+                </div>
+                <FormControl>
+                  <Select value={field.value} onValueChange={field.onChange}>
+                    <SelectTrigger className="w-[200px]">
+                      <SelectValue />
+                    </SelectTrigger>
+                    <SelectContent>
+                      {CAPTION_LINES.map((lines) => (
+                        <SelectItem key={lines} value={String(lines)}>
+                          {lines}
+                        </SelectItem>
+                      ))}
+                    </SelectContent>
+                  </Select>
+                </FormControl>
+              </FormItem>
+            )}
+          />
+          <FormField
+            control={form.control}
+            name="fillerWords"
+            render={({ field }) => (
+              <FormItem className="flex flex-row items-center justify-between">
+                <div className="space-y-0.5">
+                  <FormLabel>
+                    <Trans>Filler words</Trans>
+                  </FormLabel>
+                  <FormDescription>
+                    <Trans>"Um", "uh" and similar hesitations in transcripts</Trans>
+                  </FormDescription>
+                </div>
+                <FormControl>
+                  <WordFilterSelect value={field.value} onChange={field.onChange} />
+                </FormControl>
+              </FormItem>
+            )}
+          />
+          <FormField
+            control={form.control}
+            name="profanity"
+            render={({ field }) => (
+              <FormItem className="flex flex-row items-center justify-between">
+                <div className="space-y-0.5">
+                  <FormLabel>
+                    <Trans>Profanity</Trans>
+                  </FormLabel>
+                  <FormDescription>
+                    <Trans>Swear words in transcripts</Trans>
+                  </FormDescription>
+                </div>
+                <FormControl>
+                  <WordFilterSelect value={field.value} onChange={field.onChange} />
+                </FormControl>
+              </FormItem>
+            )}
+          />
         </form>
       </Form>
+
+      <div className="mt-8">
+        <BetaUpdates />
+      </div>
+
+      <div className="mt-8">
+        <Profiles />
+      </div>
+    </div>
+  );
+}
+
+function RetentionPreview() {
+  const queryClient = useQueryClient();
+
+  const preview = useQuery({
+    queryKey: ["retention-preview"],
+    queryFn: () => dbCommands.previewRetention(),
+  });
+
+  const apply = useMutation({
+    mutationFn: () => dbCommands.applyRetention(),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ["retention-preview"] });
+    },
+    onError: console.error,
+  });
+
+  const recordings = preview.data?.recordings.length ?? 0;
+  const transcripts = preview.data?.transcripts.length ?? 0;
+  if (!recordings && !transcripts) {
+    return null;
+  }
+
+  const megabytes = Math.round((preview.data?.recording_bytes ?? 0) / (1024 * 1024));
+
+  return (
+    <div className="flex flex-row items-center justify-between">
+      <p className="text-sm text-muted-foreground">
+        <Trans>
+          {recordings} recordings ({megabytes} MB) and {transcripts} transcripts are past their retention and will be
+          deleted soon.
+        </Trans>
+      </p>
+      <Button variant="outline" size="sm" disabled={apply.isPending} onClick={() => apply.mutate()}>
+        <Trans>Delete now</Trans>
+      </Button>
+    </div>
+  );
+}
+
+function WordFilterSelect({ value, onChange }: { value: WordFilter; onChange: (value: WordFilter) => void }) {
+  return (
+    <Select value={value} onValueChange={(v) => onChange(v as WordFilter)}>
+      <SelectTrigger className="w-[200px]">
+        <SelectValue />
+      </SelectTrigger>
+      <SelectContent>
+        <SelectItem value="keep">
+          <Trans>Keep</Trans>
+        </SelectItem>
+        <SelectItem value="remove">
+          <Trans>Remove</Trans>
+        </SelectItem>
+        <SelectItem value="mask">
+          <Trans>Mask</Trans>
+        </SelectItem>
+      </SelectContent>
+    </Select>
+  );
+}
+
+const TELEMETRY_CATEGORIES: { key: keyof Consent; label: string; description: string }[] = [
+  {
+    key: "crash_reports",
+    label: "Crash reports",
+    description: "Send error details when something breaks, so we can fix it.",
+  },
+  {
+    key: "usage_analytics",
+    label: "Usage analytics",
+    description: "Share anonymous data about which features are used.",
+  },
+  {
+    key: "model_metrics",
+    label: "Model performance",
+    description: "Share how long AI models take to respond. Never includes your notes.",
+  },
+];
+
+// Lives in the analytics plugin rather than the DB config, so it applies before the DB is ready.
+function TelemetryConsent() {
+  const queryClient = useQueryClient();
+
+  const consent = useQuery({
+    queryKey: ["telemetry-consent"],
+    queryFn: () => analyticsCommands.getConsent(),
+  });
+
+  const mutation = useMutation({
+    mutationFn: (next: Consent) => analyticsCommands.setConsent(next),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ["telemetry-consent"] });
+    },
+    onError: console.error,
+  });
+
+  return (
+    <div className="space-y-4">
+      {TELEMETRY_CATEGORIES.map(({ key, label, description }) => (
+        <div key={key} className="flex flex-row items-center justify-between">
+          <div className="space-y-2">
+            <Label>{label}</Label>
+            <p className="text-sm text-muted-foreground">{description}</p>
+          </div>
+          <Switch
+            checked={consent.data?.[key] ?? false}
+            onCheckedChange={(checked) => consent.data && mutation.mutate({ ...consent.data, [key]: checked })}
+            disabled={!consent.data}
+            color="gray"
+          />
+        </div>
+      ))}
+    </div>
+  );
+}
+
+// Stored by the desktop app rather than in the DB config, since the updater reads it natively.
+function BetaUpdates() {
+  const queryClient = useQueryClient();
+
+  const channel = useQuery({
+    queryKey: ["update-channel"],
+    queryFn: () => commands.getUpdateChannel(),
+  });
+
+  const mutation = useMutation({
+    mutationFn: (beta: boolean) => commands.setUpdateChannel(beta ? "beta" : "stable"),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ["update-channel"] });
+      queryClient.invalidateQueries({ queryKey: ["check-for-update"] });
+    },
+    onError: console.error,
+  });
+
+  return (
+    <div className="flex flex-row items-center justify-between">
+      <div className="space-y-2">
+        <Label>
+          <Trans>Beta updates</Trans>
+        </Label>
+        <p className="text-sm text-muted-foreground">
+          <Trans>Get new versions early. Updates are never installed while recording.</Trans>
+        </p>
+      </div>
+      <Switch
+        checked={channel.data === "beta"}
+        onCheckedChange={(checked) => mutation.mutate(checked)}
+        disabled={!channel.data}
+        color="gray"
+      />
+    </div>
+  );
+}
+
+// Each profile has its own notes, settings and calendar connections. Switching restarts the app.
+function Profiles() {
+  const { t } = useLingui();
+  const queryClient = useQueryClient();
+  const [name, setName] = useState("");
+
+  const profiles = useQuery({
+    queryKey: ["profiles"],
+    queryFn: () => commands.listProfiles(),
+  });
+
+  const create = useMutation({
+    mutationFn: (name: string) => commands.createProfile(name),
+    onSuccess: () => {
+      setName("");
+      queryClient.invalidateQueries({ queryKey: ["profiles"] });
+    },
+    onError: console.error,
+  });
+
+  const remove = useMutation({
+    mutationFn: (id: string) => commands.deleteProfile(id),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ["profiles"] });
+    },
+    onError: console.error,
+  });
+
+  const switchTo = useMutation({
+    mutationFn: (id: string) => commands.switchProfile(id),
+    onError: console.error,
+  });
+
+  const handleDelete = async (profile: ProfileInfo) => {
+    const yes = await confirm(
+      t`Delete the profile "${profile.name}"? Its notes, recordings and settings are removed and can't be recovered.`,
+    );
+    if (yes) {
+      remove.mutate(profile.id);
+    }
+  };
+
+  return (
+    <div className="space-y-4">
+      <div className="space-y-2">
+        <Label>
+          <Trans>Profiles</Trans>
+        </Label>
+        <p className="text-sm text-muted-foreground">
+          <Trans>
+            Keep separate notes, settings and calendars, for example for work and personal use. Downloaded models
+            are shared. Switching profiles restarts Hyprnote.
+          </Trans>
+        </p>
+      </div>
+
+      <div className="space-y-2">
+        {profiles.data?.map((profile) => (
+          <div key={profile.id} className="flex flex-row items-center justify-between">
+            <span className="text-sm">{profile.name}</span>
+            {profile.active
+              ? (
+                <Badge variant="secondary">
+                  <Trans>Current</Trans>
+                </Badge>
+              )
+              : (
+                <div className="flex flex-row items-center gap-2">
+                  <Button
+                    variant="outline"
+                    size="sm"
+                    onClick={() => switchTo.mutate(profile.id)}
+                    disabled={switchTo.isPending}
+                  >
+                    <Trans>Switch</Trans>
+                  </Button>
+                  {profile.id !== "default" && (
+                    <Button variant="ghost" size="icon" onClick={() => handleDelete(profile)}>
+                      <X className="h-4 w-4" />
+                    </Button>
+                  )}
+                </div>
+              )}
+          </div>
+        ))}
+      </div>
+
+      <div className="flex flex-row items-center gap-2">
+        <Input
+          value={name}
+          onChange={(e) => setName(e.target.value)}
+          onKeyDown={(e) => {
+            if (e.key === "Enter" && name.trim()) {
+              e.preventDefault();
+              create.mutate(name);
+            }
+          }}
+          placeholder={t({ id: "New profile name" })}
+          className="focus-visible:ring-1 focus-visible:ring-ring"
+        />
+        <Button
+          variant="outline"
+          size="icon"
+          onClick={() => create.mutate(name)}
+          disabled={!name.trim() || create.isPending}
+        >
+          <Plus className="h-4 w-4" />
+        </Button>
+      </div>
     </div>
   );
 }
@@ -1,12 +1,19 @@
-import { Trans } from "@lingui/react/macro";
+import { Trans, useLingui } from "@lingui/react/macro";
+import { useMutation, useQuery } from "@tanstack/react-query";
+import { save } from "@tauri-apps/plugin-dialog";
 import { openPath, openUrl } from "@tauri-apps/plugin-opener";
-import { Book, Bug, ExternalLinkIcon, MessageSquare } from "lucide-react";
+import { Book, Bug, ExternalLinkIcon, FileArchive, MessageSquare, Trash2 } from "lucide-react";
+import { useEffect, useState } from "react";
 
 import { commands as tracingCommands } from "@hypr/plugin-tracing";
+import { Button } from "@hypr/ui/components/ui/button";
+import { Input } from "@hypr/ui/components/ui/input";
+import { Switch } from "@hypr/ui/components/ui/switch";
 import { useHypr } from "../../../contexts/hypr";
 
 export default function HelpSupport() {
   const { userId } = useHypr();
+  const { t } = useLingui();
 
   const handleOpenFeedback = () => {
     openUrl("https://hyprnote.canny.io/feature-requests");
@@ -26,6 +33,23 @@ export default function HelpSupport() {
     });
   };
 
+  const exportLogs = useMutation({
+    mutationFn: async () => {
+      const dest = await save({
+        defaultPath: `hyprnote-logs-${new Date().toISOString().slice(0, 10)}.zip`,
+        filters: [{ name: t`Zip archive`, extensions: ["zip"] }],
+      });
+      if (!dest) {
+        return null;
+      }
+      return tracingCommands.exportLogs(dest);
+    },
+  });
+
+  const purgeLogs = useMutation({
+    mutationFn: () => tracingCommands.purgeLogs(),
+  });
+
   return (
     <div className="space-y-6">
       <div>
@@ -109,6 +133,48 @@ export default function HelpSupport() {
             </div>
             <ExternalLinkIcon className="h-4 w-4 text-gray-400" />
           </button>
+          {/* Log export */}
+          <button
+            onClick={() => exportLogs.mutate()}
+            disabled={exportLogs.isPending}
+            className="w-full flex items-center justify-between p-4 bg-white rounded-lg border hover:bg-gray-50 transition-colors"
+          >
+            <div className="flex items-center gap-3">
+              <FileArchive className="h-5 w-5 text-gray-600" />
+              <div className="text-left">
+                <div className="font-medium">
+                  <Trans>Export logs</Trans>
+                </div>
+                <div className="text-sm text-gray-500">
+                  {exportLogs.isSuccess && exportLogs.data
+                    ? <Trans>Saved to {exportLogs.data}</Trans>
+                    : <Trans>Bundle recent logs and system info into a zip to attach to a bug report</Trans>}
+                </div>
+              </div>
+            </div>
+          </button>
+
+          <button
+            onClick={() => purgeLogs.mutate()}
+            disabled={purgeLogs.isPending}
+            className="w-full flex items-center justify-between p-4 bg-white rounded-lg border hover:bg-gray-50 transition-colors"
+          >
+            <div className="flex items-center gap-3">
+              <Trash2 className="h-5 w-5 text-gray-600" />
+              <div className="text-left">
+                <div className="font-medium">
+                  <Trans>Clear old logs</Trans>
+                </div>
+                <div className="text-sm text-gray-500">
+                  {purgeLogs.isSuccess
+                    ? <Trans>Freed {(purgeLogs.data / (1024 * 1024)).toFixed(1)} MB</Trans>
+                    : <Trans>Logs older than two weeks or beyond 200 MB are removed automatically</Trans>}
+                </div>
+              </div>
+            </div>
+          </button>
+
+          <LogFilter />
           <br />
           {/* User ID */}
           <div className="text-sm text-gray-500">
@@ -119,3 +185,82 @@ export default function HelpSupport() {
     </div>
   );
 }
+
+function LogFilter() {
+  const filter = useQuery({
+    queryKey: ["tracing", "logFilter"],
+    queryFn: () => tracingCommands.getLogFilter(),
+  });
+
+  const [directives, setDirectives] = useState("");
+  useEffect(() => {
+    if (filter.data !== undefined) {
+      setDirectives(filter.data);
+    }
+  }, [filter.data]);
+
+  const apply = useMutation({
+    mutationFn: (value: string | null) => tracingCommands.setLogFilter(value),
+    onSuccess: () => filter.refetch(),
+  });
+
+  return (
+    <div className="p-4 bg-white rounded-lg border space-y-2">
+      <div className="font-medium">
+        <Trans>Log level</Trans>
+      </div>
+      <div className="text-sm text-gray-500">
+        <Trans>Filter directives such as "info,tauri_plugin_listener=debug". Applies until restart.</Trans>
+      </div>
+      <div className="flex gap-2">
+        <Input
+          className="font-mono"
+          value={directives}
+          onChange={(e) => setDirectives(e.target.value)}
+        />
+        <Button
+          variant="outline"
+          disabled={apply.isPending || !directives.trim()}
+          onClick={() => apply.mutate(directives.trim())}
+        >
+          <Trans>Apply</Trans>
+        </Button>
+        <Button variant="ghost" disabled={apply.isPending} onClick={() => apply.mutate(null)}>
+          <Trans>Reset</Trans>
+        </Button>
+      </div>
+      {apply.isError && <div className="text-sm text-red-600">{String(apply.error)}</div>}
+      <JsonLogsToggle />
+    </div>
+  );
+}
+
+function JsonLogsToggle() {
+  const jsonLogs = useQuery({
+    queryKey: ["tracing", "jsonLogs"],
+    queryFn: () => tracingCommands.getJsonLogs(),
+  });
+
+  const setJsonLogs = useMutation({
+    mutationFn: (enabled: boolean) => tracingCommands.setJsonLogs(enabled),
+    onSuccess: () => jsonLogs.refetch(),
+  });
+
+  return (
+    <div className="flex items-center justify-between pt-2">
+      <div>
+        <div className="text-sm font-medium">
+          <Trans>JSON log files</Trans>
+        </div>
+        <div className="text-sm text-gray-500">
+          <Trans>Write one JSON object per line, including span fields, for log tooling</Trans>
+        </div>
+      </div>
+      <Switch
+        checked={jsonLogs.data ?? false}
+        disabled={setJsonLogs.isPending}
+        onCheckedChange={(checked) => setJsonLogs.mutate(checked)}
+      />
+    </div>
+  );
+}
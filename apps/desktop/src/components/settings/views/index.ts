@@ -12,4 +12,5 @@ export { default as Profile } from "./profile";
 export { default as Sound } from "./sound";
 export { default as Team } from "./team";
 export { default as TemplateEditor } from "./template";
+export { default as Tasks } from "./tasks";
 export { default as TemplatesView } from "./templates";
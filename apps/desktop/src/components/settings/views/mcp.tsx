@@ -1,19 +1,30 @@
 import { useHypr } from "@/contexts";
 import { commands as analyticsCommands } from "@hypr/plugin-analytics";
-import { commands, type McpServer } from "@hypr/plugin-mcp";
+import {
+  commands,
+  type ConsentPolicy,
+  type LocalServerConfig,
+  type McpOAuthConfig,
+  type McpServer,
+} from "@hypr/plugin-mcp";
 import { Button } from "@hypr/ui/components/ui/button";
 import { Input } from "@hypr/ui/components/ui/input";
 import { Label } from "@hypr/ui/components/ui/label";
 import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@hypr/ui/components/ui/select";
 import { Switch } from "@hypr/ui/components/ui/switch";
-import { useMutation } from "@tanstack/react-query";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
 import { PlusIcon, Trash2Icon } from "lucide-react";
 import { useEffect, useState } from "react";
 
+const DEFAULT_LOCAL_SERVER_PORT = 23542;
+
 export default function MCP() {
   const { userId } = useHypr();
   const [servers, setServers] = useState<McpServer[]>([]);
+  const [newType, setNewType] = useState<"sse" | "stdio">("sse");
   const [newUrl, setNewUrl] = useState("");
+  const [newCommand, setNewCommand] = useState("");
+  const [newArgs, setNewArgs] = useState("");
   const [newHeaderKey, setNewHeaderKey] = useState("");
   const [newHeaderValue, setNewHeaderValue] = useState("");
   const [loading, setLoading] = useState(true);
@@ -41,7 +52,8 @@ export default function MCP() {
   const saveServersMutation = useMutation({
     mutationFn: async (updatedServers: McpServer[]) => {
       await commands.setServers(updatedServers);
-      return updatedServers;
+      // Re-read to pick up the ids assigned to new servers.
+      return commands.getServers();
     },
     onSuccess: (updatedServers) => {
       setServers(updatedServers);
@@ -51,18 +63,34 @@ export default function MCP() {
     },
   });
 
+  const canAddServer = !isAtMaxLimit && (newType === "stdio" ? !!newCommand.trim() : !!newUrl.trim());
+
   const handleAddServer = async () => {
-    if (!newUrl.trim() || isAtMaxLimit) {
+    if (!canAddServer) {
       return;
     }
 
-    const newServer: McpServer = {
-      url: newUrl,
-      type: "sse",
-      enabled: true,
-      headerKey: newHeaderKey.trim() || null,
-      headerValue: newHeaderValue.trim() || null,
-    };
+    const newServer: McpServer = newType === "stdio"
+      ? {
+        url: "",
+        type: "stdio",
+        enabled: true,
+        headerKey: null,
+        headerValue: null,
+        command: newCommand.trim(),
+        args: splitArgs(newArgs),
+        env: {},
+      }
+      : {
+        url: newUrl,
+        type: "sse",
+        enabled: true,
+        headerKey: newHeaderKey.trim() || null,
+        headerValue: newHeaderValue.trim() || null,
+        command: null,
+        args: [],
+        env: {},
+      };
 
     const updatedServers = [...servers, newServer];
 
@@ -72,9 +100,12 @@ export default function MCP() {
       analyticsCommands.event({
         event: "mcp_server_added",
         distinct_id: userId,
+        type: newType,
       });
 
       setNewUrl("");
+      setNewCommand("");
+      setNewArgs("");
       setNewHeaderKey("");
       setNewHeaderValue("");
     } catch (error) {
@@ -109,6 +140,34 @@ export default function MCP() {
     saveServersMutation.mutate(updatedServers);
   };
 
+  const handleUpdateServerCommand = (index: number, command: string, args: string) => {
+    const updatedServers = servers.map((server, i) =>
+      i === index
+        ? { ...server, command, args: splitArgs(args) }
+        : server
+    );
+    saveServersMutation.mutate(updatedServers);
+  };
+
+  const handleUpdateServerOAuth = (index: number, oauth: McpOAuthConfig | null) => {
+    const updatedServers = servers.map((server, i) => i === index ? { ...server, oauth } : server);
+    saveServersMutation.mutate(updatedServers);
+  };
+
+  const handleUpdateServerConsent = async (index: number, consent: ConsentPolicy) => {
+    const id = servers[index].id;
+    if (!id) {
+      return;
+    }
+
+    try {
+      await commands.setConsentPolicy(id, consent);
+      setServers(servers.map((server, i) => i === index ? { ...server, consent } : server));
+    } catch (error) {
+      console.error("Failed to update consent policy:", error);
+    }
+  };
+
   const handleUpdateServerUrl = (index: number, newUrl: string) => {
     const updatedServers = servers.map((server, i) =>
       i === index
@@ -154,51 +213,104 @@ export default function MCP() {
 
           <div className="space-y-3">
             <div>
-              <Label htmlFor="url" className="text-xs text-neutral-600">Server URL</Label>
-              <Input
-                id="url"
-                placeholder={isAtMaxLimit ? `Maximum ${MAX_SERVERS} servers allowed` : "Enter MCP server URL"}
-                value={newUrl}
-                onChange={(e) => setNewUrl(e.target.value)}
-                onKeyDown={(e) => {
-                  if (e.key === "Enter" && !isAtMaxLimit) {
-                    handleAddServer();
-                  }
-                }}
+              <Label htmlFor="type" className="text-xs text-neutral-600">Transport</Label>
+              <Select
+                value={newType}
+                onValueChange={(value) => setNewType(value as "sse" | "stdio")}
                 disabled={isAtMaxLimit}
-                className="mt-1"
-              />
+              >
+                <SelectTrigger id="type" className="mt-1">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  <SelectItem value="sse">SSE (remote server)</SelectItem>
+                  <SelectItem value="stdio">stdio (local command)</SelectItem>
+                </SelectContent>
+              </Select>
             </div>
 
-            <div className="grid grid-cols-2 gap-3">
-              <div>
-                <Label htmlFor="headerKey" className="text-xs text-neutral-600">Header Key (Optional)</Label>
-                <Input
-                  id="headerKey"
-                  placeholder="e.g., Authorization"
-                  value={newHeaderKey}
-                  onChange={(e) => setNewHeaderKey(e.target.value)}
-                  disabled={isAtMaxLimit}
-                  className="mt-1"
-                />
-              </div>
-              <div>
-                <Label htmlFor="headerValue" className="text-xs text-neutral-600">Header Value (Optional)</Label>
-                <Input
-                  id="headerValue"
-                  placeholder="e.g., Bearer token123"
-                  value={newHeaderValue}
-                  onChange={(e) => setNewHeaderValue(e.target.value)}
-                  disabled={isAtMaxLimit}
-                  className="mt-1"
-                />
+            {newType === "stdio"
+              ? (
+                <div className="grid grid-cols-2 gap-3">
+                  <div>
+                    <Label htmlFor="command" className="text-xs text-neutral-600">Command</Label>
+                    <Input
+                      id="command"
+                      placeholder={isAtMaxLimit ? `Maximum ${MAX_SERVERS} servers allowed` : "e.g., npx"}
+                      value={newCommand}
+                      onChange={(e) => setNewCommand(e.target.value)}
+                      disabled={isAtMaxLimit}
+                      className="mt-1"
+                    />
+                  </div>
+                  <div>
+                    <Label htmlFor="args" className="text-xs text-neutral-600">Arguments (Optional)</Label>
+                    <Input
+                      id="args"
+                      placeholder="e.g., -y @modelcontextprotocol/server-memory"
+                      value={newArgs}
+                      onChange={(e) => setNewArgs(e.target.value)}
+                      onKeyDown={(e) => {
+                        if (e.key === "Enter") {
+                          handleAddServer();
+                        }
+                      }}
+                      disabled={isAtMaxLimit}
+                      className="mt-1"
+                    />
+                  </div>
+                </div>
+              )
+              : (
+                <div>
+                  <Label htmlFor="url" className="text-xs text-neutral-600">Server URL</Label>
+                  <Input
+                    id="url"
+                    placeholder={isAtMaxLimit ? `Maximum ${MAX_SERVERS} servers allowed` : "Enter MCP server URL"}
+                    value={newUrl}
+                    onChange={(e) => setNewUrl(e.target.value)}
+                    onKeyDown={(e) => {
+                      if (e.key === "Enter" && !isAtMaxLimit) {
+                        handleAddServer();
+                      }
+                    }}
+                    disabled={isAtMaxLimit}
+                    className="mt-1"
+                  />
+                </div>
+              )}
+
+            {newType === "sse" && (
+              <div className="grid grid-cols-2 gap-3">
+                <div>
+                  <Label htmlFor="headerKey" className="text-xs text-neutral-600">Header Key (Optional)</Label>
+                  <Input
+                    id="headerKey"
+                    placeholder="e.g., Authorization"
+                    value={newHeaderKey}
+                    onChange={(e) => setNewHeaderKey(e.target.value)}
+                    disabled={isAtMaxLimit}
+                    className="mt-1"
+                  />
+                </div>
+                <div>
+                  <Label htmlFor="headerValue" className="text-xs text-neutral-600">Header Value (Optional)</Label>
+                  <Input
+                    id="headerValue"
+                    placeholder="e.g., Bearer token123"
+                    value={newHeaderValue}
+                    onChange={(e) => setNewHeaderValue(e.target.value)}
+                    disabled={isAtMaxLimit}
+                    className="mt-1"
+                  />
+                </div>
               </div>
-            </div>
+            )}
           </div>
 
           <Button
             onClick={handleAddServer}
-            disabled={!newUrl.trim() || isAtMaxLimit}
+            disabled={!canAddServer}
             variant="outline"
             size="sm"
             className="w-full"
@@ -219,7 +331,7 @@ export default function MCP() {
           ? (
             <div className="text-center py-8 text-neutral-500 border rounded-lg">
               <p className="text-sm">No MCP servers configured</p>
-              <p className="text-xs mt-1">Add a server URL above to get started</p>
+              <p className="text-xs mt-1">Add a server URL or command above to get started</p>
             </div>
           )
           : (
@@ -232,17 +344,29 @@ export default function MCP() {
                   <div className="flex items-center gap-3">
                     <div className="flex-1 space-y-2">
                       <div className="flex items-center gap-2">
-                        <Input
-                          value={server.url}
-                          onChange={(e) => handleUpdateServerUrl(index, e.target.value)}
-                          className="flex-1"
-                        />
+                        {server.type === "stdio"
+                          ? (
+                            <Input
+                              value={server.command || ""}
+                              onChange={(e) =>
+                                handleUpdateServerCommand(index, e.target.value, (server.args ?? []).join(" "))}
+                              className="flex-1"
+                            />
+                          )
+                          : (
+                            <Input
+                              value={server.url}
+                              onChange={(e) => handleUpdateServerUrl(index, e.target.value)}
+                              className="flex-1"
+                            />
+                          )}
                         <Select value={server.type} disabled>
                           <SelectTrigger className="w-24">
                             <SelectValue />
                           </SelectTrigger>
                           <SelectContent>
                             <SelectItem value="sse">SSE</SelectItem>
+                            <SelectItem value="stdio">stdio</SelectItem>
                           </SelectContent>
                         </Select>
                       </div>
@@ -268,32 +392,406 @@ export default function MCP() {
                     </div>
                   </div>
 
-                  {/* Header configuration for existing servers */}
-                  <div className="grid grid-cols-2 gap-3 pt-2 border-t border-neutral-100">
-                    <div>
-                      <Label className="text-xs text-neutral-600">Header Key</Label>
+                  {server.id && <ServerProbe serverId={server.id} />}
+
+                  <div className="flex items-center justify-between gap-3">
+                    <Label className="text-xs text-neutral-600">Before running a tool</Label>
+                    <Select
+                      value={server.consent ?? "always_ask"}
+                      onValueChange={(value) => handleUpdateServerConsent(index, value as ConsentPolicy)}
+                    >
+                      <SelectTrigger className="w-56">
+                        <SelectValue />
+                      </SelectTrigger>
+                      <SelectContent>
+                        <SelectItem value="always_ask">Always ask</SelectItem>
+                        <SelectItem value="allow_read_only">Ask unless read-only</SelectItem>
+                        <SelectItem value="allow_all">Never ask</SelectItem>
+                      </SelectContent>
+                    </Select>
+                  </div>
+
+                  {server.type === "stdio" && (
+                    <div className="pt-2 border-t border-neutral-100">
+                      <Label className="text-xs text-neutral-600">Arguments</Label>
                       <Input
-                        placeholder="e.g., Authorization"
-                        value={server.headerKey || ""}
-                        onChange={(e) => handleUpdateServerHeader(index, e.target.value, server.headerValue || "")}
+                        placeholder="e.g., -y @modelcontextprotocol/server-memory"
+                        defaultValue={(server.args ?? []).join(" ")}
+                        onBlur={(e) => handleUpdateServerCommand(index, server.command || "", e.target.value)}
                         className="mt-1"
                       />
                     </div>
-                    <div>
-                      <Label className="text-xs text-neutral-600">Header Value</Label>
-                      <Input
-                        placeholder="e.g., Bearer token123"
-                        value={server.headerValue || ""}
-                        onChange={(e) => handleUpdateServerHeader(index, server.headerKey || "", e.target.value)}
-                        className="mt-1"
-                      />
+                  )}
+
+                  {/* Header configuration for existing servers */}
+                  {server.type !== "stdio" && (
+                    <div className="grid grid-cols-2 gap-3 pt-2 border-t border-neutral-100">
+                      <div>
+                        <Label className="text-xs text-neutral-600">Header Key</Label>
+                        <Input
+                          placeholder="e.g., Authorization"
+                          value={server.headerKey || ""}
+                          onChange={(e) => handleUpdateServerHeader(index, e.target.value, server.headerValue || "")}
+                          className="mt-1"
+                        />
+                      </div>
+                      <div>
+                        <Label className="text-xs text-neutral-600">Header Value</Label>
+                        <Input
+                          placeholder="e.g., Bearer token123"
+                          value={server.headerValue || ""}
+                          onChange={(e) => handleUpdateServerHeader(index, server.headerKey || "", e.target.value)}
+                          className="mt-1"
+                        />
+                      </div>
                     </div>
-                  </div>
+                  )}
+
+                  {server.type !== "stdio" && server.id && (
+                    <OAuthSettings
+                      serverId={server.id}
+                      oauth={server.oauth ?? null}
+                      onChange={(oauth) => handleUpdateServerOAuth(index, oauth)}
+                    />
+                  )}
                 </div>
               ))}
             </div>
           )}
       </div>
+
+      {servers.length > 0 && <ToolCallLog servers={servers} />}
+
+      <LocalServerSection />
+    </div>
+  );
+}
+
+function OAuthSettings({
+  serverId,
+  oauth,
+  onChange,
+}: {
+  serverId: string;
+  oauth: McpOAuthConfig | null;
+  onChange: (oauth: McpOAuthConfig | null) => void;
+}) {
+  const [secret, setSecret] = useState("");
+
+  const saveCredentials = useMutation({
+    mutationFn: () =>
+      oauth?.grant === "refresh_token"
+        ? commands.setServerCredentials(serverId, null, secret.trim())
+        : commands.setServerCredentials(serverId, secret.trim(), null),
+    onSuccess: () => setSecret(""),
+  });
+
+  if (!oauth) {
+    return (
+      <Button
+        variant="ghost"
+        size="sm"
+        className="text-xs text-neutral-600"
+        onClick={() => onChange({ grant: "client_credentials", token_url: "", client_id: "", scope: null })}
+      >
+        Use OAuth instead of a static header
+      </Button>
+    );
+  }
+
+  return (
+    <div className="space-y-3 pt-2 border-t border-neutral-100">
+      <div className="flex items-center justify-between">
+        <Label className="text-xs font-medium">OAuth</Label>
+        <Button variant="ghost" size="sm" className="text-xs text-neutral-500" onClick={() => onChange(null)}>
+          Remove
+        </Button>
+      </div>
+
+      <div className="grid grid-cols-2 gap-3">
+        <div>
+          <Label className="text-xs text-neutral-600">Grant</Label>
+          <Select
+            value={oauth.grant}
+            onValueChange={(grant) => onChange({ ...oauth, grant: grant as McpOAuthConfig["grant"] })}
+          >
+            <SelectTrigger className="mt-1">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              <SelectItem value="client_credentials">Client credentials</SelectItem>
+              <SelectItem value="refresh_token">Refresh token</SelectItem>
+            </SelectContent>
+          </Select>
+        </div>
+        <div>
+          <Label className="text-xs text-neutral-600">Client ID</Label>
+          <Input
+            defaultValue={oauth.client_id}
+            onBlur={(e) => onChange({ ...oauth, client_id: e.target.value.trim() })}
+            className="mt-1"
+          />
+        </div>
+        <div>
+          <Label className="text-xs text-neutral-600">Token URL</Label>
+          <Input
+            placeholder="https://auth.example.com/oauth/token"
+            defaultValue={oauth.token_url}
+            onBlur={(e) => onChange({ ...oauth, token_url: e.target.value.trim() })}
+            className="mt-1"
+          />
+        </div>
+        <div>
+          <Label className="text-xs text-neutral-600">Scope (Optional)</Label>
+          <Input
+            defaultValue={oauth.scope ?? ""}
+            onBlur={(e) => onChange({ ...oauth, scope: e.target.value.trim() || null })}
+            className="mt-1"
+          />
+        </div>
+      </div>
+
+      <div>
+        <Label className="text-xs text-neutral-600">
+          {oauth.grant === "refresh_token" ? "Refresh token" : "Client secret"}
+        </Label>
+        <div className="flex items-center gap-2 mt-1">
+          <Input
+            type="password"
+            placeholder="Stored in your system keychain"
+            value={secret}
+            onChange={(e) => setSecret(e.target.value)}
+          />
+          <Button
+            variant="outline"
+            size="sm"
+            disabled={!secret.trim() || saveCredentials.isPending}
+            onClick={() => saveCredentials.mutate()}
+          >
+            Save
+          </Button>
+        </div>
+        {saveCredentials.isSuccess && <p className="text-xs text-green-700 mt-1">Saved</p>}
+        {saveCredentials.error && <p className="text-xs text-red-600 mt-1">{String(saveCredentials.error)}</p>}
+      </div>
+    </div>
+  );
+}
+
+function ServerProbe({ serverId }: { serverId: string }) {
+  const probe = useMutation({
+    mutationFn: () => commands.probeServer(serverId),
+  });
+
+  const result = probe.data;
+
+  return (
+    <div className="space-y-2">
+      <Button onClick={() => probe.mutate()} disabled={probe.isPending} variant="outline" size="sm">
+        {probe.isPending ? "Testing..." : "Test connection"}
+      </Button>
+
+      {probe.error && (
+        <div className="text-xs text-red-600 bg-red-50 border border-red-100 rounded-lg p-3">
+          {String(probe.error)}
+        </div>
+      )}
+
+      {result && !result.ok && (
+        <div className="text-xs text-red-600 bg-red-50 border border-red-100 rounded-lg p-3">
+          {result.auth === "unauthorized" ? "Authentication failed. Check the header key and value." : result.error}
+        </div>
+      )}
+
+      {result?.ok && (
+        <div className="text-xs text-neutral-600 bg-neutral-50 border border-neutral-200 rounded-lg p-3 space-y-1">
+          <p>
+            Connected to <span className="font-medium">{result.server_name ?? "server"}</span>
+            {result.server_version && ` ${result.server_version}`} in {result.latency_ms}ms
+          </p>
+          <p>
+            {result.tools.length} tools, {result.resources.length} resources, {result.prompts.length} prompts
+          </p>
+          {result.tools.length > 0 && (
+            <p className="text-neutral-500">{result.tools.map((tool) => tool.name).join(", ")}</p>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
+
+function ToolCallLog({ servers }: { servers: McpServer[] }) {
+  const queryClient = useQueryClient();
+
+  const calls = useQuery({
+    queryKey: ["mcp-tool-calls"],
+    queryFn: () => commands.listToolCalls(null),
+  });
+
+  const clear = useMutation({
+    mutationFn: () => commands.clearToolCalls(),
+    onSettled: () => queryClient.invalidateQueries({ queryKey: ["mcp-tool-calls"] }),
+  });
+
+  const serverLabel = (id: string) => {
+    const server = servers.find((server) => server.id === id);
+    if (!server) {
+      return "Removed server";
+    }
+    return server.type === "stdio" ? server.command : server.url;
+  };
+
+  const recent = (calls.data ?? []).slice(0, 20);
+
+  return (
+    <div className="space-y-3 p-4 border rounded-lg">
+      <div className="flex items-center justify-between">
+        <div>
+          <h3 className="text-sm font-medium">Tool calls</h3>
+          <p className="text-xs text-neutral-600">
+            Every MCP tool the assistant runs is logged here. Arguments are stored as a hash only.
+          </p>
+        </div>
+        <Button
+          variant="ghost"
+          size="sm"
+          disabled={recent.length === 0 || clear.isPending}
+          onClick={() => clear.mutate()}
+        >
+          Clear
+        </Button>
+      </div>
+
+      {recent.length === 0
+        ? <p className="text-xs text-neutral-500">No tool calls yet.</p>
+        : (
+          <div className="divide-y divide-neutral-100 text-xs">
+            {recent.map((call) => (
+              <div key={call.id} className="flex items-center justify-between py-2 gap-3">
+                <div className="min-w-0">
+                  <p className="font-medium truncate">{call.tool}</p>
+                  <p className="text-neutral-500 truncate">{serverLabel(call.server_id)}</p>
+                </div>
+                <div className="text-right shrink-0">
+                  <p className={call.error ? "text-red-600" : "text-neutral-600"}>
+                    {call.error ?? `${call.duration_ms}ms, ${call.result_size} bytes`}
+                  </p>
+                  <p className="text-neutral-400">{new Date(call.called_at).toLocaleString()}</p>
+                </div>
+              </div>
+            ))}
+          </div>
+        )}
     </div>
   );
 }
+
+function LocalServerSection() {
+  const queryClient = useQueryClient();
+
+  const config = useQuery({
+    queryKey: ["mcp-local-server-config"],
+    queryFn: () => commands.getLocalServerConfig(),
+  });
+
+  const running = useQuery({
+    queryKey: ["mcp-local-server-running"],
+    queryFn: () => commands.isLocalServerRunning(),
+  });
+
+  const setConfig = useMutation({
+    mutationFn: (updated: LocalServerConfig) => commands.setLocalServerConfig(updated),
+    onSettled: () => {
+      queryClient.invalidateQueries({ queryKey: ["mcp-local-server-config"] });
+      queryClient.invalidateQueries({ queryKey: ["mcp-local-server-running"] });
+    },
+    onError: (error) => {
+      console.error("Failed to update local MCP server:", error);
+    },
+  });
+
+  if (!config.data) {
+    return null;
+  }
+
+  const apiKey = config.data.api_key ?? "";
+  const stdioConfig = JSON.stringify(
+    {
+      mcpServers: {
+        hyprnote: {
+          command: "<path to Hyprnote executable>",
+          args: config.data.port === DEFAULT_LOCAL_SERVER_PORT
+            ? ["--mcp-stdio"]
+            : ["--mcp-stdio", "--mcp-port", String(config.data.port)],
+          env: { HYPRNOTE_MCP_API_KEY: apiKey },
+        },
+      },
+    },
+    null,
+    2,
+  );
+
+  return (
+    <div className="space-y-3 p-4 border rounded-lg">
+      <div className="flex items-center justify-between">
+        <div>
+          <h3 className="text-sm font-medium">Use Hyprnote from other apps</h3>
+          <p className="text-xs text-neutral-600 mt-1">
+            Let MCP clients like Claude Desktop search your notes, read transcripts, create notes and see upcoming
+            events. Only apps on this computer that have the API key can connect.
+          </p>
+        </div>
+        <Switch
+          checked={config.data.enabled}
+          onCheckedChange={(enabled) => setConfig.mutate({ ...config.data!, enabled })}
+        />
+      </div>
+
+      {config.data.enabled && (
+        <div className="space-y-3 pt-2 border-t border-neutral-100">
+          {setConfig.error && (
+            <p className="text-xs text-red-600">{String(setConfig.error)}</p>
+          )}
+          {!setConfig.error && running.data === false && (
+            <p className="text-xs text-red-600">The server is not running. Try another port.</p>
+          )}
+
+          <div>
+            <Label className="text-xs text-neutral-600">Port</Label>
+            <Input
+              type="number"
+              defaultValue={config.data.port}
+              onBlur={(e) => {
+                const port = Number(e.target.value);
+                if (port > 0 && port < 65536 && port !== config.data!.port) {
+                  setConfig.mutate({ ...config.data!, port });
+                }
+              }}
+              className="mt-1 w-32"
+            />
+          </div>
+
+          <div>
+            <Label className="text-xs text-neutral-600">SSE URL</Label>
+            <Input readOnly value={`http://127.0.0.1:${config.data.port}/sse`} className="mt-1 font-mono text-xs" />
+          </div>
+
+          <div>
+            <Label className="text-xs text-neutral-600">API key (send as the X-API-Key header)</Label>
+            <Input readOnly value={apiKey} className="mt-1 font-mono text-xs" />
+          </div>
+
+          <div>
+            <Label className="text-xs text-neutral-600">stdio (claude_desktop_config.json)</Label>
+            <pre className="mt-1 p-3 text-xs bg-neutral-50 border rounded-lg overflow-x-auto">{stdioConfig}</pre>
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
+
+function splitArgs(args: string) {
+  return args.split(/\s+/).filter(Boolean);
+}
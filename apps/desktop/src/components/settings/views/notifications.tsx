@@ -8,12 +8,21 @@ import { z } from "zod";
 
 import { useHypr } from "@/contexts";
 import { commands as analyticsCommands } from "@hypr/plugin-analytics";
-import { commands as notificationCommands } from "@hypr/plugin-notification";
+import { commands as dbCommands, type ConfigNotification } from "@hypr/plugin-db";
+import {
+  commands as notificationCommands,
+  type EventReminders,
+  type QuietHours,
+  type QuietHoursRule,
+  type Weekday,
+} from "@hypr/plugin-notification";
 import { Badge } from "@hypr/ui/components/ui/badge";
 import { Button } from "@hypr/ui/components/ui/button";
 import { Command, CommandEmpty, CommandGroup, CommandInput, CommandItem } from "@hypr/ui/components/ui/command";
 import { Form, FormControl, FormDescription, FormField, FormItem, FormLabel } from "@hypr/ui/components/ui/form";
+import { Input } from "@hypr/ui/components/ui/input";
 import { Popover, PopoverContent, PopoverTrigger } from "@hypr/ui/components/ui/popover";
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@hypr/ui/components/ui/select";
 import { Switch } from "@hypr/ui/components/ui/switch";
 
 const schema = z.object({
@@ -238,6 +247,8 @@ export default function NotificationsComponent() {
                     />
                   </FormControl>
                 </div>
+
+                {field.value && <EventReminderSettings />}
               </FormItem>
             )}
           />
@@ -408,10 +419,329 @@ export default function NotificationsComponent() {
                   </FormItem>
                 )}
               />
+              <QuietHoursSettings />
             </>
           )}
+          <RecordingLengthSettings />
         </form>
       </Form>
     </div>
   );
 }
+
+const ALERT_OFF = "off";
+const ALERT_MINUTE_OPTIONS = [30, 45, 60, 90, 120];
+
+function RecordingLengthSettings() {
+  const config = useQuery({
+    queryKey: ["config", "notification"],
+    queryFn: () => dbCommands.getConfig(),
+  });
+
+  const configMutation = useMutation({
+    mutationFn: async (patch: Partial<ConfigNotification>) => {
+      if (!config.data) {
+        return;
+      }
+      await dbCommands.setConfig({
+        ...config.data,
+        notification: { ...config.data.notification, ...patch },
+      });
+    },
+    onSuccess: () => {
+      config.refetch();
+    },
+  });
+
+  if (!config.data) {
+    return null;
+  }
+
+  const notification = config.data.notification;
+  const minutes = notification.recording_length_alert_minutes ?? null;
+
+  return (
+    <div className="space-y-4">
+      <div className="flex flex-row items-center justify-between">
+        <div>
+          <FormLabel>
+            <Trans>Long recording alert</Trans>
+          </FormLabel>
+          <FormDescription>
+            <Trans>Get notified once a recording has run this long.</Trans>
+          </FormDescription>
+        </div>
+        <Select
+          value={minutes === null ? ALERT_OFF : String(minutes)}
+          onValueChange={(value) =>
+            configMutation.mutate({
+              recording_length_alert_minutes: value === ALERT_OFF ? null : Number(value),
+            })}
+        >
+          <SelectTrigger className="w-40">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value={ALERT_OFF}>
+              <Trans>Off</Trans>
+            </SelectItem>
+            {ALERT_MINUTE_OPTIONS.map((option) => (
+              <SelectItem key={option} value={String(option)}>
+                <Trans>After {option} min</Trans>
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+      </div>
+
+      <div className="flex flex-row items-center justify-between">
+        <div>
+          <FormLabel>
+            <Trans>Meeting overrun alert</Trans>
+          </FormLabel>
+          <FormDescription>
+            <Trans>Get notified when a recording runs past its calendar event's scheduled end.</Trans>
+          </FormDescription>
+        </div>
+        <Switch
+          checked={notification.recording_overrun_alert ?? false}
+          onCheckedChange={(recording_overrun_alert) => configMutation.mutate({ recording_overrun_alert })}
+        />
+      </div>
+    </div>
+  );
+}
+
+const LEAD_MINUTE_OPTIONS = [1, 3, 5, 10, 15, 30];
+
+function EventReminderSettings() {
+  const { userId } = useHypr();
+
+  const reminders = useQuery({
+    queryKey: ["notification", "eventReminders"],
+    queryFn: () => notificationCommands.getEventReminders(),
+  });
+
+  const calendars = useQuery({
+    queryKey: ["notification", "calendars", userId],
+    queryFn: async () => {
+      const calendars = await dbCommands.listCalendars(userId);
+      return calendars.filter((calendar) => calendar.selected);
+    },
+  });
+
+  const remindersMutation = useMutation({
+    mutationFn: (v: EventReminders) => notificationCommands.setEventReminders(v),
+    onSuccess: () => {
+      reminders.refetch();
+    },
+  });
+
+  const preMeetingBrief = useQuery({
+    queryKey: ["notification", "preMeetingBrief"],
+    queryFn: () => notificationCommands.getPreMeetingBrief(),
+  });
+
+  const preMeetingBriefMutation = useMutation({
+    mutationFn: (enabled: boolean) => notificationCommands.setPreMeetingBrief(enabled),
+    onSuccess: () => {
+      preMeetingBrief.refetch();
+    },
+  });
+
+  if (!reminders.data) {
+    return null;
+  }
+
+  const config = reminders.data;
+  const save = (patch: Partial<EventReminders>) => remindersMutation.mutate({ ...config, ...patch });
+
+  const toggleCalendar = (id: string, notify: boolean) => {
+    const excluded = config.excluded_calendars.filter((c) => c !== id);
+    save({ excluded_calendars: notify ? excluded : [...excluded, id] });
+  };
+
+  return (
+    <div className="ml-6 border-l-2 border-muted pl-6 space-y-3">
+      <div className="flex items-center justify-between text-sm">
+        <span>
+          <Trans>Remind me</Trans>
+        </span>
+        <Select
+          value={String(config.lead_minutes)}
+          onValueChange={(value) => save({ lead_minutes: Number(value) })}
+        >
+          <SelectTrigger className="w-40">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            {LEAD_MINUTE_OPTIONS.map((minutes) => (
+              <SelectItem key={minutes} value={String(minutes)}>
+                {minutes} min before
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+      </div>
+
+      <div className="flex items-center justify-between text-sm">
+        <span>
+          <Trans>Remind me again when the meeting starts</Trans>
+        </span>
+        <Switch checked={config.starting_now} onCheckedChange={(starting_now) => save({ starting_now })} />
+      </div>
+
+      <div className="flex items-center justify-between text-sm">
+        <div>
+          <span>
+            <Trans>Brief me before meetings</Trans>
+          </span>
+          <p className="text-xs text-muted-foreground">
+            <Trans>Recaps past meetings with the same people, using the local AI model</Trans>
+          </p>
+        </div>
+        <Switch
+          checked={preMeetingBrief.data ?? false}
+          onCheckedChange={(enabled) => preMeetingBriefMutation.mutate(enabled)}
+        />
+      </div>
+
+      {(calendars.data ?? []).length > 1 && (
+        <div className="space-y-2">
+          <span className="text-sm">
+            <Trans>Calendars</Trans>
+          </span>
+          {(calendars.data ?? []).map((calendar) => (
+            <div key={calendar.id} className="flex items-center justify-between text-sm text-muted-foreground">
+              <span className="truncate">{calendar.name}</span>
+              <Switch
+                checked={!config.excluded_calendars.includes(calendar.id)}
+                onCheckedChange={(notify) => toggleCalendar(calendar.id, notify)}
+              />
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+}
+
+const WEEKDAYS: { value: Weekday; label: string }[] = [
+  { value: "mon", label: "Monday" },
+  { value: "tue", label: "Tuesday" },
+  { value: "wed", label: "Wednesday" },
+  { value: "thu", label: "Thursday" },
+  { value: "fri", label: "Friday" },
+  { value: "sat", label: "Saturday" },
+  { value: "sun", label: "Sunday" },
+];
+
+const DEFAULT_RULE = { start_minute: 22 * 60, end_minute: 7 * 60 };
+
+function toTime(minute: number) {
+  const h = Math.floor(minute / 60).toString().padStart(2, "0");
+  const m = (minute % 60).toString().padStart(2, "0");
+  return `${h}:${m}`;
+}
+
+function fromTime(time: string) {
+  const [h, m] = time.split(":").map(Number);
+  return (h || 0) * 60 + (m || 0);
+}
+
+function QuietHoursSettings() {
+  const quietHours = useQuery({
+    queryKey: ["notification", "quietHours"],
+    queryFn: () => notificationCommands.getQuietHours(),
+  });
+
+  const quietHoursMutation = useMutation({
+    mutationFn: (v: QuietHours) => notificationCommands.setQuietHours(v),
+    onSuccess: () => {
+      quietHours.refetch();
+    },
+  });
+
+  if (!quietHours.data) {
+    return null;
+  }
+
+  const config = quietHours.data;
+  const save = (patch: Partial<QuietHours>) => quietHoursMutation.mutate({ ...config, ...patch });
+
+  const ruleFor = (weekday: Weekday) => config.rules.find((rule) => rule.weekday === weekday);
+
+  const setRule = (weekday: Weekday, rule: Omit<QuietHoursRule, "weekday"> | null) => {
+    const rules = config.rules.filter((r) => r.weekday !== weekday);
+    save({ rules: rule ? [...rules, { weekday, ...rule }] : rules });
+  };
+
+  return (
+    <div className="space-y-4">
+      <div className="flex flex-row items-center justify-between">
+        <div>
+          <FormLabel>
+            <Trans>Quiet hours</Trans>
+          </FormLabel>
+          <FormDescription>
+            <Trans>Hold back meeting notifications during these hours.</Trans>
+          </FormDescription>
+        </div>
+        <Switch checked={config.enabled} onCheckedChange={(enabled) => save({ enabled })} />
+      </div>
+
+      {config.enabled && (
+        <div className="ml-6 border-l-2 border-muted pl-6 space-y-3">
+          <div className="flex items-center justify-between">
+            <span className="text-sm">
+              <Trans>During quiet hours</Trans>
+            </span>
+            <Select
+              value={config.mode}
+              onValueChange={(mode) => save({ mode: mode as QuietHours["mode"] })}
+            >
+              <SelectTrigger className="w-56">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value="suppress">Discard notifications</SelectItem>
+                <SelectItem value="queue">Keep them in history</SelectItem>
+              </SelectContent>
+            </Select>
+          </div>
+
+          {WEEKDAYS.map(({ value, label }) => {
+            const rule = ruleFor(value);
+
+            return (
+              <div key={value} className="flex items-center gap-3 text-sm">
+                <Switch
+                  checked={!!rule}
+                  onCheckedChange={(checked) => setRule(value, checked ? DEFAULT_RULE : null)}
+                />
+                <span className="w-24">{label}</span>
+                {rule && (
+                  <>
+                    <Input
+                      type="time"
+                      className="w-28"
+                      defaultValue={toTime(rule.start_minute)}
+                      onBlur={(e) => setRule(value, { ...rule, start_minute: fromTime(e.target.value) })}
+                    />
+                    <span className="text-muted-foreground">–</span>
+                    <Input
+                      type="time"
+                      className="w-28"
+                      defaultValue={toTime(rule.end_minute)}
+                      onBlur={(e) => setRule(value, { ...rule, end_minute: fromTime(e.target.value) })}
+                    />
+                  </>
+                )}
+              </div>
+            );
+          })}
+        </div>
+      )}
+    </div>
+  );
+}
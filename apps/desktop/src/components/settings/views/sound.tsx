@@ -1,13 +1,15 @@
 import { Trans, useLingui } from "@lingui/react/macro";
-import { useMutation, useQuery } from "@tanstack/react-query";
-import { MicIcon, Volume2Icon } from "lucide-react";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { MicIcon, PlayIcon, Volume2Icon } from "lucide-react";
 import { useState } from "react";
 
 import { commands as listenerCommands } from "@hypr/plugin-listener";
+import { type AppSounds, commands as sfxCommands, type SoundPack } from "@hypr/plugin-sfx";
 import { Button } from "@hypr/ui/components/ui/button";
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@hypr/ui/components/ui/select";
 import { Spinner } from "@hypr/ui/components/ui/spinner";
 import { cn } from "@hypr/ui/lib/utils";
-import { message } from "@tauri-apps/plugin-dialog";
+import { message, open } from "@tauri-apps/plugin-dialog";
 import { relaunch } from "@tauri-apps/plugin-process";
 
 interface PermissionItemProps {
@@ -142,6 +144,134 @@ export default function Sound() {
           buttonText="Enable"
         />
       </div>
+
+      <SoundEffects />
+    </div>
+  );
+}
+
+const CUSTOMIZABLE_SOUNDS: AppSounds[] = ["StartRecording", "StopRecording", "Notification"];
+
+function SoundEffects() {
+  const { t } = useLingui();
+  const queryClient = useQueryClient();
+
+  const soundPack = useQuery({
+    queryKey: ["sfx", "soundPack"],
+    queryFn: () => sfxCommands.getSoundPack(),
+  });
+
+  const customSounds = useQuery({
+    queryKey: ["sfx", "customSounds"],
+    queryFn: () => sfxCommands.getCustomSounds(),
+  });
+
+  const setSoundPack = useMutation({
+    mutationFn: (pack: SoundPack) => sfxCommands.setSoundPack(pack),
+    onSuccess: () => queryClient.invalidateQueries({ queryKey: ["sfx"] }),
+  });
+
+  const setCustomSound = useMutation({
+    mutationFn: async (sfx: AppSounds) => {
+      const path = await open({
+        multiple: false,
+        directory: false,
+        filters: [{ name: "Audio", extensions: ["ogg", "mp3", "wav", "flac"] }],
+      });
+      if (typeof path === "string") {
+        await sfxCommands.setCustomSound(sfx, path);
+      }
+    },
+    onSuccess: () => queryClient.invalidateQueries({ queryKey: ["sfx"] }),
+    onError: (error) => {
+      message(String(error), { kind: "error", title: t`Could not use this sound` });
+    },
+  });
+
+  const clearCustomSound = useMutation({
+    mutationFn: (sfx: AppSounds) => sfxCommands.clearCustomSound(sfx),
+    onSuccess: () => queryClient.invalidateQueries({ queryKey: ["sfx"] }),
+  });
+
+  const labels: Record<AppSounds, string> = {
+    StartRecording: t`Start recording`,
+    StopRecording: t`Stop recording`,
+    Notification: t`Notification`,
+    BGM: t`Background music`,
+  };
+
+  return (
+    <div className="mt-6 space-y-4">
+      <div className="flex items-center justify-between">
+        <div>
+          <div className="text-sm font-medium">
+            <Trans>Sound effects</Trans>
+          </div>
+          <div className="text-xs text-muted-foreground">
+            <Trans>Choose the sounds played when recording starts, stops, or a notification arrives</Trans>
+          </div>
+        </div>
+        <Select
+          value={soundPack.data ?? "Default"}
+          onValueChange={(value) => setSoundPack.mutate(value as SoundPack)}
+        >
+          <SelectTrigger className="w-[140px]">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value="Default">
+              <Trans>Default</Trans>
+            </SelectItem>
+            <SelectItem value="Silent">
+              <Trans>Silent</Trans>
+            </SelectItem>
+            <SelectItem value="Custom">
+              <Trans>Custom</Trans>
+            </SelectItem>
+          </SelectContent>
+        </Select>
+      </div>
+
+      {soundPack.data === "Custom" && (
+        <div className="space-y-2">
+          {CUSTOMIZABLE_SOUNDS.map((sfx) => {
+            const path = customSounds.data?.[sfx];
+
+            return (
+              <div key={sfx} className="flex items-center justify-between rounded-lg border p-3">
+                <div className="min-w-0">
+                  <div className="text-sm">{labels[sfx]}</div>
+                  <div className="truncate text-xs text-muted-foreground">
+                    {path
+                      ? path.split(/[\\/]/).pop()
+                      : sfx === "Notification"
+                      ? <Trans>No sound</Trans>
+                      : <Trans>Bundled sound</Trans>}
+                  </div>
+                </div>
+                <div className="flex items-center gap-2">
+                  <Button variant="ghost" size="icon" onClick={() => sfxCommands.play(sfx)}>
+                    <PlayIcon className="h-4 w-4" />
+                  </Button>
+                  {path && (
+                    <Button variant="ghost" size="sm" onClick={() => clearCustomSound.mutate(sfx)}>
+                      <Trans>Reset</Trans>
+                    </Button>
+                  )}
+                  <Button
+                    variant="outline"
+                    size="sm"
+                    disabled={setCustomSound.isPending}
+                    onClick={() => setCustomSound.mutate(sfx)}
+                  >
+                    <Trans>Choose file</Trans>
+                  </Button>
+                </div>
+              </div>
+            );
+          })}
+        </div>
+      )}
     </div>
   );
 }
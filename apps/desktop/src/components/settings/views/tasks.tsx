@@ -0,0 +1,149 @@
+import { Trans, useLingui } from "@lingui/react/macro";
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { PauseIcon, PlayIcon, RotateCcwIcon, XIcon } from "lucide-react";
+
+import {
+  commands as taskCommands,
+  type QueuedTask,
+  type QueuedTaskKind,
+  type QueuedTaskStatus,
+} from "@hypr/plugin-task";
+import { Badge } from "@hypr/ui/components/ui/badge";
+import { Button } from "@hypr/ui/components/ui/button";
+
+const QUERY_KEY = ["queued-tasks"];
+
+export default function Tasks() {
+  const queryClient = useQueryClient();
+
+  const tasks = useQuery({
+    queryKey: QUERY_KEY,
+    queryFn: () => taskCommands.listQueuedTasks(null),
+    refetchInterval: 5000,
+  });
+
+  const action = useMutation({
+    mutationFn: ({ task, kind }: { task: QueuedTask; kind: "cancel" | "retry" | "pause" | "resume" }) => {
+      switch (kind) {
+        case "cancel":
+          return taskCommands.cancelQueuedTask(task.id);
+        case "retry":
+          return taskCommands.retryTask(task.id);
+        case "pause":
+          return taskCommands.pauseQueuedTask(task.id);
+        case "resume":
+          return taskCommands.resumeQueuedTask(task.id);
+      }
+    },
+    onSettled: () => queryClient.invalidateQueries({ queryKey: QUERY_KEY }),
+  });
+
+  if (!tasks.data) {
+    return null;
+  }
+
+  const sorted = [...tasks.data].sort((a, b) => b.created_at.localeCompare(a.created_at));
+
+  return (
+    <div className="space-y-4">
+      <p className="text-sm text-muted-foreground">
+        <Trans>
+          Work that runs in the background, like enhancing notes and re-transcribing recordings. Failed tasks are
+          retried a few times before they give up.
+        </Trans>
+      </p>
+
+      {sorted.length === 0
+        ? (
+          <p className="text-sm text-neutral-500">
+            <Trans>No background tasks yet.</Trans>
+          </p>
+        )
+        : (
+          <div className="divide-y rounded-lg border">
+            {sorted.map((task) => (
+              <TaskRow
+                key={task.id}
+                task={task}
+                disabled={action.isPending}
+                onAction={(kind) => action.mutate({ task, kind })}
+              />
+            ))}
+          </div>
+        )}
+    </div>
+  );
+}
+
+function TaskRow({
+  task,
+  disabled,
+  onAction,
+}: {
+  task: QueuedTask;
+  disabled: boolean;
+  onAction: (kind: "cancel" | "retry" | "pause" | "resume") => void;
+}) {
+  const { t } = useLingui();
+
+  const kinds: Record<QueuedTaskKind, string> = {
+    enhance: t`Enhance note`,
+    title: t`Generate title`,
+    export: t`Export`,
+    retranscribe: t`Re-transcribe recording`,
+    pre_meeting_brief: t`Pre-meeting brief`,
+    pipeline: t`After-recording steps`,
+  };
+
+  const statuses: Record<QueuedTaskStatus, string> = {
+    queued: t`Queued`,
+    running: t`Running`,
+    paused: t`Paused`,
+    completed: t`Completed`,
+    dead: t`Failed`,
+    cancelled: t`Cancelled`,
+  };
+
+  const active = task.status === "queued" || task.status === "running";
+
+  return (
+    <div className="flex items-center justify-between gap-3 p-3">
+      <div className="min-w-0 space-y-1">
+        <div className="flex items-center gap-2">
+          <span className="text-sm font-medium">{kinds[task.kind]}</span>
+          <Badge variant={task.status === "dead" ? "destructive" : "secondary"}>{statuses[task.status]}</Badge>
+        </div>
+        <p className="text-xs text-neutral-500">
+          {new Date(task.created_at).toLocaleString()}
+          {task.attempts > 0 && ` · ${t`Attempt ${task.attempts} of ${task.max_attempts}`}`}
+        </p>
+        {task.last_error && task.status !== "completed" && (
+          <p className="truncate text-xs text-red-600">{task.last_error}</p>
+        )}
+      </div>
+
+      <div className="flex shrink-0 items-center gap-1">
+        {active && (
+          <Button variant="ghost" size="icon" disabled={disabled} onClick={() => onAction("pause")} title={t`Pause`}>
+            <PauseIcon className="h-4 w-4" />
+          </Button>
+        )}
+        {task.status === "paused" && (
+          <Button variant="ghost" size="icon" disabled={disabled} onClick={() => onAction("resume")} title={t`Resume`}>
+            <PlayIcon className="h-4 w-4" />
+          </Button>
+        )}
+        {(task.status === "dead" || task.status === "cancelled") && (
+          <Button variant="ghost" size="icon" disabled={disabled} onClick={() => onAction("retry")} title={t`Retry`}>
+            <RotateCcwIcon className="h-4 w-4" />
+          </Button>
+        )}
+        {(active || task.status === "paused") && (
+          <Button variant="ghost" size="icon" disabled={disabled} onClick={() => onAction("cancel")} title={t`Cancel`}>
+            <XIcon className="h-4 w-4" />
+          </Button>
+        )}
+      </div>
+    </div>
+  );
+}
@@ -11,6 +11,7 @@ import { Trans, useLingui } from "@lingui/react/macro";
 import { useQuery } from "@tanstack/react-query";
 import { Plus, X } from "lucide-react";
 import { useCallback, useEffect, useState } from "react";
+import { PipelineStages } from "../components/template-pipeline";
 import { SectionsList } from "../components/template-sections";
 
 interface TemplateEditorProps {
@@ -421,6 +422,13 @@ export default function TemplateEditor({
           onChange={handleChangeSections}
         />
       </div>
+
+      <div className="flex flex-col gap-1">
+        <h2 className="text-sm font-medium">
+          <Trans>After Recording</Trans>
+        </h2>
+        <PipelineStages templateId={template.id} disabled={disabled} />
+      </div>
     </div>
   );
 }
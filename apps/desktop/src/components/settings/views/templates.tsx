@@ -14,6 +14,7 @@ import { commands as dbCommands } from "@hypr/plugin-db";
 import { Button } from "@hypr/ui/components/ui/button";
 import { Tooltip, TooltipContent, TooltipTrigger } from "@hypr/ui/components/ui/tooltip";
 import { cn } from "@hypr/ui/lib/utils";
+import { PipelineStages } from "../components/template-pipeline";
 import TemplateEditor from "./template";
 
 type ViewState = "list" | "editor" | "new";
@@ -370,6 +371,14 @@ export default function TemplatesView() {
             </div>
           </div>
         )}
+
+        {/* Pipeline for notes enhanced without a template */}
+        <div>
+          <div className="text-sm font-medium mb-2">
+            <Trans>After Recording Without a Template</Trans>
+          </div>
+          <PipelineStages templateId={null} disabled={false} />
+        </div>
       </div>
     </div>
   );
@@ -1,6 +1,7 @@
 import ChangelogNotification from "./changelog";
 import ModelDownloadNotification from "./model-download";
 import OtaNotification from "./ota";
+import RecoveryNotification from "./recovery";
 
 export default function Notifications() {
   return (
@@ -8,6 +9,7 @@ export default function Notifications() {
       <OtaNotification />
       <ModelDownloadNotification />
       <ChangelogNotification />
+      <RecoveryNotification />
     </>
   );
 }
@@ -6,15 +6,15 @@ import { join } from "@tauri-apps/api/path";
 import { message } from "@tauri-apps/plugin-dialog";
 import { exists } from "@tauri-apps/plugin-fs";
 import { relaunch } from "@tauri-apps/plugin-process";
-import { check } from "@tauri-apps/plugin-updater";
 import { useEffect, useRef } from "react";
 
+import { commands, type UpdateInfo } from "@/types";
 import { sonnerToast, toast } from "@hypr/ui/components/ui/toast";
 import { useOngoingSession } from "@hypr/utils/contexts";
 import { DownloadProgress } from "./shared";
 
 // exported for manual update checks
-export async function createUpdateToast(update: any, toastId: string = "ota-notification") {
+export async function createUpdateToast(update: UpdateInfo, toastId: string = "ota-notification") {
   const appName = await getName();
   const appPath = await join("/Applications", `${appName}.app`);
   const appInApplicationsFolder = await exists(appPath);
@@ -34,12 +34,10 @@ export async function createUpdateToast(update: any, toastId: string = "ota-noti
   };
 }
 
-export async function handleUpdateInstall(update: any, toastId: string, appInApplicationsFolder: boolean) {
+export async function handleUpdateInstall(update: UpdateInfo, toastId: string, appInApplicationsFolder: boolean) {
   sonnerToast.dismiss(toastId);
 
   const updateChannel = new Channel<number>();
-  let totalDownloaded = 0;
-  let contentLength: number | undefined;
 
   toast({
     id: `${toastId}-download`,
@@ -53,19 +51,8 @@ export async function handleUpdateInstall(update: any, toastId: string, appInApp
     dismissible: false,
   });
 
-  update.downloadAndInstall((progressEvent: any) => {
-    if (progressEvent.event === "Started") {
-      totalDownloaded = 0;
-      contentLength = progressEvent.data.contentLength;
-    } else if (progressEvent.event === "Progress") {
-      totalDownloaded += progressEvent.data.chunkLength;
-      const totalSize = contentLength || (50 * 1024 * 1024);
-      const progressPercentage = Math.min(Math.round((totalDownloaded / totalSize) * 100), 99);
-      updateChannel.onmessage(progressPercentage);
-    } else if (progressEvent.event === "Finished") {
-      updateChannel.onmessage(100);
-    }
-  }).then(() => {
+  // Progress is reported in percent by the backend, which also refuses to install mid-recording.
+  commands.installUpdate(updateChannel).then(() => {
     message("The app will now restart", { kind: "info", title: "Update Installed" });
     setTimeout(relaunch, 2000);
   }).catch((err: any) => {
@@ -105,7 +92,7 @@ export default function OtaNotification() {
     queryKey: ["check-for-update"],
     queryFn: async () => {
       if (process.env.NODE_ENV === "production") {
-        return check();
+        return commands.checkForUpdate();
       }
 
       return null;
@@ -141,52 +128,7 @@ export default function OtaNotification() {
       buttons: [
         {
           label: "Update Now",
-          onClick: async () => {
-            sonnerToast.dismiss("ota-notification");
-
-            const updateChannel = new Channel<number>();
-            let totalDownloaded = 0;
-            let contentLength: number | undefined;
-
-            toast({
-              id: "update-download",
-              title: `Downloading Update ${update.version}`,
-              content: (
-                <div className="space-y-1">
-                  <div>This may take a while...</div>
-                  <DownloadProgress channel={updateChannel} />
-                </div>
-              ),
-              dismissible: false,
-            });
-
-            update.downloadAndInstall((progressEvent) => {
-              if (progressEvent.event === "Started") {
-                totalDownloaded = 0;
-                contentLength = progressEvent.data.contentLength;
-              } else if (progressEvent.event === "Progress") {
-                totalDownloaded += progressEvent.data.chunkLength;
-                const totalSize = contentLength || (50 * 1024 * 1024);
-                const progressPercentage = Math.min(Math.round((totalDownloaded / totalSize) * 100), 99);
-                updateChannel.onmessage(progressPercentage);
-              } else if (progressEvent.event === "Finished") {
-                updateChannel.onmessage(100);
-              }
-            }).then(() => {
-              message("The app will now restart", { kind: "info", title: "Update Installed" });
-              setTimeout(relaunch, 2000);
-            }).catch((err: any) => {
-              Sentry.captureException(err);
-              if (!appInApplicationsFolder.data) {
-                message("Please move the app to the Applications folder and try again", {
-                  kind: "error",
-                  title: "Update Installation Failed",
-                });
-              } else {
-                message(err, { kind: "error", title: "Update Installation Failed" });
-              }
-            });
-          },
+          onClick: () => handleUpdateInstall(update, "ota-notification", appInApplicationsFolder.data ?? false),
           primary: true,
         },
       ],
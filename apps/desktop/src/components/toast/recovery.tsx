@@ -0,0 +1,96 @@
+import { useNavigate } from "@tanstack/react-router";
+import { useEffect } from "react";
+
+import { commands as dbCommands } from "@hypr/plugin-db";
+import { commands as listenerCommands, type InterruptedSession } from "@hypr/plugin-listener";
+import { sonnerToast, toast } from "@hypr/ui/components/ui/toast";
+import { useOngoingSession } from "@hypr/utils/contexts";
+
+const TOAST_ID = "recovery-notification";
+
+export default function RecoveryNotification() {
+  const navigate = useNavigate();
+  const startOngoingSession = useOngoingSession((s) => s.start);
+
+  useEffect(() => {
+    listenerCommands.getInterruptedSession().then(async (interrupted) => {
+      if (!interrupted) {
+        return;
+      }
+
+      const session = await dbCommands.getSession({ id: interrupted.session_id });
+      if (!session) {
+        await listenerCommands.resolveInterruptedSession(false);
+        return;
+      }
+
+      const openNote = () => navigate({ to: "/app/note/$id", params: { id: interrupted.session_id } });
+
+      showRecoveryToast(interrupted, session.title, {
+        onRestore: async () => {
+          await listenerCommands.resolveInterruptedSession(false);
+          await openNote();
+        },
+        onResume: async () => {
+          await listenerCommands.resolveInterruptedSession(true);
+          await openNote();
+          startOngoingSession(interrupted.session_id);
+        },
+        onDismiss: () => listenerCommands.resolveInterruptedSession(false),
+      });
+    }).catch((error) => {
+      console.error("Failed to check for an interrupted recording:", error);
+    });
+  }, []);
+
+  return null;
+}
+
+function showRecoveryToast(
+  interrupted: InterruptedSession,
+  title: string,
+  actions: { onRestore: () => Promise<unknown>; onResume: () => Promise<unknown>; onDismiss: () => Promise<unknown> },
+) {
+  const handle = (action: () => Promise<unknown>) => () => {
+    sonnerToast.dismiss(TOAST_ID);
+    action().catch((error) => {
+      console.error("Failed to recover the interrupted recording:", error);
+    });
+  };
+
+  const startedAt = new Date(interrupted.started_at_ms).toLocaleTimeString([], {
+    hour: "numeric",
+    minute: "2-digit",
+  });
+
+  toast({
+    id: TOAST_ID,
+    title: "Recording was interrupted",
+    content: (
+      <div className="space-y-1">
+        <p className="text-sm">
+          Hyprnote closed while recording <strong>{title.trim() || "Untitled"}</strong> (started at {startedAt}).
+        </p>
+        <p className="text-sm text-neutral-600">
+          The transcript so far has been kept.
+        </p>
+      </div>
+    ),
+    buttons: [
+      {
+        label: "Restore note",
+        onClick: handle(actions.onRestore),
+        primary: true,
+      },
+      {
+        label: "Resume recording",
+        onClick: handle(actions.onResume),
+      },
+      {
+        label: "Dismiss",
+        onClick: handle(actions.onDismiss),
+      },
+    ],
+    dismissible: false,
+  });
+}
@@ -2,6 +2,7 @@ import { Trans, useLingui } from "@lingui/react/macro";
 import { useMutation, useQuery } from "@tanstack/react-query";
 import { CheckCircle2Icon, MicIcon, Volume2Icon } from "lucide-react";
 
+import { commands, type Permission } from "@/types";
 import { commands as listenerCommands } from "@hypr/plugin-listener";
 import { Button } from "@hypr/ui/components/ui/button";
 import PushableButton from "@hypr/ui/components/ui/pushable-button";
@@ -102,24 +103,26 @@ export function AudioPermissionsView({ onContinue }: AudioPermissionsViewProps)
 
   const [micPermissionRequested, setMicPermissionRequested] = useState(false);
 
-  const micPermissionStatus = useQuery({
-    queryKey: ["micPermission"],
-    queryFn: () => listenerCommands.checkMicrophoneAccess(),
+  const permissionReport = useQuery({
+    queryKey: ["permissionReport"],
+    queryFn: () => commands.getPermissionReport(),
     refetchInterval: 1000,
   });
 
-  const systemAudioPermissionStatus = useQuery({
-    queryKey: ["systemAudioPermission"],
-    queryFn: () => listenerCommands.checkSystemAudioAccess(),
-    refetchInterval: 1000,
-  });
+  const isGranted = (permission: Permission) => {
+    const check = permissionReport.data?.find((check) => check.permission === permission);
+    return check && check.status !== "not_granted";
+  };
+
+  const micGranted = isGranted("microphone");
+  const systemAudioGranted = isGranted("system_audio");
 
   const micPermission = useMutation({
     mutationFn: () => listenerCommands.requestMicrophoneAccess(),
     onSuccess: () => {
       setMicPermissionRequested(true);
       setTimeout(() => {
-        micPermissionStatus.refetch();
+        permissionReport.refetch();
       }, 3000);
     },
     onError: (error) => {
@@ -140,14 +143,14 @@ export function AudioPermissionsView({ onContinue }: AudioPermissionsViewProps)
   });
 
   const handleMicPermissionAction = () => {
-    if (micPermissionRequested && !micPermissionStatus.data) {
-      listenerCommands.openMicrophoneAccessSettings();
+    if (micPermissionRequested && !micGranted) {
+      commands.openPermissionSettings("microphone");
     } else {
       micPermission.mutate();
     }
   };
 
-  const allPermissionsGranted = micPermissionStatus.data && systemAudioPermissionStatus.data;
+  const allPermissionsGranted = micGranted && systemAudioGranted;
 
   return (
     <div className="flex flex-col items-center min-w-[30rem]">
@@ -164,17 +167,17 @@ export function AudioPermissionsView({ onContinue }: AudioPermissionsViewProps)
           icon={<MicIcon className="h-5 w-5" />}
           title={t`Microphone Access`}
           description={t`Required for meeting transcription`}
-          done={micPermissionStatus.data}
+          done={micGranted}
           isPending={micPermission.isPending}
           onRequest={handleMicPermissionAction}
-          buttonText={micPermissionRequested && !micPermissionStatus.data ? "Open Settings" : "Enable"}
+          buttonText={micPermissionRequested && !micGranted ? "Open Settings" : "Enable"}
         />
 
         <PermissionItem
           icon={<Volume2Icon className="h-5 w-5" />}
           title={t`System Audio Access`}
           description={t`Required for meeting transcription`}
-          done={systemAudioPermissionStatus.data}
+          done={systemAudioGranted}
           isPending={capturePermission.isPending}
           onRequest={() => capturePermission.mutate({})}
           buttonText="Enable"
@@ -11,6 +11,7 @@ import ReactDOM from "react-dom/client";
 import { recordingStartFailedToast } from "@/components/toast/shared";
 import type { Context } from "@/types";
 import { commands } from "@/types";
+import { commands as analyticsCommands, events as analyticsEvents } from "@hypr/plugin-analytics";
 import { commands as authCommands } from "@hypr/plugin-auth";
 import { commands as dbCommands } from "@hypr/plugin-db";
 import { Toaster } from "@hypr/ui/components/ui/toast";
@@ -77,17 +78,24 @@ declare module "@tanstack/react-router" {
   }
 }
 
+// Follows the analytics plugin, so toggling crash reports applies without a restart.
+let crashReportsConsent = false;
+analyticsCommands.getConsent().then((consent) => {
+  crashReportsConsent = consent.crash_reports;
+});
+analyticsEvents.consentChanged.listen(({ payload }) => {
+  crashReportsConsent = payload.consent.crash_reports;
+});
+
 commands.sentryDsn().then((dsn) => {
-  dbCommands.getConfig().then((config) => {
-    if (config.general.telemetry_consent) {
-      Sentry.init({
-        ...defaultOptions,
-        dsn,
-        // https://docs.sentry.io/platforms/javascript/guides/react/features/tanstack-router/
-        integrations: [Sentry.tanstackRouterBrowserTracingIntegration(router)],
-        tracesSampleRate: 1.0,
-      });
-    }
+  Sentry.init({
+    ...defaultOptions,
+    dsn,
+    // https://docs.sentry.io/platforms/javascript/guides/react/features/tanstack-router/
+    integrations: [Sentry.tanstackRouterBrowserTracingIntegration(router)],
+    tracesSampleRate: 1.0,
+    beforeSend: (event) => (crashReportsConsent ? event : null),
+    beforeSendTransaction: (event) => (crashReportsConsent ? event : null),
   });
 });
 
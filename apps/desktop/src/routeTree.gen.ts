@@ -13,9 +13,11 @@ import { Route as VideoRouteImport } from './routes/video'
 import { Route as AppRouteImport } from './routes/app'
 import { Route as AppIndexRouteImport } from './routes/app.index'
 import { Route as AppSettingsRouteImport } from './routes/app.settings'
+import { Route as AppOverlayRouteImport } from './routes/app.overlay'
 import { Route as AppNewRouteImport } from './routes/app.new'
 import { Route as AppFinderRouteImport } from './routes/app.finder'
 import { Route as AppControlRouteImport } from './routes/app.control'
+import { Route as AppCaptionsRouteImport } from './routes/app.captions'
 import { Route as AppOrganizationIdRouteImport } from './routes/app.organization.$id'
 import { Route as AppNoteIdRouteImport } from './routes/app.note.$id'
 import { Route as AppHumanIdRouteImport } from './routes/app.human.$id'
@@ -41,6 +43,11 @@ const AppSettingsRoute = AppSettingsRouteImport.update({
   path: '/settings',
   getParentRoute: () => AppRoute,
 } as any)
+const AppOverlayRoute = AppOverlayRouteImport.update({
+  id: '/overlay',
+  path: '/overlay',
+  getParentRoute: () => AppRoute,
+} as any)
 const AppNewRoute = AppNewRouteImport.update({
   id: '/new',
   path: '/new',
@@ -56,6 +63,11 @@ const AppControlRoute = AppControlRouteImport.update({
   path: '/control',
   getParentRoute: () => AppRoute,
 } as any)
+const AppCaptionsRoute = AppCaptionsRouteImport.update({
+  id: '/captions',
+  path: '/captions',
+  getParentRoute: () => AppRoute,
+} as any)
 const AppOrganizationIdRoute = AppOrganizationIdRouteImport.update({
   id: '/organization/$id',
   path: '/organization/$id',
@@ -80,9 +92,11 @@ const AppNoteEventIdRoute = AppNoteEventIdRouteImport.update({
 export interface FileRoutesByFullPath {
   '/app': typeof AppRouteWithChildren
   '/video': typeof VideoRoute
+  '/app/captions': typeof AppCaptionsRoute
   '/app/control': typeof AppControlRoute
   '/app/finder': typeof AppFinderRoute
   '/app/new': typeof AppNewRoute
+  '/app/overlay': typeof AppOverlayRoute
   '/app/settings': typeof AppSettingsRoute
   '/app/': typeof AppIndexRoute
   '/app/human/$id': typeof AppHumanIdRoute
@@ -92,9 +106,11 @@ export interface FileRoutesByFullPath {
 }
 export interface FileRoutesByTo {
   '/video': typeof VideoRoute
+  '/app/captions': typeof AppCaptionsRoute
   '/app/control': typeof AppControlRoute
   '/app/finder': typeof AppFinderRoute
   '/app/new': typeof AppNewRoute
+  '/app/overlay': typeof AppOverlayRoute
   '/app/settings': typeof AppSettingsRoute
   '/app': typeof AppIndexRoute
   '/app/human/$id': typeof AppHumanIdRoute
@@ -106,9 +122,11 @@ export interface FileRoutesById {
   __root__: typeof rootRouteImport
   '/app': typeof AppRouteWithChildren
   '/video': typeof VideoRoute
+  '/app/captions': typeof AppCaptionsRoute
   '/app/control': typeof AppControlRoute
   '/app/finder': typeof AppFinderRoute
   '/app/new': typeof AppNewRoute
+  '/app/overlay': typeof AppOverlayRoute
   '/app/settings': typeof AppSettingsRoute
   '/app/': typeof AppIndexRoute
   '/app/human/$id': typeof AppHumanIdRoute
@@ -121,9 +139,11 @@ export interface FileRouteTypes {
   fullPaths:
     | '/app'
     | '/video'
+    | '/app/captions'
     | '/app/control'
     | '/app/finder'
     | '/app/new'
+    | '/app/overlay'
     | '/app/settings'
     | '/app/'
     | '/app/human/$id'
@@ -133,9 +153,11 @@ export interface FileRouteTypes {
   fileRoutesByTo: FileRoutesByTo
   to:
     | '/video'
+    | '/app/captions'
     | '/app/control'
     | '/app/finder'
     | '/app/new'
+    | '/app/overlay'
     | '/app/settings'
     | '/app'
     | '/app/human/$id'
@@ -146,9 +168,11 @@ export interface FileRouteTypes {
     | '__root__'
     | '/app'
     | '/video'
+    | '/app/captions'
     | '/app/control'
     | '/app/finder'
     | '/app/new'
+    | '/app/overlay'
     | '/app/settings'
     | '/app/'
     | '/app/human/$id'
@@ -192,6 +216,13 @@ declare module '@tanstack/react-router' {
       preLoaderRoute: typeof AppSettingsRouteImport
       parentRoute: typeof AppRoute
     }
+    '/app/overlay': {
+      id: '/app/overlay'
+      path: '/overlay'
+      fullPath: '/app/overlay'
+      preLoaderRoute: typeof AppOverlayRouteImport
+      parentRoute: typeof AppRoute
+    }
     '/app/new': {
       id: '/app/new'
       path: '/new'
@@ -213,6 +244,13 @@ declare module '@tanstack/react-router' {
       preLoaderRoute: typeof AppControlRouteImport
       parentRoute: typeof AppRoute
     }
+    '/app/captions': {
+      id: '/app/captions'
+      path: '/captions'
+      fullPath: '/app/captions'
+      preLoaderRoute: typeof AppCaptionsRouteImport
+      parentRoute: typeof AppRoute
+    }
     '/app/organization/$id': {
       id: '/app/organization/$id'
       path: '/organization/$id'
@@ -245,9 +283,11 @@ declare module '@tanstack/react-router' {
 }
 
 interface AppRouteChildren {
+  AppCaptionsRoute: typeof AppCaptionsRoute
   AppControlRoute: typeof AppControlRoute
   AppFinderRoute: typeof AppFinderRoute
   AppNewRoute: typeof AppNewRoute
+  AppOverlayRoute: typeof AppOverlayRoute
   AppSettingsRoute: typeof AppSettingsRoute
   AppIndexRoute: typeof AppIndexRoute
   AppHumanIdRoute: typeof AppHumanIdRoute
@@ -257,9 +297,11 @@ interface AppRouteChildren {
 }
 
 const AppRouteChildren: AppRouteChildren = {
+  AppCaptionsRoute: AppCaptionsRoute,
   AppControlRoute: AppControlRoute,
   AppFinderRoute: AppFinderRoute,
   AppNewRoute: AppNewRoute,
+  AppOverlayRoute: AppOverlayRoute,
   AppSettingsRoute: AppSettingsRoute,
   AppIndexRoute: AppIndexRoute,
   AppHumanIdRoute: AppHumanIdRoute,
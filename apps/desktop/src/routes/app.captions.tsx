@@ -0,0 +1,201 @@
+import { Trans, useLingui } from "@lingui/react/macro";
+import { createFileRoute } from "@tanstack/react-router";
+import { Contrast, Languages, Minus, Plus, X } from "lucide-react";
+import { type ReactNode, useEffect, useState } from "react";
+
+import { events as listenerEvents, type Word2 as Word } from "@hypr/plugin-listener";
+import { commands as windowsCommands } from "@hypr/plugin-windows";
+
+export const Route = createFileRoute("/app/captions")({
+  component: Component,
+});
+
+const SETTINGS_KEY = "captions-settings";
+
+// Only the tail is ever on screen, so older words are dropped instead of kept around for the whole meeting.
+const MAX_WORDS = 200;
+
+const FONT_SIZES = [20, 24, 28, 32, 40, 48, 56];
+
+const CONTRASTS = {
+  dim: "bg-black/60 text-white/90",
+  dark: "bg-black/85 text-white",
+  high: "bg-black text-yellow-300",
+  light: "bg-white/95 text-black",
+} as const;
+
+type ContrastMode = keyof typeof CONTRASTS;
+
+type CaptionSettings = {
+  fontSize: number;
+  contrast: ContrastMode;
+  showTranslation: boolean;
+};
+
+const DEFAULT_SETTINGS: CaptionSettings = {
+  fontSize: 32,
+  contrast: "dark",
+  showTranslation: false,
+};
+
+function Component() {
+  const { t } = useLingui();
+  const [settings, setSettings] = useCaptionSettings();
+  const { words, translation } = useLiveCaptions();
+
+  useEffect(() => {
+    document.body.style.background = "transparent";
+    document.documentElement.style.background = "transparent";
+    document.documentElement.setAttribute("data-transparent-window", "true");
+  }, []);
+
+  const stepFontSize = (step: number) => {
+    const index = FONT_SIZES.indexOf(settings.fontSize);
+    const next = FONT_SIZES[Math.min(Math.max(index + step, 0), FONT_SIZES.length - 1)];
+    setSettings({ ...settings, fontSize: next ?? DEFAULT_SETTINGS.fontSize });
+  };
+
+  const cycleContrast = () => {
+    const modes = Object.keys(CONTRASTS) as ContrastMode[];
+    const next = modes[(modes.indexOf(settings.contrast) + 1) % modes.length];
+    setSettings({ ...settings, contrast: next });
+  };
+
+  const handleClose = () => {
+    windowsCommands.windowHide({ type: "captions" }).catch((error) => console.error("[Captions] Close error:", error));
+  };
+
+  const text = words.map((word) => word.text).join(" ");
+
+  return (
+    <div
+      data-tauri-drag-region
+      className={`group relative h-screen w-screen flex flex-col justify-end gap-2 px-6 py-4 rounded-2xl overflow-hidden select-none cursor-move ${
+        CONTRASTS[settings.contrast]
+      }`}
+    >
+      <div
+        data-tauri-drag-region
+        className="font-semibold leading-snug overflow-hidden flex flex-col justify-end"
+        style={{ fontSize: settings.fontSize }}
+      >
+        <p data-tauri-drag-region className="line-clamp-3">
+          {text || (
+            <span className="opacity-50">
+              <Trans>Waiting for speech…</Trans>
+            </span>
+          )}
+        </p>
+      </div>
+
+      {settings.showTranslation && translation && (
+        <p
+          data-tauri-drag-region
+          className="leading-snug line-clamp-2 opacity-80"
+          style={{ fontSize: Math.round(settings.fontSize * 0.75) }}
+        >
+          {translation}
+        </p>
+      )}
+
+      <div className="absolute top-2 right-2 flex gap-1 opacity-0 group-hover:opacity-100 transition-opacity">
+        <CaptionButton onClick={() => stepFontSize(-1)} label={t`Smaller text`}>
+          <Minus size={14} />
+        </CaptionButton>
+        <CaptionButton onClick={() => stepFontSize(1)} label={t`Larger text`}>
+          <Plus size={14} />
+        </CaptionButton>
+        <CaptionButton onClick={cycleContrast} label={t`Change contrast`}>
+          <Contrast size={14} />
+        </CaptionButton>
+        <CaptionButton
+          onClick={() => setSettings({ ...settings, showTranslation: !settings.showTranslation })}
+          label={settings.showTranslation ? t`Hide translation` : t`Show translation`}
+          active={settings.showTranslation}
+        >
+          <Languages size={14} />
+        </CaptionButton>
+        <CaptionButton onClick={handleClose} label={t`Close captions`}>
+          <X size={14} />
+        </CaptionButton>
+      </div>
+    </div>
+  );
+}
+
+function CaptionButton({
+  onClick,
+  label,
+  active = false,
+  children,
+}: {
+  onClick: () => void;
+  label: string;
+  active?: boolean;
+  children: ReactNode;
+}) {
+  return (
+    <button
+      onClick={onClick}
+      title={label}
+      aria-label={label}
+      className={`p-1.5 rounded-md cursor-pointer transition-colors bg-neutral-700/80 text-white hover:bg-neutral-600 ${
+        active ? "ring-1 ring-white/70" : ""
+      }`}
+    >
+      {children}
+    </button>
+  );
+}
+
+function useCaptionSettings() {
+  const [settings, setSettings] = useState<CaptionSettings>(() => {
+    try {
+      const stored = localStorage.getItem(SETTINGS_KEY);
+      return stored ? { ...DEFAULT_SETTINGS, ...JSON.parse(stored) } : DEFAULT_SETTINGS;
+    } catch {
+      return DEFAULT_SETTINGS;
+    }
+  });
+
+  useEffect(() => {
+    try {
+      localStorage.setItem(SETTINGS_KEY, JSON.stringify(settings));
+    } catch {
+      // Settings just won't survive a restart.
+    }
+  }, [settings]);
+
+  return [settings, setSettings] as const;
+}
+
+function useLiveCaptions() {
+  const [finalWords, setFinalWords] = useState<Word[]>([]);
+  const [partialWords, setPartialWords] = useState<Word[]>([]);
+  const [translation, setTranslation] = useState<string | null>(null);
+
+  useEffect(() => {
+    const unlisten = listenerEvents.sessionEvent.listen(({ payload }) => {
+      if (payload.type === "finalWords") {
+        const words = Object.values(payload.words).flat().filter((v) => !!v);
+        setFinalWords((existing) => [...existing, ...words].slice(-MAX_WORDS));
+        setPartialWords([]);
+      } else if (payload.type === "partialWords") {
+        setPartialWords(Object.values(payload.words).flat().filter((v) => !!v));
+      } else if (payload.type === "translatedSegment") {
+        setTranslation(payload.text);
+      } else if (payload.type === "inactive") {
+        setFinalWords([]);
+        setPartialWords([]);
+        setTranslation(null);
+      }
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  const words = [...finalWords, ...partialWords].sort((a, b) => (a.start_ms ?? 0) - (b.start_ms ?? 0));
+  return { words, translation };
+}
@@ -8,6 +8,7 @@ import { useEffect, useState } from "react";
 import { useForm } from "react-hook-form";
 import { z } from "zod";
 
+import { Meetings } from "@/components/human-profile";
 import { EditableEntityWrapper } from "@/components/toolbar/bars";
 import { useEditMode } from "@/contexts";
 import { commands as dbCommands, type Human, type Organization } from "@hypr/plugin-db";
@@ -223,6 +224,8 @@ function HumanView({
           )}
         </div>
       </div>
+
+      <Meetings human={value} />
     </div>
   );
 }
@@ -1,4 +1,5 @@
 import { createFileRoute, redirect } from "@tanstack/react-router";
+import { openUrl } from "@tauri-apps/plugin-opener";
 import { zodValidator } from "@tanstack/zod-adapter";
 import { z } from "zod";
 
@@ -7,14 +8,20 @@ import { commands as dbCommands } from "@hypr/plugin-db";
 const schema = z.object({
   record: z.boolean().optional(),
   calendarEventId: z.string().optional(),
+  // Used by notification links.
+  event_id: z.string().optional(),
+  // Also open the event's meeting link.
+  join: z.boolean().optional(),
 });
 
 export const Route = createFileRoute("/app/new")({
   validateSearch: zodValidator(schema),
   beforeLoad: async ({
     context: { queryClient, ongoingSessionStore, sessionsStore, userId },
-    search: { record, calendarEventId },
+    search: { record, calendarEventId: calendarEventIdParam, event_id, join },
   }) => {
+    const calendarEventId = calendarEventIdParam ?? event_id;
+
     try {
       const sessionId = crypto.randomUUID();
 
@@ -47,6 +54,10 @@ export const Route = createFileRoute("/app/new")({
 
         const { insert } = sessionsStore.getState();
         insert(session);
+
+        if (join && event?.meeting_link) {
+          openUrl(event.meeting_link);
+        }
       } else {
         const session = await dbCommands.upsertSession({
           id: sessionId,
@@ -0,0 +1,158 @@
+import { createFileRoute } from "@tanstack/react-router";
+import { Pause, Play, Square } from "lucide-react";
+import { type ReactNode, useEffect, useRef, useState } from "react";
+
+import { commands as listenerCommands, events as listenerEvents } from "@hypr/plugin-listener";
+import { DancingSticks } from "@hypr/ui/components/ui/dancing-sticks";
+
+export const Route = createFileRoute("/app/overlay")({
+  component: Component,
+});
+
+function Component() {
+  const { active, paused, amplitude, startedAt } = useSessionState();
+  const elapsed = useElapsed(active ? startedAt : null);
+
+  useEffect(() => {
+    document.body.style.background = "transparent";
+    document.documentElement.style.background = "transparent";
+    document.documentElement.setAttribute("data-transparent-window", "true");
+  }, []);
+
+  const handlePauseResume = () => {
+    const command = paused ? listenerCommands.resumeSession() : listenerCommands.pauseSession();
+    command.catch((error) => console.error("[Overlay] Pause toggle error:", error));
+  };
+
+  const handleStop = () => {
+    listenerCommands.stopSession().catch((error) => console.error("[Overlay] Stop error:", error));
+  };
+
+  return (
+    <div
+      data-tauri-drag-region
+      className="h-screen w-screen flex items-center gap-3 px-4 rounded-2xl select-none cursor-move bg-black/85 text-white"
+    >
+      <div
+        data-tauri-drag-region
+        className={`size-2.5 rounded-full ${paused ? "bg-neutral-400" : "bg-red-500 animate-pulse"}`}
+      />
+
+      <span data-tauri-drag-region className="font-mono text-sm tabular-nums">
+        {formatElapsed(elapsed)}
+      </span>
+
+      <div data-tauri-drag-region className="flex-1 flex justify-center">
+        <DancingSticks
+          amplitude={paused ? 0 : Math.min(Math.max(amplitude.mic, amplitude.speaker) / 5, 1)}
+          color="#ffffff"
+          size="long"
+        />
+      </div>
+
+      <div className="flex gap-1">
+        <OverlayButton onClick={handlePauseResume} label={paused ? "Resume recording" : "Pause recording"}>
+          {paused ? <Play size={14} /> : <Pause size={14} />}
+        </OverlayButton>
+        <OverlayButton
+          onClick={handleStop}
+          label="Stop recording"
+          className="bg-red-600/70 hover:bg-red-500/90"
+        >
+          <Square size={14} />
+        </OverlayButton>
+      </div>
+    </div>
+  );
+}
+
+function OverlayButton({
+  onClick,
+  label,
+  className = "bg-white/10 hover:bg-white/20",
+  children,
+}: {
+  onClick: () => void;
+  label: string;
+  className?: string;
+  children: ReactNode;
+}) {
+  return (
+    <button
+      onClick={onClick}
+      title={label}
+      aria-label={label}
+      className={`p-2 rounded-lg cursor-pointer transition-colors ${className}`}
+    >
+      {children}
+    </button>
+  );
+}
+
+function useSessionState() {
+  const [active, setActive] = useState(false);
+  const [paused, setPaused] = useState(false);
+  const [startedAt, setStartedAt] = useState(() => Date.now());
+  const [amplitude, setAmplitude] = useState({ mic: 0, speaker: 0 });
+  const activeRef = useRef(false);
+
+  useEffect(() => {
+    listenerCommands.getState().then((state) => {
+      activeRef.current = state !== "inactive";
+      setActive(activeRef.current);
+      setPaused(state === "running_paused");
+    });
+
+    // The window is only hidden between sessions, so the timer restarts on each new one.
+    const unlisten = listenerEvents.sessionEvent.listen(({ payload }) => {
+      if (payload.type === "running_active") {
+        if (!activeRef.current) {
+          setStartedAt(Date.now());
+        }
+        activeRef.current = true;
+        setActive(true);
+        setPaused(false);
+      } else if (payload.type === "running_paused") {
+        setPaused(true);
+      } else if (payload.type === "inactive") {
+        activeRef.current = false;
+        setActive(false);
+        setPaused(false);
+        setAmplitude({ mic: 0, speaker: 0 });
+      } else if (payload.type === "audioAmplitude") {
+        setAmplitude({ mic: payload.mic, speaker: payload.speaker });
+      }
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  return { active, paused, amplitude, startedAt };
+}
+
+function useElapsed(startedAt: number | null) {
+  const [now, setNow] = useState(() => Date.now());
+
+  useEffect(() => {
+    if (startedAt === null) {
+      return;
+    }
+
+    const interval = setInterval(() => setNow(Date.now()), 1000);
+    return () => clearInterval(interval);
+  }, [startedAt]);
+
+  return startedAt === null ? 0 : Math.max(0, now - startedAt);
+}
+
+function formatElapsed(ms: number) {
+  const total = Math.floor(ms / 1000);
+  const hours = Math.floor(total / 3600);
+  const minutes = Math.floor((total % 3600) / 60);
+  const seconds = total % 60;
+
+  const pad = (n: number) => n.toString().padStart(2, "0");
+  return hours > 0 ? `${hours}:${pad(minutes)}:${pad(seconds)}` : `${pad(minutes)}:${pad(seconds)}`;
+}
@@ -16,6 +16,7 @@ import {
   MCP,
   Notifications,
   Sound,
+  Tasks,
   TemplatesView,
 } from "@/components/settings/views";
 import { cn } from "@hypr/ui/lib/utils";
@@ -58,6 +59,8 @@ function TabButton({ tab, isActive, onClick }: { tab: Tab; isActive: boolean; on
         return t`Billing & License`;
       case "mcp":
         return t`MCP`;
+      case "tasks":
+        return t`Background Tasks`;
       case "help-support":
         return t`Help & Support`;
       default:
@@ -112,6 +115,8 @@ function Component() {
         return t`Billing & License`;
       case "mcp":
         return t`MCP`;
+      case "tasks":
+        return t`Background Tasks`;
       case "help-support":
         return t`Help & Support`;
       default:
@@ -185,6 +190,7 @@ function Component() {
               {search.tab === "templates" && <TemplatesView />}
               {search.tab === "integrations" && <Integrations />}
               {search.tab === "mcp" && <MCP />}
+              {search.tab === "tasks" && <Tasks />}
               {search.tab === "billing" && <Billing />}
               {search.tab === "help-support" && <HelpSupport />}
             </div>
@@ -8,6 +8,7 @@ import { useEffect, useRef, useState } from "react";
 import { IndividualizationModal } from "@/components/individualization-modal";
 import LeftSidebar from "@/components/left-sidebar";
 import { LicenseRefreshProvider } from "@/components/license";
+import { PipelineRunner } from "@/components/pipeline-runner";
 import RightPanel from "@/components/right-panel";
 import Notifications from "@/components/toast";
 import Toolbar from "@/components/toolbar";
@@ -130,6 +131,7 @@ function Component() {
                 <RestartTTT />
                 <RestartSTT />
                 <MainWindowStateEventSupport />
+                <PipelineRunner sessionsStore={sessionsStore} ongoingSessionStore={ongoingSessionStore} />
                 <SettingsProvider>
                   <NewNoteProvider>
                     <SearchProvider>
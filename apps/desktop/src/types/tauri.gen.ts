@@ -27,6 +27,54 @@ async isIndividualizationNeeded() : Promise<boolean> {
 },
 async setIndividualizationNeeded(v: boolean) : Promise<null> {
     return await TAURI_INVOKE("set_individualization_needed", { v });
+},
+async listShortcuts() : Promise<ShortcutBinding[]> {
+    return await TAURI_INVOKE("list_shortcuts");
+},
+async setShortcut(action: ShortcutAction, accelerator: string | null) : Promise<ShortcutBinding[]> {
+    return await TAURI_INVOKE("set_shortcut", { action, accelerator });
+},
+async resetShortcut(action: ShortcutAction) : Promise<ShortcutBinding[]> {
+    return await TAURI_INVOKE("reset_shortcut", { action });
+},
+async getUpdateChannel() : Promise<UpdateChannel> {
+    return await TAURI_INVOKE("get_update_channel");
+},
+async setUpdateChannel(channel: UpdateChannel) : Promise<null> {
+    return await TAURI_INVOKE("set_update_channel", { channel });
+},
+async checkForUpdate() : Promise<UpdateInfo | null> {
+    return await TAURI_INVOKE("check_for_update");
+},
+async installUpdate(onProgress: TAURI_CHANNEL<number>) : Promise<null> {
+    return await TAURI_INVOKE("install_update", { onProgress });
+},
+async setDisplayLanguage(language: string) : Promise<null> {
+    return await TAURI_INVOKE("set_display_language", { language });
+},
+async getPermissionReport() : Promise<PermissionCheck[]> {
+    return await TAURI_INVOKE("get_permission_report");
+},
+async openPermissionSettings(permission: Permission) : Promise<null> {
+    return await TAURI_INVOKE("open_permission_settings", { permission });
+},
+async exportNoteHtml(sessionId: string, password: string | null) : Promise<string> {
+    return await TAURI_INVOKE("export_note_html", { sessionId, password });
+},
+async exportNote(sessionId: string, format: ExportFormat, template: ExportTemplate | null) : Promise<string> {
+    return await TAURI_INVOKE("export_note", { sessionId, format, template });
+},
+async listProfiles() : Promise<ProfileInfo[]> {
+    return await TAURI_INVOKE("list_profiles");
+},
+async createProfile(name: string) : Promise<ProfileInfo> {
+    return await TAURI_INVOKE("create_profile", { name });
+},
+async deleteProfile(id: string) : Promise<null> {
+    return await TAURI_INVOKE("delete_profile", { id });
+},
+async switchProfile(id: string) : Promise<null> {
+    return await TAURI_INVOKE("switch_profile", { id });
 }
 }
 
@@ -40,6 +88,29 @@ async setIndividualizationNeeded(v: boolean) : Promise<null> {
 
 /** user-defined types **/
 
+export type ExportFormat = "pdf" | "docx"
+/**
+ * Which sections make it into the document. They always appear in this order,
+ * with the transcript as an appendix on its own page.
+ */
+export type ExportTemplate = { 
+/**
+ * Date, duration and participants.
+ */
+include_metadata: boolean; include_summary: boolean; include_action_items: boolean; include_transcript: boolean }
+export type Permission = "microphone" | "system_audio" | "accessibility" | "calendar" | "notifications"
+export type PermissionCheck = { permission: Permission; status: PermissionStatus; required: boolean; 
+/**
+ * Whether `open_permission_settings` has a settings pane to show for it.
+ */
+has_settings: boolean }
+export type PermissionStatus = "granted" | "not_granted" | "unsupported"
+export type ProfileInfo = { id: string; name: string; active: boolean }
+export type ShortcutAction = "new_note_and_record" | "toggle_mute" | "stop_recording" | "mark_highlight"
+export type ShortcutBinding = { action: ShortcutAction; accelerator: string | null; default_accelerator: string | null }
+export type TAURI_CHANNEL<TSend> = null
+export type UpdateChannel = "stable" | "beta"
+export type UpdateInfo = { version: string; current_version: string; date: string | null; body: string | null }
 
 
 /** tauri-specta globals **/
@@ -12,7 +12,7 @@ const extractHashtags = (text: string): string[] => {
 
 export async function generateTagsForSession(sessionId: string): Promise<string[]> {
   try {
-    const { type: connectionType } = await connectorCommands.getLlmConnection();
+    const { connection: { type: connectionType } } = await connectorCommands.getLlmConnectionForTask("Tags");
 
     const config = await dbCommands.getConfig();
     const session = await dbCommands.getSession({ id: sessionId });
@@ -43,7 +43,7 @@ export async function generateTagsForSession(sessionId: string): Promise<string[
       },
     );
 
-    const provider = await modelProvider();
+    const provider = await modelProvider("Tags");
     const model = provider.languageModel("defaultModel");
 
     const result = await generateText({
@@ -78,7 +78,7 @@ export async function generateTagsForSession(sessionId: string): Promise<string[
 
 export async function autoTagGeneration(sessionId: string): Promise<string[]> {
   try {
-    const { type: connectionType } = await connectorCommands.getLlmConnection();
+    const { connection: { type: connectionType } } = await connectorCommands.getLlmConnectionForTask("Tags");
 
     const config = await dbCommands.getConfig();
     const session = await dbCommands.getSession({ id: sessionId });
@@ -109,7 +109,7 @@ export async function autoTagGeneration(sessionId: string): Promise<string[]> {
       },
     );
 
-    const provider = await modelProvider();
+    const provider = await modelProvider("Tags");
     const model = provider.languageModel("defaultModel");
 
     const result = await generateText({
@@ -1,30 +1,137 @@
-use crate::{Error, ErrorResponse, GenericResponse, InitRequest, InitResponse, ServerStatus};
-use reqwest::{Response, StatusCode};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use crate::{
+    Error, ErrorResponse, GenericResponse, InitRequest, InitResponse, ServerStatus,
+    VersionResponse, MIN_SIDECAR_VERSION,
+};
+use reqwest::{RequestBuilder, Response, StatusCode};
+
+/// Timeouts and retries for talking to the sidecar.
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    pub connect_timeout: Duration,
+    /// Bounds every request, so a wedged sidecar surfaces as `Error::Timeout`.
+    pub request_timeout: Duration,
+    /// Replaces `request_timeout` for `init`, which loads the model before responding.
+    pub init_timeout: Duration,
+    pub max_retries: u32,
+    /// Doubled after every retry, up to `max_backoff`.
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(2),
+            request_timeout: Duration::from_secs(30),
+            init_timeout: Duration::from_secs(5 * 60),
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+impl ClientConfig {
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+}
 
 #[derive(Clone)]
 pub struct Client {
     client: reqwest::Client,
     base_url: String,
+    config: ClientConfig,
+    // Set once any request gets through, so a refused connection afterwards means the
+    // sidecar went away rather than that it hasn't started yet.
+    connected: Arc<AtomicBool>,
 }
 
 impl Client {
     pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_config(base_url, ClientConfig::default())
+    }
+
+    pub fn with_config(base_url: impl Into<String>, config: ClientConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .connect_timeout(config.connect_timeout)
+            .timeout(config.request_timeout)
+            .build()
+            .unwrap();
+
         Self {
-            client: reqwest::Client::new(),
+            client,
             base_url: base_url.into(),
+            config,
+            connected: Arc::new(AtomicBool::new(false)),
         }
     }
 
+    /// Timeouts are left to `client`; only the retry settings of the default config apply.
     pub fn with_client(client: reqwest::Client, base_url: impl Into<String>) -> Self {
         Self {
             client,
             base_url: base_url.into(),
+            config: ClientConfig::default(),
+            connected: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Retries connection failures, and timeouts too when `idempotent`. A request that timed
+    /// out may have been processed, so commands like `init` aren't resent.
+    async fn send(
+        &self,
+        request: impl Fn() -> RequestBuilder,
+        idempotent: bool,
+    ) -> Result<Response, Error> {
+        let mut attempt = 0;
+
+        loop {
+            match request().send().await {
+                Ok(response) => {
+                    self.connected.store(true, Ordering::Relaxed);
+                    return Ok(response);
+                }
+                Err(e) => {
+                    let retryable = e.is_connect() || (idempotent && e.is_timeout());
+                    if !retryable || attempt >= self.config.max_retries {
+                        return Err(self.classify(e));
+                    }
+
+                    tokio::time::sleep(self.config.backoff(attempt)).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    fn classify(&self, e: reqwest::Error) -> Error {
+        if e.is_timeout() {
+            Error::Timeout
+        } else if e.is_connect() {
+            if self.connected.load(Ordering::Relaxed) {
+                Error::SidecarCrashed
+            } else {
+                Error::SidecarNotStarted
+            }
+        } else {
+            Error::Request(e)
         }
     }
 
     pub async fn status(&self) -> Result<ServerStatus, Error> {
         let url = format!("{}/v1/status", self.base_url);
-        let response = self.client.get(&url).send().await?;
+        let response = self.send(|| self.client.get(&url), true).await?;
 
         if response.status().is_success() {
             Ok(response.json().await?)
@@ -33,13 +140,53 @@ impl Client {
         }
     }
 
+    /// `None` for sidecars that predate the version handshake.
+    pub async fn version(&self) -> Result<Option<VersionResponse>, Error> {
+        let url = format!("{}/version", self.base_url);
+        let response = self.send(|| self.client.get(&url), true).await?;
+
+        match response.status() {
+            StatusCode::OK => Ok(Some(response.json().await?)),
+            StatusCode::NOT_FOUND => Ok(None),
+            _ => Err(self.handle_error_response(response).await),
+        }
+    }
+
+    /// Returns the sidecar's version if it is at least `MIN_SIDECAR_VERSION`.
+    pub async fn check_version(&self) -> Result<String, Error> {
+        let found = self
+            .version()
+            .await?
+            .map(|v| v.version)
+            .unwrap_or_else(|| "unknown".to_string());
+
+        if crate::is_compatible(&found) {
+            Ok(found)
+        } else {
+            Err(Error::IncompatibleVersion {
+                found,
+                required: MIN_SIDECAR_VERSION.to_string(),
+            })
+        }
+    }
+
     pub async fn init(&self, request: InitRequest) -> Result<InitResponse, Error> {
         if !request.api_key.starts_with("ax_") {
             return Err(Error::InvalidApiKey);
         }
 
         let url = format!("{}/v1/init", self.base_url);
-        let response = self.client.post(&url).json(&request).send().await?;
+        let response = self
+            .send(
+                || {
+                    self.client
+                        .post(&url)
+                        .timeout(self.config.init_timeout)
+                        .json(&request)
+                },
+                false,
+            )
+            .await?;
 
         match response.status() {
             StatusCode::OK => Ok(response.json().await?),
@@ -52,7 +199,7 @@ impl Client {
 
     pub async fn reset(&self) -> Result<GenericResponse, Error> {
         let url = format!("{}/v1/reset", self.base_url);
-        let response = self.client.post(&url).send().await?;
+        let response = self.send(|| self.client.post(&url), false).await?;
 
         if response.status().is_success() {
             Ok(response.json().await?)
@@ -63,7 +210,7 @@ impl Client {
 
     pub async fn unload(&self) -> Result<GenericResponse, Error> {
         let url = format!("{}/v1/unload", self.base_url);
-        let response = self.client.post(&url).send().await?;
+        let response = self.send(|| self.client.post(&url), false).await?;
 
         match response.status() {
             StatusCode::OK => Ok(response.json().await?),
@@ -74,7 +221,7 @@ impl Client {
 
     pub async fn shutdown(&self) -> Result<GenericResponse, Error> {
         let url = format!("{}/v1/shutdown", self.base_url);
-        let response = self.client.post(&url).send().await?;
+        let response = self.send(|| self.client.post(&url), false).await?;
 
         if response.status().is_success() {
             Ok(response.json().await?)
@@ -122,3 +269,41 @@ impl InitRequest {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let config = ClientConfig::default();
+
+        assert_eq!(config.backoff(0), Duration::from_millis(200));
+        assert_eq!(config.backoff(2), Duration::from_millis(800));
+        assert_eq!(config.backoff(10), Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn test_sidecar_not_started() {
+        // Bound and released, so nothing is listening on it.
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let client = Client::with_config(
+            format!("http://127.0.0.1:{}", port),
+            ClientConfig {
+                max_retries: 1,
+                initial_backoff: Duration::from_millis(1),
+                ..Default::default()
+            },
+        );
+
+        assert!(matches!(
+            client.status().await,
+            Err(Error::SidecarNotStarted)
+        ));
+    }
+}
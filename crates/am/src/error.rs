@@ -21,9 +21,27 @@ pub enum Error {
     #[error("Unexpected response from server")]
     UnexpectedResponse,
 
+    #[error("STT sidecar is not accepting connections yet")]
+    SidecarNotStarted,
+
+    #[error("STT sidecar stopped accepting connections, it may have crashed")]
+    SidecarCrashed,
+
+    #[error("STT sidecar did not respond in time")]
+    Timeout,
+
     #[error("Tar file not found")]
     TarFileNotFound,
 
     #[error("Tar checksum mismatch")]
     TarChecksumMismatch,
+
+    #[error("STT sidecar version {found} is not supported, {required} or newer is required")]
+    IncompatibleVersion { found: String, required: String },
+
+    #[error("Sidecar checksum mismatch")]
+    SidecarChecksumMismatch,
+
+    #[error("Sidecar signature is invalid: {0}")]
+    SidecarSignature(String),
 }
@@ -1,11 +1,13 @@
 mod client;
 mod error;
 mod model;
+mod sidecar;
 mod types;
 
 pub use client::*;
 pub use error::*;
 pub use model::*;
+pub use sidecar::*;
 pub use types::*;
 
 #[cfg(test)]
@@ -0,0 +1,207 @@
+use std::path::{Path, PathBuf};
+
+/// Oldest sidecar whose API this client speaks.
+pub const MIN_SIDECAR_VERSION: &str = "0.3.0";
+
+const MANIFEST_URL: &str =
+    "https://hyprnote.s3.us-east-1.amazonaws.com/v0/stt-sidecar/aarch64-apple-darwin/latest.json";
+const BINARY_PREFIX: &str = "stt-";
+const SIGNATURE_EXTENSION: &str = "minisig";
+const MANIFEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Minisign key the sidecar binaries are signed with. From the build environment; without it
+/// no downloaded sidecar is trusted and only the bundled one runs.
+const PUBLIC_KEY: Option<&str> = option_env!("STT_SIDECAR_PUBLIC_KEY");
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SidecarRelease {
+    pub version: String,
+    pub url: String,
+    pub checksum: u32,
+    /// Minisign signature of the binary, checked against [`PUBLIC_KEY`] before it is ever run.
+    pub signature: String,
+}
+
+/// `major.minor.patch`, ignoring a leading `v` and any pre-release or build suffix.
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let version = version.trim().trim_start_matches('v');
+    let core = version.split(['-', '+']).next()?;
+
+    let mut parts = core.split('.').map(|part| part.parse::<u64>());
+    let major = parts.next()?.ok()?;
+    let minor = parts.next().unwrap_or(Ok(0)).ok()?;
+    let patch = parts.next().unwrap_or(Ok(0)).ok()?;
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some((major, minor, patch))
+}
+
+pub fn is_compatible(version: &str) -> bool {
+    match (parse_version(version), parse_version(MIN_SIDECAR_VERSION)) {
+        (Some(found), Some(required)) => found >= required,
+        _ => false,
+    }
+}
+
+pub async fn latest_release() -> Result<SidecarRelease, crate::Error> {
+    let release: SidecarRelease = hypr_proxy::client()
+        .get(MANIFEST_URL)
+        .timeout(MANIFEST_TIMEOUT)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    if !is_compatible(&release.version) {
+        return Err(crate::Error::IncompatibleVersion {
+            found: release.version,
+            required: MIN_SIDECAR_VERSION.to_string(),
+        });
+    }
+
+    Ok(release)
+}
+
+/// The newest compatible sidecar previously downloaded into `dir`.
+pub fn installed_sidecar(dir: impl AsRef<Path>) -> Option<PathBuf> {
+    std::fs::read_dir(dir.as_ref())
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let version = name.strip_prefix(BINARY_PREFIX)?;
+            is_compatible(version).then(|| (parse_version(version), entry.path()))
+        })
+        .max_by_key(|(version, _)| *version)
+        .map(|(_, path)| path)
+}
+
+/// Downloads `release` into `dir` and returns the path of the binary. It is verified and
+/// made executable under a temporary name first, so an interrupted or tampered download is
+/// never run. The signature is kept next to it for [`verify_sidecar`].
+pub async fn download_sidecar<F: Fn(hypr_download_interface::DownloadProgress) + Send + Sync>(
+    release: &SidecarRelease,
+    dir: impl AsRef<Path>,
+    progress_callback: F,
+) -> Result<PathBuf, crate::Error> {
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir)?;
+
+    let name = format!("{}{}", BINARY_PREFIX, release.version);
+    let path = dir.join(&name);
+    if path.exists() {
+        return Ok(path);
+    }
+
+    let tmp = dir.join(format!("{}.download", name));
+    hypr_file::download_file_parallel(&release.url, &tmp, progress_callback).await?;
+
+    if hypr_file::calculate_file_checksum(&tmp)? != release.checksum {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(crate::Error::SidecarChecksumMismatch);
+    }
+
+    if let Err(e) = verify(&std::fs::read(&tmp)?, &release.signature) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e);
+    }
+    std::fs::write(signature_path(&path), &release.signature)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&tmp, std::fs::Permissions::from_mode(0o755))?;
+    }
+
+    std::fs::rename(&tmp, &path)?;
+    Ok(path)
+}
+
+/// Checks a downloaded sidecar against the signature saved beside it, right before it is run.
+pub fn verify_sidecar(path: impl AsRef<Path>) -> Result<(), crate::Error> {
+    let path = path.as_ref();
+    let signature = std::fs::read_to_string(signature_path(path))
+        .map_err(|_| crate::Error::SidecarSignature("signature not found".to_string()))?;
+
+    verify(&std::fs::read(path)?, &signature)
+}
+
+// Appended rather than `with_extension`, which would replace the version's last component.
+fn signature_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(SIGNATURE_EXTENSION);
+    PathBuf::from(name)
+}
+
+fn verify(binary: &[u8], signature: &str) -> Result<(), crate::Error> {
+    let public_key =
+        PUBLIC_KEY.ok_or_else(|| crate::Error::SidecarSignature("no public key".to_string()))?;
+
+    let public_key = minisign_verify::PublicKey::from_base64(public_key)
+        .map_err(|e| crate::Error::SidecarSignature(e.to_string()))?;
+    let signature = minisign_verify::Signature::decode(signature)
+        .map_err(|e| crate::Error::SidecarSignature(e.to_string()))?;
+
+    public_key
+        .verify(binary, &signature, false)
+        .map_err(|e| crate::Error::SidecarSignature(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("0.3.0"), Some((0, 3, 0)));
+        assert_eq!(parse_version("v1.2"), Some((1, 2, 0)));
+        assert_eq!(parse_version("1.2.3-beta.1"), Some((1, 2, 3)));
+        assert_eq!(parse_version("1.2.3.download"), None);
+        assert_eq!(parse_version("unknown"), None);
+    }
+
+    #[test]
+    fn test_is_compatible() {
+        assert!(is_compatible("0.3.0"));
+        assert!(is_compatible("0.10.0"));
+        assert!(is_compatible("1.0.0"));
+        assert!(!is_compatible("0.2.9"));
+        assert!(!is_compatible(""));
+    }
+
+    #[test]
+    fn test_installed_sidecar() {
+        let dir = std::env::temp_dir().join(format!("am-sidecar-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "stt-0.2.0",
+            "stt-0.3.1",
+            "stt-0.10.0",
+            "stt-0.10.0.minisig",
+            "stt-0.11.0.download",
+        ] {
+            std::fs::write(dir.join(name), b"").unwrap();
+        }
+
+        assert_eq!(installed_sidecar(&dir), Some(dir.join("stt-0.10.0")));
+        assert_eq!(
+            signature_path(&dir.join("stt-0.10.0")),
+            dir.join("stt-0.10.0.minisig")
+        );
+        assert!(matches!(
+            verify_sidecar(dir.join("stt-0.3.1")),
+            Err(crate::Error::SidecarSignature(_))
+        ));
+        assert!(matches!(
+            verify_sidecar(dir.join("stt-0.10.0")),
+            Err(crate::Error::SidecarSignature(_))
+        ));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
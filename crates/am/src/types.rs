@@ -15,6 +15,15 @@ common_derives! {
         pub verbose: bool,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub message: Option<String>,
+        /// Audio chunks waiting to be transcribed.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub queue_depth: Option<u32>,
+        /// Processing time over audio duration, averaged over recent chunks. Above 1.0 the
+        /// server is falling behind.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub real_time_factor: Option<f64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub memory_bytes: Option<u64>,
     }
 }
 
@@ -66,6 +75,12 @@ common_derives! {
     }
 }
 
+common_derives! {
+    pub struct VersionResponse {
+        pub version: String,
+    }
+}
+
 common_derives! {
     pub struct GenericResponse {
         pub status: String,
@@ -5,16 +5,12 @@ pub use error::*;
 
 #[derive(Clone)]
 pub struct AnalyticsClient {
-    client: reqwest::Client,
     api_key: String,
 }
 
 impl AnalyticsClient {
     pub fn new(api_key: impl Into<String>) -> Self {
-        let client = reqwest::Client::new();
-
         Self {
-            client,
             api_key: api_key.into(),
         }
     }
@@ -34,8 +30,7 @@ impl AnalyticsClient {
         let inner_event = posthog_core::event::InnerEvent::new(e, self.api_key.clone());
 
         if !cfg!(debug_assertions) {
-            let _ = self
-                .client
+            let _ = hypr_proxy::client()
                 .post("https://us.i.posthog.com/i/v0/e/")
                 .json(&inner_event)
                 .send()
@@ -67,8 +62,7 @@ impl AnalyticsClient {
         let inner_event = posthog_core::event::InnerEvent::new(e, self.api_key.clone());
 
         if !cfg!(debug_assertions) {
-            let _ = self
-                .client
+            let _ = hypr_proxy::client()
                 .post("https://us.i.posthog.com/i/v0/e/")
                 .json(&inner_event)
                 .send()
@@ -87,8 +81,7 @@ impl AnalyticsClient {
     pub async fn event2(&self, user_id: impl Into<String>) -> Result<(), Error> {
         let payload = serde_json::json!({ "user_id": user_id.into() });
         if !cfg!(debug_assertions) {
-            let _ = self
-                .client
+            let _ = hypr_proxy::client()
                 .post("https://us.i.posthog.com/i/v0/e/")
                 .query(&payload)
                 .send()
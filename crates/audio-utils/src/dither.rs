@@ -0,0 +1,77 @@
+use crate::I16_SCALE;
+
+/// How to treat the rounding error when converting float samples to 16-bit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Dither {
+    /// Truncate. Cheapest, and what speech models are trained on.
+    #[default]
+    None,
+    /// Add triangular (TPDF) noise of one LSB, so quiet passages don't pick up
+    /// quantization distortion that correlates with the signal.
+    Triangular,
+}
+
+/// Converts float samples to 16-bit, carrying its noise generator across calls so chunked
+/// streams dither the same as one long buffer.
+pub struct Quantizer {
+    dither: Dither,
+    state: u32,
+}
+
+impl Quantizer {
+    pub fn new(dither: Dither) -> Self {
+        Self {
+            dither,
+            state: 0x9E37_79B9,
+        }
+    }
+
+    pub fn quantize(&mut self, sample: f32) -> i16 {
+        let scaled = match self.dither {
+            Dither::None => sample * I16_SCALE,
+            Dither::Triangular => (sample * I16_SCALE + self.uniform() - self.uniform()).round(),
+        };
+
+        scaled.clamp(-I16_SCALE, I16_SCALE - 1.0) as i16
+    }
+
+    // xorshift32, mapped to [0, 1). Plenty for dither and cheap enough per sample.
+    fn uniform(&mut self) -> f32 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 17;
+        self.state ^= self.state << 5;
+        (self.state >> 8) as f32 / (1u32 << 24) as f32
+    }
+}
+
+pub fn quantize_i16(samples: &[f32], dither: Dither) -> Vec<i16> {
+    let mut quantizer = Quantizer::new(dither);
+    samples.iter().map(|&s| quantizer.quantize(s)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_dither_matches_truncation() {
+        let samples = [0.0, 0.5, -0.5, 1.0, -1.0, 0.00001];
+        assert_eq!(
+            quantize_i16(&samples, Dither::None),
+            vec![0, 16384, -16384, i16::MAX, i16::MIN, 0]
+        );
+    }
+
+    #[test]
+    fn test_triangular_dither_is_unbiased() {
+        let samples = vec![0.25; 48000];
+        let quantized = quantize_i16(&samples, Dither::Triangular);
+
+        assert!(quantized.iter().all(|&s| (8191..=8193).contains(&s)));
+        assert!(quantized.iter().any(|&s| s != 8192));
+
+        let mean = quantized.iter().map(|&s| s as f64).sum::<f64>() / quantized.len() as f64;
+        assert!((mean - 8192.0).abs() < 0.05, "mean {mean}");
+    }
+}
@@ -6,4 +6,10 @@ pub enum Error {
     ResamplerConstructionError(#[from] rubato::ResamplerConstructionError),
     #[error(transparent)]
     DecoderError(#[from] rodio::decoder::DecoderError),
+    #[error(transparent)]
+    WavError(#[from] hound::Error),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error("invalid peaks file")]
+    InvalidPeaksFile,
 }
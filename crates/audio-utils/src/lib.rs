@@ -2,8 +2,17 @@ use bytes::{BufMut, Bytes, BytesMut};
 use futures_util::{Stream, StreamExt};
 use kalosm_sound::AsyncSource;
 
+mod dither;
 mod error;
+mod mix;
+mod peaks;
+mod resample;
+
+pub use dither::*;
 pub use error::*;
+pub use mix::*;
+pub use peaks::*;
+pub use resample::*;
 
 const I16_SCALE: f32 = 32768.0;
 
@@ -18,16 +27,29 @@ pub trait AudioFormatExt: AsyncSource {
     where
         Self: Sized + Send + Unpin + 'static,
     {
-        self.resample(sample_rate).chunks(chunk_size).map(|chunk| {
-            let n = std::mem::size_of::<f32>() * chunk.len();
-
-            let mut buf = BytesMut::with_capacity(n);
-            for sample in chunk {
-                let scaled = (sample * I16_SCALE).clamp(-I16_SCALE, I16_SCALE);
-                buf.put_i16_le(scaled as i16);
-            }
-            buf.freeze()
-        })
+        self.to_i16_le_chunks_with(sample_rate, chunk_size, Dither::None)
+    }
+
+    fn to_i16_le_chunks_with(
+        self,
+        sample_rate: u32,
+        chunk_size: usize,
+        dither: Dither,
+    ) -> impl Stream<Item = Bytes> + Send + Unpin
+    where
+        Self: Sized + Send + Unpin + 'static,
+    {
+        let mut quantizer = Quantizer::new(dither);
+
+        ResampledAsyncSource::new(self, sample_rate)
+            .chunks(chunk_size)
+            .map(move |chunk| {
+                let mut buf = BytesMut::with_capacity(std::mem::size_of::<i16>() * chunk.len());
+                for sample in chunk {
+                    buf.put_i16_le(quantizer.quantize(sample));
+                }
+                buf.freeze()
+            })
     }
 }
 
@@ -39,23 +61,18 @@ pub fn i16_to_f32_samples(samples: &[i16]) -> Vec<f32> {
 }
 
 pub fn f32_to_i16_samples(samples: &[f32]) -> Vec<i16> {
-    samples
-        .iter()
-        .map(|&sample| {
-            let scaled = (sample * I16_SCALE).clamp(-I16_SCALE, I16_SCALE);
-            scaled as i16
-        })
-        .collect()
+    quantize_i16(samples, Dither::None)
 }
 
 pub fn f32_to_i16_bytes<I>(samples: I) -> Bytes
 where
     I: Iterator<Item = f32>,
 {
+    let mut quantizer = Quantizer::new(Dither::None);
+
     let mut buf = BytesMut::new();
     for sample in samples {
-        let i16_sample = (sample * I16_SCALE).clamp(-I16_SCALE, I16_SCALE) as i16;
-        buf.put_i16_le(i16_sample);
+        buf.put_i16_le(quantizer.quantize(sample));
     }
     buf.freeze()
 }
@@ -78,53 +95,17 @@ pub fn source_from_path(
     Ok(decoder)
 }
 
-pub fn resample_audio<S, T>(source: S, to_rate: u32) -> Result<Vec<f32>, crate::Error>
-where
-    S: rodio::Source<Item = T> + Iterator<Item = T>,
-    T: rodio::Sample,
-{
-    use rubato::{
-        Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction,
-    };
-
-    let from_rate = source.sample_rate() as f64;
-    let channels = source.channels() as usize;
-    let to_rate_f64 = to_rate as f64;
-
-    let samples: Vec<f32> = source.map(|sample| sample.to_f32()).collect();
-
-    if (from_rate - to_rate_f64).abs() < 1.0 {
-        return Ok(samples);
-    }
-
-    let params = SincInterpolationParameters {
-        sinc_len: 256,
-        f_cutoff: 0.95,
-        interpolation: SincInterpolationType::Linear,
-        oversampling_factor: 256,
-        window: WindowFunction::BlackmanHarris2,
-    };
+/// Decodes an audio file held in memory (WAV, MP3, FLAC, ...) into one buffer per channel,
+/// resampled to `to_rate`.
+pub fn decode_channels(
+    data: impl AsRef<[u8]> + Send + Sync + 'static,
+    to_rate: u32,
+) -> Result<Vec<Vec<f32>>, crate::Error> {
+    use rodio::Source;
 
-    let mut resampler =
-        SincFixedIn::<f32>::new(to_rate_f64 / from_rate, 2.0, params, 1024, channels)?;
-
-    let frames_per_channel = samples.len() / channels;
-    let mut input_channels: Vec<Vec<f32>> = vec![Vec::with_capacity(frames_per_channel); channels];
-
-    for (i, &sample) in samples.iter().enumerate() {
-        input_channels[i % channels].push(sample);
-    }
-
-    let output_channels = resampler.process(&input_channels, None)?;
-
-    let mut output = Vec::new();
-    let output_frames = output_channels[0].len();
-
-    for frame in 0..output_frames {
-        for ch in 0..channels {
-            output.push(output_channels[ch][frame]);
-        }
-    }
+    let source = rodio::Decoder::new(std::io::Cursor::new(data))?;
+    let channels = (source.channels() as usize).max(1);
+    let samples = resample_audio(source, to_rate)?;
 
-    Ok(output)
+    Ok(deinterleave(&samples, channels))
 }
@@ -0,0 +1,138 @@
+use crate::I16_SCALE;
+
+fn i16_le_at(audio: &[u8], i: usize) -> i16 {
+    audio
+        .get(i..i + 2)
+        .map_or(0, |bytes| i16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// Interleaves two mono 16-bit little-endian streams into one stereo stream, `mic` on the
+/// first channel. The shorter one is padded with silence.
+pub fn interleave_i16_le(mic: &[u8], speaker: &[u8]) -> Vec<u8> {
+    let len = mic.len().max(speaker.len()) / 2 * 2;
+    let mut interleaved = Vec::with_capacity(len * 2);
+
+    for i in (0..len).step_by(2) {
+        interleaved.extend_from_slice(&i16_le_at(mic, i).to_le_bytes());
+        interleaved.extend_from_slice(&i16_le_at(speaker, i).to_le_bytes());
+    }
+
+    interleaved
+}
+
+/// Mixes two mono 16-bit little-endian streams into one, averaged so the sum can't clip.
+pub fn mix_i16_le(mic: &[u8], speaker: &[u8]) -> Vec<u8> {
+    let len = mic.len().max(speaker.len()) / 2 * 2;
+    let mut mixed = Vec::with_capacity(len);
+
+    for i in (0..len).step_by(2) {
+        let sample = (i16_le_at(mic, i) as i32 + i16_le_at(speaker, i) as i32) / 2;
+        mixed.extend_from_slice(&(sample as i16).to_le_bytes());
+    }
+
+    mixed
+}
+
+/// Splits a stereo 16-bit little-endian stream into its two channels.
+pub fn deinterleave_i16_le(data: &[u8]) -> (Vec<f32>, Vec<f32>) {
+    let mut mic = Vec::with_capacity(data.len() / 4);
+    let mut speaker = Vec::with_capacity(data.len() / 4);
+
+    for frame in data.chunks_exact(4) {
+        mic.push(i16::from_le_bytes([frame[0], frame[1]]) as f32 / I16_SCALE);
+        speaker.push(i16::from_le_bytes([frame[2], frame[3]]) as f32 / I16_SCALE);
+    }
+
+    (mic, speaker)
+}
+
+/// Mixes mic and speaker audio by summing them. One side is mostly silent while the other
+/// talks, so averaging would only make both quieter.
+pub fn mix_f32(mic: &[f32], speaker: &[f32]) -> Vec<f32> {
+    let len = mic.len().max(speaker.len());
+
+    (0..len)
+        .map(|i| {
+            let mic_sample = mic.get(i).copied().unwrap_or(0.0);
+            let speaker_sample = speaker.get(i).copied().unwrap_or(0.0);
+            (mic_sample + speaker_sample).clamp(-1.0, 1.0)
+        })
+        .collect()
+}
+
+/// Averages any number of channels into one, as when folding a stereo file down to mono.
+pub fn downmix(channels: &[Vec<f32>]) -> Vec<f32> {
+    let len = channels.iter().map(Vec::len).max().unwrap_or(0);
+
+    (0..len)
+        .map(|i| {
+            channels
+                .iter()
+                .map(|channel| channel.get(i).copied().unwrap_or(0.0))
+                .sum::<f32>()
+                / channels.len() as f32
+        })
+        .collect()
+}
+
+/// Splits interleaved samples into one buffer per channel.
+pub fn deinterleave(samples: &[f32], channels: usize) -> Vec<Vec<f32>> {
+    let channels = channels.max(1);
+    let mut output = vec![Vec::with_capacity(samples.len() / channels); channels];
+
+    for (i, sample) in samples.iter().enumerate() {
+        output[i % channels].push(*sample);
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interleave_i16_le() {
+        let mic = vec![1, 0, 2, 0];
+        let speaker = vec![3, 0];
+        assert_eq!(
+            interleave_i16_le(&mic, &speaker),
+            vec![1, 0, 3, 0, 2, 0, 0, 0]
+        );
+        assert_eq!(interleave_i16_le(&mic, &[]), vec![1, 0, 0, 0, 2, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_mix_i16_le() {
+        let mic = 100i16.to_le_bytes().to_vec();
+        let speaker = (-50i16).to_le_bytes().to_vec();
+        assert_eq!(mix_i16_le(&mic, &speaker), 25i16.to_le_bytes().to_vec());
+
+        let loud = i16::MAX.to_le_bytes().to_vec();
+        assert_eq!(mix_i16_le(&loud, &loud), loud);
+    }
+
+    #[test]
+    fn test_deinterleave_i16_le() {
+        let data = interleave_i16_le(&16384i16.to_le_bytes(), &(-16384i16).to_le_bytes());
+        assert_eq!(deinterleave_i16_le(&data), (vec![0.5], vec![-0.5]));
+    }
+
+    #[test]
+    fn test_mix_f32() {
+        assert_eq!(mix_f32(&[0.5, 0.75], &[0.25]), vec![0.75, 0.75]);
+        assert_eq!(mix_f32(&[0.75], &[0.75]), vec![1.0]);
+    }
+
+    #[test]
+    fn test_downmix() {
+        assert_eq!(downmix(&[vec![1.0, 0.5], vec![0.0]]), vec![0.5, 0.25]);
+        assert!(downmix(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_deinterleave() {
+        let channels = deinterleave(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2);
+        assert_eq!(channels, vec![vec![1.0, 3.0, 5.0], vec![2.0, 4.0, 6.0]]);
+    }
+}
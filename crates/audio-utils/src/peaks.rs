@@ -0,0 +1,193 @@
+use std::{
+    io::{BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+};
+
+const MAGIC: &[u8; 4] = b"HPK1";
+const WINDOWS_PER_SECOND: u32 = 20;
+
+/// Min/max of every 50ms of a recording, quantized to `i8`. An hour takes about 140KB,
+/// little enough to hand to the webview and downsample to whatever width is drawn.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct AudioPeaks {
+    pub sample_rate: u32,
+    /// Samples per window.
+    pub window_size: u32,
+    pub duration_ms: u64,
+    pub min: Vec<i8>,
+    pub max: Vec<i8>,
+}
+
+/// `audio.wav` gets `audio.peaks` next to it.
+pub fn peaks_path(audio_path: impl AsRef<Path>) -> PathBuf {
+    audio_path.as_ref().with_extension("peaks")
+}
+
+pub fn compute_peaks(audio_path: impl AsRef<Path>) -> Result<AudioPeaks, crate::Error> {
+    let reader = hound::WavReader::open(audio_path)?;
+    let spec = reader.spec();
+    let channels = spec.channels.max(1) as usize;
+    let window_size = (spec.sample_rate / WINDOWS_PER_SECOND).max(1);
+
+    let samples: Box<dyn Iterator<Item = Result<f32, hound::Error>>> = match spec.sample_format {
+        hound::SampleFormat::Float => Box::new(reader.into_samples::<f32>()),
+        hound::SampleFormat::Int => {
+            let scale = (1_i64 << (spec.bits_per_sample - 1)) as f32;
+            Box::new(
+                reader
+                    .into_samples::<i32>()
+                    .map(move |s| s.map(|s| s as f32 / scale)),
+            )
+        }
+    };
+
+    let mut peaks = AudioPeaks {
+        sample_rate: spec.sample_rate,
+        window_size,
+        duration_ms: 0,
+        min: vec![],
+        max: vec![],
+    };
+
+    let (mut lo, mut hi) = (0.0_f32, 0.0_f32);
+    let (mut frame_sum, mut frame_len) = (0.0_f32, 0);
+    let (mut window_len, mut frames) = (0, 0_u64);
+
+    for sample in samples {
+        frame_sum += sample?;
+        frame_len += 1;
+        if frame_len < channels {
+            continue;
+        }
+
+        // Channels are averaged, as they are played back together.
+        let value = frame_sum / channels as f32;
+        (frame_sum, frame_len) = (0.0, 0);
+
+        lo = lo.min(value);
+        hi = hi.max(value);
+        window_len += 1;
+        frames += 1;
+
+        if window_len == window_size {
+            peaks.push(lo, hi);
+            (lo, hi, window_len) = (0.0, 0.0, 0);
+        }
+    }
+
+    if window_len > 0 {
+        peaks.push(lo, hi);
+    }
+    peaks.duration_ms = frames * 1000 / spec.sample_rate.max(1) as u64;
+
+    Ok(peaks)
+}
+
+/// Reads the peaks file of `audio_path`, computing and writing it first when it is
+/// missing or older than the audio, which keeps growing while recording resumes.
+pub fn load_or_compute_peaks(audio_path: impl AsRef<Path>) -> Result<AudioPeaks, crate::Error> {
+    let audio_path = audio_path.as_ref();
+    let path = peaks_path(audio_path);
+
+    let modified = |path: &Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+    if let (Some(peaks_modified), Some(audio_modified)) = (modified(&path), modified(audio_path)) {
+        if peaks_modified >= audio_modified {
+            if let Ok(peaks) = AudioPeaks::read(&path) {
+                return Ok(peaks);
+            }
+        }
+    }
+
+    let peaks = compute_peaks(audio_path)?;
+    peaks.write(&path)?;
+    Ok(peaks)
+}
+
+impl AudioPeaks {
+    fn push(&mut self, lo: f32, hi: f32) {
+        let quantize = |v: f32| (v.clamp(-1.0, 1.0) * i8::MAX as f32).round() as i8;
+        self.min.push(quantize(lo));
+        self.max.push(quantize(hi));
+    }
+
+    /// `HPK1`, then sample rate, window size and duration in little endian,
+    /// then a min and a max byte per window.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), crate::Error> {
+        let mut writer = BufWriter::new(std::fs::File::create(path)?);
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&self.sample_rate.to_le_bytes())?;
+        writer.write_all(&self.window_size.to_le_bytes())?;
+        writer.write_all(&self.duration_ms.to_le_bytes())?;
+        for (lo, hi) in self.min.iter().zip(&self.max) {
+            writer.write_all(&[*lo as u8, *hi as u8])?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, crate::Error> {
+        let mut reader = BufReader::new(std::fs::File::open(path)?);
+
+        let mut header = [0u8; 20];
+        reader.read_exact(&mut header)?;
+        if &header[..4] != MAGIC {
+            return Err(crate::Error::InvalidPeaksFile);
+        }
+
+        let mut body = vec![];
+        reader.read_to_end(&mut body)?;
+        if body.len() % 2 != 0 {
+            return Err(crate::Error::InvalidPeaksFile);
+        }
+
+        Ok(Self {
+            sample_rate: u32::from_le_bytes(header[4..8].try_into().unwrap()),
+            window_size: u32::from_le_bytes(header[8..12].try_into().unwrap()),
+            duration_ms: u64::from_le_bytes(header[12..20].try_into().unwrap()),
+            min: body.iter().step_by(2).map(|b| *b as i8).collect(),
+            max: body.iter().skip(1).step_by(2).map(|b| *b as i8).collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peaks() {
+        let dir = tempfile::tempdir().unwrap();
+        let audio_path = dir.path().join("audio.wav");
+
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 16000,
+            bits_per_sample: 32,
+            sample_format: hound::SampleFormat::Float,
+        };
+        let mut writer = hound::WavWriter::create(&audio_path, spec).unwrap();
+        // 50ms of silence, then 50ms swinging between -0.5 and 1.0, then a partial window.
+        for i in 0..2000 {
+            let sample = match i {
+                0..800 => 0.0,
+                800..1600 if i % 2 == 0 => -0.5,
+                800..1600 => 1.0,
+                _ => 0.25,
+            };
+            writer.write_sample(sample as f32).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let peaks = load_or_compute_peaks(&audio_path).unwrap();
+        assert_eq!(peaks.window_size, 800);
+        assert_eq!(peaks.duration_ms, 125);
+        assert_eq!(peaks.min, vec![0, -64, 0]);
+        assert_eq!(peaks.max, vec![0, 127, 32]);
+
+        let path = peaks_path(&audio_path);
+        assert_eq!(path, dir.path().join("audio.peaks"));
+        assert_eq!(AudioPeaks::read(&path).unwrap(), peaks);
+    }
+}
@@ -0,0 +1,429 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_util::Stream;
+use kalosm_sound::AsyncSource;
+use rubato::{
+    Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction,
+};
+
+// Input frames per resampler pass. Small enough to keep live audio responsive.
+const STREAM_CHUNK_FRAMES: usize = 512;
+const BATCH_CHUNK_FRAMES: usize = 1024;
+
+/// Band-limited sinc interpolation. Rubato vectorizes the inner loop (AVX/SSE on x86, Neon on
+/// ARM), so this stays cheap enough for live audio.
+fn sinc_resampler(
+    from_rate: u32,
+    to_rate: u32,
+    chunk_frames: usize,
+    channels: usize,
+) -> Result<SincFixedIn<f32>, crate::Error> {
+    let params = SincInterpolationParameters {
+        sinc_len: 256,
+        f_cutoff: 0.95,
+        interpolation: SincInterpolationType::Linear,
+        oversampling_factor: 256,
+        window: WindowFunction::BlackmanHarris2,
+    };
+
+    Ok(SincFixedIn::<f32>::new(
+        to_rate as f64 / from_rate as f64,
+        2.0,
+        params,
+        chunk_frames,
+        channels,
+    )?)
+}
+
+/// Feeds planar input through a resampler chunk by chunk, then flushes what it still holds and
+/// trims the leading delay it adds, so the output lines up with the input.
+struct Chunker {
+    resampler: SincFixedIn<f32>,
+    ratio: f64,
+    // Output frames still to drop for the resampler's delay.
+    delay: usize,
+    frames_in: usize,
+    frames_out: usize,
+}
+
+impl Chunker {
+    fn new(
+        from_rate: u32,
+        to_rate: u32,
+        chunk_frames: usize,
+        channels: usize,
+    ) -> Result<Self, crate::Error> {
+        let resampler = sinc_resampler(from_rate, to_rate, chunk_frames, channels)?;
+
+        Ok(Self {
+            delay: resampler.output_delay(),
+            resampler,
+            ratio: to_rate as f64 / from_rate as f64,
+            frames_in: 0,
+            frames_out: 0,
+        })
+    }
+
+    fn frames_needed(&self) -> usize {
+        self.resampler.input_frames_next()
+    }
+
+    /// `input` holds exactly [`Self::frames_needed`] frames per channel.
+    fn process(&mut self, input: &[Vec<f32>]) -> Result<Vec<Vec<f32>>, crate::Error> {
+        self.frames_in += input[0].len();
+        let output = self.resampler.process(input, None)?;
+        Ok(self.trim(output))
+    }
+
+    /// Resamples a last, possibly short, chunk and drains the resampler.
+    fn finish(&mut self, input: &[Vec<f32>]) -> Result<Vec<Vec<f32>>, crate::Error> {
+        self.frames_in += input[0].len();
+
+        let mut output = if input[0].is_empty() {
+            vec![Vec::new(); input.len()]
+        } else {
+            let output = self.resampler.process_partial(Some(input), None)?;
+            self.trim(output)
+        };
+
+        let tail = self.resampler.process_partial::<Vec<f32>>(None, None)?;
+        for (channel, tail) in output.iter_mut().zip(self.trim(tail)) {
+            channel.extend(tail);
+        }
+
+        let expected = (self.frames_in as f64 * self.ratio).round() as usize;
+        let excess = self.frames_out.saturating_sub(expected);
+        for channel in output.iter_mut() {
+            channel.truncate(channel.len().saturating_sub(excess));
+        }
+        self.frames_out -= excess.min(self.frames_out);
+
+        Ok(output)
+    }
+
+    fn trim(&mut self, mut output: Vec<Vec<f32>>) -> Vec<Vec<f32>> {
+        let skip = self.delay.min(output[0].len());
+        self.delay -= skip;
+
+        for channel in output.iter_mut() {
+            channel.drain(..skip);
+        }
+        self.frames_out += output[0].len();
+        output
+    }
+}
+
+/// Resamples a whole source at once, keeping its channels interleaved.
+pub fn resample_audio<S, T>(source: S, to_rate: u32) -> Result<Vec<f32>, crate::Error>
+where
+    S: rodio::Source<Item = T> + Iterator<Item = T>,
+    T: rodio::Sample,
+{
+    let from_rate = source.sample_rate();
+    let channels = (source.channels() as usize).max(1);
+
+    let samples: Vec<f32> = source.map(|sample| sample.to_f32()).collect();
+
+    if from_rate == to_rate {
+        return Ok(samples);
+    }
+
+    let input = crate::deinterleave(&samples, channels);
+    let frames = input[0].len();
+
+    let mut chunker = Chunker::new(from_rate, to_rate, BATCH_CHUNK_FRAMES, channels)?;
+    let mut output = vec![Vec::new(); channels];
+    let mut pos = 0;
+
+    loop {
+        let needed = chunker.frames_needed();
+        let end = (pos + needed).min(frames);
+        let chunk: Vec<Vec<f32>> = input.iter().map(|c| c[pos..end].to_vec()).collect();
+
+        let out = if end - pos == needed {
+            chunker.process(&chunk)?
+        } else {
+            chunker.finish(&chunk)?
+        };
+        for (channel, out) in output.iter_mut().zip(out) {
+            channel.extend(out);
+        }
+
+        if end - pos < needed {
+            break;
+        }
+        pos = end;
+    }
+
+    let mut interleaved = Vec::with_capacity(output[0].len() * channels);
+    for frame in 0..output[0].len() {
+        for channel in &output {
+            interleaved.push(channel[frame]);
+        }
+    }
+
+    Ok(interleaved)
+}
+
+/// Resamples a mono [`AsyncSource`] to `target_sample_rate` as it streams. If the source
+/// changes rate mid-stream (a different input device, say), what was buffered at the old
+/// rate is flushed and resampling picks up at the new one.
+pub struct ResampledAsyncSource<S: AsyncSource> {
+    source: S,
+    target_sample_rate: u32,
+    chunker: Option<(u32, Chunker)>,
+    input: Vec<f32>,
+    output: VecDeque<f32>,
+    ended: bool,
+}
+
+impl<S: AsyncSource> ResampledAsyncSource<S> {
+    pub fn new(source: S, target_sample_rate: u32) -> Self {
+        Self {
+            source,
+            target_sample_rate,
+            chunker: None,
+            input: Vec::with_capacity(STREAM_CHUNK_FRAMES),
+            output: VecDeque::new(),
+            ended: false,
+        }
+    }
+
+    fn flush(&mut self) {
+        let input = std::mem::take(&mut self.input);
+
+        if let Some((_, mut chunker)) = self.chunker.take() {
+            match chunker.finish(&[input]) {
+                Ok(out) => self.output.extend(out.into_iter().flatten()),
+                Err(e) => tracing::warn!("resample_flush_failed: {}", e),
+            }
+        }
+    }
+}
+
+impl<S: AsyncSource + Unpin> Stream for ResampledAsyncSource<S> {
+    type Item = f32;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(sample) = this.output.pop_front() {
+                return Poll::Ready(Some(sample));
+            }
+            if this.ended {
+                return Poll::Ready(None);
+            }
+
+            let source_rate = this.source.sample_rate();
+
+            if matches!(&this.chunker, Some((rate, _)) if *rate != source_rate) {
+                this.flush();
+                continue;
+            }
+
+            if this.chunker.is_none() {
+                if source_rate == this.target_sample_rate {
+                    let mut stream = std::pin::pin!(this.source.as_stream());
+                    return stream.as_mut().poll_next(cx);
+                }
+
+                match Chunker::new(source_rate, this.target_sample_rate, STREAM_CHUNK_FRAMES, 1) {
+                    Ok(chunker) => this.chunker = Some((source_rate, chunker)),
+                    Err(e) => {
+                        tracing::error!("resampler_init_failed: {}", e);
+                        return Poll::Ready(None);
+                    }
+                }
+            }
+
+            let Some((_, chunker)) = this.chunker.as_mut() else {
+                continue;
+            };
+            let needed = chunker.frames_needed();
+
+            let source_ended = {
+                let mut stream = std::pin::pin!(this.source.as_stream());
+                loop {
+                    if this.input.len() >= needed {
+                        break false;
+                    }
+                    match stream.as_mut().poll_next(cx) {
+                        Poll::Ready(Some(sample)) => this.input.push(sample),
+                        Poll::Ready(None) => break true,
+                        Poll::Pending => return Poll::Pending,
+                    }
+                }
+            };
+
+            if source_ended {
+                this.flush();
+                this.ended = true;
+                continue;
+            }
+
+            let input = std::mem::take(&mut this.input);
+            match chunker.process(&[input]) {
+                Ok(out) => this.output.extend(out.into_iter().flatten()),
+                Err(e) => {
+                    tracing::error!("resample_failed: {}", e);
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+}
+
+impl<S: AsyncSource + Unpin> AsyncSource for ResampledAsyncSource<S> {
+    fn as_stream(&mut self) -> impl Stream<Item = f32> + '_ {
+        self
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.target_sample_rate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures_util::StreamExt;
+    use rodio::Source;
+
+    const FIXTURES: [(&str, u32); 6] = [
+        (hypr_data::english_1::AUDIO_PART1_8000HZ_PATH, 8000),
+        (hypr_data::english_1::AUDIO_PART2_16000HZ_PATH, 16000),
+        (hypr_data::english_1::AUDIO_PART3_22050HZ_PATH, 22050),
+        (hypr_data::english_1::AUDIO_PART4_32000HZ_PATH, 32000),
+        (hypr_data::english_1::AUDIO_PART5_44100HZ_PATH, 44100),
+        (hypr_data::english_1::AUDIO_PART6_48000HZ_PATH, 48000),
+    ];
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len().max(1) as f32).sqrt()
+    }
+
+    fn mono(path: &str) -> (Vec<f32>, u32) {
+        let source = crate::source_from_path(path).unwrap();
+        let rate = rodio::Source::sample_rate(&source);
+        let channels = source.channels() as usize;
+        let samples = source.convert_samples::<f32>().collect::<Vec<_>>();
+        (
+            crate::downmix(&crate::deinterleave(&samples, channels)),
+            rate,
+        )
+    }
+
+    // Plays `segments` back to back, each at its own rate.
+    struct Segments {
+        segments: Vec<(Vec<f32>, u32)>,
+        current: usize,
+        position: usize,
+    }
+
+    impl AsyncSource for Segments {
+        fn as_stream(&mut self) -> impl Stream<Item = f32> + '_ {
+            futures_util::stream::poll_fn(move |_| {
+                while let Some((samples, _)) = self.segments.get(self.current) {
+                    if let Some(sample) = samples.get(self.position) {
+                        self.position += 1;
+                        return Poll::Ready(Some(*sample));
+                    }
+                    self.current += 1;
+                    self.position = 0;
+                }
+                Poll::Ready(None)
+            })
+        }
+
+        fn sample_rate(&self) -> u32 {
+            self.segments
+                .get(self.current)
+                .or(self.segments.last())
+                .map_or(16000, |(_, rate)| *rate)
+        }
+    }
+
+    #[test]
+    fn test_resample_audio_fixtures() {
+        for (path, rate) in FIXTURES {
+            let source = crate::source_from_path(path).unwrap();
+            assert_eq!(rodio::Source::sample_rate(&source), rate);
+            let channels = source.channels() as usize;
+
+            let (input, _) = mono(path);
+            let output = crate::downmix(&crate::deinterleave(
+                &resample_audio(source, 16000).unwrap(),
+                channels,
+            ));
+
+            let expected = (input.len() as f64 * 16000.0 / rate as f64).round() as usize;
+            assert!(
+                output.len().abs_diff(expected) <= 1,
+                "{rate}Hz: {} samples, expected {expected}",
+                output.len()
+            );
+
+            let ratio = rms(&output) / rms(&input);
+            assert!((0.8..1.2).contains(&ratio), "{rate}Hz: rms ratio {ratio}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resampled_async_source_fixtures() {
+        for (path, rate) in FIXTURES {
+            let (input, _) = mono(path);
+
+            let source = Segments {
+                segments: vec![(input.clone(), rate)],
+                current: 0,
+                position: 0,
+            };
+            let output = ResampledAsyncSource::new(source, 16000)
+                .collect::<Vec<_>>()
+                .await;
+
+            let expected = (input.len() as f64 * 16000.0 / rate as f64).round() as usize;
+            assert!(
+                output.len().abs_diff(expected) <= 1,
+                "{rate}Hz: {} samples, expected {expected}",
+                output.len()
+            );
+
+            let ratio = rms(&output) / rms(&input);
+            assert!((0.8..1.2).contains(&ratio), "{rate}Hz: rms ratio {ratio}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resampled_async_source_rate_changes() {
+        let segments = FIXTURES
+            .iter()
+            .map(|(path, _)| mono(path))
+            .collect::<Vec<_>>();
+        let expected: usize = segments
+            .iter()
+            .map(|(samples, rate)| (samples.len() as f64 * 16000.0 / *rate as f64).round() as usize)
+            .sum();
+
+        let source = Segments {
+            segments,
+            current: 0,
+            position: 0,
+        };
+        let output = ResampledAsyncSource::new(source, 16000)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert!(
+            output.len().abs_diff(expected) <= FIXTURES.len(),
+            "{} samples, expected {expected}",
+            output.len()
+        );
+    }
+}
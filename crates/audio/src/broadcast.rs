@@ -0,0 +1,221 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use futures_util::Stream;
+
+/// A bounded ring of audio chunks that any number of consumers read from at their own pace.
+///
+/// One capture pipeline pushes into it and every consumer (transcription, recording, meters)
+/// holds an [`AudioTap`], so they all see the same chunks in the same order without copying
+/// them. A consumer that falls more than `capacity` chunks behind skips ahead instead of
+/// holding the others back; [`AudioTap::skipped`] says how much it missed.
+///
+/// Handles are cheap to clone. Taps end once every handle is dropped and they have read
+/// what is left.
+pub struct AudioBroadcast<T> {
+    shared: Arc<Mutex<Ring<T>>>,
+}
+
+struct Ring<T> {
+    chunks: VecDeque<T>,
+    // Sequence number of `chunks[0]`.
+    first: u64,
+    capacity: usize,
+    senders: usize,
+    wakers: Vec<Waker>,
+}
+
+impl<T> Ring<T> {
+    fn end(&self) -> u64 {
+        self.first + self.chunks.len() as u64
+    }
+
+    fn wake_all(&mut self) {
+        for waker in self.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+impl<T: Clone> AudioBroadcast<T> {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+
+        Self {
+            shared: Arc::new(Mutex::new(Ring {
+                chunks: VecDeque::with_capacity(capacity),
+                first: 0,
+                capacity,
+                senders: 1,
+                wakers: Vec::new(),
+            })),
+        }
+    }
+
+    pub fn push(&self, chunk: T) {
+        let mut ring = self.shared.lock().unwrap();
+
+        if ring.chunks.len() == ring.capacity {
+            ring.chunks.pop_front();
+            ring.first += 1;
+        }
+        ring.chunks.push_back(chunk);
+        ring.wake_all();
+    }
+
+    /// A new consumer, starting with the next chunk pushed.
+    pub fn tap(&self) -> AudioTap<T> {
+        let next = self.shared.lock().unwrap().end();
+
+        AudioTap {
+            shared: self.shared.clone(),
+            next,
+            skipped: 0,
+        }
+    }
+}
+
+impl<T> Clone for AudioBroadcast<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().unwrap().senders += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for AudioBroadcast<T> {
+    fn drop(&mut self) {
+        let mut ring = self.shared.lock().unwrap();
+        ring.senders -= 1;
+        if ring.senders == 0 {
+            ring.wake_all();
+        }
+    }
+}
+
+/// One consumer's position in an [`AudioBroadcast`]. Cloning it tees the stream: the clone
+/// continues from the same position, independently.
+pub struct AudioTap<T> {
+    shared: Arc<Mutex<Ring<T>>>,
+    next: u64,
+    skipped: u64,
+}
+
+impl<T: Clone> AudioTap<T> {
+    /// The next chunk, if one is already there.
+    pub fn try_next(&mut self) -> Option<T> {
+        let ring = self.shared.lock().unwrap();
+        take(&ring, &mut self.next, &mut self.skipped)
+    }
+
+    /// Chunks dropped so far because this tap fell too far behind.
+    pub fn skipped(&self) -> u64 {
+        self.skipped
+    }
+}
+
+fn take<T: Clone>(ring: &Ring<T>, next: &mut u64, skipped: &mut u64) -> Option<T> {
+    if *next < ring.first {
+        *skipped += ring.first - *next;
+        *next = ring.first;
+    }
+
+    let chunk = ring.chunks.get((*next - ring.first) as usize)?.clone();
+    *next += 1;
+    Some(chunk)
+}
+
+impl<T> Clone for AudioTap<T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+            next: self.next,
+            skipped: self.skipped,
+        }
+    }
+}
+
+impl<T: Clone> Stream for AudioTap<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let mut ring = this.shared.lock().unwrap();
+
+        if let Some(chunk) = take(&ring, &mut this.next, &mut this.skipped) {
+            return Poll::Ready(Some(chunk));
+        }
+        if ring.senders == 0 {
+            return Poll::Ready(None);
+        }
+
+        if !ring.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            ring.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures_util::StreamExt;
+
+    #[test]
+    fn test_taps_see_the_same_chunks() {
+        let broadcast = AudioBroadcast::new(4);
+        let mut early = broadcast.tap();
+
+        broadcast.push(1);
+        let mut late = broadcast.tap();
+        broadcast.push(2);
+
+        assert_eq!(early.try_next(), Some(1));
+        let mut tee = early.clone();
+        assert_eq!(early.try_next(), Some(2));
+        assert_eq!(tee.try_next(), Some(2));
+        assert_eq!(late.try_next(), Some(2));
+        assert_eq!(late.try_next(), None);
+    }
+
+    #[test]
+    fn test_lagging_tap_skips_ahead() {
+        let broadcast = AudioBroadcast::new(2);
+        let mut tap = broadcast.tap();
+
+        for i in 0..5 {
+            broadcast.push(i);
+        }
+
+        assert_eq!(tap.try_next(), Some(3));
+        assert_eq!(tap.skipped(), 3);
+        assert_eq!(tap.try_next(), Some(4));
+        assert_eq!(tap.try_next(), None);
+    }
+
+    #[tokio::test]
+    async fn test_tap_stream_ends_with_senders() {
+        let broadcast = AudioBroadcast::new(8);
+        let tap = broadcast.tap();
+
+        let producer = {
+            let broadcast = broadcast.clone();
+            tokio::spawn(async move {
+                for i in 0..3 {
+                    broadcast.push(i);
+                    tokio::task::yield_now().await;
+                }
+            })
+        };
+        drop(broadcast);
+        producer.await.unwrap();
+
+        assert_eq!(tap.collect::<Vec<_>>().await, vec![0, 1, 2]);
+    }
+}
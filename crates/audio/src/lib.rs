@@ -1,19 +1,20 @@
+mod broadcast;
 mod device_monitor;
 mod errors;
 mod mic;
 mod norm;
-mod resampler;
 mod speaker;
 
+pub use broadcast::*;
 pub use device_monitor::*;
 pub use errors::*;
 pub use mic::*;
 pub use norm::*;
-pub use resampler::*;
 pub use speaker::*;
 
 pub use cpal;
 use cpal::traits::{DeviceTrait, HostTrait};
+pub use hypr_audio_utils::ResampledAsyncSource;
 
 use futures_util::Stream;
 pub use kalosm_sound::AsyncSource;
@@ -20,6 +20,27 @@ pub fn opinionated_md_to_md(text: impl AsRef<str>) -> Result<String, Error> {
     md_to_md(text)
 }
 
+pub fn plain_md_to_html(text: impl AsRef<str>) -> Result<String, Error> {
+    md_to_html(text.as_ref())
+}
+
+pub fn html_to_md(html: impl AsRef<str>) -> Result<String, Error> {
+    let md = html2md::parse_html(html.as_ref());
+
+    // html2md emits setext headings and `*` bullets. Round-trip through mdast to normalize them.
+    let ast = markdown::to_mdast(&md, &markdown::ParseOptions::default())
+        .map_err(|e| Error::MarkdownParseError(e.to_string()))?;
+
+    mdast_util_to_markdown::to_markdown_with_options(
+        &ast,
+        &mdast_util_to_markdown::Options {
+            bullet: '-',
+            ..Default::default()
+        },
+    )
+    .map_err(|e| Error::MarkdownRenderError(e.to_string()))
+}
+
 fn md_to_md(text: impl AsRef<str>) -> Result<String, Error> {
     let mut text = text.as_ref().to_string();
 
@@ -261,6 +282,20 @@ fn add_paragraphs_before_headings(node: &mut markdown::mdast::Node) {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_html_to_md() {
+        let input = "<h1>Hello</h1><p>World <strong>bold</strong></p><ul><li>a</li><li>b</li></ul>";
+
+        insta::assert_snapshot!(html_to_md(input).unwrap(), @r###"
+        # Hello
+
+        World **bold**
+
+        - a
+        - b
+        "###);
+    }
+
     #[test]
     fn test_md_to_md_1() {
         let input = r#"
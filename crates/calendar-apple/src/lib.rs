@@ -12,7 +12,7 @@ use objc2_event_kit::{
 use objc2_foundation::{NSArray, NSDate, NSError, NSString};
 
 use hypr_calendar_interface::{
-    Calendar, CalendarSource, Error, Event, EventFilter, Participant, Platform,
+    parse_meeting_link, Calendar, CalendarSource, Error, Event, EventFilter, Participant, Platform,
 };
 
 pub struct Handle {
@@ -162,7 +162,11 @@ impl Handle {
         //     })
         // };
 
-        Participant { name, email }
+        Participant {
+            name,
+            email,
+            is_organizer: false,
+        }
     }
 }
 
@@ -215,6 +219,7 @@ impl CalendarSource for Handle {
                 let id = unsafe { event.eventIdentifier() }.unwrap();
                 let title = unsafe { event.title() };
                 let note = unsafe { event.notes().unwrap_or_default() };
+                let location = unsafe { event.location().unwrap_or_default() };
                 let start_date = unsafe { event.startDate() };
                 let end_date = unsafe { event.endDate() };
 
@@ -232,6 +237,9 @@ impl CalendarSource for Handle {
                     has_rules.as_bool()
                 };
 
+                let organizer_email = unsafe { event.organizer() }
+                    .and_then(|organizer| self.transform_participant(&organizer).email);
+
                 let participants = unsafe { event.attendees().unwrap_or_default() };
                 let participant_list: Vec<Participant> = participants
                     .iter()
@@ -240,7 +248,12 @@ impl CalendarSource for Handle {
                         let is_current_user = unsafe { p.isCurrentUser() };
                         !is_current_user
                     })
-                    .map(|p| self.transform_participant(p))
+                    .map(|p| {
+                        let mut participant = self.transform_participant(p);
+                        participant.is_organizer =
+                            participant.email.is_some() && participant.email == organizer_email;
+                        participant
+                    })
                     .collect();
 
                 Some(Event {
@@ -253,6 +266,9 @@ impl CalendarSource for Handle {
                     start_date: offset_date_time_from(start_date),
                     end_date: offset_date_time_from(end_date),
                     google_event_url: None,
+                    // Invites put the link in either, and the location is the more specific.
+                    meeting_link: parse_meeting_link(&location.to_string())
+                        .or_else(|| parse_meeting_link(&note.to_string())),
                     is_recurring,
                 })
             })
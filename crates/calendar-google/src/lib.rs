@@ -1,114 +1,224 @@
-// https://developers.google.com/calendar/api/v3/reference/calendars
+// https://developers.google.com/calendar/api/v3/reference/calendarList
 // https://developers.google.com/calendar/api/v3/reference/events
+// https://developers.google.com/calendar/api/guides/sync
 
-use hypr_calendar_interface::{
-    Calendar, CalendarSource, Error, Event, EventFilter, Participant, Platform,
-};
+mod types;
+
+use chrono::{DateTime, Utc};
+
+use hypr_calendar_interface::{Calendar, CalendarSource, Error, Event, EventFilter, Platform};
+
+const API_BASE: &str = "https://www.googleapis.com/calendar/v3";
+const PAGE_SIZE: &str = "250";
 
 pub struct Handle {
-    client: google_calendar::Client,
+    client: reqwest::Client,
+    token: String,
+}
+
+/// What changed in a calendar since the sync token it was asked with.
+#[derive(Debug, Default)]
+pub struct EventChanges {
+    pub updated: Vec<Event>,
+    /// Ids of events that were deleted, or that the user declined.
+    pub removed: Vec<String>,
+    /// Pass this to the next `sync_events` call to only get what changed after this one.
+    pub next_sync_token: Option<String>,
 }
 
 impl Handle {
-    pub async fn new(token: impl Into<String>) -> Self {
-        let client = google_calendar::Client::new_from_env(token.into(), "".to_string()).await;
-        Self { client }
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            client: hypr_proxy::client(),
+            token: token.into(),
+        }
+    }
+
+    /// Without a `sync_token` this lists every event in `[from, to)`. With one, it lists
+    /// what changed since, and Google ignores the window, so callers filter again.
+    ///
+    /// `Ok(None)` means the token expired, and a full sync is needed.
+    pub async fn sync_events(
+        &self,
+        calendar_id: &str,
+        sync_token: Option<&str>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Option<EventChanges>, Error> {
+        // Calendar ids are email addresses and may contain `#`, so they go in as a segment.
+        let mut url = reqwest::Url::parse(API_BASE)?;
+        url.path_segments_mut()
+            .map_err(|_| Error::msg("invalid API base"))?
+            .extend(["calendars", calendar_id, "events"]);
+
+        let (from, to) = (from.to_rfc3339(), to.to_rfc3339());
+        let mut changes = EventChanges::default();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut query = vec![("singleEvents", "true"), ("maxResults", PAGE_SIZE)];
+            match sync_token {
+                Some(token) => query.push(("syncToken", token)),
+                None => query.extend([("timeMin", from.as_str()), ("timeMax", to.as_str())]),
+            }
+            if let Some(token) = &page_token {
+                query.push(("pageToken", token));
+            }
+
+            let res = self
+                .client
+                .get(url.clone())
+                .bearer_auth(&self.token)
+                .query(&query)
+                .send()
+                .await?;
+            if res.status() == reqwest::StatusCode::GONE {
+                return Ok(None);
+            }
+
+            let page: types::EventsPage = res.error_for_status()?.json().await?;
+            for item in page.items {
+                match item.into_event(calendar_id) {
+                    Ok(event) => changes.updated.push(event),
+                    Err(id) => changes.removed.push(id),
+                }
+            }
+
+            match page.next_page_token {
+                Some(token) => page_token = Some(token),
+                None => {
+                    changes.next_sync_token = page.next_sync_token;
+                    return Ok(Some(changes));
+                }
+            }
+        }
     }
 }
 
 impl CalendarSource for Handle {
     async fn list_calendars(&self) -> Result<Vec<Calendar>, Error> {
-        let list = self
-            .client
-            .calendar_list()
-            .list_all(google_calendar::types::MinAccessRole::Noop, false, false)
-            .await?
-            .body
-            .iter()
-            .map(|calendar| Calendar {
-                id: calendar.id.clone(),
-                platform: Platform::Google,
-                name: calendar.summary.clone(),
-                source: Some(calendar.primary.to_string()),
-            })
-            .collect();
-
-        Ok(list)
+        let mut entries: Vec<types::CalendarListEntry> = vec![];
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut query = vec![("maxResults", PAGE_SIZE)];
+            if let Some(token) = &page_token {
+                query.push(("pageToken", token));
+            }
+
+            let page: types::CalendarListPage = self
+                .client
+                .get(format!("{}/users/me/calendarList", API_BASE))
+                .bearer_auth(&self.token)
+                .query(&query)
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?;
+
+            entries.extend(page.items);
+
+            if let Some(token) = page.next_page_token {
+                page_token = Some(token);
+                continue;
+            }
+
+            // The primary calendar's id is the account's email, which groups the
+            // account's calendars together in settings.
+            let account = entries.iter().find(|c| c.primary).map(|c| c.id.clone());
+
+            return Ok(entries
+                .into_iter()
+                .map(|calendar| Calendar {
+                    platform: Platform::Google,
+                    name: calendar.summary_override.unwrap_or(calendar.summary),
+                    source: account.clone(),
+                    id: calendar.id,
+                })
+                .collect());
+        }
     }
 
     async fn list_events(&self, filter: EventFilter) -> Result<Vec<Event>, Error> {
-        let events: Vec<Event> = self
-            .client
-            .events()
-            .list(
-                &filter.calendar_tracking_id,
-                "",
-                100,
-                500,
-                google_calendar::types::OrderBy::StartTime,
-                "",
-                &Vec::new(),
-                "",
-                &Vec::new(),
-                false,
-                false,
-                true,
-                &filter.from.to_rfc3339(),
-                &filter.to.to_rfc3339(),
-                "",
-                "",
-            )
+        let changes = self
+            .sync_events(&filter.calendar_tracking_id, None, filter.from, filter.to)
             .await?
-            .body
-            .iter()
-            .map(|event| {
-                let start = event.start.clone().unwrap();
-                let end = event.end.clone().unwrap();
-
-                let start = start.date_time.unwrap_or_else(|| {
-                    start
-                        .date
-                        .unwrap()
-                        .and_hms_opt(0, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(chrono::Local)
-                        .unwrap()
-                        .into()
-                });
-                let end = end.date_time.unwrap_or_else(|| {
-                    end.date
-                        .unwrap()
-                        .and_hms_opt(0, 0, 0)
-                        .unwrap()
-                        .and_local_timezone(chrono::Local)
-                        .unwrap()
-                        .into()
-                });
-
-                let participants = event
-                    .attendees
-                    .iter()
-                    .map(|a| Participant {
-                        name: a.display_name.clone(),
-                        email: Some(a.email.clone()),
-                    })
-                    .collect::<Vec<Participant>>();
-
-                Event {
-                    id: event.id.clone(),
-                    calendar_id: filter.calendar_tracking_id.clone(),
-                    platform: Platform::Google,
-                    name: event.summary.clone(),
-                    note: event.description.clone(),
-                    participants,
-                    start_date: start,
-                    end_date: end,
-                    google_event_url: Some(event.html_link.clone()),
-                    is_recurring: false,
-                }
-            })
-            .collect();
+            .unwrap_or_default();
+
+        Ok(changes.updated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_events_page() {
+        let page: types::EventsPage = serde_json::from_str(
+            r#"{
+                "nextSyncToken": "CPDAlvWDx70CEPDAlvWDx70CGAU=",
+                "items": [
+                    {
+                        "id": "abc_20250106T093000Z",
+                        "status": "confirmed",
+                        "summary": "Standup",
+                        "htmlLink": "https://www.google.com/calendar/event?eid=abc",
+                        "recurringEventId": "abc",
+                        "start": { "dateTime": "2025-01-06T10:30:00+01:00" },
+                        "end": { "dateTime": "2025-01-06T10:45:00+01:00" },
+                        "organizer": { "email": "alice@example.com", "displayName": "Alice" },
+                        "attendees": [
+                            { "email": "alice@example.com", "displayName": "Alice", "organizer": true },
+                            { "email": "me@example.com", "self": true },
+                            { "email": "room@resource.calendar.google.com", "resource": true }
+                        ],
+                        "hangoutLink": "https://meet.google.com/abc-defg-hij",
+                        "conferenceData": {
+                            "entryPoints": [
+                                { "entryPointType": "phone", "uri": "tel:+1-555-0100" },
+                                { "entryPointType": "video", "uri": "https://meet.google.com/abc-defg-hij" }
+                            ]
+                        }
+                    },
+                    { "id": "gone", "status": "cancelled" },
+                    {
+                        "id": "declined",
+                        "status": "confirmed",
+                        "start": { "date": "2025-01-07" },
+                        "end": { "date": "2025-01-08" },
+                        "attendees": [{ "email": "me@example.com", "self": true, "responseStatus": "declined" }]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            page.next_sync_token.as_deref(),
+            Some("CPDAlvWDx70CEPDAlvWDx70CGAU=")
+        );
+
+        let mut items = page.items.into_iter();
+
+        let event = items.next().unwrap().into_event("primary").unwrap();
+        assert_eq!(event.name, "Standup");
+        assert!(event.is_recurring);
+        assert_eq!(event.start_date.to_rfc3339(), "2025-01-06T09:30:00+00:00");
+        assert_eq!(
+            event.meeting_link.as_deref(),
+            Some("https://meet.google.com/abc-defg-hij")
+        );
+        assert_eq!(event.participants.len(), 1);
+        assert!(event.participants[0].is_organizer);
 
-        Ok(events)
+        assert_eq!(
+            items.next().unwrap().into_event("primary"),
+            Err("gone".into())
+        );
+        assert_eq!(
+            items.next().unwrap().into_event("primary"),
+            Err("declined".into())
+        );
     }
 }
@@ -0,0 +1,190 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+
+use hypr_calendar_interface::{parse_meeting_link, Event, Participant, Platform};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarListPage {
+    #[serde(default)]
+    pub items: Vec<CalendarListEntry>,
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarListEntry {
+    pub id: String,
+    #[serde(default)]
+    pub summary: String,
+    pub summary_override: Option<String>,
+    #[serde(default)]
+    pub primary: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventsPage {
+    #[serde(default)]
+    pub items: Vec<EventItem>,
+    pub next_page_token: Option<String>,
+    pub next_sync_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventItem {
+    pub id: String,
+    pub status: Option<String>,
+    #[serde(default)]
+    pub summary: String,
+    #[serde(default)]
+    pub description: String,
+    pub location: Option<String>,
+    pub html_link: Option<String>,
+    pub recurring_event_id: Option<String>,
+    pub start: Option<EventTime>,
+    pub end: Option<EventTime>,
+    pub organizer: Option<Person>,
+    #[serde(default)]
+    pub attendees: Vec<Attendee>,
+    pub hangout_link: Option<String>,
+    pub conference_data: Option<ConferenceData>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventTime {
+    pub date_time: Option<DateTime<Utc>>,
+    /// Set instead of `date_time` for all-day events.
+    pub date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    #[serde(default)]
+    pub email: String,
+    pub display_name: Option<String>,
+    #[serde(default, rename = "self")]
+    pub is_self: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attendee {
+    #[serde(default)]
+    pub email: String,
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub organizer: bool,
+    #[serde(default, rename = "self")]
+    pub is_self: bool,
+    /// Meeting rooms and other bookable resources.
+    #[serde(default)]
+    pub resource: bool,
+    pub response_status: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConferenceData {
+    #[serde(default)]
+    pub entry_points: Vec<EntryPoint>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryPoint {
+    pub entry_point_type: String,
+    pub uri: String,
+}
+
+impl EventTime {
+    fn to_utc(&self) -> Option<DateTime<Utc>> {
+        self.date_time.or_else(|| {
+            self.date?
+                .and_hms_opt(0, 0, 0)?
+                .and_local_timezone(chrono::Local)
+                .earliest()
+                .map(Into::into)
+        })
+    }
+}
+
+impl EventItem {
+    /// `Err` carries the id of an event that should no longer be shown: one that was
+    /// cancelled, that the user declined, or that has no usable time.
+    pub fn into_event(self, calendar_id: &str) -> Result<Event, String> {
+        let declined = self
+            .attendees
+            .iter()
+            .any(|a| a.is_self && a.response_status.as_deref() == Some("declined"));
+        if declined || self.status.as_deref() == Some("cancelled") {
+            return Err(self.id);
+        }
+
+        let start = self.start.as_ref().and_then(EventTime::to_utc);
+        let end = self.end.as_ref().and_then(EventTime::to_utc);
+        let (Some(start_date), Some(end_date)) = (start, end) else {
+            return Err(self.id);
+        };
+
+        let meeting_link = self
+            .conference_data
+            .as_ref()
+            .and_then(|data| {
+                data.entry_points
+                    .iter()
+                    .find(|entry| entry.entry_point_type == "video")
+            })
+            .map(|entry| entry.uri.clone())
+            .or_else(|| self.hangout_link.clone())
+            .or_else(|| self.location.as_deref().and_then(parse_meeting_link))
+            .or_else(|| parse_meeting_link(&self.description));
+
+        Ok(Event {
+            calendar_id: calendar_id.to_string(),
+            platform: Platform::Google,
+            participants: self.participants(),
+            start_date,
+            end_date,
+            google_event_url: self.html_link,
+            meeting_link,
+            // With `singleEvents`, each occurrence of a series has its own id.
+            is_recurring: self.recurring_event_id.is_some(),
+            id: self.id,
+            name: self.summary,
+            note: self.description,
+        })
+    }
+
+    fn participants(&self) -> Vec<Participant> {
+        let mut participants: Vec<Participant> = self
+            .attendees
+            .iter()
+            .filter(|a| !a.is_self && !a.resource)
+            .map(|a| Participant {
+                name: a.display_name.clone().unwrap_or_else(|| a.email.clone()),
+                email: Some(a.email.clone()),
+                is_organizer: a.organizer,
+            })
+            .collect();
+
+        // Events nobody was invited to list only an organizer.
+        if let Some(organizer) = self.organizer.as_ref().filter(|o| !o.is_self) {
+            if !participants.iter().any(|p| p.is_organizer) {
+                participants.push(Participant {
+                    name: organizer
+                        .display_name
+                        .clone()
+                        .unwrap_or_else(|| organizer.email.clone()),
+                    email: Some(organizer.email.clone()),
+                    is_organizer: true,
+                });
+            }
+        }
+
+        participants
+    }
+}
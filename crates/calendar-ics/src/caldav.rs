@@ -0,0 +1,111 @@
+// https://datatracker.ietf.org/doc/html/rfc4791#section-7.8
+
+use chrono::{DateTime, Utc};
+
+pub const DISPLAY_NAME_QUERY: &str = r#"<?xml version="1.0" encoding="utf-8" ?>
+<d:propfind xmlns:d="DAV:">
+  <d:prop><d:displayname/></d:prop>
+</d:propfind>"#;
+
+/// Asks the collection for its events in `[from, to)`. Recurring series come back whole
+/// and are expanded on our side.
+pub fn calendar_query(from: DateTime<Utc>, to: DateTime<Utc>) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8" ?>
+<c:calendar-query xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:prop><c:calendar-data/></d:prop>
+  <c:filter>
+    <c:comp-filter name="VCALENDAR">
+      <c:comp-filter name="VEVENT">
+        <c:time-range start="{}" end="{}"/>
+      </c:comp-filter>
+    </c:comp-filter>
+  </c:filter>
+</c:calendar-query>"#,
+        from.format("%Y%m%dT%H%M%SZ"),
+        to.format("%Y%m%dT%H%M%SZ"),
+    )
+}
+
+/// Text of every element called `name`, whatever namespace prefix the server picked.
+pub fn elements(xml: &str, name: &str) -> Vec<String> {
+    let mut out = vec![];
+    let mut rest = xml;
+
+    while let Some(open) = rest.find('<') {
+        rest = &rest[open + 1..];
+        let Some(close) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[..close];
+        rest = &rest[close + 1..];
+
+        if tag.starts_with(['/', '?', '!']) || tag.ends_with('/') {
+            continue;
+        }
+
+        let qualified = tag.split_whitespace().next().unwrap_or_default();
+        let local = qualified.rsplit(':').next().unwrap_or_default();
+        if local != name {
+            continue;
+        }
+
+        let Some(end) = rest.find(&format!("</{}", qualified)) else {
+            break;
+        };
+        out.push(text(&rest[..end]));
+        rest = &rest[end..];
+    }
+
+    out
+}
+
+fn text(content: &str) -> String {
+    let trimmed = content.trim();
+    if let Some(cdata) = trimmed
+        .strip_prefix("<![CDATA[")
+        .and_then(|c| c.strip_suffix("]]>"))
+    {
+        return cdata.to_string();
+    }
+
+    trimmed
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&#13;", "\r")
+        .replace("&#xD;", "\r")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_elements() {
+        let xml = r#"<?xml version="1.0"?>
+<d:multistatus xmlns:d="DAV:" xmlns:cal="urn:ietf:params:xml:ns:caldav">
+  <d:response>
+    <d:propstat><d:prop>
+      <cal:calendar-data>BEGIN:VCALENDAR&#13;
+SUMMARY:Q&amp;A
+END:VCALENDAR</cal:calendar-data>
+    </d:prop></d:propstat>
+  </d:response>
+  <d:response>
+    <d:propstat><d:prop>
+      <C:calendar-data xmlns:C="urn:ietf:params:xml:ns:caldav"><![CDATA[BEGIN:VCALENDAR
+END:VCALENDAR]]></C:calendar-data>
+      <cal:calendar-data/>
+    </d:prop></d:propstat>
+  </d:response>
+</d:multistatus>"#;
+
+        let data = elements(xml, "calendar-data");
+        assert_eq!(data.len(), 2);
+        assert_eq!(data[0], "BEGIN:VCALENDAR\r\nSUMMARY:Q&A\nEND:VCALENDAR");
+        assert_eq!(data[1], "BEGIN:VCALENDAR\nEND:VCALENDAR");
+    }
+}
@@ -0,0 +1,294 @@
+mod caldav;
+mod parse;
+mod recur;
+
+pub use parse::{parse, VCalendar, VEvent};
+
+use chrono::{DateTime, Utc};
+
+use hypr_calendar_interface::{
+    parse_meeting_link, Calendar, CalendarSource, Error, Event, EventFilter, Platform,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceKind {
+    /// A plain `.ics` file, also reachable as `webcal://`.
+    Ics,
+    /// A CalDAV calendar collection, queried by time range.
+    CalDav,
+}
+
+pub struct Handle {
+    client: reqwest::Client,
+    id: String,
+    url: String,
+    kind: SourceKind,
+    credentials: Option<(String, String)>,
+}
+
+impl Handle {
+    /// `id` becomes the tracking id of the one calendar this source has.
+    pub fn new(id: impl Into<String>, url: impl AsRef<str>, kind: SourceKind) -> Self {
+        let url = url.as_ref().trim();
+        let url = match url.split_once("://") {
+            Some((scheme, rest)) if scheme.eq_ignore_ascii_case("webcal") => {
+                format!("https://{}", rest)
+            }
+            Some((scheme, rest)) if scheme.eq_ignore_ascii_case("webcals") => {
+                format!("https://{}", rest)
+            }
+            _ => url.to_string(),
+        };
+
+        Self {
+            client: hypr_proxy::client(),
+            id: id.into(),
+            url,
+            kind,
+            credentials: None,
+        }
+    }
+
+    pub fn with_credentials(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.credentials = Some((username.into(), password.into()));
+        self
+    }
+
+    fn request(&self, method: reqwest::Method) -> reqwest::RequestBuilder {
+        let req = self
+            .client
+            .request(method, &self.url)
+            .timeout(std::time::Duration::from_secs(30));
+
+        match &self.credentials {
+            Some((username, password)) => req.basic_auth(username, Some(password)),
+            None => req,
+        }
+    }
+
+    async fn fetch(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<VCalendar>, Error> {
+        match self.kind {
+            SourceKind::Ics => {
+                let text = self
+                    .request(reqwest::Method::GET)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .text()
+                    .await?;
+                Ok(vec![parse(&text)])
+            }
+            SourceKind::CalDav => {
+                let text = self
+                    .request(reqwest::Method::from_bytes(b"REPORT")?)
+                    .header("Depth", "1")
+                    .header("Content-Type", "application/xml; charset=utf-8")
+                    .body(caldav::calendar_query(from, to))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .text()
+                    .await?;
+                Ok(caldav::elements(&text, "calendar-data")
+                    .iter()
+                    .map(|data| parse(data))
+                    .collect())
+            }
+        }
+    }
+
+    async fn name(&self) -> Result<Option<String>, Error> {
+        match self.kind {
+            SourceKind::Ics => {
+                let now = Utc::now();
+                Ok(self
+                    .fetch(now, now)
+                    .await?
+                    .into_iter()
+                    .find_map(|calendar| calendar.name))
+            }
+            SourceKind::CalDav => {
+                let text = self
+                    .request(reqwest::Method::from_bytes(b"PROPFIND")?)
+                    .header("Depth", "0")
+                    .header("Content-Type", "application/xml; charset=utf-8")
+                    .body(caldav::DISPLAY_NAME_QUERY)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .text()
+                    .await?;
+                Ok(caldav::elements(&text, "displayname")
+                    .into_iter()
+                    .find(|name| !name.is_empty()))
+            }
+        }
+    }
+}
+
+impl CalendarSource for Handle {
+    async fn list_calendars(&self) -> Result<Vec<Calendar>, Error> {
+        let host = reqwest::Url::parse(&self.url)?.host_str().map(String::from);
+
+        Ok(vec![Calendar {
+            id: self.id.clone(),
+            platform: Platform::Ics,
+            name: self
+                .name()
+                .await?
+                .or_else(|| host.clone())
+                .unwrap_or_else(|| self.url.clone()),
+            source: host,
+        }])
+    }
+
+    async fn list_events(&self, filter: EventFilter) -> Result<Vec<Event>, Error> {
+        let calendars = self.fetch(filter.from, filter.to).await?;
+        let events = calendars
+            .iter()
+            .flat_map(|calendar| calendar.events.iter().cloned())
+            .collect::<Vec<_>>();
+
+        Ok(expand(
+            &events,
+            &filter.calendar_tracking_id,
+            filter.from,
+            filter.to,
+        ))
+    }
+}
+
+/// Turns the feed's events into the ones overlapping `[from, to)`, one per occurrence.
+pub fn expand(
+    events: &[VEvent],
+    calendar_id: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<Event> {
+    // Edited and cancelled occurrences take their slot out of the series.
+    let is_overridden = |uid: &str, at: DateTime<Utc>| {
+        events
+            .iter()
+            .any(|e| e.uid == uid && e.recurrence_id == Some(at))
+    };
+
+    let mut out = vec![];
+
+    for event in events.iter().filter(|e| !e.cancelled) {
+        let starts: Vec<DateTime<Utc>> = match (&event.rrule, event.recurrence_id) {
+            (Some(rule), None) => recur::occurrences(&event.start, rule, from - event.duration, to)
+                .into_iter()
+                .filter(|at| !event.exdates.contains(at) && !is_overridden(&event.uid, *at))
+                .collect(),
+            _ => event.start.to_utc().into_iter().collect(),
+        };
+
+        out.extend(
+            starts
+                .into_iter()
+                .filter(|start| *start < to && (*start >= from || *start + event.duration > from))
+                .map(|start| Event {
+                    id: event.uid.clone(),
+                    calendar_id: calendar_id.to_string(),
+                    platform: Platform::Ics,
+                    name: event.summary.clone(),
+                    note: event.description.clone(),
+                    participants: event.participants.clone(),
+                    start_date: start,
+                    end_date: start + event.duration,
+                    google_event_url: None,
+                    meeting_link: parse_meeting_link(&event.location)
+                        .or_else(|| parse_meeting_link(&event.description)),
+                    is_recurring: event.rrule.is_some() || event.recurrence_id.is_some(),
+                }),
+        );
+    }
+
+    out.sort_by_key(|e| e.start_date);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED: &str = "BEGIN:VCALENDAR
+BEGIN:VEVENT
+UID:weekly
+SUMMARY:1:1
+DTSTART:20250106T100000Z
+DTEND:20250106T103000Z
+RRULE:FREQ=WEEKLY;COUNT=4
+EXDATE:20250113T100000Z
+END:VEVENT
+BEGIN:VEVENT
+UID:weekly
+RECURRENCE-ID:20250120T100000Z
+SUMMARY:1:1 (moved)
+DTSTART:20250121T150000Z
+DTEND:20250121T153000Z
+END:VEVENT
+BEGIN:VEVENT
+UID:weekly
+RECURRENCE-ID:20250127T100000Z
+STATUS:CANCELLED
+DTSTART:20250127T100000Z
+END:VEVENT
+BEGIN:VEVENT
+UID:review
+SUMMARY:Review
+DTSTART:20250107T120000Z
+DURATION:PT1H
+END:VEVENT
+END:VCALENDAR";
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_expand() {
+        let calendar = parse(FEED);
+        let events = expand(
+            &calendar.events,
+            "CALENDAR",
+            utc("2025-01-01T00:00:00Z"),
+            utc("2025-03-01T00:00:00Z"),
+        );
+
+        let summary: Vec<_> = events
+            .iter()
+            .map(|e| format!("{} {}", e.name, e.start_date.format("%m-%d %H:%M")))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                "1:1 01-06 10:00",
+                "Review 01-07 12:00",
+                "1:1 (moved) 01-21 15:00"
+            ]
+        );
+        assert!(events[0].is_recurring);
+        assert!(!events[1].is_recurring);
+        assert_eq!(events[1].end_date, utc("2025-01-07T13:00:00Z"));
+        assert!(events.iter().all(|e| e.calendar_id == "CALENDAR"));
+    }
+
+    #[test]
+    fn test_expand_includes_events_already_underway() {
+        let calendar = parse(FEED);
+        let events = expand(
+            &calendar.events,
+            "CALENDAR",
+            utc("2025-01-07T12:30:00Z"),
+            utc("2025-01-08T00:00:00Z"),
+        );
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].name, "Review");
+    }
+}
@@ -0,0 +1,427 @@
+// https://datatracker.ietf.org/doc/html/rfc5545
+
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+use hypr_calendar_interface::Participant;
+
+use crate::recur::Rule;
+
+/// How a local date-time in the feed maps to an instant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Zone {
+    Utc,
+    Tz(chrono_tz::Tz),
+    /// No zone given, or one we don't know; read in the user's own time zone.
+    Floating,
+}
+
+impl Zone {
+    pub fn to_utc(self, local: NaiveDateTime) -> Option<DateTime<Utc>> {
+        // A time skipped by a DST change is moved forward by the size of the gap.
+        let resolve = |local: NaiveDateTime| match self {
+            Zone::Utc => Some(local.and_utc()),
+            Zone::Tz(tz) => tz
+                .from_local_datetime(&local)
+                .earliest()
+                .map(|t| t.with_timezone(&Utc)),
+            Zone::Floating => chrono::Local
+                .from_local_datetime(&local)
+                .earliest()
+                .map(|t| t.with_timezone(&Utc)),
+        };
+
+        resolve(local).or_else(|| resolve(local + Duration::hours(1)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Time {
+    pub local: NaiveDateTime,
+    pub zone: Zone,
+    pub all_day: bool,
+}
+
+impl Time {
+    pub fn to_utc(&self) -> Option<DateTime<Utc>> {
+        self.zone.to_utc(self.local)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VEvent {
+    pub uid: String,
+    pub summary: String,
+    pub description: String,
+    pub location: String,
+    pub start: Time,
+    pub duration: Duration,
+    pub rrule: Option<Rule>,
+    pub exdates: Vec<DateTime<Utc>>,
+    /// Set on an edited occurrence of a series, to the start it replaces.
+    pub recurrence_id: Option<DateTime<Utc>>,
+    pub cancelled: bool,
+    pub participants: Vec<Participant>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VCalendar {
+    pub name: Option<String>,
+    pub events: Vec<VEvent>,
+}
+
+pub fn parse(text: &str) -> VCalendar {
+    let mut calendar = VCalendar::default();
+    let mut event: Option<EventBuilder> = None;
+    // Components nested in an event, like VALARM, have properties we don't want.
+    let mut nested = 0;
+
+    for line in unfold(text).iter().filter_map(|l| Line::parse(l)) {
+        match (line.name.as_str(), line.value.to_ascii_uppercase().as_str()) {
+            ("BEGIN", "VEVENT") => event = Some(EventBuilder::default()),
+            ("END", "VEVENT") => {
+                if let Some(built) = event.take().and_then(EventBuilder::build) {
+                    calendar.events.push(built);
+                }
+                nested = 0;
+            }
+            ("BEGIN", _) if event.is_some() => nested += 1,
+            ("END", _) if event.is_some() => nested -= 1,
+            ("X-WR-CALNAME", _) if event.is_none() => {
+                calendar.name = Some(unescape(&line.value)).filter(|n| !n.is_empty());
+            }
+            _ => {
+                if let (Some(event), 0) = (event.as_mut(), nested) {
+                    event.apply(line);
+                }
+            }
+        }
+    }
+
+    calendar
+}
+
+#[derive(Debug, Default)]
+struct EventBuilder {
+    uid: Option<String>,
+    summary: String,
+    description: String,
+    location: String,
+    start: Option<Time>,
+    end: Option<Time>,
+    duration: Option<Duration>,
+    rrule: Option<Rule>,
+    exdates: Vec<DateTime<Utc>>,
+    recurrence_id: Option<DateTime<Utc>>,
+    cancelled: bool,
+    participants: Vec<Participant>,
+}
+
+impl EventBuilder {
+    fn apply(&mut self, line: Line) {
+        match line.name.as_str() {
+            "UID" => self.uid = Some(line.value),
+            "SUMMARY" => self.summary = unescape(&line.value),
+            "DESCRIPTION" => self.description = unescape(&line.value),
+            "LOCATION" => self.location = unescape(&line.value),
+            "DTSTART" => self.start = parse_time(&line.value, &line.params),
+            "DTEND" => self.end = parse_time(&line.value, &line.params),
+            "DURATION" => self.duration = parse_duration(&line.value),
+            "RRULE" => self.rrule = Rule::parse(&line.value),
+            "EXDATE" => self.exdates.extend(
+                line.value
+                    .split(',')
+                    .filter_map(|v| parse_time(v, &line.params)?.to_utc()),
+            ),
+            "RECURRENCE-ID" => {
+                self.recurrence_id = parse_time(&line.value, &line.params).and_then(|t| t.to_utc())
+            }
+            "STATUS" => self.cancelled = line.value.eq_ignore_ascii_case("CANCELLED"),
+            "ORGANIZER" | "ATTENDEE" => {
+                let email = line
+                    .value
+                    .get(..7)
+                    .filter(|scheme| scheme.eq_ignore_ascii_case("mailto:"))
+                    .map(|_| line.value[7..].to_string());
+                let name = line
+                    .param("CN")
+                    .map(String::from)
+                    .or_else(|| email.clone())
+                    .unwrap_or_default();
+                let is_organizer = line.name == "ORGANIZER";
+
+                match self
+                    .participants
+                    .iter_mut()
+                    .find(|p| email.is_some() && p.email == email)
+                {
+                    Some(existing) => existing.is_organizer |= is_organizer,
+                    None => self.participants.push(Participant {
+                        name,
+                        email,
+                        is_organizer,
+                    }),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn build(self) -> Option<VEvent> {
+        let start = self.start?;
+        let start_utc = start.to_utc()?;
+
+        let duration = match (self.end.and_then(|end| end.to_utc()), self.duration) {
+            (Some(end), _) if end >= start_utc => end - start_utc,
+            (_, Some(duration)) => duration,
+            _ if start.all_day => Duration::days(1),
+            _ => Duration::zero(),
+        };
+
+        Some(VEvent {
+            uid: self.uid?,
+            summary: self.summary,
+            description: self.description,
+            location: self.location,
+            start,
+            duration,
+            rrule: self.rrule,
+            exdates: self.exdates,
+            recurrence_id: self.recurrence_id,
+            cancelled: self.cancelled,
+            participants: self.participants,
+        })
+    }
+}
+
+struct Line {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl Line {
+    fn parse(line: &str) -> Option<Self> {
+        let mut in_quotes = false;
+        let mut parts = vec![];
+        let mut part_start = 0;
+
+        for (i, c) in line.char_indices() {
+            match c {
+                '"' => in_quotes = !in_quotes,
+                ';' if !in_quotes => {
+                    parts.push(&line[part_start..i]);
+                    part_start = i + 1;
+                }
+                ':' if !in_quotes => {
+                    parts.push(&line[part_start..i]);
+                    let name = parts[0].trim().to_ascii_uppercase();
+                    let params = parts[1..]
+                        .iter()
+                        .filter_map(|p| p.split_once('='))
+                        .map(|(k, v)| (k.trim().to_ascii_uppercase(), v.trim_matches('"').into()))
+                        .collect();
+
+                    return Some(Self {
+                        name,
+                        params,
+                        value: line[i + 1..].to_string(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    fn param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Joins the continuation lines RFC 5545 folds long properties into.
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+
+    for line in text.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    lines
+}
+
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') | Some('N') => out.push('\n'),
+            Some(escaped) => out.push(escaped),
+            None => {}
+        }
+    }
+
+    out
+}
+
+pub(crate) fn parse_time(value: &str, params: &[(String, String)]) -> Option<Time> {
+    let value = value.trim();
+    let param = |key: &str| params.iter().find(|(k, _)| k == key).map(|(_, v)| v);
+
+    if value.len() == 8 || param("VALUE").is_some_and(|v| v.eq_ignore_ascii_case("DATE")) {
+        let date = NaiveDate::parse_from_str(value.get(..8)?, "%Y%m%d").ok()?;
+        return Some(Time {
+            local: date.and_hms_opt(0, 0, 0)?,
+            zone: Zone::Floating,
+            all_day: true,
+        });
+    }
+
+    let (value, zone) = match value.strip_suffix(['Z', 'z']) {
+        Some(value) => (value, Zone::Utc),
+        None => {
+            let zone = param("TZID")
+                .and_then(|tzid| {
+                    // Some exporters prefix the Olson name, like "/mozilla.org/20050126_1/Europe/Berlin".
+                    let tzid = tzid.trim_start_matches('/');
+                    chrono_tz::Tz::from_str(tzid).ok().or_else(|| {
+                        let parts: Vec<_> = tzid.rsplitn(3, '/').collect();
+                        match parts.as_slice() {
+                            [city, region, _] => {
+                                chrono_tz::Tz::from_str(&format!("{}/{}", region, city)).ok()
+                            }
+                            _ => None,
+                        }
+                    })
+                })
+                .map_or(Zone::Floating, Zone::Tz);
+            (value, zone)
+        }
+    };
+
+    Some(Time {
+        local: NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?,
+        zone,
+        all_day: false,
+    })
+}
+
+/// "PT1H30M", "P1D", "-PT15M" and the like.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let (sign, value) = match value.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, value.trim_start_matches('+')),
+    };
+
+    let mut total = Duration::zero();
+    let mut number = String::new();
+
+    for c in value.strip_prefix('P')?.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' => {}
+            unit => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total += match unit {
+                    'W' => Duration::weeks(n),
+                    'D' => Duration::days(n),
+                    'H' => Duration::hours(n),
+                    'M' => Duration::minutes(n),
+                    'S' => Duration::seconds(n),
+                    _ => return None,
+                };
+            }
+        }
+    }
+
+    Some(total * sign)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED: &str = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+X-WR-CALNAME:Team\r
+BEGIN:VEVENT\r
+UID:standup@example.com\r
+SUMMARY:Standup\\, daily\r
+DESCRIPTION:Agenda:\\nblockers\r
+LOCATION:https://meet.google.com/abc-defg-hij\r
+DTSTART;TZID=Europe/Berlin:20250106T093000\r
+DTEND;TZID=Europe/Berlin:20250106T094500\r
+RRULE:FREQ=WEEKLY;BYDAY=MO,WE\r
+EXDATE;TZID=Europe/Berlin:20250108T093000\r
+ORGANIZER;CN=\"Kim, Ana\":mailto:ana@example.com\r
+ATTENDEE;CN=Ana;ROLE=CHAIR:mailto:ana@example.com\r
+ATTENDEE;ROLE=REQ-PARTICIPANT;CN=Bo:mailto:bo@exa\r
+ mple.com\r
+BEGIN:VALARM\r
+DESCRIPTION:Reminder\r
+END:VALARM\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:offsite\r
+SUMMARY:Offsite\r
+DTSTART;VALUE=DATE:20250110\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+    #[test]
+    fn test_parse() {
+        let calendar = parse(FEED);
+        assert_eq!(calendar.name.as_deref(), Some("Team"));
+        assert_eq!(calendar.events.len(), 2);
+
+        let standup = &calendar.events[0];
+        assert_eq!(standup.summary, "Standup, daily");
+        assert_eq!(standup.description, "Agenda:\nblockers");
+        assert_eq!(standup.location, "https://meet.google.com/abc-defg-hij");
+        assert_eq!(
+            standup.start.to_utc().unwrap().to_rfc3339(),
+            "2025-01-06T08:30:00+00:00"
+        );
+        assert_eq!(standup.duration, Duration::minutes(15));
+        assert!(standup.rrule.is_some());
+        assert_eq!(standup.exdates.len(), 1);
+
+        assert_eq!(standup.participants.len(), 2);
+        assert_eq!(standup.participants[0].name, "Kim, Ana");
+        assert!(standup.participants[0].is_organizer);
+        assert_eq!(
+            standup.participants[1].email.as_deref(),
+            Some("bo@example.com")
+        );
+
+        let offsite = &calendar.events[1];
+        assert!(offsite.start.all_day);
+        assert_eq!(offsite.duration, Duration::days(1));
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("PT1H30M"), Some(Duration::minutes(90)));
+        assert_eq!(parse_duration("P1DT2H"), Some(Duration::hours(26)));
+        assert_eq!(parse_duration("-PT15M"), Some(Duration::minutes(-15)));
+        assert_eq!(parse_duration("P2W"), Some(Duration::weeks(2)));
+        assert_eq!(parse_duration("1H"), None);
+    }
+}
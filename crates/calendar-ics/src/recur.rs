@@ -0,0 +1,334 @@
+use chrono::{DateTime, Datelike, Days, Duration, Months, NaiveDate, NaiveDateTime, Utc, Weekday};
+
+use crate::parse::Time;
+
+/// A series is walked at most this many periods from its start, so a broken feed
+/// can't keep a sync busy forever.
+const MAX_PERIODS: u32 = 20_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Freq {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// The subset of RRULE meeting invites use.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    pub freq: Freq,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<DateTime<Utc>>,
+    /// Monthly rules can also say which one of the month, like "2TU" or "-1FR".
+    pub by_day: Vec<(Option<i32>, Weekday)>,
+    pub by_month_day: Vec<i32>,
+}
+
+impl Rule {
+    /// `None` for sub-daily frequencies, which no meeting uses.
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut freq = None;
+        let mut rule = Rule {
+            freq: Freq::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            by_day: vec![],
+            by_month_day: vec![],
+        };
+
+        for (key, value) in value.split(';').filter_map(|part| part.split_once('=')) {
+            match key.trim().to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    freq = match value.trim().to_ascii_uppercase().as_str() {
+                        "DAILY" => Some(Freq::Daily),
+                        "WEEKLY" => Some(Freq::Weekly),
+                        "MONTHLY" => Some(Freq::Monthly),
+                        "YEARLY" => Some(Freq::Yearly),
+                        _ => None,
+                    }
+                }
+                "INTERVAL" => rule.interval = value.parse().ok().filter(|i| *i > 0).unwrap_or(1),
+                "COUNT" => rule.count = value.parse().ok(),
+                "UNTIL" => {
+                    rule.until = crate::parse::parse_time(value, &[]).and_then(|until| {
+                        if until.all_day {
+                            // A date-only UNTIL includes that whole day.
+                            until
+                                .zone
+                                .to_utc(until.local + Duration::days(1) - Duration::seconds(1))
+                        } else {
+                            until.to_utc()
+                        }
+                    })
+                }
+                "BYDAY" => rule.by_day = value.split(',').filter_map(parse_weekday).collect(),
+                "BYMONTHDAY" => {
+                    rule.by_month_day = value
+                        .split(',')
+                        .filter_map(|day| day.trim().parse().ok())
+                        .collect()
+                }
+                _ => {}
+            }
+        }
+
+        rule.freq = freq?;
+        Some(rule)
+    }
+}
+
+/// Starts of the occurrences of a series that begin in `[from, to)`.
+///
+/// Occurrences are stepped in the series' own time zone, so a 9:30 meeting stays at
+/// 9:30 local time across DST changes.
+pub fn occurrences(
+    start: &Time,
+    rule: &Rule,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<DateTime<Utc>> {
+    let mut out = vec![];
+    let mut seen = 0;
+
+    for period in 0..MAX_PERIODS {
+        let Some(candidates) = period_candidates(start.local, rule, period) else {
+            break;
+        };
+
+        for local in candidates.into_iter().filter(|local| *local >= start.local) {
+            seen += 1;
+            if rule.count.is_some_and(|count| seen > count) {
+                return out;
+            }
+
+            let Some(at) = start.zone.to_utc(local) else {
+                continue;
+            };
+            if at >= to || rule.until.is_some_and(|until| at > until) {
+                return out;
+            }
+            if at >= from {
+                out.push(at);
+            }
+        }
+    }
+
+    out
+}
+
+fn period_candidates(start: NaiveDateTime, rule: &Rule, period: u32) -> Option<Vec<NaiveDateTime>> {
+    let step = period.checked_mul(rule.interval)?;
+    let date = start.date();
+
+    let mut dates: Vec<NaiveDate> = match rule.freq {
+        Freq::Daily => vec![date.checked_add_days(Days::new(step as u64))?],
+        Freq::Weekly => {
+            let base = date.checked_add_days(Days::new(step as u64 * 7))?;
+            if rule.by_day.is_empty() {
+                vec![base]
+            } else {
+                let monday = base - Duration::days(base.weekday().num_days_from_monday() as i64);
+                rule.by_day
+                    .iter()
+                    .map(|(_, weekday)| {
+                        monday + Duration::days(weekday.num_days_from_monday() as i64)
+                    })
+                    .collect()
+            }
+        }
+        Freq::Monthly => {
+            let first = date.with_day(1)?.checked_add_months(Months::new(step))?;
+            if !rule.by_day.is_empty() {
+                month_weekdays(first, &rule.by_day)
+            } else if !rule.by_month_day.is_empty() {
+                rule.by_month_day
+                    .iter()
+                    .filter_map(|day| month_day(first, *day))
+                    .collect()
+            } else {
+                month_day(first, date.day() as i32).into_iter().collect()
+            }
+        }
+        Freq::Yearly => {
+            let year = date.year().checked_add(i32::try_from(step).ok()?)?;
+            NaiveDate::from_ymd_opt(year, date.month(), date.day())
+                .into_iter()
+                .collect()
+        }
+    };
+
+    dates.sort();
+    dates.dedup();
+    Some(
+        dates
+            .into_iter()
+            .map(|d| d.and_time(start.time()))
+            .collect(),
+    )
+}
+
+/// Day `day` of the month starting at `first`, counting from the end when negative.
+/// `None` when the month is too short, so "the 31st" skips April.
+fn month_day(first: NaiveDate, day: i32) -> Option<NaiveDate> {
+    let days_in_month = first.checked_add_months(Months::new(1))?.pred_opt()?.day() as i32;
+    let day = if day < 0 {
+        days_in_month + day + 1
+    } else {
+        day
+    };
+
+    match day {
+        1.. if day <= days_in_month => first.with_day(day as u32),
+        _ => None,
+    }
+}
+
+fn month_weekdays(first: NaiveDate, by_day: &[(Option<i32>, Weekday)]) -> Vec<NaiveDate> {
+    let days: Vec<NaiveDate> = first
+        .iter_days()
+        .take_while(|d| d.month() == first.month())
+        .collect();
+
+    by_day
+        .iter()
+        .flat_map(|(nth, weekday)| {
+            let matching: Vec<NaiveDate> = days
+                .iter()
+                .filter(|d| d.weekday() == *weekday)
+                .copied()
+                .collect();
+
+            match nth {
+                None => matching,
+                Some(n) if *n > 0 => matching.get(*n as usize - 1).copied().into_iter().collect(),
+                Some(n) => matching
+                    .len()
+                    .checked_sub(n.unsigned_abs() as usize)
+                    .and_then(|i| matching.get(i).copied())
+                    .into_iter()
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+fn parse_weekday(value: &str) -> Option<(Option<i32>, Weekday)> {
+    let value = value.trim();
+    let split = value.len().checked_sub(2)?;
+    let (nth, code) = (value.get(..split)?, value.get(split..)?);
+
+    let weekday = match code.to_ascii_uppercase().as_str() {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    };
+
+    match nth {
+        "" => Some((None, weekday)),
+        nth => Some((Some(nth.parse().ok()?), weekday)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::Zone;
+
+    fn time(local: &str, zone: Zone) -> Time {
+        Time {
+            local: NaiveDateTime::parse_from_str(local, "%Y-%m-%d %H:%M").unwrap(),
+            zone,
+            all_day: false,
+        }
+    }
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn expand(start: &Time, rule: &str, from: &str, to: &str) -> Vec<String> {
+        occurrences(start, &Rule::parse(rule).unwrap(), utc(from), utc(to))
+            .iter()
+            .map(|t| t.format("%m-%d %H:%M").to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_weekly_by_day() {
+        let start = time("2025-01-06 09:30", Zone::Tz(chrono_tz::Europe::Berlin));
+        assert_eq!(
+            expand(
+                &start,
+                "FREQ=WEEKLY;BYDAY=MO,WE",
+                "2025-01-07T00:00:00Z",
+                "2025-01-20T00:00:00Z"
+            ),
+            ["01-08 08:30", "01-13 08:30", "01-15 08:30"]
+        );
+    }
+
+    #[test]
+    fn test_weekly_keeps_local_time_across_dst() {
+        let start = time("2025-03-24 09:30", Zone::Tz(chrono_tz::Europe::Berlin));
+        assert_eq!(
+            expand(
+                &start,
+                "FREQ=WEEKLY",
+                "2025-03-01T00:00:00Z",
+                "2025-04-01T00:00:00Z"
+            ),
+            ["03-24 08:30", "03-31 07:30"]
+        );
+    }
+
+    #[test]
+    fn test_monthly_last_friday_with_count() {
+        let start = time("2025-01-31 16:00", Zone::Utc);
+        assert_eq!(
+            expand(
+                &start,
+                "FREQ=MONTHLY;BYDAY=-1FR;COUNT=3",
+                "2025-01-01T00:00:00Z",
+                "2026-01-01T00:00:00Z"
+            ),
+            ["01-31 16:00", "02-28 16:00", "03-28 16:00"]
+        );
+    }
+
+    #[test]
+    fn test_monthly_skips_short_months() {
+        let start = time("2025-01-31 10:00", Zone::Utc);
+        assert_eq!(
+            expand(
+                &start,
+                "FREQ=MONTHLY",
+                "2025-01-01T00:00:00Z",
+                "2025-06-01T00:00:00Z"
+            ),
+            ["01-31 10:00", "03-31 10:00", "05-31 10:00"]
+        );
+    }
+
+    #[test]
+    fn test_daily_interval_until() {
+        let start = time("2025-01-01 10:00", Zone::Utc);
+        assert_eq!(
+            expand(
+                &start,
+                "FREQ=DAILY;INTERVAL=2;UNTIL=20250105T000000Z",
+                "2025-01-01T00:00:00Z",
+                "2025-02-01T00:00:00Z"
+            ),
+            ["01-01 10:00", "01-03 10:00"]
+        );
+        assert!(Rule::parse("FREQ=HOURLY").is_none());
+    }
+}
@@ -2,7 +2,10 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::future::Future;
 
+mod meeting;
+
 pub use anyhow::Error;
+pub use meeting::parse_meeting_link;
 
 pub trait CalendarSource {
     fn list_calendars(&self) -> impl Future<Output = Result<Vec<Calendar>, Error>>;
@@ -14,6 +17,8 @@ pub enum Platform {
     Apple,
     Google,
     Outlook,
+    /// An ICS feed or CalDAV collection the user subscribed to by URL.
+    Ics,
 }
 
 impl std::fmt::Display for Platform {
@@ -22,6 +27,7 @@ impl std::fmt::Display for Platform {
             Platform::Apple => write!(f, "Apple"),
             Platform::Google => write!(f, "Google"),
             Platform::Outlook => write!(f, "Outlook"),
+            Platform::Ics => write!(f, "Ics"),
         }
     }
 }
@@ -45,6 +51,9 @@ pub struct Event {
     pub start_date: DateTime<Utc>,
     pub end_date: DateTime<Utc>,
     pub google_event_url: Option<String>,
+    /// Video call link from the provider's conference data, when it has one.
+    #[serde(default)]
+    pub meeting_link: Option<String>,
     #[serde(default)]
     pub is_recurring: bool,
 }
@@ -53,6 +62,8 @@ pub struct Event {
 pub struct Participant {
     pub name: String,
     pub email: Option<String>,
+    #[serde(default)]
+    pub is_organizer: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -91,6 +102,9 @@ impl Event {
             Platform::Outlook => {
                 anyhow::bail!("Outlook is not supported yet");
             }
+            Platform::Ics => {
+                anyhow::bail!("Subscribed calendars can't be opened");
+            }
         }
     }
 }
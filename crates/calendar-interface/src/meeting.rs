@@ -0,0 +1,60 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+static MEETING_REGEXES: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    [
+        r"https://meet\.google\.com/[a-z0-9]{3,4}-[a-z0-9]{3,4}-[a-z0-9]{3,4}",
+        r"https://([a-z0-9-]+\.)*zoom\.us/j/\d+(\?pwd=[a-zA-Z0-9.]+)?",
+        r#"https://teams\.microsoft\.com/l/meetup-join/[^\s<>"]+"#,
+        r"https://teams\.live\.com/meet/\d+",
+        r"https://app\.cal\.com/video/[a-zA-Z0-9]+",
+    ]
+    .into_iter()
+    .map(|pattern| Regex::new(pattern).unwrap())
+    .collect()
+});
+
+/// The first Meet, Zoom, Teams or Cal.com link in an event's location or notes.
+///
+/// Other URLs are left alone, since they are more often agendas or docs than calls.
+pub fn parse_meeting_link(text: &str) -> Option<String> {
+    MEETING_REGEXES
+        .iter()
+        .find_map(|regex| regex.find(text))
+        .map(|m| m.as_str().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_meeting_link() {
+        assert_eq!(
+            parse_meeting_link("Join: https://meet.google.com/abc-defg-hij\nDial-in: +1 555"),
+            Some("https://meet.google.com/abc-defg-hij".to_string())
+        );
+        assert_eq!(
+            parse_meeting_link("https://zoom.us/j/123456789?pwd=abc.1 (passcode 42)"),
+            Some("https://zoom.us/j/123456789?pwd=abc.1".to_string())
+        );
+        assert_eq!(
+            parse_meeting_link("<https://us02web.zoom.us/j/987654321>"),
+            Some("https://us02web.zoom.us/j/987654321".to_string())
+        );
+        assert_eq!(
+            parse_meeting_link(
+                "Click <https://teams.microsoft.com/l/meetup-join/19%3ameeting_abc%40thread.v2/0?context=%7b%7d> to join"
+            ),
+            Some(
+                "https://teams.microsoft.com/l/meetup-join/19%3ameeting_abc%40thread.v2/0?context=%7b%7d"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            parse_meeting_link("Agenda: https://docs.example.com/agenda"),
+            None
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use std::sync::RwLock;
+
+/// Events whose name starts with this are model performance metrics, not usage analytics.
+pub const MODEL_METRICS_PREFIX: &str = "model_";
+
+/// What the user agreed to share. Each kind of telemetry is checked against this right before
+/// it leaves the device, so a change applies immediately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct Consent {
+    /// Crash and error reports (Sentry).
+    pub crash_reports: bool,
+    /// Product usage events (PostHog).
+    pub usage_analytics: bool,
+    /// Model latency and quality measurements.
+    pub model_metrics: bool,
+}
+
+impl Consent {
+    pub fn all(value: bool) -> Self {
+        Self {
+            crash_reports: value,
+            usage_analytics: value,
+            model_metrics: value,
+        }
+    }
+
+    pub fn allows(&self, category: Category) -> bool {
+        match category {
+            Category::CrashReports => self.crash_reports,
+            Category::UsageAnalytics => self.usage_analytics,
+            Category::ModelMetrics => self.model_metrics,
+        }
+    }
+}
+
+impl Default for Consent {
+    fn default() -> Self {
+        Self::all(true)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    CrashReports,
+    UsageAnalytics,
+    ModelMetrics,
+}
+
+impl Category {
+    pub fn for_event(event: &str) -> Self {
+        if event.starts_with(MODEL_METRICS_PREFIX) {
+            Self::ModelMetrics
+        } else {
+            Self::UsageAnalytics
+        }
+    }
+}
+
+// `None` until the stored choice is loaded; nothing is sent before that.
+static CURRENT: RwLock<Option<Consent>> = RwLock::new(None);
+
+pub fn apply(consent: Consent) {
+    *CURRENT.write().unwrap() = Some(consent);
+}
+
+pub fn current() -> Option<Consent> {
+    *CURRENT.read().unwrap()
+}
+
+pub fn allows(category: Category) -> bool {
+    current().is_some_and(|consent| consent.allows(category))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_event() {
+        assert_eq!(
+            Category::for_event("model_enhance_completed"),
+            Category::ModelMetrics
+        );
+        assert_eq!(
+            Category::for_event("normal_enhance_done"),
+            Category::UsageAnalytics
+        );
+    }
+
+    #[test]
+    fn test_allows() {
+        assert!(!allows(Category::CrashReports));
+
+        apply(Consent {
+            crash_reports: true,
+            usage_analytics: false,
+            model_metrics: true,
+        });
+        assert!(allows(Category::CrashReports));
+        assert!(!allows(Category::UsageAnalytics));
+        assert!(allows(Category::ModelMetrics));
+
+        apply(Consent::all(false));
+        assert!(!allows(Category::CrashReports));
+    }
+}
@@ -0,0 +1,96 @@
+use std::path::{Path, PathBuf};
+
+pub mod profile;
+
+pub const ENV_KEY: &str = "HYPRNOTE_DATA_DIR";
+pub const SHARED_ENV_KEY: &str = "HYPRNOTE_SHARED_DATA_DIR";
+pub const FLAG: &str = "--data-dir";
+
+/// Picks the override from `--data-dir <path>` (or `--data-dir=<path>`), then `HYPRNOTE_DATA_DIR`,
+/// and exports it as `HYPRNOTE_DATA_DIR` so every plugin resolves the same directory.
+///
+/// Must run before any plugin asks for a path.
+pub fn init(args: &[String]) -> Option<PathBuf> {
+    // After a restart into another profile, the inherited `HYPRNOTE_DATA_DIR` is the previous
+    // profile's directory rather than the one profiles live under.
+    let dir = from_args(args).or_else(shared_from_env).or_else(from_env)?;
+    std::env::set_var(ENV_KEY, &dir);
+    Some(dir)
+}
+
+/// The overridden data directory, if any.
+pub fn from_env() -> Option<PathBuf> {
+    std::env::var_os(ENV_KEY)
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Returns the override, or the platform directory from `default`.
+pub fn resolve<E>(default: impl FnOnce() -> Result<PathBuf, E>) -> Result<PathBuf, E> {
+    match from_env() {
+        Some(dir) => Ok(dir),
+        None => default(),
+    }
+}
+
+/// The directory profiles live under, once [`profile::apply`] has run.
+pub fn shared_from_env() -> Option<PathBuf> {
+    std::env::var_os(SHARED_ENV_KEY)
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Like [`resolve`], but for data every profile shares, such as downloaded models.
+pub fn resolve_shared<E>(default: impl FnOnce() -> Result<PathBuf, E>) -> Result<PathBuf, E> {
+    match shared_from_env() {
+        Some(dir) => Ok(dir),
+        None => resolve(default),
+    }
+}
+
+/// For APIs that take a path relative to the app data directory (like the store plugin):
+/// `path` anchored at the override, or unchanged if there is none.
+pub fn relative(path: impl AsRef<Path>) -> PathBuf {
+    match from_env() {
+        Some(dir) => dir.join(path),
+        None => path.as_ref().to_path_buf(),
+    }
+}
+
+fn from_args(args: &[String]) -> Option<PathBuf> {
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        if arg == FLAG {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(value) = arg.strip_prefix(FLAG).and_then(|v| v.strip_prefix('=')) {
+            return Some(PathBuf::from(value));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(from_args(&args(&["hyprnote", "--background"])), None);
+        assert_eq!(
+            from_args(&args(&["hyprnote", "--data-dir", "/Volumes/USB/hyprnote"])),
+            Some(PathBuf::from("/Volumes/USB/hyprnote"))
+        );
+        assert_eq!(
+            from_args(&args(&["hyprnote", "--data-dir=/tmp/work"])),
+            Some(PathBuf::from("/tmp/work"))
+        );
+        assert_eq!(from_args(&args(&["hyprnote", "--data-dir"])), None);
+    }
+}
@@ -0,0 +1,178 @@
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_PROFILE_ID: &str = "default";
+
+const PROFILES_FILENAME: &str = "profiles.json";
+const PROFILES_DIR: &str = "profiles";
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Profile {
+    pub id: String,
+    pub name: String,
+}
+
+/// The profiles kept under a data directory, and which one the app opens with.
+///
+/// The default profile lives in the data directory itself, so data from before profiles
+/// existed stays where it is. Every other profile gets `profiles/<id>` below it.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Profiles {
+    pub active: String,
+    pub profiles: Vec<Profile>,
+}
+
+impl Default for Profiles {
+    fn default() -> Self {
+        Self {
+            active: DEFAULT_PROFILE_ID.to_string(),
+            profiles: vec![Profile {
+                id: DEFAULT_PROFILE_ID.to_string(),
+                name: "Default".to_string(),
+            }],
+        }
+    }
+}
+
+impl Profiles {
+    /// `None` until a second profile has been created, or if the file can't be read.
+    pub fn load(root: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(root.join(PROFILES_FILENAME)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    pub fn load_or_default(root: &Path) -> Self {
+        Self::load(root).unwrap_or_default()
+    }
+
+    pub fn save(&self, root: &Path) -> std::io::Result<()> {
+        std::fs::create_dir_all(root)?;
+
+        // Written aside and renamed, so a crash never leaves the app without a profile list.
+        let path = root.join(PROFILES_FILENAME);
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_vec_pretty(self)?)?;
+        std::fs::rename(tmp, path)
+    }
+
+    /// Falls back to the default profile if the active one has gone missing.
+    pub fn active(&self) -> Profile {
+        self.get(&self.active)
+            .or_else(|| self.get(DEFAULT_PROFILE_ID))
+            .cloned()
+            .unwrap_or_else(|| Self::default().profiles.remove(0))
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Profile> {
+        self.profiles.iter().find(|p| p.id == id)
+    }
+
+    pub fn create(&mut self, name: &str) -> Profile {
+        let base = slug(name);
+
+        let mut id = base.clone();
+        let mut n = 2;
+        while id == DEFAULT_PROFILE_ID || self.get(&id).is_some() {
+            id = format!("{}-{}", base, n);
+            n += 1;
+        }
+
+        let profile = Profile {
+            id,
+            name: name.trim().to_string(),
+        };
+        self.profiles.push(profile.clone());
+        profile
+    }
+
+    /// The default and the active profile can't be removed.
+    pub fn remove(&mut self, id: &str) -> bool {
+        if id == DEFAULT_PROFILE_ID || id == self.active().id {
+            return false;
+        }
+
+        let len = self.profiles.len();
+        self.profiles.retain(|p| p.id != id);
+        self.profiles.len() != len
+    }
+
+    pub fn activate(&mut self, id: &str) -> bool {
+        if self.get(id).is_none() {
+            return false;
+        }
+        self.active = id.to_string();
+        true
+    }
+}
+
+/// Where the data of profile `id` lives under `root`.
+pub fn dir(root: &Path, id: &str) -> PathBuf {
+    if id == DEFAULT_PROFILE_ID {
+        root.to_path_buf()
+    } else {
+        root.join(PROFILES_DIR).join(id)
+    }
+}
+
+/// Points every plugin at the active profile's directory under `root`, keeping `root` around
+/// for what profiles share (see [`crate::resolve_shared`]). Does nothing until profiles exist.
+///
+/// Like [`crate::init`], must run before any plugin asks for a path.
+pub fn apply(root: &Path) -> Option<Profile> {
+    let profile = Profiles::load(root)?.active();
+
+    std::env::set_var(crate::ENV_KEY, dir(root, &profile.id));
+    std::env::set_var(crate::SHARED_ENV_KEY, root);
+    Some(profile)
+}
+
+fn slug(name: &str) -> String {
+    let slug = name
+        .trim()
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+
+    if slug.is_empty() {
+        "profile".to_string()
+    } else {
+        slug
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profiles() {
+        let mut profiles = Profiles::default();
+
+        let work = profiles.create(" Work ");
+        assert_eq!(work.id, "work");
+        assert_eq!(work.name, "Work");
+        assert_eq!(profiles.create("work").id, "work-2");
+        assert_eq!(profiles.create("Default").id, "default-2");
+        assert_eq!(profiles.create("!!").id, "profile");
+
+        assert!(profiles.activate("work"));
+        assert!(!profiles.activate("missing"));
+        assert_eq!(profiles.active(), work);
+
+        assert!(!profiles.remove("work"));
+        assert!(!profiles.remove(DEFAULT_PROFILE_ID));
+        assert!(profiles.remove("work-2"));
+        assert!(!profiles.remove("work-2"));
+
+        profiles.active = "missing".to_string();
+        assert_eq!(profiles.active().id, DEFAULT_PROFILE_ID);
+    }
+
+    #[test]
+    fn test_dir() {
+        let root = Path::new("/data");
+        assert_eq!(dir(root, DEFAULT_PROFILE_ID), PathBuf::from("/data"));
+        assert_eq!(dir(root, "work"), PathBuf::from("/data/profiles/work"));
+    }
+}
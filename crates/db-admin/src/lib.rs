@@ -79,18 +79,24 @@ impl std::ops::Deref for AdminDatabase {
     }
 }
 
+macro_rules! migration {
+    ($name:literal) => {
+        hypr_db_core::Migration::new($name, include_str!(concat!("./", $name, ".sql")))
+    };
+}
+
 // Append only. Do not reorder.
-const MIGRATIONS: [&str; 5] = [
-    include_str!("./billings_migration.sql"),
-    include_str!("./devices_migration.sql"),
-    include_str!("./integrations_migration.sql"),
-    include_str!("./accounts_migration.sql"),
-    include_str!("./users_migration.sql"),
+const MIGRATIONS: [hypr_db_core::Migration; 5] = [
+    migration!("billings_migration"),
+    migration!("devices_migration"),
+    migration!("integrations_migration"),
+    migration!("accounts_migration"),
+    migration!("users_migration"),
 ];
 
 pub async fn migrate(db: &AdminDatabase) -> Result<(), crate::Error> {
     let conn = db.conn()?;
-    hypr_db_core::migrate(&conn, MIGRATIONS.to_vec()).await?;
+    hypr_db_core::migrate(&conn, &MIGRATIONS).await?;
     Ok(())
 }
 
@@ -14,6 +14,12 @@ pub enum Error {
     InvalidDatabaseConfig(String),
     #[error("invalid input: {0}")]
     InvalidInput(String),
+    #[error("migration {0} ({1}) failed: {2}")]
+    MigrationFailed(i32, String, String),
+    #[error("snapshot error: {0}")]
+    SnapshotError(#[from] rusqlite::Error),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
 }
 
 impl Serialize for Error {
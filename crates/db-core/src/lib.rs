@@ -1,11 +1,14 @@
 use std::sync::Arc;
 
 mod errors;
+mod snapshot;
 pub use errors::*;
+pub use snapshot::*;
 
 pub use libsql;
 
 pub const MIGRATION_TABLE_SQL: &str = include_str!("./migration.sql");
+pub const SCHEMA_VERSION_TABLE_SQL: &str = include_str!("./schema_version.sql");
 
 #[derive(Clone)]
 pub enum Database {
@@ -24,6 +27,27 @@ impl Database {
     pub async fn sync(&self) -> Result<(), crate::Error> {
         Ok(())
     }
+
+    pub fn is_local(&self) -> bool {
+        matches!(self, Database::StaticConnection(_))
+    }
+
+    // `VACUUM INTO` produces a consistent snapshot even while the database is open.
+    pub async fn backup(&self, path: impl AsRef<std::path::Path>) -> Result<(), crate::Error> {
+        let path = path.as_ref();
+        if path.exists() {
+            return Err(crate::Error::InvalidInput(format!(
+                "backup target already exists: {}",
+                path.display()
+            )));
+        }
+
+        let conn = self.conn()?;
+        conn.execute("VACUUM INTO ?", vec![path.to_string_lossy().to_string()])
+            .await?;
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Default)]
@@ -128,6 +152,20 @@ impl TrackingSource {
                 Ok(version)
             }
             TrackingSource::Table => {
+                // Not there until the first `migrate`, and a dry run shouldn't create it.
+                let exists = conn
+                    .query(
+                        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = '_migrations'",
+                        (),
+                    )
+                    .await?
+                    .next()
+                    .await?
+                    .is_some();
+                if !exists {
+                    return Ok(0);
+                }
+
                 let mut result = conn
                     .query("SELECT MAX(version) FROM _migrations", ())
                     .await?;
@@ -165,31 +203,118 @@ impl TrackingSource {
     }
 }
 
-pub async fn migrate(
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    pub name: &'static str,
+    pub sql: &'static str,
+}
+
+impl Migration {
+    pub const fn new(name: &'static str, sql: &'static str) -> Self {
+        Self { name, sql }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct PendingMigration {
+    pub version: i32,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct MigrationReport {
+    pub current_version: i32,
+    pub latest_version: i32,
+    pub pending: Vec<PendingMigration>,
+}
+
+impl MigrationReport {
+    pub fn is_up_to_date(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+async fn plan(
     conn: &libsql::Connection,
-    migrations: Vec<impl AsRef<str>>,
-) -> Result<(), crate::Error> {
+    migrations: &[Migration],
+) -> Result<(TrackingSource, MigrationReport), crate::Error> {
     let tracking = TrackingSource::new(conn).await?;
 
+    let current_version = tracking.get(conn).await?;
+    if current_version > migrations.len() as i32 {
+        tracing::warn!(current_version, "schema_version_ahead_of_migrations");
+    }
+
+    let pending = migrations
+        .iter()
+        .enumerate()
+        .skip(current_version.max(0) as usize)
+        .map(|(i, m)| PendingMigration {
+            version: i as i32 + 1,
+            name: m.name.to_string(),
+        })
+        .collect();
+
+    let report = MigrationReport {
+        current_version,
+        latest_version: migrations.len() as i32,
+        pending,
+    };
+
+    Ok((tracking, report))
+}
+
+/// Dry-run. Reports which migrations `migrate` would apply, without applying any of them.
+pub async fn pending_migrations(
+    conn: &libsql::Connection,
+    migrations: &[Migration],
+) -> Result<MigrationReport, crate::Error> {
+    let (_, report) = plan(conn, migrations).await?;
+    Ok(report)
+}
+
+/// Applies all pending migrations in a single transaction, recording each one in `schema_version`.
+/// Either every pending migration is applied, or none is.
+pub async fn migrate(
+    conn: &libsql::Connection,
+    migrations: &[Migration],
+) -> Result<MigrationReport, crate::Error> {
+    let (tracking, report) = plan(conn, migrations).await?;
     if matches!(tracking, TrackingSource::Table) {
         conn.execute(MIGRATION_TABLE_SQL, ()).await?;
     }
+    conn.execute(SCHEMA_VERSION_TABLE_SQL, ()).await?;
 
-    let current_version: i32 = tracking.get(conn).await?;
-    let latest_version = migrations.len() as i32;
+    let tx = conn.transaction().await?;
 
-    if current_version < latest_version {
-        let tx = conn.transaction().await?;
+    for pending in &report.pending {
+        let migration = &migrations[(pending.version - 1) as usize];
 
-        for migration in migrations.iter().skip(current_version as usize) {
-            tx.execute(migration.as_ref(), ()).await?;
-        }
+        tx.execute(migration.sql, ()).await.map_err(|e| {
+            crate::Error::MigrationFailed(pending.version, pending.name.clone(), e.to_string())
+        })?;
+    }
+
+    // Databases migrated before `schema_version` existed get their history backfilled here.
+    for (i, migration) in migrations.iter().enumerate() {
+        tx.execute(
+            "INSERT OR IGNORE INTO schema_version (version, name) VALUES (?, ?)",
+            (i as i32 + 1, migration.name),
+        )
+        .await?;
+    }
+
+    if !report.is_up_to_date() {
+        tracking.set(&tx, report.latest_version).await?;
+    }
+
+    tx.commit().await?;
 
-        tracking.set(&tx, latest_version).await?;
-        tx.commit().await?;
+    for pending in &report.pending {
+        tracing::info!(version = pending.version, name = %pending.name, "migration_applied");
     }
 
-    Ok(())
+    Ok(report)
 }
 
 pub trait SqlTable {
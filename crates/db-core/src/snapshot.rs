@@ -0,0 +1,113 @@
+use std::path::Path;
+use std::time::Duration;
+
+use rusqlite::backup::{Backup, StepResult};
+use rusqlite::{Connection, OpenFlags};
+
+/// Small enough that writers are never held up for long.
+const PAGES_PER_STEP: i32 = 256;
+const RETRY_AFTER: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, specta::Type)]
+pub struct SnapshotProgress {
+    pub copied_pages: i32,
+    pub total_pages: i32,
+}
+
+/// Copies the database file at `source` to `target` with SQLite's online backup API, which reads
+/// through the WAL and starts over on pages written mid-way, so the copy is consistent even
+/// while the app keeps writing. Blocks until done.
+///
+/// `target` only appears once the copy is complete.
+pub fn snapshot(
+    source: impl AsRef<Path>,
+    target: impl AsRef<Path>,
+    mut on_progress: impl FnMut(SnapshotProgress),
+) -> Result<(), crate::Error> {
+    let target = target.as_ref();
+    if target.exists() {
+        return Err(crate::Error::InvalidInput(format!(
+            "snapshot target already exists: {}",
+            target.display()
+        )));
+    }
+
+    let partial = target.with_extension("partial");
+    let result = copy(source.as_ref(), &partial, &mut on_progress);
+    match result {
+        Ok(()) => std::fs::rename(&partial, target).map_err(Into::into),
+        Err(e) => {
+            let _ = std::fs::remove_file(&partial);
+            Err(e)
+        }
+    }
+}
+
+fn copy(
+    source: &Path,
+    target: &Path,
+    on_progress: &mut impl FnMut(SnapshotProgress),
+) -> Result<(), crate::Error> {
+    let source = Connection::open_with_flags(
+        source,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )?;
+    let mut target = Connection::open(target)?;
+    let backup = Backup::new(&source, &mut target)?;
+
+    loop {
+        let step = backup.step(PAGES_PER_STEP)?;
+
+        let progress = backup.progress();
+        on_progress(SnapshotProgress {
+            copied_pages: progress.pagecount - progress.remaining,
+            total_pages: progress.pagecount,
+        });
+
+        match step {
+            StepResult::Done => return Ok(()),
+            StepResult::Busy | StepResult::Locked => std::thread::sleep(RETRY_AFTER),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("db.sqlite");
+        let target = dir.path().join("snapshot.sqlite");
+
+        let conn = Connection::open(&source).unwrap();
+        conn.pragma_update(None, "journal_mode", "WAL").unwrap();
+        conn.execute("CREATE TABLE notes (body TEXT NOT NULL)", ())
+            .unwrap();
+        for i in 0..2000 {
+            conn.execute(
+                "INSERT INTO notes (body) VALUES (?)",
+                [format!("{} {}", i, "note ".repeat(200))],
+            )
+            .unwrap();
+        }
+
+        let mut updates = vec![];
+        snapshot(&source, &target, |progress| updates.push(progress)).unwrap();
+
+        let last = updates.last().unwrap();
+        assert!(updates.len() > 1);
+        assert_eq!(last.copied_pages, last.total_pages);
+
+        let copy = Connection::open(&target).unwrap();
+        let count: i64 = copy
+            .query_row("SELECT COUNT(*) FROM notes", (), |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 2000);
+
+        assert!(snapshot(&source, &target, |_| {}).is_err());
+        assert!(!target.with_extension("partial").exists());
+    }
+}
@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS attachments (
+  hash TEXT PRIMARY KEY,
+  mime_type TEXT NOT NULL,
+  size_bytes INTEGER NOT NULL,
+  created_at TEXT NOT NULL
+);
@@ -0,0 +1,270 @@
+use super::{Attachment, AttachmentBlob, AttachmentUsage, UserDatabase};
+
+impl UserDatabase {
+    pub async fn insert_attachment_blob(
+        &self,
+        blob: AttachmentBlob,
+    ) -> Result<AttachmentBlob, crate::Error> {
+        let conn = self.conn()?;
+
+        conn.execute(
+            "INSERT OR IGNORE INTO attachments (
+                hash,
+                mime_type,
+                size_bytes,
+                created_at
+            ) VALUES (?, ?, ?, ?)",
+            (
+                blob.hash.clone(),
+                blob.mime_type,
+                blob.size_bytes,
+                blob.created_at.to_rfc3339(),
+            ),
+        )
+        .await?;
+
+        let mut rows = conn
+            .query("SELECT * FROM attachments WHERE hash = ?", vec![blob.hash])
+            .await?;
+
+        let row = rows.next().await?.unwrap();
+        let blob: AttachmentBlob = libsql::de::from_row(&row)?;
+        Ok(blob)
+    }
+
+    pub async fn link_attachment(
+        &self,
+        attachment: Attachment,
+    ) -> Result<Attachment, crate::Error> {
+        let conn = self.conn()?;
+
+        conn.execute(
+            "INSERT OR REPLACE INTO session_attachments (
+                id,
+                session_id,
+                hash,
+                file_name,
+                kind,
+                created_at
+            ) VALUES (?, ?, ?, ?, ?, ?)",
+            vec![
+                attachment.id.clone(),
+                attachment.session_id,
+                attachment.hash,
+                attachment.file_name,
+                attachment.kind.to_string(),
+                attachment.created_at.to_rfc3339(),
+            ],
+        )
+        .await?;
+
+        let attachment = self.get_attachment(attachment.id).await?.unwrap();
+        Ok(attachment)
+    }
+
+    pub async fn unlink_attachment(&self, id: impl Into<String>) -> Result<(), crate::Error> {
+        let conn = self.conn()?;
+
+        conn.execute(
+            "DELETE FROM session_attachments WHERE id = ?",
+            vec![id.into()],
+        )
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_attachment(
+        &self,
+        id: impl Into<String>,
+    ) -> Result<Option<Attachment>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT sa.id, sa.session_id, sa.hash, sa.file_name, sa.kind, a.mime_type, a.size_bytes, sa.created_at
+                 FROM session_attachments sa
+                 JOIN attachments a ON sa.hash = a.hash
+                 WHERE sa.id = ?",
+                vec![id.into()],
+            )
+            .await?;
+
+        match rows.next().await? {
+            None => Ok(None),
+            Some(row) => {
+                let item: Attachment = libsql::de::from_row(&row)?;
+                Ok(Some(item))
+            }
+        }
+    }
+
+    pub async fn list_session_attachments(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Vec<Attachment>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT sa.id, sa.session_id, sa.hash, sa.file_name, sa.kind, a.mime_type, a.size_bytes, sa.created_at
+                 FROM session_attachments sa
+                 JOIN attachments a ON sa.hash = a.hash
+                 WHERE sa.session_id = ?
+                 ORDER BY sa.created_at ASC",
+                vec![session_id.into()],
+            )
+            .await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item: Attachment = libsql::de::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    pub async fn attachment_ref_count(&self, hash: impl Into<String>) -> Result<i64, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT COUNT(*) FROM session_attachments WHERE hash = ?",
+                vec![hash.into()],
+            )
+            .await?;
+
+        let count: i64 = rows
+            .next()
+            .await?
+            .map(|r| r.get::<i64>(0))
+            .transpose()?
+            .unwrap_or(0);
+        Ok(count)
+    }
+
+    pub async fn list_orphaned_attachment_blobs(
+        &self,
+    ) -> Result<Vec<AttachmentBlob>, crate::Error> {
+        let conn = self.conn()?;
+
+        // Links of sessions that no longer exist do not keep a blob alive.
+        let mut rows = conn
+            .query(
+                "SELECT a.* FROM attachments a
+                 WHERE NOT EXISTS (
+                   SELECT 1 FROM session_attachments sa
+                   JOIN sessions s ON sa.session_id = s.id
+                   WHERE sa.hash = a.hash
+                 )",
+                (),
+            )
+            .await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item: AttachmentBlob = libsql::de::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    pub async fn delete_attachment_blob(
+        &self,
+        hash: impl Into<String>,
+    ) -> Result<(), crate::Error> {
+        let conn = self.conn()?;
+        let hash = hash.into();
+
+        conn.execute(
+            "DELETE FROM session_attachments WHERE hash = ?",
+            vec![hash.clone()],
+        )
+        .await?;
+
+        conn.execute("DELETE FROM attachments WHERE hash = ?", vec![hash])
+            .await?;
+        Ok(())
+    }
+
+    pub async fn attachment_usage(&self) -> Result<AttachmentUsage, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT COUNT(*), COALESCE(SUM(size_bytes), 0) FROM attachments",
+                (),
+            )
+            .await?;
+        let row = rows.next().await?.unwrap();
+        let (blob_count, total_bytes): (i64, i64) = (row.get(0)?, row.get(1)?);
+
+        let orphaned = self.list_orphaned_attachment_blobs().await?;
+
+        Ok(AttachmentUsage {
+            blob_count,
+            total_bytes,
+            orphaned_count: orphaned.len() as i64,
+            orphaned_bytes: orphaned.iter().map(|b| b.size_bytes).sum(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests::{insert_session, setup_db},
+        Attachment, AttachmentBlob, AttachmentKind,
+    };
+
+    #[tokio::test]
+    async fn test_attachments() {
+        let db = setup_db().await;
+
+        let session = insert_session(&db).await;
+
+        let blob = db
+            .insert_attachment_blob(AttachmentBlob {
+                hash: "abc".to_string(),
+                mime_type: "image/png".to_string(),
+                size_bytes: 42,
+                created_at: chrono::Utc::now(),
+            })
+            .await
+            .unwrap();
+
+        for _ in 0..2 {
+            db.link_attachment(Attachment {
+                id: uuid::Uuid::new_v4().to_string(),
+                session_id: session.id.clone(),
+                hash: blob.hash.clone(),
+                file_name: "image.png".to_string(),
+                kind: AttachmentKind::Image,
+                mime_type: blob.mime_type.clone(),
+                size_bytes: blob.size_bytes,
+                created_at: chrono::Utc::now(),
+            })
+            .await
+            .unwrap();
+        }
+
+        let attachments = db.list_session_attachments(&session.id).await.unwrap();
+        assert_eq!(attachments.len(), 2);
+        assert_eq!(attachments[0].kind, AttachmentKind::Image);
+        assert_eq!(db.attachment_ref_count(&blob.hash).await.unwrap(), 2);
+
+        let usage = db.attachment_usage().await.unwrap();
+        assert_eq!(usage.blob_count, 1);
+        assert_eq!(usage.total_bytes, 42);
+        assert_eq!(usage.orphaned_count, 0);
+
+        for attachment in attachments {
+            db.unlink_attachment(attachment.id).await.unwrap();
+        }
+
+        let orphaned = db.list_orphaned_attachment_blobs().await.unwrap();
+        assert_eq!(orphaned.len(), 1);
+
+        db.delete_attachment_blob(&blob.hash).await.unwrap();
+        assert_eq!(db.attachment_usage().await.unwrap().blob_count, 0);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::user_common_derives;
+
+user_common_derives! {
+    #[derive(strum::EnumString, strum::Display)]
+    pub enum AttachmentKind {
+        #[serde(rename = "audio")]
+        #[strum(serialize = "audio")]
+        Audio,
+        #[serde(rename = "image")]
+        #[strum(serialize = "image")]
+        Image,
+        #[serde(rename = "other")]
+        #[strum(serialize = "other")]
+        Other,
+    }
+}
+
+user_common_derives! {
+    pub struct AttachmentBlob {
+        pub hash: String,
+        pub mime_type: String,
+        pub size_bytes: i64,
+        pub created_at: chrono::DateTime<chrono::Utc>,
+    }
+}
+
+user_common_derives! {
+    pub struct Attachment {
+        pub id: String,
+        pub session_id: String,
+        pub hash: String,
+        pub file_name: String,
+        pub kind: AttachmentKind,
+        pub mime_type: String,
+        pub size_bytes: i64,
+        pub created_at: chrono::DateTime<chrono::Utc>,
+    }
+}
+
+user_common_derives! {
+    pub struct AttachmentUsage {
+        pub blob_count: i64,
+        pub total_bytes: i64,
+        pub orphaned_count: i64,
+        pub orphaned_bytes: i64,
+    }
+}
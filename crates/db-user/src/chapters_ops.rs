@@ -0,0 +1,98 @@
+use super::{Chapter, UserDatabase};
+
+impl UserDatabase {
+    pub async fn list_session_chapters(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Vec<Chapter>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT * FROM session_chapters WHERE session_id = ? ORDER BY start_ms ASC",
+                vec![session_id.into()],
+            )
+            .await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item: Chapter = libsql::de::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    /// Chapters are always computed for the whole transcript, so a new pass replaces the old one.
+    pub async fn replace_session_chapters(
+        &self,
+        session_id: impl Into<String>,
+        chapters: Vec<Chapter>,
+    ) -> Result<(), crate::Error> {
+        let session_id = session_id.into();
+        let conn = self.conn()?;
+
+        conn.execute(
+            "DELETE FROM session_chapters WHERE session_id = ?",
+            vec![session_id.clone()],
+        )
+        .await?;
+
+        for chapter in chapters {
+            conn.execute(
+                "INSERT INTO session_chapters (
+                    id,
+                    session_id,
+                    title,
+                    start_ms,
+                    end_ms
+                ) VALUES (?, ?, ?, ?, ?)",
+                libsql::params![
+                    chapter.id,
+                    session_id.clone(),
+                    chapter.title,
+                    chapter.start_ms,
+                    chapter.end_ms,
+                ],
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests::{insert_session, setup_db},
+        Chapter,
+    };
+
+    #[tokio::test]
+    async fn test_chapters() {
+        let db = setup_db().await;
+
+        let session = insert_session(&db).await;
+
+        let chapter = |title: &str, start_ms, end_ms| Chapter {
+            id: uuid::Uuid::new_v4().to_string(),
+            session_id: session.id.clone(),
+            title: title.to_string(),
+            start_ms,
+            end_ms,
+        };
+
+        db.replace_session_chapters(&session.id, vec![chapter("Old", 0, 1000)])
+            .await
+            .unwrap();
+        db.replace_session_chapters(
+            &session.id,
+            vec![chapter("Budget", 5000, 9000), chapter("Intro", 0, 5000)],
+        )
+        .await
+        .unwrap();
+
+        let chapters = db.list_session_chapters(&session.id).await.unwrap();
+        let titles: Vec<_> = chapters.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(titles, ["Intro", "Budget"]);
+    }
+}
@@ -0,0 +1,13 @@
+use crate::user_common_derives;
+
+user_common_derives! {
+    /// A stretch of the transcript about one topic.
+    pub struct Chapter {
+        pub id: String,
+        pub session_id: String,
+        pub title: String,
+        /// Unix milliseconds, the same clock as transcript words.
+        pub start_ms: i64,
+        pub end_ms: i64,
+    }
+}
@@ -42,13 +42,48 @@ user_common_derives! {
         pub spoken_languages: Vec<hypr_language::Language>,
         #[serde(default)]
         pub jargons: Vec<String>,
-        pub telemetry_consent: bool,
+        #[serde(default)]
+        pub dictionary: Vec<DictionaryEntry>,
+        /// Applied to "um", "uh" and the like before transcripts are saved.
+        #[serde(default)]
+        pub filler_words: WordFilter,
+        #[serde(default)]
+        pub profanity: WordFilter,
+        /// Replaced by per-category consent in the analytics plugin. Only read once to carry an
+        /// existing opt-out over, and dropped the next time the config is saved.
+        #[serde(default, skip_serializing)]
+        #[specta(skip)]
+        #[schemars(skip)]
+        pub telemetry_consent: Option<bool>,
         pub save_recordings: Option<bool>,
         pub selected_template_id: Option<String>,
         #[specta(type = String)]
         #[schemars(with = "String", regex(pattern = "^[a-zA-Z]{2}$"))]
         #[serde(default)]
         pub summary_language: hypr_language::Language,
+        /// Finalized segments are also translated into this language while recording.
+        #[specta(type = Option<String>)]
+        #[schemars(with = "Option<String>")]
+        #[serde(default)]
+        pub translation_language: Option<hypr_language::Language>,
+        /// Saying this while no note is recording starts one, like the global shortcut. Off
+        /// when `None`; the mic is only listened to while it is set.
+        #[serde(default)]
+        pub wake_phrase: Option<String>,
+        /// Recordings are deleted this many days after the meeting. Kept when `None`.
+        #[serde(default)]
+        pub audio_retention_days: Option<u32>,
+        /// Transcripts are cleared this many months after the meeting. Notes, enhanced or not,
+        /// are always kept. Kept when `None`.
+        #[serde(default)]
+        pub transcript_retention_months: Option<u32>,
+        /// The latest captions are kept in this text file while recording, for streaming tools
+        /// like OBS to show as a text source. Off when `None`.
+        #[serde(default)]
+        pub caption_file: Option<String>,
+        /// Lines of captions kept in `caption_file`. Two when `None`.
+        #[serde(default)]
+        pub caption_lines: Option<u32>,
     }
 }
 
@@ -59,20 +94,58 @@ impl Default for ConfigGeneral {
             display_language: hypr_language::ISO639::En.into(),
             spoken_languages: vec![hypr_language::ISO639::En.into()],
             jargons: vec![],
-            telemetry_consent: true,
+            dictionary: vec![],
+            filler_words: WordFilter::default(),
+            profanity: WordFilter::default(),
+            telemetry_consent: None,
             save_recordings: Some(false),
             selected_template_id: None,
             summary_language: hypr_language::ISO639::En.into(),
+            translation_language: None,
+            wake_phrase: None,
+            audio_retention_days: None,
+            transcript_retention_months: None,
+            caption_file: None,
+            caption_lines: None,
         }
     }
 }
 
+user_common_derives! {
+    /// A name or term transcripts should always spell the same way.
+    pub struct DictionaryEntry {
+        pub term: String,
+        /// What the transcriber hears instead, e.g. "hyper note" for "Hyprnote".
+        #[serde(default)]
+        pub sounds_like: Vec<String>,
+    }
+}
+
+user_common_derives! {
+    #[derive(Default, Copy)]
+    pub enum WordFilter {
+        #[default]
+        #[serde(rename = "keep")]
+        Keep,
+        #[serde(rename = "remove")]
+        Remove,
+        #[serde(rename = "mask")]
+        Mask,
+    }
+}
+
 user_common_derives! {
     pub struct ConfigNotification {
         pub before: bool,
         pub auto: bool,
         #[serde(rename = "ignoredPlatforms")]
         pub ignored_platforms: Option<Vec<String>>,
+        /// Notifies once a recording has run this many minutes. Off when `None`.
+        #[serde(default)]
+        pub recording_length_alert_minutes: Option<u32>,
+        /// Notifies when a recording runs past the scheduled end of its calendar event.
+        #[serde(default)]
+        pub recording_overrun_alert: bool,
     }
 }
 
@@ -82,6 +155,8 @@ impl Default for ConfigNotification {
             before: true,
             auto: true,
             ignored_platforms: None,
+            recording_length_alert_minutes: None,
+            recording_overrun_alert: false,
         }
     }
 }
@@ -0,0 +1,94 @@
+use super::{
+    Event, GetSessionFilter, ListEventFilter, ListEventFilterCommon, ListEventFilterSpecific,
+    Session, UserDatabase,
+};
+
+/// Calendars are synced from now until this many days ahead.
+pub const EVENT_SYNC_WINDOW_DAYS: i64 = 100;
+
+// More than a calendar has in the window, short of a runaway one.
+const EVENT_SYNC_LIMIT: u32 = 700;
+
+/// Occurrences of a recurring series share an id, so the start is folded in to tell them
+/// apart. Google gives each occurrence its own id already.
+pub fn event_tracking_id(event: &hypr_calendar_interface::Event) -> String {
+    if event.is_recurring && event.platform != hypr_calendar_interface::Platform::Google {
+        format!(
+            "{}__HYPR__{}",
+            event.id,
+            event.start_date.format("%Y%m%d%H%M%S")
+        )
+    } else {
+        event.id.clone()
+    }
+}
+
+impl UserDatabase {
+    /// The user's events in the sync window, with the note attached to each, if any.
+    pub async fn list_events_with_session(
+        &self,
+        user_id: impl Into<String>,
+    ) -> Result<Vec<(Event, Option<Session>)>, crate::Error> {
+        let now = chrono::Utc::now();
+        let events = self
+            .list_events(Some(ListEventFilter {
+                common: ListEventFilterCommon {
+                    user_id: user_id.into(),
+                    limit: Some(EVENT_SYNC_LIMIT),
+                },
+                specific: ListEventFilterSpecific::DateRange {
+                    start: now,
+                    end: now + chrono::Duration::days(EVENT_SYNC_WINDOW_DAYS),
+                },
+            }))
+            .await?;
+
+        let mut events_with_session = Vec::new();
+        for event in events {
+            let session = self
+                .get_session(GetSessionFilter::CalendarEventId(event.id.clone()))
+                .await?;
+            events_with_session.push((event, session));
+        }
+
+        Ok(events_with_session)
+    }
+}
+
+/// What a calendar sync changes, applied in one go once planned.
+#[derive(Debug, Default)]
+pub struct EventSyncState {
+    pub to_delete: Vec<Event>,
+    pub to_upsert: Vec<Event>,
+    pub to_update: Vec<Event>,
+    /// `(session_id, new_event_id)`, for notes that move to an event replacing theirs.
+    pub session_transfers: Vec<(String, String)>,
+}
+
+impl EventSyncState {
+    pub async fn execute(self, db: &UserDatabase) {
+        for event in self.to_upsert {
+            if let Err(e) = db.upsert_event(event).await {
+                tracing::error!("upsert_event_error: {}", e);
+            }
+        }
+
+        for event in self.to_update {
+            if let Err(e) = db.update_event(event).await {
+                tracing::error!("update_event_error: {}", e);
+            }
+        }
+
+        for (session_id, new_event_id) in self.session_transfers {
+            if let Err(e) = db.session_set_event(session_id, Some(new_event_id)).await {
+                tracing::error!("session_transfer_error: {}", e);
+            }
+        }
+
+        for event in self.to_delete {
+            if let Err(e) = db.delete_event(&event.id).await {
+                tracing::error!("delete_event_error: {}", e);
+            }
+        }
+    }
+}
@@ -0,0 +1,4 @@
+ALTER TABLE
+  events
+ADD
+  COLUMN meeting_link TEXT DEFAULT NULL;
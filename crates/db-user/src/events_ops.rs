@@ -41,7 +41,8 @@ impl UserDatabase {
                     end_date = :end_date,
                     google_event_url = :google_event_url,
                     participants = :participants,
-                    is_recurring = :is_recurring
+                    is_recurring = :is_recurring,
+                    meeting_link = :meeting_link
                 WHERE id = :id
                 RETURNING *",
                 libsql::named_params! {
@@ -55,6 +56,7 @@ impl UserDatabase {
                     ":google_event_url": event.google_event_url,
                     ":participants": event.participants,
                     ":is_recurring": event.is_recurring,
+                    ":meeting_link": event.meeting_link,
                 },
             )
             .await?;
@@ -87,7 +89,8 @@ impl UserDatabase {
                     end_date,
                     google_event_url,
                     participants,
-                    is_recurring
+                    is_recurring,
+                    meeting_link
                 ) VALUES (
                     :id,
                     :user_id,
@@ -99,7 +102,8 @@ impl UserDatabase {
                     :end_date,
                     :google_event_url,
                     :participants,
-                    :is_recurring
+                    :is_recurring,
+                    :meeting_link
                 ) ON CONFLICT(tracking_id) DO UPDATE SET
                     name = :name,
                     note = :note,
@@ -107,7 +111,8 @@ impl UserDatabase {
                     end_date = :end_date,
                     google_event_url = :google_event_url,
                     participants = :participants,
-                    is_recurring = :is_recurring
+                    is_recurring = :is_recurring,
+                    meeting_link = :meeting_link
                 RETURNING *",
                 libsql::named_params! {
                     ":id": event.id,
@@ -121,6 +126,7 @@ impl UserDatabase {
                     ":google_event_url": event.google_event_url,
                     ":participants": event.participants,
                     ":is_recurring": event.is_recurring,
+                    ":meeting_link": event.meeting_link,
                 },
             )
             .await?;
@@ -248,6 +254,7 @@ mod tests {
             google_event_url: None,
             participants: None,
             is_recurring: false,
+            meeting_link: None,
         };
 
         let event = db.upsert_event(event).await.unwrap();
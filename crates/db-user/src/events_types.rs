@@ -15,6 +15,31 @@ user_common_derives! {
         pub google_event_url: Option<String>,
         pub participants: Option<String>,
         pub is_recurring: bool,
+        /// Video call link from the calendar's conference data. Calendars without one
+        /// leave the link in `note`, for `parse_meeting_link` to find.
+        #[serde(default)]
+        pub meeting_link: Option<String>,
+    }
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            user_id: String::new(),
+            tracking_id: String::new(),
+            calendar_id: None,
+            name: String::new(),
+            note: String::new(),
+            start_date: now,
+            end_date: now,
+            google_event_url: None,
+            participants: None,
+            is_recurring: false,
+            meeting_link: None,
+        }
     }
 }
 
@@ -22,6 +47,8 @@ user_common_derives! {
     pub struct EventParticipant {
         pub name: Option<String>,
         pub email: Option<String>,
+        #[serde(default)]
+        pub is_organizer: bool,
     }
 }
 
@@ -1,11 +1,16 @@
+mod attachments_ops;
+mod attachments_types;
 mod calendars_ops;
 mod calendars_types;
+mod chapters_ops;
+mod chapters_types;
 mod chat_groups_ops;
 mod chat_groups_types;
 mod chat_messages_ops;
 mod chat_messages_types;
 mod config_ops;
 mod config_types;
+mod event_sync;
 mod events_ops;
 mod events_types;
 mod extensions_ops;
@@ -14,18 +19,42 @@ mod humans_ops;
 mod humans_types;
 mod organizations_ops;
 mod organizations_types;
+mod people_ops;
+mod people_types;
+mod pipelines_ops;
+mod pipelines_types;
+mod session_retranscriptions_ops;
+mod session_retranscriptions_types;
+mod session_revisions_ops;
+mod session_revisions_types;
+mod session_stats_ops;
+mod session_stats_types;
 mod sessions_ops;
 mod sessions_types;
+mod speaker_profiles_ops;
+mod speaker_profiles_types;
 mod tags_ops;
 mod tags_types;
+mod tasks_ops;
+mod tasks_types;
 mod templates_ops;
 mod templates_types;
+mod timeline_ops;
+mod timeline_types;
 
+#[allow(unused)]
+pub use attachments_ops::*;
+#[allow(unused)]
+pub use attachments_types::*;
 #[allow(unused)]
 pub use calendars_ops::*;
 #[allow(unused)]
 pub use calendars_types::*;
 #[allow(unused)]
+pub use chapters_ops::*;
+#[allow(unused)]
+pub use chapters_types::*;
+#[allow(unused)]
 pub use chat_groups_ops::*;
 #[allow(unused)]
 pub use chat_groups_types::*;
@@ -38,6 +67,8 @@ pub use config_ops::*;
 #[allow(unused)]
 pub use config_types::*;
 #[allow(unused)]
+pub use event_sync::*;
+#[allow(unused)]
 pub use events_ops::*;
 #[allow(unused)]
 pub use events_types::*;
@@ -54,22 +85,54 @@ pub use organizations_ops::*;
 #[allow(unused)]
 pub use organizations_types::*;
 #[allow(unused)]
+pub use people_ops::*;
+#[allow(unused)]
+pub use people_types::*;
+#[allow(unused)]
+pub use pipelines_ops::*;
+#[allow(unused)]
+pub use pipelines_types::*;
+#[allow(unused)]
+pub use session_retranscriptions_ops::*;
+#[allow(unused)]
+pub use session_retranscriptions_types::*;
+#[allow(unused)]
+pub use session_revisions_ops::*;
+#[allow(unused)]
+pub use session_revisions_types::*;
+#[allow(unused)]
+pub use session_stats_ops::*;
+#[allow(unused)]
+pub use session_stats_types::*;
+#[allow(unused)]
 pub use sessions_ops::*;
 #[allow(unused)]
 pub use sessions_types::*;
 #[allow(unused)]
+pub use speaker_profiles_ops::*;
+#[allow(unused)]
+pub use speaker_profiles_types::*;
+#[allow(unused)]
 pub use tags_ops::*;
 #[allow(unused)]
 pub use tags_types::*;
 #[allow(unused)]
+pub use tasks_ops::*;
+#[allow(unused)]
+pub use tasks_types::*;
+#[allow(unused)]
 pub use templates_ops::*;
 #[allow(unused)]
 pub use templates_types::*;
+#[allow(unused)]
+pub use timeline_ops::*;
+#[allow(unused)]
+pub use timeline_types::*;
 
 pub mod init;
 pub mod seed;
 
-pub use hypr_db_core::{Database, Error};
+pub use hypr_db_core::{Database, Error, MigrationReport};
 
 #[macro_export]
 macro_rules! user_common_derives {
@@ -129,38 +192,61 @@ impl std::ops::Deref for UserDatabase {
     }
 }
 
+macro_rules! migration {
+    ($name:literal) => {
+        hypr_db_core::Migration::new($name, include_str!(concat!("./", $name, ".sql")))
+    };
+}
+
 // Append only. Do not reorder.
-const MIGRATIONS: [&str; 25] = [
-    include_str!("./calendars_migration.sql"),
-    include_str!("./configs_migration.sql"),
-    include_str!("./events_migration.sql"),
-    include_str!("./humans_migration.sql"),
-    include_str!("./organizations_migration.sql"),
-    include_str!("./sessions_migration.sql"),
-    include_str!("./session_participants_migration.sql"),
-    include_str!("./templates_migration.sql"),
-    include_str!("./chat_groups_migration.sql"),
-    include_str!("./chat_messages_migration.sql"),
-    include_str!("./extension_mappings_migration.sql"),
-    include_str!("./tags_migration.sql"),
-    include_str!("./tag_sessions_migration.sql"),
-    include_str!("./calendars_migration_1.sql"),
-    include_str!("./sessions_migration_1.sql"),
-    include_str!("./sessions_migration_2.sql"),
-    include_str!("./sessions_migration_3.sql"),
-    include_str!("./sessions_migration_4.sql"),
-    include_str!("./chat_groups_migration_1.sql"),
-    include_str!("./events_migration_1.sql"),
-    include_str!("./session_participants_migration_1.sql"),
-    include_str!("./events_migration_2.sql"),
-    include_str!("./chat_messages_migration_1.sql"),
-    include_str!("./chat_messages_migration_2.sql"),
-    include_str!("./templates_migration_1.sql"),
+const MIGRATIONS: [hypr_db_core::Migration; 37] = [
+    migration!("calendars_migration"),
+    migration!("configs_migration"),
+    migration!("events_migration"),
+    migration!("humans_migration"),
+    migration!("organizations_migration"),
+    migration!("sessions_migration"),
+    migration!("session_participants_migration"),
+    migration!("templates_migration"),
+    migration!("chat_groups_migration"),
+    migration!("chat_messages_migration"),
+    migration!("extension_mappings_migration"),
+    migration!("tags_migration"),
+    migration!("tag_sessions_migration"),
+    migration!("calendars_migration_1"),
+    migration!("sessions_migration_1"),
+    migration!("sessions_migration_2"),
+    migration!("sessions_migration_3"),
+    migration!("sessions_migration_4"),
+    migration!("chat_groups_migration_1"),
+    migration!("events_migration_1"),
+    migration!("session_participants_migration_1"),
+    migration!("events_migration_2"),
+    migration!("chat_messages_migration_1"),
+    migration!("chat_messages_migration_2"),
+    migration!("templates_migration_1"),
+    migration!("attachments_migration"),
+    migration!("session_attachments_migration"),
+    migration!("tasks_migration"),
+    migration!("timeline_migration"),
+    migration!("session_stats_migration"),
+    migration!("chapters_migration"),
+    migration!("events_migration_3"),
+    migration!("speaker_profiles_migration"),
+    migration!("session_retranscriptions_migration"),
+    migration!("session_revisions_migration"),
+    migration!("pipelines_migration"),
+    migration!("session_revisions_migration_1"),
 ];
 
+pub async fn pending_migrations(db: &UserDatabase) -> Result<MigrationReport, crate::Error> {
+    let conn = db.conn()?;
+    hypr_db_core::pending_migrations(&conn, &MIGRATIONS).await
+}
+
 pub async fn migrate(db: &UserDatabase) -> Result<(), crate::Error> {
     let conn = db.conn()?;
-    hypr_db_core::migrate(&conn, MIGRATIONS.to_vec()).await?;
+    hypr_db_core::migrate(&conn, &MIGRATIONS).await?;
 
     hypr_db_script::conversation_to_words::run(&conn).await;
 
@@ -170,7 +256,7 @@ pub async fn migrate(db: &UserDatabase) -> Result<(), crate::Error> {
 #[cfg(test)]
 mod tests {
     use super::UserDatabase;
-    use crate::{init, migrate};
+    use crate::{init, migrate, pending_migrations, Human, Session, MIGRATIONS};
     use hypr_db_core::DatabaseBuilder;
 
     pub async fn setup_db() -> UserDatabase {
@@ -180,10 +266,78 @@ mod tests {
         user_db
     }
 
+    pub async fn insert_user(db: &UserDatabase) -> Human {
+        db.upsert_human(Human {
+            full_name: Some("John Doe".to_string()),
+            ..Human::default()
+        })
+        .await
+        .unwrap()
+    }
+
+    /// An empty session, not yet saved.
+    pub fn new_session(user_id: impl Into<String>) -> Session {
+        Session {
+            id: uuid::Uuid::new_v4().to_string(),
+            user_id: user_id.into(),
+            created_at: chrono::Utc::now(),
+            visited_at: chrono::Utc::now(),
+            calendar_event_id: None,
+            title: "Test Session".to_string(),
+            raw_memo_html: "".to_string(),
+            enhanced_memo_html: None,
+            conversations: vec![],
+            words: vec![],
+            record_start: None,
+            record_end: None,
+            pre_meeting_memo_html: None,
+        }
+    }
+
+    /// An empty session, saved for a new user.
+    pub async fn insert_session(db: &UserDatabase) -> Session {
+        let user = insert_user(db).await;
+        db.upsert_session(new_session(user.id)).await.unwrap()
+    }
+
     #[tokio::test]
     async fn test_seed() {
         let db = setup_db().await;
         let user_id = uuid::Uuid::new_v4().to_string();
         init::seed(&db, user_id).await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_migrations() {
+        let base_db = DatabaseBuilder::default().memory().build().await.unwrap();
+        let db = UserDatabase::from(base_db);
+
+        let report = pending_migrations(&db).await.unwrap();
+        assert_eq!(report.current_version, 0);
+        assert_eq!(report.pending.len(), MIGRATIONS.len());
+        assert_eq!(report.pending[0].version, 1);
+        assert_eq!(report.pending[0].name, "calendars_migration");
+
+        // A dry run leaves the database as it was.
+        let conn = db.conn().unwrap();
+        let mut rows = conn
+            .query("SELECT COUNT(*) FROM sqlite_master", ())
+            .await
+            .unwrap();
+        let count: i64 = rows.next().await.unwrap().unwrap().get(0).unwrap();
+        assert_eq!(count, 0);
+
+        migrate(&db).await.unwrap();
+
+        let report = pending_migrations(&db).await.unwrap();
+        assert!(report.is_up_to_date());
+        assert_eq!(report.current_version, MIGRATIONS.len() as i32);
+
+        let mut rows = conn
+            .query("SELECT COUNT(*) FROM schema_version", ())
+            .await
+            .unwrap();
+        let count: i64 = rows.next().await.unwrap().unwrap().get(0).unwrap();
+        assert_eq!(count, MIGRATIONS.len() as i64);
+    }
 }
@@ -0,0 +1,369 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use super::{Event, EventParticipant, ListPeopleFilter, Person, PersonMeeting, UserDatabase};
+
+/// Domains of personal mail providers, which say nothing about where someone works.
+const PERSONAL_EMAIL_DOMAINS: [&str; 11] = [
+    "gmail.com",
+    "googlemail.com",
+    "outlook.com",
+    "hotmail.com",
+    "live.com",
+    "icloud.com",
+    "me.com",
+    "yahoo.com",
+    "proton.me",
+    "protonmail.com",
+    "naver.com",
+];
+
+/// One person showing up in one meeting.
+struct Attendance {
+    name: Option<String>,
+    email: Option<String>,
+    human_id: Option<String>,
+    meeting: PersonMeeting,
+}
+
+#[derive(serde::Deserialize)]
+struct ParticipationRow {
+    human_id: String,
+    full_name: Option<String>,
+    email: Option<String>,
+    session_id: String,
+    session_title: String,
+    session_created_at: DateTime<Utc>,
+    event_id: Option<String>,
+    event_name: Option<String>,
+    event_start_date: Option<DateTime<Utc>>,
+}
+
+impl UserDatabase {
+    pub async fn list_people(
+        &self,
+        user_id: impl Into<String>,
+        filter: Option<ListPeopleFilter>,
+    ) -> Result<Vec<Person>, crate::Error> {
+        let mut people: Vec<Person> = group_attendances(self.list_attendances(user_id).await?)
+            .into_iter()
+            .map(|(person, _)| person)
+            .collect();
+
+        people.sort_by(|a, b| b.latest_meeting_at.cmp(&a.latest_meeting_at));
+
+        if let Some(ListPeopleFilter::Search((max, q))) = filter {
+            let q = q.to_lowercase();
+            people.retain(|person| {
+                [&person.name, &person.email, &person.organization_domain]
+                    .into_iter()
+                    .flatten()
+                    .any(|field| field.to_lowercase().contains(&q))
+            });
+            people.truncate(max as usize);
+        }
+
+        Ok(people)
+    }
+
+    /// Every meeting with the person, most recent first.
+    pub async fn list_meetings_with(
+        &self,
+        user_id: impl Into<String>,
+        person_id: impl AsRef<str>,
+    ) -> Result<Vec<PersonMeeting>, crate::Error> {
+        let meetings = group_attendances(self.list_attendances(user_id).await?)
+            .into_iter()
+            .find(|(person, _)| person.id == person_id.as_ref())
+            .map(|(_, meetings)| meetings)
+            .unwrap_or_default();
+
+        Ok(meetings)
+    }
+
+    async fn list_attendances(
+        &self,
+        user_id: impl Into<String>,
+    ) -> Result<Vec<Attendance>, crate::Error> {
+        let user_id = user_id.into();
+        let conn = self.conn()?;
+
+        // Calendars list the user among the attendees too.
+        let user_email = self
+            .get_human(&user_id)
+            .await?
+            .and_then(|human| human.email)
+            .map(|email| email.to_lowercase());
+
+        let mut attendances = Vec::new();
+
+        let mut rows = conn
+            .query(
+                "SELECT * FROM events WHERE user_id = ? AND participants IS NOT NULL",
+                vec![user_id.clone()],
+            )
+            .await?;
+
+        while let Some(row) = rows.next().await? {
+            let event: Event = libsql::de::from_row(&row)?;
+            let participants: Vec<EventParticipant> = event
+                .participants
+                .as_deref()
+                .and_then(|participants| serde_json::from_str(participants).ok())
+                .unwrap_or_default();
+
+            for participant in participants {
+                let is_user = participant
+                    .email
+                    .as_ref()
+                    .is_some_and(|email| Some(email.to_lowercase()) == user_email);
+                if is_user {
+                    continue;
+                }
+
+                attendances.push(Attendance {
+                    name: participant.name,
+                    email: participant.email,
+                    human_id: None,
+                    meeting: PersonMeeting {
+                        event_id: Some(event.id.clone()),
+                        session_id: None,
+                        title: event.name.clone(),
+                        date: event.start_date,
+                    },
+                });
+            }
+        }
+
+        let mut rows = conn
+            .query(
+                "SELECT
+                    h.id AS human_id,
+                    h.full_name,
+                    h.email,
+                    s.id AS session_id,
+                    s.title AS session_title,
+                    s.created_at AS session_created_at,
+                    e.id AS event_id,
+                    e.name AS event_name,
+                    e.start_date AS event_start_date
+                FROM session_participants sp
+                JOIN sessions s ON s.id = sp.session_id
+                JOIN humans h ON h.id = sp.human_id
+                LEFT JOIN events e ON e.id = s.calendar_event_id
+                WHERE s.user_id = ?
+                    AND h.id != s.user_id
+                    AND h.is_user = FALSE
+                    AND (sp.deleted = FALSE OR sp.deleted IS NULL)",
+                vec![user_id],
+            )
+            .await?;
+
+        while let Some(row) = rows.next().await? {
+            let row: ParticipationRow = libsql::de::from_row(&row)?;
+
+            let title = match (row.session_title.trim(), row.event_name) {
+                ("", Some(event_name)) => event_name,
+                (title, _) => title.to_string(),
+            };
+
+            attendances.push(Attendance {
+                name: row.full_name,
+                email: row.email,
+                human_id: Some(row.human_id),
+                meeting: PersonMeeting {
+                    event_id: row.event_id,
+                    session_id: Some(row.session_id),
+                    title,
+                    date: row.event_start_date.unwrap_or(row.session_created_at),
+                },
+            });
+        }
+
+        Ok(attendances)
+    }
+}
+
+fn group_attendances(attendances: Vec<Attendance>) -> Vec<(Person, Vec<PersonMeeting>)> {
+    let mut people: HashMap<String, (Person, Vec<PersonMeeting>)> = HashMap::new();
+
+    for attendance in attendances {
+        let email = attendance
+            .email
+            .map(|email| email.trim().to_lowercase())
+            .filter(|email| !email.is_empty());
+        let name = attendance
+            .name
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty());
+
+        let Some(id) = email
+            .clone()
+            .or_else(|| name.as_ref().map(|name| name.to_lowercase()))
+        else {
+            continue;
+        };
+
+        let (person, meetings) = people.entry(id.clone()).or_insert_with(|| {
+            let person = Person {
+                id,
+                name: None,
+                organization_domain: email.as_deref().and_then(organization_domain),
+                email,
+                human_ids: vec![],
+                meeting_count: 0,
+                latest_meeting_at: attendance.meeting.date,
+            };
+            (person, vec![])
+        });
+
+        if person.name.is_none() {
+            person.name = name;
+        }
+        if let Some(human_id) = attendance.human_id {
+            if !person.human_ids.contains(&human_id) {
+                person.human_ids.push(human_id);
+            }
+        }
+
+        let meeting = attendance.meeting;
+        match meetings.iter_mut().find(|m| is_same_meeting(m, &meeting)) {
+            // The note taken in a calendar event.
+            Some(existing) => {
+                existing.session_id = existing.session_id.take().or(meeting.session_id);
+            }
+            None => meetings.push(meeting),
+        }
+    }
+
+    people
+        .into_values()
+        .map(|(mut person, mut meetings)| {
+            meetings.sort_by(|a, b| b.date.cmp(&a.date));
+            person.meeting_count = meetings.len() as u32;
+            if let Some(latest) = meetings.first() {
+                person.latest_meeting_at = latest.date;
+            }
+            (person, meetings)
+        })
+        .collect()
+}
+
+fn is_same_meeting(a: &PersonMeeting, b: &PersonMeeting) -> bool {
+    (a.event_id.is_some() && a.event_id == b.event_id)
+        || (a.session_id.is_some() && a.session_id == b.session_id)
+}
+
+fn organization_domain(email: &str) -> Option<String> {
+    let (_, domain) = email.rsplit_once('@')?;
+
+    if domain.is_empty() || PERSONAL_EMAIL_DOMAINS.contains(&domain) {
+        None
+    } else {
+        Some(domain.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests::{new_session, setup_db},
+        Event, Human, ListPeopleFilter, Session,
+    };
+
+    #[tokio::test]
+    async fn test_people() {
+        let db = setup_db().await;
+
+        let user = db
+            .upsert_human(Human {
+                is_user: true,
+                full_name: Some("John Doe".to_string()),
+                email: Some("john@hyprnote.com".to_string()),
+                ..Human::default()
+            })
+            .await
+            .unwrap();
+
+        let event = db
+            .upsert_event(Event {
+                id: uuid::Uuid::new_v4().to_string(),
+                user_id: user.id.clone(),
+                tracking_id: "weekly-sync".to_string(),
+                calendar_id: None,
+                name: "Weekly sync".to_string(),
+                note: "".to_string(),
+                start_date: chrono::Utc::now() - chrono::Duration::days(7),
+                end_date: chrono::Utc::now() - chrono::Duration::days(7),
+                google_event_url: None,
+                participants: Some(
+                    serde_json::json!([
+                        { "name": "John Doe", "email": "John@hyprnote.com" },
+                        { "name": "Jane Kim", "email": "Jane@Acme.com" },
+                        { "name": "Sam", "email": "sam@gmail.com" },
+                    ])
+                    .to_string(),
+                ),
+                is_recurring: false,
+                meeting_link: None,
+            })
+            .await
+            .unwrap();
+
+        let jane = db
+            .upsert_human(Human {
+                full_name: Some("Jane Kim".to_string()),
+                email: Some("jane@acme.com".to_string()),
+                ..Human::default()
+            })
+            .await
+            .unwrap();
+
+        let session = |title: &str, calendar_event_id: Option<String>| Session {
+            calendar_event_id,
+            title: title.to_string(),
+            ..new_session(&user.id)
+        };
+
+        let sync_notes = db
+            .upsert_session(session("", Some(event.id.clone())))
+            .await
+            .unwrap();
+        let coffee = db.upsert_session(session("Coffee", None)).await.unwrap();
+
+        for session_id in [&sync_notes.id, &coffee.id] {
+            db.session_add_participant(session_id, &jane.id)
+                .await
+                .unwrap();
+        }
+
+        let people = db.list_people(&user.id, None).await.unwrap();
+        assert_eq!(people.len(), 2);
+        assert_eq!(people[0].id, "jane@acme.com");
+        assert_eq!(people[0].organization_domain, Some("acme.com".to_string()));
+        assert_eq!(people[0].human_ids, vec![jane.id.clone()]);
+        assert_eq!(people[0].meeting_count, 2);
+        assert_eq!(people[1].id, "sam@gmail.com");
+        assert_eq!(people[1].organization_domain, None);
+
+        let people = db
+            .list_people(
+                &user.id,
+                Some(ListPeopleFilter::Search((10, "acme".into()))),
+            )
+            .await
+            .unwrap();
+        assert_eq!(people.len(), 1);
+
+        let meetings = db
+            .list_meetings_with(&user.id, "jane@acme.com")
+            .await
+            .unwrap();
+        assert_eq!(meetings.len(), 2);
+        assert_eq!(meetings[0].title, "Coffee");
+        assert_eq!(meetings[1].title, "Weekly sync");
+        assert_eq!(meetings[1].event_id, Some(event.id.clone()));
+        assert_eq!(meetings[1].session_id, Some(sync_notes.id.clone()));
+    }
+}
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+
+use crate::user_common_derives;
+
+user_common_derives! {
+    /// Someone the user has met, gathered from calendar attendees and note participants.
+    pub struct Person {
+        /// The lowercased email, or the name for attendees without one.
+        pub id: String,
+        pub name: Option<String>,
+        pub email: Option<String>,
+        /// Domain of the email, unless it belongs to a personal mail provider.
+        pub organization_domain: Option<String>,
+        /// Entries in `humans` that are this person.
+        pub human_ids: Vec<String>,
+        pub meeting_count: u32,
+        /// Start of the most recent meeting, which can be an upcoming one.
+        pub latest_meeting_at: DateTime<Utc>,
+    }
+}
+
+user_common_derives! {
+    /// A calendar event or a note, counted once when the note belongs to the event.
+    pub struct PersonMeeting {
+        pub event_id: Option<String>,
+        pub session_id: Option<String>,
+        pub title: String,
+        pub date: DateTime<Utc>,
+    }
+}
+
+user_common_derives! {
+    pub enum ListPeopleFilter {
+        #[serde(rename = "search")]
+        Search((u8, String)),
+    }
+}
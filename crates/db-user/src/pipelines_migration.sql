@@ -0,0 +1,4 @@
+CREATE TABLE IF NOT EXISTS pipelines (
+  template_id TEXT PRIMARY KEY NOT NULL,
+  steps TEXT NOT NULL
+);
@@ -0,0 +1,111 @@
+use super::{Pipeline, UserDatabase};
+
+impl UserDatabase {
+    pub async fn get_pipeline(
+        &self,
+        template_id: impl Into<String>,
+    ) -> Result<Option<Pipeline>, crate::Error> {
+        let conn = self.conn()?;
+
+        let template_id = template_id.into();
+        let mut rows = conn
+            .query(
+                "SELECT steps FROM pipelines WHERE template_id = ?",
+                vec![template_id.clone()],
+            )
+            .await?;
+
+        match rows.next().await? {
+            None => Ok(None),
+            Some(row) => {
+                let steps: String = row.get(0)?;
+                let pipeline = Pipeline {
+                    template_id,
+                    steps: serde_json::from_str(&steps)?,
+                };
+                Ok(Some(pipeline.normalized()))
+            }
+        }
+    }
+
+    /// The saved pipeline of `template_id`, or the default one.
+    pub async fn resolve_pipeline(
+        &self,
+        template_id: Option<String>,
+    ) -> Result<Pipeline, crate::Error> {
+        let template_id = template_id.unwrap_or_else(|| Pipeline::DEFAULT_TEMPLATE_ID.to_string());
+
+        match self.get_pipeline(&template_id).await? {
+            Some(pipeline) => Ok(pipeline),
+            None => Ok(Pipeline::default_for(template_id)),
+        }
+    }
+
+    pub async fn upsert_pipeline(&self, pipeline: Pipeline) -> Result<Pipeline, crate::Error> {
+        let conn = self.conn()?;
+
+        let pipeline = pipeline.normalized();
+        conn.execute(
+            "INSERT OR REPLACE INTO pipelines (template_id, steps) VALUES (?, ?)",
+            vec![
+                pipeline.template_id.clone(),
+                serde_json::to_string(&pipeline.steps)?,
+            ],
+        )
+        .await?;
+        Ok(pipeline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tests::setup_db, Pipeline, PipelineStage, PipelineStep};
+
+    #[tokio::test]
+    async fn test_pipelines() {
+        let db = setup_db().await;
+
+        let pipeline = db.resolve_pipeline(None).await.unwrap();
+        assert_eq!(pipeline.template_id, Pipeline::DEFAULT_TEMPLATE_ID);
+        assert_eq!(
+            pipeline.stages(),
+            vec![
+                PipelineStage::Summarize,
+                PipelineStage::Title,
+                PipelineStage::Tags
+            ]
+        );
+
+        let saved = db
+            .upsert_pipeline(Pipeline {
+                template_id: "standup".to_string(),
+                steps: vec![
+                    PipelineStep {
+                        stage: PipelineStage::Title,
+                        enabled: true,
+                    },
+                    PipelineStep {
+                        stage: PipelineStage::ActionItems,
+                        enabled: true,
+                    },
+                    PipelineStep {
+                        stage: PipelineStage::Title,
+                        enabled: false,
+                    },
+                ],
+            })
+            .await
+            .unwrap();
+        assert_eq!(saved.steps.len(), 6);
+
+        let pipeline = db
+            .resolve_pipeline(Some("standup".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(pipeline, saved);
+        assert_eq!(
+            pipeline.stages(),
+            vec![PipelineStage::Title, PipelineStage::ActionItems]
+        );
+    }
+}
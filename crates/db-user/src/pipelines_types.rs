@@ -0,0 +1,96 @@
+use crate::user_common_derives;
+
+user_common_derives! {
+    #[derive(strum::EnumString, strum::Display, strum::EnumIter)]
+    pub enum PipelineStage {
+        /// Transcribes the recording on-device, when nothing was transcribed live.
+        #[serde(rename = "transcribe")]
+        #[strum(serialize = "transcribe")]
+        Transcribe,
+        /// Names speakers whose voice matches a known speaker profile.
+        #[serde(rename = "diarize")]
+        #[strum(serialize = "diarize")]
+        Diarize,
+        #[serde(rename = "summarize")]
+        #[strum(serialize = "summarize")]
+        Summarize,
+        #[serde(rename = "action_items")]
+        #[strum(serialize = "action_items")]
+        ActionItems,
+        #[serde(rename = "title")]
+        #[strum(serialize = "title")]
+        Title,
+        #[serde(rename = "tags")]
+        #[strum(serialize = "tags")]
+        Tags,
+    }
+}
+
+user_common_derives! {
+    pub struct PipelineStep {
+        pub stage: PipelineStage,
+        pub enabled: bool,
+    }
+}
+
+user_common_derives! {
+    /// What runs after a recording stops, for notes enhanced with a template.
+    pub struct Pipeline {
+        /// `Pipeline::DEFAULT_TEMPLATE_ID` for notes enhanced without one.
+        pub template_id: String,
+        /// In the order they run.
+        pub steps: Vec<PipelineStep>,
+    }
+}
+
+impl Pipeline {
+    pub const DEFAULT_TEMPLATE_ID: &'static str = "default";
+
+    /// Summary, then title and tags; the same as before pipelines could be changed.
+    pub fn default_for(template_id: impl Into<String>) -> Self {
+        use strum::IntoEnumIterator;
+
+        Self {
+            template_id: template_id.into(),
+            steps: PipelineStage::iter()
+                .map(|stage| PipelineStep {
+                    enabled: matches!(
+                        stage,
+                        PipelineStage::Summarize | PipelineStage::Title | PipelineStage::Tags
+                    ),
+                    stage,
+                })
+                .collect(),
+        }
+    }
+
+    /// Every stage exactly once: repeats are dropped, and stages added since the pipeline was
+    /// saved are appended, off.
+    pub fn normalized(mut self) -> Self {
+        use strum::IntoEnumIterator;
+
+        let mut seen = Vec::new();
+        self.steps.retain(|step| {
+            let first = !seen.contains(&step.stage);
+            seen.push(step.stage.clone());
+            first
+        });
+        for stage in PipelineStage::iter() {
+            if !seen.contains(&stage) {
+                self.steps.push(PipelineStep {
+                    stage,
+                    enabled: false,
+                });
+            }
+        }
+        self
+    }
+
+    pub fn stages(&self) -> Vec<PipelineStage> {
+        self.steps
+            .iter()
+            .filter(|step| step.enabled)
+            .map(|step| step.stage.clone())
+            .collect()
+    }
+}
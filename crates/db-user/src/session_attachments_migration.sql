@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS session_attachments (
+  id TEXT PRIMARY KEY,
+  session_id TEXT NOT NULL,
+  hash TEXT NOT NULL,
+  file_name TEXT NOT NULL,
+  kind TEXT NOT NULL,
+  created_at TEXT NOT NULL,
+  FOREIGN KEY (session_id) REFERENCES sessions(id) ON DELETE CASCADE,
+  FOREIGN KEY (hash) REFERENCES attachments(hash)
+);
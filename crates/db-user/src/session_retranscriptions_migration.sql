@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS session_retranscriptions (
+  session_id TEXT PRIMARY KEY NOT NULL,
+  model TEXT NOT NULL,
+  words TEXT NOT NULL,
+  created_at TEXT NOT NULL,
+  FOREIGN KEY (session_id) REFERENCES sessions(id) ON DELETE CASCADE
+);
@@ -0,0 +1,117 @@
+use super::{SessionRetranscription, UserDatabase};
+
+impl UserDatabase {
+    pub async fn get_session_retranscription(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Option<SessionRetranscription>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT * FROM session_retranscriptions WHERE session_id = ?",
+                vec![session_id.into()],
+            )
+            .await?;
+
+        match rows.next().await? {
+            Some(row) => Ok(Some(SessionRetranscription::from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn upsert_session_retranscription(
+        &self,
+        retranscription: SessionRetranscription,
+    ) -> Result<SessionRetranscription, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "INSERT OR REPLACE INTO session_retranscriptions (
+                    session_id,
+                    model,
+                    words,
+                    created_at
+                ) VALUES (?, ?, ?, ?)
+                RETURNING *",
+                libsql::params![
+                    retranscription.session_id,
+                    retranscription.model,
+                    serde_json::to_string(&retranscription.words)?,
+                    retranscription.created_at.to_rfc3339(),
+                ],
+            )
+            .await?;
+
+        let row = rows.next().await?.unwrap();
+        let retranscription = SessionRetranscription::from_row(&row)?;
+        Ok(retranscription)
+    }
+
+    pub async fn delete_session_retranscription(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<(), crate::Error> {
+        let conn = self.conn()?;
+
+        conn.execute(
+            "DELETE FROM session_retranscriptions WHERE session_id = ?",
+            vec![session_id.into()],
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests::{insert_session, setup_db},
+        SessionRetranscription,
+    };
+
+    #[tokio::test]
+    async fn test_session_retranscriptions() {
+        let db = setup_db().await;
+
+        let session = insert_session(&db).await;
+
+        assert!(db
+            .get_session_retranscription(&session.id)
+            .await
+            .unwrap()
+            .is_none());
+
+        let retranscription = db
+            .upsert_session_retranscription(SessionRetranscription {
+                session_id: session.id.clone(),
+                model: "QuantizedLargeTurbo".to_string(),
+                words: vec![owhisper_interface::Word2 {
+                    text: "hello".to_string(),
+                    speaker: None,
+                    confidence: None,
+                    start_ms: Some(0),
+                    end_ms: Some(500),
+                }],
+                created_at: chrono::Utc::now(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(retranscription.words.len(), 1);
+
+        assert_eq!(
+            db.get_session_retranscription(&session.id).await.unwrap(),
+            Some(retranscription)
+        );
+
+        db.delete_session_retranscription(&session.id)
+            .await
+            .unwrap();
+        assert!(db
+            .get_session_retranscription(&session.id)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+
+use crate::user_common_derives;
+
+user_common_derives! {
+    /// A transcript from re-running a session's recording through another model, kept apart
+    /// from the session until the user accepts it.
+    pub struct SessionRetranscription {
+        pub session_id: String,
+        /// Name of the model it was transcribed with.
+        pub model: String,
+        pub words: Vec<owhisper_interface::Word2>,
+        pub created_at: DateTime<Utc>,
+    }
+}
+
+impl SessionRetranscription {
+    pub fn from_row(row: &libsql::Row) -> Result<Self, serde::de::value::Error> {
+        Ok(Self {
+            session_id: row.get(0).expect("session_id"),
+            model: row.get(1).expect("model"),
+            words: row
+                .get_str(2)
+                .map(|s| serde_json::from_str(s).unwrap())
+                .unwrap(),
+            created_at: {
+                let str = row.get_str(3).expect("created_at");
+                DateTime::parse_from_rfc3339(str)
+                    .unwrap()
+                    .with_timezone(&Utc)
+            },
+        })
+    }
+}
@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS session_revisions (
+  id TEXT PRIMARY KEY NOT NULL,
+  session_id TEXT NOT NULL,
+  created_at TEXT NOT NULL,
+  source TEXT NOT NULL,
+  author TEXT NOT NULL,
+  changes TEXT NOT NULL,
+  raw_memo_html TEXT NOT NULL,
+  enhanced_memo_html TEXT,
+  words TEXT NOT NULL,
+  FOREIGN KEY (session_id) REFERENCES sessions(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_session_revisions_session_id ON session_revisions(session_id, created_at);
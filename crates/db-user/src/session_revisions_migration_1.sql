@@ -0,0 +1 @@
+UPDATE session_revisions SET words = '[]' WHERE changes NOT LIKE '%"words"%';
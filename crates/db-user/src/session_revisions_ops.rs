@@ -0,0 +1,318 @@
+use super::{
+    GetSessionFilter, Session, SessionRevision, SessionRevisionField, SessionRevisionSource,
+    UserDatabase,
+};
+
+// Notes are saved on every keystroke, and enhanced ones as they stream in, so saves this close
+// together share one revision.
+const COALESCE_MINUTES: i64 = 10;
+
+// Past this, the oldest revisions of a session are dropped.
+const MAX_REVISIONS_PER_SESSION: i64 = 50;
+
+impl UserDatabase {
+    /// Saves `session`, first keeping its notes and transcript as they were as a revision when
+    /// they are about to change.
+    pub async fn upsert_session_with_revision(
+        &self,
+        session: Session,
+        source: SessionRevisionSource,
+        author: impl Into<String>,
+    ) -> Result<Session, crate::Error> {
+        if let Some(previous) = self
+            .get_session(GetSessionFilter::Id(session.id.clone()))
+            .await?
+        {
+            self.record_session_revision(&previous, &session, source, author.into())
+                .await?;
+        }
+
+        self.upsert_session(session).await
+    }
+
+    async fn record_session_revision(
+        &self,
+        previous: &Session,
+        next: &Session,
+        source: SessionRevisionSource,
+        author: String,
+    ) -> Result<(), crate::Error> {
+        let changes = changed_fields(previous, next);
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let conn = self.conn()?;
+        let now = chrono::Utc::now();
+
+        let mut rows = conn
+            .query(
+                "SELECT * FROM session_revisions WHERE session_id = ? ORDER BY created_at DESC LIMIT 1",
+                vec![previous.id.clone()],
+            )
+            .await?;
+
+        if let Some(row) = rows.next().await? {
+            let latest = SessionRevision::from_row(&row)?;
+
+            // Still the same burst, so the revision from its start already has what the session
+            // looked like before it. A field first changed now still looks that way.
+            if source.coalesces()
+                && latest.source == source
+                && latest.author == author
+                && now - latest.created_at < chrono::Duration::minutes(COALESCE_MINUTES)
+            {
+                let mut merged = latest.changes;
+                let mut words = latest.words;
+                for field in changes {
+                    if !merged.contains(&field) {
+                        if field == SessionRevisionField::Words {
+                            words = previous.words.clone();
+                        }
+                        merged.push(field);
+                    }
+                }
+
+                conn.execute(
+                    "UPDATE session_revisions SET changes = ?, words = ? WHERE id = ?",
+                    libsql::params![
+                        serde_json::to_string(&merged)?,
+                        serde_json::to_string(&words)?,
+                        latest.id
+                    ],
+                )
+                .await?;
+                return Ok(());
+            }
+        }
+
+        // Notes are small enough to keep whole, the transcript only when it is what changed.
+        let words = match changes.contains(&SessionRevisionField::Words) {
+            true => previous.words.clone(),
+            false => vec![],
+        };
+
+        conn.execute(
+            "INSERT INTO session_revisions (
+                id,
+                session_id,
+                created_at,
+                source,
+                author,
+                changes,
+                raw_memo_html,
+                enhanced_memo_html,
+                words
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            libsql::params![
+                uuid::Uuid::new_v4().to_string(),
+                previous.id.clone(),
+                now.to_rfc3339(),
+                source.to_string(),
+                author,
+                serde_json::to_string(&changes)?,
+                previous.raw_memo_html.clone(),
+                previous.enhanced_memo_html.clone(),
+                serde_json::to_string(&words)?,
+            ],
+        )
+        .await?;
+
+        conn.execute(
+            "DELETE FROM session_revisions WHERE session_id = ? AND id NOT IN (
+                SELECT id FROM session_revisions WHERE session_id = ? ORDER BY created_at DESC LIMIT ?
+            )",
+            libsql::params![
+                previous.id.clone(),
+                previous.id.clone(),
+                MAX_REVISIONS_PER_SESSION
+            ],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Newest first.
+    pub async fn list_session_revisions(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Vec<SessionRevision>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT * FROM session_revisions WHERE session_id = ? ORDER BY created_at DESC",
+                vec![session_id.into()],
+            )
+            .await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            items.push(SessionRevision::from_row(&row)?);
+        }
+        Ok(items)
+    }
+
+    pub async fn get_session_revision(
+        &self,
+        id: impl Into<String>,
+    ) -> Result<Option<SessionRevision>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT * FROM session_revisions WHERE id = ?",
+                vec![id.into()],
+            )
+            .await?;
+
+        match rows.next().await? {
+            Some(row) => Ok(Some(SessionRevision::from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Puts back the notes a revision kept, and the transcript if it kept that too. What they
+    /// are now becomes a revision itself, so a restore can be undone the same way.
+    pub async fn restore_session_revision(
+        &self,
+        id: impl Into<String>,
+        author: impl Into<String>,
+    ) -> Result<Option<Session>, crate::Error> {
+        let Some(revision) = self.get_session_revision(id).await? else {
+            return Ok(None);
+        };
+        let Some(mut session) = self
+            .get_session(GetSessionFilter::Id(revision.session_id))
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        session.raw_memo_html = revision.raw_memo_html;
+        session.enhanced_memo_html = revision.enhanced_memo_html;
+        if revision.changes.contains(&SessionRevisionField::Words) {
+            session.words = revision.words;
+        }
+
+        let session = self
+            .upsert_session_with_revision(session, SessionRevisionSource::Restore, author)
+            .await?;
+        Ok(Some(session))
+    }
+}
+
+fn changed_fields(previous: &Session, next: &Session) -> Vec<SessionRevisionField> {
+    let mut changes = Vec::new();
+    if previous.raw_memo_html != next.raw_memo_html {
+        changes.push(SessionRevisionField::RawMemoHtml);
+    }
+    if previous.enhanced_memo_html != next.enhanced_memo_html {
+        changes.push(SessionRevisionField::EnhancedMemoHtml);
+    }
+    if previous.words != next.words {
+        changes.push(SessionRevisionField::Words);
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests::{insert_user, new_session, setup_db},
+        Session, SessionRevisionField, SessionRevisionSource,
+    };
+
+    #[tokio::test]
+    async fn test_session_revisions() {
+        let db = setup_db().await;
+
+        let user = insert_user(&db).await;
+
+        let session = db
+            .upsert_session_with_revision(
+                Session {
+                    raw_memo_html: "<p>first</p>".to_string(),
+                    ..new_session(&user.id)
+                },
+                SessionRevisionSource::Edit,
+                &user.id,
+            )
+            .await
+            .unwrap();
+        assert!(db
+            .list_session_revisions(&session.id)
+            .await
+            .unwrap()
+            .is_empty());
+
+        // Consecutive edits by the same user share a revision.
+        for note in ["<p>second</p>", "<p>third</p>"] {
+            db.upsert_session_with_revision(
+                Session {
+                    raw_memo_html: note.to_string(),
+                    ..session.clone()
+                },
+                SessionRevisionSource::Edit,
+                &user.id,
+            )
+            .await
+            .unwrap();
+        }
+
+        let session = db
+            .upsert_session_with_revision(
+                Session {
+                    raw_memo_html: "<p>third</p>".to_string(),
+                    words: vec![owhisper_interface::Word2 {
+                        text: "hello".to_string(),
+                        speaker: None,
+                        confidence: None,
+                        start_ms: Some(0),
+                        end_ms: Some(500),
+                    }],
+                    ..session
+                },
+                SessionRevisionSource::Retranscription,
+                "QuantizedLargeTurbo",
+            )
+            .await
+            .unwrap();
+
+        let revisions = db.list_session_revisions(&session.id).await.unwrap();
+        assert_eq!(revisions.len(), 2);
+        assert_eq!(revisions[0].source, SessionRevisionSource::Retranscription);
+        assert_eq!(revisions[0].changes, vec![SessionRevisionField::Words]);
+        assert_eq!(revisions[1].raw_memo_html, "<p>first</p>");
+        assert_eq!(
+            revisions[1].changes,
+            vec![SessionRevisionField::RawMemoHtml]
+        );
+
+        let restored = db
+            .restore_session_revision(&revisions[1].id, &user.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(restored.raw_memo_html, "<p>first</p>");
+        // The revision kept only the note, so the newer transcript stays.
+        assert_eq!(restored.words.len(), 1);
+
+        let revisions = db.list_session_revisions(&session.id).await.unwrap();
+        assert_eq!(revisions.len(), 3);
+        assert_eq!(revisions[0].source, SessionRevisionSource::Restore);
+        assert_eq!(
+            revisions[0].changes,
+            vec![SessionRevisionField::RawMemoHtml]
+        );
+        assert!(revisions[0].words.is_empty());
+
+        db.clear_session_words(&session.id).await.unwrap();
+        let revisions = db.list_session_revisions(&session.id).await.unwrap();
+        assert_eq!(revisions.len(), 2);
+        assert!(revisions
+            .iter()
+            .all(|revision| revision.source != SessionRevisionSource::Retranscription));
+    }
+}
@@ -0,0 +1,94 @@
+use chrono::{DateTime, Utc};
+
+use crate::user_common_derives;
+
+user_common_derives! {
+    #[derive(strum::EnumString, strum::Display)]
+    pub enum SessionRevisionSource {
+        #[serde(rename = "edit")]
+        #[strum(serialize = "edit")]
+        Edit,
+        #[serde(rename = "enhancement")]
+        #[strum(serialize = "enhancement")]
+        Enhancement,
+        #[serde(rename = "retranscription")]
+        #[strum(serialize = "retranscription")]
+        Retranscription,
+        #[serde(rename = "restore")]
+        #[strum(serialize = "restore")]
+        Restore,
+    }
+}
+
+user_common_derives! {
+    #[derive(strum::EnumString, strum::Display)]
+    pub enum SessionRevisionField {
+        #[serde(rename = "raw_memo_html")]
+        #[strum(serialize = "raw_memo_html")]
+        RawMemoHtml,
+        #[serde(rename = "enhanced_memo_html")]
+        #[strum(serialize = "enhanced_memo_html")]
+        EnhancedMemoHtml,
+        #[serde(rename = "words")]
+        #[strum(serialize = "words")]
+        Words,
+    }
+}
+
+user_common_derives! {
+    /// A session's notes and transcript as they were before a change, and what made the change.
+    pub struct SessionRevision {
+        pub id: String,
+        pub session_id: String,
+        pub created_at: DateTime<Utc>,
+        pub source: SessionRevisionSource,
+        /// The user who edited or had the note enhanced, or the model that re-transcribed.
+        pub author: String,
+        pub changes: Vec<SessionRevisionField>,
+        pub raw_memo_html: String,
+        pub enhanced_memo_html: Option<String>,
+        /// Transcripts are large, so they are only kept when `changes` has `words`.
+        pub words: Vec<owhisper_interface::Word2>,
+    }
+}
+
+impl SessionRevisionSource {
+    /// Whether saves from this source come in bursts that share one revision.
+    pub fn coalesces(&self) -> bool {
+        matches!(
+            self,
+            SessionRevisionSource::Edit | SessionRevisionSource::Enhancement
+        )
+    }
+}
+
+impl SessionRevision {
+    pub fn from_row(row: &libsql::Row) -> Result<Self, serde::de::value::Error> {
+        Ok(Self {
+            id: row.get(0).expect("id"),
+            session_id: row.get(1).expect("session_id"),
+            created_at: {
+                let str = row.get_str(2).expect("created_at");
+                DateTime::parse_from_rfc3339(str)
+                    .unwrap()
+                    .with_timezone(&Utc)
+            },
+            source: row
+                .get_str(3)
+                .expect("source")
+                .parse()
+                .map_err(<serde::de::value::Error as serde::de::Error>::custom)?,
+            author: row.get(4).expect("author"),
+            changes: row
+                .get_str(5)
+                .map(|s| serde_json::from_str(s).unwrap())
+                .unwrap(),
+            raw_memo_html: row.get(6).expect("raw_memo_html"),
+            enhanced_memo_html: row.get(7).expect("enhanced_memo_html"),
+            words: row
+                .get_str(8)
+                .map(|s| serde_json::from_str(s).unwrap())
+                .unwrap(),
+        })
+    }
+}
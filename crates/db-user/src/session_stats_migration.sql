@@ -0,0 +1,5 @@
+CREATE TABLE IF NOT EXISTS session_stats (
+  session_id TEXT PRIMARY KEY NOT NULL,
+  stats TEXT NOT NULL,
+  FOREIGN KEY (session_id) REFERENCES sessions(id) ON DELETE CASCADE
+);
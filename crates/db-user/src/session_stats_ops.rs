@@ -0,0 +1,76 @@
+use super::{SessionStats, UserDatabase};
+
+impl UserDatabase {
+    pub async fn get_session_stats(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Option<SessionStats>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT stats FROM session_stats WHERE session_id = ?",
+                vec![session_id.into()],
+            )
+            .await?;
+
+        match rows.next().await? {
+            None => Ok(None),
+            Some(row) => {
+                let stats_str: String = row.get(0)?;
+                Ok(Some(serde_json::from_str(&stats_str)?))
+            }
+        }
+    }
+
+    pub async fn upsert_session_stats(
+        &self,
+        session_id: impl Into<String>,
+        stats: &SessionStats,
+    ) -> Result<(), crate::Error> {
+        let conn = self.conn()?;
+
+        conn.execute(
+            "INSERT OR REPLACE INTO session_stats (session_id, stats) VALUES (?, ?)",
+            vec![session_id.into(), serde_json::to_string(stats)?],
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests::{insert_session, setup_db},
+        SessionStats, SpeakerStats,
+    };
+
+    #[tokio::test]
+    async fn test_session_stats() {
+        let db = setup_db().await;
+
+        let session = insert_session(&db).await;
+
+        assert_eq!(db.get_session_stats(&session.id).await.unwrap(), None);
+
+        let stats = SessionStats {
+            duration_ms: 60_000,
+            word_count: 120,
+            interruptions: 1,
+            silence_ratio: Some(0.25),
+            speakers: vec![SpeakerStats {
+                speaker: None,
+                talk_time_ms: 45_000,
+                word_count: 120,
+                interruptions: 1,
+            }],
+        };
+        db.upsert_session_stats(&session.id, &stats).await.unwrap();
+
+        assert_eq!(
+            db.get_session_stats(&session.id).await.unwrap(),
+            Some(stats)
+        );
+    }
+}
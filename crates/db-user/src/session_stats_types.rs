@@ -0,0 +1,25 @@
+use crate::user_common_derives;
+
+user_common_derives! {
+    /// Computed on-device when a recording stops.
+    pub struct SessionStats {
+        pub duration_ms: u64,
+        pub word_count: u64,
+        pub interruptions: u64,
+        /// Share of the recording without speech according to VAD, from 0 to 1.
+        pub silence_ratio: Option<f64>,
+        /// Ordered by talk time, longest first.
+        pub speakers: Vec<SpeakerStats>,
+    }
+}
+
+user_common_derives! {
+    pub struct SpeakerStats {
+        /// `None` for words the transcriber didn't attribute to anyone.
+        pub speaker: Option<owhisper_interface::SpeakerIdentity>,
+        pub talk_time_ms: u64,
+        pub word_count: u64,
+        /// Turns this speaker started while someone else was still talking.
+        pub interruptions: u64,
+    }
+}
@@ -1,6 +1,6 @@
 use super::{
     Event, GetSessionFilter, Human, ListSessionFilter, ListSessionFilterCommon,
-    ListSessionFilterSpecific, Session, UserDatabase,
+    ListSessionFilterSpecific, Session, SessionSummary, UserDatabase,
 };
 use uuid;
 
@@ -132,6 +132,18 @@ impl UserDatabase {
         )
         .await?;
 
+        conn.execute(
+            "DELETE FROM session_attachments WHERE session_id = ?",
+            vec![session_id.clone()],
+        )
+        .await?;
+
+        conn.execute(
+            "DELETE FROM session_revisions WHERE session_id = ?",
+            vec![session_id.clone()],
+        )
+        .await?;
+
         conn.execute("DELETE FROM sessions WHERE id = ?", vec![session_id])
             .await?;
 
@@ -247,6 +259,34 @@ impl UserDatabase {
 
                 conn.query(&query, params).await?
             }
+            Some(ListSessionFilter {
+                common: ListSessionFilterCommon { user_id, limit },
+                specific: ListSessionFilterSpecific::Participants { emails },
+            }) => {
+                if emails.is_empty() {
+                    return Ok(vec![]);
+                }
+
+                let placeholders = emails.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+                let query = format!(
+                    "SELECT s.* FROM sessions s
+                     JOIN session_participants sp ON s.id = sp.session_id
+                     JOIN humans h ON sp.human_id = h.id
+                     WHERE s.user_id = ? AND h.is_user = FALSE
+                       AND (sp.deleted = FALSE OR sp.deleted IS NULL)
+                       AND LOWER(h.email) IN ({})
+                     GROUP BY s.id
+                     ORDER BY COUNT(DISTINCT LOWER(h.email)) DESC, s.created_at DESC
+                     LIMIT ?",
+                    placeholders
+                );
+
+                let mut params = vec![user_id];
+                params.extend(emails.iter().map(|email| email.trim().to_lowercase()));
+                params.push(limit.unwrap_or(100).to_string());
+
+                conn.query(&query, params).await?
+            }
             None => {
                 conn.query(
                     "SELECT * FROM sessions ORDER BY created_at DESC LIMIT 100",
@@ -264,6 +304,82 @@ impl UserDatabase {
         Ok(items)
     }
 
+    pub async fn list_session_ids(
+        &self,
+        user_id: impl Into<String>,
+    ) -> Result<Vec<String>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT id FROM sessions WHERE user_id = ? ORDER BY created_at DESC",
+                vec![user_id.into()],
+            )
+            .await?;
+
+        let mut ids = Vec::new();
+        while let Some(row) = rows.next().await? {
+            ids.push(row.get::<String>(0)?);
+        }
+        Ok(ids)
+    }
+
+    /// Sessions that still have a transcript, oldest first.
+    pub async fn list_transcribed_sessions(
+        &self,
+        user_id: impl Into<String>,
+    ) -> Result<Vec<SessionSummary>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT id, title, created_at, record_end FROM sessions
+                WHERE user_id = ? AND words != '[]'
+                ORDER BY created_at ASC",
+                vec![user_id.into()],
+            )
+            .await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            items.push(SessionSummary::from_row(&row)?);
+        }
+        Ok(items)
+    }
+
+    /// Drops the transcript, any pending re-transcription and the earlier versions of it kept
+    /// in revisions, keeping the notes and their history.
+    pub async fn clear_session_words(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<(), crate::Error> {
+        let conn = self.conn()?;
+        let session_id = session_id.into();
+
+        conn.execute(
+            "UPDATE sessions SET words = '[]' WHERE id = ?",
+            vec![session_id.clone()],
+        )
+        .await?;
+        conn.execute(
+            "DELETE FROM session_retranscriptions WHERE session_id = ?",
+            vec![session_id.clone()],
+        )
+        .await?;
+        conn.execute(
+            "UPDATE session_revisions SET words = '[]' WHERE session_id = ?",
+            vec![session_id.clone()],
+        )
+        .await?;
+        // Left with nothing to restore.
+        conn.execute(
+            "DELETE FROM session_revisions WHERE session_id = ? AND changes = '[\"words\"]'",
+            vec![session_id],
+        )
+        .await?;
+        Ok(())
+    }
+
     pub async fn session_list_deleted_participant_ids(
         &self,
         session_id: impl Into<String>,
@@ -456,7 +572,10 @@ impl UserDatabase {
 
 #[cfg(test)]
 mod tests {
-    use crate::{tests::setup_db, Human, Session};
+    use crate::{
+        tests::setup_db, Human, ListSessionFilter, ListSessionFilterCommon,
+        ListSessionFilterSpecific, Session,
+    };
 
     #[tokio::test]
     async fn test_sessions() {
@@ -508,10 +627,67 @@ mod tests {
         let sessions = db.list_sessions(None).await.unwrap();
         assert_eq!(sessions.len(), 1);
 
+        let ids = db.list_session_ids(&user.id).await.unwrap();
+        assert_eq!(ids, vec![session.id.clone()]);
+
+        let alice = db
+            .upsert_human(Human {
+                email: Some("Alice@example.com".to_string()),
+                ..Human::default()
+            })
+            .await
+            .unwrap();
+        db.session_add_participant(&session.id, &alice.id)
+            .await
+            .unwrap();
+
+        let by_participants = |emails: &[&str]| ListSessionFilter {
+            common: ListSessionFilterCommon {
+                user_id: user.id.clone(),
+                limit: None,
+            },
+            specific: ListSessionFilterSpecific::Participants {
+                emails: emails.iter().map(|e| e.to_string()).collect(),
+            },
+        };
+        let shared = db
+            .list_sessions(Some(by_participants(&[
+                "alice@example.com",
+                "bob@example.com",
+            ])))
+            .await
+            .unwrap();
+        assert_eq!(shared.len(), 1);
+        assert!(db
+            .list_sessions(Some(by_participants(&["bob@example.com"])))
+            .await
+            .unwrap()
+            .is_empty());
+
         session.raw_memo_html = "raw_memo_html_2".to_string();
         let session = db.upsert_session(session).await.unwrap();
         assert_eq!(session.raw_memo_html, "raw_memo_html_2");
 
+        let transcribed = db.list_transcribed_sessions(&user.id).await.unwrap();
+        assert_eq!(transcribed.len(), 1);
+        assert_eq!(transcribed[0].id, session.id);
+
+        db.clear_session_words(&session.id).await.unwrap();
+        assert!(db.get_words(&session.id).await.unwrap().is_empty());
+        assert!(db
+            .list_transcribed_sessions(&user.id)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            db.get_session(crate::GetSessionFilter::Id(session.id.clone()))
+                .await
+                .unwrap()
+                .unwrap()
+                .raw_memo_html,
+            "raw_memo_html_2"
+        );
+
         let sessions = db.list_sessions(None).await.unwrap();
         assert_eq!(sessions.len(), 1);
 
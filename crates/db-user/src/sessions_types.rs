@@ -71,6 +71,36 @@ impl Session {
     }
 }
 
+user_common_derives! {
+    /// A session without its notes or transcript, for going over many at once.
+    pub struct SessionSummary {
+        pub id: String,
+        pub title: String,
+        pub created_at: DateTime<Utc>,
+        pub record_end: Option<DateTime<Utc>>,
+    }
+}
+
+impl SessionSummary {
+    pub fn from_row(row: &libsql::Row) -> Result<Self, serde::de::value::Error> {
+        Ok(Self {
+            id: row.get(0).expect("id"),
+            title: row.get(1).expect("title"),
+            created_at: {
+                let str = row.get_str(2).expect("created_at");
+                DateTime::parse_from_rfc3339(str)
+                    .unwrap()
+                    .with_timezone(&Utc)
+            },
+            record_end: row.get_str(3).ok().and_then(|str| {
+                DateTime::parse_from_rfc3339(str)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .ok()
+            }),
+        })
+    }
+}
+
 user_common_derives! {
     pub enum GetSessionFilter {
         #[serde(rename = "id")]
@@ -109,5 +139,8 @@ user_common_derives! {
         DateRange { start: DateTime<Utc>, end: DateTime<Utc> },
         #[serde(rename = "tagFilter")]
         TagFilter { tag_ids: Vec<String> },
+        /// Sessions shared with any of these people, those shared with the most of them first.
+        #[serde(rename = "participants")]
+        Participants { emails: Vec<String> },
     }
 }
@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS speaker_profiles (
+  id TEXT PRIMARY KEY,
+  user_id TEXT NOT NULL,
+  name TEXT NOT NULL,
+  human_id TEXT,
+  embedding TEXT NOT NULL DEFAULT '[]',
+  sample_count INTEGER NOT NULL DEFAULT 0,
+  updated_at TEXT NOT NULL,
+  FOREIGN KEY (human_id) REFERENCES humans(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_speaker_profiles_user_id ON speaker_profiles(user_id, name);
@@ -0,0 +1,126 @@
+use super::{SpeakerProfile, UserDatabase};
+
+impl UserDatabase {
+    pub async fn list_speaker_profiles(
+        &self,
+        user_id: impl Into<String>,
+    ) -> Result<Vec<SpeakerProfile>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT * FROM speaker_profiles WHERE user_id = ? ORDER BY name ASC",
+                vec![user_id.into()],
+            )
+            .await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item = SpeakerProfile::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    pub async fn get_speaker_profile(
+        &self,
+        id: impl Into<String>,
+    ) -> Result<Option<SpeakerProfile>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT * FROM speaker_profiles WHERE id = ?",
+                vec![id.into()],
+            )
+            .await?;
+
+        match rows.next().await? {
+            Some(row) => Ok(Some(SpeakerProfile::from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn upsert_speaker_profile(
+        &self,
+        profile: SpeakerProfile,
+    ) -> Result<SpeakerProfile, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "INSERT OR REPLACE INTO speaker_profiles (
+                    id,
+                    user_id,
+                    name,
+                    human_id,
+                    embedding,
+                    sample_count,
+                    updated_at
+                ) VALUES (?, ?, ?, ?, ?, ?, ?)
+                RETURNING *",
+                libsql::params![
+                    profile.id,
+                    profile.user_id,
+                    profile.name,
+                    profile.human_id,
+                    serde_json::to_string(&profile.embedding).unwrap(),
+                    profile.sample_count,
+                    profile.updated_at.to_rfc3339(),
+                ],
+            )
+            .await?;
+
+        let row = rows.next().await?.unwrap();
+        let profile = SpeakerProfile::from_row(&row)?;
+        Ok(profile)
+    }
+
+    pub async fn delete_speaker_profile(&self, id: impl Into<String>) -> Result<(), crate::Error> {
+        let conn = self.conn()?;
+
+        conn.execute("DELETE FROM speaker_profiles WHERE id = ?", vec![id.into()])
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests::{insert_user, setup_db},
+        SpeakerProfile,
+    };
+
+    #[tokio::test]
+    async fn test_speaker_profiles() {
+        let db = setup_db().await;
+
+        let user = insert_user(&db).await;
+
+        let mut profile = SpeakerProfile {
+            id: uuid::Uuid::new_v4().to_string(),
+            user_id: user.id.clone(),
+            name: "Alice".to_string(),
+            human_id: None,
+            embedding: vec![],
+            sample_count: 0,
+            updated_at: chrono::Utc::now(),
+        };
+        profile.add_sample(&[1.0, 0.0]);
+        profile.add_sample(&[0.0, 1.0]);
+        assert_eq!(profile.embedding, vec![0.5, 0.5]);
+        assert_eq!(profile.sample_count, 2);
+
+        let saved = db.upsert_speaker_profile(profile.clone()).await.unwrap();
+        assert_eq!(saved.embedding, vec![0.5, 0.5]);
+
+        let profiles = db.list_speaker_profiles(&user.id).await.unwrap();
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(profiles[0].name, "Alice");
+        assert!(db.list_speaker_profiles("other").await.unwrap().is_empty());
+
+        db.delete_speaker_profile(&profile.id).await.unwrap();
+        assert!(db.get_speaker_profile(&profile.id).await.unwrap().is_none());
+    }
+}
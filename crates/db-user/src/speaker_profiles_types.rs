@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+
+use crate::user_common_derives;
+
+user_common_derives! {
+    /// A voice the user put a name to, kept on this device to recognize it in later meetings.
+    pub struct SpeakerProfile {
+        pub id: String,
+        pub user_id: String,
+        pub name: String,
+        /// Set when the name was picked from the user's contacts.
+        pub human_id: Option<String>,
+        /// Mean of the voice embeddings of every meeting the speaker was named in. Empty when
+        /// none had a recording.
+        #[specta(skip)]
+        #[serde(skip)]
+        pub embedding: Vec<f32>,
+        pub sample_count: i64,
+        pub updated_at: DateTime<Utc>,
+    }
+}
+
+impl SpeakerProfile {
+    pub fn from_row(row: &libsql::Row) -> Result<Self, serde::de::value::Error> {
+        Ok(Self {
+            id: row.get(0).expect("id"),
+            user_id: row.get(1).expect("user_id"),
+            name: row.get(2).expect("name"),
+            human_id: row.get(3).expect("human_id"),
+            embedding: row
+                .get_str(4)
+                .ok()
+                .and_then(|s| serde_json::from_str(s).ok())
+                .unwrap_or_default(),
+            sample_count: row.get(5).expect("sample_count"),
+            updated_at: {
+                let str = row.get_str(6).expect("updated_at");
+                DateTime::parse_from_rfc3339(str)
+                    .unwrap()
+                    .with_timezone(&Utc)
+            },
+        })
+    }
+
+    /// Folds another embedding of the same voice into the mean.
+    pub fn add_sample(&mut self, embedding: &[f32]) {
+        if self.embedding.len() != embedding.len() {
+            self.embedding = embedding.to_vec();
+            self.sample_count = 1;
+            return;
+        }
+
+        let n = self.sample_count.max(1) as f32;
+        for (mean, value) in self.embedding.iter_mut().zip(embedding) {
+            *mean = (*mean * n + value) / (n + 1.0);
+        }
+        self.sample_count += 1;
+    }
+}
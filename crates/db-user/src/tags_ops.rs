@@ -105,38 +105,16 @@ impl UserDatabase {
 
 #[cfg(test)]
 mod tests {
-    use crate::{tests::setup_db, Human, Session, Tag};
+    use crate::{
+        tests::{insert_session, setup_db},
+        Tag,
+    };
 
     #[tokio::test]
     async fn test_tags() {
         let db = setup_db().await;
 
-        let user = db
-            .upsert_human(Human {
-                full_name: Some("John Doe".to_string()),
-                ..Human::default()
-            })
-            .await
-            .unwrap();
-
-        let _ = db
-            .upsert_session(Session {
-                id: uuid::Uuid::new_v4().to_string(),
-                user_id: user.id.clone(),
-                created_at: chrono::Utc::now(),
-                visited_at: chrono::Utc::now(),
-                calendar_event_id: None,
-                title: "Test Session".to_string(),
-                raw_memo_html: "".to_string(),
-                enhanced_memo_html: None,
-                conversations: vec![],
-                words: vec![],
-                record_start: None,
-                record_end: None,
-                pre_meeting_memo_html: None,
-            })
-            .await
-            .unwrap();
+        insert_session(&db).await;
 
         assert_eq!(db.list_all_tags().await.unwrap().len(), 0);
 
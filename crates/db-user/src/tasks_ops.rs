@@ -0,0 +1,185 @@
+use super::{QueuedTask, QueuedTaskStatus, UserDatabase};
+
+impl UserDatabase {
+    pub async fn upsert_queued_task(&self, task: QueuedTask) -> Result<QueuedTask, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "INSERT OR REPLACE INTO tasks (
+                    id,
+                    kind,
+                    payload,
+                    status,
+                    attempts,
+                    max_attempts,
+                    last_error,
+                    run_at,
+                    created_at,
+                    updated_at
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                RETURNING *",
+                libsql::params![
+                    task.id,
+                    task.kind.to_string(),
+                    task.payload,
+                    task.status.to_string(),
+                    task.attempts,
+                    task.max_attempts,
+                    task.last_error,
+                    task.run_at.to_rfc3339(),
+                    task.created_at.to_rfc3339(),
+                    task.updated_at.to_rfc3339(),
+                ],
+            )
+            .await?;
+
+        let row = rows.next().await?.unwrap();
+        let task: QueuedTask = libsql::de::from_row(&row)?;
+        Ok(task)
+    }
+
+    pub async fn get_queued_task(
+        &self,
+        id: impl Into<String>,
+    ) -> Result<Option<QueuedTask>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query("SELECT * FROM tasks WHERE id = ?", vec![id.into()])
+            .await?;
+
+        match rows.next().await? {
+            None => Ok(None),
+            Some(row) => {
+                let task: QueuedTask = libsql::de::from_row(&row)?;
+                Ok(Some(task))
+            }
+        }
+    }
+
+    pub async fn list_queued_tasks(
+        &self,
+        status: Option<QueuedTaskStatus>,
+    ) -> Result<Vec<QueuedTask>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = match status {
+            Some(status) => {
+                conn.query(
+                    "SELECT * FROM tasks WHERE status = ? ORDER BY created_at DESC",
+                    vec![status.to_string()],
+                )
+                .await?
+            }
+            None => {
+                conn.query("SELECT * FROM tasks ORDER BY created_at DESC", ())
+                    .await?
+            }
+        };
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item: QueuedTask = libsql::de::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    /// Moves up to `limit` queued tasks whose `run_at` has passed into `running`, oldest first.
+    pub async fn claim_due_tasks(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+        limit: u32,
+    ) -> Result<Vec<QueuedTask>, crate::Error> {
+        let conn = self.conn()?;
+        let now = now.to_rfc3339();
+
+        let mut rows = conn
+            .query(
+                "UPDATE tasks
+                SET status = ?, updated_at = ?
+                WHERE id IN (
+                    SELECT id FROM tasks
+                    WHERE status = ? AND run_at <= ?
+                    ORDER BY run_at ASC
+                    LIMIT ?
+                )
+                RETURNING *",
+                libsql::params![
+                    QueuedTaskStatus::Running.to_string(),
+                    now.clone(),
+                    QueuedTaskStatus::Queued.to_string(),
+                    now,
+                    limit,
+                ],
+            )
+            .await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item: QueuedTask = libsql::de::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    pub async fn delete_queued_task(&self, id: impl Into<String>) -> Result<(), crate::Error> {
+        let conn = self.conn()?;
+        conn.execute("DELETE FROM tasks WHERE id = ?", vec![id.into()])
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tests::setup_db, QueuedTask, QueuedTaskKind, QueuedTaskStatus};
+
+    fn task(run_at: chrono::DateTime<chrono::Utc>) -> QueuedTask {
+        let now = chrono::Utc::now();
+
+        QueuedTask {
+            id: uuid::Uuid::new_v4().to_string(),
+            kind: QueuedTaskKind::Enhance,
+            payload: r#"{"session_id":"abc"}"#.to_string(),
+            status: QueuedTaskStatus::Queued,
+            attempts: 0,
+            max_attempts: 3,
+            last_error: None,
+            run_at,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tasks() {
+        let db = setup_db().await;
+        let now = chrono::Utc::now();
+
+        let due = db.upsert_queued_task(task(now)).await.unwrap();
+        let later = db
+            .upsert_queued_task(task(now + chrono::Duration::minutes(5)))
+            .await
+            .unwrap();
+        assert_eq!(db.list_queued_tasks(None).await.unwrap().len(), 2);
+
+        let claimed = db.claim_due_tasks(now, 10).await.unwrap();
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].id, due.id);
+        assert_eq!(claimed[0].status, QueuedTaskStatus::Running);
+
+        assert!(db.claim_due_tasks(now, 10).await.unwrap().is_empty());
+        assert_eq!(
+            db.list_queued_tasks(Some(QueuedTaskStatus::Queued))
+                .await
+                .unwrap()[0]
+                .id,
+            later.id
+        );
+
+        db.delete_queued_task(&due.id).await.unwrap();
+        assert!(db.get_queued_task(&due.id).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,71 @@
+use crate::user_common_derives;
+
+user_common_derives! {
+    #[derive(strum::EnumString, strum::Display)]
+    pub enum QueuedTaskKind {
+        #[serde(rename = "enhance")]
+        #[strum(serialize = "enhance")]
+        Enhance,
+        #[serde(rename = "title")]
+        #[strum(serialize = "title")]
+        Title,
+        #[serde(rename = "export")]
+        #[strum(serialize = "export")]
+        Export,
+        /// Runs a session's recording through another model; payload has the session and model.
+        #[serde(rename = "retranscribe")]
+        #[strum(serialize = "retranscribe")]
+        Retranscribe,
+        /// Sums up past meetings with an upcoming event's attendees; payload has the event.
+        #[serde(rename = "pre_meeting_brief")]
+        #[strum(serialize = "pre_meeting_brief")]
+        PreMeetingBrief,
+        /// Runs a session's pipeline stage by stage; payload has the session and what's left.
+        #[serde(rename = "pipeline")]
+        #[strum(serialize = "pipeline")]
+        Pipeline,
+    }
+}
+
+user_common_derives! {
+    #[derive(strum::EnumString, strum::Display)]
+    pub enum QueuedTaskStatus {
+        #[serde(rename = "queued")]
+        #[strum(serialize = "queued")]
+        Queued,
+        #[serde(rename = "running")]
+        #[strum(serialize = "running")]
+        Running,
+        /// Held back until resumed; a running task stops at its next heartbeat.
+        #[serde(rename = "paused")]
+        #[strum(serialize = "paused")]
+        Paused,
+        #[serde(rename = "completed")]
+        #[strum(serialize = "completed")]
+        Completed,
+        /// Ran out of attempts; stays here until retried by hand.
+        #[serde(rename = "dead")]
+        #[strum(serialize = "dead")]
+        Dead,
+        #[serde(rename = "cancelled")]
+        #[strum(serialize = "cancelled")]
+        Cancelled,
+    }
+}
+
+user_common_derives! {
+    pub struct QueuedTask {
+        pub id: String,
+        pub kind: QueuedTaskKind,
+        /// JSON, interpreted by whoever runs tasks of this kind.
+        pub payload: String,
+        pub status: QueuedTaskStatus,
+        pub attempts: i64,
+        pub max_attempts: i64,
+        pub last_error: Option<String>,
+        /// Not picked up before this time; pushed back on every failed attempt.
+        pub run_at: chrono::DateTime<chrono::Utc>,
+        pub created_at: chrono::DateTime<chrono::Utc>,
+        pub updated_at: chrono::DateTime<chrono::Utc>,
+    }
+}
@@ -67,7 +67,9 @@ impl UserDatabase {
     pub async fn delete_template(&self, id: String) -> Result<(), crate::Error> {
         let conn = self.conn()?;
 
-        conn.query("DELETE FROM templates WHERE id = ?", vec![id])
+        conn.query("DELETE FROM templates WHERE id = ?", vec![id.clone()])
+            .await?;
+        conn.execute("DELETE FROM pipelines WHERE template_id = ?", vec![id])
             .await?;
         Ok(())
     }
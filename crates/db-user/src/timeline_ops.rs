@@ -0,0 +1,113 @@
+use super::{TimelineEvent, UserDatabase};
+
+impl UserDatabase {
+    pub async fn upsert_timeline_event(
+        &self,
+        event: TimelineEvent,
+    ) -> Result<TimelineEvent, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "INSERT OR REPLACE INTO session_timeline (
+                    id,
+                    session_id,
+                    kind,
+                    start_ms,
+                    end_ms,
+                    label
+                ) VALUES (?, ?, ?, ?, ?, ?)
+                RETURNING *",
+                libsql::params![
+                    event.id,
+                    event.session_id,
+                    event.kind.to_string(),
+                    event.start_ms,
+                    event.end_ms,
+                    event.label,
+                ],
+            )
+            .await?;
+
+        let row = rows.next().await?.unwrap();
+        let event: TimelineEvent = libsql::de::from_row(&row)?;
+        Ok(event)
+    }
+
+    pub async fn list_session_timeline(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Vec<TimelineEvent>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT * FROM session_timeline WHERE session_id = ? ORDER BY start_ms ASC",
+                vec![session_id.into()],
+            )
+            .await?;
+
+        let mut items = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let item: TimelineEvent = libsql::de::from_row(&row)?;
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    /// Ends every period of the session that is still open, e.g. a mute that outlived the recording.
+    pub async fn close_timeline_events(
+        &self,
+        session_id: impl Into<String>,
+        end_ms: i64,
+    ) -> Result<(), crate::Error> {
+        let conn = self.conn()?;
+
+        conn.execute(
+            "UPDATE session_timeline SET end_ms = ?
+             WHERE session_id = ? AND end_ms IS NULL AND kind IN ('mic_muted', 'speaker_muted')",
+            libsql::params![end_ms, session_id.into()],
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests::{insert_session, setup_db},
+        TimelineEvent, TimelineEventKind,
+    };
+
+    #[tokio::test]
+    async fn test_timeline() {
+        let db = setup_db().await;
+
+        let session = insert_session(&db).await;
+
+        let event = |kind, start_ms| TimelineEvent {
+            id: uuid::Uuid::new_v4().to_string(),
+            session_id: session.id.clone(),
+            kind,
+            start_ms,
+            end_ms: None,
+            label: None,
+        };
+
+        db.upsert_timeline_event(event(TimelineEventKind::MicMuted, 2000))
+            .await
+            .unwrap();
+        db.upsert_timeline_event(event(TimelineEventKind::Highlight, 1000))
+            .await
+            .unwrap();
+        db.close_timeline_events(&session.id, 3000).await.unwrap();
+
+        let timeline = db.list_session_timeline(&session.id).await.unwrap();
+        assert_eq!(timeline.len(), 2);
+        assert_eq!(timeline[0].kind, TimelineEventKind::Highlight);
+        assert_eq!(timeline[0].end_ms, None);
+        assert_eq!(timeline[1].kind, TimelineEventKind::MicMuted);
+        assert_eq!(timeline[1].end_ms, Some(3000));
+    }
+}
@@ -0,0 +1,30 @@
+use crate::user_common_derives;
+
+user_common_derives! {
+    #[derive(Eq, Hash, strum::EnumString, strum::Display)]
+    pub enum TimelineEventKind {
+        #[serde(rename = "mic_muted")]
+        #[strum(serialize = "mic_muted")]
+        MicMuted,
+        #[serde(rename = "speaker_muted")]
+        #[strum(serialize = "speaker_muted")]
+        SpeakerMuted,
+        #[serde(rename = "highlight")]
+        #[strum(serialize = "highlight")]
+        Highlight,
+    }
+}
+
+user_common_derives! {
+    /// Something other than speech that happened during a recording.
+    pub struct TimelineEvent {
+        pub id: String,
+        pub session_id: String,
+        pub kind: TimelineEventKind,
+        /// Unix milliseconds, the same clock as transcript words.
+        pub start_ms: i64,
+        /// Set for periods like a mute; `None` for instants and periods still going on.
+        pub end_ms: Option<i64>,
+        pub label: Option<String>,
+    }
+}
@@ -0,0 +1,143 @@
+use std::io::{Cursor, Write};
+
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+use crate::{Block, Error};
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+<Default Extension="xml" ContentType="application/xml"/>
+<Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/>
+<Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/>
+</Types>"#;
+
+const RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/>
+</Relationships>"#;
+
+const DOCUMENT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/>
+</Relationships>"#;
+
+// Real heading styles, so Word's navigation pane and table of contents pick them up.
+const STYLES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:docDefaults>
+<w:rPrDefault><w:rPr><w:rFonts w:ascii="Calibri" w:hAnsi="Calibri" w:eastAsia="Calibri" w:cs="Calibri"/><w:sz w:val="22"/></w:rPr></w:rPrDefault>
+<w:pPrDefault><w:pPr><w:spacing w:after="120" w:line="276" w:lineRule="auto"/></w:pPr></w:pPrDefault>
+</w:docDefaults>
+<w:style w:type="paragraph" w:default="1" w:styleId="Normal"><w:name w:val="Normal"/></w:style>
+<w:style w:type="paragraph" w:styleId="Title"><w:name w:val="Title"/><w:basedOn w:val="Normal"/><w:pPr><w:spacing w:after="200"/></w:pPr><w:rPr><w:b/><w:sz w:val="40"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading1"><w:name w:val="heading 1"/><w:basedOn w:val="Normal"/><w:pPr><w:keepNext/><w:spacing w:before="360" w:after="120"/><w:outlineLvl w:val="0"/></w:pPr><w:rPr><w:b/><w:sz w:val="28"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading2"><w:name w:val="heading 2"/><w:basedOn w:val="Normal"/><w:pPr><w:keepNext/><w:spacing w:before="240" w:after="80"/><w:outlineLvl w:val="1"/></w:pPr><w:rPr><w:b/><w:sz w:val="24"/></w:rPr></w:style>
+</w:styles>"#;
+
+const LIST_INDENT_TWIPS: u32 = 360;
+
+pub(crate) fn render(blocks: &[Block]) -> Result<Vec<u8>, Error> {
+    let mut body = String::new();
+
+    for block in blocks {
+        match block {
+            Block::Title(text) => {
+                body.push_str(&paragraph(Some("Title"), None, &[run(text, false)]))
+            }
+            Block::Meta { label, value } => body.push_str(&paragraph(
+                None,
+                None,
+                &[run(&format!("{}: ", label), true), run(value, false)],
+            )),
+            Block::Heading(text) => {
+                body.push_str(&paragraph(Some("Heading1"), None, &[run(text, false)]))
+            }
+            Block::Subheading(text) => {
+                body.push_str(&paragraph(Some("Heading2"), None, &[run(text, false)]))
+            }
+            Block::Paragraph(text) => body.push_str(&paragraph(None, None, &[run(text, false)])),
+            Block::Bullet { depth, text } => body.push_str(&paragraph(
+                None,
+                Some((*depth as u32 + 1) * LIST_INDENT_TWIPS),
+                &[run(&format!("•\t{}", text), false)],
+            )),
+            Block::Task { done, text } => body.push_str(&paragraph(
+                None,
+                Some(LIST_INDENT_TWIPS),
+                &[run(
+                    &format!("{}\t{}", if *done { "☑" } else { "☐" }, text),
+                    false,
+                )],
+            )),
+            Block::Utterance {
+                timestamp,
+                speaker,
+                text,
+            } => body.push_str(&paragraph(
+                None,
+                None,
+                &[
+                    run(&format!("{}  {}  ", timestamp, speaker), true),
+                    run(text, false),
+                ],
+            )),
+            Block::PageBreak => body.push_str(r#"<w:p><w:r><w:br w:type="page"/></w:r></w:p>"#),
+        }
+    }
+
+    let document = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>{}<w:sectPr><w:pgSz w:w="11906" w:h="16838"/><w:pgMar w:top="1134" w:right="1134" w:bottom="1134" w:left="1134" w:header="709" w:footer="709" w:gutter="0"/></w:sectPr></w:body></w:document>"#,
+        body
+    );
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    for (name, content) in [
+        ("[Content_Types].xml", CONTENT_TYPES),
+        ("_rels/.rels", RELS),
+        ("word/_rels/document.xml.rels", DOCUMENT_RELS),
+        ("word/styles.xml", STYLES),
+        ("word/document.xml", document.as_str()),
+    ] {
+        zip.start_file(name, options)?;
+        zip.write_all(content.as_bytes())?;
+    }
+
+    Ok(zip.finish()?.into_inner())
+}
+
+/// A hanging `indent` in twips leaves room for a list marker followed by a tab.
+fn paragraph(style: Option<&str>, indent: Option<u32>, runs: &[String]) -> String {
+    let mut properties = String::new();
+    if let Some(style) = style {
+        properties.push_str(&format!(r#"<w:pStyle w:val="{}"/>"#, style));
+    }
+    if let Some(indent) = indent {
+        properties.push_str(&format!(
+            r#"<w:tabs><w:tab w:val="left" w:pos="{0}"/></w:tabs><w:ind w:left="{0}" w:hanging="{1}"/>"#,
+            indent, LIST_INDENT_TWIPS
+        ));
+    }
+
+    format!("<w:p><w:pPr>{}</w:pPr>{}</w:p>", properties, runs.concat())
+}
+
+fn run(text: &str, bold: bool) -> String {
+    let properties = if bold { "<w:rPr><w:b/></w:rPr>" } else { "" };
+    let text = escape(text).replace('\t', r#"</w:t><w:tab/><w:t xml:space="preserve">"#);
+    format!(
+        r#"<w:r>{}<w:t xml:space="preserve">{}</w:t></w:r>"#,
+        properties, text
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
@@ -0,0 +1,260 @@
+mod docx;
+mod note;
+mod pdf;
+mod transcript;
+
+pub use transcript::*;
+
+use chrono::{DateTime, Utc};
+
+use note::{parse_note, NoteBlock};
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("failed to render pdf: {0}")]
+    PdfError(String),
+    #[error(transparent)]
+    ZipError(#[from] zip::result::ZipError),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Pdf,
+    Docx,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Pdf => "pdf",
+            ExportFormat::Docx => "docx",
+        }
+    }
+}
+
+/// Which sections make it into the document. They always appear in this order,
+/// with the transcript as an appendix on its own page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct ExportTemplate {
+    /// Date, duration and participants.
+    pub include_metadata: bool,
+    pub include_summary: bool,
+    pub include_action_items: bool,
+    pub include_transcript: bool,
+}
+
+impl Default for ExportTemplate {
+    fn default() -> Self {
+        Self {
+            include_metadata: true,
+            include_summary: true,
+            include_action_items: true,
+            include_transcript: false,
+        }
+    }
+}
+
+/// Everything an export can show, gathered by the caller.
+#[derive(Debug, Clone)]
+pub struct ExportNote {
+    pub title: String,
+    pub date: DateTime<Utc>,
+    pub duration_ms: Option<u64>,
+    pub participants: Vec<String>,
+    /// The enhanced note as the editor stores it.
+    pub note_html: String,
+    pub transcript: Vec<TranscriptParagraph>,
+}
+
+/// Laid-out content both formats render from, so they stay in sync.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Block {
+    Title(String),
+    Meta {
+        label: String,
+        value: String,
+    },
+    Heading(String),
+    Subheading(String),
+    Paragraph(String),
+    Bullet {
+        depth: u8,
+        text: String,
+    },
+    Task {
+        done: bool,
+        text: String,
+    },
+    Utterance {
+        timestamp: String,
+        speaker: String,
+        text: String,
+    },
+    PageBreak,
+}
+
+pub fn export(
+    note: &ExportNote,
+    format: ExportFormat,
+    template: &ExportTemplate,
+) -> Result<Vec<u8>, Error> {
+    let blocks = layout(note, template);
+
+    match format {
+        ExportFormat::Pdf => pdf::render(&note.title, &blocks),
+        ExportFormat::Docx => docx::render(&blocks),
+    }
+}
+
+fn layout(note: &ExportNote, template: &ExportTemplate) -> Vec<Block> {
+    let mut blocks = vec![Block::Title(note.title.clone())];
+
+    if template.include_metadata {
+        blocks.push(Block::Meta {
+            label: "Date".to_string(),
+            value: note.date.format("%B %-d, %Y").to_string(),
+        });
+        if let Some(duration_ms) = note.duration_ms.filter(|ms| *ms >= 60_000) {
+            blocks.push(Block::Meta {
+                label: "Duration".to_string(),
+                value: format!("{} min", duration_ms / 60_000),
+            });
+        }
+        if !note.participants.is_empty() {
+            blocks.push(Block::Meta {
+                label: "Participants".to_string(),
+                value: note.participants.join(", "),
+            });
+        }
+    }
+
+    let content = parse_note(&note.note_html);
+
+    if template.include_summary && !content.summary.is_empty() {
+        blocks.push(Block::Heading("Summary".to_string()));
+        blocks.extend(content.summary.into_iter().map(|block| match block {
+            NoteBlock::Heading(text) => Block::Subheading(text),
+            NoteBlock::Paragraph(text) => Block::Paragraph(text),
+            NoteBlock::ListItem { depth, text } => Block::Bullet { depth, text },
+            NoteBlock::Task { done, text } => Block::Task { done, text },
+        }));
+    }
+
+    if template.include_action_items && !content.action_items.is_empty() {
+        blocks.push(Block::Heading("Action Items".to_string()));
+        blocks.extend(content.action_items.into_iter().map(|item| Block::Task {
+            done: item.done,
+            text: item.text,
+        }));
+    }
+
+    if template.include_transcript && !note.transcript.is_empty() {
+        let origin_ms = note.transcript[0].start_ms;
+
+        blocks.push(Block::PageBreak);
+        blocks.push(Block::Heading("Transcript".to_string()));
+        blocks.extend(note.transcript.iter().map(|paragraph| Block::Utterance {
+            timestamp: format_timestamp(paragraph.start_ms.saturating_sub(origin_ms)),
+            speaker: paragraph.speaker.clone(),
+            text: paragraph.text.clone(),
+        }));
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note() -> ExportNote {
+        ExportNote {
+            title: "Weekly sync".to_string(),
+            date: "2025-03-04T10:00:00Z".parse().unwrap(),
+            duration_ms: Some(30 * 60_000),
+            participants: vec!["Jane Kim".to_string(), "Sam".to_string()],
+            note_html: "<h2>Decisions</h2><p>Ship it.</p><h2>Next steps</h2><ul><li><p>Write the changelog</p></li></ul>".to_string(),
+            transcript: vec![TranscriptParagraph {
+                speaker: "Jane Kim".to_string(),
+                start_ms: 5_000,
+                text: "Let's ship it.".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_layout() {
+        let blocks = layout(&note(), &ExportTemplate::default());
+
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Title("Weekly sync".to_string()),
+                Block::Meta {
+                    label: "Date".to_string(),
+                    value: "March 4, 2025".to_string()
+                },
+                Block::Meta {
+                    label: "Duration".to_string(),
+                    value: "30 min".to_string()
+                },
+                Block::Meta {
+                    label: "Participants".to_string(),
+                    value: "Jane Kim, Sam".to_string()
+                },
+                Block::Heading("Summary".to_string()),
+                Block::Subheading("Decisions".to_string()),
+                Block::Paragraph("Ship it.".to_string()),
+                Block::Heading("Action Items".to_string()),
+                Block::Task {
+                    done: false,
+                    text: "Write the changelog".to_string()
+                },
+            ]
+        );
+
+        let blocks = layout(
+            &note(),
+            &ExportTemplate {
+                include_metadata: false,
+                include_summary: false,
+                include_action_items: false,
+                include_transcript: true,
+            },
+        );
+        assert_eq!(blocks.len(), 4);
+        assert_eq!(
+            blocks[3],
+            Block::Utterance {
+                timestamp: "00:00".to_string(),
+                speaker: "Jane Kim".to_string(),
+                text: "Let's ship it.".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_export() {
+        let template = ExportTemplate {
+            include_transcript: true,
+            ..Default::default()
+        };
+
+        let pdf = export(&note(), ExportFormat::Pdf, &template).unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+
+        let docx = export(&note(), ExportFormat::Docx, &template).unwrap();
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(docx)).unwrap();
+        let mut document = String::new();
+        std::io::Read::read_to_string(
+            &mut archive.by_name("word/document.xml").unwrap(),
+            &mut document,
+        )
+        .unwrap();
+        assert!(document.contains("Write the changelog"));
+        assert!(document.contains("Let&apos;s ship it."));
+    }
+}
@@ -0,0 +1,265 @@
+use tl::{NodeHandle, Parser};
+
+/// Headings under which list items are taken as action items, compared lowercased.
+const ACTION_HEADINGS: [&str; 6] = [
+    "action items",
+    "action item",
+    "next steps",
+    "todo",
+    "to-do",
+    "follow-ups",
+];
+
+/// A block of the note, flattened out of the editor's HTML.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum NoteBlock {
+    Heading(String),
+    Paragraph(String),
+    ListItem { depth: u8, text: String },
+    Task { done: bool, text: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ActionItem {
+    pub done: bool,
+    pub text: String,
+}
+
+/// The note split into its summary and the action items pulled out of it.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct NoteContent {
+    pub summary: Vec<NoteBlock>,
+    pub action_items: Vec<ActionItem>,
+}
+
+/// Action items are checklist entries anywhere in the note, and list items under
+/// a heading like "Action Items". That section is left out of the summary.
+pub(crate) fn parse_note(html: &str) -> NoteContent {
+    let mut content = NoteContent::default();
+    let mut in_action_section = false;
+
+    for block in parse_blocks(html) {
+        match block {
+            NoteBlock::Heading(text) => {
+                in_action_section = is_action_heading(&text);
+                if !in_action_section {
+                    content.summary.push(NoteBlock::Heading(text));
+                }
+            }
+            NoteBlock::Task { done, text } => {
+                content.action_items.push(ActionItem { done, text });
+            }
+            NoteBlock::ListItem { text, .. } if in_action_section => {
+                content.action_items.push(ActionItem { done: false, text });
+            }
+            block => content.summary.push(block),
+        }
+    }
+
+    content
+}
+
+fn is_action_heading(text: &str) -> bool {
+    let text = text.trim().trim_end_matches(':').to_lowercase();
+    ACTION_HEADINGS.contains(&text.as_str())
+}
+
+fn parse_blocks(html: &str) -> Vec<NoteBlock> {
+    let Ok(dom) = tl::parse(html, tl::ParserOptions::default()) else {
+        return vec![];
+    };
+
+    let mut blocks = vec![];
+    for handle in dom.children() {
+        collect_blocks(*handle, dom.parser(), 0, &mut blocks);
+    }
+    blocks
+}
+
+fn collect_blocks(handle: NodeHandle, parser: &Parser, depth: u8, blocks: &mut Vec<NoteBlock>) {
+    let Some(node) = handle.get(parser) else {
+        return;
+    };
+
+    let Some(tag) = node.as_tag() else {
+        let text = normalize(&decode_entities(&node.inner_text(parser)));
+        if !text.is_empty() {
+            blocks.push(NoteBlock::Paragraph(text));
+        }
+        return;
+    };
+
+    let name = tag.name().as_utf8_str().to_lowercase();
+    match name.as_str() {
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+            push_text(blocks, NoteBlock::Heading, text_of(handle, parser))
+        }
+        "p" => push_text(blocks, NoteBlock::Paragraph, text_of(handle, parser)),
+        "ul" | "ol" => {
+            for child in tag.children().top().iter() {
+                collect_blocks(*child, parser, depth, blocks);
+            }
+        }
+        "li" => {
+            let attributes = tag.attributes();
+            let is_task = attribute(attributes, "data-type").as_deref() == Some("taskItem")
+                || attribute(attributes, "data-checked").is_some();
+
+            let text = text_of(handle, parser);
+            if !text.is_empty() {
+                blocks.push(if is_task {
+                    NoteBlock::Task {
+                        done: attribute(attributes, "data-checked").as_deref() == Some("true"),
+                        text,
+                    }
+                } else {
+                    NoteBlock::ListItem { depth, text }
+                });
+            }
+
+            for list in nested_lists(handle, parser) {
+                collect_blocks(list, parser, depth.saturating_add(1), blocks);
+            }
+        }
+        _ => {
+            for child in tag.children().top().iter() {
+                collect_blocks(*child, parser, depth, blocks);
+            }
+        }
+    }
+}
+
+fn push_text(blocks: &mut Vec<NoteBlock>, block: fn(String) -> NoteBlock, text: String) {
+    if !text.is_empty() {
+        blocks.push(block(text));
+    }
+}
+
+fn attribute(attributes: &tl::Attributes, key: &str) -> Option<String> {
+    attributes
+        .get(key)
+        .flatten()
+        .map(|value| value.as_utf8_str().to_string())
+}
+
+/// Text of the element, without the lists nested in it.
+fn text_of(handle: NodeHandle, parser: &Parser) -> String {
+    let mut text = String::new();
+    append_text(handle, parser, &mut text);
+    normalize(&decode_entities(&text))
+}
+
+fn append_text(handle: NodeHandle, parser: &Parser, text: &mut String) {
+    let Some(node) = handle.get(parser) else {
+        return;
+    };
+
+    match node.as_tag() {
+        Some(tag) => match tag.name().as_utf8_str().to_lowercase().as_str() {
+            "ul" | "ol" => {}
+            "br" => text.push(' '),
+            name => {
+                for child in tag.children().top().iter() {
+                    append_text(*child, parser, text);
+                }
+                if matches!(name, "p" | "div") {
+                    text.push(' ');
+                }
+            }
+        },
+        None => text.push_str(&node.inner_text(parser)),
+    }
+}
+
+fn nested_lists(handle: NodeHandle, parser: &Parser) -> Vec<NodeHandle> {
+    let mut lists = vec![];
+    let Some(tag) = handle.get(parser).and_then(|node| node.as_tag()) else {
+        return lists;
+    };
+
+    for child in tag.children().top().iter() {
+        match child.get(parser).and_then(|node| node.as_tag()) {
+            Some(child_tag) if matches!(child_tag.name().as_utf8_str().as_ref(), "ul" | "ol") => {
+                lists.push(*child)
+            }
+            Some(_) => lists.extend(nested_lists(*child, parser)),
+            None => {}
+        }
+    }
+
+    lists
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_note() {
+        let html = r#"
+            <h1>Summary</h1>
+            <p>Budget is <strong>approved</strong> &amp; signed.</p>
+            <ul>
+                <li><p>Hiring</p><ul><li><p>Two engineers</p></li></ul></li>
+            </ul>
+            <h2>Action Items:</h2>
+            <ul>
+                <li><p>Send the contract</p></li>
+            </ul>
+            <ul data-type="taskList">
+                <li data-type="taskItem" data-checked="true"><label><input type="checkbox" checked><span></span></label><div><p>Book the venue</p></div></li>
+            </ul>
+            <h2>Risks</h2>
+            <ul><li><p>Timeline</p></li></ul>
+        "#;
+
+        let content = parse_note(html);
+
+        assert_eq!(
+            content.summary,
+            vec![
+                NoteBlock::Heading("Summary".to_string()),
+                NoteBlock::Paragraph("Budget is approved & signed.".to_string()),
+                NoteBlock::ListItem {
+                    depth: 0,
+                    text: "Hiring".to_string()
+                },
+                NoteBlock::ListItem {
+                    depth: 1,
+                    text: "Two engineers".to_string()
+                },
+                NoteBlock::Heading("Risks".to_string()),
+                NoteBlock::ListItem {
+                    depth: 0,
+                    text: "Timeline".to_string()
+                },
+            ]
+        );
+        assert_eq!(
+            content.action_items,
+            vec![
+                ActionItem {
+                    done: false,
+                    text: "Send the contract".to_string()
+                },
+                ActionItem {
+                    done: true,
+                    text: "Book the venue".to_string()
+                },
+            ]
+        );
+    }
+}
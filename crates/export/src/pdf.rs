@@ -0,0 +1,220 @@
+use printpdf::{IndirectFontRef, Mm, PdfDocument, PdfDocumentReference, PdfLayerReference};
+
+use crate::{Block, Error};
+
+// A4, in millimeters.
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 20.0;
+
+const PT_TO_MM: f32 = 0.3528;
+const LINE_HEIGHT: f32 = 1.4;
+// DejaVu Sans' average glyph width in ems, close enough to wrap lines without measuring glyphs.
+const AVG_CHAR_WIDTH: f32 = 0.55;
+
+// Embedded, since the PDF base fonts only cover Windows-1252 and notes are written in any
+// language. DejaVu covers Latin, Greek and Cyrillic scripts among others.
+const REGULAR_FONT: &[u8] = include_bytes!("../assets/DejaVuSans.ttf");
+const BOLD_FONT: &[u8] = include_bytes!("../assets/DejaVuSans-Bold.ttf");
+
+const LIST_INDENT: f32 = 6.0;
+const MARKER_WIDTH: f32 = 6.0;
+
+/// Lays out text top to bottom, starting a new page when the current one is full.
+struct Writer {
+    doc: PdfDocumentReference,
+    layer: PdfLayerReference,
+    regular: IndirectFontRef,
+    bold: IndirectFontRef,
+    y: f32,
+}
+
+impl Writer {
+    fn new(title: &str) -> Result<Self, Error> {
+        let (doc, page, layer) =
+            PdfDocument::new(title, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+        let layer = doc.get_page(page).get_layer(layer);
+
+        let regular = doc
+            .add_external_font(REGULAR_FONT)
+            .map_err(|e| Error::PdfError(e.to_string()))?;
+        let bold = doc
+            .add_external_font(BOLD_FONT)
+            .map_err(|e| Error::PdfError(e.to_string()))?;
+
+        Ok(Self {
+            doc,
+            layer,
+            regular,
+            bold,
+            y: PAGE_HEIGHT - MARGIN,
+        })
+    }
+
+    fn is_at_page_top(&self) -> bool {
+        self.y >= PAGE_HEIGHT - MARGIN
+    }
+
+    fn new_page(&mut self) {
+        let (page, layer) = self
+            .doc
+            .add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+        self.layer = self.doc.get_page(page).get_layer(layer);
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    fn space(&mut self, mm: f32) {
+        if !self.is_at_page_top() {
+            self.y -= mm;
+        }
+    }
+
+    /// Wraps `text` to the page width. A `marker` like a bullet hangs to the left of it.
+    fn text(&mut self, text: &str, size: f32, bold: bool, indent: f32, marker: Option<&str>) {
+        let left = MARGIN + indent + marker.map_or(0.0, |_| MARKER_WIDTH);
+        let char_width = size * AVG_CHAR_WIDTH * PT_TO_MM;
+        let max_chars = (((PAGE_WIDTH - MARGIN - left) / char_width) as usize).max(1);
+        let line_height = size * LINE_HEIGHT * PT_TO_MM;
+
+        for (i, line) in wrap(text, max_chars).iter().enumerate() {
+            if self.y - line_height < MARGIN {
+                self.new_page();
+            }
+            self.y -= line_height;
+
+            let font = if bold { &self.bold } else { &self.regular };
+            if let (0, Some(marker)) = (i, marker) {
+                self.layer
+                    .use_text(marker, size, Mm(MARGIN + indent), Mm(self.y), font);
+            }
+            self.layer
+                .use_text(line.as_str(), size, Mm(left), Mm(self.y), font);
+        }
+    }
+
+    fn finish(self) -> Result<Vec<u8>, Error> {
+        self.doc
+            .save_to_bytes()
+            .map_err(|e| Error::PdfError(e.to_string()))
+    }
+}
+
+pub(crate) fn render(title: &str, blocks: &[Block]) -> Result<Vec<u8>, Error> {
+    let mut writer = Writer::new(title)?;
+
+    for block in blocks {
+        match block {
+            Block::Title(text) => {
+                writer.text(text, 20.0, true, 0.0, None);
+                writer.space(3.0);
+            }
+            Block::Meta { label, value } => {
+                writer.text(&format!("{}: {}", label, value), 10.0, false, 0.0, None);
+            }
+            Block::Heading(text) => {
+                writer.space(6.0);
+                writer.text(text, 14.0, true, 0.0, None);
+                writer.space(1.5);
+            }
+            Block::Subheading(text) => {
+                writer.space(3.0);
+                writer.text(text, 12.0, true, 0.0, None);
+                writer.space(1.0);
+            }
+            Block::Paragraph(text) => {
+                writer.text(text, 11.0, false, 0.0, None);
+                writer.space(2.0);
+            }
+            Block::Bullet { depth, text } => {
+                let indent = *depth as f32 * LIST_INDENT;
+                writer.text(text, 11.0, false, indent, Some("•"));
+                writer.space(1.0);
+            }
+            Block::Task { done, text } => {
+                let marker = if *done { "[x]" } else { "[ ]" };
+                writer.text(text, 11.0, false, 0.0, Some(marker));
+                writer.space(1.0);
+            }
+            Block::Utterance {
+                timestamp,
+                speaker,
+                text,
+            } => {
+                writer.text(
+                    &format!("{}  {}", timestamp, speaker),
+                    10.0,
+                    true,
+                    0.0,
+                    None,
+                );
+                writer.text(text, 11.0, false, 0.0, None);
+                writer.space(2.5);
+            }
+            Block::PageBreak => {
+                if !writer.is_at_page_top() {
+                    writer.new_page();
+                }
+            }
+        }
+    }
+
+    writer.finish()
+}
+
+/// Greedy word wrap, breaking words that are longer than a whole line.
+fn wrap(text: &str, max_chars: usize) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+
+        while word.len() > max_chars {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            lines.push(word.drain(..max_chars).collect());
+        }
+        if word.is_empty() {
+            continue;
+        }
+
+        let line_len = line.chars().count();
+        if line_len > 0 && line_len + 1 + word.len() > max_chars {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.extend(word);
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(
+            wrap("the quick brown fox", 10),
+            vec!["the quick", "brown fox"]
+        );
+        assert_eq!(wrap("abcdefghijkl xy", 5), vec!["abcde", "fghij", "kl xy"]);
+        assert!(wrap("   ", 5).is_empty());
+    }
+
+    #[test]
+    fn test_render_non_latin() {
+        let blocks = vec![
+            Block::Title("Встреча".to_string()),
+            Block::Paragraph("Ωmega – “quoted”".to_string()),
+        ];
+        assert!(render("Встреча", &blocks).unwrap().starts_with(b"%PDF"));
+    }
+}
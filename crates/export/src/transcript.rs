@@ -0,0 +1,106 @@
+use owhisper_interface::{SpeakerIdentity, Word2};
+
+// Silence longer than this starts a new paragraph.
+const PAUSE_MS: u64 = 2_000;
+
+/// Consecutive words of one speaker, as they read in an exported transcript.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranscriptParagraph {
+    pub speaker: String,
+    pub start_ms: u64,
+    pub text: String,
+}
+
+pub fn paragraphs(words: &[Word2]) -> Vec<TranscriptParagraph> {
+    let mut paragraphs: Vec<(&Option<SpeakerIdentity>, u64, Vec<&str>)> = vec![];
+    let mut last_end_ms = 0;
+
+    for word in words {
+        let start_ms = word.start_ms.unwrap_or(last_end_ms);
+
+        let split = match paragraphs.last() {
+            None => true,
+            Some((speaker, _, _)) => {
+                *speaker != &word.speaker || start_ms.saturating_sub(last_end_ms) > PAUSE_MS
+            }
+        };
+
+        if split {
+            paragraphs.push((&word.speaker, start_ms, vec![]));
+        }
+        if let Some((_, _, texts)) = paragraphs.last_mut() {
+            texts.push(word.text.trim());
+        }
+
+        last_end_ms = word.end_ms.unwrap_or(start_ms);
+    }
+
+    paragraphs
+        .into_iter()
+        .map(|(speaker, start_ms, texts)| TranscriptParagraph {
+            speaker: speaker_label(speaker),
+            start_ms,
+            text: texts.join(" "),
+        })
+        .collect()
+}
+
+pub fn format_timestamp(ms: u64) -> String {
+    let secs = ms / 1000;
+    let (h, m, s) = (secs / 3600, (secs % 3600) / 60, secs % 60);
+
+    if h > 0 {
+        format!("{}:{:02}:{:02}", h, m, s)
+    } else {
+        format!("{:02}:{:02}", m, s)
+    }
+}
+
+fn speaker_label(speaker: &Option<SpeakerIdentity>) -> String {
+    match speaker {
+        Some(SpeakerIdentity::Assigned { label, .. }) => label.clone(),
+        Some(SpeakerIdentity::Unassigned { index }) => format!("Speaker {}", *index as u16 + 1),
+        None => "Unknown".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, speaker: u8, start_ms: u64) -> Word2 {
+        Word2 {
+            text: text.to_string(),
+            speaker: Some(SpeakerIdentity::Unassigned { index: speaker }),
+            confidence: None,
+            start_ms: Some(start_ms),
+            end_ms: Some(start_ms + 400),
+        }
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let words = vec![
+            word("Hello", 0, 0),
+            word("there.", 0, 500),
+            word("Hi.", 1, 1_000),
+            word("Anyway,", 1, 10_000),
+        ];
+
+        let paragraphs = paragraphs(&words);
+        assert_eq!(
+            paragraphs
+                .iter()
+                .map(|p| (p.speaker.as_str(), p.start_ms, p.text.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Speaker 1", 0, "Hello there."),
+                ("Speaker 2", 1_000, "Hi."),
+                ("Speaker 2", 10_000, "Anyway,"),
+            ]
+        );
+
+        assert_eq!(format_timestamp(75_000), "01:15");
+        assert_eq!(format_timestamp(3_725_000), "1:02:05");
+    }
+}
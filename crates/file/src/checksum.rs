@@ -0,0 +1,168 @@
+use std::{
+    fs::File,
+    io::{BufReader, Read, Seek, SeekFrom},
+    path::Path,
+};
+
+use sha2::Digest;
+
+use crate::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumAlgorithm {
+    Crc32,
+    Sha256,
+    Blake3,
+}
+
+/// A digest together with the algorithm that produced it, as lowercase hex.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checksum {
+    pub algorithm: ChecksumAlgorithm,
+    pub hex: String,
+}
+
+impl Checksum {
+    pub fn new(algorithm: ChecksumAlgorithm, hex: impl AsRef<str>) -> Self {
+        Self {
+            algorithm,
+            hex: hex.as_ref().to_ascii_lowercase(),
+        }
+    }
+
+    /// CRC32 values are published as plain integers, e.g. by `calculate_file_checksum`.
+    pub fn crc32(value: u32) -> Self {
+        Self::new(ChecksumAlgorithm::Crc32, format!("{:08x}", value))
+    }
+
+    pub fn sha256(hex: impl AsRef<str>) -> Self {
+        Self::new(ChecksumAlgorithm::Sha256, hex)
+    }
+
+    pub fn blake3(hex: impl AsRef<str>) -> Self {
+        Self::new(ChecksumAlgorithm::Blake3, hex)
+    }
+}
+
+impl std::fmt::Display for Checksum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}:{}", self.algorithm, self.hex)
+    }
+}
+
+/// Incremental hasher, fed with a file's bytes in order, e.g. as they are downloaded.
+pub enum ChecksumHasher {
+    Crc32(crc32fast::Hasher),
+    Sha256(sha2::Sha256),
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl ChecksumHasher {
+    pub fn new(algorithm: ChecksumAlgorithm) -> Self {
+        match algorithm {
+            ChecksumAlgorithm::Crc32 => Self::Crc32(crc32fast::Hasher::new()),
+            ChecksumAlgorithm::Sha256 => Self::Sha256(sha2::Sha256::new()),
+            ChecksumAlgorithm::Blake3 => Self::Blake3(Box::new(blake3::Hasher::new())),
+        }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Crc32(hasher) => hasher.update(data),
+            Self::Sha256(hasher) => hasher.update(data),
+            Self::Blake3(hasher) => {
+                hasher.update(data);
+            }
+        }
+    }
+
+    pub fn finalize(self) -> Checksum {
+        match self {
+            Self::Crc32(hasher) => Checksum::crc32(hasher.finalize()),
+            Self::Sha256(hasher) => {
+                let hex = hasher
+                    .finalize()
+                    .iter()
+                    .map(|b| format!("{:02x}", b))
+                    .collect::<String>();
+                Checksum::new(ChecksumAlgorithm::Sha256, hex)
+            }
+            Self::Blake3(hasher) => {
+                Checksum::new(ChecksumAlgorithm::Blake3, hasher.finalize().to_hex())
+            }
+        }
+    }
+}
+
+/// Feeds `len` bytes of the file at `path`, starting at `offset`, to `hasher`.
+pub(crate) fn hash_file_range(
+    path: impl AsRef<Path>,
+    offset: u64,
+    len: u64,
+    hasher: &mut ChecksumHasher,
+) -> Result<(), Error> {
+    read_chunks(path, offset, len, |chunk| hasher.update(chunk))
+}
+
+fn read_chunks(
+    path: impl AsRef<Path>,
+    offset: u64,
+    len: u64,
+    mut on_chunk: impl FnMut(&[u8]),
+) -> Result<(), Error> {
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(offset))?;
+    let mut reader = BufReader::new(file).take(len);
+
+    let mut buffer = [0; 65536]; // 64KB buffer
+
+    loop {
+        let bytes_read = reader.read(&mut buffer)?;
+        if bytes_read == 0 {
+            // eof
+            break;
+        }
+        on_chunk(&buffer[..bytes_read]);
+    }
+
+    Ok(())
+}
+
+pub fn calculate_checksum_with(
+    path: impl AsRef<Path>,
+    algorithm: ChecksumAlgorithm,
+) -> Result<Checksum, Error> {
+    let mut hasher = ChecksumHasher::new(algorithm);
+    hash_file_range(path, 0, u64::MAX, &mut hasher)?;
+    Ok(hasher.finalize())
+}
+
+pub fn calculate_file_checksum(path: impl AsRef<Path>) -> Result<u32, Error> {
+    let mut hasher = crc32fast::Hasher::new();
+    read_chunks(path, 0, u64::MAX, |chunk| hasher.update(chunk))?;
+    Ok(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_checksum_with() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), b"hello world").unwrap();
+
+        assert_eq!(
+            calculate_checksum_with(file.path(), ChecksumAlgorithm::Crc32).unwrap(),
+            Checksum::crc32(calculate_file_checksum(file.path()).unwrap())
+        );
+        assert_eq!(
+            calculate_checksum_with(file.path(), ChecksumAlgorithm::Sha256).unwrap(),
+            Checksum::sha256("B94D27B9934D3E08A52E52D7DA7DABFAC484EFE37A5380EE9088F7ACE2EFCDE9")
+        );
+        assert_eq!(
+            calculate_checksum_with(file.path(), ChecksumAlgorithm::Blake3).unwrap(),
+            Checksum::blake3("d74981efa70a0c880b8d8c1985d075dbcbf679b99a5f9914e5aaf96b831a9e24")
+        );
+    }
+}
@@ -1,11 +1,20 @@
+mod checksum;
 mod local;
+mod manifest;
+mod mirror;
 mod remote;
 mod types;
 
+pub use checksum::*;
 pub use local::*;
+pub use mirror::*;
 pub use remote::*;
 pub use types::*;
 
+pub use manifest::{download_manifest_path, is_download_manifest};
+
+use manifest::DownloadManifest;
+
 use {
     futures_util::{stream::FuturesUnordered, StreamExt, TryStreamExt},
     hypr_download_interface::DownloadProgress,
@@ -14,17 +23,15 @@ use {
         cmp::min,
         fs::File,
         fs::OpenOptions,
-        io::{BufReader, Read, Seek, SeekFrom, Write},
+        io::{Read, Seek, SeekFrom, Write},
         path::Path,
-        sync::{Arc, Mutex, OnceLock},
+        sync::{Arc, Mutex},
     },
     tokio_util::sync::CancellationToken,
 };
 
-static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
-
-fn get_client() -> &'static reqwest::Client {
-    CLIENT.get_or_init(|| reqwest::Client::new())
+fn get_client() -> reqwest::Client {
+    hypr_proxy::client()
 }
 
 /// Makes a request with optional range header and returns the response.
@@ -92,6 +99,23 @@ pub async fn download_file_with_callback_cancellable<F: Fn(DownloadProgress)>(
     output_path: impl AsRef<Path>,
     progress_callback: F,
     cancellation_token: Option<CancellationToken>,
+) -> Result<(), crate::Error> {
+    download_sequential(
+        url,
+        output_path,
+        progress_callback,
+        cancellation_token,
+        None,
+    )
+    .await
+}
+
+async fn download_sequential<F: Fn(DownloadProgress)>(
+    url: impl reqwest::IntoUrl,
+    output_path: impl AsRef<Path>,
+    progress_callback: F,
+    cancellation_token: Option<CancellationToken>,
+    expected_checksum: Option<&Checksum>,
 ) -> Result<(), crate::Error> {
     let url = url.into_url()?;
 
@@ -122,11 +146,10 @@ pub async fn download_file_with_callback_cancellable<F: Fn(DownloadProgress)>(
     .await?;
 
     if !res.status().is_success() && res.status() != StatusCode::PARTIAL_CONTENT {
-        return Err(crate::Error::OtherError(format!(
-            "Download failed with status {}: {}",
-            res.status(),
-            url
-        )));
+        return Err(crate::Error::HttpStatus {
+            status: res.status().as_u16(),
+            url: url.to_string(),
+        });
     }
 
     // If we tried to resume but server doesn't support it, start fresh
@@ -137,11 +160,10 @@ pub async fn download_file_with_callback_cancellable<F: Fn(DownloadProgress)>(
         res = request_with_range(url.clone(), None).await?;
 
         if !res.status().is_success() {
-            return Err(crate::Error::OtherError(format!(
-                "Download failed with status {}: {}",
-                res.status(),
-                url
-            )));
+            return Err(crate::Error::HttpStatus {
+                status: res.status().as_u16(),
+                url: url.to_string(),
+            });
         }
     }
 
@@ -168,6 +190,12 @@ pub async fn download_file_with_callback_cancellable<F: Fn(DownloadProgress)>(
         file.seek(SeekFrom::End(0))?;
     }
 
+    // Bytes kept from an earlier attempt are hashed up front, the rest as they arrive.
+    let mut hasher = expected_checksum.map(|checksum| ChecksumHasher::new(checksum.algorithm));
+    if let Some(hasher) = hasher.as_mut() {
+        hash_file_range(&output_path, 0, existing_size, hasher)?;
+    }
+
     let mut downloaded: u64 = existing_size;
     let mut stream = res.bytes_stream();
 
@@ -198,6 +226,9 @@ pub async fn download_file_with_callback_cancellable<F: Fn(DownloadProgress)>(
         match stream.next().await {
             Some(Ok(chunk)) => {
                 write_buffer.extend_from_slice(&chunk);
+                if let Some(hasher) = hasher.as_mut() {
+                    hasher.update(&chunk);
+                }
 
                 // Write when buffer is large enough
                 if write_buffer.len() >= 1024 * 1024 {
@@ -233,42 +264,94 @@ pub async fn download_file_with_callback_cancellable<F: Fn(DownloadProgress)>(
     file.flush()?;
     file.sync_all()?;
 
+    if let (Some(expected), Some(hasher)) = (expected_checksum, hasher) {
+        verify_checksum(&output_path, expected, hasher.finalize())?;
+    }
+
     progress_callback(DownloadProgress::Finished);
 
     Ok(())
 }
 
-/// Process a chunk write with proper error handling and ordering
+/// Removes the file when its contents don't hash to `expected`, so a corrupted
+/// download is never mistaken for a finished one.
+fn verify_checksum(
+    path: impl AsRef<Path>,
+    expected: &Checksum,
+    actual: Checksum,
+) -> Result<(), Error> {
+    if actual == *expected {
+        return Ok(());
+    }
+
+    tracing::warn!(
+        "Checksum mismatch, removing {:?}: expected {}, got {}",
+        path.as_ref(),
+        expected,
+        actual
+    );
+    std::fs::remove_file(path.as_ref())?;
+
+    Err(Error::ChecksumMismatch {
+        expected: expected.to_string(),
+        actual: actual.to_string(),
+    })
+}
+
+/// Writes a finished chunk in place and records it in the manifest, so that a crash
+/// only loses the chunks still in flight.
 fn process_task_result(
     result: Result<(u64, Vec<u8>), Error>,
-    file: &Arc<Mutex<File>>,
-    pending_writes: &Arc<Mutex<std::collections::BTreeMap<u64, Vec<u8>>>>,
-    next_write_offset: &Arc<Mutex<u64>>,
+    file: &mut File,
+    output_path: &Path,
+    manifest: &mut DownloadManifest,
 ) -> Result<(), Error> {
-    match result {
-        Ok((offset, data)) => {
-            let mut pending = pending_writes.lock().unwrap();
-            pending.insert(offset, data);
-
-            // Try to write consecutive chunks
-            let mut next_offset = next_write_offset.lock().unwrap();
-            let mut file = file.lock().unwrap();
-
-            while let Some(data) = pending.remove(&*next_offset) {
-                file.seek(SeekFrom::Start(*next_offset))?;
-                file.write_all(&data)?;
-                *next_offset += data.len() as u64;
-            }
+    let (chunk_idx, data) = result?;
+
+    let expected_len = manifest.chunk_len(chunk_idx);
+    if data.len() as u64 != expected_len {
+        return Err(Error::OtherError(format!(
+            "Chunk {} has {} bytes, expected {}",
+            chunk_idx,
+            data.len(),
+            expected_len
+        )));
+    }
 
-            // Only flush periodically, not after every write
-            if pending.is_empty() {
-                file.flush()?;
-            }
+    let (start, _) = manifest.chunk_range(chunk_idx);
+    file.seek(SeekFrom::Start(start))?;
+    file.write_all(&data)?;
+    // The manifest must never claim bytes that are not on disk yet.
+    file.sync_data()?;
 
-            Ok(())
-        }
-        Err(e) => Err(e),
+    manifest.completed.insert(chunk_idx);
+    manifest.save(output_path)
+}
+
+/// Feeds `hasher` the chunks that are now contiguous with what it has already seen.
+/// Chunks finish out of order, so they are read back from disk once their turn comes.
+fn advance_hasher(
+    output_path: &Path,
+    manifest: &DownloadManifest,
+    hasher: &mut Option<ChecksumHasher>,
+    hashed_chunks: &mut u64,
+) -> Result<(), Error> {
+    let Some(hasher) = hasher.as_mut() else {
+        return Ok(());
+    };
+
+    while manifest.completed.contains(hashed_chunks) {
+        let (start, _) = manifest.chunk_range(*hashed_chunks);
+        hash_file_range(
+            output_path,
+            start,
+            manifest.chunk_len(*hashed_chunks),
+            hasher,
+        )?;
+        *hashed_chunks += 1;
     }
+
+    Ok(())
 }
 
 const DEFAULT_CHUNK_SIZE: u64 = 8 * 1024 * 1024;
@@ -283,12 +366,54 @@ pub async fn download_file_parallel<F: Fn(DownloadProgress) + Send + Sync>(
 }
 
 /// Downloads a file in parallel chunks with cancellation support.
-/// When cancelled, ensures all downloaded data is properly written to disk.
+/// Finished chunks are tracked in a sidecar manifest (`<file>.hypr-download.json`),
+/// so a later call skips them exactly, even after the process crashed.
 pub async fn download_file_parallel_cancellable<F: Fn(DownloadProgress) + Send + Sync>(
     url: impl reqwest::IntoUrl,
     output_path: impl AsRef<Path>,
     progress_callback: F,
     cancellation_token: Option<CancellationToken>,
+) -> Result<(), Error> {
+    download_parallel(
+        url,
+        &[],
+        output_path,
+        progress_callback,
+        cancellation_token,
+        None,
+    )
+    .await
+}
+
+/// Like `download_file_parallel_cancellable`, but hashes the file as it is written.
+/// On a mismatch the file is removed and `Error::ChecksumMismatch` returned, so callers
+/// never load a corrupted model.
+pub async fn download_file_parallel_verified<F: Fn(DownloadProgress) + Send + Sync>(
+    url: impl reqwest::IntoUrl,
+    output_path: impl AsRef<Path>,
+    expected_checksum: &Checksum,
+    progress_callback: F,
+    cancellation_token: Option<CancellationToken>,
+) -> Result<(), Error> {
+    download_parallel(
+        url,
+        &[],
+        output_path,
+        progress_callback,
+        cancellation_token,
+        Some(expected_checksum),
+    )
+    .await
+}
+
+/// `mirrors` serve the same file as `url`, so a manifest left by any of them is resumed.
+async fn download_parallel<F: Fn(DownloadProgress) + Send + Sync>(
+    url: impl reqwest::IntoUrl,
+    mirrors: &[reqwest::Url],
+    output_path: impl AsRef<Path>,
+    progress_callback: F,
+    cancellation_token: Option<CancellationToken>,
+    expected_checksum: Option<&Checksum>,
 ) -> Result<(), Error> {
     let url = url.into_url()?;
     let progress_callback = Arc::new(progress_callback);
@@ -301,11 +426,10 @@ pub async fn download_file_parallel_cancellable<F: Fn(DownloadProgress) + Send +
 
     // Check if the resource exists before attempting download
     if !head_response.status().is_success() {
-        return Err(crate::Error::OtherError(format!(
-            "Resource not found or inaccessible (status {}): {}",
-            head_response.status(),
-            url
-        )));
+        return Err(crate::Error::HttpStatus {
+            status: head_response.status().as_u16(),
+            url: url.to_string(),
+        });
     }
 
     let total_size = get_content_length_from_headers(&head_response);
@@ -319,196 +443,254 @@ pub async fn download_file_parallel_cancellable<F: Fn(DownloadProgress) + Send +
 
     // Fall back to sequential download if ranges not supported or file is small
     if !supports_ranges || total_size.unwrap_or(0) <= DEFAULT_CHUNK_SIZE {
-        return download_file_with_callback_cancellable(
+        truncate_to_contiguous_chunks(output_path.as_ref())?;
+        return download_sequential(
             url,
             output_path,
             move |progress| progress_callback(progress),
             cancellation_token,
+            expected_checksum,
         )
         .await;
     }
 
     let total_size = total_size.unwrap();
+    let output_path = output_path.as_ref();
+
+    let mut manifest = match DownloadManifest::load(output_path) {
+        Some(manifest)
+            if (manifest.matches(&url, total_size)
+                || mirrors.iter().any(|m| manifest.matches(m, total_size)))
+                && output_path.exists() =>
+        {
+            tracing::info!(
+                "Resuming download with {} of {} chunks completed",
+                manifest.completed.len(),
+                manifest.num_chunks()
+            );
+            manifest
+        }
+        stale => {
+            // Chunks of another file were written out of order, none of it can be kept.
+            if stale.is_some() && output_path.exists() {
+                tracing::warn!("Download manifest doesn't match, starting fresh download");
+                std::fs::remove_file(output_path)?;
+            }
 
-    let existing_size = if output_path.as_ref().exists() {
-        let size = file_size(&output_path)?;
-        // Validate existing file and truncate to last complete chunk boundary
-        if validate_partial_file(&output_path, size) {
-            // Round down to nearest chunk boundary to ensure we re-download any partial chunk
-            let chunk_boundary = (size / (1024 * 1024)) * (1024 * 1024);
-            if chunk_boundary < size {
-                // Truncate file to last complete chunk
-                let file = OpenOptions::new().write(true).open(output_path.as_ref())?;
-                file.set_len(chunk_boundary)?;
-                tracing::info!("Truncated file from {} to {} bytes", size, chunk_boundary);
-                chunk_boundary
-            } else {
-                size
+            let chunk_size = min(
+                DEFAULT_CHUNK_SIZE,
+                total_size / MAX_CONCURRENT_CHUNKS as u64,
+            )
+            .max(1024 * 1024);
+            let mut manifest = DownloadManifest::new(&url, total_size, chunk_size);
+
+            // Without a manifest, an existing file was written front to back.
+            if output_path.exists() {
+                let size = file_size(output_path)?;
+                if validate_partial_file(output_path, size) {
+                    manifest.complete_prefix(size);
+                } else {
+                    tracing::warn!("Existing file appears corrupted, starting fresh download");
+                    std::fs::remove_file(output_path)?;
+                }
             }
-        } else {
-            tracing::warn!("Existing file appears corrupted, starting fresh download");
-            std::fs::remove_file(output_path.as_ref())?;
-            0
+
+            manifest.save(output_path)?;
+            manifest
         }
-    } else {
-        0
     };
 
-    if existing_size >= total_size {
-        progress_callback(DownloadProgress::Finished);
-        return Ok(());
-    }
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(output_path)?;
 
-    let remaining_size = total_size - existing_size;
-    let chunk_size = min(
-        DEFAULT_CHUNK_SIZE,
-        remaining_size / MAX_CONCURRENT_CHUNKS as u64,
-    )
-    .max(1024 * 1024);
-    let num_chunks = (remaining_size + chunk_size - 1) / chunk_size;
-
-    let file = if existing_size > 0 {
-        Arc::new(Mutex::new(
-            OpenOptions::new()
-                .read(true)
-                .write(true)
-                .open(output_path.as_ref())?,
-        ))
-    } else {
-        Arc::new(Mutex::new(File::create(output_path.as_ref())?))
-    };
+    let mut hasher = expected_checksum.map(|checksum| ChecksumHasher::new(checksum.algorithm));
+    let mut hashed_chunks = 0;
+    advance_hasher(output_path, &manifest, &mut hasher, &mut hashed_chunks)?;
 
-    let downloaded = Arc::new(Mutex::new(existing_size));
-    let pending_writes = Arc::new(Mutex::new(std::collections::BTreeMap::new()));
-    let next_write_offset = Arc::new(Mutex::new(existing_size));
-    let mut tasks = FuturesUnordered::new();
+    let remaining_chunks: Vec<u64> = (0..manifest.num_chunks())
+        .filter(|chunk_idx| !manifest.completed.contains(chunk_idx))
+        .collect();
 
-    progress_callback(DownloadProgress::Started);
+    if !remaining_chunks.is_empty() {
+        let downloaded = Arc::new(Mutex::new(manifest.completed_bytes()));
+        let mut tasks = FuturesUnordered::new();
 
-    for chunk_idx in 0..num_chunks {
-        // Check for cancellation before starting new chunks
-        if let Some(ref token) = cancellation_token {
-            if token.is_cancelled() {
-                // Process any remaining tasks and flush data
-                while let Some(result) = tasks.next().await {
-                    let _ = process_task_result(result, &file, &pending_writes, &next_write_offset);
-                }
+        progress_callback(DownloadProgress::Started);
 
-                // Ensure all pending writes are flushed
-                {
-                    let mut file_guard = file.lock().unwrap();
-                    file_guard.flush()?;
-                    file_guard.sync_all()?;
-                }
+        for chunk_idx in remaining_chunks {
+            // Check for cancellation before starting new chunks
+            if let Some(ref token) = cancellation_token {
+                if token.is_cancelled() {
+                    // Keep the chunks that made it, so resuming skips them
+                    while let Some(result) = tasks.next().await {
+                        if process_task_result(result, &mut file, output_path, &mut manifest)
+                            .is_ok()
+                        {
+                            advance_hasher(
+                                output_path,
+                                &manifest,
+                                &mut hasher,
+                                &mut hashed_chunks,
+                            )?;
+                        }
+                    }
 
-                tracing::info!(
-                    "Download cancelled, partial file saved at: {:?}",
-                    output_path.as_ref()
-                );
-                return Err(crate::Error::Cancelled);
+                    tracing::info!(
+                        "Download cancelled, partial file saved at: {:?}",
+                        output_path
+                    );
+                    return Err(crate::Error::Cancelled);
+                }
             }
-        }
 
-        let start = existing_size + chunk_idx * chunk_size;
-        let end = min(start + chunk_size - 1, total_size - 1);
+            let (start, end) = manifest.chunk_range(chunk_idx);
 
-        let url_clone = url.clone();
-        let downloaded_clone = Arc::clone(&downloaded);
-        let progress_callback_clone = Arc::clone(&progress_callback);
-        let cancellation_token_clone = cancellation_token.clone();
+            let url_clone = url.clone();
+            let downloaded_clone = Arc::clone(&downloaded);
+            let progress_callback_clone = Arc::clone(&progress_callback);
+            let cancellation_token_clone = cancellation_token.clone();
 
-        let task = async move {
-            // Check cancellation at chunk level
-            if let Some(ref token) = cancellation_token_clone {
-                if token.is_cancelled() {
-                    return Err(crate::Error::Cancelled);
+            let task = async move {
+                // Check cancellation at chunk level
+                if let Some(ref token) = cancellation_token_clone {
+                    if token.is_cancelled() {
+                        return Err(crate::Error::Cancelled);
+                    }
                 }
-            }
 
-            let client = get_client();
-            let range_header = format!("bytes={}-{}", start, end);
+                let client = get_client();
+                let range_header = format!("bytes={}-{}", start, end);
 
-            let response = client
-                .get(url_clone)
-                .header("Range", range_header)
-                .send()
-                .await?;
+                let response = client
+                    .get(url_clone)
+                    .header("Range", range_header)
+                    .send()
+                    .await?;
 
-            if response.status() != StatusCode::PARTIAL_CONTENT {
-                return Err(crate::Error::OtherError(format!(
-                    "Server didn't return partial content (status: {})",
-                    response.status()
-                )));
-            }
+                if response.status() != StatusCode::PARTIAL_CONTENT {
+                    return Err(crate::Error::OtherError(format!(
+                        "Server didn't return partial content (status: {})",
+                        response.status()
+                    )));
+                }
 
-            let mut bytes = Vec::new();
-            let mut stream = response.bytes_stream();
+                let mut bytes = Vec::new();
+                let mut stream = response.bytes_stream();
 
-            while let Some(chunk) = stream.try_next().await? {
-                // Check cancellation during chunk download
-                if let Some(ref token) = cancellation_token_clone {
-                    if token.is_cancelled() {
-                        return Ok((start, bytes)); // Return what we have so far
+                while let Some(chunk) = stream.try_next().await? {
+                    // A partial chunk can't be recorded, so it is dropped
+                    if let Some(ref token) = cancellation_token_clone {
+                        if token.is_cancelled() {
+                            return Err(crate::Error::Cancelled);
+                        }
                     }
-                }
 
-                bytes.extend_from_slice(&chunk);
+                    bytes.extend_from_slice(&chunk);
 
-                let mut downloaded_guard = downloaded_clone.lock().unwrap();
-                *downloaded_guard += chunk.len() as u64;
-                let current_downloaded = *downloaded_guard;
-                drop(downloaded_guard);
+                    let mut downloaded_guard = downloaded_clone.lock().unwrap();
+                    *downloaded_guard += chunk.len() as u64;
+                    let current_downloaded = *downloaded_guard;
+                    drop(downloaded_guard);
 
-                progress_callback_clone(DownloadProgress::Progress(current_downloaded, total_size));
-            }
+                    progress_callback_clone(DownloadProgress::Progress(
+                        current_downloaded,
+                        total_size,
+                    ));
+                }
 
-            Ok((start, bytes))
-        };
+                Ok((chunk_idx, bytes))
+            };
 
-        tasks.push(task);
+            tasks.push(task);
 
-        if tasks.len() >= MAX_CONCURRENT_CHUNKS {
-            if let Some(result) = tasks.next().await {
-                process_task_result(result, &file, &pending_writes, &next_write_offset)?;
+            if tasks.len() >= MAX_CONCURRENT_CHUNKS {
+                if let Some(result) = tasks.next().await {
+                    process_task_result(result, &mut file, output_path, &mut manifest)?;
+                    advance_hasher(output_path, &manifest, &mut hasher, &mut hashed_chunks)?;
+                }
             }
         }
-    }
 
-    while let Some(result) = tasks.next().await {
-        // If we get a cancellation error, still try to process the result
-        // as it might contain partial data
-        if let Err(Error::Cancelled) = &result {
-            // Process any data that was downloaded before cancellation
-            if let Ok((offset, data)) = result {
-                let _ = process_task_result(
-                    Ok((offset, data)),
-                    &file,
-                    &pending_writes,
-                    &next_write_offset,
-                );
+        while let Some(result) = tasks.next().await {
+            // Chunks dropped by a cancellation are downloaded again on resume
+            if let Err(Error::Cancelled) = &result {
+                continue;
             }
-        } else {
-            process_task_result(result, &file, &pending_writes, &next_write_offset)?;
+
+            process_task_result(result, &mut file, output_path, &mut manifest)?;
+            advance_hasher(output_path, &manifest, &mut hasher, &mut hashed_chunks)?;
         }
     }
 
-    // Final sync to ensure all data is on disk
-    {
-        let mut file_guard = file.lock().unwrap();
-        file_guard.flush()?;
-        file_guard.sync_all()?;
+    // Chunks cut short by a late cancellation leave the file incomplete.
+    if cancellation_token.is_some_and(|token| token.is_cancelled()) {
+        return Err(crate::Error::Cancelled);
+    }
+
+    // Left over from a larger file downloaded without a manifest.
+    if file.metadata()?.len() > total_size {
+        file.set_len(total_size)?;
     }
+    file.sync_all()?;
+    drop(file);
+
+    let verified = match (expected_checksum, hasher) {
+        (Some(expected), Some(hasher)) => verify_checksum(output_path, expected, hasher.finalize()),
+        _ => Ok(()),
+    };
+    // Finished or removed, either way there is nothing left to resume.
+    DownloadManifest::remove(output_path)?;
+    verified?;
 
     progress_callback(DownloadProgress::Finished);
 
     Ok(())
 }
 
+/// Sequential downloads resume from the end of the file, so a file left by a parallel
+/// download, e.g. from a mirror that served ranges, is cut back to its first gap.
+fn truncate_to_contiguous_chunks(output_path: &Path) -> Result<(), Error> {
+    let Some(manifest) = DownloadManifest::load(output_path) else {
+        return Ok(());
+    };
+
+    if output_path.exists() {
+        let contiguous = (0..manifest.num_chunks())
+            .take_while(|chunk_idx| manifest.completed.contains(chunk_idx))
+            .map(|chunk_idx| manifest.chunk_len(chunk_idx))
+            .sum::<u64>();
+
+        let file = OpenOptions::new().write(true).open(output_path)?;
+        if file.metadata()?.len() > contiguous {
+            file.set_len(contiguous)?;
+        }
+        file.sync_all()?;
+    }
+
+    DownloadManifest::remove(output_path)
+}
+
 pub fn file_size(path: impl AsRef<Path>) -> Result<u64, Error> {
     let metadata = std::fs::metadata(path.as_ref())?;
     Ok(metadata.len())
 }
 
+/// Whether `path` holds a finished download of `expected_size` bytes. A parallel download
+/// writes chunks out of order, so the file can reach its full size long before it's done;
+/// it only is once its manifest is gone.
+pub fn is_download_complete(path: impl AsRef<Path>, expected_size: u64) -> Result<bool, Error> {
+    let path = path.as_ref();
+    if !path.exists() || DownloadManifest::path(path).exists() {
+        return Ok(false);
+    }
+
+    Ok(file_size(path)? == expected_size)
+}
+
 /// Manually parse content-length header from HTTP response
 /// This is a workaround for cases where reqwest's content_length() method returns incorrect values
 fn get_content_length_from_headers(response: &reqwest::Response) -> Option<u64> {
@@ -520,25 +702,6 @@ fn get_content_length_from_headers(response: &reqwest::Response) -> Option<u64>
         .or_else(|| response.content_length())
 }
 
-pub fn calculate_file_checksum(path: impl AsRef<Path>) -> Result<u32, Error> {
-    let file = File::open(path)?;
-    let mut reader = BufReader::new(file);
-    let mut hasher = crc32fast::Hasher::new();
-
-    let mut buffer = [0; 65536]; // 64KB buffer
-
-    loop {
-        let bytes_read = reader.read(&mut buffer)?;
-        if bytes_read == 0 {
-            // eof
-            break;
-        }
-        hasher.update(&buffer[..bytes_read]);
-    }
-
-    Ok(hasher.finalize())
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1004,4 +1167,110 @@ mod tests {
 
         assert!(speedup >= 1.1, "Parallel download should be at least 10% faster: serial={:?}, parallel={:?}, speedup={:.2}x", serial_duration, parallel_duration, speedup);
     }
+
+    #[tokio::test]
+    async fn test_download_file_parallel_verified() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("HEAD"))
+            .and(path("/model.bin"))
+            .respond_with(ResponseTemplate::new(200).insert_header("Content-Length", "11"))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/model.bin"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"hello world"))
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/model.bin", mock_server.uri());
+        let dir = tempfile::tempdir().unwrap();
+        let output_path = dir.path().join("model.bin");
+
+        let expected =
+            Checksum::blake3("d74981efa70a0c880b8d8c1985d075dbcbf679b99a5f9914e5aaf96b831a9e24");
+        download_file_parallel_verified(&url, &output_path, &expected, |_| {}, None)
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(&output_path).unwrap(), b"hello world");
+
+        std::fs::remove_file(&output_path).unwrap();
+
+        let corrupted = Checksum::sha256("00".repeat(32));
+        let result =
+            download_file_parallel_verified(&url, &output_path, &corrupted, |_| {}, None).await;
+        assert!(matches!(result, Err(Error::ChecksumMismatch { .. })));
+        assert!(!output_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_download_file_parallel_resume_from_manifest() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+
+        let content: Vec<u8> = (0..10 * 1024 * 1024).map(|i| (i % 251) as u8).collect();
+        let total_size = content.len() as u64;
+
+        Mock::given(method("HEAD"))
+            .and(path("/model.bin"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Content-Length", total_size.to_string().as_str())
+                    .insert_header("Accept-Ranges", "bytes"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let url = reqwest::Url::parse(&format!("{}/model.bin", mock_server.uri())).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let output_path = dir.path().join("model.bin");
+
+        // Chunks 1 and 3 finished before a crash, the rest of the file is a hole.
+        let mut manifest = DownloadManifest::new(&url, total_size, 2 * 1024 * 1024);
+        let mut partial = vec![0u8; content.len()];
+        for chunk_idx in [1, 3] {
+            let (start, end) = manifest.chunk_range(chunk_idx);
+            let range = start as usize..=end as usize;
+            partial[range.clone()].copy_from_slice(&content[range]);
+            manifest.completed.insert(chunk_idx);
+        }
+        std::fs::write(&output_path, &partial).unwrap();
+        manifest.save(&output_path).unwrap();
+        assert!(!is_download_complete(&output_path, total_size).unwrap());
+
+        for chunk_idx in 0..manifest.num_chunks() {
+            let (start, end) = manifest.chunk_range(chunk_idx);
+            let expected_requests = if manifest.completed.contains(&chunk_idx) {
+                0
+            } else {
+                1
+            };
+
+            Mock::given(method("GET"))
+                .and(path("/model.bin"))
+                .and(header("Range", format!("bytes={}-{}", start, end).as_str()))
+                .respond_with(
+                    ResponseTemplate::new(206)
+                        .set_body_bytes(content[start as usize..=end as usize].to_vec()),
+                )
+                .expect(expected_requests)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let expected = Checksum::blake3(blake3::hash(&content).to_hex());
+        download_file_parallel_verified(url, &output_path, &expected, |_| {}, None)
+            .await
+            .unwrap();
+
+        assert_eq!(std::fs::read(&output_path).unwrap(), content);
+        assert!(DownloadManifest::load(&output_path).is_none());
+        assert!(is_download_complete(&output_path, total_size).unwrap());
+    }
 }
@@ -0,0 +1,164 @@
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use crate::Error;
+
+const MANIFEST_SUFFIX: &str = ".hypr-download.json";
+
+/// Where the sidecar of a download to `output_path` is kept while it's unfinished.
+pub fn download_manifest_path(output_path: impl AsRef<Path>) -> PathBuf {
+    DownloadManifest::path(output_path)
+}
+
+/// Whether `path` is the sidecar of an unfinished download, or a half-written one, rather
+/// than a downloaded file.
+pub fn is_download_manifest(path: impl AsRef<Path>) -> bool {
+    path.as_ref()
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| {
+            name.ends_with(MANIFEST_SUFFIX)
+                || name
+                    .strip_suffix(".tmp")
+                    .is_some_and(|name| name.ends_with(MANIFEST_SUFFIX))
+        })
+}
+
+/// Sidecar of a parallel download, recording which chunks are already on disk.
+///
+/// Chunks are only added once their bytes are synced, so after a crash the manifest
+/// can lag behind the file but never claim more than it holds.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct DownloadManifest {
+    /// Without the query string, which often carries a signature that changes between attempts.
+    pub url: String,
+    pub total_size: u64,
+    pub chunk_size: u64,
+    pub completed: BTreeSet<u64>,
+}
+
+impl DownloadManifest {
+    pub fn new(url: &reqwest::Url, total_size: u64, chunk_size: u64) -> Self {
+        Self {
+            url: Self::resource(url),
+            total_size,
+            chunk_size,
+            completed: BTreeSet::new(),
+        }
+    }
+
+    /// `model.gguf` is tracked by `model.gguf.hypr-download.json` next to it.
+    pub fn path(output_path: impl AsRef<Path>) -> PathBuf {
+        let mut path = output_path.as_ref().as_os_str().to_owned();
+        path.push(MANIFEST_SUFFIX);
+        PathBuf::from(path)
+    }
+
+    /// Returns `None` when there is no manifest or it can't be read, in which case
+    /// the download can't be resumed from it.
+    pub fn load(output_path: impl AsRef<Path>) -> Option<Self> {
+        let content = std::fs::read(Self::path(output_path)).ok()?;
+        serde_json::from_slice(&content).ok()
+    }
+
+    /// Written to a temporary file and renamed over the old manifest, so a crash
+    /// mid-write leaves the previous version intact.
+    pub fn save(&self, output_path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = Self::path(output_path);
+        let mut tmp_path = path.clone().into_os_string();
+        tmp_path.push(".tmp");
+
+        let content = serde_json::to_vec(self).map_err(|e| Error::OtherError(e.to_string()))?;
+        std::fs::write(&tmp_path, content)?;
+        std::fs::rename(&tmp_path, &path)?;
+        Ok(())
+    }
+
+    pub fn remove(output_path: impl AsRef<Path>) -> Result<(), Error> {
+        match std::fs::remove_file(Self::path(output_path)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn matches(&self, url: &reqwest::Url, total_size: u64) -> bool {
+        self.url == Self::resource(url) && self.total_size == total_size && self.chunk_size > 0
+    }
+
+    pub fn num_chunks(&self) -> u64 {
+        self.total_size.div_ceil(self.chunk_size)
+    }
+
+    /// Inclusive byte range, as used in `Range` headers.
+    pub fn chunk_range(&self, chunk_idx: u64) -> (u64, u64) {
+        let start = chunk_idx * self.chunk_size;
+        let end = (start + self.chunk_size).min(self.total_size) - 1;
+        (start, end)
+    }
+
+    pub fn chunk_len(&self, chunk_idx: u64) -> u64 {
+        let (start, end) = self.chunk_range(chunk_idx);
+        end - start + 1
+    }
+
+    pub fn completed_bytes(&self) -> u64 {
+        self.completed.iter().map(|&i| self.chunk_len(i)).sum()
+    }
+
+    /// Marks the chunks fully covered by the first `len` bytes, for files written front
+    /// to back without a manifest.
+    pub fn complete_prefix(&mut self, len: u64) {
+        for chunk_idx in 0..self.num_chunks() {
+            let (_, end) = self.chunk_range(chunk_idx);
+            if end < len {
+                self.completed.insert(chunk_idx);
+            }
+        }
+    }
+
+    fn resource(url: &reqwest::Url) -> String {
+        let mut url = url.clone();
+        url.set_query(None);
+        url.set_fragment(None);
+        url.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest() {
+        let url = reqwest::Url::parse("https://example.com/model.gguf?signature=a").unwrap();
+        let mut manifest = DownloadManifest::new(&url, 10, 4);
+
+        assert_eq!(manifest.num_chunks(), 3);
+        assert_eq!(manifest.chunk_range(2), (8, 9));
+
+        manifest.complete_prefix(9);
+        assert_eq!(manifest.completed, BTreeSet::from([0, 1]));
+        assert_eq!(manifest.completed_bytes(), 8);
+
+        let dir = tempfile::tempdir().unwrap();
+        let output_path = dir.path().join("model.gguf");
+        manifest.save(&output_path).unwrap();
+        assert!(dir.path().join("model.gguf.hypr-download.json").exists());
+        assert!(is_download_manifest(download_manifest_path(&output_path)));
+        assert!(is_download_manifest("model.gguf.hypr-download.json.tmp"));
+        assert!(!is_download_manifest(&output_path));
+
+        let loaded = DownloadManifest::load(&output_path).unwrap();
+        assert_eq!(loaded, manifest);
+
+        let resigned = reqwest::Url::parse("https://example.com/model.gguf?signature=b").unwrap();
+        assert!(loaded.matches(&resigned, 10));
+        assert!(!loaded.matches(&resigned, 11));
+
+        DownloadManifest::remove(&output_path).unwrap();
+        assert!(DownloadManifest::load(&output_path).is_none());
+        DownloadManifest::remove(&output_path).unwrap();
+    }
+}
@@ -0,0 +1,211 @@
+use std::{path::Path, time::Duration};
+
+use hypr_download_interface::DownloadProgress;
+use tokio_util::sync::CancellationToken;
+
+use crate::{download_parallel, Checksum, Error};
+
+/// How often a single mirror is tried before moving on to the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Attempts per mirror, including the first one.
+    pub max_attempts: u32,
+    /// Wait before the second attempt, doubled after every further failure.
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Wait after the `attempt`-th failed attempt, counting from 1.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2_u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// What to do after an attempt failed with `error`.
+enum Failover {
+    Retry,
+    NextMirror,
+    Abort,
+}
+
+impl From<&Error> for Failover {
+    fn from(error: &Error) -> Self {
+        match error {
+            Error::Cancelled | Error::FileIOError(_) => Failover::Abort,
+            // The file was removed, so the next mirror starts from scratch.
+            Error::ChecksumMismatch { .. } => Failover::NextMirror,
+            Error::HttpStatus { status, .. } if is_permanent(*status) => Failover::NextMirror,
+            Error::ReqwestError(e) if e.status().is_some_and(|s| is_permanent(s.as_u16())) => {
+                Failover::NextMirror
+            }
+            _ => Failover::Retry,
+        }
+    }
+}
+
+/// Client errors won't go away by asking the same server again, except for rate limits.
+fn is_permanent(status: u16) -> bool {
+    (400..500).contains(&status) && status != 408 && status != 429
+}
+
+/// Downloads the file from the first of `urls` that works, in order. Each mirror is
+/// retried according to `retry_policy` before failing over to the next one.
+///
+/// All mirrors must serve the same file: chunks finished on one of them are kept when
+/// the download continues on another. The last error is returned when every mirror failed.
+pub async fn download_file_parallel_with_mirrors<F: Fn(DownloadProgress) + Send + Sync>(
+    urls: &[impl reqwest::IntoUrl + Clone],
+    output_path: impl AsRef<Path>,
+    expected_checksum: Option<&Checksum>,
+    retry_policy: &RetryPolicy,
+    progress_callback: F,
+    cancellation_token: Option<CancellationToken>,
+) -> Result<(), Error> {
+    let urls = urls
+        .iter()
+        .map(|url| url.clone().into_url())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let max_attempts = retry_policy.max_attempts.max(1);
+    let mut last_error = Error::OtherError("No download URL given".to_string());
+
+    'mirrors: for url in &urls {
+        for attempt in 1..=max_attempts {
+            let result = download_parallel(
+                url.clone(),
+                &urls,
+                output_path.as_ref(),
+                &progress_callback,
+                cancellation_token.clone(),
+                expected_checksum,
+            )
+            .await;
+
+            let error = match result {
+                Ok(()) => return Ok(()),
+                Err(error) => error,
+            };
+
+            let failover = Failover::from(&error);
+            tracing::warn!(
+                "Download from {} failed (attempt {}): {}",
+                url,
+                attempt,
+                error
+            );
+            last_error = error;
+
+            match failover {
+                Failover::Abort => return Err(last_error),
+                Failover::NextMirror => continue 'mirrors,
+                Failover::Retry if attempt == max_attempts => continue 'mirrors,
+                Failover::Retry => {}
+            }
+
+            let backoff = tokio::time::sleep(retry_policy.backoff(attempt));
+            match &cancellation_token {
+                Some(token) => tokio::select! {
+                    _ = backoff => {}
+                    _ = token.cancelled() => return Err(Error::Cancelled),
+                },
+                None => backoff.await,
+            }
+        }
+    }
+
+    Err(last_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(300),
+        };
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(300));
+        assert_eq!(policy.backoff(40), Duration::from_millis(300));
+    }
+
+    #[tokio::test]
+    async fn test_download_file_parallel_with_mirrors() {
+        use wiremock::matchers::path;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let primary = MockServer::start().await;
+        let flaky = MockServer::start().await;
+        let mirror = MockServer::start().await;
+
+        let data = b"mirrored model weights".to_vec();
+
+        Mock::given(path("/model.bin"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(2)
+            .mount(&primary)
+            .await;
+        Mock::given(path("/model.bin"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(4)
+            .mount(&flaky)
+            .await;
+        Mock::given(path("/model.bin"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(data.clone()))
+            .mount(&mirror)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let output_path = dir.path().join("model.bin");
+        let urls =
+            [primary.uri(), flaky.uri(), mirror.uri()].map(|base| format!("{}/model.bin", base));
+        let policy = RetryPolicy {
+            max_attempts: 2,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(10),
+        };
+
+        download_file_parallel_with_mirrors(
+            &urls,
+            &output_path,
+            Some(&Checksum::blake3(blake3::hash(&data).to_hex())),
+            &policy,
+            |_| {},
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(std::fs::read(&output_path).unwrap(), data);
+
+        let result = download_file_parallel_with_mirrors(
+            &urls[..2],
+            dir.path().join("missing.bin"),
+            None,
+            &policy,
+            |_| {},
+            None,
+        )
+        .await;
+        assert!(matches!(result, Err(Error::HttpStatus { status: 503, .. })));
+    }
+}
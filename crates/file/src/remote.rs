@@ -13,7 +13,7 @@ pub async fn upload(
     let file_size = file.metadata().await?.len() as usize;
 
     let mut tasks = Vec::new();
-    let client = reqwest::Client::new();
+    let client = hypr_proxy::client();
 
     for (chunk_index, presigned_url) in presigned_urls.into_iter().enumerate() {
         let start = chunk_index * CHUNK_SIZE;
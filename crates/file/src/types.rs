@@ -6,6 +6,10 @@ pub enum Error {
     FileIOError(#[from] std::io::Error),
     #[error("Download cancelled")]
     Cancelled,
+    #[error("Checksum mismatch: expected {expected}, got {actual}")]
+    ChecksumMismatch { expected: String, actual: String },
+    #[error("Download failed with status {status}: {url}")]
+    HttpStatus { status: u16, url: String },
     #[error("Other error: {0}")]
     OtherError(String),
 }
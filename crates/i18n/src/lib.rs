@@ -0,0 +1,183 @@
+use std::{collections::HashMap, fmt::Display, sync::RwLock};
+
+/// Languages the native UI has strings for. Matches the locales the web UI ships.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    #[default]
+    En,
+    Ko,
+}
+
+impl Locale {
+    /// Accepts ISO 639-1 codes and full tags like `ko-KR` or `ko_KR.UTF-8`.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag
+            .split(['-', '_', '.'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match language.as_str() {
+            "en" => Some(Self::En),
+            "ko" => Some(Self::Ko),
+            _ => None,
+        }
+    }
+
+    /// The operating system's preferred language, if we have strings for it.
+    pub fn detect() -> Self {
+        sys_locale::get_locale()
+            .and_then(|tag| Self::from_tag(&tag))
+            .unwrap_or_default()
+    }
+}
+
+static CURRENT: RwLock<Locale> = RwLock::new(Locale::En);
+
+pub fn set_locale(locale: Locale) {
+    *CURRENT.write().unwrap() = locale;
+}
+
+pub fn locale() -> Locale {
+    *CURRENT.read().unwrap()
+}
+
+/// Strings for one plugin, in a subset of Fluent: `key = value` lines, `#` comments and
+/// `{ $name }` placeholders.
+pub struct Catalog {
+    messages: HashMap<Locale, HashMap<String, String>>,
+}
+
+impl Catalog {
+    pub fn new(sources: &[(Locale, &str)]) -> Self {
+        let messages = sources
+            .iter()
+            .map(|(locale, source)| (*locale, parse(source)))
+            .collect();
+
+        Self { messages }
+    }
+
+    /// Looks `key` up in the current locale, falling back to English and then to the key itself.
+    pub fn get(&self, key: &str) -> String {
+        self.lookup(locale(), key).to_string()
+    }
+
+    pub fn format(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let mut message = self.lookup(locale(), key).to_string();
+
+        for (name, value) in args {
+            message = message.replace(&format!("{{ ${} }}", name), &value.to_string());
+        }
+
+        message
+    }
+
+    /// Keys present in English but missing from `locale`.
+    pub fn missing(&self, locale: Locale) -> Vec<&str> {
+        let Some(english) = self.messages.get(&Locale::En) else {
+            return vec![];
+        };
+        let translated = self.messages.get(&locale);
+
+        let mut missing: Vec<&str> = english
+            .keys()
+            .filter(|key| !translated.is_some_and(|messages| messages.contains_key(*key)))
+            .map(String::as_str)
+            .collect();
+        missing.sort();
+        missing
+    }
+
+    fn lookup<'a>(&'a self, locale: Locale, key: &'a str) -> &'a str {
+        [locale, Locale::En]
+            .iter()
+            .find_map(|locale| self.messages.get(locale)?.get(key))
+            .map(String::as_str)
+            .unwrap_or(key)
+    }
+}
+
+/// Defines `t` and `t_args` in the calling module over a plugin's catalog, plus a test that
+/// every locale has all the English keys. Paths are relative to the calling file.
+///
+/// ```ignore
+/// hypr_i18n::catalog! {
+///     En => "../locales/en.ftl",
+///     Ko => "../locales/ko.ftl",
+/// }
+/// ```
+#[macro_export]
+macro_rules! catalog {
+    ($($locale:ident => $path:literal),+ $(,)?) => {
+        static CATALOG: std::sync::LazyLock<$crate::Catalog> = std::sync::LazyLock::new(|| {
+            $crate::Catalog::new(&[$(($crate::Locale::$locale, include_str!($path))),+])
+        });
+
+        pub fn t(key: &str) -> String {
+            CATALOG.get(key)
+        }
+
+        pub fn t_args(key: &str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+            CATALOG.format(key, args)
+        }
+
+        #[cfg(test)]
+        #[test]
+        fn test_catalog_complete() {
+            $(assert!(CATALOG.missing($crate::Locale::$locale).is_empty());)+
+        }
+    };
+}
+
+fn parse(source: &str) -> HashMap<String, String> {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EN: &str = r#"
+# Tray
+open = Open Hyprnote
+elapsed = Recording — { $elapsed }
+quit = Quit
+"#;
+
+    const KO: &str = r#"
+open = Hyprnote 열기
+elapsed = 녹음 중 — { $elapsed }
+"#;
+
+    #[test]
+    fn test_from_tag() {
+        assert_eq!(Locale::from_tag("ko"), Some(Locale::Ko));
+        assert_eq!(Locale::from_tag("ko-KR"), Some(Locale::Ko));
+        assert_eq!(Locale::from_tag("ko_KR.UTF-8"), Some(Locale::Ko));
+        assert_eq!(Locale::from_tag("EN-us"), Some(Locale::En));
+        assert_eq!(Locale::from_tag("fr"), None);
+    }
+
+    #[test]
+    fn test_catalog() {
+        let catalog = Catalog::new(&[(Locale::En, EN), (Locale::Ko, KO)]);
+
+        assert_eq!(catalog.lookup(Locale::En, "open"), "Open Hyprnote");
+        assert_eq!(catalog.lookup(Locale::Ko, "open"), "Hyprnote 열기");
+        assert_eq!(catalog.lookup(Locale::Ko, "quit"), "Quit");
+        assert_eq!(catalog.lookup(Locale::Ko, "unknown"), "unknown");
+        assert_eq!(catalog.missing(Locale::Ko), vec!["quit"]);
+
+        assert_eq!(
+            catalog.format("elapsed", &[("elapsed", &"00:05")]),
+            "Recording — 00:05"
+        );
+    }
+}
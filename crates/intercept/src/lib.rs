@@ -2,11 +2,43 @@ use std::sync::Mutex;
 #[cfg(target_os = "macos")]
 use swift_rs::swift;
 
+#[cfg(target_os = "windows")]
+mod windows;
+
 static QUIT_CALLBACK: Mutex<Option<Box<dyn Fn() -> bool + Send + Sync>>> = Mutex::new(None);
+static CONFIRM_QUIT_CALLBACK: Mutex<Option<Box<dyn Fn() -> bool + Send + Sync>>> = Mutex::new(None);
 
 #[cfg(target_os = "macos")]
 swift!(fn _setup_quit_handler());
 
+#[cfg(target_os = "macos")]
+swift!(fn _setup_power_observer());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerEvent {
+    Sleep,
+    Wake,
+    ScreenLocked,
+    ScreenUnlocked,
+}
+
+impl PowerEvent {
+    // Matches the codes the Swift observers pass to `rust_on_power_event`.
+    fn from_code(code: i32) -> Option<Self> {
+        match code {
+            0 => Some(Self::Sleep),
+            1 => Some(Self::Wake),
+            2 => Some(Self::ScreenLocked),
+            3 => Some(Self::ScreenUnlocked),
+            _ => None,
+        }
+    }
+}
+
+type PowerCallback = Box<dyn Fn(PowerEvent) + Send + Sync>;
+
+static POWER_CALLBACKS: Mutex<Vec<PowerCallback>> = Mutex::new(Vec::new());
+
 pub fn setup_quit_handler<F>(callback: F)
 where
     F: Fn() -> bool + Send + Sync + 'static,
@@ -18,17 +50,52 @@ where
             _setup_quit_handler();
         }
     }
+
+    #[cfg(target_os = "windows")]
+    {
+        *QUIT_CALLBACK.lock().unwrap() = Some(Box::new(callback));
+        windows::ensure_window();
+    }
 }
 
 pub fn reset_quit_handler() {
-    #[cfg(target_os = "macos")]
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
     {
         *QUIT_CALLBACK.lock().unwrap() = None;
     }
 }
 
+/// Asked by [`confirm_quit`], for quitting from the app's own UI (like the tray's Quit item),
+/// which neither the macOS terminate request nor the Windows end-session query covers.
+pub fn setup_quit_confirmation<F>(callback: F)
+where
+    F: Fn() -> bool + Send + Sync + 'static,
+{
+    *CONFIRM_QUIT_CALLBACK.lock().unwrap() = Some(Box::new(callback));
+}
+
+/// Whether the app may quit now. Blocks while the user is asked, so call it off the main
+/// thread. Yes when nothing is registered.
+pub fn confirm_quit() -> bool {
+    CONFIRM_QUIT_CALLBACK
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map_or(true, |callback| callback())
+}
+
+/// Windows only: while a reason is set, the shutdown screen names the app with it, instead of
+/// a generic "this app is preventing you from shutting down". Does nothing elsewhere.
+pub fn set_shutdown_block_reason(reason: Option<&str>) {
+    #[cfg(target_os = "windows")]
+    windows::set_block_reason(reason);
+
+    #[cfg(not(target_os = "windows"))]
+    let _ = reason;
+}
+
 #[no_mangle]
-#[cfg(target_os = "macos")]
+#[cfg(any(target_os = "macos", target_os = "windows"))]
 pub extern "C" fn rust_should_quit() -> bool {
     QUIT_CALLBACK
         .lock()
@@ -36,3 +103,34 @@ pub extern "C" fn rust_should_quit() -> bool {
         .as_ref()
         .map_or(true, |callback| callback())
 }
+
+/// Registers `callback` for system sleep, wake and screen lock changes. Callbacks run on the
+/// thread that observed the event and should hand off anything slow, because the system
+/// doesn't wait long before going to sleep.
+pub fn on_power_event<F>(callback: F)
+where
+    F: Fn(PowerEvent) + Send + Sync + 'static,
+{
+    POWER_CALLBACKS.lock().unwrap().push(Box::new(callback));
+
+    #[cfg(target_os = "macos")]
+    unsafe {
+        _setup_power_observer();
+    }
+
+    #[cfg(target_os = "windows")]
+    windows::ensure_window();
+}
+
+pub(crate) fn dispatch_power_event(event: PowerEvent) {
+    for callback in POWER_CALLBACKS.lock().unwrap().iter() {
+        callback(event);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn rust_on_power_event(code: i32) {
+    if let Some(event) = PowerEvent::from_code(code) {
+        dispatch_power_event(event);
+    }
+}
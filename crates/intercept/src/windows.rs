@@ -0,0 +1,139 @@
+use std::sync::{
+    atomic::{AtomicPtr, Ordering},
+    Mutex, Once,
+};
+
+use windows_sys::Win32::{
+    Foundation::{HWND, LPARAM, LRESULT, WPARAM},
+    System::{
+        LibraryLoader::GetModuleHandleW,
+        RemoteDesktop::{
+            WTSRegisterSessionNotification, NOTIFY_FOR_THIS_SESSION, WTS_SESSION_LOCK,
+            WTS_SESSION_UNLOCK,
+        },
+        Shutdown::{ShutdownBlockReasonCreate, ShutdownBlockReasonDestroy},
+    },
+    UI::WindowsAndMessaging::{
+        CreateWindowExW, DefWindowProcW, DispatchMessageW, GetMessageW, PostMessageW,
+        RegisterClassW, TranslateMessage, MSG, PBT_APMRESUMEAUTOMATIC, PBT_APMSUSPEND, WM_APP,
+        WM_POWERBROADCAST, WM_QUERYENDSESSION, WM_WTSSESSION_CHANGE, WNDCLASSW, WS_OVERLAPPED,
+    },
+};
+
+use crate::PowerEvent;
+
+static INIT: Once = Once::new();
+static HWND_PTR: AtomicPtr<std::ffi::c_void> = AtomicPtr::new(std::ptr::null_mut());
+static BLOCK_REASON: Mutex<Option<Vec<u16>>> = Mutex::new(None);
+
+// Posted to the window when the block reason changes, since only its own thread may set it.
+const WM_APP_BLOCK_REASON: u32 = WM_APP + 1;
+
+/// Windows asks every top-level window whether the session may end and broadcasts power
+/// changes to them, so a hidden one is kept on its own thread to answer for the app. It lives
+/// for the rest of the process; resetting a handler only clears the callback.
+pub(crate) fn ensure_window() {
+    INIT.call_once(|| {
+        std::thread::spawn(|| unsafe { run() });
+    });
+}
+
+unsafe fn run() {
+    let class_name: Vec<u16> = "hypr-intercept\0".encode_utf16().collect();
+    let instance = GetModuleHandleW(std::ptr::null());
+
+    let class = WNDCLASSW {
+        lpfnWndProc: Some(window_proc),
+        hInstance: instance,
+        lpszClassName: class_name.as_ptr(),
+        ..std::mem::zeroed()
+    };
+    if RegisterClassW(&class) == 0 {
+        return;
+    }
+
+    let hwnd = CreateWindowExW(
+        0,
+        class_name.as_ptr(),
+        std::ptr::null(),
+        WS_OVERLAPPED,
+        0,
+        0,
+        0,
+        0,
+        std::ptr::null_mut(),
+        std::ptr::null_mut(),
+        instance,
+        std::ptr::null(),
+    );
+    if hwnd.is_null() {
+        return;
+    }
+    HWND_PTR.store(hwnd, Ordering::Release);
+    apply_block_reason(hwnd);
+
+    // Lock and unlock are only delivered to windows that ask for them.
+    WTSRegisterSessionNotification(hwnd, NOTIFY_FOR_THIS_SESSION);
+
+    let mut msg: MSG = std::mem::zeroed();
+    while GetMessageW(&mut msg, std::ptr::null_mut(), 0, 0) > 0 {
+        TranslateMessage(&msg);
+        DispatchMessageW(&msg);
+    }
+}
+
+pub(crate) fn set_block_reason(reason: Option<&str>) {
+    *BLOCK_REASON.lock().unwrap() =
+        reason.map(|reason| reason.encode_utf16().chain(std::iter::once(0)).collect());
+
+    // Before the window exists, `run` applies the reason once it does.
+    let hwnd = HWND_PTR.load(Ordering::Acquire);
+    if !hwnd.is_null() {
+        unsafe { PostMessageW(hwnd, WM_APP_BLOCK_REASON, 0, 0) };
+    }
+}
+
+unsafe fn apply_block_reason(hwnd: HWND) {
+    match BLOCK_REASON.lock().unwrap().as_ref() {
+        Some(reason) => ShutdownBlockReasonCreate(hwnd, reason.as_ptr()),
+        None => ShutdownBlockReasonDestroy(hwnd),
+    };
+}
+
+unsafe extern "system" fn window_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    match msg {
+        // Returning 0 asks Windows to hold the logoff or shutdown. The handler sets a block
+        // reason whenever it does, so that is applied before answering.
+        WM_QUERYENDSESSION => {
+            let should_quit = crate::rust_should_quit();
+            apply_block_reason(hwnd);
+            should_quit as LRESULT
+        }
+        WM_APP_BLOCK_REASON => {
+            apply_block_reason(hwnd);
+            0
+        }
+        WM_POWERBROADCAST => {
+            match wparam as u32 {
+                PBT_APMSUSPEND => crate::dispatch_power_event(PowerEvent::Sleep),
+                PBT_APMRESUMEAUTOMATIC => crate::dispatch_power_event(PowerEvent::Wake),
+                _ => {}
+            }
+            1
+        }
+        WM_WTSSESSION_CHANGE => {
+            match wparam as u32 {
+                WTS_SESSION_LOCK => crate::dispatch_power_event(PowerEvent::ScreenLocked),
+                WTS_SESSION_UNLOCK => crate::dispatch_power_event(PowerEvent::ScreenUnlocked),
+                _ => {}
+            }
+            0
+        }
+        _ => DefWindowProcW(hwnd, msg, wparam, lparam),
+    }
+}
@@ -40,3 +40,33 @@ public func _setupQuitHandler() {
   interceptor = QuitInterceptor(originalDelegate: originalDelegate)
   app.delegate = interceptor
 }
+
+private var powerObservers: [NSObjectProtocol] = []
+
+@_silgen_name("rust_on_power_event")
+func rustOnPowerEvent(_ event: Int32)
+
+@_cdecl("_setup_power_observer")
+public func _setupPowerObserver() {
+  if !powerObservers.isEmpty {
+    return
+  }
+
+  let workspace = NSWorkspace.shared.notificationCenter
+  let distributed = DistributedNotificationCenter.default()
+
+  powerObservers = [
+    workspace.addObserver(
+      forName: NSWorkspace.willSleepNotification, object: nil, queue: nil
+    ) { _ in rustOnPowerEvent(0) },
+    workspace.addObserver(
+      forName: NSWorkspace.didWakeNotification, object: nil, queue: nil
+    ) { _ in rustOnPowerEvent(1) },
+    distributed.addObserver(
+      forName: NSNotification.Name("com.apple.screenIsLocked"), object: nil, queue: nil
+    ) { _ in rustOnPowerEvent(2) },
+    distributed.addObserver(
+      forName: NSNotification.Name("com.apple.screenIsUnlocked"), object: nil, queue: nil
+    ) { _ in rustOnPowerEvent(3) },
+  ]
+}
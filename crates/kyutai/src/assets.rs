@@ -1,3 +1,49 @@
+/// Where the candle build of `kyutai/stt-1b-en_fr` is mirrored, under the names in `filename`.
+pub const BASE_URL: &str = "https://storage2.hyprnote.com/v0/kyutai/stt-1b-en_fr-candle";
+
+#[derive(
+    Debug,
+    Clone,
+    serde::Serialize,
+    serde::Deserialize,
+    specta::Type,
+    strum::Display,
+    Eq,
+    Hash,
+    PartialEq,
+)]
+pub enum KyutaiModel {
+    #[serde(rename = "kyutai-stt-1b-en-fr")]
+    #[strum(serialize = "kyutai-stt-1b-en-fr")]
+    Stt1bEnFr,
+}
+
+impl KyutaiModel {
+    pub fn model_dir(&self) -> &str {
+        match self {
+            KyutaiModel::Stt1bEnFr => "kyutai-stt-1b-en_fr",
+        }
+    }
+
+    pub fn display_name(&self) -> &str {
+        match self {
+            KyutaiModel::Stt1bEnFr => "Kyutai STT 1B (English, French)",
+        }
+    }
+
+    pub fn model_size_bytes(&self) -> u64 {
+        Assets::ALL.iter().map(Assets::size).sum()
+    }
+
+    /// Every asset is only moved into place after its checksum matched, so presence is enough.
+    pub fn is_downloaded(&self, base_dir: impl AsRef<std::path::Path>) -> bool {
+        let model_dir = base_dir.as_ref().join(self.model_dir());
+        Assets::ALL
+            .iter()
+            .all(|asset| model_dir.join(asset.filename()).exists())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Assets {
     Config,
@@ -7,6 +53,13 @@ pub enum Assets {
 }
 
 impl Assets {
+    pub const ALL: [Assets; 4] = [
+        Assets::Config,
+        Assets::Model,
+        Assets::Tokenizer,
+        Assets::Mimi,
+    ];
+
     pub fn filename(&self) -> &'static str {
         match self {
             Assets::Config => "config.json",
@@ -19,4 +72,28 @@ impl Assets {
     pub fn url(&self, base_url: &str) -> String {
         format!("{}/{}", base_url, self.filename())
     }
+
+    pub fn download_url(&self) -> String {
+        self.url(BASE_URL)
+    }
+
+    pub fn size(&self) -> u64 {
+        match self {
+            Assets::Config => 1_847,
+            Assets::Model => 2_247_327_232,
+            Assets::Tokenizer => 252_538,
+            Assets::Mimi => 384_644_900,
+        }
+    }
+
+    /// CRC32 of the mirrored file, pinned here like the other models' so a replaced file on the
+    /// mirror is rejected instead of trusted.
+    pub fn checksum(&self) -> u32 {
+        match self {
+            Assets::Config => 2_804_181_375,
+            Assets::Model => 1_367_309_448,
+            Assets::Tokenizer => 3_129_502_711,
+            Assets::Mimi => 868_112_543,
+        }
+    }
 }
@@ -1,7 +1,13 @@
 pub mod assets;
+#[cfg(feature = "model")]
 pub mod config;
+#[cfg(feature = "model")]
 pub mod model;
 
-pub use assets::Assets;
+pub use assets::{Assets, KyutaiModel, BASE_URL};
+#[cfg(feature = "model")]
+pub use candle::Device;
+#[cfg(feature = "model")]
 pub use config::{Config, SttConfig};
-pub use model::Model;
+#[cfg(feature = "model")]
+pub use model::{device, Model, Word, SAMPLE_RATE};
@@ -4,6 +4,18 @@ use std::path::Path;
 
 use crate::config::Config;
 
+pub const SAMPLE_RATE: u32 = 24000;
+// Mimi consumes audio in 80ms frames.
+const FRAME_SIZE: usize = 1920;
+
+/// A decoded word, with times in seconds from the start of the audio passed to `step`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Word {
+    pub text: String,
+    pub start: f64,
+    pub end: f64,
+}
+
 pub struct Model {
     state: moshi::asr::State,
     text_tokenizer: sentencepiece::SentencePieceProcessor,
@@ -11,6 +23,21 @@ pub struct Model {
     vad: bool,
     config: Config,
     dev: Device,
+    buffer: Vec<f32>,
+    started: bool,
+    // The last word seen; it is only complete once its end time is known.
+    pending: Option<(String, f64)>,
+}
+
+/// Picks the accelerator the crate was built for, falling back to the CPU.
+pub fn device() -> Result<Device> {
+    if cfg!(feature = "metal") {
+        Ok(Device::new_metal(0)?)
+    } else if cfg!(feature = "cuda") {
+        Ok(Device::new_cuda(0)?)
+    } else {
+        Ok(Device::Cpu)
+    }
 }
 
 impl Model {
@@ -43,9 +70,89 @@ impl Model {
             timestamps,
             vad,
             dev: dev.clone(),
+            buffer: Vec::new(),
+            started: false,
+            pending: None,
         })
     }
 
+    /// Feeds mono audio at `SAMPLE_RATE` and returns the words completed so far. Samples that
+    /// don't fill a whole frame are kept for the next call.
+    pub fn step(&mut self, pcm: &[f32]) -> Result<Vec<Word>> {
+        if !self.started {
+            self.started = true;
+            let silence_len =
+                (self.config.stt_config.audio_silence_prefix_seconds * SAMPLE_RATE as f64) as usize;
+            self.buffer.resize(silence_len, 0.0);
+        }
+
+        self.buffer.extend_from_slice(pcm);
+        let len = self.buffer.len() / FRAME_SIZE * FRAME_SIZE;
+        let frames: Vec<f32> = self.buffer.drain(..len).collect();
+
+        self.decode(&frames)
+    }
+
+    /// Pushes enough silence through the model to flush its delay, and returns the rest of
+    /// the words. Call once, after the last `step`.
+    pub fn flush(&mut self) -> Result<Vec<Word>> {
+        let suffix =
+            ((self.config.stt_config.audio_delay_seconds + 1.0) * SAMPLE_RATE as f64) as usize;
+        let mut pcm = std::mem::take(&mut self.buffer);
+        pcm.resize((pcm.len() + suffix).div_ceil(FRAME_SIZE) * FRAME_SIZE, 0.0);
+
+        let mut words = self.decode(&pcm)?;
+        if let Some((text, start)) = self.pending.take() {
+            words.push(self.word(text, start, start));
+        }
+
+        Ok(words)
+    }
+
+    fn decode(&mut self, pcm: &[f32]) -> Result<Vec<Word>> {
+        let mut words = Vec::new();
+
+        for frame in pcm.chunks(FRAME_SIZE) {
+            let frame = Tensor::new(frame, &self.dev)?.reshape((1, 1, ()))?;
+            let asr_msgs = self.state.step_pcm(frame, None, &().into(), |_, _, _| ())?;
+
+            for asr_msg in asr_msgs.iter() {
+                match asr_msg {
+                    moshi::asr::AsrMsg::Step { .. } => {}
+                    moshi::asr::AsrMsg::EndWord { stop_time, .. } => {
+                        if let Some((text, start)) = self.pending.take() {
+                            words.push(self.word(text, start, *stop_time));
+                        }
+                    }
+                    moshi::asr::AsrMsg::Word {
+                        tokens, start_time, ..
+                    } => {
+                        let text = self
+                            .text_tokenizer
+                            .decode_piece_ids(tokens)
+                            .unwrap_or_else(|_| String::new());
+                        if let Some((prev, prev_start)) = self.pending.replace((text, *start_time))
+                        {
+                            words.push(self.word(prev, prev_start, *start_time));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(words)
+    }
+
+    // Model times include the silence `step` prepends; report them against the caller's audio.
+    fn word(&self, text: String, start: f64, end: f64) -> Word {
+        let offset = self.config.stt_config.audio_silence_prefix_seconds;
+        Word {
+            text,
+            start: (start - offset).max(0.0),
+            end: (end - offset).max(0.0),
+        }
+    }
+
     pub fn run(&mut self, mut pcm: Vec<f32>) -> Result<()> {
         use std::io::Write;
 
@@ -259,6 +259,42 @@ impl Language {
         self.iso639
     }
 
+    /// Cloud recognizers want a region for most languages; this picks the one most speakers
+    /// would expect.
+    pub fn bcp47_locale(&self) -> String {
+        let code = self.iso639.code();
+        let region = match code {
+            "en" => "US",
+            "es" => "ES",
+            "pt" => "BR",
+            "zh" => "CN",
+            "ja" => "JP",
+            "ko" => "KR",
+            "ar" => "SA",
+            "hi" => "IN",
+            "sv" => "SE",
+            "da" => "DK",
+            "uk" => "UA",
+            "cs" => "CZ",
+            "el" => "GR",
+            "he" => "IL",
+            "vi" => "VN",
+            "ms" => "MY",
+            "et" => "EE",
+            "sl" => "SI",
+            "ta" => "IN",
+            "ca" | "gl" => "ES",
+            "sr" => "RS",
+            "bs" => "BA",
+            "fa" => "IR",
+            "ur" => "PK",
+            "no" => return "nb-NO".to_string(),
+            "tl" => return "fil-PH".to_string(),
+            _ => return format!("{}-{}", code, code.to_uppercase()),
+        };
+        format!("{}-{}", code, region)
+    }
+
     #[cfg(feature = "deepgram")]
     pub fn for_deepgram(self) -> Result<deepgram::common::options::Language, Error> {
         use deepgram::common::options::Language as DG;
@@ -322,3 +358,16 @@ impl<'de> serde::Deserialize<'de> for Language {
         Ok(iso639.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bcp47_locale() {
+        let locale = |iso639| Language::from(iso639).bcp47_locale();
+        assert_eq!(locale(ISO639::En), "en-US");
+        assert_eq!(locale(ISO639::De), "de-DE");
+        assert_eq!(locale(ISO639::No), "nb-NO");
+    }
+}
@@ -19,6 +19,10 @@ pub enum Error {
     #[error(transparent)]
     DecodeError(#[from] llama_cpp_2::DecodeError),
     #[error(transparent)]
+    EmbeddingsError(#[from] llama_cpp_2::EmbeddingsError),
+    #[error("embedding request dropped before completing")]
+    EmbeddingCancelled,
+    #[error(transparent)]
     TaskSendError(#[from] tokio::sync::mpsc::error::SendError<crate::Task>),
 }
 
@@ -2,7 +2,7 @@ use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
 
 use llama_cpp_2::{
-    context::params::LlamaContextParams,
+    context::params::{LlamaContextParams, LlamaPoolingType},
     llama_backend::LlamaBackend,
     llama_batch::LlamaBatch,
     model::{params::LlamaModelParams, AddBos, LlamaModel, Special},
@@ -24,6 +24,7 @@ pub use types::*;
 
 const DEFAULT_MAX_INPUT_TOKENS: u32 = 1024 * 16;
 const DEFAULT_MAX_OUTPUT_TOKENS: u32 = 1024 * 2;
+const DEFAULT_MAX_EMBEDDING_TOKENS: u32 = 512;
 
 static LLAMA_BACKEND: OnceLock<Arc<LlamaBackend>> = OnceLock::new();
 
@@ -45,6 +46,10 @@ pub enum Task {
         callback: Box<dyn FnMut(f64) + Send + 'static>,
         cancellation_token: CancellationToken,
     },
+    Embed {
+        texts: Vec<String>,
+        response_sender: tokio::sync::oneshot::Sender<Result<Vec<Vec<f32>>, crate::Error>>,
+    },
 }
 
 struct ProgressData {
@@ -302,6 +307,59 @@ impl Llama {
         }
     }
 
+    /// One mean-pooled, normalized vector per text, so similarity is a plain dot product.
+    fn process_embeddings(
+        model: &LlamaModel,
+        backend: &LlamaBackend,
+        texts: &[String],
+    ) -> Result<Vec<Vec<f32>>, crate::Error> {
+        let tokens = texts
+            .iter()
+            .map(|text| {
+                let mut tokens = model.str_to_token(text, AddBos::Always)?;
+                tokens.truncate(DEFAULT_MAX_EMBEDDING_TOKENS as usize);
+                Ok(tokens)
+            })
+            .collect::<Result<Vec<_>, crate::Error>>()?;
+
+        let mut ctx = model.new_context(
+            backend,
+            LlamaContextParams::default()
+                .with_n_ctx(std::num::NonZeroU32::new(DEFAULT_MAX_EMBEDDING_TOKENS))
+                .with_n_batch(DEFAULT_MAX_EMBEDDING_TOKENS)
+                .with_n_ubatch(DEFAULT_MAX_EMBEDDING_TOKENS)
+                .with_embeddings(true)
+                .with_pooling_type(LlamaPoolingType::Mean),
+        )?;
+
+        let mut batch = LlamaBatch::new(DEFAULT_MAX_EMBEDDING_TOKENS as usize, 1);
+        let mut embeddings = Vec::with_capacity(tokens.len());
+
+        for tokens in tokens {
+            if tokens.is_empty() {
+                embeddings.push(vec![0.0; model.n_embd() as usize]);
+                continue;
+            }
+
+            batch.clear();
+            batch.add_sequence(&tokens, 0, false)?;
+
+            ctx.clear_kv_cache();
+            ctx.decode(&mut batch)?;
+
+            let embedding = ctx.embeddings_seq_ith(0)?;
+            let norm = embedding
+                .iter()
+                .map(|v| v * v)
+                .sum::<f32>()
+                .sqrt()
+                .max(f32::EPSILON);
+            embeddings.push(embedding.iter().map(|v| v / norm).collect());
+        }
+
+        Ok(embeddings)
+    }
+
     fn setup_log() {
         send_logs_to_tracing(LogOptions::default().with_logs_enabled(false));
     }
@@ -357,6 +415,13 @@ impl Llama {
                                 }
                             }
                         }
+                        Task::Embed {
+                            texts,
+                            response_sender,
+                        } => {
+                            let _ = response_sender
+                                .send(Self::process_embeddings(&model, &backend, &texts));
+                        }
                     }
                 }
             }
@@ -402,6 +467,20 @@ impl Llama {
 
         Ok((stream, cancellation_token))
     }
+
+    /// Runs on the same thread as generation, so it waits for any request in progress.
+    pub async fn embed(&self, texts: Vec<String>) -> Result<Vec<Vec<f32>>, crate::Error> {
+        let (response_sender, response_receiver) = tokio::sync::oneshot::channel();
+
+        self.task_sender.send(Task::Embed {
+            texts,
+            response_sender,
+        })?;
+
+        response_receiver
+            .await
+            .map_err(|_| crate::Error::EmbeddingCancelled)?
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,91 @@
+//! Shared by the loopback servers other apps on this computer talk to (MCP and the inbound API).
+
+/// Binding to loopback keeps other machines out, but not web pages that rebind a hostname they
+/// control to 127.0.0.1. Requests whose `Host` is anything else are from such a page.
+pub fn is_loopback_host(host: &str) -> bool {
+    let name = match host.strip_prefix('[') {
+        Some(rest) => rest.split(']').next().unwrap_or_default(),
+        None => host.split(':').next().unwrap_or_default(),
+    };
+    matches!(name, "localhost" | "127.0.0.1" | "::1")
+}
+
+/// Constant-time comparison. An empty `expected` never matches, so an unset key can't be
+/// satisfied by leaving the header out.
+pub fn keys_match(provided: &str, expected: &str) -> bool {
+    !expected.is_empty()
+        && provided.len() == expected.len()
+        && provided
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
+
+/// A new, unrecorded note whose memo is `content` as plain text, one paragraph per line.
+pub fn new_note(user_id: String, title: String, content: &str) -> hypr_db_user::Session {
+    let now = chrono::Utc::now();
+    hypr_db_user::Session {
+        id: uuid::Uuid::new_v4().to_string(),
+        created_at: now,
+        visited_at: now,
+        user_id,
+        calendar_event_id: None,
+        title,
+        raw_memo_html: text_to_html(content),
+        enhanced_memo_html: None,
+        conversations: vec![],
+        words: vec![],
+        record_start: None,
+        record_end: None,
+        pre_meeting_memo_html: None,
+    }
+}
+
+/// Notes are stored as HTML, so escape the text and keep its line breaks as paragraphs.
+pub fn text_to_html(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let escaped = line
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            format!("<p>{}</p>", escaped)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_loopback_host() {
+        assert!(is_loopback_host("127.0.0.1:23542"));
+        assert!(is_loopback_host("localhost:23542"));
+        assert!(is_loopback_host("[::1]:23542"));
+        assert!(is_loopback_host("localhost"));
+        assert!(!is_loopback_host("attacker.example:23542"));
+        assert!(!is_loopback_host("localhost.attacker.example"));
+        assert!(!is_loopback_host(""));
+    }
+
+    #[test]
+    fn test_keys_match() {
+        assert!(keys_match("hypr_abc", "hypr_abc"));
+        assert!(!keys_match("hypr_abd", "hypr_abc"));
+        assert!(!keys_match("", "hypr_abc"));
+        assert!(!keys_match("", ""));
+    }
+
+    #[test]
+    fn test_text_to_html() {
+        assert_eq!(
+            text_to_html("Agenda\n\n  a < b & c  \n"),
+            "<p>Agenda</p><p>a &lt; b &amp; c</p>"
+        );
+        assert_eq!(text_to_html(""), "");
+    }
+}
@@ -4,6 +4,9 @@ pub struct Notification {
     pub title: String,
     pub message: String,
     pub url: Option<String>,
+    /// Label of the button that opens `url`.
+    #[serde(default)]
+    pub action_label: Option<String>,
     pub timeout: Option<std::time::Duration>,
 }
 
@@ -19,6 +22,7 @@ pub struct NotificationBuilder {
     title: Option<String>,
     message: Option<String>,
     url: Option<String>,
+    action_label: Option<String>,
     timeout: Option<std::time::Duration>,
 }
 
@@ -43,6 +47,11 @@ impl NotificationBuilder {
         self
     }
 
+    pub fn action_label(mut self, action_label: impl Into<String>) -> Self {
+        self.action_label = Some(action_label.into());
+        self
+    }
+
     pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
         self.timeout = Some(timeout);
         self
@@ -53,6 +62,7 @@ impl NotificationBuilder {
         let title = self.title.unwrap();
         let message = self.message.unwrap();
         let url = self.url.clone();
+        let action_label = self.action_label.clone();
         let timeout = self.timeout.clone();
 
         Notification {
@@ -60,6 +70,7 @@ impl NotificationBuilder {
             title,
             message,
             url,
+            action_label,
             timeout,
         }
     }
@@ -8,6 +8,7 @@ swift!(fn _show_notification(
     title: &SRString,
     message: &SRString,
     url: &SRString,
+    action_label: &SRString,
     timeout_seconds: f64
 ) -> Bool);
 
@@ -21,9 +22,10 @@ pub fn show(notification: &hypr_notification_interface::Notification) {
             .as_ref()
             .map(|u| SRString::from(u.as_str()))
             .unwrap_or_else(|| SRString::from(""));
+        let action_label = SRString::from(notification.action_label.as_deref().unwrap_or(""));
         let timeout_seconds = notification.timeout.map(|d| d.as_secs_f64()).unwrap_or(5.0);
 
-        _show_notification(&title, &message, &url, timeout_seconds);
+        _show_notification(&title, &message, &url, &action_label, timeout_seconds);
     }
 }
 
@@ -286,7 +286,9 @@ class NotificationManager {
   }
 
   // MARK: - Public Methods
-  func show(title: String, message: String, url: String?, timeoutSeconds: Double) {
+  func show(
+    title: String, message: String, url: String?, actionLabel: String?, timeoutSeconds: Double
+  ) {
     DispatchQueue.main.async { [weak self] in
       guard let self else { return }
       self.setupApplicationIfNeeded()
@@ -294,6 +296,7 @@ class NotificationManager {
         title: title,
         message: message,
         url: url,
+        actionLabel: actionLabel,
         timeoutSeconds: timeoutSeconds
       )
     }
@@ -336,7 +339,7 @@ class NotificationManager {
   }
 
   private func createAndShowNotification(
-    title: String, message: String, url: String?, timeoutSeconds: Double
+    title: String, message: String, url: String?, actionLabel: String?, timeoutSeconds: Double
   ) {
     guard let screen = NSScreen.main else { return }
 
@@ -352,7 +355,8 @@ class NotificationManager {
     clickableView.notification = notification
 
     setupContent(
-      effectView: effectView, title: title, message: message, url: url, notification: notification)
+      effectView: effectView, title: title, message: message, url: url, actionLabel: actionLabel,
+      notification: notification)
 
     clickableView.addSubview(container)
     panel.contentView = clickableView
@@ -466,6 +470,7 @@ class NotificationManager {
     title: String,
     message: String,
     url: String?,
+    actionLabel: String?,
     notification: NotificationInstance
   ) {
     let hasUrl = (url != nil && !url!.isEmpty)
@@ -473,7 +478,7 @@ class NotificationManager {
     let contentView = createNotificationView(
       title: title,
       body: message,
-      buttonTitle: hasUrl ? "Take Notes" : nil,
+      buttonTitle: hasUrl ? (actionLabel ?? "Take Notes") : nil,
       notification: notification
     )
     contentView.translatesAutoresizingMaskIntoConstraints = false
@@ -725,17 +730,21 @@ public func _showNotification(
   title: SRString,
   message: SRString,
   url: SRString,
+  actionLabel: SRString,
   timeoutSeconds: Double
 ) -> Bool {
   let titleStr = title.toString()
   let messageStr = message.toString()
   let urlStr = url.toString()
   let finalUrl = urlStr.isEmpty ? nil : urlStr
+  let actionLabelStr = actionLabel.toString()
+  let finalActionLabel = actionLabelStr.isEmpty ? nil : actionLabelStr
 
   NotificationManager.shared.show(
     title: titleStr,
     message: messageStr,
     url: finalUrl,
+    actionLabel: finalActionLabel,
     timeoutSeconds: timeoutSeconds
   )
 
@@ -0,0 +1,82 @@
+use crate::{embedding::EmbeddingExtractor, segmentation::Segment};
+
+// Cosine similarity above which two segments are taken to share a speaker.
+const SAME_SPEAKER_THRESHOLD: f32 = 0.5;
+
+/// Labels each speech segment with a speaker index, see [`assign_speakers`].
+pub fn label_segments(segments: &[Segment]) -> Result<Vec<usize>, crate::Error> {
+    let mut extractor = EmbeddingExtractor::new();
+
+    let embeddings = segments
+        .iter()
+        .map(|segment| extractor.compute(segment.samples.iter().copied()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(assign_speakers(&embeddings, SAME_SPEAKER_THRESHOLD))
+}
+
+/// Groups speaker embeddings greedily: each one joins the closest existing speaker if their
+/// cosine similarity reaches `threshold`, otherwise it starts a new one. Returns a speaker
+/// index per embedding, numbered in order of first appearance.
+pub fn assign_speakers(embeddings: &[Vec<f32>], threshold: f32) -> Vec<usize> {
+    let mut centroids: Vec<(Vec<f32>, usize)> = Vec::new();
+    let mut assignments = Vec::with_capacity(embeddings.len());
+
+    for embedding in embeddings {
+        let closest = centroids
+            .iter()
+            .enumerate()
+            .map(|(i, (centroid, _))| (i, cosine_similarity(centroid, embedding)))
+            .filter(|(_, similarity)| *similarity >= threshold)
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        match closest {
+            Some((i, _)) => {
+                let (centroid, count) = &mut centroids[i];
+                *count += 1;
+                for (c, e) in centroid.iter_mut().zip(embedding) {
+                    *c += (e - *c) / *count as f32;
+                }
+                assignments.push(i);
+            }
+            None => {
+                centroids.push((embedding.clone(), 1));
+                assignments.push(centroids.len() - 1);
+            }
+        }
+    }
+
+    assignments
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot = a.iter().zip(b).map(|(x, y)| x * y).sum::<f32>();
+    let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+    let denom = norm(a) * norm(b);
+    if denom == 0.0 {
+        0.0
+    } else {
+        dot / denom
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assign_speakers() {
+        let embeddings = vec![
+            vec![1.0, 0.0],
+            vec![0.0, 1.0],
+            vec![0.9, 0.1],
+            vec![0.1, 0.9],
+            vec![-1.0, 0.0],
+        ];
+
+        assert_eq!(assign_speakers(&embeddings, 0.8), vec![0, 1, 0, 1, 2]);
+        assert_eq!(assign_speakers(&embeddings, 1.1), vec![0, 1, 2, 3, 4]);
+        assert!(assign_speakers(&[], 0.8).is_empty());
+    }
+}
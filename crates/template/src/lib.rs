@@ -42,6 +42,27 @@ pub enum Template {
     #[strum(serialize = "auto_generate_tags.user")]
     #[serde(rename = "auto_generate_tags.user")]
     AutoGenerateTagsUser,
+    #[strum(serialize = "obsidian.note")]
+    #[serde(rename = "obsidian.note")]
+    ObsidianNote,
+    #[strum(serialize = "notification_event.title")]
+    #[serde(rename = "notification_event.title")]
+    NotificationEventTitle,
+    #[strum(serialize = "notification_event.message")]
+    #[serde(rename = "notification_event.message")]
+    NotificationEventMessage,
+    #[strum(serialize = "pre_meeting_brief.system")]
+    #[serde(rename = "pre_meeting_brief.system")]
+    PreMeetingBriefSystem,
+    #[strum(serialize = "pre_meeting_brief.user")]
+    #[serde(rename = "pre_meeting_brief.user")]
+    PreMeetingBriefUser,
+    #[strum(serialize = "action_items.system")]
+    #[serde(rename = "action_items.system")]
+    ActionItemsSystem,
+    #[strum(serialize = "action_items.user")]
+    #[serde(rename = "action_items.user")]
+    ActionItemsUser,
 }
 
 pub const ENHANCE_SYSTEM_TPL: &str = include_str!("../assets/enhance.system.jinja");
@@ -56,6 +77,16 @@ pub const AUTO_GENERATE_TAGS_USER_TPL: &str =
     include_str!("../assets/auto_generate_tags.user.jinja");
 pub const CHAT_SYSTEM_TPL: &str = include_str!("../assets/chat.system.jinja");
 pub const CHAT_USER_TPL: &str = include_str!("../assets/chat.user.jinja");
+pub const OBSIDIAN_NOTE_TPL: &str = include_str!("../assets/obsidian.note.jinja");
+pub const NOTIFICATION_EVENT_TITLE_TPL: &str =
+    include_str!("../assets/notification_event.title.jinja");
+pub const NOTIFICATION_EVENT_MESSAGE_TPL: &str =
+    include_str!("../assets/notification_event.message.jinja");
+pub const PRE_MEETING_BRIEF_SYSTEM_TPL: &str =
+    include_str!("../assets/pre_meeting_brief.system.jinja");
+pub const PRE_MEETING_BRIEF_USER_TPL: &str = include_str!("../assets/pre_meeting_brief.user.jinja");
+pub const ACTION_ITEMS_SYSTEM_TPL: &str = include_str!("../assets/action_items.system.jinja");
+pub const ACTION_ITEMS_USER_TPL: &str = include_str!("../assets/action_items.user.jinja");
 
 pub fn init(env: &mut minijinja::Environment) {
     env.set_unknown_method_callback(minijinja_contrib::pycompat::unknown_method_callback);
@@ -93,6 +124,35 @@ pub fn init(env: &mut minijinja::Environment) {
             AUTO_GENERATE_TAGS_USER_TPL,
         )
         .unwrap();
+        env.add_template(Template::ObsidianNote.as_ref(), OBSIDIAN_NOTE_TPL)
+            .unwrap();
+        env.add_template(
+            Template::NotificationEventTitle.as_ref(),
+            NOTIFICATION_EVENT_TITLE_TPL,
+        )
+        .unwrap();
+        env.add_template(
+            Template::NotificationEventMessage.as_ref(),
+            NOTIFICATION_EVENT_MESSAGE_TPL,
+        )
+        .unwrap();
+        env.add_template(
+            Template::PreMeetingBriefSystem.as_ref(),
+            PRE_MEETING_BRIEF_SYSTEM_TPL,
+        )
+        .unwrap();
+        env.add_template(
+            Template::PreMeetingBriefUser.as_ref(),
+            PRE_MEETING_BRIEF_USER_TPL,
+        )
+        .unwrap();
+        env.add_template(
+            Template::ActionItemsSystem.as_ref(),
+            ACTION_ITEMS_SYSTEM_TPL,
+        )
+        .unwrap();
+        env.add_template(Template::ActionItemsUser.as_ref(), ACTION_ITEMS_USER_TPL)
+            .unwrap();
     }
 
     {
@@ -123,3 +183,11 @@ pub fn render(
         s
     })
 }
+
+pub fn render_str(
+    env: &minijinja::Environment<'static>,
+    source: &str,
+    ctx: &serde_json::Map<String, serde_json::Value>,
+) -> Result<String, crate::Error> {
+    env.render_str(source, ctx).map_err(Into::into)
+}
@@ -22,14 +22,19 @@ use std::{
 };
 use tower::Service;
 
+use hypr_audio_utils::{interleave_i16_le, mix_i16_le};
+
 use aws_config::{meta::region::RegionProviderChain, BehaviorVersion};
 use aws_sdk_transcribestreaming::primitives::Blob;
 use aws_sdk_transcribestreaming::types::{
-    AudioEvent, AudioStream, LanguageCode, MediaEncoding, TranscriptResultStream,
+    AudioEvent, AudioStream, ItemType, LanguageCode, MediaEncoding, Result as TranscriptResult,
+    TranscriptResultStream,
 };
 use aws_sdk_transcribestreaming::{config::Region, Client};
 
-use owhisper_interface::{ListenInputChunk, ListenOutputChunk, ListenParams, Word2};
+use owhisper_interface::{
+    ListenInputChunk, ListenOutputChunk, ListenParams, SpeakerIdentity, Word2,
+};
 
 mod error;
 pub use error::*;
@@ -68,7 +73,9 @@ impl TranscribeService {
     async fn handle_socket(self, socket: WebSocket, params: Option<ListenParams>) {
         let (sender, mut receiver) = socket.split();
 
-        let _params = params.unwrap_or_default();
+        let params = params.unwrap_or_default();
+        // Each channel is transcribed on its own, so words carry the channel as their speaker.
+        let dual = params.channels >= 2;
 
         let (audio_tx, audio_rx) = mpsc::channel::<Bytes>(100);
 
@@ -80,6 +87,11 @@ impl TranscribeService {
                         if let Ok(chunk) = serde_json::from_str::<ListenInputChunk>(&data) {
                             match chunk {
                                 ListenInputChunk::Audio { data } => {
+                                    let data = if dual {
+                                        interleave_i16_le(&data, &[])
+                                    } else {
+                                        data
+                                    };
                                     if !data.is_empty() {
                                         if audio_tx.send(Bytes::from(data)).await.is_err() {
                                             break;
@@ -87,10 +99,13 @@ impl TranscribeService {
                                     }
                                 }
                                 ListenInputChunk::DualAudio { mic, speaker } => {
-                                    // For now, mix the dual audio channels
-                                    let mixed = mix_audio(mic, speaker);
-                                    if !mixed.is_empty() {
-                                        if audio_tx.send(Bytes::from(mixed)).await.is_err() {
+                                    let audio = if dual {
+                                        interleave_i16_le(&mic, &speaker)
+                                    } else {
+                                        mix_i16_le(&mic, &speaker)
+                                    };
+                                    if !audio.is_empty() {
+                                        if audio_tx.send(Bytes::from(audio)).await.is_err() {
                                             break;
                                         }
                                     }
@@ -106,7 +121,7 @@ impl TranscribeService {
         });
 
         // Start transcription
-        if let Err(e) = self.start_transcription(audio_rx, sender).await {
+        if let Err(e) = self.start_transcription(audio_rx, sender, &params).await {
             error!("Transcription error: {}", e);
         }
 
@@ -117,6 +132,7 @@ impl TranscribeService {
         &self,
         mut audio_rx: mpsc::Receiver<Bytes>,
         mut sender: futures_util::stream::SplitSink<WebSocket, Message>,
+        params: &ListenParams,
     ) -> Result<(), crate::Error> {
         // Create audio stream for AWS Transcribe
         let input_stream = stream! {
@@ -130,15 +146,25 @@ impl TranscribeService {
         };
 
         // Start streaming transcription
-        let mut output = self
+        let mut request = self
             .client
             .start_stream_transcription()
             .language_code(LanguageCode::EnUs) // TODO: make configurable
             .media_sample_rate_hertz(16000)
             .media_encoding(MediaEncoding::Pcm)
-            .audio_stream(input_stream.into())
-            .send()
-            .await?;
+            .audio_stream(input_stream.into());
+
+        // AWS rejects speaker labels together with channel identification, and with separate
+        // channels the speaker is already known.
+        if params.channels >= 2 {
+            request = request
+                .enable_channel_identification(true)
+                .number_of_channels(2);
+        } else if params.diarize {
+            request = request.show_speaker_label(true);
+        }
+
+        let mut output = request.send().await?;
 
         while let Some(event) = output.transcript_result_stream.recv().await? {
             match event {
@@ -150,37 +176,13 @@ impl TranscribeService {
                                 continue;
                             }
 
-                            if let Some(alternatives) = result.alternatives {
-                                if let Some(first) = alternatives.first() {
-                                    if let Some(text) = &first.transcript {
-                                        let mut words = Vec::new();
-
-                                        // AWS doesn't provide word-level data in the same way
-                                        // So we'll split the transcript into words
-                                        for word_text in text.split_whitespace() {
-                                            words.push(Word2 {
-                                                text: word_text.to_string(),
-                                                speaker: None,
-                                                confidence: None,
-                                                start_ms: Some((result.start_time * 1000.0) as u64),
-                                                end_ms: Some((result.end_time * 1000.0) as u64),
-                                            });
-                                        }
+                            let words = result_words(&result);
+                            if !words.is_empty() {
+                                let output_chunk = ListenOutputChunk { meta: None, words };
 
-                                        if !words.is_empty() {
-                                            let output_chunk =
-                                                ListenOutputChunk { meta: None, words };
-
-                                            if let Ok(json) = serde_json::to_string(&output_chunk) {
-                                                if sender
-                                                    .send(Message::Text(json.into()))
-                                                    .await
-                                                    .is_err()
-                                                {
-                                                    break;
-                                                }
-                                            }
-                                        }
+                                if let Ok(json) = serde_json::to_string(&output_chunk) {
+                                    if sender.send(Message::Text(json.into())).await.is_err() {
+                                        break;
                                     }
                                 }
                             }
@@ -213,8 +215,16 @@ impl Service<Request<Body>> for TranscribeService {
                 let (parts, body) = req.into_parts();
                 let axum_req = axum::extract::Request::from_parts(parts, body);
 
+                let query_string = axum_req.uri().query().unwrap_or("");
+                let params: ListenParams = match serde_qs::from_str(query_string) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        return Ok((StatusCode::BAD_REQUEST, e.to_string()).into_response());
+                    }
+                };
+
                 match WebSocketUpgrade::from_request(axum_req, &()).await {
-                    Ok(ws) => Ok(service.handle_websocket(ws, None).await),
+                    Ok(ws) => Ok(service.handle_websocket(ws, Some(params)).await),
                     Err(_) => Ok(Response::builder()
                         .status(StatusCode::BAD_REQUEST)
                         .body(Body::from("Invalid WebSocket upgrade request"))
@@ -230,31 +240,76 @@ impl Service<Request<Body>> for TranscribeService {
     }
 }
 
-fn mix_audio(mic: Vec<u8>, speaker: Vec<u8>) -> Vec<u8> {
-    // Mix the two audio channels by averaging them
-    let len = mic.len().max(speaker.len());
-    let mut mixed = Vec::with_capacity(len);
-
-    for i in (0..len).step_by(2) {
-        // Process 16-bit samples (2 bytes each)
-        let mic_sample = if i + 1 < mic.len() {
-            i16::from_le_bytes([mic[i], mic[i + 1]])
-        } else {
-            0
-        };
+/// Words of a final result. The speaker is the channel when channels are identified, and
+/// AWS's speaker label otherwise.
+fn result_words(result: &TranscriptResult) -> Vec<Word2> {
+    let Some(alternative) = result.alternatives.as_ref().and_then(|a| a.first()) else {
+        return vec![];
+    };
+
+    let channel = result.channel_id.as_deref().and_then(label_index);
+    let speaker = |label: Option<&str>| {
+        channel
+            .or_else(|| label.and_then(label_index))
+            .map(|index| SpeakerIdentity::Unassigned { index })
+    };
+
+    let items = alternative.items.as_deref().unwrap_or_default();
+    if items.is_empty() {
+        // No word timings; every word spans the whole result.
+        return alternative
+            .transcript
+            .as_deref()
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(|text| Word2 {
+                text: text.to_string(),
+                speaker: speaker(None),
+                confidence: None,
+                start_ms: Some((result.start_time * 1000.0) as u64),
+                end_ms: Some((result.end_time * 1000.0) as u64),
+            })
+            .collect();
+    }
 
-        let speaker_sample = if i + 1 < speaker.len() {
-            i16::from_le_bytes([speaker[i], speaker[i + 1]])
-        } else {
-            0
+    let mut words: Vec<Word2> = Vec::with_capacity(items.len());
+    for item in items {
+        let Some(content) = item.content.as_deref() else {
+            continue;
         };
 
-        // Mix by averaging and prevent clipping
-        let mixed_sample = ((mic_sample as i32 + speaker_sample as i32) / 2) as i16;
-        let bytes = mixed_sample.to_le_bytes();
-        mixed.push(bytes[0]);
-        mixed.push(bytes[1]);
+        if item.r#type == Some(ItemType::Punctuation) {
+            if let Some(last) = words.last_mut() {
+                last.text.push_str(content);
+            }
+            continue;
+        }
+
+        words.push(Word2 {
+            text: content.to_string(),
+            speaker: speaker(item.speaker.as_deref()),
+            confidence: item.confidence.map(|c| c as f32),
+            start_ms: Some((item.start_time * 1000.0) as u64),
+            end_ms: Some((item.end_time * 1000.0) as u64),
+        });
     }
+    words
+}
 
-    mixed
+/// `ch_1`, `spk_1` or `1` as `1`.
+fn label_index(label: &str) -> Option<u8> {
+    label.rsplit('_').next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_index() {
+        assert_eq!(label_index("ch_1"), Some(1));
+        assert_eq!(label_index("spk_0"), Some(0));
+        assert_eq!(label_index("2"), Some(2));
+        assert_eq!(label_index("unknown"), None);
+    }
 }
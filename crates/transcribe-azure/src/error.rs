@@ -1,7 +1,11 @@
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
-    #[error("Invalid input: {0}")]
-    InvalidInput(String),
-    #[error("Service error: {0}")]
-    ServiceError(String),
+    #[error(transparent)]
+    WebSocketError(#[from] tokio_tungstenite::tungstenite::Error),
+    #[error(transparent)]
+    UrlError(#[from] url::ParseError),
+    #[error("invalid config: {0}")]
+    InvalidConfig(String),
+    #[error("recognition failed: {0}")]
+    RecognitionError(String),
 }
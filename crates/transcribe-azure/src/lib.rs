@@ -1,102 +1,575 @@
 use bytes::Bytes;
-use serde::{Deserialize, Serialize};
-use std::sync::Arc;
 
-use async_stream::stream;
 use tokio::sync::mpsc;
-use tracing::{error, info};
+use tracing::error;
 
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
 use axum::{
-    extract::ws::{Message, WebSocket, WebSocketUpgrade},
-    response::IntoResponse,
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        FromRequest,
+    },
+    response::{IntoResponse, Response},
 };
 use futures_util::{SinkExt, StreamExt};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tower::Service;
 
-mod error;
-pub use error::*;
+use tokio_tungstenite::tungstenite::{
+    self, client::IntoClientRequest, http::HeaderValue, Message as AzureMessage,
+};
 
-/// Configuration for the transcription service
-#[derive(Debug, Clone)]
-pub struct TranscribeConfig {}
+use owhisper_interface::{
+    ListenInputChunk, ListenOutputChunk, ListenParams, SpeakerIdentity, Word2,
+};
 
-impl Default for TranscribeConfig {
-    fn default() -> Self {
-        Self {}
-    }
-}
+mod error;
+pub use error::*;
 
-/// Message types for WebSocket communication
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
-pub enum WsMessage {
-    AudioData { data: Vec<u8> },
-    Transcript { text: String, is_partial: bool },
-    Error { message: String },
-    Complete,
-}
+const SAMPLE_RATE: u32 = 16000;
+/// 16-bit mono samples.
+const BYTES_PER_MS: u64 = SAMPLE_RATE as u64 * 2 / 1000;
+/// Azure drops a connection after ten minutes, so a new one is opened before that.
+const STREAM_LIMIT_MS: u64 = 540_000;
+/// Offsets and durations are in 100-nanosecond ticks.
+const TICKS_PER_MS: u64 = 10_000;
 
 #[derive(Clone)]
 pub struct TranscribeService {
-    config: TranscribeConfig,
+    region: String,
+    api_key: HeaderValue,
 }
 
 impl TranscribeService {
-    pub async fn new(config: TranscribeConfig) -> Result<Self, Error> {
-        Ok(Self { config })
+    pub fn new(config: owhisper_config::AzureModelConfig) -> Result<Self, crate::Error> {
+        let api_key = HeaderValue::from_str(&config.api_key)
+            .map_err(|_| crate::Error::InvalidConfig("api_key".to_string()))?;
+
+        Ok(Self {
+            region: config.region,
+            api_key,
+        })
     }
 
-    /// Handle WebSocket upgrade for streaming transcription
-    pub async fn handle_websocket(self, ws: WebSocketUpgrade) -> impl IntoResponse {
-        ws.on_upgrade(move |socket| self.handle_socket(socket))
+    pub async fn handle_websocket(
+        self,
+        ws: WebSocketUpgrade,
+        params: Option<ListenParams>,
+    ) -> Response {
+        ws.on_upgrade(move |socket| self.handle_socket(socket, params))
+            .into_response()
     }
 
-    /// Handle WebSocket connection
-    async fn handle_socket(self, socket: WebSocket) {
-        let (mut sender, mut receiver) = socket.split();
-        let (audio_tx, audio_rx) = mpsc::channel::<Bytes>(100);
-        let (result_tx, mut result_rx) = mpsc::channel::<WsMessage>(100);
-
-        // Task to handle incoming audio data from WebSocket
-        let audio_handler = tokio::spawn(async move {
-            while let Some(Ok(Message::Binary(data))) = receiver.next().await {
-                if audio_tx.send(Bytes::from(data)).await.is_err() {
-                    break;
+    async fn handle_socket(self, socket: WebSocket, params: Option<ListenParams>) {
+        let (sender, mut receiver) = socket.split();
+
+        let params = params.unwrap_or_default();
+        // Azure only takes mono audio, so each channel gets its own connection and words carry
+        // the channel as their speaker.
+        let dual = params.channels >= 2;
+
+        let (audio_txs, audio_rxs): (Vec<_>, Vec<_>) = (0..if dual { 2 } else { 1 })
+            .map(|_| mpsc::channel::<Bytes>(100))
+            .unzip();
+
+        let audio_task = tokio::spawn(async move {
+            while let Some(Ok(msg)) = receiver.next().await {
+                match msg {
+                    Message::Text(data) => {
+                        if let Ok(chunk) = serde_json::from_str::<ListenInputChunk>(&data) {
+                            let audio = match chunk {
+                                ListenInputChunk::Audio { data } if dual => {
+                                    let silence = vec![0; data.len()];
+                                    vec![data, silence]
+                                }
+                                ListenInputChunk::Audio { data } => vec![data],
+                                ListenInputChunk::DualAudio { mic, speaker } if dual => {
+                                    vec![mic, speaker]
+                                }
+                                ListenInputChunk::DualAudio { mic, speaker } => {
+                                    vec![hypr_audio_utils::mix_i16_le(&mic, &speaker)]
+                                }
+                                ListenInputChunk::End => break,
+                            };
+
+                            for (tx, audio) in audio_txs.iter().zip(audio) {
+                                if !audio.is_empty() && tx.send(Bytes::from(audio)).await.is_err() {
+                                    return;
+                                }
+                            }
+                        }
+                    }
+                    Message::Close(_) => break,
+                    _ => {}
                 }
             }
         });
 
-        // Task to send transcription results back to WebSocket
-        let result_sender = tokio::spawn(async move {
-            while let Some(msg) = result_rx.recv().await {
-                let json = match serde_json::to_string(&msg) {
-                    Ok(json) => json,
-                    Err(e) => {
-                        error!("Failed to serialize message: {}", e);
+        if let Err(e) = self.start_transcription(audio_rxs, sender, &params).await {
+            error!("Transcription error: {}", e);
+        }
+
+        audio_task.abort();
+    }
+
+    async fn start_transcription(
+        &self,
+        audio_rxs: Vec<mpsc::Receiver<Bytes>>,
+        mut sender: futures_util::stream::SplitSink<WebSocket, Message>,
+        params: &ListenParams,
+    ) -> Result<(), crate::Error> {
+        let dual = audio_rxs.len() > 1;
+        let (words_tx, mut words_rx) = mpsc::channel::<Vec<Word2>>(100);
+
+        let tasks: Vec<_> = audio_rxs
+            .into_iter()
+            .enumerate()
+            .map(|(index, audio_rx)| {
+                let service = self.clone();
+                let params = params.clone();
+                let channel = dual.then_some(index as u8);
+                let words_tx = words_tx.clone();
+
+                tokio::spawn(async move {
+                    service
+                        .recognize(audio_rx, channel, &params, words_tx)
+                        .await
+                })
+            })
+            .collect();
+        drop(words_tx);
+
+        while let Some(words) = words_rx.recv().await {
+            let output_chunk = ListenOutputChunk { meta: None, words };
+            if let Ok(json) = serde_json::to_string(&output_chunk) {
+                if sender.send(Message::Text(json.into())).await.is_err() {
+                    tasks.iter().for_each(|task| task.abort());
+                    return Ok(());
+                }
+            }
+        }
+
+        let _ = sender.close().await;
+
+        for task in tasks {
+            if let Ok(Err(e)) = task.await {
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Streams one channel, reconnecting before Azure's connection limit, until the audio ends.
+    async fn recognize(
+        &self,
+        mut audio_rx: mpsc::Receiver<Bytes>,
+        channel: Option<u8>,
+        params: &ListenParams,
+        words_tx: mpsc::Sender<Vec<Word2>>,
+    ) -> Result<(), crate::Error> {
+        let mut offset_ms = 0;
+
+        loop {
+            let (socket, _) = tokio_tungstenite::connect_async(self.request(params)?).await?;
+            let (mut write, mut read) = socket.split();
+            let request_id = uuid::Uuid::new_v4().simple().to_string();
+
+            write
+                .send(AzureMessage::Text(
+                    text_message("speech.config", &request_id, &speech_config()).into(),
+                ))
+                .await?;
+            if !params.keyterms.is_empty() {
+                write
+                    .send(AzureMessage::Text(
+                        text_message(
+                            "speech.context",
+                            &request_id,
+                            &phrase_list(&params.keyterms),
+                        )
+                        .into(),
+                    ))
+                    .await?;
+            }
+
+            let writer = async {
+                write
+                    .send(AzureMessage::Binary(
+                        audio_message(&request_id, &wav_header()).into(),
+                    ))
+                    .await?;
+
+                let mut sent_bytes = 0;
+                let mut ended = false;
+                while sent_bytes < STREAM_LIMIT_MS * BYTES_PER_MS {
+                    let Some(chunk) = audio_rx.recv().await else {
+                        ended = true;
+                        break;
+                    };
+                    sent_bytes += chunk.len() as u64;
+                    write
+                        .send(AzureMessage::Binary(
+                            audio_message(&request_id, &chunk).into(),
+                        ))
+                        .await?;
+                }
+
+                // An empty audio message ends the turn.
+                write
+                    .send(AzureMessage::Binary(audio_message(&request_id, &[]).into()))
+                    .await?;
+                Ok::<_, crate::Error>((sent_bytes, ended))
+            };
+
+            let reader = async {
+                while let Some(message) = read.next().await {
+                    let text = match message? {
+                        AzureMessage::Text(text) => text,
+                        AzureMessage::Close(_) => break,
+                        _ => continue,
+                    };
+
+                    let Some((path, body)) = parse_message(&text) else {
                         continue;
+                    };
+                    match path {
+                        "speech.phrase" => {
+                            let phrase: Phrase = match serde_json::from_str(body) {
+                                Ok(phrase) => phrase,
+                                Err(e) => {
+                                    error!("azure_phrase_invalid: {}", e);
+                                    continue;
+                                }
+                            };
+                            if phrase.recognition_status == "Error" {
+                                return Err(crate::Error::RecognitionError(body.to_string()));
+                            }
+
+                            let words = phrase_words(&phrase, offset_ms, channel);
+                            if !words.is_empty() && words_tx.send(words).await.is_err() {
+                                break;
+                            }
+                        }
+                        "turn.end" => break,
+                        _ => {}
+                    }
+                }
+                Ok(())
+            };
+
+            let (written, read) = tokio::join!(writer, reader);
+            let (sent_bytes, ended) = written?;
+            read?;
+
+            if ended || words_tx.is_closed() {
+                return Ok(());
+            }
+            offset_ms += sent_bytes / BYTES_PER_MS;
+        }
+    }
+
+    fn request(
+        &self,
+        params: &ListenParams,
+    ) -> Result<tungstenite::handshake::client::Request, crate::Error> {
+        let mut url = url::Url::parse(&format!(
+            "wss://{}.stt.speech.microsoft.com/speech/recognition/conversation/cognitiveservices/v1",
+            self.region
+        ))?;
+        url.query_pairs_mut()
+            .append_pair(
+                "language",
+                &params
+                    .languages
+                    .first()
+                    .map(hypr_language::Language::bcp47_locale)
+                    .unwrap_or_else(|| "en-US".to_string()),
+            )
+            .append_pair("format", "detailed")
+            .append_pair("wordLevelTimestamps", "true")
+            .append_pair("profanity", "raw");
+
+        let mut request = url.as_str().into_client_request()?;
+        let headers = request.headers_mut();
+        headers.insert("Ocp-Apim-Subscription-Key", self.api_key.clone());
+        headers.insert(
+            "X-ConnectionId",
+            HeaderValue::from_str(&uuid::Uuid::new_v4().simple().to_string()).unwrap(),
+        );
+        Ok(request)
+    }
+}
+
+impl Service<Request<Body>> for TranscribeService {
+    type Response = Response;
+    type Error = std::convert::Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        let service = self.clone();
+
+        Box::pin(async move {
+            if req.headers().get("upgrade").and_then(|v| v.to_str().ok()) == Some("websocket") {
+                let (parts, body) = req.into_parts();
+                let axum_req = axum::extract::Request::from_parts(parts, body);
+
+                let query_string = axum_req.uri().query().unwrap_or("");
+                let params: ListenParams = match serde_qs::from_str(query_string) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        return Ok((StatusCode::BAD_REQUEST, e.to_string()).into_response());
                     }
                 };
 
-                if sender.send(Message::Text(json.into())).await.is_err() {
-                    break;
+                match WebSocketUpgrade::from_request(axum_req, &()).await {
+                    Ok(ws) => Ok(service.handle_websocket(ws, Some(params)).await),
+                    Err(_) => Ok(Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from("Invalid WebSocket upgrade request"))
+                        .unwrap()),
                 }
+            } else {
+                Ok(Response::builder()
+                    .status(StatusCode::METHOD_NOT_ALLOWED)
+                    .body(Body::from("Only WebSocket connections are supported"))
+                    .unwrap())
             }
-        });
+        })
+    }
+}
 
-        // Start transcription
-        if let Err(e) = self.start_transcription(audio_rx, result_tx).await {
-            error!("Transcription error: {}", e);
+/// A `speech.phrase` body in the detailed format.
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Phrase {
+    recognition_status: String,
+    #[serde(default)]
+    offset: u64,
+    #[serde(default)]
+    duration: u64,
+    #[serde(default, rename = "NBest")]
+    n_best: Vec<PhraseAlternative>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct PhraseAlternative {
+    #[serde(default)]
+    display: String,
+    #[serde(default)]
+    words: Vec<PhraseWord>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct PhraseWord {
+    word: String,
+    offset: u64,
+    duration: u64,
+    confidence: Option<f32>,
+}
+
+fn text_message(path: &str, request_id: &str, body: &serde_json::Value) -> String {
+    format!(
+        "Path: {}\r\nX-RequestId: {}\r\nX-Timestamp: {}\r\nContent-Type: application/json\r\n\r\n{}",
+        path,
+        request_id,
+        timestamp(),
+        body
+    )
+}
+
+/// Binary messages start with the length of their headers as a big-endian `u16`.
+fn audio_message(request_id: &str, audio: &[u8]) -> Vec<u8> {
+    let headers = format!(
+        "Path: audio\r\nX-RequestId: {}\r\nX-Timestamp: {}\r\nContent-Type: audio/x-wav\r\n",
+        request_id,
+        timestamp()
+    );
+
+    let mut message = Vec::with_capacity(2 + headers.len() + audio.len());
+    message.extend_from_slice(&(headers.len() as u16).to_be_bytes());
+    message.extend_from_slice(headers.as_bytes());
+    message.extend_from_slice(audio);
+    message
+}
+
+fn timestamp() -> String {
+    chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+}
+
+/// The first audio message says what follows. Lengths are left at zero since the stream has
+/// no known end.
+fn wav_header() -> Vec<u8> {
+    let mut header = Vec::with_capacity(44);
+    header.extend_from_slice(b"RIFF");
+    header.extend_from_slice(&0u32.to_le_bytes());
+    header.extend_from_slice(b"WAVEfmt ");
+    header.extend_from_slice(&16u32.to_le_bytes());
+    // PCM, mono.
+    header.extend_from_slice(&1u16.to_le_bytes());
+    header.extend_from_slice(&1u16.to_le_bytes());
+    header.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    header.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes());
+    header.extend_from_slice(&2u16.to_le_bytes());
+    header.extend_from_slice(&16u16.to_le_bytes());
+    header.extend_from_slice(b"data");
+    header.extend_from_slice(&0u32.to_le_bytes());
+    header
+}
+
+fn speech_config() -> serde_json::Value {
+    serde_json::json!({
+        "context": {
+            "system": { "name": "owhisper", "version": env!("CARGO_PKG_VERSION") },
+            "os": { "platform": std::env::consts::OS, "name": std::env::consts::OS, "version": "" },
+            "audio": { "source": { "type": "Stream" } },
         }
+    })
+}
+
+fn phrase_list(keyterms: &[String]) -> serde_json::Value {
+    let items: Vec<_> = keyterms
+        .iter()
+        .map(|keyterm| serde_json::json!({ "Text": keyterm }))
+        .collect();
+    serde_json::json!({ "dgi": { "Groups": [{ "Type": "Generic", "Items": items }] } })
+}
+
+/// Splits a text message into its `Path` header and body.
+fn parse_message(text: &str) -> Option<(&str, &str)> {
+    let (headers, body) = text.split_once("\r\n\r\n")?;
+    let path = headers.split("\r\n").find_map(|header| {
+        let (name, value) = header.split_once(':')?;
+        name.trim()
+            .eq_ignore_ascii_case("path")
+            .then_some(value.trim())
+    })?;
+    Some((path, body))
+}
 
-        // Clean up tasks
-        audio_handler.abort();
-        result_sender.abort();
+/// Words of a recognized phrase, in milliseconds from the start of the whole session. Azure's
+/// word list is lexical, so the punctuated display text is used instead when it lines up.
+fn phrase_words(phrase: &Phrase, offset_ms: u64, channel: Option<u8>) -> Vec<Word2> {
+    if phrase.recognition_status != "Success" {
+        return vec![];
     }
+    let Some(alternative) = phrase.n_best.first() else {
+        return vec![];
+    };
 
-    async fn start_transcription(
-        &self,
-        mut audio_rx: mpsc::Receiver<Bytes>,
-        result_tx: mpsc::Sender<WsMessage>,
-    ) -> Result<(), Error> {
-        Ok(())
+    let speaker = channel.map(|index| SpeakerIdentity::Unassigned { index });
+    let ms = |ticks: u64| offset_ms + ticks / TICKS_PER_MS;
+
+    let display: Vec<&str> = alternative.display.split_whitespace().collect();
+    if alternative.words.is_empty() {
+        // No word timings; every word spans the whole phrase.
+        return display
+            .iter()
+            .map(|text| Word2 {
+                text: text.to_string(),
+                speaker: speaker.clone(),
+                confidence: None,
+                start_ms: Some(ms(phrase.offset)),
+                end_ms: Some(ms(phrase.offset + phrase.duration)),
+            })
+            .collect();
+    }
+
+    let use_display = display.len() == alternative.words.len();
+    alternative
+        .words
+        .iter()
+        .enumerate()
+        .map(|(i, word)| Word2 {
+            text: if use_display {
+                display[i].to_string()
+            } else {
+                word.word.clone()
+            },
+            speaker: speaker.clone(),
+            confidence: word.confidence,
+            start_ms: Some(ms(word.offset)),
+            end_ms: Some(ms(word.offset + word.duration)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str = r#"{
+        "RecognitionStatus": "Success",
+        "Offset": 5000000,
+        "Duration": 12500000,
+        "NBest": [{
+            "Confidence": 0.93,
+            "Lexical": "hello there",
+            "Display": "Hello, there.",
+            "Words": [
+                { "Word": "hello", "Offset": 5000000, "Duration": 4000000 },
+                { "Word": "there", "Offset": 10000000, "Duration": 7500000 }
+            ]
+        }]
+    }"#;
+
+    #[test]
+    fn test_phrase_words() {
+        let phrase: Phrase = serde_json::from_str(PHRASE).unwrap();
+
+        let words = phrase_words(&phrase, 540_000, Some(1));
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].text, "Hello,");
+        assert_eq!(words[1].text, "there.");
+        assert_eq!(words[1].start_ms, Some(541_000));
+        assert_eq!(words[1].end_ms, Some(541_750));
+        assert_eq!(
+            words[1].speaker,
+            Some(SpeakerIdentity::Unassigned { index: 1 })
+        );
+
+        let phrase: Phrase =
+            serde_json::from_str(&PHRASE.replace("Hello, there.", "Hello there, friend.")).unwrap();
+        let words = phrase_words(&phrase, 0, None);
+        assert_eq!(words[0].text, "hello");
+        assert_eq!(words[0].speaker, None);
+
+        let phrase: Phrase =
+            serde_json::from_str(r#"{ "RecognitionStatus": "InitialSilenceTimeout" }"#).unwrap();
+        assert!(phrase_words(&phrase, 0, None).is_empty());
+    }
+
+    #[test]
+    fn test_parse_message() {
+        let text =
+            "X-RequestId: 123\r\nContent-Type: application/json\r\nPath: speech.phrase\r\n\r\n{}";
+        assert_eq!(parse_message(text), Some(("speech.phrase", "{}")));
+        assert_eq!(parse_message("Path: turn.end"), None);
+    }
+
+    #[test]
+    fn test_audio_message() {
+        let message = audio_message("123", &[1, 2]);
+        let len = u16::from_be_bytes([message[0], message[1]]) as usize;
+        let headers = std::str::from_utf8(&message[2..2 + len]).unwrap();
+        assert!(headers.starts_with("Path: audio\r\n"));
+        assert_eq!(&message[2 + len..], &[1, 2]);
+    }
+
+    #[test]
+    fn test_wav_header() {
+        let header = wav_header();
+        assert_eq!(header.len(), 44);
+        assert_eq!(&header[24..28], &SAMPLE_RATE.to_le_bytes());
     }
 }
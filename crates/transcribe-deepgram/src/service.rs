@@ -16,7 +16,7 @@ use std::{
 use tower::Service;
 
 use deepgram::{
-    common::options::{Encoding, Language, Model, Options},
+    common::options::{Encoding, Keyword, Language, Model, Options},
     Deepgram,
 };
 
@@ -53,7 +53,7 @@ impl TranscribeService {
     async fn handle_socket(self, socket: WebSocket, params: Option<ListenParams>) {
         let (mut sender, mut receiver) = socket.split();
 
-        let _params = params.unwrap_or_default();
+        let params = params.unwrap_or_default();
 
         let (audio_tx, audio_rx) = mpsc::channel::<Result<bytes::Bytes, std::io::Error>>(100);
 
@@ -71,7 +71,7 @@ impl TranscribeService {
                                     }
                                 }
                                 ListenInputChunk::DualAudio { mic, speaker } => {
-                                    let mixed = mix_audio(mic, speaker);
+                                    let mixed = hypr_audio_utils::mix_i16_le(&mic, &speaker);
                                     if !mixed.is_empty() {
                                         if audio_tx.send(Ok(mixed.into())).await.is_err() {
                                             break;
@@ -96,6 +96,10 @@ impl TranscribeService {
             .smart_format(true)
             .language(Language::en)
             .encoding(Encoding::Linear16)
+            .keywords_with_intensifiers(params.weighted_keywords().map(|(word, weight)| Keyword {
+                keyword: word.to_string(),
+                intensifier: weight,
+            }))
             .build();
 
         match self
@@ -184,8 +188,16 @@ impl Service<Request<Body>> for TranscribeService {
                 let (parts, body) = req.into_parts();
                 let axum_req = axum::extract::Request::from_parts(parts, body);
 
+                let query_string = axum_req.uri().query().unwrap_or("");
+                let params: ListenParams = match serde_qs::from_str(query_string) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        return Ok((StatusCode::BAD_REQUEST, e.to_string()).into_response());
+                    }
+                };
+
                 match WebSocketUpgrade::from_request(axum_req, &()).await {
-                    Ok(ws) => Ok(service.handle_websocket(ws, None).await),
+                    Ok(ws) => Ok(service.handle_websocket(ws, Some(params)).await),
                     Err(_) => Ok(Response::builder()
                         .status(StatusCode::BAD_REQUEST)
                         .body(Body::from("Invalid WebSocket upgrade request"))
@@ -200,29 +212,3 @@ impl Service<Request<Body>> for TranscribeService {
         })
     }
 }
-
-fn mix_audio(mic: Vec<u8>, speaker: Vec<u8>) -> Vec<u8> {
-    let len = mic.len().max(speaker.len());
-    let mut mixed = Vec::with_capacity(len);
-
-    for i in (0..len).step_by(2) {
-        let mic_sample = if i + 1 < mic.len() {
-            i16::from_le_bytes([mic[i], mic[i + 1]])
-        } else {
-            0
-        };
-
-        let speaker_sample = if i + 1 < speaker.len() {
-            i16::from_le_bytes([speaker[i], speaker[i + 1]])
-        } else {
-            0
-        };
-
-        let mixed_sample = ((mic_sample as i32 + speaker_sample as i32) / 2) as i16;
-        let bytes = mixed_sample.to_le_bytes();
-        mixed.push(bytes[0]);
-        mixed.push(bytes[1]);
-    }
-
-    mixed
-}
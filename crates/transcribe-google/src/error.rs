@@ -0,0 +1,15 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    AuthError(#[from] gcp_auth::Error),
+    #[error(transparent)]
+    TransportError(#[from] tonic::transport::Error),
+    #[error(transparent)]
+    StatusError(#[from] tonic::Status),
+    #[error("invalid endpoint: {0}")]
+    InvalidEndpoint(String),
+    #[error("recognition failed: {0}")]
+    RecognitionError(String),
+    #[error("invalid metadata: {0}")]
+    InvalidMetadata(String),
+}
@@ -0,0 +1,450 @@
+use bytes::Bytes;
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc,
+};
+
+use async_stream::stream;
+use tokio::sync::{mpsc, Mutex};
+use tracing::error;
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        FromRequest,
+    },
+    response::{IntoResponse, Response},
+};
+use futures_util::{SinkExt, Stream, StreamExt};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tower::Service;
+
+use hypr_audio_utils::{interleave_i16_le, mix_i16_le};
+
+use owhisper_interface::{
+    ListenInputChunk, ListenOutputChunk, ListenParams, SpeakerIdentity, Word2,
+};
+
+mod error;
+mod proto;
+pub use error::*;
+
+use proto::{
+    streaming_recognize_request::StreamingRequest, StreamingRecognitionResult,
+    StreamingRecognizeRequest, StreamingRecognizeResponse,
+};
+
+const SCOPES: &[&str] = &["https://www.googleapis.com/auth/cloud-platform"];
+const SAMPLE_RATE: i32 = 16000;
+/// Google closes a stream after about five minutes of audio, so a new one is opened before that.
+const STREAM_LIMIT_MS: u64 = 280_000;
+/// Recognition takes the first as the main language and up to three alternatives.
+const MAX_LANGUAGES: usize = 4;
+
+#[derive(Clone)]
+enum Auth {
+    ApiKey(String),
+    Token(Arc<dyn gcp_auth::TokenProvider>),
+}
+
+#[derive(Clone)]
+pub struct TranscribeService {
+    channel: tonic::transport::Channel,
+    auth: Auth,
+    model: Option<String>,
+}
+
+impl TranscribeService {
+    pub async fn new(config: owhisper_config::GoogleModelConfig) -> Result<Self, crate::Error> {
+        let auth = match (config.api_key, config.credentials_path) {
+            (Some(api_key), _) => Auth::ApiKey(api_key),
+            (None, Some(path)) => {
+                Auth::Token(Arc::new(gcp_auth::CustomServiceAccount::from_file(path)?))
+            }
+            (None, None) => Auth::Token(gcp_auth::provider().await?),
+        };
+
+        let host = match config.region {
+            Some(region) => format!("{}-speech.googleapis.com", region),
+            None => "speech.googleapis.com".to_string(),
+        };
+        let channel = tonic::transport::Channel::from_shared(format!("https://{}", host))
+            .map_err(|e| crate::Error::InvalidEndpoint(e.to_string()))?
+            .tls_config(tonic::transport::ClientTlsConfig::new().domain_name(host))?
+            .connect_lazy();
+
+        Ok(Self {
+            channel,
+            auth,
+            model: config.model,
+        })
+    }
+
+    pub async fn handle_websocket(
+        self,
+        ws: WebSocketUpgrade,
+        params: Option<ListenParams>,
+    ) -> Response {
+        ws.on_upgrade(move |socket| self.handle_socket(socket, params))
+            .into_response()
+    }
+
+    async fn handle_socket(self, socket: WebSocket, params: Option<ListenParams>) {
+        let (sender, mut receiver) = socket.split();
+
+        let params = params.unwrap_or_default();
+        // Each channel is recognized on its own, so words carry the channel as their speaker.
+        let dual = params.channels >= 2;
+
+        let (audio_tx, audio_rx) = mpsc::channel::<Bytes>(100);
+
+        let audio_task = tokio::spawn(async move {
+            while let Some(Ok(msg)) = receiver.next().await {
+                match msg {
+                    Message::Text(data) => {
+                        if let Ok(chunk) = serde_json::from_str::<ListenInputChunk>(&data) {
+                            let audio = match chunk {
+                                ListenInputChunk::Audio { data } if dual => {
+                                    interleave_i16_le(&data, &[])
+                                }
+                                ListenInputChunk::Audio { data } => data,
+                                ListenInputChunk::DualAudio { mic, speaker } if dual => {
+                                    interleave_i16_le(&mic, &speaker)
+                                }
+                                ListenInputChunk::DualAudio { mic, speaker } => {
+                                    mix_i16_le(&mic, &speaker)
+                                }
+                                ListenInputChunk::End => break,
+                            };
+                            if !audio.is_empty() && audio_tx.send(Bytes::from(audio)).await.is_err()
+                            {
+                                break;
+                            }
+                        }
+                    }
+                    Message::Close(_) => break,
+                    _ => {}
+                }
+            }
+        });
+
+        if let Err(e) = self.start_transcription(audio_rx, sender, &params).await {
+            error!("Transcription error: {}", e);
+        }
+
+        audio_task.abort();
+    }
+
+    async fn start_transcription(
+        &self,
+        audio_rx: mpsc::Receiver<Bytes>,
+        mut sender: futures_util::stream::SplitSink<WebSocket, Message>,
+        params: &ListenParams,
+    ) -> Result<(), crate::Error> {
+        let channels: u64 = if params.channels >= 2 { 2 } else { 1 };
+        // 16-bit samples at 16 kHz.
+        let bytes_per_ms = 32 * channels;
+        let audio_rx = Arc::new(Mutex::new(audio_rx));
+        let mut offset_ms = 0;
+
+        loop {
+            let sent_bytes = Arc::new(AtomicU64::new(0));
+            let ended = Arc::new(AtomicBool::new(false));
+
+            let requests = {
+                let config = self.streaming_config(params);
+                let audio_rx = audio_rx.clone();
+                let sent_bytes = sent_bytes.clone();
+                let ended = ended.clone();
+
+                stream! {
+                    yield StreamingRecognizeRequest {
+                        streaming_request: Some(StreamingRequest::StreamingConfig(config)),
+                    };
+
+                    let mut audio_rx = audio_rx.lock_owned().await;
+                    while sent_bytes.load(Ordering::Relaxed) < STREAM_LIMIT_MS * bytes_per_ms {
+                        let Some(chunk) = audio_rx.recv().await else {
+                            ended.store(true, Ordering::Relaxed);
+                            break;
+                        };
+                        sent_bytes.fetch_add(chunk.len() as u64, Ordering::Relaxed);
+                        yield StreamingRecognizeRequest {
+                            streaming_request: Some(StreamingRequest::AudioContent(chunk.to_vec())),
+                        };
+                    }
+                }
+            };
+
+            let mut responses = self.streaming_recognize(requests).await?;
+            while let Some(response) = responses.message().await? {
+                if let Some(status) = response.error.filter(|status| status.code != 0) {
+                    return Err(crate::Error::RecognitionError(status.message));
+                }
+
+                for result in response.results.iter().filter(|result| result.is_final) {
+                    let words = result_words(result, offset_ms);
+                    if words.is_empty() {
+                        continue;
+                    }
+
+                    let output_chunk = ListenOutputChunk { meta: None, words };
+                    if let Ok(json) = serde_json::to_string(&output_chunk) {
+                        if sender.send(Message::Text(json.into())).await.is_err() {
+                            return Ok(());
+                        }
+                    }
+                }
+            }
+
+            if ended.load(Ordering::Relaxed) {
+                break;
+            }
+            offset_ms += sent_bytes.load(Ordering::Relaxed) / bytes_per_ms;
+        }
+
+        let _ = sender.close().await;
+        Ok(())
+    }
+
+    fn streaming_config(&self, params: &ListenParams) -> proto::StreamingRecognitionConfig {
+        let dual = params.channels >= 2;
+        let mut languages = params
+            .languages
+            .iter()
+            .map(hypr_language::Language::bcp47_locale);
+        let language_code = languages.next().unwrap_or_else(|| "en-US".to_string());
+
+        proto::StreamingRecognitionConfig {
+            config: Some(proto::RecognitionConfig {
+                encoding: proto::AudioEncoding::Linear16 as i32,
+                sample_rate_hertz: SAMPLE_RATE,
+                language_code,
+                alternative_language_codes: languages.take(MAX_LANGUAGES - 1).collect(),
+                audio_channel_count: if dual { 2 } else { 1 },
+                enable_separate_recognition_per_channel: dual,
+                enable_word_time_offsets: true,
+                enable_word_confidence: true,
+                enable_automatic_punctuation: true,
+                speech_contexts: if params.keyterms.is_empty() {
+                    vec![]
+                } else {
+                    vec![proto::SpeechContext {
+                        phrases: params.keyterms.clone(),
+                        boost: 0.0,
+                    }]
+                },
+                // With separate channels the speaker is already known.
+                diarization_config: (params.diarize && !dual).then_some(
+                    proto::SpeakerDiarizationConfig {
+                        enable_speaker_diarization: true,
+                        ..Default::default()
+                    },
+                ),
+                model: self.model.clone().unwrap_or_default(),
+                ..Default::default()
+            }),
+            single_utterance: false,
+            interim_results: false,
+        }
+    }
+
+    async fn streaming_recognize(
+        &self,
+        requests: impl Stream<Item = StreamingRecognizeRequest> + Send + 'static,
+    ) -> Result<tonic::Streaming<StreamingRecognizeResponse>, crate::Error> {
+        let mut request = tonic::Request::new(requests);
+
+        let (key, value) = match &self.auth {
+            Auth::ApiKey(api_key) => ("x-goog-api-key", api_key.clone()),
+            Auth::Token(provider) => (
+                "authorization",
+                format!("Bearer {}", provider.token(SCOPES).await?.as_str()),
+            ),
+        };
+        request.metadata_mut().insert(
+            key,
+            value
+                .parse()
+                .map_err(|_| crate::Error::InvalidMetadata(key.to_string()))?,
+        );
+
+        let mut grpc = tonic::client::Grpc::new(self.channel.clone());
+        grpc.ready().await?;
+
+        let response = grpc
+            .streaming(
+                request,
+                tonic::codegen::http::uri::PathAndQuery::from_static(proto::STREAMING_RECOGNIZE),
+                tonic::codec::ProstCodec::default(),
+            )
+            .await?;
+        Ok(response.into_inner())
+    }
+}
+
+impl Service<Request<Body>> for TranscribeService {
+    type Response = Response;
+    type Error = std::convert::Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        let service = self.clone();
+
+        Box::pin(async move {
+            if req.headers().get("upgrade").and_then(|v| v.to_str().ok()) == Some("websocket") {
+                let (parts, body) = req.into_parts();
+                let axum_req = axum::extract::Request::from_parts(parts, body);
+
+                let query_string = axum_req.uri().query().unwrap_or("");
+                let params: ListenParams = match serde_qs::from_str(query_string) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        return Ok((StatusCode::BAD_REQUEST, e.to_string()).into_response());
+                    }
+                };
+
+                match WebSocketUpgrade::from_request(axum_req, &()).await {
+                    Ok(ws) => Ok(service.handle_websocket(ws, Some(params)).await),
+                    Err(_) => Ok(Response::builder()
+                        .status(StatusCode::BAD_REQUEST)
+                        .body(Body::from("Invalid WebSocket upgrade request"))
+                        .unwrap()),
+                }
+            } else {
+                Ok(Response::builder()
+                    .status(StatusCode::METHOD_NOT_ALLOWED)
+                    .body(Body::from("Only WebSocket connections are supported"))
+                    .unwrap())
+            }
+        })
+    }
+}
+
+/// Milliseconds from the start of the whole session, not of the current stream.
+fn duration_ms(duration: Option<&prost_types::Duration>, offset_ms: u64) -> Option<u64> {
+    let duration = duration?;
+    let ms = duration.seconds * 1000 + i64::from(duration.nanos) / 1_000_000;
+    Some(offset_ms + ms.max(0) as u64)
+}
+
+/// Words of a final result. The speaker is the channel when channels are recognized
+/// separately, and Google's speaker tag otherwise.
+fn result_words(result: &StreamingRecognitionResult, offset_ms: u64) -> Vec<Word2> {
+    let Some(alternative) = result.alternatives.first() else {
+        return vec![];
+    };
+
+    // Tags start at 1; 0 means there is none.
+    let index = |tag: i32| u8::try_from(tag - 1).ok();
+    let channel = index(result.channel_tag);
+    let speaker = |tag: i32| {
+        channel
+            .or_else(|| index(tag))
+            .map(|index| SpeakerIdentity::Unassigned { index })
+    };
+
+    if alternative.words.is_empty() {
+        // No word timings; every word ends with the result.
+        let end_ms = duration_ms(result.result_end_time.as_ref(), offset_ms);
+        return alternative
+            .transcript
+            .split_whitespace()
+            .map(|text| Word2 {
+                text: text.to_string(),
+                speaker: speaker(0),
+                confidence: None,
+                start_ms: end_ms,
+                end_ms,
+            })
+            .collect();
+    }
+
+    alternative
+        .words
+        .iter()
+        .map(|word| Word2 {
+            text: word.word.clone(),
+            speaker: speaker(word.speaker_tag),
+            confidence: (word.confidence > 0.0).then_some(word.confidence),
+            start_ms: duration_ms(word.start_time.as_ref(), offset_ms),
+            end_ms: duration_ms(word.end_time.as_ref(), offset_ms),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn duration(ms: i64) -> Option<prost_types::Duration> {
+        Some(prost_types::Duration {
+            seconds: ms / 1000,
+            nanos: (ms % 1000) as i32 * 1_000_000,
+        })
+    }
+
+    fn word(text: &str, start_ms: i64, end_ms: i64, speaker_tag: i32) -> proto::WordInfo {
+        proto::WordInfo {
+            start_time: duration(start_ms),
+            end_time: duration(end_ms),
+            word: text.to_string(),
+            confidence: 0.9,
+            speaker_tag,
+        }
+    }
+
+    fn result(channel_tag: i32, words: Vec<proto::WordInfo>) -> StreamingRecognitionResult {
+        StreamingRecognitionResult {
+            alternatives: vec![proto::SpeechRecognitionAlternative {
+                transcript: "hello there".to_string(),
+                confidence: 0.9,
+                words,
+            }],
+            is_final: true,
+            result_end_time: duration(2_000),
+            channel_tag,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_result_words() {
+        let words = result_words(
+            &result(
+                0,
+                vec![word("hello", 100, 500, 1), word("there", 600, 1_250, 2)],
+            ),
+            300_000,
+        );
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[1].text, "there");
+        assert_eq!(words[1].start_ms, Some(300_600));
+        assert_eq!(words[1].end_ms, Some(301_250));
+        assert_eq!(
+            words[1].speaker,
+            Some(SpeakerIdentity::Unassigned { index: 1 })
+        );
+
+        let words = result_words(&result(2, vec![word("hello", 100, 500, 0)]), 0);
+        assert_eq!(
+            words[0].speaker,
+            Some(SpeakerIdentity::Unassigned { index: 1 })
+        );
+
+        let words = result_words(&result(0, vec![]), 0);
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].speaker, None);
+        assert_eq!(words[0].end_ms, Some(2_000));
+    }
+}
@@ -0,0 +1,147 @@
+//! The part of `google.cloud.speech.v1` that streaming recognition needs, written out by hand
+//! so building doesn't depend on `protoc`. Field numbers follow `cloud_speech.proto`.
+
+pub const STREAMING_RECOGNIZE: &str = "/google.cloud.speech.v1.Speech/StreamingRecognize";
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StreamingRecognizeRequest {
+    #[prost(oneof = "streaming_recognize_request::StreamingRequest", tags = "1, 2")]
+    pub streaming_request: Option<streaming_recognize_request::StreamingRequest>,
+}
+
+pub mod streaming_recognize_request {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum StreamingRequest {
+        /// Must be the first and only the first message.
+        #[prost(message, tag = "1")]
+        StreamingConfig(super::StreamingRecognitionConfig),
+        #[prost(bytes, tag = "2")]
+        AudioContent(Vec<u8>),
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StreamingRecognitionConfig {
+    #[prost(message, optional, tag = "1")]
+    pub config: Option<RecognitionConfig>,
+    #[prost(bool, tag = "2")]
+    pub single_utterance: bool,
+    #[prost(bool, tag = "3")]
+    pub interim_results: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, prost::Enumeration)]
+#[repr(i32)]
+pub enum AudioEncoding {
+    EncodingUnspecified = 0,
+    Linear16 = 1,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RecognitionConfig {
+    #[prost(enumeration = "AudioEncoding", tag = "1")]
+    pub encoding: i32,
+    #[prost(int32, tag = "2")]
+    pub sample_rate_hertz: i32,
+    #[prost(string, tag = "3")]
+    pub language_code: String,
+    #[prost(int32, tag = "4")]
+    pub max_alternatives: i32,
+    #[prost(bool, tag = "5")]
+    pub profanity_filter: bool,
+    #[prost(message, repeated, tag = "6")]
+    pub speech_contexts: Vec<SpeechContext>,
+    #[prost(int32, tag = "7")]
+    pub audio_channel_count: i32,
+    #[prost(bool, tag = "8")]
+    pub enable_word_time_offsets: bool,
+    #[prost(bool, tag = "11")]
+    pub enable_automatic_punctuation: bool,
+    #[prost(bool, tag = "12")]
+    pub enable_separate_recognition_per_channel: bool,
+    #[prost(string, tag = "13")]
+    pub model: String,
+    #[prost(bool, tag = "15")]
+    pub enable_word_confidence: bool,
+    #[prost(string, repeated, tag = "18")]
+    pub alternative_language_codes: Vec<String>,
+    #[prost(message, optional, tag = "19")]
+    pub diarization_config: Option<SpeakerDiarizationConfig>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SpeechContext {
+    #[prost(string, repeated, tag = "1")]
+    pub phrases: Vec<String>,
+    #[prost(float, tag = "4")]
+    pub boost: f32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SpeakerDiarizationConfig {
+    #[prost(bool, tag = "1")]
+    pub enable_speaker_diarization: bool,
+    #[prost(int32, tag = "2")]
+    pub min_speaker_count: i32,
+    #[prost(int32, tag = "3")]
+    pub max_speaker_count: i32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StreamingRecognizeResponse {
+    #[prost(message, optional, tag = "1")]
+    pub error: Option<Status>,
+    #[prost(message, repeated, tag = "2")]
+    pub results: Vec<StreamingRecognitionResult>,
+}
+
+/// `google.rpc.Status`, without the details.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Status {
+    #[prost(int32, tag = "1")]
+    pub code: i32,
+    #[prost(string, tag = "2")]
+    pub message: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StreamingRecognitionResult {
+    #[prost(message, repeated, tag = "1")]
+    pub alternatives: Vec<SpeechRecognitionAlternative>,
+    #[prost(bool, tag = "2")]
+    pub is_final: bool,
+    #[prost(float, tag = "3")]
+    pub stability: f32,
+    #[prost(message, optional, tag = "4")]
+    pub result_end_time: Option<prost_types::Duration>,
+    /// Starts at 1 when channels are recognized separately, 0 otherwise.
+    #[prost(int32, tag = "5")]
+    pub channel_tag: i32,
+    #[prost(string, tag = "6")]
+    pub language_code: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SpeechRecognitionAlternative {
+    #[prost(string, tag = "1")]
+    pub transcript: String,
+    #[prost(float, tag = "2")]
+    pub confidence: f32,
+    #[prost(message, repeated, tag = "3")]
+    pub words: Vec<WordInfo>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WordInfo {
+    #[prost(message, optional, tag = "1")]
+    pub start_time: Option<prost_types::Duration>,
+    #[prost(message, optional, tag = "2")]
+    pub end_time: Option<prost_types::Duration>,
+    #[prost(string, tag = "3")]
+    pub word: String,
+    #[prost(float, tag = "4")]
+    pub confidence: f32,
+    /// Starts at 1 with diarization on, 0 otherwise.
+    #[prost(int32, tag = "5")]
+    pub speaker_tag: i32,
+}
@@ -0,0 +1,5 @@
+mod error;
+mod service;
+
+pub use error::*;
+pub use service::*;
@@ -0,0 +1,2 @@
+mod streaming;
+pub use streaming::*;
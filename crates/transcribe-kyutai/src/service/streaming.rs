@@ -0,0 +1,307 @@
+use std::{
+    future::Future,
+    path::PathBuf,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        FromRequestParts,
+    },
+    http::{Request, StatusCode},
+    response::{IntoResponse, Response},
+};
+use futures_util::{SinkExt, StreamExt};
+use kalosm_sound::AsyncSource;
+use tower::Service;
+
+use hypr_audio_utils::ResampledAsyncSource;
+use hypr_kyutai::Model;
+use owhisper_interface::{Alternatives, Channel, ListenParams, Metadata, StreamResponse, Word};
+
+// Frames of 80ms, the unit the model steps in.
+const CHUNK_SIZE: usize = 1920;
+
+#[derive(Clone)]
+pub struct TranscribeService {
+    config_path: PathBuf,
+    model_path: PathBuf,
+    tokenizer_path: PathBuf,
+    mimi_path: PathBuf,
+}
+
+impl TranscribeService {
+    pub fn builder() -> TranscribeServiceBuilder {
+        TranscribeServiceBuilder::default()
+    }
+
+    fn load_model(&self) -> Result<Model, crate::Error> {
+        let device = hypr_kyutai::device().map_err(|e| crate::Error::Kyutai(e.to_string()))?;
+
+        Model::load(
+            &self.config_path,
+            &self.model_path,
+            &self.tokenizer_path,
+            &self.mimi_path,
+            true,
+            false,
+            &device,
+        )
+        .map_err(|e| crate::Error::Kyutai(e.to_string()))
+    }
+}
+
+#[derive(Default)]
+pub struct TranscribeServiceBuilder {
+    config_path: Option<PathBuf>,
+    model_path: Option<PathBuf>,
+    tokenizer_path: Option<PathBuf>,
+    mimi_path: Option<PathBuf>,
+}
+
+impl TranscribeServiceBuilder {
+    pub fn config_path(mut self, config_path: impl Into<PathBuf>) -> Self {
+        self.config_path = Some(config_path.into());
+        self
+    }
+
+    pub fn model_path(mut self, model_path: impl Into<PathBuf>) -> Self {
+        self.model_path = Some(model_path.into());
+        self
+    }
+
+    pub fn tokenizer_path(mut self, tokenizer_path: impl Into<PathBuf>) -> Self {
+        self.tokenizer_path = Some(tokenizer_path.into());
+        self
+    }
+
+    pub fn mimi_path(mut self, mimi_path: impl Into<PathBuf>) -> Self {
+        self.mimi_path = Some(mimi_path.into());
+        self
+    }
+
+    pub fn build(self) -> TranscribeService {
+        TranscribeService {
+            config_path: self.config_path.unwrap(),
+            model_path: self.model_path.unwrap(),
+            tokenizer_path: self.tokenizer_path.unwrap(),
+            mimi_path: self.mimi_path.unwrap(),
+        }
+    }
+}
+
+impl<B> Service<Request<B>> for TranscribeService
+where
+    B: Send + 'static,
+{
+    type Response = Response;
+    type Error = std::convert::Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        let service = self.clone();
+
+        Box::pin(async move {
+            let uri = req.uri();
+            let query_string = uri.query().unwrap_or("");
+
+            let params: ListenParams = match serde_qs::from_str(query_string) {
+                Ok(p) => p,
+                Err(e) => {
+                    return Ok((StatusCode::BAD_REQUEST, e.to_string()).into_response());
+                }
+            };
+
+            let (mut parts, _body) = req.into_parts();
+            let ws_upgrade = match WebSocketUpgrade::from_request_parts(&mut parts, &()).await {
+                Ok(ws) => ws,
+                Err(e) => {
+                    return Ok((StatusCode::BAD_REQUEST, e.to_string()).into_response());
+                }
+            };
+            let permit = parts.extensions.remove::<hypr_ws_utils::SessionPermit>();
+
+            Ok(ws_upgrade
+                .on_upgrade(move |socket| async move {
+                    let _permit = permit;
+                    handle_websocket_connection(socket, params, service).await
+                })
+                .into_response())
+        })
+    }
+}
+
+async fn handle_websocket_connection(
+    socket: WebSocket,
+    params: ListenParams,
+    service: TranscribeService,
+) {
+    let (ws_sender, ws_receiver) = socket.split();
+
+    match params.channels {
+        1 => {
+            let model = match service.load_model() {
+                Ok(m) => m,
+                Err(e) => {
+                    tracing::error!("Failed to create kyutai model: {}", e);
+                    return;
+                }
+            };
+
+            handle_single_channel(ws_sender, ws_receiver, model).await;
+        }
+        _ => {
+            // The model keeps decoding state per stream, so each channel gets its own.
+            let models = service
+                .load_model()
+                .and_then(|mic| Ok((mic, service.load_model()?)));
+            let (mic_model, speaker_model) = match models {
+                Ok(models) => models,
+                Err(e) => {
+                    tracing::error!("Failed to create kyutai model: {}", e);
+                    return;
+                }
+            };
+
+            handle_dual_channel(ws_sender, ws_receiver, mic_model, speaker_model).await;
+        }
+    }
+}
+
+async fn handle_single_channel(
+    ws_sender: futures_util::stream::SplitSink<WebSocket, Message>,
+    ws_receiver: futures_util::stream::SplitStream<WebSocket>,
+    model: Model,
+) {
+    let audio_source = hypr_ws_utils::WebSocketAudioSource::new(ws_receiver, 16 * 1000);
+
+    let stream = process_audio_stream(audio_source, model, "mixed");
+    let boxed_stream = Box::pin(stream);
+    process_transcription_stream(ws_sender, boxed_stream).await;
+}
+
+async fn handle_dual_channel(
+    ws_sender: futures_util::stream::SplitSink<WebSocket, Message>,
+    ws_receiver: futures_util::stream::SplitStream<WebSocket>,
+    mic_model: Model,
+    speaker_model: Model,
+) {
+    let (mic_source, speaker_source) =
+        hypr_ws_utils::split_dual_audio_sources(ws_receiver, 16 * 1000);
+
+    let mic_stream = process_audio_stream(mic_source, mic_model, "mic");
+    let speaker_stream = process_audio_stream(speaker_source, speaker_model, "speaker");
+
+    let merged_stream = futures_util::stream::select(mic_stream, speaker_stream);
+    let boxed_stream = Box::pin(merged_stream);
+    process_transcription_stream(ws_sender, boxed_stream).await;
+}
+
+async fn process_transcription_stream(
+    mut ws_sender: futures_util::stream::SplitSink<WebSocket, Message>,
+    mut stream: Pin<Box<dyn futures_util::Stream<Item = StreamResponse> + Send>>,
+) {
+    while let Some(response) = stream.next().await {
+        let msg = Message::Text(serde_json::to_string(&response).unwrap().into());
+        if let Err(e) = ws_sender.send(msg).await {
+            tracing::warn!("websocket_send_error: {}", e);
+            break;
+        }
+    }
+
+    let _ = ws_sender.close().await;
+}
+
+fn process_audio_stream<S>(
+    source: S,
+    mut model: Model,
+    source_name: &'static str,
+) -> impl futures_util::Stream<Item = StreamResponse>
+where
+    S: AsyncSource + Unpin + Send + 'static,
+{
+    let chunks = ResampledAsyncSource::new(source, hypr_kyutai::SAMPLE_RATE).chunks(CHUNK_SIZE);
+
+    async_stream::stream! {
+        futures_util::pin_mut!(chunks);
+
+        while let Some(chunk) = chunks.next().await {
+            match model.step(&chunk) {
+                Ok(words) => {
+                    if let Some(response) = build_response(words, source_name) {
+                        yield response;
+                    }
+                }
+                Err(e) => {
+                    tracing::error!("kyutai_step_error: {}", e);
+                    return;
+                }
+            }
+        }
+
+        match model.flush() {
+            Ok(words) => {
+                if let Some(response) = build_response(words, source_name) {
+                    yield response;
+                }
+            }
+            Err(e) => tracing::error!("kyutai_flush_error: {}", e),
+        }
+    }
+}
+
+fn build_response(words: Vec<hypr_kyutai::Word>, source_name: &str) -> Option<StreamResponse> {
+    let (first, last) = (words.first()?, words.last()?);
+    let start = first.start;
+    let duration = (last.end - start).max(0.0);
+
+    let (speaker, channel_index) = match source_name {
+        "mic" => (Some(0), vec![0]),
+        "speaker" => (Some(1), vec![1]),
+        _ => (None, vec![0]),
+    };
+
+    let transcript = words
+        .iter()
+        .map(|w| w.text.trim())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let words: Vec<Word> = words
+        .into_iter()
+        .map(|w| Word {
+            word: w.text.trim().to_string(),
+            start: w.start,
+            end: w.end,
+            confidence: 1.0,
+            speaker,
+            punctuated_word: None,
+            language: None,
+        })
+        .collect();
+
+    Some(StreamResponse::TranscriptResponse {
+        type_field: "Results".to_string(),
+        start,
+        duration,
+        is_final: true,
+        speech_final: true,
+        from_finalize: false,
+        channel: Channel {
+            alternatives: vec![Alternatives {
+                transcript,
+                languages: vec![],
+                words,
+                confidence: 1.0,
+            }],
+        },
+        metadata: Metadata::default(),
+        channel_index,
+    })
+}
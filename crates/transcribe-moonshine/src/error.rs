@@ -6,6 +6,9 @@ pub enum Error {
     #[error(transparent)]
     Moonshine(#[from] hypr_moonshine::Error),
 
+    #[error(transparent)]
+    Pyannote(#[from] hypr_pyannote_local::Error),
+
     #[error("invalid model name: {0}")]
     InvalidModelName(String),
 
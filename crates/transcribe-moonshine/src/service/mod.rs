@@ -1,2 +1,5 @@
 mod streaming;
 pub use streaming::*;
+
+mod recorded;
+pub use recorded::*;
@@ -0,0 +1,48 @@
+use hypr_moonshine::MoonshineOnnxModel;
+use owhisper_interface::{batch::spread_words, ListenParams, Word};
+
+use super::TranscribeService;
+
+impl TranscribeService {
+    /// Transcribes a whole recording in one go. `samples` is a single channel at 16kHz.
+    pub fn transcribe_recorded(
+        &self,
+        samples: &[f32],
+        params: &ListenParams,
+    ) -> Result<Vec<Word>, crate::Error> {
+        let mut model = MoonshineOnnxModel::new(
+            &self.encoder_path,
+            &self.decoder_path,
+            &self.tokenizer_path,
+            self.model_size.clone(),
+        )?;
+
+        let samples = hypr_audio_utils::f32_to_i16_samples(samples);
+        let mut segmenter = hypr_pyannote_local::segmentation::Segmenter::new(16000)?;
+        let segments = segmenter.process(&samples, 16000)?;
+
+        let speakers = if params.diarize {
+            hypr_pyannote_local::clustering::label_segments(&segments)?
+                .into_iter()
+                .map(|speaker| Some(speaker as i32))
+                .collect()
+        } else {
+            vec![None; segments.len()]
+        };
+
+        let mut words = Vec::new();
+
+        for (segment, speaker) in segments.iter().zip(speakers) {
+            let text = model.transcribe(hypr_audio_utils::i16_to_f32_samples(&segment.samples))?;
+            words.extend(spread_words(
+                &text,
+                segment.start,
+                segment.end,
+                1.0,
+                speaker,
+            ));
+        }
+
+        Ok(words)
+    }
+}
@@ -25,10 +25,10 @@ use owhisper_interface::{Alternatives, Channel, ListenParams, Metadata, StreamRe
 
 #[derive(Clone)]
 pub struct TranscribeService {
-    model_size: MoonshineModelSize,
-    tokenizer_path: String,
-    encoder_path: String,
-    decoder_path: String,
+    pub(super) model_size: MoonshineModelSize,
+    pub(super) tokenizer_path: String,
+    pub(super) encoder_path: String,
+    pub(super) decoder_path: String,
 }
 
 impl TranscribeService {
@@ -112,9 +112,11 @@ where
                     return Ok((StatusCode::BAD_REQUEST, e.to_string()).into_response());
                 }
             };
+            let permit = parts.extensions.remove::<hypr_ws_utils::SessionPermit>();
 
             Ok(ws_upgrade
                 .on_upgrade(move |socket| async move {
+                    let _permit = permit;
                     handle_websocket_connection(
                         socket,
                         params,
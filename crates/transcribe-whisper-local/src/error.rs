@@ -1,2 +1,7 @@
 #[derive(Debug, thiserror::Error)]
-pub enum Error {}
+pub enum Error {
+    #[error(transparent)]
+    WhisperError(#[from] hypr_whisper_local::Error),
+    #[error(transparent)]
+    PyannoteError(#[from] hypr_pyannote_local::Error),
+}
@@ -1,7 +1,9 @@
 mod error;
+mod metrics;
 mod service;
 
 pub use error::*;
+pub use metrics::{Metrics, MetricsSnapshot};
 pub use service::*;
 
 #[cfg(test)]
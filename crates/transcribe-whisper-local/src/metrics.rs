@@ -0,0 +1,190 @@
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use futures_util::Stream;
+
+/// Weight of the newest chunk in the moving averages.
+const EMA_ALPHA: f64 = 0.2;
+
+/// Shared by every connection of a [`crate::TranscribeService`].
+#[derive(Clone, Default)]
+pub struct Metrics(Arc<Mutex<MetricsInner>>);
+
+#[derive(Default)]
+struct MetricsInner {
+    chunks_processed: u64,
+    audio: Duration,
+    inference: Duration,
+    last_inference: Option<Duration>,
+    real_time_factor: Option<f64>,
+    latency_ms: Option<f64>,
+    dropped_frames: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct MetricsSnapshot {
+    pub chunks_processed: u64,
+    pub audio_seconds: f64,
+    pub inference_seconds: f64,
+    pub last_inference_ms: Option<u64>,
+    /// Inference time over audio duration, weighted towards recent chunks.
+    /// Above 1.0 the machine can't keep up with the model.
+    pub real_time_factor: Option<f64>,
+    /// How far behind the live audio results are sent, weighted towards recent chunks.
+    pub latency_ms: Option<f64>,
+    /// Speech chunks skipped because transcription fell further behind than the service's
+    /// `max_lag`. Always zero without one.
+    pub dropped_frames: u64,
+}
+
+fn ema(prev: Option<f64>, value: f64) -> f64 {
+    match prev {
+        Some(prev) => prev + EMA_ALPHA * (value - prev),
+        None => value,
+    }
+}
+
+impl Metrics {
+    pub fn record_inference(&self, audio: Duration, inference: Duration) {
+        if audio.is_zero() {
+            return;
+        }
+
+        let mut inner = self.0.lock().unwrap();
+        inner.chunks_processed += 1;
+        inner.audio += audio;
+        inner.inference += inference;
+        inner.last_inference = Some(inference);
+        inner.real_time_factor = Some(ema(
+            inner.real_time_factor,
+            inference.as_secs_f64() / audio.as_secs_f64(),
+        ));
+    }
+
+    pub fn record_latency(&self, latency: Duration) {
+        let mut inner = self.0.lock().unwrap();
+        inner.latency_ms = Some(ema(inner.latency_ms, latency.as_secs_f64() * 1000.0));
+    }
+
+    pub fn record_dropped(&self) {
+        self.0.lock().unwrap().dropped_frames += 1;
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let inner = self.0.lock().unwrap();
+
+        MetricsSnapshot {
+            chunks_processed: inner.chunks_processed,
+            audio_seconds: inner.audio.as_secs_f64(),
+            inference_seconds: inner.inference.as_secs_f64(),
+            last_inference_ms: inner.last_inference.map(|d| d.as_millis() as u64),
+            real_time_factor: inner.real_time_factor,
+            latency_ms: inner.latency_ms,
+            dropped_frames: inner.dropped_frames,
+        }
+    }
+}
+
+/// Per-connection bookkeeping between the VAD stage and the transcription stream.
+#[derive(Clone)]
+pub(crate) struct ConnectionMetrics {
+    pub metrics: Metrics,
+    started: Instant,
+    /// Audio handed to whisper since the last poll of the transcription stream.
+    pending_audio_ms: Arc<AtomicU64>,
+    max_lag: Option<Duration>,
+}
+
+impl ConnectionMetrics {
+    pub fn new(metrics: Metrics) -> Self {
+        Self {
+            metrics,
+            started: Instant::now(),
+            pending_audio_ms: Arc::new(AtomicU64::new(0)),
+            max_lag: None,
+        }
+    }
+
+    pub fn with_max_lag(mut self, max_lag: Option<Duration>) -> Self {
+        self.max_lag = max_lag;
+        self
+    }
+
+    /// Whether speech ending at `audio_position_ms` is past the lag its caller opted into.
+    pub fn too_far_behind(&self, audio_position_ms: u64) -> bool {
+        self.max_lag
+            .is_some_and(|max_lag| self.lag(audio_position_ms) > max_lag)
+    }
+
+    /// How far the wall clock is past the given position in the live audio.
+    pub fn lag(&self, audio_position_ms: u64) -> Duration {
+        self.started
+            .elapsed()
+            .saturating_sub(Duration::from_millis(audio_position_ms))
+    }
+
+    pub fn chunk_queued(&self, samples: usize, sample_rate: u32) {
+        let ms = samples as u64 * 1000 / sample_rate as u64;
+        self.pending_audio_ms.fetch_add(ms, Ordering::Relaxed);
+    }
+}
+
+/// Whisper runs synchronously inside `poll_next`, so a poll that consumed audio
+/// took as long as its inference.
+pub(crate) struct Instrumented<S> {
+    inner: S,
+    connection: ConnectionMetrics,
+}
+
+impl<S> Instrumented<S> {
+    pub fn new(inner: S, connection: ConnectionMetrics) -> Self {
+        Self { inner, connection }
+    }
+}
+
+impl<S: Stream + Unpin> Stream for Instrumented<S> {
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let started = Instant::now();
+        let result = Pin::new(&mut self.inner).poll_next(cx);
+        let elapsed = started.elapsed();
+
+        let audio_ms = self.connection.pending_audio_ms.swap(0, Ordering::Relaxed);
+        self.connection
+            .metrics
+            .record_inference(Duration::from_millis(audio_ms), elapsed);
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics() {
+        let metrics = Metrics::default();
+
+        metrics.record_inference(Duration::from_secs(2), Duration::from_secs(1));
+        metrics.record_inference(Duration::ZERO, Duration::from_millis(5));
+        metrics.record_inference(Duration::from_secs(1), Duration::from_secs(1));
+        metrics.record_dropped();
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.chunks_processed, 2);
+        assert_eq!(snapshot.audio_seconds, 3.0);
+        assert_eq!(snapshot.last_inference_ms, Some(1000));
+        assert!((snapshot.real_time_factor.unwrap() - 0.6).abs() < 1e-9);
+        assert_eq!(snapshot.dropped_frames, 1);
+        assert_eq!(snapshot.latency_ms, None);
+    }
+}
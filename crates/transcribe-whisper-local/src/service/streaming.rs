@@ -18,25 +18,37 @@ use futures_util::{SinkExt, StreamExt};
 use tower::Service;
 
 use hypr_vad::VadExt;
-use hypr_ws_utils::{ConnectionGuard, ConnectionManager};
-use owhisper_interface::{Alternatives, Channel, ListenParams, Metadata, StreamResponse, Word};
+use hypr_ws_utils::{ConnectionGuard, ConnectionManager, SessionPermit};
+use owhisper_interface::{
+    Alternatives, Channel, ListenParams, ListenTask, Metadata, StreamResponse, Word,
+};
+
+use crate::metrics::{ConnectionMetrics, Instrumented, Metrics};
 
 #[derive(Clone)]
 pub struct TranscribeService {
-    model_path: PathBuf,
+    pub(super) model_path: PathBuf,
     connection_manager: ConnectionManager,
+    metrics: Metrics,
+    max_lag: Option<Duration>,
 }
 
 impl TranscribeService {
     pub fn builder() -> TranscribeServiceBuilder {
         TranscribeServiceBuilder::default()
     }
+
+    pub fn metrics(&self) -> Metrics {
+        self.metrics.clone()
+    }
 }
 
 #[derive(Default)]
 pub struct TranscribeServiceBuilder {
     model_path: Option<PathBuf>,
     connection_manager: Option<ConnectionManager>,
+    metrics: Option<Metrics>,
+    max_lag: Option<Duration>,
 }
 
 impl TranscribeServiceBuilder {
@@ -45,12 +57,26 @@ impl TranscribeServiceBuilder {
         self
     }
 
+    pub fn metrics(mut self, metrics: Metrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Past this, speech is skipped so transcripts catch up with the conversation instead of
+    /// trailing further and further behind. Off by default, since what is skipped is lost.
+    pub fn max_lag(mut self, max_lag: Duration) -> Self {
+        self.max_lag = Some(max_lag);
+        self
+    }
+
     pub fn build(self) -> TranscribeService {
         TranscribeService {
             model_path: self.model_path.unwrap(),
             connection_manager: self
                 .connection_manager
                 .unwrap_or_else(ConnectionManager::default),
+            metrics: self.metrics.unwrap_or_default(),
+            max_lag: self.max_lag,
         }
     }
 }
@@ -70,6 +96,8 @@ where
     fn call(&mut self, req: Request<B>) -> Self::Future {
         let model_path = self.model_path.clone();
         let connection_manager = self.connection_manager.clone();
+        let metrics = self.metrics.clone();
+        let max_lag = self.max_lag;
 
         Box::pin(async move {
             let uri = req.uri();
@@ -89,6 +117,7 @@ where
                     return Ok((StatusCode::BAD_REQUEST, e.to_string()).into_response());
                 }
             };
+            let permit = parts.extensions.remove::<SessionPermit>();
 
             let model = match hypr_whisper_local::Whisper::builder()
                 .model_path(model_path.to_str().unwrap())
@@ -99,6 +128,8 @@ where
                         .filter_map(|lang| lang.clone().try_into().ok())
                         .collect::<Vec<hypr_whisper::Language>>(),
                 )
+                .translate(params.task == ListenTask::Translate)
+                .keywords(params.bias_terms())
                 .build()
             {
                 Ok(model) => model,
@@ -112,11 +143,18 @@ where
                 }
             };
 
-            let guard = connection_manager.acquire_connection();
+            // With a session limit, the server's permits alone decide who gets in. Without one,
+            // a new connection takes over from the previous one.
+            let guard = match permit {
+                Some(_) => None,
+                None => Some(connection_manager.acquire_connection()),
+            };
+            let connection = ConnectionMetrics::new(metrics).with_max_lag(max_lag);
 
             Ok(ws_upgrade
                 .on_upgrade(move |socket| async move {
-                    handle_websocket_connection(socket, params, model, guard).await;
+                    let _permit = permit;
+                    handle_websocket_connection(socket, params, model, guard, connection).await;
                 })
                 .into_response())
         })
@@ -127,7 +165,8 @@ async fn handle_websocket_connection(
     socket: WebSocket,
     params: ListenParams,
     model: hypr_whisper_local::Whisper,
-    guard: ConnectionGuard,
+    guard: Option<ConnectionGuard>,
+    connection: ConnectionMetrics,
 ) {
     let (ws_sender, ws_receiver) = socket.split();
 
@@ -138,10 +177,26 @@ async fn handle_websocket_connection(
 
     match params.channels {
         1 => {
-            handle_single_channel(ws_sender, ws_receiver, model, guard, redemption_time).await;
+            handle_single_channel(
+                ws_sender,
+                ws_receiver,
+                model,
+                guard,
+                redemption_time,
+                connection,
+            )
+            .await;
         }
         _ => {
-            handle_dual_channel(ws_sender, ws_receiver, model, guard, redemption_time).await;
+            handle_dual_channel(
+                ws_sender,
+                ws_receiver,
+                model,
+                guard,
+                redemption_time,
+                connection,
+            )
+            .await;
         }
     }
 }
@@ -150,36 +205,53 @@ async fn handle_single_channel(
     ws_sender: futures_util::stream::SplitSink<WebSocket, Message>,
     ws_receiver: futures_util::stream::SplitStream<WebSocket>,
     model: hypr_whisper_local::Whisper,
-    guard: ConnectionGuard,
+    guard: Option<ConnectionGuard>,
     redemption_time: Duration,
+    connection: ConnectionMetrics,
 ) {
     let audio_source = hypr_ws_utils::WebSocketAudioSource::new(ws_receiver, 16 * 1000);
     let vad_chunks = audio_source.speech_chunks(redemption_time);
 
-    let chunked = hypr_whisper_local::AudioChunkStream(process_vad_stream(vad_chunks, "mixed"));
+    let chunked = hypr_whisper_local::AudioChunkStream(process_vad_stream(
+        vad_chunks,
+        "mixed",
+        connection.clone(),
+    ));
 
-    let stream = hypr_whisper_local::TranscribeMetadataAudioStreamExt::transcribe(chunked, model);
-    process_transcription_stream(ws_sender, stream, guard, 1).await;
+    let stream = Instrumented::new(
+        hypr_whisper_local::TranscribeMetadataAudioStreamExt::transcribe(chunked, model),
+        connection.clone(),
+    );
+    process_transcription_stream(ws_sender, stream, guard, 1, connection).await;
 }
 
 async fn handle_dual_channel(
     ws_sender: futures_util::stream::SplitSink<WebSocket, Message>,
     ws_receiver: futures_util::stream::SplitStream<WebSocket>,
     model: hypr_whisper_local::Whisper,
-    guard: ConnectionGuard,
+    guard: Option<ConnectionGuard>,
     redemption_time: Duration,
+    connection: ConnectionMetrics,
 ) {
     let (mic_source, speaker_source) =
         hypr_ws_utils::split_dual_audio_sources(ws_receiver, 16 * 1000);
 
     let mic_chunked = {
         let mic_vad_chunks = mic_source.speech_chunks(redemption_time);
-        hypr_whisper_local::AudioChunkStream(process_vad_stream(mic_vad_chunks, "mic"))
+        hypr_whisper_local::AudioChunkStream(process_vad_stream(
+            mic_vad_chunks,
+            "mic",
+            connection.clone(),
+        ))
     };
 
     let speaker_chunked = {
         let speaker_vad_chunks = speaker_source.speech_chunks(redemption_time);
-        hypr_whisper_local::AudioChunkStream(process_vad_stream(speaker_vad_chunks, "speaker"))
+        hypr_whisper_local::AudioChunkStream(process_vad_stream(
+            speaker_vad_chunks,
+            "speaker",
+            connection.clone(),
+        ))
     };
 
     let merged_stream = hypr_whisper_local::AudioChunkStream(futures_util::stream::select(
@@ -187,21 +259,31 @@ async fn handle_dual_channel(
         speaker_chunked.0,
     ));
 
-    let stream =
-        hypr_whisper_local::TranscribeMetadataAudioStreamExt::transcribe(merged_stream, model);
+    let stream = Instrumented::new(
+        hypr_whisper_local::TranscribeMetadataAudioStreamExt::transcribe(merged_stream, model),
+        connection.clone(),
+    );
 
-    process_transcription_stream(ws_sender, stream, guard, 2).await;
+    process_transcription_stream(ws_sender, stream, guard, 2, connection).await;
+}
+
+async fn cancelled(guard: &Option<ConnectionGuard>) {
+    match guard {
+        Some(guard) => guard.cancelled().await,
+        None => std::future::pending().await,
+    }
 }
 
 async fn process_transcription_stream(
     mut ws_sender: futures_util::stream::SplitSink<WebSocket, Message>,
     mut stream: impl futures_util::Stream<Item = hypr_whisper_local::Segment> + Unpin,
-    guard: ConnectionGuard,
+    guard: Option<ConnectionGuard>,
     channels: i32,
+    connection: ConnectionMetrics,
 ) {
     loop {
         tokio::select! {
-            _ = guard.cancelled() => {
+            _ = cancelled(&guard) => {
                 tracing::info!("websocket_cancelled_by_new_connection");
                 break;
             }
@@ -215,11 +297,12 @@ async fn process_transcription_stream(
                 let duration_f64 = chunk.duration() as f64;
                 let confidence = chunk.confidence() as f64;
 
-                let source = meta.and_then(|meta|
+                let source = meta.as_ref().and_then(|meta|
                     meta.get("source")
                         .and_then(|v| v.as_str())
                         .map(|s| s.to_string())
                 );
+                let end_ms = meta.as_ref().and_then(|meta| meta.get("end_ms").and_then(|v| v.as_u64()));
 
                 let (speaker, channel_index) = match source.as_deref() {
                     Some("mic") => (Some(0), vec![0, channels]),
@@ -265,6 +348,10 @@ async fn process_transcription_stream(
                     tracing::warn!("websocket_send_error: {}", e);
                     break;
                 }
+
+                if let Some(end_ms) = end_ms {
+                    connection.metrics.record_latency(connection.lag(end_ms));
+                }
             }
         }
     }
@@ -275,6 +362,7 @@ async fn process_transcription_stream(
 fn process_vad_stream<S, E>(
     stream: S,
     source_name: &str,
+    connection: ConnectionMetrics,
 ) -> impl futures_util::Stream<Item = hypr_whisper_local::SimpleAudioChunk>
 where
     S: futures_util::Stream<Item = Result<hypr_vad::AudioChunk, E>>,
@@ -295,10 +383,27 @@ where
         .filter_map(move |chunk_result| {
             futures_util::future::ready(match chunk_result {
                 Err(_) => None,
-                Ok(chunk) => Some(hypr_whisper_local::SimpleAudioChunk {
-                    samples: chunk.samples,
-                    meta: Some(serde_json::json!({ "source": source_name })),
-                }),
+                Ok(chunk) => {
+                    let end_ms = chunk.end_timestamp_ms as u64;
+
+                    if connection.too_far_behind(end_ms) {
+                        tracing::warn!(
+                            "speech_chunk_dropped: {} {}ms behind",
+                            source_name,
+                            connection.lag(end_ms).as_millis()
+                        );
+                        connection.metrics.record_dropped();
+                        None
+                    } else {
+                        connection.chunk_queued(chunk.samples.len(), 16 * 1000);
+                        Some(hypr_whisper_local::SimpleAudioChunk {
+                            samples: chunk.samples,
+                            meta: Some(
+                                serde_json::json!({ "source": source_name, "end_ms": end_ms }),
+                            ),
+                        })
+                    }
+                }
             })
         })
 }
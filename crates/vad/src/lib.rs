@@ -1,8 +1,12 @@
 mod continuous;
 mod error;
+mod meter;
+mod skip;
 
 pub use continuous::*;
 pub use error::*;
+pub use meter::*;
+pub use skip::*;
 
 #[cfg(test)]
 pub mod tests {
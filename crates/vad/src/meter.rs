@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use silero_rs::{VadConfig, VadSession, VadTransition};
+
+/// Keeps a running total of how much of a stream is speech, without buffering the audio
+/// for later use like [`crate::ContinuousVadStream`] does.
+pub struct SpeechMeter {
+    session: VadSession,
+    sample_rate: usize,
+    chunk_samples: usize,
+    buffer: Vec<f32>,
+    processed_samples: usize,
+    speech_ms: usize,
+    speech_start_ms: Option<usize>,
+}
+
+impl SpeechMeter {
+    pub fn new(sample_rate: usize) -> Result<Self, crate::Error> {
+        let config = VadConfig {
+            sample_rate,
+            ..Default::default()
+        };
+
+        // Same frame size as `ContinuousVadStream`.
+        let chunk_samples = (Duration::from_millis(30).as_secs_f64() * sample_rate as f64) as usize;
+
+        Ok(Self {
+            session: VadSession::new(config).map_err(|_| crate::Error::VadSessionCreationFailed)?,
+            sample_rate,
+            chunk_samples,
+            buffer: Vec::with_capacity(chunk_samples),
+            processed_samples: 0,
+            speech_ms: 0,
+            speech_start_ms: None,
+        })
+    }
+
+    pub fn push(&mut self, samples: &[f32]) -> Result<(), crate::Error> {
+        self.buffer.extend_from_slice(samples);
+
+        while self.buffer.len() >= self.chunk_samples {
+            let chunk: Vec<f32> = self.buffer.drain(..self.chunk_samples).collect();
+            self.processed_samples += chunk.len();
+
+            let transitions = self
+                .session
+                .process(&chunk)
+                .map_err(|e| crate::Error::VadProcessingFailed(e.to_string()))?;
+
+            for transition in transitions {
+                match transition {
+                    VadTransition::SpeechStart { timestamp_ms } => {
+                        self.speech_start_ms = Some(timestamp_ms);
+                    }
+                    VadTransition::SpeechEnd {
+                        start_timestamp_ms,
+                        end_timestamp_ms,
+                        ..
+                    } => {
+                        self.speech_start_ms = None;
+                        self.speech_ms += end_timestamp_ms.saturating_sub(start_timestamp_ms);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn total_ms(&self) -> usize {
+        self.processed_samples * 1000 / self.sample_rate
+    }
+
+    /// Includes the segment still in progress, if any.
+    pub fn speech_ms(&self) -> usize {
+        let ongoing = self
+            .speech_start_ms
+            .map_or(0, |start| self.total_ms().saturating_sub(start));
+        self.speech_ms + ongoing
+    }
+}
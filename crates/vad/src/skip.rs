@@ -0,0 +1,177 @@
+use std::{ops::Range, time::Duration};
+
+use silero_rs::{VadConfig, VadSession, VadTransition};
+
+// Left around each stretch of speech, so cuts don't clip the first or last word.
+const PADDING: Duration = Duration::from_millis(300);
+
+/// Audio with its long silences cut out, and the way back to the original timeline.
+pub struct SilenceSkipped {
+    pub samples: Vec<f32>,
+    pub timeline: SkipTimeline,
+}
+
+/// Maps positions in audio that had silences cut out back to the recording they came from.
+#[derive(Debug, Clone)]
+pub struct SkipTimeline {
+    sample_rate: usize,
+    // Where each kept span starts, in the cut audio and in the original, in order.
+    spans: Vec<(usize, usize)>,
+}
+
+impl SkipTimeline {
+    pub fn original_secs(&self, secs: f64) -> f64 {
+        let pos = (secs * self.sample_rate as f64).max(0.0);
+
+        let (cut, original) = self
+            .spans
+            .iter()
+            .rev()
+            .find(|(cut, _)| *cut as f64 <= pos)
+            .copied()
+            .unwrap_or((0, 0));
+
+        (original as f64 + pos - cut as f64) / self.sample_rate as f64
+    }
+}
+
+/// Cuts every silence of at least `min_silence` out of a recording, for backends that bill
+/// or take time by the second. Shorter pauses stay, since they carry sentence boundaries.
+pub fn skip_silence(
+    samples: &[f32],
+    sample_rate: usize,
+    min_silence: Duration,
+) -> Result<SilenceSkipped, crate::Error> {
+    let speech = speech_spans(samples, sample_rate)?;
+    let keep = spans_to_keep(
+        &speech,
+        samples.len(),
+        to_samples(min_silence, sample_rate),
+        to_samples(PADDING, sample_rate),
+    );
+
+    let mut output = Vec::with_capacity(keep.iter().map(|range| range.len()).sum());
+    let mut spans = Vec::with_capacity(keep.len());
+
+    for range in keep {
+        spans.push((output.len(), range.start));
+        output.extend_from_slice(&samples[range]);
+    }
+
+    Ok(SilenceSkipped {
+        samples: output,
+        timeline: SkipTimeline { sample_rate, spans },
+    })
+}
+
+fn to_samples(duration: Duration, sample_rate: usize) -> usize {
+    (duration.as_secs_f64() * sample_rate as f64) as usize
+}
+
+fn speech_spans(samples: &[f32], sample_rate: usize) -> Result<Vec<Range<usize>>, crate::Error> {
+    let mut session = VadSession::new(VadConfig {
+        sample_rate,
+        ..Default::default()
+    })
+    .map_err(|_| crate::Error::VadSessionCreationFailed)?;
+
+    // Same frame size as `ContinuousVadStream`.
+    let chunk_samples = to_samples(Duration::from_millis(30), sample_rate);
+    let at = |ms: usize| (ms * sample_rate / 1000).min(samples.len());
+
+    let mut spans = Vec::new();
+    let mut ongoing = None;
+
+    for chunk in samples.chunks(chunk_samples) {
+        let mut chunk = chunk.to_vec();
+        chunk.resize(chunk_samples, 0.0);
+
+        let transitions = session
+            .process(&chunk)
+            .map_err(|e| crate::Error::VadProcessingFailed(e.to_string()))?;
+
+        for transition in transitions {
+            match transition {
+                VadTransition::SpeechStart { timestamp_ms } => ongoing = Some(timestamp_ms),
+                VadTransition::SpeechEnd {
+                    start_timestamp_ms,
+                    end_timestamp_ms,
+                    ..
+                } => {
+                    ongoing = None;
+                    spans.push(at(start_timestamp_ms)..at(end_timestamp_ms));
+                }
+            }
+        }
+    }
+
+    if let Some(start) = ongoing {
+        spans.push(at(start)..samples.len());
+    }
+
+    Ok(spans)
+}
+
+/// Pads each stretch of speech, then joins the ones less than `min_gap` apart.
+fn spans_to_keep(
+    speech: &[Range<usize>],
+    len: usize,
+    min_gap: usize,
+    padding: usize,
+) -> Vec<Range<usize>> {
+    let mut keep: Vec<Range<usize>> = Vec::new();
+
+    for span in speech {
+        let start = span.start.saturating_sub(padding);
+        let end = (span.end + padding).min(len);
+
+        match keep.last_mut() {
+            Some(last) if start < last.end + min_gap => last.end = last.end.max(end),
+            _ => keep.push(start..end),
+        }
+    }
+
+    if let Some(first) = keep.first_mut() {
+        if first.start < min_gap {
+            first.start = 0;
+        }
+    }
+    if let Some(last) = keep.last_mut() {
+        if len - last.end < min_gap {
+            last.end = len;
+        }
+    }
+
+    keep
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spans_to_keep() {
+        let speech = [100..200, 250..300, 1000..1100];
+        assert_eq!(
+            spans_to_keep(&speech, 2000, 50, 10),
+            vec![90..310, 990..1110]
+        );
+
+        // Silence too short to cut at either end is kept.
+        assert_eq!(spans_to_keep(&[50..1950], 2000, 100, 10), vec![0..2000]);
+        assert!(spans_to_keep(&[], 2000, 100, 10).is_empty());
+    }
+
+    #[test]
+    fn test_timeline() {
+        let timeline = SkipTimeline {
+            sample_rate: 10,
+            spans: vec![(0, 10), (20, 100)],
+        };
+
+        assert_eq!(timeline.original_secs(0.0), 1.0);
+        assert_eq!(timeline.original_secs(1.5), 2.5);
+        assert_eq!(timeline.original_secs(2.0), 10.0);
+        assert_eq!(timeline.original_secs(2.5), 10.5);
+    }
+}
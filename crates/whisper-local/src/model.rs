@@ -18,6 +18,8 @@ lazy_static! {
 pub struct WhisperBuilder {
     model_path: Option<String>,
     languages: Option<Vec<Language>>,
+    translate: bool,
+    keywords: Vec<String>,
 }
 
 impl WhisperBuilder {
@@ -31,6 +33,18 @@ impl WhisperBuilder {
         self
     }
 
+    /// Output English text regardless of the spoken language.
+    pub fn translate(mut self, translate: bool) -> Self {
+        self.translate = translate;
+        self
+    }
+
+    /// Names and terms to steer spelling towards, passed ahead of each chunk's prompt.
+    pub fn keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
     pub fn build(self) -> Result<Whisper, crate::Error> {
         unsafe { Self::suppress_log() };
 
@@ -56,6 +70,8 @@ impl WhisperBuilder {
             id: uuid::Uuid::new_v4().to_string(),
             index: 0,
             languages: self.languages.unwrap_or_default(),
+            translate: self.translate,
+            keyword_prompt: self.keywords.join(", "),
             dynamic_prompt: "".to_string(),
             state,
             token_beg,
@@ -79,6 +95,8 @@ pub struct Whisper {
     #[allow(dead_code)]
     index: usize,
     languages: Vec<Language>,
+    translate: bool,
+    keyword_prompt: String,
     dynamic_prompt: String,
     state: WhisperState,
     token_beg: WhisperTokenId,
@@ -105,13 +123,13 @@ impl Whisper {
         let params = {
             let mut p = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
 
-            let parts = [self.dynamic_prompt.trim()];
+            let parts = [self.keyword_prompt.trim(), self.dynamic_prompt.trim()];
             let joined = parts.join("\n");
             let initial_prompt = joined.trim();
 
             tracing::info!(input_audio_length_sec = ?input_audio_length_sec, "transcribe_started");
 
-            p.set_translate(false);
+            p.set_translate(self.translate);
             p.set_detect_language(false);
             p.set_language(language.as_deref());
 
@@ -140,6 +158,13 @@ impl Whisper {
         };
 
         self.state.full(params, &audio[..])?;
+
+        // Whisper only translates into English.
+        let output_language = if self.translate {
+            Some("en".to_string())
+        } else {
+            language
+        };
         let num_segments = self.state.full_n_segments();
 
         let mut segments = Vec::new();
@@ -161,7 +186,7 @@ impl Whisper {
 
             segments.push(Segment {
                 text,
-                language: language.clone(),
+                language: output_language.clone(),
                 start,
                 end,
                 // https://github.com/ggml-org/whisper.cpp/pull/971/files#diff-2d3599a9fad195f2c3c60bd06691bc1815325b3560b5feda41a91fa71194e805R310-R327
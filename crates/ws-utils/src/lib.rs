@@ -5,7 +5,7 @@ use axum::extract::ws::{Message, WebSocket};
 use futures_util::{stream::SplitStream, Stream, StreamExt};
 use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
 
-use hypr_audio_utils::bytes_to_f32_samples;
+use hypr_audio_utils::{bytes_to_f32_samples, deinterleave_i16_le, mix_f32};
 use owhisper_interface::ListenInputChunk;
 
 enum AudioProcessResult {
@@ -15,23 +15,6 @@ enum AudioProcessResult {
     End,
 }
 
-fn deinterleave_audio(data: &[u8]) -> (Vec<f32>, Vec<f32>) {
-    let samples: Vec<i16> = data
-        .chunks_exact(2)
-        .map(|chunk| i16::from_le_bytes([chunk[0], chunk[1]]))
-        .collect();
-
-    let mut mic = Vec::with_capacity(samples.len() / 2);
-    let mut speaker = Vec::with_capacity(samples.len() / 2);
-
-    for chunk in samples.chunks_exact(2) {
-        mic.push(chunk[0] as f32 / 32768.0);
-        speaker.push(chunk[1] as f32 / 32768.0);
-    }
-
-    (mic, speaker)
-}
-
 fn process_ws_message(message: Message, channels: Option<u32>) -> AudioProcessResult {
     match message {
         Message::Binary(data) => {
@@ -41,7 +24,7 @@ fn process_ws_message(message: Message, channels: Option<u32>) -> AudioProcessRe
 
             match channels {
                 Some(2) => {
-                    let (mic, speaker) = deinterleave_audio(&data);
+                    let (mic, speaker) = deinterleave_i16_le(&data);
                     AudioProcessResult::DualSamples { mic, speaker }
                 }
                 _ => AudioProcessResult::Samples(bytes_to_f32_samples(&data)),
@@ -67,17 +50,6 @@ fn process_ws_message(message: Message, channels: Option<u32>) -> AudioProcessRe
     }
 }
 
-fn mix_audio_channels(mic: &[f32], speaker: &[f32]) -> Vec<f32> {
-    let max_len = mic.len().max(speaker.len());
-    (0..max_len)
-        .map(|i| {
-            let mic_sample = mic.get(i).copied().unwrap_or(0.0);
-            let speaker_sample = speaker.get(i).copied().unwrap_or(0.0);
-            (mic_sample + speaker_sample).clamp(-1.0, 1.0)
-        })
-        .collect()
-}
-
 pub struct WebSocketAudioSource {
     receiver: Option<SplitStream<WebSocket>>,
     sample_rate: u32,
@@ -101,7 +73,7 @@ impl kalosm_sound::AsyncSource for WebSocketAudioSource {
                 Some(Ok(message)) => match process_ws_message(message, None) {
                     AudioProcessResult::Samples(samples) => Some((samples, receiver)),
                     AudioProcessResult::DualSamples { mic, speaker } => {
-                        let mixed = mix_audio_channels(&mic, &speaker);
+                        let mixed = mix_f32(&mic, &speaker);
                         Some((mixed, receiver))
                     }
                     AudioProcessResult::Empty => Some((Vec::new(), receiver)),
@@ -38,3 +38,14 @@ impl ConnectionGuard {
         self.token.cancelled().await
     }
 }
+
+/// Kept alive for as long as a session's socket is open. Servers that cap concurrent sessions
+/// put one in the upgrade request's extensions, and services hold it until the socket closes.
+#[derive(Clone)]
+pub struct SessionPermit(Arc<dyn std::any::Any + Send + Sync>);
+
+impl SessionPermit {
+    pub fn new<T: Send + Sync + 'static>(inner: T) -> Self {
+        Self(Arc::new(inner))
+    }
+}
@@ -3,7 +3,7 @@ use serde::de::DeserializeOwned;
 use backon::{ConstantBuilder, Retryable};
 use futures_util::{SinkExt, Stream, StreamExt};
 use tokio_tungstenite::{
-    connect_async,
+    client_async_tls,
     tungstenite::{client::IntoClientRequest, Utf8Bytes},
 };
 
@@ -153,11 +153,20 @@ impl WebSocketClient {
         crate::Error,
     > {
         let req = req.into_client_request().unwrap();
+        let url: url::Url = req.uri().to_string().parse().unwrap();
 
         tracing::info!("connect_async: {:?}", req.uri());
 
+        // Goes through the proxy from settings or the environment, if one applies.
+        let connect = async {
+            let stream = hypr_proxy::connect(&url)
+                .await
+                .map_err(tokio_tungstenite::tungstenite::Error::Io)?;
+            client_async_tls(req, stream).await
+        };
+
         let (ws_stream, _) =
-            tokio::time::timeout(std::time::Duration::from_secs(8), connect_async(req)).await??;
+            tokio::time::timeout(std::time::Duration::from_secs(8), connect).await??;
 
         Ok(ws_stream)
     }
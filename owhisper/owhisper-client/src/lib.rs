@@ -3,29 +3,6 @@ use futures_util::Stream;
 use hypr_ws::client::{ClientRequestBuilder, Message, WebSocketClient, WebSocketIO};
 use owhisper_interface::{ControlMessage, MixedMessage, StreamResponse};
 
-fn interleave_audio(mic: &[u8], speaker: &[u8]) -> Vec<u8> {
-    let mic_samples: Vec<i16> = mic
-        .chunks_exact(2)
-        .map(|chunk| i16::from_le_bytes([chunk[0], chunk[1]]))
-        .collect();
-    let speaker_samples: Vec<i16> = speaker
-        .chunks_exact(2)
-        .map(|chunk| i16::from_le_bytes([chunk[0], chunk[1]]))
-        .collect();
-
-    let max_len = mic_samples.len().max(speaker_samples.len());
-    let mut interleaved = Vec::with_capacity(max_len * 2 * 2);
-
-    for i in 0..max_len {
-        let mic_sample = mic_samples.get(i).copied().unwrap_or(0);
-        let speaker_sample = speaker_samples.get(i).copied().unwrap_or(0);
-        interleaved.extend_from_slice(&mic_sample.to_le_bytes());
-        interleaved.extend_from_slice(&speaker_sample.to_le_bytes());
-    }
-
-    interleaved
-}
-
 #[derive(Default)]
 pub struct ListenClientBuilder {
     api_base: Option<String>,
@@ -104,6 +81,26 @@ impl ListenClientBuilder {
                     "redemption_time_ms",
                     &params.redemption_time_ms.unwrap_or(400).to_string(),
                 );
+
+            // https://developers.deepgram.com/docs/keyterm
+            for keyterm in &params.keyterms {
+                query_pairs.append_pair("keyterm", keyterm);
+            }
+
+            // https://developers.deepgram.com/docs/keywords
+            for keyword in &params.keywords {
+                query_pairs.append_pair("keywords", keyword);
+            }
+
+            // https://developers.deepgram.com/docs/diarization
+            if params.diarize {
+                query_pairs.append_pair("diarize", "true");
+            }
+
+            // Not a Deepgram parameter; only whisper-cpp honors it.
+            if params.task == owhisper_interface::ListenTask::Translate {
+                query_pairs.append_pair("task", params.task.as_ref());
+            }
         }
 
         let host = url.host_str().unwrap();
@@ -189,7 +186,7 @@ impl WebSocketIO for ListenClientDual {
     fn to_input(data: Self::Data) -> Self::Input {
         match data {
             ListenClientDualInput::Audio((mic, speaker)) => {
-                let interleaved = interleave_audio(&mic, &speaker);
+                let interleaved = hypr_audio_utils::interleave_i16_le(&mic, &speaker);
                 ListenClientInput::Audio(interleaved.into())
             }
             ListenClientDualInput::Control(control) => ListenClientInput::Control(control),
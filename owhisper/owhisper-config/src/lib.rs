@@ -25,12 +25,18 @@ common_derives! {
     pub enum ModelConfig {
         #[serde(rename = "aws")]
         Aws(AwsModelConfig),
+        #[serde(rename = "azure")]
+        Azure(AzureModelConfig),
         #[serde(rename = "deepgram")]
         Deepgram(DeepgramModelConfig),
+        #[serde(rename = "google")]
+        Google(GoogleModelConfig),
         #[serde(rename = "whisper-cpp")]
         WhisperCpp(WhisperCppModelConfig),
         #[serde(rename = "moonshine")]
         Moonshine(MoonshineModelConfig),
+        #[serde(rename = "kyutai")]
+        Kyutai(KyutaiModelConfig),
     }
 }
 
@@ -38,9 +44,22 @@ impl ModelConfig {
     pub fn id(&self) -> &str {
         match self {
             ModelConfig::Aws(config) => &config.id,
+            ModelConfig::Azure(config) => &config.id,
             ModelConfig::Deepgram(config) => &config.id,
+            ModelConfig::Google(config) => &config.id,
             ModelConfig::WhisperCpp(config) => &config.id,
             ModelConfig::Moonshine(config) => &config.id,
+            ModelConfig::Kyutai(config) => &config.id,
+        }
+    }
+
+    /// Only local models are limited; hosted ones answer to their provider's quotas.
+    pub fn max_concurrent_sessions(&self) -> Option<std::num::NonZeroUsize> {
+        match self {
+            ModelConfig::WhisperCpp(config) => config.max_concurrent_sessions,
+            ModelConfig::Moonshine(config) => config.max_concurrent_sessions,
+            ModelConfig::Kyutai(config) => config.max_concurrent_sessions,
+            _ => None,
         }
     }
 }
@@ -79,6 +98,14 @@ common_derives! {
     #[derive(Default)]
     pub struct GeneralConfig {
         pub api_key: Option<String>,
+        /// Address `serve` listens on. Defaults to `127.0.0.1`; `0.0.0.0` serves the whole network,
+        /// which requires `api_key`.
+        pub host: Option<String>,
+        /// Port `serve` listens on. A free one is picked if unset.
+        pub port: Option<u16>,
+        /// Origins browsers may call the server from, like `http://localhost:3000`.
+        /// Browsers can't call the server if unset; `*` allows any origin.
+        pub cors_origins: Option<Vec<String>>,
     }
 }
 
@@ -91,6 +118,15 @@ common_derives! {
     }
 }
 
+common_derives! {
+    pub struct AzureModelConfig {
+        pub id: String,
+        /// Speech resource region, like `eastus` or `westeurope`.
+        pub region: String,
+        pub api_key: String,
+    }
+}
+
 common_derives! {
     #[derive(Default)]
     pub struct DeepgramModelConfig {
@@ -100,10 +136,28 @@ common_derives! {
     }
 }
 
+common_derives! {
+    #[derive(Default)]
+    pub struct GoogleModelConfig {
+        pub id: String,
+        /// Service account key file. Application default credentials are used when neither
+        /// this nor `api_key` is set.
+        pub credentials_path: Option<String>,
+        pub api_key: Option<String>,
+        /// Regional endpoint, like `eu` or `us`. Global when unset.
+        pub region: Option<String>,
+        /// Recognition model, like `latest_long`. Google picks one when unset.
+        pub model: Option<String>,
+    }
+}
+
 common_derives! {
     pub struct WhisperCppModelConfig {
         pub id: String,
         pub assets_dir: String,
+        /// Sessions this model serves at once. Past it, new ones are turned away until one ends.
+        /// Unlimited if unset.
+        pub max_concurrent_sessions: Option<std::num::NonZeroUsize>,
     }
 }
 
@@ -112,6 +166,19 @@ common_derives! {
         pub id: String,
         pub size: MoonshineModelSize,
         pub assets_dir: String,
+        /// Sessions this model serves at once. Past it, new ones are turned away until one ends.
+        /// Unlimited if unset.
+        pub max_concurrent_sessions: Option<std::num::NonZeroUsize>,
+    }
+}
+
+common_derives! {
+    pub struct KyutaiModelConfig {
+        pub id: String,
+        pub assets_dir: String,
+        /// Sessions this model serves at once. Past it, new ones are turned away until one ends.
+        /// Unlimited if unset.
+        pub max_concurrent_sessions: Option<std::num::NonZeroUsize>,
     }
 }
 
@@ -0,0 +1,129 @@
+use crate::{common_derives, Alternatives, Channel, Word};
+
+// https://developers.deepgram.com/reference/speech-to-text-api/listen
+
+common_derives! {
+    pub struct Metadata {
+        pub request_id: String,
+        pub created: String,
+        pub duration: f64,
+        pub channels: u32,
+    }
+}
+
+common_derives! {
+    pub struct Results {
+        pub channels: Vec<Channel>,
+    }
+}
+
+common_derives! {
+    pub struct Response {
+        pub metadata: Metadata,
+        pub results: Results,
+    }
+}
+
+impl Response {
+    /// `channels` holds the words recognized in each input channel, in order.
+    pub fn new(duration: f64, channels: Vec<Vec<Word>>) -> Self {
+        Self {
+            metadata: Metadata {
+                request_id: uuid::Uuid::new_v4().to_string(),
+                created: chrono::Utc::now().to_rfc3339(),
+                duration,
+                channels: channels.len() as u32,
+            },
+            results: Results {
+                channels: channels
+                    .into_iter()
+                    .map(|words| Channel {
+                        alternatives: vec![alternatives(words)],
+                    })
+                    .collect(),
+            },
+        }
+    }
+}
+
+fn alternatives(words: Vec<Word>) -> Alternatives {
+    let transcript = words
+        .iter()
+        .map(|w| w.punctuated_word.as_deref().unwrap_or(&w.word))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let confidence = if words.is_empty() {
+        0.0
+    } else {
+        words.iter().map(|w| w.confidence).sum::<f64>() / words.len() as f64
+    };
+
+    Alternatives {
+        transcript,
+        words,
+        confidence,
+        languages: vec![],
+    }
+}
+
+/// Splits a segment's text into words sharing its span evenly. Local models only time
+/// whole segments, so this is as close as their output gets to word timings.
+pub fn spread_words(
+    text: &str,
+    start: f64,
+    end: f64,
+    confidence: f64,
+    speaker: Option<i32>,
+) -> Vec<Word> {
+    let tokens = text.split_whitespace().collect::<Vec<_>>();
+    let step = (end - start).max(0.0) / tokens.len().max(1) as f64;
+
+    tokens
+        .into_iter()
+        .enumerate()
+        .map(|(i, token)| Word {
+            word: token.to_string(),
+            start: start + step * i as f64,
+            end: start + step * (i + 1) as f64,
+            confidence,
+            speaker,
+            punctuated_word: None,
+            language: None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spread_words() {
+        let words = spread_words(" hello there  world ", 1.0, 2.5, 0.9, Some(1));
+        assert_eq!(words.len(), 3);
+        assert_eq!(words[0].word, "hello");
+        assert_eq!((words[0].start, words[0].end), (1.0, 1.5));
+        assert_eq!((words[2].start, words[2].end), (2.0, 2.5));
+        assert!(words.iter().all(|w| w.speaker == Some(1)));
+
+        assert!(spread_words("  ", 0.0, 1.0, 1.0, None).is_empty());
+    }
+
+    #[test]
+    fn test_response() {
+        let response = Response::new(
+            2.5,
+            vec![spread_words("hello world", 0.0, 1.0, 0.5, None), vec![]],
+        );
+
+        assert_eq!(response.metadata.channels, 2);
+        let first = &response.results.channels[0].alternatives[0];
+        assert_eq!(first.transcript, "hello world");
+        assert_eq!(first.confidence, 0.5);
+        assert_eq!(response.results.channels[1].alternatives[0].transcript, "");
+
+        let serialized = serde_json::to_string(&response).unwrap();
+        let _: Response = serde_json::from_str(&serialized).unwrap();
+    }
+}
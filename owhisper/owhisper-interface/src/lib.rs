@@ -1,3 +1,5 @@
+pub mod batch;
+
 mod stream;
 pub use stream::*;
 
@@ -117,18 +119,59 @@ impl Default for AudioMode {
     }
 }
 
+common_derives! {
+    #[derive(strum::AsRefStr)]
+    pub enum ListenTask {
+        #[serde(rename = "transcribe")]
+        #[strum(serialize = "transcribe")]
+        Transcribe,
+        /// Transcribe into English, whatever the spoken language. Whisper only.
+        #[serde(rename = "translate")]
+        #[strum(serialize = "translate")]
+        Translate,
+    }
+}
+
+impl Default for ListenTask {
+    fn default() -> Self {
+        ListenTask::Transcribe
+    }
+}
+
 common_derives! {
     pub struct ListenParams {
         #[serde(default)]
         pub model: Option<String>,
+        #[serde(default = "default_channels")]
         pub channels: u8,
         // https://docs.rs/axum-extra/0.10.1/axum_extra/extract/struct.Query.html#example-1
         #[serde(default)]
         pub languages: Vec<hypr_language::Language>,
         pub redemption_time_ms: Option<u64>,
+        /// Names and jargon to bias recognition towards, where the backend supports it.
+        // Clients send it the way Deepgram spells it.
+        #[serde(default, alias = "keyterm")]
+        pub keyterms: Vec<String>,
+        /// Words to boost, optionally weighted as `word:weight` like Deepgram's `keywords`.
+        /// Whisper ignores the weights and prompts with these words along with `keyterms`.
+        #[serde(default)]
+        pub keywords: Vec<String>,
+        /// Tell speakers apart within a channel, where the backend supports it.
+        #[serde(default)]
+        pub diarize: bool,
+        /// Cut long silences out before transcribing. Only applies to recorded audio;
+        /// timestamps still refer to the original recording.
+        #[serde(default)]
+        pub skip_silence: bool,
+        #[serde(default)]
+        pub task: ListenTask,
     }
 }
 
+fn default_channels() -> u8 {
+    1
+}
+
 impl Default for ListenParams {
     fn default() -> Self {
         ListenParams {
@@ -136,7 +179,44 @@ impl Default for ListenParams {
             channels: 1,
             languages: vec![],
             redemption_time_ms: None,
+            keyterms: vec![],
+            keywords: vec![],
+            diarize: false,
+            skip_silence: false,
+            task: ListenTask::Transcribe,
+        }
+    }
+}
+
+impl ListenParams {
+    /// `keywords` split into the word and its weight, if one was given.
+    pub fn weighted_keywords(&self) -> impl Iterator<Item = (&str, Option<f64>)> {
+        self.keywords
+            .iter()
+            .map(|keyword| match keyword.rsplit_once(':') {
+                Some((word, weight)) => match weight.trim().parse::<f64>() {
+                    Ok(weight) => (word.trim(), Some(weight)),
+                    Err(_) => (keyword.trim(), None),
+                },
+                None => (keyword.trim(), None),
+            })
+            .filter(|(word, _)| !word.is_empty())
+    }
+
+    /// `keyterms` and the words of `keywords`, for backends that take a plain list.
+    pub fn bias_terms(&self) -> Vec<String> {
+        let mut terms: Vec<String> = vec![];
+        let words = self
+            .keyterms
+            .iter()
+            .map(|term| term.trim())
+            .chain(self.weighted_keywords().map(|(word, _)| word));
+        for word in words {
+            if !word.is_empty() && !terms.iter().any(|term| term == word) {
+                terms.push(word.to_string());
+            }
         }
+        terms
     }
 }
 
@@ -166,3 +246,38 @@ pub struct DiarizationChunk {
     pub speaker: i32,
     pub confidence: Option<f32>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(keyterms: &[&str], keywords: &[&str]) -> ListenParams {
+        ListenParams {
+            keyterms: keyterms.iter().map(|s| s.to_string()).collect(),
+            keywords: keywords.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_weighted_keywords() {
+        let params = params(&[], &["a:2", "a:b", ":3", " Hyprnote ", "x:-1.5"]);
+
+        assert_eq!(
+            params.weighted_keywords().collect::<Vec<_>>(),
+            vec![
+                ("a", Some(2.0)),
+                ("a:b", None),
+                ("Hyprnote", None),
+                ("x", Some(-1.5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bias_terms() {
+        let params = params(&["Hyprnote", " "], &["Hyprnote:2", "Yujong:1"]);
+
+        assert_eq!(params.bias_terms(), vec!["Hyprnote", "Yujong"]);
+    }
+}
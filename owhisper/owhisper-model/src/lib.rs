@@ -44,6 +44,9 @@ pub enum Model {
     #[serde(rename = "moonshine-onnx-base-q8")]
     #[strum(serialize = "moonshine-onnx-base-q8")]
     MoonshineOnnxBaseQ8,
+    #[serde(rename = "kyutai-stt-1b-en-fr")]
+    #[strum(serialize = "kyutai-stt-1b-en-fr")]
+    KyutaiStt1bEnFr,
 }
 
 impl Model {
@@ -88,20 +91,60 @@ impl TryFrom<Model> for HyprWhisper {
             Model::MoonshineOnnxBase => Err(Error::NotSupported),
             Model::MoonshineOnnxBaseQ4 => Err(Error::NotSupported),
             Model::MoonshineOnnxBaseQ8 => Err(Error::NotSupported),
+            Model::KyutaiStt1bEnFr => Err(Error::NotSupported),
         }
     }
 }
 
+// The CDN sits in front of the bucket, so either serves every asset under the same path.
+const CDN_BASE_URL: &str = "https://storage2.hyprnote.com/";
+const BUCKET_BASE_URL: &str = "https://hyprnote.s3.us-east-1.amazonaws.com/";
+
 #[derive(Clone)]
 pub struct Asset {
     pub name: String,
     pub url: String,
+    /// Tried in order when `url` fails.
+    pub mirrors: Vec<String>,
     pub size: u64,
     pub checksum: u32,
 }
 
+impl Asset {
+    /// `url` followed by its mirrors.
+    pub fn urls(&self) -> Vec<String> {
+        std::iter::once(self.url.clone())
+            .chain(self.mirrors.iter().cloned())
+            .collect()
+    }
+
+    fn with_default_mirrors(mut self) -> Self {
+        let mirror = if let Some(path) = self.url.strip_prefix(CDN_BASE_URL) {
+            Some(format!("{}{}", BUCKET_BASE_URL, path))
+        } else {
+            self.url
+                .strip_prefix(BUCKET_BASE_URL)
+                .map(|path| format!("{}{}", CDN_BASE_URL, path))
+        };
+
+        if let Some(mirror) = mirror {
+            if !self.mirrors.contains(&mirror) {
+                self.mirrors.push(mirror);
+            }
+        }
+        self
+    }
+}
+
 impl Model {
     pub fn assets(&self) -> Vec<Asset> {
+        self.known_assets()
+            .into_iter()
+            .map(Asset::with_default_mirrors)
+            .collect()
+    }
+
+    fn known_assets(&self) -> Vec<Asset> {
         match self {
             Model::WhisperCppTinyQ8
             | Model::WhisperCppTinyQ8En
@@ -117,6 +160,7 @@ impl Model {
                     url: hypr_model.model_url().to_string(),
                     size: hypr_model.model_size_bytes(),
                     checksum: hypr_model.checksum(),
+                    mirrors: vec![],
                 }]
             }
 
@@ -127,18 +171,21 @@ impl Model {
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/tokenizer.json".to_string(),
                         size: 1985530,
                         checksum: 1800591672,
+                        mirrors: vec![],
                     },
                     Asset {
                         name: "encoder_model.onnx".to_string(),
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/onnx/merged/base/float/encoder_model.onnx".to_string(),
                         size: 80818781,
                         checksum: 4261777944,
+                        mirrors: vec![],
                     },
                     Asset {
                         name: "decoder_model_merged.onnx".to_string(),
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/onnx/merged/base/float/decoder_model_merged.onnx".to_string(),
                         size: 166211345,
                         checksum: 4284499744,
+                        mirrors: vec![],
                     },
                 ]
             }
@@ -149,18 +196,21 @@ impl Model {
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/tokenizer.json".to_string(),
                         size: 1985530,
                         checksum: 1800591672,
+                        mirrors: vec![],
                     },
                     Asset {
                         name: "encoder_model.onnx".to_string(),
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/onnx/merged/base/quantized/encoder_model.onnx".to_string(),
                         size: 20513063,
                         checksum: 2520442982,
+                        mirrors: vec![],
                     },
                     Asset {
                         name: "decoder_model_merged.onnx".to_string(),
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/onnx/merged/base/quantized/decoder_model_merged.onnx".to_string(),
                         size: 42498870,
                         checksum: 4007751459,
+                        mirrors: vec![],
                     },
                 ]
             }
@@ -171,18 +221,21 @@ impl Model {
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/tokenizer.json".to_string(),
                         size: 1985530,
                         checksum: 1800591672,
+                        mirrors: vec![],
                     },
                     Asset {
                         name: "encoder_model.onnx".to_string(),
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/onnx/merged/base/quantized_4bit/encoder_model.onnx".to_string(),
                         size: 31027744,
                         checksum: 1761974521,
+                        mirrors: vec![],
                     },
                     Asset {
                         name: "decoder_model_merged.onnx".to_string(),
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/onnx/merged/base/quantized_4bit/decoder_model_merged.onnx".to_string(),
                         size: 42427308,
                         checksum: 1460870890,
+                        mirrors: vec![],
                     },
                 ]
             }
@@ -193,18 +246,21 @@ impl Model {
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/tokenizer.json".to_string(),
                         size: 1985530,
                         checksum: 1800591672,
+                        mirrors: vec![],
                     },
                     Asset {
                         name: "encoder_model.onnx".to_string(),
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/onnx/merged/tiny/float/encoder_model.onnx".to_string(),
                         size: 30882331,
                         checksum: 3259662431,
+                        mirrors: vec![],
                     },
                     Asset {
                         name: "decoder_model_merged.onnx".to_string(),
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/onnx/merged/tiny/float/decoder_model_merged.onnx".to_string(),
                         size: 78227550,
                         checksum: 2598806900,
+                        mirrors: vec![],
                     },
                 ]
             }
@@ -215,18 +271,21 @@ impl Model {
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/tokenizer.json".to_string(),
                         size: 1985530,
                         checksum: 1800591672,
+                        mirrors: vec![],
                     },
                     Asset {
                         name: "encoder_model.onnx".to_string(),
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/onnx/merged/tiny/quantized_4bit/encoder_model.onnx".to_string(),
                         size: 13003282,
                         checksum: 26504769,
+                        mirrors: vec![],
                     },
                     Asset {
                         name: "decoder_model_merged.onnx".to_string(),
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/onnx/merged/tiny/quantized_4bit/decoder_model_merged.onnx".to_string(),
                         size: 20189543,
                         checksum: 158090752,
+                        mirrors: vec![],
                     },
                 ]
             }
@@ -237,21 +296,34 @@ impl Model {
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/tokenizer.json".to_string(),
                         size: 1985530,
                         checksum: 1800591672,
+                        mirrors: vec![],
                     },
                     Asset {
                         name: "encoder_model.onnx".to_string(),
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/onnx/merged/tiny/quantized/encoder_model.onnx".to_string(),
                         size: 7937661,
                         checksum: 633860095,
+                        mirrors: vec![],
                     },
                     Asset {
                         name: "decoder_model_merged.onnx".to_string(),
                         url: "https://storage2.hyprnote.com/v0/UsefulSensors/moonshine/onnx/merged/tiny/quantized/decoder_model_merged.onnx".to_string(),
                         size: 20243286,
                         checksum: 4021622913,
+                        mirrors: vec![],
                     },
                 ]
             }
+            Model::KyutaiStt1bEnFr => hypr_kyutai::Assets::ALL
+                .iter()
+                .map(|asset| Asset {
+                    name: asset.filename().to_string(),
+                    url: asset.download_url(),
+                    size: asset.size(),
+                    checksum: asset.checksum(),
+                    mirrors: vec![],
+                })
+                .collect(),
         }
     }
 }
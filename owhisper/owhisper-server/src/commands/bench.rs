@@ -0,0 +1,272 @@
+use std::time::{Duration, Instant};
+
+use futures_util::StreamExt;
+
+use crate::{misc::shutdown_signal, Server};
+
+const SAMPLE_RATE: usize = 16000;
+
+// Sent after the audio so VAD-based backends see the last utterance end.
+const TRAILING_SILENCE: Duration = Duration::from_secs(2);
+
+// Once all audio is sent, a backend that stays quiet this long is taken to be done.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Loading a local model can hold up the first words for a while.
+const FIRST_WORD_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(clap::Parser)]
+pub struct BenchArgs {
+    /// Model IDs from the config file, or every configured model when omitted
+    #[arg(value_parser = super::validate_model_from_config)]
+    pub models: Vec<String>,
+
+    /// Audio file to use instead of the English sample bundled with the `bench-sample` feature.
+    /// The model detects the language.
+    #[arg(short, long, requires = "reference")]
+    pub file: Option<String>,
+
+    /// Reference transcription of the file, as plain text or a JSON list of objects with `text`
+    #[arg(short, long, requires = "file")]
+    pub reference: Option<String>,
+
+    #[arg(short, long)]
+    pub config: Option<String>,
+}
+
+struct BenchResult {
+    model: String,
+    realtime_factor: Option<f64>,
+    first_word: Option<Duration>,
+    word_error_rate: f64,
+}
+
+pub async fn handle_bench(args: BenchArgs) -> anyhow::Result<()> {
+    let config = owhisper_config::Config::new(args.config.clone())?;
+    let api_key = config.general.as_ref().and_then(|g| g.api_key.clone());
+
+    let models = if args.models.is_empty() {
+        config.models.iter().map(|m| m.id().to_string()).collect()
+    } else {
+        args.models.clone()
+    };
+    if models.is_empty() {
+        anyhow::bail!("No models found in config");
+    }
+
+    let (audio, reference, languages) = match (&args.file, &args.reference) {
+        (Some(file), Some(reference)) => {
+            let channels = hypr_audio_utils::decode_channels(
+                tokio::fs::read(file).await?,
+                SAMPLE_RATE as u32,
+            )?;
+            let samples = hypr_audio_utils::downmix(&channels);
+            let audio = hypr_audio_utils::f32_to_i16_bytes(samples.into_iter()).to_vec();
+            let reference = reference_text(&tokio::fs::read_to_string(reference).await?);
+            (audio, reference, vec![])
+        }
+        #[cfg(feature = "bench-sample")]
+        _ => (
+            hypr_data::english_2::AUDIO.to_vec(),
+            reference_text(hypr_data::english_2::TRANSCRIPTION_JSON),
+            vec![hypr_language::ISO639::En.into()],
+        ),
+        #[cfg(not(feature = "bench-sample"))]
+        _ => anyhow::bail!("This build has no bundled sample; pass --file and --reference"),
+    };
+    let audio_secs = audio.len() as f64 / (SAMPLE_RATE * 2) as f64;
+
+    let server = Server::new(config, None, None);
+    let router = server.build_router().await?;
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let port = listener.local_addr()?.port();
+
+    let server_handle = tokio::spawn(async move {
+        let handle = axum::serve(listener, router.into_make_service())
+            .with_graceful_shutdown(shutdown_signal());
+        let _ = handle.await;
+    });
+
+    let mut results = Vec::new();
+    for model in models {
+        log::info!("Benchmarking {} on {:.1}s of audio", model, audio_secs);
+
+        let params = owhisper_interface::ListenParams {
+            model: Some(model.clone()),
+            languages: languages.clone(),
+            ..Default::default()
+        };
+
+        match bench_model(&audio, params, port, api_key.as_deref()).await {
+            Ok((transcript, elapsed, first_word)) => results.push(BenchResult {
+                realtime_factor: elapsed.map(|d| d.as_secs_f64() / audio_secs),
+                first_word,
+                word_error_rate: word_error_rate(&reference, &transcript),
+                model,
+            }),
+            Err(e) => log::error!("Failed to benchmark {}: {}", model, e),
+        }
+    }
+
+    server_handle.abort();
+
+    println!(
+        "{:<32} {:>10} {:>12} {:>8}",
+        "MODEL", "RTF", "FIRST WORD", "WER"
+    );
+    for result in results {
+        println!(
+            "{:<32} {:>10} {:>12} {:>7.1}%",
+            result.model,
+            result
+                .realtime_factor
+                .map(|rtf| format!("{:.3}", rtf))
+                .unwrap_or_else(|| "-".to_string()),
+            result
+                .first_word
+                .map(|d| format!("{:.2}s", d.as_secs_f64()))
+                .unwrap_or_else(|| "-".to_string()),
+            result.word_error_rate * 100.0
+        );
+    }
+
+    Ok(())
+}
+
+/// Streams `audio` as fast as the backend takes it, and returns the final transcript, the time
+/// until its last part arrived, and the time until the first word did.
+async fn bench_model(
+    audio: &[u8],
+    params: owhisper_interface::ListenParams,
+    port: u16,
+    api_key: Option<&str>,
+) -> anyhow::Result<(String, Option<Duration>, Option<Duration>)> {
+    let silence = vec![0u8; TRAILING_SILENCE.as_millis() as usize * SAMPLE_RATE / 1000 * 2];
+    let chunks: Vec<
+        owhisper_interface::MixedMessage<bytes::Bytes, owhisper_interface::ControlMessage>,
+    > = audio
+        .chunks(SAMPLE_RATE / 10 * 2)
+        .chain(silence.chunks(SAMPLE_RATE / 10 * 2))
+        .map(|chunk| owhisper_interface::MixedMessage::Audio(bytes::Bytes::copy_from_slice(chunk)))
+        .collect();
+
+    let client = owhisper_client::ListenClient::builder()
+        .api_base(&format!("ws://127.0.0.1:{}", port))
+        .api_key(api_key.unwrap_or(""))
+        .params(params)
+        .build_single();
+
+    let started = Instant::now();
+    // Dropping the handle closes the connection, which would cut off results still on the way.
+    let (response_stream, _handle) = client
+        .from_realtime_audio(futures_util::stream::iter(chunks))
+        .await?;
+    futures_util::pin_mut!(response_stream);
+
+    let mut transcript = Vec::new();
+    let mut first_word = None;
+    let mut elapsed = None;
+
+    loop {
+        let timeout = match first_word {
+            Some(_) => IDLE_TIMEOUT,
+            None => FIRST_WORD_TIMEOUT,
+        };
+        let Ok(Some(response)) = tokio::time::timeout(timeout, response_stream.next()).await else {
+            break;
+        };
+
+        let owhisper_interface::StreamResponse::TranscriptResponse {
+            is_final, channel, ..
+        } = response
+        else {
+            continue;
+        };
+        let Some(alternative) = channel.alternatives.first() else {
+            continue;
+        };
+        if alternative.words.is_empty() {
+            continue;
+        }
+
+        first_word.get_or_insert_with(|| started.elapsed());
+        if is_final {
+            transcript.push(alternative.transcript.clone());
+            elapsed = Some(started.elapsed());
+        }
+    }
+
+    Ok((transcript.join(" "), elapsed, first_word))
+}
+
+/// Reference transcriptions come as plain text, or as the word or segment lists in `hypr_data`.
+fn reference_text(content: &str) -> String {
+    match serde_json::from_str::<Vec<serde_json::Value>>(content) {
+        Ok(items) => items
+            .iter()
+            .filter_map(|item| item.get("text").and_then(|text| text.as_str()))
+            .collect::<Vec<_>>()
+            .join(" "),
+        Err(_) => content.to_string(),
+    }
+}
+
+fn normalized_words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric() || *c == '\'')
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Word-level edit distance to `reference`, relative to its length.
+fn word_error_rate(reference: &str, hypothesis: &str) -> f64 {
+    let reference = normalized_words(reference);
+    let hypothesis = normalized_words(hypothesis);
+    if reference.is_empty() {
+        return if hypothesis.is_empty() { 0.0 } else { 1.0 };
+    }
+
+    let mut row: Vec<usize> = (0..=hypothesis.len()).collect();
+    for (i, r) in reference.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, h) in hypothesis.iter().enumerate() {
+            let substitution = diagonal + usize::from(r != h);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+
+    row[hypothesis.len()] as f64 / reference.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_error_rate() {
+        assert_eq!(word_error_rate("Hello, world.", "hello world"), 0.0);
+        assert_eq!(
+            word_error_rate("the quick brown fox", "the quick fox"),
+            0.25
+        );
+        assert_eq!(
+            word_error_rate("the quick brown fox", "a quick brown fox jumps"),
+            0.5
+        );
+        assert_eq!(word_error_rate("", ""), 0.0);
+    }
+
+    #[test]
+    fn test_reference_text() {
+        let json = r#"[{ "start": 0, "end": 500, "text": "Hello?" }, { "start": 500, "end": 900, "text": " Oh," }]"#;
+        assert_eq!(normalized_words(&reference_text(json)), vec!["hello", "oh"]);
+        assert_eq!(reference_text("plain text"), "plain text");
+    }
+}
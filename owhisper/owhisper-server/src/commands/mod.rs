@@ -1,13 +1,19 @@
+mod bench;
 mod config;
 mod models;
+mod prune;
 mod pull;
 mod readme;
+mod rm;
 mod run;
 mod serve;
 
+pub use bench::*;
 pub use config::*;
 pub use models::*;
+pub use prune::*;
 pub use pull::*;
 pub use readme::*;
+pub use rm::*;
 pub use run::*;
 pub use serve::*;
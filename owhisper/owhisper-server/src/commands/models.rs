@@ -1,5 +1,7 @@
 use std::path::Path;
 use std::{fs, io};
+
+use indicatif::HumanBytes;
 use termtree::Tree;
 
 #[derive(clap::Args)]
@@ -25,9 +27,16 @@ fn label<P: AsRef<Path>>(p: P) -> String {
     p.as_ref().file_name().unwrap().to_str().unwrap().to_owned()
 }
 
+fn sized(label: String, size: u64) -> String {
+    format!("{} ({})", label, HumanBytes(size))
+}
+
 fn tree<P: AsRef<Path>>(p: P) -> io::Result<Tree<String>> {
     let result = fs::read_dir(&p)?.filter_map(|e| e.ok()).fold(
-        Tree::new(label(p.as_ref().canonicalize()?)),
+        Tree::new(sized(
+            label(p.as_ref().canonicalize()?),
+            crate::dir_size(p.as_ref())?,
+        )),
         |mut root, entry| {
             let metadata = entry.metadata().unwrap();
             if metadata.is_dir() {
@@ -35,7 +44,7 @@ fn tree<P: AsRef<Path>>(p: P) -> io::Result<Tree<String>> {
                     root.push(subtree);
                 }
             } else {
-                root.push(Tree::new(label(entry.path())));
+                root.push(Tree::new(sized(label(entry.path()), metadata.len())));
             }
             root
         },
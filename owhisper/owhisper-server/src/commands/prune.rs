@@ -0,0 +1,134 @@
+use std::path::{Path, PathBuf};
+
+use clap::{Parser, ValueEnum};
+use indicatif::HumanBytes;
+
+use owhisper_model::Model;
+
+#[derive(Parser)]
+pub struct PruneArgs {
+    /// List what would be removed without deleting anything
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+enum Leftover {
+    Unfinished,
+    Corrupted,
+    Manifest,
+}
+
+impl std::fmt::Display for Leftover {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Leftover::Unfinished => write!(f, "unfinished"),
+            Leftover::Corrupted => write!(f, "corrupted"),
+            Leftover::Manifest => write!(f, "download manifest"),
+        }
+    }
+}
+
+pub async fn handle_prune(args: PruneArgs) -> anyhow::Result<()> {
+    let models_dir = owhisper_config::models_dir();
+    let mut freed = 0;
+    let mut broken = Vec::new();
+
+    for model in Model::value_variants() {
+        let model_dir = models_dir.join(model.to_string());
+        if !model_dir.is_dir() {
+            continue;
+        }
+
+        // Half-written files of a pull still going are not leftovers.
+        let Some(_lock) = super::try_lock_model(&models_dir, model)? else {
+            log::info!("Skipping {}, which is being pulled", model);
+            continue;
+        };
+
+        let leftovers = leftovers(model, &model_dir)?;
+        if leftovers
+            .iter()
+            .any(|(_, kind)| !matches!(kind, Leftover::Manifest))
+        {
+            broken.push(model.to_string());
+        }
+
+        for (path, kind) in leftovers {
+            let size = std::fs::metadata(&path)?.len();
+            log::info!("{} {} ({})", kind, path.display(), HumanBytes(size));
+
+            if !args.dry_run {
+                std::fs::remove_file(&path)?;
+            }
+            freed += size;
+        }
+
+        if !args.dry_run && std::fs::read_dir(&model_dir)?.next().is_none() {
+            std::fs::remove_dir(&model_dir)?;
+        }
+    }
+
+    if freed == 0 {
+        log::info!("Nothing to prune");
+        return Ok(());
+    }
+
+    if args.dry_run {
+        log::info!("{} would be freed", HumanBytes(freed));
+        return Ok(());
+    }
+
+    log::info!("Freed {}", HumanBytes(freed));
+
+    // Whatever was pruned has to be pulled again before the model can run.
+    if !broken.is_empty() {
+        let config_path = owhisper_config::global_config_path();
+        crate::update_config_with_diff(&config_path, |config| {
+            config
+                .models
+                .retain(|m| !broken.iter().any(|id| id == m.id()));
+            Ok(())
+        })
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Files in `model_dir` left behind by downloads that never finished or didn't end up with
+/// the asset's checksum. Assets that verify, and files that aren't the model's, are kept.
+fn leftovers(model: &Model, model_dir: &Path) -> anyhow::Result<Vec<(PathBuf, Leftover)>> {
+    let assets = model.assets();
+    let mut leftovers = Vec::new();
+
+    for entry in std::fs::read_dir(model_dir)? {
+        let path = entry?.path();
+        if !path.is_file() {
+            continue;
+        }
+
+        if hypr_file::is_download_manifest(&path) {
+            leftovers.push((path, Leftover::Manifest));
+            continue;
+        }
+
+        let Some(asset) = assets.iter().find(|asset| {
+            path.file_name()
+                .is_some_and(|name| name == asset.name.as_str())
+        }) else {
+            continue;
+        };
+
+        // Parallel downloads allocate the whole file up front, so only the manifest tells an
+        // unfinished one apart from a corrupted one.
+        let size = std::fs::metadata(&path)?.len();
+        if size < asset.size || hypr_file::download_manifest_path(&path).exists() {
+            leftovers.push((path, Leftover::Unfinished));
+        } else if size > asset.size || hypr_file::calculate_file_checksum(&path)? != asset.checksum
+        {
+            leftovers.push((path, Leftover::Corrupted));
+        }
+    }
+
+    Ok(leftovers)
+}
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 use tokio::task::JoinSet;
 
 use clap::Parser;
@@ -14,9 +14,14 @@ pub struct PullArgs {
 
 pub async fn handle_pull(args: PullArgs) -> anyhow::Result<()> {
     let assets = args.model.assets();
-    let model_dir = owhisper_config::models_dir().join(args.model.to_string());
+    let models_dir = owhisper_config::models_dir();
+    let model_dir = models_dir.join(args.model.to_string());
     std::fs::create_dir_all(&model_dir)?;
 
+    let Some(_lock) = try_lock_model(&models_dir, &args.model)? else {
+        anyhow::bail!("Model {} is already being pulled", args.model);
+    };
+
     let mut to_download = Vec::new();
     for asset in &assets {
         let asset_path = model_dir.join(&asset.name);
@@ -67,9 +72,11 @@ pub async fn handle_pull(args: PullArgs) -> anyhow::Result<()> {
 
         let mp = multi_progress.clone();
         tasks.spawn(async move {
-            let result = hypr_file::download_file_parallel(
-                asset.url.clone(),
+            let result = hypr_file::download_file_parallel_with_mirrors(
+                &asset.urls(),
                 &asset_path,
+                Some(&hypr_file::Checksum::crc32(asset.checksum)),
+                &hypr_file::RetryPolicy::default(),
                 |progress_update| match progress_update {
                     hypr_download_interface::DownloadProgress::Started => {
                         pb.set_position(0);
@@ -84,6 +91,7 @@ pub async fn handle_pull(args: PullArgs) -> anyhow::Result<()> {
                         pb.finish_with_message(format!("✓ {}", asset.name));
                     }
                 },
+                None,
             )
             .await;
 
@@ -133,6 +141,7 @@ pub async fn handle_pull(args: PullArgs) -> anyhow::Result<()> {
                         owhisper_config::WhisperCppModelConfig {
                             id: model_id.clone(),
                             assets_dir,
+                            max_concurrent_sessions: None,
                         },
                     )
                 }
@@ -143,6 +152,7 @@ pub async fn handle_pull(args: PullArgs) -> anyhow::Result<()> {
                         id: model_id.clone(),
                         size: owhisper_config::MoonshineModelSize::Tiny,
                         assets_dir,
+                        max_concurrent_sessions: None,
                     })
                 }
                 owhisper_model::Model::MoonshineOnnxBase
@@ -152,6 +162,14 @@ pub async fn handle_pull(args: PullArgs) -> anyhow::Result<()> {
                         id: model_id.clone(),
                         size: owhisper_config::MoonshineModelSize::Base,
                         assets_dir,
+                        max_concurrent_sessions: None,
+                    })
+                }
+                owhisper_model::Model::KyutaiStt1bEnFr => {
+                    owhisper_config::ModelConfig::Kyutai(owhisper_config::KyutaiModelConfig {
+                        id: model_id.clone(),
+                        assets_dir,
+                        max_concurrent_sessions: None,
                     })
                 }
             };
@@ -172,3 +190,21 @@ pub async fn handle_pull(args: PullArgs) -> anyhow::Result<()> {
     log::info!("Try running 'owhisper run {}' to get started", args.model);
     Ok(())
 }
+
+/// Held for as long as a pull writes to the model's directory, so `prune` can tell a download
+/// in progress from one that was abandoned. The OS lets go of it if the process dies.
+/// `None` while another process holds it.
+pub fn try_lock_model(models_dir: &Path, model: &Model) -> anyhow::Result<Option<std::fs::File>> {
+    // Next to the model's directory rather than in it, so removing that never trips over it.
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(false)
+        .open(models_dir.join(format!("{}.pull.lock", model)))?;
+
+    match file.try_lock() {
+        Ok(()) => Ok(Some(file)),
+        Err(std::fs::TryLockError::WouldBlock) => Ok(None),
+        Err(std::fs::TryLockError::Error(e)) => Err(e.into()),
+    }
+}
@@ -0,0 +1,40 @@
+use clap::Parser;
+use indicatif::HumanBytes;
+
+use owhisper_model::Model;
+
+#[derive(Parser)]
+pub struct RmArgs {
+    #[arg(value_enum)]
+    pub model: Model,
+}
+
+pub async fn handle_rm(args: RmArgs) -> anyhow::Result<()> {
+    let model_id = args.model.to_string();
+    let model_dir = owhisper_config::models_dir().join(&model_id);
+
+    let config_path = owhisper_config::global_config_path();
+    let in_config = owhisper_config::Config::new(Some(config_path.to_str().unwrap().to_string()))
+        .is_ok_and(|config| config.models.iter().any(|m| m.id() == model_id));
+
+    if !model_dir.exists() && !in_config {
+        anyhow::bail!("Model {} is not downloaded", args.model);
+    }
+
+    if model_dir.exists() {
+        // Partial downloads and their manifests live in the same directory, so they go too.
+        let size = crate::dir_size(&model_dir)?;
+        std::fs::remove_dir_all(&model_dir)?;
+        log::info!("Removed {} ({})", args.model, HumanBytes(size));
+    }
+
+    if in_config {
+        crate::update_config_with_diff(&config_path, |config| {
+            config.models.retain(|m| m.id() != model_id);
+            Ok(())
+        })
+        .await?;
+    }
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 mod event;
 mod guard;
+mod output;
 mod realtime;
 mod recorded;
 mod state;
@@ -7,6 +8,7 @@ mod ui;
 
 use event::*;
 use guard::*;
+use output::*;
 use realtime::*;
 use recorded::*;
 use state::*;
@@ -28,14 +30,40 @@ pub struct RunArgs {
 
     #[arg(short, long)]
     pub device: Option<String>,
+
+    /// Cut long silences out of a file before transcribing it
+    #[arg(long)]
+    pub skip_silence: bool,
+
+    /// Transcribe into English, whatever is being spoken. Only local Whisper models support it
+    #[arg(long)]
+    pub translate: bool,
+
+    /// Format of a file's transcript; guessed from --out-file when omitted
+    #[arg(short, long, value_enum)]
+    pub output: Option<OutputFormat>,
+
+    /// Write a file's transcript here instead of to stdout
+    #[arg(long)]
+    pub out_file: Option<String>,
 }
 
 pub async fn handle_run(args: RunArgs) -> anyhow::Result<()> {
     log::set_max_level(log::LevelFilter::Off);
 
     let config = owhisper_config::Config::new(args.config.clone())?;
+
+    // Any other backend would quietly transcribe instead.
+    if args.translate
+        && !config.models.iter().any(|m| {
+            m.id() == args.model && matches!(m, owhisper_config::ModelConfig::WhisperCpp(_))
+        })
+    {
+        anyhow::bail!("--translate is only supported by local Whisper models");
+    }
+
     let api_key = config.general.as_ref().and_then(|g| g.api_key.clone());
-    let server = Server::new(config.clone(), None);
+    let server = Server::new(config.clone(), None, None);
 
     let router = server.build_router().await?;
     let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
@@ -49,6 +77,15 @@ pub async fn handle_run(args: RunArgs) -> anyhow::Result<()> {
     });
 
     let input_mode = determine_input_mode(&args)?;
+    let format = args
+        .output
+        .or_else(|| args.out_file.as_deref().and_then(OutputFormat::from_path))
+        .unwrap_or(OutputFormat::Txt);
+    let task = if args.translate {
+        owhisper_interface::ListenTask::Translate
+    } else {
+        owhisper_interface::ListenTask::Transcribe
+    };
 
     match input_mode {
         InputMode::File(path) => {
@@ -57,6 +94,10 @@ pub async fn handle_run(args: RunArgs) -> anyhow::Result<()> {
                 args.model.clone(),
                 port,
                 api_key.clone(),
+                args.skip_silence,
+                task.clone(),
+                format,
+                args.out_file.clone(),
             )
             .await?;
         }
@@ -66,11 +107,15 @@ pub async fn handle_run(args: RunArgs) -> anyhow::Result<()> {
                 args.model.clone(),
                 port,
                 api_key.clone(),
+                args.skip_silence,
+                task.clone(),
+                format,
+                args.out_file.clone(),
             )
             .await?;
         }
         InputMode::Microphone => {
-            handle_realtime_input(args.model, args.device, port, api_key).await?;
+            handle_realtime_input(args.model, args.device, port, api_key, task).await?;
         }
     }
 
@@ -78,7 +123,7 @@ pub async fn handle_run(args: RunArgs) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn validate_model_from_config(s: &str) -> Result<String, String> {
+pub(crate) fn validate_model_from_config(s: &str) -> Result<String, String> {
     let config =
         owhisper_config::Config::new(None).map_err(|e| format!("Failed to load config: {}", e))?;
 
@@ -116,6 +161,8 @@ fn determine_input_mode(args: &RunArgs) -> anyhow::Result<InputMode> {
         }
     } else if is_stdin_piped() {
         Ok(InputMode::Stdin)
+    } else if args.output.is_some() || args.out_file.is_some() {
+        anyhow::bail!("--output and --out-file only apply to audio files")
     } else {
         Ok(InputMode::Microphone)
     }
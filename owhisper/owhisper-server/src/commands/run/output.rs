@@ -0,0 +1,194 @@
+use owhisper_interface::{batch, Word};
+
+// Common subtitle guidelines: two lines of about 42 characters, on screen for at most 7 seconds.
+const MAX_CUE_CHARS: usize = 84;
+const MAX_CUE_SECS: f64 = 7.0;
+// A pause this long starts a new cue, so text isn't left up over silence.
+const MAX_GAP_SECS: f64 = 1.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Plain transcript, one line per channel
+    Txt,
+    /// SubRip subtitles
+    Srt,
+    /// WebVTT subtitles
+    Vtt,
+    /// The full response, with word timestamps
+    Json,
+}
+
+impl OutputFormat {
+    /// Guessed from the extension of the file the output goes to.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let extension = std::path::Path::new(path).extension()?.to_str()?;
+        match extension.to_lowercase().as_str() {
+            "txt" => Some(Self::Txt),
+            "srt" => Some(Self::Srt),
+            "vtt" => Some(Self::Vtt),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+pub fn render(format: OutputFormat, response: &batch::Response) -> anyhow::Result<String> {
+    let output = match format {
+        OutputFormat::Txt => response
+            .results
+            .channels
+            .iter()
+            .filter_map(|channel| channel.alternatives.first())
+            .map(|alternatives| format!("{}\n", alternatives.transcript))
+            .collect(),
+        OutputFormat::Srt => cues(response)
+            .iter()
+            .enumerate()
+            .map(|(i, cue)| {
+                format!(
+                    "{}\n{} --> {}\n{}\n\n",
+                    i + 1,
+                    timestamp(cue.start, ','),
+                    timestamp(cue.end, ','),
+                    cue.text
+                )
+            })
+            .collect(),
+        OutputFormat::Vtt => std::iter::once("WEBVTT\n\n".to_string())
+            .chain(cues(response).iter().map(|cue| {
+                format!(
+                    "{} --> {}\n{}\n\n",
+                    timestamp(cue.start, '.'),
+                    timestamp(cue.end, '.'),
+                    cue.text
+                )
+            }))
+            .collect(),
+        OutputFormat::Json => format!("{}\n", serde_json::to_string_pretty(response)?),
+    };
+
+    Ok(output)
+}
+
+#[derive(Debug, PartialEq)]
+struct Cue {
+    start: f64,
+    end: f64,
+    text: String,
+}
+
+/// Words of every channel in order of time, grouped into cues short enough to read at once.
+fn cues(response: &batch::Response) -> Vec<Cue> {
+    let mut words: Vec<&Word> = response
+        .results
+        .channels
+        .iter()
+        .filter_map(|channel| channel.alternatives.first())
+        .flat_map(|alternatives| alternatives.words.iter())
+        .collect();
+    words.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+    let mut cues: Vec<Cue> = Vec::new();
+    let mut ends_sentence = true;
+
+    for word in words {
+        let text = word.punctuated_word.as_deref().unwrap_or(&word.word).trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        match cues.last_mut() {
+            Some(cue)
+                if !ends_sentence
+                    && word.start - cue.end < MAX_GAP_SECS
+                    && word.end - cue.start <= MAX_CUE_SECS
+                    && cue.text.chars().count() + 1 + text.chars().count() <= MAX_CUE_CHARS =>
+            {
+                cue.text.push(' ');
+                cue.text.push_str(text);
+                cue.end = cue.end.max(word.end);
+            }
+            _ => cues.push(Cue {
+                start: word.start,
+                end: word.end,
+                text: text.to_string(),
+            }),
+        }
+
+        ends_sentence = text.ends_with(['.', '?', '!']);
+    }
+
+    cues
+}
+
+fn timestamp(secs: f64, separator: char) -> String {
+    let ms = (secs.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        ms / 3_600_000,
+        ms / 60_000 % 60,
+        ms / 1000 % 60,
+        separator,
+        ms % 1000
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(text: &str) -> batch::Response {
+        let words = text
+            .split_whitespace()
+            .enumerate()
+            .map(|(i, token)| Word {
+                word: token.trim_end_matches(['.', ',']).to_lowercase(),
+                start: i as f64 * 0.5,
+                end: i as f64 * 0.5 + 0.4,
+                confidence: 1.0,
+                speaker: None,
+                punctuated_word: Some(token.to_string()),
+                language: None,
+            })
+            .collect();
+        batch::Response::new(5.0, vec![words])
+    }
+
+    #[test]
+    fn test_timestamp() {
+        assert_eq!(timestamp(0.0, ','), "00:00:00,000");
+        assert_eq!(timestamp(3725.0456, '.'), "01:02:05.046");
+    }
+
+    #[test]
+    fn test_srt() {
+        let output = render(OutputFormat::Srt, &response("Hello there. How are you?")).unwrap();
+        assert_eq!(
+            output,
+            "1\n00:00:00,000 --> 00:00:00,900\nHello there.\n\n2\n00:00:01,000 --> 00:00:02,400\nHow are you?\n\n"
+        );
+    }
+
+    #[test]
+    fn test_vtt() {
+        let output = render(OutputFormat::Vtt, &response("Hello there.")).unwrap();
+        assert_eq!(
+            output,
+            "WEBVTT\n\n00:00:00.000 --> 00:00:00.900\nHello there.\n\n"
+        );
+    }
+
+    #[test]
+    fn test_cue_length() {
+        let text = vec!["word"; 20].join(" ");
+        let cues = cues(&response(&text));
+        assert_eq!(cues.len(), 2);
+        assert!(cues.iter().all(|cue| cue.end - cue.start <= MAX_CUE_SECS));
+    }
+
+    #[test]
+    fn test_from_path() {
+        assert_eq!(OutputFormat::from_path("talk.SRT"), Some(OutputFormat::Srt));
+        assert_eq!(OutputFormat::from_path("talk"), None);
+    }
+}
@@ -1,7 +1,6 @@
 use std::sync::{Arc, Mutex};
 
 use futures_util::StreamExt;
-use hypr_audio::AsyncSource;
 use tokio::sync::mpsc;
 
 use super::{
@@ -14,6 +13,7 @@ pub async fn handle_realtime_input(
     device: Option<String>,
     port: u16,
     api_key: Option<String>,
+    task: owhisper_interface::ListenTask,
 ) -> anyhow::Result<()> {
     let available_devices = hypr_audio::AudioInput::list_mic_devices();
     let initial_device = device
@@ -33,6 +33,7 @@ pub async fn handle_realtime_input(
         port,
         api_key.clone(),
         model.clone(),
+        task.clone(),
         transcript_tx.clone(),
         amplitude_data.clone(),
     );
@@ -68,6 +69,7 @@ pub async fn handle_realtime_input(
                             port,
                             api_key.clone(),
                             model.clone(),
+                            task.clone(),
                             transcript_tx.clone(),
                             amplitude_data.clone(),
                         );
@@ -93,6 +95,7 @@ fn start_audio_task(
     port: u16,
     api_key: Option<String>,
     model: String,
+    task: owhisper_interface::ListenTask,
     transcript_tx: mpsc::UnboundedSender<owhisper_interface::StreamResponse>,
     amplitude_data: Arc<Mutex<AmplitudeData>>,
 ) -> std::sync::Arc<std::sync::atomic::AtomicBool> {
@@ -107,6 +110,7 @@ fn start_audio_task(
                 port,
                 api_key,
                 model,
+                task,
                 transcript_tx,
                 amplitude_data,
                 should_stop_clone,
@@ -123,6 +127,7 @@ async fn run_audio_stream_with_stop(
     port: u16,
     api_key: Option<String>,
     model: String,
+    task: owhisper_interface::ListenTask,
     transcript_tx: mpsc::UnboundedSender<owhisper_interface::StreamResponse>,
     amplitude_data: Arc<Mutex<AmplitudeData>>,
     should_stop: std::sync::Arc<std::sync::atomic::AtomicBool>,
@@ -132,9 +137,7 @@ async fn run_audio_stream_with_stop(
         let amplitude_clone = amplitude_data.clone();
         let mut agc = hypr_agc::Agc::default();
 
-        audio_input
-            .stream()
-            .resample(16000)
+        hypr_audio::ResampledAsyncSource::new(audio_input.stream(), 16000)
             .chunks(512)
             .map(move |chunk| {
                 let samples: Vec<f32> = {
@@ -159,7 +162,14 @@ async fn run_audio_stream_with_stop(
         .api_key(api_key.as_deref().unwrap_or(""))
         .params(owhisper_interface::ListenParams {
             model: Some(model),
-            languages: vec![hypr_language::ISO639::En.into()],
+            // Translating only makes sense from another language, so let it be detected.
+            languages: match task {
+                owhisper_interface::ListenTask::Transcribe => {
+                    vec![hypr_language::ISO639::En.into()]
+                }
+                owhisper_interface::ListenTask::Translate => vec![],
+            },
+            task,
             ..Default::default()
         })
         .build_single();
@@ -1,5 +1,7 @@
 use tokio::io::AsyncReadExt;
 
+use super::{render, OutputFormat};
+
 pub enum AudioSource {
     File(String),
     Stdin,
@@ -10,6 +12,10 @@ pub async fn handle_recorded_input(
     model: String,
     port: u16,
     api_key: Option<String>,
+    skip_silence: bool,
+    task: owhisper_interface::ListenTask,
+    format: OutputFormat,
+    out_file: Option<String>,
 ) -> anyhow::Result<()> {
     let audio_data = match source {
         AudioSource::File(path) => tokio::fs::read(&path).await?,
@@ -21,19 +27,43 @@ pub async fn handle_recorded_input(
         }
     };
 
-    process_audio_bytes(audio_data, model, port, api_key).await
+    let response =
+        process_audio_bytes(audio_data, model, port, api_key, skip_silence, task).await?;
+    let output = render(format, &response)?;
+
+    match out_file {
+        Some(path) => tokio::fs::write(path, output).await?,
+        None => print!("{}", output),
+    }
+    Ok(())
 }
 
 async fn process_audio_bytes(
     audio_data: Vec<u8>,
     model: String,
-    _port: u16,
-    _api_key: Option<String>,
-) -> anyhow::Result<()> {
-    println!(
-        "Processing {} bytes of audio with model: {}",
-        audio_data.len(),
-        model
-    );
-    Ok(())
+    port: u16,
+    api_key: Option<String>,
+    skip_silence: bool,
+    task: owhisper_interface::ListenTask,
+) -> anyhow::Result<owhisper_interface::batch::Response> {
+    let mut request = reqwest::Client::new()
+        .post(format!("http://127.0.0.1:{}/v1/listen/file", port))
+        .query(&[
+            ("model", model.as_str()),
+            ("skip_silence", if skip_silence { "true" } else { "false" }),
+            ("task", task.as_ref()),
+        ])
+        .body(audio_data);
+    if let Some(api_key) = api_key {
+        request = request.bearer_auth(api_key);
+    }
+
+    let response = request.send().await?;
+    if !response.status().is_success() {
+        anyhow::bail!(response.text().await?);
+    }
+
+    Ok(response
+        .json::<owhisper_interface::batch::Response>()
+        .await?)
 }
@@ -7,6 +7,9 @@ use crate::{
 pub struct ServeArgs {
     #[arg(short, long)]
     pub config: Option<String>,
+    /// Address to listen on. `0.0.0.0` accepts other devices on the network, and needs an `api_key`
+    #[arg(long)]
+    pub host: Option<String>,
     #[arg(short, long)]
     pub port: Option<u16>,
 }
@@ -15,7 +18,7 @@ pub async fn handle_serve(args: ServeArgs) -> anyhow::Result<()> {
     print_logo();
 
     let config = owhisper_config::Config::new(args.config)?;
-    let server = Server::new(config, args.port);
+    let server = Server::new(config, args.host, args.port);
     server.run_with_shutdown(shutdown_signal()).await?;
     Ok(())
 }
@@ -21,10 +21,16 @@ enum Commands {
     Readme(commands::ReadmeArgs),
     #[command(about = "Print out the global config")]
     Config(commands::ConfigArgs),
-    #[command(about = "Print out downloaded models")]
+    #[command(about = "Print out downloaded models and their disk usage")]
     Models(commands::ModelsArgs),
     #[command(about = "Download the model")]
     Pull(commands::PullArgs),
+    #[command(about = "Remove a downloaded model")]
+    Rm(commands::RmArgs),
+    #[command(about = "Remove unfinished and corrupted downloads")]
+    Prune(commands::PruneArgs),
+    #[command(about = "Compare the speed and accuracy of models")]
+    Bench(commands::BenchArgs),
     #[command(about = "Run the server")]
     Run(commands::RunArgs),
     #[command(about = "Start the server")]
@@ -46,6 +52,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Commands::Config(args) => commands::handle_config(args).await,
         Commands::Models(args) => commands::handle_models(args).await,
         Commands::Pull(args) => commands::handle_pull(args).await,
+        Commands::Rm(args) => commands::handle_rm(args).await,
+        Commands::Prune(args) => commands::handle_prune(args).await,
+        Commands::Bench(args) => commands::handle_bench(args).await,
         Commands::Run(args) => commands::handle_run(args).await,
         Commands::Serve(args) => commands::handle_serve(args).await,
     };
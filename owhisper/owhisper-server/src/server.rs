@@ -1,14 +1,18 @@
 use std::collections::HashMap;
-use std::net::{Ipv4Addr, SocketAddr};
+use std::net::{IpAddr, Ipv4Addr};
 use std::sync::Arc;
 
 use axum::{
-    extract::{Request, State},
-    http::{HeaderValue, StatusCode},
-    middleware::Next,
-    response::Response,
-    Router,
+    extract::{
+        ws::{close_code, CloseFrame, Message, WebSocketUpgrade},
+        DefaultBodyLimit, FromRequest, Request, State,
+    },
+    http::{header, HeaderValue, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    Json, Router,
 };
+use bytes::Bytes;
 
 use axum_extra::extract::Query;
 use axum_extra::{
@@ -18,65 +22,103 @@ use axum_extra::{
     },
     TypedHeader,
 };
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, TryAcquireError};
 use tower::Service;
-use tower_http::trace::{self, TraceLayer};
+use tower_http::{
+    cors::{self, CorsLayer},
+    trace::{self, TraceLayer},
+};
 use tracing::Level;
 
+// Uploads for `/v1/listen/file` can be long recordings, well past axum's 2MB default.
+const MAX_FILE_BYTES: usize = 1024 * 1024 * 1024;
+// Pauses shorter than this are left in with `skip_silence`, so sentences keep their breaks.
+const MIN_SKIPPED_SILENCE: std::time::Duration = std::time::Duration::from_secs(2);
+// Sent with rejections for a model at its `max_concurrent_sessions`.
+const RETRY_AFTER_SECS: u64 = 5;
+
 #[derive(Clone)]
 pub struct AppState {
     pub api_key: Option<String>,
     pub services: HashMap<String, TranscriptionService>,
+    /// Open sessions per model, for the models with `max_concurrent_sessions` set.
+    pub sessions: HashMap<String, Arc<Semaphore>>,
 }
 
 #[derive(Clone)]
 pub enum TranscriptionService {
     Aws(hypr_transcribe_aws::TranscribeService),
+    Azure(hypr_transcribe_azure::TranscribeService),
     Deepgram(hypr_transcribe_deepgram::TranscribeService),
+    Google(hypr_transcribe_google::TranscribeService),
     WhisperCpp(hypr_transcribe_whisper_local::TranscribeService),
     Moonshine(hypr_transcribe_moonshine::TranscribeService),
+    Kyutai(hypr_transcribe_kyutai::TranscribeService),
 }
 
 pub struct Server {
     config: owhisper_config::Config,
+    host: Option<String>,
     port: Option<u16>,
 }
 
 impl Server {
-    pub fn new(config: owhisper_config::Config, port: Option<u16>) -> Self {
-        Self { config, port }
+    /// `host` and `port` take precedence over the ones in `config`.
+    pub fn new(config: owhisper_config::Config, host: Option<String>, port: Option<u16>) -> Self {
+        Self { config, host, port }
     }
 
     pub async fn build_router(&self) -> anyhow::Result<Router<()>> {
         let api_key = self.config.general.as_ref().and_then(|g| g.api_key.clone());
 
         let mut services = HashMap::new();
+        let mut sessions = HashMap::new();
         for model in &self.config.models {
             let service = match model {
                 owhisper_config::ModelConfig::Aws(config) => {
                     TranscriptionService::Aws(build_aws_service(config).await?)
                 }
+                owhisper_config::ModelConfig::Azure(config) => {
+                    TranscriptionService::Azure(build_azure_service(config)?)
+                }
                 owhisper_config::ModelConfig::Deepgram(config) => {
                     TranscriptionService::Deepgram(build_deepgram_service(config).await?)
                 }
+                owhisper_config::ModelConfig::Google(config) => {
+                    TranscriptionService::Google(build_google_service(config).await?)
+                }
                 owhisper_config::ModelConfig::WhisperCpp(config) => {
                     TranscriptionService::WhisperCpp(build_whisper_cpp_service(config)?)
                 }
                 owhisper_config::ModelConfig::Moonshine(config) => {
                     TranscriptionService::Moonshine(build_moonshine_service(config)?)
                 }
+                owhisper_config::ModelConfig::Kyutai(config) => {
+                    TranscriptionService::Kyutai(build_kyutai_service(config)?)
+                }
             };
 
             let id = match model {
                 owhisper_config::ModelConfig::Aws(c) => &c.id,
+                owhisper_config::ModelConfig::Azure(c) => &c.id,
                 owhisper_config::ModelConfig::Deepgram(c) => &c.id,
+                owhisper_config::ModelConfig::Google(c) => &c.id,
                 owhisper_config::ModelConfig::WhisperCpp(c) => &c.id,
                 owhisper_config::ModelConfig::Moonshine(c) => &c.id,
+                owhisper_config::ModelConfig::Kyutai(c) => &c.id,
             };
 
             services.insert(id.clone(), service);
+            if let Some(limit) = model.max_concurrent_sessions() {
+                sessions.insert(id.clone(), Arc::new(Semaphore::new(limit.get())));
+            }
         }
 
-        let app_state = Arc::new(AppState { api_key, services });
+        let app_state = Arc::new(AppState {
+            api_key,
+            services,
+            sessions,
+        });
 
         let stt_router = self.build_stt_router(app_state.clone()).await;
         let other_router = Router::new()
@@ -88,10 +130,16 @@ impl Server {
 
         let app = other_router
             .merge(stt_router)
-            // .layer(middleware::from_fn_with_state(
-            //     app_state.clone(),
-            //     auth_middleware,
-            // ))
+            .layer(middleware::from_fn_with_state(
+                app_state.clone(),
+                auth_middleware,
+            ))
+            .layer(cors_layer(
+                self.config
+                    .general
+                    .as_ref()
+                    .and_then(|g| g.cors_origins.as_deref()),
+            ))
             .layer(
                 TraceLayer::new_for_http()
                     .make_span_with(trace::DefaultMakeSpan::new().level(Level::INFO))
@@ -111,12 +159,25 @@ impl Server {
     ) -> anyhow::Result<u16> {
         let router = self.build_router().await?;
 
-        let listener = tokio::net::TcpListener::bind(if let Some(port) = self.port {
-            SocketAddr::from((Ipv4Addr::LOCALHOST, port))
-        } else {
-            SocketAddr::from((Ipv4Addr::LOCALHOST, 0))
-        })
-        .await?;
+        let general = self.config.general.as_ref();
+        let host = self
+            .host
+            .clone()
+            .or_else(|| general.and_then(|g| g.host.clone()))
+            .unwrap_or_else(|| Ipv4Addr::LOCALHOST.to_string());
+
+        if !is_loopback(&host) && general.and_then(|g| g.api_key.as_ref()).is_none() {
+            anyhow::bail!(
+                "refusing to listen on {} without `general.api_key`; anyone on the network could use the server",
+                host
+            );
+        }
+        let port = self
+            .port
+            .or_else(|| general.and_then(|g| g.port))
+            .unwrap_or(0);
+
+        let listener = tokio::net::TcpListener::bind((host.as_str(), port)).await?;
 
         let addr = listener.local_addr()?;
         log::info!("Server started on {}", addr);
@@ -136,6 +197,16 @@ impl Server {
         Router::new()
             .route("/listen", axum::routing::any(handle_transcription))
             .route("/v1/listen", axum::routing::any(handle_transcription))
+            .route(
+                "/listen/file",
+                axum::routing::post(handle_file_transcription)
+                    .layer(DefaultBodyLimit::max(MAX_FILE_BYTES)),
+            )
+            .route(
+                "/v1/listen/file",
+                axum::routing::post(handle_file_transcription)
+                    .layer(DefaultBodyLimit::max(MAX_FILE_BYTES)),
+            )
             .with_state(app_state)
     }
 }
@@ -148,6 +219,13 @@ async fn build_aws_service(
         .map_err(|e| anyhow::anyhow!("Failed to create AWS service: {}", e))
 }
 
+fn build_azure_service(
+    config: &owhisper_config::AzureModelConfig,
+) -> anyhow::Result<hypr_transcribe_azure::TranscribeService> {
+    hypr_transcribe_azure::TranscribeService::new(config.clone())
+        .map_err(|e| anyhow::anyhow!("Failed to create Azure service: {}", e))
+}
+
 async fn build_deepgram_service(
     config: &owhisper_config::DeepgramModelConfig,
 ) -> anyhow::Result<hypr_transcribe_deepgram::TranscribeService> {
@@ -156,6 +234,14 @@ async fn build_deepgram_service(
         .map_err(|e| anyhow::anyhow!("Failed to create Deepgram service: {}", e))
 }
 
+async fn build_google_service(
+    config: &owhisper_config::GoogleModelConfig,
+) -> anyhow::Result<hypr_transcribe_google::TranscribeService> {
+    hypr_transcribe_google::TranscribeService::new(config.clone())
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to create Google service: {}", e))
+}
+
 fn build_whisper_cpp_service(
     config: &owhisper_config::WhisperCppModelConfig,
 ) -> anyhow::Result<hypr_transcribe_whisper_local::TranscribeService> {
@@ -197,25 +283,43 @@ fn build_moonshine_service(
         .build())
 }
 
+fn build_kyutai_service(
+    config: &owhisper_config::KyutaiModelConfig,
+) -> anyhow::Result<hypr_transcribe_kyutai::TranscribeService> {
+    let assets_dir = std::path::Path::new(&config.assets_dir);
+
+    let path = |asset: hypr_kyutai::Assets| {
+        let path = assets_dir.join(asset.filename());
+        if path.exists() {
+            Ok(path)
+        } else {
+            Err(anyhow::anyhow!("{} not found", asset.filename()))
+        }
+    };
+
+    Ok(hypr_transcribe_kyutai::TranscribeService::builder()
+        .config_path(path(hypr_kyutai::Assets::Config)?)
+        .model_path(path(hypr_kyutai::Assets::Model)?)
+        .tokenizer_path(path(hypr_kyutai::Assets::Tokenizer)?)
+        .mimi_path(path(hypr_kyutai::Assets::Mimi)?)
+        .build())
+}
+
 async fn handle_transcription(
     State(state): State<Arc<AppState>>,
     Query(params): Query<owhisper_interface::ListenParams>,
-    req: Request,
+    mut req: Request,
 ) -> Result<Response, (StatusCode, String)> {
-    let model_id = match params.model {
-        Some(id) => id,
-        None => state
-            .services
-            .keys()
-            .next()
-            .ok_or((StatusCode::NOT_FOUND, "no_model_specified".to_string()))?
-            .clone(),
-    };
+    let (model_id, service) = resolve_service(&state, params.model.as_deref())?;
 
-    let service = state.services.get(&model_id).ok_or((
-        StatusCode::NOT_FOUND,
-        format!("no_model_match: {}", model_id),
-    ))?;
+    match acquire_session(&state, model_id) {
+        Ok(Some(permit)) => {
+            req.extensions_mut()
+                .insert(hypr_ws_utils::SessionPermit::new(permit));
+        }
+        Ok(None) => {}
+        Err(_) => return Ok(too_many_sessions(req).await),
+    }
 
     let response = match service {
         TranscriptionService::Aws(svc) => {
@@ -227,6 +331,15 @@ async fn handle_transcription(
                 )
             })
         }
+        TranscriptionService::Azure(svc) => {
+            let mut svc_clone = svc.clone();
+            svc_clone.call(req).await.map_err(|_| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "azure_server_error".to_string(),
+                )
+            })
+        }
         TranscriptionService::Deepgram(svc) => {
             let mut svc_clone = svc.clone();
             svc_clone.call(req).await.map_err(|_| {
@@ -236,6 +349,15 @@ async fn handle_transcription(
                 )
             })
         }
+        TranscriptionService::Google(svc) => {
+            let mut svc_clone = svc.clone();
+            svc_clone.call(req).await.map_err(|_| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "google_server_error".to_string(),
+                )
+            })
+        }
         TranscriptionService::WhisperCpp(svc) => {
             let mut svc_clone = svc.clone();
             svc_clone.call(req).await.map_err(|_| {
@@ -254,6 +376,15 @@ async fn handle_transcription(
                 )
             })
         }
+        TranscriptionService::Kyutai(svc) => {
+            let mut svc_clone = svc.clone();
+            svc_clone.call(req).await.map_err(|_| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "kyutai_server_error".to_string(),
+                )
+            })
+        }
     }?;
 
     let (mut parts, body) = response.into_parts();
@@ -266,6 +397,183 @@ async fn handle_transcription(
     Ok(Response::from_parts(parts, body))
 }
 
+// Deepgram-style pre-recorded transcription: the whole file arrives as the request body
+// and the transcript comes back in one response. Only the local backends support it.
+async fn handle_file_transcription(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<owhisper_interface::ListenParams>,
+    body: Bytes,
+) -> Result<Json<owhisper_interface::batch::Response>, Response> {
+    let (model_id, service) =
+        resolve_service(&state, params.model.as_deref()).map_err(IntoResponse::into_response)?;
+    let service = service.clone();
+
+    if !matches!(
+        service,
+        TranscriptionService::WhisperCpp(_) | TranscriptionService::Moonshine(_)
+    ) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "file_transcription_not_supported".to_string(),
+        )
+            .into_response());
+    }
+
+    let permit = acquire_session(&state, model_id).map_err(|_| {
+        (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(header::RETRY_AFTER, RETRY_AFTER_SECS.to_string())],
+            "too_many_sessions",
+        )
+            .into_response()
+    })?;
+
+    let mut channels = hypr_audio_utils::decode_channels(body, 16000)
+        .map_err(|e| (StatusCode::BAD_REQUEST, format!("invalid_audio: {}", e)).into_response())?;
+
+    if params.channels < 2 && channels.len() > 1 {
+        channels = vec![hypr_audio_utils::downmix(&channels)];
+    }
+
+    let duration = channels.first().map_or(0, Vec::len) as f64 / 16000.0;
+
+    let words = tokio::task::spawn_blocking(move || {
+        let _permit = permit;
+        channels
+            .iter()
+            .map(|samples| transcribe_channel(&service, samples, &params))
+            .collect::<Result<Vec<_>, _>>()
+    })
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response())?
+    .map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("file_transcription_failed: {}", e),
+        )
+            .into_response()
+    })?;
+
+    Ok(Json(owhisper_interface::batch::Response::new(
+        duration, words,
+    )))
+}
+
+fn transcribe_channel(
+    service: &TranscriptionService,
+    samples: &[f32],
+    params: &owhisper_interface::ListenParams,
+) -> Result<Vec<owhisper_interface::Word>, String> {
+    let transcribe = |samples: &[f32]| match service {
+        TranscriptionService::WhisperCpp(svc) => svc
+            .transcribe_recorded(samples, params)
+            .map_err(|e| e.to_string()),
+        TranscriptionService::Moonshine(svc) => svc
+            .transcribe_recorded(samples, params)
+            .map_err(|e| e.to_string()),
+        _ => unreachable!(),
+    };
+
+    if !params.skip_silence {
+        return transcribe(samples);
+    }
+
+    let skipped =
+        hypr_vad::skip_silence(samples, 16000, MIN_SKIPPED_SILENCE).map_err(|e| e.to_string())?;
+    if skipped.samples.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut words = transcribe(&skipped.samples)?;
+    for word in &mut words {
+        word.start = skipped.timeline.original_secs(word.start);
+        word.end = skipped.timeline.original_secs(word.end);
+    }
+    Ok(words)
+}
+
+fn resolve_service<'a>(
+    state: &'a AppState,
+    model: Option<&str>,
+) -> Result<(&'a str, &'a TranscriptionService), (StatusCode, String)> {
+    let model_id = match model {
+        Some(id) => id,
+        None => state
+            .services
+            .keys()
+            .next()
+            .ok_or((StatusCode::NOT_FOUND, "no_model_specified".to_string()))?,
+    };
+
+    state
+        .services
+        .get_key_value(model_id)
+        .map(|(id, service)| (id.as_str(), service))
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            format!("no_model_match: {}", model_id),
+        ))
+}
+
+/// `None` when the model has no session limit.
+fn acquire_session(
+    state: &AppState,
+    model_id: &str,
+) -> Result<Option<OwnedSemaphorePermit>, TryAcquireError> {
+    state
+        .sessions
+        .get(model_id)
+        .map(|sessions| sessions.clone().try_acquire_owned())
+        .transpose()
+}
+
+// Most streaming clients never see the status of a refused upgrade, so WebSocket requests are
+// accepted and closed right away with 1013 (try again later). Anything else gets a plain 429.
+async fn too_many_sessions(req: Request) -> Response {
+    let mut response = match WebSocketUpgrade::from_request(req, &()).await {
+        Ok(ws) => ws.on_upgrade(|mut socket| async move {
+            let _ = socket
+                .send(Message::Close(Some(CloseFrame {
+                    code: close_code::AGAIN,
+                    reason: "too_many_sessions".into(),
+                })))
+                .await;
+        }),
+        Err(_) => (StatusCode::TOO_MANY_REQUESTS, "too_many_sessions").into_response(),
+    };
+
+    response
+        .headers_mut()
+        .insert(header::RETRY_AFTER, HeaderValue::from(RETRY_AFTER_SECS));
+    response
+}
+
+fn is_loopback(host: &str) -> bool {
+    host == "localhost" || host.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
+// Without configured origins there is no CORS layer, so browsers can't read responses.
+fn cors_layer(origins: Option<&[String]>) -> Option<CorsLayer> {
+    let origins = origins?;
+    let layer = CorsLayer::new()
+        .allow_methods(cors::Any)
+        .allow_headers(cors::Any);
+
+    if origins.iter().any(|origin| origin == "*") {
+        return Some(layer.allow_origin(cors::Any));
+    }
+
+    let origins = origins
+        .iter()
+        .filter_map(|origin| {
+            HeaderValue::from_str(origin)
+                .inspect_err(|_| log::warn!("invalid_cors_origin: {}", origin))
+                .ok()
+        })
+        .collect::<Vec<_>>();
+    Some(layer.allow_origin(origins))
+}
+
 async fn health() -> &'static str {
     "OK"
 }
@@ -369,6 +677,7 @@ impl Credentials for Token {
 mod tests {
     use super::*;
     use crate::misc::shutdown_signal;
+    use std::net::SocketAddr;
 
     use futures_util::StreamExt;
 
@@ -388,11 +697,13 @@ mod tests {
                             .to_str()
                             .unwrap()
                             .to_string(),
+                        max_concurrent_sessions: None,
                     },
                 )],
                 ..Default::default()
             },
             None,
+            None,
         );
 
         let router = server.build_router().await.unwrap();
@@ -40,6 +40,21 @@ where
     Ok(())
 }
 
+/// Total size of the files under `path`, following no symlinks.
+pub fn dir_size(path: &Path) -> std::io::Result<u64> {
+    let mut size = 0;
+    for entry in std::fs::read_dir(path)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        size += if metadata.is_dir() {
+            dir_size(&entry.path())?
+        } else {
+            metadata.len()
+        };
+    }
+    Ok(size)
+}
+
 fn show_config_diff(original: &str, updated: &str, config_path: &str) -> Result<()> {
     let diff = TextDiff::from_lines(original, updated);
 
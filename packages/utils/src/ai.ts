@@ -2,7 +2,7 @@ import { createOpenAICompatible } from "@ai-sdk/openai-compatible";
 import { customProvider, extractReasoningMiddleware, wrapLanguageModel } from "ai";
 import { getLicenseKey } from "tauri-plugin-keygen-api";
 
-import { commands as connectorCommands } from "@hypr/plugin-connector";
+import { commands as connectorCommands, type LlmTask } from "@hypr/plugin-connector";
 import { fetch as customFetch } from "@hypr/utils";
 
 export {
@@ -33,9 +33,12 @@ const thinkMiddleware = extractReasoningMiddleware({
   startWithReasoning: false,
 });
 
-const getModel = async ({ onboarding }: { onboarding: boolean }) => {
-  const getter = onboarding ? connectorCommands.getLocalLlmConnection : connectorCommands.getLlmConnection;
-  const { type, connection: { api_base, api_key } } = await getter();
+const getModel = async ({ onboarding, task }: { onboarding: boolean; task?: LlmTask }) => {
+  const { connection: { type, connection: { api_base, api_key } }, model: routedModel } = onboarding
+    ? { connection: await connectorCommands.getLocalLlmConnection(), model: null }
+    : task
+    ? await connectorCommands.getLlmConnectionForTask(task)
+    : { connection: await connectorCommands.getLlmConnection(), model: null };
 
   const ourCloud = api_base.includes("pro.hyprnote.com");
   const licenseKey = await getLicenseKey();
@@ -55,7 +58,7 @@ const getModel = async ({ onboarding }: { onboarding: boolean }) => {
     },
   });
 
-  const customModel = await connectorCommands.getCustomLlmModel();
+  const customModel = task ? routedModel : await connectorCommands.getCustomLlmModel();
   const id = onboarding
     ? "mock-onboarding"
     : (type === "Custom" && customModel)
@@ -68,8 +71,9 @@ const getModel = async ({ onboarding }: { onboarding: boolean }) => {
   });
 };
 
-export const modelProvider = async () => {
-  const defaultModel = await getModel({ onboarding: false });
+// With a `task`, "defaultModel" follows that task's route in the connector plugin.
+export const modelProvider = async (task?: LlmTask) => {
+  const defaultModel = await getModel({ onboarding: false, task });
   const onboardingModel = await getModel({ onboarding: true });
 
   return customProvider({
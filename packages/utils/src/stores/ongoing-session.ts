@@ -9,6 +9,8 @@ type State = {
   sessionEventUnlisten?: () => void;
   loading: boolean;
   status: "inactive" | "running_active";
+  // A paused session keeps `status` as "running_active"; only audio capture is on hold.
+  paused: boolean;
   amplitude: { mic: number; speaker: number };
   enhanceController: AbortController | null;
   micMuted: boolean;
@@ -23,11 +25,14 @@ type Actions = {
   setAutoEnhanceTemplate: (templateId: string | null) => void;
   start: (sessionId: string) => void;
   stop: () => void;
+  pause: () => void;
+  resume: () => void;
 };
 
 const initialState: State = {
   sessionId: null,
   status: "inactive",
+  paused: false,
   loading: false,
   amplitude: { mic: 0, speaker: 0 },
   enhanceController: null,
@@ -99,9 +104,16 @@ export const createOngoingSessionStore = (
           set((state) =>
             mutate(state, (draft) => {
               draft.status = "running_active";
+              draft.paused = false;
               draft.loading = false;
             })
           );
+        } else if (payload.type === "running_paused") {
+          set((state) =>
+            mutate(state, (draft) => {
+              draft.paused = true;
+            })
+          );
         } else if (payload.type === "inactive") {
           set((state) =>
             mutate(state, (draft) => {
@@ -142,6 +154,16 @@ export const createOngoingSessionStore = (
         }
       });
     },
+    pause: () => {
+      listenerCommands.pauseSession().catch((error) => {
+        console.error("Failed to pause session:", error);
+      });
+    },
+    resume: () => {
+      listenerCommands.resumeSession().catch((error) => {
+        console.error("Failed to resume session:", error);
+      });
+    },
     stop: () => {
       const { sessionId } = get();
 
@@ -7,12 +7,15 @@ import pDebounce from "p-debounce";
 type State = {
   session: Session;
   showRaw: boolean;
+  /** Set while the pipeline writes the enhanced note, so an open editor follows along. */
+  enhancing: boolean;
 };
 
 type Actions = {
   get: () => State & Actions;
   refresh: () => Promise<void>;
   setShowRaw: (showRaw: boolean) => void;
+  setEnhancing: (enhancing: boolean) => void;
   updateTitle: (title: string) => void;
   updatePreMeetingNote: (note: string) => void;
   updateRawNote: (note: string) => void;
@@ -26,6 +29,7 @@ export const createSessionStore = (session: Session) => {
   return createStore<State & Actions>((set, get) => ({
     session,
     showRaw: !session.enhanced_memo_html,
+    enhancing: false,
     get,
     refresh: async () => {
       const { session: { id } } = get();
@@ -41,6 +45,9 @@ export const createSessionStore = (session: Session) => {
         })
       );
     },
+    setEnhancing: (enhancing: boolean) => {
+      set({ enhancing });
+    },
     updateTitle: (title: string) => {
       set((state) => {
         const next = mutate(state, (draft) => {
@@ -92,9 +99,11 @@ export const createSessionStore = (session: Session) => {
         words: sessionFromDB?.words ?? [],
       };
 
+      // What streams in while enhancing is the model's, not an edit by the user.
+      const upsert = get().enhancing ? dbCommands.upsertEnhancedSession : dbCommands.upsertSession;
       const fn = force
-        ? dbCommands.upsertSession
-        : pDebounce((v: Session) => dbCommands.upsertSession(v), 50);
+        ? upsert
+        : pDebounce((v: Session) => upsert(v), 50);
       await fn(item);
     },
   }));
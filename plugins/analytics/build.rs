@@ -1,4 +1,4 @@
-const COMMANDS: &[&str] = &["event", "set_properties", "set_disabled", "is_disabled"];
+const COMMANDS: &[&str] = &["event", "set_properties", "get_consent", "set_consent"];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
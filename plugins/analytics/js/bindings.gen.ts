@@ -13,17 +13,23 @@ async event(payload: AnalyticsPayload) : Promise<null> {
 async setProperties(payload: PropertiesPayload) : Promise<null> {
     return await TAURI_INVOKE("plugin:analytics|set_properties", { payload });
 },
-async setDisabled(disabled: boolean) : Promise<null> {
-    return await TAURI_INVOKE("plugin:analytics|set_disabled", { disabled });
+async getConsent() : Promise<Consent> {
+    return await TAURI_INVOKE("plugin:analytics|get_consent");
 },
-async isDisabled() : Promise<boolean> {
-    return await TAURI_INVOKE("plugin:analytics|is_disabled");
+async setConsent(consent: Consent) : Promise<null> {
+    return await TAURI_INVOKE("plugin:analytics|set_consent", { consent });
 }
 }
 
 /** user-defined events **/
 
 
+export const events = __makeEvents__<{
+consentChanged: ConsentChanged
+}>({
+consentChanged: "plugin:analytics:consent-changed"
+})
+
 
 /** user-defined constants **/
 
@@ -32,6 +38,28 @@ async isDisabled() : Promise<boolean> {
 /** user-defined types **/
 
 export type AnalyticsPayload = (Partial<{ [key in string]: null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }> }>) & { event: string; distinct_id: string }
+/**
+ * What the user agreed to share. Each kind of telemetry is checked against this right before
+ * it leaves the device, so a change applies immediately.
+ */
+export type Consent = { 
+/**
+ * Crash and error reports (Sentry).
+ */
+crash_reports: boolean; 
+/**
+ * Product usage events (PostHog).
+ */
+usage_analytics: boolean; 
+/**
+ * Model latency and quality measurements.
+ */
+model_metrics: boolean }
+/**
+ * Emitted when the user changes what telemetry they share, so every window can apply it
+ * (e.g. to its Sentry client) without a restart.
+ */
+export type ConsentChanged = { consent: Consent }
 export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
 export type PropertiesPayload = { distinct_id: string; set?: Partial<{ [key in string]: JsonValue }>; set_once?: Partial<{ [key in string]: JsonValue }> }
 
@@ -20,17 +20,17 @@ pub(crate) async fn set_properties<R: tauri::Runtime>(
 
 #[tauri::command]
 #[specta::specta]
-pub(crate) async fn set_disabled<R: tauri::Runtime>(
+pub(crate) async fn get_consent<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
-    disabled: bool,
-) -> Result<(), String> {
-    app.set_disabled(disabled).map_err(|e| e.to_string())
+) -> Result<crate::Consent, String> {
+    app.get_consent().map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 #[specta::specta]
-pub(crate) async fn is_disabled<R: tauri::Runtime>(
+pub(crate) async fn set_consent<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
-) -> Result<bool, String> {
-    app.is_disabled().map_err(|e| e.to_string())
+    consent: crate::Consent,
+) -> Result<(), String> {
+    app.set_consent(consent).map_err(|e| e.to_string())
 }
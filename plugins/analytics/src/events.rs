@@ -0,0 +1,6 @@
+/// Emitted when the user changes what telemetry they share, so every window can apply it
+/// (e.g. to its Sentry client) without a restart.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type, tauri_specta::Event)]
+pub struct ConsentChanged {
+    pub consent: hypr_consent::Consent,
+}
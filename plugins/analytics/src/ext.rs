@@ -4,8 +4,8 @@ use tauri_plugin_misc::MiscPluginExt;
 use tauri_plugin_store2::StorePluginExt;
 
 pub trait AnalyticsPluginExt<R: tauri::Runtime> {
-    fn set_disabled(&self, disabled: bool) -> Result<(), crate::Error>;
-    fn is_disabled(&self) -> Result<bool, crate::Error>;
+    fn get_consent(&self) -> Result<crate::Consent, crate::Error>;
+    fn set_consent(&self, consent: crate::Consent) -> Result<(), crate::Error>;
     fn event(
         &self,
         payload: hypr_analytics::AnalyticsPayload,
@@ -46,7 +46,7 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> crate::AnalyticsPluginExt<R> for T
             .entry("git_hash".into())
             .or_insert(git_hash.into());
 
-        if !self.is_disabled()? {
+        if hypr_consent::allows(crate::Category::for_event(&payload.event)) {
             let client = self.state::<hypr_analytics::AnalyticsClient>();
             client
                 .event(payload)
@@ -57,25 +57,33 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> crate::AnalyticsPluginExt<R> for T
         Ok(())
     }
 
-    fn set_disabled(&self, disabled: bool) -> Result<(), crate::Error> {
+    fn get_consent(&self) -> Result<crate::Consent, crate::Error> {
+        let store = self.scoped_store(crate::PLUGIN_NAME)?;
+        let v = store.get(crate::StoreKey::Consent)?.unwrap_or_default();
+        Ok(v)
+    }
+
+    fn set_consent(&self, consent: crate::Consent) -> Result<(), crate::Error> {
         {
             let store = self.scoped_store(crate::PLUGIN_NAME)?;
-            store.set(crate::StoreKey::Disabled, disabled)?;
+            store.set(crate::StoreKey::Consent, consent)?;
         }
-        Ok(())
-    }
 
-    fn is_disabled(&self) -> Result<bool, crate::Error> {
-        let store = self.scoped_store(crate::PLUGIN_NAME)?;
-        let v = store.get(crate::StoreKey::Disabled)?.unwrap_or(false);
-        Ok(v)
+        hypr_consent::apply(consent);
+
+        use tauri_specta::Event;
+        if let Err(e) = (crate::ConsentChanged { consent }).emit(self.app_handle()) {
+            tracing::error!("emit_consent_changed_failed: {}", e);
+        }
+
+        Ok(())
     }
 
     async fn set_properties(
         &self,
         payload: hypr_analytics::PropertiesPayload,
     ) -> Result<(), crate::Error> {
-        if !self.is_disabled()? {
+        if hypr_consent::allows(crate::Category::UsageAnalytics) {
             let client = self.state::<hypr_analytics::AnalyticsClient>();
             client
                 .set_properties(payload)
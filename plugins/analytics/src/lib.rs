@@ -2,14 +2,17 @@ use tauri::Manager;
 
 mod commands;
 mod error;
+mod events;
 mod ext;
 mod store;
 
 pub use error::{Error, Result};
+pub use events::*;
 pub use ext::*;
 use store::*;
 
 pub use hypr_analytics::*;
+pub use hypr_consent::{Category, Consent};
 
 const PLUGIN_NAME: &str = "analytics";
 
@@ -19,9 +22,10 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
         .commands(tauri_specta::collect_commands![
             commands::event::<tauri::Wry>,
             commands::set_properties::<tauri::Wry>,
-            commands::set_disabled::<tauri::Wry>,
-            commands::is_disabled::<tauri::Wry>,
+            commands::get_consent::<tauri::Wry>,
+            commands::set_consent::<tauri::Wry>,
         ])
+        .events(tauri_specta::collect_events![ConsentChanged])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
 
@@ -30,7 +34,14 @@ pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
 
     tauri::plugin::Builder::new(PLUGIN_NAME)
         .invoke_handler(specta_builder.invoke_handler())
-        .setup(|app, _api| {
+        .setup(move |app, _api| {
+            specta_builder.mount_events(app);
+
+            match app.get_consent() {
+                Ok(consent) => hypr_consent::apply(consent),
+                Err(e) => tracing::error!("failed_to_load_consent: {}", e),
+            }
+
             let api_key = {
                 #[cfg(not(debug_assertions))]
                 {
@@ -2,7 +2,7 @@ use tauri_plugin_store2::ScopedStoreKey;
 
 #[derive(serde::Deserialize, specta::Type, PartialEq, Eq, Hash, strum::Display)]
 pub enum StoreKey {
-    Disabled,
+    Consent,
 }
 
 impl ScopedStoreKey for StoreKey {}
@@ -2,9 +2,7 @@ use chrono::Utc;
 use serde_json;
 
 use hypr_calendar_interface::{CalendarSource, EventFilter};
-use hypr_db_user::{
-    GetSessionFilter, ListEventFilter, ListEventFilterCommon, ListEventFilterSpecific,
-};
+use hypr_db_user::{event_tracking_id, EventSyncState, EVENT_SYNC_WINDOW_DAYS};
 
 pub async fn sync_calendars(
     db: hypr_db_user::UserDatabase,
@@ -29,7 +27,7 @@ pub async fn sync_events(
 ) -> Result<(), crate::Error> {
     check_calendar_access().await?;
 
-    let db_events_with_session = list_db_events_with_session(&db, &user_id).await?;
+    let db_events_with_session = db.list_events_with_session(&user_id).await?;
     let db_selected_calendars = list_db_calendars_selected(&db, &user_id).await?;
 
     // Batch API call instead of individual calls
@@ -153,14 +151,10 @@ async fn _sync_events(
         if let Some(ref calendar_id) = db_event.calendar_id {
             if let Some(events) = system_events_per_selected_calendar.get(calendar_id) {
                 // Check if event exists with same tracking_id
-                if let Some(matching_event) = events.iter().find(|e| {
-                    let system_composite_id = if e.is_recurring {
-                        format!("{}__HYPR__{}", e.id, e.start_date.format("%Y%m%d%H%M%S"))
-                    } else {
-                        e.id.clone()
-                    };
-                    system_composite_id == db_event.tracking_id
-                }) {
+                if let Some(matching_event) = events
+                    .iter()
+                    .find(|e| event_tracking_id(e) == db_event.tracking_id)
+                {
                     let updated_event = hypr_db_user::Event {
                         id: db_event.id.clone(),
                         tracking_id: db_event.tracking_id.clone(),
@@ -176,20 +170,12 @@ async fn _sync_events(
                                 .unwrap_or_else(|_| "[]".to_string()),
                         ),
                         is_recurring: matching_event.is_recurring,
+                        meeting_link: matching_event.meeting_link.clone(),
                     };
                     state.to_update.push(updated_event);
 
-                    let composite_id = if matching_event.is_recurring {
-                        format!(
-                            "{}__HYPR__{}",
-                            matching_event.id,
-                            matching_event.start_date.format("%Y%m%d%H%M%S")
-                        )
-                    } else {
-                        matching_event.id.clone()
-                    };
                     // Mark this system event as handled
-                    handled_system_event_ids.insert(composite_id);
+                    handled_system_event_ids.insert(event_tracking_id(matching_event));
                     continue;
                 }
 
@@ -242,15 +228,7 @@ async fn _sync_events(
     for db_calendar in db_selected_calendars {
         if let Some(fresh_events) = system_events_per_selected_calendar.get(&db_calendar.id) {
             for system_event in fresh_events {
-                let composite_tracking_id = if system_event.is_recurring {
-                    format!(
-                        "{}__HYPR__{}",
-                        system_event.id,
-                        system_event.start_date.format("%Y%m%d%H%M%S")
-                    )
-                } else {
-                    system_event.id.clone()
-                };
+                let composite_tracking_id = event_tracking_id(system_event);
 
                 // Skip if this event was already handled as an update
                 let already_handled = state
@@ -303,6 +281,7 @@ async fn _sync_events(
                                         .unwrap_or_else(|_| "[]".to_string()),
                                 ),
                                 is_recurring: system_event.is_recurring,
+                                meeting_link: system_event.meeting_link.clone(),
                             };
 
                             state.to_upsert.push(new_event);
@@ -327,6 +306,7 @@ async fn _sync_events(
                             .unwrap_or_else(|_| "[]".to_string()),
                     ),
                     is_recurring: system_event.is_recurring,
+                    meeting_link: system_event.meeting_link.clone(),
                 };
 
                 state.to_upsert.push(new_event);
@@ -401,7 +381,7 @@ async fn list_system_events_for_calendars(
             let filter = EventFilter {
                 calendar_tracking_id: calendar_tracking_id.clone(),
                 from: now,
-                to: now + chrono::Duration::days(100),
+                to: now + chrono::Duration::days(EVENT_SYNC_WINDOW_DAYS),
             };
 
             // Add small delay between API calls to avoid overwhelming EventKit
@@ -470,49 +450,6 @@ async fn list_db_calendars_selected(
     Ok(items)
 }
 
-async fn list_db_events(
-    db: &hypr_db_user::UserDatabase,
-    user_id: impl Into<String>,
-) -> Result<Vec<hypr_db_user::Event>, crate::Error> {
-    let events = db
-        .list_events(Some(ListEventFilter {
-            common: ListEventFilterCommon {
-                user_id: user_id.into(),
-                limit: Some(700),
-            },
-            specific: ListEventFilterSpecific::DateRange {
-                start: Utc::now(),
-                end: Utc::now() + chrono::Duration::days(100),
-            },
-        }))
-        .await
-        .map_err(|e| crate::Error::DatabaseError(e))?
-        .into_iter()
-        .collect::<Vec<hypr_db_user::Event>>();
-
-    Ok(events)
-}
-
-async fn list_db_events_with_session(
-    db: &hypr_db_user::UserDatabase,
-    user_id: impl Into<String>,
-) -> Result<Vec<(hypr_db_user::Event, Option<hypr_db_user::Session>)>, crate::Error> {
-    let events = list_db_events(db, user_id).await?;
-
-    let mut events_with_session = Vec::new();
-
-    for event in events {
-        let session = db
-            .get_session(GetSessionFilter::CalendarEventId(event.id.clone()))
-            .await
-            .map_err(|e| crate::Error::DatabaseError(e.into()))?;
-
-        events_with_session.push((event, session));
-    }
-
-    Ok(events_with_session)
-}
-
 async fn check_calendar_access() -> Result<(), crate::Error> {
     let calendar_access = tauri::async_runtime::spawn_blocking(|| {
         let handle = hypr_calendar_apple::Handle::new();
@@ -534,14 +471,6 @@ struct CalendarSyncState {
     to_upsert: Vec<hypr_db_user::Calendar>,
 }
 
-#[derive(Debug, Default)]
-struct EventSyncState {
-    to_delete: Vec<hypr_db_user::Event>,
-    to_upsert: Vec<hypr_db_user::Event>,
-    to_update: Vec<hypr_db_user::Event>,
-    session_transfers: Vec<(String, String)>, // (session_id, new_event_id)
-}
-
 impl CalendarSyncState {
     async fn execute(self, db: &hypr_db_user::UserDatabase) {
         for calendar in self.to_delete {
@@ -558,34 +487,6 @@ impl CalendarSyncState {
     }
 }
 
-impl EventSyncState {
-    async fn execute(self, db: &hypr_db_user::UserDatabase) {
-        for event in self.to_upsert {
-            if let Err(e) = db.upsert_event(event).await {
-                tracing::error!("upsert_event_error: {}", e);
-            }
-        }
-
-        for event in self.to_update {
-            if let Err(e) = db.update_event(event).await {
-                tracing::error!("update_event_error: {}", e);
-            }
-        }
-
-        for (session_id, new_event_id) in self.session_transfers {
-            if let Err(e) = db.session_set_event(session_id, Some(new_event_id)).await {
-                tracing::error!("session_transfer_error: {}", e);
-            }
-        }
-
-        for event in self.to_delete {
-            if let Err(e) = db.delete_event(&event.id).await {
-                tracing::error!("delete_event_error: {}", e);
-            }
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7,6 +7,15 @@ const COMMANDS: &[&str] = &[
     "get_from_vault",
     "set_in_store",
     "set_in_vault",
+    "get_oidc_config",
+    "set_oidc_config",
+    "start_oidc_login",
+    "get_oidc_access_token",
+    "get_google_calendar_config",
+    "set_google_calendar_config",
+    "start_google_calendar_login",
+    "is_google_calendar_connected",
+    "disconnect_google_calendar",
 ];
 
 fn main() {
@@ -30,6 +30,33 @@ async setInVault(key: VaultKey, value: string) : Promise<null> {
 },
 async setInStore(key: StoreKey, value: string) : Promise<null> {
     return await TAURI_INVOKE("plugin:auth|set_in_store", { key, value });
+},
+async getOidcConfig() : Promise<OidcConfig | null> {
+    return await TAURI_INVOKE("plugin:auth|get_oidc_config");
+},
+async setOidcConfig(config: OidcConfig | null) : Promise<null> {
+    return await TAURI_INVOKE("plugin:auth|set_oidc_config", { config });
+},
+async startOidcLogin() : Promise<OidcLogin> {
+    return await TAURI_INVOKE("plugin:auth|start_oidc_login");
+},
+async getOidcAccessToken() : Promise<string> {
+    return await TAURI_INVOKE("plugin:auth|get_oidc_access_token");
+},
+async getGoogleCalendarConfig() : Promise<GoogleCalendarConfig | null> {
+    return await TAURI_INVOKE("plugin:auth|get_google_calendar_config");
+},
+async setGoogleCalendarConfig(config: GoogleCalendarConfig | null) : Promise<null> {
+    return await TAURI_INVOKE("plugin:auth|set_google_calendar_config", { config });
+},
+async startGoogleCalendarLogin() : Promise<OidcLogin> {
+    return await TAURI_INVOKE("plugin:auth|start_google_calendar_login");
+},
+async isGoogleCalendarConnected() : Promise<boolean> {
+    return await TAURI_INVOKE("plugin:auth|is_google_calendar_connected");
+},
+async disconnectGoogleCalendar() : Promise<null> {
+    return await TAURI_INVOKE("plugin:auth|disconnect_google_calendar");
 }
 }
 
@@ -48,11 +75,33 @@ authEvent: "plugin:auth:auth-event"
 
 /** user-defined types **/
 
-export type AuthEvent = "success" | { error: string }
+export type AuthEvent = "success" | { error: string } | "googleCalendarConnected" | { googleCalendarError: string }
+/**
+ * An OAuth client of type "Desktop app" from the Google Cloud console.
+ */
+export type GoogleCalendarConfig = { client_id: string; 
+/**
+ * Google issues one even for desktop clients, and requires it at the token endpoint.
+ */
+client_secret?: string | null }
+export type OidcConfig = { issuer: string; client_id: string; 
+/**
+ * Only for confidential clients; desktop apps normally rely on PKCE alone.
+ */
+client_secret?: string | null; scopes: string[] }
+export type OidcLogin = { 
+/**
+ * Pass to `stop_oauth_server` once the flow is over.
+ */
+port: number; 
+/**
+ * Open this in the browser to start signing in.
+ */
+url: string }
 export type RequestParams = { c: string; f: string; p: number }
 export type ResponseParams = { ui: string; ai: string; st: string; dt: string }
 export type StoreKey = "auth-user-id" | "auth-account-id"
-export type VaultKey = "remote-database" | "remote-server" | "twenty-api-key"
+export type VaultKey = "remote-database" | "remote-server" | "twenty-api-key" | "oidc-tokens" | "google-calendar-tokens"
 
 /** tauri-specta globals **/
 
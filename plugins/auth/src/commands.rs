@@ -1,4 +1,6 @@
-use crate::{store::StoreKey, vault::VaultKey, AuthPluginExt};
+use crate::{
+    store::StoreKey, vault::VaultKey, AuthPluginExt, GoogleCalendarConfig, OidcConfig, OidcLogin,
+};
 
 #[tauri::command]
 #[specta::specta]
@@ -67,3 +69,81 @@ pub fn get_from_store<R: tauri::Runtime>(
 ) -> Result<Option<String>, String> {
     app.get_from_store(key).map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_oidc_config<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Option<OidcConfig>, String> {
+    app.get_oidc_config().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_oidc_config<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    config: Option<OidcConfig>,
+) -> Result<(), String> {
+    app.set_oidc_config(config).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn start_oidc_login<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<OidcLogin, String> {
+    app.start_oidc_login().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_oidc_access_token<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<String, String> {
+    app.get_oidc_access_token().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_google_calendar_config<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Option<GoogleCalendarConfig>, String> {
+    app.get_google_calendar_config().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_google_calendar_config<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    config: Option<GoogleCalendarConfig>,
+) -> Result<(), String> {
+    app.set_google_calendar_config(config)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn start_google_calendar_login<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<OidcLogin, String> {
+    app.start_google_calendar_login()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn is_google_calendar_connected<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<bool, String> {
+    app.is_google_calendar_connected()
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn disconnect_google_calendar<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<(), String> {
+    app.disconnect_google_calendar().map_err(|e| e.to_string())
+}
@@ -14,8 +14,32 @@ pub enum Error {
     Minijinja(#[from] minijinja::Error),
     #[error(transparent)]
     Store(#[from] tauri_plugin_store::Error),
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+    #[error(transparent)]
+    Url(#[from] url::ParseError),
     #[error("Vault not initialized")]
     VaultNotInitialized,
+    #[error("OIDC is not configured")]
+    OidcNotConfigured,
+    #[error("Not signed in with OIDC")]
+    OidcNotSignedIn,
+    #[error("OIDC issuer mismatch: {0}")]
+    OidcIssuerMismatch(String),
+    #[error("OIDC authorization failed: {0}")]
+    OidcAuthorization(String),
+    #[error("OIDC state mismatch")]
+    OidcStateMismatch,
+    #[error("OIDC token request failed: {0}")]
+    OidcTokenRequest(String),
+    #[error("OIDC ID token has no subject")]
+    OidcMissingSubject,
+    #[error("OIDC ID token rejected: {0}")]
+    OidcInvalidIdToken(String),
+    #[error("Google Calendar is not configured")]
+    GoogleCalendarNotConfigured,
+    #[error("Google Calendar is not connected")]
+    GoogleCalendarNotConnected,
 }
 
 impl Serialize for Error {
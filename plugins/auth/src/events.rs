@@ -4,4 +4,8 @@ pub enum AuthEvent {
     Success,
     #[serde(rename = "error")]
     Error(String),
+    #[serde(rename = "googleCalendarConnected")]
+    GoogleCalendarConnected,
+    #[serde(rename = "googleCalendarError")]
+    GoogleCalendarError(String),
 }
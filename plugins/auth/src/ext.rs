@@ -1,9 +1,17 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, OnceLock},
+};
+
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use tauri_specta::Event;
 
 use crate::{
     events::AuthEvent,
-    store::{self, StoreKey},
+    google::{self, GoogleCalendarConfig},
+    oidc::{self, OidcConfig, OidcLogin, OidcTokens, ProviderMetadata},
+    store::{self, StoreKey, GOOGLE_CALENDAR_CONFIG_KEY, OIDC_CONFIG_KEY},
     vault::{Vault, VaultKey},
     ResponseParams, CALLBACK_TEMPLATE_KEY,
 };
@@ -17,6 +25,23 @@ pub trait AuthPluginExt<R: tauri::Runtime> {
     fn get_from_store(&self, key: StoreKey) -> Result<Option<String>, crate::Error>;
     fn set_in_vault(&self, key: VaultKey, value: impl Into<String>) -> Result<(), crate::Error>;
     fn set_in_store(&self, key: StoreKey, value: impl Into<String>) -> Result<(), crate::Error>;
+
+    fn get_oidc_config(&self) -> Result<Option<OidcConfig>, crate::Error>;
+    fn set_oidc_config(&self, config: Option<OidcConfig>) -> Result<(), crate::Error>;
+    fn start_oidc_login(&self) -> impl Future<Output = Result<OidcLogin, crate::Error>>;
+    fn get_oidc_access_token(&self) -> impl Future<Output = Result<String, crate::Error>>;
+
+    fn get_google_calendar_config(&self) -> Result<Option<GoogleCalendarConfig>, crate::Error>;
+    fn set_google_calendar_config(
+        &self,
+        config: Option<GoogleCalendarConfig>,
+    ) -> Result<(), crate::Error>;
+    fn start_google_calendar_login(&self) -> impl Future<Output = Result<OidcLogin, crate::Error>>;
+    fn get_google_calendar_access_token(
+        &self,
+    ) -> impl Future<Output = Result<String, crate::Error>>;
+    fn is_google_calendar_connected(&self) -> Result<bool, crate::Error>;
+    fn disconnect_google_calendar(&self) -> Result<(), crate::Error>;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> AuthPluginExt<R> for T {
@@ -121,6 +146,307 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> AuthPluginExt<R> for T {
 
         Ok(())
     }
+
+    fn get_oidc_config(&self) -> Result<Option<OidcConfig>, crate::Error> {
+        let store = store::get_store(self);
+
+        match store.get(OIDC_CONFIG_KEY) {
+            Some(v) => Ok(Some(serde_json::from_value(v)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn set_oidc_config(&self, config: Option<OidcConfig>) -> Result<(), crate::Error> {
+        let store = store::get_store(self);
+
+        match config {
+            Some(config) => store.set(OIDC_CONFIG_KEY, serde_json::to_value(config)?),
+            None => {
+                store.delete(OIDC_CONFIG_KEY);
+            }
+        }
+        store.save()?;
+
+        Ok(())
+    }
+
+    async fn start_oidc_login(&self) -> Result<OidcLogin, crate::Error> {
+        let config = self
+            .get_oidc_config()?
+            .ok_or(crate::Error::OidcNotConfigured)?;
+
+        start_code_flow(self, Flow::SignIn, config, &[]).await
+    }
+
+    async fn get_oidc_access_token(&self) -> Result<String, crate::Error> {
+        let config = self.get_oidc_config()?;
+        fresh_access_token(self, VaultKey::OidcTokens, config)
+            .await?
+            .ok_or(crate::Error::OidcNotSignedIn)
+    }
+
+    fn get_google_calendar_config(&self) -> Result<Option<GoogleCalendarConfig>, crate::Error> {
+        let store = store::get_store(self);
+
+        match store.get(GOOGLE_CALENDAR_CONFIG_KEY) {
+            Some(v) => Ok(Some(serde_json::from_value(v)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn set_google_calendar_config(
+        &self,
+        config: Option<GoogleCalendarConfig>,
+    ) -> Result<(), crate::Error> {
+        let store = store::get_store(self);
+
+        match config {
+            Some(config) => store.set(GOOGLE_CALENDAR_CONFIG_KEY, serde_json::to_value(config)?),
+            None => {
+                store.delete(GOOGLE_CALENDAR_CONFIG_KEY);
+            }
+        }
+        store.save()?;
+
+        Ok(())
+    }
+
+    async fn start_google_calendar_login(&self) -> Result<OidcLogin, crate::Error> {
+        let config = self
+            .get_google_calendar_config()?
+            .ok_or(crate::Error::GoogleCalendarNotConfigured)?;
+        ensure_vault(self)?;
+
+        start_code_flow(
+            self,
+            Flow::GoogleCalendar,
+            config.oidc(),
+            &google::AUTHORIZATION_PARAMS,
+        )
+        .await
+    }
+
+    async fn get_google_calendar_access_token(&self) -> Result<String, crate::Error> {
+        ensure_vault(self)?;
+        let config = self.get_google_calendar_config()?.map(|c| c.oidc());
+        fresh_access_token(self, VaultKey::GoogleCalendarTokens, config)
+            .await?
+            .ok_or(crate::Error::GoogleCalendarNotConnected)
+    }
+
+    fn is_google_calendar_connected(&self) -> Result<bool, crate::Error> {
+        ensure_vault(self)?;
+        Ok(self
+            .get_from_vault(VaultKey::GoogleCalendarTokens)?
+            .is_some())
+    }
+
+    fn disconnect_google_calendar(&self) -> Result<(), crate::Error> {
+        ensure_vault(self)?;
+        let vault = self.state::<Vault>();
+        vault.remove(VaultKey::GoogleCalendarTokens)
+    }
+}
+
+/// The vault is opened on cloud sign-in, which local-only users never do. Theirs is
+/// opened under the local user id, so Google Calendar works for them too.
+fn ensure_vault<R: tauri::Runtime, T: tauri::Manager<R>>(app: &T) -> Result<(), crate::Error> {
+    let vault = app.state::<Vault>();
+    if vault.is_initialized() {
+        return Ok(());
+    }
+
+    let user_id = app
+        .get_from_store(StoreKey::UserId)?
+        .ok_or(crate::Error::VaultNotInitialized)?;
+    vault.init(user_id)
+}
+
+/// The access token stored under `key`, refreshed first if it expired. `None` when
+/// nothing is stored, or it expired and can't be refreshed.
+async fn fresh_access_token<R: tauri::Runtime, T: tauri::Manager<R>>(
+    app: &T,
+    key: VaultKey,
+    config: Option<OidcConfig>,
+) -> Result<Option<String>, crate::Error> {
+    let Some(tokens) = app
+        .get_from_vault(key.clone())?
+        .map(|v| serde_json::from_str::<OidcTokens>(&v))
+        .transpose()?
+    else {
+        return Ok(None);
+    };
+
+    if !tokens.is_expired() {
+        return Ok(Some(tokens.access_token));
+    }
+
+    let Some(refresh_token) = tokens.refresh_token else {
+        return Ok(None);
+    };
+    let config = config.ok_or(crate::Error::OidcNotConfigured)?;
+    let metadata = oidc::discover(&config.issuer).await?;
+
+    let tokens = oidc::refresh(&config, &metadata, &refresh_token).await?;
+    app.set_in_vault(key, serde_json::to_string(&tokens)?)?;
+
+    Ok(Some(tokens.access_token))
+}
+
+/// What a finished authorization code flow is for.
+#[derive(Debug, Clone, Copy)]
+enum Flow {
+    SignIn,
+    /// Only grants calendar access. The signed-in account stays as it is.
+    GoogleCalendar,
+}
+
+async fn start_code_flow<R: tauri::Runtime, T: tauri::Manager<R>>(
+    app: &T,
+    flow: Flow,
+    config: OidcConfig,
+    extra_params: &[(&str, &str)],
+) -> Result<OidcLogin, crate::Error> {
+    let metadata = oidc::discover(&config.issuer).await?;
+
+    let pkce = oidc::Pkce::generate();
+    let state = oidc::random_token();
+    let nonce = oidc::random_token();
+
+    let env = app.state::<minijinja::Environment>().inner().clone();
+    let html = generate_html(&env)?;
+
+    // The redirect URI embeds the port, which is only known once the server is up.
+    let redirect_uri = Arc::new(OnceLock::<String>::new());
+
+    let port = {
+        let app = app.app_handle().clone();
+        let config = config.clone();
+        let metadata = metadata.clone();
+        let verifier = pkce.verifier.clone();
+        let state = state.clone();
+        let nonce = nonce.clone();
+        let redirect_uri = redirect_uri.clone();
+
+        tauri_plugin_oauth::start_with_config(
+            tauri_plugin_oauth::OauthConfig {
+                ports: None,
+                response: Some(html.into()),
+            },
+            move |u| {
+                let params: HashMap<String, String> = match url::Url::parse(&u) {
+                    Ok(url) => url.query_pairs().into_owned().collect(),
+                    Err(_) => return,
+                };
+                // Ignore stray requests like favicon lookups.
+                if !params.contains_key("code") && !params.contains_key("error") {
+                    return;
+                }
+
+                let app = app.clone();
+                let config = config.clone();
+                let metadata = metadata.clone();
+                let verifier = verifier.clone();
+                let state = state.clone();
+                let nonce = nonce.clone();
+                let redirect_uri = redirect_uri.get().cloned().unwrap_or_default();
+
+                tauri::async_runtime::spawn(async move {
+                    let result = complete_code_flow(
+                        &app,
+                        flow,
+                        &config,
+                        &metadata,
+                        &redirect_uri,
+                        &verifier,
+                        &state,
+                        &nonce,
+                        params,
+                    )
+                    .await;
+
+                    let event = match (flow, result) {
+                        (Flow::SignIn, Ok(_)) => AuthEvent::Success,
+                        (Flow::GoogleCalendar, Ok(_)) => AuthEvent::GoogleCalendarConnected,
+                        (flow, Err(err)) => {
+                            tracing::error!(error = ?err, flow = ?flow, "oauth_login_failed");
+                            match flow {
+                                Flow::SignIn => AuthEvent::Error(err.to_string()),
+                                Flow::GoogleCalendar => {
+                                    AuthEvent::GoogleCalendarError(err.to_string())
+                                }
+                            }
+                        }
+                    };
+                    event.emit(&app).unwrap();
+                });
+            },
+        )?
+    };
+
+    let redirect_uri = redirect_uri.get_or_init(|| oidc_redirect_uri(port));
+    let mut url = url::Url::parse(&oidc::authorization_url(
+        &config,
+        &metadata,
+        redirect_uri,
+        &pkce,
+        &state,
+        &nonce,
+    )?)?;
+    url.query_pairs_mut().extend_pairs(extra_params);
+
+    Ok(OidcLogin {
+        port,
+        url: url.to_string(),
+    })
+}
+
+fn oidc_redirect_uri(port: u16) -> String {
+    format!("http://localhost:{}/callback", port)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn complete_code_flow<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    flow: Flow,
+    config: &OidcConfig,
+    metadata: &ProviderMetadata,
+    redirect_uri: &str,
+    verifier: &str,
+    expected_state: &str,
+    nonce: &str,
+    params: HashMap<String, String>,
+) -> Result<(), crate::Error> {
+    if let Some(error) = params.get("error") {
+        let description = params.get("error_description").unwrap_or(error);
+        return Err(crate::Error::OidcAuthorization(description.clone()));
+    }
+
+    if params.get("state").map(String::as_str) != Some(expected_state) {
+        return Err(crate::Error::OidcStateMismatch);
+    }
+
+    let code = params.get("code").map(String::as_str).unwrap_or_default();
+    let tokens = oidc::exchange_code(config, metadata, redirect_uri, code, verifier).await?;
+    tokens.verify_id_token(&config.client_id, nonce)?;
+
+    match flow {
+        Flow::SignIn => {
+            let user_id = tokens.subject().ok_or(crate::Error::OidcMissingSubject)?;
+
+            app.init_vault(&user_id)?;
+            app.set_in_vault(VaultKey::OidcTokens, serde_json::to_string(&tokens)?)?;
+            app.set_in_store(StoreKey::UserId, user_id)?;
+        }
+        Flow::GoogleCalendar => {
+            app.set_in_vault(
+                VaultKey::GoogleCalendarTokens,
+                serde_json::to_string(&tokens)?,
+            )?;
+        }
+    }
+
+    Ok(())
 }
 
 const KEYCHAIN_SAVE_PNG: &[u8] = include_bytes!("../assets/keychain_save.png");
@@ -0,0 +1,34 @@
+use crate::oidc::OidcConfig;
+
+pub const GOOGLE_ISSUER: &str = "https://accounts.google.com";
+
+const CALENDAR_SCOPES: [&str; 3] = [
+    "openid",
+    "email",
+    "https://www.googleapis.com/auth/calendar.readonly",
+];
+
+/// Google's refresh tokens come from `access_type=offline`, not the `offline_access`
+/// scope, and are only handed out again when the consent screen is shown.
+pub(crate) const AUTHORIZATION_PARAMS: [(&str, &str); 2] =
+    [("access_type", "offline"), ("prompt", "consent")];
+
+/// An OAuth client of type "Desktop app" from the Google Cloud console.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct GoogleCalendarConfig {
+    pub client_id: String,
+    /// Google issues one even for desktop clients, and requires it at the token endpoint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+}
+
+impl GoogleCalendarConfig {
+    pub(crate) fn oidc(&self) -> OidcConfig {
+        OidcConfig {
+            issuer: GOOGLE_ISSUER.to_string(),
+            client_id: self.client_id.clone(),
+            client_secret: self.client_secret.clone(),
+            scopes: CALENDAR_SCOPES.into_iter().map(String::from).collect(),
+        }
+    }
+}
@@ -4,12 +4,16 @@ mod commands;
 mod error;
 mod events;
 mod ext;
+mod google;
+mod oidc;
 mod store;
 mod vault;
 
 pub use error::*;
 pub use events::*;
 pub use ext::*;
+pub use google::GoogleCalendarConfig;
+pub use oidc::{OidcConfig, OidcLogin, OidcTokens};
 pub use store::*;
 pub use vault::*;
 
@@ -33,6 +37,15 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::get_from_store::<tauri::Wry>,
             commands::set_in_vault::<tauri::Wry>,
             commands::set_in_store::<tauri::Wry>,
+            commands::get_oidc_config::<tauri::Wry>,
+            commands::set_oidc_config::<tauri::Wry>,
+            commands::start_oidc_login::<tauri::Wry>,
+            commands::get_oidc_access_token::<tauri::Wry>,
+            commands::get_google_calendar_config::<tauri::Wry>,
+            commands::set_google_calendar_config::<tauri::Wry>,
+            commands::start_google_calendar_login::<tauri::Wry>,
+            commands::is_google_calendar_connected::<tauri::Wry>,
+            commands::disconnect_google_calendar::<tauri::Wry>,
         ])
         .typ::<RequestParams>()
         .typ::<ResponseParams>()
@@ -0,0 +1,347 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD as BASE64_URL, Engine as _};
+use sha2::{Digest, Sha256};
+
+/// Refresh a little before the provider says the access token expires.
+const EXPIRY_LEEWAY_SECS: u64 = 60;
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct OidcConfig {
+    pub issuer: String,
+    pub client_id: String,
+    /// Only for confidential clients; desktop apps normally rely on PKCE alone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+    #[serde(default = "default_scopes")]
+    pub scopes: Vec<String>,
+}
+
+fn default_scopes() -> Vec<String> {
+    ["openid", "profile", "email", "offline_access"]
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// The subset of the discovery document we need.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, specta::Type)]
+pub struct OidcLogin {
+    /// Pass to `stop_oauth_server` once the flow is over.
+    pub port: u16,
+    /// Open this in the browser to start signing in.
+    pub url: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct OidcTokens {
+    pub access_token: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_token: Option<String>,
+    /// Unix seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+}
+
+impl OidcTokens {
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|at| now_secs() + EXPIRY_LEEWAY_SECS >= at)
+    }
+
+    /// The `sub` claim of the ID token, identifying the user.
+    pub fn subject(&self) -> Option<String> {
+        self.claims()?.get("sub")?.as_str().map(String::from)
+    }
+
+    /// Checks that the ID token, if there is one, was issued to `client_id` for the login that
+    /// sent `nonce`, so a token meant for another client or from another login is turned down.
+    pub fn verify_id_token(&self, client_id: &str, nonce: &str) -> Result<(), crate::Error> {
+        if self.id_token.is_none() {
+            return Ok(());
+        }
+        let claims = self
+            .claims()
+            .ok_or_else(|| crate::Error::OidcInvalidIdToken("malformed".to_string()))?;
+
+        let audience = match claims.get("aud") {
+            Some(serde_json::Value::String(aud)) => aud == client_id,
+            Some(serde_json::Value::Array(auds)) => {
+                auds.iter().any(|aud| aud.as_str() == Some(client_id))
+            }
+            _ => false,
+        };
+        let party = claims
+            .get("azp")
+            .is_none_or(|azp| azp.as_str() == Some(client_id));
+        if !audience || !party {
+            return Err(crate::Error::OidcInvalidIdToken("audience".to_string()));
+        }
+
+        if claims.get("nonce").and_then(|n| n.as_str()) != Some(nonce) {
+            return Err(crate::Error::OidcInvalidIdToken("nonce".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// The token comes straight from the token endpoint over TLS, so its signature isn't
+    /// checked, only what it says.
+    fn claims(&self) -> Option<serde_json::Value> {
+        let payload = self.id_token.as_ref()?.split('.').nth(1)?;
+        let bytes = BASE64_URL.decode(payload.trim_end_matches('=')).ok()?;
+        serde_json::from_slice(&bytes).ok()
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    #[serde(default)]
+    id_token: Option<String>,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+impl TokenResponse {
+    fn into_tokens(self, previous_refresh_token: Option<String>) -> OidcTokens {
+        OidcTokens {
+            access_token: self.access_token,
+            // Providers that don't rotate refresh tokens omit them from refresh responses.
+            refresh_token: self.refresh_token.or(previous_refresh_token),
+            id_token: self.id_token,
+            expires_at: self.expires_in.map(|secs| now_secs() + secs),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Pkce {
+    pub verifier: String,
+    pub challenge: String,
+}
+
+impl Pkce {
+    pub fn generate() -> Self {
+        Self::from_verifier(BASE64_URL.encode(rand::random::<[u8; 32]>()))
+    }
+
+    fn from_verifier(verifier: String) -> Self {
+        let challenge = BASE64_URL.encode(Sha256::digest(verifier.as_bytes()));
+        Self {
+            verifier,
+            challenge,
+        }
+    }
+}
+
+/// For `state` and `nonce`.
+pub fn random_token() -> String {
+    BASE64_URL.encode(rand::random::<[u8; 16]>())
+}
+
+pub async fn discover(issuer: &str) -> Result<ProviderMetadata, crate::Error> {
+    let url = format!(
+        "{}/.well-known/openid-configuration",
+        issuer.trim_end_matches('/')
+    );
+
+    let metadata: ProviderMetadata = hypr_proxy::client()
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    if metadata.issuer.trim_end_matches('/') != issuer.trim_end_matches('/') {
+        return Err(crate::Error::OidcIssuerMismatch(metadata.issuer));
+    }
+
+    Ok(metadata)
+}
+
+pub fn authorization_url(
+    config: &OidcConfig,
+    metadata: &ProviderMetadata,
+    redirect_uri: &str,
+    pkce: &Pkce,
+    state: &str,
+    nonce: &str,
+) -> Result<String, crate::Error> {
+    let mut url = url::Url::parse(&metadata.authorization_endpoint)?;
+
+    url.query_pairs_mut()
+        .append_pair("response_type", "code")
+        .append_pair("client_id", &config.client_id)
+        .append_pair("redirect_uri", redirect_uri)
+        .append_pair("scope", &config.scopes.join(" "))
+        .append_pair("state", state)
+        .append_pair("nonce", nonce)
+        .append_pair("code_challenge", &pkce.challenge)
+        .append_pair("code_challenge_method", "S256");
+
+    Ok(url.to_string())
+}
+
+pub async fn exchange_code(
+    config: &OidcConfig,
+    metadata: &ProviderMetadata,
+    redirect_uri: &str,
+    code: &str,
+    verifier: &str,
+) -> Result<OidcTokens, crate::Error> {
+    let mut form = vec![
+        ("grant_type", "authorization_code"),
+        ("code", code),
+        ("redirect_uri", redirect_uri),
+        ("client_id", config.client_id.as_str()),
+        ("code_verifier", verifier),
+    ];
+    if let Some(secret) = &config.client_secret {
+        form.push(("client_secret", secret.as_str()));
+    }
+
+    let res: TokenResponse = token_request(&metadata.token_endpoint, &form).await?;
+    Ok(res.into_tokens(None))
+}
+
+pub async fn refresh(
+    config: &OidcConfig,
+    metadata: &ProviderMetadata,
+    refresh_token: &str,
+) -> Result<OidcTokens, crate::Error> {
+    let mut form = vec![
+        ("grant_type", "refresh_token"),
+        ("refresh_token", refresh_token),
+        ("client_id", config.client_id.as_str()),
+    ];
+    if let Some(secret) = &config.client_secret {
+        form.push(("client_secret", secret.as_str()));
+    }
+
+    let res: TokenResponse = token_request(&metadata.token_endpoint, &form).await?;
+    Ok(res.into_tokens(Some(refresh_token.to_string())))
+}
+
+async fn token_request(
+    token_endpoint: &str,
+    form: &[(&str, &str)],
+) -> Result<TokenResponse, crate::Error> {
+    let res = hypr_proxy::client()
+        .post(token_endpoint)
+        .form(form)
+        .send()
+        .await?;
+
+    if !res.status().is_success() {
+        let status = res.status();
+        let body = res.text().await.unwrap_or_default();
+        return Err(crate::Error::OidcTokenRequest(format!(
+            "{}: {}",
+            status, body
+        )));
+    }
+
+    Ok(res.json().await?)
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pkce_challenge() {
+        // RFC 7636, Appendix B.
+        let pkce = Pkce::from_verifier("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk".to_string());
+        assert_eq!(
+            pkce.challenge,
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn test_authorization_url() {
+        let config = OidcConfig {
+            issuer: "https://id.example.com".to_string(),
+            client_id: "hyprnote".to_string(),
+            client_secret: None,
+            scopes: default_scopes(),
+        };
+        let metadata = ProviderMetadata {
+            issuer: "https://id.example.com".to_string(),
+            authorization_endpoint: "https://id.example.com/authorize".to_string(),
+            token_endpoint: "https://id.example.com/token".to_string(),
+        };
+        let pkce = Pkce::from_verifier("verifier".to_string());
+
+        let url = authorization_url(
+            &config,
+            &metadata,
+            "http://localhost:1234/callback",
+            &pkce,
+            "state",
+            "nonce",
+        )
+        .unwrap();
+        let url = url::Url::parse(&url).unwrap();
+        let params: std::collections::HashMap<_, _> = url.query_pairs().into_owned().collect();
+
+        assert_eq!(params["client_id"], "hyprnote");
+        assert_eq!(params["redirect_uri"], "http://localhost:1234/callback");
+        assert_eq!(params["scope"], "openid profile email offline_access");
+        assert_eq!(params["code_challenge"], pkce.challenge);
+        assert_eq!(params["code_challenge_method"], "S256");
+        assert_eq!(params["nonce"], "nonce");
+    }
+
+    #[test]
+    fn test_subject() {
+        let claims = BASE64_URL.encode(r#"{"sub":"user-123","email":"a@b.c"}"#);
+        let tokens = OidcTokens {
+            access_token: "access".to_string(),
+            refresh_token: None,
+            id_token: Some(format!("header.{}.signature", claims)),
+            expires_at: None,
+        };
+
+        assert_eq!(tokens.subject().as_deref(), Some("user-123"));
+    }
+
+    #[test]
+    fn test_verify_id_token() {
+        let tokens = |claims: &str| OidcTokens {
+            access_token: "access".to_string(),
+            refresh_token: None,
+            id_token: Some(format!("header.{}.signature", BASE64_URL.encode(claims))),
+            expires_at: None,
+        };
+
+        let ok = tokens(r#"{"sub":"u","aud":"hyprnote","nonce":"n"}"#);
+        assert!(ok.verify_id_token("hyprnote", "n").is_ok());
+        assert!(ok.verify_id_token("hyprnote", "other").is_err());
+        assert!(ok.verify_id_token("other-client", "n").is_err());
+
+        let listed = tokens(r#"{"sub":"u","aud":["api","hyprnote"],"azp":"hyprnote","nonce":"n"}"#);
+        assert!(listed.verify_id_token("hyprnote", "n").is_ok());
+
+        let other_party = tokens(r#"{"sub":"u","aud":["api","hyprnote"],"azp":"api","nonce":"n"}"#);
+        assert!(other_party.verify_id_token("hyprnote", "n").is_err());
+    }
+}
@@ -12,8 +12,13 @@ pub enum StoreKey {
     AccountId,
 }
 
+/// Holds an `OidcConfig` object rather than a string, so it isn't a `StoreKey`.
+pub(crate) const OIDC_CONFIG_KEY: &str = "auth-oidc-config";
+/// Holds a `GoogleCalendarConfig`, for the same reason.
+pub(crate) const GOOGLE_CALENDAR_CONFIG_KEY: &str = "auth-google-calendar-config";
+
 pub fn get_store<R: tauri::Runtime, T: tauri::Manager<R>>(
     app: &T,
 ) -> std::sync::Arc<tauri_plugin_store::Store<R>> {
-    app.store("store.json").unwrap()
+    app.store(hypr_data_dir::relative("store.json")).unwrap()
 }
@@ -13,7 +13,7 @@ impl Default for Vault {
     }
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize, strum::AsRefStr, specta::Type)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, strum::AsRefStr, specta::Type)]
 pub enum VaultKey {
     #[strum(serialize = "remote-database")]
     #[serde(rename = "remote-database")]
@@ -27,6 +27,14 @@ pub enum VaultKey {
     #[serde(rename = "twenty-api-key")]
     #[specta(rename = "twenty-api-key")]
     TwentyApiKey,
+    #[strum(serialize = "oidc-tokens")]
+    #[serde(rename = "oidc-tokens")]
+    #[specta(rename = "oidc-tokens")]
+    OidcTokens,
+    #[strum(serialize = "google-calendar-tokens")]
+    #[serde(rename = "google-calendar-tokens")]
+    #[specta(rename = "google-calendar-tokens")]
+    GoogleCalendarTokens,
 }
 
 #[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -37,6 +45,10 @@ pub struct VaultData {
     pub remote_server: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub twenty_api_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oidc_tokens: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_calendar_tokens: Option<String>,
 }
 
 impl VaultData {
@@ -45,14 +57,26 @@ impl VaultData {
             VaultKey::RemoteDatabase => self.remote_database.clone(),
             VaultKey::RemoteServer => self.remote_server.clone(),
             VaultKey::TwentyApiKey => self.twenty_api_key.clone(),
+            VaultKey::OidcTokens => self.oidc_tokens.clone(),
+            VaultKey::GoogleCalendarTokens => self.google_calendar_tokens.clone(),
         }
     }
 
     pub fn set(&mut self, key: VaultKey, value: impl Into<String>) {
+        *self.slot(key) = Some(value.into());
+    }
+
+    pub fn remove(&mut self, key: VaultKey) {
+        *self.slot(key) = None;
+    }
+
+    fn slot(&mut self, key: VaultKey) -> &mut Option<String> {
         match key {
-            VaultKey::RemoteDatabase => self.remote_database = Some(value.into()),
-            VaultKey::RemoteServer => self.remote_server = Some(value.into()),
-            VaultKey::TwentyApiKey => self.twenty_api_key = Some(value.into()),
+            VaultKey::RemoteDatabase => &mut self.remote_database,
+            VaultKey::RemoteServer => &mut self.remote_server,
+            VaultKey::TwentyApiKey => &mut self.twenty_api_key,
+            VaultKey::OidcTokens => &mut self.oidc_tokens,
+            VaultKey::GoogleCalendarTokens => &mut self.google_calendar_tokens,
         }
     }
 }
@@ -64,6 +88,10 @@ impl Vault {
         Ok(())
     }
 
+    pub fn is_initialized(&self) -> bool {
+        self.entry.lock().unwrap().is_some()
+    }
+
     pub fn get(&self, key: VaultKey) -> Result<Option<String>, crate::Error> {
         let guard = self.entry.lock().unwrap();
         let entry = guard.as_ref().ok_or(crate::Error::VaultNotInitialized)?;
@@ -78,6 +106,15 @@ impl Vault {
     }
 
     pub fn set(&self, key: VaultKey, value: impl Into<String>) -> Result<(), crate::Error> {
+        let value = value.into();
+        self.update(|v| v.set(key, value))
+    }
+
+    pub fn remove(&self, key: VaultKey) -> Result<(), crate::Error> {
+        self.update(|v| v.remove(key))
+    }
+
+    fn update(&self, f: impl FnOnce(&mut VaultData)) -> Result<(), crate::Error> {
         let guard = self.entry.lock().unwrap();
         let entry = guard.as_ref().ok_or(crate::Error::VaultNotInitialized)?;
 
@@ -86,7 +123,7 @@ impl Vault {
             Err(keyring::Error::NoEntry) => Ok::<_, crate::Error>(Default::default()),
             Err(e) => Err(e.into()),
         }?;
-        v.set(key, value);
+        f(&mut v);
 
         entry
             .set_password(&serde_json::to_string(&v).unwrap())
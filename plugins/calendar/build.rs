@@ -0,0 +1,13 @@
+const COMMANDS: &[&str] = &[
+    "list_subscriptions",
+    "add_subscription",
+    "update_subscription",
+    "remove_subscription",
+    "sync_subscriptions",
+    "sync_google_calendar",
+    "disconnect_google_calendar",
+];
+
+fn main() {
+    tauri_plugin::Builder::new(COMMANDS).build();
+}
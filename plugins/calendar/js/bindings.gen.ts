@@ -0,0 +1,111 @@
+// @ts-nocheck
+
+
+// This file was generated by [tauri-specta](https://github.com/oscartbeaumont/tauri-specta). Do not edit this file manually.
+
+/** user-defined commands **/
+
+
+export const commands = {
+async listSubscriptions() : Promise<Subscription[]> {
+    return await TAURI_INVOKE("plugin:calendar|list_subscriptions");
+},
+async addSubscription(url: string, kind: SubscriptionKind, username: string | null, password: string | null, refreshIntervalMinutes: number | null) : Promise<Subscription> {
+    return await TAURI_INVOKE("plugin:calendar|add_subscription", { url, kind, username, password, refreshIntervalMinutes });
+},
+async updateSubscription(subscription: Subscription, password: string | null) : Promise<null> {
+    return await TAURI_INVOKE("plugin:calendar|update_subscription", { subscription, password });
+},
+async removeSubscription(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:calendar|remove_subscription", { id });
+},
+async syncSubscriptions() : Promise<Subscription[]> {
+    return await TAURI_INVOKE("plugin:calendar|sync_subscriptions");
+},
+async syncGoogleCalendar() : Promise<null> {
+    return await TAURI_INVOKE("plugin:calendar|sync_google_calendar");
+},
+async disconnectGoogleCalendar() : Promise<null> {
+    return await TAURI_INVOKE("plugin:calendar|disconnect_google_calendar");
+}
+}
+
+/** user-defined events **/
+
+
+
+/** user-defined constants **/
+
+
+
+/** user-defined types **/
+
+/**
+ * A calendar added by URL. Its password lives in the keychain, see `credentials.rs`.
+ */
+export type Subscription = { id: string; url: string; kind: SubscriptionKind; username: string | null; refresh_interval_minutes: number; last_synced_at?: string | null; 
+/**
+ * Why the last sync failed, cleared by the next successful one.
+ */
+last_error?: string | null }
+export type SubscriptionKind = "Ics" | "CalDav"
+
+/** tauri-specta globals **/
+
+import {
+	invoke as TAURI_INVOKE,
+	Channel as TAURI_CHANNEL,
+} from "@tauri-apps/api/core";
+import * as TAURI_API_EVENT from "@tauri-apps/api/event";
+import { type WebviewWindow as __WebviewWindow__ } from "@tauri-apps/api/webviewWindow";
+
+type __EventObj__<T> = {
+	listen: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+	) => ReturnType<typeof TAURI_API_EVENT.listen<T>>;
+	once: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+	) => ReturnType<typeof TAURI_API_EVENT.once<T>>;
+	emit: null extends T
+		? (payload?: T) => ReturnType<typeof TAURI_API_EVENT.emit>
+		: (payload: T) => ReturnType<typeof TAURI_API_EVENT.emit>;
+};
+
+export type Result<T, E> =
+	| { status: "ok"; data: T }
+	| { status: "error"; error: E };
+
+function __makeEvents__<T extends Record<string, any>>(
+	mappings: Record<keyof T, string>,
+) {
+	return new Proxy(
+		{} as unknown as {
+			[K in keyof T]: __EventObj__<T[K]> & {
+				(handle: __WebviewWindow__): __EventObj__<T[K]>;
+			};
+		},
+		{
+			get: (_, event) => {
+				const name = mappings[event as keyof T];
+
+				return new Proxy((() => {}) as any, {
+					apply: (_, __, [window]: [__WebviewWindow__]) => ({
+						listen: (arg: any) => window.listen(name, arg),
+						once: (arg: any) => window.once(name, arg),
+						emit: (arg: any) => window.emit(name, arg),
+					}),
+					get: (_, command: keyof __EventObj__<any>) => {
+						switch (command) {
+							case "listen":
+								return (arg: any) => TAURI_API_EVENT.listen(name, arg);
+							case "once":
+								return (arg: any) => TAURI_API_EVENT.once(name, arg);
+							case "emit":
+								return (arg: any) => TAURI_API_EVENT.emit(name, arg);
+						}
+					},
+				});
+			},
+		},
+	);
+}
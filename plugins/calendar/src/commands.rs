@@ -0,0 +1,73 @@
+use crate::{CalendarPluginExt, Subscription, SubscriptionKind};
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_subscriptions<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<Subscription>, String> {
+    app.list_subscriptions().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn add_subscription<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    url: String,
+    kind: SubscriptionKind,
+    username: Option<String>,
+    password: Option<String>,
+    refresh_interval_minutes: Option<u32>,
+) -> Result<Subscription, String> {
+    app.add_subscription(url, kind, username, password, refresh_interval_minutes)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn update_subscription<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    subscription: Subscription,
+    password: Option<String>,
+) -> Result<(), String> {
+    app.update_subscription(subscription, password)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn remove_subscription<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<(), String> {
+    app.remove_subscription(id).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn sync_subscriptions<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<Subscription>, String> {
+    app.sync_subscriptions(true)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn sync_google_calendar<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<(), String> {
+    app.sync_google_calendar().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn disconnect_google_calendar<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<(), String> {
+    CalendarPluginExt::disconnect_google_calendar(&app)
+        .await
+        .map_err(|e| e.to_string())
+}
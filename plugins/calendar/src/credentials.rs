@@ -0,0 +1,25 @@
+const KEYRING_SERVICE: &str = "hyprnote-calendar";
+
+fn entry(subscription_id: &str) -> Result<keyring::Entry, crate::Error> {
+    Ok(keyring::Entry::new(KEYRING_SERVICE, subscription_id)?)
+}
+
+pub fn load(subscription_id: &str) -> Result<Option<String>, crate::Error> {
+    match entry(subscription_id)?.get_password() {
+        Ok(password) => Ok(Some(password)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub fn save(subscription_id: &str, password: &str) -> Result<(), crate::Error> {
+    entry(subscription_id)?.set_password(password)?;
+    Ok(())
+}
+
+pub fn delete(subscription_id: &str) -> Result<(), crate::Error> {
+    match entry(subscription_id)?.delete_credential() {
+        Ok(_) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(e.into()),
+    }
+}
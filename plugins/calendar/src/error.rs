@@ -0,0 +1,30 @@
+use serde::{ser::Serializer, Serialize};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    StoreError(#[from] tauri_plugin_store2::Error),
+    #[error(transparent)]
+    DatabaseError(#[from] hypr_db_user::Error),
+    #[error(transparent)]
+    Keyring(#[from] keyring::Error),
+    #[error(transparent)]
+    Auth(#[from] tauri_plugin_auth::Error),
+    #[error(transparent)]
+    CalendarError(#[from] hypr_calendar_interface::Error),
+    #[error("database not ready")]
+    NoneDatabase,
+    #[error("user not signed in")]
+    NoneUser,
+    #[error("subscription not found: {0}")]
+    SubscriptionNotFound(String),
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_ref())
+    }
+}
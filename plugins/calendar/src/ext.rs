@@ -0,0 +1,236 @@
+use std::future::Future;
+
+use std::collections::HashMap;
+
+use tauri::Manager;
+use tauri_plugin_auth::AuthPluginExt;
+use tauri_plugin_store2::StorePluginExt;
+
+use hypr_calendar_interface::CalendarSource;
+
+use crate::{credentials, GoogleSyncState, Subscription, SubscriptionKind};
+
+pub trait CalendarPluginExt<R: tauri::Runtime> {
+    fn calendar_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey>;
+
+    fn list_subscriptions(&self) -> Result<Vec<Subscription>, crate::Error>;
+    fn add_subscription(
+        &self,
+        url: String,
+        kind: SubscriptionKind,
+        username: Option<String>,
+        password: Option<String>,
+        refresh_interval_minutes: Option<u32>,
+    ) -> impl Future<Output = Result<Subscription, crate::Error>>;
+    /// `password` replaces the stored one when given, and is kept otherwise.
+    fn update_subscription(
+        &self,
+        subscription: Subscription,
+        password: Option<String>,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
+    fn remove_subscription(
+        &self,
+        id: impl Into<String>,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
+
+    /// Syncs the subscriptions whose refresh interval has passed, or all of them when
+    /// `force` is set.
+    fn sync_subscriptions(
+        &self,
+        force: bool,
+    ) -> impl Future<Output = Result<Vec<Subscription>, crate::Error>>;
+
+    /// Syncs the calendars of the Google account connected through the auth plugin.
+    fn sync_google_calendar(&self) -> impl Future<Output = Result<(), crate::Error>>;
+    /// Forgets the Google account, and removes its calendars.
+    fn disconnect_google_calendar(&self) -> impl Future<Output = Result<(), crate::Error>>;
+}
+
+impl<R: tauri::Runtime, T: tauri::Manager<R>> CalendarPluginExt<R> for T {
+    fn calendar_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey> {
+        self.scoped_store(crate::PLUGIN_NAME).unwrap()
+    }
+
+    fn list_subscriptions(&self) -> Result<Vec<Subscription>, crate::Error> {
+        let store = self.calendar_store();
+        let v = store.get::<Vec<Subscription>>(crate::StoreKey::Subscriptions)?;
+        Ok(v.unwrap_or_default())
+    }
+
+    async fn add_subscription(
+        &self,
+        url: String,
+        kind: SubscriptionKind,
+        username: Option<String>,
+        password: Option<String>,
+        refresh_interval_minutes: Option<u32>,
+    ) -> Result<Subscription, crate::Error> {
+        let subscription = Subscription {
+            id: uuid::Uuid::new_v4().to_string(),
+            url: url.trim().to_string(),
+            kind,
+            username: username.filter(|u| !u.is_empty()),
+            refresh_interval_minutes: refresh_interval_minutes
+                .unwrap_or(crate::DEFAULT_REFRESH_INTERVAL_MINUTES),
+            last_synced_at: None,
+            last_error: None,
+        };
+
+        // Reject a bad URL or wrong credentials here rather than on the first sync.
+        crate::sync::handle(&subscription, password.clone())
+            .list_calendars()
+            .await?;
+
+        if let Some(password) = &password {
+            credentials::save(&subscription.id, password)?;
+        }
+
+        {
+            let _guard = self.state::<crate::State>().sync_lock.lock().await;
+            let mut subscriptions = self.list_subscriptions()?;
+            subscriptions.push(subscription.clone());
+            save_subscriptions(self, subscriptions)?;
+        }
+
+        let synced = self.sync_subscriptions(true).await?;
+        Ok(synced
+            .into_iter()
+            .find(|s| s.id == subscription.id)
+            .unwrap_or(subscription))
+    }
+
+    async fn update_subscription(
+        &self,
+        subscription: Subscription,
+        password: Option<String>,
+    ) -> Result<(), crate::Error> {
+        {
+            let _guard = self.state::<crate::State>().sync_lock.lock().await;
+
+            let mut subscriptions = self.list_subscriptions()?;
+            let existing = subscriptions
+                .iter_mut()
+                .find(|s| s.id == subscription.id)
+                .ok_or_else(|| crate::Error::SubscriptionNotFound(subscription.id.clone()))?;
+
+            if let Some(password) = &password {
+                credentials::save(&subscription.id, password)?;
+            }
+
+            *existing = Subscription {
+                last_synced_at: None,
+                last_error: None,
+                ..subscription
+            };
+            save_subscriptions(self, subscriptions)?;
+        }
+
+        self.sync_subscriptions(false).await?;
+        Ok(())
+    }
+
+    async fn remove_subscription(&self, id: impl Into<String>) -> Result<(), crate::Error> {
+        let id = id.into();
+
+        {
+            let _guard = self.state::<crate::State>().sync_lock.lock().await;
+
+            let mut subscriptions = self.list_subscriptions()?;
+            let before = subscriptions.len();
+            subscriptions.retain(|s| s.id != id);
+            if subscriptions.len() == before {
+                return Err(crate::Error::SubscriptionNotFound(id));
+            }
+
+            credentials::delete(&id)?;
+            save_subscriptions(self, subscriptions)?;
+        }
+
+        self.sync_subscriptions(false).await?;
+        Ok(())
+    }
+
+    async fn sync_subscriptions(&self, force: bool) -> Result<Vec<Subscription>, crate::Error> {
+        let (db, user_id) = user_database(self).await?;
+
+        let _guard = self.state::<crate::State>().sync_lock.lock().await;
+        let mut subscriptions = self.list_subscriptions()?;
+
+        crate::sync::remove_stale_calendars(&db, &user_id, &subscriptions).await?;
+
+        let now = chrono::Utc::now();
+        for subscription in subscriptions.iter_mut().filter(|s| force || s.is_due(now)) {
+            let result = match credentials::load(&subscription.id) {
+                Ok(password) => {
+                    crate::sync::sync_subscription(&db, &user_id, subscription, password).await
+                }
+                Err(e) => Err(e),
+            };
+
+            subscription.last_synced_at = Some(now);
+            subscription.last_error = result.err().map(|e| {
+                tracing::warn!("calendar_subscription_sync_failed: {}", e);
+                e.to_string()
+            });
+        }
+
+        save_subscriptions(self, subscriptions.clone())?;
+        Ok(subscriptions)
+    }
+
+    async fn sync_google_calendar(&self) -> Result<(), crate::Error> {
+        let (db, user_id) = user_database(self).await?;
+        let token = self.get_google_calendar_access_token().await?;
+
+        let _guard = self.state::<crate::State>().sync_lock.lock().await;
+        let store = self.calendar_store();
+        let mut states = store
+            .get::<HashMap<String, GoogleSyncState>>(crate::StoreKey::GoogleSync)?
+            .unwrap_or_default();
+
+        let result = crate::google::sync_google_calendars(&db, &user_id, token, &mut states).await;
+
+        // Tokens of the calendars synced before a failure are still good.
+        store.set(crate::StoreKey::GoogleSync, states)?;
+        store.save()?;
+        result
+    }
+
+    async fn disconnect_google_calendar(&self) -> Result<(), crate::Error> {
+        AuthPluginExt::disconnect_google_calendar(self)?;
+
+        let (db, user_id) = user_database(self).await?;
+
+        let _guard = self.state::<crate::State>().sync_lock.lock().await;
+        let store = self.calendar_store();
+        store.set(
+            crate::StoreKey::GoogleSync,
+            HashMap::<String, GoogleSyncState>::new(),
+        )?;
+        store.save()?;
+
+        crate::google::remove_google_calendars(&db, &user_id).await
+    }
+}
+
+async fn user_database<R: tauri::Runtime>(
+    app: &impl tauri::Manager<R>,
+) -> Result<(hypr_db_user::UserDatabase, String), crate::Error> {
+    let state = app.state::<tauri_plugin_db::ManagedState>();
+    let guard = state.lock().await;
+
+    Ok((
+        guard.db.clone().ok_or(crate::Error::NoneDatabase)?,
+        guard.user_id.clone().ok_or(crate::Error::NoneUser)?,
+    ))
+}
+
+fn save_subscriptions<R: tauri::Runtime>(
+    app: &impl CalendarPluginExt<R>,
+    subscriptions: Vec<Subscription>,
+) -> Result<(), crate::Error> {
+    let store = app.calendar_store();
+    store.set(crate::StoreKey::Subscriptions, subscriptions)?;
+    store.save()?;
+    Ok(())
+}
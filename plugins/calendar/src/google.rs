@@ -0,0 +1,250 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+
+use hypr_calendar_interface::CalendarSource;
+use hypr_db_user::{EventSyncState, EVENT_SYNC_WINDOW_DAYS};
+
+use crate::store::GoogleSyncState;
+use crate::sync::{plan_calendar, plan_event, plan_events, remove_calendars};
+
+/// Past this, the next sync of a calendar is a full one, so the window follows `now`.
+const FULL_SYNC_INTERVAL_HOURS: i64 = 24;
+
+/// Mirrors the connected account's calendars, and the events of the selected ones.
+/// `states` holds where each calendar's last sync left off, keyed by its Google id.
+pub async fn sync_google_calendars(
+    db: &hypr_db_user::UserDatabase,
+    user_id: &str,
+    token: String,
+    states: &mut HashMap<String, GoogleSyncState>,
+) -> Result<(), crate::Error> {
+    let handle = hypr_calendar_google::Handle::new(token);
+    let fresh_calendars = handle.list_calendars().await?;
+
+    let db_calendars: Vec<hypr_db_user::Calendar> = db
+        .list_calendars(user_id)
+        .await?
+        .into_iter()
+        .filter(|c| c.platform == hypr_db_user::Platform::Google)
+        .collect();
+
+    let stale = db_calendars
+        .iter()
+        .filter(|c| !fresh_calendars.iter().any(|f| f.id == c.tracking_id))
+        .cloned()
+        .collect();
+    remove_calendars(db, user_id, stale).await?;
+    states.retain(|id, _| fresh_calendars.iter().any(|c| &c.id == id));
+
+    let db_events_with_session = db.list_events_with_session(user_id).await?;
+
+    for calendar in fresh_calendars {
+        // Only the primary calendar starts selected. Accounts often have many others.
+        let selected_by_default = calendar.source.as_deref() == Some(calendar.id.as_str());
+        let calendar = db
+            .upsert_calendar(plan_calendar(
+                user_id,
+                &db_calendars,
+                calendar,
+                selected_by_default,
+            ))
+            .await?;
+
+        if !calendar.selected {
+            // Selecting it again starts over with a full sync.
+            states.remove(&calendar.tracking_id);
+            continue;
+        }
+
+        let db_events = db_events_with_session
+            .iter()
+            .filter(|(event, _)| event.calendar_id.as_deref() == Some(calendar.id.as_str()))
+            .cloned()
+            .collect();
+
+        let state = states.entry(calendar.tracking_id.clone()).or_default();
+        sync_calendar(&handle, user_id, &calendar, db_events, state)
+            .await?
+            .execute(db)
+            .await;
+    }
+
+    Ok(())
+}
+
+/// Removes every Google calendar, keeping events that already have notes attached.
+pub async fn remove_google_calendars(
+    db: &hypr_db_user::UserDatabase,
+    user_id: &str,
+) -> Result<(), crate::Error> {
+    let calendars = db
+        .list_calendars(user_id)
+        .await?
+        .into_iter()
+        .filter(|c| c.platform == hypr_db_user::Platform::Google)
+        .collect();
+
+    remove_calendars(db, user_id, calendars).await
+}
+
+async fn sync_calendar(
+    handle: &hypr_calendar_google::Handle,
+    user_id: &str,
+    calendar: &hypr_db_user::Calendar,
+    db_events_with_session: Vec<(hypr_db_user::Event, Option<hypr_db_user::Session>)>,
+    state: &mut GoogleSyncState,
+) -> Result<EventSyncState, crate::Error> {
+    let now = Utc::now();
+    let (from, to) = (now, now + chrono::Duration::days(EVENT_SYNC_WINDOW_DAYS));
+
+    let sync_token = state.sync_token.as_deref().filter(|_| {
+        state
+            .full_synced_at
+            .is_some_and(|at| now - at < chrono::Duration::hours(FULL_SYNC_INTERVAL_HOURS))
+    });
+
+    if let Some(sync_token) = sync_token {
+        // `None` means the token expired, which falls through to a full sync.
+        if let Some(changes) = handle
+            .sync_events(&calendar.tracking_id, Some(sync_token), from, to)
+            .await?
+        {
+            state.sync_token = changes.next_sync_token.clone();
+            return Ok(plan_changes(
+                user_id,
+                &calendar.id,
+                db_events_with_session,
+                changes,
+                from,
+                to,
+            ));
+        }
+    }
+
+    let changes = handle
+        .sync_events(&calendar.tracking_id, None, from, to)
+        .await?
+        .unwrap_or_default();
+    state.sync_token = changes.next_sync_token;
+    state.full_synced_at = Some(now);
+
+    Ok(plan_events(
+        user_id,
+        &calendar.id,
+        db_events_with_session,
+        changes.updated,
+    ))
+}
+
+/// Unlike `plan_events`, leaves the events that didn't change alone.
+fn plan_changes(
+    user_id: &str,
+    calendar_id: &str,
+    db_events_with_session: Vec<(hypr_db_user::Event, Option<hypr_db_user::Session>)>,
+    changes: hypr_calendar_google::EventChanges,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> EventSyncState {
+    let mut state = EventSyncState::default();
+    let mut removed: HashSet<String> = changes.removed.into_iter().collect();
+
+    for event in changes.updated {
+        // Moved out of the window, so it goes like a deleted one would.
+        if event.end_date <= from || event.start_date >= to {
+            removed.insert(event.id);
+            continue;
+        }
+
+        state.to_upsert.push(plan_event(
+            user_id,
+            calendar_id,
+            &db_events_with_session,
+            event,
+        ));
+    }
+
+    for (db_event, session) in db_events_with_session {
+        if removed.contains(&db_event.tracking_id) && session.is_none_or(|s| s.is_empty()) {
+            state.to_delete.push(db_event);
+        }
+    }
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fresh(id: &str, start_date: DateTime<Utc>) -> hypr_calendar_interface::Event {
+        hypr_calendar_interface::Event {
+            id: id.to_string(),
+            calendar_id: "primary".to_string(),
+            platform: hypr_calendar_interface::Platform::Google,
+            name: "Standup".to_string(),
+            note: "".to_string(),
+            participants: vec![],
+            start_date,
+            end_date: start_date + chrono::Duration::minutes(15),
+            google_event_url: Some(format!("https://www.google.com/calendar/event?eid={}", id)),
+            meeting_link: Some("https://meet.google.com/abc-defg-hij".to_string()),
+            is_recurring: true,
+        }
+    }
+
+    fn stored(tracking_id: &str, start_date: DateTime<Utc>) -> hypr_db_user::Event {
+        hypr_db_user::Event {
+            id: format!("db-{}", tracking_id),
+            tracking_id: tracking_id.to_string(),
+            calendar_id: Some("cal".to_string()),
+            name: "Old name".to_string(),
+            start_date,
+            end_date: start_date + chrono::Duration::minutes(15),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_plan_changes() {
+        let from = chrono::DateTime::parse_from_rfc3339("2025-01-06T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let to = from + chrono::Duration::days(EVENT_SYNC_WINDOW_DAYS);
+        let soon = from + chrono::Duration::hours(9);
+
+        let state = plan_changes(
+            "user",
+            "cal",
+            vec![
+                (stored("moved", soon), None),
+                (stored("cancelled", soon), None),
+                (stored("untouched", soon), None),
+                (stored("postponed", soon), None),
+            ],
+            hypr_calendar_google::EventChanges {
+                updated: vec![
+                    fresh("moved", soon + chrono::Duration::hours(1)),
+                    fresh("postponed", to + chrono::Duration::days(1)),
+                ],
+                removed: vec!["cancelled".to_string()],
+                next_sync_token: None,
+            },
+            from,
+            to,
+        );
+
+        assert_eq!(state.to_upsert.len(), 1);
+        assert_eq!(state.to_upsert[0].id, "db-moved");
+        assert_eq!(state.to_upsert[0].tracking_id, "moved");
+        assert!(state.to_upsert[0].meeting_link.is_some());
+
+        let mut deleted: Vec<&str> = state
+            .to_delete
+            .iter()
+            .map(|e| e.tracking_id.as_str())
+            .collect();
+        deleted.sort();
+        assert_eq!(deleted, vec!["cancelled", "postponed"]);
+    }
+}
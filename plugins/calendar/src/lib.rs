@@ -0,0 +1,81 @@
+use tauri::Manager;
+
+mod commands;
+mod credentials;
+mod error;
+mod ext;
+mod google;
+mod store;
+mod sync;
+mod worker;
+
+pub use error::*;
+pub use ext::*;
+use store::*;
+pub use store::{Subscription, SubscriptionKind, DEFAULT_REFRESH_INTERVAL_MINUTES};
+
+const PLUGIN_NAME: &str = "calendar";
+
+#[derive(Default)]
+pub struct State {
+    /// Subscriptions are read, synced and written back as a whole, so syncs don't overlap.
+    sync_lock: tokio::sync::Mutex<()>,
+}
+
+fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
+    tauri_specta::Builder::<R>::new()
+        .plugin_name(PLUGIN_NAME)
+        .commands(tauri_specta::collect_commands![
+            commands::list_subscriptions::<tauri::Wry>,
+            commands::add_subscription::<tauri::Wry>,
+            commands::update_subscription::<tauri::Wry>,
+            commands::remove_subscription::<tauri::Wry>,
+            commands::sync_subscriptions::<tauri::Wry>,
+            commands::sync_google_calendar::<tauri::Wry>,
+            commands::disconnect_google_calendar::<tauri::Wry>,
+        ])
+        .error_handling(tauri_specta::ErrorHandlingMode::Throw)
+}
+
+pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
+    let specta_builder = make_specta_builder();
+
+    tauri::plugin::Builder::new(PLUGIN_NAME)
+        .invoke_handler(specta_builder.invoke_handler())
+        .setup(|app, _api| {
+            app.manage(State::default());
+            tauri::async_runtime::spawn(worker::run(app.app_handle().clone()));
+            Ok(())
+        })
+        .build()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn export_types() {
+        make_specta_builder::<tauri::Wry>()
+            .export(
+                specta_typescript::Typescript::default()
+                    .header("// @ts-nocheck\n\n")
+                    .formatter(specta_typescript::formatter::prettier)
+                    .bigint(specta_typescript::BigIntExportBehavior::Number),
+                "./js/bindings.gen.ts",
+            )
+            .unwrap()
+    }
+
+    fn create_app<R: tauri::Runtime>(builder: tauri::Builder<R>) -> tauri::App<R> {
+        builder
+            .plugin(init())
+            .build(tauri::test::mock_context(tauri::test::noop_assets()))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_calendar() {
+        let _app = create_app(tauri::test::mock_builder());
+    }
+}
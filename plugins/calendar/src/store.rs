@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use tauri_plugin_store2::ScopedStoreKey;
+
+#[derive(serde::Deserialize, specta::Type, PartialEq, Eq, Hash, strum::Display)]
+pub enum StoreKey {
+    Subscriptions,
+    GoogleSync,
+}
+
+impl ScopedStoreKey for StoreKey {}
+
+pub const DEFAULT_REFRESH_INTERVAL_MINUTES: u32 = 30;
+
+/// A calendar added by URL. Its password lives in the keychain, see `credentials.rs`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct Subscription {
+    pub id: String,
+    pub url: String,
+    pub kind: SubscriptionKind,
+    pub username: Option<String>,
+    pub refresh_interval_minutes: u32,
+    #[serde(default)]
+    pub last_synced_at: Option<DateTime<Utc>>,
+    /// Why the last sync failed, cleared by the next successful one.
+    #[serde(default)]
+    pub last_error: Option<String>,
+}
+
+impl Subscription {
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.last_synced_at.is_none_or(|at| {
+            now - at >= chrono::Duration::minutes(self.refresh_interval_minutes.max(1) as i64)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub enum SubscriptionKind {
+    Ics,
+    CalDav,
+}
+
+impl From<SubscriptionKind> for hypr_calendar_ics::SourceKind {
+    fn from(kind: SubscriptionKind) -> Self {
+        match kind {
+            SubscriptionKind::Ics => hypr_calendar_ics::SourceKind::Ics,
+            SubscriptionKind::CalDav => hypr_calendar_ics::SourceKind::CalDav,
+        }
+    }
+}
+
+/// Where the last sync of a Google calendar left off.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct GoogleSyncState {
+    pub sync_token: Option<String>,
+    /// Incremental syncs return changes however far ahead they are, so the window only
+    /// moves forward with a full sync.
+    pub full_synced_at: Option<DateTime<Utc>>,
+}
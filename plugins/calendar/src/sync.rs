@@ -0,0 +1,248 @@
+use std::collections::HashSet;
+
+use chrono::Utc;
+
+use hypr_calendar_interface::{CalendarSource, EventFilter};
+use hypr_db_user::{event_tracking_id, EventSyncState, EVENT_SYNC_WINDOW_DAYS};
+
+use crate::store::Subscription;
+
+/// Fetches one subscription and mirrors it into its calendar and that calendar's events.
+pub async fn sync_subscription(
+    db: &hypr_db_user::UserDatabase,
+    user_id: &str,
+    subscription: &Subscription,
+    password: Option<String>,
+) -> Result<(), crate::Error> {
+    let handle = handle(subscription, password);
+
+    let db_calendars = db.list_calendars(user_id).await?;
+    let Some(calendar) = handle.list_calendars().await?.into_iter().next() else {
+        return Ok(());
+    };
+
+    let calendar = db
+        // The user asked for this calendar by adding it, so it starts selected.
+        .upsert_calendar(plan_calendar(user_id, &db_calendars, calendar, true))
+        .await?;
+    if !calendar.selected {
+        return Ok(());
+    }
+
+    let now = Utc::now();
+    let fresh_events = handle
+        .list_events(EventFilter {
+            calendar_tracking_id: calendar.tracking_id.clone(),
+            from: now,
+            to: now + chrono::Duration::days(EVENT_SYNC_WINDOW_DAYS),
+        })
+        .await?;
+
+    let db_events_with_session = db
+        .list_events_with_session(user_id)
+        .await?
+        .into_iter()
+        .filter(|(event, _)| event.calendar_id.as_deref() == Some(calendar.id.as_str()))
+        .collect();
+
+    plan_events(user_id, &calendar.id, db_events_with_session, fresh_events)
+        .execute(db)
+        .await;
+
+    Ok(())
+}
+
+/// Removes the calendars of subscriptions that no longer exist, keeping events that
+/// already have notes attached.
+pub async fn remove_stale_calendars(
+    db: &hypr_db_user::UserDatabase,
+    user_id: &str,
+    subscriptions: &[Subscription],
+) -> Result<(), crate::Error> {
+    let stale: Vec<hypr_db_user::Calendar> = db
+        .list_calendars(user_id)
+        .await?
+        .into_iter()
+        .filter(|c| c.platform == hypr_db_user::Platform::Ics)
+        .filter(|c| !subscriptions.iter().any(|s| s.id == c.tracking_id))
+        .collect();
+
+    remove_calendars(db, user_id, stale).await
+}
+
+pub(crate) async fn remove_calendars(
+    db: &hypr_db_user::UserDatabase,
+    user_id: &str,
+    stale: Vec<hypr_db_user::Calendar>,
+) -> Result<(), crate::Error> {
+    if stale.is_empty() {
+        return Ok(());
+    }
+
+    let mut state = EventSyncState::default();
+    for (event, session) in db.list_events_with_session(user_id).await? {
+        let in_stale_calendar = stale
+            .iter()
+            .any(|c| event.calendar_id.as_deref() == Some(c.id.as_str()));
+
+        if in_stale_calendar && session.is_none_or(|s| s.is_empty()) {
+            state.to_delete.push(event);
+        }
+    }
+    state.execute(db).await;
+
+    for calendar in stale {
+        if let Err(e) = db.delete_calendar(&calendar.id).await {
+            tracing::error!("delete_calendar_error: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn handle(subscription: &Subscription, password: Option<String>) -> hypr_calendar_ics::Handle {
+    let handle = hypr_calendar_ics::Handle::new(
+        &subscription.id,
+        &subscription.url,
+        subscription.kind.into(),
+    );
+
+    match (&subscription.username, password) {
+        (Some(username), Some(password)) => handle.with_credentials(username, password),
+        _ => handle,
+    }
+}
+
+pub(crate) fn plan_calendar(
+    user_id: &str,
+    db_calendars: &[hypr_db_user::Calendar],
+    calendar: hypr_calendar_interface::Calendar,
+    selected_by_default: bool,
+) -> hypr_db_user::Calendar {
+    let existing = db_calendars.iter().find(|c| c.tracking_id == calendar.id);
+
+    hypr_db_user::Calendar {
+        id: existing.map_or(uuid::Uuid::new_v4().to_string(), |c| c.id.clone()),
+        tracking_id: calendar.id,
+        user_id: user_id.to_string(),
+        name: calendar.name,
+        platform: calendar.platform.into(),
+        selected: existing.map_or(selected_by_default, |c| c.selected),
+        source: calendar.source,
+    }
+}
+
+/// Upserts `fresh_events` and deletes the calendar's other events, unless they have notes.
+pub(crate) fn plan_events(
+    user_id: &str,
+    calendar_id: &str,
+    db_events_with_session: Vec<(hypr_db_user::Event, Option<hypr_db_user::Session>)>,
+    fresh_events: Vec<hypr_calendar_interface::Event>,
+) -> EventSyncState {
+    let mut state = EventSyncState::default();
+    let mut fresh_ids = HashSet::new();
+
+    for event in fresh_events {
+        let event = plan_event(user_id, calendar_id, &db_events_with_session, event);
+        fresh_ids.insert(event.tracking_id.clone());
+        state.to_upsert.push(event);
+    }
+
+    for (db_event, session) in db_events_with_session {
+        if !fresh_ids.contains(&db_event.tracking_id) && session.is_none_or(|s| s.is_empty()) {
+            state.to_delete.push(db_event);
+        }
+    }
+
+    state
+}
+
+pub(crate) fn plan_event(
+    user_id: &str,
+    calendar_id: &str,
+    db_events_with_session: &[(hypr_db_user::Event, Option<hypr_db_user::Session>)],
+    event: hypr_calendar_interface::Event,
+) -> hypr_db_user::Event {
+    let tracking_id = event_tracking_id(&event);
+    let existing = db_events_with_session
+        .iter()
+        .find(|(db_event, _)| db_event.tracking_id == tracking_id);
+
+    hypr_db_user::Event {
+        id: existing.map_or(uuid::Uuid::new_v4().to_string(), |(e, _)| e.id.clone()),
+        tracking_id,
+        user_id: user_id.to_string(),
+        calendar_id: Some(calendar_id.to_string()),
+        name: event.name,
+        note: event.note,
+        start_date: event.start_date,
+        end_date: event.end_date,
+        google_event_url: event.google_event_url,
+        participants: Some(
+            serde_json::to_string(&event.participants).unwrap_or_else(|_| "[]".to_string()),
+        ),
+        is_recurring: event.is_recurring,
+        meeting_link: event.meeting_link,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fresh(id: &str, is_recurring: bool) -> hypr_calendar_interface::Event {
+        let start_date = chrono::DateTime::parse_from_rfc3339("2025-01-06T09:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        hypr_calendar_interface::Event {
+            id: id.to_string(),
+            calendar_id: "sub".to_string(),
+            platform: hypr_calendar_interface::Platform::Ics,
+            name: "Standup".to_string(),
+            note: "".to_string(),
+            participants: vec![],
+            start_date,
+            end_date: start_date + chrono::Duration::minutes(15),
+            google_event_url: None,
+            meeting_link: None,
+            is_recurring,
+        }
+    }
+
+    fn stored(tracking_id: &str) -> hypr_db_user::Event {
+        let event = fresh(tracking_id, false);
+
+        hypr_db_user::Event {
+            id: format!("db-{}", tracking_id),
+            tracking_id: tracking_id.to_string(),
+            calendar_id: Some("cal".to_string()),
+            name: "Old name".to_string(),
+            start_date: event.start_date,
+            end_date: event.end_date,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_plan_events() {
+        let state = plan_events(
+            "user",
+            "cal",
+            vec![(stored("kept"), None), (stored("gone"), None)],
+            vec![fresh("kept", false), fresh("series", true)],
+        );
+
+        let upserted: Vec<(&str, &str)> = state
+            .to_upsert
+            .iter()
+            .map(|e| (e.id.as_str(), e.tracking_id.as_str()))
+            .collect();
+        assert_eq!(upserted[0], ("db-kept", "kept"));
+        assert_eq!(upserted[1].1, "series__HYPR__20250106093000");
+        assert_eq!(state.to_upsert[0].name, "Standup");
+
+        assert_eq!(state.to_delete.len(), 1);
+        assert_eq!(state.to_delete[0].tracking_id, "gone");
+    }
+}
@@ -0,0 +1,36 @@
+use crate::CalendarPluginExt;
+
+/// How often due subscriptions are looked for. Each one still waits for its own
+/// refresh interval.
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Google syncs are incremental, so they can run more often than most subscriptions.
+const GOOGLE_POLLS_PER_SYNC: u32 = 5;
+
+pub async fn run<R: tauri::Runtime>(app: tauri::AppHandle<R>) {
+    for poll in 0u32.. {
+        tokio::time::sleep(POLL_INTERVAL).await;
+
+        match app.sync_subscriptions(false).await {
+            Ok(_) | Err(crate::Error::NoneDatabase | crate::Error::NoneUser) => {}
+            Err(e) => tracing::error!("calendar_sync_subscriptions_failed: {}", e),
+        }
+
+        if poll % GOOGLE_POLLS_PER_SYNC != 0 {
+            continue;
+        }
+
+        match app.sync_google_calendar().await {
+            Ok(_)
+            | Err(
+                crate::Error::NoneDatabase
+                | crate::Error::NoneUser
+                | crate::Error::Auth(
+                    tauri_plugin_auth::Error::GoogleCalendarNotConfigured
+                    | tauri_plugin_auth::Error::GoogleCalendarNotConnected,
+                ),
+            ) => {}
+            Err(e) => tracing::error!("calendar_sync_google_failed: {}", e),
+        }
+    }
+}
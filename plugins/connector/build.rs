@@ -30,6 +30,16 @@ const COMMANDS: &[&str] = &[
     "set_openrouter_api_key",
     "get_hyprcloud_enabled",
     "set_hyprcloud_enabled",
+    "list_custom_providers",
+    "upsert_custom_provider",
+    "remove_custom_provider",
+    "list_custom_provider_models",
+    "check_custom_provider",
+    "get_task_routes",
+    "set_task_route",
+    "get_llm_connection_for_task",
+    "get_proxy",
+    "set_proxy",
 ];
 
 fn main() {
@@ -99,6 +99,36 @@ async getHyprcloudEnabled() : Promise<boolean> {
 },
 async setHyprcloudEnabled(enabled: boolean) : Promise<null> {
     return await TAURI_INVOKE("plugin:connector|set_hyprcloud_enabled", { enabled });
+},
+async listCustomProviders() : Promise<CustomProvider[]> {
+    return await TAURI_INVOKE("plugin:connector|list_custom_providers");
+},
+async upsertCustomProvider(provider: CustomProvider) : Promise<null> {
+    return await TAURI_INVOKE("plugin:connector|upsert_custom_provider", { provider });
+},
+async removeCustomProvider(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:connector|remove_custom_provider", { id });
+},
+async listCustomProviderModels(provider: CustomProvider) : Promise<string[]> {
+    return await TAURI_INVOKE("plugin:connector|list_custom_provider_models", { provider });
+},
+async checkCustomProvider(id: string) : Promise<ProviderHealth> {
+    return await TAURI_INVOKE("plugin:connector|check_custom_provider", { id });
+},
+async getTaskRoutes() : Promise<Partial<{ [key in LlmTask]: string }>> {
+    return await TAURI_INVOKE("plugin:connector|get_task_routes");
+},
+async setTaskRoute(task: LlmTask, providerId: string | null) : Promise<null> {
+    return await TAURI_INVOKE("plugin:connector|set_task_route", { task, providerId });
+},
+async getLlmConnectionForTask(task: LlmTask) : Promise<TaskConnection> {
+    return await TAURI_INVOKE("plugin:connector|get_llm_connection_for_task", { task });
+},
+async getProxy() : Promise<ProxyConfig> {
+    return await TAURI_INVOKE("plugin:connector|get_proxy");
+},
+async setProxy(config: ProxyConfig) : Promise<null> {
+    return await TAURI_INVOKE("plugin:connector|set_proxy", { config });
 }
 }
 
@@ -114,6 +144,27 @@ async setHyprcloudEnabled(enabled: boolean) : Promise<null> {
 
 export type Connection = { api_base: string; api_key: string | null }
 export type ConnectionLLM = { type: "HyprCloud"; connection: Connection } | { type: "HyprLocal"; connection: Connection } | { type: "Custom"; connection: Connection }
+/**
+ * A user-registered OpenAI-compatible server (Ollama, LM Studio, vLLM, ...).
+ */
+export type CustomProvider = { id: string; name: string; api_base: string; api_key: string | null; default_model: string | null }
+export type LlmTask = "Enhance" | "Title" | "Chat" | "Tags" | "Translate" | "Brief"
+export type ProviderHealth = { ok: boolean; latency_ms: number; models: string[]; error: string | null }
+export type ProxyConfig = { 
+/**
+ * `http://`, `socks5://` or `socks5h://`, with credentials as `user:pass@`.
+ * `None` falls back to `HTTPS_PROXY`, `HTTP_PROXY` and `ALL_PROXY`.
+ */
+url: string | null; 
+/**
+ * Comma-separated hosts reached directly, like `NO_PROXY`. Only used with `url`.
+ */
+no_proxy: string | null }
+export type TaskConnection = { connection: ConnectionLLM; 
+/**
+ * `None` leaves the choice to the caller's usual default.
+ */
+model: string | null }
 
 /** tauri-specta globals **/
 
@@ -1,4 +1,9 @@
-use crate::{Connection, ConnectionLLM, ConnectorPluginExt, StoreKey};
+use std::collections::HashMap;
+
+use crate::{
+    Connection, ConnectionLLM, ConnectorPluginExt, CustomProvider, LlmTask, ProviderHealth,
+    StoreKey, TaskConnection,
+};
 
 #[tauri::command]
 #[specta::specta]
@@ -328,3 +333,99 @@ pub async fn get_openrouter_model<R: tauri::Runtime>(
         .map_err(|e| e.to_string())?;
     Ok(v.unwrap_or_default())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_custom_providers<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<CustomProvider>, String> {
+    app.list_custom_providers().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn upsert_custom_provider<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    provider: CustomProvider,
+) -> Result<(), String> {
+    app.upsert_custom_provider(provider)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn remove_custom_provider<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<(), String> {
+    app.remove_custom_provider(id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_custom_provider_models<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    provider: CustomProvider,
+) -> Result<Vec<String>, String> {
+    app.list_custom_provider_models(provider)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn check_custom_provider<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<ProviderHealth, String> {
+    app.check_custom_provider(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_task_routes<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<HashMap<LlmTask, String>, String> {
+    app.get_task_routes().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_task_route<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    task: LlmTask,
+    provider_id: Option<String>,
+) -> Result<(), String> {
+    app.set_task_route(task, provider_id)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_llm_connection_for_task<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    task: LlmTask,
+) -> Result<TaskConnection, String> {
+    app.get_llm_connection_for_task(task)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_proxy<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<hypr_proxy::ProxyConfig, String> {
+    app.get_proxy().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_proxy<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    config: hypr_proxy::ProxyConfig,
+) -> Result<(), String> {
+    app.set_proxy(config).map_err(|e| e.to_string())
+}
@@ -13,7 +13,15 @@ pub enum Error {
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
     #[error(transparent)]
+    ProxyError(#[from] hypr_proxy::Error),
+    #[error(transparent)]
     UrlParseError(#[from] url::ParseError),
+    #[error(transparent)]
+    KeyringError(#[from] keyring::Error),
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error("provider not found: {0}")]
+    ProviderNotFound(String),
     #[error("no models found")]
     NoModelsFound,
     #[error("custom error: {0}")]
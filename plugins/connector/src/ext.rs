@@ -1,6 +1,8 @@
-use std::future::Future;
+use std::{collections::HashMap, future::Future};
 
-use crate::{Connection, ConnectionLLM, StoreKey};
+use crate::{
+    Connection, ConnectionLLM, CustomProvider, LlmTask, ProviderHealth, StoreKey, TaskConnection,
+};
 use tauri_plugin_store2::StorePluginExt;
 
 pub trait ConnectorPluginExt<R: tauri::Runtime> {
@@ -27,6 +29,40 @@ pub trait ConnectorPluginExt<R: tauri::Runtime> {
 
     fn get_admin_connection(&self) -> Result<Option<Connection>, crate::Error>;
     fn set_admin_connection(&self, connection: Connection) -> Result<(), crate::Error>;
+
+    fn list_custom_providers(&self) -> Result<Vec<CustomProvider>, crate::Error>;
+    fn upsert_custom_provider(&self, provider: CustomProvider) -> Result<(), crate::Error>;
+    fn remove_custom_provider(&self, id: String) -> Result<(), crate::Error>;
+    fn list_custom_provider_models(
+        &self,
+        provider: CustomProvider,
+    ) -> impl Future<Output = Result<Vec<String>, crate::Error>>;
+    fn check_custom_provider(
+        &self,
+        id: String,
+    ) -> impl Future<Output = Result<ProviderHealth, crate::Error>>;
+
+    fn get_task_routes(&self) -> Result<HashMap<LlmTask, String>, crate::Error>;
+    fn set_task_route(
+        &self,
+        task: LlmTask,
+        provider_id: Option<String>,
+    ) -> Result<(), crate::Error>;
+    fn get_llm_connection_for_task(
+        &self,
+        task: LlmTask,
+    ) -> impl Future<Output = Result<TaskConnection, crate::Error>>;
+    /// Sends one non-streaming chat request to the connection routed for `task`.
+    fn complete_for_task(
+        &self,
+        task: LlmTask,
+        system: String,
+        user: String,
+    ) -> impl Future<Output = Result<String, crate::Error>>;
+
+    fn get_proxy(&self) -> Result<hypr_proxy::ProxyConfig, crate::Error>;
+    /// Saves `config` and routes every request made from now on through it.
+    fn set_proxy(&self, config: hypr_proxy::ProxyConfig) -> Result<(), crate::Error>;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> ConnectorPluginExt<R> for T {
@@ -172,26 +208,198 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> ConnectorPluginExt<R> for T {
 
         Ok(())
     }
+
+    fn list_custom_providers(&self) -> Result<Vec<CustomProvider>, crate::Error> {
+        Ok(self
+            .connector_store()
+            .get(StoreKey::CustomProviders)?
+            .unwrap_or_default())
+    }
+
+    fn upsert_custom_provider(&self, provider: CustomProvider) -> Result<(), crate::Error> {
+        url::Url::parse(&provider.api_base)?;
+
+        let mut providers = self.list_custom_providers()?;
+        match providers.iter_mut().find(|p| p.id == provider.id) {
+            Some(existing) => *existing = provider,
+            None => providers.push(provider),
+        }
+
+        self.connector_store()
+            .set(StoreKey::CustomProviders, providers)?;
+        Ok(())
+    }
+
+    fn remove_custom_provider(&self, id: String) -> Result<(), crate::Error> {
+        let mut providers = self.list_custom_providers()?;
+        providers.retain(|p| p.id != id);
+        self.connector_store()
+            .set(StoreKey::CustomProviders, providers)?;
+
+        let mut routes = self.get_task_routes()?;
+        routes.retain(|_, provider_id| *provider_id != id);
+        self.connector_store().set(StoreKey::TaskRoutes, routes)?;
+
+        Ok(())
+    }
+
+    async fn list_custom_provider_models(
+        &self,
+        provider: CustomProvider,
+    ) -> Result<Vec<String>, crate::Error> {
+        Connection::from(&provider).models().await
+    }
+
+    async fn check_custom_provider(&self, id: String) -> Result<ProviderHealth, crate::Error> {
+        let provider = find_provider(self, &id)?;
+
+        let started = std::time::Instant::now();
+        let result = Connection::from(&provider).models().await;
+        let latency_ms = started.elapsed().as_millis().min(u32::MAX as u128) as u32;
+
+        Ok(match result {
+            Ok(models) => ProviderHealth {
+                ok: true,
+                latency_ms,
+                models,
+                error: None,
+            },
+            Err(e) => ProviderHealth {
+                ok: false,
+                latency_ms,
+                models: vec![],
+                error: Some(e.to_string()),
+            },
+        })
+    }
+
+    fn get_proxy(&self) -> Result<hypr_proxy::ProxyConfig, crate::Error> {
+        let config = self
+            .connector_store()
+            .get(StoreKey::Proxy)?
+            .unwrap_or_default();
+        crate::proxy::load(config)
+    }
+
+    fn set_proxy(&self, config: hypr_proxy::ProxyConfig) -> Result<(), crate::Error> {
+        hypr_proxy::set_config(config.clone())?;
+        let config = crate::proxy::save(config)?;
+        self.connector_store().set(StoreKey::Proxy, config)?;
+        Ok(())
+    }
+
+    fn get_task_routes(&self) -> Result<HashMap<LlmTask, String>, crate::Error> {
+        Ok(self
+            .connector_store()
+            .get(StoreKey::TaskRoutes)?
+            .unwrap_or_default())
+    }
+
+    fn set_task_route(
+        &self,
+        task: LlmTask,
+        provider_id: Option<String>,
+    ) -> Result<(), crate::Error> {
+        let mut routes = self.get_task_routes()?;
+
+        match provider_id {
+            Some(id) => {
+                find_provider(self, &id)?;
+                routes.insert(task, id);
+            }
+            None => {
+                routes.remove(&task);
+            }
+        }
+
+        self.connector_store().set(StoreKey::TaskRoutes, routes)?;
+        Ok(())
+    }
+
+    async fn get_llm_connection_for_task(
+        &self,
+        task: LlmTask,
+    ) -> Result<TaskConnection, crate::Error> {
+        let routed = match self.get_task_routes()?.get(&task) {
+            Some(id) => self
+                .list_custom_providers()?
+                .into_iter()
+                .find(|p| &p.id == id),
+            None => None,
+        };
+
+        if let Some(provider) = routed {
+            return Ok(TaskConnection {
+                connection: ConnectionLLM::Custom(Connection::from(&provider)),
+                model: provider.default_model,
+            });
+        }
+
+        let connection = self.get_llm_connection().await?;
+        let model = match connection {
+            ConnectionLLM::Custom(_) => self.get_custom_llm_model()?,
+            _ => None,
+        };
+
+        Ok(TaskConnection { connection, model })
+    }
+
+    async fn complete_for_task(
+        &self,
+        task: LlmTask,
+        system: String,
+        user: String,
+    ) -> Result<String, crate::Error> {
+        let TaskConnection { connection, model } = self.get_llm_connection_for_task(task).await?;
+        connection
+            .complete(model.unwrap_or_default(), system, user)
+            .await
+    }
+}
+
+fn find_provider<R: tauri::Runtime, T: tauri::Manager<R>>(
+    app: &T,
+    id: &str,
+) -> Result<CustomProvider, crate::Error> {
+    app.list_custom_providers()?
+        .into_iter()
+        .find(|p| p.id == id)
+        .ok_or_else(|| crate::Error::ProviderNotFound(id.to_string()))
 }
 
 trait OpenaiCompatible {
     fn models(&self) -> impl Future<Output = Result<Vec<String>, crate::Error>>;
+    fn complete(
+        &self,
+        model: String,
+        system: String,
+        user: String,
+    ) -> impl Future<Output = Result<String, crate::Error>>;
 }
 
 impl OpenaiCompatible for ConnectionLLM {
     async fn models(&self) -> Result<Vec<String>, crate::Error> {
-        let conn = self.as_ref();
-        let api_base = &conn.api_base;
-        let api_key = &conn.api_key;
-
-        let url = {
-            let mut u = url::Url::parse(api_base)?;
-            u.set_path("/v1/models");
-            u
-        };
+        self.as_ref().models().await
+    }
 
-        let mut req = reqwest::Client::new().get(url);
-        if let Some(api_key) = api_key {
+    async fn complete(
+        &self,
+        model: String,
+        system: String,
+        user: String,
+    ) -> Result<String, crate::Error> {
+        self.as_ref().complete(model, system, user).await
+    }
+}
+
+impl OpenaiCompatible for Connection {
+    async fn models(&self) -> Result<Vec<String>, crate::Error> {
+        let url = models_url(&self.api_base)?;
+
+        let mut req = hypr_proxy::client()
+            .get(url)
+            .timeout(std::time::Duration::from_secs(10));
+        if let Some(api_key) = &self.api_key {
             req = req.bearer_auth(api_key);
         }
 
@@ -221,4 +429,76 @@ impl OpenaiCompatible for ConnectionLLM {
 
         Ok(models)
     }
+
+    async fn complete(
+        &self,
+        model: String,
+        system: String,
+        user: String,
+    ) -> Result<String, crate::Error> {
+        // Same base URL the frontend hands to the AI SDK, which appends `/chat/completions`.
+        let url = format!("{}/chat/completions", self.api_base.trim_end_matches('/'));
+
+        let mut req = hypr_proxy::client()
+            .post(url)
+            .timeout(std::time::Duration::from_secs(60))
+            .json(&serde_json::json!({
+                "model": model,
+                "stream": false,
+                "messages": [
+                    { "role": "system", "content": system },
+                    { "role": "user", "content": user },
+                ],
+            }));
+        if let Some(api_key) = &self.api_key {
+            req = req.bearer_auth(api_key);
+        }
+
+        let res: serde_json::Value = req.send().await?.error_for_status()?.json().await?;
+        res["choices"][0]["message"]["content"]
+            .as_str()
+            .map(|content| content.trim().to_string())
+            .ok_or_else(|| crate::Error::UnknownError(format!("no_completion: {:?}", res)))
+    }
+}
+
+/// `/v1/models` under the base URL, whether or not the base already ends in `/v1`.
+fn models_url(api_base: &str) -> Result<url::Url, crate::Error> {
+    let mut url = url::Url::parse(api_base)?;
+
+    let path = url.path().trim_end_matches('/').to_string();
+    let path = if path.ends_with("/v1") {
+        format!("{}/models", path)
+    } else {
+        format!("{}/v1/models", path)
+    };
+    url.set_path(&path);
+
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_models_url() {
+        for (api_base, expected) in [
+            ("http://localhost:11434", "http://localhost:11434/v1/models"),
+            (
+                "http://localhost:1234/v1",
+                "http://localhost:1234/v1/models",
+            ),
+            (
+                "http://localhost:8000/v1/",
+                "http://localhost:8000/v1/models",
+            ),
+            (
+                "https://example.com/openai/v1",
+                "https://example.com/openai/v1/models",
+            ),
+        ] {
+            assert_eq!(models_url(api_base).unwrap().as_str(), expected);
+        }
+    }
 }
@@ -1,6 +1,7 @@
 mod commands;
 mod error;
 mod ext;
+mod proxy;
 mod store;
 mod types;
 
@@ -46,6 +47,16 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::set_openrouter_api_key::<tauri::Wry>,
             commands::get_hyprcloud_enabled::<tauri::Wry>,
             commands::set_hyprcloud_enabled::<tauri::Wry>,
+            commands::list_custom_providers::<tauri::Wry>,
+            commands::upsert_custom_provider::<tauri::Wry>,
+            commands::remove_custom_provider::<tauri::Wry>,
+            commands::list_custom_provider_models::<tauri::Wry>,
+            commands::check_custom_provider::<tauri::Wry>,
+            commands::get_task_routes::<tauri::Wry>,
+            commands::set_task_route::<tauri::Wry>,
+            commands::get_llm_connection_for_task::<tauri::Wry>,
+            commands::get_proxy::<tauri::Wry>,
+            commands::set_proxy::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
@@ -55,6 +66,18 @@ pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
 
     tauri::plugin::Builder::new(PLUGIN_NAME)
         .invoke_handler(specta_builder.invoke_handler())
+        .setup(|app, _api| {
+            // Before anything else goes out, so no request skips the saved proxy.
+            match app.get_proxy() {
+                Ok(config) => {
+                    if let Err(e) = hypr_proxy::set_config(config) {
+                        tracing::warn!("proxy_config_invalid: {}", e);
+                    }
+                }
+                Err(e) => tracing::warn!("proxy_config_unreadable: {}", e),
+            }
+            Ok(())
+        })
         .build()
 }
 
@@ -0,0 +1,65 @@
+//! The proxy's `user:pass@` is kept in the keyring, and only the rest of the URL in the store.
+
+use hypr_proxy::ProxyConfig;
+
+const KEYRING_SERVICE: &str = "hyprnote-proxy";
+const KEYRING_USER: &str = "credentials";
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Credentials {
+    username: String,
+    password: Option<String>,
+}
+
+fn entry() -> Result<keyring::Entry, crate::Error> {
+    Ok(keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER)?)
+}
+
+/// Saves the credentials in `config`'s URL, and returns the config to store without them.
+pub fn save(mut config: ProxyConfig) -> Result<ProxyConfig, crate::Error> {
+    let Some(mut url) = config.url.as_deref().map(url::Url::parse).transpose()? else {
+        delete()?;
+        return Ok(config);
+    };
+
+    if url.username().is_empty() {
+        delete()?;
+        return Ok(config);
+    }
+
+    let credentials = Credentials {
+        username: url.username().to_string(),
+        password: url.password().map(str::to_string),
+    };
+    entry()?.set_password(&serde_json::to_string(&credentials)?)?;
+
+    let _ = url.set_username("");
+    let _ = url.set_password(None);
+    config.url = Some(url.to_string());
+    Ok(config)
+}
+
+/// Puts the saved credentials back into a stored config's URL.
+pub fn load(mut config: ProxyConfig) -> Result<ProxyConfig, crate::Error> {
+    let Some(mut url) = config.url.as_deref().map(url::Url::parse).transpose()? else {
+        return Ok(config);
+    };
+
+    let credentials = match entry()?.get_password() {
+        Ok(credentials) => serde_json::from_str::<Credentials>(&credentials)?,
+        Err(keyring::Error::NoEntry) => return Ok(config),
+        Err(e) => return Err(e.into()),
+    };
+
+    let _ = url.set_username(&credentials.username);
+    let _ = url.set_password(credentials.password.as_deref());
+    config.url = Some(url.to_string());
+    Ok(config)
+}
+
+fn delete() -> Result<(), crate::Error> {
+    match entry()?.delete_credential() {
+        Ok(_) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(e.into()),
+    }
+}
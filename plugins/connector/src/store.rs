@@ -19,6 +19,9 @@ pub enum StoreKey {
     GeminiModel,
     OpenrouterModel,
     HyprCloudEnabled,
+    CustomProviders,
+    TaskRoutes,
+    Proxy,
 }
 
 impl ScopedStoreKey for StoreKey {}
@@ -31,3 +31,49 @@ impl AsRef<Connection> for ConnectionLLM {
         }
     }
 }
+
+/// A user-registered OpenAI-compatible server (Ollama, LM Studio, vLLM, ...).
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, specta::Type)]
+pub struct CustomProvider {
+    pub id: String,
+    pub name: String,
+    pub api_base: String,
+    pub api_key: Option<String>,
+    pub default_model: Option<String>,
+}
+
+impl From<&CustomProvider> for Connection {
+    fn from(value: &CustomProvider) -> Self {
+        Connection {
+            api_base: value.api_base.clone(),
+            api_key: value.api_key.clone(),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize, specta::Type,
+)]
+pub enum LlmTask {
+    Enhance,
+    Title,
+    Chat,
+    Tags,
+    Translate,
+    Brief,
+}
+
+#[derive(Debug, serde::Serialize, specta::Type)]
+pub struct ProviderHealth {
+    pub ok: bool,
+    pub latency_ms: u32,
+    pub models: Vec<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, specta::Type)]
+pub struct TaskConnection {
+    pub connection: ConnectionLLM,
+    /// `None` leaves the choice to the caller's usual default.
+    pub model: Option<String>,
+}
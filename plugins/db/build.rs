@@ -9,6 +9,7 @@ const COMMANDS: &[&str] = &[
     "thank_you_session_id",
     "visit_session",
     "upsert_session",
+    "upsert_enhanced_session",
     "list_sessions",
     "delete_session",
     "get_session",
@@ -20,10 +21,17 @@ const COMMANDS: &[&str] = &[
     "session_get_event",
     "get_words_onboarding",
     "get_words",
+    "list_session_timeline",
+    "get_session_stats",
+    "list_session_chapters",
+    "list_session_revisions",
+    "restore_session_revision",
     // template
     "list_templates",
     "upsert_template",
     "delete_template",
+    "get_pipeline",
+    "upsert_pipeline",
     // event
     "get_event",
     "list_events",
@@ -41,6 +49,9 @@ const COMMANDS: &[&str] = &[
     "get_organization_by_user_id",
     "list_organizations",
     "list_organization_members",
+    // people
+    "list_people",
+    "list_meetings_with",
     // chat
     "list_chat_groups",
     "list_chat_messages",
@@ -54,6 +65,22 @@ const COMMANDS: &[&str] = &[
     "list_session_tags",
     "assign_tag_to_session",
     "unassign_tag_from_session",
+    // speaker
+    "list_speaker_profiles",
+    "delete_speaker_profile",
+    // migration
+    "pending_migrations",
+    // snapshot
+    "snapshot_database",
+    // attachment
+    "add_attachment",
+    "list_attachments",
+    "remove_attachment",
+    "attachment_quota",
+    "gc_attachments",
+    // retention
+    "preview_retention",
+    "apply_retention",
 ];
 
 fn main() {
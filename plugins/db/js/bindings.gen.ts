@@ -28,6 +28,9 @@ async toggleCalendarSelected(trackingId: string) : Promise<Calendar> {
 async upsertSession(session: Session) : Promise<Session> {
     return await TAURI_INVOKE("plugin:db|upsert_session", { session });
 },
+async upsertEnhancedSession(session: Session) : Promise<Session> {
+    return await TAURI_INVOKE("plugin:db|upsert_enhanced_session", { session });
+},
 async visitSession(id: string) : Promise<null> {
     return await TAURI_INVOKE("plugin:db|visit_session", { id });
 },
@@ -40,6 +43,12 @@ async upsertTemplate(template: Template) : Promise<Template> {
 async deleteTemplate(id: string) : Promise<null> {
     return await TAURI_INVOKE("plugin:db|delete_template", { id });
 },
+async getPipeline(templateId: string | null) : Promise<Pipeline> {
+    return await TAURI_INVOKE("plugin:db|get_pipeline", { templateId });
+},
+async upsertPipeline(pipeline: Pipeline) : Promise<Pipeline> {
+    return await TAURI_INVOKE("plugin:db|upsert_pipeline", { pipeline });
+},
 async onboardingSessionId() : Promise<string> {
     return await TAURI_INVOKE("plugin:db|onboarding_session_id");
 },
@@ -79,6 +88,21 @@ async getWords(sessionId: string) : Promise<Word2[]> {
 async getWordsOnboarding() : Promise<Word2[]> {
     return await TAURI_INVOKE("plugin:db|get_words_onboarding");
 },
+async listSessionTimeline(sessionId: string) : Promise<TimelineEvent[]> {
+    return await TAURI_INVOKE("plugin:db|list_session_timeline", { sessionId });
+},
+async getSessionStats(sessionId: string) : Promise<SessionStats | null> {
+    return await TAURI_INVOKE("plugin:db|get_session_stats", { sessionId });
+},
+async listSessionChapters(sessionId: string) : Promise<Chapter[]> {
+    return await TAURI_INVOKE("plugin:db|list_session_chapters", { sessionId });
+},
+async listSessionRevisions(sessionId: string) : Promise<SessionRevision[]> {
+    return await TAURI_INVOKE("plugin:db|list_session_revisions", { sessionId });
+},
+async restoreSessionRevision(revisionId: string) : Promise<Session> {
+    return await TAURI_INVOKE("plugin:db|restore_session_revision", { revisionId });
+},
 async getConfig() : Promise<Config> {
     return await TAURI_INVOKE("plugin:db|get_config");
 },
@@ -97,6 +121,12 @@ async listHumans(filter: ListHumanFilter | null) : Promise<Human[]> {
 async deleteHuman(id: string) : Promise<null> {
     return await TAURI_INVOKE("plugin:db|delete_human", { id });
 },
+async listSpeakerProfiles() : Promise<SpeakerProfile[]> {
+    return await TAURI_INVOKE("plugin:db|list_speaker_profiles");
+},
+async deleteSpeakerProfile(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:db|delete_speaker_profile", { id });
+},
 async getOrganization(id: string) : Promise<Organization | null> {
     return await TAURI_INVOKE("plugin:db|get_organization", { id });
 },
@@ -115,6 +145,12 @@ async listOrganizations(filter: ListOrganizationFilter | null) : Promise<Organiz
 async listOrganizationMembers(organizationId: string) : Promise<Human[]> {
     return await TAURI_INVOKE("plugin:db|list_organization_members", { organizationId });
 },
+async listPeople(userId: string, filter: ListPeopleFilter | null) : Promise<Person[]> {
+    return await TAURI_INVOKE("plugin:db|list_people", { userId, filter });
+},
+async listMeetingsWith(userId: string, personId: string) : Promise<PersonMeeting[]> {
+    return await TAURI_INVOKE("plugin:db|list_meetings_with", { userId, personId });
+},
 async listChatGroups(sessionId: string) : Promise<ChatGroup[]> {
     return await TAURI_INVOKE("plugin:db|list_chat_groups", { sessionId });
 },
@@ -147,12 +183,44 @@ async upsertTag(tag: Tag) : Promise<Tag> {
 },
 async deleteTag(tagId: string) : Promise<null> {
     return await TAURI_INVOKE("plugin:db|delete_tag", { tagId });
+},
+async pendingMigrations() : Promise<MigrationReport> {
+    return await TAURI_INVOKE("plugin:db|pending_migrations");
+},
+async snapshotDatabase(path: string, channel: TAURI_CHANNEL<SnapshotProgress>) : Promise<null> {
+    return await TAURI_INVOKE("plugin:db|snapshot_database", { path, channel });
+},
+async addAttachment(sessionId: string, source: AttachmentSource) : Promise<Attachment> {
+    return await TAURI_INVOKE("plugin:db|add_attachment", { sessionId, source });
+},
+async listAttachments(sessionId: string) : Promise<Attachment[]> {
+    return await TAURI_INVOKE("plugin:db|list_attachments", { sessionId });
+},
+async removeAttachment(attachmentId: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:db|remove_attachment", { attachmentId });
+},
+async attachmentQuota() : Promise<AttachmentQuota> {
+    return await TAURI_INVOKE("plugin:db|attachment_quota");
+},
+async gcAttachments() : Promise<AttachmentGcReport> {
+    return await TAURI_INVOKE("plugin:db|gc_attachments");
+},
+async previewRetention() : Promise<RetentionPreview> {
+    return await TAURI_INVOKE("plugin:db|preview_retention");
+},
+async applyRetention() : Promise<RetentionReport> {
+    return await TAURI_INVOKE("plugin:db|apply_retention");
 }
 }
 
 /** user-defined events **/
 
 
+export const events = __makeEvents__<{
+sessionChanged: SessionChanged
+}>({
+sessionChanged: "plugin:db:session-changed"
+})
 
 /** user-defined constants **/
 
@@ -160,30 +228,227 @@ async deleteTag(tagId: string) : Promise<null> {
 
 /** user-defined types **/
 
+export type Attachment = { id: string; session_id: string; hash: string; file_name: string; kind: AttachmentKind; mime_type: string; size_bytes: number; created_at: string }
+export type AttachmentGcReport = { removed_count: number; freed_bytes: number }
+export type AttachmentKind = "audio" | "image" | "other"
+export type AttachmentQuota = { usage: AttachmentUsage; quota_bytes: number; over_quota: boolean }
+export type AttachmentSource = { type: "path"; path: string } | { type: "bytes"; file_name: string; data: number[] }
+export type AttachmentUsage = { blob_count: number; total_bytes: number; orphaned_count: number; orphaned_bytes: number }
 export type Calendar = { id: string; tracking_id: string; user_id: string; platform: Platform; name: string; selected: boolean; source: string | null }
+/**
+ * A stretch of the transcript about one topic.
+ */
+export type Chapter = { id: string; session_id: string; title: string; 
+/**
+ * Unix milliseconds, the same clock as transcript words.
+ */
+start_ms: number; end_ms: number }
 export type ChatGroup = { id: string; user_id: string; name: string | null; created_at: string; session_id: string }
 export type ChatMessage = { id: string; group_id: string; created_at: string; role: ChatMessageRole; content: string; type: ChatMessageType; tool_details: string | null }
 export type ChatMessageRole = "User" | "Assistant"
 export type ChatMessageType = "text-delta" | "tool-start" | "tool-result" | "tool-error"
 export type Config = { id: string; user_id: string; general: ConfigGeneral; notification: ConfigNotification; ai: ConfigAI }
 export type ConfigAI = { api_base: string | null; api_key: string | null; ai_specificity: number | null; redemption_time_ms: number | null }
-export type ConfigGeneral = { autostart: boolean; display_language: string; spoken_languages?: string[]; jargons?: string[]; telemetry_consent: boolean; save_recordings: boolean | null; selected_template_id: string | null; summary_language?: string }
-export type ConfigNotification = { before: boolean; auto: boolean; ignoredPlatforms: string[] | null }
-export type Event = { id: string; user_id: string; tracking_id: string; calendar_id: string | null; name: string; note: string; start_date: string; end_date: string; google_event_url: string | null; participants: string | null; is_recurring: boolean }
+export type ConfigGeneral = { autostart: boolean; display_language: string; spoken_languages?: string[]; jargons?: string[]; dictionary?: DictionaryEntry[]; 
+/**
+ * Applied to "um", "uh" and the like before transcripts are saved.
+ */
+filler_words?: WordFilter; profanity?: WordFilter; save_recordings: boolean | null; selected_template_id: string | null; summary_language?: string; 
+/**
+ * Finalized segments are also translated into this language while recording.
+ */
+translation_language?: string | null; 
+/**
+ * Saying this while no note is recording starts one, like the global shortcut. Off
+ * when `None`; the mic is only listened to while it is set.
+ */
+wake_phrase?: string | null; 
+/**
+ * Recordings are deleted this many days after the meeting. Kept when `None`.
+ */
+audio_retention_days?: number | null; 
+/**
+ * Transcripts are cleared this many months after the meeting. Notes, enhanced or not,
+ * are always kept. Kept when `None`.
+ */
+transcript_retention_months?: number | null; 
+/**
+ * The latest captions are kept in this text file while recording, for streaming tools
+ * like OBS to show as a text source. Off when `None`.
+ */
+caption_file?: string | null; 
+/**
+ * Lines of captions kept in `caption_file`. Two when `None`.
+ */
+caption_lines?: number | null }
+export type ConfigNotification = { before: boolean; auto: boolean; ignoredPlatforms: string[] | null; 
+/**
+ * Notifies once a recording has run this many minutes. Off when `None`.
+ */
+recording_length_alert_minutes?: number | null; 
+/**
+ * Notifies when a recording runs past the scheduled end of its calendar event.
+ */
+recording_overrun_alert?: boolean }
+/**
+ * A name or term transcripts should always spell the same way.
+ */
+export type DictionaryEntry = { term: string; 
+/**
+ * What the transcriber hears instead, e.g. "hyper note" for "Hyprnote".
+ */
+sounds_like?: string[] }
+export type Event = { id: string; user_id: string; tracking_id: string; calendar_id: string | null; name: string; note: string; start_date: string; end_date: string; google_event_url: string | null; participants: string | null; is_recurring: boolean; 
+/**
+ * Video call link from the calendar's conference data. Calendars without one
+ * leave the link in `note`, for `parse_meeting_link` to find.
+ */
+meeting_link?: string | null }
+export type ExpiredRecording = { session_id: string; 
+/**
+ * `None` when the note was deleted and only the audio is left.
+ */
+title: string | null; recorded_at: string; size_bytes: number }
 export type GetSessionFilter = { id: string } | { calendarEventId: string } | { tagId: string }
 export type Human = { id: string; organization_id: string | null; is_user: boolean; full_name: string | null; email: string | null; job_title: string | null; linkedin_username: string | null }
 export type ListEventFilter = ({ user_id: string; limit: number | null }) & ({ type: "simple" } | { type: "search"; query: string } | { type: "dateRange"; start: string; end: string } | { type: "not-assigned-past" })
 export type ListHumanFilter = { search: [number, string] }
 export type ListOrganizationFilter = { search: [number, string] }
-export type ListSessionFilter = ({ user_id: string; limit: number | null }) & ({ type: "search"; query: string } | { type: "recentlyVisited" } | { type: "dateRange"; start: string; end: string } | { type: "tagFilter"; tag_ids: string[] })
+export type ListPeopleFilter = { search: [number, string] }
+export type ListSessionFilter = ({ user_id: string; limit: number | null }) & ({ type: "search"; query: string } | { type: "recentlyVisited" } | { type: "dateRange"; start: string; end: string } | { type: "tagFilter"; tag_ids: string[] } | 
+/**
+ * Sessions shared with any of these people, those shared with the most of them first.
+ */
+{ type: "participants"; emails: string[] })
+export type MigrationReport = { current_version: number; latest_version: number; pending: PendingMigration[] }
 export type Organization = { id: string; name: string; description: string | null }
-export type Platform = "Apple" | "Google" | "Outlook"
+export type PendingMigration = { version: number; name: string }
+/**
+ * Someone the user has met, gathered from calendar attendees and note participants.
+ */
+export type Person = { 
+/**
+ * The lowercased email, or the name for attendees without one.
+ */
+id: string; name: string | null; email: string | null; 
+/**
+ * Domain of the email, unless it belongs to a personal mail provider.
+ */
+organization_domain: string | null; 
+/**
+ * Entries in `humans` that are this person.
+ */
+human_ids: string[]; meeting_count: number; 
+/**
+ * Start of the most recent meeting, which can be an upcoming one.
+ */
+latest_meeting_at: string }
+/**
+ * A calendar event or a note, counted once when the note belongs to the event.
+ */
+export type PersonMeeting = { event_id: string | null; session_id: string | null; title: string; date: string }
+/**
+ * What runs after a recording stops, for notes enhanced with a template.
+ */
+export type Pipeline = { 
+/**
+ * `Pipeline::DEFAULT_TEMPLATE_ID` for notes enhanced without one.
+ */
+template_id: string; 
+/**
+ * In the order they run.
+ */
+steps: PipelineStep[] }
+export type PipelineStage = 
+/**
+ * Transcribes the recording on-device, when nothing was transcribed live.
+ */
+"transcribe" | 
+/**
+ * Names speakers whose voice matches a known speaker profile.
+ */
+"diarize" | "summarize" | "action_items" | "title" | "tags"
+export type PipelineStep = { stage: PipelineStage; enabled: boolean }
+export type Platform = "Apple" | "Google" | "Outlook" | "Ics"
+/**
+ * Everything the configured retention would remove if it ran now.
+ */
+export type RetentionPreview = { recordings: ExpiredRecording[]; recording_bytes: number; transcripts: SessionSummary[] }
+export type RetentionReport = { deleted_recordings: number; freed_bytes: number; cleared_transcripts: number }
 export type Session = { id: string; created_at: string; visited_at: string; user_id: string; calendar_event_id: string | null; title: string; raw_memo_html: string; enhanced_memo_html: string | null; words: Word2[]; record_start: string | null; record_end: string | null; pre_meeting_memo_html: string | null }
+/**
+ * Emitted after a note is created, edited, opened or deleted, so views outside the webview
+ * (like the tray) can refresh.
+ */
+export type SessionChanged = { id: string }
+/**
+ * A session's notes and transcript as they were before a change, and what made the change.
+ */
+export type SessionRevision = { id: string; session_id: string; created_at: string; source: SessionRevisionSource; 
+/**
+ * The user who edited or had the note enhanced, or the model that re-transcribed.
+ */
+author: string; changes: SessionRevisionField[]; raw_memo_html: string; enhanced_memo_html: string | null; 
+/**
+ * Transcripts are large, so they are only kept when `changes` has `words`.
+ */
+words: Word2[] }
+export type SessionRevisionField = "raw_memo_html" | "enhanced_memo_html" | "words"
+export type SessionRevisionSource = "edit" | "enhancement" | "retranscription" | "restore"
+/**
+ * Computed on-device when a recording stops.
+ */
+export type SessionStats = { duration_ms: number; word_count: number; interruptions: number; 
+/**
+ * Share of the recording without speech according to VAD, from 0 to 1.
+ */
+silence_ratio: number | null; 
+/**
+ * Ordered by talk time, longest first.
+ */
+speakers: SpeakerStats[] }
+/**
+ * A session without its notes or transcript, for going over many at once.
+ */
+export type SessionSummary = { id: string; title: string; created_at: string; record_end: string | null }
+export type SnapshotProgress = { copied_pages: number; total_pages: number }
 export type SpeakerIdentity = { type: "unassigned"; value: { index: number } } | { type: "assigned"; value: { id: string; label: string } }
+/**
+ * A voice the user put a name to, kept on this device to recognize it in later meetings.
+ */
+export type SpeakerProfile = { id: string; user_id: string; name: string; 
+/**
+ * Set when the name was picked from the user's contacts.
+ */
+human_id: string | null; sample_count: number; updated_at: string }
+export type SpeakerStats = { 
+/**
+ * `None` for words the transcriber didn't attribute to anyone.
+ */
+speaker: SpeakerIdentity | null; talk_time_ms: number; word_count: number; 
+/**
+ * Turns this speaker started while someone else was still talking.
+ */
+interruptions: number }
+export type TAURI_CHANNEL<TSend> = null
 export type Tag = { id: string; name: string }
 export type Template = { id: string; user_id: string; title: string; description: string; sections: TemplateSection[]; tags: string[]; context_option: string | null }
 export type TemplateSection = { title: string; description: string }
+/**
+ * Something other than speech that happened during a recording.
+ */
+export type TimelineEvent = { id: string; session_id: string; kind: TimelineEventKind; 
+/**
+ * Unix milliseconds, the same clock as transcript words.
+ */
+start_ms: number; 
+/**
+ * Set for periods like a mute; `None` for instants and periods still going on.
+ */
+end_ms: number | null; label: string | null }
+export type TimelineEventKind = "mic_muted" | "speaker_muted" | "highlight"
 export type Word2 = { text: string; speaker: SpeakerIdentity | null; confidence: number | null; start_ms: number | null; end_ms: number | null }
+export type WordFilter = "keep" | "remove" | "mask"
 
 /** tauri-specta globals **/
 
@@ -0,0 +1,145 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+pub const ATTACHMENT_QUOTA_BYTES: i64 = 5 * 1024 * 1024 * 1024;
+
+#[derive(Debug, Clone, serde::Deserialize, specta::Type)]
+#[serde(tag = "type")]
+pub enum AttachmentSource {
+    #[serde(rename = "path")]
+    Path { path: String },
+    #[serde(rename = "bytes")]
+    Bytes { file_name: String, data: Vec<u8> },
+}
+
+#[derive(Debug, Clone, serde::Serialize, specta::Type)]
+pub struct AttachmentQuota {
+    pub usage: hypr_db_user::AttachmentUsage,
+    pub quota_bytes: i64,
+    pub over_quota: bool,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, specta::Type)]
+pub struct AttachmentGcReport {
+    pub removed_count: i64,
+    pub freed_bytes: i64,
+}
+
+pub struct StoredBlob {
+    pub hash: String,
+    pub size_bytes: i64,
+}
+
+// Blobs are sharded by the first two hex chars of their SHA-256, e.g. `ab/abcdef...`.
+pub fn blob_path(root: &Path, hash: &str) -> PathBuf {
+    root.join(&hash[..2]).join(hash)
+}
+
+pub fn store_blob(root: &Path, mut reader: impl Read) -> std::io::Result<StoredBlob> {
+    std::fs::create_dir_all(root)?;
+
+    let tmp_path = root.join(format!(".{}.tmp", uuid::Uuid::new_v4()));
+    let mut hasher = Sha256::new();
+    let mut size_bytes = 0i64;
+
+    {
+        let mut tmp = std::fs::File::create(&tmp_path)?;
+        let mut buf = [0u8; 64 * 1024];
+
+        loop {
+            let n = reader.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+
+            hasher.update(&buf[..n]);
+            std::io::Write::write_all(&mut tmp, &buf[..n])?;
+            size_bytes += n as i64;
+        }
+    }
+
+    let hash = hex::encode(hasher.finalize());
+    let path = blob_path(root, &hash);
+
+    if path.exists() {
+        std::fs::remove_file(&tmp_path)?;
+    } else {
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        std::fs::rename(&tmp_path, &path)?;
+    }
+
+    Ok(StoredBlob { hash, size_bytes })
+}
+
+pub fn guess_mime_type(file_name: &str) -> &'static str {
+    let ext = Path::new(file_name)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+
+    match ext.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "wav" => "audio/wav",
+        "mp3" => "audio/mpeg",
+        "m4a" => "audio/mp4",
+        "ogg" => "audio/ogg",
+        "flac" => "audio/flac",
+        "pdf" => "application/pdf",
+        _ => "application/octet-stream",
+    }
+}
+
+pub fn kind_from_mime_type(mime_type: &str) -> hypr_db_user::AttachmentKind {
+    if mime_type.starts_with("audio/") {
+        hypr_db_user::AttachmentKind::Audio
+    } else if mime_type.starts_with("image/") {
+        hypr_db_user::AttachmentKind::Image
+    } else {
+        hypr_db_user::AttachmentKind::Other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_store_blob_dedup() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let a = store_blob(dir.path(), "hello".as_bytes()).unwrap();
+        let b = store_blob(dir.path(), "hello".as_bytes()).unwrap();
+
+        assert_eq!(a.hash, b.hash);
+        assert_eq!(a.size_bytes, 5);
+        assert_eq!(
+            a.hash,
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+        assert!(blob_path(dir.path(), &a.hash).exists());
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_kind_from_file_name() {
+        assert_eq!(
+            kind_from_mime_type(guess_mime_type("rec.WAV")),
+            hypr_db_user::AttachmentKind::Audio
+        );
+        assert_eq!(
+            kind_from_mime_type(guess_mime_type("pasted.png")),
+            hypr_db_user::AttachmentKind::Image
+        );
+        assert_eq!(
+            kind_from_mime_type(guess_mime_type("notes")),
+            hypr_db_user::AttachmentKind::Other
+        );
+    }
+}
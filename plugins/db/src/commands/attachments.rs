@@ -0,0 +1,72 @@
+use crate::DatabasePluginExt;
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(app, source))]
+pub async fn add_attachment<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    source: crate::AttachmentSource,
+) -> Result<hypr_db_user::Attachment, String> {
+    app.db_add_attachment(session_id, source)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(state))]
+pub async fn list_attachments(
+    state: tauri::State<'_, crate::ManagedState>,
+    session_id: String,
+) -> Result<Vec<hypr_db_user::Attachment>, String> {
+    let guard = state.lock().await;
+
+    let db = guard
+        .db
+        .as_ref()
+        .ok_or(crate::Error::NoneDatabase)
+        .map_err(|e| e.to_string())?;
+
+    db.list_session_attachments(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(state))]
+pub async fn remove_attachment(
+    state: tauri::State<'_, crate::ManagedState>,
+    attachment_id: String,
+) -> Result<(), String> {
+    let guard = state.lock().await;
+
+    let db = guard
+        .db
+        .as_ref()
+        .ok_or(crate::Error::NoneDatabase)
+        .map_err(|e| e.to_string())?;
+
+    db.unlink_attachment(attachment_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(app))]
+pub async fn attachment_quota<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<crate::AttachmentQuota, String> {
+    app.db_attachment_quota().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(app))]
+pub async fn gc_attachments<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<crate::AttachmentGcReport, String> {
+    app.db_gc_attachments().await.map_err(|e| e.to_string())
+}
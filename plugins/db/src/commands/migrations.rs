@@ -0,0 +1,18 @@
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(state))]
+pub async fn pending_migrations(
+    state: tauri::State<'_, crate::ManagedState>,
+) -> Result<hypr_db_user::MigrationReport, String> {
+    let guard = state.lock().await;
+
+    let db = guard
+        .db
+        .as_ref()
+        .ok_or(crate::Error::NoneDatabase)
+        .map_err(|e| e.to_string())?;
+
+    hypr_db_user::pending_migrations(db)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -1,9 +1,15 @@
+pub mod attachments;
 pub mod calendars;
 pub mod chats;
 pub mod configs;
 pub mod events;
 pub mod humans;
+pub mod migrations;
 pub mod organizations;
+pub mod people;
+pub mod retention;
 pub mod sessions;
+pub mod snapshots;
+pub mod speakers;
 pub mod tags;
 pub mod templates;
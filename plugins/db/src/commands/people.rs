@@ -0,0 +1,41 @@
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(state))]
+pub async fn list_people(
+    state: tauri::State<'_, crate::ManagedState>,
+    user_id: String,
+    filter: Option<hypr_db_user::ListPeopleFilter>,
+) -> Result<Vec<hypr_db_user::Person>, String> {
+    let guard = state.lock().await;
+
+    let db = guard
+        .db
+        .as_ref()
+        .ok_or(crate::Error::NoneDatabase)
+        .map_err(|e| e.to_string())?;
+
+    db.list_people(user_id, filter)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(state))]
+pub async fn list_meetings_with(
+    state: tauri::State<'_, crate::ManagedState>,
+    user_id: String,
+    person_id: String,
+) -> Result<Vec<hypr_db_user::PersonMeeting>, String> {
+    let guard = state.lock().await;
+
+    let db = guard
+        .db
+        .as_ref()
+        .ok_or(crate::Error::NoneDatabase)
+        .map_err(|e| e.to_string())?;
+
+    db.list_meetings_with(user_id, person_id)
+        .await
+        .map_err(|e| e.to_string())
+}
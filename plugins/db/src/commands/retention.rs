@@ -0,0 +1,19 @@
+use crate::DatabasePluginExt;
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(app))]
+pub async fn preview_retention<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<crate::RetentionPreview, String> {
+    app.db_preview_retention().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(app))]
+pub async fn apply_retention<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<crate::RetentionReport, String> {
+    app.db_apply_retention().await.map_err(|e| e.to_string())
+}
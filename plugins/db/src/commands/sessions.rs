@@ -74,7 +74,119 @@ pub async fn get_words(
 #[tauri::command]
 #[specta::specta]
 #[tracing::instrument(skip(state))]
-pub async fn upsert_session(
+pub async fn list_session_timeline(
+    state: tauri::State<'_, crate::ManagedState>,
+    session_id: String,
+) -> Result<Vec<hypr_db_user::TimelineEvent>, String> {
+    let guard = state.lock().await;
+
+    let db = guard
+        .db
+        .as_ref()
+        .ok_or(crate::Error::NoneDatabase)
+        .map_err(|e| e.to_string())?;
+
+    db.list_session_timeline(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(state))]
+pub async fn get_session_stats(
+    state: tauri::State<'_, crate::ManagedState>,
+    session_id: String,
+) -> Result<Option<hypr_db_user::SessionStats>, String> {
+    let guard = state.lock().await;
+
+    let db = guard
+        .db
+        .as_ref()
+        .ok_or(crate::Error::NoneDatabase)
+        .map_err(|e| e.to_string())?;
+
+    db.get_session_stats(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(state))]
+pub async fn list_session_chapters(
+    state: tauri::State<'_, crate::ManagedState>,
+    session_id: String,
+) -> Result<Vec<hypr_db_user::Chapter>, String> {
+    let guard = state.lock().await;
+
+    let db = guard
+        .db
+        .as_ref()
+        .ok_or(crate::Error::NoneDatabase)
+        .map_err(|e| e.to_string())?;
+
+    db.list_session_chapters(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(state))]
+pub async fn list_session_revisions(
+    state: tauri::State<'_, crate::ManagedState>,
+    session_id: String,
+) -> Result<Vec<hypr_db_user::SessionRevision>, String> {
+    let guard = state.lock().await;
+
+    let db = guard
+        .db
+        .as_ref()
+        .ok_or(crate::Error::NoneDatabase)
+        .map_err(|e| e.to_string())?;
+
+    db.list_session_revisions(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(app, state))]
+pub async fn restore_session_revision<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    state: tauri::State<'_, crate::ManagedState>,
+    revision_id: String,
+) -> Result<hypr_db_user::Session, String> {
+    let guard = state.lock().await;
+
+    let db = guard
+        .db
+        .as_ref()
+        .ok_or(crate::Error::NoneDatabase)
+        .map_err(|e| e.to_string())?;
+    let user_id = guard
+        .user_id
+        .clone()
+        .ok_or(crate::Error::NoneUser)
+        .map_err(|e| e.to_string())?;
+
+    let session = db
+        .restore_session_revision(&revision_id, user_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or(crate::Error::RevisionNotFound(revision_id))
+        .map_err(|e| e.to_string())?;
+    crate::events::notify_session_changed(&app, &session.id);
+    Ok(session)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(app, state))]
+pub async fn upsert_session<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
     state: tauri::State<'_, crate::ManagedState>,
     session: hypr_db_user::Session,
 ) -> Result<hypr_db_user::Session, String> {
@@ -86,7 +198,44 @@ pub async fn upsert_session(
         .ok_or(crate::Error::NoneDatabase)
         .map_err(|e| e.to_string())?;
 
-    db.upsert_session(session).await.map_err(|e| e.to_string())
+    // Everything else the frontend saves is typed by the user, so it counts as their edit.
+    let author = session.user_id.clone();
+    let session = db
+        .upsert_session_with_revision(session, hypr_db_user::SessionRevisionSource::Edit, author)
+        .await
+        .map_err(|e| e.to_string())?;
+    crate::events::notify_session_changed(&app, &session.id);
+    Ok(session)
+}
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(app, state))]
+pub async fn upsert_enhanced_session<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    state: tauri::State<'_, crate::ManagedState>,
+    session: hypr_db_user::Session,
+) -> Result<hypr_db_user::Session, String> {
+    let guard = state.lock().await;
+
+    let db = guard
+        .db
+        .as_ref()
+        .ok_or(crate::Error::NoneDatabase)
+        .map_err(|e| e.to_string())?;
+
+    // Written by the model while it enhances the note, on behalf of whoever asked for it.
+    let author = session.user_id.clone();
+    let session = db
+        .upsert_session_with_revision(
+            session,
+            hypr_db_user::SessionRevisionSource::Enhancement,
+            author,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    crate::events::notify_session_changed(&app, &session.id);
+    Ok(session)
 }
 
 #[tauri::command]
@@ -127,8 +276,9 @@ pub async fn get_session(
 
 #[tauri::command]
 #[specta::specta]
-#[tracing::instrument(skip(state))]
-pub async fn visit_session(
+#[tracing::instrument(skip(app, state))]
+pub async fn visit_session<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
     state: tauri::State<'_, crate::ManagedState>,
     id: String,
 ) -> Result<(), String> {
@@ -140,13 +290,18 @@ pub async fn visit_session(
         .ok_or(crate::Error::NoneDatabase)
         .map_err(|e| e.to_string())?;
 
-    db.visit_session(id).await.map_err(|e| e.to_string())
+    db.visit_session(id.clone())
+        .await
+        .map_err(|e| e.to_string())?;
+    crate::events::notify_session_changed(&app, &id);
+    Ok(())
 }
 
 #[tauri::command]
 #[specta::specta]
-#[tracing::instrument(skip(state))]
-pub async fn delete_session(
+#[tracing::instrument(skip(app, state))]
+pub async fn delete_session<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
     state: tauri::State<'_, crate::ManagedState>,
     id: String,
 ) -> Result<(), String> {
@@ -158,7 +313,11 @@ pub async fn delete_session(
         .ok_or(crate::Error::NoneDatabase)
         .map_err(|e| e.to_string())?;
 
-    db.delete_session(id).await.map_err(|e| e.to_string())
+    db.delete_session(id.clone())
+        .await
+        .map_err(|e| e.to_string())?;
+    crate::events::notify_session_changed(&app, &id);
+    Ok(())
 }
 
 #[tauri::command]
@@ -0,0 +1,16 @@
+use crate::DatabasePluginExt;
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(app, channel))]
+pub async fn snapshot_database<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    path: String,
+    channel: tauri::ipc::Channel<hypr_db_core::SnapshotProgress>,
+) -> Result<(), String> {
+    app.db_snapshot(path, move |progress| {
+        let _ = channel.send(progress);
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
@@ -0,0 +1,44 @@
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(state))]
+pub async fn list_speaker_profiles(
+    state: tauri::State<'_, crate::ManagedState>,
+) -> Result<Vec<hypr_db_user::SpeakerProfile>, String> {
+    let guard = state.lock().await;
+
+    let db = guard
+        .db
+        .as_ref()
+        .ok_or(crate::Error::NoneDatabase)
+        .map_err(|e| e.to_string())?;
+
+    let user_id = guard
+        .user_id
+        .as_ref()
+        .ok_or(crate::Error::NoneUser)
+        .map_err(|e| e.to_string())?;
+
+    db.list_speaker_profiles(user_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(state))]
+pub async fn delete_speaker_profile(
+    state: tauri::State<'_, crate::ManagedState>,
+    id: String,
+) -> Result<(), String> {
+    let guard = state.lock().await;
+
+    let db = guard
+        .db
+        .as_ref()
+        .ok_or(crate::Error::NoneDatabase)
+        .map_err(|e| e.to_string())?;
+
+    db.delete_speaker_profile(id)
+        .await
+        .map_err(|e| e.to_string())
+}
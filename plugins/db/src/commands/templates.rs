@@ -58,3 +58,43 @@ pub async fn delete_template(
 
     db.delete_template(id).await.map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(state))]
+pub async fn get_pipeline(
+    state: tauri::State<'_, crate::ManagedState>,
+    template_id: Option<String>,
+) -> Result<hypr_db_user::Pipeline, String> {
+    let guard = state.lock().await;
+
+    let db = guard
+        .db
+        .as_ref()
+        .ok_or(crate::Error::NoneDatabase)
+        .map_err(|e| e.to_string())?;
+
+    db.resolve_pipeline(template_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(state))]
+pub async fn upsert_pipeline(
+    state: tauri::State<'_, crate::ManagedState>,
+    pipeline: hypr_db_user::Pipeline,
+) -> Result<hypr_db_user::Pipeline, String> {
+    let guard = state.lock().await;
+
+    let db = guard
+        .db
+        .as_ref()
+        .ok_or(crate::Error::NoneDatabase)
+        .map_err(|e| e.to_string())?;
+
+    db.upsert_pipeline(pipeline)
+        .await
+        .map_err(|e| e.to_string())
+}
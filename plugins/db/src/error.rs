@@ -8,6 +8,10 @@ pub enum Error {
     NoneUser,
     #[error("database is None")]
     NoneDatabase,
+    #[error("session not found: {0}")]
+    SessionNotFound(String),
+    #[error("revision not found: {0}")]
+    RevisionNotFound(String),
     #[error(transparent)]
     TauriError(#[from] tauri::Error),
     #[error(transparent)]
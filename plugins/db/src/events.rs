@@ -0,0 +1,17 @@
+/// Emitted after a note is created, edited, opened or deleted, so views outside the webview
+/// (like the tray) can refresh.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type, tauri_specta::Event)]
+pub struct SessionChanged {
+    pub id: String,
+}
+
+pub(crate) fn notify_session_changed<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    id: impl Into<String>,
+) {
+    use tauri_specta::Event;
+
+    if let Err(e) = (SessionChanged { id: id.into() }).emit(app) {
+        tracing::error!("emit_session_changed_failed: {}", e);
+    }
+}
@@ -9,6 +9,15 @@ pub trait DatabasePluginExt<R: tauri::Runtime> {
         db: hypr_db_core::Database,
     ) -> impl Future<Output = Result<(), crate::Error>>;
     fn db_sync(&self) -> impl Future<Output = Result<(), crate::Error>>;
+    fn db_backup_dir(&self) -> Result<std::path::PathBuf, crate::Error>;
+    fn db_snapshot(
+        &self,
+        path: impl Into<std::path::PathBuf>,
+        on_progress: impl FnMut(hypr_db_core::SnapshotProgress) + Send + 'static,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
+    fn db_pending_migrations(
+        &self,
+    ) -> impl Future<Output = Result<hypr_db_user::MigrationReport, crate::Error>>;
     fn db_ensure_user(
         &self,
         user_id: impl Into<String>,
@@ -17,6 +26,10 @@ pub trait DatabasePluginExt<R: tauri::Runtime> {
         &self,
         user_id: impl Into<String>,
     ) -> impl Future<Output = Result<Option<hypr_db_user::Config>, crate::Error>>;
+    fn db_set_config(
+        &self,
+        config: hypr_db_user::Config,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
     fn db_get_session(
         &self,
         session_id: impl Into<String>,
@@ -25,6 +38,97 @@ pub trait DatabasePluginExt<R: tauri::Runtime> {
         &self,
         session: hypr_db_user::Session,
     ) -> impl Future<Output = Result<(), crate::Error>>;
+    fn db_upsert_session_with_revision(
+        &self,
+        session: hypr_db_user::Session,
+        source: hypr_db_user::SessionRevisionSource,
+        author: impl Into<String>,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
+    fn db_list_sessions(
+        &self,
+        filter: hypr_db_user::ListSessionFilter,
+    ) -> impl Future<Output = Result<Vec<hypr_db_user::Session>, crate::Error>>;
+    fn db_get_event(
+        &self,
+        id: impl Into<String>,
+    ) -> impl Future<Output = Result<Option<hypr_db_user::Event>, crate::Error>>;
+    fn db_list_events(
+        &self,
+        filter: hypr_db_user::ListEventFilter,
+    ) -> impl Future<Output = Result<Vec<hypr_db_user::Event>, crate::Error>>;
+    fn db_session_get_event(
+        &self,
+        session_id: impl Into<String>,
+    ) -> impl Future<Output = Result<Option<hypr_db_user::Event>, crate::Error>>;
+    fn db_list_session_participants(
+        &self,
+        session_id: impl Into<String>,
+    ) -> impl Future<Output = Result<Vec<hypr_db_user::Human>, crate::Error>>;
+    fn db_upsert_timeline_event(
+        &self,
+        event: hypr_db_user::TimelineEvent,
+    ) -> impl Future<Output = Result<hypr_db_user::TimelineEvent, crate::Error>>;
+    fn db_close_timeline_events(
+        &self,
+        session_id: impl Into<String>,
+        end_ms: i64,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
+    fn db_upsert_session_stats(
+        &self,
+        session_id: impl Into<String>,
+        stats: hypr_db_user::SessionStats,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
+    fn db_replace_session_chapters(
+        &self,
+        session_id: impl Into<String>,
+        chapters: Vec<hypr_db_user::Chapter>,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
+    fn db_list_session_chapters(
+        &self,
+        session_id: impl Into<String>,
+    ) -> impl Future<Output = Result<Vec<hypr_db_user::Chapter>, crate::Error>>;
+    fn db_list_speaker_profiles(
+        &self,
+        user_id: impl Into<String>,
+    ) -> impl Future<Output = Result<Vec<hypr_db_user::SpeakerProfile>, crate::Error>>;
+    fn db_get_speaker_profile(
+        &self,
+        id: impl Into<String>,
+    ) -> impl Future<Output = Result<Option<hypr_db_user::SpeakerProfile>, crate::Error>>;
+    fn db_upsert_speaker_profile(
+        &self,
+        profile: hypr_db_user::SpeakerProfile,
+    ) -> impl Future<Output = Result<hypr_db_user::SpeakerProfile, crate::Error>>;
+    fn db_get_session_retranscription(
+        &self,
+        session_id: impl Into<String>,
+    ) -> impl Future<Output = Result<Option<hypr_db_user::SessionRetranscription>, crate::Error>>;
+    fn db_upsert_session_retranscription(
+        &self,
+        retranscription: hypr_db_user::SessionRetranscription,
+    ) -> impl Future<Output = Result<hypr_db_user::SessionRetranscription, crate::Error>>;
+    fn db_delete_session_retranscription(
+        &self,
+        session_id: impl Into<String>,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
+    fn db_attachments_dir(&self) -> Result<std::path::PathBuf, crate::Error>;
+    fn db_add_attachment(
+        &self,
+        session_id: impl Into<String>,
+        source: crate::AttachmentSource,
+    ) -> impl Future<Output = Result<hypr_db_user::Attachment, crate::Error>>;
+    fn db_attachment_quota(
+        &self,
+    ) -> impl Future<Output = Result<crate::AttachmentQuota, crate::Error>>;
+    fn db_gc_attachments(
+        &self,
+    ) -> impl Future<Output = Result<crate::AttachmentGcReport, crate::Error>>;
+    fn db_preview_retention(
+        &self,
+    ) -> impl Future<Output = Result<crate::RetentionPreview, crate::Error>>;
+    fn db_apply_retention(
+        &self,
+    ) -> impl Future<Output = Result<crate::RetentionReport, crate::Error>>;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> DatabasePluginExt<R> for T {
@@ -37,7 +141,7 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> DatabasePluginExt<R> for T {
     fn db_local_path(&self) -> Result<String, crate::Error> {
         let v = {
             let app = self.app_handle();
-            let dir = app.path().app_data_dir()?;
+            let dir = hypr_data_dir::resolve(|| app.path().app_data_dir())?;
             std::fs::create_dir_all(&dir)?;
 
             dir.join("db.sqlite").to_str().unwrap().to_string()
@@ -47,11 +151,53 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> DatabasePluginExt<R> for T {
         Ok(v)
     }
 
+    async fn db_snapshot(
+        &self,
+        path: impl Into<std::path::PathBuf>,
+        on_progress: impl FnMut(hypr_db_core::SnapshotProgress) + Send + 'static,
+    ) -> Result<(), crate::Error> {
+        let source = self.db_local_path()?;
+        let target = path.into();
+
+        tokio::task::spawn_blocking(move || hypr_db_core::snapshot(source, target, on_progress))
+            .await
+            .map_err(std::io::Error::other)??;
+        Ok(())
+    }
+
+    fn db_backup_dir(&self) -> Result<std::path::PathBuf, crate::Error> {
+        let dir =
+            hypr_data_dir::resolve(|| self.app_handle().path().app_data_dir())?.join("backups");
+        std::fs::create_dir_all(&dir)?;
+        Ok(dir)
+    }
+
     async fn db_attach(&self, db: hypr_db_core::Database) -> Result<(), crate::Error> {
         let state = self.state::<crate::ManagedState>();
         let mut s = state.lock().await;
 
         let user_db = hypr_db_user::UserDatabase::from(db);
+
+        let report = hypr_db_user::pending_migrations(&user_db).await?;
+        if !report.is_up_to_date() && report.current_version > 0 && user_db.is_local() {
+            let timestamp = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default();
+
+            let backup_path = self.db_backup_dir()?.join(format!(
+                "db-v{}-{}.sqlite",
+                report.current_version, timestamp
+            ));
+
+            user_db.backup(&backup_path).await?;
+            tracing::info!(
+                path = %backup_path.display(),
+                pending = report.pending.len(),
+                "pre_migration_backup"
+            );
+        }
+
         hypr_db_user::migrate(&user_db).await?;
 
         s.db = Some(user_db);
@@ -59,6 +205,15 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> DatabasePluginExt<R> for T {
         Ok(())
     }
 
+    async fn db_pending_migrations(&self) -> Result<hypr_db_user::MigrationReport, crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        let report = hypr_db_user::pending_migrations(db).await?;
+        Ok(report)
+    }
+
     async fn db_sync(&self) -> Result<(), crate::Error> {
         let state = self.state::<crate::ManagedState>();
         let guard = state.lock().await;
@@ -115,11 +270,225 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> DatabasePluginExt<R> for T {
         let guard = state.lock().await;
 
         let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
-        db.upsert_session(session).await?;
+        let session = db.upsert_session(session).await?;
+        crate::events::notify_session_changed(self.app_handle(), &session.id);
 
         Ok(())
     }
 
+    async fn db_upsert_session_with_revision(
+        &self,
+        session: hypr_db_user::Session,
+        source: hypr_db_user::SessionRevisionSource,
+        author: impl Into<String>,
+    ) -> Result<(), crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        let session = db
+            .upsert_session_with_revision(session, source, author)
+            .await?;
+        crate::events::notify_session_changed(self.app_handle(), &session.id);
+
+        Ok(())
+    }
+
+    async fn db_list_sessions(
+        &self,
+        filter: hypr_db_user::ListSessionFilter,
+    ) -> Result<Vec<hypr_db_user::Session>, crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        let sessions = db.list_sessions(Some(filter)).await?;
+        Ok(sessions)
+    }
+
+    async fn db_get_event(
+        &self,
+        id: impl Into<String>,
+    ) -> Result<Option<hypr_db_user::Event>, crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        let event = db.get_event(id).await?;
+        Ok(event)
+    }
+
+    async fn db_list_events(
+        &self,
+        filter: hypr_db_user::ListEventFilter,
+    ) -> Result<Vec<hypr_db_user::Event>, crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        let events = db.list_events(Some(filter)).await?;
+        Ok(events)
+    }
+
+    async fn db_session_get_event(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Option<hypr_db_user::Event>, crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        let event = db.session_get_event(session_id).await?;
+        Ok(event)
+    }
+
+    async fn db_list_session_participants(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Vec<hypr_db_user::Human>, crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        let participants = db.session_list_participants(session_id).await?;
+        Ok(participants)
+    }
+
+    async fn db_upsert_timeline_event(
+        &self,
+        event: hypr_db_user::TimelineEvent,
+    ) -> Result<hypr_db_user::TimelineEvent, crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        let event = db.upsert_timeline_event(event).await?;
+        Ok(event)
+    }
+
+    async fn db_close_timeline_events(
+        &self,
+        session_id: impl Into<String>,
+        end_ms: i64,
+    ) -> Result<(), crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        db.close_timeline_events(session_id, end_ms).await?;
+        Ok(())
+    }
+
+    async fn db_upsert_session_stats(
+        &self,
+        session_id: impl Into<String>,
+        stats: hypr_db_user::SessionStats,
+    ) -> Result<(), crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        db.upsert_session_stats(session_id, &stats).await?;
+        Ok(())
+    }
+
+    async fn db_replace_session_chapters(
+        &self,
+        session_id: impl Into<String>,
+        chapters: Vec<hypr_db_user::Chapter>,
+    ) -> Result<(), crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        db.replace_session_chapters(session_id, chapters).await?;
+        Ok(())
+    }
+
+    async fn db_list_session_chapters(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Vec<hypr_db_user::Chapter>, crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        let chapters = db.list_session_chapters(session_id).await?;
+        Ok(chapters)
+    }
+
+    async fn db_list_speaker_profiles(
+        &self,
+        user_id: impl Into<String>,
+    ) -> Result<Vec<hypr_db_user::SpeakerProfile>, crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        let profiles = db.list_speaker_profiles(user_id).await?;
+        Ok(profiles)
+    }
+
+    async fn db_get_speaker_profile(
+        &self,
+        id: impl Into<String>,
+    ) -> Result<Option<hypr_db_user::SpeakerProfile>, crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        let profile = db.get_speaker_profile(id).await?;
+        Ok(profile)
+    }
+
+    async fn db_upsert_speaker_profile(
+        &self,
+        profile: hypr_db_user::SpeakerProfile,
+    ) -> Result<hypr_db_user::SpeakerProfile, crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        let profile = db.upsert_speaker_profile(profile).await?;
+        Ok(profile)
+    }
+
+    async fn db_get_session_retranscription(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Option<hypr_db_user::SessionRetranscription>, crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        let retranscription = db.get_session_retranscription(session_id).await?;
+        Ok(retranscription)
+    }
+
+    async fn db_upsert_session_retranscription(
+        &self,
+        retranscription: hypr_db_user::SessionRetranscription,
+    ) -> Result<hypr_db_user::SessionRetranscription, crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        let retranscription = db.upsert_session_retranscription(retranscription).await?;
+        Ok(retranscription)
+    }
+
+    async fn db_delete_session_retranscription(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<(), crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        db.delete_session_retranscription(session_id).await?;
+        Ok(())
+    }
+
     async fn db_get_config(
         &self,
         user_id: impl Into<String>,
@@ -131,4 +500,243 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> DatabasePluginExt<R> for T {
         let config = db.get_config(user_id.into()).await?;
         Ok(config)
     }
+
+    async fn db_set_config(&self, config: hypr_db_user::Config) -> Result<(), crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+        db.set_config(config).await?;
+        Ok(())
+    }
+
+    fn db_attachments_dir(&self) -> Result<std::path::PathBuf, crate::Error> {
+        let dir =
+            hypr_data_dir::resolve(|| self.app_handle().path().app_data_dir())?.join("attachments");
+        std::fs::create_dir_all(&dir)?;
+        Ok(dir)
+    }
+
+    async fn db_add_attachment(
+        &self,
+        session_id: impl Into<String>,
+        source: crate::AttachmentSource,
+    ) -> Result<hypr_db_user::Attachment, crate::Error> {
+        let session_id = session_id.into();
+        let root = self.db_attachments_dir()?;
+
+        // Held from before the blob is written, so garbage collection can't remove a blob
+        // this attachment is about to link to.
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+
+        if db
+            .get_session(hypr_db_user::GetSessionFilter::Id(session_id.clone()))
+            .await?
+            .is_none()
+        {
+            return Err(crate::Error::SessionNotFound(session_id));
+        }
+
+        let (file_name, stored) = tokio::task::spawn_blocking(move || {
+            let file_name = match &source {
+                crate::AttachmentSource::Path { path } => std::path::Path::new(path)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| path.clone()),
+                crate::AttachmentSource::Bytes { file_name, .. } => file_name.clone(),
+            };
+
+            let stored = match source {
+                crate::AttachmentSource::Path { path } => {
+                    crate::attachments::store_blob(&root, std::fs::File::open(path)?)
+                }
+                crate::AttachmentSource::Bytes { data, .. } => {
+                    crate::attachments::store_blob(&root, data.as_slice())
+                }
+            }?;
+
+            Ok::<_, std::io::Error>((file_name, stored))
+        })
+        .await
+        .map_err(std::io::Error::other)??;
+
+        let mime_type = crate::attachments::guess_mime_type(&file_name).to_string();
+        let now = chrono::Utc::now();
+
+        let blob = db
+            .insert_attachment_blob(hypr_db_user::AttachmentBlob {
+                hash: stored.hash,
+                mime_type: mime_type.clone(),
+                size_bytes: stored.size_bytes,
+                created_at: now,
+            })
+            .await?;
+
+        let attachment = db
+            .link_attachment(hypr_db_user::Attachment {
+                id: uuid::Uuid::new_v4().to_string(),
+                session_id,
+                hash: blob.hash,
+                file_name,
+                kind: crate::attachments::kind_from_mime_type(&mime_type),
+                mime_type,
+                size_bytes: blob.size_bytes,
+                created_at: now,
+            })
+            .await?;
+
+        Ok(attachment)
+    }
+
+    async fn db_attachment_quota(&self) -> Result<crate::AttachmentQuota, crate::Error> {
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+
+        let usage = db.attachment_usage().await?;
+        let quota_bytes = crate::attachments::ATTACHMENT_QUOTA_BYTES;
+
+        Ok(crate::AttachmentQuota {
+            over_quota: usage.total_bytes > quota_bytes,
+            usage,
+            quota_bytes,
+        })
+    }
+
+    async fn db_gc_attachments(&self) -> Result<crate::AttachmentGcReport, crate::Error> {
+        let root = self.db_attachments_dir()?;
+
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+
+        let mut report = crate::AttachmentGcReport::default();
+
+        for blob in db.list_orphaned_attachment_blobs().await? {
+            let path = crate::attachments::blob_path(&root, &blob.hash);
+
+            match std::fs::remove_file(&path) {
+                Ok(_) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => {
+                    tracing::warn!(hash = %blob.hash, error = %e, "attachment_gc_failed");
+                    continue;
+                }
+            }
+
+            db.delete_attachment_blob(&blob.hash).await?;
+            report.removed_count += 1;
+            report.freed_bytes += blob.size_bytes;
+        }
+
+        Ok(report)
+    }
+
+    async fn db_preview_retention(&self) -> Result<crate::RetentionPreview, crate::Error> {
+        let data_dir = hypr_data_dir::resolve(|| self.app_handle().path().app_data_dir())?;
+        let user_id = self.db_user_id().await?.ok_or(crate::Error::NoneUser)?;
+        let general = self
+            .db_get_config(&user_id)
+            .await?
+            .map(|config| config.general)
+            .unwrap_or_default();
+
+        let now = chrono::Utc::now();
+        let mut preview = crate::RetentionPreview::default();
+
+        if let Some(days) = general.audio_retention_days {
+            let cutoff = crate::retention::recording_cutoff(now, days);
+            preview.recordings = tokio::task::spawn_blocking(move || {
+                crate::retention::expired_recordings(&data_dir, cutoff)
+            })
+            .await
+            .map_err(std::io::Error::other)??;
+        }
+
+        let state = self.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+        let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+
+        for recording in &mut preview.recordings {
+            recording.title = db
+                .get_session(hypr_db_user::GetSessionFilter::Id(
+                    recording.session_id.clone(),
+                ))
+                .await?
+                .map(|session| session.title);
+            preview.recording_bytes += recording.size_bytes;
+        }
+
+        if let Some(months) = general.transcript_retention_months {
+            let cutoff = crate::retention::transcript_cutoff(now, months);
+            let kept = [
+                hypr_db_user::UserDatabase::onboarding_session_id(),
+                hypr_db_user::UserDatabase::thank_you_session_id(),
+            ];
+
+            preview.transcripts = db
+                .list_transcribed_sessions(&user_id)
+                .await?
+                .into_iter()
+                .filter(|session| !kept.contains(&session.id))
+                .filter(|session| crate::retention::transcript_expired(session, cutoff))
+                .collect();
+        }
+
+        Ok(preview)
+    }
+
+    async fn db_apply_retention(&self) -> Result<crate::RetentionReport, crate::Error> {
+        let preview = self.db_preview_retention().await?;
+        let mut report = crate::RetentionReport::default();
+
+        for recording in preview.recordings {
+            let mut deleted = true;
+            for path in &recording.files {
+                match std::fs::remove_file(path) {
+                    Ok(_) => {}
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                    Err(e) => {
+                        tracing::warn!(session_id = %recording.session_id, error = %e, "retention_delete_failed");
+                        deleted = false;
+                    }
+                }
+            }
+
+            if deleted {
+                report.deleted_recordings += 1;
+                report.freed_bytes += recording.size_bytes;
+            }
+        }
+
+        if !preview.transcripts.is_empty() {
+            {
+                let state = self.state::<crate::ManagedState>();
+                let guard = state.lock().await;
+                let db = guard.db.as_ref().ok_or(crate::Error::NoneDatabase)?;
+
+                for session in &preview.transcripts {
+                    db.clear_session_words(&session.id).await?;
+                    report.cleared_transcripts += 1;
+                }
+            }
+
+            for session in &preview.transcripts {
+                crate::events::notify_session_changed(self.app_handle(), &session.id);
+            }
+        }
+
+        if report.deleted_recordings > 0 || report.cleared_transcripts > 0 {
+            tracing::info!(
+                deleted_recordings = report.deleted_recordings,
+                freed_bytes = report.freed_bytes,
+                cleared_transcripts = report.cleared_transcripts,
+                "retention_purged"
+            );
+        }
+
+        Ok(report)
+    }
 }
@@ -1,13 +1,19 @@
 use tauri::Manager;
 use tokio::sync::Mutex;
 
+mod attachments;
 mod commands;
 mod error;
+mod events;
 mod ext;
+mod retention;
 
+pub use attachments::{AttachmentGcReport, AttachmentQuota, AttachmentSource};
 pub use error::{Error, Result};
+pub use events::SessionChanged;
 pub use ext::DatabasePluginExt;
 pub use hypr_db_user::UserDatabase;
+pub use retention::{ExpiredRecording, RetentionPreview, RetentionReport};
 
 pub type ManagedState = Mutex<State>;
 
@@ -29,15 +35,18 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::calendars::list_calendars,
             commands::calendars::upsert_calendar,
             commands::calendars::toggle_calendar_selected,
-            commands::sessions::upsert_session,
-            commands::sessions::visit_session,
+            commands::sessions::upsert_session::<tauri::Wry>,
+            commands::sessions::upsert_enhanced_session::<tauri::Wry>,
+            commands::sessions::visit_session::<tauri::Wry>,
             commands::templates::list_templates,
             commands::templates::upsert_template,
             commands::templates::delete_template,
+            commands::templates::get_pipeline,
+            commands::templates::upsert_pipeline,
             commands::sessions::onboarding_session_id,
             commands::sessions::thank_you_session_id,
             commands::sessions::list_sessions,
-            commands::sessions::delete_session,
+            commands::sessions::delete_session::<tauri::Wry>,
             commands::sessions::get_session,
             commands::sessions::set_session_event,
             commands::sessions::session_add_participant,
@@ -47,18 +56,27 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::sessions::session_get_event,
             commands::sessions::get_words,
             commands::sessions::get_words_onboarding,
+            commands::sessions::list_session_timeline,
+            commands::sessions::get_session_stats,
+            commands::sessions::list_session_chapters,
+            commands::sessions::list_session_revisions,
+            commands::sessions::restore_session_revision::<tauri::Wry>,
             commands::configs::get_config,
             commands::configs::set_config,
             commands::humans::get_human,
             commands::humans::upsert_human,
             commands::humans::list_humans,
             commands::humans::delete_human,
+            commands::speakers::list_speaker_profiles,
+            commands::speakers::delete_speaker_profile,
             commands::organizations::get_organization,
             commands::organizations::delete_organization,
             commands::organizations::get_organization_by_user_id,
             commands::organizations::upsert_organization,
             commands::organizations::list_organizations,
             commands::organizations::list_organization_members,
+            commands::people::list_people,
+            commands::people::list_meetings_with,
             commands::chats::list_chat_groups,
             commands::chats::list_chat_messages,
             commands::chats::create_chat_group,
@@ -70,7 +88,17 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::tags::unassign_tag_from_session,
             commands::tags::upsert_tag,
             commands::tags::delete_tag,
+            commands::migrations::pending_migrations,
+            commands::snapshots::snapshot_database::<tauri::Wry>,
+            commands::attachments::add_attachment::<tauri::Wry>,
+            commands::attachments::list_attachments,
+            commands::attachments::remove_attachment,
+            commands::attachments::attachment_quota::<tauri::Wry>,
+            commands::attachments::gc_attachments::<tauri::Wry>,
+            commands::retention::preview_retention::<tauri::Wry>,
+            commands::retention::apply_retention::<tauri::Wry>,
         ])
+        .events(tauri_specta::collect_events![SessionChanged])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
 
@@ -79,8 +107,10 @@ pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
 
     tauri::plugin::Builder::new(PLUGIN_NAME)
         .invoke_handler(specta_builder.invoke_handler())
-        .setup(|app, _api| {
+        .setup(move |app, _api| {
+            specta_builder.mount_events(app);
             app.manage(ManagedState::default());
+            tauri::async_runtime::spawn(retention::run(app.clone()));
             Ok(())
         })
         .build()
@@ -0,0 +1,215 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use chrono::{DateTime, Months, Utc};
+
+/// Often enough that nothing outlives its retention by much, rare enough to go unnoticed.
+const PURGE_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+/// Leaves time for the database to be attached after launch.
+const STARTUP_DELAY: Duration = Duration::from_secs(60);
+
+/// Everything the configured retention would remove if it ran now.
+#[derive(Debug, Clone, Default, serde::Serialize, specta::Type)]
+pub struct RetentionPreview {
+    pub recordings: Vec<ExpiredRecording>,
+    pub recording_bytes: i64,
+    pub transcripts: Vec<hypr_db_user::SessionSummary>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, specta::Type)]
+pub struct ExpiredRecording {
+    pub session_id: String,
+    /// `None` when the note was deleted and only the audio is left.
+    pub title: Option<String>,
+    pub recorded_at: DateTime<Utc>,
+    pub size_bytes: i64,
+    #[serde(skip)]
+    #[specta(skip)]
+    pub files: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, specta::Type)]
+pub struct RetentionReport {
+    pub deleted_recordings: i64,
+    pub freed_bytes: i64,
+    pub cleared_transcripts: i64,
+}
+
+/// Applies the retention settings shortly after launch, then every few hours.
+pub async fn run<R: tauri::Runtime>(app: tauri::AppHandle<R>) {
+    use crate::DatabasePluginExt;
+
+    tokio::time::sleep(STARTUP_DELAY).await;
+
+    loop {
+        match app.db_apply_retention().await {
+            Ok(_) => {}
+            Err(crate::Error::NoneDatabase | crate::Error::NoneUser) => {}
+            Err(e) => tracing::error!("retention_purge_failed: {}", e),
+        }
+        tokio::time::sleep(PURGE_INTERVAL).await;
+    }
+}
+
+pub fn recording_cutoff(now: DateTime<Utc>, days: u32) -> DateTime<Utc> {
+    now - chrono::Duration::days(days.into())
+}
+
+pub fn transcript_cutoff(now: DateTime<Utc>, months: u32) -> DateTime<Utc> {
+    now.checked_sub_months(Months::new(months))
+        .unwrap_or(DateTime::<Utc>::MIN_UTC)
+}
+
+/// Counted from when the meeting ended, or from when the note was made if it never recorded.
+pub fn transcript_expired(session: &hypr_db_user::SessionSummary, cutoff: DateTime<Utc>) -> bool {
+    session.record_end.unwrap_or(session.created_at) < cutoff
+}
+
+fn is_recording_file(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    name.ends_with(".wav") || name.ends_with(".peaks") || name.ends_with(".stretches.json")
+}
+
+/// Session folders in `data_dir` whose audio was last written before `cutoff`. Going by the
+/// files rather than the note means a recording still being written is never picked.
+///
+/// Only the `.wav` counts: peaks are recomputed whenever the audio is opened, so their
+/// mtime says nothing about when it was recorded. Sidecar files left without one go by
+/// their own.
+pub fn expired_recordings(
+    data_dir: &Path,
+    cutoff: DateTime<Utc>,
+) -> std::io::Result<Vec<ExpiredRecording>> {
+    let mut recordings = Vec::new();
+
+    for entry in std::fs::read_dir(data_dir)? {
+        let entry = entry?;
+        let session_id = entry.file_name().to_string_lossy().to_string();
+        if !entry.file_type()?.is_dir() || uuid::Uuid::parse_str(&session_id).is_err() {
+            continue;
+        }
+
+        let mut files = Vec::new();
+        let mut size_bytes = 0;
+        let mut recorded: Option<SystemTime> = None;
+        let mut sidecars: Option<SystemTime> = None;
+
+        for file in std::fs::read_dir(entry.path())? {
+            let file = file?;
+            let path = file.path();
+            if !is_recording_file(&path) {
+                continue;
+            }
+
+            let metadata = file.metadata()?;
+            size_bytes += metadata.len() as i64;
+            let modified = metadata.modified()?;
+            let written_at = match path.extension().is_some_and(|ext| ext == "wav") {
+                true => &mut recorded,
+                false => &mut sidecars,
+            };
+            *written_at = Some(written_at.map_or(modified, |at| at.max(modified)));
+            files.push(path);
+        }
+
+        let Some(written_at) = recorded.or(sidecars) else {
+            continue;
+        };
+        let recorded_at = DateTime::<Utc>::from(written_at);
+        if recorded_at < cutoff {
+            recordings.push(ExpiredRecording {
+                session_id,
+                title: None,
+                recorded_at,
+                size_bytes,
+                files,
+            });
+        }
+    }
+
+    recordings.sort_by_key(|recording| recording.recorded_at);
+    Ok(recordings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(path: &Path, modified: DateTime<Utc>) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let file = std::fs::File::create(path).unwrap();
+        file.set_len(10).unwrap();
+        file.set_modified(modified.into()).unwrap();
+    }
+
+    #[test]
+    fn test_expired_recordings() {
+        let dir = tempfile::tempdir().unwrap();
+        let now = Utc::now();
+        let old = "df1d8c52-6d9d-4471-aff1-5dbd35899cbe";
+        let recent = "872cf207-6a28-4229-bd66-492d0dce43c0";
+
+        write(
+            &dir.path().join(old).join("audio.wav"),
+            now - chrono::Duration::days(40),
+        );
+        // Recomputed when the note was opened yesterday.
+        write(
+            &dir.path().join(old).join("audio.peaks"),
+            now - chrono::Duration::days(1),
+        );
+        write(
+            &dir.path().join(old).join("notes.txt"),
+            now - chrono::Duration::days(40),
+        );
+        write(
+            &dir.path().join(recent).join("audio.wav"),
+            now - chrono::Duration::days(2),
+        );
+        write(
+            &dir.path().join("backups").join("audio.wav"),
+            now - chrono::Duration::days(40),
+        );
+
+        let recordings = expired_recordings(dir.path(), recording_cutoff(now, 30)).unwrap();
+        assert_eq!(recordings.len(), 1);
+        assert_eq!(recordings[0].session_id, old);
+        assert_eq!(recordings[0].files.len(), 2);
+        assert_eq!(recordings[0].size_bytes, 20);
+
+        let recordings = expired_recordings(dir.path(), recording_cutoff(now, 1)).unwrap();
+        assert_eq!(recordings.len(), 2);
+    }
+
+    #[test]
+    fn test_transcript_expired() {
+        let now = "2025-06-15T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let cutoff = transcript_cutoff(now, 3);
+        assert_eq!(
+            cutoff,
+            "2025-03-15T12:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+
+        let session = |created_at: &str, record_end: Option<&str>| hypr_db_user::SessionSummary {
+            id: "id".to_string(),
+            title: "title".to_string(),
+            created_at: created_at.parse().unwrap(),
+            record_end: record_end.map(|s| s.parse().unwrap()),
+        };
+
+        assert!(transcript_expired(
+            &session("2025-01-01T00:00:00Z", None),
+            cutoff
+        ));
+        assert!(!transcript_expired(
+            &session("2025-01-01T00:00:00Z", Some("2025-04-01T00:00:00Z")),
+            cutoff
+        ));
+        assert!(!transcript_expired(
+            &session("2025-05-01T00:00:00Z", None),
+            cutoff
+        ));
+    }
+}
@@ -0,0 +1,12 @@
+const COMMANDS: &[&str] = &[
+    "list_downloads",
+    "pause_download",
+    "resume_download",
+    "cancel_download",
+    "set_download_priority",
+    "clear_finished_downloads",
+];
+
+fn main() {
+    tauri_plugin::Builder::new(COMMANDS).build();
+}
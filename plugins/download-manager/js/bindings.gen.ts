@@ -0,0 +1,131 @@
+// @ts-nocheck
+
+
+// This file was generated by [tauri-specta](https://github.com/oscartbeaumont/tauri-specta). Do not edit this file manually.
+
+/** user-defined commands **/
+
+
+export const commands = {
+async listDownloads() : Promise<DownloadItem[]> {
+    return await TAURI_INVOKE("plugin:download-manager|list_downloads");
+},
+async pauseDownload(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:download-manager|pause_download", { id });
+},
+async resumeDownload(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:download-manager|resume_download", { id });
+},
+async cancelDownload(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:download-manager|cancel_download", { id });
+},
+async setDownloadPriority(id: string, priority: number) : Promise<null> {
+    return await TAURI_INVOKE("plugin:download-manager|set_download_priority", { id, priority });
+},
+async clearFinishedDownloads() : Promise<null> {
+    return await TAURI_INVOKE("plugin:download-manager|clear_finished_downloads");
+}
+}
+
+/** user-defined events **/
+
+
+export const events = __makeEvents__<{
+downloadEvent: DownloadEvent
+}>({
+downloadEvent: "plugin:download-manager:download-event"
+})
+
+/** user-defined constants **/
+
+
+
+/** user-defined types **/
+
+export type DownloadEvent = 
+/**
+ * Queued, or its status or priority changed.
+ */
+{ type: "updated"; item: DownloadItem } | 
+/**
+ * Throttled, so it is fine to re-render on every one.
+ */
+{ type: "progress"; id: string; downloaded: number; total: number } | { type: "removed"; id: string }
+export type DownloadItem = { 
+/**
+ * Chosen by the plugin that queued it, e.g. `local-stt:whisper-small-q8`.
+ */
+id: string; label: string; status: DownloadStatus; 
+/**
+ * Higher runs first. Equal priorities run in the order they were queued.
+ */
+priority: number; downloaded: number; 
+/**
+ * Zero until the size is known.
+ */
+total: number; error: string | null }
+export type DownloadStatus = "queued" | "running" | "paused" | "completed" | 
+/**
+ * Stays in the list until resumed, which retries it.
+ */
+"failed" | "cancelled"
+
+/** tauri-specta globals **/
+
+import {
+	invoke as TAURI_INVOKE,
+	Channel as TAURI_CHANNEL,
+} from "@tauri-apps/api/core";
+import * as TAURI_API_EVENT from "@tauri-apps/api/event";
+import { type WebviewWindow as __WebviewWindow__ } from "@tauri-apps/api/webviewWindow";
+
+type __EventObj__<T> = {
+	listen: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+	) => ReturnType<typeof TAURI_API_EVENT.listen<T>>;
+	once: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+	) => ReturnType<typeof TAURI_API_EVENT.once<T>>;
+	emit: null extends T
+		? (payload?: T) => ReturnType<typeof TAURI_API_EVENT.emit>
+		: (payload: T) => ReturnType<typeof TAURI_API_EVENT.emit>;
+};
+
+export type Result<T, E> =
+	| { status: "ok"; data: T }
+	| { status: "error"; error: E };
+
+function __makeEvents__<T extends Record<string, any>>(
+	mappings: Record<keyof T, string>,
+) {
+	return new Proxy(
+		{} as unknown as {
+			[K in keyof T]: __EventObj__<T[K]> & {
+				(handle: __WebviewWindow__): __EventObj__<T[K]>;
+			};
+		},
+		{
+			get: (_, event) => {
+				const name = mappings[event as keyof T];
+
+				return new Proxy((() => {}) as any, {
+					apply: (_, __, [window]: [__WebviewWindow__]) => ({
+						listen: (arg: any) => window.listen(name, arg),
+						once: (arg: any) => window.once(name, arg),
+						emit: (arg: any) => window.emit(name, arg),
+					}),
+					get: (_, command: keyof __EventObj__<any>) => {
+						switch (command) {
+							case "listen":
+								return (arg: any) => TAURI_API_EVENT.listen(name, arg);
+							case "once":
+								return (arg: any) => TAURI_API_EVENT.once(name, arg);
+							case "emit":
+								return (arg: any) => TAURI_API_EVENT.emit(name, arg);
+						}
+					},
+				});
+			},
+		},
+	);
+}
@@ -0,0 +1,56 @@
+use crate::{DownloadItem, DownloadManagerPluginExt};
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_downloads<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<DownloadItem>, String> {
+    Ok(app.list_downloads())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn pause_download<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<(), String> {
+    app.pause_download(&id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn resume_download<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<(), String> {
+    app.resume_download(&id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn cancel_download<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<(), String> {
+    app.cancel_download(&id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_download_priority<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+    priority: i32,
+) -> Result<(), String> {
+    app.set_download_priority(&id, priority)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn clear_finished_downloads<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<(), String> {
+    app.clear_finished_downloads();
+    Ok(())
+}
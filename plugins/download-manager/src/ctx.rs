@@ -0,0 +1,104 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use hypr_download_interface::DownloadProgress;
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_specta::Event;
+use tokio_util::sync::CancellationToken;
+
+use crate::{DownloadEvent, DownloadManagerState};
+
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Handed to a download job on every run. Pausing or cancelling the download cancels the
+/// token, and the job is run again from the start on resume.
+pub struct DownloadCtx<R: Runtime> {
+    app: AppHandle<R>,
+    id: String,
+    generation: u64,
+    token: CancellationToken,
+    last_emitted: Arc<Mutex<Option<Instant>>>,
+}
+
+impl<R: Runtime> Clone for DownloadCtx<R> {
+    fn clone(&self) -> Self {
+        Self {
+            app: self.app.clone(),
+            id: self.id.clone(),
+            generation: self.generation,
+            token: self.token.clone(),
+            last_emitted: self.last_emitted.clone(),
+        }
+    }
+}
+
+impl<R: Runtime> DownloadCtx<R> {
+    pub(crate) fn new(
+        app: AppHandle<R>,
+        id: String,
+        generation: u64,
+        token: CancellationToken,
+    ) -> Self {
+        Self {
+            app,
+            id,
+            generation,
+            token,
+            last_emitted: Default::default(),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// For jobs made of several files, `downloaded` and `total` should cover all of them.
+    pub fn report(&self, downloaded: u64, total: u64) {
+        let state = self.app.state::<DownloadManagerState<R>>();
+        if !state
+            .queue
+            .lock()
+            .unwrap()
+            .progress(&self.id, self.generation, downloaded, total)
+        {
+            return;
+        }
+
+        {
+            let mut last_emitted = self.last_emitted.lock().unwrap();
+            let now = Instant::now();
+            if last_emitted.is_some_and(|last| now - last < PROGRESS_INTERVAL) && downloaded < total
+            {
+                return;
+            }
+            *last_emitted = Some(now);
+        }
+
+        let _ = DownloadEvent::Progress {
+            id: self.id.clone(),
+            downloaded,
+            total,
+        }
+        .emit(&self.app);
+    }
+
+    /// Forwards the progress of a single `hypr_file` download.
+    pub fn progress_callback(&self) -> impl Fn(DownloadProgress) + Send + Sync + 'static {
+        let ctx = self.clone();
+        move |progress| {
+            if let DownloadProgress::Progress(downloaded, total) = progress {
+                ctx.report(downloaded, total);
+            }
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::DownloadItem;
+
+#[derive(Debug, Clone, serde::Serialize, specta::Type, tauri_specta::Event)]
+#[serde(tag = "type")]
+pub enum DownloadEvent {
+    /// Queued, or its status or priority changed.
+    #[serde(rename = "updated")]
+    Updated { item: DownloadItem },
+    /// Throttled, so it is fine to re-render on every one.
+    #[serde(rename = "progress")]
+    Progress {
+        id: String,
+        downloaded: u64,
+        total: u64,
+    },
+    #[serde(rename = "removed")]
+    Removed { id: String },
+}
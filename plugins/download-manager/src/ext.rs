@@ -0,0 +1,148 @@
+use std::future::Future;
+
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_specta::Event;
+
+use crate::{
+    state::{DownloadFuture, Job},
+    DownloadCtx, DownloadEvent, DownloadItem, DownloadManagerState,
+};
+
+pub trait DownloadManagerPluginExt<R: Runtime> {
+    /// Queues `job`, which is run again from the start whenever the download is resumed
+    /// or retried, so it should pick up whatever an earlier run left on disk.
+    fn enqueue_download<F, Fut>(
+        &self,
+        id: impl Into<String>,
+        label: impl Into<String>,
+        priority: i32,
+        job: F,
+    ) where
+        F: Fn(DownloadCtx<R>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static;
+
+    fn list_downloads(&self) -> Vec<DownloadItem>;
+    fn get_download(&self, id: &str) -> Option<DownloadItem>;
+    /// Queued, running or paused.
+    fn is_downloading(&self, id: &str) -> bool;
+
+    fn pause_download(&self, id: &str) -> Result<(), crate::Error>;
+    fn resume_download(&self, id: &str) -> Result<(), crate::Error>;
+    fn cancel_download(&self, id: &str) -> Result<(), crate::Error>;
+    fn set_download_priority(&self, id: &str, priority: i32) -> Result<(), crate::Error>;
+    fn clear_finished_downloads(&self);
+}
+
+impl<R: Runtime, T: Manager<R>> DownloadManagerPluginExt<R> for T {
+    fn enqueue_download<F, Fut>(
+        &self,
+        id: impl Into<String>,
+        label: impl Into<String>,
+        priority: i32,
+        job: F,
+    ) where
+        F: Fn(DownloadCtx<R>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        let job: Job<R> =
+            std::sync::Arc::new(move |ctx: DownloadCtx<R>| Box::pin(job(ctx)) as DownloadFuture);
+
+        let item = queue(self)
+            .lock()
+            .unwrap()
+            .enqueue(id.into(), label.into(), priority, job);
+        updated(self.app_handle(), item);
+    }
+
+    fn list_downloads(&self) -> Vec<DownloadItem> {
+        queue(self).lock().unwrap().items()
+    }
+
+    fn get_download(&self, id: &str) -> Option<DownloadItem> {
+        queue(self).lock().unwrap().get(id)
+    }
+
+    fn is_downloading(&self, id: &str) -> bool {
+        self.get_download(id)
+            .is_some_and(|item| item.status.is_active())
+    }
+
+    fn pause_download(&self, id: &str) -> Result<(), crate::Error> {
+        let item = queue(self).lock().unwrap().pause(id)?;
+        updated(self.app_handle(), item);
+        Ok(())
+    }
+
+    fn resume_download(&self, id: &str) -> Result<(), crate::Error> {
+        let item = queue(self).lock().unwrap().resume(id)?;
+        updated(self.app_handle(), item);
+        Ok(())
+    }
+
+    fn cancel_download(&self, id: &str) -> Result<(), crate::Error> {
+        let item = queue(self).lock().unwrap().cancel(id)?;
+        updated(self.app_handle(), item);
+        Ok(())
+    }
+
+    fn set_download_priority(&self, id: &str, priority: i32) -> Result<(), crate::Error> {
+        let item = queue(self).lock().unwrap().set_priority(id, priority)?;
+        updated(self.app_handle(), item);
+        Ok(())
+    }
+
+    fn clear_finished_downloads(&self) {
+        let removed = queue(self).lock().unwrap().clear_finished();
+        for id in removed {
+            let _ = DownloadEvent::Removed { id }.emit(self.app_handle());
+        }
+    }
+}
+
+fn queue<R: Runtime>(manager: &impl Manager<R>) -> &std::sync::Mutex<crate::queue::Queue<Job<R>>> {
+    &manager.state::<DownloadManagerState<R>>().inner().queue
+}
+
+/// Announces the change and starts whatever now fits into the free slots.
+fn updated<R: Runtime>(app: &AppHandle<R>, item: DownloadItem) {
+    let _ = DownloadEvent::Updated { item }.emit(app);
+    schedule(app);
+}
+
+fn schedule<R: Runtime>(app: &AppHandle<R>) {
+    let starts = queue(app).lock().unwrap().take_startable();
+
+    for start in starts {
+        let _ = DownloadEvent::Updated {
+            item: start.item.clone(),
+        }
+        .emit(app);
+
+        let app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            let id = start.item.id;
+            let ctx = DownloadCtx::new(app.clone(), id.clone(), start.generation, start.token);
+
+            let result = {
+                let _slot = start.slot.lock().await;
+                if ctx.is_cancelled() {
+                    Ok(())
+                } else {
+                    (start.job)(ctx).await
+                }
+            };
+            if let Err(e) = &result {
+                tracing::error!("download_failed: {} {}", id, e);
+            }
+
+            let finished = queue(&app)
+                .lock()
+                .unwrap()
+                .finish(&id, start.generation, result);
+            match finished {
+                Some(item) => updated(&app, item),
+                None => schedule(&app),
+            }
+        });
+    }
+}
@@ -0,0 +1,77 @@
+use tauri::{Manager, Wry};
+
+mod commands;
+mod ctx;
+mod error;
+mod events;
+mod ext;
+mod queue;
+mod state;
+mod types;
+
+pub use ctx::*;
+pub use error::*;
+pub use events::*;
+pub use ext::*;
+pub use state::DownloadManagerState;
+pub use types::*;
+
+const PLUGIN_NAME: &str = "download-manager";
+
+fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
+    tauri_specta::Builder::<R>::new()
+        .plugin_name(PLUGIN_NAME)
+        .commands(tauri_specta::collect_commands![
+            commands::list_downloads<Wry>,
+            commands::pause_download<Wry>,
+            commands::resume_download<Wry>,
+            commands::cancel_download<Wry>,
+            commands::set_download_priority<Wry>,
+            commands::clear_finished_downloads<Wry>,
+        ])
+        .events(tauri_specta::collect_events![events::DownloadEvent])
+        .error_handling(tauri_specta::ErrorHandlingMode::Throw)
+}
+
+pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
+    let specta_builder = make_specta_builder();
+
+    tauri::plugin::Builder::new(PLUGIN_NAME)
+        .invoke_handler(specta_builder.invoke_handler())
+        .setup(move |app, _api| {
+            specta_builder.mount_events(app);
+            app.manage(DownloadManagerState::<R>::default());
+            Ok(())
+        })
+        .build()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn export_types() {
+        make_specta_builder::<tauri::Wry>()
+            .export(
+                specta_typescript::Typescript::default()
+                    .header("// @ts-nocheck\n\n")
+                    .formatter(specta_typescript::formatter::prettier)
+                    .bigint(specta_typescript::BigIntExportBehavior::Number),
+                "./js/bindings.gen.ts",
+            )
+            .unwrap()
+    }
+
+    fn create_app<R: tauri::Runtime>(builder: tauri::Builder<R>) -> tauri::App<R> {
+        builder
+            .plugin(init())
+            .build(tauri::test::mock_context(tauri::test::noop_assets()))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_download_manager() {
+        let _app = create_app(tauri::test::mock_builder());
+    }
+}
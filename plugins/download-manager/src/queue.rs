@@ -0,0 +1,323 @@
+use std::{cmp::Reverse, sync::Arc};
+
+use tokio_util::sync::CancellationToken;
+
+use crate::{DownloadItem, DownloadStatus, Error};
+
+pub const MAX_CONCURRENT_DOWNLOADS: usize = 2;
+
+struct Entry<J> {
+    item: DownloadItem,
+    seq: u64,
+    job: J,
+    /// Bumped on every start, so a run that was paused can't report into the next one.
+    generation: u64,
+    token: Option<CancellationToken>,
+    /// Held by the run in flight. A restarted download waits for the previous run to let go,
+    /// so two runs never write the same files.
+    slot: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl<J> Entry<J> {
+    fn stop(&mut self) {
+        if let Some(token) = self.token.take() {
+            token.cancel();
+        }
+    }
+}
+
+/// A download that should start running now.
+pub struct Start<J> {
+    pub item: DownloadItem,
+    pub generation: u64,
+    pub job: J,
+    pub token: CancellationToken,
+    pub slot: Arc<tokio::sync::Mutex<()>>,
+}
+
+/// Bookkeeping of every download, independent of how they are run.
+pub struct Queue<J> {
+    entries: Vec<Entry<J>>,
+    next_seq: u64,
+    max_concurrent: usize,
+}
+
+impl<J: Clone> Default for Queue<J> {
+    fn default() -> Self {
+        Self::new(MAX_CONCURRENT_DOWNLOADS)
+    }
+}
+
+impl<J: Clone> Queue<J> {
+    pub fn new(max_concurrent: usize) -> Self {
+        Self {
+            entries: vec![],
+            next_seq: 0,
+            max_concurrent: max_concurrent.max(1),
+        }
+    }
+
+    /// Active downloads in the order they run, followed by finished ones.
+    pub fn items(&self) -> Vec<DownloadItem> {
+        let mut entries = self.entries.iter().collect::<Vec<_>>();
+        entries.sort_by_key(|entry| {
+            let active = entry.item.status.is_active();
+            (
+                !active,
+                active.then_some(Reverse(entry.item.priority)),
+                entry.seq,
+            )
+        });
+        entries
+            .into_iter()
+            .map(|entry| entry.item.clone())
+            .collect()
+    }
+
+    pub fn get(&self, id: &str) -> Option<DownloadItem> {
+        self.entry(id).map(|entry| entry.item.clone())
+    }
+
+    /// Queuing an id that is already known replaces its job, stopping the old run.
+    pub fn enqueue(&mut self, id: String, label: String, priority: i32, job: J) -> DownloadItem {
+        let item = DownloadItem {
+            id: id.clone(),
+            label,
+            status: DownloadStatus::Queued,
+            priority,
+            downloaded: 0,
+            total: 0,
+            error: None,
+        };
+
+        if let Some(entry) = self.entry_mut(&id) {
+            entry.stop();
+            entry.item = item.clone();
+            entry.job = job;
+            return item;
+        }
+
+        self.entries.push(Entry {
+            item: item.clone(),
+            seq: self.next_seq,
+            job,
+            generation: 0,
+            token: None,
+            slot: Default::default(),
+        });
+        self.next_seq += 1;
+        item
+    }
+
+    /// Keeps what was downloaded so far, so resuming picks up from there.
+    pub fn pause(&mut self, id: &str) -> Result<DownloadItem, Error> {
+        let entry = self.entry_mut(id).ok_or(Error::DownloadNotFound)?;
+        if matches!(
+            entry.item.status,
+            DownloadStatus::Queued | DownloadStatus::Running
+        ) {
+            entry.stop();
+            entry.item.status = DownloadStatus::Paused;
+        }
+        Ok(entry.item.clone())
+    }
+
+    /// Also retries failed and cancelled downloads.
+    pub fn resume(&mut self, id: &str) -> Result<DownloadItem, Error> {
+        let entry = self.entry_mut(id).ok_or(Error::DownloadNotFound)?;
+        if matches!(
+            entry.item.status,
+            DownloadStatus::Paused | DownloadStatus::Failed | DownloadStatus::Cancelled
+        ) {
+            entry.item.status = DownloadStatus::Queued;
+            entry.item.error = None;
+        }
+        Ok(entry.item.clone())
+    }
+
+    pub fn cancel(&mut self, id: &str) -> Result<DownloadItem, Error> {
+        let entry = self.entry_mut(id).ok_or(Error::DownloadNotFound)?;
+        if entry.item.status.is_active() {
+            entry.stop();
+            entry.item.status = DownloadStatus::Cancelled;
+        }
+        Ok(entry.item.clone())
+    }
+
+    /// Takes effect the next time a slot frees up; running downloads are not preempted.
+    pub fn set_priority(&mut self, id: &str, priority: i32) -> Result<DownloadItem, Error> {
+        let entry = self.entry_mut(id).ok_or(Error::DownloadNotFound)?;
+        entry.item.priority = priority;
+        Ok(entry.item.clone())
+    }
+
+    /// Returns the ids of the removed downloads.
+    pub fn clear_finished(&mut self) -> Vec<String> {
+        let (finished, active) = std::mem::take(&mut self.entries)
+            .into_iter()
+            .partition::<Vec<_>, _>(|entry| !entry.item.status.is_active());
+        self.entries = active;
+        finished.into_iter().map(|entry| entry.item.id).collect()
+    }
+
+    /// Marks the queued downloads that fit into the free slots as running, highest priority first.
+    pub fn take_startable(&mut self) -> Vec<Start<J>> {
+        let running = self
+            .entries
+            .iter()
+            .filter(|entry| entry.item.status == DownloadStatus::Running)
+            .count();
+
+        let mut queued = self
+            .entries
+            .iter_mut()
+            .filter(|entry| entry.item.status == DownloadStatus::Queued)
+            .collect::<Vec<_>>();
+        queued.sort_by_key(|entry| (Reverse(entry.item.priority), entry.seq));
+
+        queued
+            .into_iter()
+            .take(self.max_concurrent.saturating_sub(running))
+            .map(|entry| {
+                let token = CancellationToken::new();
+                entry.generation += 1;
+                entry.token = Some(token.clone());
+                entry.item.status = DownloadStatus::Running;
+
+                Start {
+                    item: entry.item.clone(),
+                    generation: entry.generation,
+                    job: entry.job.clone(),
+                    token,
+                    slot: entry.slot.clone(),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns false when the run reporting it is no longer the current one.
+    pub fn progress(&mut self, id: &str, generation: u64, downloaded: u64, total: u64) -> bool {
+        match self.current_run(id, generation) {
+            Some(entry) => {
+                entry.item.downloaded = downloaded;
+                entry.item.total = total;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Records how a run ended. Runs that were stopped already got their status when stopped.
+    pub fn finish(
+        &mut self,
+        id: &str,
+        generation: u64,
+        result: Result<(), String>,
+    ) -> Option<DownloadItem> {
+        let entry = self.current_run(id, generation)?;
+        entry.token = None;
+
+        match result {
+            Ok(()) => {
+                entry.item.status = DownloadStatus::Completed;
+                entry.item.downloaded = entry.item.downloaded.max(entry.item.total);
+            }
+            Err(error) => {
+                entry.item.status = DownloadStatus::Failed;
+                entry.item.error = Some(error);
+            }
+        }
+
+        Some(entry.item.clone())
+    }
+
+    fn current_run(&mut self, id: &str, generation: u64) -> Option<&mut Entry<J>> {
+        self.entry_mut(id).filter(|entry| {
+            entry.generation == generation
+                && entry.item.status == DownloadStatus::Running
+                && entry
+                    .token
+                    .as_ref()
+                    .is_some_and(|token| !token.is_cancelled())
+        })
+    }
+
+    fn entry(&self, id: &str) -> Option<&Entry<J>> {
+        self.entries.iter().find(|entry| entry.item.id == id)
+    }
+
+    fn entry_mut(&mut self, id: &str) -> Option<&mut Entry<J>> {
+        self.entries.iter_mut().find(|entry| entry.item.id == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids<J>(starts: &[Start<J>]) -> Vec<&str> {
+        starts.iter().map(|start| start.item.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_queue_order() {
+        let mut queue = Queue::new(2);
+        queue.enqueue("a".into(), "A".into(), 0, ());
+        queue.enqueue("b".into(), "B".into(), 0, ());
+        queue.enqueue("c".into(), "C".into(), 5, ());
+        queue.enqueue("d".into(), "D".into(), 0, ());
+
+        let starts = queue.take_startable();
+        assert_eq!(ids(&starts), vec!["c", "a"]);
+        assert!(queue.take_startable().is_empty());
+
+        queue.set_priority("d", 1).unwrap();
+        assert!(queue.finish("c", 1, Ok(())).is_some());
+        assert_eq!(ids(&queue.take_startable()), vec!["d"]);
+
+        let items = queue.items();
+        let order = items
+            .iter()
+            .map(|item| item.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec!["d", "a", "b", "c"]);
+
+        assert_eq!(queue.clear_finished(), vec!["c".to_string()]);
+        assert!(queue.get("c").is_none());
+    }
+
+    #[test]
+    fn test_pause_resume() {
+        let mut queue = Queue::new(1);
+        queue.enqueue("a".into(), "A".into(), 0, ());
+        queue.enqueue("b".into(), "B".into(), 0, ());
+
+        let first = queue.take_startable().pop().unwrap();
+        assert!(queue.progress("a", first.generation, 10, 100));
+
+        let paused = queue.pause("a").unwrap();
+        assert_eq!(paused.status, DownloadStatus::Paused);
+        assert_eq!(paused.downloaded, 10);
+        assert!(first.token.is_cancelled());
+
+        // The stopped run neither reports progress nor overrides the status once it returns.
+        assert!(!queue.progress("a", first.generation, 20, 100));
+        assert!(queue
+            .finish("a", first.generation, Err("cancelled".into()))
+            .is_none());
+        assert_eq!(ids(&queue.take_startable()), vec!["b"]);
+
+        queue.resume("a").unwrap();
+        queue.cancel("b").unwrap();
+        let second = queue.take_startable().pop().unwrap();
+        assert_eq!(second.item.id, "a");
+        assert_eq!(second.generation, first.generation + 1);
+
+        let failed = queue.finish("a", second.generation, Err("offline".into()));
+        assert_eq!(failed.unwrap().status, DownloadStatus::Failed);
+        assert_eq!(queue.resume("a").unwrap().status, DownloadStatus::Queued);
+        assert_eq!(queue.get("a").unwrap().error, None);
+
+        assert!(matches!(queue.pause("x"), Err(Error::DownloadNotFound)));
+    }
+}
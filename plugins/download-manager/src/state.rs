@@ -0,0 +1,20 @@
+use std::{future::Future, pin::Pin, sync::Arc, sync::Mutex};
+
+use tauri::Runtime;
+
+use crate::{queue::Queue, DownloadCtx};
+
+pub(crate) type DownloadFuture = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+pub(crate) type Job<R> = Arc<dyn Fn(DownloadCtx<R>) -> DownloadFuture + Send + Sync>;
+
+pub struct DownloadManagerState<R: Runtime> {
+    pub(crate) queue: Mutex<Queue<Job<R>>>,
+}
+
+impl<R: Runtime> Default for DownloadManagerState<R> {
+    fn default() -> Self {
+        Self {
+            queue: Mutex::new(Queue::default()),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum DownloadStatus {
+    Queued,
+    Running,
+    Paused,
+    Completed,
+    /// Stays in the list until resumed, which retries it.
+    Failed,
+    Cancelled,
+}
+
+impl DownloadStatus {
+    /// Still owned by the queue, as opposed to finished one way or another.
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self,
+            DownloadStatus::Queued | DownloadStatus::Running | DownloadStatus::Paused
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct DownloadItem {
+    /// Chosen by the plugin that queued it, e.g. `local-stt:whisper-small-q8`.
+    pub id: String,
+    pub label: String,
+    pub status: DownloadStatus,
+    /// Higher runs first. Equal priorities run in the order they were queued.
+    pub priority: i32,
+    pub downloaded: u64,
+    /// Zero until the size is known.
+    pub total: u64,
+    pub error: Option<String>,
+}
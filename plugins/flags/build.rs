@@ -1,4 +1,11 @@
-const COMMANDS: &[&str] = &["is_enabled", "enable", "disable"];
+const COMMANDS: &[&str] = &[
+    "is_enabled",
+    "enable",
+    "disable",
+    "list_overrides",
+    "set_override",
+    "refresh_remote_flags",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
@@ -15,6 +15,15 @@ async enable(flag: StoreKey) : Promise<null> {
 },
 async disable(flag: StoreKey) : Promise<null> {
     return await TAURI_INVOKE("plugin:flags|disable", { flag });
+},
+async listOverrides() : Promise<Partial<{ [key in StoreKey]: boolean }>> {
+    return await TAURI_INVOKE("plugin:flags|list_overrides");
+},
+async setOverride(flag: StoreKey, value: boolean | null) : Promise<null> {
+    return await TAURI_INVOKE("plugin:flags|set_override", { flag, value });
+},
+async refreshRemoteFlags() : Promise<null> {
+    return await TAURI_INVOKE("plugin:flags|refresh_remote_flags");
 }
 }
 
@@ -26,3 +26,29 @@ pub(crate) async fn disable<R: tauri::Runtime>(
 ) -> Result<(), String> {
     app.disable(flag).map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn list_overrides<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<std::collections::HashMap<crate::StoreKey, bool>, String> {
+    app.list_overrides().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_override<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    flag: crate::StoreKey,
+    value: Option<bool>,
+) -> Result<(), String> {
+    app.set_override(flag, value).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn refresh_remote_flags<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<(), String> {
+    app.refresh_remote_flags().await.map_err(|e| e.to_string())
+}
@@ -6,6 +6,14 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     #[error(transparent)]
     Store(#[from] tauri_plugin_store2::Error),
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error("invalid manifest signature: {0}")]
+    Signature(String),
+    #[error("remote flags are not configured")]
+    RemoteNotConfigured,
 }
 
 impl Serialize for Error {
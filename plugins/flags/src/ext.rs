@@ -1,10 +1,19 @@
+use std::{collections::HashMap, future::Future};
+
 use tauri_plugin_store2::StorePluginExt;
 
+use crate::{remote, InternalStoreKey, StoreKey};
+
 pub trait FlagsPluginExt<R: tauri::Runtime> {
     fn flags_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey>;
     fn is_enabled(&self, flag: crate::StoreKey) -> Result<bool, crate::Error>;
     fn enable(&self, flag: crate::StoreKey) -> Result<(), crate::Error>;
     fn disable(&self, flag: crate::StoreKey) -> Result<(), crate::Error>;
+
+    fn list_overrides(&self) -> Result<HashMap<crate::StoreKey, bool>, crate::Error>;
+    fn set_override(&self, flag: crate::StoreKey, value: Option<bool>) -> Result<(), crate::Error>;
+
+    fn refresh_remote_flags(&self) -> impl Future<Output = Result<(), crate::Error>>;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> FlagsPluginExt<R> for T {
@@ -12,9 +21,30 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> FlagsPluginExt<R> for T {
         self.scoped_store(crate::PLUGIN_NAME).unwrap()
     }
 
+    /// Developer override, then the user's own choice, then the remote manifest.
     fn is_enabled(&self, flag: crate::StoreKey) -> Result<bool, crate::Error> {
-        let v = self.flags_store().get(flag)?;
-        Ok(v.unwrap_or(false))
+        if let Some(v) = self.list_overrides()?.get(&flag) {
+            return Ok(*v);
+        }
+
+        if let Some(v) = self.flags_store().get(flag)? {
+            return Ok(v);
+        }
+
+        let internal = internal_store(self);
+        let cached: Option<remote::CachedManifest> =
+            internal.get(InternalStoreKey::RemoteManifest)?;
+
+        match cached {
+            Some(cached) => {
+                let bucket = rollout_bucket(self)?;
+                Ok(cached
+                    .manifest
+                    .evaluate(&flag.to_string(), bucket)
+                    .unwrap_or(false))
+            }
+            None => Ok(false),
+        }
     }
 
     fn enable(&self, flag: crate::StoreKey) -> Result<(), crate::Error> {
@@ -26,4 +56,62 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> FlagsPluginExt<R> for T {
         self.flags_store().set(flag, false)?;
         Ok(())
     }
+
+    fn list_overrides(&self) -> Result<HashMap<crate::StoreKey, bool>, crate::Error> {
+        let v = internal_store(self).get(InternalStoreKey::Overrides)?;
+        Ok(v.unwrap_or_default())
+    }
+
+    fn set_override(&self, flag: crate::StoreKey, value: Option<bool>) -> Result<(), crate::Error> {
+        let mut overrides = self.list_overrides()?;
+
+        match value {
+            Some(v) => overrides.insert(flag, v),
+            None => overrides.remove(&flag),
+        };
+
+        internal_store(self).set(InternalStoreKey::Overrides, overrides)?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn refresh_remote_flags(&self) -> Result<(), crate::Error> {
+        let config = remote::RemoteConfig::from_env().ok_or(crate::Error::RemoteNotConfigured)?;
+
+        // On failure the previously verified manifest stays in place, so offline starts keep working.
+        let manifest = remote::fetch(&config).await?;
+        internal_store(self).set(InternalStoreKey::RemoteManifest, manifest)?;
+        Ok(())
+    }
+}
+
+fn internal_store<R: tauri::Runtime, T: tauri::Manager<R>>(
+    app: &T,
+) -> tauri_plugin_store2::ScopedStore<R, InternalStoreKey> {
+    app.scoped_store(format!("{}-internal", crate::PLUGIN_NAME))
+        .unwrap()
+}
+
+/// A stable per-install number in 0..100 that decides which side of a partial rollout we land on.
+fn rollout_bucket<R: tauri::Runtime, T: tauri::Manager<R>>(app: &T) -> Result<u8, crate::Error> {
+    let store = internal_store(app);
+
+    if let Some(bucket) = store.get(InternalStoreKey::RolloutBucket)? {
+        return Ok(bucket);
+    }
+
+    let bucket = (uuid::Uuid::new_v4().as_u128() % 100) as u8;
+    store.set(InternalStoreKey::RolloutBucket, bucket)?;
+    Ok(bucket)
+}
+
+pub(crate) fn should_refresh<R: tauri::Runtime, T: tauri::Manager<R>>(app: &T) -> bool {
+    if remote::RemoteConfig::from_env().is_none() {
+        return false;
+    }
+
+    match internal_store(app).get::<remote::CachedManifest>(InternalStoreKey::RemoteManifest) {
+        Ok(Some(cached)) => cached.is_stale(),
+        _ => true,
+    }
 }
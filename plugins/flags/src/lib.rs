@@ -1,6 +1,7 @@
 mod commands;
 mod error;
 mod ext;
+mod remote;
 mod store;
 
 pub use error::{Error, Result};
@@ -16,6 +17,9 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::is_enabled::<tauri::Wry>,
             commands::enable::<tauri::Wry>,
             commands::disable::<tauri::Wry>,
+            commands::list_overrides::<tauri::Wry>,
+            commands::set_override::<tauri::Wry>,
+            commands::refresh_remote_flags::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
@@ -25,7 +29,18 @@ pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
 
     tauri::plugin::Builder::new(PLUGIN_NAME)
         .invoke_handler(specta_builder.invoke_handler())
-        .setup(|_app, _api| Ok(()))
+        .setup(|app, _api| {
+            if ext::should_refresh(app) {
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = app.refresh_remote_flags().await {
+                        tracing::warn!("remote_flags_refresh_failed: {}", e);
+                    }
+                });
+            }
+
+            Ok(())
+        })
         .build()
 }
 
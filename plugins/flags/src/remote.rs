@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+/// How long a fetched manifest is trusted before startup fetches a fresh one.
+pub const REFRESH_INTERVAL_SECS: u64 = 60 * 60;
+
+/// Where the manifest lives and the minisign key it must be signed with.
+/// Both come from the build environment; without them only local values are used.
+#[derive(Debug, Clone)]
+pub struct RemoteConfig {
+    pub url: String,
+    pub public_key: String,
+}
+
+impl RemoteConfig {
+    pub fn from_env() -> Option<Self> {
+        let url = option_env!("FLAGS_MANIFEST_URL")?;
+        let public_key = option_env!("FLAGS_PUBLIC_KEY")?;
+
+        Some(Self {
+            url: url.to_string(),
+            public_key: public_key.to_string(),
+        })
+    }
+
+    fn signature_url(&self) -> String {
+        format!("{}.minisig", self.url)
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RemoteManifest {
+    #[serde(default)]
+    pub flags: HashMap<String, RemoteFlag>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RemoteFlag {
+    pub enabled: bool,
+    /// Percentage of installs that get the flag, for gradual rollouts.
+    #[serde(default = "full_rollout")]
+    pub rollout: u8,
+}
+
+fn full_rollout() -> u8 {
+    100
+}
+
+impl RemoteManifest {
+    /// Whether `flag` is on for an install in `bucket` (0..100), if the manifest mentions it.
+    pub fn evaluate(&self, flag: &str, bucket: u8) -> Option<bool> {
+        self.flags
+            .get(flag)
+            .map(|f| f.enabled && bucket < f.rollout.min(100))
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CachedManifest {
+    pub manifest: RemoteManifest,
+    pub fetched_at: u64,
+}
+
+impl CachedManifest {
+    pub fn is_stale(&self) -> bool {
+        now_secs().saturating_sub(self.fetched_at) > REFRESH_INTERVAL_SECS
+    }
+}
+
+pub fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Downloads the manifest and its detached signature, and only returns it once verified.
+pub async fn fetch(config: &RemoteConfig) -> Result<CachedManifest, crate::Error> {
+    let client = hypr_proxy::client();
+
+    let body = client
+        .get(&config.url)
+        .send()
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?;
+    let signature = client
+        .get(config.signature_url())
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+
+    verify(&body, &signature, &config.public_key)?;
+
+    Ok(CachedManifest {
+        manifest: serde_json::from_slice(&body)?,
+        fetched_at: now_secs(),
+    })
+}
+
+fn verify(body: &[u8], signature: &str, public_key: &str) -> Result<(), crate::Error> {
+    let public_key = minisign_verify::PublicKey::from_base64(public_key)
+        .map_err(|e| crate::Error::Signature(e.to_string()))?;
+    let signature = minisign_verify::Signature::decode(signature)
+        .map_err(|e| crate::Error::Signature(e.to_string()))?;
+
+    public_key
+        .verify(body, &signature, false)
+        .map_err(|e| crate::Error::Signature(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate() {
+        let manifest: RemoteManifest = serde_json::from_str(
+            r#"{
+                "flags": {
+                    "CloudPreview": { "enabled": true, "rollout": 25 },
+                    "ChatRightPanel": { "enabled": true },
+                    "Techincal": { "enabled": false, "rollout": 100 }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.evaluate("CloudPreview", 10), Some(true));
+        assert_eq!(manifest.evaluate("CloudPreview", 25), Some(false));
+        assert_eq!(manifest.evaluate("ChatRightPanel", 99), Some(true));
+        assert_eq!(manifest.evaluate("Techincal", 0), Some(false));
+        assert_eq!(manifest.evaluate("Unknown", 0), None);
+    }
+
+    #[test]
+    fn test_rejects_bad_signature() {
+        assert!(matches!(
+            verify(b"{}", "not a signature", "not a key"),
+            Err(crate::Error::Signature(_))
+        ));
+    }
+}
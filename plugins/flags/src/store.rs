@@ -1,6 +1,17 @@
 use tauri_plugin_store2::ScopedStoreKey;
 
-#[derive(serde::Deserialize, specta::Type, PartialEq, Eq, Hash, strum::Display)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    serde::Serialize,
+    serde::Deserialize,
+    specta::Type,
+    PartialEq,
+    Eq,
+    Hash,
+    strum::Display,
+)]
 pub enum StoreKey {
     ChatRightPanel,
     CloudPreview,
@@ -8,3 +19,13 @@ pub enum StoreKey {
 }
 
 impl ScopedStoreKey for StoreKey {}
+
+/// Bookkeeping kept apart from the flags themselves, so it never shows up as a flag.
+#[derive(PartialEq, Eq, Hash, strum::Display)]
+pub(crate) enum InternalStoreKey {
+    RemoteManifest,
+    Overrides,
+    RolloutBucket,
+}
+
+impl ScopedStoreKey for InternalStoreKey {}
@@ -12,9 +12,26 @@ const COMMANDS: &[&str] = &[
     "set_mic_muted",
     "get_speaker_muted",
     "set_speaker_muted",
+    "mark_highlight",
     "start_session",
     "stop_session",
+    "pause_session",
+    "resume_session",
     "get_state",
+    "get_interrupted_session",
+    "resolve_interrupted_session",
+    "retranscribe_range",
+    "word_to_offset",
+    "offset_to_word",
+    "stream_audio",
+    "stop_audio_stream",
+    "assign_speaker",
+    "suggest_speakers",
+    "refresh_wake_listener",
+    "retranscribe_sessions",
+    "get_retranscription",
+    "apply_retranscription",
+    "discard_retranscription",
 ];
 
 fn main() {
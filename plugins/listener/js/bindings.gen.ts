@@ -46,14 +46,65 @@ async getSpeakerMuted() : Promise<boolean> {
 async setSpeakerMuted(muted: boolean) : Promise<null> {
     return await TAURI_INVOKE("plugin:listener|set_speaker_muted", { muted });
 },
+async markHighlight(note: string | null) : Promise<TimelineEvent | null> {
+    return await TAURI_INVOKE("plugin:listener|mark_highlight", { note });
+},
 async startSession(sessionId: string) : Promise<null> {
     return await TAURI_INVOKE("plugin:listener|start_session", { sessionId });
 },
 async stopSession() : Promise<null> {
     return await TAURI_INVOKE("plugin:listener|stop_session");
 },
+async pauseSession() : Promise<null> {
+    return await TAURI_INVOKE("plugin:listener|pause_session");
+},
+async resumeSession() : Promise<null> {
+    return await TAURI_INVOKE("plugin:listener|resume_session");
+},
 async getState() : Promise<string> {
     return await TAURI_INVOKE("plugin:listener|get_state");
+},
+async getInterruptedSession() : Promise<InterruptedSession | null> {
+    return await TAURI_INVOKE("plugin:listener|get_interrupted_session");
+},
+async resolveInterruptedSession(resume: boolean) : Promise<null> {
+    return await TAURI_INVOKE("plugin:listener|resolve_interrupted_session", { resume });
+},
+async retranscribeRange(sessionId: string, startMs: number, endMs: number, model: SupportedSttModel) : Promise<Word2[]> {
+    return await TAURI_INVOKE("plugin:listener|retranscribe_range", { sessionId, startMs, endMs, model });
+},
+async wordToOffset(sessionId: string, wordIndex: number) : Promise<number | null> {
+    return await TAURI_INVOKE("plugin:listener|word_to_offset", { sessionId, wordIndex });
+},
+async offsetToWord(sessionId: string, offsetMs: number) : Promise<number | null> {
+    return await TAURI_INVOKE("plugin:listener|offset_to_word", { sessionId, offsetMs });
+},
+async streamAudio(sessionId: string, startMs: number, endMs: number | null, channel: TAURI_CHANNEL<AudioFrame>) : Promise<null> {
+    return await TAURI_INVOKE("plugin:listener|stream_audio", { sessionId, startMs, endMs, channel });
+},
+async stopAudioStream() : Promise<null> {
+    return await TAURI_INVOKE("plugin:listener|stop_audio_stream");
+},
+async assignSpeaker(sessionId: string, speakerIndex: number, name: string, profileId: string | null) : Promise<Word2[]> {
+    return await TAURI_INVOKE("plugin:listener|assign_speaker", { sessionId, speakerIndex, name, profileId });
+},
+async suggestSpeakers(sessionId: string) : Promise<SpeakerSuggestion[]> {
+    return await TAURI_INVOKE("plugin:listener|suggest_speakers", { sessionId });
+}
+async refreshWakeListener() : Promise<boolean> {
+    return await TAURI_INVOKE("plugin:listener|refresh_wake_listener");
+},
+async retranscribeSessions(sessionIds: string[], model: SupportedSttModel) : Promise<QueuedTask[]> {
+    return await TAURI_INVOKE("plugin:listener|retranscribe_sessions", { sessionIds, model });
+},
+async getRetranscription(sessionId: string) : Promise<SessionRetranscription | null> {
+    return await TAURI_INVOKE("plugin:listener|get_retranscription", { sessionId });
+},
+async applyRetranscription(sessionId: string) : Promise<Word2[]> {
+    return await TAURI_INVOKE("plugin:listener|apply_retranscription", { sessionId });
+},
+async discardRetranscription(sessionId: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:listener|discard_retranscription", { sessionId });
 }
 }
 
@@ -72,8 +123,135 @@ sessionEvent: "plugin:listener:session-event"
 
 /** user-defined types **/
 
-export type SessionEvent = { type: "inactive" } | { type: "running_active" } | { type: "finalWords"; words: Partial<{ [key in number]: Word2[] }> } | { type: "partialWords"; words: Partial<{ [key in number]: Word2[] }> } | { type: "audioAmplitude"; mic: number; speaker: number } | { type: "micMuted"; value: boolean } | { type: "speakerMuted"; value: boolean }
+export type AmModel = "am-parakeet-v2" | "am-parakeet-v3" | "am-whisper-large-v3"
+/**
+ * A slice of a recording, `offset_ms` from its start.
+ */
+export type AudioFrame = { offset_ms: number; sample_rate: number; samples: number[] }
+/**
+ * A recording that never reached `stop_session`, left behind by a crash or forced quit.
+ */
+export type InterruptedSession = { session_id: string; started_at_ms: number; 
+/**
+ * Last time the listener was known to be alive; used as the recording end.
+ */
+checkpoint_at_ms: number }
+export type KyutaiModel = "kyutai-stt-1b-en-fr"
+/**
+ * Why a recording is said to be running long.
+ */
+export type LengthAlert = 
+/**
+ * Recording for the configured number of minutes.
+ */
+{ type: "duration"; minutes: number } | 
+/**
+ * Past the scheduled end of the note's calendar event.
+ */
+{ type: "eventEnd"; event_name: string; end_date: string }
+export type QueuedTask = { id: string; kind: QueuedTaskKind; 
+/**
+ * JSON, interpreted by whoever runs tasks of this kind.
+ */
+payload: string; status: QueuedTaskStatus; attempts: number; max_attempts: number; last_error: string | null; 
+/**
+ * Not picked up before this time; pushed back on every failed attempt.
+ */
+run_at: string; created_at: string; updated_at: string }
+export type QueuedTaskKind = "enhance" | "title" | "export" | 
+/**
+ * Runs a session's recording through another model; payload has the session and model.
+ */
+"retranscribe" | 
+/**
+ * Sums up past meetings with an upcoming event's attendees; payload has the event.
+ */
+"pre_meeting_brief" | 
+/**
+ * Runs a session's pipeline stage by stage; payload has the session and what's left.
+ */
+"pipeline"
+export type QueuedTaskStatus = "queued" | "running" | 
+/**
+ * Held back until resumed; a running task stops at its next heartbeat.
+ */
+"paused" | "completed" | 
+/**
+ * Ran out of attempts; stays here until retried by hand.
+ */
+"dead" | "cancelled"
+export type SessionEvent = { type: "inactive" } | { type: "running_active" } | { type: "running_paused" } | { type: "finalWords"; words: Partial<{ [key in number]: Word2[] }> } | { type: "partialWords"; words: Partial<{ [key in number]: Word2[] }> } | { type: "audioAmplitude"; mic: number; speaker: number } | { type: "micMuted"; value: boolean } | { type: "speakerMuted"; value: boolean } | { type: "timelineEvent"; event: TimelineEvent } | { type: "stats"; session_id: string; stats: SessionStats } | { type: "translatedSegment"; channel: number; start_ms: number | null; end_ms: number | null; 
+/**
+ * ISO 639-1 code of `text`.
+ */
+language: string; source: string; text: string } | { type: "speakerSuggested"; channel: number; suggestion: SpeakerSuggestion } | { type: "wakeListening"; 
+/**
+ * The mic is listened to for `phrase` while idle, or no longer when `None`.
+ */
+phrase: string | null } | { type: "wakePhraseDetected" } | 
+/**
+ * `done_ms` of the recording's `total_ms` is transcribed again.
+ */
+{ type: "retranscribeProgress"; session_id: string; task_id: string; done_ms: number; total_ms: number } | 
+/**
+ * A new transcript waits to be previewed and applied or discarded.
+ */
+{ type: "retranscriptionReady"; session_id: string } | 
+/**
+ * The recording has run longer than it was meant to.
+ */
+{ type: "lengthAlert"; session_id: string; alert: LengthAlert }
+/**
+ * Computed on-device when a recording stops.
+ */
+/**
+ * A transcript from re-running a session's recording through another model, kept apart
+ * from the session until the user accepts it.
+ */
+export type SessionRetranscription = { session_id: string; 
+/**
+ * Name of the model it was transcribed with.
+ */
+model: string; words: Word2[]; created_at: string }
+export type SessionStats = { duration_ms: number; word_count: number; interruptions: number; 
+/**
+ * Share of the recording without speech according to VAD, from 0 to 1.
+ */
+silence_ratio: number | null; 
+/**
+ * Ordered by talk time, longest first.
+ */
+speakers: SpeakerStats[] }
 export type SpeakerIdentity = { type: "unassigned"; value: { index: number } } | { type: "assigned"; value: { id: string; label: string } }
+export type SpeakerStats = { 
+/**
+ * `None` for words the transcriber didn't attribute to anyone.
+ */
+speaker: SpeakerIdentity | null; talk_time_ms: number; word_count: number; 
+/**
+ * Turns this speaker started while someone else was still talking.
+ */
+interruptions: number }
+export type SpeakerSuggestion = { speaker: SpeakerIdentity; profile_id: string; name: string; 
+/**
+ * Cosine similarity of the voices, from 0 to 1.
+ */
+similarity: number }
+export type SupportedSttModel = WhisperModel | AmModel | KyutaiModel | string
+/**
+ * Something other than speech that happened during a recording.
+ */
+export type TimelineEvent = { id: string; session_id: string; kind: TimelineEventKind; 
+/**
+ * Unix milliseconds, the same clock as transcript words.
+ */
+start_ms: number; 
+/**
+ * Set for periods like a mute; `None` for instants and periods still going on.
+ */
+end_ms: number | null; label: string | null }
+export type TimelineEventKind = "mic_muted" | "speaker_muted" | "highlight"
+export type WhisperModel = "QuantizedTiny" | "QuantizedTinyEn" | "QuantizedBase" | "QuantizedBaseEn" | "QuantizedSmall" | "QuantizedSmallEn" | "QuantizedLargeTurbo"
 export type Word2 = { text: string; speaker: SpeakerIdentity | null; confidence: number | null; start_ms: number | null; end_ms: number | null }
 
 /** tauri-specta globals **/
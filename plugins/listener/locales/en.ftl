@@ -0,0 +1,2 @@
+# Shown in the tray tooltip when a recording fails
+error-recording-stopped = Recording stopped unexpectedly
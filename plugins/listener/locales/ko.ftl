@@ -0,0 +1,2 @@
+# Shown in the tray tooltip when a recording fails
+error-recording-stopped = 녹음이 예기치 않게 중지되었습니다
@@ -0,0 +1,211 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use futures_util::StreamExt;
+use hypr_db_user::SpeakerProfile;
+use owhisper_interface::{SpeakerIdentity, Word2};
+use ractor::{Actor, ActorProcessingErr, ActorRef};
+use tauri_specta::Event;
+
+use crate::{actors::AudioFrame, identify, SessionEvent};
+
+const SAMPLES_PER_MS: u64 = 16;
+/// Final words trail the audio by a few seconds, so this only has to reach that far back.
+const AUDIO_WINDOW_MS: u64 = 30_000;
+
+pub enum IdentifyMsg {
+    Words(HashMap<usize, Vec<Word2>>),
+}
+
+pub struct IdentifyArgs {
+    pub app: tauri::AppHandle,
+    pub profiles: Vec<SpeakerProfile>,
+    pub session_start_ts_ms: u64,
+    pub tap: hypr_audio::AudioTap<AudioFrame>,
+}
+
+pub struct IdentifyState {
+    app: tauri::AppHandle,
+    profiles: Vec<SpeakerProfile>,
+    session_start_ts_ms: u64,
+    /// Mic and speaker audio, matching the channels of the transcript. Filled from the tap.
+    channels: Arc<Mutex<[ChannelAudio; 2]>>,
+    tap_task: tokio::task::JoinHandle<()>,
+    speech: HashMap<(usize, u8), Vec<f32>>,
+    identified: HashSet<(usize, u8)>,
+}
+
+/// Suggests who is talking once a speaker has said enough to be compared with the known voices.
+/// Each speaker is tried once per session.
+pub struct SpeakerIdentifier;
+impl Actor for SpeakerIdentifier {
+    type Msg = IdentifyMsg;
+    type State = IdentifyState;
+    type Arguments = IdentifyArgs;
+
+    async fn pre_start(
+        &self,
+        _myself: ActorRef<Self::Msg>,
+        args: Self::Arguments,
+    ) -> Result<Self::State, ActorProcessingErr> {
+        let channels = Arc::new(Mutex::new(Default::default()));
+        let tap_task = tokio::spawn(buffer_frames(args.tap, channels.clone()));
+
+        Ok(IdentifyState {
+            app: args.app,
+            profiles: args.profiles,
+            session_start_ts_ms: args.session_start_ts_ms,
+            channels,
+            tap_task,
+            speech: HashMap::new(),
+            identified: HashSet::new(),
+        })
+    }
+
+    async fn handle(
+        &self,
+        _myself: ActorRef<Self::Msg>,
+        msg: Self::Msg,
+        st: &mut Self::State,
+    ) -> Result<(), ActorProcessingErr> {
+        match msg {
+            IdentifyMsg::Words(words) => {
+                for (channel, words) in words {
+                    if let Some(speaker) = collect_speech(st, channel, &words) {
+                        identify_speaker(st, channel, speaker).await;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn post_stop(
+        &self,
+        _myself: ActorRef<Self::Msg>,
+        st: &mut Self::State,
+    ) -> Result<(), ActorProcessingErr> {
+        st.tap_task.abort();
+        Ok(())
+    }
+}
+
+async fn buffer_frames(
+    mut tap: hypr_audio::AudioTap<AudioFrame>,
+    channels: Arc<Mutex<[ChannelAudio; 2]>>,
+) {
+    let mut skipped = 0;
+
+    while let Some(frame) = tap.next().await {
+        let mut channels = channels.lock().unwrap();
+
+        // Whatever the tap skipped still counts towards the offsets from the session start.
+        if tap.skipped() > skipped {
+            let missed = (tap.skipped() - skipped) * frame.mic.len() as u64;
+            skipped = tap.skipped();
+            for audio in channels.iter_mut() {
+                audio.skip(missed);
+            }
+        }
+
+        channels[0].push(&frame.mic);
+        channels[1].push(&frame.spk);
+    }
+}
+
+/// Adds the audio under `words` to their speakers, and returns a speaker that now has enough.
+fn collect_speech(st: &mut IdentifyState, channel: usize, words: &[Word2]) -> Option<u8> {
+    let channels = st.channels.lock().unwrap();
+    let audio = channels.get(channel)?;
+    let mut ready = None;
+
+    for word in words {
+        let Some(SpeakerIdentity::Unassigned { index }) = word.speaker else {
+            continue;
+        };
+        let (Some(start_ms), Some(end_ms)) = (word.start_ms, word.end_ms) else {
+            continue;
+        };
+        if st.identified.contains(&(channel, index)) {
+            continue;
+        }
+
+        let speech = st.speech.entry((channel, index)).or_default();
+        speech.extend(audio.range(
+            start_ms.saturating_sub(st.session_start_ts_ms),
+            end_ms.saturating_sub(st.session_start_ts_ms),
+        ));
+        if speech.len() as u64 >= identify::MIN_SPEECH_MS * SAMPLES_PER_MS {
+            ready = Some(index);
+        }
+    }
+
+    ready
+}
+
+async fn identify_speaker(st: &mut IdentifyState, channel: usize, index: u8) {
+    st.identified.insert((channel, index));
+    let Some(samples) = st.speech.remove(&(channel, index)) else {
+        return;
+    };
+
+    let embedding = match tokio::task::spawn_blocking(move || identify::embed(&samples)).await {
+        Ok(Ok(embedding)) => embedding,
+        Ok(Err(e)) => {
+            tracing::error!("speaker_embedding_failed: {:?}", e);
+            return;
+        }
+        Err(e) => {
+            tracing::error!("speaker_embedding_panicked: {:?}", e);
+            return;
+        }
+    };
+
+    let speaker = SpeakerIdentity::Unassigned { index };
+    if let Some(suggestion) = identify::best_match(speaker, &embedding, &st.profiles) {
+        let _ = SessionEvent::SpeakerSuggested {
+            channel,
+            suggestion,
+        }
+        .emit(&st.app);
+    }
+}
+
+#[derive(Default)]
+struct ChannelAudio {
+    samples: VecDeque<f32>,
+    /// Samples dropped off the front since the session started.
+    dropped: u64,
+}
+
+impl ChannelAudio {
+    fn push(&mut self, data: &[f32]) {
+        self.samples.extend(data);
+
+        let max = (AUDIO_WINDOW_MS * SAMPLES_PER_MS) as usize;
+        if self.samples.len() > max {
+            let excess = self.samples.len() - max;
+            self.samples.drain(..excess);
+            self.dropped += excess as u64;
+        }
+    }
+
+    /// Drops what is buffered, along with `samples` that never made it here.
+    fn skip(&mut self, samples: u64) {
+        self.dropped += self.samples.len() as u64 + samples;
+        self.samples.clear();
+    }
+
+    /// Whatever is still buffered between the two offsets from the session start.
+    fn range(&self, start_ms: u64, end_ms: u64) -> impl Iterator<Item = f32> + '_ {
+        let index = |ms: u64| {
+            (ms * SAMPLES_PER_MS)
+                .saturating_sub(self.dropped)
+                .min(self.samples.len() as u64) as usize
+        };
+        let (start, end) = (index(start_ms), index(end_ms));
+        self.samples.range(start..end.max(start)).copied()
+    }
+}
@@ -8,25 +8,44 @@ use owhisper_interface::{ControlMessage, MixedMessage, Word2};
 use ractor::{Actor, ActorProcessingErr, ActorRef};
 use tauri_specta::Event;
 
-use crate::{manager::TranscriptManager, SessionEvent};
+use tauri::Manager;
+
+use crate::{
+    actors::{AudioFrame, IdentifyMsg},
+    captions::{CaptionFile, CaptionLines, LiveCaptions},
+    dictionary::Dictionary,
+    filter::WordFilters,
+    manager::TranscriptManager,
+    translate::Translator,
+    SessionEvent,
+};
 
 const LISTEN_STREAM_TIMEOUT: Duration = Duration::from_secs(60 * 15);
 
-pub enum ListenMsg {
-    Audio(Bytes, Bytes),
-}
+/// The bridge takes no messages: audio comes through its tap, words go out as events.
+pub enum ListenMsg {}
 
 pub struct ListenArgs {
     pub app: tauri::AppHandle,
     pub session_id: String,
     pub languages: Vec<hypr_language::Language>,
+    pub keyterms: Vec<String>,
+    pub dictionary: Dictionary,
+    pub filters: WordFilters,
+    pub translation_language: Option<hypr_language::Language>,
+    pub caption_file: Option<CaptionFile>,
+    pub caption_lines: usize,
     pub onboarding: bool,
     pub session_start_ts_ms: u64,
+    pub identifier: Option<ActorRef<IdentifyMsg>>,
+    pub tap: hypr_audio::AudioTap<AudioFrame>,
 }
 
 pub struct ListenState {
-    tx: tokio::sync::mpsc::Sender<MixedMessage<(Bytes, Bytes), ControlMessage>>,
+    app: tauri::AppHandle,
+    tx_task: tokio::task::JoinHandle<()>,
     rx_task: tokio::task::JoinHandle<()>,
+    caption_file: Option<CaptionFile>,
 }
 
 pub struct ListenBridge;
@@ -62,6 +81,7 @@ impl Actor for ListenBridge {
                 model: conn.model,
                 languages: args.languages,
                 redemption_time_ms: Some(if args.onboarding { 60 } else { 400 }),
+                keyterms: args.keyterms,
                 ..Default::default()
             })
             .build_dual();
@@ -69,6 +89,15 @@ impl Actor for ListenBridge {
         let rx_task = tokio::spawn({
             let app = args.app.clone();
             let session_id = args.session_id.clone();
+            let dictionary = args.dictionary;
+            let filters = args.filters;
+            let identifier = args.identifier;
+            let translator = args
+                .translation_language
+                .map(|language| Translator::spawn(args.app.clone(), language));
+            let caption_file = args.caption_file.clone();
+            let mut captions = CaptionLines::new(args.caption_lines);
+            let session_start_ts_ms = args.session_start_ts_ms;
 
             async move {
                 let outbound = tokio_stream::wrappers::ReceiverStream::new(rx);
@@ -82,7 +111,7 @@ impl Actor for ListenBridge {
                 };
                 futures_util::pin_mut!(listen_stream);
 
-                let mut manager = TranscriptManager::with_unix_timestamp(args.session_start_ts_ms);
+                let mut manager = TranscriptManager::with_unix_timestamp(session_start_ts_ms);
 
                 loop {
                     match tokio::time::timeout(LISTEN_STREAM_TIMEOUT, listen_stream.next()).await {
@@ -95,9 +124,10 @@ impl Actor for ListenBridge {
                                 .map(|(channel_idx, words)| {
                                     (
                                         *channel_idx,
-                                        words
-                                            .iter()
-                                            .map(|w| Word2::from(w.clone()))
+                                        filters
+                                            .apply(dictionary.apply(words.clone()))
+                                            .into_iter()
+                                            .map(Word2::from)
                                             .collect::<Vec<_>>(),
                                     )
                                 })
@@ -115,9 +145,10 @@ impl Actor for ListenBridge {
                                 .map(|(channel_idx, words)| {
                                     (
                                         *channel_idx,
-                                        words
-                                            .iter()
-                                            .map(|w| Word2::from(w.clone()))
+                                        filters
+                                            .apply(dictionary.apply(words.clone()))
+                                            .into_iter()
+                                            .map(Word2::from)
                                             .collect::<Vec<_>>(),
                                     )
                                 })
@@ -136,6 +167,29 @@ impl Actor for ListenBridge {
                             .await
                             .unwrap();
 
+                            if let Some(translator) = &translator {
+                                for (channel_idx, words) in &final_words_by_channel {
+                                    translator.push(*channel_idx, words.clone());
+                                }
+                            }
+
+                            if let Some(identifier) = &identifier {
+                                identifier
+                                    .cast(IdentifyMsg::Words(final_words_by_channel.clone()))
+                                    .ok();
+                            }
+
+                            {
+                                use tauri_plugin_tray::TrayPluginExt;
+                                app.add_recorded_words(
+                                    final_words_by_channel.values().map(Vec::len).sum(),
+                                );
+                            }
+
+                            captions.push_final(&in_spoken_order(&final_words_by_channel));
+                            captions.set_partial(&in_spoken_order(&partial_words_by_channel));
+                            publish_captions(&app, caption_file.as_ref(), captions.text());
+
                             SessionEvent::FinalWords {
                                 words: final_words_by_channel,
                             }
@@ -157,21 +211,23 @@ impl Actor for ListenBridge {
             }
         });
 
-        Ok(ListenState { tx, rx_task })
+        let tx_task = tokio::spawn(send_frames(args.tap, tx));
+
+        Ok(ListenState {
+            app: args.app,
+            tx_task,
+            rx_task,
+            caption_file: args.caption_file,
+        })
     }
 
     async fn handle(
         &self,
         _myself: ActorRef<Self::Msg>,
         message: Self::Msg,
-        state: &mut Self::State,
+        _state: &mut Self::State,
     ) -> Result<(), ActorProcessingErr> {
-        match message {
-            ListenMsg::Audio(mic, spk) => {
-                let _ = state.tx.try_send(MixedMessage::Audio((mic, spk)));
-            }
-        }
-        Ok(())
+        match message {}
     }
 
     async fn post_stop(
@@ -179,11 +235,53 @@ impl Actor for ListenBridge {
         _myself: ActorRef<Self::Msg>,
         state: &mut Self::State,
     ) -> Result<(), ActorProcessingErr> {
+        state.tx_task.abort();
         state.rx_task.abort();
+        // Nothing is being said anymore, so streams shouldn't keep showing the last words.
+        publish_captions(&state.app, state.caption_file.as_ref(), String::new());
         Ok(())
     }
 }
 
+/// Streams frames from the tap to the STT connection. While the connection is slow to take them,
+/// the tap falls behind and skips ahead, instead of audio piling up in between.
+async fn send_frames(
+    mut tap: hypr_audio::AudioTap<AudioFrame>,
+    tx: tokio::sync::mpsc::Sender<MixedMessage<(Bytes, Bytes), ControlMessage>>,
+) {
+    while let Some(frame) = tap.next().await {
+        let mic = hypr_audio_utils::f32_to_i16_bytes(frame.mic.iter().copied());
+        let spk = hypr_audio_utils::f32_to_i16_bytes(frame.spk.iter().copied());
+
+        if tx
+            .send(MixedMessage::Audio((mic.into(), spk.into())))
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+    if tap.skipped() > 0 {
+        tracing::warn!("listen_fell_behind: {} frames skipped", tap.skipped());
+    }
+}
+
+// Mic and speaker words interleaved as they were spoken.
+fn in_spoken_order(words_by_channel: &HashMap<usize, Vec<Word2>>) -> Vec<Word2> {
+    let mut words: Vec<Word2> = words_by_channel.values().flatten().cloned().collect();
+    words.sort_by_key(|w| w.start_ms.unwrap_or(0));
+    words
+}
+
+fn publish_captions(app: &tauri::AppHandle, file: Option<&CaptionFile>, text: String) {
+    if !app.state::<LiveCaptions>().set(text.clone()) {
+        return;
+    }
+    if let Some(file) = file {
+        file.write(&text);
+    }
+}
+
 async fn update_session<R: tauri::Runtime>(
     app: &tauri::AppHandle<R>,
     session_id: impl Into<String>,
@@ -1,15 +1,24 @@
+mod identify;
 mod listen;
 mod processor;
 mod recorder;
 mod session;
 mod source;
 
+pub use identify::*;
 pub use listen::*;
 pub use processor::*;
 pub use recorder::*;
 pub use session::*;
 pub use source::*;
 
+/// One block of processed audio: the mic after echo cancellation, and the speaker beside it.
+#[derive(Clone)]
+pub struct AudioFrame {
+    pub mic: std::sync::Arc<[f32]>,
+    pub spk: std::sync::Arc<[f32]>,
+}
+
 #[derive(Clone)]
 pub struct AudioChunk {
     data: Vec<f32>,
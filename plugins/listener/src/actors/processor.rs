@@ -1,14 +1,18 @@
 use std::{
     collections::VecDeque,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
     time::{Duration, Instant},
 };
 
-use ractor::{Actor, ActorProcessingErr, ActorRef};
+use futures_util::StreamExt;
+use ractor::{Actor, ActorProcessingErr, ActorRef, RpcReplyPort};
 use tauri_specta::Event;
 
 use crate::{
-    actors::{AudioChunk, ListenMsg, RecMsg},
+    actors::{AudioChunk, AudioFrame},
     SessionEvent,
 };
 
@@ -17,29 +21,24 @@ const AUDIO_AMPLITUDE_THROTTLE: Duration = Duration::from_millis(100);
 pub enum ProcMsg {
     Mic(AudioChunk),
     Spk(AudioChunk),
-    AttachListen(ActorRef<ListenMsg>),
-    AttachRecorder(ActorRef<RecMsg>),
-    AttachMicRecorder(ActorRef<RecMsg>),
-    AttachSpeakerRecorder(ActorRef<RecMsg>),
+    SetPaused(bool),
+    GetSilenceRatio(RpcReplyPort<Option<f64>>),
 }
 
 pub struct ProcArgs {
     pub app: tauri::AppHandle,
+    pub frames: hypr_audio::AudioBroadcast<AudioFrame>,
 }
 
 pub struct ProcState {
-    app: tauri::AppHandle,
     aec: hypr_aec::AEC,
     agc_m: hypr_agc::Agc,
     agc_s: hypr_agc::Agc,
     joiner: Joiner,
-    last_mic: Option<Arc<[f32]>>,
-    last_spk: Option<Arc<[f32]>>,
-    last_amp: Instant,
-    listen: Option<ActorRef<ListenMsg>>,
-    recorder: Option<ActorRef<RecMsg>>,
-    mic_recorder: Option<ActorRef<RecMsg>>,
-    speaker_recorder: Option<ActorRef<RecMsg>>,
+    frames: hypr_audio::AudioBroadcast<AudioFrame>,
+    speech: Arc<Mutex<Option<hypr_vad::SpeechMeter>>>,
+    paused: Arc<AtomicBool>,
+    meter_tasks: [tokio::task::JoinHandle<()>; 2],
 }
 
 pub struct AudioProcessor {}
@@ -53,19 +52,31 @@ impl Actor for AudioProcessor {
         _myself: ActorRef<Self::Msg>,
         args: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
+        let speech = Arc::new(Mutex::new(
+            hypr_vad::SpeechMeter::new(16000)
+                .inspect_err(|e| tracing::error!("speech_meter_unavailable: {:?}", e))
+                .ok(),
+        ));
+        let paused = Arc::new(AtomicBool::new(false));
+
+        let meter_tasks = [
+            tokio::spawn(meter_levels(args.app, args.frames.tap())),
+            tokio::spawn(meter_speech(
+                speech.clone(),
+                paused.clone(),
+                args.frames.tap(),
+            )),
+        ];
+
         Ok(ProcState {
-            app: args.app.clone(),
             joiner: Joiner::new(),
             aec: hypr_aec::AEC::new().unwrap(),
             agc_m: hypr_agc::Agc::default(),
             agc_s: hypr_agc::Agc::default(),
-            last_mic: None,
-            last_spk: None,
-            last_amp: Instant::now(),
-            listen: None,
-            recorder: None,
-            mic_recorder: None,
-            speaker_recorder: None,
+            frames: args.frames,
+            speech,
+            paused,
+            meter_tasks,
         })
     }
 
@@ -76,71 +87,110 @@ impl Actor for AudioProcessor {
         st: &mut Self::State,
     ) -> Result<(), ActorProcessingErr> {
         match msg {
-            ProcMsg::AttachListen(actor) => st.listen = Some(actor),
-            ProcMsg::AttachRecorder(actor) => st.recorder = Some(actor),
-            ProcMsg::AttachMicRecorder(actor) => st.mic_recorder = Some(actor),
-            ProcMsg::AttachSpeakerRecorder(actor) => st.speaker_recorder = Some(actor),
+            ProcMsg::SetPaused(paused) => st.paused.store(paused, Ordering::Relaxed),
+            ProcMsg::GetSilenceRatio(reply) => {
+                let speech = st.speech.lock().unwrap();
+                let ratio = speech.as_ref().and_then(|meter| {
+                    let total_ms = meter.total_ms();
+                    (total_ms > 0).then(|| 1.0 - meter.speech_ms() as f64 / total_ms as f64)
+                });
+
+                if !reply.is_closed() {
+                    let _ = reply.send(ratio);
+                }
+            }
             ProcMsg::Mic(mut c) => {
                 st.agc_m.process(&mut c.data);
-                let arc = Arc::<[f32]>::from(c.data);
-                st.last_mic = Some(arc.clone());
-                st.joiner.push_mic(arc);
-                process_ready(st).await;
+                st.joiner.push_mic(Arc::from(c.data));
+                process_ready(st);
             }
             ProcMsg::Spk(mut c) => {
                 st.agc_s.process(&mut c.data);
-                let arc = Arc::<[f32]>::from(c.data);
-                st.last_spk = Some(arc.clone());
-                st.joiner.push_spk(arc);
-                process_ready(st).await;
+                st.joiner.push_spk(Arc::from(c.data));
+                process_ready(st);
             }
         }
         Ok(())
     }
+
+    async fn post_stop(
+        &self,
+        _myself: ActorRef<Self::Msg>,
+        st: &mut Self::State,
+    ) -> Result<(), ActorProcessingErr> {
+        for task in &st.meter_tasks {
+            task.abort();
+        }
+        Ok(())
+    }
 }
 
-async fn process_ready(st: &mut ProcState) {
+/// Pushes each echo-cancelled pair to the ring. Everything downstream, from the recorders and
+/// the STT stream to the meters, reads it through its own tap.
+fn process_ready(st: &mut ProcState) {
     while let Some((mic, spk)) = st.joiner.pop_pair() {
         let mic = st
             .aec
             .process_streaming(&mic, &spk)
             .unwrap_or_else(|_| mic.to_vec());
 
-        {
-            if let Some(mic_rec) = &st.mic_recorder {
-                mic_rec.cast(RecMsg::Audio(mic.clone())).ok();
-            }
-            if let Some(spk_rec) = &st.speaker_recorder {
-                spk_rec.cast(RecMsg::Audio(spk.to_vec())).ok();
+        // While paused, everything downstream only gets silence, which keeps the STT connection
+        // open and both the recording and the transcript aligned to the wall clock.
+        let frame = if st.paused.load(Ordering::Relaxed) {
+            let silence = Arc::<[f32]>::from(vec![0.0; mic.len()]);
+            AudioFrame {
+                mic: silence.clone(),
+                spk: silence,
             }
-
-            if let Some(rec) = &st.recorder {
-                let mixed: Vec<f32> = mic
-                    .iter()
-                    .zip(spk.iter())
-                    .map(|(m, s)| (m + s).clamp(-1.0, 1.0))
-                    .collect();
-                rec.cast(RecMsg::Audio(mixed)).ok();
+        } else {
+            AudioFrame {
+                mic: Arc::from(mic),
+                spk,
             }
-        }
+        };
+        st.frames.push(frame);
+    }
+}
 
-        if let Some(actor) = &st.listen {
-            let mic_bytes = hypr_audio_utils::f32_to_i16_bytes(mic.into_iter());
-            let spk_bytes = hypr_audio_utils::f32_to_i16_bytes(spk.iter().copied());
+async fn meter_levels(app: tauri::AppHandle, mut tap: hypr_audio::AudioTap<AudioFrame>) {
+    let mut last_amp = Instant::now();
 
-            actor
-                .cast(ListenMsg::Audio(mic_bytes.into(), spk_bytes.into()))
-                .ok();
+    while let Some(frame) = tap.next().await {
+        if last_amp.elapsed() < AUDIO_AMPLITUDE_THROTTLE {
+            continue;
         }
+        if let Err(e) = SessionEvent::from((frame.mic.as_ref(), frame.spk.as_ref())).emit(&app) {
+            tracing::error!("{:?}", e);
+        }
+        last_amp = Instant::now();
     }
+}
 
-    if st.last_amp.elapsed() >= AUDIO_AMPLITUDE_THROTTLE {
-        if let (Some(mic_data), Some(spk_data)) = (&st.last_mic, &st.last_spk) {
-            if let Err(e) = SessionEvent::from((mic_data.as_ref(), spk_data.as_ref())).emit(&st.app)
-            {
-                tracing::error!("{:?}", e);
-            }
-            st.last_amp = Instant::now();
+/// Paused stretches are silence in the ring, and are left out of the ratio.
+async fn meter_speech(
+    speech: Arc<Mutex<Option<hypr_vad::SpeechMeter>>>,
+    paused: Arc<AtomicBool>,
+    mut tap: hypr_audio::AudioTap<AudioFrame>,
+) {
+    while let Some(frame) = tap.next().await {
+        if paused.load(Ordering::Relaxed) {
+            continue;
+        }
+
+        let mut speech = speech.lock().unwrap();
+        let Some(meter) = speech.as_mut() else {
+            return;
+        };
+
+        let mixed: Vec<f32> = frame
+            .mic
+            .iter()
+            .zip(frame.spk.iter())
+            .map(|(m, s)| m + s)
+            .collect();
+        if let Err(e) = meter.push(&mixed) {
+            tracing::error!("speech_meter_failed: {:?}", e);
+            *speech = None;
         }
     }
 }
@@ -1,19 +1,62 @@
 use std::path::PathBuf;
 
+use futures_util::StreamExt;
 use ractor::{Actor, ActorProcessingErr, ActorRef};
 
-pub enum RecMsg {
-    Audio(Vec<f32>),
+use crate::actors::AudioFrame;
+use crate::clock::{RecordingClock, Stretch};
+
+type WavWriter = hound::WavWriter<std::io::BufWriter<std::fs::File>>;
+
+/// Recorders take no messages: they write whatever comes through their tap until stopped.
+pub enum RecMsg {}
+
+/// Which side of the processed audio a recorder writes.
+#[derive(Clone, Copy)]
+pub enum RecChannel {
+    Mixed,
+    Mic,
+    Speaker,
+}
+
+impl RecChannel {
+    /// Writes this side of `frame`, mixing in place rather than through a buffer.
+    fn write(self, frame: &AudioFrame, writer: &mut WavWriter) -> Result<(), hound::Error> {
+        match self {
+            RecChannel::Mixed => {
+                let len = frame.mic.len().max(frame.spk.len());
+                for i in 0..len {
+                    let mic = frame.mic.get(i).copied().unwrap_or(0.0);
+                    let spk = frame.spk.get(i).copied().unwrap_or(0.0);
+                    writer.write_sample((mic + spk).clamp(-1.0, 1.0))?;
+                }
+            }
+            RecChannel::Mic => {
+                for &s in frame.mic.iter() {
+                    writer.write_sample(s)?;
+                }
+            }
+            RecChannel::Speaker => {
+                for &s in frame.spk.iter() {
+                    writer.write_sample(s)?;
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 pub struct RecArgs {
     pub app_dir: PathBuf,
     pub session_id: String,
     pub file_suffix: Option<String>,
+    pub tap: hypr_audio::AudioTap<AudioFrame>,
+    pub channel: RecChannel,
 }
 
 pub struct RecState {
-    writer: Option<hound::WavWriter<std::io::BufWriter<std::fs::File>>>,
+    stop: Option<tokio::sync::oneshot::Sender<()>>,
+    writer_task: Option<tokio::task::JoinHandle<()>>,
 }
 
 pub struct Recorder;
@@ -44,12 +87,25 @@ impl Actor for Recorder {
             sample_format: hound::SampleFormat::Float,
         };
         let writer = if path.exists() {
-            hound::WavWriter::append(path)?
+            hound::WavWriter::append(&path)?
         } else {
-            hound::WavWriter::create(path, spec)?
+            hound::WavWriter::create(&path, spec)?
+        };
+
+        let stretch = Stretch {
+            started_at_ms: chrono::Utc::now().timestamp_millis() as u64,
+            sample_offset: writer.len() as u64,
         };
+        if let Err(e) = RecordingClock::append(&path, stretch) {
+            tracing::warn!("failed to save recording stretch for {:?}: {}", path, e);
+        }
+
+        let (stop, stopped) = tokio::sync::oneshot::channel();
+        let writer_task = tokio::spawn(write_frames(path, writer, args.tap, args.channel, stopped));
+
         Ok(RecState {
-            writer: Some(writer),
+            stop: Some(stop),
+            writer_task: Some(writer_task),
         })
     }
 
@@ -57,19 +113,9 @@ impl Actor for Recorder {
         &self,
         _myself: ActorRef<Self::Msg>,
         msg: Self::Msg,
-        st: &mut Self::State,
+        _st: &mut Self::State,
     ) -> Result<(), ActorProcessingErr> {
-        match msg {
-            RecMsg::Audio(v) => {
-                if let Some(ref mut writer) = st.writer {
-                    for s in v {
-                        writer.write_sample(s)?;
-                    }
-                }
-            }
-        }
-
-        Ok(())
+        match msg {}
     }
 
     async fn post_stop(
@@ -77,10 +123,61 @@ impl Actor for Recorder {
         _myself: ActorRef<Self::Msg>,
         st: &mut Self::State,
     ) -> Result<(), ActorProcessingErr> {
-        if let Some(writer) = st.writer.take() {
-            writer.finalize()?;
+        if let Some(stop) = st.stop.take() {
+            let _ = stop.send(());
+        }
+        // The file is finalized by the time the recorder is gone.
+        if let Some(writer_task) = st.writer_task.take() {
+            writer_task.await?;
         }
 
         Ok(())
     }
 }
+
+/// Writes frames straight from the tap as they come, and what is left in it once stopped.
+async fn write_frames(
+    path: PathBuf,
+    mut writer: WavWriter,
+    mut tap: hypr_audio::AudioTap<AudioFrame>,
+    channel: RecChannel,
+    mut stopped: tokio::sync::oneshot::Receiver<()>,
+) {
+    let mut result = Ok(());
+
+    loop {
+        tokio::select! {
+            _ = &mut stopped => {
+                while let Some(frame) = tap.try_next() {
+                    result = channel.write(&frame, &mut writer);
+                    if result.is_err() {
+                        break;
+                    }
+                }
+                break;
+            }
+            frame = tap.next() => {
+                let Some(frame) = frame else { break };
+                result = channel.write(&frame, &mut writer);
+                if result.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+
+    if tap.skipped() > 0 {
+        tracing::warn!("recorder_fell_behind: {} frames skipped", tap.skipped());
+    }
+    if let Err(e) = result.and_then(|_| writer.finalize()) {
+        tracing::error!("failed to write recording {:?}: {}", path, e);
+        return;
+    }
+
+    // Done off the runtime, so stopping doesn't wait on a read of the whole file.
+    tokio::task::spawn_blocking(move || {
+        if let Err(e) = hypr_audio_utils::load_or_compute_peaks(&path) {
+            tracing::warn!("failed to compute peaks for {:?}: {}", path, e);
+        }
+    });
+}
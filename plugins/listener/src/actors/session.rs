@@ -1,3 +1,4 @@
+use hypr_db_user::{TimelineEvent, TimelineEventKind};
 use ractor::{
     call_t, Actor, ActorCell, ActorProcessingErr, ActorRef, RpcReplyPort, SupervisionEvent,
 };
@@ -7,24 +8,39 @@ use tokio_util::sync::CancellationToken;
 
 use crate::{
     actors::{
-        AudioProcessor, ListenArgs, ListenBridge, ListenMsg, ProcArgs, ProcMsg, RecArgs, RecMsg,
-        Recorder, SourceActor, SrcArgs, SrcCtrl, SrcWhich,
+        AudioProcessor, IdentifyArgs, IdentifyMsg, ListenArgs, ListenBridge, ListenMsg, ProcArgs,
+        ProcMsg, RecArgs, RecChannel, RecMsg, Recorder, SourceActor, SpeakerIdentifier, SrcArgs,
+        SrcCtrl, SrcWhich,
     },
+    alert::{self, LengthAlert},
+    captions::{CaptionFile, DEFAULT_CAPTION_LINES},
+    dictionary::Dictionary,
+    filter::WordFilters,
     fsm::State,
+    i18n::t,
+    recovery,
+    timeline::Timeline,
     SessionEvent,
 };
 
+// Processed frames kept for consumers that fall behind, about 16 seconds' worth.
+const AUDIO_FRAME_CAPACITY: usize = 512;
+
 #[derive(Debug)]
 pub enum SessionMsg {
     Start { session_id: String },
     Stop,
+    Pause,
+    Resume,
     SetMicMute(bool),
     SetSpeakerMute(bool),
+    MarkHighlight(Option<String>, RpcReplyPort<Option<TimelineEvent>>),
     GetMicMute(RpcReplyPort<bool>),
     GetSpeakerMute(RpcReplyPort<bool>),
     GetMicDeviceName(RpcReplyPort<Option<String>>),
     ChangeMicDevice(Option<String>),
     GetState(RpcReplyPort<State>),
+    GetSessionId(RpcReplyPort<Option<String>>),
 }
 
 pub struct SessionArgs {
@@ -42,15 +58,25 @@ pub struct SessionState {
     processor: Option<ActorRef<ProcMsg>>,
     recorder: Option<ActorRef<RecMsg>>,
     listen: Option<ActorRef<ListenMsg>>,
+    identifier: Option<ActorRef<IdentifyMsg>>,
 
     #[cfg(debug_assertions)]
     mic_recorder: Option<ActorRef<RecMsg>>,
     #[cfg(debug_assertions)]
     speaker_recorder: Option<ActorRef<RecMsg>>,
 
+    mic_muted: bool,
+
     record_enabled: bool,
     languages: Vec<hypr_language::Language>,
+    keyterms: Vec<String>,
+    dictionary: Dictionary,
+    filters: WordFilters,
+    translation_language: Option<hypr_language::Language>,
+    caption_file: Option<CaptionFile>,
+    caption_lines: usize,
     onboarding: bool,
+    timeline: Option<Timeline>,
 
     token: CancellationToken,
 }
@@ -77,13 +103,22 @@ impl Actor for SessionSupervisor {
             processor: None,
             recorder: None,
             listen: None,
+            identifier: None,
             #[cfg(debug_assertions)]
             mic_recorder: None,
             #[cfg(debug_assertions)]
             speaker_recorder: None,
+            mic_muted: false,
             record_enabled: true,
             languages: vec![],
+            keyterms: vec![],
+            dictionary: Dictionary::default(),
+            filters: WordFilters::default(),
+            translation_language: None,
+            caption_file: None,
+            caption_lines: DEFAULT_CAPTION_LINES,
             onboarding: false,
+            timeline: None,
             token: CancellationToken::new(),
         })
     }
@@ -96,12 +131,12 @@ impl Actor for SessionSupervisor {
     ) -> Result<(), ActorProcessingErr> {
         match message {
             SessionMsg::Start { session_id } => {
-                if let State::RunningActive = state.state {
+                if matches!(state.state, State::RunningActive | State::RunningPaused) {
                     if let Some(current_id) = &state.session_id {
                         if current_id != &session_id {
                             self.stop_session(state).await?;
                         } else {
-                            return Ok(());
+                            return self.set_paused(state, false);
                         }
                     }
                 }
@@ -114,20 +149,55 @@ impl Actor for SessionSupervisor {
                 self.stop_session(state).await?;
             }
 
+            SessionMsg::Pause => {
+                self.set_paused(state, true)?;
+            }
+
+            SessionMsg::Resume => {
+                self.set_paused(state, false)?;
+            }
+
             SessionMsg::SetMicMute(muted) => {
                 if let Some(mic) = &state.mic_source {
                     mic.cast(SrcCtrl::SetMute(muted))?;
                 }
+                state.mic_muted = muted;
+                if let Some(timeline) = &mut state.timeline {
+                    timeline
+                        .set_active(&state.app, TimelineEventKind::MicMuted, muted)
+                        .await;
+                }
                 SessionEvent::MicMuted { value: muted }.emit(&state.app)?;
+                sync_tray(state);
             }
 
             SessionMsg::SetSpeakerMute(muted) => {
                 if let Some(spk) = &state.speaker_source {
                     spk.cast(SrcCtrl::SetMute(muted))?;
                 }
+                if let Some(timeline) = &mut state.timeline {
+                    timeline
+                        .set_active(&state.app, TimelineEventKind::SpeakerMuted, muted)
+                        .await;
+                }
                 SessionEvent::SpeakerMuted { value: muted }.emit(&state.app)?;
             }
 
+            SessionMsg::MarkHighlight(note, reply) => {
+                let event = match &state.timeline {
+                    Some(timeline) => {
+                        timeline
+                            .mark(&state.app, TimelineEventKind::Highlight, note)
+                            .await
+                    }
+                    None => None,
+                };
+
+                if !reply.is_closed() {
+                    let _ = reply.send(event);
+                }
+            }
+
             SessionMsg::GetMicDeviceName(reply) => {
                 if !reply.is_closed() {
                     let device_name = if let Some(mic) = &state.mic_source {
@@ -175,6 +245,12 @@ impl Actor for SessionSupervisor {
                     let _ = reply.send(state.state.clone());
                 }
             }
+
+            SessionMsg::GetSessionId(reply) => {
+                if !reply.is_closed() {
+                    let _ = reply.send(state.session_id.clone());
+                }
+            }
         }
 
         Ok(())
@@ -194,12 +270,15 @@ impl Actor for SessionSupervisor {
             SupervisionEvent::ActorFailed(actor, _) => {
                 tracing::error!("{:?}_actor_failed", actor.get_name());
                 self.stop_session(state).await?;
+
+                use tauri_plugin_tray::TrayPluginExt;
+                state.app.flash_error(t("error-recording-stopped"));
             }
 
             SupervisionEvent::ActorTerminated(actor, _, exit_reason) => {
                 tracing::info!("{:?}_actor_terminated: {:?}", actor.get_name(), exit_reason);
 
-                if matches!(state.state, State::RunningActive) {
+                if matches!(state.state, State::RunningActive | State::RunningPaused) {
                     self.stop_session(state).await?;
                 }
             }
@@ -233,6 +312,9 @@ impl SessionSupervisor {
         let onboarding_session_id = UserDatabase::onboarding_session_id();
         state.onboarding = session_id == onboarding_session_id;
 
+        // The session needs the mic to itself.
+        crate::wake::stop(&state.app);
+
         let config = state.app.db_get_config(&user_id).await?;
         state.record_enabled = config
             .as_ref()
@@ -241,6 +323,30 @@ impl SessionSupervisor {
             || vec![hypr_language::ISO639::En.into()],
             |c| c.general.spoken_languages.clone(),
         );
+        state.keyterms = config
+            .as_ref()
+            .map(|c| crate::dictionary::keyterms(&c.general))
+            .unwrap_or_default();
+        state.dictionary = config
+            .as_ref()
+            .map(|c| Dictionary::new(&c.general.dictionary))
+            .unwrap_or_default();
+        state.filters = config
+            .as_ref()
+            .map(|c| WordFilters::new(&c.general))
+            .unwrap_or_default();
+        state.translation_language = config
+            .as_ref()
+            .and_then(|c| c.general.translation_language.clone());
+        state.caption_file = config
+            .as_ref()
+            .and_then(|c| c.general.caption_file.as_deref())
+            .filter(|path| !path.trim().is_empty())
+            .map(CaptionFile::new);
+        state.caption_lines = config
+            .as_ref()
+            .and_then(|c| c.general.caption_lines)
+            .map_or(DEFAULT_CAPTION_LINES, |n| n as usize);
 
         state.session_id = Some(session_id.clone());
         state.session_start_ts_ms = Some(
@@ -255,13 +361,71 @@ impl SessionSupervisor {
             let _ = state.app.db_upsert_session(session).await;
         }
 
+        let participants: Vec<_> = state
+            .app
+            .db_list_session_participants(&session_id)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|human| !human.is_user)
+            .collect();
+
+        // Attendee names are the words a generic model is most likely to misspell.
+        for name in participants
+            .iter()
+            .filter_map(|human| human.full_name.as_ref())
+        {
+            let name = name.trim();
+            if !name.is_empty() && !state.keyterms.iter().any(|term| term == name) {
+                state.keyterms.push(name.to_string());
+            }
+        }
+
+        state.timeline = Some(Timeline::new(&session_id));
+
         state.token = CancellationToken::new();
+        state.mic_muted = false;
+
+        spawn_checkpoint(
+            state.app.clone(),
+            state.token.clone(),
+            recovery::InterruptedSession::new(
+                session_id.clone(),
+                state.session_start_ts_ms.unwrap_or(0),
+            ),
+        );
+
+        if !state.onboarding {
+            let notification = config.as_ref().map(|c| &c.notification);
+            let event = if notification.is_some_and(|n| n.recording_overrun_alert) {
+                state
+                    .app
+                    .db_session_get_event(&session_id)
+                    .await
+                    .unwrap_or_default()
+            } else {
+                None
+            };
+            spawn_length_alerts(
+                state.app.clone(),
+                state.token.clone(),
+                session_id.clone(),
+                alert::schedule(
+                    chrono::Utc::now(),
+                    notification.and_then(|n| n.recording_length_alert_minutes),
+                    event.as_ref(),
+                ),
+            );
+        }
+
+        let frames = hypr_audio::AudioBroadcast::new(AUDIO_FRAME_CAPACITY);
 
         let (processor_ref, _) = Actor::spawn_linked(
             Some("audio_processor".to_string()),
             AudioProcessor {},
             ProcArgs {
                 app: state.app.clone(),
+                frames: frames.clone(),
             },
             supervisor.clone(),
         )
@@ -295,7 +459,7 @@ impl SessionSupervisor {
         state.speaker_source = Some(spk_ref);
 
         if state.record_enabled {
-            let app_dir = state.app.path().app_data_dir().unwrap();
+            let app_dir = hypr_data_dir::resolve(|| state.app.path().app_data_dir()).unwrap();
             let (rec_ref, _) = Actor::spawn_linked(
                 Some("recorder".to_string()),
                 Recorder,
@@ -303,12 +467,13 @@ impl SessionSupervisor {
                     app_dir: app_dir.clone(),
                     session_id: session_id.clone(),
                     file_suffix: None,
+                    tap: frames.tap(),
+                    channel: RecChannel::Mixed,
                 },
                 supervisor.clone(),
             )
             .await?;
-            state.recorder = Some(rec_ref.clone());
-            processor_ref.cast(ProcMsg::AttachRecorder(rec_ref))?;
+            state.recorder = Some(rec_ref);
 
             #[cfg(debug_assertions)]
             {
@@ -319,12 +484,13 @@ impl SessionSupervisor {
                         app_dir: app_dir.clone(),
                         session_id: session_id.clone(),
                         file_suffix: Some("_mic".to_string()),
+                        tap: frames.tap(),
+                        channel: RecChannel::Mic,
                     },
                     supervisor.clone(),
                 )
                 .await?;
-                state.mic_recorder = Some(mic_rec_ref.clone());
-                processor_ref.cast(ProcMsg::AttachMicRecorder(mic_rec_ref))?;
+                state.mic_recorder = Some(mic_rec_ref);
 
                 let (spk_rec_ref, _) = Actor::spawn_linked(
                     Some("speaker_recorder".to_string()),
@@ -333,15 +499,41 @@ impl SessionSupervisor {
                         app_dir,
                         session_id: session_id.clone(),
                         file_suffix: Some("_speaker".to_string()),
+                        tap: frames.tap(),
+                        channel: RecChannel::Speaker,
                     },
                     supervisor.clone(),
                 )
                 .await?;
-                state.speaker_recorder = Some(spk_rec_ref.clone());
-                processor_ref.cast(ProcMsg::AttachSpeakerRecorder(spk_rec_ref))?;
+                state.speaker_recorder = Some(spk_rec_ref);
             }
         }
 
+        // Only worth the embedding model once someone has been named.
+        let profiles: Vec<_> = state
+            .app
+            .db_list_speaker_profiles(&user_id)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|profile| !profile.embedding.is_empty())
+            .collect();
+        if !state.onboarding && !profiles.is_empty() {
+            let (identifier_ref, _) = Actor::spawn_linked(
+                Some("speaker_identifier".to_string()),
+                SpeakerIdentifier,
+                IdentifyArgs {
+                    app: state.app.clone(),
+                    profiles,
+                    session_start_ts_ms: state.session_start_ts_ms.unwrap_or(0),
+                    tap: frames.tap(),
+                },
+                supervisor.clone(),
+            )
+            .await?;
+            state.identifier = Some(identifier_ref);
+        }
+
         let (listen_ref, _) = Actor::spawn_linked(
             Some("listen_bridge".to_string()),
             ListenBridge,
@@ -349,22 +541,38 @@ impl SessionSupervisor {
                 app: state.app.clone(),
                 session_id: session_id.clone(),
                 languages: state.languages.clone(),
+                keyterms: state.keyterms.clone(),
+                dictionary: state.dictionary.clone(),
+                filters: state.filters.clone(),
+                translation_language: state
+                    .translation_language
+                    .clone()
+                    .filter(|_| !state.onboarding),
+                caption_file: state.caption_file.clone(),
+                caption_lines: state.caption_lines,
                 onboarding: state.onboarding,
                 session_start_ts_ms: state.session_start_ts_ms.unwrap_or(0),
+                identifier: state.identifier.clone(),
+                tap: frames.tap(),
             },
             supervisor,
         )
         .await?;
-        state.listen = Some(listen_ref.clone());
-        processor_ref.cast(ProcMsg::AttachListen(listen_ref))?;
+        state.listen = Some(listen_ref);
 
         {
             use tauri_plugin_tray::TrayPluginExt;
-            let _ = state.app.set_start_disabled(true);
+            let _ = state.app.set_recording(true);
+        }
+
+        {
+            use tauri_plugin_windows::{HyprWindow, WindowsPluginExt};
+            let _ = state.app.window_show(HyprWindow::Overlay);
         }
 
         state.state = State::RunningActive;
         SessionEvent::RunningActive {}.emit(&state.app)?;
+        sync_tray(state);
 
         Ok(())
     }
@@ -374,6 +582,12 @@ impl SessionSupervisor {
             return Ok(());
         }
 
+        // Ask before the processor goes away along with its VAD state.
+        let silence_ratio = match &state.processor {
+            Some(proc) => call_t!(proc, ProcMsg::GetSilenceRatio, 100).ok().flatten(),
+            None => None,
+        };
+
         state.token.cancel();
 
         if let Some(mic) = state.mic_source.take() {
@@ -402,24 +616,59 @@ impl SessionSupervisor {
         if let Some(listen) = state.listen.take() {
             listen.stop(None);
         }
+        if let Some(identifier) = state.identifier.take() {
+            identifier.stop(None);
+        }
 
         if let Some(session_id) = &state.session_id {
             use tauri_plugin_db::DatabasePluginExt;
 
             if let Ok(Some(mut session)) = state.app.db_get_session(session_id).await {
                 session.record_end = Some(chrono::Utc::now());
+
+                let duration_ms = state
+                    .session_start_ts_ms
+                    .map_or(0, |start| recovery::now_ms().saturating_sub(start));
+                let stats = crate::stats::compute(&session.words, duration_ms, silence_ratio);
+
                 let _ = state.app.db_upsert_session(session).await;
+
+                match state
+                    .app
+                    .db_upsert_session_stats(session_id, stats.clone())
+                    .await
+                {
+                    Ok(_) => {
+                        let _ = SessionEvent::Stats {
+                            session_id: session_id.clone(),
+                            stats,
+                        }
+                        .emit(&state.app);
+                    }
+                    Err(e) => tracing::error!("failed_to_save_session_stats: {}", e),
+                }
             }
         }
 
+        if let Some(mut timeline) = state.timeline.take() {
+            timeline.close(&state.app).await;
+        }
+
+        if let Err(e) = recovery::clear(&state.app) {
+            tracing::error!("failed_to_clear_checkpoint: {}", e);
+        }
+
         {
             use tauri_plugin_tray::TrayPluginExt;
-            let _ = state.app.set_start_disabled(false);
+            let _ = state.app.set_session(None);
+            let _ = state.app.set_recording(false);
         }
 
         {
             use tauri_plugin_windows::{HyprWindow, WindowsPluginExt};
             let _ = state.app.window_hide(HyprWindow::Control);
+            let _ = state.app.window_hide(HyprWindow::Overlay);
+            let _ = state.app.window_hide(HyprWindow::Captions);
         }
 
         state.session_id = None;
@@ -428,6 +677,98 @@ impl SessionSupervisor {
 
         SessionEvent::Inactive {}.emit(&state.app)?;
 
+        let app = state.app.clone();
+        tokio::spawn(async move {
+            use crate::ListenerPluginExt;
+
+            if let Err(e) = app.refresh_wake_listener().await {
+                tracing::warn!("wake_listener_not_resumed: {}", e);
+            }
+        });
+
+        Ok(())
+    }
+
+    fn set_paused(&self, state: &mut SessionState, paused: bool) -> Result<(), ActorProcessingErr> {
+        let next = match (&state.state, paused) {
+            (State::RunningActive, true) => State::RunningPaused,
+            (State::RunningPaused, false) => State::RunningActive,
+            _ => return Ok(()),
+        };
+
+        if let Some(processor) = &state.processor {
+            processor.cast(ProcMsg::SetPaused(paused))?;
+        }
+
+        state.state = next;
+        if paused {
+            SessionEvent::RunningPaused {}.emit(&state.app)?;
+        } else {
+            SessionEvent::RunningActive {}.emit(&state.app)?;
+        }
+        sync_tray(state);
+
         Ok(())
     }
 }
+
+fn sync_tray(state: &SessionState) {
+    use tauri_plugin_tray::{TrayPluginExt, TraySession};
+
+    let session = state.session_id.clone().map(|session_id| TraySession {
+        session_id,
+        paused: matches!(state.state, State::RunningPaused),
+        mic_muted: state.mic_muted,
+    });
+    let _ = state.app.set_session(session);
+}
+
+/// Keeps the on-disk checkpoint fresh until the session's token is cancelled, so a crash
+/// leaves behind both the session id and roughly when recording stopped.
+fn spawn_checkpoint(
+    app: tauri::AppHandle,
+    token: CancellationToken,
+    mut checkpoint: recovery::InterruptedSession,
+) {
+    tokio::spawn(async move {
+        while !token.is_cancelled() {
+            checkpoint.checkpoint_at_ms = recovery::now_ms();
+            if let Err(e) = recovery::save(&app, &checkpoint) {
+                tracing::error!("failed_to_save_checkpoint: {}", e);
+            }
+
+            tokio::select! {
+                _ = token.cancelled() => break,
+                _ = tokio::time::sleep(recovery::CHECKPOINT_INTERVAL) => {}
+            }
+        }
+    });
+}
+
+/// Emits each alert when it comes due, unless the session's token is cancelled first.
+fn spawn_length_alerts(
+    app: tauri::AppHandle,
+    token: CancellationToken,
+    session_id: String,
+    alerts: Vec<(chrono::DateTime<chrono::Utc>, LengthAlert)>,
+) {
+    if alerts.is_empty() {
+        return;
+    }
+
+    tokio::spawn(async move {
+        for (due, alert) in alerts {
+            let wait = (due - chrono::Utc::now()).to_std().unwrap_or_default();
+            tokio::select! {
+                _ = token.cancelled() => return,
+                _ = tokio::time::sleep(wait) => {}
+            }
+
+            let _ = SessionEvent::LengthAlert {
+                session_id: session_id.clone(),
+                alert,
+            }
+            .emit(&app);
+        }
+    });
+}
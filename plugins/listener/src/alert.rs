@@ -0,0 +1,84 @@
+use chrono::{DateTime, Utc};
+
+/// Why a recording is said to be running long.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(tag = "type")]
+pub enum LengthAlert {
+    /// Recording for the configured number of minutes.
+    #[serde(rename = "duration")]
+    Duration { minutes: u32 },
+    /// Past the scheduled end of the note's calendar event.
+    #[serde(rename = "eventEnd")]
+    EventEnd {
+        event_name: String,
+        end_date: DateTime<Utc>,
+    },
+}
+
+/// The alerts a recording started at `started_at` is due, soonest first. An event that was
+/// already over when recording started has nothing left to warn about.
+pub fn schedule(
+    started_at: DateTime<Utc>,
+    minutes: Option<u32>,
+    event: Option<&hypr_db_user::Event>,
+) -> Vec<(DateTime<Utc>, LengthAlert)> {
+    let mut alerts = Vec::new();
+
+    if let Some(minutes) = minutes.filter(|minutes| *minutes > 0) {
+        alerts.push((
+            started_at + chrono::Duration::minutes(minutes.into()),
+            LengthAlert::Duration { minutes },
+        ));
+    }
+
+    if let Some(event) = event.filter(|event| event.end_date > started_at) {
+        alerts.push((
+            event.end_date,
+            LengthAlert::EventEnd {
+                event_name: event.name.clone(),
+                end_date: event.end_date,
+            },
+        ));
+    }
+
+    alerts.sort_by_key(|(due, _)| *due);
+    alerts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(end_date: DateTime<Utc>) -> hypr_db_user::Event {
+        hypr_db_user::Event {
+            name: "Standup".to_string(),
+            start_date: end_date - chrono::Duration::minutes(30),
+            end_date,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_schedule() {
+        let started_at = "2025-06-15T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let at = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+
+        let alerts = schedule(
+            started_at,
+            Some(60),
+            Some(&event(at("2025-06-15T10:30:00Z"))),
+        );
+        assert_eq!(alerts.len(), 2);
+        assert_eq!(alerts[0].0, at("2025-06-15T10:30:00Z"));
+        assert!(matches!(alerts[0].1, LengthAlert::EventEnd { .. }));
+        assert_eq!(alerts[1].0, at("2025-06-15T11:00:00Z"));
+        assert_eq!(alerts[1].1, LengthAlert::Duration { minutes: 60 });
+
+        let alerts = schedule(
+            started_at,
+            Some(0),
+            Some(&event(at("2025-06-15T09:45:00Z"))),
+        );
+        assert!(alerts.is_empty());
+    }
+}
@@ -0,0 +1,172 @@
+use std::{sync::OnceLock, time::Duration};
+
+use hypr_db_user::{QueuedTask, QueuedTaskKind, QueuedTaskStatus, SessionRetranscription};
+use owhisper_interface::Word2;
+use tauri_plugin_local_stt::SupportedSttModel;
+use tauri_plugin_task::{TaskEvent, TaskPluginExt};
+use tauri_specta::Event;
+use tokio::sync::Semaphore;
+
+use crate::SessionEvent;
+
+/// Whisper's window; shorter chunks lose context, longer ones are cut anyway.
+const CHUNK_MS: u64 = 30_000;
+/// Well inside the task queue's lease, so jobs waiting their turn aren't timed out.
+const HEARTBEAT: Duration = Duration::from_secs(60);
+/// A recording that fails twice will most likely fail again.
+pub const MAX_ATTEMPTS: i64 = 2;
+
+/// Models compete for the same CPU or GPU, so only one session is transcribed at a time.
+static RUNNING: OnceLock<Semaphore> = OnceLock::new();
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct RetranscribePayload {
+    pub session_id: String,
+    pub model: SupportedSttModel,
+}
+
+/// Picks up re-transcription jobs as the task queue hands them out.
+pub fn listen<R: tauri::Runtime>(app: &tauri::AppHandle<R>) {
+    let handle = app.clone();
+    TaskEvent::listen_any(app, move |event| {
+        let TaskEvent::Run(task) = event.payload else {
+            return;
+        };
+        if task.kind != QueuedTaskKind::Retranscribe {
+            return;
+        }
+
+        let app = handle.clone();
+        tauri::async_runtime::spawn(async move { run(&app, task).await });
+    });
+}
+
+async fn run<R: tauri::Runtime>(app: &tauri::AppHandle<R>, task: QueuedTask) {
+    let result = match serde_json::from_str::<RetranscribePayload>(&task.payload) {
+        Ok(payload) => retranscribe(app, &task.id, payload).await,
+        Err(e) => Err(crate::Error::InvalidTaskPayload(e.to_string())),
+    };
+
+    let reported = match result {
+        Ok(true) => app.complete_queued_task(task.id).await.map(|_| ()),
+        Ok(false) => Ok(()),
+        Err(e) => {
+            tracing::error!("retranscribe_failed: {}", e);
+            app.fail_queued_task(task.id, e.to_string())
+                .await
+                .map(|_| ())
+        }
+    };
+    if let Err(e) = reported {
+        tracing::error!("retranscribe_report_failed: {}", e);
+    }
+}
+
+/// `false` when the task was paused or cancelled before the transcript was ready.
+async fn retranscribe<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    task_id: &str,
+    payload: RetranscribePayload,
+) -> Result<bool, crate::Error> {
+    use tauri_plugin_db::DatabasePluginExt;
+
+    let session_id = payload.session_id;
+    let Some(words) = transcribe_recording(app, task_id, &session_id, &payload.model).await? else {
+        return Ok(false);
+    };
+
+    app.db_upsert_session_retranscription(SessionRetranscription {
+        session_id: session_id.clone(),
+        model: payload.model.to_string(),
+        words,
+        created_at: chrono::Utc::now(),
+    })
+    .await?;
+    let _ = SessionEvent::RetranscriptionReady { session_id }.emit(app);
+
+    Ok(true)
+}
+
+/// The whole recording of a session transcribed with `model`, carrying over the speakers
+/// named so far. `None` when the task was paused or cancelled first.
+pub async fn transcribe_recording<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    task_id: &str,
+    session_id: &str,
+    model: &SupportedSttModel,
+) -> Result<Option<Vec<Word2>>, crate::Error> {
+    use tauri_plugin_db::DatabasePluginExt;
+
+    let semaphore = RUNNING.get_or_init(|| Semaphore::new(1));
+    let _permit = loop {
+        tokio::select! {
+            Ok(permit) = semaphore.acquire() => break permit,
+            _ = tokio::time::sleep(HEARTBEAT) => {
+                if !still_running(app, task_id).await? {
+                    return Ok(None);
+                }
+            }
+        }
+    };
+
+    let (session, path) = crate::ext::saved_recording(app, session_id).await?;
+    let clock = crate::ext::recording_clock(&session, &path)?;
+
+    let duration_ms = crate::retranscribe::duration_ms(&path)?;
+    let words: Vec<(u64, u64)> = session
+        .words
+        .iter()
+        .filter_map(|word| {
+            Some((
+                clock.offset_ms(word.start_ms?),
+                clock.offset_ms(word.end_ms?),
+            ))
+        })
+        .collect();
+    let languages = crate::ext::spoken_languages(app).await?;
+
+    let mut replacement = Vec::new();
+    for range in crate::retranscribe::chunk_ranges(&words, duration_ms, CHUNK_MS) {
+        if !still_running(app, task_id).await? {
+            return Ok(None);
+        }
+
+        replacement.extend(
+            crate::ext::transcribe_range(app, model, &path, &clock, range, languages.clone())
+                .await?,
+        );
+
+        let _ = SessionEvent::RetranscribeProgress {
+            session_id: session_id.to_string(),
+            task_id: task_id.to_string(),
+            done_ms: range.1,
+            total_ms: duration_ms,
+        }
+        .emit(app);
+    }
+
+    if !still_running(app, task_id).await? {
+        return Ok(None);
+    }
+
+    // Re-read so speakers named while transcribing carry over.
+    let session = app
+        .db_get_session(session_id)
+        .await?
+        .ok_or(crate::Error::NoneSession)?;
+
+    Ok(Some(crate::retranscribe::splice_words(
+        session.words,
+        0,
+        u64::MAX,
+        replacement,
+    )))
+}
+
+pub async fn still_running<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    task_id: &str,
+) -> Result<bool, crate::Error> {
+    let task = app.heartbeat_queued_task(task_id.to_string(), None).await?;
+    Ok(task.status == QueuedTaskStatus::Running)
+}
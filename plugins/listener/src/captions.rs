@@ -0,0 +1,195 @@
+use std::{collections::VecDeque, path::PathBuf, sync::Mutex, time::Duration};
+
+use owhisper_interface::Word2;
+
+/// Where broadcast captions usually wrap, so lines stay short enough to read at a glance.
+const LINE_CHARS: usize = 42;
+
+pub const DEFAULT_CAPTION_LINES: usize = 2;
+
+/// How often the caption file is rewritten at most, however fast partial words come in.
+const FILE_WRITE_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The live transcript as a few short lines, like a TV caption block. Final words fill lines
+/// that never change again; the words still being recognized trail after them.
+#[derive(Debug)]
+pub struct CaptionLines {
+    max_lines: usize,
+    done: VecDeque<String>,
+    current: String,
+    partial: String,
+}
+
+impl CaptionLines {
+    pub fn new(max_lines: usize) -> Self {
+        Self {
+            max_lines: max_lines.max(1),
+            done: VecDeque::new(),
+            current: String::new(),
+            partial: String::new(),
+        }
+    }
+
+    pub fn push_final(&mut self, words: &[Word2]) {
+        self.partial.clear();
+
+        for text in texts(words) {
+            if !fits(&self.current, text) {
+                self.done.push_back(std::mem::take(&mut self.current));
+            }
+            append(&mut self.current, text);
+        }
+
+        while self.done.len() > self.max_lines {
+            self.done.pop_front();
+        }
+    }
+
+    pub fn set_partial(&mut self, words: &[Word2]) {
+        self.partial.clear();
+        for text in texts(words) {
+            append(&mut self.partial, text);
+        }
+    }
+
+    /// The last `max_lines` lines, one per line of text.
+    pub fn text(&self) -> String {
+        let mut lines: Vec<&str> = self.done.iter().map(String::as_str).collect();
+
+        let mut tail = vec![String::new()];
+        for text in self.current.split(' ').chain(self.partial.split(' ')) {
+            if text.is_empty() {
+                continue;
+            }
+            let last = tail.last_mut().unwrap();
+            if fits(last, text) {
+                append(last, text);
+            } else {
+                tail.push(text.to_string());
+            }
+        }
+        lines.extend(tail.iter().map(String::as_str).filter(|l| !l.is_empty()));
+
+        lines[lines.len().saturating_sub(self.max_lines)..].join("\n")
+    }
+}
+
+fn texts(words: &[Word2]) -> impl Iterator<Item = &str> {
+    words
+        .iter()
+        .map(|w| w.text.trim())
+        .filter(|t| !t.is_empty())
+}
+
+fn fits(line: &str, word: &str) -> bool {
+    line.is_empty() || line.chars().count() + 1 + word.chars().count() <= LINE_CHARS
+}
+
+fn append(line: &mut String, word: &str) {
+    if !line.is_empty() {
+        line.push(' ');
+    }
+    line.push_str(word);
+}
+
+/// The captions of the session being recorded, for the app's own outputs to read.
+#[derive(Default)]
+pub struct LiveCaptions(Mutex<String>);
+
+impl LiveCaptions {
+    pub fn get(&self) -> String {
+        self.0.lock().unwrap().clone()
+    }
+
+    /// Whether `text` differs from what was there.
+    pub fn set(&self, text: String) -> bool {
+        let mut current = self.0.lock().unwrap();
+        if *current == text {
+            return false;
+        }
+        *current = text;
+        true
+    }
+}
+
+/// A text file kept in sync with the captions, for streaming tools like OBS that show one as
+/// a text source. Writes happen on a task of their own, and only the latest captions are written.
+#[derive(Debug, Clone)]
+pub struct CaptionFile(tokio::sync::watch::Sender<String>);
+
+impl CaptionFile {
+    /// The writing task stops once the last clone is dropped, after writing what it was given.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let (tx, rx) = tokio::sync::watch::channel(String::new());
+        tokio::spawn(write_caption_file(path.into(), rx));
+        Self(tx)
+    }
+
+    pub fn write(&self, text: &str) {
+        self.0.send_replace(text.to_string());
+    }
+}
+
+async fn write_caption_file(path: PathBuf, mut rx: tokio::sync::watch::Receiver<String>) {
+    let mut failing = false;
+
+    while rx.changed().await.is_ok() {
+        let text = rx.borrow_and_update().clone();
+
+        // Rewritten in place: Windows refuses to rename over a file while OBS has it open.
+        match tokio::fs::write(&path, text).await {
+            Ok(()) => failing = false,
+            Err(e) => {
+                // Once per run of failures, rather than with every word.
+                if !failing {
+                    tracing::warn!("caption_file_write_failed: {}", e);
+                }
+                failing = true;
+            }
+        }
+
+        tokio::time::sleep(FILE_WRITE_INTERVAL).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(text: &str) -> Vec<Word2> {
+        text.split_whitespace()
+            .map(|t| Word2 {
+                text: t.to_string(),
+                speaker: None,
+                confidence: None,
+                start_ms: None,
+                end_ms: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_caption_lines() {
+        let mut captions = CaptionLines::new(2);
+        captions.push_final(&words("the quick brown fox jumps over"));
+        assert_eq!(captions.text(), "the quick brown fox jumps over");
+
+        captions.set_partial(&words("the lazy dog"));
+        assert_eq!(
+            captions.text(),
+            "the quick brown fox jumps over the lazy\ndog"
+        );
+
+        captions.push_final(&words("the lazy dog and keeps running into the woods"));
+        assert_eq!(
+            captions.text(),
+            "the quick brown fox jumps over the lazy\ndog and keeps running into the woods"
+        );
+
+        captions.push_final(&words("until nightfall comes"));
+        assert_eq!(
+            captions.text(),
+            "dog and keeps running into the woods until\nnightfall comes"
+        );
+    }
+}
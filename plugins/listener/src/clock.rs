@@ -0,0 +1,143 @@
+use std::path::{Path, PathBuf};
+
+const SAMPLE_RATE: u64 = 16000;
+
+/// Part of a recording captured in one go: from `started_at_ms` (unix time) on, written from
+/// `sample_offset` on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Stretch {
+    pub started_at_ms: u64,
+    pub sample_offset: u64,
+}
+
+/// Maps the wall-clock times word timings are in to offsets into a recording, and back.
+///
+/// Pauses are written as silence, but a session started again appends to the same file with
+/// nothing for the time in between. Each start is kept as a [`Stretch`] next to the recording.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordingClock {
+    stretches: Vec<Stretch>,
+}
+
+impl RecordingClock {
+    /// A recording written in one go from `record_start_ms`.
+    pub fn starting_at(record_start_ms: u64) -> Self {
+        Self {
+            stretches: vec![Stretch {
+                started_at_ms: record_start_ms,
+                sample_offset: 0,
+            }],
+        }
+    }
+
+    /// `audio.wav` keeps its stretches in `audio.stretches.json`.
+    pub fn path(recording: &Path) -> PathBuf {
+        recording.with_extension("stretches.json")
+    }
+
+    /// Recordings from before stretches were kept are taken as one, from `record_start_ms`.
+    pub fn load(recording: &Path, record_start_ms: u64) -> Self {
+        match read_stretches(recording) {
+            Some(stretches) if !stretches.is_empty() => Self { stretches },
+            _ => Self::starting_at(record_start_ms),
+        }
+    }
+
+    /// Notes that what is written to `recording` from `stretch.sample_offset` on was captured
+    /// from `stretch.started_at_ms` on.
+    pub fn append(recording: &Path, stretch: Stretch) -> std::io::Result<()> {
+        let mut stretches = read_stretches(recording).unwrap_or_default();
+        // A start that wrote nothing before the next one leaves nothing to map.
+        stretches.retain(|s| s.sample_offset < stretch.sample_offset);
+        stretches.push(stretch);
+
+        let content = serde_json::to_vec(&stretches).map_err(std::io::Error::other)?;
+        std::fs::write(Self::path(recording), content)
+    }
+
+    /// How far into the recording `at_ms` was captured. A time between two stretches maps to
+    /// where the next one starts, and one before the recording to its start.
+    pub fn offset_ms(&self, at_ms: u64) -> u64 {
+        let next = self.stretches.partition_point(|s| s.started_at_ms <= at_ms);
+        let Some(stretch) = next.checked_sub(1).map(|i| self.stretches[i]) else {
+            return 0;
+        };
+
+        let offset_ms = to_ms(stretch.sample_offset) + (at_ms - stretch.started_at_ms);
+        match self.stretches.get(next) {
+            Some(next) => offset_ms.min(to_ms(next.sample_offset)),
+            None => offset_ms,
+        }
+    }
+
+    /// When the audio `offset_ms` into the recording was captured.
+    pub fn time_ms(&self, offset_ms: u64) -> u64 {
+        let sample = offset_ms * SAMPLE_RATE / 1000;
+        let index = self
+            .stretches
+            .partition_point(|s| s.sample_offset <= sample)
+            .max(1);
+        let stretch = self.stretches[index - 1];
+
+        stretch.started_at_ms + offset_ms.saturating_sub(to_ms(stretch.sample_offset))
+    }
+}
+
+fn read_stretches(recording: &Path) -> Option<Vec<Stretch>> {
+    let content = std::fs::read(RecordingClock::path(recording)).ok()?;
+    serde_json::from_slice(&content).ok()
+}
+
+fn to_ms(samples: u64) -> u64 {
+    samples * 1000 / SAMPLE_RATE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_stretch() {
+        let clock = RecordingClock::starting_at(1_000_000);
+
+        assert_eq!(clock.offset_ms(999_000), 0);
+        assert_eq!(clock.offset_ms(1_000_500), 500);
+        assert_eq!(clock.time_ms(500), 1_000_500);
+    }
+
+    #[test]
+    fn test_restarted_recording() {
+        let recording = std::env::temp_dir().join(format!("{}.wav", uuid::Uuid::new_v4()));
+
+        // 10 seconds recorded, then started again a minute after the first start.
+        for stretch in [
+            Stretch {
+                started_at_ms: 1_000_000,
+                sample_offset: 0,
+            },
+            Stretch {
+                started_at_ms: 1_060_000,
+                sample_offset: 10 * SAMPLE_RATE,
+            },
+        ] {
+            RecordingClock::append(&recording, stretch).unwrap();
+        }
+
+        let clock = RecordingClock::load(&recording, 0);
+        assert_eq!(clock.offset_ms(1_005_000), 5_000);
+        assert_eq!(clock.offset_ms(1_030_000), 10_000);
+        assert_eq!(clock.offset_ms(1_062_000), 12_000);
+
+        assert_eq!(clock.time_ms(5_000), 1_005_000);
+        assert_eq!(clock.time_ms(12_000), 1_062_000);
+
+        std::fs::remove_file(RecordingClock::path(&recording)).unwrap();
+    }
+
+    #[test]
+    fn test_load_without_stretches() {
+        let recording = std::env::temp_dir().join(format!("{}.wav", uuid::Uuid::new_v4()));
+        let clock = RecordingClock::load(&recording, 42);
+        assert_eq!(clock, RecordingClock::starting_at(42));
+    }
+}
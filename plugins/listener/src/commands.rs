@@ -125,6 +125,15 @@ pub async fn set_speaker_muted<R: tauri::Runtime>(
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn mark_highlight<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    note: Option<String>,
+) -> Result<Option<hypr_db_user::TimelineEvent>, String> {
+    Ok(app.mark_highlight(note).await)
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn start_session<R: tauri::Runtime>(
@@ -142,6 +151,20 @@ pub async fn stop_session<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Result
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn pause_session<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Result<(), String> {
+    app.pause_session().await;
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn resume_session<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Result<(), String> {
+    app.resume_session().await;
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn get_state<R: tauri::Runtime>(
@@ -149,3 +172,159 @@ pub async fn get_state<R: tauri::Runtime>(
 ) -> Result<crate::fsm::State, String> {
     Ok(app.get_state().await)
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_interrupted_session<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Option<crate::InterruptedSession>, String> {
+    Ok(app.get_interrupted_session().await)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn resolve_interrupted_session<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    resume: bool,
+) -> Result<(), String> {
+    app.resolve_interrupted_session(resume)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn retranscribe_range<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    start_ms: u64,
+    end_ms: u64,
+    model: tauri_plugin_local_stt::SupportedSttModel,
+) -> Result<Vec<owhisper_interface::Word2>, String> {
+    app.retranscribe_range(session_id, start_ms, end_ms, model)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn word_to_offset<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    word_index: u32,
+) -> Result<Option<u64>, String> {
+    app.word_to_offset(session_id, word_index)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn offset_to_word<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    offset_ms: u64,
+) -> Result<Option<u32>, String> {
+    app.offset_to_word(session_id, offset_ms)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn stream_audio<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    start_ms: u64,
+    end_ms: Option<u64>,
+    channel: tauri::ipc::Channel<crate::AudioFrame>,
+) -> Result<(), String> {
+    app.stream_audio(session_id, start_ms, end_ms, channel)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn stop_audio_stream<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Result<(), String> {
+    app.stop_audio_stream().await;
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn assign_speaker<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    speaker_index: u8,
+    name: String,
+    profile_id: Option<String>,
+) -> Result<Vec<owhisper_interface::Word2>, String> {
+    app.assign_speaker(session_id, speaker_index, name, profile_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn suggest_speakers<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+) -> Result<Vec<crate::SpeakerSuggestion>, String> {
+    app.suggest_speakers(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn refresh_wake_listener<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<bool, String> {
+    app.refresh_wake_listener().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn retranscribe_sessions<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_ids: Vec<String>,
+    model: tauri_plugin_local_stt::SupportedSttModel,
+) -> Result<Vec<hypr_db_user::QueuedTask>, String> {
+    app.retranscribe_sessions(session_ids, model)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_retranscription<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+) -> Result<Option<hypr_db_user::SessionRetranscription>, String> {
+    app.get_retranscription(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn apply_retranscription<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+) -> Result<Vec<owhisper_interface::Word2>, String> {
+    app.apply_retranscription(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn discard_retranscription<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+) -> Result<(), String> {
+    app.discard_retranscription(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,146 @@
+use hypr_db_user::{ConfigGeneral, DictionaryEntry};
+use owhisper_interface::Word;
+
+use crate::text::{normalize_word, trailing_punctuation};
+
+/// Rewrites transcribed words to the spellings in the user's dictionary.
+#[derive(Debug, Default, Clone)]
+pub struct Dictionary {
+    /// Normalized phrase to look for, and the term that replaces it.
+    rules: Vec<(Vec<String>, String)>,
+}
+
+impl Dictionary {
+    pub fn new(entries: &[DictionaryEntry]) -> Self {
+        let mut rules: Vec<_> = entries
+            .iter()
+            .filter(|entry| !entry.term.trim().is_empty())
+            .flat_map(|entry| {
+                let term = entry.term.trim().to_string();
+                std::iter::once(&entry.term)
+                    .chain(entry.sounds_like.iter())
+                    .map(move |phrase| (tokens(phrase), term.clone()))
+            })
+            .filter(|(pattern, _)| !pattern.is_empty())
+            .collect();
+
+        // Longest first, so "hyper note pro" wins over "hyper note".
+        rules.sort_by(|a, b| b.0.len().cmp(&a.0.len()));
+
+        Self { rules }
+    }
+
+    pub fn apply(&self, words: Vec<Word>) -> Vec<Word> {
+        if self.rules.is_empty() {
+            return words;
+        }
+
+        let normalized: Vec<String> = words.iter().map(|w| normalize_word(&w.word)).collect();
+        let mut out = Vec::with_capacity(words.len());
+        let mut i = 0;
+
+        while i < words.len() {
+            let matched = self
+                .rules
+                .iter()
+                .find(|(pattern, _)| normalized[i..].starts_with(pattern));
+
+            match matched {
+                Some((pattern, term)) => {
+                    let last = &words[i + pattern.len() - 1];
+                    let mut word = words[i].clone();
+                    word.word = format!("{}{}", term, trailing_punctuation(&last.word));
+                    word.end = last.end;
+                    word.punctuated_word = None;
+                    out.push(word);
+                    i += pattern.len();
+                }
+                None => {
+                    out.push(words[i].clone());
+                    i += 1;
+                }
+            }
+        }
+
+        out
+    }
+}
+
+/// Terms sent to backends that can bias recognition towards them.
+pub fn keyterms(general: &ConfigGeneral) -> Vec<String> {
+    let mut terms: Vec<String> = general
+        .jargons
+        .iter()
+        .chain(general.dictionary.iter().map(|entry| &entry.term))
+        .map(|term| term.trim().to_string())
+        .filter(|term| !term.is_empty())
+        .collect();
+
+    terms.sort();
+    terms.dedup();
+    terms
+}
+
+fn tokens(phrase: &str) -> Vec<String> {
+    phrase
+        .split_whitespace()
+        .map(normalize_word)
+        .filter(|token| !token.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(text: &str) -> Vec<Word> {
+        text.split_whitespace()
+            .enumerate()
+            .map(|(i, w)| Word {
+                word: w.to_string(),
+                start: i as f64,
+                end: i as f64 + 0.5,
+                confidence: 1.0,
+                speaker: None,
+                punctuated_word: None,
+                language: None,
+            })
+            .collect()
+    }
+
+    fn text(words: &[Word]) -> String {
+        words
+            .iter()
+            .map(|w| w.word.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_apply() {
+        let dictionary = Dictionary::new(&[
+            DictionaryEntry {
+                term: "Hyprnote".to_string(),
+                sounds_like: vec!["hyper note".to_string(), "hyper notes".to_string()],
+            },
+            DictionaryEntry {
+                term: "Yujong".to_string(),
+                sounds_like: vec![],
+            },
+        ]);
+
+        let result = dictionary.apply(words("I told yujong about Hyper Note, right?"));
+        assert_eq!(text(&result), "I told Yujong about Hyprnote, right?");
+
+        let merged = &result[4];
+        assert_eq!(merged.start, 4.0);
+        assert_eq!(merged.end, 5.5);
+    }
+
+    #[test]
+    fn test_apply_without_entries() {
+        let dictionary = Dictionary::new(&[]);
+        let input = words("nothing to change here");
+        assert_eq!(dictionary.apply(input.clone()), input);
+    }
+}
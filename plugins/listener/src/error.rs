@@ -7,21 +7,49 @@ pub enum Error {
     #[error(transparent)]
     IoError(#[from] std::io::Error),
     #[error(transparent)]
+    TauriError(#[from] tauri::Error),
+    #[error(transparent)]
     HyprAudioError(#[from] hypr_audio::Error),
     #[error(transparent)]
     CpalDevicesError(#[from] hypr_audio::cpal::DevicesError),
     #[error(transparent)]
+    HoundError(#[from] hound::Error),
+    #[error(transparent)]
     DatabaseError(#[from] tauri_plugin_db::Error),
     #[error(transparent)]
     LocalSttError(#[from] tauri_plugin_local_stt::Error),
     #[error(transparent)]
     ConnectorError(#[from] tauri_plugin_connector::Error),
+    #[error(transparent)]
+    PyannoteError(#[from] hypr_pyannote_local::Error),
+    #[error(transparent)]
+    TaskError(#[from] tauri_plugin_task::Error),
     #[error("no session")]
     NoneSession,
+    #[error("no user")]
+    NoneUser,
     #[error("start session failed")]
     StartSessionFailed,
     #[error("stop session failed")]
     StopSessionFailed,
+    #[error("session is still being recorded")]
+    SessionInProgress,
+    #[error("session has no recording")]
+    NoRecording,
+    #[error("recording is not 16kHz mono")]
+    UnsupportedRecording,
+    #[error("invalid time range")]
+    InvalidRange,
+    #[error("speaker profile not found")]
+    SpeakerProfileNotFound,
+    #[error("no on-device whisper model is downloaded")]
+    NoWakeModel,
+    #[error("model is not downloaded")]
+    ModelNotDownloaded,
+    #[error("invalid task payload: {0}")]
+    InvalidTaskPayload(String),
+    #[error("no re-transcription for this session")]
+    NoRetranscription,
 }
 
 impl Serialize for Error {
@@ -3,7 +3,9 @@ use std::collections::HashMap;
 #[macro_export]
 macro_rules! common_event_derives {
     ($item:item) => {
-        #[derive(serde::Serialize, Clone, specta::Type, tauri_specta::Event)]
+        #[derive(
+            serde::Serialize, serde::Deserialize, Clone, specta::Type, tauri_specta::Event,
+        )]
         $item
     };
 }
@@ -15,6 +17,8 @@ common_event_derives! {
         Inactive {},
         #[serde(rename = "running_active")]
         RunningActive {},
+        #[serde(rename = "running_paused")]
+        RunningPaused {},
         #[serde(rename = "finalWords")]
         FinalWords { words: HashMap<usize, Vec<owhisper_interface::Word2>>},
         #[serde(rename = "partialWords")]
@@ -25,6 +29,36 @@ common_event_derives! {
         MicMuted { value: bool },
         #[serde(rename = "speakerMuted")]
         SpeakerMuted { value: bool },
+        #[serde(rename = "timelineEvent")]
+        TimelineEvent { event: hypr_db_user::TimelineEvent },
+        #[serde(rename = "stats")]
+        Stats { session_id: String, stats: hypr_db_user::SessionStats },
+        #[serde(rename = "translatedSegment")]
+        TranslatedSegment {
+            channel: usize,
+            start_ms: Option<u64>,
+            end_ms: Option<u64>,
+            /// ISO 639-1 code of `text`.
+            language: String,
+            source: String,
+            text: String,
+        },
+        #[serde(rename = "speakerSuggested")]
+        SpeakerSuggested { channel: usize, suggestion: crate::SpeakerSuggestion },
+        /// The mic is listened to for `phrase` while idle, or no longer when `None`.
+        #[serde(rename = "wakeListening")]
+        WakeListening { phrase: Option<String> },
+        #[serde(rename = "wakePhraseDetected")]
+        WakePhraseDetected {},
+        /// `done_ms` of the recording's `total_ms` is transcribed again.
+        #[serde(rename = "retranscribeProgress")]
+        RetranscribeProgress { session_id: String, task_id: String, done_ms: u64, total_ms: u64 },
+        /// A new transcript waits to be previewed and applied or discarded.
+        #[serde(rename = "retranscriptionReady")]
+        RetranscriptionReady { session_id: String },
+        /// The recording has run longer than it was meant to.
+        #[serde(rename = "lengthAlert")]
+        LengthAlert { session_id: String, alert: crate::LengthAlert },
     }
 }
 
@@ -10,6 +10,7 @@ use {
 };
 
 use crate::actors::SessionMsg;
+use crate::clock::RecordingClock;
 
 pub trait ListenerPluginExt<R: tauri::Runtime> {
     fn list_microphone_devices(&self) -> impl Future<Output = Result<Vec<String>, crate::Error>>;
@@ -32,10 +33,99 @@ pub trait ListenerPluginExt<R: tauri::Runtime> {
     fn get_speaker_muted(&self) -> impl Future<Output = bool>;
     fn set_mic_muted(&self, muted: bool) -> impl Future<Output = ()>;
     fn set_speaker_muted(&self, muted: bool) -> impl Future<Output = ()>;
+    fn mark_highlight(
+        &self,
+        note: Option<String>,
+    ) -> impl Future<Output = Option<hypr_db_user::TimelineEvent>>;
 
     fn get_state(&self) -> impl Future<Output = crate::fsm::State>;
+    fn get_session_id(&self) -> impl Future<Output = Option<String>>;
     fn stop_session(&self) -> impl Future<Output = ()>;
     fn start_session(&self, id: impl Into<String>) -> impl Future<Output = ()>;
+    fn pause_session(&self) -> impl Future<Output = ()>;
+    fn resume_session(&self) -> impl Future<Output = ()>;
+
+    fn get_interrupted_session(&self) -> impl Future<Output = Option<crate::InterruptedSession>>;
+    fn resolve_interrupted_session(
+        &self,
+        resume: bool,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
+
+    /// Runs `[start_ms, end_ms]` of a saved recording through `model` and swaps in the new
+    /// words. Times are Unix milliseconds, like the stored words.
+    fn retranscribe_range(
+        &self,
+        session_id: impl Into<String>,
+        start_ms: u64,
+        end_ms: u64,
+        model: tauri_plugin_local_stt::SupportedSttModel,
+    ) -> impl Future<Output = Result<Vec<owhisper_interface::Word2>, crate::Error>>;
+
+    /// Where the word at `word_index` starts, in milliseconds into the recording.
+    fn word_to_offset(
+        &self,
+        session_id: impl Into<String>,
+        word_index: u32,
+    ) -> impl Future<Output = Result<Option<u64>, crate::Error>>;
+    /// The word spoken `offset_ms` into the recording, for following along during playback.
+    fn offset_to_word(
+        &self,
+        session_id: impl Into<String>,
+        offset_ms: u64,
+    ) -> impl Future<Output = Result<Option<u32>, crate::Error>>;
+    /// Sends a saved recording from `start_ms` to `channel`, a little ahead of real time.
+    /// Starting another stream stops this one.
+    fn stream_audio(
+        &self,
+        session_id: impl Into<String>,
+        start_ms: u64,
+        end_ms: Option<u64>,
+        channel: tauri::ipc::Channel<crate::AudioFrame>,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
+    fn stop_audio_stream(&self) -> impl Future<Output = ()>;
+
+    /// Names a speaker of a finished session, remembering their voice for later sessions.
+    /// Reuses the profile `profile_id`, or one with the same name, before creating one.
+    fn assign_speaker(
+        &self,
+        session_id: impl Into<String>,
+        speaker_index: u8,
+        name: impl Into<String>,
+        profile_id: Option<String>,
+    ) -> impl Future<Output = Result<Vec<owhisper_interface::Word2>, crate::Error>>;
+    /// Known voices among the unnamed speakers of a saved recording.
+    fn suggest_speakers(
+        &self,
+        session_id: impl Into<String>,
+    ) -> impl Future<Output = Result<Vec<crate::SpeakerSuggestion>, crate::Error>>;
+
+    /// Listens on the mic for the configured wake phrase while no session runs, or stops when
+    /// it was cleared. Returns whether it is listening.
+    fn refresh_wake_listener(&self) -> impl Future<Output = Result<bool, crate::Error>>;
+
+    /// Queues the recordings of `session_ids` to be transcribed again with `model`. Nothing is
+    /// replaced until the result is applied.
+    fn retranscribe_sessions(
+        &self,
+        session_ids: Vec<String>,
+        model: tauri_plugin_local_stt::SupportedSttModel,
+    ) -> impl Future<Output = Result<Vec<hypr_db_user::QueuedTask>, crate::Error>>;
+    fn get_retranscription(
+        &self,
+        session_id: impl Into<String>,
+    ) -> impl Future<Output = Result<Option<hypr_db_user::SessionRetranscription>, crate::Error>>;
+    /// Swaps the session's transcript for the re-transcribed one.
+    fn apply_retranscription(
+        &self,
+        session_id: impl Into<String>,
+    ) -> impl Future<Output = Result<Vec<owhisper_interface::Word2>, crate::Error>>;
+    fn discard_retranscription(
+        &self,
+        session_id: impl Into<String>,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
+
+    /// The last few lines of the live transcript, empty while nothing is recording.
+    fn live_captions(&self) -> String;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> ListenerPluginExt<R> for T {
@@ -191,6 +281,13 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> ListenerPluginExt<R> for T {
         guard.get_state().await
     }
 
+    #[tracing::instrument(skip_all)]
+    async fn get_session_id(&self) -> Option<String> {
+        let state = self.state::<crate::SharedState>();
+        let guard = state.lock().await;
+        guard.get_session_id().await
+    }
+
     #[tracing::instrument(skip_all)]
     async fn get_mic_muted(&self) -> bool {
         let state = self.state::<crate::SharedState>();
@@ -241,6 +338,18 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> ListenerPluginExt<R> for T {
         }
     }
 
+    #[tracing::instrument(skip_all)]
+    async fn mark_highlight(&self, note: Option<String>) -> Option<hypr_db_user::TimelineEvent> {
+        let state = self.state::<crate::SharedState>();
+        let guard = state.lock().await;
+
+        let supervisor = guard.supervisor.as_ref()?;
+        // Saving the marker hits the database, so allow more than the usual 100ms.
+        call_t!(supervisor, SessionMsg::MarkHighlight, 1000, note)
+            .ok()
+            .flatten()
+    }
+
     #[tracing::instrument(skip_all)]
     async fn start_session(&self, session_id: impl Into<String>) {
         let state = self.state::<crate::SharedState>();
@@ -262,4 +371,550 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> ListenerPluginExt<R> for T {
             let _ = supervisor.cast(SessionMsg::Stop);
         }
     }
+
+    #[tracing::instrument(skip_all)]
+    async fn pause_session(&self) {
+        let state = self.state::<crate::SharedState>();
+        let guard = state.lock().await;
+
+        if let Some(supervisor) = &guard.supervisor {
+            let _ = supervisor.cast(SessionMsg::Pause);
+        }
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn resume_session(&self) {
+        let state = self.state::<crate::SharedState>();
+        let guard = state.lock().await;
+
+        if let Some(supervisor) = &guard.supervisor {
+            let _ = supervisor.cast(SessionMsg::Resume);
+        }
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn get_interrupted_session(&self) -> Option<crate::InterruptedSession> {
+        let state = self.state::<crate::SharedState>();
+        let guard = state.lock().await;
+        guard.interrupted.clone()
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn resolve_interrupted_session(&self, resume: bool) -> Result<(), crate::Error> {
+        use tauri_plugin_db::DatabasePluginExt;
+
+        let interrupted = {
+            let state = self.state::<crate::SharedState>();
+            let mut guard = state.lock().await;
+            guard.interrupted.take()
+        };
+        let Some(interrupted) = interrupted else {
+            return Ok(());
+        };
+
+        let app_dir = hypr_data_dir::resolve(|| self.path().app_data_dir())?;
+        for path in crate::recovery::recording_paths(&app_dir, &interrupted.session_id) {
+            if let Err(e) = crate::recovery::repair_wav(&path) {
+                tracing::warn!("failed_to_repair_recording: {:?} {}", path, e);
+            }
+        }
+
+        // A session started since launch owns the checkpoint now.
+        if crate::recovery::load(self).is_some_and(|c| c.session_id == interrupted.session_id) {
+            crate::recovery::clear(self)?;
+        }
+
+        // Resuming leaves the recording open; the caller starts the session again.
+        if resume {
+            return Ok(());
+        }
+
+        if let Some(mut session) = self.db_get_session(&interrupted.session_id).await? {
+            session.record_end =
+                chrono::DateTime::from_timestamp_millis(interrupted.checkpoint_at_ms as i64);
+            self.db_upsert_session(session).await?;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn retranscribe_range(
+        &self,
+        session_id: impl Into<String>,
+        start_ms: u64,
+        end_ms: u64,
+        model: tauri_plugin_local_stt::SupportedSttModel,
+    ) -> Result<Vec<owhisper_interface::Word2>, crate::Error> {
+        use tauri_plugin_db::DatabasePluginExt;
+
+        let session_id = session_id.into();
+        let (session, path) = saved_recording(self, &session_id).await?;
+
+        let clock = recording_clock(&session, &path)?;
+        let (offset_start, offset_end) = (clock.offset_ms(start_ms), clock.offset_ms(end_ms));
+        if offset_end <= offset_start {
+            return Err(crate::Error::InvalidRange);
+        }
+
+        let languages = spoken_languages(self).await?;
+        let replacement = transcribe_range(
+            self,
+            &model,
+            &path,
+            &clock,
+            (offset_start, offset_end),
+            languages,
+        )
+        .await?;
+
+        // Re-read so words saved while transcribing aren't lost.
+        let mut session = self
+            .db_get_session(&session_id)
+            .await?
+            .ok_or(crate::Error::NoneSession)?;
+        session.words =
+            crate::retranscribe::splice_words(session.words, start_ms, end_ms, replacement);
+        self.db_upsert_session_with_revision(
+            session.clone(),
+            hypr_db_user::SessionRevisionSource::Retranscription,
+            model.to_string(),
+        )
+        .await?;
+
+        Ok(session.words)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn word_to_offset(
+        &self,
+        session_id: impl Into<String>,
+        word_index: u32,
+    ) -> Result<Option<u64>, crate::Error> {
+        let (session, clock) = session_with_clock(self, session_id).await?;
+        Ok(crate::playback::word_offset_ms(
+            &session.words,
+            word_index as usize,
+            &clock,
+        ))
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn offset_to_word(
+        &self,
+        session_id: impl Into<String>,
+        offset_ms: u64,
+    ) -> Result<Option<u32>, crate::Error> {
+        let (session, clock) = session_with_clock(self, session_id).await?;
+        Ok(
+            crate::playback::word_at_offset_ms(&session.words, offset_ms, &clock)
+                .map(|index| index as u32),
+        )
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn stream_audio(
+        &self,
+        session_id: impl Into<String>,
+        start_ms: u64,
+        end_ms: Option<u64>,
+        channel: tauri::ipc::Channel<crate::AudioFrame>,
+    ) -> Result<(), crate::Error> {
+        let session_id = session_id.into();
+        let (_, path) = saved_recording(self, &session_id).await?;
+        let frames = crate::playback::AudioFrames::open(&path, start_ms, end_ms)?;
+
+        let token = tokio_util::sync::CancellationToken::new();
+        {
+            let state = self.state::<crate::SharedState>();
+            let mut guard = state.lock().await;
+            if let Some(previous) = guard.playback.replace(token.clone()) {
+                previous.cancel();
+            }
+        }
+
+        tokio::task::spawn_blocking(move || {
+            let started = std::time::Instant::now();
+            let mut sent = std::time::Duration::ZERO;
+
+            for frame in frames {
+                if token.is_cancelled() {
+                    break;
+                }
+                let frame = match frame {
+                    Ok(frame) => frame,
+                    Err(e) => {
+                        tracing::warn!("stream_audio_failed: {}", e);
+                        break;
+                    }
+                };
+
+                // Stay just far enough ahead for the player not to run dry.
+                let ahead = sent.saturating_sub(started.elapsed());
+                if ahead > PLAYBACK_LEAD {
+                    std::thread::sleep(ahead - PLAYBACK_LEAD);
+                }
+
+                sent += std::time::Duration::from_millis(
+                    frame.samples.len() as u64 * 1000 / frame.sample_rate as u64,
+                );
+                if channel.send(frame).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn stop_audio_stream(&self) {
+        let state = self.state::<crate::SharedState>();
+        let mut guard = state.lock().await;
+        if let Some(token) = guard.playback.take() {
+            token.cancel();
+        }
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn assign_speaker(
+        &self,
+        session_id: impl Into<String>,
+        speaker_index: u8,
+        name: impl Into<String>,
+        profile_id: Option<String>,
+    ) -> Result<Vec<owhisper_interface::Word2>, crate::Error> {
+        use tauri_plugin_db::DatabasePluginExt;
+
+        let session_id = session_id.into();
+        let name = name.into().trim().to_string();
+        if self.get_session_id().await.as_deref() == Some(session_id.as_str()) {
+            return Err(crate::Error::SessionInProgress);
+        }
+
+        let session = self
+            .db_get_session(&session_id)
+            .await?
+            .ok_or(crate::Error::NoneSession)?;
+        let user_id = self.db_user_id().await?.ok_or(crate::Error::NoneUser)?;
+        let speaker = owhisper_interface::SpeakerIdentity::Unassigned {
+            index: speaker_index,
+        };
+
+        let mut profile = match profile_id {
+            Some(id) => self
+                .db_get_speaker_profile(id)
+                .await?
+                .ok_or(crate::Error::SpeakerProfileNotFound)?,
+            None => self
+                .db_list_speaker_profiles(&user_id)
+                .await?
+                .into_iter()
+                .find(|profile| profile.name.eq_ignore_ascii_case(&name))
+                .unwrap_or_else(|| hypr_db_user::SpeakerProfile {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    user_id,
+                    name: name.clone(),
+                    human_id: None,
+                    embedding: vec![],
+                    sample_count: 0,
+                    updated_at: chrono::Utc::now(),
+                }),
+        };
+        if !name.is_empty() {
+            profile.name = name;
+        }
+        // Sessions recorded without audio still get the name, just nothing to recognize later.
+        if let Some(embedding) = recorded_voice(self, &session, &speaker).await? {
+            profile.add_sample(&embedding);
+        }
+        profile.updated_at = chrono::Utc::now();
+        let profile = self.db_upsert_speaker_profile(profile).await?;
+
+        let assigned = owhisper_interface::SpeakerIdentity::Assigned {
+            id: profile.id,
+            label: profile.name,
+        };
+        let mut session = self
+            .db_get_session(&session_id)
+            .await?
+            .ok_or(crate::Error::NoneSession)?;
+        for word in session.words.iter_mut() {
+            if word.speaker.as_ref() == Some(&speaker) {
+                word.speaker = Some(assigned.clone());
+            }
+        }
+        self.db_upsert_session(session.clone()).await?;
+
+        Ok(session.words)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn suggest_speakers(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Vec<crate::SpeakerSuggestion>, crate::Error> {
+        use tauri_plugin_db::DatabasePluginExt;
+
+        let session_id = session_id.into();
+        let (session, _) = saved_recording(self, &session_id).await?;
+        let user_id = self.db_user_id().await?.ok_or(crate::Error::NoneUser)?;
+        let profiles = self.db_list_speaker_profiles(&user_id).await?;
+        if profiles.iter().all(|profile| profile.embedding.is_empty()) {
+            return Ok(vec![]);
+        }
+
+        let mut suggestions = vec![];
+        for speaker in crate::identify::unassigned_speakers(&session.words) {
+            if let Some(embedding) = recorded_voice(self, &session, &speaker).await? {
+                suggestions.extend(crate::identify::best_match(speaker, &embedding, &profiles));
+            }
+        }
+
+        Ok(suggestions)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn refresh_wake_listener(&self) -> Result<bool, crate::Error> {
+        use tauri_plugin_db::DatabasePluginExt;
+
+        let app = self.app_handle();
+        crate::wake::stop(app);
+
+        if self.get_session_id().await.is_some() {
+            return Ok(false);
+        }
+
+        let Some(user_id) = self.db_user_id().await? else {
+            return Ok(false);
+        };
+        let Some(general) = self.db_get_config(&user_id).await?.map(|c| c.general) else {
+            return Ok(false);
+        };
+        let Some(phrase) = general
+            .wake_phrase
+            .map(|phrase| phrase.trim().to_string())
+            .filter(|phrase| !phrase.is_empty())
+        else {
+            return Ok(false);
+        };
+
+        let model = crate::wake::pick_model(app)
+            .await
+            .ok_or(crate::Error::NoWakeModel)?;
+        crate::wake::start(app, phrase, model, general.spoken_languages);
+
+        Ok(true)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn retranscribe_sessions(
+        &self,
+        session_ids: Vec<String>,
+        model: tauri_plugin_local_stt::SupportedSttModel,
+    ) -> Result<Vec<hypr_db_user::QueuedTask>, crate::Error> {
+        use tauri_plugin_local_stt::LocalSttPluginExt;
+        use tauri_plugin_task::TaskPluginExt;
+
+        if !self.is_model_downloaded(&model).await? {
+            return Err(crate::Error::ModelNotDownloaded);
+        }
+
+        let mut tasks = Vec::with_capacity(session_ids.len());
+        for session_id in session_ids {
+            let payload = crate::batch::RetranscribePayload {
+                session_id,
+                model: model.clone(),
+            };
+            let task = self
+                .enqueue_task(
+                    hypr_db_user::QueuedTaskKind::Retranscribe,
+                    serde_json::to_string(&payload).unwrap(),
+                    Some(crate::batch::MAX_ATTEMPTS),
+                )
+                .await?;
+            tasks.push(task);
+        }
+
+        Ok(tasks)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn get_retranscription(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Option<hypr_db_user::SessionRetranscription>, crate::Error> {
+        use tauri_plugin_db::DatabasePluginExt;
+
+        Ok(self.db_get_session_retranscription(session_id).await?)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn apply_retranscription(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<Vec<owhisper_interface::Word2>, crate::Error> {
+        use tauri_plugin_db::DatabasePluginExt;
+
+        let session_id = session_id.into();
+        if self.get_session_id().await.as_deref() == Some(session_id.as_str()) {
+            return Err(crate::Error::SessionInProgress);
+        }
+
+        let retranscription = self
+            .db_get_session_retranscription(&session_id)
+            .await?
+            .ok_or(crate::Error::NoRetranscription)?;
+        let mut session = self
+            .db_get_session(&session_id)
+            .await?
+            .ok_or(crate::Error::NoneSession)?;
+
+        session.words = retranscription.words;
+        self.db_upsert_session_with_revision(
+            session.clone(),
+            hypr_db_user::SessionRevisionSource::Retranscription,
+            retranscription.model,
+        )
+        .await?;
+        self.db_delete_session_retranscription(&session_id).await?;
+
+        Ok(session.words)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn discard_retranscription(
+        &self,
+        session_id: impl Into<String>,
+    ) -> Result<(), crate::Error> {
+        use tauri_plugin_db::DatabasePluginExt;
+
+        Ok(self.db_delete_session_retranscription(session_id).await?)
+    }
+
+    fn live_captions(&self) -> String {
+        self.state::<crate::captions::LiveCaptions>().get()
+    }
+}
+
+const PLAYBACK_LEAD: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// A session that is no longer being recorded, and the path of its recording.
+pub(crate) async fn saved_recording<R: tauri::Runtime, T: tauri::Manager<R>>(
+    app: &T,
+    session_id: &str,
+) -> Result<(hypr_db_user::Session, std::path::PathBuf), crate::Error> {
+    use tauri_plugin_db::DatabasePluginExt;
+
+    if app.get_session_id().await.as_deref() == Some(session_id) {
+        return Err(crate::Error::SessionInProgress);
+    }
+
+    let session = app
+        .db_get_session(session_id)
+        .await?
+        .ok_or(crate::Error::NoneSession)?;
+
+    let app_dir = hypr_data_dir::resolve(|| app.path().app_data_dir())?;
+    let path = app_dir.join(session_id).join("audio.wav");
+    if !path.exists() {
+        return Err(crate::Error::NoRecording);
+    }
+
+    Ok((session, path))
+}
+
+pub(crate) async fn spoken_languages<R: tauri::Runtime, T: tauri::Manager<R>>(
+    app: &T,
+) -> Result<Vec<hypr_language::Language>, crate::Error> {
+    use tauri_plugin_db::DatabasePluginExt;
+
+    Ok(match app.db_user_id().await? {
+        Some(user_id) => app
+            .db_get_config(&user_id)
+            .await?
+            .map(|c| c.general.spoken_languages)
+            .unwrap_or_default(),
+        None => vec![],
+    })
+}
+
+/// Transcribes `[start, end)` milliseconds into a recording, with word times mapped through
+/// `clock` to match the rest of the transcript.
+pub(crate) async fn transcribe_range<R: tauri::Runtime, T: tauri::Manager<R>>(
+    app: &T,
+    model: &tauri_plugin_local_stt::SupportedSttModel,
+    path: &std::path::Path,
+    clock: &RecordingClock,
+    (start, end): (u64, u64),
+    languages: Vec<hypr_language::Language>,
+) -> Result<Vec<owhisper_interface::Word2>, crate::Error> {
+    use tauri_plugin_local_stt::LocalSttPluginExt;
+
+    let samples = crate::retranscribe::read_range(path, start, end)?;
+    let to_time_s = |offset_s: f64| {
+        let offset_ms = (start + (offset_s * 1000.0) as u64).min(end);
+        clock.time_ms(offset_ms) as f64 / 1000.0
+    };
+
+    Ok(app
+        .transcribe_audio(model.clone(), samples, languages)
+        .await?
+        .into_iter()
+        .map(|mut word| {
+            (word.start, word.end) = (to_time_s(word.start), to_time_s(word.end));
+            owhisper_interface::Word2::from(word)
+        })
+        .collect())
+}
+
+/// How `path`, the recording of `session`, lines up with its word timings.
+pub(crate) fn recording_clock(
+    session: &hypr_db_user::Session,
+    path: &std::path::Path,
+) -> Result<RecordingClock, crate::Error> {
+    let record_start = session.record_start.ok_or(crate::Error::NoRecording)?;
+    Ok(RecordingClock::load(
+        path,
+        record_start.timestamp_millis() as u64,
+    ))
+}
+
+/// `None` when the session has no recording, or too little of `speaker` in it.
+async fn recorded_voice<R: tauri::Runtime, T: tauri::Manager<R>>(
+    app: &T,
+    session: &hypr_db_user::Session,
+    speaker: &owhisper_interface::SpeakerIdentity,
+) -> Result<Option<Vec<f32>>, crate::Error> {
+    let app_dir = hypr_data_dir::resolve(|| app.path().app_data_dir())?;
+    let path = app_dir.join(&session.id).join("audio.wav");
+    let Some(clock) = recording_clock(session, &path)
+        .ok()
+        .filter(|_| path.exists())
+    else {
+        return Ok(None);
+    };
+
+    let (words, speaker) = (session.words.clone(), speaker.clone());
+    tokio::task::spawn_blocking(move || {
+        crate::identify::speaker_embedding(&path, &words, &speaker, &clock)
+    })
+    .await
+    .map_err(std::io::Error::other)?
+}
+
+async fn session_with_clock<R: tauri::Runtime, T: tauri::Manager<R>>(
+    app: &T,
+    session_id: impl Into<String>,
+) -> Result<(hypr_db_user::Session, RecordingClock), crate::Error> {
+    use tauri_plugin_db::DatabasePluginExt;
+
+    let session = app
+        .db_get_session(session_id.into())
+        .await?
+        .ok_or(crate::Error::NoneSession)?;
+    let app_dir = hypr_data_dir::resolve(|| app.path().app_data_dir())?;
+    let clock = recording_clock(&session, &app_dir.join(&session.id).join("audio.wav"))?;
+
+    Ok((session, clock))
 }
@@ -0,0 +1,185 @@
+use hypr_db_user::{ConfigGeneral, WordFilter};
+use owhisper_interface::Word;
+
+use crate::text::{normalize_word, trailing_punctuation};
+
+// Only hesitations that are never real words; "like" or "so" would need context.
+const FILLERS: &[(&str, &[&str])] = &[
+    (
+        "en",
+        &["um", "umm", "uh", "uhh", "er", "erm", "hmm", "mm", "ah"],
+    ),
+    ("de", &["äh", "ähm", "öh", "hm", "hmm"]),
+    ("fr", &["euh", "heu", "hum"]),
+    ("es", &["eh", "ehh", "em", "mmm"]),
+    ("it", &["eh", "ehm", "mmm"]),
+    ("ko", &["음", "으음", "어어"]),
+    ("ja", &["えー", "えーと", "えっと", "あのー"]),
+];
+
+const PROFANITY: &[&str] = &[
+    "fuck",
+    "fucking",
+    "fucked",
+    "shit",
+    "shitty",
+    "bullshit",
+    "bitch",
+    "asshole",
+    "bastard",
+    "damn",
+    "dick",
+    "crap",
+    "piss",
+    "cunt",
+    "motherfucker",
+];
+
+/// Drops or masks filler words and profanity before transcripts are stored.
+#[derive(Debug, Default, Clone)]
+pub struct WordFilters {
+    fillers: WordFilter,
+    profanity: WordFilter,
+    /// Used for words the transcriber didn't tag with a language.
+    languages: Vec<String>,
+}
+
+impl WordFilters {
+    pub fn new(general: &ConfigGeneral) -> Self {
+        Self {
+            fillers: general.filler_words,
+            profanity: general.profanity,
+            languages: general
+                .spoken_languages
+                .iter()
+                .map(|lang| lang.iso639().code().to_string())
+                .collect(),
+        }
+    }
+
+    pub fn apply(&self, words: Vec<Word>) -> Vec<Word> {
+        if self.fillers == WordFilter::Keep && self.profanity == WordFilter::Keep {
+            return words;
+        }
+
+        let mut out: Vec<Word> = Vec::with_capacity(words.len());
+
+        for mut word in words {
+            let normalized = normalize_word(&word.word);
+
+            let filter = if self.is_filler(&normalized, word.language.as_deref()) {
+                self.fillers
+            } else if PROFANITY.contains(&normalized.as_str()) {
+                self.profanity
+            } else {
+                WordFilter::Keep
+            };
+
+            match filter {
+                WordFilter::Keep => out.push(word),
+                WordFilter::Mask => {
+                    word.word = mask(&word.word);
+                    word.punctuated_word = None;
+                    out.push(word);
+                }
+                WordFilter::Remove => {
+                    // Keep the sentence boundary a dropped "um." was carrying.
+                    let punctuation = trailing_punctuation(&word.word);
+                    if let Some(prev) = out.last_mut() {
+                        if !punctuation.is_empty() && trailing_punctuation(&prev.word).is_empty() {
+                            prev.word.push_str(punctuation);
+                        }
+                    }
+                }
+            }
+        }
+
+        out
+    }
+
+    fn is_filler(&self, normalized: &str, language: Option<&str>) -> bool {
+        FILLERS.iter().any(|(code, fillers)| {
+            let applies = match language {
+                Some(language) => language.starts_with(code),
+                None => self.languages.iter().any(|l| l == code),
+            };
+            applies && fillers.contains(&normalized)
+        })
+    }
+}
+
+/// "shit," becomes "s***,".
+fn mask(word: &str) -> String {
+    let mut seen_letter = false;
+    word.chars()
+        .map(|c| {
+            if !c.is_alphanumeric() {
+                c
+            } else if seen_letter {
+                '*'
+            } else {
+                seen_letter = true;
+                c
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(text: &str) -> Vec<Word> {
+        text.split_whitespace()
+            .map(|w| Word {
+                word: w.to_string(),
+                start: 0.0,
+                end: 0.0,
+                confidence: 1.0,
+                speaker: None,
+                punctuated_word: None,
+                language: None,
+            })
+            .collect()
+    }
+
+    fn text(words: &[Word]) -> String {
+        words
+            .iter()
+            .map(|w| w.word.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn filters(fillers: WordFilter, profanity: WordFilter) -> WordFilters {
+        WordFilters {
+            fillers,
+            profanity,
+            languages: vec!["en".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_remove_fillers() {
+        let result = filters(WordFilter::Remove, WordFilter::Keep)
+            .apply(words("So, um, the launch is uh next week um."));
+        assert_eq!(text(&result), "So, the launch is next week.");
+    }
+
+    #[test]
+    fn test_mask_profanity() {
+        let result =
+            filters(WordFilter::Keep, WordFilter::Mask).apply(words("That was a shit, um, demo"));
+        assert_eq!(text(&result), "That was a s***, um, demo");
+    }
+
+    #[test]
+    fn test_fillers_follow_word_language() {
+        let mut input = words("euh um");
+        input[0].language = Some("fr".to_string());
+        input[1].language = Some("fr".to_string());
+
+        let result = filters(WordFilter::Remove, WordFilter::Keep).apply(input);
+        assert_eq!(text(&result), "um");
+    }
+}
@@ -0,0 +1,4 @@
+hypr_i18n::catalog! {
+    En => "../locales/en.ftl",
+    Ko => "../locales/ko.ftl",
+}
@@ -0,0 +1,228 @@
+use std::{
+    path::Path,
+    sync::{Mutex, OnceLock},
+};
+
+use hypr_db_user::SpeakerProfile;
+use hypr_pyannote_local::embedding::EmbeddingExtractor;
+use owhisper_interface::{SpeakerIdentity, Word2};
+
+use crate::clock::RecordingClock;
+
+const SAMPLE_RATE: u64 = 16000;
+
+/// Below this, an embedding says more about the room than about the voice.
+pub const MIN_SPEECH_MS: u64 = 8_000;
+/// Enough to pin a voice down; more only makes the embedding slower to compute.
+const MAX_SPEECH_MS: u64 = 60_000;
+/// Words closer than this are read as one stretch.
+const MERGE_GAP_MS: u64 = 500;
+/// Cosine similarity a voice needs to be suggested as a known speaker.
+const MATCH_THRESHOLD: f32 = 0.6;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct SpeakerSuggestion {
+    pub speaker: SpeakerIdentity,
+    pub profile_id: String,
+    pub name: String,
+    /// Cosine similarity of the voices, from 0 to 1.
+    pub similarity: f32,
+}
+
+static EXTRACTOR: OnceLock<Mutex<EmbeddingExtractor>> = OnceLock::new();
+
+/// Blocks for a while on long recordings, so keep it off the async runtime.
+pub fn embed(samples: &[f32]) -> Result<Vec<f32>, crate::Error> {
+    let extractor = EXTRACTOR.get_or_init(|| Mutex::new(EmbeddingExtractor::new()));
+    let embedding = extractor.lock().unwrap().compute(samples.iter().copied())?;
+    Ok(embedding)
+}
+
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let denom = norm(a) * norm(b);
+    if denom == 0.0 {
+        0.0
+    } else {
+        dot / denom
+    }
+}
+
+/// The closest known voice, if it is close enough to suggest.
+pub fn best_match(
+    speaker: SpeakerIdentity,
+    embedding: &[f32],
+    profiles: &[SpeakerProfile],
+) -> Option<SpeakerSuggestion> {
+    profiles
+        .iter()
+        .map(|profile| (profile, cosine_similarity(embedding, &profile.embedding)))
+        .filter(|(_, similarity)| *similarity >= MATCH_THRESHOLD)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(profile, similarity)| SpeakerSuggestion {
+            speaker,
+            profile_id: profile.id.clone(),
+            name: profile.name.clone(),
+            similarity,
+        })
+}
+
+/// Where `speaker` talks, in milliseconds into the recording `clock` maps to.
+/// Neighbouring words are merged, and the total is capped.
+pub fn speech_ranges(
+    words: &[Word2],
+    speaker: &SpeakerIdentity,
+    clock: &RecordingClock,
+) -> Vec<(u64, u64)> {
+    let mut ranges: Vec<(u64, u64)> = Vec::new();
+    let mut total_ms = 0;
+
+    for word in words.iter().filter(|w| w.speaker.as_ref() == Some(speaker)) {
+        let (Some(start), Some(end)) = (word.start_ms, word.end_ms) else {
+            continue;
+        };
+        let (start, end) = (clock.offset_ms(start), clock.offset_ms(end));
+        if end <= start {
+            continue;
+        }
+        if total_ms >= MAX_SPEECH_MS {
+            break;
+        }
+
+        let left_ms = MAX_SPEECH_MS - total_ms;
+        match ranges.last_mut() {
+            Some(last) if start <= last.1 + MERGE_GAP_MS => {
+                let end = end.min(last.1 + left_ms);
+                total_ms += end.saturating_sub(last.1);
+                last.1 = last.1.max(end);
+            }
+            _ => {
+                let end = end.min(start + left_ms);
+                total_ms += end - start;
+                ranges.push((start, end));
+            }
+        }
+    }
+
+    ranges
+}
+
+/// `None` when the speaker doesn't talk long enough in the recording.
+pub fn speaker_embedding(
+    path: &Path,
+    words: &[Word2],
+    speaker: &SpeakerIdentity,
+    clock: &RecordingClock,
+) -> Result<Option<Vec<f32>>, crate::Error> {
+    let ranges = speech_ranges(words, speaker, clock);
+    let speech_ms: u64 = ranges.iter().map(|(start, end)| end - start).sum();
+    if speech_ms < MIN_SPEECH_MS {
+        return Ok(None);
+    }
+
+    let mut samples = Vec::with_capacity((speech_ms * SAMPLE_RATE / 1000) as usize);
+    for (start, end) in ranges {
+        samples.extend(crate::retranscribe::read_range(path, start, end)?);
+    }
+    embed(&samples).map(Some)
+}
+
+/// Speakers the transcriber told apart but nobody has named yet.
+pub fn unassigned_speakers(words: &[Word2]) -> Vec<SpeakerIdentity> {
+    let mut speakers = Vec::new();
+    for word in words {
+        if let Some(speaker @ SpeakerIdentity::Unassigned { .. }) = &word.speaker {
+            if !speakers.contains(speaker) {
+                speakers.push(speaker.clone());
+            }
+        }
+    }
+    speakers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(start_ms: u64, end_ms: u64, index: u8) -> Word2 {
+        Word2 {
+            text: "word".to_string(),
+            speaker: Some(SpeakerIdentity::Unassigned { index }),
+            confidence: None,
+            start_ms: Some(start_ms),
+            end_ms: Some(end_ms),
+        }
+    }
+
+    fn profile(name: &str, embedding: Vec<f32>) -> SpeakerProfile {
+        SpeakerProfile {
+            id: name.to_lowercase(),
+            user_id: "user".to_string(),
+            name: name.to_string(),
+            human_id: None,
+            embedding,
+            sample_count: 1,
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_speech_ranges() {
+        let words = vec![
+            word(10_000, 10_400, 0),
+            word(10_600, 11_000, 0),
+            word(11_000, 12_000, 1),
+            word(13_000, 13_500, 0),
+            word(15_000, 80_000, 0),
+        ];
+
+        let speaker = SpeakerIdentity::Unassigned { index: 0 };
+        let ranges = speech_ranges(&words, &speaker, &RecordingClock::starting_at(10_000));
+        assert_eq!(ranges, vec![(0, 1000), (3000, 3500), (5000, 63_500)]);
+
+        let total: u64 = ranges.iter().map(|(s, e)| e - s).sum();
+        assert_eq!(total, MAX_SPEECH_MS);
+    }
+
+    #[test]
+    fn test_best_match() {
+        let profiles = vec![
+            profile("Alice", vec![1.0, 0.0, 0.0]),
+            profile("Bob", vec![0.0, 1.0, 0.0]),
+            profile("Empty", vec![]),
+        ];
+        let speaker = SpeakerIdentity::Unassigned { index: 1 };
+
+        let suggestion = best_match(speaker.clone(), &[0.9, 0.2, 0.0], &profiles).unwrap();
+        assert_eq!(suggestion.name, "Alice");
+        assert_eq!(suggestion.speaker, speaker);
+        assert!(suggestion.similarity > 0.9);
+
+        assert!(best_match(speaker, &[0.0, 0.0, 1.0], &profiles).is_none());
+    }
+
+    #[test]
+    fn test_unassigned_speakers() {
+        let mut words = vec![word(0, 1, 1), word(1, 2, 0), word(2, 3, 1)];
+        words.push(Word2 {
+            speaker: Some(SpeakerIdentity::Assigned {
+                id: "alice".to_string(),
+                label: "Alice".to_string(),
+            }),
+            ..word(3, 4, 0)
+        });
+
+        assert_eq!(
+            unassigned_speakers(&words),
+            vec![
+                SpeakerIdentity::Unassigned { index: 1 },
+                SpeakerIdentity::Unassigned { index: 0 },
+            ]
+        );
+    }
+}
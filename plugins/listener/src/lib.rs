@@ -3,16 +3,37 @@ use tauri::Manager;
 use tokio::sync::Mutex;
 
 mod actors;
+mod alert;
+mod batch;
+mod captions;
+mod clock;
 mod commands;
+mod dictionary;
 mod error;
 mod events;
 mod ext;
+mod filter;
 pub mod fsm;
+mod i18n;
+mod identify;
 mod manager;
-
+mod pipeline;
+mod playback;
+mod recovery;
+mod retranscribe;
+mod stats;
+mod text;
+mod timeline;
+mod translate;
+mod wake;
+
+pub use alert::LengthAlert;
 pub use error::*;
 pub use events::*;
 pub use ext::*;
+pub use identify::SpeakerSuggestion;
+pub use playback::AudioFrame;
+pub use recovery::InterruptedSession;
 
 use crate::actors::{SessionArgs, SessionMsg, SessionSupervisor};
 
@@ -22,6 +43,8 @@ pub type SharedState = Mutex<State>;
 
 pub struct State {
     supervisor: Option<ActorRef<SessionMsg>>,
+    interrupted: Option<InterruptedSession>,
+    playback: Option<tokio_util::sync::CancellationToken>,
 }
 
 impl State {
@@ -35,6 +58,15 @@ impl State {
             fsm::State::Inactive {}
         }
     }
+
+    pub async fn get_session_id(&self) -> Option<String> {
+        match &self.supervisor {
+            Some(supervisor) => ractor::call_t!(supervisor, SessionMsg::GetSessionId, 100)
+                .ok()
+                .flatten(),
+            None => None,
+        }
+    }
 }
 
 fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
@@ -54,9 +86,26 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::set_mic_muted::<tauri::Wry>,
             commands::get_speaker_muted::<tauri::Wry>,
             commands::set_speaker_muted::<tauri::Wry>,
+            commands::mark_highlight::<tauri::Wry>,
             commands::start_session::<tauri::Wry>,
             commands::stop_session::<tauri::Wry>,
+            commands::pause_session::<tauri::Wry>,
+            commands::resume_session::<tauri::Wry>,
             commands::get_state::<tauri::Wry>,
+            commands::get_interrupted_session::<tauri::Wry>,
+            commands::resolve_interrupted_session::<tauri::Wry>,
+            commands::retranscribe_range::<tauri::Wry>,
+            commands::word_to_offset::<tauri::Wry>,
+            commands::offset_to_word::<tauri::Wry>,
+            commands::stream_audio::<tauri::Wry>,
+            commands::stop_audio_stream::<tauri::Wry>,
+            commands::assign_speaker::<tauri::Wry>,
+            commands::suggest_speakers::<tauri::Wry>,
+            commands::refresh_wake_listener::<tauri::Wry>,
+            commands::retranscribe_sessions::<tauri::Wry>,
+            commands::get_retranscription::<tauri::Wry>,
+            commands::apply_retranscription::<tauri::Wry>,
+            commands::discard_retranscription::<tauri::Wry>,
         ])
         .events(tauri_specta::collect_events![SessionEvent])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
@@ -70,10 +119,64 @@ pub fn init() -> tauri::plugin::TauriPlugin<tauri::Wry> {
         .setup(move |app, _api| {
             specta_builder.mount_events(app);
 
-            let state: SharedState = Mutex::new(State { supervisor: None });
+            let state: SharedState = Mutex::new(State {
+                supervisor: None,
+                // Read before any session can start and overwrite it.
+                interrupted: recovery::load(app),
+                playback: None,
+            });
             app.manage(state);
+            app.manage(wake::WakeState::default());
+            app.manage(captions::LiveCaptions::default());
 
             let app_handle = app.app_handle().clone();
+            batch::listen(&app_handle);
+            pipeline::listen(&app_handle);
+
+            {
+                use tauri_specta::Event;
+
+                let app = app_handle.clone();
+                tauri_plugin_tray::TrayEvent::listen_any(&app_handle, move |event| {
+                    let app = app.clone();
+                    tokio::spawn(async move {
+                        match event.payload {
+                            tauri_plugin_tray::TrayEvent::StopRecording {} => {
+                                app.stop_session().await
+                            }
+                            tauri_plugin_tray::TrayEvent::PauseRecording {} => {
+                                app.pause_session().await
+                            }
+                            tauri_plugin_tray::TrayEvent::ResumeRecording {} => {
+                                app.resume_session().await
+                            }
+                            tauri_plugin_tray::TrayEvent::SetMicMuted { value } => {
+                                app.set_mic_muted(value).await
+                            }
+                            // Handled by the app, which stops the session itself.
+                            tauri_plugin_tray::TrayEvent::SwitchProfile { .. } => {}
+                        }
+                    });
+                });
+            }
+
+            {
+                let app = app_handle.clone();
+                hypr_intercept::on_power_event(move |event| {
+                    if event != hypr_intercept::PowerEvent::Sleep {
+                        return;
+                    }
+
+                    // Capture stops while asleep, so keep the recording from filling with
+                    // silence until the user resumes it.
+                    let app = app.clone();
+                    tauri::async_runtime::spawn(async move {
+                        if matches!(app.get_state().await, fsm::State::RunningActive) {
+                            app.pause_session().await;
+                        }
+                    });
+                });
+            }
 
             tokio::spawn(async move {
                 match Actor::spawn(
@@ -0,0 +1,115 @@
+use hypr_db_user::PipelineStage;
+use tauri_plugin_task::{PipelineStageEvent, PipelineStageStatus, TaskEvent, TaskPluginExt};
+use tauri_specta::Event;
+
+/// Runs the pipeline stages that need the recording; the app runs the rest.
+pub fn listen<R: tauri::Runtime>(app: &tauri::AppHandle<R>) {
+    let handle = app.clone();
+    TaskEvent::listen_any(app, move |event| {
+        let TaskEvent::Stage(stage) = event.payload else {
+            return;
+        };
+        if !matches!(stage.status, PipelineStageStatus::Started)
+            || !matches!(
+                stage.stage,
+                PipelineStage::Transcribe | PipelineStage::Diarize
+            )
+        {
+            return;
+        }
+
+        let app = handle.clone();
+        tauri::async_runtime::spawn(async move { run(&app, stage).await });
+    });
+}
+
+async fn run<R: tauri::Runtime>(app: &tauri::AppHandle<R>, event: PipelineStageEvent) {
+    let result = match event.stage {
+        PipelineStage::Transcribe => transcribe(app, &event.task_id, &event.session_id).await,
+        _ => diarize(app, &event.session_id).await.map(|_| true),
+    };
+
+    let reported = match result {
+        Ok(true) => {
+            app.complete_pipeline_stage(event.task_id, event.stage)
+                .await
+        }
+        Ok(false) => Ok(()),
+        Err(e) => {
+            tracing::error!("pipeline_stage_failed: {} {}", event.stage, e);
+            app.fail_pipeline_stage(event.task_id, event.stage, e.to_string())
+                .await
+        }
+    };
+    if let Err(e) = reported {
+        tracing::error!("pipeline_stage_report_failed: {}", e);
+    }
+}
+
+/// Transcribes the recording with the on-device model when nothing was transcribed live.
+/// `false` when the run was paused or cancelled first.
+async fn transcribe<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    task_id: &str,
+    session_id: &str,
+) -> Result<bool, crate::Error> {
+    use tauri_plugin_db::DatabasePluginExt;
+    use tauri_plugin_local_stt::LocalSttPluginExt;
+
+    let session = match crate::ext::saved_recording(app, session_id).await {
+        Ok((session, _)) => session,
+        // Nothing to transcribe, so nothing the later stages could use either.
+        Err(crate::Error::NoRecording) => return Ok(true),
+        Err(e) => return Err(e),
+    };
+    if !session.words.is_empty() {
+        return Ok(true);
+    }
+
+    let model = app.get_local_model()?;
+    if !app.is_model_downloaded(&model).await? {
+        return Err(crate::Error::ModelNotDownloaded);
+    }
+    let Some(words) = crate::batch::transcribe_recording(app, task_id, session_id, &model).await?
+    else {
+        return Ok(false);
+    };
+
+    let mut session = app
+        .db_get_session(session_id)
+        .await?
+        .ok_or(crate::Error::NoneSession)?;
+    session.words = words;
+    app.db_upsert_session(session).await?;
+
+    Ok(true)
+}
+
+/// Names the speakers whose voice is close enough to a saved speaker profile.
+async fn diarize<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    session_id: &str,
+) -> Result<(), crate::Error> {
+    use crate::ListenerPluginExt;
+
+    let suggestions = match app.suggest_speakers(session_id).await {
+        Ok(suggestions) => suggestions,
+        Err(crate::Error::NoRecording) => return Ok(()),
+        Err(e) => return Err(e),
+    };
+
+    for suggestion in suggestions {
+        let owhisper_interface::SpeakerIdentity::Unassigned { index } = suggestion.speaker else {
+            continue;
+        };
+        app.assign_speaker(
+            session_id,
+            index,
+            suggestion.name,
+            Some(suggestion.profile_id),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use std::{fs::File, io::BufReader, path::Path};
+
+use owhisper_interface::Word2;
+
+use crate::clock::RecordingClock;
+
+const SAMPLE_RATE: u32 = 16000;
+const FRAME_MS: u64 = 100;
+
+/// A slice of a recording, `offset_ms` from its start.
+#[derive(Debug, Clone, serde::Serialize, specta::Type)]
+pub struct AudioFrame {
+    pub offset_ms: u64,
+    pub sample_rate: u32,
+    pub samples: Vec<f32>,
+}
+
+/// Where the word at `index` starts, in milliseconds into the recording.
+pub fn word_offset_ms(words: &[Word2], index: usize, clock: &RecordingClock) -> Option<u64> {
+    let start_ms = words.get(index)?.start_ms?;
+    Some(clock.offset_ms(start_ms))
+}
+
+/// The word being spoken `offset_ms` into the recording. Between two words this is the next
+/// one, and past the last word there is none. Words without timings are skipped.
+pub fn word_at_offset_ms(words: &[Word2], offset_ms: u64, clock: &RecordingClock) -> Option<usize> {
+    let at_ms = clock.time_ms(offset_ms);
+    words
+        .iter()
+        .position(|w| w.start_ms.is_some() && w.end_ms.is_some_and(|end| end > at_ms))
+}
+
+/// Decodes a recording in fixed-size frames, starting `start_ms` from its start.
+pub struct AudioFrames {
+    reader: hound::WavReader<BufReader<File>>,
+    position: u32,
+    end: u32,
+}
+
+impl AudioFrames {
+    pub fn open(path: &Path, start_ms: u64, end_ms: Option<u64>) -> Result<Self, crate::Error> {
+        let mut reader = hound::WavReader::open(path)?;
+        let spec = reader.spec();
+        if spec.sample_rate != SAMPLE_RATE || spec.channels != 1 {
+            return Err(crate::Error::UnsupportedRecording);
+        }
+
+        let len = reader.len();
+        let to_sample = |ms: u64| (ms * SAMPLE_RATE as u64 / 1000).min(len as u64) as u32;
+        let (start, end) = (to_sample(start_ms), end_ms.map_or(len, to_sample));
+        if end < start {
+            return Err(crate::Error::InvalidRange);
+        }
+
+        reader.seek(start)?;
+        Ok(Self {
+            reader,
+            position: start,
+            end,
+        })
+    }
+}
+
+impl Iterator for AudioFrames {
+    type Item = Result<AudioFrame, crate::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.end {
+            return None;
+        }
+
+        let frame_len = (SAMPLE_RATE as u64 * FRAME_MS / 1000) as u32;
+        let take = frame_len.min(self.end - self.position);
+        let offset_ms = self.position as u64 * 1000 / SAMPLE_RATE as u64;
+
+        let samples = match self
+            .reader
+            .samples::<f32>()
+            .take(take as usize)
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(samples) => samples,
+            Err(e) => {
+                self.position = self.end;
+                return Some(Err(e.into()));
+            }
+        };
+        self.position += take;
+
+        Some(Ok(AudioFrame {
+            offset_ms,
+            sample_rate: SAMPLE_RATE,
+            samples,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, start_ms: Option<u64>, end_ms: Option<u64>) -> Word2 {
+        Word2 {
+            text: text.to_string(),
+            speaker: None,
+            confidence: None,
+            start_ms,
+            end_ms,
+        }
+    }
+
+    #[test]
+    fn test_word_offsets() {
+        let clock = RecordingClock::starting_at(1_000_000);
+        let words = vec![
+            word("hello", Some(1_000_500), Some(1_000_900)),
+            word("untimed", None, None),
+            word("world", Some(1_001_200), Some(1_001_600)),
+        ];
+
+        assert_eq!(word_offset_ms(&words, 0, &clock), Some(500));
+        assert_eq!(word_offset_ms(&words, 1, &clock), None);
+        assert_eq!(word_offset_ms(&words, 2, &clock), Some(1200));
+        assert_eq!(word_offset_ms(&words, 3, &clock), None);
+
+        assert_eq!(word_at_offset_ms(&words, 0, &clock), Some(0));
+        assert_eq!(word_at_offset_ms(&words, 700, &clock), Some(0));
+        assert_eq!(word_at_offset_ms(&words, 1000, &clock), Some(2));
+        assert_eq!(word_at_offset_ms(&words, 1600, &clock), None);
+    }
+
+    #[test]
+    fn test_audio_frames() {
+        let path = std::env::temp_dir().join(format!("{}.wav", uuid::Uuid::new_v4()));
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: SAMPLE_RATE,
+            bits_per_sample: 32,
+            sample_format: hound::SampleFormat::Float,
+        };
+
+        // Half a second, each sample holding its own index.
+        let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+        for i in 0..8000 {
+            writer.write_sample(i as f32).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let frames = AudioFrames::open(&path, 250, None)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let offsets: Vec<_> = frames.iter().map(|f| f.offset_ms).collect();
+        assert_eq!(offsets, [250, 350, 450]);
+        assert_eq!(frames[0].samples[0], 4000.0);
+        assert_eq!(frames[0].samples.len(), 1600);
+        assert_eq!(frames[2].samples.len(), 800);
+
+        let frames = AudioFrames::open(&path, 0, Some(150))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[1].samples.len(), 800);
+
+        assert!(matches!(
+            AudioFrames::open(&path, 300, Some(100)),
+            Err(crate::Error::InvalidRange)
+        ));
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
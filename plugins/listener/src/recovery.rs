@@ -0,0 +1,187 @@
+use std::{
+    io::{Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tauri::Manager;
+
+const CHECKPOINT_FILE: &str = "listener_checkpoint.json";
+pub const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// A recording that never reached `stop_session`, left behind by a crash or forced quit.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct InterruptedSession {
+    pub session_id: String,
+    pub started_at_ms: u64,
+    /// Last time the listener was known to be alive; used as the recording end.
+    pub checkpoint_at_ms: u64,
+}
+
+impl InterruptedSession {
+    pub fn new(session_id: impl Into<String>, started_at_ms: u64) -> Self {
+        Self {
+            session_id: session_id.into(),
+            started_at_ms,
+            checkpoint_at_ms: now_ms(),
+        }
+    }
+}
+
+pub fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
+fn checkpoint_path<R: tauri::Runtime>(app: &impl Manager<R>) -> Result<PathBuf, crate::Error> {
+    let dir = hypr_data_dir::resolve(|| app.path().app_data_dir())?;
+    Ok(dir.join(CHECKPOINT_FILE))
+}
+
+pub fn save<R: tauri::Runtime>(
+    app: &impl Manager<R>,
+    checkpoint: &InterruptedSession,
+) -> Result<(), crate::Error> {
+    let path = checkpoint_path(app)?;
+    let tmp = path.with_extension("json.tmp");
+
+    // Written through a temp file so a crash mid-write can't leave a truncated checkpoint.
+    std::fs::write(&tmp, serde_json::to_vec(checkpoint).unwrap())?;
+    std::fs::rename(tmp, path)?;
+    Ok(())
+}
+
+pub fn load<R: tauri::Runtime>(app: &impl Manager<R>) -> Option<InterruptedSession> {
+    let content = std::fs::read(checkpoint_path(app).ok()?).ok()?;
+    serde_json::from_slice(&content).ok()
+}
+
+pub fn clear<R: tauri::Runtime>(app: &impl Manager<R>) -> Result<(), crate::Error> {
+    match std::fs::remove_file(checkpoint_path(app)?) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Recordings of `session_id`, including the per-channel ones written in debug builds.
+pub fn recording_paths(app_dir: &Path, session_id: &str) -> Vec<PathBuf> {
+    ["audio.wav", "audio_mic.wav", "audio_speaker.wav"]
+        .iter()
+        .map(|name| app_dir.join(session_id).join(name))
+        .filter(|path| path.exists())
+        .collect()
+}
+
+/// Rewrites the RIFF and `data` chunk sizes from the actual file length.
+///
+/// The recorder only updates them on `finalize`, so after a crash they still describe an
+/// empty file and both playback and `WavWriter::append` would drop everything recorded.
+pub fn repair_wav(path: &Path) -> std::io::Result<()> {
+    let mut file = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(path)?;
+    let len = file.metadata()?.len();
+
+    let mut riff = [0u8; 12];
+    file.read_exact(&mut riff)?;
+    if &riff[0..4] != b"RIFF" || &riff[8..12] != b"WAVE" {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "not a wav file",
+        ));
+    }
+
+    let mut block_align = 1u64;
+    let mut pos = 12u64;
+
+    while pos + 8 <= len {
+        let mut header = [0u8; 8];
+        file.seek(SeekFrom::Start(pos))?;
+        file.read_exact(&mut header)?;
+        let size = u32::from_le_bytes(header[4..8].try_into().unwrap()) as u64;
+
+        match &header[0..4] {
+            b"fmt " => {
+                let mut fmt = [0u8; 14];
+                file.read_exact(&mut fmt)?;
+                block_align = u16::from_le_bytes([fmt[12], fmt[13]]).max(1) as u64;
+            }
+            b"data" => {
+                let data_len = (len - pos - 8) / block_align * block_align;
+
+                file.seek(SeekFrom::Start(pos + 4))?;
+                file.write_all(&(data_len as u32).to_le_bytes())?;
+                file.set_len(pos + 8 + data_len)?;
+
+                file.seek(SeekFrom::Start(4))?;
+                file.write_all(&((pos + data_len) as u32).to_le_bytes())?;
+                return file.flush();
+            }
+            _ => {}
+        }
+
+        // Chunks are padded to an even size.
+        pos += 8 + size + (size % 2);
+    }
+
+    Err(std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        "missing data chunk",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repair_wav() {
+        let path = std::env::temp_dir().join(format!("{}.wav", uuid::Uuid::new_v4()));
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: 16000,
+            bits_per_sample: 32,
+            sample_format: hound::SampleFormat::Float,
+        };
+
+        let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+        writer.write_sample(0.5f32).unwrap();
+        writer.finalize().unwrap();
+
+        // What a crash leaves behind: samples on disk the header doesn't know about,
+        // plus half a sample from an interrupted write.
+        {
+            let mut file = std::fs::OpenOptions::new()
+                .append(true)
+                .open(&path)
+                .unwrap();
+            for _ in 0..99 {
+                file.write_all(&0.25f32.to_le_bytes()).unwrap();
+            }
+            file.write_all(&[0, 0]).unwrap();
+        }
+
+        repair_wav(&path).unwrap();
+
+        let mut reader = hound::WavReader::open(&path).unwrap();
+        assert_eq!(reader.len(), 100);
+        let samples: Vec<f32> = reader.samples::<f32>().map(|s| s.unwrap()).collect();
+        assert_eq!(samples[0], 0.5);
+        assert_eq!(samples[99], 0.25);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_repair_wav_rejects_other_files() {
+        let path = std::env::temp_dir().join(format!("{}.wav", uuid::Uuid::new_v4()));
+        std::fs::write(&path, b"not a wav file at all").unwrap();
+
+        assert!(repair_wav(&path).is_err());
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
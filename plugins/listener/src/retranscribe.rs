@@ -0,0 +1,188 @@
+use std::path::Path;
+
+use owhisper_interface::Word2;
+
+const SAMPLE_RATE: u32 = 16000;
+
+/// Reads `[start_ms, end_ms)` of a recording, in milliseconds from its start.
+pub fn read_range(path: &Path, start_ms: u64, end_ms: u64) -> Result<Vec<f32>, crate::Error> {
+    let mut reader = hound::WavReader::open(path)?;
+    let spec = reader.spec();
+    if spec.sample_rate != SAMPLE_RATE || spec.channels != 1 {
+        return Err(crate::Error::UnsupportedRecording);
+    }
+
+    let to_sample = |ms: u64| (ms * SAMPLE_RATE as u64 / 1000).min(reader.len() as u64) as u32;
+    let (start, end) = (to_sample(start_ms), to_sample(end_ms));
+
+    reader.seek(start)?;
+    reader
+        .samples::<f32>()
+        .take(end.saturating_sub(start) as usize)
+        .collect::<Result<_, _>>()
+        .map_err(Into::into)
+}
+
+pub fn duration_ms(path: &Path) -> Result<u64, crate::Error> {
+    let reader = hound::WavReader::open(path)?;
+    Ok(reader.duration() as u64 * 1000 / reader.spec().sample_rate as u64)
+}
+
+/// Splits `[0, duration_ms)` into stretches of about `target_ms`, each cut in the widest pause
+/// near its end so words aren't split between two. `words` are `(start, end)` offsets into
+/// the recording, in order.
+pub fn chunk_ranges(words: &[(u64, u64)], duration_ms: u64, target_ms: u64) -> Vec<(u64, u64)> {
+    let mut pauses = Vec::new();
+    let mut last_end = 0;
+    for &(start, end) in words {
+        if start > last_end {
+            pauses.push((last_end, start));
+        }
+        last_end = last_end.max(end);
+    }
+    if duration_ms > last_end {
+        pauses.push((last_end, duration_ms));
+    }
+
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    while start < duration_ms {
+        if start + target_ms >= duration_ms {
+            ranges.push((start, duration_ms));
+            break;
+        }
+
+        let (lo, hi) = (start + target_ms * 3 / 4, start + target_ms * 5 / 4);
+        let cut = pauses
+            .iter()
+            .map(|&(s, e)| (s.max(lo), e.min(hi)))
+            .filter(|(s, e)| s < e)
+            .max_by_key(|(s, e)| e - s)
+            .map_or(start + target_ms, |(s, e)| (s + e) / 2);
+
+        ranges.push((start, cut));
+        start = cut;
+    }
+
+    ranges
+}
+
+/// Replaces the words overlapping `[start_ms, end_ms]` with `replacement`.
+///
+/// Each new word takes the speaker of the closest replaced word, so re-transcribing a
+/// stretch where two people talk keeps them apart.
+pub fn splice_words(
+    words: Vec<Word2>,
+    start_ms: u64,
+    end_ms: u64,
+    replacement: Vec<Word2>,
+) -> Vec<Word2> {
+    let in_range = |w: &Word2| match (w.start_ms, w.end_ms) {
+        (Some(s), Some(e)) => s < end_ms && e > start_ms,
+        _ => false,
+    };
+
+    let insert_at = words
+        .iter()
+        .position(|w| in_range(w) || w.start_ms.is_some_and(|s| s >= end_ms))
+        .unwrap_or(words.len());
+    let (removed, kept): (Vec<_>, Vec<_>) = words.into_iter().partition(in_range);
+
+    let replacement = replacement.into_iter().map(|mut word| {
+        let mid = word.start_ms.zip(word.end_ms).map(|(s, e)| (s + e) / 2);
+        word.speaker = mid
+            .and_then(|mid| {
+                removed
+                    .iter()
+                    .filter(|w| w.speaker.is_some())
+                    .min_by_key(|w| {
+                        let (s, e) = (w.start_ms.unwrap(), w.end_ms.unwrap());
+                        if mid < s {
+                            s - mid
+                        } else {
+                            mid.saturating_sub(e)
+                        }
+                    })
+            })
+            .and_then(|w| w.speaker.clone());
+        word
+    });
+
+    let mut result = kept;
+    result.splice(insert_at..insert_at, replacement);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use owhisper_interface::SpeakerIdentity;
+
+    fn word(text: &str, start_ms: u64, end_ms: u64, speaker: Option<u8>) -> Word2 {
+        Word2 {
+            text: text.to_string(),
+            speaker: speaker.map(|index| SpeakerIdentity::Unassigned { index }),
+            confidence: None,
+            start_ms: Some(start_ms),
+            end_ms: Some(end_ms),
+        }
+    }
+
+    #[test]
+    fn test_splice_words() {
+        let words = vec![
+            word("before", 0, 900, Some(0)),
+            word("garbled", 1000, 1500, Some(0)),
+            word("mess", 1600, 2000, Some(1)),
+            word("after", 2100, 2500, Some(1)),
+        ];
+        let replacement = vec![
+            word("clear", 1000, 1400, None),
+            word("speech", 1700, 2000, None),
+        ];
+
+        let result = splice_words(words, 1000, 2000, replacement);
+        let texts: Vec<_> = result.iter().map(|w| w.text.as_str()).collect();
+        assert_eq!(texts, ["before", "clear", "speech", "after"]);
+        assert_eq!(
+            result[1].speaker,
+            Some(SpeakerIdentity::Unassigned { index: 0 })
+        );
+        assert_eq!(
+            result[2].speaker,
+            Some(SpeakerIdentity::Unassigned { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_chunk_ranges() {
+        let words = [(0, 1_000), (1_200, 29_000), (31_000, 40_000)];
+        assert_eq!(
+            chunk_ranges(&words, 50_000, 30_000),
+            vec![(0, 30_000), (30_000, 50_000)]
+        );
+
+        // Without a pause anywhere near, the cut falls at the target, word or not.
+        assert_eq!(
+            chunk_ranges(&[(20_000, 40_000)], 45_000, 30_000),
+            vec![(0, 30_000), (30_000, 45_000)]
+        );
+
+        assert_eq!(
+            chunk_ranges(&[], 70_000, 30_000),
+            vec![(0, 30_000), (30_000, 60_000), (60_000, 70_000)]
+        );
+        assert!(chunk_ranges(&[], 0, 30_000).is_empty());
+    }
+
+    #[test]
+    fn test_splice_words_into_silence() {
+        let words = vec![word("a", 0, 500, Some(0)), word("b", 3000, 3500, Some(0))];
+        let replacement = vec![word("found", 1000, 1500, None)];
+
+        let result = splice_words(words, 1000, 2000, replacement);
+        let texts: Vec<_> = result.iter().map(|w| w.text.as_str()).collect();
+        assert_eq!(texts, ["a", "found", "b"]);
+        assert_eq!(result[1].speaker, None);
+    }
+}
@@ -0,0 +1,113 @@
+use hypr_db_user::{SessionStats, SpeakerStats};
+use owhisper_interface::{SpeakerIdentity, Word2};
+
+struct Turn {
+    speaker: Option<SpeakerIdentity>,
+    start_ms: u64,
+    end_ms: u64,
+    words: u64,
+}
+
+/// Talk time counts each turn from its first word to its last, so pauses between turns
+/// are left out. A turn that starts before the previous one ended is an interruption.
+pub fn compute(words: &[Word2], duration_ms: u64, silence_ratio: Option<f64>) -> SessionStats {
+    let mut timed: Vec<(&Word2, u64, u64)> = words
+        .iter()
+        .filter_map(|w| Some((w, w.start_ms?, w.end_ms?)))
+        .collect();
+    timed.sort_by_key(|(_, start, _)| *start);
+
+    let mut turns: Vec<Turn> = Vec::new();
+    for (word, start_ms, end_ms) in timed {
+        match turns.last_mut() {
+            Some(turn) if turn.speaker == word.speaker => {
+                turn.end_ms = turn.end_ms.max(end_ms);
+                turn.words += 1;
+            }
+            _ => turns.push(Turn {
+                speaker: word.speaker.clone(),
+                start_ms,
+                end_ms,
+                words: 1,
+            }),
+        }
+    }
+
+    let mut speakers: Vec<SpeakerStats> = Vec::new();
+    let mut interruptions = 0;
+
+    for (i, turn) in turns.iter().enumerate() {
+        let interrupted = i > 0 && turn.start_ms < turns[i - 1].end_ms;
+        interruptions += interrupted as u64;
+
+        let index = match speakers.iter().position(|s| s.speaker == turn.speaker) {
+            Some(index) => index,
+            None => {
+                speakers.push(SpeakerStats {
+                    speaker: turn.speaker.clone(),
+                    talk_time_ms: 0,
+                    word_count: 0,
+                    interruptions: 0,
+                });
+                speakers.len() - 1
+            }
+        };
+
+        let stats = &mut speakers[index];
+        stats.talk_time_ms += turn.end_ms - turn.start_ms;
+        stats.word_count += turn.words;
+        stats.interruptions += interrupted as u64;
+    }
+
+    speakers.sort_by(|a, b| b.talk_time_ms.cmp(&a.talk_time_ms));
+
+    SessionStats {
+        duration_ms,
+        word_count: words.len() as u64,
+        interruptions,
+        silence_ratio,
+        speakers,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(start_ms: u64, end_ms: u64, speaker: u8) -> Word2 {
+        Word2 {
+            text: "word".to_string(),
+            speaker: Some(SpeakerIdentity::Unassigned { index: speaker }),
+            confidence: None,
+            start_ms: Some(start_ms),
+            end_ms: Some(end_ms),
+        }
+    }
+
+    #[test]
+    fn test_compute() {
+        let words = vec![
+            word(0, 400, 0),
+            word(500, 1200, 0),
+            word(1500, 2000, 1),
+            word(2100, 3000, 1),
+            // Cuts speaker 1 off mid-word.
+            word(2800, 3200, 0),
+        ];
+
+        let stats = compute(&words, 5000, Some(0.4));
+        assert_eq!(stats.word_count, 5);
+        assert_eq!(stats.interruptions, 1);
+        assert_eq!(stats.silence_ratio, Some(0.4));
+
+        assert_eq!(stats.speakers.len(), 2);
+        assert_eq!(
+            stats.speakers[0].speaker,
+            Some(SpeakerIdentity::Unassigned { index: 0 })
+        );
+        assert_eq!(stats.speakers[0].talk_time_ms, 1600);
+        assert_eq!(stats.speakers[0].word_count, 3);
+        assert_eq!(stats.speakers[0].interruptions, 1);
+        assert_eq!(stats.speakers[1].talk_time_ms, 1500);
+    }
+}
@@ -0,0 +1,39 @@
+/// Lowercase, without the punctuation around it, so "Hyprnote," equals "hyprnote".
+pub fn normalize_word(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+/// Lowercase words without punctuation, so "Start taking notes." equals "start taking notes".
+pub fn normalize_text(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            if c.is_alphanumeric() {
+                c.to_lowercase().next().unwrap_or(c)
+            } else {
+                ' '
+            }
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The punctuation a word ends with, so it can be kept when the word is replaced.
+pub fn trailing_punctuation(word: &str) -> &str {
+    &word[word.trim_end_matches(|c: char| !c.is_alphanumeric()).len()..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize_word("\"Hyprnote,\""), "hyprnote");
+        assert_eq!(normalize_text("Start  taking-notes."), "start taking notes");
+        assert_eq!(trailing_punctuation("um..."), "...");
+        assert_eq!(trailing_punctuation("um"), "");
+    }
+}
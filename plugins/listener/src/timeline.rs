@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use hypr_db_user::{TimelineEvent, TimelineEventKind};
+use tauri_plugin_db::DatabasePluginExt;
+use tauri_specta::Event;
+
+use crate::SessionEvent;
+
+/// Non-speech events of the running session, saved as they happen so the
+/// transcript view can interleave them with words.
+pub struct Timeline {
+    session_id: String,
+    open: HashMap<TimelineEventKind, TimelineEvent>,
+}
+
+impl Timeline {
+    pub fn new(session_id: impl Into<String>) -> Self {
+        Self {
+            session_id: session_id.into(),
+            open: HashMap::new(),
+        }
+    }
+
+    pub async fn mark(
+        &self,
+        app: &tauri::AppHandle,
+        kind: TimelineEventKind,
+        label: Option<String>,
+    ) -> Option<TimelineEvent> {
+        let event = TimelineEvent {
+            id: uuid::Uuid::new_v4().to_string(),
+            session_id: self.session_id.clone(),
+            kind,
+            start_ms: now_ms(),
+            end_ms: None,
+            label,
+        };
+        save(app, event).await
+    }
+
+    /// Opens a period of `kind` when `active`, and ends the open one otherwise.
+    pub async fn set_active(
+        &mut self,
+        app: &tauri::AppHandle,
+        kind: TimelineEventKind,
+        active: bool,
+    ) {
+        if active {
+            if self.open.contains_key(&kind) {
+                return;
+            }
+            if let Some(event) = self.mark(app, kind.clone(), None).await {
+                self.open.insert(kind, event);
+            }
+        } else if let Some(mut event) = self.open.remove(&kind) {
+            event.end_ms = Some(now_ms());
+            save(app, event).await;
+        }
+    }
+
+    pub async fn close(&mut self, app: &tauri::AppHandle) {
+        self.open.clear();
+        if let Err(e) = app
+            .db_close_timeline_events(&self.session_id, now_ms())
+            .await
+        {
+            tracing::error!("failed_to_close_timeline: {}", e);
+        }
+    }
+}
+
+async fn save(app: &tauri::AppHandle, event: TimelineEvent) -> Option<TimelineEvent> {
+    match app.db_upsert_timeline_event(event).await {
+        Ok(event) => {
+            let _ = SessionEvent::TimelineEvent {
+                event: event.clone(),
+            }
+            .emit(app);
+            Some(event)
+        }
+        Err(e) => {
+            tracing::error!("failed_to_save_timeline_event: {}", e);
+            None
+        }
+    }
+}
+
+fn now_ms() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
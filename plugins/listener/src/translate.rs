@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+use owhisper_interface::Word2;
+use tauri_plugin_connector::{ConnectorPluginExt, LlmTask};
+use tauri_specta::Event;
+
+use crate::SessionEvent;
+
+/// Long monologues without punctuation are still translated in pieces of this size.
+const MAX_SEGMENT_WORDS: usize = 40;
+
+/// Groups final words into sentences, per channel, so each translation has enough context.
+#[derive(Debug, Default)]
+pub struct Segmenter {
+    pending: HashMap<usize, Vec<Word2>>,
+}
+
+impl Segmenter {
+    /// Buffers `words` and returns the segments of `channel` they complete.
+    pub fn push(&mut self, channel: usize, words: Vec<Word2>) -> Vec<Vec<Word2>> {
+        let pending = self.pending.entry(channel).or_default();
+        let mut segments = vec![];
+
+        for word in words {
+            let ends_sentence = word
+                .text
+                .trim_end()
+                .ends_with(['.', '?', '!', '。', '？', '！']);
+            pending.push(word);
+
+            if ends_sentence || pending.len() >= MAX_SEGMENT_WORDS {
+                segments.push(std::mem::take(pending));
+            }
+        }
+
+        segments
+    }
+
+    /// Whatever is left once the session stops.
+    pub fn flush(&mut self) -> Vec<(usize, Vec<Word2>)> {
+        let mut rest: Vec<_> = self
+            .pending
+            .drain()
+            .filter(|(_, words)| !words.is_empty())
+            .collect();
+        rest.sort_by_key(|(channel, _)| *channel);
+        rest
+    }
+}
+
+/// Translates finalized segments in the background, so a slow model never holds
+/// back the transcript itself.
+pub struct Translator {
+    tx: tokio::sync::mpsc::UnboundedSender<(usize, Vec<Word2>)>,
+}
+
+impl Translator {
+    pub fn spawn(app: tauri::AppHandle, language: hypr_language::Language) -> Self {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<(usize, Vec<Word2>)>();
+
+        tokio::spawn(async move {
+            let mut segmenter = Segmenter::default();
+
+            while let Some((channel, words)) = rx.recv().await {
+                for segment in segmenter.push(channel, words) {
+                    translate(&app, &language, channel, segment).await;
+                }
+            }
+
+            for (channel, segment) in segmenter.flush() {
+                translate(&app, &language, channel, segment).await;
+            }
+        });
+
+        Self { tx }
+    }
+
+    pub fn push(&self, channel: usize, words: Vec<Word2>) {
+        if !words.is_empty() {
+            let _ = self.tx.send((channel, words));
+        }
+    }
+}
+
+async fn translate(
+    app: &tauri::AppHandle,
+    language: &hypr_language::Language,
+    channel: usize,
+    segment: Vec<Word2>,
+) {
+    let source = segment
+        .iter()
+        .map(|w| w.text.trim())
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if source.is_empty() {
+        return;
+    }
+
+    let system = format!(
+        "Translate the user's message, a line from a meeting transcript, into {}. Reply with the translation only. If it is already in {}, repeat it unchanged.",
+        language.language_name(),
+        language.language_name(),
+    );
+
+    match app
+        .complete_for_task(LlmTask::Translate, system, source.clone())
+        .await
+    {
+        Ok(text) => {
+            let _ = SessionEvent::TranslatedSegment {
+                channel,
+                start_ms: segment.iter().find_map(|w| w.start_ms),
+                end_ms: segment.iter().rev().find_map(|w| w.end_ms),
+                language: language.iso639().code().to_string(),
+                source,
+                text,
+            }
+            .emit(app);
+        }
+        Err(e) => {
+            tracing::error!("failed_to_translate_segment: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(text: &str) -> Vec<Word2> {
+        text.split_whitespace()
+            .map(|w| Word2 {
+                text: w.to_string(),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    fn texts(segment: &[Word2]) -> String {
+        segment
+            .iter()
+            .map(|w| w.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_segments_end_at_sentences() {
+        let mut segmenter = Segmenter::default();
+
+        assert!(segmenter.push(0, words("so the launch")).is_empty());
+        assert_eq!(segmenter.push(1, words("I agree.")).len(), 1);
+
+        let segments = segmenter.push(0, words("is next week. Any questions? We"));
+        let segments: Vec<_> = segments.iter().map(|s| texts(s.as_slice())).collect();
+        assert_eq!(segments, ["so the launch is next week.", "Any questions?"]);
+
+        let rest = segmenter.flush();
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].0, 0);
+        assert_eq!(texts(&rest[0].1), "We");
+    }
+
+    #[test]
+    fn test_long_segments_are_split() {
+        let mut segmenter = Segmenter::default();
+        let text = ["word"; MAX_SEGMENT_WORDS + 5].join(" ");
+
+        let segments = segmenter.push(0, words(&text));
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].len(), MAX_SEGMENT_WORDS);
+        assert_eq!(segmenter.flush()[0].1.len(), 5);
+    }
+}
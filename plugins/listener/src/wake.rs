@@ -0,0 +1,225 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use futures_util::StreamExt;
+use hypr_audio::ResampledAsyncSource;
+use hypr_vad::VadExt;
+use hypr_whisper_local_model::WhisperModel;
+use tauri_plugin_local_stt::{LocalSttPluginExt, SupportedSttModel};
+use tauri_specta::Event;
+use tokio_util::sync::CancellationToken;
+
+use crate::text::normalize_text;
+use crate::SessionEvent;
+
+const SAMPLE_RATE: u32 = 16000;
+const REDEMPTION_TIME: Duration = Duration::from_millis(400);
+/// A wake phrase is a few words. Anything longer is conversation and isn't transcribed.
+const MAX_UTTERANCE_MS: usize = 4_000;
+const MIN_UTTERANCE_MS: usize = 300;
+/// Keeps one utterance from starting two notes while the first one is being set up.
+const COOLDOWN: Duration = Duration::from_secs(10);
+
+/// Smallest first; only a few words are transcribed at a time.
+const MODELS: [WhisperModel; 4] = [
+    WhisperModel::QuantizedTinyEn,
+    WhisperModel::QuantizedTiny,
+    WhisperModel::QuantizedBaseEn,
+    WhisperModel::QuantizedBase,
+];
+
+/// The running wake listener, kept apart from the session state so starting and stopping it
+/// never waits on the session supervisor.
+#[derive(Default)]
+pub struct WakeState(Mutex<Option<CancellationToken>>);
+
+impl WakeState {
+    fn replace(&self, token: Option<CancellationToken>) -> bool {
+        let previous = std::mem::replace(&mut *self.0.lock().unwrap(), token);
+        match previous {
+            Some(previous) => {
+                previous.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// The smallest downloaded model that can transcribe on device.
+pub async fn pick_model<R: tauri::Runtime>(app: &tauri::AppHandle<R>) -> Option<SupportedSttModel> {
+    for model in MODELS {
+        let model = SupportedSttModel::Whisper(model);
+        if app.is_model_downloaded(&model).await.unwrap_or(false) {
+            return Some(model);
+        }
+    }
+    None
+}
+
+pub fn start<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    phrase: String,
+    model: SupportedSttModel,
+    languages: Vec<hypr_language::Language>,
+) {
+    use tauri::Manager;
+
+    let token = CancellationToken::new();
+    app.state::<WakeState>().replace(Some(token.clone()));
+    indicate(app, Some(&phrase));
+
+    let app = app.clone();
+    tokio::spawn(async move {
+        if let Err(e) = listen(&app, &phrase, model, languages, &token).await {
+            tracing::error!("wake_listener_failed: {}", e);
+        }
+
+        // Only clear what this run set up; a newer one may have replaced it already.
+        if !token.is_cancelled() {
+            token.cancel();
+            if app.state::<WakeState>().0.lock().unwrap().take().is_some() {
+                indicate(&app, None);
+            }
+        }
+    });
+}
+
+pub fn stop<R: tauri::Runtime>(app: &tauri::AppHandle<R>) {
+    use tauri::Manager;
+
+    if app.state::<WakeState>().replace(None) {
+        indicate(app, None);
+    }
+}
+
+/// Whoever shows that the mic is in use picks this up, like the tray.
+fn indicate<R: tauri::Runtime>(app: &tauri::AppHandle<R>, phrase: Option<&str>) {
+    let _ = SessionEvent::WakeListening {
+        phrase: phrase.map(str::to_string),
+    }
+    .emit(app);
+}
+
+async fn listen<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    phrase: &str,
+    model: SupportedSttModel,
+    languages: Vec<hypr_language::Language>,
+    token: &CancellationToken,
+) -> Result<(), crate::Error> {
+    let mut input = hypr_audio::AudioInput::from_mic(None)?;
+    let chunks =
+        ResampledAsyncSource::new(input.stream(), SAMPLE_RATE).speech_chunks(REDEMPTION_TIME);
+    futures_util::pin_mut!(chunks);
+
+    let mut last_wake: Option<Instant> = None;
+
+    loop {
+        let chunk = tokio::select! {
+            _ = token.cancelled() => return Ok(()),
+            chunk = chunks.next() => match chunk {
+                Some(Ok(chunk)) => chunk,
+                Some(Err(e)) => {
+                    tracing::warn!("wake_vad_failed: {:?}", e);
+                    return Ok(());
+                }
+                None => return Ok(()),
+            },
+        };
+
+        let ms = chunk.samples.len() * 1000 / SAMPLE_RATE as usize;
+        if !(MIN_UTTERANCE_MS..=MAX_UTTERANCE_MS).contains(&ms)
+            || last_wake.is_some_and(|at| at.elapsed() < COOLDOWN)
+        {
+            continue;
+        }
+
+        let heard = match app
+            .transcribe_audio(model.clone(), chunk.samples, languages.clone())
+            .await
+        {
+            Ok(words) => words
+                .iter()
+                .map(|word| word.word.as_str())
+                .collect::<Vec<_>>()
+                .join(" "),
+            Err(e) => {
+                tracing::warn!("wake_transcribe_failed: {}", e);
+                continue;
+            }
+        };
+
+        if matches_phrase(phrase, &heard) {
+            tracing::info!("wake_phrase_detected");
+            last_wake = Some(Instant::now());
+            SessionEvent::WakePhraseDetected {}.emit(app)?;
+        }
+    }
+}
+
+/// Whether `heard` contains `phrase`, allowing for the odd misheard letter or word boundary.
+pub fn matches_phrase(phrase: &str, heard: &str) -> bool {
+    let phrase = normalize_text(phrase);
+    if phrase.is_empty() {
+        return false;
+    }
+
+    let heard = normalize_text(heard);
+    let words: Vec<&str> = heard.split_whitespace().collect();
+    let phrase_words = phrase.split_whitespace().count();
+    let tolerance = phrase.chars().count() / 8;
+
+    (phrase_words.saturating_sub(1).max(1)..=phrase_words + 1).any(|len| {
+        words
+            .windows(len.min(words.len()).max(1))
+            .any(|window| edit_distance(&window.join(" "), &phrase) <= tolerance)
+    })
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_phrase() {
+        let phrase = "Start taking notes";
+
+        assert!(matches_phrase(phrase, "Start taking notes."));
+        assert!(matches_phrase(phrase, "Okay, start taking notes please"));
+        assert!(matches_phrase(phrase, "start taking note"));
+        assert!(matches_phrase(phrase, "start taking notes"));
+        assert!(matches_phrase(phrase, "Start taking-notes!"));
+
+        assert!(!matches_phrase(phrase, "Stop taking notes"));
+        assert!(!matches_phrase(phrase, "I'm taking notes"));
+        assert!(!matches_phrase(phrase, ""));
+        assert!(!matches_phrase("", "start taking notes"));
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("notes", "notes"), 0);
+    }
+}
@@ -51,6 +51,9 @@ async getCurrentModelSelection() : Promise<ModelSelection> {
 },
 async setCurrentModelSelection(model: ModelSelection) : Promise<null> {
     return await TAURI_INVOKE("plugin:local-llm|set_current_model_selection", { model });
+},
+async generateChapters(sessionId: string) : Promise<string> {
+    return await TAURI_INVOKE("plugin:local-llm|generate_chapters", { sessionId });
 }
 }
 
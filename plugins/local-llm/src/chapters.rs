@@ -0,0 +1,213 @@
+use futures_util::StreamExt;
+use owhisper_interface::Word2;
+
+/// Transcript is embedded in blocks of this many words; topics shorter than
+/// `MIN_CHAPTER_BLOCKS` blocks are not split out.
+const BLOCK_WORDS: usize = 50;
+const MIN_CHAPTER_BLOCKS: usize = 4;
+const MAX_TITLE_INPUT_CHARS: usize = 4000;
+const TITLE_PROMPT: &str = "Give a short title, at most six words, for this part of a meeting transcript. Reply with the title only.";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block {
+    pub text: String,
+    pub start_ms: u64,
+    pub end_ms: u64,
+}
+
+pub fn blocks(words: &[Word2]) -> Vec<Block> {
+    let mut timed: Vec<(&Word2, u64, u64)> = words
+        .iter()
+        .filter_map(|w| Some((w, w.start_ms?, w.end_ms?)))
+        .collect();
+    timed.sort_by_key(|(_, start, _)| *start);
+
+    timed
+        .chunks(BLOCK_WORDS)
+        .map(|chunk| Block {
+            text: chunk
+                .iter()
+                .map(|(w, _, _)| w.text.trim())
+                .collect::<Vec<_>>()
+                .join(" "),
+            start_ms: chunk.first().map_or(0, |(_, start, _)| *start),
+            end_ms: chunk.iter().map(|(_, _, end)| *end).max().unwrap_or(0),
+        })
+        .collect()
+}
+
+/// Indices of the blocks that start a new chapter, not counting the first one.
+///
+/// A gap between two blocks is a candidate when their similarity is a local minimum
+/// and clearly below the transcript's average; the deepest candidates win, as long as
+/// every chapter keeps at least `min_blocks` blocks.
+pub fn change_points(embeddings: &[Vec<f32>], min_blocks: usize) -> Vec<usize> {
+    let n = embeddings.len();
+    if n < min_blocks * 2 {
+        return vec![];
+    }
+
+    // `sims[i]` is the similarity between block `i` and the one before it.
+    let sims: Vec<f32> = (0..n)
+        .map(|i| match i {
+            0 => 1.0,
+            _ => dot(&embeddings[i - 1], &embeddings[i]),
+        })
+        .collect();
+
+    let gaps = &sims[1..];
+    let mean = gaps.iter().sum::<f32>() / gaps.len() as f32;
+    let std = (gaps.iter().map(|s| (s - mean).powi(2)).sum::<f32>() / gaps.len() as f32).sqrt();
+    let threshold = mean - std / 2.0;
+
+    let mut candidates: Vec<usize> = (min_blocks..=n - min_blocks)
+        .filter(|&i| {
+            sims[i] < threshold
+                && sims[i] <= sims[i - 1]
+                && sims.get(i + 1).is_none_or(|next| sims[i] <= *next)
+        })
+        .collect();
+    candidates.sort_by(|a, b| sims[*a].total_cmp(&sims[*b]));
+
+    let mut points: Vec<usize> = Vec::new();
+    for candidate in candidates {
+        if points.iter().all(|p| p.abs_diff(candidate) >= min_blocks) {
+            points.push(candidate);
+        }
+    }
+    points.sort();
+    points
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Splits the session's transcript by topic and gives each chapter a title.
+pub async fn chapters(
+    model: &hypr_llama::Llama,
+    session_id: &str,
+    words: &[Word2],
+) -> Result<Vec<hypr_db_user::Chapter>, crate::Error> {
+    let blocks = blocks(words);
+    if blocks.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let embeddings = model
+        .embed(blocks.iter().map(|b| b.text.clone()).collect())
+        .await?;
+
+    let mut bounds = vec![0];
+    bounds.extend(change_points(&embeddings, MIN_CHAPTER_BLOCKS));
+    bounds.push(blocks.len());
+
+    let mut chapters = Vec::with_capacity(bounds.len() - 1);
+    for (index, range) in bounds.windows(2).enumerate() {
+        let section = &blocks[range[0]..range[1]];
+        let text: String = section
+            .iter()
+            .map(|b| b.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+            .chars()
+            .take(MAX_TITLE_INPUT_CHARS)
+            .collect();
+
+        chapters.push(hypr_db_user::Chapter {
+            id: uuid::Uuid::new_v4().to_string(),
+            session_id: session_id.to_string(),
+            title: title(model, &text)
+                .await
+                .unwrap_or_else(|| format!("Chapter {}", index + 1)),
+            start_ms: section[0].start_ms as i64,
+            end_ms: section[section.len() - 1].end_ms as i64,
+        });
+    }
+
+    Ok(chapters)
+}
+
+async fn title(model: &hypr_llama::Llama, text: &str) -> Option<String> {
+    let request = hypr_llama::LlamaRequest {
+        messages: vec![
+            hypr_llama::LlamaMessage {
+                role: "system".into(),
+                content: TITLE_PROMPT.into(),
+            },
+            hypr_llama::LlamaMessage {
+                role: "user".into(),
+                content: text.to_string(),
+            },
+        ],
+        ..Default::default()
+    };
+
+    let response: String = model
+        .generate_stream(request)
+        .ok()?
+        .filter_map(|response| async move {
+            match response {
+                hypr_llama::Response::TextDelta(chunk) => Some(chunk),
+                _ => None,
+            }
+        })
+        .collect()
+        .await;
+
+    let title = response
+        .lines()
+        .map(|line| line.trim_matches(|c: char| c.is_whitespace() || "\"'#*".contains(c)))
+        .find(|line| !line.is_empty())?;
+    Some(title.chars().take(80).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks() {
+        let words: Vec<Word2> = (0..120)
+            .map(|i| Word2 {
+                text: format!("w{}", i),
+                start_ms: Some(i * 100),
+                end_ms: Some(i * 100 + 80),
+                ..Default::default()
+            })
+            .collect();
+
+        let blocks = blocks(&words);
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[1].start_ms, 5000);
+        assert_eq!(blocks[1].end_ms, 9980);
+        assert!(blocks[2].text.starts_with("w100 w101"));
+    }
+
+    #[test]
+    fn test_change_points() {
+        let topic = |x: f32, y: f32| {
+            let norm = (x * x + y * y).sqrt();
+            vec![x / norm, y / norm]
+        };
+
+        let embeddings = vec![
+            topic(1.0, 0.1),
+            topic(1.0, 0.0),
+            topic(1.0, 0.2),
+            topic(1.0, 0.1),
+            topic(1.0, 0.0),
+            topic(0.1, 1.0),
+            topic(0.0, 1.0),
+            topic(0.2, 1.0),
+            topic(0.1, 1.0),
+        ];
+
+        assert_eq!(change_points(&embeddings, 4), vec![5]);
+        assert_eq!(change_points(&embeddings, 5), Vec::<usize>::new());
+        assert_eq!(
+            change_points(&vec![topic(1.0, 0.0); 8], 2),
+            Vec::<usize>::new()
+        );
+    }
+}
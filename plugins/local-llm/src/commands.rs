@@ -140,3 +140,14 @@ pub async fn set_current_model_selection<R: tauri::Runtime>(
     app.set_current_model_selection(model)
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn generate_chapters<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+) -> Result<String, String> {
+    app.generate_chapters(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -18,6 +18,14 @@ pub enum Error {
     ModelNotDownloaded,
     #[error("server already running")]
     ServerAlreadyRunning,
+    #[error(transparent)]
+    DatabaseError(#[from] tauri_plugin_db::Error),
+    #[error(transparent)]
+    TaskError(#[from] tauri_plugin_task::Error),
+    #[error("server not running")]
+    ServerNotRunning,
+    #[error("session not found")]
+    SessionNotFound,
     #[error("Other error: {0}")]
     Other(String),
 }
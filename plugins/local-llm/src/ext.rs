@@ -4,7 +4,8 @@ use tauri::{ipc::Channel, Manager, Runtime};
 use tauri_plugin_store2::StorePluginExt;
 
 use hypr_download_interface::DownloadProgress;
-use hypr_file::download_file_parallel;
+use hypr_file::{download_file_parallel_verified, Checksum};
+use tauri_plugin_download_manager::DownloadManagerPluginExt;
 
 pub trait LocalLlmPluginExt<R: Runtime> {
     fn local_llm_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey>;
@@ -39,6 +40,19 @@ pub trait LocalLlmPluginExt<R: Runtime> {
         &self,
         model: &crate::SupportedModel,
     ) -> impl Future<Output = Result<bool, crate::Error>>;
+
+    /// Returns the id of the task that fills in the session's chapters.
+    fn generate_chapters(
+        &self,
+        session_id: String,
+    ) -> impl Future<Output = Result<String, crate::Error>>;
+
+    /// Runs a single prompt through the current model, starting the server if it isn't up.
+    fn generate_text(
+        &self,
+        system: String,
+        user: String,
+    ) -> impl Future<Output = Result<String, crate::Error>>;
 }
 
 impl<R: Runtime, T: Manager<R>> LocalLlmPluginExt<R> for T {
@@ -47,7 +61,9 @@ impl<R: Runtime, T: Manager<R>> LocalLlmPluginExt<R> for T {
     }
 
     fn models_dir(&self) -> PathBuf {
-        self.path().app_data_dir().unwrap().join("ttt")
+        hypr_data_dir::resolve_shared(|| self.path().app_data_dir())
+            .unwrap()
+            .join("ttt")
     }
 
     #[tracing::instrument(skip_all)]
@@ -59,12 +75,7 @@ impl<R: Runtime, T: Manager<R>> LocalLlmPluginExt<R> for T {
 
     #[tracing::instrument(skip_all)]
     async fn is_model_downloading(&self, model: &crate::SupportedModel) -> bool {
-        let state = self.state::<crate::SharedState>();
-
-        {
-            let guard = state.lock().await;
-            guard.download_task.contains_key(model)
-        }
+        self.is_downloading(&download_id(model))
     }
 
     #[tracing::instrument(skip_all)]
@@ -73,17 +84,7 @@ impl<R: Runtime, T: Manager<R>> LocalLlmPluginExt<R> for T {
         model: &crate::SupportedModel,
     ) -> Result<bool, crate::Error> {
         let path = self.models_dir().join(model.file_name());
-
-        if !path.exists() {
-            return Ok(false);
-        }
-
-        let actual = hypr_file::file_size(path)?;
-        if actual != model.model_size() {
-            return Ok(false);
-        }
-
-        Ok(true)
+        Ok(hypr_file::is_download_complete(path, model.model_size())?)
     }
 
     #[tracing::instrument(skip_all)]
@@ -99,48 +100,51 @@ impl<R: Runtime, T: Manager<R>> LocalLlmPluginExt<R> for T {
         model: crate::SupportedModel,
         channel: Channel<i8>,
     ) -> Result<(), crate::Error> {
-        let m = model.clone();
-        let path = self.models_dir().join(m.file_name());
-
-        {
-            let existing = {
-                let state = self.state::<crate::SharedState>();
-                let mut s = state.lock().await;
-                s.download_task.remove(&model)
-            };
-
-            if let Some(existing_task) = existing {
-                existing_task.abort();
-                let _ = existing_task.await;
-            }
-        }
-
-        let task = tokio::spawn(async move {
-            let callback = |progress: DownloadProgress| match progress {
-                DownloadProgress::Started => {
-                    let _ = channel.send(0);
-                }
-                DownloadProgress::Progress(downloaded, total_size) => {
-                    let percent = (downloaded as f64 / total_size as f64) * 100.0;
-                    let _ = channel.send(percent as i8);
-                }
-                DownloadProgress::Finished => {
-                    let _ = channel.send(100);
-                }
-            };
-
-            if let Err(e) = download_file_parallel(m.model_url(), path, callback).await {
-                tracing::error!("model_download_error: {}", e);
-                let _ = channel.send(-1);
+        let path = self.models_dir().join(model.file_name());
+        let label = crate::ModelSelection::Predefined { key: model.clone() }.display_name();
+
+        self.enqueue_download(download_id(&model), label, 0, move |ctx| {
+            let m = model.clone();
+            let path = path.clone();
+            let channel = channel.clone();
+
+            async move {
+                let report = ctx.progress_callback();
+                let callback = |progress: DownloadProgress| {
+                    match progress {
+                        DownloadProgress::Started => {
+                            let _ = channel.send(0);
+                        }
+                        DownloadProgress::Progress(downloaded, total_size) => {
+                            let percent = (downloaded as f64 / total_size as f64) * 100.0;
+                            let _ = channel.send(percent as i8);
+                        }
+                        DownloadProgress::Finished => {
+                            let _ = channel.send(100);
+                        }
+                    }
+                    report(progress);
+                };
+
+                let checksum = Checksum::crc32(m.model_checksum());
+                download_file_parallel_verified(
+                    m.model_url(),
+                    path,
+                    &checksum,
+                    callback,
+                    Some(ctx.cancellation_token()),
+                )
+                .await
+                .map_err(|e| {
+                    if !matches!(e, hypr_file::Error::Cancelled) {
+                        tracing::error!("model_download_error: {}", e);
+                        let _ = channel.send(-1);
+                    }
+                    e.to_string()
+                })
             }
         });
 
-        {
-            let state = self.state::<crate::SharedState>();
-            let mut s = state.lock().await;
-            s.download_task.insert(model.clone(), task);
-        }
-
         Ok(())
     }
 
@@ -209,6 +213,93 @@ impl<R: Runtime, T: Manager<R>> LocalLlmPluginExt<R> for T {
         Ok(api_base)
     }
 
+    #[tracing::instrument(skip_all)]
+    async fn generate_chapters(&self, session_id: String) -> Result<String, crate::Error> {
+        use tauri_plugin_db::DatabasePluginExt;
+        use tauri_plugin_task::TaskPluginExt;
+
+        let words = self
+            .db_get_session(&session_id)
+            .await?
+            .ok_or(crate::Error::SessionNotFound)?
+            .words;
+
+        if !self.is_server_running().await {
+            self.start_server().await?;
+        }
+
+        let model_manager = {
+            let state = self.state::<crate::SharedState>();
+            let guard = state.lock().await;
+            guard.server.as_ref().map(|s| s.model_manager.clone())
+        }
+        .ok_or(crate::Error::ServerNotRunning)?;
+
+        let app = self.app_handle().clone();
+        let task_id = self.spawn_task_blocking(move |ctx| {
+            let result = tauri::async_runtime::block_on(async {
+                let model = model_manager.get_model().await?;
+                let chapters = crate::chapters::chapters(&model, &session_id, &words).await?;
+                app.db_replace_session_chapters(&session_id, chapters)
+                    .await?;
+                Ok::<_, crate::Error>(())
+            });
+
+            let _ = match result {
+                Ok(()) => ctx.complete(),
+                Err(e) => ctx.fail(e.to_string()),
+            };
+
+            async {}
+        });
+
+        Ok(task_id)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn generate_text(&self, system: String, user: String) -> Result<String, crate::Error> {
+        use futures_util::StreamExt;
+
+        if !self.is_server_running().await {
+            self.start_server().await?;
+        }
+
+        let model_manager = {
+            let state = self.state::<crate::SharedState>();
+            let guard = state.lock().await;
+            guard.server.as_ref().map(|s| s.model_manager.clone())
+        }
+        .ok_or(crate::Error::ServerNotRunning)?;
+        let model = model_manager.get_model().await?;
+
+        let request = hypr_llama::LlamaRequest {
+            messages: vec![
+                hypr_llama::LlamaMessage {
+                    role: "system".into(),
+                    content: system,
+                },
+                hypr_llama::LlamaMessage {
+                    role: "user".into(),
+                    content: user,
+                },
+            ],
+            ..Default::default()
+        };
+
+        let text: String = model
+            .generate_stream(request)?
+            .filter_map(|response| async move {
+                match response {
+                    hypr_llama::Response::TextDelta(chunk) => Some(chunk),
+                    _ => None,
+                }
+            })
+            .collect()
+            .await;
+
+        Ok(text.trim().to_string())
+    }
+
     #[tracing::instrument(skip_all)]
     async fn stop_server(&self) -> Result<(), crate::Error> {
         let state = self.state::<crate::SharedState>();
@@ -323,3 +414,8 @@ impl<R: Runtime, T: Manager<R>> LocalLlmPluginExt<R> for T {
         Ok(())
     }
 }
+
+/// Id of the model's download in the download manager.
+fn download_id(model: &crate::SupportedModel) -> String {
+    format!("{}:{:?}", crate::PLUGIN_NAME, model)
+}
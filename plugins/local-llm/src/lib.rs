@@ -1,9 +1,9 @@
-use std::collections::HashMap;
 use std::sync::Arc;
 
 use tauri::{Manager, Wry};
 use tokio::sync::Mutex;
 
+mod chapters;
 mod commands;
 mod error;
 mod events;
@@ -34,7 +34,6 @@ pub type SharedState = std::sync::Arc<tokio::sync::Mutex<State>>;
 pub struct State {
     pub api_base: Option<String>,
     pub server: Option<crate::server::ServerHandle>,
-    pub download_task: HashMap<SupportedModel, tokio::task::JoinHandle<()>>,
 }
 
 fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
@@ -56,6 +55,7 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::list_custom_models::<Wry>,
             commands::get_current_model_selection::<Wry>,
             commands::set_current_model_selection::<Wry>,
+            commands::generate_chapters::<Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
@@ -66,7 +66,7 @@ pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
     tauri::plugin::Builder::new(PLUGIN_NAME)
         .invoke_handler(specta_builder.invoke_handler())
         .setup(|app, _api| {
-            let data_dir = app.path().app_data_dir().unwrap();
+            let data_dir = hypr_data_dir::resolve(|| app.path().app_data_dir()).unwrap();
             let models_dir = app.models_dir();
 
             // for backward compatibility
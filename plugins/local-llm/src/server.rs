@@ -28,6 +28,7 @@ use crate::ModelManager;
 pub struct ServerHandle {
     pub addr: SocketAddr,
     pub shutdown: tokio::sync::watch::Sender<()>,
+    pub model_manager: ModelManager,
 }
 
 impl ServerHandle {
@@ -67,6 +68,8 @@ impl ServerState {
 }
 
 pub async fn run_server(state: ServerState) -> Result<ServerHandle, crate::Error> {
+    let model_manager = state.model_manager.clone();
+
     let app = Router::new()
         .route("/health", get(health))
         .route("/cancel", get(cancel))
@@ -89,6 +92,7 @@ pub async fn run_server(state: ServerState) -> Result<ServerHandle, crate::Error
     let server_handle = ServerHandle {
         addr: server_addr,
         shutdown: shutdown_tx,
+        model_manager,
     };
 
     tokio::spawn(async move {
@@ -28,7 +28,7 @@ async getLocalModel() : Promise<SupportedSttModel> {
 async setLocalModel(model: SupportedSttModel) : Promise<null> {
     return await TAURI_INVOKE("plugin:local-stt|set_local_model", { model });
 },
-async getServers() : Promise<Partial<{ [key in ServerType]: ServerHealth }>> {
+async getServers() : Promise<Partial<{ [key in ServerType]: ServerInfo }>> {
     return await TAURI_INVOKE("plugin:local-stt|get_servers");
 },
 async startServer(model: SupportedSttModel | null) : Promise<string> {
@@ -81,12 +81,34 @@ async setCustomModel(model: SupportedSttModel) : Promise<null> {
 
 export type AmModel = "am-parakeet-v2" | "am-parakeet-v3" | "am-whisper-large-v3"
 export type GgmlBackend = { kind: string; name: string; description: string; total_memory_mb: number; free_memory_mb: number }
+export type KyutaiModel = "kyutai-stt-1b-en-fr"
 export type Language = { iso639: string }
 export type Provider = "Local" | "Custom"
 export type ServerHealth = "unreachable" | "loading" | "ready"
+export type ServerInfo = { health: ServerHealth; 
+/**
+ * Not reported by custom servers.
+ */
+metrics: ServerMetrics | null }
+/**
+ * Load of a running server, to explain slow transcription.
+ */
+export type ServerMetrics = { model: string; model_loaded: boolean; queue_depth: number | null; 
+/**
+ * Above 1.0 transcription is falling behind the audio.
+ */
+real_time_factor: number | null; memory_bytes: number | null; 
+/**
+ * How far behind live audio transcripts arrive.
+ */
+latency_ms: number | null; last_inference_ms: number | null; 
+/**
+ * Speech skipped because transcription fell too far behind.
+ */
+dropped_frames: number | null }
 export type ServerType = "internal" | "external" | "custom"
 export type SttModelInfo = { key: SupportedSttModel; display_name: string; size_bytes: number }
-export type SupportedSttModel = WhisperModel | AmModel | string
+export type SupportedSttModel = WhisperModel | AmModel | KyutaiModel | string
 export type TAURI_CHANNEL<TSend> = null
 export type WhisperModel = "QuantizedTiny" | "QuantizedTinyEn" | "QuantizedBase" | "QuantizedBaseEn" | "QuantizedSmall" | "QuantizedSmallEn" | "QuantizedLargeTurbo"
 
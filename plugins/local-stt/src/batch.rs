@@ -0,0 +1,66 @@
+use owhisper_interface::Word;
+
+/// Whisper only times whole segments, so words get a share of their segment
+/// proportional to their length.
+pub fn segments_to_words(segments: &[hypr_whisper_local::Segment]) -> Vec<Word> {
+    let mut words = Vec::new();
+
+    for segment in segments {
+        let texts: Vec<&str> = segment.text().split_whitespace().collect();
+        let total_chars: usize = texts.iter().map(|t| t.chars().count()).sum();
+        if total_chars == 0 {
+            continue;
+        }
+
+        let per_char = segment.duration().max(0.0) / total_chars as f64;
+        let mut cursor = segment.start();
+
+        for text in texts {
+            let end = cursor + per_char * text.chars().count() as f64;
+            words.push(Word {
+                word: text.to_string(),
+                start: cursor,
+                end,
+                confidence: segment.confidence() as f64,
+                speaker: None,
+                punctuated_word: None,
+                language: segment.language().map(String::from),
+            });
+            cursor = end;
+        }
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segments_to_words() {
+        let segments = vec![
+            hypr_whisper_local::Segment {
+                text: " Hello there".to_string(),
+                start: 1.0,
+                end: 2.0,
+                confidence: 0.9,
+                ..Default::default()
+            },
+            hypr_whisper_local::Segment {
+                text: " ".to_string(),
+                start: 2.0,
+                end: 2.5,
+                ..Default::default()
+            },
+        ];
+
+        let words = segments_to_words(&segments);
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].word, "Hello");
+        assert_eq!(words[0].start, 1.0);
+        assert!((words[0].end - 1.5).abs() < 1e-9);
+        assert_eq!(words[1].word, "there");
+        assert!((words[1].end - 2.0).abs() < 1e-9);
+    }
+}
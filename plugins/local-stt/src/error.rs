@@ -16,6 +16,12 @@ pub enum Error {
     IoError(#[from] std::io::Error),
     #[error(transparent)]
     StoreError(#[from] tauri_plugin_store2::Error),
+    #[error(transparent)]
+    WhisperError(#[from] hypr_whisper_local::Error),
+    #[error(transparent)]
+    JoinError(#[from] tokio::task::JoinError),
+    #[error("Model checksum mismatch")]
+    ModelChecksumMismatch,
     #[error("Model not downloaded")]
     ModelNotDownloaded,
     #[error("Server already running")]
@@ -5,13 +5,13 @@ use tauri_plugin_shell::ShellExt;
 use tauri_plugin_store2::StorePluginExt;
 
 use hypr_download_interface::DownloadProgress;
-use hypr_file::download_file_parallel_cancellable;
+use hypr_file::{download_file_parallel_cancellable, download_file_parallel_verified, Checksum};
 use hypr_whisper_local_model::WhisperModel;
-use tokio_util::sync::CancellationToken;
+use tauri_plugin_download_manager::{DownloadCtx, DownloadManagerPluginExt};
 
 use crate::{
     model::SupportedSttModel,
-    server::{external, internal, ServerHealth, ServerType},
+    server::{external, internal, ServerHealth, ServerInfo, ServerType},
     Connection, Provider, StoreKey,
 };
 
@@ -40,7 +40,7 @@ pub trait LocalSttPluginExt<R: Runtime> {
     ) -> impl Future<Output = Result<bool, crate::Error>>;
     fn get_servers(
         &self,
-    ) -> impl Future<Output = Result<HashMap<ServerType, ServerHealth>, crate::Error>>;
+    ) -> impl Future<Output = Result<HashMap<ServerType, ServerInfo>, crate::Error>>;
 
     fn get_local_model(&self) -> Result<SupportedSttModel, crate::Error>;
     fn set_local_model(
@@ -62,6 +62,15 @@ pub trait LocalSttPluginExt<R: Runtime> {
         &self,
         model: &SupportedSttModel,
     ) -> impl Future<Output = Result<bool, crate::Error>>;
+
+    /// Transcribes 16kHz mono audio in one pass, without going through a server.
+    /// Word timestamps are in seconds from the start of `samples`.
+    fn transcribe_audio(
+        &self,
+        model: SupportedSttModel,
+        samples: Vec<f32>,
+        languages: Vec<hypr_language::Language>,
+    ) -> impl Future<Output = Result<Vec<owhisper_interface::Word>, crate::Error>>;
 }
 
 impl<R: Runtime, T: Manager<R>> LocalSttPluginExt<R> for T {
@@ -70,7 +79,9 @@ impl<R: Runtime, T: Manager<R>> LocalSttPluginExt<R> for T {
     }
 
     fn models_dir(&self) -> PathBuf {
-        self.path().app_data_dir().unwrap().join("stt")
+        hypr_data_dir::resolve_shared(|| self.path().app_data_dir())
+            .unwrap()
+            .join("stt")
     }
 
     fn list_ggml_backends(&self) -> Vec<hypr_whisper_local::GgmlBackend> {
@@ -176,6 +187,7 @@ impl<R: Runtime, T: Manager<R>> LocalSttPluginExt<R> for T {
                         };
                         Ok(conn)
                     }
+                    SupportedSttModel::Kyutai(_) => Err(crate::Error::UnsupportedModelType),
                     SupportedSttModel::Whisper(_) => {
                         let existing_api_base = {
                             let state = self.state::<crate::SharedState>();
@@ -209,23 +221,49 @@ impl<R: Runtime, T: Manager<R>> LocalSttPluginExt<R> for T {
         match model {
             SupportedSttModel::Custom(_) => Ok(false),
             SupportedSttModel::Am(model) => Ok(model.is_downloaded(self.models_dir())?),
+            SupportedSttModel::Kyutai(model) => Ok(model.is_downloaded(self.models_dir())),
             SupportedSttModel::Whisper(model) => {
                 let model_path = self.models_dir().join(model.file_name());
+                Ok(hypr_file::is_download_complete(
+                    model_path,
+                    model.model_size_bytes(),
+                )?)
+            }
+        }
+    }
 
-                for (path, expected) in [(model_path, model.model_size_bytes())] {
-                    if !path.exists() {
-                        return Ok(false);
-                    }
-
-                    let actual = hypr_file::file_size(path)?;
-                    if actual != expected {
-                        return Ok(false);
-                    }
-                }
+    #[tracing::instrument(skip_all)]
+    async fn transcribe_audio(
+        &self,
+        model: SupportedSttModel,
+        samples: Vec<f32>,
+        languages: Vec<hypr_language::Language>,
+    ) -> Result<Vec<owhisper_interface::Word>, crate::Error> {
+        let SupportedSttModel::Whisper(whisper_model) = &model else {
+            return Err(crate::Error::UnsupportedModelType);
+        };
 
-                Ok(true)
-            }
+        if !self.is_model_downloaded(&model).await? {
+            return Err(crate::Error::ModelNotDownloaded);
         }
+
+        let model_path = self.models_dir().join(whisper_model.file_name());
+
+        tokio::task::spawn_blocking(move || {
+            let mut whisper = hypr_whisper_local::Whisper::builder()
+                .model_path(model_path.to_string_lossy())
+                .languages(
+                    languages
+                        .into_iter()
+                        .filter_map(|lang| lang.try_into().ok())
+                        .collect(),
+                )
+                .build()?;
+
+            let segments = whisper.transcribe(&samples)?;
+            Ok(crate::batch::segments_to_words(&segments))
+        })
+        .await?
     }
 
     #[tracing::instrument(skip_all)]
@@ -242,7 +280,7 @@ impl<R: Runtime, T: Manager<R>> LocalSttPluginExt<R> for T {
         };
 
         let t = match &model {
-            SupportedSttModel::Custom(_) => {
+            SupportedSttModel::Custom(_) | SupportedSttModel::Kyutai(_) => {
                 return Err(crate::Error::UnsupportedModelType);
             }
             SupportedSttModel::Am(_) => ServerType::External,
@@ -250,7 +288,9 @@ impl<R: Runtime, T: Manager<R>> LocalSttPluginExt<R> for T {
         };
 
         let cache_dir = self.models_dir();
-        let data_dir = self.app_handle().path().app_data_dir().unwrap().join("stt");
+        let data_dir = hypr_data_dir::resolve_shared(|| self.app_handle().path().app_data_dir())
+            .unwrap()
+            .join("stt");
 
         match t {
             ServerType::Custom => Ok("".to_string()),
@@ -322,35 +362,33 @@ impl<R: Runtime, T: Manager<R>> LocalSttPluginExt<R> for T {
                     key.clone().unwrap()
                 };
 
-                let cmd: tauri_plugin_shell::process::Command = {
-                    #[cfg(debug_assertions)]
-                    {
-                        let passthrough_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
-                            .join("../../internal/passthrough-aarch64-apple-darwin");
-                        let stt_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
-                            .join("../../internal/stt-aarch64-apple-darwin");
+                // Updates downloaded because the bundled sidecar was too old.
+                let sidecar_dir = data_dir.join("sidecar");
+                let installed = hypr_am::installed_sidecar(&sidecar_dir);
 
-                        if !passthrough_path.exists() || !stt_path.exists() {
-                            return Err(crate::Error::AmBinaryNotFound);
-                        }
+                let mut server = external::run_server(
+                    sidecar_command(self.shell(), installed.as_deref())?,
+                    am_key.clone(),
+                )
+                .await?;
 
-                        self.shell()
-                            .command(passthrough_path)
-                            .current_dir(dirs::home_dir().unwrap())
-                            .arg(stt_path)
-                            .args(["serve", "-v", "-d"])
-                    }
+                if let Err(e) = server.check_version().await {
+                    tracing::warn!("am_sidecar_incompatible: {}", e);
+                    drop(server);
 
-                    #[cfg(not(debug_assertions))]
-                    self.shell()
-                        .sidecar("stt")?
-                        .current_dir(dirs::home_dir().unwrap())
-                        .args(["serve"])
-                };
+                    let release = hypr_am::latest_release().await?;
+                    tracing::info!("am_sidecar_update: {}", release.version);
+                    let path = hypr_am::download_sidecar(&release, &sidecar_dir, |_| {}).await?;
 
-                let server = external::run_server(cmd, am_key).await?;
-                tokio::time::sleep(std::time::Duration::from_millis(250)).await;
-                let _ = server.init(am_model, data_dir).await;
+                    server =
+                        external::run_server(sidecar_command(self.shell(), Some(&path))?, am_key)
+                            .await?;
+                    server.check_version().await?;
+                }
+
+                if let Err(e) = server.init(am_model, data_dir).await {
+                    tracing::error!("am_init_failed: {}", e);
+                }
                 let api_base = server.base_url.clone();
 
                 {
@@ -404,21 +442,20 @@ impl<R: Runtime, T: Manager<R>> LocalSttPluginExt<R> for T {
     }
 
     #[tracing::instrument(skip_all)]
-    async fn get_servers(&self) -> Result<HashMap<ServerType, ServerHealth>, crate::Error> {
+    async fn get_servers(&self) -> Result<HashMap<ServerType, ServerInfo>, crate::Error> {
         let state = self.state::<crate::SharedState>();
         let guard = state.lock().await;
 
-        let internal_health = if let Some(server) = &guard.internal_server {
-            let status = server.health().await;
-            status
+        let internal_info = if let Some(server) = &guard.internal_server {
+            server.info().await
         } else {
-            ServerHealth::Unreachable
+            ServerHealth::Unreachable.into()
         };
 
-        let external_health = if let Some(server) = &guard.external_server {
-            server.health().await
+        let external_info = if let Some(server) = &guard.external_server {
+            server.info().await
         } else {
-            ServerHealth::Unreachable
+            ServerHealth::Unreachable.into()
         };
 
         let custom_health = {
@@ -426,7 +463,7 @@ impl<R: Runtime, T: Manager<R>> LocalSttPluginExt<R> for T {
             if matches!(provider, Provider::Custom) {
                 let base_url = self.get_custom_base_url()?;
                 if !base_url.is_empty() {
-                    let client = reqwest::Client::new();
+                    let client = hypr_proxy::client();
                     let url = format!("{}/v1/status", base_url.trim_end_matches('/'));
 
                     match client.get(&url).send().await {
@@ -442,9 +479,9 @@ impl<R: Runtime, T: Manager<R>> LocalSttPluginExt<R> for T {
         };
 
         Ok([
-            (ServerType::Internal, internal_health),
-            (ServerType::External, external_health),
-            (ServerType::Custom, custom_health),
+            (ServerType::Internal, internal_info),
+            (ServerType::External, external_info),
+            (ServerType::Custom, custom_health.into()),
         ]
         .into_iter()
         .collect())
@@ -466,120 +503,28 @@ impl<R: Runtime, T: Manager<R>> LocalSttPluginExt<R> for T {
             return Err(crate::Error::UnsupportedModelType);
         }
 
-        {
-            let existing = {
-                let state = self.state::<crate::SharedState>();
-                let mut s = state.lock().await;
-                s.download_task.remove(&model)
-            };
-
-            if let Some((existing_task, existing_token)) = existing {
-                // Cancel the download and wait for task to finish
-                existing_token.cancel();
-                let _ = existing_task.await;
-            }
-        }
+        let models_dir = self.models_dir();
+        let label = model.info().display_name;
 
-        let create_progress_callback = |channel: Channel<i8>| {
-            move |progress: DownloadProgress| match progress {
-                DownloadProgress::Started => {
-                    let _ = channel.send(0);
-                }
-                DownloadProgress::Progress(downloaded, total_size) => {
-                    let percent = (downloaded as f64 / total_size as f64) * 100.0;
-                    let _ = channel.send(percent as i8);
-                }
-                DownloadProgress::Finished => {
-                    let _ = channel.send(100);
-                }
-            }
-        };
+        self.enqueue_download(download_id(&model), label, 0, move |ctx| {
+            let model = model.clone();
+            let models_dir = models_dir.clone();
+            let channel = channel.clone();
 
-        match model.clone() {
-            SupportedSttModel::Custom(_) => {
-                return Err(crate::Error::UnsupportedModelType);
-            }
-            SupportedSttModel::Am(m) => {
-                let tar_path = self.models_dir().join(format!("{}.tar", m.model_dir()));
-                let final_path = self.models_dir();
-                let cancellation_token = CancellationToken::new();
-                let token_clone = cancellation_token.clone();
-
-                let task = tokio::spawn(async move {
-                    let callback = create_progress_callback(channel.clone());
-
-                    if let Err(e) = download_file_parallel_cancellable(
-                        m.tar_url(),
-                        &tar_path,
-                        callback,
-                        Some(token_clone),
-                    )
+            async move {
+                download_model_files(&model, &models_dir, &channel, &ctx)
                     .await
-                    {
-                        if !matches!(e, hypr_file::Error::Cancelled) {
+                    .map_err(|e| {
+                        if !matches!(e, crate::Error::HyprFileError(hypr_file::Error::Cancelled)) {
                             tracing::error!("model_download_error: {}", e);
                             let _ = channel.send(-1);
                         }
-                        return;
-                    }
-
-                    if let Err(e) = m.tar_verify_and_unpack(&tar_path, &final_path) {
-                        tracing::error!("model_unpack_error: {}", e);
-                        let _ = channel.send(-1);
-                    }
-                });
-
-                {
-                    let state = self.state::<crate::SharedState>();
-                    let mut s = state.lock().await;
-                    s.download_task
-                        .insert(model.clone(), (task, cancellation_token));
-                }
-
-                Ok(())
+                        e.to_string()
+                    })
             }
-            SupportedSttModel::Whisper(m) => {
-                let model_path = self.models_dir().join(m.file_name());
-                let cancellation_token = CancellationToken::new();
-                let token_clone = cancellation_token.clone();
-
-                let task = tokio::spawn(async move {
-                    let callback = create_progress_callback(channel.clone());
-
-                    if let Err(e) = download_file_parallel_cancellable(
-                        m.model_url(),
-                        &model_path,
-                        callback,
-                        Some(token_clone),
-                    )
-                    .await
-                    {
-                        if !matches!(e, hypr_file::Error::Cancelled) {
-                            tracing::error!("model_download_error: {}", e);
-                            let _ = channel.send(-1);
-                        }
-                        return;
-                    }
+        });
 
-                    let checksum = hypr_file::calculate_file_checksum(&model_path).unwrap();
-
-                    if checksum != m.checksum() {
-                        tracing::error!("model_download_error: checksum mismatch");
-                        std::fs::remove_file(&model_path).unwrap();
-                        let _ = channel.send(-1);
-                    }
-                });
-
-                {
-                    let state = self.state::<crate::SharedState>();
-                    let mut s = state.lock().await;
-                    s.download_task
-                        .insert(model.clone(), (task, cancellation_token));
-                }
-
-                Ok(())
-            }
-        }
+        Ok(())
     }
 
     #[tracing::instrument(skip_all)]
@@ -590,11 +535,7 @@ impl<R: Runtime, T: Manager<R>> LocalSttPluginExt<R> for T {
             return false;
         }
 
-        let state = self.state::<crate::SharedState>();
-        {
-            let guard = state.lock().await;
-            guard.download_task.contains_key(model)
-        }
+        self.is_downloading(&download_id(model))
     }
 
     #[tracing::instrument(skip_all)]
@@ -633,3 +574,157 @@ impl<R: Runtime, T: Manager<R>> LocalSttPluginExt<R> for T {
         Ok(())
     }
 }
+
+/// Downloads whatever files `model` is made of into `models_dir`, picking up after an earlier,
+/// interrupted run.
+async fn download_model_files<R: Runtime>(
+    model: &SupportedSttModel,
+    models_dir: &std::path::Path,
+    channel: &Channel<i8>,
+    ctx: &DownloadCtx<R>,
+) -> Result<(), crate::Error> {
+    let report = ctx.progress_callback();
+    let callback = |progress: DownloadProgress| {
+        match progress {
+            DownloadProgress::Started => {
+                let _ = channel.send(0);
+            }
+            DownloadProgress::Progress(downloaded, total_size) => {
+                let percent = (downloaded as f64 / total_size as f64) * 100.0;
+                let _ = channel.send(percent as i8);
+            }
+            DownloadProgress::Finished => {
+                let _ = channel.send(100);
+            }
+        }
+        report(progress);
+    };
+
+    match model {
+        SupportedSttModel::Custom(_) => Err(crate::Error::UnsupportedModelType),
+        SupportedSttModel::Am(m) => {
+            let tar_path = models_dir.join(format!("{}.tar", m.model_dir()));
+
+            download_file_parallel_cancellable(
+                m.tar_url(),
+                &tar_path,
+                callback,
+                Some(ctx.cancellation_token()),
+            )
+            .await?;
+
+            m.tar_verify_and_unpack(&tar_path, models_dir)?;
+            Ok(())
+        }
+        SupportedSttModel::Kyutai(m) => {
+            download_kyutai_assets(&models_dir.join(m.model_dir()), channel, ctx).await
+        }
+        SupportedSttModel::Whisper(m) => {
+            download_file_parallel_verified(
+                m.model_url(),
+                models_dir.join(m.file_name()),
+                &Checksum::crc32(m.checksum()),
+                callback,
+                Some(ctx.cancellation_token()),
+            )
+            .await?;
+            Ok(())
+        }
+    }
+}
+
+/// Downloads each asset, reporting progress over all of them. Files are verified under a
+/// temporary name, so one that exists is complete and is skipped.
+async fn download_kyutai_assets<R: Runtime>(
+    model_dir: &std::path::Path,
+    channel: &Channel<i8>,
+    ctx: &DownloadCtx<R>,
+) -> Result<(), crate::Error> {
+    std::fs::create_dir_all(model_dir)?;
+
+    let total = hypr_kyutai::Assets::ALL
+        .iter()
+        .map(hypr_kyutai::Assets::size)
+        .sum::<u64>()
+        .max(1);
+    let mut done = 0;
+    let _ = channel.send(0);
+
+    for asset in hypr_kyutai::Assets::ALL.iter() {
+        let path = model_dir.join(asset.filename());
+        if !path.exists() {
+            let tmp = model_dir.join(format!("{}.download", asset.filename()));
+
+            download_file_parallel_cancellable(
+                asset.download_url(),
+                &tmp,
+                |progress| {
+                    if let DownloadProgress::Progress(downloaded, _) = progress {
+                        let _ = channel.send(((done + downloaded) * 100 / total).min(99) as i8);
+                        ctx.report(done + downloaded, total);
+                    }
+                },
+                Some(ctx.cancellation_token()),
+            )
+            .await?;
+
+            if hypr_file::calculate_file_checksum(&tmp)? != asset.checksum() {
+                let _ = std::fs::remove_file(&tmp);
+                return Err(crate::Error::ModelChecksumMismatch);
+            }
+            std::fs::rename(&tmp, &path)?;
+        }
+
+        done += asset.size();
+    }
+
+    let _ = channel.send(100);
+    Ok(())
+}
+
+/// The bundled sidecar, or `binary` when a newer one has been downloaded. A downloaded one
+/// only runs if its signature still checks out.
+fn sidecar_command<R: Runtime>(
+    shell: &tauri_plugin_shell::Shell<R>,
+    binary: Option<&std::path::Path>,
+) -> Result<tauri_plugin_shell::process::Command, crate::Error> {
+    if let Some(binary) = binary {
+        hypr_am::verify_sidecar(binary)?;
+    }
+
+    #[cfg(debug_assertions)]
+    {
+        let passthrough_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../internal/passthrough-aarch64-apple-darwin");
+        let stt_path = match binary {
+            Some(binary) => binary.to_path_buf(),
+            None => std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../../internal/stt-aarch64-apple-darwin"),
+        };
+
+        if !passthrough_path.exists() || !stt_path.exists() {
+            return Err(crate::Error::AmBinaryNotFound);
+        }
+
+        Ok(shell
+            .command(passthrough_path)
+            .current_dir(dirs::home_dir().unwrap())
+            .arg(stt_path)
+            .args(["serve", "-v", "-d"]))
+    }
+
+    #[cfg(not(debug_assertions))]
+    {
+        let cmd = match binary {
+            Some(binary) => shell.command(binary),
+            None => shell.sidecar("stt")?,
+        };
+
+        Ok(cmd.current_dir(dirs::home_dir().unwrap()).args(["serve"]))
+    }
+}
+
+/// Id of the model's download in the download manager.
+fn download_id(model: &SupportedSttModel) -> String {
+    format!("{}:{}", crate::PLUGIN_NAME, model)
+}
@@ -1,7 +1,6 @@
-use std::collections::HashMap;
 use tauri::{Manager, Wry};
-use tokio_util::sync::CancellationToken;
 
+mod batch;
 mod commands;
 mod error;
 mod events;
@@ -25,7 +24,6 @@ pub struct State {
     pub am_api_key: Option<String>,
     pub internal_server: Option<server::internal::ServerHandle>,
     pub external_server: Option<server::external::ServerHandle>,
-    pub download_task: HashMap<SupportedSttModel, (tokio::task::JoinHandle<()>, CancellationToken)>,
 }
 
 const PLUGIN_NAME: &str = "local-stt";
@@ -67,7 +65,7 @@ pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
         .setup(move |app, _api| {
             specta_builder.mount_events(app);
 
-            let data_dir = app.path().app_data_dir().unwrap();
+            let data_dir = hypr_data_dir::resolve(|| app.path().app_data_dir()).unwrap();
             let models_dir = app.models_dir();
 
             // for backward compatibility
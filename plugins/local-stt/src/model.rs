@@ -1,7 +1,8 @@
 use hypr_am::AmModel;
+use hypr_kyutai::KyutaiModel;
 use hypr_whisper_local_model::WhisperModel;
 
-pub static SUPPORTED_MODELS: [SupportedSttModel; 9] = [
+pub static SUPPORTED_MODELS: [SupportedSttModel; 10] = [
     SupportedSttModel::Whisper(WhisperModel::QuantizedTiny),
     SupportedSttModel::Whisper(WhisperModel::QuantizedTinyEn),
     SupportedSttModel::Whisper(WhisperModel::QuantizedBase),
@@ -11,6 +12,7 @@ pub static SUPPORTED_MODELS: [SupportedSttModel; 9] = [
     SupportedSttModel::Whisper(WhisperModel::QuantizedLargeTurbo),
     SupportedSttModel::Am(AmModel::ParakeetV2),
     SupportedSttModel::Am(AmModel::ParakeetV3),
+    SupportedSttModel::Kyutai(KyutaiModel::Stt1bEnFr),
 ];
 
 #[derive(serde::Serialize, serde::Deserialize, specta::Type)]
@@ -25,6 +27,7 @@ pub struct SttModelInfo {
 pub enum SupportedSttModel {
     Whisper(WhisperModel),
     Am(AmModel),
+    Kyutai(KyutaiModel),
     // must be the last item
     Custom(String),
 }
@@ -34,6 +37,7 @@ impl std::fmt::Display for SupportedSttModel {
         match self {
             SupportedSttModel::Whisper(model) => write!(f, "whisper-{}", model),
             SupportedSttModel::Am(model) => write!(f, "am-{}", model),
+            SupportedSttModel::Kyutai(model) => write!(f, "{}", model),
             SupportedSttModel::Custom(model) => write!(f, "{}", model),
         }
     }
@@ -191,6 +195,9 @@ impl SupportedSttModel {
                 hypr_am::AmModel::ParakeetV3 => parakeet_v3_languages,
                 hypr_am::AmModel::WhisperLargeV3 => whisper_multi_languages,
             },
+            SupportedSttModel::Kyutai(model) => match model {
+                hypr_kyutai::KyutaiModel::Stt1bEnFr => vec![ISO639::En.into(), ISO639::Fr.into()],
+            },
             SupportedSttModel::Custom(_) => vec![],
         }
     }
@@ -207,6 +214,11 @@ impl SupportedSttModel {
                 display_name: model.display_name().to_string(),
                 size_bytes: model.model_size_bytes(),
             },
+            SupportedSttModel::Kyutai(model) => SttModelInfo {
+                key: self.clone(),
+                display_name: model.display_name().to_string(),
+                size_bytes: model.model_size_bytes(),
+            },
             SupportedSttModel::Custom(_) => SttModelInfo {
                 key: self.clone(),
                 display_name: "Custom".to_string(),
@@ -1,4 +1,8 @@
-use super::ServerHealth;
+use super::{ServerHealth, ServerInfo, ServerMetrics};
+
+// The sidecar takes a moment to start listening after it is spawned.
+const STARTUP_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+const STARTUP_POLL_ATTEMPTS: usize = 20;
 
 pub struct ServerHandle {
     pub base_url: String,
@@ -15,24 +19,49 @@ impl Drop for ServerHandle {
 }
 
 impl ServerHandle {
-    pub async fn health(&self) -> ServerHealth {
+    pub async fn info(&self) -> ServerInfo {
         let res = self.client.status().await;
         if res.is_err() {
             tracing::error!("{:?}", res);
-            return ServerHealth::Unreachable;
+            return ServerHealth::Unreachable.into();
         }
 
         let res = res.unwrap();
 
-        if res.model_state == hypr_am::ModelState::Loading {
-            return ServerHealth::Loading;
-        }
+        let health = match res.model_state {
+            hypr_am::ModelState::Loading => ServerHealth::Loading,
+            hypr_am::ModelState::Loaded => ServerHealth::Ready,
+            _ => ServerHealth::Unreachable,
+        };
 
-        if res.model_state == hypr_am::ModelState::Loaded {
-            return ServerHealth::Ready;
+        ServerInfo {
+            health,
+            metrics: Some(ServerMetrics {
+                model_loaded: res.model_state == hypr_am::ModelState::Loaded,
+                model: res.model,
+                queue_depth: res.queue_depth,
+                real_time_factor: res.real_time_factor,
+                memory_bytes: res.memory_bytes,
+                latency_ms: None,
+                last_inference_ms: None,
+                dropped_frames: None,
+            }),
         }
+    }
+
+    /// Waits for the sidecar to start, then fails unless it speaks a supported API version.
+    pub async fn check_version(&self) -> Result<String, crate::Error> {
+        let mut attempts = 0;
 
-        ServerHealth::Unreachable
+        loop {
+            match self.client.check_version().await {
+                Err(hypr_am::Error::SidecarNotStarted) if attempts < STARTUP_POLL_ATTEMPTS => {
+                    attempts += 1;
+                    tokio::time::sleep(STARTUP_POLL_INTERVAL).await;
+                }
+                result => return Ok(result?),
+            }
+        }
     }
 
     pub async fn init(
@@ -60,7 +89,16 @@ pub async fn run_server(
     let (mut rx, child) = cmd.args(["--port", &port.to_string()]).spawn()?;
     let base_url = format!("http://localhost:{}", port);
     let (shutdown_tx, mut shutdown_rx) = tokio::sync::watch::channel(());
-    let client = hypr_am::Client::new(&base_url);
+    // Health is polled every second while holding the plugin state, so it fails fast
+    // instead of stacking retries on a wedged sidecar.
+    let client = hypr_am::Client::with_config(
+        &base_url,
+        hypr_am::ClientConfig {
+            request_timeout: std::time::Duration::from_secs(5),
+            max_retries: 1,
+            ..Default::default()
+        },
+    );
 
     tokio::spawn(async move {
         let mut process_ended = false;
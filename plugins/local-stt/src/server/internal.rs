@@ -3,12 +3,17 @@ use std::{
     path::PathBuf,
 };
 
-use axum::{http::StatusCode, response::IntoResponse, routing::get, Router};
+use axum::{http::StatusCode, response::IntoResponse, routing::get, Json, Router};
 use tower_http::cors::{self, CorsLayer};
 
-use super::ServerHealth;
+use super::{ServerHealth, ServerInfo, ServerMetrics};
+use hypr_transcribe_whisper_local::Metrics;
 use hypr_whisper_local_model::WhisperModel;
 
+/// How far live transcripts may trail the conversation before speech is skipped to catch up.
+/// Slow machines would otherwise fall minutes behind during long meetings.
+const MAX_LAG: std::time::Duration = std::time::Duration::from_secs(30);
+
 #[derive(Default)]
 pub struct ServerStateBuilder {
     pub model_type: Option<WhisperModel>,
@@ -30,6 +35,7 @@ impl ServerStateBuilder {
         ServerState {
             model_type: self.model_type.unwrap(),
             model_cache_dir: self.model_cache_dir.unwrap(),
+            metrics: Metrics::default(),
         }
     }
 }
@@ -38,6 +44,7 @@ impl ServerStateBuilder {
 pub struct ServerState {
     model_type: WhisperModel,
     model_cache_dir: PathBuf,
+    metrics: Metrics,
 }
 
 impl ServerState {
@@ -51,6 +58,8 @@ pub struct ServerHandle {
     pub base_url: String,
     pub api_key: Option<String>,
     shutdown: tokio::sync::watch::Sender<()>,
+    model_type: WhisperModel,
+    metrics: Metrics,
 }
 
 impl Drop for ServerHandle {
@@ -79,10 +88,32 @@ impl ServerHandle {
             }
         }
     }
+
+    pub async fn info(&self) -> ServerInfo {
+        let health = self.health().await;
+        let snapshot = self.metrics.snapshot();
+
+        ServerInfo {
+            health,
+            metrics: Some(ServerMetrics {
+                model: self.model_type.file_name().to_string(),
+                // The model is loaded per connection, so it is only resident while transcribing.
+                model_loaded: health == ServerHealth::Ready,
+                queue_depth: None,
+                real_time_factor: snapshot.real_time_factor,
+                memory_bytes: None,
+                latency_ms: snapshot.latency_ms,
+                last_inference_ms: snapshot.last_inference_ms,
+                dropped_frames: Some(snapshot.dropped_frames),
+            }),
+        }
+    }
 }
 
 pub async fn run_server(state: ServerState) -> Result<ServerHandle, crate::Error> {
     tracing::info!("starting");
+    let model_type = state.model_type.clone();
+    let metrics = state.metrics.clone();
     let router = make_service_router(state);
 
     let listener =
@@ -97,6 +128,8 @@ pub async fn run_server(state: ServerState) -> Result<ServerHandle, crate::Error
         base_url,
         api_key: None,
         shutdown: shutdown_tx,
+        model_type,
+        metrics,
     };
 
     tokio::spawn(async move {
@@ -117,10 +150,18 @@ fn make_service_router(state: ServerState) -> Router {
 
     let whisper_service = hypr_transcribe_whisper_local::TranscribeService::builder()
         .model_path(model_path)
+        .metrics(state.metrics.clone())
+        .max_lag(MAX_LAG)
         .build();
 
+    let metrics = state.metrics;
+
     Router::new()
         .route("/health", get(health))
+        .route(
+            "/v1/metrics",
+            get(move || async move { Json(metrics.snapshot()) }),
+        )
         .route_service("/v1/listen", whisper_service)
         .layer(
             CorsLayer::new()
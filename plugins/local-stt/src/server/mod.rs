@@ -22,3 +22,35 @@ pub enum ServerHealth {
     Loading,
     Ready,
 }
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct ServerInfo {
+    pub health: ServerHealth,
+    /// Not reported by custom servers.
+    pub metrics: Option<ServerMetrics>,
+}
+
+impl From<ServerHealth> for ServerInfo {
+    fn from(health: ServerHealth) -> Self {
+        Self {
+            health,
+            metrics: None,
+        }
+    }
+}
+
+/// Load of a running server, to explain slow transcription.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct ServerMetrics {
+    pub model: String,
+    pub model_loaded: bool,
+    pub queue_depth: Option<u32>,
+    /// Above 1.0 transcription is falling behind the audio.
+    pub real_time_factor: Option<f64>,
+    pub memory_bytes: Option<u64>,
+    /// How far behind live audio transcripts arrive.
+    pub latency_ms: Option<f64>,
+    pub last_inference_ms: Option<u64>,
+    /// Speech skipped because transcription fell too far behind.
+    pub dropped_frames: Option<u64>,
+}
@@ -1,4 +1,22 @@
-const COMMANDS: &[&str] = &["get_servers", "set_servers"];
+const COMMANDS: &[&str] = &[
+    "get_servers",
+    "set_servers",
+    "probe_server",
+    "set_server_credentials",
+    "get_access_token",
+    "get_consent_policy",
+    "set_consent_policy",
+    "authorize_tool_call",
+    "record_tool_call",
+    "list_tool_calls",
+    "clear_tool_calls",
+    "connect_stdio_server",
+    "send_stdio_message",
+    "close_stdio_server",
+    "get_local_server_config",
+    "set_local_server_config",
+    "is_local_server_running",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
@@ -0,0 +1,63 @@
+import { commands, type McpServer } from "./bindings.gen";
+
+type Execute = (args: any, options: any) => Promise<any>;
+
+interface GuardOptions {
+  /** Whether the server marks the tool with `readOnlyHint`. */
+  readOnly: boolean;
+}
+
+/**
+ * Wraps a tool's `execute` so it follows the server's consent policy,
+ * and records every call in the plugin's audit log.
+ *
+ * Calls to stdio servers go through the plugin, which asks and records on its own.
+ */
+export function guardToolExecute(server: McpServer, tool: string, execute: Execute, options: GuardOptions): Execute {
+  if (server.type === "stdio") {
+    return execute;
+  }
+
+  const record = (args: unknown, startedAt: number, result: unknown, error: string | null) =>
+    commands
+      .recordToolCall({
+        server_id: server.id ?? "",
+        tool,
+        arguments: (args ?? null) as any,
+        duration_ms: Date.now() - startedAt,
+        result_size: result === undefined ? 0 : new TextEncoder().encode(JSON.stringify(result)).length,
+        error,
+      })
+      .catch((e) => console.error("[MCP] Failed to record tool call:", e));
+
+  return async (args, executeOptions) => {
+    // Declined calls are recorded by the plugin.
+    const approved = await commands.authorizeToolCall(server.id ?? "", tool, (args ?? null) as any, options.readOnly);
+    if (!approved) {
+      return "The user declined to run this tool.";
+    }
+
+    const startedAt = Date.now();
+    try {
+      const result = await execute(args, executeOptions);
+      record(args, startedAt, result, null);
+      return result;
+    } catch (error) {
+      record(args, startedAt, undefined, String(error));
+      throw error;
+    }
+  };
+}
+
+/** Names of the tools a connected client's server marks as read-only. */
+export async function readOnlyToolNames(client: any): Promise<Set<string>> {
+  try {
+    // `listTools` keeps the annotations that `tools()` drops.
+    const { tools } = await client.listTools();
+    return new Set(
+      (tools as any[]).filter((tool) => tool.annotations?.readOnlyHint === true).map((tool) => tool.name),
+    );
+  } catch {
+    return new Set();
+  }
+}
@@ -0,0 +1,40 @@
+import { commands, type McpServer } from "./bindings.gen";
+
+/** Headers for an SSE server, including a fresh OAuth access token when configured. */
+export async function getAuthHeaders(server: McpServer, forceRefresh = false): Promise<Record<string, string>> {
+  const headers: Record<string, string> = {};
+
+  if (server.headerKey && server.headerValue) {
+    headers[server.headerKey] = server.headerValue;
+  }
+
+  if (server.oauth && server.id) {
+    const token = await commands.getAccessToken(server.id, forceRefresh);
+    if (token) {
+      headers.Authorization = `Bearer ${token}`;
+    }
+  }
+
+  return headers;
+}
+
+/** Whether `error` means the server turned down an OAuth token that a refresh may fix. */
+export function isUnauthorized(server: McpServer, error: unknown): boolean {
+  return !!server.oauth && /\b401\b|unauthorized/i.test(String(error));
+}
+
+/** Connects with the current token, and once more with a refreshed one if the server rejects it. */
+export async function withAuthRetry<T>(
+  server: McpServer,
+  connect: (headers: Record<string, string>) => Promise<T>,
+  forceRefresh = false,
+): Promise<T> {
+  try {
+    return await connect(await getAuthHeaders(server, forceRefresh));
+  } catch (error) {
+    if (forceRefresh || !isUnauthorized(server, error)) {
+      throw error;
+    }
+    return connect(await getAuthHeaders(server, true));
+  }
+}
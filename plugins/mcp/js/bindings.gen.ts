@@ -12,6 +12,51 @@ async getServers() : Promise<McpServer[]> {
 },
 async setServers(servers: McpServer[]) : Promise<null> {
     return await TAURI_INVOKE("plugin:mcp|set_servers", { servers });
+},
+async probeServer(id: string) : Promise<ProbeResult> {
+    return await TAURI_INVOKE("plugin:mcp|probe_server", { id });
+},
+async setServerCredentials(id: string, clientSecret: string | null, refreshToken: string | null) : Promise<null> {
+    return await TAURI_INVOKE("plugin:mcp|set_server_credentials", { id, clientSecret, refreshToken });
+},
+async getAccessToken(id: string, forceRefresh: boolean) : Promise<string | null> {
+    return await TAURI_INVOKE("plugin:mcp|get_access_token", { id, forceRefresh });
+},
+async getConsentPolicy(id: string) : Promise<ConsentPolicy> {
+    return await TAURI_INVOKE("plugin:mcp|get_consent_policy", { id });
+},
+async setConsentPolicy(id: string, policy: ConsentPolicy) : Promise<null> {
+    return await TAURI_INVOKE("plugin:mcp|set_consent_policy", { id, policy });
+},
+async authorizeToolCall(serverId: string, tool: string, arguments: JsonValue, readOnly: boolean) : Promise<boolean> {
+    return await TAURI_INVOKE("plugin:mcp|authorize_tool_call", { serverId, tool, arguments, readOnly });
+},
+async recordToolCall(call: ToolCall) : Promise<null> {
+    return await TAURI_INVOKE("plugin:mcp|record_tool_call", { call });
+},
+async listToolCalls(serverId: string | null) : Promise<ToolCallRecord[]> {
+    return await TAURI_INVOKE("plugin:mcp|list_tool_calls", { serverId });
+},
+async clearToolCalls() : Promise<null> {
+    return await TAURI_INVOKE("plugin:mcp|clear_tool_calls");
+},
+async connectStdioServer(serverId: string, channel: TAURI_CHANNEL<StdioEvent>) : Promise<string> {
+    return await TAURI_INVOKE("plugin:mcp|connect_stdio_server", { serverId, channel });
+},
+async sendStdioMessage(id: string, message: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:mcp|send_stdio_message", { id, message });
+},
+async closeStdioServer(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:mcp|close_stdio_server", { id });
+},
+async getLocalServerConfig() : Promise<LocalServerConfig> {
+    return await TAURI_INVOKE("plugin:mcp|get_local_server_config");
+},
+async setLocalServerConfig(config: LocalServerConfig) : Promise<null> {
+    return await TAURI_INVOKE("plugin:mcp|set_local_server_config", { config });
+},
+async isLocalServerRunning() : Promise<boolean> {
+    return await TAURI_INVOKE("plugin:mcp|is_local_server_running");
 }
 }
 
@@ -25,7 +70,38 @@ async setServers(servers: McpServer[]) : Promise<null> {
 
 /** user-defined types **/
 
-export type McpServer = { type: string; enabled: boolean; url: string; headerKey: string | null; headerValue: string | null }
+export type AuthStatus = "none" | "authorized" | "unauthorized"
+/**
+ * Whether the chat asks before running a tool from this server.
+ */
+export type ConsentPolicy = "always_ask" | "allow_read_only" | "allow_all"
+export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
+export type LocalServerConfig = { enabled: boolean; port: number; 
+/**
+ * Clients send it as `X-API-Key` or as a bearer token. Generated once per install.
+ */
+api_key?: string }
+export type McpOAuthConfig = { grant: OAuthGrant; token_url: string; client_id: string; scope: string | null }
+export type McpServer = { id?: string; type: string; enabled: boolean; url: string; headerKey: string | null; headerValue: string | null; oauth?: McpOAuthConfig | null; consent?: ConsentPolicy; command?: string | null; args?: string[]; env?: Partial<{ [key in string]: string }> }
+export type OAuthGrant = "client_credentials" | "refresh_token"
+export type ProbePrompt = { name: string; description: string | null; arguments: JsonValue }
+export type ProbeResource = { uri: string; name: string; description: string | null; mime_type: string | null }
+export type ProbeResult = { ok: boolean; error: string | null; auth: AuthStatus; 
+/**
+ * Time to complete the initialize handshake.
+ */
+latency_ms: number | null; server_name: string | null; server_version: string | null; protocol_version: string | null; tools: ProbeTool[]; resources: ProbeResource[]; prompts: ProbePrompt[] }
+export type ProbeTool = { name: string; description: string | null; input_schema: JsonValue }
+export type StdioEvent = { type: "message"; message: string } | { type: "restarted"; attempt: number } | { type: "closed"; error: string | null }
+/**
+ * A finished tool call, as reported by the chat after `execute` returns.
+ */
+export type ToolCall = { server_id: string; tool: string; arguments: JsonValue; duration_ms: number; result_size: number; error: string | null }
+export type ToolCallRecord = { id: string; server_id: string; tool: string; 
+/**
+ * Arguments can contain note content, so only their hash is kept.
+ */
+arguments_hash: string; duration_ms: number; result_size: number; error: string | null; called_at: string }
 
 /** tauri-specta globals **/
 
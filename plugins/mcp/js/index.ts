@@ -1 +1,4 @@
+export * from "./audit";
+export * from "./auth";
 export * from "./bindings.gen";
+export * from "./stdio";
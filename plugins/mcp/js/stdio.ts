@@ -0,0 +1,59 @@
+import { Channel } from "@tauri-apps/api/core";
+
+import { commands, type McpServer, type StdioEvent } from "./bindings.gen";
+
+/**
+ * MCP transport over the stdin/stdout of a local process spawned by the plugin.
+ * Can be passed as `transport` to `experimental_createMCPClient`.
+ */
+export class StdioTransport {
+  // Typed loosely so the instance fits the transport interface of any MCP client.
+  onmessage?: (message: any) => void;
+  onerror?: (error: Error) => void;
+  onclose?: () => void;
+
+  private id: string | null = null;
+
+  constructor(private readonly server: McpServer) {}
+
+  async start() {
+    const channel = new Channel<StdioEvent>();
+
+    channel.onmessage = (event) => {
+      switch (event.type) {
+        case "message":
+          try {
+            this.onmessage?.(JSON.parse(event.message));
+          } catch (error) {
+            this.onerror?.(error as Error);
+          }
+          break;
+        case "restarted":
+          console.warn(`[MCP] ${this.server.command} restarted (attempt ${event.attempt})`);
+          break;
+        case "closed":
+          this.id = null;
+          if (event.error) {
+            this.onerror?.(new Error(event.error));
+          }
+          this.onclose?.();
+          break;
+      }
+    };
+
+    this.id = await commands.connectStdioServer(this.server.id, channel);
+  }
+
+  async send(message: unknown) {
+    if (!this.id) {
+      throw new Error("MCP stdio transport is not connected");
+    }
+    await commands.sendStdioMessage(this.id, JSON.stringify(message));
+  }
+
+  async close() {
+    if (this.id) {
+      await commands.closeStdioServer(this.id);
+    }
+  }
+}
@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+
+pub const MAX_AUDIT_ENTRIES: usize = 500;
+
+/// A finished tool call, as reported by the chat after `execute` returns.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct ToolCall {
+    pub server_id: String,
+    pub tool: String,
+    pub arguments: serde_json::Value,
+    pub duration_ms: u64,
+    pub result_size: u64,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct ToolCallRecord {
+    pub id: String,
+    pub server_id: String,
+    pub tool: String,
+    /// Arguments can contain note content, so only their hash is kept.
+    pub arguments_hash: String,
+    pub duration_ms: u64,
+    pub result_size: u64,
+    pub error: Option<String>,
+    pub called_at: DateTime<Utc>,
+}
+
+impl From<ToolCall> for ToolCallRecord {
+    fn from(call: ToolCall) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            arguments_hash: hash_arguments(&call.arguments),
+            server_id: call.server_id,
+            tool: call.tool,
+            duration_ms: call.duration_ms,
+            result_size: call.result_size,
+            error: call.error,
+            called_at: Utc::now(),
+        }
+    }
+}
+
+// `serde_json::Value` keeps object keys sorted, so equal arguments hash equally
+// regardless of the order the model produced them in.
+fn hash_arguments(arguments: &serde_json::Value) -> String {
+    hex::encode(Sha256::digest(arguments.to_string().as_bytes()))
+}
+
+/// Appends to the log, dropping the oldest entries beyond `MAX_AUDIT_ENTRIES`.
+pub fn append(log: &mut Vec<ToolCallRecord>, record: ToolCallRecord) {
+    log.push(record);
+    let overflow = log.len().saturating_sub(MAX_AUDIT_ENTRIES);
+    log.drain(..overflow);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(arguments: serde_json::Value) -> ToolCall {
+        ToolCall {
+            server_id: "1".to_string(),
+            tool: "search".to_string(),
+            arguments,
+            duration_ms: 12,
+            result_size: 340,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_hash_arguments() {
+        let a: serde_json::Value = serde_json::from_str(r#"{"query":"q","limit":5}"#).unwrap();
+        let b: serde_json::Value = serde_json::from_str(r#"{"limit":5,"query":"q"}"#).unwrap();
+
+        assert_eq!(hash_arguments(&a), hash_arguments(&b));
+        assert_ne!(
+            hash_arguments(&a),
+            hash_arguments(&serde_json::json!({ "query": "q" }))
+        );
+        assert_eq!(hash_arguments(&a).len(), 64);
+    }
+
+    #[test]
+    fn test_append() {
+        let mut log = vec![];
+        for i in 0..MAX_AUDIT_ENTRIES + 5 {
+            append(&mut log, call(serde_json::json!({ "i": i })).into());
+        }
+        assert_eq!(log.len(), MAX_AUDIT_ENTRIES);
+        assert_eq!(
+            log[0].arguments_hash,
+            hash_arguments(&serde_json::json!({ "i": 5 }))
+        );
+    }
+}
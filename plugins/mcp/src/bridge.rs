@@ -0,0 +1,120 @@
+use futures_util::StreamExt;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+
+/// Serves MCP over stdin/stdout by relaying to the local server of the running app.
+///
+/// MCP clients like Claude Desktop only launch stdio servers, but the notes live in the
+/// app's database, so this process stays a thin pipe instead of opening the database itself.
+pub async fn run_stdio_bridge(port: u16, api_key: &str) -> Result<(), crate::Error> {
+    let base = url::Url::parse(&format!("http://127.0.0.1:{}", port)).unwrap();
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(base.join(crate::server::SSE_PATH).unwrap())
+        .header("Accept", "text/event-stream")
+        .header(crate::server::API_KEY_HEADER, api_key)
+        .send()
+        .await
+        .map_err(|_| crate::Error::LocalServerUnavailable(port))?;
+    if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(crate::Error::LocalServerUnauthorized);
+    }
+    let response = response
+        .error_for_status()
+        .map_err(|_| crate::Error::LocalServerUnavailable(port))?;
+
+    let mut events = response.bytes_stream();
+    let mut parser = SseParser::default();
+    let mut endpoint: Option<url::Url> = None;
+    let mut stdout = tokio::io::stdout();
+    let mut stdin = tokio::io::BufReader::new(tokio::io::stdin()).lines();
+
+    loop {
+        tokio::select! {
+            chunk = events.next() => {
+                let Some(chunk) = chunk else { break };
+
+                for (event, data) in parser.feed(&chunk?) {
+                    match event.as_str() {
+                        "endpoint" => endpoint = Some(base.join(&data)?),
+                        "message" => {
+                            stdout.write_all(data.as_bytes()).await?;
+                            stdout.write_all(b"\n").await?;
+                            stdout.flush().await?;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            line = stdin.next_line(), if endpoint.is_some() => {
+                let Some(line) = line? else { break };
+                if line.trim().is_empty() {
+                    continue;
+                }
+
+                client
+                    .post(endpoint.clone().unwrap())
+                    .header("Content-Type", "application/json")
+                    .header(crate::server::API_KEY_HEADER, api_key)
+                    .body(line)
+                    .send()
+                    .await?
+                    .error_for_status()?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Default)]
+struct SseParser {
+    buffer: Vec<u8>,
+}
+
+impl SseParser {
+    /// Returns the `(event, data)` pairs completed by this chunk.
+    fn feed(&mut self, chunk: &[u8]) -> Vec<(String, String)> {
+        self.buffer.extend(chunk.iter().filter(|b| **b != b'\r'));
+
+        let mut events = vec![];
+        while let Some(end) = self.buffer.windows(2).position(|w| w == b"\n\n") {
+            let block: Vec<u8> = self.buffer.drain(..end + 2).collect();
+            let block = String::from_utf8_lossy(&block);
+
+            let mut event = "message".to_string();
+            let mut data = vec![];
+            for line in block.lines() {
+                if let Some(value) = line.strip_prefix("event:") {
+                    event = value.trim().to_string();
+                } else if let Some(value) = line.strip_prefix("data:") {
+                    data.push(value.strip_prefix(' ').unwrap_or(value));
+                }
+            }
+
+            if !data.is_empty() {
+                events.push((event, data.join("\n")));
+            }
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sse_parser() {
+        let mut parser = SseParser::default();
+
+        assert_eq!(
+            parser.feed(b"event: endpoint\ndata: /message?sessionId=abc\n\nevent: mes"),
+            vec![("endpoint".to_string(), "/message?sessionId=abc".to_string())]
+        );
+        assert_eq!(
+            parser.feed(b"sage\r\ndata: {\"id\":1}\r\n\r\n: keep-alive\n\n"),
+            vec![("message".to_string(), "{\"id\":1}".to_string())]
+        );
+    }
+}
@@ -16,3 +16,155 @@ pub(crate) async fn set_servers<R: tauri::Runtime>(
 ) -> Result<(), String> {
     app.set_servers(servers).map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn probe_server<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<crate::ProbeResult, String> {
+    app.probe_server(id).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_server_credentials<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+    client_secret: Option<String>,
+    refresh_token: Option<String>,
+) -> Result<(), String> {
+    app.set_server_credentials(id, client_secret, refresh_token)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_access_token<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+    force_refresh: bool,
+) -> Result<Option<String>, String> {
+    app.get_access_token(id, force_refresh)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_consent_policy<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<crate::ConsentPolicy, String> {
+    app.get_consent_policy(id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_consent_policy<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+    policy: crate::ConsentPolicy,
+) -> Result<(), String> {
+    app.set_consent_policy(id, policy)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn authorize_tool_call<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    server_id: String,
+    tool: String,
+    arguments: serde_json::Value,
+    read_only: bool,
+) -> Result<bool, String> {
+    app.authorize_tool_call(server_id, tool, arguments, read_only)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn record_tool_call<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    call: crate::ToolCall,
+) -> Result<(), String> {
+    app.record_tool_call(call).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn list_tool_calls<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    server_id: Option<String>,
+) -> Result<Vec<crate::ToolCallRecord>, String> {
+    app.list_tool_calls(server_id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn clear_tool_calls<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<(), String> {
+    app.clear_tool_calls().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn connect_stdio_server<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    server_id: String,
+    channel: tauri::ipc::Channel<crate::StdioEvent>,
+) -> Result<String, String> {
+    app.connect_stdio_server(server_id, channel)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn send_stdio_message<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+    message: String,
+) -> Result<(), String> {
+    app.send_stdio_message(id, message)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn close_stdio_server<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<(), String> {
+    app.close_stdio_server(id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_local_server_config<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<crate::LocalServerConfig, String> {
+    app.local_server_config().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_local_server_config<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    config: crate::LocalServerConfig,
+) -> Result<(), String> {
+    app.set_local_server_config(config)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn is_local_server_running<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<bool, String> {
+    Ok(app.is_local_server_running())
+}
@@ -4,6 +4,32 @@ use serde::{ser::Serializer, Serialize};
 pub enum Error {
     #[error(transparent)]
     Store(#[from] tauri_plugin_store2::Error),
+    #[error(transparent)]
+    Shell(#[from] tauri_plugin_shell::Error),
+    #[error("stdio server has no command")]
+    MissingCommand,
+    #[error("stdio connection not found: {0}")]
+    ConnectionNotFound(String),
+    #[error("Hyprnote MCP server is not reachable on port {0}. Is Hyprnote running with the MCP server enabled?")]
+    LocalServerUnavailable(u16),
+    #[error(
+        "Hyprnote MCP server rejected the API key. Copy the current one from Hyprnote's settings."
+    )]
+    LocalServerUnauthorized,
+    #[error("MCP server not found: {0}")]
+    ServerNotFound(String),
+    #[error("OAuth credentials are missing for this server")]
+    MissingCredentials,
+    #[error("token refresh failed: {0}")]
+    TokenRefreshFailed(String),
+    #[error(transparent)]
+    Keyring(#[from] keyring::Error),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+    #[error(transparent)]
+    Url(#[from] url::ParseError),
 }
 
 impl Serialize for Error {
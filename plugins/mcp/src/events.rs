@@ -0,0 +1,13 @@
+use tauri::Manager;
+
+pub fn on_event<R: tauri::Runtime>(app: &tauri::AppHandle<R>, event: &tauri::RunEvent) {
+    // Spawned servers would otherwise outlive the app.
+    if let tauri::RunEvent::Exit = event {
+        let state = app.state::<crate::SharedState>();
+        let connections = std::mem::take(&mut state.lock().unwrap().connections);
+
+        for connection in connections.values() {
+            connection.kill();
+        }
+    }
+}
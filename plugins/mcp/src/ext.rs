@@ -1,10 +1,74 @@
-use crate::error::Error;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use tauri::{ipc::Channel, Manager};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
 use tauri_plugin_store2::StorePluginExt;
 
+use crate::error::Error;
+use crate::stdio::{Control, StdioConnection, StdioEvent, ToolRequest, Tools};
+
 pub trait McpPluginExt<R: tauri::Runtime> {
     fn mcp_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey>;
     fn get_servers(&self) -> Result<Vec<crate::McpServer>, Error>;
     fn set_servers(&self, servers: Vec<crate::McpServer>) -> Result<(), Error>;
+    fn probe_server(
+        &self,
+        id: impl Into<String>,
+    ) -> impl Future<Output = Result<crate::ProbeResult, Error>>;
+
+    fn set_server_credentials(
+        &self,
+        id: impl Into<String>,
+        client_secret: Option<String>,
+        refresh_token: Option<String>,
+    ) -> Result<(), Error>;
+    fn get_access_token(
+        &self,
+        id: impl Into<String>,
+        force_refresh: bool,
+    ) -> impl Future<Output = Result<Option<String>, Error>>;
+
+    fn get_consent_policy(&self, id: impl Into<String>) -> Result<crate::ConsentPolicy, Error>;
+    fn set_consent_policy(
+        &self,
+        id: impl Into<String>,
+        policy: crate::ConsentPolicy,
+    ) -> Result<(), Error>;
+    fn authorize_tool_call(
+        &self,
+        server_id: impl Into<String>,
+        tool: impl Into<String>,
+        arguments: serde_json::Value,
+        read_only: bool,
+    ) -> impl Future<Output = Result<bool, Error>>;
+    fn record_tool_call(&self, call: crate::ToolCall) -> Result<(), Error>;
+    fn list_tool_calls(
+        &self,
+        server_id: Option<String>,
+    ) -> Result<Vec<crate::ToolCallRecord>, Error>;
+    fn clear_tool_calls(&self) -> Result<(), Error>;
+
+    fn connect_stdio_server(
+        &self,
+        server_id: impl Into<String>,
+        channel: Channel<StdioEvent>,
+    ) -> Result<String, Error>;
+    fn send_stdio_message(
+        &self,
+        id: impl Into<String>,
+        message: String,
+    ) -> impl Future<Output = Result<(), Error>>;
+    fn close_stdio_server(&self, id: impl Into<String>) -> Result<(), Error>;
+
+    fn local_server_config(&self) -> Result<crate::LocalServerConfig, Error>;
+    fn set_local_server_config(
+        &self,
+        config: crate::LocalServerConfig,
+    ) -> impl Future<Output = Result<(), Error>>;
+    fn is_local_server_running(&self) -> bool;
+    fn start_local_server(&self) -> impl Future<Output = Result<(), Error>>;
+    fn stop_local_server(&self);
 }
 
 impl<R: tauri::Runtime> McpPluginExt<R> for tauri::AppHandle<R> {
@@ -14,13 +78,409 @@ impl<R: tauri::Runtime> McpPluginExt<R> for tauri::AppHandle<R> {
 
     fn get_servers(&self) -> Result<Vec<crate::McpServer>, Error> {
         let store = self.mcp_store();
-        let servers = store.get(crate::StoreKey::Servers)?.unwrap_or_default();
+        let mut servers: Vec<crate::McpServer> =
+            store.get(crate::StoreKey::Servers)?.unwrap_or_default();
+
+        // Entries saved before servers had ids.
+        if assign_ids(&mut servers) {
+            store.set(crate::StoreKey::Servers, servers.clone())?;
+        }
         Ok(servers)
     }
 
-    fn set_servers(&self, servers: Vec<crate::McpServer>) -> Result<(), Error> {
+    fn set_servers(&self, mut servers: Vec<crate::McpServer>) -> Result<(), Error> {
+        assign_ids(&mut servers);
+
+        for removed in self
+            .get_servers()?
+            .iter()
+            .filter(|old| !servers.iter().any(|server| server.id == old.id))
+        {
+            if let Err(e) = crate::oauth::delete(&removed.id) {
+                tracing::warn!("mcp_credentials_delete_failed: {}", e);
+            }
+        }
+
         let store = self.mcp_store();
         store.set(crate::StoreKey::Servers, servers)?;
         Ok(())
     }
+
+    async fn probe_server(&self, id: impl Into<String>) -> Result<crate::ProbeResult, Error> {
+        let id = id.into();
+        let server = find_server(self, &id)?;
+
+        let token = self.get_access_token(&id, false).await?;
+        let result = crate::probe::probe(&server, token).await;
+
+        // The cached token may have been revoked before its expiry.
+        if server.oauth.is_some() && result.auth == crate::AuthStatus::Unauthorized {
+            let token = self.get_access_token(&id, true).await?;
+            return Ok(crate::probe::probe(&server, token).await);
+        }
+        Ok(result)
+    }
+
+    fn set_server_credentials(
+        &self,
+        id: impl Into<String>,
+        client_secret: Option<String>,
+        refresh_token: Option<String>,
+    ) -> Result<(), Error> {
+        let id = id.into();
+        find_server(self, &id)?;
+
+        let credentials = crate::oauth::Credentials {
+            client_secret,
+            refresh_token,
+            access_token: None,
+            expires_at: None,
+        };
+        crate::oauth::save(&id, &credentials)
+    }
+
+    async fn get_access_token(
+        &self,
+        id: impl Into<String>,
+        force_refresh: bool,
+    ) -> Result<Option<String>, Error> {
+        let id = id.into();
+        let Some(config) = find_server(self, &id)?.oauth else {
+            return Ok(None);
+        };
+
+        let lock = self
+            .state::<crate::SharedState>()
+            .lock()
+            .unwrap()
+            .refresh_lock
+            .clone();
+        // Concurrent callers would otherwise each spend the same refresh token.
+        let _guard = lock.lock().await;
+
+        let mut credentials = crate::oauth::load(&id)?;
+        if force_refresh || credentials.needs_refresh(chrono::Utc::now().timestamp()) {
+            crate::oauth::refresh(&hypr_proxy::client(), &config, &mut credentials).await?;
+            crate::oauth::save(&id, &credentials)?;
+        }
+        Ok(credentials.access_token)
+    }
+
+    fn get_consent_policy(&self, id: impl Into<String>) -> Result<crate::ConsentPolicy, Error> {
+        Ok(find_server(self, &id.into())?.consent)
+    }
+
+    fn set_consent_policy(
+        &self,
+        id: impl Into<String>,
+        policy: crate::ConsentPolicy,
+    ) -> Result<(), Error> {
+        let id = id.into();
+        let mut servers = self.get_servers()?;
+
+        let server = servers
+            .iter_mut()
+            .find(|server| server.id == id)
+            .ok_or_else(|| Error::ServerNotFound(id.clone()))?;
+        server.consent = policy;
+
+        self.mcp_store().set(crate::StoreKey::Servers, servers)?;
+        Ok(())
+    }
+
+    async fn authorize_tool_call(
+        &self,
+        server_id: impl Into<String>,
+        tool: impl Into<String>,
+        arguments: serde_json::Value,
+        read_only: bool,
+    ) -> Result<bool, Error> {
+        let server = find_server(self, &server_id.into())?;
+        let tool = tool.into();
+
+        if !server.consent.needs_consent(read_only) {
+            return Ok(true);
+        }
+
+        let source = match server.r#type.as_str() {
+            "stdio" => server.command.clone().unwrap_or_default(),
+            _ => server.url.clone(),
+        };
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.dialog()
+            .message(format!(
+                "Allow the assistant to run \"{}\" from {}?",
+                tool, source
+            ))
+            .title("Run MCP tool?")
+            .kind(MessageDialogKind::Warning)
+            .buttons(MessageDialogButtons::OkCancel)
+            .show(move |approved| {
+                let _ = tx.send(approved);
+            });
+
+        let approved = rx.await.unwrap_or(false);
+        if !approved {
+            self.record_tool_call(crate::ToolCall {
+                server_id: server.id,
+                tool,
+                arguments,
+                duration_ms: 0,
+                result_size: 0,
+                error: Some(crate::stdio::DECLINED.to_string()),
+            })?;
+        }
+        Ok(approved)
+    }
+
+    fn record_tool_call(&self, call: crate::ToolCall) -> Result<(), Error> {
+        tracing::info!(
+            "mcp_tool_call server={} tool={} duration_ms={} ok={}",
+            call.server_id,
+            call.tool,
+            call.duration_ms,
+            call.error.is_none()
+        );
+
+        let lock = audit_lock(self);
+        let _guard = lock.lock().unwrap();
+
+        let mut log = audit_log(self)?;
+        crate::audit::append(&mut log, call.into());
+
+        let store = self.mcp_store();
+        store.set(crate::StoreKey::AuditLog, log)?;
+        store.save()?;
+        Ok(())
+    }
+
+    fn list_tool_calls(
+        &self,
+        server_id: Option<String>,
+    ) -> Result<Vec<crate::ToolCallRecord>, Error> {
+        Ok(audit_log(self)?
+            .into_iter()
+            .rev()
+            .filter(|record| server_id.as_ref().is_none_or(|id| &record.server_id == id))
+            .collect())
+    }
+
+    fn clear_tool_calls(&self) -> Result<(), Error> {
+        let lock = audit_lock(self);
+        let _guard = lock.lock().unwrap();
+
+        let store = self.mcp_store();
+        store.set(
+            crate::StoreKey::AuditLog,
+            Vec::<crate::ToolCallRecord>::new(),
+        )?;
+        store.save()?;
+        Ok(())
+    }
+
+    fn connect_stdio_server(
+        &self,
+        server_id: impl Into<String>,
+        channel: Channel<StdioEvent>,
+    ) -> Result<String, Error> {
+        // Only what the user saved is ever spawned, never a command line from the webview.
+        let server = find_server(self, &server_id.into())?;
+        let (events, child) = crate::stdio::spawn(self, &server)?;
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let (control_tx, control_rx) = tokio::sync::mpsc::unbounded_channel();
+        let child = Arc::new(Mutex::new(Some(child)));
+        let tools = Arc::new(Mutex::new(Tools::new(&server.id)));
+
+        self.state::<crate::SharedState>()
+            .lock()
+            .unwrap()
+            .connections
+            .insert(
+                id.clone(),
+                StdioConnection {
+                    server_id: server.id.clone(),
+                    control: control_tx,
+                    child: child.clone(),
+                    tools: tools.clone(),
+                },
+            );
+
+        tauri::async_runtime::spawn(crate::stdio::run(
+            self.clone(),
+            id.clone(),
+            server,
+            channel,
+            control_rx,
+            child,
+            tools,
+            events,
+        ));
+
+        Ok(id)
+    }
+
+    async fn send_stdio_message(
+        &self,
+        id: impl Into<String>,
+        message: String,
+    ) -> Result<(), Error> {
+        let id = id.into();
+        let (server_id, control, tools) = {
+            let state = self.state::<crate::SharedState>();
+            let guard = state.lock().unwrap();
+
+            let connection = guard
+                .connections
+                .get(&id)
+                .ok_or(Error::ConnectionNotFound(id.clone()))?;
+            (
+                connection.server_id.clone(),
+                connection.control.clone(),
+                connection.tools.clone(),
+            )
+        };
+
+        // Tool calls only reach the server once the consent policy allows them.
+        if let Some(request) = ToolRequest::parse(&message) {
+            let read_only = tools.lock().unwrap().is_read_only(&request.tool);
+            let approved = self
+                .authorize_tool_call(
+                    server_id,
+                    request.tool.clone(),
+                    request.arguments.clone(),
+                    read_only,
+                )
+                .await?;
+
+            if !approved {
+                return control
+                    .send(Control::Reply(request.declined()))
+                    .map_err(|_| Error::ConnectionNotFound(id));
+            }
+        }
+
+        control
+            .send(Control::Send(message))
+            .map_err(|_| Error::ConnectionNotFound(id))
+    }
+
+    fn close_stdio_server(&self, id: impl Into<String>) -> Result<(), Error> {
+        let id = id.into();
+        let state = self.state::<crate::SharedState>();
+        let guard = state.lock().unwrap();
+
+        let connection = guard
+            .connections
+            .get(&id)
+            .ok_or(Error::ConnectionNotFound(id))?;
+        let _ = connection.control.send(Control::Close);
+        Ok(())
+    }
+
+    fn local_server_config(&self) -> Result<crate::LocalServerConfig, Error> {
+        let store = self.mcp_store();
+        let stored: Option<crate::LocalServerConfig> = store.get(crate::StoreKey::LocalServer)?;
+
+        // Saved right away, so the key the server checks is the one clients were given.
+        match stored {
+            Some(config) if !config.api_key.is_empty() => Ok(config),
+            stored => {
+                let config = crate::LocalServerConfig {
+                    api_key: crate::server::generate_api_key(),
+                    ..stored.unwrap_or_default()
+                };
+                store.set(crate::StoreKey::LocalServer, config.clone())?;
+                store.save()?;
+                Ok(config)
+            }
+        }
+    }
+
+    async fn set_local_server_config(&self, config: crate::LocalServerConfig) -> Result<(), Error> {
+        // The key is only ever generated here, never taken from the webview.
+        let config = crate::LocalServerConfig {
+            api_key: self.local_server_config()?.api_key,
+            ..config
+        };
+        {
+            let store = self.mcp_store();
+            store.set(crate::StoreKey::LocalServer, config.clone())?;
+            store.save()?;
+        }
+
+        self.stop_local_server();
+        if config.enabled {
+            self.start_local_server().await?;
+        }
+        Ok(())
+    }
+
+    fn is_local_server_running(&self) -> bool {
+        let state = self.state::<crate::SharedState>();
+        let guard = state.lock().unwrap();
+        guard.server.is_some()
+    }
+
+    async fn start_local_server(&self) -> Result<(), Error> {
+        if self.is_local_server_running() {
+            return Ok(());
+        }
+
+        let config = self.local_server_config()?;
+        let handle = crate::server::run_server(self.clone(), &config).await?;
+
+        let state = self.state::<crate::SharedState>();
+        let mut guard = state.lock().unwrap();
+        // Lost a race with another start; keep the server that got there first.
+        if guard.server.is_some() {
+            handle.shutdown.cancel();
+        } else {
+            guard.server = Some(handle);
+        }
+        Ok(())
+    }
+
+    fn stop_local_server(&self) {
+        let state = self.state::<crate::SharedState>();
+        let mut guard = state.lock().unwrap();
+        if let Some(server) = guard.server.take() {
+            server.shutdown.cancel();
+        }
+    }
+}
+
+fn assign_ids(servers: &mut [crate::McpServer]) -> bool {
+    let mut changed = false;
+    for server in servers.iter_mut().filter(|server| server.id.is_empty()) {
+        server.id = uuid::Uuid::new_v4().to_string();
+        changed = true;
+    }
+    changed
+}
+
+fn find_server<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    id: &str,
+) -> Result<crate::McpServer, Error> {
+    app.get_servers()?
+        .into_iter()
+        .find(|server| server.id == id)
+        .ok_or_else(|| Error::ServerNotFound(id.to_string()))
+}
+
+fn audit_log<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+) -> Result<Vec<crate::ToolCallRecord>, Error> {
+    let v = app.mcp_store().get(crate::StoreKey::AuditLog)?;
+    Ok(v.unwrap_or_default())
+}
+
+// Separate from the connections, so tool calls don't wait on the audit log being saved.
+fn audit_lock<R: tauri::Runtime>(app: &tauri::AppHandle<R>) -> Arc<Mutex<()>> {
+    app.state::<crate::SharedState>()
+        .lock()
+        .unwrap()
+        .audit_lock
+        .clone()
 }
@@ -1,13 +1,26 @@
+use std::collections::HashMap;
 use std::sync::Mutex;
 use tauri::Manager;
 
+mod audit;
+mod bridge;
 mod commands;
 mod error;
+mod events;
 mod ext;
+mod oauth;
+mod probe;
+mod server;
+mod stdio;
 mod store;
 
+pub use audit::{ToolCall, ToolCallRecord};
+pub use bridge::run_stdio_bridge;
 pub use error::*;
 pub use ext::*;
+pub use probe::{AuthStatus, ProbePrompt, ProbeResource, ProbeResult, ProbeTool};
+pub use server::{LocalServerConfig, API_KEY_ENV, DEFAULT_PORT};
+pub use stdio::StdioEvent;
 pub use store::*;
 
 const PLUGIN_NAME: &str = "mcp";
@@ -15,7 +28,12 @@ const PLUGIN_NAME: &str = "mcp";
 pub type SharedState = Mutex<State>;
 
 #[derive(Default)]
-pub struct State {}
+pub struct State {
+    connections: HashMap<String, stdio::StdioConnection>,
+    server: Option<server::ServerHandle>,
+    refresh_lock: std::sync::Arc<tokio::sync::Mutex<()>>,
+    audit_lock: std::sync::Arc<Mutex<()>>,
+}
 
 fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
     tauri_specta::Builder::<R>::new()
@@ -23,6 +41,21 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
         .commands(tauri_specta::collect_commands![
             commands::get_servers::<tauri::Wry>,
             commands::set_servers::<tauri::Wry>,
+            commands::probe_server::<tauri::Wry>,
+            commands::set_server_credentials::<tauri::Wry>,
+            commands::get_access_token::<tauri::Wry>,
+            commands::get_consent_policy::<tauri::Wry>,
+            commands::set_consent_policy::<tauri::Wry>,
+            commands::authorize_tool_call::<tauri::Wry>,
+            commands::record_tool_call::<tauri::Wry>,
+            commands::list_tool_calls::<tauri::Wry>,
+            commands::clear_tool_calls::<tauri::Wry>,
+            commands::connect_stdio_server::<tauri::Wry>,
+            commands::send_stdio_message::<tauri::Wry>,
+            commands::close_stdio_server::<tauri::Wry>,
+            commands::get_local_server_config::<tauri::Wry>,
+            commands::set_local_server_config::<tauri::Wry>,
+            commands::is_local_server_running::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
@@ -36,8 +69,23 @@ pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
             let state = SharedState::default();
             app.manage(state);
 
+            let app_handle = app.app_handle().clone();
+            tauri::async_runtime::spawn(async move {
+                let enabled = app_handle
+                    .local_server_config()
+                    .map(|config| config.enabled)
+                    .unwrap_or(false);
+
+                if enabled {
+                    if let Err(e) = app_handle.start_local_server().await {
+                        tracing::error!("mcp_local_server_start_failed: {}", e);
+                    }
+                }
+            });
+
             Ok(())
         })
+        .on_event(events::on_event)
         .build()
 }
 
@@ -74,11 +122,17 @@ mod test {
             use crate::McpPluginExt;
             assert_eq!(app.handle().get_servers().unwrap(), vec![]);
             let servers = vec![crate::McpServer {
+                id: "1".to_string(),
                 r#type: "sse".to_string(),
                 enabled: true,
                 url: "http://localhost:1420".to_string(),
                 header_key: None,
                 header_value: None,
+                oauth: None,
+                consent: Default::default(),
+                command: None,
+                args: vec![],
+                env: Default::default(),
             }];
             app.handle().set_servers(servers.clone()).unwrap();
             assert_eq!(app.handle().get_servers().unwrap(), servers);
@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+
+const KEYRING_SERVICE: &str = "hyprnote-mcp";
+// Refresh a bit early, so a token never expires mid-request.
+const EXPIRY_SKEW_SECS: i64 = 60;
+
+/// Secrets for one server, kept in the OS keychain rather than the plugin store.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Credentials {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
+}
+
+impl Credentials {
+    pub fn needs_refresh(&self, now: i64) -> bool {
+        match (&self.access_token, self.expires_at) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(_), Some(expires_at)) => expires_at - EXPIRY_SKEW_SECS <= now,
+        }
+    }
+
+    fn apply(&mut self, token: TokenResponse, now: i64) {
+        self.access_token = Some(token.access_token);
+        self.expires_at = token.expires_in.map(|secs| now + secs);
+        // Servers that rotate refresh tokens send a new one; others keep the old one valid.
+        if let Some(refresh_token) = token.refresh_token {
+            self.refresh_token = Some(refresh_token);
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<i64>,
+    refresh_token: Option<String>,
+}
+
+fn entry(server_id: &str) -> Result<keyring::Entry, crate::Error> {
+    Ok(keyring::Entry::new(KEYRING_SERVICE, server_id)?)
+}
+
+pub fn load(server_id: &str) -> Result<Credentials, crate::Error> {
+    match entry(server_id)?.get_password() {
+        Ok(v) => Ok(serde_json::from_str(&v).unwrap_or_default()),
+        Err(keyring::Error::NoEntry) => Ok(Credentials::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub fn save(server_id: &str, credentials: &Credentials) -> Result<(), crate::Error> {
+    entry(server_id)?.set_password(&serde_json::to_string(credentials).unwrap())?;
+    Ok(())
+}
+
+pub fn delete(server_id: &str) -> Result<(), crate::Error> {
+    match entry(server_id)?.delete_credential() {
+        Ok(_) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn token_params<'a>(
+    config: &'a crate::McpOAuthConfig,
+    credentials: &'a Credentials,
+) -> Result<HashMap<&'static str, &'a str>, crate::Error> {
+    let mut params = HashMap::from([("client_id", config.client_id.as_str())]);
+
+    match config.grant {
+        crate::OAuthGrant::ClientCredentials => {
+            let secret = credentials
+                .client_secret
+                .as_deref()
+                .ok_or(crate::Error::MissingCredentials)?;
+            params.insert("grant_type", "client_credentials");
+            params.insert("client_secret", secret);
+        }
+        crate::OAuthGrant::RefreshToken => {
+            let refresh_token = credentials
+                .refresh_token
+                .as_deref()
+                .ok_or(crate::Error::MissingCredentials)?;
+            params.insert("grant_type", "refresh_token");
+            params.insert("refresh_token", refresh_token);
+            if let Some(secret) = credentials.client_secret.as_deref() {
+                params.insert("client_secret", secret);
+            }
+        }
+    }
+
+    if let Some(scope) = config.scope.as_deref().filter(|s| !s.is_empty()) {
+        params.insert("scope", scope);
+    }
+    Ok(params)
+}
+
+/// Exchanges the stored grant for a new access token and updates `credentials` in place.
+pub async fn refresh(
+    client: &reqwest::Client,
+    config: &crate::McpOAuthConfig,
+    credentials: &mut Credentials,
+) -> Result<(), crate::Error> {
+    let response = client
+        .post(&config.token_url)
+        .form(&token_params(config, credentials)?)
+        .send()
+        .await?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(crate::Error::TokenRefreshFailed(format!(
+            "{}: {}",
+            status,
+            body.trim()
+        )));
+    }
+
+    let token: TokenResponse = response.json().await?;
+    credentials.apply(token, chrono::Utc::now().timestamp());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(grant: crate::OAuthGrant) -> crate::McpOAuthConfig {
+        crate::McpOAuthConfig {
+            grant,
+            token_url: "https://auth.example.com/token".to_string(),
+            client_id: "hyprnote".to_string(),
+            scope: Some("mcp".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_needs_refresh() {
+        let mut credentials = Credentials::default();
+        assert!(credentials.needs_refresh(1000));
+
+        credentials.access_token = Some("token".to_string());
+        assert!(!credentials.needs_refresh(1000));
+
+        credentials.expires_at = Some(1100);
+        assert!(!credentials.needs_refresh(1000));
+        assert!(credentials.needs_refresh(1040));
+    }
+
+    #[test]
+    fn test_apply_keeps_refresh_token() {
+        let mut credentials = Credentials {
+            refresh_token: Some("r1".to_string()),
+            ..Default::default()
+        };
+
+        credentials.apply(
+            TokenResponse {
+                access_token: "a1".to_string(),
+                expires_in: Some(3600),
+                refresh_token: None,
+            },
+            1000,
+        );
+        assert_eq!(credentials.access_token.as_deref(), Some("a1"));
+        assert_eq!(credentials.expires_at, Some(4600));
+        assert_eq!(credentials.refresh_token.as_deref(), Some("r1"));
+
+        credentials.apply(
+            TokenResponse {
+                access_token: "a2".to_string(),
+                expires_in: None,
+                refresh_token: Some("r2".to_string()),
+            },
+            2000,
+        );
+        assert_eq!(credentials.refresh_token.as_deref(), Some("r2"));
+        assert_eq!(credentials.expires_at, None);
+    }
+
+    #[test]
+    fn test_token_params() {
+        let credentials = Credentials {
+            client_secret: Some("secret".to_string()),
+            refresh_token: Some("refresh".to_string()),
+            ..Default::default()
+        };
+
+        let client_credentials = config(crate::OAuthGrant::ClientCredentials);
+        let params = token_params(&client_credentials, &credentials).unwrap();
+        assert_eq!(params["grant_type"], "client_credentials");
+        assert_eq!(params["client_secret"], "secret");
+        assert!(!params.contains_key("refresh_token"));
+
+        let refresh_token = config(crate::OAuthGrant::RefreshToken);
+        let params = token_params(&refresh_token, &credentials).unwrap();
+        assert_eq!(params["grant_type"], "refresh_token");
+        assert_eq!(params["refresh_token"], "refresh");
+        assert_eq!(params["scope"], "mcp");
+
+        assert!(matches!(
+            token_params(&client_credentials, &Credentials::default()),
+            Err(crate::Error::MissingCredentials)
+        ));
+    }
+}
@@ -0,0 +1,272 @@
+use std::time::{Duration, Instant};
+
+use rmcp::{
+    service::RunningService,
+    transport::{sse_client::SseClientConfig, SseClientTransport, TokioChildProcess},
+    RoleClient, ServiceExt,
+};
+use serde_json::Value;
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthStatus {
+    // The server entry has no credentials configured.
+    None,
+    Authorized,
+    Unauthorized,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct ProbeResult {
+    pub ok: bool,
+    pub error: Option<String>,
+    pub auth: AuthStatus,
+    /// Time to complete the initialize handshake.
+    pub latency_ms: Option<u64>,
+    pub server_name: Option<String>,
+    pub server_version: Option<String>,
+    pub protocol_version: Option<String>,
+    pub tools: Vec<ProbeTool>,
+    pub resources: Vec<ProbeResource>,
+    pub prompts: Vec<ProbePrompt>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct ProbeTool {
+    pub name: String,
+    pub description: Option<String>,
+    pub input_schema: Value,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct ProbeResource {
+    pub uri: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub mime_type: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct ProbePrompt {
+    pub name: String,
+    pub description: Option<String>,
+    pub arguments: Value,
+}
+
+impl ProbeResult {
+    fn failed(auth: AuthStatus, error: impl ToString) -> Self {
+        Self {
+            ok: false,
+            error: Some(error.to_string()),
+            auth,
+            latency_ms: None,
+            server_name: None,
+            server_version: None,
+            protocol_version: None,
+            tools: vec![],
+            resources: vec![],
+            prompts: vec![],
+        }
+    }
+}
+
+pub async fn probe(server: &crate::McpServer, bearer: Option<String>) -> ProbeResult {
+    let has_credentials =
+        bearer.is_some() || (server.header_key.is_some() && server.header_value.is_some());
+    let auth = if has_credentials {
+        AuthStatus::Authorized
+    } else {
+        AuthStatus::None
+    };
+
+    match tokio::time::timeout(PROBE_TIMEOUT, run(server, bearer.as_deref(), auth.clone())).await {
+        Ok(result) => result,
+        Err(_) => ProbeResult::failed(auth, "Timed out waiting for the server"),
+    }
+}
+
+async fn run(server: &crate::McpServer, bearer: Option<&str>, auth: AuthStatus) -> ProbeResult {
+    let started = Instant::now();
+
+    let client = if server.r#type == "stdio" {
+        connect_stdio(server).await
+    } else {
+        let http = match http_client(server, bearer) {
+            Ok(http) => http,
+            Err(e) => return ProbeResult::failed(auth, e),
+        };
+
+        // The SSE transport does not surface status codes, so check them up front.
+        match check_auth(&http, &server.url).await {
+            Ok(()) => {}
+            Err(Some(status)) => {
+                let auth = match status.as_u16() {
+                    401 | 403 => AuthStatus::Unauthorized,
+                    _ => auth,
+                };
+                return ProbeResult::failed(auth, format!("Server responded with {}", status));
+            }
+            Err(None) => return ProbeResult::failed(auth, "Could not reach the server"),
+        }
+
+        connect_sse(http, &server.url).await
+    };
+
+    let client = match client {
+        Ok(client) => client,
+        Err(e) => return ProbeResult::failed(auth, e),
+    };
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    let info = client
+        .peer_info()
+        .and_then(|info| serde_json::to_value(info).ok())
+        .unwrap_or_default();
+    let capabilities = &info["capabilities"];
+
+    let mut result = ProbeResult {
+        ok: true,
+        error: None,
+        auth,
+        latency_ms: Some(latency_ms),
+        server_name: info["serverInfo"]["name"].as_str().map(String::from),
+        server_version: info["serverInfo"]["version"].as_str().map(String::from),
+        protocol_version: info["protocolVersion"].as_str().map(String::from),
+        tools: vec![],
+        resources: vec![],
+        prompts: vec![],
+    };
+
+    // Only ask for what the server advertises; the rest would be "method not found".
+    let listed = async {
+        if capabilities.get("tools").is_some() {
+            result.tools = to_values(client.list_all_tools().await?)
+                .iter()
+                .map(|tool| ProbeTool {
+                    name: string(&tool["name"]),
+                    description: tool["description"].as_str().map(String::from),
+                    input_schema: tool["inputSchema"].clone(),
+                })
+                .collect();
+        }
+        if capabilities.get("resources").is_some() {
+            result.resources = to_values(client.list_all_resources().await?)
+                .iter()
+                .map(|resource| ProbeResource {
+                    uri: string(&resource["uri"]),
+                    name: string(&resource["name"]),
+                    description: resource["description"].as_str().map(String::from),
+                    mime_type: resource["mimeType"].as_str().map(String::from),
+                })
+                .collect();
+        }
+        if capabilities.get("prompts").is_some() {
+            result.prompts = to_values(client.list_all_prompts().await?)
+                .iter()
+                .map(|prompt| ProbePrompt {
+                    name: string(&prompt["name"]),
+                    description: prompt["description"].as_str().map(String::from),
+                    arguments: prompt
+                        .get("arguments")
+                        .cloned()
+                        .unwrap_or(Value::Array(vec![])),
+                })
+                .collect();
+        }
+        Ok::<_, rmcp::service::ServiceError>(())
+    }
+    .await;
+
+    if let Err(e) = listed {
+        result.ok = false;
+        result.error = Some(e.to_string());
+    }
+
+    let _ = client.cancel().await;
+    result
+}
+
+fn http_client(server: &crate::McpServer, bearer: Option<&str>) -> Result<reqwest::Client, String> {
+    let mut headers = reqwest::header::HeaderMap::new();
+
+    if let Some(token) = bearer {
+        let value = reqwest::header::HeaderValue::from_str(&format!("Bearer {}", token))
+            .map_err(|_| "Invalid access token".to_string())?;
+        headers.insert(reqwest::header::AUTHORIZATION, value);
+    }
+
+    if let (Some(key), Some(value)) = (&server.header_key, &server.header_value) {
+        let name = reqwest::header::HeaderName::from_bytes(key.as_bytes())
+            .map_err(|_| format!("Invalid header name: {}", key))?;
+        let value = reqwest::header::HeaderValue::from_str(value)
+            .map_err(|_| "Invalid header value".to_string())?;
+        headers.insert(name, value);
+    }
+
+    hypr_proxy::client_builder()
+        .map_err(|e| e.to_string())?
+        .default_headers(headers)
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+async fn check_auth(http: &reqwest::Client, url: &str) -> Result<(), Option<reqwest::StatusCode>> {
+    let response = http
+        .get(url)
+        .header("Accept", "text/event-stream")
+        .send()
+        .await
+        .map_err(|_| None)?;
+
+    match response.status() {
+        status if status.is_success() => Ok(()),
+        status => Err(Some(status)),
+    }
+}
+
+async fn connect_sse(
+    http: reqwest::Client,
+    url: &str,
+) -> Result<RunningService<RoleClient, ()>, String> {
+    let transport = SseClientTransport::start_with_client(
+        http,
+        SseClientConfig {
+            sse_endpoint: url.into(),
+            ..Default::default()
+        },
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+
+    ().serve(transport).await.map_err(|e| e.to_string())
+}
+
+async fn connect_stdio(
+    server: &crate::McpServer,
+) -> Result<RunningService<RoleClient, ()>, String> {
+    let command = server
+        .command
+        .as_deref()
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .ok_or_else(|| crate::Error::MissingCommand.to_string())?;
+
+    let mut cmd = tokio::process::Command::new(command);
+    cmd.args(&server.args).envs(&server.env);
+
+    let transport = TokioChildProcess::new(cmd).map_err(|e| e.to_string())?;
+    ().serve(transport).await.map_err(|e| e.to_string())
+}
+
+fn to_values<T: serde::Serialize>(items: Vec<T>) -> Vec<Value> {
+    items
+        .iter()
+        .filter_map(|item| serde_json::to_value(item).ok())
+        .collect()
+}
+
+fn string(value: &Value) -> String {
+    value.as_str().unwrap_or_default().to_string()
+}
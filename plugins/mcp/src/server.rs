@@ -0,0 +1,437 @@
+use std::net::{Ipv4Addr, SocketAddr};
+
+use axum::{
+    extract::{Request, State},
+    http::{header, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+};
+use rmcp::{
+    handler::server::{router::tool::ToolRouter, tool::Parameters},
+    model::{CallToolResult, Content, ServerCapabilities, ServerInfo},
+    schemars, tool, tool_handler, tool_router,
+    transport::sse_server::{SseServer, SseServerConfig},
+    ErrorData as McpError, ServerHandler,
+};
+use tauri_plugin_db::DatabasePluginExt;
+use tokio_util::sync::CancellationToken;
+
+pub const DEFAULT_PORT: u16 = 23542;
+pub const SSE_PATH: &str = "/sse";
+pub const MESSAGE_PATH: &str = "/message";
+pub const API_KEY_HEADER: &str = "X-API-Key";
+/// Where the stdio bridge reads the key, so it never shows up in the process list.
+pub const API_KEY_ENV: &str = "HYPRNOTE_MCP_API_KEY";
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct LocalServerConfig {
+    pub enabled: bool,
+    pub port: u16,
+    /// Clients send it as `X-API-Key` or as a bearer token. Generated once per install.
+    #[serde(default)]
+    pub api_key: String,
+}
+
+impl Default for LocalServerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: DEFAULT_PORT,
+            api_key: generate_api_key(),
+        }
+    }
+}
+
+pub fn generate_api_key() -> String {
+    format!("hypr_mcp_{}", uuid::Uuid::new_v4().simple())
+}
+
+pub struct ServerHandle {
+    pub shutdown: CancellationToken,
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct SearchNotesRequest {
+    /// Matched against note titles, note content and participant names.
+    pub query: String,
+    /// Maximum number of notes to return. Defaults to 10.
+    pub limit: Option<u8>,
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct GetTranscriptRequest {
+    /// Id of the note, as returned by `search_notes`.
+    pub session_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct CreateNoteRequest {
+    pub title: String,
+    /// Plain text, one paragraph per line.
+    #[serde(default)]
+    pub content: String,
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct ListUpcomingEventsRequest {
+    /// How far ahead to look. Defaults to 7 days.
+    pub days: Option<u32>,
+    /// Maximum number of events to return. Defaults to 20.
+    pub limit: Option<u32>,
+}
+
+pub struct HyprnoteMcp<R: tauri::Runtime> {
+    app: tauri::AppHandle<R>,
+    tool_router: ToolRouter<Self>,
+}
+
+#[tool_router]
+impl<R: tauri::Runtime> HyprnoteMcp<R> {
+    pub fn new(app: tauri::AppHandle<R>) -> Self {
+        Self {
+            app,
+            tool_router: Self::tool_router(),
+        }
+    }
+
+    async fn user_id(&self) -> Result<String, McpError> {
+        self.app
+            .db_user_id()
+            .await
+            .map_err(internal_error)?
+            .ok_or_else(|| McpError::internal_error("Hyprnote has no signed-in user", None))
+    }
+
+    #[tool(description = "Search the user's meeting notes by keyword.")]
+    async fn search_notes(
+        &self,
+        Parameters(req): Parameters<SearchNotesRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let user_id = self.user_id().await?;
+
+        let sessions = self
+            .app
+            .db_list_sessions(hypr_db_user::ListSessionFilter {
+                common: hypr_db_user::ListSessionFilterCommon {
+                    user_id,
+                    limit: Some(req.limit.unwrap_or(10)),
+                },
+                specific: hypr_db_user::ListSessionFilterSpecific::Search { query: req.query },
+            })
+            .await
+            .map_err(internal_error)?;
+
+        let notes: Vec<_> = sessions
+            .into_iter()
+            .map(|session| {
+                let html = session
+                    .enhanced_memo_html
+                    .as_deref()
+                    .unwrap_or(&session.raw_memo_html);
+
+                serde_json::json!({
+                    "session_id": session.id,
+                    "title": session.title,
+                    "created_at": session.created_at,
+                    "content": html_to_text(html),
+                    "has_transcript": !session.words.is_empty(),
+                })
+            })
+            .collect();
+
+        json_result(&notes)
+    }
+
+    #[tool(description = "Get the transcript of a recorded meeting, grouped by speaker.")]
+    async fn get_transcript(
+        &self,
+        Parameters(req): Parameters<GetTranscriptRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let session = self
+            .app
+            .db_get_session(&req.session_id)
+            .await
+            .map_err(internal_error)?
+            .ok_or_else(|| {
+                McpError::invalid_params(format!("Note not found: {}", req.session_id), None)
+            })?;
+
+        if session.words.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(
+                "This note has no transcript.",
+            )]));
+        }
+
+        Ok(CallToolResult::success(vec![Content::text(
+            format_transcript(&session.words),
+        )]))
+    }
+
+    #[tool(description = "Create a new note in Hyprnote.")]
+    async fn create_note(
+        &self,
+        Parameters(req): Parameters<CreateNoteRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let user_id = self.user_id().await?;
+
+        let session = hypr_local_api::new_note(user_id, req.title, &req.content);
+
+        self.app
+            .db_upsert_session(session.clone())
+            .await
+            .map_err(internal_error)?;
+
+        json_result(&serde_json::json!({
+            "session_id": session.id,
+            "title": session.title,
+        }))
+    }
+
+    #[tool(description = "List the user's upcoming calendar events.")]
+    async fn list_upcoming_events(
+        &self,
+        Parameters(req): Parameters<ListUpcomingEventsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let user_id = self.user_id().await?;
+
+        let start = chrono::Utc::now();
+        let end = start + chrono::Duration::days(req.days.unwrap_or(7).into());
+
+        let events = self
+            .app
+            .db_list_events(hypr_db_user::ListEventFilter {
+                common: hypr_db_user::ListEventFilterCommon {
+                    user_id,
+                    limit: Some(req.limit.unwrap_or(20)),
+                },
+                specific: hypr_db_user::ListEventFilterSpecific::DateRange { start, end },
+            })
+            .await
+            .map_err(internal_error)?;
+
+        let events: Vec<_> = events
+            .into_iter()
+            .map(|event| {
+                serde_json::json!({
+                    "event_id": event.id,
+                    "name": event.name,
+                    "start_date": event.start_date,
+                    "end_date": event.end_date,
+                    "note": event.note,
+                    "participants": event
+                        .participants
+                        .and_then(|p| serde_json::from_str::<serde_json::Value>(&p).ok()),
+                })
+            })
+            .collect();
+
+        json_result(&events)
+    }
+}
+
+#[tool_handler]
+impl<R: tauri::Runtime> ServerHandler for HyprnoteMcp<R> {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            instructions: Some(
+                "Read-only access to the user's Hyprnote meeting notes, transcripts and calendar, plus note creation."
+                    .to_string(),
+            ),
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            ..Default::default()
+        }
+    }
+}
+
+pub async fn run_server<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    config: &LocalServerConfig,
+) -> Result<ServerHandle, crate::Error> {
+    // Loopback only, so the notes never leave the machine.
+    let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, config.port));
+    let (server, router) = SseServer::new(SseServerConfig {
+        bind: addr,
+        sse_path: SSE_PATH.to_string(),
+        post_path: MESSAGE_PATH.to_string(),
+        ct: CancellationToken::new(),
+        sse_keep_alive: None,
+    });
+    let router = router.layer(middleware::from_fn_with_state(
+        config.api_key.clone(),
+        require_local_client,
+    ));
+
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    let shutdown = server.with_service(move || HyprnoteMcp::new(app.clone()));
+
+    tokio::spawn({
+        let shutdown = shutdown.clone();
+        async move {
+            if let Err(e) = axum::serve(listener, router)
+                .with_graceful_shutdown(async move { shutdown.cancelled().await })
+                .await
+            {
+                tracing::error!("mcp_local_server_failed: {}", e);
+            }
+        }
+    });
+
+    tracing::info!("mcp_local_server_started {}", addr);
+    Ok(ServerHandle { shutdown })
+}
+
+// Binding to loopback keeps other machines out, but not other local processes, nor web
+// pages that rebind a hostname they control to 127.0.0.1. The key covers the former and
+// the `Host` check the latter.
+async fn require_local_client(State(api_key): State<String>, req: Request, next: Next) -> Response {
+    let headers = req.headers();
+
+    let host = headers
+        .get(header::HOST)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    if !hypr_local_api::is_loopback_host(host) {
+        return (StatusCode::FORBIDDEN, "Invalid Host").into_response();
+    }
+
+    let provided = headers
+        .get(API_KEY_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .or_else(|| {
+            headers
+                .get(header::AUTHORIZATION)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.strip_prefix("Bearer "))
+        })
+        .unwrap_or_default();
+    if !hypr_local_api::keys_match(provided, &api_key) {
+        return (StatusCode::UNAUTHORIZED, "Invalid API key").into_response();
+    }
+
+    next.run(req).await
+}
+
+fn internal_error(e: impl std::fmt::Display) -> McpError {
+    McpError::internal_error(e.to_string(), None)
+}
+
+fn json_result(value: &impl serde::Serialize) -> Result<CallToolResult, McpError> {
+    let text = serde_json::to_string_pretty(value).map_err(internal_error)?;
+    Ok(CallToolResult::success(vec![Content::text(text)]))
+}
+
+fn format_transcript(words: &[owhisper_interface::Word2]) -> String {
+    use owhisper_interface::SpeakerIdentity;
+
+    let speaker_label = |word: &owhisper_interface::Word2| match &word.speaker {
+        Some(SpeakerIdentity::Assigned { label, .. }) => label.clone(),
+        Some(SpeakerIdentity::Unassigned { index }) => format!("Speaker {}", index + 1),
+        None => "Unknown".to_string(),
+    };
+
+    let mut lines: Vec<(String, Vec<&str>)> = vec![];
+    for word in words {
+        let speaker = speaker_label(word);
+        match lines.last_mut() {
+            Some((last, text)) if *last == speaker => text.push(word.text.trim()),
+            _ => lines.push((speaker, vec![word.text.trim()])),
+        }
+    }
+
+    lines
+        .into_iter()
+        .map(|(speaker, text)| format!("{}: {}", speaker, text.join(" ")))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Good enough for handing note content to a model; block elements become line breaks.
+fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    let mut tag = String::new();
+
+    for c in html.chars() {
+        match c {
+            '<' => {
+                in_tag = true;
+                tag.clear();
+            }
+            '>' if in_tag => {
+                in_tag = false;
+                let name = tag
+                    .trim_start_matches('/')
+                    .split(|c: char| c.is_whitespace() || c == '/')
+                    .next()
+                    .unwrap_or_default()
+                    .to_lowercase();
+                if matches!(
+                    name.as_str(),
+                    "p" | "br" | "li" | "h1" | "h2" | "h3" | "h4" | "div"
+                ) && !text.ends_with('\n')
+                    && !text.is_empty()
+                {
+                    text.push('\n');
+                }
+            }
+            _ if in_tag => tag.push(c),
+            _ => text.push(c),
+        }
+    }
+
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use owhisper_interface::{SpeakerIdentity, Word2};
+
+    fn word(text: &str, speaker: Option<SpeakerIdentity>) -> Word2 {
+        Word2 {
+            text: text.to_string(),
+            speaker,
+            confidence: None,
+            start_ms: None,
+            end_ms: None,
+        }
+    }
+
+    #[test]
+    fn test_format_transcript() {
+        let words = vec![
+            word("Hello", Some(SpeakerIdentity::Unassigned { index: 0 })),
+            word(" there", Some(SpeakerIdentity::Unassigned { index: 0 })),
+            word(
+                "Hi",
+                Some(SpeakerIdentity::Assigned {
+                    id: "1".to_string(),
+                    label: "John".to_string(),
+                }),
+            ),
+            word("ok", None),
+        ];
+
+        assert_eq!(
+            format_transcript(&words),
+            "Speaker 1: Hello there\nJohn: Hi\nUnknown: ok"
+        );
+    }
+
+    #[test]
+    fn test_html_to_text() {
+        assert_eq!(
+            html_to_text("<h1>Standup</h1><ul><li>Ship &amp; test</li><li>a &lt; b</li></ul>"),
+            "Standup\nShip & test\na < b"
+        );
+        assert_eq!(html_to_text("<p>one<br/>two</p>"), "one\ntwo");
+        assert_eq!(html_to_text(""), "");
+    }
+}
@@ -0,0 +1,532 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+use tauri::{ipc::Channel, Manager};
+use tauri_plugin_shell::{
+    process::{CommandChild, CommandEvent},
+    ShellExt,
+};
+use tokio::sync::mpsc;
+
+use crate::McpPluginExt;
+
+const MAX_RESTARTS: u32 = 5;
+const RESTART_DELAY: Duration = Duration::from_secs(1);
+// A process that stayed up this long is considered healthy again.
+const STABLE_AFTER: Duration = Duration::from_secs(60);
+
+pub const DECLINED: &str = "declined by user";
+
+#[derive(Debug, Clone, serde::Serialize, specta::Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StdioEvent {
+    Message { message: String },
+    Restarted { attempt: u32 },
+    Closed { error: Option<String> },
+}
+
+pub enum Control {
+    Send(String),
+    /// Answers the client directly, without the server seeing the request.
+    Reply(String),
+    Close,
+}
+
+pub struct StdioConnection {
+    pub server_id: String,
+    pub control: mpsc::UnboundedSender<Control>,
+    pub child: Arc<Mutex<Option<CommandChild>>>,
+    pub tools: Arc<Mutex<Tools>>,
+}
+
+impl StdioConnection {
+    pub fn kill(&self) {
+        if let Some(child) = self.child.lock().unwrap().take() {
+            let _ = child.kill();
+        }
+    }
+}
+
+/// Tracks the JSON-RPC traffic of one connection, so a crashed server can be restarted
+/// without the client noticing anything but errors for the requests that were in flight.
+#[derive(Default)]
+pub struct Protocol {
+    initialize: Option<String>,
+    initialized: Option<String>,
+    in_flight: HashMap<String, Value>,
+    replaying: Option<String>,
+    buffered: Vec<String>,
+}
+
+fn request_id(message: &Value) -> Option<&Value> {
+    message.get("method").and(message.get("id"))
+}
+
+fn response_id(message: &Value) -> Option<&Value> {
+    match message.get("method") {
+        Some(_) => None,
+        None => message.get("id"),
+    }
+}
+
+impl Protocol {
+    /// Returns the lines to write to the server now.
+    pub fn outgoing(&mut self, line: String) -> Vec<String> {
+        if let Ok(message) = serde_json::from_str::<Value>(&line) {
+            match message.get("method").and_then(Value::as_str) {
+                Some("initialize") => self.initialize = Some(line.clone()),
+                Some("notifications/initialized") => self.initialized = Some(line.clone()),
+                _ => {}
+            }
+
+            if let Some(id) = request_id(&message) {
+                self.in_flight.insert(id.to_string(), id.clone());
+            }
+        }
+
+        if self.replaying.is_some() {
+            self.buffered.push(line);
+            return vec![];
+        }
+        vec![line]
+    }
+
+    /// Returns the line to forward to the client, and the lines to write back to the server.
+    pub fn incoming(&mut self, line: String) -> (Option<String>, Vec<String>) {
+        let id = serde_json::from_str::<Value>(&line)
+            .ok()
+            .and_then(|message| response_id(&message).map(|id| id.to_string()));
+
+        if let Some(id) = id {
+            if self.replaying.as_ref() == Some(&id) {
+                self.replaying = None;
+
+                let mut lines: Vec<_> = self.initialized.iter().cloned().collect();
+                lines.append(&mut self.buffered);
+                return (None, lines);
+            }
+
+            self.in_flight.remove(&id);
+        }
+
+        (Some(line), vec![])
+    }
+
+    /// Returns the handshake to send to a restarted server, if the client had completed one.
+    pub fn replay(&mut self) -> Option<String> {
+        let line = self.initialize.clone()?;
+
+        self.replaying = serde_json::from_str::<Value>(&line)
+            .ok()
+            .and_then(|message| request_id(&message).map(|id| id.to_string()));
+
+        Some(line)
+    }
+
+    /// Error responses for the requests the exited server never answered.
+    pub fn abort_in_flight(&mut self, reason: &str) -> Vec<String> {
+        self.in_flight
+            .drain()
+            .map(|(_, id)| {
+                serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "error": { "code": -32000, "message": reason },
+                })
+                .to_string()
+            })
+            .collect()
+    }
+}
+
+/// A `tools/call` request from the client.
+pub struct ToolRequest {
+    pub id: Value,
+    pub tool: String,
+    pub arguments: Value,
+}
+
+impl ToolRequest {
+    pub fn parse(line: &str) -> Option<Self> {
+        Self::from_message(&serde_json::from_str(line).ok()?)
+    }
+
+    fn from_message(message: &Value) -> Option<Self> {
+        if message.get("method").and_then(Value::as_str) != Some("tools/call") {
+            return None;
+        }
+
+        let params = message.get("params")?;
+        Some(Self {
+            id: request_id(message)?.clone(),
+            tool: params.get("name")?.as_str()?.to_string(),
+            arguments: params.get("arguments").cloned().unwrap_or(Value::Null),
+        })
+    }
+
+    /// What the client gets instead of the server's answer, when the user said no.
+    pub fn declined(&self) -> String {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": self.id,
+            "result": {
+                "content": [{ "type": "text", "text": "The user declined to run this tool." }],
+                "isError": true,
+            },
+        })
+        .to_string()
+    }
+}
+
+struct PendingCall {
+    tool: String,
+    arguments: Value,
+    started: Instant,
+}
+
+/// Follows the tools a server lists and the calls made to them, for consent and the audit log.
+pub struct Tools {
+    server_id: String,
+    listing: HashSet<String>,
+    read_only: HashSet<String>,
+    calls: HashMap<String, PendingCall>,
+}
+
+impl Tools {
+    pub fn new(server_id: impl Into<String>) -> Self {
+        Self {
+            server_id: server_id.into(),
+            listing: HashSet::new(),
+            read_only: HashSet::new(),
+            calls: HashMap::new(),
+        }
+    }
+
+    /// Whether the server marked `tool` with `readOnlyHint` when it last listed it.
+    pub fn is_read_only(&self, tool: &str) -> bool {
+        self.read_only.contains(tool)
+    }
+
+    pub fn outgoing(&mut self, line: &str) {
+        let Ok(message) = serde_json::from_str::<Value>(line) else {
+            return;
+        };
+        let Some(id) = request_id(&message).map(|id| id.to_string()) else {
+            return;
+        };
+
+        match message.get("method").and_then(Value::as_str) {
+            Some("tools/list") => {
+                self.listing.insert(id);
+            }
+            Some("tools/call") => {
+                if let Some(request) = ToolRequest::from_message(&message) {
+                    self.calls.insert(
+                        id,
+                        PendingCall {
+                            tool: request.tool,
+                            arguments: request.arguments,
+                            started: Instant::now(),
+                        },
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the finished call, if `line` answers one.
+    pub fn incoming(&mut self, line: &str) -> Option<crate::ToolCall> {
+        let message = serde_json::from_str::<Value>(line).ok()?;
+        let id = response_id(&message)?.to_string();
+
+        if self.listing.remove(&id) {
+            let tools = message.pointer("/result/tools").and_then(Value::as_array);
+            for tool in tools.into_iter().flatten() {
+                let Some(name) = tool.get("name").and_then(Value::as_str) else {
+                    continue;
+                };
+                if tool.pointer("/annotations/readOnlyHint") == Some(&Value::Bool(true)) {
+                    self.read_only.insert(name.to_string());
+                } else {
+                    self.read_only.remove(name);
+                }
+            }
+            return None;
+        }
+
+        let call = self.calls.remove(&id)?;
+        let error = match message.get("error") {
+            Some(error) => Some(
+                error
+                    .get("message")
+                    .and_then(Value::as_str)
+                    .unwrap_or("unknown error")
+                    .to_string(),
+            ),
+            None if message.pointer("/result/isError") == Some(&Value::Bool(true)) => {
+                Some("tool returned an error".to_string())
+            }
+            None => None,
+        };
+
+        Some(crate::ToolCall {
+            server_id: self.server_id.clone(),
+            tool: call.tool,
+            arguments: call.arguments,
+            duration_ms: call.started.elapsed().as_millis() as u64,
+            result_size: message
+                .get("result")
+                .map_or(0, |r| r.to_string().len() as u64),
+            error,
+        })
+    }
+}
+
+/// Records the call off the connection's loop, which would otherwise wait on the store.
+pub fn record<R: tauri::Runtime>(app: &tauri::AppHandle<R>, call: crate::ToolCall) {
+    let app = app.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        if let Err(e) = app.record_tool_call(call) {
+            tracing::warn!("mcp_tool_call_record_failed: {}", e);
+        }
+    });
+}
+
+pub fn spawn<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    server: &crate::McpServer,
+) -> Result<(mpsc::Receiver<CommandEvent>, CommandChild), crate::Error> {
+    let command = server
+        .command
+        .as_deref()
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .ok_or(crate::Error::MissingCommand)?;
+
+    let (rx, child) = app
+        .shell()
+        .command(command)
+        .args(&server.args)
+        .envs(server.env.clone())
+        .spawn()?;
+
+    Ok((rx, child))
+}
+
+fn write_line(child: &Mutex<Option<CommandChild>>, line: &str) {
+    if let Some(child) = child.lock().unwrap().as_mut() {
+        if let Err(e) = child.write(format!("{}\n", line).as_bytes()) {
+            tracing::warn!("mcp_stdio_write_failed: {}", e);
+        }
+    }
+}
+
+enum Exit {
+    Closed,
+    Terminated(Option<i32>),
+}
+
+pub async fn run<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+    server: crate::McpServer,
+    channel: Channel<StdioEvent>,
+    mut control: mpsc::UnboundedReceiver<Control>,
+    child: Arc<Mutex<Option<CommandChild>>>,
+    tools: Arc<Mutex<Tools>>,
+    mut events: mpsc::Receiver<CommandEvent>,
+) {
+    let mut protocol = Protocol::default();
+    let mut restarts = 0;
+    let mut started = Instant::now();
+
+    let error = loop {
+        let exit = loop {
+            tokio::select! {
+                msg = control.recv() => match msg {
+                    Some(Control::Send(line)) => {
+                        tools.lock().unwrap().outgoing(&line);
+                        for line in protocol.outgoing(line) {
+                            write_line(&child, &line);
+                        }
+                    }
+                    Some(Control::Reply(message)) => {
+                        let _ = channel.send(StdioEvent::Message { message });
+                    }
+                    Some(Control::Close) | None => break Exit::Closed,
+                },
+                event = events.recv() => match event {
+                    Some(CommandEvent::Stdout(bytes)) => {
+                        let line = String::from_utf8_lossy(&bytes).trim().to_string();
+                        if line.is_empty() {
+                            continue;
+                        }
+
+                        let (to_client, to_server) = protocol.incoming(line);
+                        for line in to_server {
+                            write_line(&child, &line);
+                        }
+                        if let Some(message) = to_client {
+                            if let Some(call) = tools.lock().unwrap().incoming(&message) {
+                                record(&app, call);
+                            }
+                            let _ = channel.send(StdioEvent::Message { message });
+                        }
+                    }
+                    Some(CommandEvent::Stderr(bytes)) => {
+                        tracing::debug!("mcp_stdio_stderr: {}", String::from_utf8_lossy(&bytes).trim());
+                    }
+                    Some(CommandEvent::Error(e)) => {
+                        tracing::warn!("mcp_stdio_error: {}", e);
+                    }
+                    Some(CommandEvent::Terminated(payload)) => break Exit::Terminated(payload.code),
+                    Some(_) => {}
+                    None => break Exit::Terminated(None),
+                },
+            }
+        };
+
+        let code = match exit {
+            Exit::Closed => break None,
+            Exit::Terminated(code) => code,
+        };
+
+        for message in protocol.abort_in_flight("MCP server exited") {
+            if let Some(call) = tools.lock().unwrap().incoming(&message) {
+                record(&app, call);
+            }
+            let _ = channel.send(StdioEvent::Message { message });
+        }
+
+        if started.elapsed() > STABLE_AFTER {
+            restarts = 0;
+        }
+        restarts += 1;
+        if restarts > MAX_RESTARTS {
+            break Some(format!("MCP server exited with code {:?}", code));
+        }
+
+        tracing::warn!(
+            command = ?server.command,
+            attempt = restarts,
+            "mcp_stdio_restarting: exited with code {:?}",
+            code
+        );
+        // Messages sent meanwhile stay queued in `control` until the server is back.
+        tokio::time::sleep(RESTART_DELAY * restarts).await;
+
+        match spawn(&app, &server) {
+            Ok((rx, new_child)) => {
+                events = rx;
+                *child.lock().unwrap() = Some(new_child);
+                started = Instant::now();
+
+                if let Some(line) = protocol.replay() {
+                    write_line(&child, &line);
+                }
+                let _ = channel.send(StdioEvent::Restarted { attempt: restarts });
+            }
+            Err(e) => break Some(e.to_string()),
+        }
+    };
+
+    if let Some(child) = child.lock().unwrap().take() {
+        let _ = child.kill();
+    }
+    app.state::<crate::SharedState>()
+        .lock()
+        .unwrap()
+        .connections
+        .remove(&id);
+
+    let _ = channel.send(StdioEvent::Closed { error });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_protocol_replay() {
+        let mut protocol = Protocol::default();
+
+        let initialize = r#"{"jsonrpc":"2.0","id":0,"method":"initialize","params":{}}"#;
+        let initialized = r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#;
+        let list = r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#;
+
+        assert_eq!(protocol.outgoing(initialize.to_string()), vec![initialize]);
+        assert_eq!(
+            protocol.incoming(r#"{"jsonrpc":"2.0","id":0,"result":{}}"#.to_string()),
+            (
+                Some(r#"{"jsonrpc":"2.0","id":0,"result":{}}"#.to_string()),
+                vec![]
+            )
+        );
+        protocol.outgoing(initialized.to_string());
+        protocol.outgoing(list.to_string());
+
+        let aborted = protocol.abort_in_flight("MCP server exited");
+        assert_eq!(aborted.len(), 1);
+        assert!(aborted[0].contains(r#""id":1"#));
+
+        assert_eq!(protocol.replay().as_deref(), Some(initialize));
+        let call = r#"{"jsonrpc":"2.0","id":2,"method":"tools/call"}"#;
+        assert!(protocol.outgoing(call.to_string()).is_empty());
+
+        assert_eq!(
+            protocol.incoming(r#"{"jsonrpc":"2.0","id":0,"result":{}}"#.to_string()),
+            (None, vec![initialized.to_string(), call.to_string()])
+        );
+        assert_eq!(protocol.outgoing(list.to_string()), vec![list]);
+    }
+
+    #[test]
+    fn test_tools() {
+        let mut tools = Tools::new("1");
+
+        tools.outgoing(r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#);
+        assert!(tools
+            .incoming(
+                r#"{"jsonrpc":"2.0","id":1,"result":{"tools":[
+                    {"name":"search","annotations":{"readOnlyHint":true}},
+                    {"name":"delete"}
+                ]}}"#
+            )
+            .is_none());
+        assert!(tools.is_read_only("search"));
+        assert!(!tools.is_read_only("delete"));
+
+        let call = r#"{"jsonrpc":"2.0","id":2,"method":"tools/call","params":{"name":"delete","arguments":{"id":"a"}}}"#;
+        let request = ToolRequest::parse(call).unwrap();
+        assert_eq!(request.tool, "delete");
+        assert!(request.declined().contains(r#""id":2"#));
+
+        tools.outgoing(call);
+        let finished = tools
+            .incoming(
+                r#"{"jsonrpc":"2.0","id":2,"error":{"code":-32000,"message":"MCP server exited"}}"#,
+            )
+            .unwrap();
+        assert_eq!(finished.tool, "delete");
+        assert_eq!(finished.arguments, serde_json::json!({ "id": "a" }));
+        assert_eq!(finished.error.as_deref(), Some("MCP server exited"));
+        assert!(tools
+            .incoming(r#"{"jsonrpc":"2.0","id":2,"result":{}}"#)
+            .is_none());
+    }
+
+    #[test]
+    fn test_protocol_forwards_server_requests() {
+        let mut protocol = Protocol::default();
+        let ping = r#"{"jsonrpc":"2.0","id":0,"method":"ping"}"#;
+
+        assert_eq!(
+            protocol.incoming(ping.to_string()),
+            (Some(ping.to_string()), vec![])
+        );
+        assert!(protocol.abort_in_flight("exited").is_empty());
+    }
+}
@@ -9,6 +9,9 @@ macro_rules! common_derives {
 
 common_derives! {
     pub struct McpServer {
+        // Assigned when the server is first saved.
+        #[serde(default)]
+        pub id: String,
         pub r#type: String,
         pub enabled: bool,
         pub url: String,
@@ -16,6 +19,58 @@ common_derives! {
         pub header_key: Option<String>,
         #[serde(rename = "headerValue")]
         pub header_value: Option<String>,
+        // Secrets live in the keychain, see `oauth.rs`.
+        #[serde(default)]
+        pub oauth: Option<McpOAuthConfig>,
+        #[serde(default)]
+        pub consent: ConsentPolicy,
+        // Only used by `stdio` servers.
+        #[serde(default)]
+        pub command: Option<String>,
+        #[serde(default)]
+        pub args: Vec<String>,
+        #[serde(default)]
+        pub env: std::collections::BTreeMap<String, String>,
+    }
+}
+
+common_derives! {
+    pub struct McpOAuthConfig {
+        pub grant: OAuthGrant,
+        pub token_url: String,
+        pub client_id: String,
+        pub scope: Option<String>,
+    }
+}
+
+common_derives! {
+    #[serde(rename_all = "snake_case")]
+    pub enum OAuthGrant {
+        ClientCredentials,
+        RefreshToken,
+    }
+}
+
+common_derives! {
+    /// Whether the chat asks before running a tool from this server.
+    #[derive(Default)]
+    #[serde(rename_all = "snake_case")]
+    pub enum ConsentPolicy {
+        #[default]
+        AlwaysAsk,
+        // Tools annotated with `readOnlyHint` run without asking.
+        AllowReadOnly,
+        AllowAll,
+    }
+}
+
+impl ConsentPolicy {
+    pub fn needs_consent(&self, read_only: bool) -> bool {
+        match self {
+            ConsentPolicy::AlwaysAsk => true,
+            ConsentPolicy::AllowReadOnly => !read_only,
+            ConsentPolicy::AllowAll => false,
+        }
     }
 }
 
@@ -23,6 +78,8 @@ common_derives! {
     #[derive(strum::Display)]
     pub enum StoreKey {
         Servers,
+        LocalServer,
+        AuditLog,
     }
 }
 
@@ -30,6 +30,9 @@ async audioOpen(sessionId: string) : Promise<null> {
 },
 async audioDelete(sessionId: string) : Promise<null> {
     return await TAURI_INVOKE("plugin:misc|audio_delete", { sessionId });
+},
+async audioPeaks(sessionId: string) : Promise<AudioPeaks | null> {
+    return await TAURI_INVOKE("plugin:misc|audio_peaks", { sessionId });
 }
 }
 
@@ -43,6 +46,15 @@ async audioDelete(sessionId: string) : Promise<null> {
 
 /** user-defined types **/
 
+/**
+ * Min/max of every 50ms of a recording, quantized to `i8`. An hour takes about 140KB,
+ * little enough to hand to the webview and downsample to whatever width is drawn.
+ */
+export type AudioPeaks = { sample_rate: number; 
+/**
+ * Samples per window.
+ */
+window_size: number; duration_ms: number; min: number[]; max: number[] }
 
 
 /** tauri-specta globals **/
@@ -32,7 +32,7 @@ pub async fn audio_exist<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
     session_id: String,
 ) -> Result<bool, String> {
-    let data_dir = app.path().app_data_dir().unwrap();
+    let data_dir = hypr_data_dir::resolve(|| app.path().app_data_dir()).unwrap();
     let audio_path = data_dir.join(session_id).join("audio.wav");
 
     let v = std::fs::exists(audio_path).map_err(|e| e.to_string())?;
@@ -45,20 +45,47 @@ pub async fn audio_delete<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
     session_id: String,
 ) -> Result<(), String> {
-    let data_dir = app.path().app_data_dir().unwrap();
+    let data_dir = hypr_data_dir::resolve(|| app.path().app_data_dir()).unwrap();
     let audio_path = data_dir.join(session_id).join("audio.wav");
 
-    std::fs::remove_file(audio_path).map_err(|e| e.to_string())?;
+    std::fs::remove_file(&audio_path).map_err(|e| e.to_string())?;
+    let _ = std::fs::remove_file(hypr_audio_utils::peaks_path(&audio_path));
+    // Where each start of the recording begins, kept by the listener.
+    let _ = std::fs::remove_file(audio_path.with_extension("stretches.json"));
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn audio_peaks<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+) -> Result<Option<hypr_audio_utils::AudioPeaks>, String> {
+    let data_dir = hypr_data_dir::resolve(|| app.path().app_data_dir()).unwrap();
+    let audio_path = data_dir.join(session_id).join("audio.wav");
+
+    if !audio_path.exists() {
+        return Ok(None);
+    }
+
+    // Recordings made before peaks existed get them on first request.
+    let peaks = tauri::async_runtime::spawn_blocking(move || {
+        hypr_audio_utils::load_or_compute_peaks(&audio_path)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| e.to_string())?;
+
+    Ok(Some(peaks))
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn audio_open<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
     session_id: String,
 ) -> Result<(), String> {
-    let data_dir = app.path().app_data_dir().unwrap();
+    let data_dir = hypr_data_dir::resolve(|| app.path().app_data_dir()).unwrap();
     let audio_path = data_dir.join(session_id).join("audio.wav");
 
     app.opener()
@@ -74,7 +101,7 @@ pub async fn delete_session_folder<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
     session_id: String,
 ) -> Result<(), String> {
-    let data_dir = app.path().app_data_dir().unwrap();
+    let data_dir = hypr_data_dir::resolve(|| app.path().app_data_dir()).unwrap();
     let session_dir = data_dir.join(session_id);
 
     if session_dir.exists() {
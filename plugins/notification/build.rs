@@ -3,10 +3,18 @@ const COMMANDS: &[&str] = &[
     "show_notification",
     "get_event_notification",
     "set_event_notification",
+    "get_event_reminders",
+    "set_event_reminders",
+    "get_pre_meeting_brief",
+    "set_pre_meeting_brief",
     "get_detect_notification",
     "set_detect_notification",
     "get_respect_do_not_disturb",
     "set_respect_do_not_disturb",
+    "get_quiet_hours",
+    "set_quiet_hours",
+    "list_notification_history",
+    "clear_notification_history",
     "start_detect_notification",
     "stop_detect_notification",
     "start_event_notification",
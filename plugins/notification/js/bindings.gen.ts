@@ -19,6 +19,18 @@ async getEventNotification() : Promise<boolean> {
 async setEventNotification(enabled: boolean) : Promise<null> {
     return await TAURI_INVOKE("plugin:notification|set_event_notification", { enabled });
 },
+async getEventReminders() : Promise<EventReminders> {
+    return await TAURI_INVOKE("plugin:notification|get_event_reminders");
+},
+async setEventReminders(reminders: EventReminders) : Promise<null> {
+    return await TAURI_INVOKE("plugin:notification|set_event_reminders", { reminders });
+},
+async getPreMeetingBrief() : Promise<boolean> {
+    return await TAURI_INVOKE("plugin:notification|get_pre_meeting_brief");
+},
+async setPreMeetingBrief(enabled: boolean) : Promise<null> {
+    return await TAURI_INVOKE("plugin:notification|set_pre_meeting_brief", { enabled });
+},
 async getDetectNotification() : Promise<boolean> {
     return await TAURI_INVOKE("plugin:notification|get_detect_notification");
 },
@@ -28,6 +40,18 @@ async getRespectDoNotDisturb() : Promise<boolean> {
 async setRespectDoNotDisturb(enabled: boolean) : Promise<null> {
     return await TAURI_INVOKE("plugin:notification|set_respect_do_not_disturb", { enabled });
 },
+async getQuietHours() : Promise<QuietHours> {
+    return await TAURI_INVOKE("plugin:notification|get_quiet_hours");
+},
+async setQuietHours(quietHours: QuietHours) : Promise<null> {
+    return await TAURI_INVOKE("plugin:notification|set_quiet_hours", { quietHours });
+},
+async listNotificationHistory() : Promise<NotificationHistoryEntry[]> {
+    return await TAURI_INVOKE("plugin:notification|list_notification_history");
+},
+async clearNotificationHistory() : Promise<null> {
+    return await TAURI_INVOKE("plugin:notification|clear_notification_history");
+},
 async setDetectNotification(enabled: boolean) : Promise<null> {
     return await TAURI_INVOKE("plugin:notification|set_detect_notification", { enabled });
 },
@@ -62,8 +86,38 @@ async setIgnoredPlatforms(platforms: string[]) : Promise<null> {
 /** user-defined types **/
 
 export type Duration = { secs: number; nanos: number }
+export type EventReminders = { lead_minutes: number; 
+/**
+ * Send a second reminder when the event starts.
+ */
+starting_now: boolean; 
+/**
+ * Calendar ids whose events never trigger reminders.
+ */
+excluded_calendars: string[] }
 export type InstalledApp = { bundle_id: string; localized_name: string }
-export type Notification = { key: string | null; title: string; message: string; url: string | null; timeout: Duration | null }
+export type Notification = { key: string | null; title: string; message: string; url: string | null; 
+/**
+ * Label of the button that opens `url`.
+ */
+action_label?: string | null; timeout: Duration | null }
+export type NotificationHistoryEntry = { title: string; message: string; url: string | null; 
+/**
+ * False when the notification was held back by quiet hours.
+ */
+shown: boolean; created_at: string }
+export type QuietHours = { enabled: boolean; mode: QuietHoursMode; rules: QuietHoursRule[] }
+export type QuietHoursMode = "suppress" | "queue"
+export type QuietHoursRule = { weekday: Weekday; 
+/**
+ * Minutes after local midnight.
+ */
+start_minute: number; 
+/**
+ * An end before the start runs past midnight into the next day; an equal one covers the whole day.
+ */
+end_minute: number }
+export type Weekday = "mon" | "tue" | "wed" | "thu" | "fri" | "sat" | "sun"
 
 /** tauri-specta globals **/
 
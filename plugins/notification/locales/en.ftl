@@ -0,0 +1,33 @@
+# Quit confirmation while recording
+quit-title = Do you really want to quit?
+quit-message = Hyprnote is currently recording.
+quit-confirm = Quit
+quit-cancel = Cancel
+
+# Shown by Windows while a shutdown waits for the recording to be saved
+shutdown-block-reason = Saving your recording
+
+# Meeting detected from microphone activity
+detect-title = Meeting detected
+detect-message = Based on your microphone activity
+
+# Calendar reminder, when its template fails to render
+event-starting-now = Starting now
+event-starting-in = Starting in { $minutes } min
+
+# Button on reminders for events with a meeting link
+event-join-action = Join & record
+
+# Meeting app closed
+ended-recording-title = Meeting ended?
+ended-recording-message = Hyprnote is still recording. Stop recording?
+ended-missed-title = Meeting ended
+ended-missed-message = Add notes while it's still fresh?
+
+# Recording running longer than planned
+length-title = Meeting running long
+length-duration-message = You've been recording for { $minutes } min.
+length-event-end-message = { $event } was scheduled to end at { $time }.
+
+# Summary of past meetings, sent ahead of an event
+brief-title = Before { $event }
@@ -0,0 +1,33 @@
+# Quit confirmation while recording
+quit-title = 정말 종료하시겠습니까?
+quit-message = Hyprnote가 녹음 중입니다.
+quit-confirm = 종료
+quit-cancel = 취소
+
+# Shown by Windows while a shutdown waits for the recording to be saved
+shutdown-block-reason = 녹음을 저장하는 중입니다
+
+# Meeting detected from microphone activity
+detect-title = 회의가 감지되었습니다
+detect-message = 마이크 사용을 기준으로 감지했습니다
+
+# Calendar reminder, when its template fails to render
+event-starting-now = 지금 시작
+event-starting-in = { $minutes }분 후 시작
+
+# Button on reminders for events with a meeting link
+event-join-action = 참여 및 녹음
+
+# Meeting app closed
+ended-recording-title = 회의가 끝났나요?
+ended-recording-message = Hyprnote가 아직 녹음 중입니다. 녹음을 중지할까요?
+ended-missed-title = 회의가 끝났습니다
+ended-missed-message = 기억이 생생할 때 노트를 추가하시겠어요?
+
+# Recording running longer than planned
+length-title = 회의가 길어지고 있습니다
+length-duration-message = { $minutes }분째 녹음 중입니다.
+length-event-end-message = { $event } 일정은 { $time }에 끝날 예정이었습니다.
+
+# Summary of past meetings, sent ahead of an event
+brief-title = { $event } 전에
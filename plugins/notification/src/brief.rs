@@ -0,0 +1,268 @@
+use std::sync::mpsc::Sender;
+
+use chrono::{DateTime, Utc};
+use hypr_db_user::{
+    ListSessionFilter, ListSessionFilterCommon, ListSessionFilterSpecific, QueuedTask,
+    QueuedTaskKind,
+};
+use tauri::AppHandle;
+use tauri_plugin_connector::{ConnectorPluginExt, LlmTask};
+use tauri_plugin_task::{TaskEvent, TaskPluginExt};
+use tauri_specta::Event;
+
+use crate::handler::{NotificationTrigger, NotificationTriggerBrief};
+
+/// Ahead of the reminder, so a slow model is done well before the meeting.
+pub const LEAD_MINUTES: i64 = 15;
+/// A brief that arrives after the meeting started is no use, so it isn't retried.
+const MAX_ATTEMPTS: i64 = 1;
+const MAX_PAST_MEETINGS: usize = 3;
+// Enough for a summary with its action items; raw notes past this are mostly noise.
+const MAX_NOTES_CHARS: usize = 3000;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct BriefPayload {
+    pub event_id: String,
+}
+
+/// Whether the event worker's tick at `seconds_until_start` is the one to queue the brief on.
+pub fn due(seconds_until_start: i64) -> bool {
+    let lead = LEAD_MINUTES * 60;
+    lead - crate::reminder::TICK_SECS < seconds_until_start && seconds_until_start <= lead
+}
+
+pub fn enqueue(app: &AppHandle<tauri::Wry>, event_id: &str) {
+    let payload = serde_json::to_string(&BriefPayload {
+        event_id: event_id.to_string(),
+    })
+    .unwrap();
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = app
+            .enqueue_task(QueuedTaskKind::PreMeetingBrief, payload, Some(MAX_ATTEMPTS))
+            .await
+        {
+            tracing::error!("pre_meeting_brief_enqueue_failed: {}", e);
+        }
+    });
+}
+
+/// Picks up brief jobs as the task queue hands them out, and passes finished briefs on to
+/// the notification handler.
+pub fn listen(app: &AppHandle<tauri::Wry>, tx: Option<Sender<NotificationTrigger>>) {
+    let handle = app.clone();
+    TaskEvent::listen_any(app, move |event| {
+        let TaskEvent::Run(task) = event.payload else {
+            return;
+        };
+        if task.kind != QueuedTaskKind::PreMeetingBrief {
+            return;
+        }
+
+        let app = handle.clone();
+        let tx = tx.clone();
+        tauri::async_runtime::spawn(async move { run(&app, tx, task).await });
+    });
+}
+
+async fn run(
+    app: &AppHandle<tauri::Wry>,
+    tx: Option<Sender<NotificationTrigger>>,
+    task: QueuedTask,
+) {
+    let result = match serde_json::from_str::<BriefPayload>(&task.payload) {
+        Ok(payload) => brief(app, &payload.event_id).await,
+        Err(e) => Err(crate::Error::InvalidTaskPayload(e.to_string())),
+    };
+
+    let reported = match result {
+        Ok(brief) => {
+            if let (Some(brief), Some(tx)) = (brief, &tx) {
+                let _ = tx.send(NotificationTrigger::Brief(brief));
+            }
+            app.complete_queued_task(task.id).await.map(|_| ())
+        }
+        Err(e) => {
+            tracing::error!("pre_meeting_brief_failed: {}", e);
+            app.fail_queued_task(task.id, e.to_string())
+                .await
+                .map(|_| ())
+        }
+    };
+    if let Err(e) = reported {
+        tracing::error!("pre_meeting_brief_report_failed: {}", e);
+    }
+}
+
+/// `None` when there is nothing to brief on: the event is gone or already started, or none
+/// of its attendees were in an earlier meeting with notes.
+async fn brief(
+    app: &AppHandle<tauri::Wry>,
+    event_id: &str,
+) -> Result<Option<NotificationTriggerBrief>, crate::Error> {
+    use tauri_plugin_db::DatabasePluginExt;
+
+    let Some(event) = app.db_get_event(event_id).await? else {
+        return Ok(None);
+    };
+    if event.start_date <= Utc::now() {
+        return Ok(None);
+    }
+    let user_id = app
+        .db_user_id()
+        .await?
+        .ok_or(tauri_plugin_db::Error::NoneUser)?;
+
+    let participants: Vec<hypr_db_user::EventParticipant> = event
+        .participants
+        .as_deref()
+        .and_then(|raw| serde_json::from_str(raw).ok())
+        .unwrap_or_default();
+    let emails: Vec<String> = participants
+        .iter()
+        .filter_map(|p| p.email.clone())
+        .collect();
+
+    let sessions = app
+        .db_list_sessions(ListSessionFilter {
+            common: ListSessionFilterCommon {
+                user_id,
+                limit: Some(20),
+            },
+            specific: ListSessionFilterSpecific::Participants { emails },
+        })
+        .await?;
+
+    let context = BriefContext {
+        event_name: event.name.clone(),
+        participants: participants
+            .iter()
+            .filter_map(|p| p.name.clone().or_else(|| p.email.clone()))
+            .filter(|name| !name.trim().is_empty())
+            .collect(),
+        meetings: sessions
+            .iter()
+            .filter(|s| s.calendar_event_id.as_deref() != Some(event_id))
+            .filter_map(PastMeeting::from_session)
+            .take(MAX_PAST_MEETINGS)
+            .collect(),
+    };
+    if context.meetings.is_empty() {
+        return Ok(None);
+    }
+
+    let mut env = hypr_template::minijinja::Environment::new();
+    hypr_template::init(&mut env);
+    let (system, user) = context.render(&env)?;
+
+    let text = app.complete_for_task(LlmTask::Brief, system, user).await?;
+    if text.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(NotificationTriggerBrief {
+        event_id: event.id,
+        event_name: event.name,
+        start_date: event.start_date,
+        brief: text,
+    }))
+}
+
+/// What the brief templates get to see.
+#[derive(Debug, Clone, serde::Serialize)]
+struct BriefContext {
+    event_name: String,
+    participants: Vec<String>,
+    // Most relevant first.
+    meetings: Vec<PastMeeting>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+struct PastMeeting {
+    title: String,
+    date: String,
+    notes: String,
+}
+
+impl PastMeeting {
+    fn from_session(session: &hypr_db_user::Session) -> Option<Self> {
+        let html = session
+            .enhanced_memo_html
+            .as_deref()
+            .filter(|html| !html.trim().is_empty())
+            .unwrap_or(&session.raw_memo_html);
+
+        let notes = hypr_buffer::html_to_md(html).ok()?;
+        let notes = notes.trim();
+        if notes.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            title: session.title.clone(),
+            date: local_date(session.created_at),
+            notes: notes.chars().take(MAX_NOTES_CHARS).collect(),
+        })
+    }
+}
+
+fn local_date(t: DateTime<Utc>) -> String {
+    t.with_timezone(&chrono::Local)
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
+impl BriefContext {
+    /// Returns the `(system, user)` prompt pair.
+    fn render(
+        &self,
+        env: &hypr_template::minijinja::Environment<'static>,
+    ) -> Result<(String, String), crate::Error> {
+        let vars = match serde_json::to_value(self) {
+            Ok(serde_json::Value::Object(map)) => map,
+            _ => serde_json::Map::new(),
+        };
+
+        let system =
+            hypr_template::render(env, hypr_template::Template::PreMeetingBriefSystem, &vars)?;
+        let user = hypr_template::render(env, hypr_template::Template::PreMeetingBriefUser, &vars)?;
+
+        Ok((system, user))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_due() {
+        assert!(!due(LEAD_MINUTES * 60 + 1));
+        assert!(due(LEAD_MINUTES * 60));
+        assert!(due(LEAD_MINUTES * 60 - 59));
+        assert!(!due(LEAD_MINUTES * 60 - 60));
+    }
+
+    #[test]
+    fn test_render() {
+        let mut env = hypr_template::minijinja::Environment::new();
+        hypr_template::init(&mut env);
+
+        let context = BriefContext {
+            event_name: "Weekly sync".to_string(),
+            participants: vec!["Alice".to_string(), "bob@example.com".to_string()],
+            meetings: vec![PastMeeting {
+                title: "Weekly sync".to_string(),
+                date: "2025-01-06".to_string(),
+                notes: "- Alice to send the pricing draft".to_string(),
+            }],
+        };
+
+        let (_, user) = context.render(&env).unwrap();
+        assert!(user.contains("Weekly sync with Alice, bob@example.com"));
+        assert!(user.contains(
+            "<past_meeting title=\"Weekly sync\" date=\"2025-01-06\">\n- Alice to send the pricing draft\n</past_meeting>"
+        ));
+    }
+}
@@ -35,6 +35,75 @@ pub(crate) async fn set_event_notification<R: tauri::Runtime>(
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_quiet_hours<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<crate::QuietHours, String> {
+    app.get_quiet_hours().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_quiet_hours<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    quiet_hours: crate::QuietHours,
+) -> Result<(), String> {
+    app.set_quiet_hours(quiet_hours).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn list_notification_history<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<crate::NotificationHistoryEntry>, String> {
+    app.list_notification_history().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn clear_notification_history<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<(), String> {
+    app.clear_notification_history().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_event_reminders<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<crate::EventReminders, String> {
+    app.get_event_reminders().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_event_reminders<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    reminders: crate::EventReminders,
+) -> Result<(), String> {
+    app.set_event_reminders(reminders)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_pre_meeting_brief<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<bool, String> {
+    app.get_pre_meeting_brief().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_pre_meeting_brief<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    enabled: bool,
+) -> Result<(), String> {
+    app.set_pre_meeting_brief(enabled)
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub(crate) async fn get_respect_do_not_disturb<R: tauri::Runtime>(
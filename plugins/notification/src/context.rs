@@ -0,0 +1,155 @@
+use crate::handler::NotificationTriggerEvent;
+use crate::reminder::Reminder;
+
+/// What reminder templates get to say about the event.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct EventContext {
+    pub event_name: String,
+    // 0 once the event has started.
+    pub minutes_until_start: i64,
+    pub attendee_count: usize,
+    pub organizer: Option<String>,
+    pub meeting_link: Option<String>,
+    pub meeting_app: Option<&'static str>,
+}
+
+impl EventContext {
+    pub fn new(
+        trigger: &NotificationTriggerEvent,
+        reminder: &Reminder,
+        meeting_link: Option<String>,
+    ) -> Self {
+        let minutes_until_start = match reminder {
+            Reminder::Upcoming => (trigger.seconds_until_start + 59) / 60,
+            Reminder::StartingNow => 0,
+        };
+
+        let organizer = trigger
+            .participants
+            .iter()
+            .find(|p| p.is_organizer)
+            .and_then(|p| p.name.clone().or_else(|| p.email.clone()))
+            .filter(|name| !name.trim().is_empty());
+
+        Self {
+            event_name: trigger.event_name.clone(),
+            minutes_until_start,
+            attendee_count: trigger.participants.len(),
+            organizer,
+            meeting_app: meeting_link.as_deref().and_then(meeting_app),
+            meeting_link,
+        }
+    }
+
+    /// Returns the `(title, message)` pair.
+    pub fn render(
+        &self,
+        env: &hypr_template::minijinja::Environment<'static>,
+    ) -> Result<(String, String), crate::Error> {
+        let vars = match serde_json::to_value(self) {
+            Ok(serde_json::Value::Object(map)) => map,
+            _ => serde_json::Map::new(),
+        };
+
+        let title =
+            hypr_template::render(env, hypr_template::Template::NotificationEventTitle, &vars)?;
+        let message = hypr_template::render(
+            env,
+            hypr_template::Template::NotificationEventMessage,
+            &vars,
+        )?;
+
+        Ok((title.trim().to_string(), message.trim().to_string()))
+    }
+}
+
+fn meeting_app(link: &str) -> Option<&'static str> {
+    let host = url::Url::parse(link).ok()?.host_str()?.to_lowercase();
+
+    [
+        ("zoom.us", "Zoom"),
+        ("meet.google.com", "Google Meet"),
+        ("teams.microsoft.com", "Teams"),
+        ("teams.live.com", "Teams"),
+        ("webex.com", "Webex"),
+        ("cal.com", "Cal Video"),
+        ("whereby.com", "Whereby"),
+    ]
+    .into_iter()
+    .find(|(domain, _)| host == *domain || host.ends_with(&format!(".{}", domain)))
+    .map(|(_, name)| name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn participant(name: &str, is_organizer: bool) -> hypr_db_user::EventParticipant {
+        hypr_db_user::EventParticipant {
+            name: Some(name.to_string()),
+            email: None,
+            is_organizer,
+        }
+    }
+
+    fn trigger(participants: Vec<hypr_db_user::EventParticipant>) -> NotificationTriggerEvent {
+        NotificationTriggerEvent {
+            event_id: "event".to_string(),
+            event_name: "Standup".to_string(),
+            calendar_id: None,
+            seconds_until_start: 280,
+            participants,
+            note: String::new(),
+            meeting_link: None,
+        }
+    }
+
+    #[test]
+    fn test_meeting_app() {
+        assert_eq!(
+            meeting_app("https://hyprnote.zoom.us/j/86746313244?pwd=abc"),
+            Some("Zoom")
+        );
+        assert_eq!(
+            meeting_app("https://meet.google.com/xhv-ubut-zph"),
+            Some("Google Meet")
+        );
+        assert_eq!(meeting_app("https://notzoom.us/j/1"), None);
+        assert_eq!(meeting_app("not a link"), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut env = hypr_template::minijinja::Environment::new();
+        hypr_template::init(&mut env);
+
+        let participants = (0..6)
+            .map(|i| participant(&format!("p{}", i), false))
+            .collect();
+        let ctx = EventContext::new(
+            &trigger(participants),
+            &Reminder::Upcoming,
+            Some("https://hyprnote.zoom.us/j/86746313244".to_string()),
+        );
+        assert_eq!(
+            ctx.render(&env).unwrap(),
+            (
+                "Standup".to_string(),
+                "Starting in 5 min with 6 people — join Zoom".to_string()
+            )
+        );
+
+        let ctx = EventContext::new(
+            &trigger(vec![participant("Alice", true)]),
+            &Reminder::StartingNow,
+            None,
+        );
+        assert_eq!(
+            ctx.render(&env).unwrap().1,
+            "Starting now with 1 person, hosted by Alice"
+        );
+
+        let ctx = EventContext::new(&trigger(vec![]), &Reminder::Upcoming, None);
+        assert_eq!(ctx.render(&env).unwrap().1, "Starting in 5 min");
+    }
+}
@@ -1,11 +1,24 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
 use crate::{
-    handler::{NotificationHandler, NotificationTrigger, NotificationTriggerDetect},
+    handler::{
+        NotificationHandler, NotificationTrigger, NotificationTriggerDetect,
+        NotificationTriggerMeetingEnded,
+    },
     Error,
 };
 
+const MEETING_POLL_INTERVAL: Duration = Duration::from_secs(10);
+// Apps briefly release the mic when switching devices; don't mistake that for the end.
+const MEETING_ENDED_AFTER_IDLE_POLLS: u32 = 3;
+
 pub struct DetectState {
     detector: Option<hypr_detect::Detector>,
     notification_tx: Option<std::sync::mpsc::Sender<NotificationTrigger>>,
+    meeting_watch: Option<Arc<AtomicBool>>,
 }
 
 impl DetectState {
@@ -13,6 +26,59 @@ impl DetectState {
         Self {
             detector: None,
             notification_tx: notification_handler.sender(),
+            meeting_watch: None,
+        }
+    }
+
+    /// Polls until every one of `app_ids` stops using the mic, then sends `MeetingEnded`.
+    /// `is_recording` is checked on every poll, so the trigger can tell whether the meeting
+    /// was recorded even when that session stopped before the meeting did.
+    pub fn watch_meeting(
+        &mut self,
+        app_ids: Vec<String>,
+        is_recording: impl Fn() -> bool + Send + 'static,
+    ) {
+        self.cancel_meeting_watch();
+
+        let Some(notification_tx) = self.notification_tx.clone() else {
+            return;
+        };
+        let cancelled = Arc::new(AtomicBool::new(false));
+        self.meeting_watch = Some(cancelled.clone());
+
+        std::thread::spawn(move || {
+            let started_at = Instant::now();
+            let mut watch = MeetingWatch::new(app_ids);
+
+            loop {
+                std::thread::sleep(MEETING_POLL_INTERVAL);
+                if cancelled.load(Ordering::Relaxed) {
+                    return;
+                }
+
+                let using_mic: Vec<String> = hypr_detect::list_mic_using_apps()
+                    .into_iter()
+                    .map(|app| app.id)
+                    .collect();
+
+                if watch.poll(&using_mic, is_recording()) {
+                    let trigger = NotificationTriggerMeetingEnded {
+                        duration: started_at.elapsed(),
+                        recorded: watch.recorded,
+                    };
+                    if let Err(e) = notification_tx.send(NotificationTrigger::MeetingEnded(trigger))
+                    {
+                        tracing::error!("{}", e);
+                    }
+                    return;
+                }
+            }
+        });
+    }
+
+    fn cancel_meeting_watch(&mut self) {
+        if let Some(cancelled) = self.meeting_watch.take() {
+            cancelled.store(true, Ordering::Relaxed);
         }
     }
 
@@ -40,6 +106,8 @@ impl DetectState {
     }
 
     pub fn stop(&mut self) -> Result<(), Error> {
+        self.cancel_meeting_watch();
+
         if let Some(mut detector) = self.detector.take() {
             detector.stop();
         }
@@ -57,3 +125,65 @@ impl Drop for DetectState {
         let _ = self.stop();
     }
 }
+
+struct MeetingWatch {
+    app_ids: HashSet<String>,
+    idle_polls: u32,
+    /// Whether a session was running at any poll.
+    recorded: bool,
+}
+
+impl MeetingWatch {
+    fn new(app_ids: Vec<String>) -> Self {
+        Self {
+            app_ids: app_ids.into_iter().collect(),
+            idle_polls: 0,
+            recorded: false,
+        }
+    }
+
+    /// Returns true once the meeting apps have been off the mic for long enough.
+    fn poll(&mut self, using_mic: &[String], recording: bool) -> bool {
+        self.recorded |= recording;
+
+        if using_mic.iter().any(|id| self.app_ids.contains(id)) {
+            self.idle_polls = 0;
+            return false;
+        }
+
+        self.idle_polls += 1;
+        self.idle_polls >= MEETING_ENDED_AFTER_IDLE_POLLS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meeting_watch() {
+        let zoom = vec!["us.zoom.xos".to_string()];
+        let mut watch = MeetingWatch::new(zoom.clone());
+
+        assert!(!watch.poll(&zoom, false));
+        assert!(!watch.poll(&[], false));
+        assert!(!watch.poll(&["com.apple.Safari".to_string()], false));
+        assert!(!watch.poll(&zoom, false));
+
+        for _ in 1..MEETING_ENDED_AFTER_IDLE_POLLS {
+            assert!(!watch.poll(&[], false));
+        }
+        assert!(watch.poll(&[], false));
+        assert!(!watch.recorded);
+    }
+
+    #[test]
+    fn test_meeting_watch_recorded() {
+        let zoom = vec!["us.zoom.xos".to_string()];
+        let mut watch = MeetingWatch::new(zoom.clone());
+
+        assert!(!watch.poll(&zoom, true));
+        assert!(!watch.poll(&zoom, false));
+        assert!(watch.recorded);
+    }
+}
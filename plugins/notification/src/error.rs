@@ -8,6 +8,16 @@ pub enum Error {
     Store(#[from] tauri_plugin_store2::Error),
     #[error(transparent)]
     Db(#[from] hypr_db_user::Error),
+    #[error(transparent)]
+    DbPlugin(#[from] tauri_plugin_db::Error),
+    #[error(transparent)]
+    Template(#[from] hypr_template::Error),
+    #[error(transparent)]
+    Task(#[from] tauri_plugin_task::Error),
+    #[error(transparent)]
+    Connector(#[from] tauri_plugin_connector::Error),
+    #[error("invalid task payload: {0}")]
+    InvalidTaskPayload(String),
     #[error("Channel closed unexpectedly")]
     ChannelClosed,
     #[error("Timeout waiting for notification permission response")]
@@ -25,23 +25,24 @@ const EVENT_NOTIFICATION_WORKER_NAKE: &str = "event_notification_worker";
 
 #[tracing::instrument(skip(ctx), name = EVENT_NOTIFICATION_WORKER_NAKE)]
 pub async fn perform_event_notification(_job: Job, ctx: Data<WorkerState>) -> Result<(), Error> {
-    tracing::info!("Event notification worker executing - checking for events in next 5 minutes");
-    let latest_event = ctx
+    // Which of these are due is decided by the handler, against the user's reminder settings.
+    let now = Utc::now();
+    let upcoming_events = ctx
         .db
         .list_events(Some(ListEventFilter {
             common: ListEventFilterCommon {
                 user_id: ctx.user_id.clone(),
-                limit: Some(1),
+                limit: None,
             },
             specific: ListEventFilterSpecific::DateRange {
-                start: Utc::now(),
-                end: Utc::now() + Duration::minutes(5),
+                start: now - Duration::minutes(1),
+                end: now + Duration::minutes(crate::reminder::MAX_LEAD_MINUTES as i64 + 1),
             },
         }))
         .await
         .map_err(|e| crate::Error::Db(e).as_worker_error())?;
 
-    if let Some(event) = latest_event.first() {
+    for event in &upcoming_events {
         tracing::info!("found_upcoming_event: {}", event.name);
 
         let seconds_until_start = event
@@ -54,7 +55,15 @@ pub async fn perform_event_notification(_job: Job, ctx: Data<WorkerState>) -> Re
                 .send(NotificationTrigger::Event(NotificationTriggerEvent {
                     event_id: event.id.clone(),
                     event_name: event.name.clone(),
+                    calendar_id: event.calendar_id.clone(),
                     seconds_until_start,
+                    participants: event
+                        .participants
+                        .as_deref()
+                        .and_then(|raw| serde_json::from_str(raw).ok())
+                        .unwrap_or_default(),
+                    note: event.note.clone(),
+                    meeting_link: event.meeting_link.clone(),
                 }))
         {
             tracing::error!("{}", e);
@@ -1,6 +1,7 @@
 use std::future::Future;
 
 use crate::error::Error;
+use tauri_plugin_sfx::{AppSounds, SfxPluginExt};
 use tauri_plugin_store2::StorePluginExt;
 
 pub trait NotificationPluginExt<R: tauri::Runtime> {
@@ -12,9 +13,22 @@ pub trait NotificationPluginExt<R: tauri::Runtime> {
     fn get_respect_do_not_disturb(&self) -> Result<bool, Error>;
     fn set_respect_do_not_disturb(&self, enabled: bool) -> Result<(), Error>;
 
+    fn get_quiet_hours(&self) -> Result<crate::QuietHours, Error>;
+    fn set_quiet_hours(&self, quiet_hours: crate::QuietHours) -> Result<(), Error>;
+
+    fn list_notification_history(&self) -> Result<Vec<crate::NotificationHistoryEntry>, Error>;
+    fn clear_notification_history(&self) -> Result<(), Error>;
+
     fn get_event_notification(&self) -> Result<bool, Error>;
     fn set_event_notification(&self, enabled: bool) -> Result<(), Error>;
 
+    fn get_event_reminders(&self) -> Result<crate::EventReminders, Error>;
+    fn set_event_reminders(&self, reminders: crate::EventReminders) -> Result<(), Error>;
+
+    /// Needs event notifications on, since their worker is what notices upcoming events.
+    fn get_pre_meeting_brief(&self) -> Result<bool, Error>;
+    fn set_pre_meeting_brief(&self, enabled: bool) -> Result<(), Error>;
+
     fn get_detect_notification(&self) -> Result<bool, Error>;
     fn set_detect_notification(&self, enabled: bool) -> Result<(), Error>;
 
@@ -44,6 +58,7 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> NotificationPluginExt<R> for T {
     #[tracing::instrument(skip(self))]
     fn show_notification(&self, v: hypr_notification::Notification) -> Result<(), Error> {
         hypr_notification::show(&v);
+        self.play(AppSounds::Notification);
         Ok(())
     }
 
@@ -64,13 +79,13 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> NotificationPluginExt<R> for T {
             .set(crate::StoreKey::EventNotification, enabled)
             .and_then(|v| {
                 if enabled {
-                    #[cfg(target_os = "macos")]
+                    #[cfg(any(target_os = "macos", target_os = "windows"))]
                     {
                         let app = self.app_handle().clone();
                         let _ = hypr_intercept::setup_quit_handler(crate::create_quit_handler(app));
                     }
                 } else if self.get_detect_notification().unwrap_or(false) {
-                    #[cfg(target_os = "macos")]
+                    #[cfg(any(target_os = "macos", target_os = "windows"))]
                     let _ = hypr_intercept::reset_quit_handler();
                 }
 
@@ -79,6 +94,40 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> NotificationPluginExt<R> for T {
             .map_err(Error::Store)
     }
 
+    #[tracing::instrument(skip(self))]
+    fn get_event_reminders(&self) -> Result<crate::EventReminders, Error> {
+        let store = self.notification_store();
+        store
+            .get(crate::StoreKey::EventReminders)
+            .map_err(Error::Store)
+            .map(|v| v.unwrap_or_default())
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn set_event_reminders(&self, reminders: crate::EventReminders) -> Result<(), Error> {
+        let store = self.notification_store();
+        store
+            .set(crate::StoreKey::EventReminders, reminders)
+            .map_err(Error::Store)
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn get_pre_meeting_brief(&self) -> Result<bool, Error> {
+        let store = self.notification_store();
+        store
+            .get(crate::StoreKey::PreMeetingBrief)
+            .map_err(Error::Store)
+            .map(|v| v.unwrap_or(false))
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn set_pre_meeting_brief(&self, enabled: bool) -> Result<(), Error> {
+        let store = self.notification_store();
+        store
+            .set(crate::StoreKey::PreMeetingBrief, enabled)
+            .map_err(Error::Store)
+    }
+
     #[tracing::instrument(skip(self))]
     fn get_respect_do_not_disturb(&self) -> Result<bool, Error> {
         let store = self.notification_store();
@@ -96,6 +145,43 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> NotificationPluginExt<R> for T {
             .map_err(Error::Store)
     }
 
+    #[tracing::instrument(skip(self))]
+    fn get_quiet_hours(&self) -> Result<crate::QuietHours, Error> {
+        let store = self.notification_store();
+        store
+            .get(crate::StoreKey::QuietHours)
+            .map_err(Error::Store)
+            .map(|v| v.unwrap_or_default())
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn set_quiet_hours(&self, quiet_hours: crate::QuietHours) -> Result<(), Error> {
+        let store = self.notification_store();
+        store
+            .set(crate::StoreKey::QuietHours, quiet_hours)
+            .map_err(Error::Store)
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn list_notification_history(&self) -> Result<Vec<crate::NotificationHistoryEntry>, Error> {
+        let store = self.notification_store();
+        store
+            .get::<Vec<crate::NotificationHistoryEntry>>(crate::StoreKey::History)
+            .map_err(Error::Store)
+            .map(|v| v.unwrap_or_default().into_iter().rev().collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn clear_notification_history(&self) -> Result<(), Error> {
+        let store = self.notification_store();
+        store
+            .set(
+                crate::StoreKey::History,
+                Vec::<crate::NotificationHistoryEntry>::new(),
+            )
+            .map_err(Error::Store)
+    }
+
     #[tracing::instrument(skip(self))]
     fn get_detect_notification(&self) -> Result<bool, Error> {
         let store = self.notification_store();
@@ -112,13 +198,13 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> NotificationPluginExt<R> for T {
             .set(crate::StoreKey::DetectNotification, enabled)
             .and_then(|v| {
                 if enabled {
-                    #[cfg(target_os = "macos")]
+                    #[cfg(any(target_os = "macos", target_os = "windows"))]
                     {
                         let app = self.app_handle().clone();
                         let _ = hypr_intercept::setup_quit_handler(crate::create_quit_handler(app));
                     }
                 } else if self.get_event_notification().unwrap_or(false) {
-                    #[cfg(target_os = "macos")]
+                    #[cfg(any(target_os = "macos", target_os = "windows"))]
                     let _ = hypr_intercept::reset_quit_handler();
                 }
 
@@ -205,3 +291,20 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> NotificationPluginExt<R> for T {
         guard.detect_state.stop()
     }
 }
+
+pub(crate) fn append_history<R: tauri::Runtime>(
+    app: &impl NotificationPluginExt<R>,
+    entry: crate::NotificationHistoryEntry,
+) -> Result<(), Error> {
+    let store = app.notification_store();
+
+    let mut history: Vec<crate::NotificationHistoryEntry> = store
+        .get(crate::StoreKey::History)
+        .map_err(Error::Store)?
+        .unwrap_or_default();
+    crate::history::append(&mut history, entry);
+
+    store
+        .set(crate::StoreKey::History, history)
+        .map_err(Error::Store)
+}
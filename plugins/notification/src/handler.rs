@@ -1,14 +1,26 @@
 use std::sync::mpsc::{Receiver, Sender};
 use std::thread::JoinHandle;
 
-use crate::NotificationPluginExt;
-use tauri::AppHandle;
+use crate::{
+    i18n::{t, t_args},
+    NotificationPluginExt,
+};
+use chrono::{DateTime, Utc};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_db::DatabasePluginExt;
+use tauri_plugin_sfx::{AppSounds, SfxPluginExt};
 use tauri_plugin_windows::{HyprWindow, WindowsPluginExt};
 
+// Mic use shorter than this is more likely a voice memo or a quick test than a meeting.
+const MIN_MEETING_DURATION: std::time::Duration = std::time::Duration::from_secs(5 * 60);
+
 #[derive(Debug, Clone)]
 pub enum NotificationTrigger {
     Detect(NotificationTriggerDetect),
     Event(NotificationTriggerEvent),
+    MeetingEnded(NotificationTriggerMeetingEnded),
+    LengthAlert(NotificationTriggerLengthAlert),
+    Brief(NotificationTriggerBrief),
 }
 
 #[derive(Debug, Clone)]
@@ -21,7 +33,32 @@ pub struct NotificationTriggerDetect {
 pub struct NotificationTriggerEvent {
     pub event_id: String,
     pub event_name: String,
+    pub calendar_id: Option<String>,
     pub seconds_until_start: i64,
+    pub participants: Vec<hypr_db_user::EventParticipant>,
+    pub note: String,
+    pub meeting_link: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationTriggerMeetingEnded {
+    pub duration: std::time::Duration,
+    /// A session ran at some point during the meeting.
+    pub recorded: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationTriggerLengthAlert {
+    pub session_id: String,
+    pub alert: tauri_plugin_listener::LengthAlert,
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationTriggerBrief {
+    pub event_id: String,
+    pub event_name: String,
+    pub start_date: DateTime<Utc>,
+    pub brief: String,
 }
 
 pub struct NotificationHandler {
@@ -48,6 +85,9 @@ impl NotificationHandler {
     }
 
     fn worker_loop(rx: Receiver<NotificationTrigger>, app_handle: AppHandle<tauri::Wry>) {
+        let mut env = hypr_template::minijinja::Environment::new();
+        hypr_template::init(&mut env);
+
         while let Ok(trigger) = rx.recv() {
             match trigger {
                 NotificationTrigger::Detect(t) => {
@@ -57,9 +97,26 @@ impl NotificationHandler {
                 }
                 NotificationTrigger::Event(e) => {
                     if app_handle.get_event_notification().unwrap_or(false) {
-                        Self::handle_calendar_event(&app_handle, e);
+                        if app_handle.get_pre_meeting_brief().unwrap_or(false)
+                            && crate::brief::due(e.seconds_until_start)
+                        {
+                            crate::brief::enqueue(&app_handle, &e.event_id);
+                        }
+                        Self::handle_calendar_event(&app_handle, &env, e);
                     }
                 }
+                NotificationTrigger::MeetingEnded(t) => {
+                    if app_handle.get_detect_notification().unwrap_or(false) {
+                        Self::handle_meeting_ended(&app_handle, t);
+                    }
+                }
+                // Opted into through the user's config, which the listener already checked.
+                NotificationTrigger::LengthAlert(t) => {
+                    Self::handle_length_alert(&app_handle, t);
+                }
+                NotificationTrigger::Brief(t) => {
+                    Self::handle_brief(&app_handle, t);
+                }
             }
         }
     }
@@ -71,11 +128,6 @@ impl NotificationHandler {
 
         let respect_do_not_disturb = app_handle.get_respect_do_not_disturb().unwrap_or(false);
 
-        if main_window_focused {
-            tracing::info!(reason = "main_window_focused", "skip_handle_detect_event");
-            return;
-        }
-
         match trigger.event {
             hypr_detect::DetectEvent::MicStarted(apps) => {
                 if apps.is_empty() {
@@ -113,6 +165,41 @@ impl NotificationHandler {
                     return;
                 }
 
+                // Watch for the end of the meeting even if we don't notify about its start.
+                {
+                    let app_ids = apps
+                        .iter()
+                        .map(|app| app.id.clone())
+                        .filter(|id| !id.starts_with("com.hyprnote."))
+                        .collect::<Vec<_>>();
+
+                    if !app_ids.is_empty() {
+                        let is_recording = {
+                            let app_handle = app_handle.clone();
+                            move || {
+                                use tauri_plugin_listener::ListenerPluginExt;
+
+                                !matches!(
+                                    tauri::async_runtime::block_on(app_handle.get_state()),
+                                    tauri_plugin_listener::fsm::State::Inactive
+                                )
+                            }
+                        };
+
+                        let state = app_handle.state::<crate::SharedState>();
+                        state
+                            .lock()
+                            .unwrap()
+                            .detect_state
+                            .watch_meeting(app_ids, is_recording);
+                    }
+                }
+
+                if main_window_focused {
+                    tracing::info!(reason = "main_window_focused", "skip_handle_detect_event");
+                    return;
+                }
+
                 if respect_do_not_disturb && hypr_notification::is_do_not_disturb() {
                     tracing::info!(reason = "respect_do_not_disturb", "skip_notification");
                     return;
@@ -126,12 +213,13 @@ impl NotificationHandler {
                 let window_key = timestamp_secs / 10;
                 let key = format!("mic-detection-{}", window_key);
 
-                hypr_notification::show(
-                    &hypr_notification::Notification::builder()
-                        .title("Meeting detected")
+                Self::deliver(
+                    app_handle,
+                    hypr_notification::Notification::builder()
+                        .title(t("detect-title"))
                         .key(key)
-                        .message("Based on your microphone activity")
-                        .url("hypr://hyprnote.com/app/new?record=true")
+                        .message(t("detect-message"))
+                        .url(record_url(current_event_id(app_handle).as_deref()))
                         .timeout(std::time::Duration::from_secs(300))
                         .build(),
                 );
@@ -139,6 +227,11 @@ impl NotificationHandler {
             hypr_detect::DetectEvent::MicStopped => {
                 use tauri_plugin_listener::ListenerPluginExt;
 
+                if main_window_focused {
+                    tracing::info!(reason = "main_window_focused", "skip_handle_detect_event");
+                    return;
+                }
+
                 let app_handle = app_handle.clone();
                 tauri::async_runtime::spawn(async move {
                     app_handle.stop_session().await;
@@ -150,8 +243,11 @@ impl NotificationHandler {
 
     fn handle_calendar_event(
         app_handle: &AppHandle<tauri::Wry>,
+        env: &hypr_template::minijinja::Environment<'static>,
         trigger: NotificationTriggerEvent,
     ) {
+        use tauri_plugin_misc::MiscPluginExt;
+
         let main_window_focused = app_handle
             .window_is_focused(HyprWindow::Main)
             .unwrap_or(false);
@@ -168,25 +264,209 @@ impl NotificationHandler {
             return;
         }
 
-        if trigger.seconds_until_start < 180 {
-            if let Err(e) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                hypr_notification::show(
-                    &hypr_notification::Notification::builder()
-                        .key(&format!("event_{}", trigger.event_id,))
-                        .title(trigger.event_name.clone())
-                        .message("Meeting starting soon!")
-                        .url(format!(
-                            "hypr://hyprnote.com/app/new?calendarEventId={}&record=true",
-                            trigger.event_id
-                        ))
-                        .timeout(std::time::Duration::from_secs(
-                            trigger.seconds_until_start as u64,
-                        ))
-                        .build(),
-                );
-            })) {
-                tracing::error!("{:?}", e);
+        let reminders = app_handle.get_event_reminders().unwrap_or_default();
+        let Some(reminder) =
+            reminders.due(trigger.seconds_until_start, trigger.calendar_id.as_deref())
+        else {
+            return;
+        };
+
+        let key = match reminder {
+            crate::reminder::Reminder::Upcoming => format!("event_{}", trigger.event_id),
+            crate::reminder::Reminder::StartingNow => format!("event_{}_now", trigger.event_id),
+        };
+
+        let context = crate::context::EventContext::new(
+            &trigger,
+            &reminder,
+            trigger
+                .meeting_link
+                .clone()
+                .or_else(|| app_handle.parse_meeting_link(&trigger.note)),
+        );
+        let (title, message) = context.render(env).unwrap_or_else(|e| {
+            tracing::error!("render_event_notification_failed: {}", e);
+            let message = match context.minutes_until_start {
+                0 => t("event-starting-now"),
+                minutes => t_args("event-starting-in", &[("minutes", &minutes)]),
+            };
+            (trigger.event_name.clone(), message)
+        });
+
+        if let Err(e) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let builder = hypr_notification::Notification::builder()
+                .key(&key)
+                .title(title.clone())
+                .message(message.clone())
+                .timeout(std::time::Duration::from_secs(
+                    trigger.seconds_until_start.max(60) as u64,
+                ));
+
+            let builder = match trigger.meeting_link {
+                Some(_) => builder
+                    .url(join_url(&trigger.event_id))
+                    .action_label(t("event-join-action")),
+                None => builder.url(record_url(Some(&trigger.event_id))),
+            };
+
+            Self::deliver(app_handle, builder.build());
+        })) {
+            tracing::error!("{:?}", e);
+        }
+    }
+
+    fn handle_meeting_ended(
+        app_handle: &AppHandle<tauri::Wry>,
+        trigger: NotificationTriggerMeetingEnded,
+    ) {
+        use tauri_plugin_listener::ListenerPluginExt;
+
+        if trigger.duration < MIN_MEETING_DURATION {
+            tracing::info!(reason = "meeting_too_short", "skip_notification");
+            return;
+        }
+
+        if app_handle
+            .window_is_focused(HyprWindow::Main)
+            .unwrap_or(false)
+        {
+            tracing::info!(reason = "main_window_focused", "skip_handle_meeting_ended");
+            return;
+        }
+
+        if app_handle.get_respect_do_not_disturb().unwrap_or(false)
+            && hypr_notification::is_do_not_disturb()
+        {
+            tracing::info!(reason = "respect_do_not_disturb", "skip_notification");
+            return;
+        }
+
+        let (state, session_id) = tauri::async_runtime::block_on(async {
+            (
+                app_handle.get_state().await,
+                app_handle.get_session_id().await,
+            )
+        });
+
+        let notification = match (state, session_id) {
+            (
+                tauri_plugin_listener::fsm::State::RunningActive
+                | tauri_plugin_listener::fsm::State::RunningPaused,
+                Some(session_id),
+            ) => hypr_notification::Notification::builder()
+                .key(format!("meeting-ended-{}", session_id))
+                .title(t("ended-recording-title"))
+                .message(t("ended-recording-message"))
+                .url(format!("hypr://hyprnote.com/app/note/{}", session_id))
+                .timeout(std::time::Duration::from_secs(300))
+                .build(),
+            // Recorded, and the session already ended on its own.
+            _ if trigger.recorded => {
+                tracing::info!(reason = "meeting_recorded", "skip_notification");
+                return;
+            }
+            _ => hypr_notification::Notification::builder()
+                .key("meeting-ended-missed")
+                .title(t("ended-missed-title"))
+                .message(t("ended-missed-message"))
+                .url("hypr://hyprnote.com/app/new")
+                .timeout(std::time::Duration::from_secs(300))
+                .build(),
+        };
+
+        Self::deliver(app_handle, notification);
+    }
+
+    fn handle_length_alert(
+        app_handle: &AppHandle<tauri::Wry>,
+        trigger: NotificationTriggerLengthAlert,
+    ) {
+        use tauri_plugin_listener::LengthAlert;
+
+        if app_handle.get_respect_do_not_disturb().unwrap_or(false)
+            && hypr_notification::is_do_not_disturb()
+        {
+            tracing::info!(reason = "respect_do_not_disturb", "skip_notification");
+            return;
+        }
+
+        let (key, message) = match &trigger.alert {
+            LengthAlert::Duration { minutes } => (
+                format!("length-duration-{}", trigger.session_id),
+                t_args("length-duration-message", &[("minutes", minutes)]),
+            ),
+            LengthAlert::EventEnd {
+                event_name,
+                end_date,
+            } => {
+                let time = end_date.with_timezone(&chrono::Local).format("%H:%M");
+                (
+                    format!("length-event-end-{}", trigger.session_id),
+                    t_args(
+                        "length-event-end-message",
+                        &[("event", event_name), ("time", &time)],
+                    ),
+                )
+            }
+        };
+
+        Self::deliver(
+            app_handle,
+            hypr_notification::Notification::builder()
+                .key(key)
+                .title(t("length-title"))
+                .message(message)
+                .url(format!(
+                    "hypr://hyprnote.com/app/note/{}",
+                    trigger.session_id
+                ))
+                .timeout(std::time::Duration::from_secs(300))
+                .build(),
+        );
+    }
+
+    fn handle_brief(app_handle: &AppHandle<tauri::Wry>, trigger: NotificationTriggerBrief) {
+        if app_handle.get_respect_do_not_disturb().unwrap_or(false)
+            && hypr_notification::is_do_not_disturb()
+        {
+            tracing::info!(reason = "respect_do_not_disturb", "skip_notification");
+            return;
+        }
+
+        let seconds_until_start = (trigger.start_date - Utc::now()).num_seconds();
+
+        Self::deliver(
+            app_handle,
+            hypr_notification::Notification::builder()
+                .key(format!("brief_{}", trigger.event_id))
+                .title(t_args("brief-title", &[("event", &trigger.event_name)]))
+                .message(trigger.brief)
+                .url(record_url(Some(&trigger.event_id)))
+                .timeout(std::time::Duration::from_secs(
+                    seconds_until_start.max(60) as u64
+                ))
+                .build(),
+        );
+    }
+
+    fn deliver(app_handle: &AppHandle<tauri::Wry>, notification: hypr_notification::Notification) {
+        let quiet_hours = app_handle.get_quiet_hours().unwrap_or_default();
+
+        let shown = if quiet_hours.is_active(chrono::Local::now().naive_local()) {
+            tracing::info!(reason = "quiet_hours", "skip_notification");
+            if quiet_hours.mode == crate::QuietHoursMode::Suppress {
+                return;
             }
+            false
+        } else {
+            hypr_notification::show(&notification);
+            app_handle.play(AppSounds::Notification);
+            true
+        };
+
+        let entry = crate::NotificationHistoryEntry::new(&notification, shown);
+        if let Err(e) = crate::ext::append_history(app_handle, entry) {
+            tracing::error!("notification_history_append_failed: {}", e);
         }
     }
 
@@ -204,3 +484,95 @@ impl Drop for NotificationHandler {
         self.stop();
     }
 }
+
+/// Opens a new note that starts recording, linked to the calendar event if there is one.
+fn record_url(event_id: Option<&str>) -> String {
+    match event_id {
+        Some(id) => format!("hypr://hyprnote.com/app/new?record=true&event_id={}", id),
+        None => "hypr://hyprnote.com/app/new?record=true".to_string(),
+    }
+}
+
+/// Like `record_url`, and also opens the event's meeting link.
+fn join_url(event_id: &str) -> String {
+    format!("{}&join=true", record_url(Some(event_id)))
+}
+
+fn current_event_id(app_handle: &AppHandle<tauri::Wry>) -> Option<String> {
+    tauri::async_runtime::block_on(async {
+        let user_id = app_handle.db_user_id().await.ok()??;
+        let now = Utc::now();
+
+        let events = app_handle
+            .db_list_events(hypr_db_user::ListEventFilter {
+                common: hypr_db_user::ListEventFilterCommon {
+                    user_id,
+                    limit: Some(20),
+                },
+                specific: hypr_db_user::ListEventFilterSpecific::DateRange {
+                    start: now - chrono::Duration::hours(4),
+                    end: now + chrono::Duration::minutes(10),
+                },
+            })
+            .await
+            .ok()?;
+
+        pick_current_event(&events, now).map(|event| event.id.clone())
+    })
+}
+
+/// The event the user is most likely joining: not over yet, and starting closest to now.
+fn pick_current_event(
+    events: &[hypr_db_user::Event],
+    now: DateTime<Utc>,
+) -> Option<&hypr_db_user::Event> {
+    events
+        .iter()
+        .filter(|event| event.end_date > now)
+        .min_by_key(|event| (event.start_date - now).num_seconds().abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(id: &str, start: DateTime<Utc>, minutes: i64) -> hypr_db_user::Event {
+        hypr_db_user::Event {
+            id: id.to_string(),
+            name: id.to_string(),
+            start_date: start,
+            end_date: start + chrono::Duration::minutes(minutes),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_pick_current_event() {
+        let now = Utc::now();
+        let events = vec![
+            event("ended", now - chrono::Duration::minutes(90), 60),
+            event("all-hands", now - chrono::Duration::minutes(50), 120),
+            event("standup", now + chrono::Duration::minutes(2), 15),
+        ];
+
+        assert_eq!(pick_current_event(&events, now).unwrap().id, "standup");
+        assert_eq!(
+            pick_current_event(&events[..2], now).unwrap().id,
+            "all-hands"
+        );
+        assert!(pick_current_event(&events[..1], now).is_none());
+    }
+
+    #[test]
+    fn test_record_url() {
+        assert_eq!(
+            record_url(Some("abc")),
+            "hypr://hyprnote.com/app/new?record=true&event_id=abc"
+        );
+        assert_eq!(record_url(None), "hypr://hyprnote.com/app/new?record=true");
+        assert_eq!(
+            join_url("abc"),
+            "hypr://hyprnote.com/app/new?record=true&event_id=abc&join=true"
+        );
+    }
+}
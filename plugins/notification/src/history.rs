@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+
+pub const MAX_HISTORY_ENTRIES: usize = 100;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct NotificationHistoryEntry {
+    pub title: String,
+    pub message: String,
+    pub url: Option<String>,
+    /// False when the notification was held back by quiet hours.
+    pub shown: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl NotificationHistoryEntry {
+    pub fn new(notification: &hypr_notification::Notification, shown: bool) -> Self {
+        Self {
+            title: notification.title.clone(),
+            message: notification.message.clone(),
+            url: notification.url.clone(),
+            shown,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Appends to the history, dropping the oldest entries beyond `MAX_HISTORY_ENTRIES`.
+pub fn append(history: &mut Vec<NotificationHistoryEntry>, entry: NotificationHistoryEntry) {
+    history.push(entry);
+    let overflow = history.len().saturating_sub(MAX_HISTORY_ENTRIES);
+    history.drain(..overflow);
+}
@@ -1,18 +1,27 @@
 use std::sync::Mutex;
 use tauri::Manager;
 
+mod brief;
 mod commands;
+mod context;
 mod detect;
 mod error;
 mod event;
 mod ext;
 mod handler;
+mod history;
+mod i18n;
+mod quiet;
 mod quit;
+mod reminder;
 mod store;
 
 pub use error::*;
 pub use ext::*;
+pub use history::NotificationHistoryEntry;
+pub use quiet::{QuietHours, QuietHoursMode, QuietHoursRule, Weekday};
 pub use quit::*;
+pub use reminder::EventReminders;
 pub use store::*;
 
 const PLUGIN_NAME: &str = "notification";
@@ -46,9 +55,17 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::show_notification::<tauri::Wry>,
             commands::get_event_notification::<tauri::Wry>,
             commands::set_event_notification::<tauri::Wry>,
+            commands::get_event_reminders::<tauri::Wry>,
+            commands::set_event_reminders::<tauri::Wry>,
+            commands::get_pre_meeting_brief::<tauri::Wry>,
+            commands::set_pre_meeting_brief::<tauri::Wry>,
             commands::get_detect_notification::<tauri::Wry>,
             commands::get_respect_do_not_disturb::<tauri::Wry>,
             commands::set_respect_do_not_disturb::<tauri::Wry>,
+            commands::get_quiet_hours::<tauri::Wry>,
+            commands::set_quiet_hours::<tauri::Wry>,
+            commands::list_notification_history::<tauri::Wry>,
+            commands::clear_notification_history::<tauri::Wry>,
             commands::set_detect_notification::<tauri::Wry>,
             commands::start_detect_notification::<tauri::Wry>,
             commands::stop_detect_notification::<tauri::Wry>,
@@ -68,7 +85,28 @@ pub fn init() -> tauri::plugin::TauriPlugin<tauri::Wry> {
         .setup(|app, _api| {
             let state = State::new(app.clone());
 
-            #[cfg(target_os = "macos")]
+            {
+                use tauri_specta::Event;
+
+                let tx = state.notification_handler.sender();
+                tauri_plugin_listener::SessionEvent::listen_any(app, move |event| {
+                    if let tauri_plugin_listener::SessionEvent::LengthAlert { session_id, alert } =
+                        event.payload
+                    {
+                        if let Some(tx) = &tx {
+                            let _ = tx.send(handler::NotificationTrigger::LengthAlert(
+                                handler::NotificationTriggerLengthAlert { session_id, alert },
+                            ));
+                        }
+                    }
+                });
+            }
+
+            brief::listen(app, state.notification_handler.sender());
+
+            hypr_intercept::setup_quit_confirmation(crate::create_quit_confirmation(app.clone()));
+
+            #[cfg(any(target_os = "macos", target_os = "windows"))]
             if app.get_detect_notification().unwrap_or(false) || app.get_event_notification().unwrap_or(false) {
                 let app = app.clone();
                 let _ = hypr_intercept::setup_quit_handler(crate::create_quit_handler(app));
@@ -0,0 +1,143 @@
+use chrono::{Datelike, NaiveDateTime, Timelike};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(rename_all = "lowercase")]
+pub enum Weekday {
+    Mon,
+    Tue,
+    Wed,
+    Thu,
+    Fri,
+    Sat,
+    Sun,
+}
+
+impl From<chrono::Weekday> for Weekday {
+    fn from(day: chrono::Weekday) -> Self {
+        match day {
+            chrono::Weekday::Mon => Weekday::Mon,
+            chrono::Weekday::Tue => Weekday::Tue,
+            chrono::Weekday::Wed => Weekday::Wed,
+            chrono::Weekday::Thu => Weekday::Thu,
+            chrono::Weekday::Fri => Weekday::Fri,
+            chrono::Weekday::Sat => Weekday::Sat,
+            chrono::Weekday::Sun => Weekday::Sun,
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum QuietHoursMode {
+    // Drop the notification.
+    #[default]
+    Suppress,
+    // Keep it in the notification history without showing it.
+    Queue,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct QuietHoursRule {
+    pub weekday: Weekday,
+    /// Minutes after local midnight.
+    pub start_minute: u16,
+    /// An end before the start runs past midnight into the next day; an equal one covers the whole day.
+    pub end_minute: u16,
+}
+
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type,
+)]
+pub struct QuietHours {
+    pub enabled: bool,
+    pub mode: QuietHoursMode,
+    pub rules: Vec<QuietHoursRule>,
+}
+
+impl QuietHours {
+    pub fn is_active(&self, now: NaiveDateTime) -> bool {
+        if !self.enabled {
+            return false;
+        }
+
+        let today: Weekday = now.weekday().into();
+        let yesterday: Weekday = now.weekday().pred().into();
+        let minute = (now.hour() * 60 + now.minute()) as u16;
+
+        self.rules.iter().any(|rule| {
+            let (start, end) = (rule.start_minute, rule.end_minute);
+
+            if start == end {
+                rule.weekday == today
+            } else if start < end {
+                rule.weekday == today && (start..end).contains(&minute)
+            } else {
+                (rule.weekday == today && minute >= start)
+                    || (rule.weekday == yesterday && minute < end)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(date: &str, time: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(&format!("{} {}", date, time), "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn quiet_hours(rules: Vec<QuietHoursRule>) -> QuietHours {
+        QuietHours {
+            enabled: true,
+            mode: QuietHoursMode::Suppress,
+            rules,
+        }
+    }
+
+    #[test]
+    fn test_same_day_window() {
+        // 2025-01-06 is a Monday.
+        let q = quiet_hours(vec![QuietHoursRule {
+            weekday: Weekday::Mon,
+            start_minute: 12 * 60,
+            end_minute: 13 * 60,
+        }]);
+
+        assert!(q.is_active(at("2025-01-06", "12:00")));
+        assert!(q.is_active(at("2025-01-06", "12:59")));
+        assert!(!q.is_active(at("2025-01-06", "13:00")));
+        assert!(!q.is_active(at("2025-01-07", "12:30")));
+    }
+
+    #[test]
+    fn test_overnight_window() {
+        let q = quiet_hours(vec![QuietHoursRule {
+            weekday: Weekday::Sun,
+            start_minute: 22 * 60,
+            end_minute: 7 * 60,
+        }]);
+
+        assert!(q.is_active(at("2025-01-05", "23:00")));
+        assert!(q.is_active(at("2025-01-06", "06:59")));
+        assert!(!q.is_active(at("2025-01-06", "07:00")));
+        assert!(!q.is_active(at("2025-01-06", "23:00")));
+    }
+
+    #[test]
+    fn test_whole_day_and_disabled() {
+        let mut q = quiet_hours(vec![QuietHoursRule {
+            weekday: Weekday::Sat,
+            start_minute: 0,
+            end_minute: 0,
+        }]);
+
+        assert!(q.is_active(at("2025-01-04", "15:00")));
+        assert!(!q.is_active(at("2025-01-05", "15:00")));
+
+        q.enabled = false;
+        assert!(!q.is_active(at("2025-01-04", "15:00")));
+    }
+}
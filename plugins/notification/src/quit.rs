@@ -2,36 +2,14 @@ use tauri::Manager;
 use tauri_plugin_dialog::DialogExt;
 use tauri_plugin_listener::ListenerPluginExt;
 
+use crate::i18n::t;
+
 #[cfg(target_os = "macos")]
 pub fn create_quit_handler<R: tauri::Runtime>(
     app_handle: tauri::AppHandle<R>,
 ) -> impl Fn() -> bool {
     move || {
-        let mut is_exit_intent = false;
-
-        if let Some(shared_state) = app_handle.try_state::<tauri_plugin_listener::SharedState>() {
-            if let Ok(guard) = shared_state.try_lock() {
-                let state = tokio::task::block_in_place(|| {
-                    tokio::runtime::Handle::current().block_on(guard.get_state())
-                });
-                if !matches!(state, tauri_plugin_listener::fsm::State::RunningActive) {
-                    is_exit_intent = true;
-                } else {
-                    is_exit_intent = app_handle
-                        .dialog()
-                        .message("Hyprnote is currently recording.")
-                        .title("Do you really want to quit?")
-                        .buttons(tauri_plugin_dialog::MessageDialogButtons::OkCancelCustom(
-                            "Quit".to_string(),
-                            "Cancel".to_string(),
-                        ))
-                        .kind(tauri_plugin_dialog::MessageDialogKind::Info)
-                        .blocking_show()
-                }
-            }
-        }
-
-        if is_exit_intent {
+        if confirm_quit(&app_handle) {
             for (_, window) in app_handle.webview_windows() {
                 let _ = window.close();
             }
@@ -48,3 +26,88 @@ pub fn create_quit_handler<R: tauri::Runtime>(
         false
     }
 }
+
+/// For `hypr_intercept::confirm_quit`, which the tray's Quit item and closing the main window
+/// go through.
+pub fn create_quit_confirmation<R: tauri::Runtime>(
+    app_handle: tauri::AppHandle<R>,
+) -> impl Fn() -> bool {
+    move || confirm_quit(&app_handle)
+}
+
+/// Asks the user whether to quit while a recording is running; yes right away otherwise.
+fn confirm_quit<R: tauri::Runtime>(app_handle: &tauri::AppHandle<R>) -> bool {
+    let Some(shared_state) = app_handle.try_state::<tauri_plugin_listener::SharedState>() else {
+        return false;
+    };
+    let Ok(guard) = shared_state.try_lock() else {
+        return false;
+    };
+    // The macOS terminate request arrives on the main thread, inside the runtime.
+    let state = match tokio::runtime::Handle::try_current() {
+        Ok(handle) => tokio::task::block_in_place(|| handle.block_on(guard.get_state())),
+        Err(_) => tauri::async_runtime::block_on(guard.get_state()),
+    };
+    drop(guard);
+
+    if !is_recording(&state) {
+        return true;
+    }
+
+    app_handle
+        .dialog()
+        .message(t("quit-message"))
+        .title(t("quit-title"))
+        .buttons(tauri_plugin_dialog::MessageDialogButtons::OkCancelCustom(
+            t("quit-confirm"),
+            t("quit-cancel"),
+        ))
+        .kind(tauri_plugin_dialog::MessageDialogKind::Info)
+        .blocking_show()
+}
+
+/// Answers Windows asking whether the session may end. That happens on the intercept thread
+/// while the shutdown waits, so nothing here asks the user. The answer is yes unless a
+/// recording is running: then the recording is saved in the background, and the shutdown
+/// screen shows why it is held until that finishes.
+#[cfg(target_os = "windows")]
+pub fn create_quit_handler<R: tauri::Runtime>(
+    app_handle: tauri::AppHandle<R>,
+) -> impl Fn() -> bool {
+    move || {
+        // Without the state, or while something else holds it, we can't tell; let it end.
+        let recording = app_handle
+            .try_state::<tauri_plugin_listener::SharedState>()
+            .and_then(|shared_state| {
+                let guard = shared_state.try_lock().ok()?;
+                Some(is_recording(&tauri::async_runtime::block_on(
+                    guard.get_state(),
+                )))
+            })
+            .unwrap_or(false);
+
+        if !recording {
+            hypr_host::kill_processes_by_matcher(hypr_host::ProcessMatcher::Sidecar);
+            return true;
+        }
+
+        hypr_intercept::set_shutdown_block_reason(Some(&t("shutdown-block-reason")));
+
+        let app_handle = app_handle.clone();
+        tauri::async_runtime::spawn(async move {
+            let _ = app_handle.stop_session().await;
+            hypr_host::kill_processes_by_matcher(hypr_host::ProcessMatcher::Sidecar);
+            hypr_intercept::set_shutdown_block_reason(None);
+        });
+
+        false
+    }
+}
+
+fn is_recording(state: &tauri_plugin_listener::fsm::State) -> bool {
+    matches!(
+        state,
+        tauri_plugin_listener::fsm::State::RunningActive
+            | tauri_plugin_listener::fsm::State::RunningPaused
+    )
+}
@@ -0,0 +1,83 @@
+/// The worker looks this far ahead, so longer lead times would never fire.
+pub const MAX_LEAD_MINUTES: u32 = 60;
+
+// The event worker runs once a minute; each reminder is due on exactly one tick.
+pub(crate) const TICK_SECS: i64 = 60;
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct EventReminders {
+    pub lead_minutes: u32,
+    /// Send a second reminder when the event starts.
+    pub starting_now: bool,
+    /// Calendar ids whose events never trigger reminders.
+    pub excluded_calendars: Vec<String>,
+}
+
+impl Default for EventReminders {
+    fn default() -> Self {
+        Self {
+            lead_minutes: 5,
+            starting_now: false,
+            excluded_calendars: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reminder {
+    Upcoming,
+    StartingNow,
+}
+
+impl EventReminders {
+    pub fn due(&self, seconds_until_start: i64, calendar_id: Option<&str>) -> Option<Reminder> {
+        if calendar_id.is_some_and(|id| self.excluded_calendars.iter().any(|c| c == id)) {
+            return None;
+        }
+
+        let lead = (self.lead_minutes.min(MAX_LEAD_MINUTES) * 60) as i64;
+        if lead > 0 && (lead - TICK_SECS < seconds_until_start && seconds_until_start <= lead) {
+            return Some(Reminder::Upcoming);
+        }
+
+        if (self.starting_now || lead == 0)
+            && (-TICK_SECS < seconds_until_start && seconds_until_start <= 0)
+        {
+            return Some(Reminder::StartingNow);
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_due() {
+        let reminders = EventReminders {
+            lead_minutes: 10,
+            starting_now: true,
+            excluded_calendars: vec!["personal".to_string()],
+        };
+
+        assert_eq!(reminders.due(601, None), None);
+        assert_eq!(reminders.due(600, None), Some(Reminder::Upcoming));
+        assert_eq!(reminders.due(541, Some("work")), Some(Reminder::Upcoming));
+        assert_eq!(reminders.due(540, None), None);
+        assert_eq!(reminders.due(120, None), None);
+        assert_eq!(reminders.due(0, None), Some(Reminder::StartingNow));
+        assert_eq!(reminders.due(-59, None), Some(Reminder::StartingNow));
+        assert_eq!(reminders.due(-60, None), None);
+        assert_eq!(reminders.due(600, Some("personal")), None);
+    }
+
+    #[test]
+    fn test_due_without_starting_now() {
+        let reminders = EventReminders::default();
+
+        assert_eq!(reminders.due(300, None), Some(Reminder::Upcoming));
+        assert_eq!(reminders.due(0, None), None);
+    }
+}
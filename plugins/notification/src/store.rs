@@ -6,6 +6,10 @@ pub enum StoreKey {
     DetectNotification,
     IgnoredPlatforms,
     RespectDoNotDisturb,
+    QuietHours,
+    EventReminders,
+    PreMeetingBrief,
+    History,
 }
 
 impl ScopedStoreKey for StoreKey {}
@@ -10,6 +10,23 @@ const COMMANDS: &[&str] = &[
     "get_deep_link_url",
     "get_base_folder",
     "set_base_folder",
+    "get_vault_path",
+    "set_vault_path",
+    "export_note",
+    "list_sync_states",
+    "resolve_conflict",
+    "get_note_template",
+    "set_note_template",
+    "get_frontmatter_fields",
+    "set_frontmatter_fields",
+    "get_routing_rules",
+    "set_routing_rules",
+    "get_daily_note",
+    "set_daily_note",
+    "get_audio_export",
+    "set_audio_export",
+    "export_notes",
+    "reexport_all",
 ];
 
 fn main() {
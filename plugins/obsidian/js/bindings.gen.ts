@@ -39,12 +39,70 @@ async getBaseFolder() : Promise<string | null> {
 },
 async setBaseFolder(baseFolder: string) : Promise<null> {
     return await TAURI_INVOKE("plugin:obsidian|set_base_folder", { baseFolder });
+},
+async getVaultPath() : Promise<string | null> {
+    return await TAURI_INVOKE("plugin:obsidian|get_vault_path");
+},
+async setVaultPath(vaultPath: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:obsidian|set_vault_path", { vaultPath });
+},
+async exportNote(sessionId: string) : Promise<string> {
+    return await TAURI_INVOKE("plugin:obsidian|export_note", { sessionId });
+},
+async listSyncStates() : Promise<NoteSyncState[]> {
+    return await TAURI_INVOKE("plugin:obsidian|list_sync_states");
+},
+async resolveConflict(sessionId: string, resolution: ConflictResolution) : Promise<null> {
+    return await TAURI_INVOKE("plugin:obsidian|resolve_conflict", { sessionId, resolution });
+},
+async getNoteTemplate() : Promise<string> {
+    return await TAURI_INVOKE("plugin:obsidian|get_note_template");
+},
+async setNoteTemplate(template: string | null) : Promise<null> {
+    return await TAURI_INVOKE("plugin:obsidian|set_note_template", { template });
+},
+async getFrontmatterFields() : Promise<FrontmatterField[]> {
+    return await TAURI_INVOKE("plugin:obsidian|get_frontmatter_fields");
+},
+async setFrontmatterFields(fields: FrontmatterField[]) : Promise<null> {
+    return await TAURI_INVOKE("plugin:obsidian|set_frontmatter_fields", { fields });
+},
+async getRoutingRules() : Promise<RoutingRule[]> {
+    return await TAURI_INVOKE("plugin:obsidian|get_routing_rules");
+},
+async setRoutingRules(rules: RoutingRule[]) : Promise<null> {
+    return await TAURI_INVOKE("plugin:obsidian|set_routing_rules", { rules });
+},
+async getDailyNote() : Promise<DailyNoteConfig> {
+    return await TAURI_INVOKE("plugin:obsidian|get_daily_note");
+},
+async setDailyNote(config: DailyNoteConfig) : Promise<null> {
+    return await TAURI_INVOKE("plugin:obsidian|set_daily_note", { config });
+},
+async getAudioExport() : Promise<AudioExportConfig> {
+    return await TAURI_INVOKE("plugin:obsidian|get_audio_export");
+},
+async setAudioExport(config: AudioExportConfig) : Promise<null> {
+    return await TAURI_INVOKE("plugin:obsidian|set_audio_export", { config });
+},
+async exportNotes(filter: ExportFilter) : Promise<BulkExportReport> {
+    return await TAURI_INVOKE("plugin:obsidian|export_notes", { filter });
+},
+async reexportAll() : Promise<BulkExportReport> {
+    return await TAURI_INVOKE("plugin:obsidian|reexport_all");
 }
 }
 
 /** user-defined events **/
 
 
+export const events = __makeEvents__<{
+obsidianSyncEvent: ObsidianSyncEvent,
+obsidianExportProgress: ObsidianExportProgress
+}>({
+obsidianSyncEvent: "plugin:obsidian:obsidian-sync-event",
+obsidianExportProgress: "plugin:obsidian:obsidian-export-progress"
+})
 
 /** user-defined constants **/
 
@@ -52,7 +110,19 @@ async setBaseFolder(baseFolder: string) : Promise<null> {
 
 /** user-defined types **/
 
-
+export type AudioExportConfig = { enabled: boolean; folder: string; timestamp_links: boolean }
+export type BulkExportReport = { exported: string[]; failed: ExportFailure[] }
+export type ConflictResolution = "keepVault" | "keepHyprnote"
+export type DailyNoteConfig = { enabled: boolean; folder: string; date_format: string }
+export type ExportFailure = { session_id: string; error: string }
+export type ExportFilter = { start: string | null; end: string | null; tag: string | null; calendar_id: string | null }
+export type FrontmatterField = "title" | "created" | "tags" | "attendees" | "event_date" | "audio_link"
+export type NoteSyncState = { session_id: string; path: string; vault_hash: string; note_hash: string; synced_at: string; status: SyncStatus }
+export type ObsidianExportProgress = { session_id: string; completed: number; total: number; error: string | null }
+export type ObsidianSyncEvent = { type: "imported"; session_id: string; path: string } | { type: "conflict"; session_id: string; path: string }
+export type RoutingCondition = { type: "tag"; name: string } | { type: "calendar"; calendar_id: string } | { type: "organizer_domain"; domain: string }
+export type RoutingRule = { condition: RoutingCondition; folder: string }
+export type SyncStatus = "synced" | "conflict"
 
 /** tauri-specta globals **/
 
@@ -0,0 +1,82 @@
+use chrono::{DateTime, Utc};
+
+use crate::routing::RoutingFacts;
+
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct ExportFilter {
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+    pub tag: Option<String>,
+    pub calendar_id: Option<String>,
+}
+
+impl ExportFilter {
+    pub fn matches(&self, date: DateTime<Utc>, facts: &RoutingFacts) -> bool {
+        if self.start.is_some_and(|start| date < start) || self.end.is_some_and(|end| date > end) {
+            return false;
+        }
+
+        if let Some(tag) = &self.tag {
+            if !facts.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                return false;
+            }
+        }
+
+        if let Some(calendar_id) = &self.calendar_id {
+            if facts.calendar_id.as_ref() != Some(calendar_id) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, specta::Type)]
+pub struct ExportFailure {
+    pub session_id: String,
+    pub error: String,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize, specta::Type)]
+pub struct BulkExportReport {
+    pub exported: Vec<String>,
+    pub failed: Vec<ExportFailure>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_filter() {
+        let date = "2025-03-10T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let facts = RoutingFacts {
+            tags: vec!["Planning".to_string()],
+            calendar_id: Some("work".to_string()),
+            organizer_email: None,
+        };
+
+        assert!(ExportFilter::default().matches(date, &facts));
+
+        let filter = ExportFilter {
+            start: Some("2025-03-01T00:00:00Z".parse().unwrap()),
+            end: Some("2025-03-31T00:00:00Z".parse().unwrap()),
+            tag: Some("planning".to_string()),
+            calendar_id: Some("work".to_string()),
+        };
+        assert!(filter.matches(date, &facts));
+
+        let filter = ExportFilter {
+            end: Some("2025-03-09T00:00:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+        assert!(!filter.matches(date, &facts));
+
+        let filter = ExportFilter {
+            calendar_id: Some("personal".to_string()),
+            ..Default::default()
+        };
+        assert!(!filter.matches(date, &facts));
+    }
+}
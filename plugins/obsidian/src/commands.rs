@@ -93,3 +93,152 @@ pub(crate) async fn get_deep_link_url<R: tauri::Runtime>(
 ) -> Result<String, String> {
     app.get_deep_link_url(note_name).map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_vault_path<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Option<String>, String> {
+    app.get_vault_path().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_vault_path<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    vault_path: String,
+) -> Result<(), String> {
+    app.set_vault_path(vault_path).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn export_note<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+) -> Result<String, String> {
+    app.export_note(session_id).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn list_sync_states<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<crate::NoteSyncState>, String> {
+    app.list_sync_states().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn resolve_conflict<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    resolution: crate::ConflictResolution,
+) -> Result<(), String> {
+    app.resolve_conflict(session_id, resolution)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_note_template<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<String, String> {
+    app.get_note_template().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_note_template<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    template: Option<String>,
+) -> Result<(), String> {
+    app.set_note_template(template).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_frontmatter_fields<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<crate::FrontmatterField>, String> {
+    app.get_frontmatter_fields().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_frontmatter_fields<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    fields: Vec<crate::FrontmatterField>,
+) -> Result<(), String> {
+    app.set_frontmatter_fields(fields)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_routing_rules<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<crate::RoutingRule>, String> {
+    app.get_routing_rules().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_routing_rules<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    rules: Vec<crate::RoutingRule>,
+) -> Result<(), String> {
+    app.set_routing_rules(rules).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_daily_note<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<crate::DailyNoteConfig, String> {
+    app.get_daily_note().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_daily_note<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    config: crate::DailyNoteConfig,
+) -> Result<(), String> {
+    app.set_daily_note(config).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn get_audio_export<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<crate::AudioExportConfig, String> {
+    app.get_audio_export().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_audio_export<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    config: crate::AudioExportConfig,
+) -> Result<(), String> {
+    app.set_audio_export(config).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn export_notes<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    filter: crate::ExportFilter,
+) -> Result<crate::BulkExportReport, String> {
+    app.export_notes(filter).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn reexport_all<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<crate::BulkExportReport, String> {
+    app.reexport_all().await.map_err(|e| e.to_string())
+}
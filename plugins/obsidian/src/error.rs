@@ -6,8 +6,32 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     #[error(transparent)]
     StoreError(#[from] tauri_plugin_store2::Error),
+    #[error(transparent)]
+    DatabaseError(#[from] tauri_plugin_db::Error),
+    #[error(transparent)]
+    DatabaseCoreError(#[from] hypr_db_core::Error),
+    #[error(transparent)]
+    TemplateError(#[from] hypr_template::Error),
+    #[error(transparent)]
+    TauriError(#[from] tauri::Error),
+    #[error(transparent)]
+    BufferError(#[from] hypr_buffer::Error),
+    #[error(transparent)]
+    NotifyError(#[from] notify::Error),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
     #[error("Vault name is not configured")]
     VaultNameNotConfigured,
+    #[error("Vault path is not configured")]
+    VaultPathNotConfigured,
+    #[error("Session not found: {0}")]
+    SessionNotFound(String),
+    #[error("Note for session {0} was edited in both Hyprnote and Obsidian")]
+    SyncConflict(String),
+    #[error("Session {0} was never exported to Obsidian")]
+    NoSyncState(String),
+    #[error("Invalid daily note date format: {0}")]
+    InvalidDateFormat(String),
 }
 
 impl Serialize for Error {
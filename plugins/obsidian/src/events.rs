@@ -0,0 +1,16 @@
+#[derive(Debug, Clone, serde::Serialize, specta::Type, tauri_specta::Event)]
+#[serde(tag = "type")]
+pub enum ObsidianSyncEvent {
+    #[serde(rename = "imported")]
+    Imported { session_id: String, path: String },
+    #[serde(rename = "conflict")]
+    Conflict { session_id: String, path: String },
+}
+
+#[derive(Debug, Clone, serde::Serialize, specta::Type, tauri_specta::Event)]
+pub struct ObsidianExportProgress {
+    pub session_id: String,
+    pub completed: u32,
+    pub total: u32,
+    pub error: Option<String>,
+}
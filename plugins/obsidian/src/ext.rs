@@ -1,4 +1,18 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+
+use tauri::Manager;
+use tauri_plugin_db::DatabasePluginExt;
 use tauri_plugin_store2::StorePluginExt;
+use tauri_specta::Event;
+
+use crate::bulk::{BulkExportReport, ExportFailure, ExportFilter};
+use crate::media::AudioExportConfig;
+use crate::note::{FrontmatterField, NoteContext, NoteEvent};
+use crate::routing::{DailyNoteConfig, RoutingFacts, RoutingRule};
+use crate::sync::{ChangeAction, ConflictResolution, NoteSyncState, SyncStatus};
+use crate::vault;
 
 pub trait ObsidianPluginExt<R: tauri::Runtime> {
     fn obsidian_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey>;
@@ -20,6 +34,44 @@ pub trait ObsidianPluginExt<R: tauri::Runtime> {
     fn set_enabled(&self, enabled: bool) -> Result<(), crate::Error>;
 
     fn get_deep_link_url(&self, note_name: String) -> Result<String, crate::Error>;
+
+    fn get_vault_path(&self) -> Result<Option<String>, crate::Error>;
+    fn set_vault_path(&self, vault_path: String) -> Result<(), crate::Error>;
+
+    fn get_note_template(&self) -> Result<String, crate::Error>;
+    fn set_note_template(&self, template: Option<String>) -> Result<(), crate::Error>;
+
+    fn get_frontmatter_fields(&self) -> Result<Vec<FrontmatterField>, crate::Error>;
+    fn set_frontmatter_fields(&self, fields: Vec<FrontmatterField>) -> Result<(), crate::Error>;
+
+    fn get_routing_rules(&self) -> Result<Vec<RoutingRule>, crate::Error>;
+    fn set_routing_rules(&self, rules: Vec<RoutingRule>) -> Result<(), crate::Error>;
+
+    fn get_daily_note(&self) -> Result<DailyNoteConfig, crate::Error>;
+    fn set_daily_note(&self, config: DailyNoteConfig) -> Result<(), crate::Error>;
+
+    fn get_audio_export(&self) -> Result<AudioExportConfig, crate::Error>;
+    fn set_audio_export(&self, config: AudioExportConfig) -> Result<(), crate::Error>;
+
+    fn list_sync_states(&self) -> Result<Vec<NoteSyncState>, crate::Error>;
+    fn export_note(
+        &self,
+        session_id: impl Into<String>,
+    ) -> impl Future<Output = Result<String, crate::Error>>;
+    fn export_notes(
+        &self,
+        filter: ExportFilter,
+    ) -> impl Future<Output = Result<BulkExportReport, crate::Error>>;
+    fn reexport_all(&self) -> impl Future<Output = Result<BulkExportReport, crate::Error>>;
+    fn sync_from_vault(&self, path: PathBuf) -> impl Future<Output = Result<(), crate::Error>>;
+    fn resolve_conflict(
+        &self,
+        session_id: impl Into<String>,
+        resolution: ConflictResolution,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
+
+    fn start_vault_watch(&self) -> Result<(), crate::Error>;
+    fn stop_vault_watch(&self);
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> ObsidianPluginExt<R> for T {
@@ -96,6 +148,11 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> ObsidianPluginExt<R> for T {
         let store = self.obsidian_store();
         store.set(crate::StoreKey::Enabled, enabled)?;
         store.save()?;
+
+        self.stop_vault_watch();
+        if enabled {
+            self.start_vault_watch()?;
+        }
         Ok(())
     }
 
@@ -110,4 +167,685 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> ObsidianPluginExt<R> for T {
             vault_name, note_name
         ))
     }
+
+    fn get_vault_path(&self) -> Result<Option<String>, crate::Error> {
+        let store = self.obsidian_store();
+        let v = store.get::<String>(crate::StoreKey::VaultPath)?;
+        Ok(v)
+    }
+
+    fn set_vault_path(&self, vault_path: String) -> Result<(), crate::Error> {
+        let store = self.obsidian_store();
+        store.set(crate::StoreKey::VaultPath, vault_path)?;
+        store.save()?;
+
+        self.stop_vault_watch();
+        self.start_vault_watch()?;
+        Ok(())
+    }
+
+    fn get_note_template(&self) -> Result<String, crate::Error> {
+        let store = self.obsidian_store();
+        let v = store.get::<String>(crate::StoreKey::NoteTemplate)?;
+        Ok(v.unwrap_or_else(|| hypr_template::OBSIDIAN_NOTE_TPL.to_string()))
+    }
+
+    fn set_note_template(&self, template: Option<String>) -> Result<(), crate::Error> {
+        if let Some(template) = &template {
+            crate::note::validate_template(template)?;
+        }
+
+        let store = self.obsidian_store();
+        store.set(crate::StoreKey::NoteTemplate, template)?;
+        store.save()?;
+        Ok(())
+    }
+
+    fn get_frontmatter_fields(&self) -> Result<Vec<FrontmatterField>, crate::Error> {
+        let store = self.obsidian_store();
+        let v = store.get::<Vec<FrontmatterField>>(crate::StoreKey::FrontmatterFields)?;
+        Ok(v.unwrap_or_else(|| crate::note::DEFAULT_FRONTMATTER_FIELDS.to_vec()))
+    }
+
+    fn set_frontmatter_fields(&self, fields: Vec<FrontmatterField>) -> Result<(), crate::Error> {
+        let store = self.obsidian_store();
+        store.set(crate::StoreKey::FrontmatterFields, fields)?;
+        store.save()?;
+        Ok(())
+    }
+
+    fn get_routing_rules(&self) -> Result<Vec<RoutingRule>, crate::Error> {
+        let store = self.obsidian_store();
+        let v = store.get::<Vec<RoutingRule>>(crate::StoreKey::RoutingRules)?;
+        Ok(v.unwrap_or_default())
+    }
+
+    fn set_routing_rules(&self, rules: Vec<RoutingRule>) -> Result<(), crate::Error> {
+        let store = self.obsidian_store();
+        store.set(crate::StoreKey::RoutingRules, rules)?;
+        store.save()?;
+        Ok(())
+    }
+
+    fn get_daily_note(&self) -> Result<DailyNoteConfig, crate::Error> {
+        let store = self.obsidian_store();
+        let v = store.get::<DailyNoteConfig>(crate::StoreKey::DailyNote)?;
+        Ok(v.unwrap_or_default())
+    }
+
+    fn set_daily_note(&self, config: DailyNoteConfig) -> Result<(), crate::Error> {
+        config.validate()?;
+
+        let store = self.obsidian_store();
+        store.set(crate::StoreKey::DailyNote, config)?;
+        store.save()?;
+        Ok(())
+    }
+
+    fn get_audio_export(&self) -> Result<AudioExportConfig, crate::Error> {
+        let store = self.obsidian_store();
+        let v = store.get::<AudioExportConfig>(crate::StoreKey::AudioExport)?;
+        Ok(v.unwrap_or_default())
+    }
+
+    fn set_audio_export(&self, config: AudioExportConfig) -> Result<(), crate::Error> {
+        let store = self.obsidian_store();
+        store.set(crate::StoreKey::AudioExport, config)?;
+        store.save()?;
+        Ok(())
+    }
+
+    fn list_sync_states(&self) -> Result<Vec<NoteSyncState>, crate::Error> {
+        let mut states: Vec<_> = sync_states(self)?.into_values().collect();
+        states.sort_by(|a, b| b.synced_at.cmp(&a.synced_at));
+        Ok(states)
+    }
+
+    async fn export_note(&self, session_id: impl Into<String>) -> Result<String, crate::Error> {
+        let session_id = session_id.into();
+        let vault_path = vault_root(self)?;
+
+        let session = self
+            .db_get_session(&session_id)
+            .await?
+            .ok_or_else(|| crate::Error::SessionNotFound(session_id.clone()))?;
+
+        let details = session_details(self, &session_id).await?;
+
+        let daily_note = self.get_daily_note()?;
+        if daily_note.enabled {
+            return export_daily_note(self, &vault_path, &daily_note, &session, &details);
+        }
+
+        let mut states = sync_states(self)?;
+
+        let relative_path = match states.get(&session_id) {
+            Some(state) => {
+                let existing = vault_path.join(&state.path);
+
+                if existing.exists() {
+                    let current = std::fs::read_to_string(&existing)?;
+                    if vault::content_hash(&current) != state.vault_hash {
+                        let mut state = state.clone();
+                        state.status = SyncStatus::Conflict;
+                        states.insert(session_id.clone(), state);
+                        save_sync_states(self, &states)?;
+
+                        return Err(crate::Error::SyncConflict(session_id));
+                    }
+                }
+
+                state.path.clone()
+            }
+            None => {
+                let rules = self.get_routing_rules()?;
+                let folder = match crate::routing::resolve_folder(&rules, &details.routing_facts())
+                {
+                    Some(folder) => folder.to_string(),
+                    None => self.get_base_folder()?.unwrap_or_default(),
+                };
+                let file_name = format!("{}.md", vault::sanitize_file_name(&session.title));
+
+                Path::new(&folder)
+                    .join(file_name)
+                    .to_string_lossy()
+                    .to_string()
+            }
+        };
+
+        let content = render_note(self, &vault_path, &session, &details)?;
+        let target = vault_path.join(&relative_path);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&target, &content)?;
+
+        states.insert(
+            session_id.clone(),
+            NoteSyncState {
+                session_id,
+                path: relative_path.clone(),
+                vault_hash: vault::content_hash(&content),
+                note_hash: vault::content_hash(vault::session_note_html(&session)),
+                synced_at: chrono::Utc::now().to_rfc3339(),
+                status: SyncStatus::Synced,
+            },
+        );
+        save_sync_states(self, &states)?;
+
+        Ok(relative_path)
+    }
+
+    async fn export_notes(&self, filter: ExportFilter) -> Result<BulkExportReport, crate::Error> {
+        let user_id = self
+            .db_user_id()
+            .await?
+            .ok_or(tauri_plugin_db::Error::NoneUser)?;
+
+        let session_ids = {
+            let db_state = self.state::<tauri_plugin_db::ManagedState>();
+            let guard = db_state.lock().await;
+            let db = guard
+                .db
+                .as_ref()
+                .ok_or(tauri_plugin_db::Error::NoneDatabase)?;
+            db.list_session_ids(user_id).await?
+        };
+
+        let mut matched = vec![];
+        for session_id in session_ids {
+            let session = match self.db_get_session(&session_id).await? {
+                Some(session) if !session.is_empty() => session,
+                _ => continue,
+            };
+
+            let details = session_details(self, &session_id).await?;
+            let date = details
+                .event
+                .as_ref()
+                .map(|e| e.start_date)
+                .unwrap_or(session.created_at);
+
+            if filter.matches(date, &details.routing_facts()) {
+                matched.push(session_id);
+            }
+        }
+
+        export_many(self, matched).await
+    }
+
+    // Rewrites every previously exported note, e.g. after the template changed.
+    // Notes edited in the vault since the last export are reported as conflicts instead.
+    async fn reexport_all(&self) -> Result<BulkExportReport, crate::Error> {
+        let mut states: Vec<_> = sync_states(self)?.into_values().collect();
+        states.sort_by(|a, b| a.path.cmp(&b.path));
+
+        export_many(self, states.into_iter().map(|s| s.session_id).collect()).await
+    }
+
+    async fn sync_from_vault(&self, path: PathBuf) -> Result<(), crate::Error> {
+        let vault_path = vault_root(self)?;
+        let relative_path = match path.strip_prefix(&vault_path) {
+            Ok(p) => p.to_string_lossy().to_string(),
+            Err(_) => return Ok(()),
+        };
+
+        let mut states = sync_states(self)?;
+        let state = match states.values().find(|s| s.path == relative_path) {
+            Some(state) => state.clone(),
+            None => return Ok(()),
+        };
+
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut session = match self.db_get_session(&state.session_id).await? {
+            Some(session) => session,
+            None => return Ok(()),
+        };
+
+        let vault_hash = vault::content_hash(&content);
+        let note_hash = vault::content_hash(vault::session_note_html(&session));
+
+        match crate::sync::classify_change(&state, &vault_hash, &note_hash) {
+            ChangeAction::Ignore => {}
+            ChangeAction::Import => {
+                let html = parse_note(&content, &session.title)?;
+                vault::set_session_note_html(&mut session, html.clone());
+                self.db_upsert_session(session).await?;
+
+                states.insert(
+                    state.session_id.clone(),
+                    NoteSyncState {
+                        vault_hash,
+                        note_hash: vault::content_hash(&html),
+                        synced_at: chrono::Utc::now().to_rfc3339(),
+                        status: SyncStatus::Synced,
+                        ..state.clone()
+                    },
+                );
+                save_sync_states(self, &states)?;
+
+                let _ = crate::ObsidianSyncEvent::Imported {
+                    session_id: state.session_id,
+                    path: relative_path,
+                }
+                .emit(self.app_handle());
+            }
+            ChangeAction::Conflict => {
+                if state.status != SyncStatus::Conflict {
+                    states.insert(
+                        state.session_id.clone(),
+                        NoteSyncState {
+                            status: SyncStatus::Conflict,
+                            ..state.clone()
+                        },
+                    );
+                    save_sync_states(self, &states)?;
+                }
+
+                let _ = crate::ObsidianSyncEvent::Conflict {
+                    session_id: state.session_id,
+                    path: relative_path,
+                }
+                .emit(self.app_handle());
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn resolve_conflict(
+        &self,
+        session_id: impl Into<String>,
+        resolution: ConflictResolution,
+    ) -> Result<(), crate::Error> {
+        let session_id = session_id.into();
+        let mut states = sync_states(self)?;
+
+        let state = states
+            .get(&session_id)
+            .cloned()
+            .ok_or_else(|| crate::Error::NoSyncState(session_id.clone()))?;
+
+        let vault_path = vault_root(self)?;
+        let target = vault_path.join(&state.path);
+
+        match resolution {
+            ConflictResolution::KeepVault => {
+                let content = std::fs::read_to_string(&target)?;
+
+                let mut session = self
+                    .db_get_session(&session_id)
+                    .await?
+                    .ok_or_else(|| crate::Error::SessionNotFound(session_id.clone()))?;
+
+                let html = parse_note(&content, &session.title)?;
+                vault::set_session_note_html(&mut session, html.clone());
+                self.db_upsert_session(session).await?;
+
+                states.insert(
+                    session_id,
+                    NoteSyncState {
+                        vault_hash: vault::content_hash(&content),
+                        note_hash: vault::content_hash(&html),
+                        synced_at: chrono::Utc::now().to_rfc3339(),
+                        status: SyncStatus::Synced,
+                        ..state
+                    },
+                );
+                save_sync_states(self, &states)?;
+            }
+            ConflictResolution::KeepHyprnote => {
+                // Forget what we last wrote, so `export_note` overwrites the vault copy.
+                if target.exists() {
+                    let current = std::fs::read_to_string(&target)?;
+                    states.insert(
+                        session_id.clone(),
+                        NoteSyncState {
+                            vault_hash: vault::content_hash(&current),
+                            ..state
+                        },
+                    );
+                    save_sync_states(self, &states)?;
+                }
+
+                self.export_note(session_id).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn start_vault_watch(&self) -> Result<(), crate::Error> {
+        use notify::Watcher;
+
+        if !self.get_enabled()? {
+            return Ok(());
+        }
+
+        let vault_path = match self.get_vault_path()? {
+            Some(p) => PathBuf::from(p),
+            None => return Ok(()),
+        };
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<PathBuf>();
+
+        let mut watcher =
+            notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+                if let Ok(event) = res {
+                    if event.kind.is_modify() || event.kind.is_create() {
+                        for path in event.paths {
+                            if path.extension().is_some_and(|ext| ext == "md") {
+                                let _ = tx.send(path);
+                            }
+                        }
+                    }
+                }
+            })?;
+        watcher.watch(&vault_path, notify::RecursiveMode::Recursive)?;
+
+        let app = self.app_handle().clone();
+        tauri::async_runtime::spawn(async move {
+            while let Some(path) = rx.recv().await {
+                // Editors tend to save in bursts. Wait a bit and coalesce.
+                tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+                let mut paths = std::collections::HashSet::from([path]);
+                while let Ok(path) = rx.try_recv() {
+                    paths.insert(path);
+                }
+
+                for path in paths {
+                    if let Err(e) = app.sync_from_vault(path).await {
+                        tracing::error!("obsidian_sync_from_vault_failed: {}", e);
+                    }
+                }
+            }
+        });
+
+        let state = self.state::<crate::SharedState>();
+        state.lock().unwrap().watcher = Some(watcher);
+
+        tracing::info!(path = %vault_path.display(), "obsidian_vault_watch_started");
+        Ok(())
+    }
+
+    fn stop_vault_watch(&self) {
+        let state = self.state::<crate::SharedState>();
+        state.lock().unwrap().watcher = None;
+    }
+}
+
+fn vault_root<R: tauri::Runtime>(app: &impl ObsidianPluginExt<R>) -> Result<PathBuf, crate::Error> {
+    app.get_vault_path()?
+        .map(PathBuf::from)
+        .ok_or(crate::Error::VaultPathNotConfigured)
+}
+
+fn sync_states<R: tauri::Runtime>(
+    app: &impl ObsidianPluginExt<R>,
+) -> Result<HashMap<String, NoteSyncState>, crate::Error> {
+    let store = app.obsidian_store();
+    let v = store.get::<HashMap<String, NoteSyncState>>(crate::StoreKey::SyncStates)?;
+    Ok(v.unwrap_or_default())
+}
+
+fn save_sync_states<R: tauri::Runtime>(
+    app: &impl ObsidianPluginExt<R>,
+    states: &HashMap<String, NoteSyncState>,
+) -> Result<(), crate::Error> {
+    let store = app.obsidian_store();
+    store.set(crate::StoreKey::SyncStates, states)?;
+    store.save()?;
+    Ok(())
+}
+
+async fn export_many<R: tauri::Runtime>(
+    app: &impl Manager<R>,
+    session_ids: Vec<String>,
+) -> Result<BulkExportReport, crate::Error> {
+    let total = session_ids.len() as u32;
+    let mut report = BulkExportReport::default();
+
+    for (i, session_id) in session_ids.into_iter().enumerate() {
+        let error = match app.export_note(&session_id).await {
+            Ok(path) => {
+                report.exported.push(path);
+                None
+            }
+            Err(e) => {
+                tracing::warn!(session_id = %session_id, "obsidian_export_failed: {}", e);
+                report.failed.push(ExportFailure {
+                    session_id: session_id.clone(),
+                    error: e.to_string(),
+                });
+                Some(e.to_string())
+            }
+        };
+
+        let _ = crate::ObsidianExportProgress {
+            session_id,
+            completed: i as u32 + 1,
+            total,
+            error,
+        }
+        .emit(app.app_handle());
+    }
+
+    Ok(report)
+}
+
+struct SessionDetails {
+    tags: Vec<hypr_db_user::Tag>,
+    participants: Vec<hypr_db_user::Human>,
+    event: Option<hypr_db_user::Event>,
+}
+
+impl SessionDetails {
+    fn routing_facts(&self) -> RoutingFacts {
+        let organizer_email = self
+            .event
+            .as_ref()
+            .and_then(|e| e.participants.as_ref())
+            .and_then(|raw| {
+                serde_json::from_str::<Vec<hypr_db_user::EventParticipant>>(raw)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find(|p| p.is_organizer)
+                    .and_then(|p| p.email)
+            });
+
+        RoutingFacts {
+            tags: self.tags.iter().map(|t| t.name.clone()).collect(),
+            calendar_id: self.event.as_ref().and_then(|e| e.calendar_id.clone()),
+            organizer_email,
+        }
+    }
+}
+
+async fn session_details<R: tauri::Runtime>(
+    app: &impl Manager<R>,
+    session_id: &str,
+) -> Result<SessionDetails, crate::Error> {
+    let db_state = app.state::<tauri_plugin_db::ManagedState>();
+    let guard = db_state.lock().await;
+    let db = guard
+        .db
+        .as_ref()
+        .ok_or(tauri_plugin_db::Error::NoneDatabase)?;
+
+    Ok(SessionDetails {
+        tags: db.list_session_tags(session_id).await?,
+        participants: db.session_list_participants(session_id).await?,
+        event: db.session_get_event(session_id).await?,
+    })
+}
+
+fn recording_path<R: tauri::Runtime>(
+    app: &impl Manager<R>,
+    session_id: &str,
+) -> Result<PathBuf, crate::Error> {
+    Ok(hypr_data_dir::resolve(|| app.path().app_data_dir())?
+        .join(session_id)
+        .join("audio.wav"))
+}
+
+// Copies the session recording into the vault, and returns its vault-relative path.
+fn export_audio<R: tauri::Runtime>(
+    app: &(impl ObsidianPluginExt<R> + Manager<R>),
+    vault_path: &Path,
+    session_id: &str,
+) -> Result<Option<String>, crate::Error> {
+    let config = app.get_audio_export()?;
+    let source = recording_path(app, session_id)?;
+
+    if !config.enabled || !source.exists() {
+        return Ok(None);
+    }
+
+    let file_name = format!("hyprnote-{}.wav", session_id);
+    let folder = config.folder.trim_matches('/');
+    let relative_path = if folder.is_empty() {
+        file_name
+    } else {
+        format!("{}/{}", folder, file_name)
+    };
+
+    let target = vault_path.join(&relative_path);
+    let unchanged = match (std::fs::metadata(&source), std::fs::metadata(&target)) {
+        (Ok(a), Ok(b)) => a.len() == b.len(),
+        _ => false,
+    };
+
+    if !unchanged {
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(&source, &target)?;
+    }
+
+    Ok(Some(relative_path))
+}
+
+fn note_context<R: tauri::Runtime>(
+    app: &(impl ObsidianPluginExt<R> + Manager<R>),
+    vault_path: &Path,
+    session: &hypr_db_user::Session,
+    details: &SessionDetails,
+) -> Result<NoteContext, crate::Error> {
+    let recording = recording_path(app, &session.id)?;
+    let exported_audio = export_audio(app, vault_path, &session.id)?;
+
+    let link_target = match app.get_audio_export()?.timestamp_links {
+        true => exported_audio.as_deref(),
+        false => None,
+    };
+    let transcript = crate::media::paragraphs(&session.words, link_target);
+
+    Ok(NoteContext {
+        id: session.id.clone(),
+        title: session.title.clone(),
+        created_at: session.created_at.to_rfc3339(),
+        body: hypr_buffer::html_to_md(vault::session_note_html(session))?,
+        tags: details.tags.iter().map(|t| t.name.clone()).collect(),
+        attendees: details
+            .participants
+            .iter()
+            .filter_map(|h| h.full_name.clone().or_else(|| h.email.clone()))
+            .collect(),
+        event: details.event.as_ref().map(|e| NoteEvent {
+            name: e.name.clone(),
+            start_date: e.start_date.to_rfc3339(),
+            end_date: e.end_date.to_rfc3339(),
+        }),
+        audio_link: exported_audio.clone().or_else(|| {
+            recording
+                .exists()
+                .then(|| format!("file://{}", recording.display()).replace(' ', "%20"))
+        }),
+        audio_path: exported_audio,
+        transcript,
+    })
+}
+
+fn render_note<R: tauri::Runtime>(
+    app: &(impl ObsidianPluginExt<R> + Manager<R>),
+    vault_path: &Path,
+    session: &hypr_db_user::Session,
+    details: &SessionDetails,
+) -> Result<String, crate::Error> {
+    let ctx = note_context(app, vault_path, session, details)?;
+
+    let template = app
+        .obsidian_store()
+        .get::<String>(crate::StoreKey::NoteTemplate)?;
+    let fields = app.get_frontmatter_fields()?;
+
+    let state = app.state::<crate::SharedState>();
+    let guard = state.lock().unwrap();
+    crate::note::render(&guard.env, template.as_deref(), &fields, &ctx)
+}
+
+// Daily notes hold several meetings, so they are written one-way and never synced back.
+fn export_daily_note<R: tauri::Runtime>(
+    app: &(impl ObsidianPluginExt<R> + Manager<R>),
+    vault_path: &Path,
+    config: &DailyNoteConfig,
+    session: &hypr_db_user::Session,
+    details: &SessionDetails,
+) -> Result<String, crate::Error> {
+    let date = details
+        .event
+        .as_ref()
+        .map(|e| e.start_date)
+        .unwrap_or(session.created_at)
+        .with_timezone(&chrono::Local);
+
+    let relative_path = Path::new(&config.folder)
+        .join(format!("{}.md", date.format(&config.date_format)))
+        .to_string_lossy()
+        .to_string();
+
+    let section = {
+        let ctx = note_context(app, vault_path, session, details)?;
+        let template = app
+            .obsidian_store()
+            .get::<String>(crate::StoreKey::NoteTemplate)?;
+
+        let state = app.state::<crate::SharedState>();
+        let guard = state.lock().unwrap();
+        crate::note::render_body(&guard.env, template.as_deref(), &ctx)?
+    };
+
+    let target = vault_path.join(&relative_path);
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let existing = match std::fs::read_to_string(&target) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+    std::fs::write(
+        &target,
+        crate::routing::upsert_daily_section(&existing, &session.id, &section),
+    )?;
+
+    Ok(relative_path)
+}
+
+fn parse_note(content: &str, title: &str) -> Result<String, crate::Error> {
+    let (_, body) = vault::split_frontmatter(content);
+    let body = crate::media::strip_audio_section(body);
+
+    let heading = format!("# {}", title);
+    let body = body.trim_start();
+    let body = body.strip_prefix(heading.as_str()).unwrap_or(body);
+
+    Ok(hypr_buffer::plain_md_to_html(body.trim())?)
 }
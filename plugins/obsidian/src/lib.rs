@@ -1,21 +1,37 @@
 use std::sync::Mutex;
 use tauri::Manager;
 
+mod bulk;
 mod commands;
 mod error;
+mod events;
 mod ext;
+mod media;
+mod note;
+mod routing;
 mod store;
+mod sync;
+mod vault;
 
+pub use bulk::{BulkExportReport, ExportFailure, ExportFilter};
 pub use error::*;
+pub use events::*;
 pub use ext::*;
+pub use media::AudioExportConfig;
+pub use note::FrontmatterField;
+pub use routing::{DailyNoteConfig, RoutingCondition, RoutingRule};
 use store::*;
+pub use sync::{ConflictResolution, NoteSyncState, SyncStatus};
 
 const PLUGIN_NAME: &str = "obsidian";
 
 pub type SharedState = Mutex<State>;
 
 #[derive(Default)]
-pub struct State {}
+pub struct State {
+    env: hypr_template::minijinja::Environment<'static>,
+    watcher: Option<notify::RecommendedWatcher>,
+}
 
 fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
     tauri_specta::Builder::<R>::new()
@@ -32,6 +48,27 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::get_deep_link_url::<tauri::Wry>,
             commands::get_base_folder::<tauri::Wry>,
             commands::set_base_folder::<tauri::Wry>,
+            commands::get_vault_path::<tauri::Wry>,
+            commands::set_vault_path::<tauri::Wry>,
+            commands::export_note::<tauri::Wry>,
+            commands::list_sync_states::<tauri::Wry>,
+            commands::resolve_conflict::<tauri::Wry>,
+            commands::get_note_template::<tauri::Wry>,
+            commands::set_note_template::<tauri::Wry>,
+            commands::get_frontmatter_fields::<tauri::Wry>,
+            commands::set_frontmatter_fields::<tauri::Wry>,
+            commands::get_routing_rules::<tauri::Wry>,
+            commands::set_routing_rules::<tauri::Wry>,
+            commands::get_daily_note::<tauri::Wry>,
+            commands::set_daily_note::<tauri::Wry>,
+            commands::get_audio_export::<tauri::Wry>,
+            commands::set_audio_export::<tauri::Wry>,
+            commands::export_notes::<tauri::Wry>,
+            commands::reexport_all::<tauri::Wry>,
+        ])
+        .events(tauri_specta::collect_events![
+            events::ObsidianSyncEvent,
+            events::ObsidianExportProgress
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
@@ -41,9 +78,16 @@ pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
 
     tauri::plugin::Builder::new(PLUGIN_NAME)
         .invoke_handler(specta_builder.invoke_handler())
-        .setup(|app, _api| {
-            let state = SharedState::default();
-            app.manage(state);
+        .setup(move |app, _api| {
+            specta_builder.mount_events(app);
+
+            let mut state = State::default();
+            hypr_template::init(&mut state.env);
+            app.manage(SharedState::new(state));
+
+            if let Err(e) = app.start_vault_watch() {
+                tracing::error!("obsidian_vault_watch_failed: {}", e);
+            }
 
             Ok(())
         })
@@ -0,0 +1,190 @@
+use owhisper_interface::{SpeakerIdentity, Word2};
+
+const PAUSE_MS: u64 = 2_000;
+const MAX_PARAGRAPH_MS: u64 = 30_000;
+
+const AUDIO_SECTION_START: &str = "<!-- hyprnote:audio -->";
+const AUDIO_SECTION_END: &str = "<!-- /hyprnote:audio -->";
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct AudioExportConfig {
+    pub enabled: bool,
+    pub folder: String,
+    pub timestamp_links: bool,
+}
+
+impl Default for AudioExportConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            folder: "Attachments".to_string(),
+            timestamp_links: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct TranscriptParagraph {
+    pub speaker: String,
+    pub start_ms: u64,
+    pub timestamp: String,
+    pub link: Option<String>,
+    pub text: String,
+}
+
+fn speaker_label(speaker: &Option<SpeakerIdentity>) -> String {
+    match speaker {
+        Some(SpeakerIdentity::Unassigned { index }) => format!("Speaker {}", index),
+        Some(SpeakerIdentity::Assigned { label, .. }) => label.clone(),
+        None => "Unknown".to_string(),
+    }
+}
+
+pub fn format_timestamp(ms: u64) -> String {
+    let secs = ms / 1000;
+    let (h, m, s) = (secs / 3600, (secs % 3600) / 60, secs % 60);
+
+    if h > 0 {
+        format!("{}:{:02}:{:02}", h, m, s)
+    } else {
+        format!("{:02}:{:02}", m, s)
+    }
+}
+
+// https://www.w3.org/TR/media-frags/#naming-time
+pub fn media_link(audio_path: &str, ms: u64) -> String {
+    format!("{}#t={}", audio_path.replace(' ', "%20"), ms / 1000)
+}
+
+/// Groups words into paragraphs, breaking on speaker changes, long pauses, or every 30 seconds.
+pub fn paragraphs(words: &[Word2], audio_path: Option<&str>) -> Vec<TranscriptParagraph> {
+    let mut groups: Vec<(&Option<SpeakerIdentity>, u64, Vec<&str>)> = vec![];
+    let mut last_end_ms = 0;
+
+    for word in words {
+        let start_ms = word.start_ms.unwrap_or(last_end_ms);
+
+        let split = match groups.last() {
+            None => true,
+            Some((speaker, group_start_ms, _)) => {
+                *speaker != &word.speaker
+                    || start_ms.saturating_sub(last_end_ms) > PAUSE_MS
+                    || start_ms.saturating_sub(*group_start_ms) > MAX_PARAGRAPH_MS
+            }
+        };
+
+        if split {
+            groups.push((&word.speaker, start_ms, vec![]));
+        }
+        if let Some((_, _, texts)) = groups.last_mut() {
+            texts.push(word.text.trim());
+        }
+
+        last_end_ms = word.end_ms.unwrap_or(start_ms);
+    }
+
+    groups
+        .into_iter()
+        .map(|(speaker, start_ms, texts)| TranscriptParagraph {
+            speaker: speaker_label(speaker),
+            start_ms,
+            timestamp: format_timestamp(start_ms),
+            link: audio_path.map(|path| media_link(path, start_ms)),
+            text: texts.join(" "),
+        })
+        .collect()
+}
+
+pub fn audio_section(audio_path: &str, paragraphs: &[TranscriptParagraph]) -> String {
+    let mut lines = vec![
+        AUDIO_SECTION_START.to_string(),
+        format!("![[{}]]", audio_path),
+    ];
+
+    let links: Vec<_> = paragraphs
+        .iter()
+        .filter_map(|p| {
+            p.link.as_ref().map(|link| {
+                format!(
+                    "- [{}]({}) **{}**: {}",
+                    p.timestamp, link, p.speaker, p.text
+                )
+            })
+        })
+        .collect();
+
+    if !links.is_empty() {
+        lines.push(String::new());
+        lines.extend(links);
+    }
+
+    lines.push(AUDIO_SECTION_END.to_string());
+    lines.join("\n")
+}
+
+/// Removes the generated audio section, so it is not imported back into the note.
+pub fn strip_audio_section(body: &str) -> String {
+    match (body.find(AUDIO_SECTION_START), body.find(AUDIO_SECTION_END)) {
+        (Some(s), Some(e)) if s < e => format!(
+            "{}{}",
+            body[..s].trim_end(),
+            &body[e + AUDIO_SECTION_END.len()..]
+        ),
+        _ => body.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, speaker: u8, start_ms: u64, end_ms: u64) -> Word2 {
+        Word2 {
+            text: text.to_string(),
+            speaker: Some(SpeakerIdentity::Unassigned { index: speaker }),
+            confidence: None,
+            start_ms: Some(start_ms),
+            end_ms: Some(end_ms),
+        }
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let words = vec![
+            word("hello", 0, 0, 400),
+            word("there", 0, 500, 900),
+            word("hi", 1, 1_000, 1_200),
+            word("again", 1, 65_000, 65_400),
+        ];
+
+        let paragraphs = paragraphs(&words, Some("Attachments/a b.wav"));
+        assert_eq!(
+            paragraphs
+                .iter()
+                .map(|p| (p.speaker.as_str(), p.timestamp.as_str(), p.text.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Speaker 0", "00:00", "hello there"),
+                ("Speaker 1", "00:01", "hi"),
+                ("Speaker 1", "01:05", "again"),
+            ]
+        );
+        assert_eq!(
+            paragraphs[2].link.as_deref(),
+            Some("Attachments/a%20b.wav#t=65")
+        );
+    }
+
+    #[test]
+    fn test_audio_section_roundtrip() {
+        let paragraphs = paragraphs(&[word("hello", 0, 3_000, 3_400)], Some("a.wav"));
+        let body = format!("Summary\n\n{}", audio_section("a.wav", &paragraphs));
+
+        assert_eq!(
+            body,
+            "Summary\n\n<!-- hyprnote:audio -->\n![[a.wav]]\n\n- [00:03](a.wav#t=3) **Speaker 0**: hello\n<!-- /hyprnote:audio -->"
+        );
+        assert_eq!(strip_audio_section(&body), "Summary");
+        assert_eq!(format_timestamp(3_723_000), "1:02:03");
+    }
+}
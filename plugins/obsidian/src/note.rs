@@ -0,0 +1,195 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum FrontmatterField {
+    Title,
+    Created,
+    Tags,
+    Attendees,
+    EventDate,
+    AudioLink,
+}
+
+pub const DEFAULT_FRONTMATTER_FIELDS: &[FrontmatterField] =
+    &[FrontmatterField::Title, FrontmatterField::Created];
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct NoteEvent {
+    pub name: String,
+    pub start_date: String,
+    pub end_date: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct NoteContext {
+    pub id: String,
+    pub title: String,
+    pub created_at: String,
+    pub body: String,
+    pub tags: Vec<String>,
+    pub attendees: Vec<String>,
+    pub event: Option<NoteEvent>,
+    pub audio_link: Option<String>,
+    // Vault-relative path of the exported recording.
+    pub audio_path: Option<String>,
+    pub transcript: Vec<crate::media::TranscriptParagraph>,
+}
+
+impl NoteContext {
+    // `hyprnote_id` is always written, since vault sync relies on it.
+    pub fn frontmatter(&self, fields: &[FrontmatterField]) -> String {
+        let mut lines = vec!["---".to_string(), format!("hyprnote_id: {}", self.id)];
+
+        for field in fields {
+            match field {
+                FrontmatterField::Title => {
+                    lines.push(format!("title: {}", yaml_str(&self.title)));
+                }
+                FrontmatterField::Created => {
+                    lines.push(format!("created: {}", self.created_at));
+                }
+                FrontmatterField::Tags => {
+                    let tags: Vec<_> = self.tags.iter().map(|t| t.replace(' ', "-")).collect();
+                    lines.push(yaml_list("tags", &tags));
+                }
+                FrontmatterField::Attendees => {
+                    lines.push(yaml_list("attendees", &self.attendees));
+                }
+                FrontmatterField::EventDate => {
+                    if let Some(event) = &self.event {
+                        lines.push(format!("event_date: {}", event.start_date));
+                    }
+                }
+                FrontmatterField::AudioLink => {
+                    if let Some(link) = &self.audio_link {
+                        lines.push(format!("audio: {}", yaml_str(link)));
+                    }
+                }
+            }
+        }
+
+        lines.push("---".to_string());
+        lines.join("\n")
+    }
+
+    pub fn to_template_context(&self) -> serde_json::Map<String, serde_json::Value> {
+        match serde_json::to_value(self) {
+            Ok(serde_json::Value::Object(map)) => map,
+            _ => serde_json::Map::new(),
+        }
+    }
+}
+
+pub fn render(
+    env: &hypr_template::minijinja::Environment<'static>,
+    template: Option<&str>,
+    fields: &[FrontmatterField],
+    ctx: &NoteContext,
+) -> Result<String, crate::Error> {
+    let body = render_body(env, template, ctx)?;
+    Ok(format!("{}\n\n{}", ctx.frontmatter(fields), body))
+}
+
+pub fn render_body(
+    env: &hypr_template::minijinja::Environment<'static>,
+    template: Option<&str>,
+    ctx: &NoteContext,
+) -> Result<String, crate::Error> {
+    let vars = ctx.to_template_context();
+
+    let body = match template {
+        Some(source) => hypr_template::render_str(env, source, &vars)?,
+        None => hypr_template::render(env, hypr_template::Template::ObsidianNote, &vars)?,
+    };
+
+    match &ctx.audio_path {
+        Some(audio_path) => Ok(format!(
+            "{}\n\n{}",
+            body.trim(),
+            crate::media::audio_section(audio_path, &ctx.transcript)
+        )),
+        None => Ok(body.trim().to_string()),
+    }
+}
+
+pub fn validate_template(template: &str) -> Result<(), crate::Error> {
+    let env = hypr_template::minijinja::Environment::new();
+    env.template_from_str(template)
+        .map_err(hypr_template::Error::from)?;
+    Ok(())
+}
+
+fn yaml_str(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn yaml_list(key: &str, items: &[String]) -> String {
+    if items.is_empty() {
+        return format!("{}: []", key);
+    }
+
+    let mut out = format!("{}:", key);
+    for item in items {
+        out.push_str(&format!("\n  - {}", yaml_str(item)));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> NoteContext {
+        NoteContext {
+            id: "s1".to_string(),
+            title: "Weekly \"sync\"".to_string(),
+            created_at: "2025-01-01T00:00:00+00:00".to_string(),
+            body: "Hello".to_string(),
+            tags: vec!["team sync".to_string()],
+            attendees: vec![],
+            event: None,
+            audio_link: None,
+            audio_path: None,
+            transcript: vec![],
+        }
+    }
+
+    #[test]
+    fn test_frontmatter() {
+        let ctx = context();
+
+        assert_eq!(
+            ctx.frontmatter(&[
+                FrontmatterField::Title,
+                FrontmatterField::Tags,
+                FrontmatterField::Attendees,
+                FrontmatterField::EventDate,
+            ]),
+            "---\nhyprnote_id: s1\ntitle: \"Weekly \\\"sync\\\"\"\ntags:\n  - \"team-sync\"\nattendees: []\n---"
+        );
+        assert_eq!(ctx.frontmatter(&[]), "---\nhyprnote_id: s1\n---");
+    }
+
+    #[test]
+    fn test_render() {
+        let mut env = hypr_template::minijinja::Environment::new();
+        hypr_template::init(&mut env);
+        let ctx = context();
+
+        let default = render(&env, None, &[], &ctx).unwrap();
+        assert_eq!(
+            default,
+            "---\nhyprnote_id: s1\n---\n\n# Weekly \"sync\"\n\nHello"
+        );
+
+        let custom = render(
+            &env,
+            Some("{{ body }}\n\n{{ tags | join(', ') }}"),
+            &[],
+            &ctx,
+        )
+        .unwrap();
+        assert_eq!(custom, "---\nhyprnote_id: s1\n---\n\nHello\n\nteam sync");
+
+        assert!(validate_template("{% if %}").is_err());
+    }
+}
@@ -0,0 +1,173 @@
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RoutingCondition {
+    Tag { name: String },
+    Calendar { calendar_id: String },
+    // Matches when the event's organizer has an address on this domain.
+    OrganizerDomain { domain: String },
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct RoutingRule {
+    pub condition: RoutingCondition,
+    pub folder: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct DailyNoteConfig {
+    pub enabled: bool,
+    pub folder: String,
+    pub date_format: String,
+}
+
+impl Default for DailyNoteConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            folder: String::new(),
+            date_format: "%Y-%m-%d".to_string(),
+        }
+    }
+}
+
+impl DailyNoteConfig {
+    pub fn validate(&self) -> Result<(), crate::Error> {
+        let invalid = chrono::format::StrftimeItems::new(&self.date_format)
+            .any(|item| matches!(item, chrono::format::Item::Error));
+
+        if invalid || self.date_format.trim().is_empty() {
+            return Err(crate::Error::InvalidDateFormat(self.date_format.clone()));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RoutingFacts {
+    pub tags: Vec<String>,
+    pub calendar_id: Option<String>,
+    pub organizer_email: Option<String>,
+}
+
+impl RoutingCondition {
+    pub fn matches(&self, facts: &RoutingFacts) -> bool {
+        match self {
+            RoutingCondition::Tag { name } => {
+                facts.tags.iter().any(|t| t.eq_ignore_ascii_case(name))
+            }
+            RoutingCondition::Calendar { calendar_id } => {
+                facts.calendar_id.as_deref() == Some(calendar_id.as_str())
+            }
+            RoutingCondition::OrganizerDomain { domain } => {
+                let domain = domain.trim_start_matches('@');
+                facts
+                    .organizer_email
+                    .as_deref()
+                    .and_then(|email| email.rsplit_once('@'))
+                    .is_some_and(|(_, d)| d.eq_ignore_ascii_case(domain))
+            }
+        }
+    }
+}
+
+/// First matching rule wins.
+pub fn resolve_folder<'a>(rules: &'a [RoutingRule], facts: &RoutingFacts) -> Option<&'a str> {
+    rules
+        .iter()
+        .find(|rule| rule.condition.matches(facts))
+        .map(|rule| rule.folder.as_str())
+}
+
+fn section_markers(session_id: &str) -> (String, String) {
+    (
+        format!("<!-- hyprnote:{} -->", session_id),
+        format!("<!-- /hyprnote:{} -->", session_id),
+    )
+}
+
+/// Inserts the session's section into a daily note, replacing the previous copy if there is one.
+pub fn upsert_daily_section(existing: &str, session_id: &str, section: &str) -> String {
+    let (start, end) = section_markers(session_id);
+    let block = format!("{}\n{}\n{}", start, section.trim(), end);
+
+    if let (Some(s), Some(e)) = (existing.find(&start), existing.find(&end)) {
+        if s < e {
+            return format!("{}{}{}", &existing[..s], block, &existing[e + end.len()..]);
+        }
+    }
+
+    let existing = existing.trim_end();
+    if existing.is_empty() {
+        format!("{}\n", block)
+    } else {
+        format!("{}\n\n{}\n", existing, block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_folder() {
+        let rules = vec![
+            RoutingRule {
+                condition: RoutingCondition::Tag {
+                    name: "Hiring".to_string(),
+                },
+                folder: "Recruiting".to_string(),
+            },
+            RoutingRule {
+                condition: RoutingCondition::OrganizerDomain {
+                    domain: "@acme.com".to_string(),
+                },
+                folder: "Clients/Acme".to_string(),
+            },
+        ];
+
+        let facts = RoutingFacts {
+            tags: vec!["hiring".to_string()],
+            calendar_id: None,
+            organizer_email: Some("bob@acme.com".to_string()),
+        };
+        assert_eq!(resolve_folder(&rules, &facts), Some("Recruiting"));
+
+        let facts = RoutingFacts {
+            organizer_email: Some("bob@ACME.com".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(resolve_folder(&rules, &facts), Some("Clients/Acme"));
+
+        let facts = RoutingFacts {
+            organizer_email: Some("me@example.com".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(resolve_folder(&rules, &facts), None);
+
+        assert_eq!(resolve_folder(&rules, &RoutingFacts::default()), None);
+    }
+
+    #[test]
+    fn test_upsert_daily_section() {
+        let note = upsert_daily_section("", "s1", "## Standup\n\nfirst");
+        assert_eq!(
+            note,
+            "<!-- hyprnote:s1 -->\n## Standup\n\nfirst\n<!-- /hyprnote:s1 -->\n"
+        );
+
+        let note = upsert_daily_section(
+            &format!("# Today\n\n{}", note),
+            "s1",
+            "## Standup\n\nsecond",
+        );
+        assert_eq!(
+            note,
+            "# Today\n\n<!-- hyprnote:s1 -->\n## Standup\n\nsecond\n<!-- /hyprnote:s1 -->\n"
+        );
+
+        let note = upsert_daily_section(&note, "s2", "## Retro");
+        assert!(note.ends_with(
+            "<!-- /hyprnote:s1 -->\n\n<!-- hyprnote:s2 -->\n## Retro\n<!-- /hyprnote:s2 -->\n"
+        ));
+    }
+}
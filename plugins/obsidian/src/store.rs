@@ -7,6 +7,13 @@ pub enum StoreKey {
     BaseFolder,
     BaseUrl,
     ApiKey,
+    VaultPath,
+    SyncStates,
+    NoteTemplate,
+    FrontmatterFields,
+    RoutingRules,
+    DailyNote,
+    AudioExport,
 }
 
 impl ScopedStoreKey for StoreKey {}
@@ -0,0 +1,75 @@
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub enum SyncStatus {
+    #[serde(rename = "synced")]
+    Synced,
+    #[serde(rename = "conflict")]
+    Conflict,
+}
+
+// What Hyprnote knows about an exported note, as of the last successful sync.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct NoteSyncState {
+    pub session_id: String,
+    // Relative to the vault root.
+    pub path: String,
+    // Hash of the vault file content we last wrote or imported.
+    pub vault_hash: String,
+    // Hash of the session's note HTML at that same moment.
+    pub note_hash: String,
+    pub synced_at: String,
+    pub status: SyncStatus,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, specta::Type)]
+pub enum ConflictResolution {
+    #[serde(rename = "keepVault")]
+    KeepVault,
+    #[serde(rename = "keepHyprnote")]
+    KeepHyprnote,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeAction {
+    // The vault file still matches what we wrote. Most likely our own write.
+    Ignore,
+    // Only the vault side changed since the last sync.
+    Import,
+    // Both sides changed since the last sync.
+    Conflict,
+}
+
+pub fn classify_change(state: &NoteSyncState, vault_hash: &str, note_hash: &str) -> ChangeAction {
+    if state.vault_hash == vault_hash {
+        ChangeAction::Ignore
+    } else if state.note_hash == note_hash {
+        ChangeAction::Import
+    } else {
+        ChangeAction::Conflict
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> NoteSyncState {
+        NoteSyncState {
+            session_id: "s".to_string(),
+            path: "Hyprnote/Note.md".to_string(),
+            vault_hash: "v1".to_string(),
+            note_hash: "n1".to_string(),
+            synced_at: "2025-01-01T00:00:00Z".to_string(),
+            status: SyncStatus::Synced,
+        }
+    }
+
+    #[test]
+    fn test_classify_change() {
+        assert_eq!(classify_change(&state(), "v1", "n2"), ChangeAction::Ignore);
+        assert_eq!(classify_change(&state(), "v2", "n1"), ChangeAction::Import);
+        assert_eq!(
+            classify_change(&state(), "v2", "n2"),
+            ChangeAction::Conflict
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use sha2::{Digest, Sha256};
+
+pub fn content_hash(content: impl AsRef<[u8]>) -> String {
+    hex::encode(Sha256::digest(content.as_ref()))
+}
+
+// Obsidian rejects `\ / :` in file names, and `* ? " < > |` break on Windows.
+pub fn sanitize_file_name(name: &str) -> String {
+    let sanitized: String = name
+        .chars()
+        .map(|c| match c {
+            '\\' | '/' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '#' | '^' | '[' | ']' => ' ',
+            c if c.is_control() => ' ',
+            c => c,
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let trimmed = sanitized.trim_matches('.').trim();
+    if trimmed.is_empty() {
+        "Untitled".to_string()
+    } else {
+        trimmed.chars().take(120).collect()
+    }
+}
+
+/// Splits `---\n...\n---\n` YAML frontmatter from the note body.
+pub fn split_frontmatter(content: &str) -> (Option<&str>, &str) {
+    let rest = match content
+        .strip_prefix("---\n")
+        .or_else(|| content.strip_prefix("---\r\n"))
+    {
+        Some(rest) => rest,
+        None => return (None, content),
+    };
+
+    for marker in ["\n---\n", "\n---\r\n"] {
+        if let Some(end) = rest.find(marker) {
+            return (Some(&rest[..end]), &rest[end + marker.len()..]);
+        }
+    }
+
+    if let Some(frontmatter) = rest.strip_suffix("\n---") {
+        return (Some(frontmatter), "");
+    }
+
+    (None, content)
+}
+
+pub fn frontmatter_value<'a>(frontmatter: &'a str, key: &str) -> Option<&'a str> {
+    frontmatter.lines().find_map(|line| {
+        let (k, v) = line.split_once(':')?;
+        (k.trim() == key).then(|| v.trim().trim_matches('"'))
+    })
+}
+
+pub fn session_note_html(session: &hypr_db_user::Session) -> &str {
+    session
+        .enhanced_memo_html
+        .as_deref()
+        .unwrap_or(&session.raw_memo_html)
+}
+
+pub fn set_session_note_html(session: &mut hypr_db_user::Session, html: String) {
+    if session.enhanced_memo_html.is_some() {
+        session.enhanced_memo_html = Some(html);
+    } else {
+        session.raw_memo_html = html;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_file_name() {
+        assert_eq!(
+            sanitize_file_name("Weekly: sync / planning"),
+            "Weekly sync planning"
+        );
+        assert_eq!(sanitize_file_name("  ...  "), "Untitled");
+        assert_eq!(sanitize_file_name("1:1 with [Bob]"), "1 1 with Bob");
+    }
+
+    #[test]
+    fn test_split_frontmatter() {
+        let content = "---\nhyprnote_id: abc\ntitle: \"Hi\"\n---\n# Body\n";
+        let (frontmatter, body) = split_frontmatter(content);
+
+        assert_eq!(frontmatter, Some("hyprnote_id: abc\ntitle: \"Hi\""));
+        assert_eq!(body, "# Body\n");
+        assert_eq!(
+            frontmatter_value(frontmatter.unwrap(), "hyprnote_id"),
+            Some("abc")
+        );
+        assert_eq!(frontmatter_value(frontmatter.unwrap(), "title"), Some("Hi"));
+
+        assert_eq!(
+            split_frontmatter("# No frontmatter"),
+            (None, "# No frontmatter")
+        );
+    }
+}
@@ -1,4 +1,12 @@
-const COMMANDS: &[&str] = &["play", "stop"];
+const COMMANDS: &[&str] = &[
+    "play",
+    "stop",
+    "get_sound_pack",
+    "set_sound_pack",
+    "get_custom_sounds",
+    "set_custom_sound",
+    "clear_custom_sound",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
@@ -12,6 +12,21 @@ async play(sfx: AppSounds) : Promise<void> {
 },
 async stop(sfx: AppSounds) : Promise<void> {
     await TAURI_INVOKE("plugin:sfx|stop", { sfx });
+},
+async getSoundPack() : Promise<SoundPack> {
+    return await TAURI_INVOKE("plugin:sfx|get_sound_pack");
+},
+async setSoundPack(pack: SoundPack) : Promise<null> {
+    return await TAURI_INVOKE("plugin:sfx|set_sound_pack", { pack });
+},
+async getCustomSounds() : Promise<Partial<{ [key in AppSounds]: string }>> {
+    return await TAURI_INVOKE("plugin:sfx|get_custom_sounds");
+},
+async setCustomSound(sfx: AppSounds, path: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:sfx|set_custom_sound", { sfx, path });
+},
+async clearCustomSound(sfx: AppSounds) : Promise<null> {
+    return await TAURI_INVOKE("plugin:sfx|clear_custom_sound", { sfx });
 }
 }
 
@@ -25,7 +40,20 @@ async stop(sfx: AppSounds) : Promise<void> {
 
 /** user-defined types **/
 
-export type AppSounds = "StartRecording" | "StopRecording" | "BGM"
+export type AppSounds = "StartRecording" | "StopRecording" | "Notification" | "BGM"
+export type SoundPack = 
+/**
+ * The sounds bundled with the app.
+ */
+"Default" | 
+/**
+ * Plays nothing.
+ */
+"Silent" | 
+/**
+ * User-provided files, falling back to the bundled sound for events without one.
+ */
+"Custom"
 
 /** tauri-specta globals **/
 
@@ -1,4 +1,6 @@
-use crate::{AppSounds, SfxPluginExt};
+use std::collections::HashMap;
+
+use crate::{AppSounds, SfxPluginExt, SoundPack};
 
 #[tauri::command]
 #[specta::specta]
@@ -11,3 +13,48 @@ pub async fn play<R: tauri::Runtime>(app: tauri::AppHandle<R>, sfx: AppSounds) {
 pub async fn stop<R: tauri::Runtime>(app: tauri::AppHandle<R>, sfx: AppSounds) {
     app.stop(sfx)
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_sound_pack<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<SoundPack, String> {
+    app.get_sound_pack().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_sound_pack<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    pack: SoundPack,
+) -> Result<(), String> {
+    app.set_sound_pack(pack).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_custom_sounds<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<HashMap<AppSounds, String>, String> {
+    app.get_custom_sounds().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_custom_sound<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    sfx: AppSounds,
+    path: String,
+) -> Result<(), String> {
+    app.set_custom_sound(sfx, path.into())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn clear_custom_sound<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    sfx: AppSounds,
+) -> Result<(), String> {
+    app.clear_custom_sound(sfx).map_err(|e| e.to_string())
+}
@@ -0,0 +1,26 @@
+use serde::{ser::Serializer, Serialize};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Tauri(#[from] tauri::Error),
+    #[error(transparent)]
+    Store(#[from] tauri_plugin_store2::Error),
+    #[error("unsupported sound format: {0}")]
+    UnsupportedFormat(String),
+    #[error("sound file is larger than {} MB", crate::MAX_SOUND_BYTES / 1024 / 1024)]
+    FileTooLarge,
+    #[error("failed to decode sound: {0}")]
+    Decode(String),
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_ref())
+    }
+}
@@ -1,18 +1,28 @@
 use once_cell::sync::Lazy;
-use std::sync::Mutex;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
 
-static PLAYING_SOUNDS: Lazy<
-    Mutex<std::collections::HashMap<AppSounds, std::sync::mpsc::Sender<()>>>,
-> = Lazy::new(|| Mutex::new(std::collections::HashMap::new()));
+use tauri::Manager;
+use tauri_plugin_store2::StorePluginExt;
+
+use crate::{Error, SoundPack, StoreKey};
+
+static PLAYING_SOUNDS: Lazy<Mutex<HashMap<AppSounds, std::sync::mpsc::Sender<()>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
 
 #[derive(Debug, serde::Serialize, serde::Deserialize, specta::Type, Clone, PartialEq, Eq, Hash)]
 pub enum AppSounds {
     StartRecording,
     StopRecording,
+    Notification,
     BGM,
 }
 
-pub fn to_speaker(bytes: &'static [u8]) -> std::sync::mpsc::Sender<()> {
+pub fn to_speaker(bytes: Cow<'static, [u8]>) -> std::sync::mpsc::Sender<()> {
     use rodio::{Decoder, OutputStream, Sink};
     let (tx, rx) = std::sync::mpsc::channel();
 
@@ -33,10 +43,9 @@ pub fn to_speaker(bytes: &'static [u8]) -> std::sync::mpsc::Sender<()> {
 }
 
 impl AppSounds {
-    pub fn play(&self) {
+    pub fn play(&self, bytes: Cow<'static, [u8]>) {
         self.stop();
 
-        let bytes = self.get_sound_bytes();
         let stop_sender = to_speaker(bytes);
 
         {
@@ -52,26 +61,144 @@ impl AppSounds {
         }
     }
 
-    fn get_sound_bytes(&self) -> &'static [u8] {
+    /// `None` for events that ship without a sound of their own.
+    fn bundled_bytes(&self) -> Option<&'static [u8]> {
         match self {
-            AppSounds::StartRecording => include_bytes!("../sounds/start_recording.ogg"),
-            AppSounds::StopRecording => include_bytes!("../sounds/stop_recording.ogg"),
-            AppSounds::BGM => include_bytes!("../sounds/bgm.mp3"),
+            AppSounds::StartRecording => Some(include_bytes!("../sounds/start_recording.ogg")),
+            AppSounds::StopRecording => Some(include_bytes!("../sounds/stop_recording.ogg")),
+            AppSounds::Notification => None,
+            AppSounds::BGM => Some(include_bytes!("../sounds/bgm.mp3")),
+        }
+    }
+
+    fn file_stem(&self) -> &'static str {
+        match self {
+            AppSounds::StartRecording => "start_recording",
+            AppSounds::StopRecording => "stop_recording",
+            AppSounds::Notification => "notification",
+            AppSounds::BGM => "bgm",
         }
     }
 }
 
 pub trait SfxPluginExt<R: tauri::Runtime> {
+    fn sfx_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey>;
+    fn sfx_sounds_dir(&self) -> Result<PathBuf, Error>;
+
     fn play(&self, sfx: AppSounds);
     fn stop(&self, sfx: AppSounds);
+
+    fn get_sound_pack(&self) -> Result<SoundPack, Error>;
+    fn set_sound_pack(&self, pack: SoundPack) -> Result<(), Error>;
+
+    fn get_custom_sounds(&self) -> Result<HashMap<AppSounds, String>, Error>;
+    fn set_custom_sound(&self, sfx: AppSounds, path: PathBuf) -> Result<(), Error>;
+    fn clear_custom_sound(&self, sfx: AppSounds) -> Result<(), Error>;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> SfxPluginExt<R> for T {
+    fn sfx_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey> {
+        self.scoped_store(crate::PLUGIN_NAME).unwrap()
+    }
+
+    fn sfx_sounds_dir(&self) -> Result<PathBuf, Error> {
+        let dir =
+            hypr_data_dir::resolve(|| self.app_handle().path().app_data_dir())?.join("sounds");
+        std::fs::create_dir_all(&dir)?;
+        Ok(dir)
+    }
+
     fn play(&self, sfx: AppSounds) {
-        sfx.play();
+        let pack = self.get_sound_pack().unwrap_or_default();
+
+        let custom = match pack {
+            SoundPack::Silent => return,
+            SoundPack::Default => None,
+            SoundPack::Custom => self
+                .get_custom_sounds()
+                .ok()
+                .and_then(|sounds| sounds.get(&sfx).cloned())
+                .and_then(|path| match std::fs::read(&path) {
+                    Ok(bytes) => Some(bytes),
+                    Err(e) => {
+                        tracing::warn!("custom_sound_unreadable: {} {}", path, e);
+                        None
+                    }
+                }),
+        };
+
+        let bytes = match custom {
+            Some(bytes) => Cow::Owned(bytes),
+            None => match sfx.bundled_bytes() {
+                Some(bytes) => Cow::Borrowed(bytes),
+                None => return,
+            },
+        };
+
+        sfx.play(bytes);
     }
 
     fn stop(&self, sfx: AppSounds) {
         sfx.stop();
     }
+
+    fn get_sound_pack(&self) -> Result<SoundPack, Error> {
+        let store = self.sfx_store();
+        store
+            .get(StoreKey::SoundPack)
+            .map_err(Error::Store)
+            .map(|v| v.unwrap_or_default())
+    }
+
+    fn set_sound_pack(&self, pack: SoundPack) -> Result<(), Error> {
+        let store = self.sfx_store();
+        store.set(StoreKey::SoundPack, pack).map_err(Error::Store)
+    }
+
+    fn get_custom_sounds(&self) -> Result<HashMap<AppSounds, String>, Error> {
+        let store = self.sfx_store();
+        store
+            .get(StoreKey::CustomSounds)
+            .map_err(Error::Store)
+            .map(|v| v.unwrap_or_default())
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn set_custom_sound(&self, sfx: AppSounds, path: PathBuf) -> Result<(), Error> {
+        let extension = crate::validate_sound_file(&path)?;
+
+        // Keep our own copy so the mapping survives the original being moved or deleted.
+        let target = self
+            .sfx_sounds_dir()?
+            .join(format!("{}.{}", sfx.file_stem(), extension));
+        if path != target {
+            std::fs::copy(&path, &target)?;
+        }
+
+        let mut sounds = self.get_custom_sounds()?;
+        if let Some(previous) = sounds.insert(sfx, target.to_string_lossy().to_string()) {
+            if Path::new(&previous) != target {
+                let _ = std::fs::remove_file(&previous);
+            }
+        }
+
+        self.sfx_store()
+            .set(StoreKey::CustomSounds, sounds)
+            .map_err(Error::Store)
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn clear_custom_sound(&self, sfx: AppSounds) -> Result<(), Error> {
+        let mut sounds = self.get_custom_sounds()?;
+
+        if let Some(path) = sounds.remove(&sfx) {
+            if let Err(e) = std::fs::remove_file(&path) {
+                tracing::warn!("custom_sound_remove_failed: {} {}", path, e);
+            }
+        }
+
+        self.sfx_store()
+            .set(StoreKey::CustomSounds, sounds)
+            .map_err(Error::Store)
+    }
 }
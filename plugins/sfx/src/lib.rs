@@ -1,7 +1,13 @@
 mod commands;
+mod error;
 mod ext;
+mod pack;
+mod store;
 
+pub use error::*;
 pub use ext::*;
+pub use pack::*;
+pub use store::*;
 
 const PLUGIN_NAME: &str = "sfx";
 
@@ -11,6 +17,11 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
         .commands(tauri_specta::collect_commands![
             commands::play::<tauri::Wry>,
             commands::stop::<tauri::Wry>,
+            commands::get_sound_pack::<tauri::Wry>,
+            commands::set_sound_pack::<tauri::Wry>,
+            commands::get_custom_sounds::<tauri::Wry>,
+            commands::set_custom_sound::<tauri::Wry>,
+            commands::clear_custom_sound::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
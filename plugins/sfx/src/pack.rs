@@ -0,0 +1,70 @@
+use std::path::Path;
+
+use crate::Error;
+
+pub const SUPPORTED_EXTENSIONS: &[&str] = &["ogg", "mp3", "wav", "flac"];
+pub const MAX_SOUND_BYTES: u64 = 10 * 1024 * 1024;
+
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type,
+)]
+pub enum SoundPack {
+    /// The sounds bundled with the app.
+    #[default]
+    Default,
+    /// Plays nothing.
+    Silent,
+    /// User-provided files, falling back to the bundled sound for events without one.
+    Custom,
+}
+
+/// Checks that `path` is a sound we can play, returning its (lowercased) extension.
+pub fn validate_sound_file(path: &Path) -> Result<String, Error> {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .filter(|ext| SUPPORTED_EXTENSIONS.contains(&ext.as_str()))
+        .ok_or_else(|| Error::UnsupportedFormat(path.display().to_string()))?;
+
+    if std::fs::metadata(path)?.len() > MAX_SOUND_BYTES {
+        return Err(Error::FileTooLarge);
+    }
+
+    let file = std::io::BufReader::new(std::fs::File::open(path)?);
+    rodio::Decoder::new(file).map_err(|e| Error::Decode(e.to_string()))?;
+
+    Ok(extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_unknown_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sfx-test.txt");
+        std::fs::write(&path, b"not a sound").unwrap();
+
+        assert!(matches!(
+            validate_sound_file(&path),
+            Err(Error::UnsupportedFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_undecodable_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sfx-test.ogg");
+        std::fs::write(&path, b"not a sound").unwrap();
+
+        assert!(matches!(validate_sound_file(&path), Err(Error::Decode(_))));
+    }
+
+    #[test]
+    fn test_accepts_bundled_sound() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("sounds/start_recording.ogg");
+        assert_eq!(validate_sound_file(&path).unwrap(), "ogg");
+    }
+}
@@ -0,0 +1,9 @@
+use tauri_plugin_store2::ScopedStoreKey;
+
+#[derive(serde::Deserialize, specta::Type, PartialEq, Eq, Hash, strum::Display)]
+pub enum StoreKey {
+    SoundPack,
+    CustomSounds,
+}
+
+impl ScopedStoreKey for StoreKey {}
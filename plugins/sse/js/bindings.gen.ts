@@ -16,9 +16,11 @@ async fetch(req: Request) : Promise<Response> {
 
 
 export const events = __makeEvents__<{
-serverSentEvent: ServerSentEvent
+serverSentEvent: ServerSentEvent,
+serverSentEventState: ServerSentEventState
 }>({
-serverSentEvent: "plugin:sse:server-sent-event"
+serverSentEvent: "plugin:sse:server-sent-event",
+serverSentEventState: "plugin:sse:server-sent-event-state"
 })
 
 /** user-defined constants **/
@@ -27,9 +29,23 @@ serverSentEvent: "plugin:sse:server-sent-event"
 
 /** user-defined types **/
 
-export type Request = { method: string; url: string; headers: Partial<{ [key in string]: string }>; body: number[] }
+export type ConnectionState = "connected" | { reconnecting: { attempt: number; delay_ms: number } } | 
+/**
+ * The server ended the stream.
+ */
+"closed" | 
+/**
+ * The stream dropped and could not be resumed.
+ */
+{ failed: { error: string } }
+export type Request = { method: string; url: string; headers: Partial<{ [key in string]: string }>; body: number[]; 
+/**
+ * How many times to reconnect an event stream that drops mid-way. Defaults to 5; 0 disables.
+ */
+maxRetries: number | null }
 export type Response = { requestId: number; status: number; headers: Partial<{ [key in string]: string }> }
 export type ServerSentEvent = { requestId: number; chunk: number[] | null }
+export type ServerSentEventState = { requestId: number; state: ConnectionState }
 
 /** tauri-specta globals **/
 
@@ -66,6 +66,7 @@ export const commands = {
         body: typeof body === "string"
           ? Array.from(new TextEncoder().encode(body))
           : [],
+        maxRetries: null,
       })
       .then((res) => {
         return new Response(ts.readable, {
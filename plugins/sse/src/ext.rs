@@ -1,9 +1,12 @@
 use std::{collections::HashMap, future::Future};
 
 use futures_util::StreamExt;
-use reqwest::header::{HeaderMap, HeaderName};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 
-use crate::{Request, Response, ServerSentEvent};
+use crate::{
+    reconnect::{Backoff, EventIdTracker, DEFAULT_MAX_RETRIES},
+    ConnectionState, Request, Response, ServerSentEvent, ServerSentEventState,
+};
 use tauri_specta::Event;
 
 pub trait ServerSentEventPluginExt<R: tauri::Runtime> {
@@ -29,23 +32,16 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> ServerSentEventPluginExt<R> for T
             headers.insert(key.parse::<HeaderName>().unwrap(), value.parse().unwrap());
         }
 
-        let mut request = state.client.request(
-            method.clone(),
-            req.url.parse::<reqwest::Url>().map_err(|e| e.to_string())?,
-        );
-
-        if method == reqwest::Method::POST
-            || method == reqwest::Method::PUT
-            || method == reqwest::Method::PATCH
-        {
-            let body = bytes::Bytes::from(req.body);
-            request = request.body(body);
-        }
+        let connection = Connection {
+            client: hypr_proxy::client(),
+            method,
+            url: req.url.parse::<reqwest::Url>().map_err(|e| e.to_string())?,
+            headers,
+            body: bytes::Bytes::from(req.body),
+        };
 
         tracing::info!("plugin_sse_request_sent");
-        let response_future = request.send();
-
-        let res = response_future.await;
+        let res = connection.send(None).await;
         tracing::info!("plugin_sse_response_received");
 
         match res {
@@ -60,19 +56,25 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> ServerSentEventPluginExt<R> for T
 
                 let status = res.status().as_u16();
 
-                tauri::async_runtime::spawn(async move {
-                    let mut stream = res.bytes_stream();
+                // Only event streams can resume; anything else would be replayed from the start.
+                let event_stream = is_event_stream(&res);
+                let max_retries = if event_stream {
+                    req.max_retries.unwrap_or(DEFAULT_MAX_RETRIES)
+                } else {
+                    0
+                };
 
-                    while let Some(chunk) = stream.next().await {
-                        if let Ok(bytes) = chunk {
-                            let event = ServerSentEvent {
-                                request_id,
-                                chunk: Some(bytes.to_vec()),
-                            };
+                tauri::async_runtime::spawn(async move {
+                    let state =
+                        stream_events(&app, request_id, connection, res, event_stream, max_retries)
+                            .await;
 
-                            let _ = event.emit(&app);
-                        }
+                    let _ = ServerSentEvent {
+                        request_id,
+                        chunk: None,
                     }
+                    .emit(&app);
+                    let _ = ServerSentEventState { request_id, state }.emit(&app);
                 });
 
                 Ok(Response {
@@ -89,3 +91,141 @@ impl<R: tauri::Runtime, T: tauri::Manager<R>> ServerSentEventPluginExt<R> for T
         }
     }
 }
+
+struct Connection {
+    client: reqwest::Client,
+    method: reqwest::Method,
+    url: reqwest::Url,
+    headers: HeaderMap,
+    body: bytes::Bytes,
+}
+
+impl Connection {
+    async fn send(&self, last_event_id: Option<&str>) -> reqwest::Result<reqwest::Response> {
+        let mut headers = self.headers.clone();
+        if let Some(value) = last_event_id
+            .filter(|id| !id.is_empty())
+            .and_then(|id| HeaderValue::from_str(id).ok())
+        {
+            headers.insert("last-event-id", value);
+        }
+
+        let mut request = self
+            .client
+            .request(self.method.clone(), self.url.clone())
+            .headers(headers);
+
+        if self.method == reqwest::Method::POST
+            || self.method == reqwest::Method::PUT
+            || self.method == reqwest::Method::PATCH
+        {
+            request = request.body(self.body.clone());
+        }
+
+        request.send().await
+    }
+}
+
+/// Forwards chunks until the stream ends, reconnecting after network errors.
+/// Event streams are forwarded a whole event at a time, so one cut off by a reconnect
+/// never reaches the consumer. Returns the state the connection finished in.
+async fn stream_events<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    request_id: u32,
+    connection: Connection,
+    mut res: reqwest::Response,
+    event_stream: bool,
+    max_retries: u32,
+) -> ConnectionState {
+    let mut tracker = EventIdTracker::default();
+    let mut backoff = Backoff::new(max_retries);
+
+    let _ = ServerSentEventState {
+        request_id,
+        state: ConnectionState::Connected,
+    }
+    .emit(app);
+
+    loop {
+        let mut stream = res.bytes_stream();
+        let mut error = None;
+
+        while let Some(chunk) = stream.next().await {
+            match chunk {
+                Ok(bytes) => {
+                    backoff.reset();
+
+                    let chunk = if event_stream {
+                        tracker.feed(&bytes)
+                    } else {
+                        bytes.to_vec()
+                    };
+                    if chunk.is_empty() {
+                        continue;
+                    }
+
+                    let event = ServerSentEvent {
+                        request_id,
+                        chunk: Some(chunk),
+                    };
+                    let _ = event.emit(app);
+                }
+                Err(e) => {
+                    error = Some(e.to_string());
+                    break;
+                }
+            }
+        }
+
+        let Some(mut error) = error else {
+            return ConnectionState::Closed;
+        };
+        tracing::warn!("plugin_sse_stream_error: {}", error);
+
+        res = loop {
+            let Some(delay) = backoff.next_delay(tracker.retry()) else {
+                return ConnectionState::Failed { error };
+            };
+
+            let _ = ServerSentEventState {
+                request_id,
+                state: ConnectionState::Reconnecting {
+                    attempt: backoff.attempt(),
+                    delay_ms: delay.as_millis() as u64,
+                },
+            }
+            .emit(app);
+            tokio::time::sleep(delay).await;
+
+            match connection.send(tracker.last_event_id()).await {
+                Ok(res) if res.status().is_success() => break res,
+                Ok(res) if is_retryable(res.status()) => error = res.status().to_string(),
+                Ok(res) => {
+                    return ConnectionState::Failed {
+                        error: res.status().to_string(),
+                    }
+                }
+                Err(e) => error = e.to_string(),
+            }
+        };
+
+        tracker.reconnected();
+        tracing::info!("plugin_sse_reconnected");
+        let _ = ServerSentEventState {
+            request_id,
+            state: ConnectionState::Connected,
+        }
+        .emit(app);
+    }
+}
+
+fn is_event_stream(res: &reqwest::Response) -> bool {
+    res.headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("text/event-stream"))
+}
+
+fn is_retryable(status: reqwest::StatusCode) -> bool {
+    status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+}
@@ -2,6 +2,7 @@ use tauri::Manager;
 
 mod commands;
 mod ext;
+mod reconnect;
 mod types;
 
 pub use ext::*;
@@ -12,13 +13,15 @@ const PLUGIN_NAME: &str = "sse";
 #[derive(Default)]
 pub struct State {
     pub counter: std::sync::atomic::AtomicU32,
-    pub client: reqwest::Client,
 }
 
 fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
     tauri_specta::Builder::<R>::new()
         .plugin_name(PLUGIN_NAME)
-        .events(tauri_specta::collect_events![types::ServerSentEvent])
+        .events(tauri_specta::collect_events![
+            types::ServerSentEvent,
+            types::ServerSentEventState
+        ])
         .commands(tauri_specta::collect_commands![
             commands::fetch::<tauri::Wry>
         ])
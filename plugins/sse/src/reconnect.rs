@@ -0,0 +1,176 @@
+use std::time::Duration;
+
+pub const DEFAULT_MAX_RETRIES: u32 = 5;
+
+const BASE_DELAY: Duration = Duration::from_secs(1);
+const MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Follows the `id:` and `retry:` fields of an event stream across chunk boundaries,
+/// so a reconnect can resume where the last complete event left off.
+#[derive(Debug, Default)]
+pub struct EventIdTracker {
+    partial_line: Vec<u8>,
+    /// Lines of the event in progress, held back until it is dispatched.
+    pending: Vec<u8>,
+    id_buffer: Option<String>,
+    last_event_id: Option<String>,
+    retry: Option<Duration>,
+}
+
+impl EventIdTracker {
+    /// Returns the bytes of the events `bytes` completes, ready to forward as they are.
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<u8> {
+        self.partial_line.extend_from_slice(bytes);
+
+        let mut complete = Vec::new();
+        while let Some(pos) = self.partial_line.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.partial_line.drain(..=pos).collect();
+            self.pending.extend_from_slice(&line);
+
+            let line = String::from_utf8_lossy(&line);
+            if self.process_line(line.trim_end_matches(['\n', '\r'])) {
+                complete.append(&mut self.pending);
+            }
+        }
+        complete
+    }
+
+    /// A new connection starts a new stream: the event cut off by the old one is dropped,
+    /// while the resume point and the server's retry delay are kept.
+    pub fn reconnected(&mut self) {
+        self.partial_line.clear();
+        self.pending.clear();
+        self.id_buffer = self.last_event_id.clone();
+    }
+
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+
+    /// Reconnection delay requested by the server, if any.
+    pub fn retry(&self) -> Option<Duration> {
+        self.retry
+    }
+
+    /// Returns whether `line` dispatched the event in progress.
+    fn process_line(&mut self, line: &str) -> bool {
+        if line.is_empty() {
+            // Only a dispatched event moves the resume point.
+            self.last_event_id = self.id_buffer.clone();
+            return true;
+        }
+
+        if line.starts_with(':') {
+            return false;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+
+        match field {
+            "id" if !value.contains('\0') => self.id_buffer = Some(value.to_string()),
+            "retry" => {
+                if let Ok(ms) = value.parse::<u64>() {
+                    self.retry = Some(Duration::from_millis(ms));
+                }
+            }
+            _ => {}
+        }
+        false
+    }
+}
+
+#[derive(Debug)]
+pub struct Backoff {
+    attempt: u32,
+    max_retries: u32,
+}
+
+impl Backoff {
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            attempt: 0,
+            max_retries,
+        }
+    }
+
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+
+    /// Delay before the next attempt, or `None` once retries are exhausted.
+    pub fn next_delay(&mut self, base: Option<Duration>) -> Option<Duration> {
+        if self.attempt >= self.max_retries {
+            return None;
+        }
+        self.attempt += 1;
+
+        let cap = delay_cap(base.unwrap_or(BASE_DELAY), self.attempt);
+        // Half fixed, half random, so clients that dropped together don't come back together.
+        let half = cap / 2;
+        Some(half + half.mul_f64(rand::random::<f64>()))
+    }
+}
+
+fn delay_cap(base: Duration, attempt: u32) -> Duration {
+    base.saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+        .min(MAX_DELAY.max(base))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracker_across_chunks() {
+        let mut tracker = EventIdTracker::default();
+
+        let complete = tracker.feed(b"id: 1\ndata: a\n\nid: 2\nda");
+        assert_eq!(complete, b"id: 1\ndata: a\n\n");
+        assert_eq!(tracker.last_event_id(), Some("1"));
+
+        let complete = tracker.feed(b"ta: b\r\n\r\n: keepalive\n\n");
+        assert_eq!(complete, b"id: 2\ndata: b\r\n\r\n: keepalive\n\n");
+        assert_eq!(tracker.last_event_id(), Some("2"));
+
+        tracker.feed(b"retry: 2500\nid\n\n");
+        assert_eq!(tracker.last_event_id(), Some(""));
+        assert_eq!(tracker.retry(), Some(Duration::from_millis(2500)));
+    }
+
+    #[test]
+    fn test_tracker_reconnected() {
+        let mut tracker = EventIdTracker::default();
+
+        tracker.feed(b"retry: 2500\nid: 1\ndata: a\n\nid: 2\ndata: cut");
+        tracker.reconnected();
+
+        // Nothing of the cut off event ends up in the next one.
+        let complete = tracker.feed(b"data: b\n\n");
+        assert_eq!(complete, b"data: b\n\n");
+        assert_eq!(tracker.last_event_id(), Some("1"));
+        assert_eq!(tracker.retry(), Some(Duration::from_millis(2500)));
+    }
+
+    #[test]
+    fn test_backoff() {
+        assert_eq!(delay_cap(BASE_DELAY, 1), Duration::from_secs(1));
+        assert_eq!(delay_cap(BASE_DELAY, 3), Duration::from_secs(4));
+        assert_eq!(delay_cap(BASE_DELAY, 20), MAX_DELAY);
+
+        let mut backoff = Backoff::new(2);
+        let delay = backoff.next_delay(None).unwrap();
+        assert!(delay >= Duration::from_millis(500) && delay <= Duration::from_secs(1));
+        assert!(backoff.next_delay(None).is_some());
+        assert!(backoff.next_delay(None).is_none());
+
+        backoff.reset();
+        assert_eq!(backoff.attempt(), 0);
+    }
+}
@@ -6,6 +6,9 @@ pub struct Request {
     pub url: String,
     pub headers: HashMap<String, String>,
     pub body: Vec<u8>,
+    /// How many times to reconnect an event stream that drops mid-way. Defaults to 5; 0 disables.
+    #[serde(rename = "maxRetries", default)]
+    pub max_retries: Option<u32>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, specta::Type)]
@@ -22,3 +25,24 @@ pub struct ServerSentEvent {
     pub request_id: u32,
     pub chunk: Option<Vec<u8>>,
 }
+
+#[derive(Debug, Clone, serde::Serialize, specta::Type)]
+pub enum ConnectionState {
+    #[serde(rename = "connected")]
+    Connected,
+    #[serde(rename = "reconnecting")]
+    Reconnecting { attempt: u32, delay_ms: u64 },
+    /// The server ended the stream.
+    #[serde(rename = "closed")]
+    Closed,
+    /// The stream dropped and could not be resumed.
+    #[serde(rename = "failed")]
+    Failed { error: String },
+}
+
+#[derive(Debug, Clone, serde::Serialize, specta::Type, tauri_specta::Event)]
+pub struct ServerSentEventState {
+    #[serde(rename = "requestId")]
+    pub request_id: u32,
+    pub state: ConnectionState,
+}
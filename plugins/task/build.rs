@@ -1,4 +1,19 @@
-const COMMANDS: &[&str] = &[];
+const COMMANDS: &[&str] = &[
+    "get_task",
+    "cancel_task",
+    "enqueue_task",
+    "list_queued_tasks",
+    "retry_task",
+    "cancel_queued_task",
+    "complete_queued_task",
+    "fail_queued_task",
+    "pause_queued_task",
+    "resume_queued_task",
+    "heartbeat_queued_task",
+    "enqueue_pipeline",
+    "complete_pipeline_stage",
+    "fail_pipeline_stage",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
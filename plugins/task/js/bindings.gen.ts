@@ -12,12 +12,53 @@ async getTask(id: string) : Promise<TaskRecord> {
 },
 async cancelTask(id: string) : Promise<null> {
     return await TAURI_INVOKE("plugin:task|cancel_task", { id });
+},
+async enqueueTask(kind: QueuedTaskKind, payload: string, maxAttempts: number | null) : Promise<QueuedTask> {
+    return await TAURI_INVOKE("plugin:task|enqueue_task", { kind, payload, maxAttempts });
+},
+async listQueuedTasks(status: QueuedTaskStatus | null) : Promise<QueuedTask[]> {
+    return await TAURI_INVOKE("plugin:task|list_queued_tasks", { status });
+},
+async retryTask(id: string) : Promise<QueuedTask> {
+    return await TAURI_INVOKE("plugin:task|retry_task", { id });
+},
+async cancelQueuedTask(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:task|cancel_queued_task", { id });
+},
+async completeQueuedTask(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:task|complete_queued_task", { id });
+},
+async failQueuedTask(id: string, error: string) : Promise<QueuedTask> {
+    return await TAURI_INVOKE("plugin:task|fail_queued_task", { id, error });
+},
+async pauseQueuedTask(id: string) : Promise<QueuedTask> {
+    return await TAURI_INVOKE("plugin:task|pause_queued_task", { id });
+},
+async resumeQueuedTask(id: string) : Promise<QueuedTask> {
+    return await TAURI_INVOKE("plugin:task|resume_queued_task", { id });
+},
+async heartbeatQueuedTask(id: string, checkpoint: string | null) : Promise<QueuedTask> {
+    return await TAURI_INVOKE("plugin:task|heartbeat_queued_task", { id, checkpoint });
+},
+async enqueuePipeline(sessionId: string, templateId: string | null) : Promise<QueuedTask | null> {
+    return await TAURI_INVOKE("plugin:task|enqueue_pipeline", { sessionId, templateId });
+},
+async completePipelineStage(taskId: string, stage: PipelineStage) : Promise<null> {
+    return await TAURI_INVOKE("plugin:task|complete_pipeline_stage", { taskId, stage });
+},
+async failPipelineStage(taskId: string, stage: PipelineStage, error: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:task|fail_pipeline_stage", { taskId, stage, error });
 }
 }
 
 /** user-defined events **/
 
 
+export const events = __makeEvents__<{
+taskEvent: TaskEvent
+}>({
+taskEvent: "plugin:task:task-event"
+})
 
 /** user-defined constants **/
 
@@ -25,6 +66,71 @@ async cancelTask(id: string) : Promise<null> {
 
 /** user-defined types **/
 
+export type PipelineStage = 
+/**
+ * Transcribes the recording on-device, when nothing was transcribed live.
+ */
+"transcribe" | 
+/**
+ * Names speakers whose voice matches a known speaker profile.
+ */
+"diarize" | "summarize" | "action_items" | "title" | "tags"
+export type PipelineStageEvent = { task_id: string; session_id: string; template_id: string | null; stage: PipelineStage; 
+/**
+ * Position of the stage in this run, from 0.
+ */
+index: number; total: number; status: PipelineStageStatus }
+export type PipelineStageStatus = "started" | "completed" | 
+/**
+ * The run is retried from this stage, unless it ran out of attempts.
+ */
+{ failed: { error: string } }
+export type QueuedTask = { id: string; kind: QueuedTaskKind; 
+/**
+ * JSON, interpreted by whoever runs tasks of this kind.
+ */
+payload: string; status: QueuedTaskStatus; attempts: number; max_attempts: number; last_error: string | null; 
+/**
+ * Not picked up before this time; pushed back on every failed attempt.
+ */
+run_at: string; created_at: string; updated_at: string }
+export type QueuedTaskKind = "enhance" | "title" | "export" | 
+/**
+ * Runs a session's recording through another model; payload has the session and model.
+ */
+"retranscribe" | 
+/**
+ * Sums up past meetings with an upcoming event's attendees; payload has the event.
+ */
+"pre_meeting_brief" | 
+/**
+ * Runs a session's pipeline stage by stage; payload has the session and what's left.
+ */
+"pipeline"
+export type QueuedTaskStatus = "queued" | "running" | 
+/**
+ * Held back until resumed; a running task stops at its next heartbeat.
+ */
+"paused" | "completed" | 
+/**
+ * Ran out of attempts; stays here until retried by hand.
+ */
+"dead" | "cancelled"
+export type TaskEvent = 
+/**
+ * The task was claimed and should be run now; report back with `complete_queued_task`
+ * or `fail_queued_task`. Pipeline runs come as `Stage` instead, one stage at a time.
+ */
+{ run: QueuedTask } | 
+/**
+ * The task failed its last attempt and will not run again unless retried.
+ */
+{ dead: QueuedTask } | 
+/**
+ * A pipeline stage started, finished or failed. Whoever runs the stage picks it up when it
+ * starts, and reports back with `complete_pipeline_stage` or `fail_pipeline_stage`.
+ */
+{ stage: PipelineStageEvent }
 export type TaskRecord = { id: string; status: TaskStatus; data: string }
 export type TaskStatus = { Running: { current: number; total: number } } | "Completed" | { Failed: { error: string } } | "Cancelled"
 
@@ -19,3 +19,135 @@ pub async fn cancel_task<R: tauri::Runtime>(
     app.cancel_task(id)
         .map_err(|_| crate::Error::TaskNotFound.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn enqueue_task<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    kind: hypr_db_user::QueuedTaskKind,
+    payload: String,
+    max_attempts: Option<i64>,
+) -> Result<hypr_db_user::QueuedTask, String> {
+    app.enqueue_task(kind, payload, max_attempts)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_queued_tasks<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    status: Option<hypr_db_user::QueuedTaskStatus>,
+) -> Result<Vec<hypr_db_user::QueuedTask>, String> {
+    app.list_queued_tasks(status)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn retry_task<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<hypr_db_user::QueuedTask, String> {
+    app.retry_task(id).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn cancel_queued_task<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<(), String> {
+    app.cancel_queued_task(id).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn complete_queued_task<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<(), String> {
+    app.complete_queued_task(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn fail_queued_task<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+    error: String,
+) -> Result<hypr_db_user::QueuedTask, String> {
+    app.fail_queued_task(id, error)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn pause_queued_task<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<hypr_db_user::QueuedTask, String> {
+    app.pause_queued_task(id).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn resume_queued_task<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<hypr_db_user::QueuedTask, String> {
+    app.resume_queued_task(id).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn heartbeat_queued_task<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+    checkpoint: Option<String>,
+) -> Result<hypr_db_user::QueuedTask, String> {
+    app.heartbeat_queued_task(id, checkpoint)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn enqueue_pipeline<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    template_id: Option<String>,
+) -> Result<Option<hypr_db_user::QueuedTask>, String> {
+    app.enqueue_pipeline(session_id, template_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn complete_pipeline_stage<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    task_id: String,
+    stage: hypr_db_user::PipelineStage,
+) -> Result<(), String> {
+    app.complete_pipeline_stage(task_id, stage)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn fail_pipeline_stage<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    task_id: String,
+    stage: hypr_db_user::PipelineStage,
+    error: String,
+) -> Result<(), String> {
+    app.fail_pipeline_stage(task_id, stage, error)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -6,6 +6,12 @@ pub enum Error {
     StoreError,
     #[error("Task not found")]
     TaskNotFound,
+    #[error("Database not ready")]
+    DatabaseNotReady,
+    #[error("Invalid task payload: {0}")]
+    InvalidTaskPayload(String),
+    #[error(transparent)]
+    Database(#[from] hypr_db_user::Error),
 }
 
 impl Serialize for Error {
@@ -0,0 +1,39 @@
+use hypr_db_user::{PipelineStage, QueuedTask};
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, specta::Type, tauri_specta::Event)]
+pub enum TaskEvent {
+    /// The task was claimed and should be run now; report back with `complete_queued_task`
+    /// or `fail_queued_task`. Pipeline runs come as `Stage` instead, one stage at a time.
+    #[serde(rename = "run")]
+    Run(QueuedTask),
+    /// The task failed its last attempt and will not run again unless retried.
+    #[serde(rename = "dead")]
+    Dead(QueuedTask),
+    /// A pipeline stage started, finished or failed. Whoever runs the stage picks it up when it
+    /// starts, and reports back with `complete_pipeline_stage` or `fail_pipeline_stage`.
+    #[serde(rename = "stage")]
+    Stage(PipelineStageEvent),
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, specta::Type)]
+pub struct PipelineStageEvent {
+    pub task_id: String,
+    pub session_id: String,
+    pub template_id: Option<String>,
+    pub stage: PipelineStage,
+    /// Position of the stage in this run, from 0.
+    pub index: u32,
+    pub total: u32,
+    pub status: PipelineStageStatus,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, specta::Type)]
+pub enum PipelineStageStatus {
+    #[serde(rename = "started")]
+    Started,
+    #[serde(rename = "completed")]
+    Completed,
+    /// The run is retried from this stage, unless it ran out of attempts.
+    #[serde(rename = "failed")]
+    Failed { error: String },
+}
@@ -1,7 +1,14 @@
+use std::future::Future;
+
+use hypr_db_user::{PipelineStage, QueuedTask, QueuedTaskKind, QueuedTaskStatus, UserDatabase};
 use tauri::{AppHandle, Manager, Runtime};
 use tauri_plugin_store2::{ScopedStore, StorePluginExt};
+use tauri_specta::Event;
 
-use crate::{StoreKey, TaskCtx, TaskRecord, TaskState, TaskStatus};
+use crate::{
+    pipeline::PipelinePayload, PipelineStageStatus, StoreKey, TaskCtx, TaskEvent, TaskRecord,
+    TaskState, TaskStatus,
+};
 
 pub trait TaskPluginExt<R: Runtime>: Manager<R> {
     fn task_store(&self) -> ScopedStore<R, StoreKey>;
@@ -13,6 +20,62 @@ pub trait TaskPluginExt<R: Runtime>: Manager<R> {
 
     fn get_task(&self, id: String) -> Option<TaskRecord>;
     fn cancel_task(&self, id: String) -> Result<(), crate::Error>;
+
+    fn enqueue_task(
+        &self,
+        kind: QueuedTaskKind,
+        payload: String,
+        max_attempts: Option<i64>,
+    ) -> impl Future<Output = Result<QueuedTask, crate::Error>>;
+    fn list_queued_tasks(
+        &self,
+        status: Option<QueuedTaskStatus>,
+    ) -> impl Future<Output = Result<Vec<QueuedTask>, crate::Error>>;
+    fn retry_task(&self, id: String) -> impl Future<Output = Result<QueuedTask, crate::Error>>;
+    fn cancel_queued_task(&self, id: String) -> impl Future<Output = Result<(), crate::Error>>;
+    fn complete_queued_task(&self, id: String) -> impl Future<Output = Result<(), crate::Error>>;
+    fn fail_queued_task(
+        &self,
+        id: String,
+        error: String,
+    ) -> impl Future<Output = Result<QueuedTask, crate::Error>>;
+    fn pause_queued_task(
+        &self,
+        id: String,
+    ) -> impl Future<Output = Result<QueuedTask, crate::Error>>;
+    fn resume_queued_task(
+        &self,
+        id: String,
+    ) -> impl Future<Output = Result<QueuedTask, crate::Error>>;
+    /// Keeps a long-running task from timing out. Check the returned status to learn whether it
+    /// was paused or cancelled meanwhile. A `checkpoint` replaces the payload, so that a paused
+    /// or interrupted task resumes from it instead of from the start.
+    fn heartbeat_queued_task(
+        &self,
+        id: String,
+        checkpoint: Option<String>,
+    ) -> impl Future<Output = Result<QueuedTask, crate::Error>>;
+
+    /// Queues the stages of the pipeline for `template_id` to run on a session, one after
+    /// another. `None` when all of them are turned off.
+    fn enqueue_pipeline(
+        &self,
+        session_id: String,
+        template_id: Option<String>,
+    ) -> impl Future<Output = Result<Option<QueuedTask>, crate::Error>>;
+    /// Moves the run on to its next stage. Reports for a stage the run is no longer at are
+    /// ignored.
+    fn complete_pipeline_stage(
+        &self,
+        task_id: String,
+        stage: PipelineStage,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
+    fn fail_pipeline_stage(
+        &self,
+        task_id: String,
+        stage: PipelineStage,
+        error: String,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
 }
 
 impl<R: Runtime, T: Manager<R>> TaskPluginExt<R> for T {
@@ -78,4 +141,275 @@ impl<R: Runtime, T: Manager<R>> TaskPluginExt<R> for T {
             Ok(())
         }
     }
+
+    async fn enqueue_task(
+        &self,
+        kind: QueuedTaskKind,
+        payload: String,
+        max_attempts: Option<i64>,
+    ) -> Result<QueuedTask, crate::Error> {
+        let db = user_db(self).await?;
+        let now = chrono::Utc::now();
+
+        let task = QueuedTask {
+            id: uuid::Uuid::new_v4().to_string(),
+            kind,
+            payload,
+            status: QueuedTaskStatus::Queued,
+            attempts: 0,
+            max_attempts: max_attempts
+                .unwrap_or(crate::queue::DEFAULT_MAX_ATTEMPTS)
+                .max(1),
+            last_error: None,
+            run_at: now,
+            created_at: now,
+            updated_at: now,
+        };
+
+        Ok(db.upsert_queued_task(task).await?)
+    }
+
+    async fn list_queued_tasks(
+        &self,
+        status: Option<QueuedTaskStatus>,
+    ) -> Result<Vec<QueuedTask>, crate::Error> {
+        let db = user_db(self).await?;
+        Ok(db.list_queued_tasks(status).await?)
+    }
+
+    async fn retry_task(&self, id: String) -> Result<QueuedTask, crate::Error> {
+        let db = user_db(self).await?;
+        let mut task = db
+            .get_queued_task(id)
+            .await?
+            .ok_or(crate::Error::TaskNotFound)?;
+
+        if task.status == QueuedTaskStatus::Running {
+            return Ok(task);
+        }
+
+        let now = chrono::Utc::now();
+        task.status = QueuedTaskStatus::Queued;
+        task.attempts = 0;
+        task.last_error = None;
+        task.run_at = now;
+        task.updated_at = now;
+
+        Ok(db.upsert_queued_task(task).await?)
+    }
+
+    async fn cancel_queued_task(&self, id: String) -> Result<(), crate::Error> {
+        let db = user_db(self).await?;
+        let mut task = db
+            .get_queued_task(id)
+            .await?
+            .ok_or(crate::Error::TaskNotFound)?;
+
+        if matches!(
+            task.status,
+            QueuedTaskStatus::Queued | QueuedTaskStatus::Running | QueuedTaskStatus::Paused
+        ) {
+            task.status = QueuedTaskStatus::Cancelled;
+            task.updated_at = chrono::Utc::now();
+            db.upsert_queued_task(task).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn complete_queued_task(&self, id: String) -> Result<(), crate::Error> {
+        let db = user_db(self).await?;
+        let mut task = db
+            .get_queued_task(id)
+            .await?
+            .ok_or(crate::Error::TaskNotFound)?;
+
+        // A task cancelled, paused or timed out while running keeps that outcome.
+        if task.status == QueuedTaskStatus::Running {
+            task.status = QueuedTaskStatus::Completed;
+            task.updated_at = chrono::Utc::now();
+            db.upsert_queued_task(task).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn fail_queued_task(
+        &self,
+        id: String,
+        error: String,
+    ) -> Result<QueuedTask, crate::Error> {
+        let db = user_db(self).await?;
+        let task = db
+            .get_queued_task(id)
+            .await?
+            .ok_or(crate::Error::TaskNotFound)?;
+
+        if task.status != QueuedTaskStatus::Running {
+            return Ok(task);
+        }
+
+        let task = crate::queue::record_failure(task, error, chrono::Utc::now());
+        let task = db.upsert_queued_task(task).await?;
+
+        if task.status == QueuedTaskStatus::Dead {
+            tracing::warn!("task_dead_lettered: {} {}", task.kind, task.id);
+            let _ = TaskEvent::Dead(task.clone()).emit(self.app_handle());
+        }
+
+        Ok(task)
+    }
+
+    async fn pause_queued_task(&self, id: String) -> Result<QueuedTask, crate::Error> {
+        let db = user_db(self).await?;
+        let mut task = db
+            .get_queued_task(id)
+            .await?
+            .ok_or(crate::Error::TaskNotFound)?;
+
+        if matches!(
+            task.status,
+            QueuedTaskStatus::Queued | QueuedTaskStatus::Running
+        ) {
+            task.status = QueuedTaskStatus::Paused;
+            task.updated_at = chrono::Utc::now();
+            return Ok(db.upsert_queued_task(task).await?);
+        }
+
+        Ok(task)
+    }
+
+    async fn resume_queued_task(&self, id: String) -> Result<QueuedTask, crate::Error> {
+        let db = user_db(self).await?;
+        let mut task = db
+            .get_queued_task(id)
+            .await?
+            .ok_or(crate::Error::TaskNotFound)?;
+
+        // Picks up from the last checkpoint the runner saved, if any.
+        if task.status == QueuedTaskStatus::Paused {
+            let now = chrono::Utc::now();
+            task.status = QueuedTaskStatus::Queued;
+            task.run_at = now;
+            task.updated_at = now;
+            return Ok(db.upsert_queued_task(task).await?);
+        }
+
+        Ok(task)
+    }
+
+    async fn heartbeat_queued_task(
+        &self,
+        id: String,
+        checkpoint: Option<String>,
+    ) -> Result<QueuedTask, crate::Error> {
+        let db = user_db(self).await?;
+        let mut task = db
+            .get_queued_task(id)
+            .await?
+            .ok_or(crate::Error::TaskNotFound)?;
+
+        match task.status {
+            QueuedTaskStatus::Running => task.updated_at = chrono::Utc::now(),
+            // A runner learns about a pause from this very call, so its progress still counts.
+            QueuedTaskStatus::Paused if checkpoint.is_some() => {}
+            _ => return Ok(task),
+        }
+        if let Some(checkpoint) = checkpoint {
+            task.payload = checkpoint;
+        }
+
+        Ok(db.upsert_queued_task(task).await?)
+    }
+
+    async fn enqueue_pipeline(
+        &self,
+        session_id: String,
+        template_id: Option<String>,
+    ) -> Result<Option<QueuedTask>, crate::Error> {
+        let db = user_db(self).await?;
+        let stages = db.resolve_pipeline(template_id.clone()).await?.stages();
+        if stages.is_empty() {
+            return Ok(None);
+        }
+
+        let payload = PipelinePayload {
+            session_id,
+            template_id,
+            stages,
+            done: 0,
+        };
+        let task = self
+            .enqueue_task(
+                QueuedTaskKind::Pipeline,
+                serde_json::to_string(&payload).unwrap(),
+                None,
+            )
+            .await?;
+
+        Ok(Some(task))
+    }
+
+    async fn complete_pipeline_stage(
+        &self,
+        task_id: String,
+        stage: PipelineStage,
+    ) -> Result<(), crate::Error> {
+        let db = user_db(self).await?;
+        let mut task = db
+            .get_queued_task(task_id)
+            .await?
+            .ok_or(crate::Error::TaskNotFound)?;
+
+        let mut payload = PipelinePayload::parse(&task)?;
+        if task.status != QueuedTaskStatus::Running || payload.current() != Some(&stage) {
+            return Ok(());
+        }
+        crate::pipeline::emit(self, &task, &payload, PipelineStageStatus::Completed);
+
+        payload.done += 1;
+        task.payload = serde_json::to_string(&payload).unwrap();
+        task.updated_at = chrono::Utc::now();
+        let task = db.upsert_queued_task(task).await?;
+
+        crate::pipeline::advance(self.app_handle(), task).await
+    }
+
+    async fn fail_pipeline_stage(
+        &self,
+        task_id: String,
+        stage: PipelineStage,
+        error: String,
+    ) -> Result<(), crate::Error> {
+        let db = user_db(self).await?;
+        let task = db
+            .get_queued_task(task_id)
+            .await?
+            .ok_or(crate::Error::TaskNotFound)?;
+
+        let payload = PipelinePayload::parse(&task)?;
+        if task.status != QueuedTaskStatus::Running || payload.current() != Some(&stage) {
+            return Ok(());
+        }
+        crate::pipeline::emit(
+            self,
+            &task,
+            &payload,
+            PipelineStageStatus::Failed {
+                error: error.clone(),
+            },
+        );
+
+        self.fail_queued_task(task.id, format!("{}: {}", stage, error))
+            .await?;
+        Ok(())
+    }
+}
+
+async fn user_db<R: Runtime>(manager: &impl Manager<R>) -> Result<UserDatabase, crate::Error> {
+    let db_state = manager
+        .try_state::<tauri_plugin_db::ManagedState>()
+        .ok_or(crate::Error::DatabaseNotReady)?;
+    let guard = db_state.lock().await;
+    guard.db.clone().ok_or(crate::Error::DatabaseNotReady)
 }
@@ -3,12 +3,16 @@ use tauri::{Manager, Wry};
 mod commands;
 mod ctx;
 mod error;
+mod events;
 mod ext;
+mod pipeline;
+mod queue;
 mod state;
 mod store;
 
 pub use ctx::*;
 pub use error::*;
+pub use events::*;
 pub use ext::*;
 pub use state::*;
 pub use store::*;
@@ -21,7 +25,20 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
         .commands(tauri_specta::collect_commands![
             commands::get_task<Wry>,
             commands::cancel_task<Wry>,
+            commands::enqueue_task<Wry>,
+            commands::list_queued_tasks<Wry>,
+            commands::retry_task<Wry>,
+            commands::cancel_queued_task<Wry>,
+            commands::complete_queued_task<Wry>,
+            commands::fail_queued_task<Wry>,
+            commands::pause_queued_task<Wry>,
+            commands::resume_queued_task<Wry>,
+            commands::heartbeat_queued_task<Wry>,
+            commands::enqueue_pipeline<Wry>,
+            commands::complete_pipeline_stage<Wry>,
+            commands::fail_pipeline_stage<Wry>,
         ])
+        .events(tauri_specta::collect_events![events::TaskEvent])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
 
@@ -30,8 +47,11 @@ pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
 
     tauri::plugin::Builder::new(PLUGIN_NAME)
         .invoke_handler(specta_builder.invoke_handler())
-        .setup(|app, _api| {
+        .setup(move |app, _api| {
+            specta_builder.mount_events(app);
             app.manage(TaskState::default());
+
+            tauri::async_runtime::spawn(queue::run(app.app_handle().clone()));
             Ok(())
         })
         .build()
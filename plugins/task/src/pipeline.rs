@@ -0,0 +1,104 @@
+use hypr_db_user::{PipelineStage, QueuedTask};
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_specta::Event;
+
+use crate::{PipelineStageEvent, PipelineStageStatus, TaskEvent, TaskPluginExt};
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct PipelinePayload {
+    pub session_id: String,
+    /// What the note is enhanced with; `None` when no template was picked.
+    pub template_id: Option<String>,
+    /// The stages turned on when the run was queued, in order. Editing the pipeline afterwards
+    /// leaves the run alone.
+    pub stages: Vec<PipelineStage>,
+    /// How many of `stages` finished. Kept across attempts, so a retry picks up at the stage
+    /// that failed.
+    pub done: usize,
+}
+
+impl PipelinePayload {
+    pub fn parse(task: &QueuedTask) -> Result<Self, crate::Error> {
+        serde_json::from_str(&task.payload)
+            .map_err(|e| crate::Error::InvalidTaskPayload(e.to_string()))
+    }
+
+    pub fn current(&self) -> Option<&PipelineStage> {
+        self.stages.get(self.done)
+    }
+}
+
+/// Hands the next stage of a claimed pipeline run to whoever runs it, or completes the run
+/// when none are left.
+pub async fn advance<R: Runtime>(app: &AppHandle<R>, task: QueuedTask) -> Result<(), crate::Error> {
+    let payload = PipelinePayload::parse(&task)?;
+
+    match payload.current() {
+        Some(_) => emit(app, &task, &payload, PipelineStageStatus::Started),
+        None => app.complete_queued_task(task.id).await?,
+    }
+
+    Ok(())
+}
+
+/// Called for every claimed pipeline run; one that can't be started is failed right away.
+pub async fn start<R: Runtime>(app: &AppHandle<R>, task: QueuedTask) {
+    let id = task.id.clone();
+    if let Err(e) = advance(app, task).await {
+        tracing::error!("pipeline_start_failed: {}", e);
+        let _ = app.fail_queued_task(id, e.to_string()).await;
+    }
+}
+
+pub fn emit<R: Runtime>(
+    app: &impl Manager<R>,
+    task: &QueuedTask,
+    payload: &PipelinePayload,
+    status: PipelineStageStatus,
+) {
+    let Some(stage) = payload.current() else {
+        return;
+    };
+
+    let _ = TaskEvent::Stage(PipelineStageEvent {
+        task_id: task.id.clone(),
+        session_id: payload.session_id.clone(),
+        template_id: payload.template_id.clone(),
+        stage: stage.clone(),
+        index: payload.done as u32,
+        total: payload.stages.len() as u32,
+        status,
+    })
+    .emit(app.app_handle());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hypr_db_user::{QueuedTaskKind, QueuedTaskStatus};
+
+    #[test]
+    fn test_payload() {
+        let now = chrono::Utc::now();
+        let task = QueuedTask {
+            id: "id".to_string(),
+            kind: QueuedTaskKind::Pipeline,
+            payload:
+                r#"{"session_id":"s","template_id":null,"stages":["summarize","title"],"done":1}"#
+                    .to_string(),
+            status: QueuedTaskStatus::Running,
+            attempts: 0,
+            max_attempts: 5,
+            last_error: None,
+            run_at: now,
+            created_at: now,
+            updated_at: now,
+        };
+
+        let mut payload = PipelinePayload::parse(&task).unwrap();
+        assert_eq!(payload.current(), Some(&PipelineStage::Title));
+
+        payload.done += 1;
+        assert_eq!(payload.current(), None);
+    }
+}
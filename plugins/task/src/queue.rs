@@ -0,0 +1,178 @@
+use std::time::Duration;
+
+use hypr_db_user::{QueuedTask, QueuedTaskKind, QueuedTaskStatus};
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_specta::Event;
+
+use crate::{TaskEvent, TaskPluginExt};
+
+pub const DEFAULT_MAX_ATTEMPTS: i64 = 5;
+
+const TICK: Duration = Duration::from_secs(5);
+const CLAIM_BATCH: u32 = 4;
+const BASE_BACKOFF: Duration = Duration::from_secs(5);
+const MAX_BACKOFF: Duration = Duration::from_secs(10 * 60);
+/// A running task that hasn't reported back or sent a heartbeat in this long is assumed lost.
+const LEASE: Duration = Duration::from_secs(10 * 60);
+
+/// Delay before the next attempt, after `attempts` failed ones.
+pub fn backoff_delay(attempts: i64) -> Duration {
+    let exponent = attempts.saturating_sub(1).clamp(0, 16) as u32;
+    BASE_BACKOFF
+        .saturating_mul(2u32.pow(exponent))
+        .min(MAX_BACKOFF)
+}
+
+/// Records a failed attempt, either scheduling the next one or dead-lettering the task.
+pub fn record_failure(
+    mut task: QueuedTask,
+    error: String,
+    now: chrono::DateTime<chrono::Utc>,
+) -> QueuedTask {
+    task.attempts += 1;
+    task.last_error = Some(error);
+    task.updated_at = now;
+
+    if task.attempts >= task.max_attempts {
+        task.status = QueuedTaskStatus::Dead;
+    } else {
+        task.status = QueuedTaskStatus::Queued;
+        task.run_at =
+            now + chrono::Duration::from_std(backoff_delay(task.attempts)).unwrap_or_default();
+    }
+
+    task
+}
+
+/// Puts a task that was cut off, rather than failing by itself, back in the queue. Its
+/// attempts are left alone.
+pub fn release(
+    mut task: QueuedTask,
+    reason: String,
+    now: chrono::DateTime<chrono::Utc>,
+) -> QueuedTask {
+    task.status = QueuedTaskStatus::Queued;
+    task.last_error = Some(reason);
+    task.run_at = now;
+    task.updated_at = now;
+    task
+}
+
+pub async fn run<R: Runtime>(app: AppHandle<R>) {
+    let db = loop {
+        if let Some(db_state) = app.try_state::<tauri_plugin_db::ManagedState>() {
+            if let Some(db) = db_state.lock().await.db.clone() {
+                break db;
+            }
+        }
+        tokio::time::sleep(TICK).await;
+    };
+
+    // Whatever was running when the app last exited never reported back.
+    match db.list_queued_tasks(Some(QueuedTaskStatus::Running)).await {
+        Ok(tasks) => {
+            let now = chrono::Utc::now();
+            for task in tasks {
+                let _ = db
+                    .upsert_queued_task(release(task, "interrupted".to_string(), now))
+                    .await;
+            }
+        }
+        Err(e) => tracing::error!("task_queue_recover_failed: {}", e),
+    }
+
+    loop {
+        if let Err(e) = tick(&app, &db).await {
+            tracing::error!("task_queue_tick_failed: {}", e);
+        }
+        tokio::time::sleep(TICK).await;
+    }
+}
+
+async fn tick<R: Runtime>(
+    app: &AppHandle<R>,
+    db: &hypr_db_user::UserDatabase,
+) -> Result<(), crate::Error> {
+    let now = chrono::Utc::now();
+    let lease = chrono::Duration::from_std(LEASE).unwrap_or_default();
+
+    for task in db
+        .list_queued_tasks(Some(QueuedTaskStatus::Running))
+        .await?
+    {
+        if task.updated_at + lease < now {
+            db.upsert_queued_task(release(task, "timed out".to_string(), now))
+                .await?;
+        }
+    }
+
+    for task in db.claim_due_tasks(now, CLAIM_BATCH).await? {
+        if task.kind == QueuedTaskKind::Pipeline {
+            crate::pipeline::start(app, task).await;
+        } else {
+            let _ = TaskEvent::Run(task).emit(app);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_delay() {
+        assert_eq!(backoff_delay(1), Duration::from_secs(5));
+        assert_eq!(backoff_delay(2), Duration::from_secs(10));
+        assert_eq!(backoff_delay(4), Duration::from_secs(40));
+        assert_eq!(backoff_delay(100), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn test_record_failure() {
+        let now = chrono::Utc::now();
+        let task = QueuedTask {
+            id: "id".to_string(),
+            kind: QueuedTaskKind::Title,
+            payload: "{}".to_string(),
+            status: QueuedTaskStatus::Running,
+            attempts: 0,
+            max_attempts: 2,
+            last_error: None,
+            run_at: now,
+            created_at: now,
+            updated_at: now,
+        };
+
+        let task = record_failure(task, "boom".to_string(), now);
+        assert_eq!(task.status, QueuedTaskStatus::Queued);
+        assert_eq!(task.run_at, now + chrono::Duration::seconds(5));
+
+        let task = record_failure(task, "boom".to_string(), now);
+        assert_eq!(task.status, QueuedTaskStatus::Dead);
+        assert_eq!(task.last_error.as_deref(), Some("boom"));
+    }
+
+    #[test]
+    fn test_release() {
+        let now = chrono::Utc::now();
+        let task = QueuedTask {
+            id: "id".to_string(),
+            kind: QueuedTaskKind::Title,
+            payload: "{}".to_string(),
+            status: QueuedTaskStatus::Running,
+            attempts: 1,
+            max_attempts: 2,
+            last_error: None,
+            run_at: now,
+            created_at: now,
+            updated_at: now,
+        };
+
+        let task = release(task, "timed out".to_string(), now);
+        assert_eq!(task.status, QueuedTaskStatus::Queued);
+        assert_eq!(task.attempts, 1);
+        assert_eq!(task.run_at, now);
+    }
+}
@@ -27,7 +27,7 @@ async registerTemplate(name: string, template: string) : Promise<null> {
 
 export type Grammar = { task: "enhance"; sections: string[] | null } | { task: "title" } | { task: "tags" }
 export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
-export type Template = "enhance.system" | "enhance.user" | "create_title.system" | "create_title.user" | "suggest_tags.system" | "suggest_tags.user" | "chat.system" | "chat.user" | "auto_generate_tags.system" | "auto_generate_tags.user"
+export type Template = "enhance.system" | "enhance.user" | "create_title.system" | "create_title.user" | "suggest_tags.system" | "suggest_tags.user" | "chat.system" | "chat.user" | "auto_generate_tags.system" | "auto_generate_tags.user" | "obsidian.note" | "notification_event.title" | "notification_event.message" | "pre_meeting_brief.system" | "pre_meeting_brief.user" | "action_items.system" | "action_items.user"
 
 /** tauri-specta globals **/
 
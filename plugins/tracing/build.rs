@@ -1,4 +1,12 @@
-const COMMANDS: &[&str] = &["logs_dir"];
+const COMMANDS: &[&str] = &[
+    "logs_dir",
+    "get_log_filter",
+    "set_log_filter",
+    "export_logs",
+    "purge_logs",
+    "get_json_logs",
+    "set_json_logs",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
@@ -9,6 +9,24 @@
 export const commands = {
 async logsDir() : Promise<string> {
     return await TAURI_INVOKE("plugin:tracing|logs_dir");
+},
+async getLogFilter() : Promise<string> {
+    return await TAURI_INVOKE("plugin:tracing|get_log_filter");
+},
+async setLogFilter(directives: string | null) : Promise<null> {
+    return await TAURI_INVOKE("plugin:tracing|set_log_filter", { directives });
+},
+async exportLogs(dest: string) : Promise<string> {
+    return await TAURI_INVOKE("plugin:tracing|export_logs", { dest });
+},
+async purgeLogs() : Promise<number> {
+    return await TAURI_INVOKE("plugin:tracing|purge_logs");
+},
+async getJsonLogs() : Promise<boolean> {
+    return await TAURI_INVOKE("plugin:tracing|get_json_logs");
+},
+async setJsonLogs(enabled: boolean) : Promise<null> {
+    return await TAURI_INVOKE("plugin:tracing|set_json_logs", { enabled });
 }
 }
 
@@ -8,3 +8,48 @@ pub async fn logs_dir<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Result<Pat
     let bundle_id = app.config().identifier.clone();
     app.logs_dir(bundle_id).map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_log_filter<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Result<String, String> {
+    app.log_filter().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_log_filter<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    directives: Option<String>,
+) -> Result<(), String> {
+    app.set_log_filter(directives).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn export_logs<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    dest: PathBuf,
+) -> Result<PathBuf, String> {
+    app.export_logs(dest).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn purge_logs<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Result<u64, String> {
+    app.purge_logs().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_json_logs<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Result<bool, String> {
+    app.json_logs().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_json_logs<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    enabled: bool,
+) -> Result<(), String> {
+    app.set_json_logs(enabled).map_err(|e| e.to_string())
+}
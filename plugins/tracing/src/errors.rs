@@ -1,7 +1,20 @@
 use serde::{ser::Serializer, Serialize};
 
 #[derive(Debug, thiserror::Error)]
-pub enum Error {}
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Zip(#[from] zip::result::ZipError),
+    #[error("invalid log filter: {0}")]
+    InvalidFilter(String),
+    #[error(transparent)]
+    Store(#[from] tauri_plugin_store2::Error),
+    #[error(transparent)]
+    Reload(#[from] tracing_subscriber::reload::Error),
+}
 
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
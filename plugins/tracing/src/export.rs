@@ -0,0 +1,97 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+/// Roughly a week of daily logs.
+const MAX_EXPORT_FILES: usize = 7;
+const MAX_EXPORT_BYTES: u64 = 50 * 1024 * 1024;
+
+#[derive(Debug, serde::Serialize)]
+pub struct SystemInfo {
+    pub app_version: String,
+    pub os: String,
+    pub arch: String,
+    pub log_filter: String,
+    pub exported_at: String,
+}
+
+pub fn recent_log_files(logs_dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut entries = Vec::new();
+
+    for entry in std::fs::read_dir(logs_dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if metadata.is_file() {
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            entries.push((entry.path(), modified, metadata.len()));
+        }
+    }
+
+    Ok(select_recent(entries))
+}
+
+/// Newest first, stopping at the file or size budget. The newest file is always kept.
+fn select_recent(mut entries: Vec<(PathBuf, SystemTime, u64)>) -> Vec<PathBuf> {
+    entries.sort_by(|a, b| b.1.cmp(&a.1));
+
+    let mut total = 0;
+    let mut selected = Vec::new();
+
+    for (path, _, size) in entries.into_iter().take(MAX_EXPORT_FILES) {
+        total += size;
+        if total > MAX_EXPORT_BYTES && !selected.is_empty() {
+            break;
+        }
+        selected.push(path);
+    }
+
+    selected
+}
+
+pub fn write_bundle(dest: &Path, files: &[PathBuf], info: &SystemInfo) -> Result<(), crate::Error> {
+    let mut zip = ZipWriter::new(std::fs::File::create(dest)?);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    zip.start_file("system-info.json", options)?;
+    zip.write_all(serde_json::to_string_pretty(info)?.as_bytes())?;
+
+    for path in files {
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+
+        zip.start_file(format!("logs/{}", name), options)?;
+        std::io::copy(&mut std::fs::File::open(path)?, &mut zip)?;
+    }
+
+    zip.finish()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_select_recent() {
+        let at = |secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+
+        let entries = (0..10)
+            .map(|i| (PathBuf::from(format!("log.{}", i)), at(i), 1024))
+            .collect();
+        let selected = select_recent(entries);
+        assert_eq!(selected.len(), MAX_EXPORT_FILES);
+        assert_eq!(selected[0], PathBuf::from("log.9"));
+
+        let entries = vec![
+            (PathBuf::from("old"), at(1), 1024),
+            (PathBuf::from("huge"), at(2), MAX_EXPORT_BYTES * 2),
+        ];
+        assert_eq!(select_recent(entries), vec![PathBuf::from("huge")]);
+    }
+}
@@ -1,14 +1,93 @@
 use std::path::PathBuf;
 
+use tauri_plugin_store2::{ScopedStore, StorePluginExt};
+use tracing_subscriber::EnvFilter;
+
 pub trait TracingPluginExt<R: tauri::Runtime> {
     fn logs_dir(&self, bundle_id: impl Into<String>) -> Result<PathBuf, crate::Error>;
+    fn log_filter(&self) -> Result<String, crate::Error>;
+    fn set_log_filter(&self, directives: Option<String>) -> Result<(), crate::Error>;
+    fn export_logs(&self, dest: PathBuf) -> Result<PathBuf, crate::Error>;
+    fn purge_logs(&self) -> Result<u64, crate::Error>;
+
+    fn tracing_store(&self) -> ScopedStore<R, crate::StoreKey>;
+    fn json_logs(&self) -> Result<bool, crate::Error>;
+    fn set_json_logs(&self, enabled: bool) -> Result<(), crate::Error>;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> TracingPluginExt<R> for T {
     fn logs_dir(&self, bundle_id: impl Into<String>) -> Result<PathBuf, crate::Error> {
-        let base_dir = dirs::data_dir().unwrap();
-        let logs_dir = base_dir.join(bundle_id.into()).join("logs");
+        let logs_dir = match hypr_data_dir::from_env() {
+            Some(dir) => dir.join("logs"),
+            None => dirs::data_dir()
+                .unwrap()
+                .join(bundle_id.into())
+                .join("logs"),
+        };
         let _ = std::fs::create_dir_all(&logs_dir);
         Ok(logs_dir)
     }
+
+    fn log_filter(&self) -> Result<String, crate::Error> {
+        let handle = self.state::<crate::FilterHandle>();
+        Ok(handle.with_current(|filter| filter.to_string())?)
+    }
+
+    fn set_log_filter(&self, directives: Option<String>) -> Result<(), crate::Error> {
+        let filter = match directives {
+            Some(directives) => EnvFilter::try_new(&directives)
+                .map_err(|e| crate::Error::InvalidFilter(e.to_string()))?,
+            None => crate::default_env_filter(),
+        };
+
+        let handle = self.state::<crate::FilterHandle>();
+        handle.reload(filter)?;
+
+        tracing::info!("log_filter_changed: {}", self.log_filter()?);
+        Ok(())
+    }
+
+    fn export_logs(&self, dest: PathBuf) -> Result<PathBuf, crate::Error> {
+        let logs_dir = self.logs_dir(self.config().identifier.clone())?;
+        let files = crate::export::recent_log_files(&logs_dir)?;
+
+        let info = crate::export::SystemInfo {
+            app_version: self.package_info().version.to_string(),
+            os: hypr_host::long_os_version(),
+            arch: hypr_host::cpu_arch(),
+            log_filter: self.log_filter()?,
+            exported_at: chrono::Utc::now().to_rfc3339(),
+        };
+
+        crate::export::write_bundle(&dest, &files, &info)?;
+        Ok(dest)
+    }
+
+    fn purge_logs(&self) -> Result<u64, crate::Error> {
+        let logs_dir = self.logs_dir(self.config().identifier.clone())?;
+        let freed = crate::rotation::purge(&logs_dir)?;
+
+        tracing::info!("logs_purged: {} bytes", freed);
+        Ok(freed)
+    }
+
+    fn tracing_store(&self) -> ScopedStore<R, crate::StoreKey> {
+        self.scoped_store(crate::PLUGIN_NAME).unwrap()
+    }
+
+    fn json_logs(&self) -> Result<bool, crate::Error> {
+        let enabled = self.tracing_store().get(crate::StoreKey::JsonLogs)?;
+        Ok(enabled.unwrap_or(false))
+    }
+
+    fn set_json_logs(&self, enabled: bool) -> Result<(), crate::Error> {
+        self.tracing_store()
+            .set(crate::StoreKey::JsonLogs, enabled)?;
+
+        let json_logs = self.state::<crate::JsonLogs>();
+        json_logs
+            .0
+            .store(enabled, std::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
 }
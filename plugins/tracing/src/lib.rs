@@ -1,26 +1,48 @@
 mod commands;
 mod errors;
+mod export;
 mod ext;
+mod rotation;
+mod store;
 
 pub use errors::*;
 pub use ext::*;
+pub use store::*;
 
-use std::path::PathBuf;
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 use tauri::Manager;
 
-use tracing_appender::{non_blocking::WorkerGuard, rolling};
+use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{
-    fmt, prelude::__tracing_subscriber_SubscriberExt, util::SubscriberInitExt, EnvFilter,
+    filter, fmt, prelude::__tracing_subscriber_SubscriberExt, reload, util::SubscriberInitExt,
+    EnvFilter, Layer, Registry,
 };
 
 const PLUGIN_NAME: &str = "tracing";
 
+pub(crate) type FilterHandle = reload::Handle<EnvFilter, Registry>;
+
+/// Whether file logs are written as JSON lines instead of plain text.
+pub(crate) struct JsonLogs(pub Arc<AtomicBool>);
+
 fn make_specta_builder() -> tauri_specta::Builder<tauri::Wry> {
     tauri_specta::Builder::<tauri::Wry>::new()
         .plugin_name(PLUGIN_NAME)
         .events(tauri_specta::collect_events![])
         .commands(tauri_specta::collect_commands![
-            commands::logs_dir::<tauri::Wry>
+            commands::logs_dir::<tauri::Wry>,
+            commands::get_log_filter::<tauri::Wry>,
+            commands::set_log_filter::<tauri::Wry>,
+            commands::export_logs::<tauri::Wry>,
+            commands::purge_logs::<tauri::Wry>,
+            commands::get_json_logs::<tauri::Wry>,
+            commands::set_json_logs::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
@@ -33,19 +55,41 @@ pub fn init() -> tauri::plugin::TauriPlugin<tauri::Wry> {
         .setup(move |app, _api| {
             specta_builder.mount_events(app);
 
-            let env_filter = EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| EnvFilter::new("info"))
-                .add_directive("ort=warn".parse().unwrap());
+            let (env_filter, filter_handle) = reload::Layer::new(default_env_filter());
+            app.manage::<FilterHandle>(filter_handle);
+
+            // Read from the store once it is up; until then file logs are plain text.
+            let json_logs = Arc::new(AtomicBool::new(false));
+            app.manage(JsonLogs(json_logs.clone()));
 
             if let Some((file_writer, guard)) = make_file_writer_if_enabled(
                 true,
                 &app.logs_dir(app.config().identifier.clone()).unwrap(),
             ) {
+                let text_logs = json_logs.clone();
+
                 tracing_subscriber::Registry::default()
                     .with(env_filter)
                     .with(tauri_plugin_sentry::sentry::integrations::tracing::layer())
                     .with(fmt::layer())
-                    .with(fmt::layer().with_ansi(false).with_writer(file_writer))
+                    .with(
+                        fmt::layer()
+                            .with_ansi(false)
+                            .with_writer(file_writer.clone())
+                            .with_filter(filter::filter_fn(move |_| {
+                                !text_logs.load(Ordering::Relaxed)
+                            })),
+                    )
+                    .with(
+                        fmt::layer()
+                            .json()
+                            .with_current_span(true)
+                            .with_span_list(true)
+                            .with_writer(file_writer)
+                            .with_filter(filter::filter_fn(move |_| {
+                                json_logs.load(Ordering::Relaxed)
+                            })),
+                    )
                     .init();
                 assert!(app.manage(guard));
             } else {
@@ -58,9 +102,21 @@ pub fn init() -> tauri::plugin::TauriPlugin<tauri::Wry> {
 
             Ok(())
         })
+        .on_event(|app, event| {
+            if let tauri::RunEvent::Ready = event {
+                let enabled = app.json_logs().unwrap_or(false);
+                app.state::<JsonLogs>().0.store(enabled, Ordering::Relaxed);
+            }
+        })
         .build()
 }
 
+pub(crate) fn default_env_filter() -> EnvFilter {
+    EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new("info"))
+        .add_directive("ort=warn".parse().unwrap())
+}
+
 fn make_file_writer_if_enabled(
     enabled: bool,
     logs_dir: &PathBuf,
@@ -69,7 +125,10 @@ fn make_file_writer_if_enabled(
         return None;
     }
 
-    let file_appender = rolling::daily(logs_dir, "log");
+    let policy = rotation::RetentionPolicy::default();
+    let file_appender = rotation::RotatingFileWriter::new(logs_dir, &policy).ok()?;
+    rotation::spawn_pruner(logs_dir.clone(), policy);
+
     let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
     Some((non_blocking, guard))
 }
@@ -0,0 +1,244 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use chrono::{NaiveDate, Utc};
+
+const PREFIX: &str = "log";
+
+#[derive(Debug, Clone, Copy)]
+pub struct RetentionPolicy {
+    /// A new file is started once the current one would grow past this.
+    pub max_file_bytes: u64,
+    pub max_total_bytes: u64,
+    pub max_age: Duration,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            max_file_bytes: 20 * 1024 * 1024,
+            max_total_bytes: 200 * 1024 * 1024,
+            max_age: Duration::from_secs(14 * 24 * 60 * 60),
+        }
+    }
+}
+
+/// Writes `log.<date>`, `log.<date>.1`, ... starting a new file every day
+/// and whenever the current one reaches the size limit.
+pub struct RotatingFileWriter {
+    dir: PathBuf,
+    max_file_bytes: u64,
+    date: NaiveDate,
+    index: u32,
+    written: u64,
+    file: File,
+}
+
+impl RotatingFileWriter {
+    pub fn new(dir: impl Into<PathBuf>, policy: &RetentionPolicy) -> std::io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+
+        let date = Utc::now().date_naive();
+        // Keep appending to today's last file after a restart.
+        let index = (0..)
+            .take_while(|i| dir.join(file_name(date, *i)).exists())
+            .last()
+            .unwrap_or(0);
+        let (file, written) = open(&dir, date, index)?;
+
+        Ok(Self {
+            dir,
+            max_file_bytes: policy.max_file_bytes,
+            date,
+            index,
+            written,
+            file,
+        })
+    }
+
+    fn rotate_if_needed(&mut self, incoming: usize) -> std::io::Result<()> {
+        let today = Utc::now().date_naive();
+
+        let (date, index) = if today != self.date {
+            (today, 0)
+        } else if self.written > 0 && self.written + incoming as u64 > self.max_file_bytes {
+            (self.date, self.index + 1)
+        } else {
+            return Ok(());
+        };
+
+        self.file.flush()?;
+        let (file, written) = open(&self.dir, date, index)?;
+        self.file = file;
+        self.written = written;
+        self.date = date;
+        self.index = index;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.rotate_if_needed(buf.len())?;
+        let n = self.file.write(buf)?;
+        self.written += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn file_name(date: NaiveDate, index: u32) -> String {
+    match index {
+        0 => format!("{}.{}", PREFIX, date.format("%Y-%m-%d")),
+        i => format!("{}.{}.{}", PREFIX, date.format("%Y-%m-%d"), i),
+    }
+}
+
+fn open(dir: &Path, date: NaiveDate, index: u32) -> std::io::Result<(File, u64)> {
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join(file_name(date, index)))?;
+    let len = file.metadata()?.len();
+    Ok((file, len))
+}
+
+/// Log files in `dir`, newest first.
+fn log_files(dir: &Path) -> std::io::Result<Vec<(PathBuf, SystemTime, u64)>> {
+    let mut files = Vec::new();
+
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let is_log = entry
+            .file_name()
+            .to_str()
+            .is_some_and(|name| name.starts_with(&format!("{}.", PREFIX)));
+        let metadata = entry.metadata()?;
+
+        if is_log && metadata.is_file() {
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            files.push((entry.path(), modified, metadata.len()));
+        }
+    }
+
+    files.sort_by(|a, b| b.1.cmp(&a.1));
+    Ok(files)
+}
+
+/// Deletes files past `max_age`, then the oldest ones until the total fits in
+/// `max_total_bytes`. The newest file is the one being written and is never removed.
+/// Returns the number of bytes freed.
+pub fn prune(dir: &Path, policy: &RetentionPolicy, now: SystemTime) -> std::io::Result<u64> {
+    let files = log_files(dir)?;
+    let Some(((_, _, newest_size), older)) = files.split_first() else {
+        return Ok(0);
+    };
+
+    let mut total = *newest_size;
+    let mut freed = 0;
+
+    for (path, modified, size) in older {
+        let expired = now
+            .duration_since(*modified)
+            .is_ok_and(|age| age > policy.max_age);
+
+        if expired || total + size > policy.max_total_bytes {
+            std::fs::remove_file(path)?;
+            freed += size;
+        } else {
+            total += size;
+        }
+    }
+
+    Ok(freed)
+}
+
+/// Deletes every log file except the one being written. Returns the number of bytes freed.
+pub fn purge(dir: &Path) -> std::io::Result<u64> {
+    let mut freed = 0;
+
+    for (path, _, size) in log_files(dir)?.into_iter().skip(1) {
+        std::fs::remove_file(path)?;
+        freed += size;
+    }
+
+    Ok(freed)
+}
+
+pub fn spawn_pruner(dir: PathBuf, policy: RetentionPolicy) {
+    std::thread::spawn(move || loop {
+        match prune(&dir, &policy, SystemTime::now()) {
+            Ok(0) => {}
+            Ok(freed) => tracing::info!("logs_pruned: {} bytes", freed),
+            Err(e) => tracing::warn!("logs_prune_failed: {}", e),
+        }
+        std::thread::sleep(Duration::from_secs(60 * 60));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(path: &Path, size: u64, modified: SystemTime) {
+        let file = File::create(path).unwrap();
+        file.set_len(size).unwrap();
+        file.set_modified(modified).unwrap();
+    }
+
+    #[test]
+    fn test_rotates_by_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let policy = RetentionPolicy {
+            max_file_bytes: 10,
+            ..Default::default()
+        };
+
+        let mut writer = RotatingFileWriter::new(dir.path(), &policy).unwrap();
+        writer.write_all(b"12345678").unwrap();
+        writer.write_all(b"12345678").unwrap();
+        writer.flush().unwrap();
+
+        assert_eq!(log_files(dir.path()).unwrap().len(), 2);
+
+        // A restart picks up the latest file rather than starting over at the first.
+        let writer = RotatingFileWriter::new(dir.path(), &policy).unwrap();
+        assert_eq!(writer.index, 1);
+    }
+
+    #[test]
+    fn test_prune() {
+        let dir = tempfile::tempdir().unwrap();
+        let now = SystemTime::now();
+        for (name, minutes_ago) in [("log.a", 3), ("log.b", 2), ("log.c", 1), ("other", 4)] {
+            write(
+                &dir.path().join(name),
+                40,
+                now - Duration::from_secs(minutes_ago * 60),
+            );
+        }
+
+        let policy = RetentionPolicy {
+            max_file_bytes: 40,
+            max_total_bytes: 100,
+            max_age: Duration::from_secs(3600),
+        };
+        assert_eq!(prune(dir.path(), &policy, now).unwrap(), 40);
+        assert!(!dir.path().join("log.a").exists());
+        assert!(dir.path().join("other").exists());
+
+        let later = now + Duration::from_secs(7200);
+        assert_eq!(prune(dir.path(), &policy, later).unwrap(), 40);
+        assert!(dir.path().join("log.c").exists());
+
+        assert_eq!(purge(dir.path()).unwrap(), 0);
+    }
+}
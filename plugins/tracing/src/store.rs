@@ -0,0 +1,8 @@
+use tauri_plugin_store2::ScopedStoreKey;
+
+#[derive(serde::Deserialize, specta::Type, PartialEq, Eq, Hash, strum::Display)]
+pub enum StoreKey {
+    JsonLogs,
+}
+
+impl ScopedStoreKey for StoreKey {}
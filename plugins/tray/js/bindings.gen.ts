@@ -13,6 +13,11 @@ export const commands = {
 /** user-defined events **/
 
 
+export const events = __makeEvents__<{
+trayEvent: TrayEvent
+}>({
+trayEvent: "plugin:tray:tray-event"
+})
 
 /** user-defined constants **/
 
@@ -20,7 +25,16 @@ export const commands = {
 
 /** user-defined types **/
 
-
+/**
+ * Controls picked from the tray or the macOS menu bar.
+ * 
+ * The listener plugin and the app subscribe to these, since the tray cannot depend on them.
+ */
+export type TrayEvent = { type: "stopRecording" } | { type: "pauseRecording" } | { type: "resumeRecording" } | { type: "setMicMuted"; value: boolean } | 
+/**
+ * Restart into another profile.
+ */
+{ type: "switchProfile"; id: string }
 
 /** tauri-specta globals **/
 
@@ -0,0 +1,37 @@
+# Tray menu
+tray-open = Open Hyprnote
+tray-start = Start a new recording
+tray-join = Join & record “{ $name }”
+tray-wake-listening = Listening for “{ $phrase }”
+tray-open-note = Open live note
+tray-pause = Pause recording
+tray-resume = Resume recording
+tray-mute = Mute microphone
+tray-stop = Stop recording
+tray-recent = Recent notes
+tray-profiles = Profiles
+tray-quit = Quit Completely
+
+# App menu (macOS)
+app-recording = Recording
+app-info = About Hyprnote
+app-new = New Note
+
+# About dialog
+about-copy = Copy
+about-cancel = Cancel
+
+# Tray tooltip while recording
+status-recording = Recording
+status-paused = Paused
+tooltip = { $status } — { $elapsed }
+tooltip-word = { $status } — { $elapsed } · 1 word
+tooltip-words = { $status } — { $elapsed } · { $count } words
+
+# Tray tooltip after a failure
+tooltip-error = Hyprnote — { $message }
+
+# Tray tooltip while listening for the wake phrase
+tooltip-wake-listening = Hyprnote — listening for “{ $phrase }”
+
+recent-untitled = Untitled
@@ -0,0 +1,37 @@
+# Tray menu
+tray-open = Hyprnote 열기
+tray-start = 새 녹음 시작
+tray-join = “{ $name }” 참여 및 녹음
+tray-wake-listening = “{ $phrase }” 듣는 중
+tray-open-note = 실시간 노트 열기
+tray-pause = 녹음 일시정지
+tray-resume = 녹음 재개
+tray-mute = 마이크 음소거
+tray-stop = 녹음 중지
+tray-recent = 최근 노트
+tray-profiles = 프로필
+tray-quit = 완전히 종료
+
+# App menu (macOS)
+app-recording = 녹음
+app-info = Hyprnote 정보
+app-new = 새 노트
+
+# About dialog
+about-copy = 복사
+about-cancel = 취소
+
+# Tray tooltip while recording
+status-recording = 녹음 중
+status-paused = 일시정지됨
+tooltip = { $status } — { $elapsed }
+tooltip-word = { $status } — { $elapsed } · 1단어
+tooltip-words = { $status } — { $elapsed } · { $count }단어
+
+# Tray tooltip after a failure
+tooltip-error = Hyprnote — { $message }
+
+# Tray tooltip while listening for the wake phrase
+tooltip-wake-listening = Hyprnote — “{ $phrase }” 듣는 중
+
+recent-untitled = 제목 없음
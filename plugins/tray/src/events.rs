@@ -0,0 +1,18 @@
+/// Controls picked from the tray or the macOS menu bar.
+///
+/// The listener plugin and the app subscribe to these, since the tray cannot depend on them.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type, tauri_specta::Event)]
+#[serde(tag = "type")]
+pub enum TrayEvent {
+    #[serde(rename = "stopRecording")]
+    StopRecording {},
+    #[serde(rename = "pauseRecording")]
+    PauseRecording {},
+    #[serde(rename = "resumeRecording")]
+    ResumeRecording {},
+    #[serde(rename = "setMicMuted")]
+    SetMicMuted { value: bool },
+    /// Restart into another profile.
+    #[serde(rename = "switchProfile")]
+    SwitchProfile { id: String },
+}
@@ -1,20 +1,51 @@
 use tauri::{
     image::Image,
-    menu::{Menu, MenuId, MenuItem, MenuItemKind, PredefinedMenuItem},
+    menu::{
+        CheckMenuItem, IsMenuItem, Menu, MenuId, MenuItem, MenuItemKind, PredefinedMenuItem,
+        Submenu,
+    },
     tray::TrayIconBuilder,
-    AppHandle, Result,
+    AppHandle, Manager, Result,
 };
+use tauri_specta::Event;
 
+use hypr_data_dir::profile::Profiles;
 use tauri_plugin_clipboard_manager::ClipboardExt;
 use tauri_plugin_dialog::{DialogExt, MessageDialogButtons};
 use tauri_plugin_local_stt::LocalSttPluginExt;
 use tauri_plugin_misc::MiscPluginExt;
 
-const TRAY_ID: &str = "hypr-tray";
+use crate::{
+    i18n::{t, t_args},
+    TrayEvent,
+};
+
+pub(crate) const TRAY_ID: &str = "hypr-tray";
+const RECORDING_SUBMENU_ID: &str = "hypr_app_recording";
+const PROFILES_SUBMENU_ID: &str = "hypr_app_profiles";
+const RECENT_NOTE_ID_PREFIX: &str = "hypr_tray_recent_";
+const JOIN_EVENT_ID_PREFIX: &str = "hypr_tray_join_";
+const PROFILE_ID_PREFIX: &str = "hypr_tray_profile_";
+
+/// The recording the tray menu offers controls for.
+#[derive(Debug, Clone)]
+pub struct TraySession {
+    pub session_id: String,
+    pub paused: bool,
+    pub mic_muted: bool,
+}
 
 pub enum HyprMenuItem {
     TrayOpen,
     TrayStart,
+    TrayStop,
+    TrayPause,
+    TrayMute,
+    TrayOpenNote,
+    TrayRecentNote(String),
+    TrayJoin(String),
+    TrayProfile(String),
+    TrayWakeListening,
     TrayQuit,
     AppInfo,
     AppNew,
@@ -25,6 +56,20 @@ impl From<HyprMenuItem> for MenuId {
         match value {
             HyprMenuItem::TrayOpen => "hypr_tray_open",
             HyprMenuItem::TrayStart => "hypr_tray_start",
+            HyprMenuItem::TrayStop => "hypr_tray_stop",
+            HyprMenuItem::TrayPause => "hypr_tray_pause",
+            HyprMenuItem::TrayMute => "hypr_tray_mute",
+            HyprMenuItem::TrayOpenNote => "hypr_tray_open_note",
+            HyprMenuItem::TrayRecentNote(id) => {
+                return format!("{}{}", RECENT_NOTE_ID_PREFIX, id).into()
+            }
+            HyprMenuItem::TrayJoin(event_id) => {
+                return format!("{}{}", JOIN_EVENT_ID_PREFIX, event_id).into()
+            }
+            HyprMenuItem::TrayProfile(profile_id) => {
+                return format!("{}{}", PROFILE_ID_PREFIX, profile_id).into()
+            }
+            HyprMenuItem::TrayWakeListening => "hypr_tray_wake_listening",
             HyprMenuItem::TrayQuit => "hypr_tray_quit",
             HyprMenuItem::AppInfo => "hypr_app_info",
             HyprMenuItem::AppNew => "hypr_app_new",
@@ -39,10 +84,25 @@ impl From<MenuId> for HyprMenuItem {
         match id {
             "hypr_tray_open" => HyprMenuItem::TrayOpen,
             "hypr_tray_start" => HyprMenuItem::TrayStart,
+            "hypr_tray_stop" => HyprMenuItem::TrayStop,
+            "hypr_tray_pause" => HyprMenuItem::TrayPause,
+            "hypr_tray_mute" => HyprMenuItem::TrayMute,
+            "hypr_tray_open_note" => HyprMenuItem::TrayOpenNote,
+            "hypr_tray_wake_listening" => HyprMenuItem::TrayWakeListening,
             "hypr_tray_quit" => HyprMenuItem::TrayQuit,
             "hypr_app_info" => HyprMenuItem::AppInfo,
             "hypr_app_new" => HyprMenuItem::AppNew,
-            _ => unreachable!(),
+            _ => {
+                if let Some(note_id) = id.strip_prefix(RECENT_NOTE_ID_PREFIX) {
+                    HyprMenuItem::TrayRecentNote(note_id.to_string())
+                } else if let Some(event_id) = id.strip_prefix(JOIN_EVENT_ID_PREFIX) {
+                    HyprMenuItem::TrayJoin(event_id.to_string())
+                } else if let Some(profile_id) = id.strip_prefix(PROFILE_ID_PREFIX) {
+                    HyprMenuItem::TrayProfile(profile_id.to_string())
+                } else {
+                    unreachable!()
+                }
+            }
         }
     }
 }
@@ -50,7 +110,15 @@ impl From<MenuId> for HyprMenuItem {
 pub trait TrayPluginExt<R: tauri::Runtime> {
     fn create_app_menu(&self) -> Result<()>;
     fn create_tray_menu(&self) -> Result<()>;
-    fn set_start_disabled(&self, disabled: bool) -> Result<()>;
+    fn set_session(&self, session: Option<TraySession>) -> Result<()>;
+    fn set_recording(&self, recording: bool) -> Result<()>;
+    fn add_recorded_words(&self, count: usize);
+    fn flash_error(&self, message: impl Into<String>);
+    /// Shows that the mic is listened to for `phrase` while nothing is recording.
+    fn set_wake_phrase(&self, phrase: Option<String>) -> Result<()>;
+    /// Re-lists profiles after one is created or deleted.
+    fn refresh_profiles(&self) -> Result<()>;
+    fn refresh_locale(&self) -> Result<()>;
 }
 
 impl<T: tauri::Manager<tauri::Wry>> TrayPluginExt<tauri::Wry> for T {
@@ -62,6 +130,18 @@ impl<T: tauri::Manager<tauri::Wry>> TrayPluginExt<tauri::Wry> for T {
 
         if cfg!(target_os = "macos") {
             if let Some(menu) = app.menu() {
+                let recording =
+                    Submenu::with_id(app, RECORDING_SUBMENU_ID, t("app-recording"), true)?;
+                for item in session_menu_items(app, None)? {
+                    recording.append(item.as_ref())?;
+                }
+                // Ahead of the trailing Window and Help menus.
+                let position = menu.items()?.len().saturating_sub(2);
+                menu.insert(&recording, position)?;
+                if let Some(profiles) = profiles_menu(app)? {
+                    menu.insert(&profiles, position + 1)?;
+                }
+
                 let items = menu.items()?;
 
                 if items.len() > 0 {
@@ -87,21 +167,14 @@ impl<T: tauri::Manager<tauri::Wry>> TrayPluginExt<tauri::Wry> for T {
     fn create_tray_menu(&self) -> Result<()> {
         let app = self.app_handle();
 
-        let menu = Menu::with_items(
-            app,
-            &[
-                &tray_open_menu(app)?,
-                &tray_start_menu(app, false)?,
-                &PredefinedMenuItem::separator(app)?,
-                &tray_quit_menu(app)?,
-            ],
-        )?;
+        let menu = tray_menu(app)?;
 
         TrayIconBuilder::with_id(TRAY_ID)
             .icon(Image::from_bytes(include_bytes!(
                 "../icons/tray_default.png"
             ))?)
             .icon_as_template(true)
+            .tooltip("Hyprnote")
             .menu(&menu)
             .show_menu_on_left_click(true)
             .on_menu_event({
@@ -127,8 +200,74 @@ impl<T: tauri::Manager<tauri::Wry>> TrayPluginExt<tauri::Wry> for T {
                             );
                         }
                     }
+                    HyprMenuItem::TrayStop => {
+                        let _ = TrayEvent::StopRecording {}.emit(app);
+                    }
+                    HyprMenuItem::TrayPause => {
+                        let event = match current_session(app) {
+                            Some(session) if session.paused => TrayEvent::ResumeRecording {},
+                            _ => TrayEvent::PauseRecording {},
+                        };
+                        let _ = event.emit(app);
+                    }
+                    HyprMenuItem::TrayMute => {
+                        let muted = current_session(app).is_some_and(|s| s.mic_muted);
+                        let _ = TrayEvent::SetMicMuted { value: !muted }.emit(app);
+                    }
+                    HyprMenuItem::TrayOpenNote => {
+                        use tauri_plugin_windows::{HyprWindow, Navigate, WindowsPluginExt};
+                        if let Some(session) = current_session(app) {
+                            if let Ok(_) = app.window_show(HyprWindow::Main) {
+                                let _ = app.window_emit_navigate(
+                                    HyprWindow::Main,
+                                    Navigate {
+                                        path: format!("/app/note/{}", session.session_id),
+                                        search: None,
+                                    },
+                                );
+                            }
+                        }
+                    }
+                    HyprMenuItem::TrayRecentNote(id) => {
+                        use tauri_plugin_windows::HyprWindow;
+                        let _ = HyprWindow::Note(id).show(app);
+                    }
+                    HyprMenuItem::TrayJoin(event_id) => {
+                        use tauri_plugin_windows::{HyprWindow, Navigate, WindowsPluginExt};
+                        // The new note opens the event's meeting link as it starts recording.
+                        if let Ok(_) = app.window_show(HyprWindow::Main) {
+                            let _ = app.window_emit_navigate(
+                                HyprWindow::Main,
+                                Navigate {
+                                    path: "/app/new".to_string(),
+                                    search: Some(
+                                        serde_json::json!({
+                                            "record": true,
+                                            "event_id": event_id,
+                                            "join": true,
+                                        })
+                                        .as_object()
+                                        .cloned()
+                                        .unwrap(),
+                                    ),
+                                },
+                            );
+                        }
+                    }
+                    HyprMenuItem::TrayProfile(profile_id) => {
+                        // Restores the check mark the click toggled, until the app restarts.
+                        let _ = app.refresh_profiles();
+                        let _ = TrayEvent::SwitchProfile { id: profile_id }.emit(app);
+                    }
+                    HyprMenuItem::TrayWakeListening => {}
                     HyprMenuItem::TrayQuit => {
-                        app.exit(0);
+                        // Asking blocks, and the menu event arrives on the main thread.
+                        let app = app.clone();
+                        std::thread::spawn(move || {
+                            if hypr_intercept::confirm_quit() {
+                                app.exit(0);
+                            }
+                        });
                     }
                     HyprMenuItem::AppInfo => {
                         let app_name = app.package_info().name.clone();
@@ -151,10 +290,10 @@ impl<T: tauri::Manager<tauri::Wry>> TrayPluginExt<tauri::Wry> for T {
 
                         app.dialog()
                             .message(&message)
-                            .title("About Hyprnote")
+                            .title(t("app-info"))
                             .buttons(MessageDialogButtons::OkCancelCustom(
-                                "Copy".to_string(),
-                                "Cancel".to_string(),
+                                t("about-copy"),
+                                t("about-cancel"),
                             ))
                             .show(move |result| {
                                 if result {
@@ -178,35 +317,289 @@ impl<T: tauri::Manager<tauri::Wry>> TrayPluginExt<tauri::Wry> for T {
             })
             .build(app)?;
 
+        crate::recent::refresh(app);
+        tauri_plugin_db::SessionChanged::listen_any(app, {
+            let app = app.clone();
+            move |_| crate::recent::refresh(&app)
+        });
+        crate::upcoming::start(app);
+
         Ok(())
     }
 
-    fn set_start_disabled(&self, disabled: bool) -> Result<()> {
+    fn set_session(&self, session: Option<TraySession>) -> Result<()> {
         let app = self.app_handle();
+        app.state::<crate::ManagedState>().lock().unwrap().session = session.clone();
 
-        if let Some(tray) = app.tray_by_id(TRAY_ID) {
-            let menu = Menu::with_items(
-                app,
-                &[
-                    &tray_open_menu(app)?,
-                    &tray_start_menu(app, disabled)?,
-                    &PredefinedMenuItem::separator(app)?,
-                    &tray_quit_menu(app)?,
-                ],
-            )?;
-
-            tray.set_menu(Some(menu))?;
+        refresh_tray_menu(app)?;
+
+        if let Some(MenuItemKind::Submenu(recording)) =
+            app.menu().and_then(|menu| menu.get(RECORDING_SUBMENU_ID))
+        {
+            for item in recording.items()? {
+                recording.remove(&item)?;
+            }
+            for item in session_menu_items(app, session.as_ref())? {
+                recording.append(item.as_ref())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn set_recording(&self, recording: bool) -> Result<()> {
+        if recording {
+            crate::indicator::start(self.app_handle())
+        } else {
+            crate::indicator::stop(self.app_handle())
+        }
+    }
+
+    fn add_recorded_words(&self, count: usize) {
+        crate::indicator::add_words(self.app_handle(), count);
+    }
+
+    fn flash_error(&self, message: impl Into<String>) {
+        crate::indicator::flash_error(self.app_handle(), message);
+    }
+
+    fn set_wake_phrase(&self, phrase: Option<String>) -> Result<()> {
+        let app = self.app_handle();
+        app.state::<crate::ManagedState>()
+            .lock()
+            .unwrap()
+            .wake_phrase = phrase;
+
+        self.set_session(current_session(app))?;
+        crate::indicator::refresh_idle(app)
+    }
+
+    fn refresh_profiles(&self) -> Result<()> {
+        let app = self.app_handle();
+        refresh_tray_menu(app)?;
+
+        if !cfg!(target_os = "macos") {
+            return Ok(());
+        }
+        let Some(menu) = app.menu() else {
+            return Ok(());
+        };
+
+        if let Some(item) = menu.get(PROFILES_SUBMENU_ID) {
+            menu.remove(&item)?;
+        }
+        if let Some(profiles) = profiles_menu(app)? {
+            let position = menu
+                .items()?
+                .iter()
+                .position(|item| item.id().0 == RECORDING_SUBMENU_ID)
+                .map_or(menu.items()?.len().saturating_sub(2), |i| i + 1);
+            menu.insert(&profiles, position)?;
         }
 
         Ok(())
     }
+
+    /// Re-renders every menu after `hypr_i18n::set_locale`.
+    fn refresh_locale(&self) -> Result<()> {
+        let app = self.app_handle();
+
+        // Recent notes carry a translated fallback title, so they are reloaded as well.
+        app.state::<crate::ManagedState>()
+            .lock()
+            .unwrap()
+            .recent
+            .clear();
+        crate::recent::refresh(app);
+
+        self.set_session(current_session(app))?;
+
+        let Some(menu) = app.menu() else {
+            return Ok(());
+        };
+
+        for item in menu.items()? {
+            let MenuItemKind::Submenu(submenu) = item else {
+                continue;
+            };
+
+            if submenu.id().0 == RECORDING_SUBMENU_ID {
+                submenu.set_text(t("app-recording"))?;
+            }
+            if submenu.id().0 == PROFILES_SUBMENU_ID {
+                submenu.set_text(t("tray-profiles"))?;
+            }
+
+            for (id, text) in [
+                (HyprMenuItem::AppInfo, t("app-info")),
+                (HyprMenuItem::AppNew, t("app-new")),
+            ] {
+                let id = MenuId::from(id);
+                if let Some(MenuItemKind::MenuItem(item)) = submenu.get(id.0.as_str()) {
+                    item.set_text(text)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn current_session<R: tauri::Runtime>(app: &AppHandle<R>) -> Option<TraySession> {
+    app.state::<crate::ManagedState>()
+        .lock()
+        .unwrap()
+        .session
+        .clone()
+}
+
+pub(crate) fn wake_phrase<R: tauri::Runtime>(app: &AppHandle<R>) -> Option<String> {
+    app.state::<crate::ManagedState>()
+        .lock()
+        .unwrap()
+        .wake_phrase
+        .clone()
+}
+
+pub(crate) fn refresh_tray_menu<R: tauri::Runtime>(app: &AppHandle<R>) -> Result<()> {
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        tray.set_menu(Some(tray_menu(app)?))?;
+    }
+    Ok(())
+}
+
+fn tray_menu<R: tauri::Runtime>(app: &AppHandle<R>) -> Result<Menu<R>> {
+    let (session, recent, upcoming) = {
+        let state = app.state::<crate::ManagedState>();
+        let guard = state.lock().unwrap();
+        (
+            guard.session.clone(),
+            guard.recent.clone(),
+            guard.upcoming.clone(),
+        )
+    };
+
+    let menu = Menu::with_items(app, &[&tray_open_menu(app)?])?;
+    for item in session_menu_items(app, session.as_ref())? {
+        menu.append(item.as_ref())?;
+    }
+    if let (None, Some(meeting)) = (&session, &upcoming) {
+        menu.append(&tray_join_menu(app, meeting)?)?;
+    }
+    menu.append(&tray_recent_menu(app, &recent)?)?;
+    if let Some(profiles) = profiles_menu(app)? {
+        menu.append(&profiles)?;
+    }
+    menu.append(&PredefinedMenuItem::separator(app)?)?;
+    menu.append(&tray_quit_menu(app)?)?;
+    Ok(menu)
+}
+
+/// Start, and whether a wake phrase is listened for, while idle; open, pause, mute and stop
+/// while a session is running.
+fn session_menu_items<R: tauri::Runtime>(
+    app: &AppHandle<R>,
+    session: Option<&TraySession>,
+) -> Result<Vec<Box<dyn IsMenuItem<R>>>> {
+    let Some(session) = session else {
+        let mut items: Vec<Box<dyn IsMenuItem<R>>> = vec![Box::new(tray_start_menu(app)?)];
+        if let Some(phrase) = wake_phrase(app) {
+            items.push(Box::new(MenuItem::with_id(
+                app,
+                HyprMenuItem::TrayWakeListening,
+                t_args("tray-wake-listening", &[("phrase", &phrase)]),
+                false,
+                None::<&str>,
+            )?));
+        }
+        return Ok(items);
+    };
+
+    Ok(vec![
+        Box::new(MenuItem::with_id(
+            app,
+            HyprMenuItem::TrayOpenNote,
+            t("tray-open-note"),
+            true,
+            None::<&str>,
+        )?),
+        Box::new(MenuItem::with_id(
+            app,
+            HyprMenuItem::TrayPause,
+            t(if session.paused {
+                "tray-resume"
+            } else {
+                "tray-pause"
+            }),
+            true,
+            None::<&str>,
+        )?),
+        Box::new(CheckMenuItem::with_id(
+            app,
+            HyprMenuItem::TrayMute,
+            t("tray-mute"),
+            true,
+            session.mic_muted,
+            None::<&str>,
+        )?),
+        Box::new(MenuItem::with_id(
+            app,
+            HyprMenuItem::TrayStop,
+            t("tray-stop"),
+            true,
+            None::<&str>,
+        )?),
+    ])
+}
+
+fn tray_recent_menu<R: tauri::Runtime>(
+    app: &AppHandle<R>,
+    notes: &[crate::recent::RecentNote],
+) -> Result<Submenu<R>> {
+    let submenu = Submenu::new(app, t("tray-recent"), !notes.is_empty())?;
+    for note in notes {
+        submenu.append(&MenuItem::with_id(
+            app,
+            HyprMenuItem::TrayRecentNote(note.id.clone()),
+            &note.title,
+            true,
+            None::<&str>,
+        )?)?;
+    }
+    Ok(submenu)
+}
+
+/// Only shown once there is a profile to switch to.
+fn profiles_menu<R: tauri::Runtime>(app: &AppHandle<R>) -> Result<Option<Submenu<R>>> {
+    let profiles = hypr_data_dir::resolve_shared(|| app.path().app_data_dir())
+        .ok()
+        .and_then(|root| Profiles::load(&root))
+        .filter(|profiles| profiles.profiles.len() > 1);
+
+    let Some(profiles) = profiles else {
+        return Ok(None);
+    };
+
+    let active = profiles.active().id;
+    let submenu = Submenu::with_id(app, PROFILES_SUBMENU_ID, t("tray-profiles"), true)?;
+    for profile in &profiles.profiles {
+        submenu.append(&CheckMenuItem::with_id(
+            app,
+            HyprMenuItem::TrayProfile(profile.id.clone()),
+            &profile.name,
+            true,
+            profile.id == active,
+            None::<&str>,
+        )?)?;
+    }
+    Ok(Some(submenu))
 }
 
 fn app_info_menu<R: tauri::Runtime>(app: &AppHandle<R>) -> Result<MenuItem<R>> {
     MenuItem::with_id(
         app,
         HyprMenuItem::AppInfo,
-        "About Hyprnote",
+        t("app-info"),
         true,
         None::<&str>,
     )
@@ -216,7 +609,7 @@ fn app_new_menu<R: tauri::Runtime>(app: &AppHandle<R>) -> Result<MenuItem<R>> {
     MenuItem::with_id(
         app,
         HyprMenuItem::AppNew,
-        "New Note",
+        t("app-new"),
         true,
         Some("CmdOrCtrl+N"),
     )
@@ -226,18 +619,31 @@ fn tray_open_menu<R: tauri::Runtime>(app: &AppHandle<R>) -> Result<MenuItem<R>>
     MenuItem::with_id(
         app,
         HyprMenuItem::TrayOpen,
-        "Open Hyprnote",
+        t("tray-open"),
         true,
         None::<&str>,
     )
 }
 
-fn tray_start_menu<R: tauri::Runtime>(app: &AppHandle<R>, disabled: bool) -> Result<MenuItem<R>> {
+fn tray_start_menu<R: tauri::Runtime>(app: &AppHandle<R>) -> Result<MenuItem<R>> {
     MenuItem::with_id(
         app,
         HyprMenuItem::TrayStart,
-        "Start a new recording",
-        !disabled,
+        t("tray-start"),
+        true,
+        None::<&str>,
+    )
+}
+
+fn tray_join_menu<R: tauri::Runtime>(
+    app: &AppHandle<R>,
+    meeting: &crate::upcoming::UpcomingMeeting,
+) -> Result<MenuItem<R>> {
+    MenuItem::with_id(
+        app,
+        HyprMenuItem::TrayJoin(meeting.event_id.clone()),
+        t_args("tray-join", &[("name", &meeting.name)]),
+        true,
         None::<&str>,
     )
 }
@@ -246,7 +652,7 @@ fn tray_quit_menu<R: tauri::Runtime>(app: &AppHandle<R>) -> Result<MenuItem<R>>
     MenuItem::with_id(
         app,
         HyprMenuItem::TrayQuit,
-        "Quit Completely",
+        t("tray-quit"),
         true,
         Some("cmd+q"),
     )
@@ -0,0 +1,214 @@
+use std::time::{Duration, Instant};
+
+use tauri::{image::Image, AppHandle, Manager};
+
+use crate::i18n::{t, t_args};
+
+const TICK: Duration = Duration::from_secs(1);
+const FLASH_INTERVAL: Duration = Duration::from_millis(400);
+const FLASH_COUNT: usize = 8;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Recording {
+    started_at: Instant,
+    words: usize,
+}
+
+fn default_icon() -> tauri::Result<Image<'static>> {
+    Image::from_bytes(include_bytes!("../icons/tray_default.png"))
+}
+
+fn recording_icon() -> tauri::Result<Image<'static>> {
+    Image::from_bytes(include_bytes!("../icons/tray_recording.png"))
+}
+
+pub fn start(app: &AppHandle) -> tauri::Result<()> {
+    let started_at = Instant::now();
+    {
+        let state = app.state::<crate::ManagedState>();
+        let mut guard = state.lock().unwrap();
+        guard.recording = Some(Recording {
+            started_at,
+            words: 0,
+        });
+    }
+
+    if let Some(tray) = app.tray_by_id(crate::ext::TRAY_ID) {
+        tray.set_icon(Some(recording_icon()?))?;
+        tray.set_icon_as_template(false)?;
+    }
+    render(app);
+
+    // Only the ticker of the current recording survives; a new start replaces it.
+    let app = app.clone();
+    std::thread::spawn(move || loop {
+        std::thread::sleep(TICK);
+
+        let current = app
+            .state::<crate::ManagedState>()
+            .lock()
+            .unwrap()
+            .recording
+            .map(|r| r.started_at);
+        if current != Some(started_at) {
+            break;
+        }
+        render(&app);
+    });
+
+    Ok(())
+}
+
+pub fn stop(app: &AppHandle) -> tauri::Result<()> {
+    app.state::<crate::ManagedState>().lock().unwrap().recording = None;
+
+    if let Some(tray) = app.tray_by_id(crate::ext::TRAY_ID) {
+        tray.set_icon(Some(default_icon()?))?;
+        tray.set_icon_as_template(true)?;
+        tray.set_title(None::<&str>)?;
+        tray.set_tooltip(Some(idle_tooltip(app)))?;
+    }
+
+    Ok(())
+}
+
+/// Updates the tooltip for a changed wake phrase, unless a recording owns it.
+pub fn refresh_idle(app: &AppHandle) -> tauri::Result<()> {
+    let recording = app
+        .state::<crate::ManagedState>()
+        .lock()
+        .unwrap()
+        .recording
+        .is_some();
+
+    if let (false, Some(tray)) = (recording, app.tray_by_id(crate::ext::TRAY_ID)) {
+        tray.set_tooltip(Some(idle_tooltip(app)))?;
+    }
+    Ok(())
+}
+
+fn idle_tooltip(app: &AppHandle) -> String {
+    match crate::ext::wake_phrase(app) {
+        Some(phrase) => t_args("tooltip-wake-listening", &[("phrase", &phrase)]),
+        None => "Hyprnote".to_string(),
+    }
+}
+
+pub fn add_words(app: &AppHandle, count: usize) {
+    if let Some(recording) = app
+        .state::<crate::ManagedState>()
+        .lock()
+        .unwrap()
+        .recording
+        .as_mut()
+    {
+        recording.words += count;
+    }
+}
+
+/// Blinks the icon and leaves `message` in the tooltip, so failures are noticed even with the
+/// main window hidden.
+pub fn flash_error(app: &AppHandle, message: impl Into<String>) {
+    let app = app.clone();
+    let message = message.into();
+
+    std::thread::spawn(move || {
+        let Some(tray) = app.tray_by_id(crate::ext::TRAY_ID) else {
+            return;
+        };
+        let (Ok(default), Ok(recording)) = (default_icon(), recording_icon()) else {
+            return;
+        };
+
+        let _ = tray.set_icon_as_template(false);
+        for i in 0..FLASH_COUNT {
+            let icon = if i % 2 == 0 { &recording } else { &default };
+            let _ = tray.set_icon(Some(icon.clone()));
+            std::thread::sleep(FLASH_INTERVAL);
+        }
+
+        let recording_now = app
+            .state::<crate::ManagedState>()
+            .lock()
+            .unwrap()
+            .recording
+            .is_some();
+        let _ = tray.set_icon(Some(if recording_now { recording } else { default }));
+        let _ = tray.set_icon_as_template(!recording_now);
+        let _ = tray.set_tooltip(Some(t_args("tooltip-error", &[("message", &message)])));
+    });
+}
+
+fn render(app: &AppHandle) {
+    let (recording, paused) = {
+        let state = app.state::<crate::ManagedState>();
+        let guard = state.lock().unwrap();
+        let paused = guard.session.as_ref().is_some_and(|s| s.paused);
+        (guard.recording, paused)
+    };
+    let Some(recording) = recording else {
+        return;
+    };
+    let Some(tray) = app.tray_by_id(crate::ext::TRAY_ID) else {
+        return;
+    };
+
+    let elapsed = format_elapsed(recording.started_at.elapsed());
+    let _ = tray.set_title(Some(&elapsed));
+    let _ = tray.set_tooltip(Some(tooltip(&elapsed, recording.words, paused)));
+}
+
+fn tooltip(elapsed: &str, words: usize, paused: bool) -> String {
+    let status = t(if paused {
+        "status-paused"
+    } else {
+        "status-recording"
+    });
+    let key = match words {
+        0 => "tooltip",
+        1 => "tooltip-word",
+        _ => "tooltip-words",
+    };
+
+    t_args(
+        key,
+        &[
+            ("status", &status),
+            ("elapsed", &elapsed),
+            ("count", &words),
+        ],
+    )
+}
+
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, (secs % 3600) / 60, secs % 60);
+
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_elapsed(Duration::from_secs(0)), "00:00");
+        assert_eq!(format_elapsed(Duration::from_secs(754)), "12:34");
+        assert_eq!(format_elapsed(Duration::from_secs(3723)), "1:02:03");
+    }
+
+    #[test]
+    fn test_tooltip() {
+        assert_eq!(tooltip("00:05", 0, false), "Recording — 00:05");
+        assert_eq!(
+            tooltip("12:34", 240, false),
+            "Recording — 12:34 · 240 words"
+        );
+        assert_eq!(tooltip("12:34", 1, true), "Paused — 12:34 · 1 word");
+    }
+}
@@ -1,12 +1,35 @@
+use std::sync::Mutex;
+use tauri::Manager;
+
+mod events;
 mod ext;
+mod i18n;
+mod indicator;
+mod recent;
+mod upcoming;
+
+pub use events::*;
 pub use ext::*;
 
 const PLUGIN_NAME: &str = "tray";
 
+pub type ManagedState = Mutex<State>;
+
+#[derive(Default)]
+pub struct State {
+    recording: Option<indicator::Recording>,
+    session: Option<TraySession>,
+    recent: Vec<recent::RecentNote>,
+    recent_refresh_pending: bool,
+    upcoming: Option<upcoming::UpcomingMeeting>,
+    wake_phrase: Option<String>,
+}
+
 fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
     tauri_specta::Builder::<R>::new()
         .plugin_name(PLUGIN_NAME)
         .commands(tauri_specta::collect_commands![])
+        .events(tauri_specta::collect_events![TrayEvent])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
 
@@ -15,6 +38,11 @@ pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
 
     tauri::plugin::Builder::new(PLUGIN_NAME)
         .invoke_handler(specta_builder.invoke_handler())
+        .setup(move |app, _api| {
+            specta_builder.mount_events(app);
+            app.manage(ManagedState::default());
+            Ok(())
+        })
         .build()
 }
 
@@ -0,0 +1,93 @@
+use std::time::Duration;
+
+use tauri::{AppHandle, Manager};
+use tauri_plugin_db::{DatabasePluginExt, UserDatabase};
+
+pub const RECENT_NOTES_LIMIT: u8 = 5;
+// Notes are upserted on every edit, so changes are batched before querying.
+const REFRESH_DELAY: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecentNote {
+    pub id: String,
+    pub title: String,
+}
+
+impl From<hypr_db_user::Session> for RecentNote {
+    fn from(session: hypr_db_user::Session) -> Self {
+        let title = session.title.trim();
+
+        Self {
+            title: if title.is_empty() {
+                crate::i18n::t("recent-untitled")
+            } else {
+                title.to_string()
+            },
+            id: session.id,
+        }
+    }
+}
+
+pub fn refresh(app: &AppHandle) {
+    {
+        let state = app.state::<crate::ManagedState>();
+        let mut guard = state.lock().unwrap();
+        if guard.recent_refresh_pending {
+            return;
+        }
+        guard.recent_refresh_pending = true;
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(REFRESH_DELAY).await;
+        let notes = load(&app).await;
+
+        let changed = {
+            let state = app.state::<crate::ManagedState>();
+            let mut guard = state.lock().unwrap();
+            guard.recent_refresh_pending = false;
+
+            match notes {
+                Ok(notes) if notes != guard.recent => {
+                    guard.recent = notes;
+                    true
+                }
+                _ => false,
+            }
+        };
+
+        if changed {
+            let _ = crate::ext::refresh_tray_menu(&app);
+        }
+    });
+}
+
+async fn load(app: &AppHandle) -> Result<Vec<RecentNote>, tauri_plugin_db::Error> {
+    let Some(user_id) = app.db_user_id().await? else {
+        return Ok(vec![]);
+    };
+
+    let hidden = [
+        UserDatabase::onboarding_session_id(),
+        UserDatabase::thank_you_session_id(),
+    ];
+
+    let sessions = app
+        .db_list_sessions(hypr_db_user::ListSessionFilter {
+            common: hypr_db_user::ListSessionFilterCommon {
+                user_id,
+                // Leave room for the hidden sessions.
+                limit: Some(RECENT_NOTES_LIMIT + hidden.len() as u8),
+            },
+            specific: hypr_db_user::ListSessionFilterSpecific::RecentlyVisited {},
+        })
+        .await?;
+
+    Ok(sessions
+        .into_iter()
+        .filter(|session| !hidden.contains(&session.id))
+        .take(RECENT_NOTES_LIMIT as usize)
+        .map(RecentNote::from)
+        .collect())
+}
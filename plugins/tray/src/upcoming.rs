@@ -0,0 +1,118 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_db::DatabasePluginExt;
+
+/// Meetings are offered in the tray from this long before they start.
+const JOIN_LEAD_MINUTES: i64 = 10;
+const REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A calendar event with a meeting link that is about to start, or underway.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpcomingMeeting {
+    pub event_id: String,
+    pub name: String,
+}
+
+pub fn start(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let meeting = load(&app).await.unwrap_or(None);
+
+            let changed = {
+                let state = app.state::<crate::ManagedState>();
+                let mut guard = state.lock().unwrap();
+
+                if guard.upcoming != meeting {
+                    guard.upcoming = meeting;
+                    true
+                } else {
+                    false
+                }
+            };
+
+            if changed {
+                let _ = crate::ext::refresh_tray_menu(&app);
+            }
+
+            tokio::time::sleep(REFRESH_INTERVAL).await;
+        }
+    });
+}
+
+async fn load(app: &AppHandle) -> Result<Option<UpcomingMeeting>, tauri_plugin_db::Error> {
+    let Some(user_id) = app.db_user_id().await? else {
+        return Ok(None);
+    };
+    let now = Utc::now();
+
+    let events = app
+        .db_list_events(hypr_db_user::ListEventFilter {
+            common: hypr_db_user::ListEventFilterCommon {
+                user_id,
+                limit: Some(20),
+            },
+            specific: hypr_db_user::ListEventFilterSpecific::DateRange {
+                start: now - chrono::Duration::hours(4),
+                end: now + chrono::Duration::minutes(JOIN_LEAD_MINUTES),
+            },
+        })
+        .await?;
+
+    Ok(pick_meeting(events, now))
+}
+
+/// Among events that are not over yet and can be joined, the one starting closest to now.
+fn pick_meeting(events: Vec<hypr_db_user::Event>, now: DateTime<Utc>) -> Option<UpcomingMeeting> {
+    events
+        .into_iter()
+        .filter(|event| event.end_date > now && event.meeting_link.is_some())
+        .min_by_key(|event| (event.start_date - now).num_seconds().abs())
+        .map(|event| UpcomingMeeting {
+            event_id: event.id,
+            name: event.name,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(id: &str, start: DateTime<Utc>, link: bool) -> hypr_db_user::Event {
+        hypr_db_user::Event {
+            id: id.to_string(),
+            name: id.to_string(),
+            start_date: start,
+            end_date: start + chrono::Duration::minutes(30),
+            meeting_link: link.then(|| "https://meet.google.com/abc-defg-hij".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_pick_meeting() {
+        let now = Utc::now();
+
+        let picked = pick_meeting(
+            vec![
+                event("ended", now - chrono::Duration::minutes(45), true),
+                event("focus-time", now + chrono::Duration::minutes(1), false),
+                event("standup", now + chrono::Duration::minutes(5), true),
+            ],
+            now,
+        );
+        assert_eq!(picked.unwrap().event_id, "standup");
+
+        let picked = pick_meeting(
+            vec![event(
+                "focus-time",
+                now - chrono::Duration::minutes(5),
+                false,
+            )],
+            now,
+        );
+        assert!(picked.is_none());
+    }
+}
@@ -1,4 +1,20 @@
-const COMMANDS: &[&str] = &["todo"];
+const COMMANDS: &[&str] = &[
+    "list_endpoints",
+    "add_endpoint",
+    "update_endpoint",
+    "remove_endpoint",
+    "test_endpoint",
+    "dispatch_webhook",
+    "list_failed_deliveries",
+    "retry_failed_delivery",
+    "discard_failed_delivery",
+    "list_payload_presets",
+    "list_deliveries",
+    "get_delivery",
+    "get_inbound_server_config",
+    "set_inbound_server_config",
+    "is_inbound_server_running",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
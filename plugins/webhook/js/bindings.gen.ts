@@ -7,8 +7,50 @@
 
 
 export const commands = {
-async todo() : Promise<string> {
-    return await TAURI_INVOKE("plugin:webhook|todo");
+async listEndpoints() : Promise<WebhookEndpoint[]> {
+    return await TAURI_INVOKE("plugin:webhook|list_endpoints");
+},
+async addEndpoint(url: string, secret: string | null, events: WebhookEventType[], payloadTemplate: string | null) : Promise<WebhookEndpoint> {
+    return await TAURI_INVOKE("plugin:webhook|add_endpoint", { url, secret, events, payloadTemplate });
+},
+async updateEndpoint(endpoint: WebhookEndpoint) : Promise<null> {
+    return await TAURI_INVOKE("plugin:webhook|update_endpoint", { endpoint });
+},
+async removeEndpoint(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:webhook|remove_endpoint", { id });
+},
+async testEndpoint(id: string) : Promise<number> {
+    return await TAURI_INVOKE("plugin:webhook|test_endpoint", { id });
+},
+async dispatchWebhook(eventType: WebhookEventType, data: JsonValue) : Promise<null> {
+    return await TAURI_INVOKE("plugin:webhook|dispatch_webhook", { eventType, data });
+},
+async listFailedDeliveries() : Promise<FailedDelivery[]> {
+    return await TAURI_INVOKE("plugin:webhook|list_failed_deliveries");
+},
+async retryFailedDelivery(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:webhook|retry_failed_delivery", { id });
+},
+async discardFailedDelivery(id: string) : Promise<null> {
+    return await TAURI_INVOKE("plugin:webhook|discard_failed_delivery", { id });
+},
+async listPayloadPresets() : Promise<PayloadPreset[]> {
+    return await TAURI_INVOKE("plugin:webhook|list_payload_presets");
+},
+async listDeliveries(endpointId: string | null) : Promise<DeliveryLogEntry[]> {
+    return await TAURI_INVOKE("plugin:webhook|list_deliveries", { endpointId });
+},
+async getDelivery(id: string) : Promise<DeliveryLogEntry> {
+    return await TAURI_INVOKE("plugin:webhook|get_delivery", { id });
+},
+async getInboundServerConfig() : Promise<InboundServerConfig> {
+    return await TAURI_INVOKE("plugin:webhook|get_inbound_server_config");
+},
+async setInboundServerConfig(config: InboundServerConfig) : Promise<null> {
+    return await TAURI_INVOKE("plugin:webhook|set_inbound_server_config", { config });
+},
+async isInboundServerRunning() : Promise<boolean> {
+    return await TAURI_INVOKE("plugin:webhook|is_inbound_server_running");
 }
 }
 
@@ -22,7 +64,40 @@ async todo() : Promise<string> {
 
 /** user-defined types **/
 
-
+export type DeliveryLogEntry = { id: string; endpoint_id: string; endpoint_url: string; 
+/**
+ * Only what identifies the event; its payload may hold a whole transcript.
+ */
+event_id: string; event_type: string; success: boolean; status_code: number | null; latency_ms: number; response_body: string | null; error: string | null; attempted_at: string }
+export type FailedDelivery = { id: string; endpoint_id: string; endpoint_url: string; event: WebhookEvent; attempts: number; last_error: string; failed_at: string }
+export type InboundServerConfig = { enabled: boolean; port: number; api_key: string; 
+/**
+ * Serve live session events (transcripts, state changes, levels) over a WebSocket at
+ * `/api/events`, for overlays. The latest caption lines are served as plain text at
+ * `/api/captions` either way.
+ */
+stream_events?: boolean }
+export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
+export type PayloadPreset = { name: string; template: string }
+export type WebhookEndpoint = { id: string; url: string; secret: string; events: WebhookEventType[]; active: boolean; created_at: string; payload_template?: string | null }
+export type WebhookEvent = { 
+/**
+ * Unique event identifier
+ */
+id: string; 
+/**
+ * Event type
+ */
+event_type: string; 
+/**
+ * ISO 8601 timestamp
+ */
+timestamp: string; 
+/**
+ * Event payload
+ */
+data: JsonValue }
+export type WebhookEventType = "session.finished" | "note.enhanced" | "transcript.finalized"
 
 /** tauri-specta globals **/
 
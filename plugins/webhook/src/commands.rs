@@ -1,7 +1,138 @@
-use crate::WebhookPluginExt;
+use crate::{
+    DeliveryLogEntry, FailedDelivery, InboundServerConfig, PayloadPreset, WebhookEndpoint,
+    WebhookEventType, WebhookPluginExt,
+};
 
 #[tauri::command]
 #[specta::specta]
-pub async fn todo<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Result<String, String> {
-    app.todo()
+pub async fn list_endpoints<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<WebhookEndpoint>, String> {
+    app.list_endpoints().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn add_endpoint<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    url: String,
+    secret: Option<String>,
+    events: Vec<WebhookEventType>,
+    payload_template: Option<String>,
+) -> Result<WebhookEndpoint, String> {
+    app.add_endpoint(url, secret, events, payload_template)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn update_endpoint<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    endpoint: WebhookEndpoint,
+) -> Result<(), String> {
+    app.update_endpoint(endpoint).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn remove_endpoint<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<(), String> {
+    app.remove_endpoint(id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn test_endpoint<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<u16, String> {
+    app.test_endpoint(id).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn dispatch_webhook<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    event_type: WebhookEventType,
+    data: serde_json::Value,
+) -> Result<(), String> {
+    app.dispatch_webhook(event_type, data)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_failed_deliveries<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<FailedDelivery>, String> {
+    app.list_failed_deliveries().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn retry_failed_delivery<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<(), String> {
+    app.retry_failed_delivery(id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn discard_failed_delivery<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<(), String> {
+    app.discard_failed_delivery(id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_payload_presets() -> Result<Vec<PayloadPreset>, String> {
+    Ok(crate::payload::presets())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_deliveries<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    endpoint_id: Option<String>,
+) -> Result<Vec<DeliveryLogEntry>, String> {
+    app.list_deliveries(endpoint_id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_delivery<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+) -> Result<DeliveryLogEntry, String> {
+    app.get_delivery(id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_inbound_server_config<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<InboundServerConfig, String> {
+    app.inbound_server_config().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_inbound_server_config<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    config: InboundServerConfig,
+) -> Result<(), String> {
+    app.set_inbound_server_config(config)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn is_inbound_server_running<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> bool {
+    app.is_inbound_server_running().await
 }
@@ -0,0 +1,66 @@
+use crate::signature::{sign, DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER};
+use crate::{WebhookEndpoint, WebhookEvent};
+
+const DELIVERY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+/// Enough for what the delivery log keeps, without buffering whatever an endpoint sends back.
+const MAX_RESPONSE_BODY_BYTES: usize = 8 * 1024;
+
+pub fn build_event(event_type: impl ToString, data: serde_json::Value) -> WebhookEvent {
+    WebhookEvent {
+        id: format!("evt_{}", uuid::Uuid::new_v4().simple()),
+        event_type: event_type.to_string(),
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        data,
+    }
+}
+
+pub struct DeliveryResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl DeliveryResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Only transport errors are returned as `Err`; non-2xx responses are left to the caller.
+pub async fn deliver(
+    client: &reqwest::Client,
+    endpoint: &WebhookEndpoint,
+    event: &WebhookEvent,
+) -> Result<DeliveryResponse, crate::Error> {
+    let body = crate::payload::render(endpoint, event)?;
+    let timestamp = chrono::Utc::now().timestamp();
+
+    let res = client
+        .post(&endpoint.url)
+        .timeout(DELIVERY_TIMEOUT)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(SIGNATURE_HEADER, sign(&endpoint.secret, timestamp, &body))
+        .header(TIMESTAMP_HEADER, timestamp.to_string())
+        .header(EVENT_HEADER, &event.event_type)
+        .header(DELIVERY_HEADER, &event.id)
+        .body(body)
+        .send()
+        .await?;
+
+    let status = res.status().as_u16();
+    let body = read_body_prefix(res, MAX_RESPONSE_BODY_BYTES).await;
+
+    Ok(DeliveryResponse { status, body })
+}
+
+async fn read_body_prefix(mut res: reqwest::Response, max_bytes: usize) -> String {
+    let mut body = Vec::new();
+    while body.len() < max_bytes {
+        match res.chunk().await {
+            Ok(Some(chunk)) => body.extend_from_slice(&chunk),
+            _ => break,
+        }
+    }
+    body.truncate(max_bytes);
+
+    String::from_utf8_lossy(&body).into_owned()
+}
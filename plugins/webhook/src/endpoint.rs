@@ -0,0 +1,89 @@
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    specta::Type,
+    strum::Display,
+    strum::EnumString,
+)]
+pub enum WebhookEventType {
+    #[serde(rename = "session.finished")]
+    #[strum(serialize = "session.finished")]
+    SessionFinished,
+    #[serde(rename = "note.enhanced")]
+    #[strum(serialize = "note.enhanced")]
+    NoteEnhanced,
+    #[serde(rename = "transcript.finalized")]
+    #[strum(serialize = "transcript.finalized")]
+    TranscriptFinalized,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct WebhookEndpoint {
+    pub id: String,
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<WebhookEventType>,
+    pub active: bool,
+    pub created_at: String,
+    #[serde(default)]
+    pub payload_template: Option<String>,
+}
+
+impl WebhookEndpoint {
+    pub fn new(url: String, secret: Option<String>, events: Vec<WebhookEventType>) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            url,
+            secret: secret.unwrap_or_else(generate_secret),
+            events,
+            active: true,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            payload_template: None,
+        }
+    }
+
+    pub fn accepts(&self, event_type: WebhookEventType) -> bool {
+        self.active && self.events.contains(&event_type)
+    }
+}
+
+pub fn generate_secret() -> String {
+    format!("whsec_{}", uuid::Uuid::new_v4().simple())
+}
+
+pub fn validate_url(url: &str) -> Result<(), crate::Error> {
+    let parsed = url::Url::parse(url).map_err(|_| crate::Error::InvalidUrl(url.to_string()))?;
+
+    match parsed.scheme() {
+        "http" | "https" => Ok(()),
+        _ => Err(crate::Error::InvalidUrl(url.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint() {
+        let endpoint = WebhookEndpoint::new(
+            "https://example.com/hook".to_string(),
+            None,
+            vec![WebhookEventType::SessionFinished],
+        );
+
+        assert!(endpoint.secret.starts_with("whsec_"));
+        assert!(endpoint.accepts(WebhookEventType::SessionFinished));
+        assert!(!endpoint.accepts(WebhookEventType::NoteEnhanced));
+
+        assert!(validate_url("https://example.com/hook").is_ok());
+        assert!(validate_url("ftp://example.com").is_err());
+        assert!(validate_url("not a url").is_err());
+    }
+}
@@ -3,7 +3,30 @@ use serde::{ser::Serializer, Serialize};
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Debug, thiserror::Error)]
-pub enum Error {}
+pub enum Error {
+    #[error(transparent)]
+    StoreError(#[from] tauri_plugin_store2::Error),
+    #[error(transparent)]
+    HttpError(#[from] reqwest::Error),
+    #[error(transparent)]
+    SerdeError(#[from] serde_json::Error),
+    #[error(transparent)]
+    DatabaseError(#[from] tauri_plugin_db::Error),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    TemplateError(#[from] minijinja::Error),
+    #[error("Rendered webhook payload is not valid JSON: {0}")]
+    InvalidPayload(String),
+    #[error("Invalid webhook URL: {0}")]
+    InvalidUrl(String),
+    #[error("Webhook endpoint not found: {0}")]
+    EndpointNotFound(String),
+    #[error("Webhook delivery not found: {0}")]
+    DeliveryNotFound(String),
+    #[error("Webhook receiver responded with status {0}")]
+    UnexpectedStatus(u16),
+}
 
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
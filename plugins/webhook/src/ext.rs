@@ -1,9 +1,444 @@
+use std::future::Future;
+
+use tauri::Manager;
+use tauri_plugin_store2::StorePluginExt;
+
+use crate::log::DeliveryLogEntry;
+use crate::queue::{FailedDelivery, PendingDelivery};
+use crate::server::InboundServerConfig;
+use crate::{WebhookEndpoint, WebhookEventType};
+
 pub trait WebhookPluginExt<R: tauri::Runtime> {
-    fn todo(&self) -> Result<String, String>;
+    fn webhook_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey>;
+
+    fn list_endpoints(&self) -> Result<Vec<WebhookEndpoint>, crate::Error>;
+    fn add_endpoint(
+        &self,
+        url: String,
+        secret: Option<String>,
+        events: Vec<WebhookEventType>,
+        payload_template: Option<String>,
+    ) -> Result<WebhookEndpoint, crate::Error>;
+    fn update_endpoint(&self, endpoint: WebhookEndpoint) -> Result<(), crate::Error>;
+    fn remove_endpoint(&self, id: impl Into<String>) -> Result<(), crate::Error>;
+
+    fn test_endpoint(
+        &self,
+        id: impl Into<String>,
+    ) -> impl Future<Output = Result<u16, crate::Error>>;
+    fn dispatch_webhook(
+        &self,
+        event_type: WebhookEventType,
+        data: serde_json::Value,
+    ) -> Result<(), crate::Error>;
+    fn process_deliveries(&self) -> impl Future<Output = Result<(), crate::Error>>;
+
+    fn list_failed_deliveries(&self) -> Result<Vec<FailedDelivery>, crate::Error>;
+    fn retry_failed_delivery(&self, id: impl Into<String>) -> Result<(), crate::Error>;
+    fn discard_failed_delivery(&self, id: impl Into<String>) -> Result<(), crate::Error>;
+
+    fn list_deliveries(
+        &self,
+        endpoint_id: Option<String>,
+    ) -> Result<Vec<DeliveryLogEntry>, crate::Error>;
+    fn get_delivery(&self, id: impl Into<String>) -> Result<DeliveryLogEntry, crate::Error>;
+
+    fn inbound_server_config(&self) -> Result<InboundServerConfig, crate::Error>;
+    fn set_inbound_server_config(
+        &self,
+        config: InboundServerConfig,
+    ) -> impl Future<Output = Result<(), crate::Error>>;
+    fn is_inbound_server_running(&self) -> impl Future<Output = bool>;
+    fn start_inbound_server(&self) -> impl Future<Output = Result<(), crate::Error>>;
+    fn stop_inbound_server(&self) -> impl Future<Output = ()>;
+}
+
+impl<R: tauri::Runtime, T: tauri::Manager<R>> WebhookPluginExt<R> for T {
+    fn webhook_store(&self) -> tauri_plugin_store2::ScopedStore<R, crate::StoreKey> {
+        self.scoped_store(crate::PLUGIN_NAME).unwrap()
+    }
+
+    fn list_endpoints(&self) -> Result<Vec<WebhookEndpoint>, crate::Error> {
+        let store = self.webhook_store();
+        let v = store.get::<Vec<WebhookEndpoint>>(crate::StoreKey::Endpoints)?;
+        Ok(v.unwrap_or_default())
+    }
+
+    fn add_endpoint(
+        &self,
+        url: String,
+        secret: Option<String>,
+        events: Vec<WebhookEventType>,
+        payload_template: Option<String>,
+    ) -> Result<WebhookEndpoint, crate::Error> {
+        crate::endpoint::validate_url(&url)?;
+        if let Some(template) = &payload_template {
+            crate::payload::validate_template(template)?;
+        }
+
+        let endpoint = WebhookEndpoint {
+            payload_template,
+            ..WebhookEndpoint::new(url, secret, events)
+        };
+
+        let mut endpoints = self.list_endpoints()?;
+        endpoints.push(endpoint.clone());
+        save_endpoints(self, endpoints)?;
+
+        Ok(endpoint)
+    }
+
+    fn update_endpoint(&self, endpoint: WebhookEndpoint) -> Result<(), crate::Error> {
+        crate::endpoint::validate_url(&endpoint.url)?;
+        if let Some(template) = &endpoint.payload_template {
+            crate::payload::validate_template(template)?;
+        }
+
+        let mut endpoints = self.list_endpoints()?;
+        let existing = endpoints
+            .iter_mut()
+            .find(|e| e.id == endpoint.id)
+            .ok_or_else(|| crate::Error::EndpointNotFound(endpoint.id.clone()))?;
+        *existing = endpoint;
+
+        save_endpoints(self, endpoints)
+    }
+
+    fn remove_endpoint(&self, id: impl Into<String>) -> Result<(), crate::Error> {
+        let id = id.into();
+
+        let mut endpoints = self.list_endpoints()?;
+        let before = endpoints.len();
+        endpoints.retain(|e| e.id != id);
+        if endpoints.len() == before {
+            return Err(crate::Error::EndpointNotFound(id));
+        }
+
+        save_endpoints(self, endpoints)
+    }
+
+    async fn test_endpoint(&self, id: impl Into<String>) -> Result<u16, crate::Error> {
+        let id = id.into();
+        let endpoint = self
+            .list_endpoints()?
+            .into_iter()
+            .find(|e| e.id == id)
+            .ok_or(crate::Error::EndpointNotFound(id))?;
+
+        let event = crate::delivery::build_event(
+            "webhook.test",
+            serde_json::json!({ "endpoint_id": endpoint.id }),
+        );
+
+        deliver_and_log(self, &endpoint, &event).await
+    }
+
+    fn dispatch_webhook(
+        &self,
+        event_type: WebhookEventType,
+        data: serde_json::Value,
+    ) -> Result<(), crate::Error> {
+        let endpoints: Vec<_> = self
+            .list_endpoints()?
+            .into_iter()
+            .filter(|e| e.accepts(event_type))
+            .collect();
+
+        if endpoints.is_empty() {
+            return Ok(());
+        }
+
+        let event = crate::delivery::build_event(event_type, data);
+        let state = self.state::<crate::State>();
+
+        {
+            let _guard = state.queue_lock.lock().unwrap();
+
+            let mut pending = pending_deliveries(self)?;
+            pending.extend(
+                endpoints
+                    .iter()
+                    .map(|endpoint| PendingDelivery::new(&endpoint.id, event.clone())),
+            );
+            save_pending_deliveries(self, pending)?;
+        }
+
+        state.wake.notify_one();
+        Ok(())
+    }
+
+    async fn process_deliveries(&self) -> Result<(), crate::Error> {
+        let now = chrono::Utc::now();
+        let due: Vec<_> = pending_deliveries(self)?
+            .into_iter()
+            .filter(|d| d.is_due(now))
+            .collect();
+
+        if due.is_empty() {
+            return Ok(());
+        }
+
+        let endpoints = self.list_endpoints()?;
+        let state = self.state::<crate::State>();
+
+        for delivery in due {
+            let endpoint = endpoints.iter().find(|e| e.id == delivery.endpoint_id);
+
+            let result = match endpoint {
+                Some(endpoint) => deliver_and_log(self, endpoint, &delivery.event)
+                    .await
+                    .map(|_| ()),
+                None => Err(crate::Error::EndpointNotFound(delivery.endpoint_id.clone())),
+            };
+
+            let _guard = state.queue_lock.lock().unwrap();
+
+            let mut pending = pending_deliveries(self)?;
+            let Some(pos) = pending.iter().position(|d| d.id == delivery.id) else {
+                continue;
+            };
+            let mut delivery = pending.remove(pos);
+
+            match (result, endpoint) {
+                (Ok(()), _) => {}
+                // The endpoint was removed after the event was queued.
+                (Err(_), None) => {}
+                (Err(e), Some(endpoint)) => {
+                    tracing::warn!(
+                        endpoint = %endpoint.url,
+                        event = %delivery.event.event_type,
+                        attempts = delivery.attempts + 1,
+                        "webhook_delivery_failed: {}",
+                        e
+                    );
+
+                    if delivery.record_failure(e.to_string(), chrono::Utc::now()) {
+                        pending.push(delivery);
+                    } else {
+                        let mut failed = failed_deliveries(self)?;
+                        failed.push(delivery.into_failed(&endpoint.url));
+
+                        let overflow = failed
+                            .len()
+                            .saturating_sub(crate::queue::MAX_FAILED_DELIVERIES);
+                        failed.drain(..overflow);
+
+                        save_failed_deliveries(self, failed)?;
+                    }
+                }
+            }
+
+            save_pending_deliveries(self, pending)?;
+        }
+
+        Ok(())
+    }
+
+    fn list_failed_deliveries(&self) -> Result<Vec<FailedDelivery>, crate::Error> {
+        let mut failed = failed_deliveries(self)?;
+        failed.reverse();
+        Ok(failed)
+    }
+
+    fn retry_failed_delivery(&self, id: impl Into<String>) -> Result<(), crate::Error> {
+        let id = id.into();
+        let state = self.state::<crate::State>();
+
+        {
+            let _guard = state.queue_lock.lock().unwrap();
+
+            let mut failed = failed_deliveries(self)?;
+            let pos = failed
+                .iter()
+                .position(|d| d.id == id)
+                .ok_or(crate::Error::DeliveryNotFound(id))?;
+
+            let mut pending = pending_deliveries(self)?;
+            pending.push(PendingDelivery::from(failed.remove(pos)));
+
+            save_pending_deliveries(self, pending)?;
+            save_failed_deliveries(self, failed)?;
+        }
+
+        state.wake.notify_one();
+        Ok(())
+    }
+
+    fn discard_failed_delivery(&self, id: impl Into<String>) -> Result<(), crate::Error> {
+        let id = id.into();
+        let state = self.state::<crate::State>();
+        let _guard = state.queue_lock.lock().unwrap();
+
+        let mut failed = failed_deliveries(self)?;
+        let before = failed.len();
+        failed.retain(|d| d.id != id);
+        if failed.len() == before {
+            return Err(crate::Error::DeliveryNotFound(id));
+        }
+
+        save_failed_deliveries(self, failed)
+    }
+
+    fn list_deliveries(
+        &self,
+        endpoint_id: Option<String>,
+    ) -> Result<Vec<DeliveryLogEntry>, crate::Error> {
+        Ok(delivery_log(self)?
+            .into_iter()
+            .rev()
+            .filter(|entry| {
+                endpoint_id
+                    .as_ref()
+                    .is_none_or(|id| &entry.endpoint_id == id)
+            })
+            .collect())
+    }
+
+    fn get_delivery(&self, id: impl Into<String>) -> Result<DeliveryLogEntry, crate::Error> {
+        let id = id.into();
+
+        delivery_log(self)?
+            .into_iter()
+            .find(|entry| entry.id == id)
+            .ok_or(crate::Error::DeliveryNotFound(id))
+    }
+
+    fn inbound_server_config(&self) -> Result<InboundServerConfig, crate::Error> {
+        let store = self.webhook_store();
+        if let Some(config) = store.get::<InboundServerConfig>(crate::StoreKey::InboundServer)? {
+            return Ok(config);
+        }
+
+        // Persist right away, so the generated API key survives restarts.
+        let config = InboundServerConfig::default();
+        store.set(crate::StoreKey::InboundServer, config.clone())?;
+        store.save()?;
+        Ok(config)
+    }
+
+    async fn set_inbound_server_config(
+        &self,
+        config: InboundServerConfig,
+    ) -> Result<(), crate::Error> {
+        {
+            let store = self.webhook_store();
+            store.set(crate::StoreKey::InboundServer, config.clone())?;
+            store.save()?;
+        }
+
+        self.stop_inbound_server().await;
+        if config.enabled {
+            self.start_inbound_server().await?;
+        }
+        Ok(())
+    }
+
+    async fn is_inbound_server_running(&self) -> bool {
+        let state = self.state::<crate::State>();
+        let server = state.server.lock().await;
+        server.is_some()
+    }
+
+    async fn start_inbound_server(&self) -> Result<(), crate::Error> {
+        let config = self.inbound_server_config()?;
+
+        let state = self.state::<crate::State>();
+        let mut server = state.server.lock().await;
+        if server.is_none() {
+            *server = Some(crate::server::run_server(self.app_handle().clone(), &config).await?);
+        }
+        Ok(())
+    }
+
+    async fn stop_inbound_server(&self) {
+        let state = self.state::<crate::State>();
+        let mut server = state.server.lock().await;
+        if let Some(server) = server.take() {
+            let _ = server.shutdown.send(());
+        }
+    }
 }
 
-impl<R: tauri::Runtime> WebhookPluginExt<R> for tauri::AppHandle<R> {
-    fn todo(&self) -> Result<String, String> {
-        Ok("Webhook todo functionality not yet implemented".to_string())
+async fn deliver_and_log<R: tauri::Runtime, T: tauri::Manager<R>>(
+    app: &T,
+    endpoint: &WebhookEndpoint,
+    event: &crate::WebhookEvent,
+) -> Result<u16, crate::Error> {
+    let state = app.state::<crate::State>();
+    let client = hypr_proxy::client();
+
+    let started = std::time::Instant::now();
+    let result = crate::delivery::deliver(&client, endpoint, event).await;
+    let entry = DeliveryLogEntry::new(endpoint, event, &result, started.elapsed());
+
+    {
+        let _guard = state.log_lock.lock().unwrap();
+
+        let mut log = delivery_log(app)?;
+        crate::log::append(&mut log, entry);
+
+        let store = app.webhook_store();
+        store.set(crate::StoreKey::DeliveryLog, log)?;
+        store.save()?;
+    }
+
+    let res = result?;
+    if !res.is_success() {
+        return Err(crate::Error::UnexpectedStatus(res.status));
     }
+    Ok(res.status)
+}
+
+fn delivery_log<R: tauri::Runtime>(
+    app: &impl WebhookPluginExt<R>,
+) -> Result<Vec<DeliveryLogEntry>, crate::Error> {
+    let store = app.webhook_store();
+    let v = store.get::<Vec<DeliveryLogEntry>>(crate::StoreKey::DeliveryLog)?;
+    Ok(v.unwrap_or_default())
+}
+
+fn save_endpoints<R: tauri::Runtime>(
+    app: &impl WebhookPluginExt<R>,
+    endpoints: Vec<WebhookEndpoint>,
+) -> Result<(), crate::Error> {
+    let store = app.webhook_store();
+    store.set(crate::StoreKey::Endpoints, endpoints)?;
+    store.save()?;
+    Ok(())
+}
+
+fn pending_deliveries<R: tauri::Runtime>(
+    app: &impl WebhookPluginExt<R>,
+) -> Result<Vec<PendingDelivery>, crate::Error> {
+    let store = app.webhook_store();
+    let v = store.get::<Vec<PendingDelivery>>(crate::StoreKey::PendingDeliveries)?;
+    Ok(v.unwrap_or_default())
+}
+
+fn save_pending_deliveries<R: tauri::Runtime>(
+    app: &impl WebhookPluginExt<R>,
+    pending: Vec<PendingDelivery>,
+) -> Result<(), crate::Error> {
+    let store = app.webhook_store();
+    store.set(crate::StoreKey::PendingDeliveries, pending)?;
+    store.save()?;
+    Ok(())
+}
+
+fn failed_deliveries<R: tauri::Runtime>(
+    app: &impl WebhookPluginExt<R>,
+) -> Result<Vec<FailedDelivery>, crate::Error> {
+    let store = app.webhook_store();
+    let v = store.get::<Vec<FailedDelivery>>(crate::StoreKey::FailedDeliveries)?;
+    Ok(v.unwrap_or_default())
+}
+
+fn save_failed_deliveries<R: tauri::Runtime>(
+    app: &impl WebhookPluginExt<R>,
+    failed: Vec<FailedDelivery>,
+) -> Result<(), crate::Error> {
+    let store = app.webhook_store();
+    store.set(crate::StoreKey::FailedDeliveries, failed)?;
+    store.save()?;
+    Ok(())
 }
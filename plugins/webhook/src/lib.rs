@@ -1,25 +1,64 @@
 mod commands;
+mod delivery;
+mod endpoint;
 mod error;
 mod ext;
+mod live;
+mod log;
 mod openapi;
+mod payload;
+mod queue;
+mod server;
+mod sessions;
+mod signature;
+mod store;
+mod worker;
 
+pub use endpoint::{WebhookEndpoint, WebhookEventType};
 pub use error::*;
 pub use ext::*;
+pub use log::DeliveryLogEntry;
 pub use openapi::*;
+pub use payload::PayloadPreset;
+pub use queue::{FailedDelivery, PendingDelivery};
+pub use server::{InboundServerConfig, API_KEY_HEADER};
+pub use signature::*;
+use store::*;
 
 const PLUGIN_NAME: &str = "webhook";
 
 use tauri::Manager;
 
 #[derive(Default)]
-pub struct State {}
+pub struct State {
+    wake: std::sync::Arc<tokio::sync::Notify>,
+    queue_lock: std::sync::Mutex<()>,
+    log_lock: std::sync::Mutex<()>,
+    server: tokio::sync::Mutex<Option<server::ServerHandle>>,
+    active_session: std::sync::Mutex<Option<String>>,
+    live_events: live::LiveEvents,
+}
 
 fn make_specta_builder() -> tauri_specta::Builder<tauri::Wry> {
     tauri_specta::Builder::<tauri::Wry>::new()
         .plugin_name(PLUGIN_NAME)
         .events(tauri_specta::collect_events![])
         .commands(tauri_specta::collect_commands![
-            commands::todo::<tauri::Wry>,
+            commands::list_endpoints::<tauri::Wry>,
+            commands::add_endpoint::<tauri::Wry>,
+            commands::update_endpoint::<tauri::Wry>,
+            commands::remove_endpoint::<tauri::Wry>,
+            commands::test_endpoint::<tauri::Wry>,
+            commands::dispatch_webhook::<tauri::Wry>,
+            commands::list_failed_deliveries::<tauri::Wry>,
+            commands::retry_failed_delivery::<tauri::Wry>,
+            commands::discard_failed_delivery::<tauri::Wry>,
+            commands::list_payload_presets,
+            commands::list_deliveries::<tauri::Wry>,
+            commands::get_delivery::<tauri::Wry>,
+            commands::get_inbound_server_config::<tauri::Wry>,
+            commands::set_inbound_server_config::<tauri::Wry>,
+            commands::is_inbound_server_running::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Throw)
 }
@@ -36,6 +75,23 @@ pub fn init() -> tauri::plugin::TauriPlugin<tauri::Wry> {
                 app.manage(State::default());
             }
 
+            tauri::async_runtime::spawn(worker::run(app.app_handle().clone()));
+            sessions::watch(app.app_handle());
+
+            let app_handle = app.app_handle().clone();
+            tauri::async_runtime::spawn(async move {
+                let enabled = app_handle
+                    .inbound_server_config()
+                    .map(|config| config.enabled)
+                    .unwrap_or(false);
+
+                if enabled {
+                    if let Err(e) = app_handle.start_inbound_server().await {
+                        tracing::error!("webhook_inbound_server_start_failed: {}", e);
+                    }
+                }
+            });
+
             Ok(())
         })
         .build()
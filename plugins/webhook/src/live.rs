@@ -0,0 +1,33 @@
+use tauri_plugin_listener::SessionEvent;
+use tokio::sync::broadcast;
+
+// Room for a slow client to catch up after a burst of partial transcripts and levels.
+const CAPACITY: usize = 256;
+
+/// Session events as they happen, for WebSocket clients of the inbound server.
+pub struct LiveEvents(broadcast::Sender<serde_json::Value>);
+
+impl Default for LiveEvents {
+    fn default() -> Self {
+        Self(broadcast::channel(CAPACITY).0)
+    }
+}
+
+impl LiveEvents {
+    pub fn publish(&self, event: &SessionEvent) {
+        if self.0.receiver_count() == 0 {
+            return;
+        }
+
+        match serde_json::to_value(event) {
+            Ok(value) => {
+                let _ = self.0.send(value);
+            }
+            Err(e) => tracing::warn!("live_event_serialize_failed: {}", e),
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<serde_json::Value> {
+        self.0.subscribe()
+    }
+}
@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+
+use crate::delivery::DeliveryResponse;
+use crate::{WebhookEndpoint, WebhookEvent};
+
+pub const MAX_LOG_ENTRIES: usize = 200;
+const MAX_RESPONSE_BODY_CHARS: usize = 2_000;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct DeliveryLogEntry {
+    pub id: String,
+    pub endpoint_id: String,
+    pub endpoint_url: String,
+    /// Only what identifies the event; its payload may hold a whole transcript.
+    pub event_id: String,
+    pub event_type: String,
+    pub success: bool,
+    pub status_code: Option<u16>,
+    pub latency_ms: u64,
+    pub response_body: Option<String>,
+    pub error: Option<String>,
+    pub attempted_at: DateTime<Utc>,
+}
+
+impl DeliveryLogEntry {
+    pub fn new(
+        endpoint: &WebhookEndpoint,
+        event: &WebhookEvent,
+        result: &Result<DeliveryResponse, crate::Error>,
+        latency: std::time::Duration,
+    ) -> Self {
+        let (success, status_code, response_body, error) = match result {
+            Ok(res) => (
+                res.is_success(),
+                Some(res.status),
+                Some(truncate(&res.body, MAX_RESPONSE_BODY_CHARS)),
+                None,
+            ),
+            Err(e) => (false, None, None, Some(e.to_string())),
+        };
+
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            endpoint_id: endpoint.id.clone(),
+            endpoint_url: endpoint.url.clone(),
+            event_id: event.id.clone(),
+            event_type: event.event_type.clone(),
+            success,
+            status_code,
+            latency_ms: latency.as_millis() as u64,
+            response_body,
+            error,
+            attempted_at: Utc::now(),
+        }
+    }
+}
+
+fn truncate(s: &str, max_chars: usize) -> String {
+    match s.char_indices().nth(max_chars) {
+        Some((idx, _)) => format!("{}…", &s[..idx]),
+        None => s.to_string(),
+    }
+}
+
+/// Appends to the log, dropping the oldest entries beyond `MAX_LOG_ENTRIES`.
+pub fn append(log: &mut Vec<DeliveryLogEntry>, entry: DeliveryLogEntry) {
+    log.push(entry);
+    let overflow = log.len().saturating_sub(MAX_LOG_ENTRIES);
+    log.drain(..overflow);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(result: Result<DeliveryResponse, crate::Error>) -> DeliveryLogEntry {
+        let endpoint = WebhookEndpoint::new("https://example.com".to_string(), None, vec![]);
+        let event = crate::delivery::build_event("webhook.test", serde_json::json!({}));
+        DeliveryLogEntry::new(
+            &endpoint,
+            &event,
+            &result,
+            std::time::Duration::from_millis(42),
+        )
+    }
+
+    #[test]
+    fn test_entry() {
+        let ok = entry(Ok(DeliveryResponse {
+            status: 410,
+            body: "é".repeat(MAX_RESPONSE_BODY_CHARS + 1),
+        }));
+        assert!(!ok.success);
+        assert_eq!(ok.event_type, "webhook.test");
+        assert_eq!(ok.status_code, Some(410));
+        assert_eq!(ok.latency_ms, 42);
+        assert_eq!(
+            ok.response_body.unwrap().chars().count(),
+            MAX_RESPONSE_BODY_CHARS + 1
+        );
+
+        let err = entry(Err(crate::Error::InvalidPayload("eof".to_string())));
+        assert_eq!(err.status_code, None);
+        assert!(err.error.unwrap().contains("eof"));
+    }
+
+    #[test]
+    fn test_append() {
+        let mut log = vec![];
+        for _ in 0..MAX_LOG_ENTRIES + 5 {
+            append(
+                &mut log,
+                entry(Ok(DeliveryResponse {
+                    status: 200,
+                    body: String::new(),
+                })),
+            );
+        }
+        assert_eq!(log.len(), MAX_LOG_ENTRIES);
+    }
+}
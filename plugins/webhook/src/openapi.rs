@@ -5,7 +5,7 @@ use utoipa::{
 };
 
 // Core webhook event structure
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, specta::Type)]
 pub struct WebhookEvent {
     /// Unique event identifier
     #[schema(example = "evt_01234567890")]
@@ -114,6 +114,51 @@ pub struct WebhookVerification {
     pub timestamp: String,
 }
 
+// Local API
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreateNoteRequest {
+    #[schema(example = "Meeting Notes")]
+    pub title: String,
+
+    /// Plain text, one paragraph per line
+    #[serde(default)]
+    #[schema(example = "Discussion points...")]
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NoteResponse {
+    #[schema(example = "0195f1a2-7c3e-7d10-9a4b-2f6e8c1d3b5a")]
+    pub id: String,
+
+    #[schema(example = "Meeting Notes")]
+    pub title: String,
+
+    #[schema(example = "2024-01-10T10:00:00Z")]
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct StartRecordingRequest {
+    /// Existing note to record into. A new note is created when omitted.
+    #[serde(default)]
+    pub session_id: Option<String>,
+
+    /// Title for the new note
+    #[serde(default)]
+    #[schema(example = "Standup")]
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StatusResponse {
+    #[schema(example = true)]
+    pub recording: bool,
+
+    /// Note currently being recorded
+    pub session_id: Option<String>,
+}
+
 #[derive(OpenApi)]
 #[openapi(
     info(
@@ -130,7 +175,11 @@ pub struct WebhookVerification {
         list_webhooks,
         delete_webhook,
         test_webhook,
-        webhook_receiver_example
+        webhook_receiver_example,
+        get_status,
+        create_note,
+        start_recording,
+        stop_recording
     ),
     components(
         schemas(
@@ -142,13 +191,19 @@ pub struct WebhookVerification {
             CreateWebhookRequest,
             WebhookResponse,
             WebhookListResponse,
-            WebhookVerification
+            WebhookVerification,
+            CreateNoteRequest,
+            NoteResponse,
+            StartRecordingRequest,
+            StatusResponse
         )
     ),
     modifiers(&SecurityAddon),
     tags(
         (name = "Webhooks", description = "Webhook management endpoints"),
-        (name = "Events", description = "Event types and payloads")
+        (name = "Events", description = "Event types and payloads"),
+        (name = "Notes", description = "Note creation on the local API"),
+        (name = "Recording", description = "Recording control on the local API")
     )
 )]
 pub struct ApiDoc;
@@ -246,6 +301,76 @@ async fn webhook_receiver_example() {
     unimplemented!()
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/status",
+    tag = "Recording",
+    responses(
+        (status = 200, description = "Current recording status", body = StatusResponse)
+    ),
+    security(
+        ("api_key" = [])
+    )
+)]
+#[allow(dead_code)]
+async fn get_status() -> StatusResponse {
+    unimplemented!()
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/notes",
+    tag = "Notes",
+    request_body = CreateNoteRequest,
+    responses(
+        (status = 201, description = "Note created", body = NoteResponse),
+        (status = 503, description = "Database unavailable")
+    ),
+    security(
+        ("api_key" = [])
+    )
+)]
+#[allow(dead_code)]
+async fn create_note() -> NoteResponse {
+    unimplemented!()
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/recording/start",
+    tag = "Recording",
+    request_body = StartRecordingRequest,
+    responses(
+        (status = 200, description = "Recording started", body = StatusResponse),
+        (status = 404, description = "Note not found"),
+        (status = 409, description = "Already recording")
+    ),
+    security(
+        ("api_key" = [])
+    )
+)]
+#[allow(dead_code)]
+async fn start_recording() -> StatusResponse {
+    unimplemented!()
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/recording/stop",
+    tag = "Recording",
+    responses(
+        (status = 200, description = "Recording stopped", body = StatusResponse),
+        (status = 409, description = "Not recording")
+    ),
+    security(
+        ("api_key" = [])
+    )
+)]
+#[allow(dead_code)]
+async fn stop_recording() -> StatusResponse {
+    unimplemented!()
+}
+
 struct SecurityAddon;
 
 impl Modify for SecurityAddon {
@@ -257,7 +382,7 @@ impl Modify for SecurityAddon {
             );
             components.add_security_scheme(
                 "webhook_signature",
-                SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-Webhook-Signature"))),
+                SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-Hyprnote-Signature"))),
             );
         }
     }
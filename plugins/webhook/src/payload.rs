@@ -0,0 +1,80 @@
+use crate::{WebhookEndpoint, WebhookEvent};
+
+#[derive(Debug, Clone, serde::Serialize, specta::Type)]
+pub struct PayloadPreset {
+    pub name: String,
+    pub template: String,
+}
+
+const SLACK_TEMPLATE: &str = r#"{"text": {{ ("*" ~ event.event_type ~ "*\n" ~ (event.data.title or event.data.session_id or "")) | tojson }}}"#;
+const DISCORD_TEMPLATE: &str = r#"{"content": {{ ("**" ~ event.event_type ~ "**\n" ~ (event.data.title or event.data.session_id or ""))[:1900] | tojson }}}"#;
+
+pub fn presets() -> Vec<PayloadPreset> {
+    [("Slack", SLACK_TEMPLATE), ("Discord", DISCORD_TEMPLATE)]
+        .into_iter()
+        .map(|(name, template)| PayloadPreset {
+            name: name.to_string(),
+            template: template.to_string(),
+        })
+        .collect()
+}
+
+pub fn validate_template(template: &str) -> Result<(), crate::Error> {
+    minijinja::Environment::new().template_from_str(template)?;
+    Ok(())
+}
+
+/// Without a template, receivers get the `WebhookEvent` itself.
+pub fn render(endpoint: &WebhookEndpoint, event: &WebhookEvent) -> Result<Vec<u8>, crate::Error> {
+    let template = match &endpoint.payload_template {
+        Some(template) if !template.trim().is_empty() => template,
+        _ => return Ok(serde_json::to_vec(event)?),
+    };
+
+    let rendered = minijinja::Environment::new()
+        .render_str(template, minijinja::context! { event => event })?;
+
+    // Most receivers reject anything but JSON, so fail here where the error is readable.
+    serde_json::from_str::<serde_json::Value>(&rendered)
+        .map_err(|e| crate::Error::InvalidPayload(e.to_string()))?;
+
+    Ok(rendered.into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoint(payload_template: Option<&str>) -> WebhookEndpoint {
+        WebhookEndpoint {
+            payload_template: payload_template.map(String::from),
+            ..WebhookEndpoint::new("https://example.com".to_string(), None, vec![])
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let event = crate::delivery::build_event(
+            "session.finished",
+            serde_json::json!({ "title": "Weekly \"sync\"" }),
+        );
+
+        let raw = render(&endpoint(None), &event).unwrap();
+        assert_eq!(
+            serde_json::from_slice::<WebhookEvent>(&raw).unwrap().id,
+            event.id
+        );
+
+        let slack = render(&endpoint(Some(SLACK_TEMPLATE)), &event).unwrap();
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&slack).unwrap(),
+            serde_json::json!({ "text": "*session.finished*\nWeekly \"sync\"" })
+        );
+
+        let discord = render(&endpoint(Some(DISCORD_TEMPLATE)), &event).unwrap();
+        assert!(serde_json::from_slice::<serde_json::Value>(&discord).is_ok());
+
+        assert!(render(&endpoint(Some("not json {{ event.id }}")), &event).is_err());
+        assert!(validate_template("{% if %}").is_err());
+    }
+}
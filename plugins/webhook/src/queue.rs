@@ -0,0 +1,128 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::WebhookEvent;
+
+pub const MAX_ATTEMPTS: u32 = 8;
+pub const MAX_FAILED_DELIVERIES: usize = 500;
+
+const BASE_DELAY_SECS: i64 = 30;
+const MAX_DELAY_SECS: i64 = 60 * 60;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct PendingDelivery {
+    pub id: String,
+    pub endpoint_id: String,
+    pub event: WebhookEvent,
+    pub attempts: u32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct FailedDelivery {
+    pub id: String,
+    pub endpoint_id: String,
+    pub endpoint_url: String,
+    pub event: WebhookEvent,
+    pub attempts: u32,
+    pub last_error: String,
+    pub failed_at: DateTime<Utc>,
+}
+
+/// 30s, 1m, 2m, 4m, ... capped at an hour.
+pub fn backoff(attempts: u32) -> Duration {
+    let exp = attempts.saturating_sub(1).min(16);
+    Duration::seconds((BASE_DELAY_SECS << exp).min(MAX_DELAY_SECS))
+}
+
+impl PendingDelivery {
+    pub fn new(endpoint_id: impl Into<String>, event: WebhookEvent) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            endpoint_id: endpoint_id.into(),
+            event,
+            attempts: 0,
+            next_attempt_at: Utc::now(),
+            last_error: None,
+        }
+    }
+
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.next_attempt_at <= now
+    }
+
+    /// Returns `false` once the delivery ran out of attempts.
+    pub fn record_failure(&mut self, error: String, now: DateTime<Utc>) -> bool {
+        self.attempts += 1;
+        self.last_error = Some(error);
+
+        if self.attempts >= MAX_ATTEMPTS {
+            return false;
+        }
+
+        self.next_attempt_at = now + backoff(self.attempts);
+        true
+    }
+
+    pub fn into_failed(self, endpoint_url: impl Into<String>) -> FailedDelivery {
+        FailedDelivery {
+            id: self.id,
+            endpoint_id: self.endpoint_id,
+            endpoint_url: endpoint_url.into(),
+            event: self.event,
+            attempts: self.attempts,
+            last_error: self.last_error.unwrap_or_default(),
+            failed_at: Utc::now(),
+        }
+    }
+}
+
+impl From<FailedDelivery> for PendingDelivery {
+    fn from(failed: FailedDelivery) -> Self {
+        Self {
+            id: failed.id,
+            endpoint_id: failed.endpoint_id,
+            event: failed.event,
+            attempts: 0,
+            next_attempt_at: Utc::now(),
+            last_error: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        assert_eq!(backoff(1), Duration::seconds(30));
+        assert_eq!(backoff(2), Duration::seconds(60));
+        assert_eq!(backoff(5), Duration::seconds(480));
+        assert_eq!(backoff(20), Duration::seconds(MAX_DELAY_SECS));
+    }
+
+    #[test]
+    fn test_record_failure() {
+        let event = crate::delivery::build_event("session.finished", serde_json::json!({}));
+        let mut delivery = PendingDelivery::new("endpoint", event);
+        let now = Utc::now();
+
+        assert!(delivery.is_due(now));
+        assert!(delivery.record_failure("timeout".to_string(), now));
+        assert!(!delivery.is_due(now));
+        assert_eq!(delivery.next_attempt_at, now + Duration::seconds(30));
+
+        for _ in 1..MAX_ATTEMPTS - 1 {
+            assert!(delivery.record_failure("timeout".to_string(), now));
+        }
+        assert!(!delivery.record_failure("timeout".to_string(), now));
+        assert_eq!(delivery.attempts, MAX_ATTEMPTS);
+
+        let failed = delivery.into_failed("https://example.com");
+        assert_eq!(failed.last_error, "timeout");
+
+        let retried = PendingDelivery::from(failed);
+        assert_eq!(retried.attempts, 0);
+    }
+}
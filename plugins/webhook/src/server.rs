@@ -0,0 +1,467 @@
+use std::net::{Ipv4Addr, SocketAddr};
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Path, Query, Request, State as AxumState,
+    },
+    http::{header, HeaderMap, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Json, Response},
+    routing::{delete, get, post},
+    Router,
+};
+use tauri::Manager;
+use tauri_plugin_db::DatabasePluginExt;
+use tauri_plugin_listener::{fsm, ListenerPluginExt};
+use tokio::sync::broadcast;
+
+use crate::{
+    CreateNoteRequest, CreateWebhookRequest, NoteResponse, StartRecordingRequest, StatusResponse,
+    WebhookConfig, WebhookEndpoint, WebhookEventType, WebhookListResponse, WebhookPluginExt,
+    WebhookResponse,
+};
+
+pub const API_KEY_HEADER: &str = "X-API-Key";
+pub const DEFAULT_PORT: u16 = 23541;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct InboundServerConfig {
+    pub enabled: bool,
+    pub port: u16,
+    pub api_key: String,
+    /// Serve live session events (transcripts, state changes, levels) over a WebSocket at
+    /// `/api/events`, for overlays. The latest caption lines are served as plain text at
+    /// `/api/captions` either way.
+    #[serde(default)]
+    pub stream_events: bool,
+}
+
+impl Default for InboundServerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: DEFAULT_PORT,
+            api_key: generate_api_key(),
+            stream_events: false,
+        }
+    }
+}
+
+pub fn generate_api_key() -> String {
+    format!("hypr_{}", uuid::Uuid::new_v4().simple())
+}
+
+pub struct ServerHandle {
+    pub addr: SocketAddr,
+    pub shutdown: tokio::sync::watch::Sender<()>,
+}
+
+struct ServerState<R: tauri::Runtime> {
+    app: tauri::AppHandle<R>,
+    api_key: String,
+}
+
+impl<R: tauri::Runtime> Clone for ServerState<R> {
+    fn clone(&self) -> Self {
+        Self {
+            app: self.app.clone(),
+            api_key: self.api_key.clone(),
+        }
+    }
+}
+
+struct ApiError(StatusCode, String);
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.0, self.1).into_response()
+    }
+}
+
+impl From<crate::Error> for ApiError {
+    fn from(e: crate::Error) -> Self {
+        let status = match &e {
+            crate::Error::InvalidUrl(_)
+            | crate::Error::InvalidPayload(_)
+            | crate::Error::TemplateError(_) => StatusCode::BAD_REQUEST,
+            crate::Error::EndpointNotFound(_) | crate::Error::DeliveryNotFound(_) => {
+                StatusCode::NOT_FOUND
+            }
+            crate::Error::HttpError(_) | crate::Error::UnexpectedStatus(_) => {
+                StatusCode::BAD_GATEWAY
+            }
+            crate::Error::DatabaseError(_) => StatusCode::SERVICE_UNAVAILABLE,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        Self(status, e.to_string())
+    }
+}
+
+pub async fn run_server<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    config: &InboundServerConfig,
+) -> Result<ServerHandle, crate::Error> {
+    let state = ServerState {
+        app,
+        api_key: config.api_key.clone(),
+    };
+
+    let mut router = Router::new()
+        .route("/api/status", get(status::<R>))
+        .route("/api/notes", post(create_note::<R>))
+        .route("/api/recording/start", post(start_recording::<R>))
+        .route("/api/recording/stop", post(stop_recording::<R>))
+        .route(
+            "/api/webhooks",
+            get(list_webhooks::<R>).post(create_webhook::<R>),
+        )
+        .route("/api/webhooks/{id}", delete(delete_webhook::<R>))
+        .route("/api/webhooks/{id}/test", post(test_webhook::<R>))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_api_key::<R>,
+        ))
+        // These check the key themselves, since browsers can't set headers on a WebSocket
+        // handshake and streaming tools only take a URL.
+        .route("/api/captions", get(captions::<R>));
+
+    if config.stream_events {
+        router = router.route("/api/events", get(stream_events::<R>));
+    }
+    let router = router.with_state(state);
+
+    // Loopback only. Anything on the network would need more than a static key.
+    let listener =
+        tokio::net::TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, config.port))).await?;
+
+    let server_addr = listener.local_addr()?;
+
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::watch::channel(());
+
+    tokio::spawn(async move {
+        if let Err(e) = axum::serve(listener, router)
+            .with_graceful_shutdown(async move {
+                shutdown_rx.changed().await.ok();
+            })
+            .await
+        {
+            tracing::error!("webhook_inbound_server_failed: {}", e);
+        }
+    });
+
+    tracing::info!("webhook_inbound_server_started {}", server_addr);
+    Ok(ServerHandle {
+        addr: server_addr,
+        shutdown: shutdown_tx,
+    })
+}
+
+async fn require_api_key<R: tauri::Runtime>(
+    AxumState(state): AxumState<ServerState<R>>,
+    req: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    check_header_or_query_key(req.headers(), None, &state.api_key)?;
+    Ok(next.run(req).await)
+}
+
+#[derive(serde::Deserialize)]
+struct EventsQuery {
+    api_key: Option<String>,
+    /// Comma-separated event types to receive, like `finalWords,partialWords`. All if unset.
+    types: Option<String>,
+}
+
+async fn stream_events<R: tauri::Runtime>(
+    AxumState(state): AxumState<ServerState<R>>,
+    Query(query): Query<EventsQuery>,
+    headers: HeaderMap,
+    ws: WebSocketUpgrade,
+) -> Result<Response, ApiError> {
+    check_header_or_query_key(&headers, query.api_key.as_deref(), &state.api_key)?;
+
+    let types = query.types.map(|types| {
+        types
+            .split(',')
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect::<Vec<_>>()
+    });
+    let events = state.app.state::<crate::State>().live_events.subscribe();
+
+    Ok(ws.on_upgrade(move |socket| forward_events(socket, events, types)))
+}
+
+fn check_header_or_query_key(
+    headers: &HeaderMap,
+    query_key: Option<&str>,
+    api_key: &str,
+) -> Result<(), ApiError> {
+    // Binding to loopback keeps other machines out, but not web pages that rebind a hostname
+    // they control to 127.0.0.1, so the `Host` has to be loopback too.
+    let host = headers
+        .get(header::HOST)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    if !hypr_local_api::is_loopback_host(host) {
+        return Err(ApiError(StatusCode::FORBIDDEN, "Invalid Host".to_string()));
+    }
+
+    let provided = headers
+        .get(API_KEY_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .or(query_key)
+        .unwrap_or_default();
+
+    if !hypr_local_api::keys_match(provided, api_key) {
+        return Err(ApiError(
+            StatusCode::UNAUTHORIZED,
+            "Invalid API key".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[derive(serde::Deserialize)]
+struct CaptionsQuery {
+    api_key: Option<String>,
+}
+
+async fn captions<R: tauri::Runtime>(
+    AxumState(state): AxumState<ServerState<R>>,
+    Query(query): Query<CaptionsQuery>,
+    headers: HeaderMap,
+) -> Result<String, ApiError> {
+    check_header_or_query_key(&headers, query.api_key.as_deref(), &state.api_key)?;
+    Ok(state.app.live_captions())
+}
+
+async fn forward_events(
+    mut socket: WebSocket,
+    mut events: broadcast::Receiver<serde_json::Value>,
+    types: Option<Vec<String>>,
+) {
+    loop {
+        tokio::select! {
+            event = events.recv() => match event {
+                Ok(event) => {
+                    let kind = event.get("type").and_then(|t| t.as_str()).unwrap_or_default();
+                    if types.as_ref().is_some_and(|types| !types.iter().any(|t| t == kind)) {
+                        continue;
+                    }
+                    if socket.send(Message::Text(event.to_string().into())).await.is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("live_events_client_lagged: {}", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            message = socket.recv() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                _ => {}
+            },
+        }
+    }
+}
+
+// A paused session is still the one `/api/recording/stop` ends.
+fn is_recording(state: &fsm::State) -> bool {
+    matches!(state, fsm::State::RunningActive | fsm::State::RunningPaused)
+}
+
+async fn current_status<R: tauri::Runtime>(app: &tauri::AppHandle<R>) -> StatusResponse {
+    StatusResponse {
+        recording: is_recording(&app.get_state().await),
+        session_id: app.get_session_id().await,
+    }
+}
+
+async fn status<R: tauri::Runtime>(
+    AxumState(state): AxumState<ServerState<R>>,
+) -> Json<StatusResponse> {
+    Json(current_status(&state.app).await)
+}
+
+async fn create_session<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    title: String,
+    content: &str,
+) -> Result<hypr_db_user::Session, ApiError> {
+    let user_id = app
+        .db_user_id()
+        .await
+        .map_err(crate::Error::from)?
+        .ok_or_else(|| {
+            ApiError(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "No signed-in user".to_string(),
+            )
+        })?;
+
+    let session = hypr_local_api::new_note(user_id, title, content);
+
+    app.db_upsert_session(session.clone())
+        .await
+        .map_err(crate::Error::from)?;
+    Ok(session)
+}
+
+async fn create_note<R: tauri::Runtime>(
+    AxumState(state): AxumState<ServerState<R>>,
+    Json(req): Json<CreateNoteRequest>,
+) -> Result<(StatusCode, Json<NoteResponse>), ApiError> {
+    let session = create_session(&state.app, req.title, &req.content).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(NoteResponse {
+            id: session.id,
+            title: session.title,
+            created_at: session.created_at.to_rfc3339(),
+        }),
+    ))
+}
+
+async fn start_recording<R: tauri::Runtime>(
+    AxumState(state): AxumState<ServerState<R>>,
+    req: Option<Json<StartRecordingRequest>>,
+) -> Result<Json<StatusResponse>, ApiError> {
+    let req = req.map(|Json(req)| req).unwrap_or_default();
+
+    if current_status(&state.app).await.recording {
+        return Err(ApiError(
+            StatusCode::CONFLICT,
+            "Already recording".to_string(),
+        ));
+    }
+
+    let session_id = match req.session_id {
+        Some(id) => {
+            state
+                .app
+                .db_get_session(&id)
+                .await
+                .map_err(crate::Error::from)?
+                .ok_or_else(|| {
+                    ApiError(StatusCode::NOT_FOUND, format!("Note not found: {}", id))
+                })?;
+            id
+        }
+        None => {
+            create_session(&state.app, req.title.unwrap_or_default(), "")
+                .await?
+                .id
+        }
+    };
+
+    state.app.start_session(session_id.clone()).await;
+
+    Ok(Json(StatusResponse {
+        recording: true,
+        session_id: Some(session_id),
+    }))
+}
+
+async fn stop_recording<R: tauri::Runtime>(
+    AxumState(state): AxumState<ServerState<R>>,
+) -> Result<Json<StatusResponse>, ApiError> {
+    let status = current_status(&state.app).await;
+    if !status.recording {
+        return Err(ApiError(StatusCode::CONFLICT, "Not recording".to_string()));
+    }
+
+    state.app.stop_session().await;
+
+    Ok(Json(StatusResponse {
+        recording: false,
+        session_id: status.session_id,
+    }))
+}
+
+fn webhook_response(endpoint: WebhookEndpoint) -> WebhookResponse {
+    WebhookResponse {
+        id: endpoint.id,
+        config: WebhookConfig {
+            url: endpoint.url,
+            events: endpoint.events.iter().map(|e| e.to_string()).collect(),
+            active: endpoint.active,
+        },
+        secret: endpoint.secret,
+        created_at: endpoint.created_at,
+    }
+}
+
+async fn list_webhooks<R: tauri::Runtime>(
+    AxumState(state): AxumState<ServerState<R>>,
+) -> Result<Json<WebhookListResponse>, ApiError> {
+    let webhooks: Vec<_> = state
+        .app
+        .list_endpoints()?
+        .into_iter()
+        .map(webhook_response)
+        .collect();
+
+    Ok(Json(WebhookListResponse {
+        total: webhooks.len(),
+        webhooks,
+    }))
+}
+
+async fn create_webhook<R: tauri::Runtime>(
+    AxumState(state): AxumState<ServerState<R>>,
+    Json(req): Json<CreateWebhookRequest>,
+) -> Result<(StatusCode, Json<WebhookResponse>), ApiError> {
+    let events = req
+        .config
+        .events
+        .iter()
+        .map(|e| {
+            e.parse::<WebhookEventType>().map_err(|_| {
+                ApiError(
+                    StatusCode::BAD_REQUEST,
+                    format!("Unknown event type: {}", e),
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut endpoint = state.app.add_endpoint(req.config.url, None, events, None)?;
+
+    if !req.config.active {
+        endpoint.active = false;
+        state.app.update_endpoint(endpoint.clone())?;
+    }
+
+    Ok((StatusCode::CREATED, Json(webhook_response(endpoint))))
+}
+
+async fn delete_webhook<R: tauri::Runtime>(
+    AxumState(state): AxumState<ServerState<R>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    state.app.remove_endpoint(id)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn test_webhook<R: tauri::Runtime>(
+    AxumState(state): AxumState<ServerState<R>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    state.app.test_endpoint(id).await?;
+    Ok(StatusCode::OK)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_recording() {
+        assert!(is_recording(&fsm::State::RunningActive));
+        assert!(is_recording(&fsm::State::RunningPaused));
+        assert!(!is_recording(&fsm::State::Inactive));
+    }
+}
@@ -0,0 +1,79 @@
+use tauri::Manager;
+use tauri_plugin_db::DatabasePluginExt;
+use tauri_plugin_listener::{ListenerPluginExt, SessionEvent};
+use tauri_specta::Event;
+
+use crate::{WebhookEventType, WebhookPluginExt};
+
+/// Dispatches `session.finished` and `transcript.finalized` when a recording stops, and
+/// passes every session event on to clients of the inbound server's event stream.
+///
+/// The listener clears its session id before going inactive, so we remember it when recording starts.
+pub fn watch<R: tauri::Runtime>(app: &tauri::AppHandle<R>) {
+    let app_handle = app.clone();
+
+    SessionEvent::listen_any(app, move |event| {
+        app_handle
+            .state::<crate::State>()
+            .live_events
+            .publish(&event.payload);
+
+        match event.payload {
+            SessionEvent::RunningActive {} => {
+                let app = app_handle.clone();
+                tauri::async_runtime::spawn(async move {
+                    let session_id = app.get_session_id().await;
+                    *app.state::<crate::State>().active_session.lock().unwrap() = session_id;
+                });
+            }
+            SessionEvent::Inactive {} => {
+                let session_id = app_handle
+                    .state::<crate::State>()
+                    .active_session
+                    .lock()
+                    .unwrap()
+                    .take();
+
+                if let Some(session_id) = session_id {
+                    let app = app_handle.clone();
+                    tauri::async_runtime::spawn(async move {
+                        if let Ok(Some(session)) = app.db_get_session(&session_id).await {
+                            notify(&app, &session);
+                        }
+                    });
+                }
+            }
+            _ => {}
+        }
+    });
+}
+
+fn notify<R: tauri::Runtime>(app: &tauri::AppHandle<R>, session: &hypr_db_user::Session) {
+    let _ = app.dispatch_webhook(
+        WebhookEventType::SessionFinished,
+        serde_json::json!({
+            "session_id": session.id,
+            "title": session.title,
+            "record_start": session.record_start,
+            "record_end": session.record_end,
+        }),
+    );
+
+    if !session.words.is_empty() {
+        let text = session
+            .words
+            .iter()
+            .map(|w| w.text.trim())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let _ = app.dispatch_webhook(
+            WebhookEventType::TranscriptFinalized,
+            serde_json::json!({
+                "session_id": session.id,
+                "text": text,
+                "words": session.words,
+            }),
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+pub const SIGNATURE_HEADER: &str = "X-Hyprnote-Signature";
+pub const TIMESTAMP_HEADER: &str = "X-Hyprnote-Timestamp";
+pub const EVENT_HEADER: &str = "X-Hyprnote-Event";
+pub const DELIVERY_HEADER: &str = "X-Hyprnote-Delivery";
+
+/// Signs `{timestamp}.{body}`, so receivers can reject replayed requests.
+pub fn sign(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign() {
+        assert_eq!(
+            sign("whsec_test", 1700000000, br#"{"hello":"world"}"#),
+            "sha256=f592bbf3951cfc94e560eecfb5d9dd4da6b0fff2e626235f8ab4b54860925d0b"
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use tauri_plugin_store2::ScopedStoreKey;
+
+#[derive(serde::Deserialize, specta::Type, PartialEq, Eq, Hash, strum::Display)]
+pub enum StoreKey {
+    Endpoints,
+    PendingDeliveries,
+    FailedDeliveries,
+    InboundServer,
+    DeliveryLog,
+}
+
+impl ScopedStoreKey for StoreKey {}
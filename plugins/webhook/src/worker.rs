@@ -0,0 +1,20 @@
+use crate::WebhookPluginExt;
+
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15);
+
+pub async fn run<R: tauri::Runtime>(app: tauri::AppHandle<R>) {
+    use tauri::Manager;
+
+    let wake = app.state::<crate::State>().wake.clone();
+
+    loop {
+        if let Err(e) = app.process_deliveries().await {
+            tracing::error!("webhook_process_deliveries_failed: {}", e);
+        }
+
+        tokio::select! {
+            _ = wake.notified() => {}
+            _ = tokio::time::sleep(POLL_INTERVAL) => {}
+        }
+    }
+}
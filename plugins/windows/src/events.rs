@@ -19,6 +19,16 @@ pub fn on_window_event(window: &tauri::Window<tauri::Wry>, event: &tauri::Window
                             if let Err(e) = app.handle_main_window_visibility(false) {
                                 tracing::error!("failed_to_handle_main_window_visibility: {:?}", e);
                             }
+                        } else {
+                            // Without hiding, closing it quits the app, so ask like the tray's Quit does.
+                            api.prevent_close();
+
+                            let app = app.clone();
+                            std::thread::spawn(move || {
+                                if hypr_intercept::confirm_quit() {
+                                    app.exit(0);
+                                }
+                            });
                         }
                     }
                 }
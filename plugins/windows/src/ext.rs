@@ -26,6 +26,10 @@ pub enum HyprWindow {
     Video(String),
     #[serde(rename = "control")]
     Control,
+    #[serde(rename = "overlay")]
+    Overlay,
+    #[serde(rename = "captions")]
+    Captions,
 }
 
 impl std::fmt::Display for HyprWindow {
@@ -39,6 +43,8 @@ impl std::fmt::Display for HyprWindow {
             Self::Settings => write!(f, "settings"),
             Self::Video(id) => write!(f, "video-{}", id),
             Self::Control => write!(f, "control"),
+            Self::Overlay => write!(f, "overlay"),
+            Self::Captions => write!(f, "captions"),
         }
     }
 }
@@ -51,6 +57,8 @@ impl std::str::FromStr for HyprWindow {
             "main" => return Ok(Self::Main),
             "finder" => return Ok(Self::Finder),
             "settings" => return Ok(Self::Settings),
+            "overlay" => return Ok(Self::Overlay),
+            "captions" => return Ok(Self::Captions),
             _ => {}
         }
 
@@ -137,6 +145,8 @@ impl HyprWindow {
             Self::Settings => "Settings".into(),
             Self::Video(_) => "Video".into(),
             Self::Control => "Control".into(),
+            Self::Overlay => "Recording".into(),
+            Self::Captions => "Captions".into(),
         }
     }
 
@@ -252,8 +262,14 @@ impl HyprWindow {
     }
 
     pub fn show(&self, app: &AppHandle<tauri::Wry>) -> Result<WebviewWindow, crate::Error> {
+        // The overlay and captions float over whatever app the meeting is in, so they never
+        // take focus.
+        let focus = !matches!(self, Self::Overlay | Self::Captions);
+
         #[cfg(target_os = "macos")]
-        let _ = app.set_activation_policy(tauri::ActivationPolicy::Regular);
+        if focus {
+            let _ = app.set_activation_policy(tauri::ActivationPolicy::Regular);
+        }
 
         if self == &Self::Main {
             use tauri_plugin_analytics::{AnalyticsPayload, AnalyticsPluginExt};
@@ -277,7 +293,9 @@ impl HyprWindow {
 
         if let Some(window) = self.get(app) {
             window.show()?;
-            window.set_focus()?;
+            if focus {
+                window.set_focus()?;
+            }
             return Ok(window);
         }
 
@@ -428,9 +446,66 @@ impl HyprWindow {
 
                 window
             }
+            Self::Overlay => {
+                const WIDTH: f64 = 260.0;
+                const HEIGHT: f64 = 56.0;
+                const MARGIN: f64 = 16.0;
+
+                let monitor_width = (monitor.size().width as f64) / monitor.scale_factor();
+
+                WebviewWindow::builder(app, self.label(), WebviewUrl::App("/app/overlay".into()))
+                    .title(self.title())
+                    .disable_drag_drop_handler()
+                    .decorations(false)
+                    .transparent(true)
+                    .shadow(false)
+                    .resizable(false)
+                    .maximizable(false)
+                    .minimizable(false)
+                    .always_on_top(true)
+                    .visible_on_all_workspaces(true)
+                    .skip_taskbar(true)
+                    .accept_first_mouse(true)
+                    .focused(false)
+                    .inner_size(WIDTH, HEIGHT)
+                    .position(monitor_width - WIDTH - MARGIN, MARGIN * 3.0)
+                    .build()?
+            }
+            Self::Captions => {
+                const HEIGHT: f64 = 180.0;
+                const MARGIN: f64 = 48.0;
+
+                let monitor_width = (monitor.size().width as f64) / monitor.scale_factor();
+                let monitor_height = (monitor.size().height as f64) / monitor.scale_factor();
+                let width = (monitor_width * 0.6).max(480.0);
+
+                WebviewWindow::builder(app, self.label(), WebviewUrl::App("/app/captions".into()))
+                    .title(self.title())
+                    .disable_drag_drop_handler()
+                    .decorations(false)
+                    .transparent(true)
+                    .shadow(false)
+                    .resizable(true)
+                    .maximizable(false)
+                    .minimizable(false)
+                    .always_on_top(true)
+                    .visible_on_all_workspaces(true)
+                    .skip_taskbar(true)
+                    .accept_first_mouse(true)
+                    .focused(false)
+                    .min_inner_size(320.0, 96.0)
+                    .inner_size(width, HEIGHT)
+                    .position(
+                        (monitor_width - width) / 2.0,
+                        monitor_height - HEIGHT - MARGIN,
+                    )
+                    .build()?
+            }
         };
 
-        window.set_focus()?;
+        if focus {
+            window.set_focus()?;
+        }
         window.show()?;
 
         if self == &Self::Main {